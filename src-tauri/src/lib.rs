@@ -3,11 +3,25 @@ mod utils;
 
 use tauri::Manager;
 use tools::global_shortcut::GlobalShortcutState;
+use tools::load_tester::LoadTesterState;
+use tools::mock_server::MockServerState;
+use tools::mq_client::MqClientState;
+use tools::sse_client::SseClientState;
+use tools::static_server::StaticServerState;
 use tools::system_settings::GlobalTrayState;
+use tools::watch_folder::WatchFolderState;
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
+        // 单实例需最先注册，第二个实例收到的 devtools:// 链接会转交给已运行的实例
+        .plugin(tauri_plugin_single_instance::init(|app, _argv, _cwd| {
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.show();
+                let _ = window.set_focus();
+            }
+        }))
+        .plugin(tauri_plugin_deep_link::init())
         .plugin(tauri_plugin_autostart::init(
             tauri_plugin_autostart::MacosLauncher::LaunchAgent,
             Some(vec!["--autostart"]),
@@ -15,6 +29,7 @@ pub fn run() {
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_opener::init())
+        .plugin(tauri_plugin_notification::init())
         .plugin(
             tauri_plugin_global_shortcut::Builder::new()
                 .with_handler(|app_handle, shortcut, event| {
@@ -28,54 +43,284 @@ pub fn run() {
         )
         .manage(GlobalTrayState::new())
         .manage(GlobalShortcutState::new())
-        .invoke_handler(tauri::generate_handler![
+        .manage(LoadTesterState::new())
+        .manage(MockServerState::new())
+        .manage(MqClientState::new())
+        .manage(SseClientState::new())
+        .manage(StaticServerState::new())
+        .manage(WatchFolderState::new())
+        .invoke_handler(utils::usage::track(tauri::generate_handler![
+            tools::app_data::export_app_data,
+            tools::app_data::import_app_data,
+            tools::archive_tool::list_archive,
+            tools::archive_tool::extract_archive,
             tools::autostart::set_autostart,
             tools::autostart::get_autostart_status,
             tools::certificate_converter::convert_pfx_to_pem,
             tools::certificate_converter::convert_pem_to_pfx,
             tools::certificate_viewer::parse_pem_certificate,
             tools::certificate_viewer::parse_pfx_certificate,
+            tools::certificate_viewer::get_certificate_details,
+            tools::cert_scanner::scan_certificates_directory,
+            tools::cheatsheet::list_reference_categories,
+            tools::cheatsheet::search_reference,
+            tools::cheatsheet::lookup_http_status,
+            tools::cheatsheet::lookup_mime_type,
+            tools::checksum_tool::verify_checksum,
+            tools::checksum_tool::generate_sri,
+            tools::csv_tool::preview_csv,
+            tools::csv_tool::convert_csv,
+            tools::csv_tool::csv_to_sql,
+            tools::format_tool::format_code,
+            tools::dns_tool::check_email_security,
+            tools::docker_tool::analyze_dockerfile,
+            tools::docker_tool::list_image_tags,
+            tools::docker_tool::inspect_image_manifest,
+            tools::email_tool::parse_eml,
+            tools::email_tool::send_test_email,
+            tools::env_tool::parse_env_file,
+            tools::env_tool::compare_env_files,
+            tools::env_tool::validate_env_schema,
+            tools::env_tool::merge_env_files,
+            tools::excel_tool::list_excel_sheets,
+            tools::excel_tool::read_excel,
+            tools::excel_tool::write_excel,
+            tools::git_tool::list_gitignore_templates,
+            tools::git_tool::generate_gitignore,
+            tools::git_tool::lint_commit_message,
             tools::global_shortcut::register_global_shortcut,
             tools::global_shortcut::unregister_global_shortcut,
             tools::global_shortcut::get_global_shortcut_config,
             tools::global_shortcut::set_global_shortcut_enabled,
+            tools::global_shortcut::set_screenshot_shortcut,
+            tools::deep_link::take_pending_deep_link,
+            tools::deep_link::parse_deep_link,
+            tools::go_struct_converter::convert_go_to_json,
+            tools::go_struct_converter::convert_go_to_sql,
+            tools::graphql_tool::graphql_sdl_to_code,
+            tools::graphql_tool::graphql_format_query,
+            tools::graphql_tool::graphql_introspect,
+            tools::http_collection::import_http_collection,
+            tools::http_collection::list_http_collections,
+            tools::http_collection::save_http_collection,
+            tools::http_collection::delete_http_collection,
+            tools::http_collection::export_http_collection,
+            tools::id_timestamp::audit_id_timestamps,
+            tools::idn_tool::convert_punycode,
+            tools::idn_tool::analyze_homograph,
             tools::ip_info::query_ip_info,
+            tools::ip_info::get_my_ip,
             tools::json_to_go::convert_json_to_go,
+            tools::json_to_go::convert_json_samples_to_go,
+            tools::k8s_tool::validate_k8s_manifest,
+            tools::k8s_tool::parse_kubeconfig,
+            tools::keystore_tool::list_keystore_entries,
+            tools::keystore_tool::export_keystore_entry,
+            tools::keystore_tool::add_keystore_entry,
+            tools::keystore_tool::remove_keystore_entry,
+            tools::keystore_tool::change_keystore_password,
+            tools::key_generator::generate_keypair,
+            tools::key_generator::compute_ssh_fingerprint,
+            tools::key_generator::sign_data,
+            tools::key_generator::verify_signature,
+            tools::log_analyzer::analyze_log,
+            tools::mock_server::start_mock_server,
+            tools::mock_server::stop_mock_server,
+            tools::mock_server::list_mock_servers,
+            tools::mock_server::update_mock_routes,
+            tools::mq_client::mq_produce,
+            tools::mq_client::mq_consume_start,
+            tools::mq_client::mq_consume_stop,
+            tools::kv_console::kv_execute,
+            tools::kv_console::kv_stats,
+            tools::network_probe::ping_host,
+            tools::network_probe::traceroute,
+            tools::pdf_tool::get_pdf_info,
+            tools::pdf_tool::merge_pdfs,
+            tools::pdf_tool::split_pdf,
+            tools::pdf_tool::extract_pdf_text,
+            tools::pdf_tool::strip_pdf_metadata,
+            tools::plugins::list_plugins,
+            tools::plugins::get_plugins_directory,
+            tools::plugins::trust_plugin,
+            tools::plugins::revoke_plugin_trust,
+            tools::plugins::run_plugin,
             tools::regex_tester::test_regex,
             tools::regex_tester::replace_regex,
+            tools::regex_tester::test_regex_file,
             tools::regex_tester::validate_regex,
+            tools::regex_library::get_regex_library,
+            tools::regex_library::save_regex_pattern,
+            tools::regex_library::delete_regex_pattern,
+            tools::regex_library::set_regex_pattern_favorite,
+            tools::snippet_generator::list_snippet_templates,
+            tools::snippet_generator::save_snippet_template,
+            tools::snippet_generator::delete_snippet_template,
+            tools::snippet_generator::render_snippet,
+            tools::snippet_generator::export_snippet_pack,
+            tools::snippet_generator::import_snippet_pack,
+            tools::package_lookup::lookup_package,
+            tools::semver_tool::evaluate_semver,
+            tools::license_tool::list_licenses,
+            tools::license_tool::detect_license,
+            tools::license_tool::check_license_compatibility,
+            tools::hex_viewer::read_file_hex,
+            tools::asn1_tool::decode_asn1,
+            tools::protobuf_wire::decode_protobuf_wire,
+            tools::binary_json_tool::convert_binary_json,
+            tools::compression_tool::compress_data,
+            tools::unicode_inspector::inspect_text,
+            tools::string_escape::escape_string,
+            tools::slug_tool::generate_slug,
+            tools::slug_tool::sanitize_filename,
+            tools::site_icons::analyze_site_icons,
+            tools::robots_sitemap::check_robots,
+            tools::robots_sitemap::check_sitemap,
+            tools::dns_propagation::list_public_resolvers,
+            tools::dns_propagation::check_dns_propagation,
+            tools::proxy_headers::inspect_proxy_headers,
+            tools::load_tester::start_load_test,
+            tools::load_tester::stop_load_test,
+            tools::cloud_latency::list_cloud_endpoints,
+            tools::cloud_latency::measure_cloud_latency,
+            tools::fake_finance::generate_test_numbers,
+            tools::fake_finance::validate_test_number,
+            tools::cn_validators::validate_cn_id_card,
+            tools::cn_validators::decode_uscc,
+            tools::cn_validators::lookup_bank_card,
             tools::sql_to_go::convert_sql_to_go,
+            tools::sql_to_go::list_sql_type_profiles,
+            tools::sql_to_go::save_sql_type_profile,
+            tools::sql_to_go::delete_sql_type_profile,
             tools::sql_to_ent::convert_sql_to_ent,
+            tools::sse_client::sse_connect,
+            tools::sse_client::sse_disconnect,
+            tools::static_server::start_static_server,
+            tools::static_server::stop_static_server,
+            tools::static_server::list_static_servers,
+            tools::ssh_tool::convert_ssh_key,
+            tools::ssh_tool::inspect_ssh_keys,
+            tools::ssh_audit::scan_ssh,
             tools::ssl_checker::check_ssl_info,
+            tools::ssl_history::get_ssl_check_history,
+            tools::ssl_history::get_ssl_check_record,
+            tools::ssl_history::diff_ssl_checks,
+            tools::ssl_history::clear_ssl_check_history,
+            tools::trust_store::get_trust_store,
+            tools::trust_store::import_trusted_cas,
+            tools::trust_store::remove_trusted_ca,
+            tools::trust_store::set_use_system_trust_store,
+            tools::http3_probe::probe_http3,
+            tools::spki_pin::calculate_spki_pins,
+            tools::san_coverage::analyze_san_coverage,
             tools::system_settings::toggle_tray,
             tools::system_settings::get_tray_status,
             tools::system_settings::set_start_minimized,
             tools::system_settings::get_start_minimized_status,
             tools::system_settings::set_close_to_tray,
             tools::system_settings::get_close_to_tray_status,
+            tools::system_settings::get_tray_actions,
+            tools::system_settings::set_tray_actions,
+            tools::system_settings::get_language,
+            tools::system_settings::set_language,
+            tools::system_settings::get_network_aggressiveness,
+            tools::system_settings::set_network_aggressiveness,
+            tools::system_settings::get_proxy_config,
+            tools::system_settings::set_proxy_config,
+            tools::system_settings::get_system_proxy,
+            tools::system_settings::test_proxy,
+            tools::system_settings::get_usage_tracking,
+            tools::system_settings::set_usage_tracking,
+            tools::system_settings::get_usage_stats,
+            tools::system_settings::export_usage_stats,
+            tools::system_settings::clear_usage_stats,
+            tools::system_settings::get_message_catalog,
+            tools::system_settings::get_notification_settings,
+            tools::system_settings::set_notification_settings,
+            tools::system_settings::get_notification_history,
+            tools::system_settings::mark_notifications_read,
+            tools::system_settings::clear_notification_history,
+            tools::text_encoding::detect_text_encoding,
+            tools::text_encoding::convert_text_encoding,
+            tools::text_encoding::get_supported_encodings,
+            tools::unit_converter::convert_units,
+            tools::url_tool::parse_url,
+            tools::url_tool::build_url,
+            tools::url_tool::parse_query_string,
+            tools::url_tool::serialize_query_string,
+            tools::url_tool::form_to_json,
+            tools::url_tool::json_to_form,
+            tools::url_tool::convert_idn,
+            tools::user_agent::parse_user_agent,
+            tools::user_agent::generate_user_agents,
             tools::video_converter::convert_video,
             tools::video_converter::get_video_info,
             tools::video_converter::check_ffmpeg_available,
+            tools::video_converter::list_video_presets,
+            tools::video_converter::save_video_preset,
+            tools::video_converter::delete_video_preset,
+            tools::animation_converter::convert_video_to_animation,
+            tools::animation_converter::convert_animation_format,
+            tools::audio_analyzer::analyze_audio,
+            tools::ocr_tool::get_ocr_environment,
+            tools::ocr_tool::extract_text_from_image,
+            tools::screenshot::capture_screenshot,
+            tools::screenshot::decode_qr_code,
+            tools::watch_folder::get_watch_rules,
+            tools::watch_folder::save_watch_rule,
+            tools::watch_folder::remove_watch_rule,
+            tools::watch_folder::set_watch_rule_enabled,
+            tools::watch_folder::get_watch_history,
+            tools::watch_folder::clear_watch_history,
+            tools::web_server_config::parse_nginx_config,
+            tools::web_server_config::parse_apache_config,
             tools::image_converter::convert_image,
+            tools::image_converter::convert_images,
             tools::image_converter::get_image_info_command,
             tools::image_converter::get_image_exif_data,
             tools::whois::query_rdap,
             tools::whois::query_whois,
             tools::whois::query_domain_multi_source,
-            tools::whois::query_whois_unified
-        ])
+            tools::whois::query_whois_unified,
+            tools::whois::get_whois_cache,
+            tools::whois::clear_whois_cache,
+            tools::workspaces::list_workspaces,
+            tools::workspaces::save_workspace,
+            tools::workspaces::load_workspace,
+            tools::workspaces::rename_workspace,
+            tools::workspaces::delete_workspace,
+            tools::domain_monitor::get_domain_watchlist,
+            tools::domain_monitor::save_domain_watch,
+            tools::domain_monitor::remove_domain_watch,
+            tools::domain_monitor::set_domain_monitor_interval,
+            tools::domain_monitor::check_domain_expiry,
+            tools::scheduler::list_scheduled_tasks,
+            tools::scheduler::save_scheduled_task,
+            tools::scheduler::remove_scheduled_task,
+            tools::scheduler::run_scheduled_task,
+            tools::scheduler::get_scheduled_task_runs,
+            tools::scheduler::preview_cron_schedule,
+            tools::uptime_monitor::list_uptime_monitors,
+            tools::uptime_monitor::save_uptime_monitor,
+            tools::uptime_monitor::remove_uptime_monitor,
+            tools::uptime_monitor::check_uptime_monitor,
+            tools::uptime_monitor::get_uptime_history,
+            tools::uptime_monitor::clear_uptime_history
+        ]))
         .setup(|app| {
             let tray_state = app.state::<GlobalTrayState>();
             let shortcut_state = app.state::<GlobalShortcutState>();
 
             // 检查是否启用托盘
-            let should_show_tray = {
+            let (should_show_tray, tray_actions) = {
                 let config = tray_state.config.lock().unwrap();
-                config.tray_enabled
+                (config.tray_enabled, config.tray_actions.clone())
             };
 
             if should_show_tray {
-                if let Ok(tray) = tools::system_settings::create_tray_icon(app.handle()) {
+                if let Ok(tray) =
+                    tools::system_settings::create_tray_icon(app.handle(), &tray_actions)
+                {
                     if let Ok(mut tray_icon) = tray_state.tray_icon.lock() {
                         *tray_icon = Some(tray);
                     }
@@ -89,6 +334,15 @@ pub fn run() {
                 eprintln!("Failed to initialize global shortcut: {}", e);
             }
 
+            // 处理 devtools:// 链接
+            tools::deep_link::setup_deep_link(app.handle());
+
+            // 启动后台调度：定时检查任务、可用性监控和域名到期检查
+            tools::scheduler::spawn_scheduler(app.handle().clone());
+
+            // 启动监控文件夹自动转换
+            tools::watch_folder::spawn_watch_folders(app.handle().clone());
+
             // 检查是否为自启动模式，如果是且启用了启动时最小化，则隐藏窗口
             let args: Vec<String> = std::env::args().collect();
             let is_autostart = args.contains(&"--autostart".to_string());
@@ -0,0 +1,412 @@
+use crate::tools::video_converter;
+use image::codecs::webp::WebPDecoder;
+use image::AnimationDecoder;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+const ANIMATION_FORMATS: [&str; 3] = ["gif", "webp", "apng"];
+const DITHER_MODES: [&str; 6] = [
+    "none",
+    "bayer",
+    "floyd_steinberg",
+    "sierra2",
+    "sierra2_4a",
+    "sierra3",
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnimationRequest {
+    pub input_path: String,
+    pub output_path: String,        // 为空时输出到输入文件所在目录
+    pub format: String,             // gif, webp, apng
+    pub start_time: Option<f64>,    // 截取起始时间（秒）
+    pub duration: Option<f64>,      // 截取时长（秒）
+    pub fps: Option<u32>,           // 帧率，默认 12
+    pub width: Option<u32>,         // 仅指定一边时按比例缩放
+    pub height: Option<u32>,        // 仅指定一边时按比例缩放
+    pub loop_count: Option<u32>,    // 播放次数，0 表示无限循环
+    pub max_colors: Option<u32>,    // GIF 调色板颜色数 2-256
+    pub dither: Option<String>,     // GIF 抖动算法
+    pub bayer_scale: Option<u8>,    // bayer 抖动强度 0-5
+    pub diff_palette: Option<bool>, // GIF 按帧差异生成调色板，适合静态背景
+    pub quality: Option<u8>,        // WebP 质量 0-100
+    pub lossless: Option<bool>,     // WebP 无损
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnimationResponse {
+    pub success: bool,
+    pub output_path: String,
+    pub format: String,
+    pub original_size: u64,
+    pub output_size: u64,
+    pub message: String,
+}
+
+/// 将视频片段转换为 GIF / 动态 WebP / APNG
+#[tauri::command]
+pub async fn convert_video_to_animation(
+    request: AnimationRequest,
+) -> Result<AnimationResponse, String> {
+    let input_path = video_converter::validate_input_file(&request.input_path)?;
+    run_animation_conversion(&input_path, &request)
+}
+
+/// 在 GIF / 动态 WebP / APNG 之间互相转换
+#[tauri::command]
+pub async fn convert_animation_format(
+    request: AnimationRequest,
+) -> Result<AnimationResponse, String> {
+    let input_path = PathBuf::from(&request.input_path);
+    if !input_path.is_file() {
+        return Err(format!("输入文件不存在: {}", request.input_path));
+    }
+    let input_format = animation_format_of(&input_path)
+        .ok_or_else(|| "仅支持 GIF、WebP、APNG 格式的动图".to_string())?;
+    if input_format == request.format.to_lowercase() {
+        return Err("目标格式与源格式相同".to_string());
+    }
+    run_animation_conversion(&input_path, &request)
+}
+
+fn run_animation_conversion(
+    input_path: &Path,
+    request: &AnimationRequest,
+) -> Result<AnimationResponse, String> {
+    ensure_ffmpeg()?;
+    validate_request(request)?;
+    let format = request.format.to_lowercase();
+    let output_path = resolve_output_path(input_path, &request.output_path, &format);
+    if let Some(parent) = output_path.parent() {
+        if !parent.exists() {
+            fs::create_dir_all(parent).map_err(|e| format!("创建输出目录失败: {}", e))?;
+        }
+    }
+
+    // FFmpeg 无法解码动态 WebP，先拆成 PNG 帧序列再交给 concat 输入
+    let frames_dir = if animation_format_of(input_path) == Some("webp") {
+        Some(extract_webp_frames(input_path)?)
+    } else {
+        None
+    };
+    let input_args = match &frames_dir {
+        Some(dir) => vec![
+            "-f".to_string(),
+            "concat".to_string(),
+            "-safe".to_string(),
+            "0".to_string(),
+            "-i".to_string(),
+            dir.join("frames.txt").to_string_lossy().to_string(),
+        ],
+        None => vec!["-i".to_string(), input_path.to_string_lossy().to_string()],
+    };
+
+    let mut command = Command::new("ffmpeg");
+    if let Some(start) = request.start_time.filter(|s| *s > 0.0) {
+        command.arg("-ss").arg(format!("{:.3}", start));
+    }
+    if let Some(duration) = request.duration {
+        command.arg("-t").arg(format!("{:.3}", duration));
+    }
+    let output = command
+        .args(&input_args)
+        .args(build_animation_args(request))
+        .arg("-y")
+        .arg(&output_path)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .map_err(|e| format!("启动 FFmpeg 进程失败: {}", e));
+    if let Some(dir) = &frames_dir {
+        let _ = fs::remove_dir_all(dir);
+    }
+    let output = output?;
+    if !output.status.success() {
+        return Err(format!(
+            "动图转换失败: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    let original_size = fs::metadata(input_path).map(|m| m.len()).unwrap_or(0);
+    let output_size = fs::metadata(&output_path).map(|m| m.len()).unwrap_or(0);
+    Ok(AnimationResponse {
+        success: true,
+        output_path: output_path.to_string_lossy().to_string(),
+        format: format.clone(),
+        original_size,
+        output_size,
+        message: format!(
+            "已生成 {}，大小 {:.2} MB",
+            format.to_uppercase(),
+            output_size as f64 / 1024.0 / 1024.0
+        ),
+    })
+}
+
+fn ensure_ffmpeg() -> Result<(), String> {
+    match Command::new("ffmpeg")
+        .arg("-version")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+    {
+        Ok(status) if status.success() => Ok(()),
+        _ => Err("FFmpeg 未安装，请先安装 FFmpeg".to_string()),
+    }
+}
+
+fn validate_request(request: &AnimationRequest) -> Result<(), String> {
+    if !ANIMATION_FORMATS.contains(&request.format.to_lowercase().as_str()) {
+        return Err(format!("不支持的动图格式: {}", request.format));
+    }
+    if request.fps.is_some_and(|fps| fps == 0 || fps > 60) {
+        return Err("帧率取值范围为 1-60".to_string());
+    }
+    if request.width == Some(0) || request.height == Some(0) {
+        return Err("尺寸必须大于 0".to_string());
+    }
+    if request.start_time.is_some_and(|s| s < 0.0) || request.duration.is_some_and(|d| d <= 0.0) {
+        return Err("截取时间无效".to_string());
+    }
+    if request.max_colors.is_some_and(|c| !(2..=256).contains(&c)) {
+        return Err("调色板颜色数取值范围为 2-256".to_string());
+    }
+    if let Some(dither) = &request.dither {
+        if !DITHER_MODES.contains(&dither.as_str()) {
+            return Err(format!("不支持的抖动算法: {}", dither));
+        }
+    }
+    if request.bayer_scale.is_some_and(|s| s > 5) {
+        return Err("bayer 抖动强度取值范围为 0-5".to_string());
+    }
+    if request.quality.is_some_and(|q| q > 100) {
+        return Err("WebP 质量取值范围为 0-100".to_string());
+    }
+    Ok(())
+}
+
+/// 生成 FFmpeg 滤镜与编码参数
+fn build_animation_args(request: &AnimationRequest) -> Vec<String> {
+    let fps = request.fps.unwrap_or(12);
+    let mut filters = vec![format!("fps={}", fps)];
+    match (request.width, request.height) {
+        (Some(w), Some(h)) => filters.push(format!("scale={}:{}:flags=lanczos", w, h)),
+        (Some(w), None) => filters.push(format!("scale={}:-1:flags=lanczos", w)),
+        (None, Some(h)) => filters.push(format!("scale=-1:{}:flags=lanczos", h)),
+        (None, None) => {}
+    }
+    let base = filters.join(",");
+    let plays = request.loop_count.unwrap_or(0);
+    let mut args = Vec::new();
+
+    match request.format.to_lowercase().as_str() {
+        "gif" => {
+            // 两遍调色板：先统计颜色生成调色板，再按调色板抖动输出
+            let stats_mode = if request.diff_palette.unwrap_or(false) {
+                "diff"
+            } else {
+                "full"
+            };
+            let dither = request.dither.as_deref().unwrap_or("sierra2_4a");
+            let mut paletteuse = format!("paletteuse=dither={}", dither);
+            if dither == "bayer" {
+                paletteuse.push_str(&format!(
+                    ":bayer_scale={}",
+                    request.bayer_scale.unwrap_or(2)
+                ));
+            }
+            if stats_mode == "diff" {
+                paletteuse.push_str(":diff_mode=rectangle");
+            }
+            args.push("-filter_complex".to_string());
+            args.push(format!(
+                "[0:v]{},split[a][b];[a]palettegen=max_colors={}:stats_mode={}[p];[b][p]{}",
+                base,
+                request.max_colors.unwrap_or(256),
+                stats_mode,
+                paletteuse
+            ));
+            // GIF 的 -loop 表示重复次数，-1 为只播放一次
+            let gif_loop = match plays {
+                0 => 0,
+                1 => -1,
+                n => n as i64 - 1,
+            };
+            args.extend(["-loop".to_string(), gif_loop.to_string()]);
+        }
+        "webp" => {
+            args.extend(["-vf".to_string(), base]);
+            args.extend(["-c:v".to_string(), "libwebp_anim".to_string()]);
+            if request.lossless.unwrap_or(false) {
+                args.extend(["-lossless".to_string(), "1".to_string()]);
+            } else {
+                args.extend([
+                    "-lossless".to_string(),
+                    "0".to_string(),
+                    "-q:v".to_string(),
+                    request.quality.unwrap_or(75).to_string(),
+                ]);
+            }
+            args.extend([
+                "-compression_level".to_string(),
+                "6".to_string(),
+                "-loop".to_string(),
+                plays.to_string(),
+            ]);
+        }
+        _ => {
+            args.extend(["-vf".to_string(), base]);
+            args.extend([
+                "-c:v".to_string(),
+                "apng".to_string(),
+                "-pred".to_string(),
+                "mixed".to_string(),
+                "-plays".to_string(),
+                plays.to_string(),
+                "-f".to_string(),
+                "apng".to_string(),
+            ]);
+        }
+    }
+    args.push("-an".to_string());
+    args
+}
+
+/// 将动态 WebP 拆分为 PNG 帧，并生成带帧时长的 concat 列表
+fn extract_webp_frames(input_path: &Path) -> Result<PathBuf, String> {
+    let file = File::open(input_path).map_err(|e| format!("打开文件失败: {}", e))?;
+    let decoder =
+        WebPDecoder::new(BufReader::new(file)).map_err(|e| format!("解析 WebP 失败: {}", e))?;
+    if !decoder.has_animation() {
+        return Err("该 WebP 不是动图".to_string());
+    }
+
+    let dir = std::env::temp_dir().join(format!(
+        "devtools-animation-{}",
+        chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+    ));
+    fs::create_dir_all(&dir).map_err(|e| format!("创建临时目录失败: {}", e))?;
+
+    let result = (|| {
+        let mut list = String::new();
+        let mut last = String::new();
+        for (i, frame) in decoder.into_frames().enumerate() {
+            let frame = frame.map_err(|e| format!("解码 WebP 帧失败: {}", e))?;
+            let (numer, denom) = frame.delay().numer_denom_ms();
+            let seconds = numer as f64 / denom.max(1) as f64 / 1000.0;
+            let name = format!("frame_{:05}.png", i);
+            frame
+                .buffer()
+                .save(dir.join(&name))
+                .map_err(|e| format!("写入临时帧失败: {}", e))?;
+            list.push_str(&format!(
+                "file '{}'\nduration {:.3}\n",
+                name,
+                seconds.max(0.01)
+            ));
+            last = name;
+        }
+        if last.is_empty() {
+            return Err("WebP 中没有可用的帧".to_string());
+        }
+        // concat 需要重复最后一帧才能使其时长生效
+        list.push_str(&format!("file '{}'\n", last));
+        fs::write(dir.join("frames.txt"), list).map_err(|e| format!("写入帧列表失败: {}", e))
+    })();
+
+    match result {
+        Ok(()) => Ok(dir),
+        Err(e) => {
+            let _ = fs::remove_dir_all(&dir);
+            Err(e)
+        }
+    }
+}
+
+/// 按扩展名识别动图格式，PNG 视为 APNG
+fn animation_format_of(path: &Path) -> Option<&'static str> {
+    let ext = path.extension()?.to_string_lossy().to_lowercase();
+    match ext.as_str() {
+        "gif" => Some("gif"),
+        "webp" => Some("webp"),
+        "png" | "apng" => Some("apng"),
+        _ => None,
+    }
+}
+
+fn resolve_output_path(input_path: &Path, output_path: &str, format: &str) -> PathBuf {
+    if !output_path.is_empty() {
+        return PathBuf::from(output_path);
+    }
+    let extension = if format == "apng" { "png" } else { format };
+    let stem = input_path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| "animation".to_string());
+    input_path.with_file_name(format!("{}_animated.{}", stem, extension))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(format: &str) -> AnimationRequest {
+        AnimationRequest {
+            input_path: "/tmp/clip.mov".to_string(),
+            output_path: String::new(),
+            format: format.to_string(),
+            start_time: Some(3.5),
+            duration: Some(4.0),
+            fps: Some(15),
+            width: Some(480),
+            height: None,
+            loop_count: None,
+            max_colors: Some(128),
+            dither: Some("bayer".to_string()),
+            bayer_scale: Some(3),
+            diff_palette: Some(true),
+            quality: None,
+            lossless: None,
+        }
+    }
+
+    #[test]
+    fn test_build_animation_args() {
+        let gif = build_animation_args(&request("gif"));
+        assert_eq!(gif[0], "-filter_complex");
+        assert_eq!(
+            gif[1],
+            "[0:v]fps=15,scale=480:-1:flags=lanczos,split[a][b];[a]palettegen=max_colors=128:stats_mode=diff[p];[b][p]paletteuse=dither=bayer:bayer_scale=3:diff_mode=rectangle"
+        );
+        assert_eq!(gif[2..4], ["-loop", "0"]);
+
+        let mut once = request("webp");
+        once.loop_count = Some(1);
+        let webp = build_animation_args(&once);
+        assert!(webp.windows(2).any(|w| w == ["-q:v", "75"]));
+        assert!(webp.windows(2).any(|w| w == ["-loop", "1"]));
+
+        let apng = build_animation_args(&request("APNG"));
+        assert!(apng.windows(2).any(|w| w == ["-f", "apng"]));
+    }
+
+    #[test]
+    fn test_validate_and_output_path() {
+        assert!(validate_request(&request("gif")).is_ok());
+        let mut invalid = request("gif");
+        invalid.dither = Some("random".to_string());
+        assert!(validate_request(&invalid).is_err());
+        assert!(validate_request(&request("mp4")).is_err());
+
+        assert_eq!(
+            resolve_output_path(Path::new("/tmp/clip.mov"), "", "apng"),
+            PathBuf::from("/tmp/clip_animated.png")
+        );
+        assert_eq!(animation_format_of(Path::new("a.WEBP")), Some("webp"));
+        assert_eq!(animation_format_of(Path::new("a.mp4")), None);
+    }
+}
@@ -0,0 +1,299 @@
+use crate::tools::global_shortcut::{GlobalShortcutConfig, GlobalShortcutState};
+use crate::tools::system_settings::{self, GlobalTrayState};
+use crate::tools::trust_store;
+use crate::utils::usage;
+use openssl::hash::MessageDigest;
+use openssl::pkcs5::pbkdf2_hmac;
+use openssl::rand::rand_bytes;
+use openssl::symm::{decrypt_aead, encrypt_aead, Cipher};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+const ARCHIVE_MAGIC: &[u8] = b"DTBAK\x01";
+const SALT_LEN: usize = 16;
+const IV_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+const PBKDF2_ITERATIONS: usize = 200_000;
+const BUNDLE_VERSION: u32 = 1;
+
+/// 备份内容，配置目录下的 JSON 文件按文件名保存
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppDataBundle {
+    pub version: u32,
+    pub app_version: String,
+    pub created_at: String,
+    pub files: BTreeMap<String, Value>,
+    pub shortcut: Option<GlobalShortcutConfig>,
+    pub frontend_data: Option<Value>, // 前端 localStorage 中的历史记录和收藏
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppDataExportResult {
+    pub path: String,
+    pub files: Vec<String>,
+    pub size: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppDataImportResult {
+    pub created_at: String,
+    pub app_version: String,
+    pub files: Vec<String>,
+    pub shortcut_restored: bool,
+    pub frontend_data: Option<Value>, // 由前端写回 localStorage
+}
+
+/// 将设置、快捷键、工具数据和已保存请求打包为加密备份文件
+#[tauri::command]
+pub async fn export_app_data(
+    shortcut_state: State<'_, GlobalShortcutState>,
+    path: String,
+    password: String,
+    frontend_data: Option<Value>,
+) -> Result<AppDataExportResult, String> {
+    if password.is_empty() {
+        return Err("请输入备份密码".to_string());
+    }
+
+    let files = read_data_files(&get_data_dir())?;
+    let shortcut = shortcut_state
+        .config
+        .lock()
+        .map_err(|e| e.to_string())?
+        .clone();
+    let bundle = AppDataBundle {
+        version: BUNDLE_VERSION,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        created_at: chrono::Local::now().to_rfc3339(),
+        files,
+        shortcut: Some(shortcut),
+        frontend_data,
+    };
+
+    let plain = serde_json::to_vec(&bundle).map_err(|e| format!("序列化备份数据失败: {}", e))?;
+    let archive = encrypt_archive(&plain, &password)?;
+    fs::write(&path, &archive).map_err(|e| format!("写入备份文件失败: {}", e))?;
+
+    Ok(AppDataExportResult {
+        path,
+        files: bundle.files.keys().cloned().collect(),
+        size: archive.len() as u64,
+    })
+}
+
+/// 从加密备份文件恢复应用数据
+#[tauri::command]
+pub async fn import_app_data(
+    app: tauri::AppHandle,
+    tray_state: State<'_, GlobalTrayState>,
+    shortcut_state: State<'_, GlobalShortcutState>,
+    path: String,
+    password: String,
+) -> Result<AppDataImportResult, String> {
+    let archive = fs::read(&path).map_err(|e| format!("读取备份文件失败: {}", e))?;
+    let plain = decrypt_archive(&archive, &password)?;
+    let bundle: AppDataBundle =
+        serde_json::from_slice(&plain).map_err(|e| format!("备份内容格式错误: {}", e))?;
+    if bundle.version > BUNDLE_VERSION {
+        return Err(format!(
+            "备份文件版本 {} 高于当前支持的版本 {}，请升级应用后再导入",
+            bundle.version, BUNDLE_VERSION
+        ));
+    }
+
+    write_data_files(&get_data_dir(), &bundle.files)?;
+
+    // 重新加载启动时读入内存的状态：语言、代理等设置，托盘菜单，信任库和使用统计
+    usage::reload();
+    trust_store::reload();
+    system_settings::reload_config(&app, &tray_state)?;
+
+    let shortcut_restored = match bundle.shortcut {
+        Some(shortcut) if shortcut.enabled => {
+            crate::tools::global_shortcut::register_global_shortcut(
+                app,
+                shortcut.hotkey,
+                shortcut_state,
+            )?;
+            true
+        }
+        Some(_) => {
+            crate::tools::global_shortcut::unregister_global_shortcut(app, shortcut_state)?;
+            true
+        }
+        None => false,
+    };
+
+    Ok(AppDataImportResult {
+        created_at: bundle.created_at,
+        app_version: bundle.app_version,
+        files: bundle.files.keys().cloned().collect(),
+        shortcut_restored,
+        frontend_data: bundle.frontend_data,
+    })
+}
+
+fn get_data_dir() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("devtools")
+}
+
+/// 读取数据目录下的 JSON 文件，缓存文件不参与备份
+fn read_data_files(dir: &Path) -> Result<BTreeMap<String, Value>, String> {
+    let mut files = BTreeMap::new();
+    if !dir.exists() {
+        return Ok(files);
+    }
+
+    let entries = fs::read_dir(dir).map_err(|e| format!("读取配置目录失败: {}", e))?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        if !path.is_file() || !name.ends_with(".json") || name.contains("cache") {
+            continue;
+        }
+        let content =
+            fs::read_to_string(&path).map_err(|e| format!("读取 {} 失败: {}", name, e))?;
+        let value = serde_json::from_str(&content)
+            .map_err(|e| format!("{} 不是有效的 JSON: {}", name, e))?;
+        files.insert(name.to_string(), value);
+    }
+    Ok(files)
+}
+
+/// 先校验全部文件名并序列化内容，再逐个写入，避免校验失败时只恢复了部分配置
+fn write_data_files(dir: &Path, files: &BTreeMap<String, Value>) -> Result<(), String> {
+    let mut contents = Vec::with_capacity(files.len());
+    for (name, value) in files {
+        // 防止备份中的文件名写出配置目录
+        if name.contains('/')
+            || name.contains('\\')
+            || name.starts_with('.')
+            || !name.ends_with(".json")
+        {
+            return Err(format!("备份中包含非法文件名: {}", name));
+        }
+        let content = serde_json::to_string_pretty(value)
+            .map_err(|e| format!("序列化 {} 失败: {}", name, e))?;
+        contents.push((name, content));
+    }
+
+    fs::create_dir_all(dir).map_err(|e| format!("创建配置目录失败: {}", e))?;
+    for (name, content) in contents {
+        fs::write(dir.join(name), content).map_err(|e| format!("写入 {} 失败: {}", name, e))?;
+    }
+    Ok(())
+}
+
+fn derive_key(password: &str, salt: &[u8]) -> Result<[u8; 32], String> {
+    let mut key = [0u8; 32];
+    pbkdf2_hmac(
+        password.as_bytes(),
+        salt,
+        PBKDF2_ITERATIONS,
+        MessageDigest::sha256(),
+        &mut key,
+    )
+    .map_err(|e| format!("密钥派生失败: {}", e))?;
+    Ok(key)
+}
+
+/// 加密格式: MAGIC | salt | iv | tag | AES-256-GCM 密文
+fn encrypt_archive(plain: &[u8], password: &str) -> Result<Vec<u8>, String> {
+    let mut salt = [0u8; SALT_LEN];
+    let mut iv = [0u8; IV_LEN];
+    rand_bytes(&mut salt).map_err(|e| format!("生成随机数失败: {}", e))?;
+    rand_bytes(&mut iv).map_err(|e| format!("生成随机数失败: {}", e))?;
+
+    let key = derive_key(password, &salt)?;
+    let mut tag = [0u8; TAG_LEN];
+    let ciphertext = encrypt_aead(
+        Cipher::aes_256_gcm(),
+        &key,
+        Some(&iv),
+        ARCHIVE_MAGIC,
+        plain,
+        &mut tag,
+    )
+    .map_err(|e| format!("加密失败: {}", e))?;
+
+    let mut archive =
+        Vec::with_capacity(ARCHIVE_MAGIC.len() + SALT_LEN + IV_LEN + TAG_LEN + ciphertext.len());
+    archive.extend_from_slice(ARCHIVE_MAGIC);
+    archive.extend_from_slice(&salt);
+    archive.extend_from_slice(&iv);
+    archive.extend_from_slice(&tag);
+    archive.extend_from_slice(&ciphertext);
+    Ok(archive)
+}
+
+fn decrypt_archive(archive: &[u8], password: &str) -> Result<Vec<u8>, String> {
+    let header_len = ARCHIVE_MAGIC.len() + SALT_LEN + IV_LEN + TAG_LEN;
+    if archive.len() < header_len || !archive.starts_with(ARCHIVE_MAGIC) {
+        return Err("不是有效的备份文件".to_string());
+    }
+
+    let mut offset = ARCHIVE_MAGIC.len();
+    let salt = &archive[offset..offset + SALT_LEN];
+    offset += SALT_LEN;
+    let iv = &archive[offset..offset + IV_LEN];
+    offset += IV_LEN;
+    let tag = &archive[offset..offset + TAG_LEN];
+    offset += TAG_LEN;
+
+    let key = derive_key(password, salt)?;
+    decrypt_aead(
+        Cipher::aes_256_gcm(),
+        &key,
+        Some(iv),
+        ARCHIVE_MAGIC,
+        &archive[offset..],
+        tag,
+    )
+    .map_err(|_| "密码错误或备份文件已损坏".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_archive_round_trip() {
+        let plain = br#"{"files":{"devtools-config.json":{"tray_enabled":true}}}"#;
+        let archive = encrypt_archive(plain, "secret").unwrap();
+        assert!(archive.starts_with(ARCHIVE_MAGIC));
+        assert_eq!(decrypt_archive(&archive, "secret").unwrap(), plain);
+        assert!(decrypt_archive(&archive, "wrong").is_err());
+        assert!(decrypt_archive(b"not a backup", "secret").is_err());
+    }
+
+    #[test]
+    fn test_data_files_round_trip() {
+        let dir = std::env::temp_dir().join(format!("devtools-app-data-{}", std::process::id()));
+        let mut files = BTreeMap::new();
+        files.insert("http-collections.json".to_string(), serde_json::json!([]));
+        write_data_files(&dir, &files).unwrap();
+        fs::write(dir.join("whois-cache.json"), "{}").unwrap();
+
+        assert_eq!(read_data_files(&dir).unwrap(), files);
+
+        let mut bad = BTreeMap::new();
+        bad.insert("../evil.json".to_string(), Value::Null);
+        assert!(write_data_files(&dir, &bad).is_err());
+
+        // 任一文件名非法时不写入任何文件
+        let mut partial = BTreeMap::new();
+        partial.insert("a-settings.json".to_string(), serde_json::json!({"a": 1}));
+        partial.insert("z/escape.json".to_string(), Value::Null);
+        assert!(write_data_files(&dir, &partial).is_err());
+        assert!(!dir.join("a-settings.json").exists());
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
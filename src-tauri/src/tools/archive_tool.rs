@@ -0,0 +1,581 @@
+use crate::utils::progress::ProgressReporter;
+use flate2::read::GzDecoder;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Component, Path, PathBuf};
+use tauri::{AppHandle, Emitter};
+
+/// 单个条目解压后大于该值且压缩比超过阈值时视为可疑
+const BOMB_MIN_SIZE: u64 = 10 * 1024 * 1024;
+const BOMB_RATIO: u64 = 100;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveEntry {
+    pub path: String,
+    pub size: u64,
+    pub compressed_size: Option<u64>, // tar 类格式无单独的压缩大小
+    pub is_dir: bool,
+    pub is_symlink: bool,
+    pub link_target: Option<String>,
+    pub modified: Option<String>,
+    pub encrypted: bool,
+    pub warning: Option<String>, // 路径穿越、绝对路径、异常压缩比等
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveListing {
+    pub path: String,
+    pub format: String, // zip, tar, tar.gz, 7z
+    pub archive_size: u64,
+    pub total_size: u64,
+    pub file_count: usize,
+    pub dir_count: usize,
+    pub compression_ratio: f64,
+    pub suspicious_count: usize,
+    pub entries: Vec<ArchiveEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtractArchiveRequest {
+    pub path: String,
+    pub output_dir: String,
+    pub entries: Option<Vec<String>>, // None 表示全部解压，目录会包含其子条目
+    pub overwrite: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkippedEntry {
+    pub path: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtractArchiveResult {
+    pub output_dir: String,
+    pub extracted_files: usize,
+    pub bytes_written: u64,
+    pub skipped: Vec<SkippedEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveProgress {
+    pub path: String,
+    pub entry: String,
+    pub processed_entries: usize,
+    pub total_entries: usize,
+    pub bytes_written: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ArchiveFormat {
+    Zip,
+    Tar,
+    TarGz,
+    SevenZ,
+}
+
+impl ArchiveFormat {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ArchiveFormat::Zip => "zip",
+            ArchiveFormat::Tar => "tar",
+            ArchiveFormat::TarGz => "tar.gz",
+            ArchiveFormat::SevenZ => "7z",
+        }
+    }
+}
+
+/// 列出归档内容，不解压
+#[tauri::command]
+pub async fn list_archive(path: String) -> Result<ArchiveListing, String> {
+    tokio::task::spawn_blocking(move || list_archive_blocking(&path))
+        .await
+        .map_err(|e| format!("读取归档任务失败: {}", e))?
+}
+
+/// 解压归档中的全部或选中条目，进度通过 archive-progress 和 task-progress 事件推送
+#[tauri::command]
+pub async fn extract_archive(
+    app: AppHandle,
+    request: ExtractArchiveRequest,
+) -> Result<ExtractArchiveResult, String> {
+    let mut reporter = ProgressReporter::new(&app, "archive_tool");
+    tokio::task::spawn_blocking(move || {
+        let result = extract_archive_blocking(&request, |progress| {
+            reporter.update(
+                progress.processed_entries as u64,
+                Some(progress.total_entries as u64),
+                Some(progress.entry.clone()),
+            );
+            let _ = app.emit("archive-progress", progress);
+        });
+        match &result {
+            Ok(summary) => {
+                reporter.finish(Some(format!("已解压 {} 个文件", summary.extracted_files)))
+            }
+            Err(e) => reporter.fail(e),
+        }
+        result
+    })
+    .await
+    .map_err(|e| format!("解压任务失败: {}", e))?
+}
+
+/// 根据文件头识别归档格式
+fn detect_format(path: &Path) -> Result<ArchiveFormat, String> {
+    let mut header = [0u8; 262];
+    let mut file = File::open(path).map_err(|e| format!("打开归档失败: {}", e))?;
+    let read = file
+        .read(&mut header)
+        .map_err(|e| format!("读取归档失败: {}", e))?;
+    let header = &header[..read];
+
+    if header.starts_with(b"PK\x03\x04") || header.starts_with(b"PK\x05\x06") {
+        Ok(ArchiveFormat::Zip)
+    } else if header.starts_with(b"7z\xBC\xAF\x27\x1C") {
+        Ok(ArchiveFormat::SevenZ)
+    } else if header.starts_with(&[0x1f, 0x8b]) {
+        Ok(ArchiveFormat::TarGz)
+    } else if header.len() >= 262 && &header[257..262] == b"ustar" {
+        Ok(ArchiveFormat::Tar)
+    } else {
+        Err("不支持的归档格式，仅支持 zip、tar、tar.gz 和 7z".to_string())
+    }
+}
+
+/// 检查条目路径是否会写出解压目录
+fn path_warning(path: &str) -> Option<String> {
+    let normalized = path.replace('\\', "/");
+    if normalized.starts_with('/') || Path::new(&normalized).has_root() {
+        return Some("绝对路径".to_string());
+    }
+    if normalized.len() >= 2 && normalized.as_bytes()[1] == b':' {
+        return Some("包含 Windows 盘符".to_string());
+    }
+    if normalized.split('/').any(|part| part == "..") {
+        return Some("包含 .. 路径穿越".to_string());
+    }
+    None
+}
+
+fn link_warning(entry_path: &str, target: &str) -> Option<String> {
+    if target.starts_with('/') || Path::new(target).has_root() {
+        return Some(format!("符号链接指向绝对路径 {}", target));
+    }
+    // 以条目所在目录为基准计算链接目标的深度
+    let mut depth = entry_path.trim_end_matches('/').split('/').count() as i64 - 1;
+    for part in target.split('/') {
+        match part {
+            ".." => depth -= 1,
+            "" | "." => {}
+            _ => depth += 1,
+        }
+        if depth < 0 {
+            return Some(format!("符号链接指向归档外 {}", target));
+        }
+    }
+    None
+}
+
+fn ratio_warning(size: u64, compressed: u64) -> Option<String> {
+    (size >= BOMB_MIN_SIZE && compressed > 0 && size / compressed >= BOMB_RATIO)
+        .then(|| format!("压缩比异常 ({}:1)，可能是压缩炸弹", size / compressed))
+}
+
+/// 将条目路径安全地拼接到输出目录下
+fn safe_join(base: &Path, entry: &str) -> Option<PathBuf> {
+    if path_warning(entry).is_some() {
+        return None;
+    }
+    let mut result = base.to_path_buf();
+    for component in Path::new(&entry.replace('\\', "/")).components() {
+        match component {
+            Component::Normal(part) => result.push(part),
+            Component::CurDir => {}
+            _ => return None,
+        }
+    }
+    Some(result)
+}
+
+fn is_selected(entry: &str, selection: &Option<Vec<String>>) -> bool {
+    let Some(selection) = selection else {
+        return true;
+    };
+    let entry = entry.trim_end_matches('/');
+    selection.iter().any(|selected| {
+        let selected = selected.trim_end_matches('/');
+        entry == selected || entry.starts_with(&format!("{}/", selected))
+    })
+}
+
+fn format_unix_time(secs: i64) -> Option<String> {
+    chrono::DateTime::from_timestamp(secs, 0).map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string())
+}
+
+fn list_archive_blocking(path: &str) -> Result<ArchiveListing, String> {
+    let archive_path = Path::new(path);
+    let format = detect_format(archive_path)?;
+    let archive_size = fs::metadata(archive_path)
+        .map_err(|e| format!("读取归档信息失败: {}", e))?
+        .len();
+
+    let entries = match format {
+        ArchiveFormat::Zip => list_zip(archive_path)?,
+        ArchiveFormat::Tar => list_tar(File::open(archive_path).map_err(|e| e.to_string())?)?,
+        ArchiveFormat::TarGz => list_tar(GzDecoder::new(
+            File::open(archive_path).map_err(|e| e.to_string())?,
+        ))?,
+        ArchiveFormat::SevenZ => list_7z(archive_path)?,
+    };
+
+    let total_size: u64 = entries.iter().map(|e| e.size).sum();
+    Ok(ArchiveListing {
+        path: path.to_string(),
+        format: format.as_str().to_string(),
+        archive_size,
+        total_size,
+        file_count: entries.iter().filter(|e| !e.is_dir).count(),
+        dir_count: entries.iter().filter(|e| e.is_dir).count(),
+        compression_ratio: if archive_size == 0 {
+            0.0
+        } else {
+            (total_size as f64 / archive_size as f64 * 100.0).round() / 100.0
+        },
+        suspicious_count: entries.iter().filter(|e| e.warning.is_some()).count(),
+        entries,
+    })
+}
+
+fn list_zip(path: &Path) -> Result<Vec<ArchiveEntry>, String> {
+    let file = File::open(path).map_err(|e| format!("打开归档失败: {}", e))?;
+    let mut archive = zip::ZipArchive::new(file).map_err(|e| format!("解析 zip 失败: {}", e))?;
+    let mut entries = Vec::with_capacity(archive.len());
+
+    for index in 0..archive.len() {
+        // raw 模式下加密条目也可读取元数据
+        let file = archive
+            .by_index_raw(index)
+            .map_err(|e| format!("读取 zip 条目失败: {}", e))?;
+        let name = file.name().to_string();
+        let modified = file.last_modified().map(|t| {
+            format!(
+                "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+                t.year(),
+                t.month(),
+                t.day(),
+                t.hour(),
+                t.minute(),
+                t.second()
+            )
+        });
+        entries.push(ArchiveEntry {
+            warning: path_warning(&name)
+                .or_else(|| ratio_warning(file.size(), file.compressed_size())),
+            path: name,
+            size: file.size(),
+            compressed_size: Some(file.compressed_size()),
+            is_dir: file.is_dir(),
+            is_symlink: file.is_symlink(),
+            link_target: None,
+            modified,
+            encrypted: file.encrypted(),
+        });
+    }
+    Ok(entries)
+}
+
+fn list_tar<R: Read>(reader: R) -> Result<Vec<ArchiveEntry>, String> {
+    let mut archive = tar::Archive::new(reader);
+    let mut entries = Vec::new();
+
+    for entry in archive
+        .entries()
+        .map_err(|e| format!("解析 tar 失败: {}", e))?
+    {
+        let entry = entry.map_err(|e| format!("读取 tar 条目失败: {}", e))?;
+        let header = entry.header();
+        let name = String::from_utf8_lossy(&entry.path_bytes()).to_string();
+        let entry_type = header.entry_type();
+        let link_target = entry
+            .link_name_bytes()
+            .map(|target| String::from_utf8_lossy(&target).to_string());
+        let is_symlink = entry_type.is_symlink() || entry_type.is_hard_link();
+
+        let warning = path_warning(&name).or_else(|| {
+            link_target
+                .as_deref()
+                .filter(|_| is_symlink)
+                .and_then(|target| link_warning(&name, target))
+        });
+        entries.push(ArchiveEntry {
+            path: name,
+            size: header.size().unwrap_or(0),
+            compressed_size: None,
+            is_dir: entry_type.is_dir(),
+            is_symlink,
+            link_target,
+            modified: header.mtime().ok().and_then(|t| format_unix_time(t as i64)),
+            encrypted: false,
+            warning,
+        });
+    }
+    Ok(entries)
+}
+
+fn list_7z(path: &Path) -> Result<Vec<ArchiveEntry>, String> {
+    let archive = sevenz_rust::Archive::open(path).map_err(|e| format!("解析 7z 失败: {}", e))?;
+    Ok(archive
+        .files
+        .iter()
+        .map(|file| ArchiveEntry {
+            path: file.name().to_string(),
+            size: file.size(),
+            compressed_size: None,
+            is_dir: file.is_directory(),
+            is_symlink: false,
+            link_target: None,
+            modified: file
+                .has_last_modified_date
+                .then(|| format_unix_time(file.last_modified_date().to_unix_time()))
+                .flatten(),
+            encrypted: false,
+            warning: path_warning(file.name()),
+        })
+        .collect())
+}
+
+/// 解压时的公共状态
+struct Extractor<'a, F: FnMut(ArchiveProgress)> {
+    archive_path: String,
+    output_dir: PathBuf,
+    selection: Option<Vec<String>>,
+    overwrite: bool,
+    total_entries: usize,
+    processed: usize,
+    result: ExtractArchiveResult,
+    on_progress: &'a mut F,
+}
+
+impl<F: FnMut(ArchiveProgress)> Extractor<'_, F> {
+    fn skip(&mut self, path: &str, reason: &str) {
+        self.result.skipped.push(SkippedEntry {
+            path: path.to_string(),
+            reason: reason.to_string(),
+        });
+    }
+
+    /// 写出单个选中条目，不安全的路径和链接记录为跳过
+    fn write_entry(
+        &mut self,
+        name: &str,
+        is_dir: bool,
+        is_link: bool,
+        reader: &mut dyn Read,
+    ) -> Result<(), String> {
+        if !is_selected(name, &self.selection) {
+            return Ok(());
+        }
+        self.processed += 1;
+
+        let Some(target) = safe_join(&self.output_dir, name) else {
+            let reason = path_warning(name).unwrap_or_else(|| "非法路径".to_string());
+            self.skip(name, &reason);
+            return Ok(());
+        };
+        if is_link {
+            self.skip(name, "链接条目已跳过");
+            return Ok(());
+        }
+
+        if is_dir {
+            fs::create_dir_all(&target).map_err(|e| format!("创建目录失败: {}", e))?;
+        } else if target.exists() && !self.overwrite {
+            self.skip(name, "目标文件已存在");
+        } else {
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent).map_err(|e| format!("创建目录失败: {}", e))?;
+            }
+            let mut output = File::create(&target).map_err(|e| format!("创建文件失败: {}", e))?;
+            let written =
+                io::copy(reader, &mut output).map_err(|e| format!("写入 {} 失败: {}", name, e))?;
+            self.result.bytes_written += written;
+            self.result.extracted_files += 1;
+        }
+
+        (self.on_progress)(ArchiveProgress {
+            path: self.archive_path.clone(),
+            entry: name.to_string(),
+            processed_entries: self.processed,
+            total_entries: self.total_entries,
+            bytes_written: self.result.bytes_written,
+        });
+        Ok(())
+    }
+}
+
+fn extract_archive_blocking<F: FnMut(ArchiveProgress)>(
+    request: &ExtractArchiveRequest,
+    mut on_progress: F,
+) -> Result<ExtractArchiveResult, String> {
+    let archive_path = Path::new(&request.path);
+    let format = detect_format(archive_path)?;
+    let listing = list_archive_blocking(&request.path)?;
+    let selection = request.entries.clone().filter(|e| !e.is_empty());
+    let total_entries = listing
+        .entries
+        .iter()
+        .filter(|e| is_selected(&e.path, &selection))
+        .count();
+
+    fs::create_dir_all(&request.output_dir).map_err(|e| format!("创建输出目录失败: {}", e))?;
+    let mut extractor = Extractor {
+        archive_path: request.path.clone(),
+        output_dir: PathBuf::from(&request.output_dir),
+        selection,
+        overwrite: request.overwrite.unwrap_or(false),
+        total_entries,
+        processed: 0,
+        result: ExtractArchiveResult {
+            output_dir: request.output_dir.clone(),
+            extracted_files: 0,
+            bytes_written: 0,
+            skipped: Vec::new(),
+        },
+        on_progress: &mut on_progress,
+    };
+
+    match format {
+        ArchiveFormat::Zip => {
+            let file = File::open(archive_path).map_err(|e| format!("打开归档失败: {}", e))?;
+            let mut archive =
+                zip::ZipArchive::new(file).map_err(|e| format!("解析 zip 失败: {}", e))?;
+            for index in 0..archive.len() {
+                let mut file = match archive.by_index(index) {
+                    Ok(file) => file,
+                    Err(e) => {
+                        let name = listing.entries[index].path.clone();
+                        if is_selected(&name, &extractor.selection) {
+                            extractor.skip(&name, &format!("无法读取: {}", e));
+                        }
+                        continue;
+                    }
+                };
+                let name = file.name().to_string();
+                let (is_dir, is_link) = (file.is_dir(), file.is_symlink());
+                extractor.write_entry(&name, is_dir, is_link, &mut file)?;
+            }
+        }
+        ArchiveFormat::Tar | ArchiveFormat::TarGz => {
+            let file = File::open(archive_path).map_err(|e| format!("打开归档失败: {}", e))?;
+            let reader: Box<dyn Read> = if format == ArchiveFormat::TarGz {
+                Box::new(GzDecoder::new(file))
+            } else {
+                Box::new(file)
+            };
+            let mut archive = tar::Archive::new(reader);
+            for entry in archive
+                .entries()
+                .map_err(|e| format!("解析 tar 失败: {}", e))?
+            {
+                let mut entry = entry.map_err(|e| format!("读取 tar 条目失败: {}", e))?;
+                let name = String::from_utf8_lossy(&entry.path_bytes()).to_string();
+                let entry_type = entry.header().entry_type();
+                let is_link = entry_type.is_symlink() || entry_type.is_hard_link();
+                extractor.write_entry(&name, entry_type.is_dir(), is_link, &mut entry)?;
+            }
+        }
+        ArchiveFormat::SevenZ => {
+            let mut reader =
+                sevenz_rust::SevenZReader::open(archive_path, sevenz_rust::Password::empty())
+                    .map_err(|e| format!("解析 7z 失败: {}", e))?;
+            let mut error = None;
+            reader
+                .for_each_entries(|entry, data| {
+                    if let Err(e) =
+                        extractor.write_entry(entry.name(), entry.is_directory(), false, data)
+                    {
+                        error = Some(e);
+                        return Ok(false);
+                    }
+                    // 未选中的条目也需读完数据流
+                    io::copy(data, &mut io::sink())?;
+                    Ok(true)
+                })
+                .map_err(|e| format!("解压 7z 失败: {}", e))?;
+            if let Some(error) = error {
+                return Err(error);
+            }
+        }
+    }
+
+    Ok(extractor.result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn append_raw(builder: &mut tar::Builder<Vec<u8>>, name: &str, data: &[u8]) {
+        let mut header = tar::Header::new_ustar();
+        // 直接写入名称以绕过 tar 对 .. 的校验
+        header.as_old_mut().name[..name.len()].copy_from_slice(name.as_bytes());
+        header.set_size(data.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder.append(&header, data).unwrap();
+    }
+
+    #[test]
+    fn test_path_warnings() {
+        assert!(path_warning("docs/readme.md").is_none());
+        assert!(path_warning("../etc/passwd").is_some());
+        assert!(path_warning("/etc/passwd").is_some());
+        assert!(path_warning("C:\\Windows\\evil.dll").is_some());
+        assert!(link_warning("a/link", "../b").is_none());
+        assert!(link_warning("a/link", "../../b").is_some());
+        assert!(is_selected(
+            "docs/a/b.txt",
+            &Some(vec!["docs/".to_string()])
+        ));
+        assert!(!is_selected("docsx/b.txt", &Some(vec!["docs".to_string()])));
+    }
+
+    #[test]
+    fn test_list_and_extract_tar() {
+        let dir = std::env::temp_dir().join(format!("devtools-archive-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let archive_path = dir.join("sample.tar");
+
+        let mut builder = tar::Builder::new(Vec::new());
+        append_raw(&mut builder, "docs/readme.md", b"hello");
+        append_raw(&mut builder, "../evil.txt", b"owned");
+        fs::write(&archive_path, builder.into_inner().unwrap()).unwrap();
+
+        let listing = list_archive_blocking(archive_path.to_str().unwrap()).unwrap();
+        assert_eq!(listing.format, "tar");
+        assert_eq!(listing.file_count, 2);
+        assert_eq!(listing.suspicious_count, 1);
+
+        let output = dir.join("out");
+        let mut events = 0;
+        let result = extract_archive_blocking(
+            &ExtractArchiveRequest {
+                path: archive_path.to_string_lossy().to_string(),
+                output_dir: output.to_string_lossy().to_string(),
+                entries: None,
+                overwrite: None,
+            },
+            |_| events += 1,
+        )
+        .unwrap();
+
+        assert_eq!(result.extracted_files, 1);
+        assert_eq!(result.skipped[0].path, "../evil.txt");
+        assert_eq!(events, 1);
+        assert_eq!(fs::read(output.join("docs/readme.md")).unwrap(), b"hello");
+        assert!(!dir.join("evil.txt").exists());
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
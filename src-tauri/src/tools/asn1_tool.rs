@@ -0,0 +1,568 @@
+use base64::{engine::general_purpose, Engine as _};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write as _;
+use x509_parser::oid_registry::{Oid, OidRegistry};
+use x509_parser::pem::Pem;
+
+/// 防止恶意构造的深层嵌套导致栈溢出
+const MAX_DEPTH: usize = 64;
+/// 原始值预览的最大字节数
+const MAX_PREVIEW_BYTES: usize = 64;
+
+/// oid-registry 未收录、但在 OCSP、CSR、PKCS 中常见的 OID
+const EXTRA_OIDS: &[(&str, &str)] = &[
+    ("1.3.6.1.5.5.7.48.1", "ocsp"),
+    ("1.3.6.1.5.5.7.48.1.1", "id-pkix-ocsp-basic"),
+    ("1.3.6.1.5.5.7.48.1.2", "id-pkix-ocsp-nonce"),
+    ("1.3.6.1.5.5.7.48.1.5", "id-pkix-ocsp-nocheck"),
+    ("1.3.6.1.5.5.7.48.2", "caIssuers"),
+    ("1.3.6.1.5.5.7.3.1", "serverAuth"),
+    ("1.3.6.1.5.5.7.3.2", "clientAuth"),
+    ("1.3.6.1.5.5.7.3.3", "codeSigning"),
+    ("1.3.6.1.5.5.7.3.4", "emailProtection"),
+    ("1.3.6.1.5.5.7.3.8", "timeStamping"),
+    ("1.3.6.1.5.5.7.3.9", "OCSPSigning"),
+    ("1.3.6.1.4.1.11129.2.4.2", "ctPrecertificateSCTs"),
+    ("1.3.101.110", "X25519"),
+    ("1.3.101.111", "X448"),
+    ("1.3.101.112", "Ed25519"),
+    ("1.3.101.113", "Ed448"),
+    ("2.23.140.1.2.1", "domain-validated"),
+    ("2.23.140.1.2.2", "organization-validated"),
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Asn1DecodeRequest {
+    pub input: Option<String>,     // PEM、Base64 或十六进制文本
+    pub file_path: Option<String>, // 与 input 二选一，读取 DER 或 PEM 文件
+    pub encoding: Option<String>,  // auto, pem, base64, hex，默认 auto
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Asn1Node {
+    pub offset: usize,
+    pub header_length: usize,
+    pub length: usize,
+    pub tag_class: String, // universal, application, context, private
+    pub tag_number: u32,
+    pub constructed: bool,
+    pub type_name: String,
+    pub value: Option<String>,
+    pub encapsulated: bool, // 子节点来自 OCTET STRING / BIT STRING 内嵌的 DER
+    pub children: Vec<Asn1Node>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Asn1Document {
+    pub label: Option<String>, // PEM 标签，如 CERTIFICATE REQUEST
+    pub byte_length: usize,
+    pub nodes: Vec<Asn1Node>,
+    pub text: String, // 缩进后的树形文本
+    pub error: Option<String>,
+}
+
+/// 解析任意 DER / PEM 数据的 ASN.1 结构
+#[tauri::command]
+pub fn decode_asn1(request: Asn1DecodeRequest) -> Result<Vec<Asn1Document>, String> {
+    let (raw, from_file) = match (&request.input, &request.file_path) {
+        (Some(input), _) if !input.trim().is_empty() => (input.as_bytes().to_vec(), false),
+        (_, Some(path)) if !path.trim().is_empty() => (
+            std::fs::read(path).map_err(|e| format!("读取文件失败: {}", e))?,
+            true,
+        ),
+        _ => return Err("请输入数据或选择文件".to_string()),
+    };
+    let encoding = request.encoding.as_deref().unwrap_or("auto").to_lowercase();
+    let blobs = decode_input(&raw, &encoding, from_file)?;
+
+    let registry = OidRegistry::default().with_all_crypto().with_x509();
+    Ok(blobs
+        .into_iter()
+        .map(|(label, data)| {
+            let mut parser = Parser {
+                data: &data,
+                registry: &registry,
+            };
+            let (nodes, error) = parser.parse_all(0, data.len(), 0);
+            let mut text = String::new();
+            for node in &nodes {
+                render(node, 0, &mut text);
+            }
+            Asn1Document {
+                label,
+                byte_length: data.len(),
+                nodes,
+                text,
+                error,
+            }
+        })
+        .collect())
+}
+
+/// (PEM 标签, DER 数据)
+type Blob = (Option<String>, Vec<u8>);
+
+/// 按编码解出一个或多个 DER 数据块
+fn decode_input(raw: &[u8], encoding: &str, from_file: bool) -> Result<Vec<Blob>, String> {
+    let text = String::from_utf8_lossy(raw);
+    let is_pem = text.contains("-----BEGIN ");
+    match encoding {
+        "pem" | "auto" if is_pem => {
+            let blobs: Vec<_> = Pem::iter_from_buffer(raw)
+                .filter_map(|pem| pem.ok())
+                .map(|pem| (Some(pem.label), pem.contents))
+                .collect();
+            if blobs.is_empty() {
+                return Err("PEM 解析失败".to_string());
+            }
+            Ok(blobs)
+        }
+        "pem" => Err("未找到 PEM 块".to_string()),
+        // 从文件读取且不是 PEM 时按二进制 DER 处理
+        "auto" if from_file => Ok(vec![(None, raw.to_vec())]),
+        "der" => Ok(vec![(None, raw.to_vec())]),
+        "hex" | "auto" if is_hex(&text) => {
+            let cleaned: String = text
+                .replace("0x", "")
+                .chars()
+                .filter(|c| c.is_ascii_hexdigit())
+                .collect();
+            hex::decode(cleaned)
+                .map(|data| vec![(None, data)])
+                .map_err(|e| format!("十六进制解码失败: {}", e))
+        }
+        "hex" => Err("输入不是有效的十六进制".to_string()),
+        "base64" | "auto" => {
+            let cleaned: String = text.chars().filter(|c| !c.is_whitespace()).collect();
+            general_purpose::STANDARD
+                .decode(&cleaned)
+                .or_else(|_| general_purpose::URL_SAFE_NO_PAD.decode(cleaned.trim_end_matches('=')))
+                .map(|data| vec![(None, data)])
+                .map_err(|e| format!("Base64 解码失败: {}", e))
+        }
+        other => Err(format!("不支持的编码: {}", other)),
+    }
+}
+
+/// 十六进制输入允许空格、冒号分隔和 0x 前缀
+fn is_hex(text: &str) -> bool {
+    let cleaned = text.trim().replace("0x", "");
+    let digits = cleaned.chars().filter(|c| c.is_ascii_hexdigit()).count();
+    digits > 0
+        && digits % 2 == 0
+        && cleaned
+            .chars()
+            .all(|c| c.is_ascii_hexdigit() || c.is_whitespace() || c == ':')
+}
+
+struct Parser<'a> {
+    data: &'a [u8],
+    registry: &'a OidRegistry<'a>,
+}
+
+struct Header {
+    class: u8,
+    constructed: bool,
+    tag: u32,
+    header_length: usize,
+    length: Option<usize>, // None 表示 BER 不定长
+}
+
+impl Parser<'_> {
+    /// 解析 [start, end) 范围内的所有节点，遇到错误时返回已解析的部分和错误信息
+    fn parse_all(
+        &mut self,
+        start: usize,
+        end: usize,
+        depth: usize,
+    ) -> (Vec<Asn1Node>, Option<String>) {
+        let mut nodes = Vec::new();
+        let mut pos = start;
+        while pos < end {
+            match self.parse_node(pos, end, depth) {
+                Ok(node) => {
+                    pos = node.offset + node.header_length + node.length;
+                    let is_eoc = node.tag_class == "universal" && node.tag_number == 0;
+                    nodes.push(node);
+                    if is_eoc {
+                        break;
+                    }
+                }
+                Err(e) => return (nodes, Some(e)),
+            }
+        }
+        (nodes, None)
+    }
+
+    fn read_header(&self, pos: usize, end: usize) -> Result<Header, String> {
+        let byte = |i: usize| {
+            if i < end {
+                Ok(self.data[i])
+            } else {
+                Err(format!("偏移 {} 处数据被截断", pos))
+            }
+        };
+        let first = byte(pos)?;
+        let mut cursor = pos + 1;
+        let mut tag = (first & 0x1f) as u32;
+        if tag == 0x1f {
+            // 高位标签号，每字节 7 位
+            tag = 0;
+            loop {
+                let b = byte(cursor)?;
+                cursor += 1;
+                tag = tag
+                    .checked_mul(128)
+                    .ok_or_else(|| format!("偏移 {} 处标签号过大", pos))?
+                    | (b & 0x7f) as u32;
+                if b & 0x80 == 0 {
+                    break;
+                }
+            }
+        }
+        let len_byte = byte(cursor)?;
+        cursor += 1;
+        let length = if len_byte == 0x80 {
+            None
+        } else if len_byte & 0x80 != 0 {
+            let count = (len_byte & 0x7f) as usize;
+            if count > 4 {
+                return Err(format!("偏移 {} 处长度字段过大", pos));
+            }
+            let mut length = 0usize;
+            for _ in 0..count {
+                length = (length << 8) | byte(cursor)? as usize;
+                cursor += 1;
+            }
+            Some(length)
+        } else {
+            Some(len_byte as usize)
+        };
+        Ok(Header {
+            class: first >> 6,
+            constructed: first & 0x20 != 0,
+            tag,
+            header_length: cursor - pos,
+            length,
+        })
+    }
+
+    fn parse_node(&mut self, pos: usize, end: usize, depth: usize) -> Result<Asn1Node, String> {
+        if depth > MAX_DEPTH {
+            return Err(format!("嵌套层级超过 {}", MAX_DEPTH));
+        }
+        let header = self.read_header(pos, end)?;
+        let content_start = pos + header.header_length;
+        let tag_class = ["universal", "application", "context", "private"][header.class as usize];
+        let type_name = type_name(header.class, header.tag);
+        let mut node = Asn1Node {
+            offset: pos,
+            header_length: header.header_length,
+            length: 0,
+            tag_class: tag_class.to_string(),
+            tag_number: header.tag,
+            constructed: header.constructed,
+            type_name,
+            value: None,
+            encapsulated: false,
+            children: Vec::new(),
+        };
+
+        let Some(length) = header.length else {
+            if !header.constructed {
+                return Err(format!("偏移 {} 处的基本类型不能使用不定长编码", pos));
+            }
+            let (children, error) = self.parse_all(content_start, end, depth + 1);
+            if let Some(e) = error {
+                return Err(e);
+            }
+            let consumed = children
+                .last()
+                .map(|c| c.offset + c.header_length + c.length - content_start)
+                .unwrap_or(0);
+            node.length = consumed;
+            node.value = Some("不定长编码".to_string());
+            node.children = children;
+            return Ok(node);
+        };
+
+        let content_end = content_start + length;
+        if content_end > end {
+            return Err(format!(
+                "偏移 {} 处声明长度 {} 超出剩余数据 {}",
+                pos,
+                length,
+                end.saturating_sub(content_start)
+            ));
+        }
+        node.length = length;
+        let content = &self.data[content_start..content_end];
+
+        if header.constructed {
+            let (children, error) = self.parse_all(content_start, content_end, depth + 1);
+            if let Some(e) = error {
+                return Err(e);
+            }
+            node.children = children;
+            return Ok(node);
+        }
+
+        node.value = Some(self.format_value(header.class, header.tag, content));
+        // OCTET STRING 和 BIT STRING 中常内嵌 DER（扩展值、公钥等）
+        let inner_start = match (header.class, header.tag) {
+            (0, 4) => Some(content_start),
+            (0, 3) if content.first() == Some(&0) => Some(content_start + 1),
+            _ => None,
+        };
+        if let Some(inner_start) = inner_start {
+            if let Some(children) = self.try_encapsulated(inner_start, content_end, depth) {
+                node.children = children;
+                node.encapsulated = true;
+            }
+        }
+        Ok(node)
+    }
+
+    /// 内容完整解析为构造类型时才视为内嵌 DER
+    fn try_encapsulated(
+        &mut self,
+        start: usize,
+        end: usize,
+        depth: usize,
+    ) -> Option<Vec<Asn1Node>> {
+        if end - start < 2 || self.data[start] & 0x20 == 0 {
+            return None;
+        }
+        match self.parse_all(start, end, depth + 1) {
+            (children, None)
+                if children
+                    .last()
+                    .is_some_and(|c| c.offset + c.header_length + c.length == end) =>
+            {
+                Some(children)
+            }
+            _ => None,
+        }
+    }
+
+    fn format_value(&self, class: u8, tag: u32, content: &[u8]) -> String {
+        if class != 0 {
+            // 上下文标签的基本类型多为 IA5String（如 GeneralName 的 dNSName）
+            return if !content.is_empty()
+                && content.iter().all(|b| b.is_ascii_graphic() || *b == b' ')
+            {
+                String::from_utf8_lossy(content).to_string()
+            } else {
+                hex_preview(content)
+            };
+        }
+        match tag {
+            1 => (content.first().is_some_and(|b| *b != 0)).to_string(),
+            2 | 10 => format_integer(content),
+            3 => match content.split_first() {
+                Some((unused, bits)) => format!("未使用位 {}，{}", unused, hex_preview(bits)),
+                None => "空".to_string(),
+            },
+            4 => hex_preview(content),
+            5 => "NULL".to_string(),
+            6 => self.format_oid(content),
+            12 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 => {
+                String::from_utf8_lossy(content).to_string()
+            }
+            30 => {
+                let units: Vec<u16> = content
+                    .chunks(2)
+                    .map(|c| u16::from_be_bytes([c[0], *c.get(1).unwrap_or(&0)]))
+                    .collect();
+                String::from_utf16_lossy(&units)
+            }
+            28 => content
+                .chunks(4)
+                .filter_map(|c| {
+                    let bytes: [u8; 4] = c.try_into().ok()?;
+                    char::from_u32(u32::from_be_bytes(bytes))
+                })
+                .collect(),
+            _ => hex_preview(content),
+        }
+    }
+
+    fn format_oid(&self, content: &[u8]) -> String {
+        if content.is_empty() {
+            return "空".to_string();
+        }
+        let oid = Oid::new(content.to_vec().into());
+        let id = oid.to_id_string();
+        let name = self
+            .registry
+            .get(&oid)
+            .map(|entry| entry.sn().to_string())
+            .or_else(|| {
+                EXTRA_OIDS
+                    .iter()
+                    .find(|(extra, _)| *extra == id)
+                    .map(|(_, name)| name.to_string())
+            });
+        match name {
+            Some(name) => format!("{} ({})", id, name),
+            None => id,
+        }
+    }
+}
+
+fn type_name(class: u8, tag: u32) -> String {
+    match class {
+        0 => match tag {
+            0 => "END OF CONTENT",
+            1 => "BOOLEAN",
+            2 => "INTEGER",
+            3 => "BIT STRING",
+            4 => "OCTET STRING",
+            5 => "NULL",
+            6 => "OBJECT IDENTIFIER",
+            10 => "ENUMERATED",
+            12 => "UTF8String",
+            16 => "SEQUENCE",
+            17 => "SET",
+            18 => "NumericString",
+            19 => "PrintableString",
+            20 => "T61String",
+            22 => "IA5String",
+            23 => "UTCTime",
+            24 => "GeneralizedTime",
+            26 => "VisibleString",
+            28 => "UniversalString",
+            30 => "BMPString",
+            _ => return format!("UNIVERSAL {}", tag),
+        }
+        .to_string(),
+        1 => format!("[APPLICATION {}]", tag),
+        2 => format!("[{}]", tag),
+        _ => format!("[PRIVATE {}]", tag),
+    }
+}
+
+/// 16 字节以内按有符号十进制显示，更长的（如 RSA 模数）显示十六进制和位数
+fn format_integer(content: &[u8]) -> String {
+    if content.is_empty() {
+        return "0".to_string();
+    }
+    if content.len() <= 16 {
+        let negative = content[0] & 0x80 != 0;
+        let mut value: i128 = if negative { -1 } else { 0 };
+        for b in content {
+            value = (value << 8) | *b as i128;
+        }
+        return value.to_string();
+    }
+    let significant = content.iter().skip_while(|b| **b == 0).count();
+    let bits = significant * 8
+        - content
+            .iter()
+            .find(|b| **b != 0)
+            .map(|b| b.leading_zeros() as usize)
+            .unwrap_or(0);
+    format!("{}（{} 位）", hex_preview(content), bits)
+}
+
+fn hex_preview(content: &[u8]) -> String {
+    let preview: Vec<String> = content
+        .iter()
+        .take(MAX_PREVIEW_BYTES)
+        .map(|b| format!("{:02X}", b))
+        .collect();
+    let mut text = preview.join(" ");
+    if content.len() > MAX_PREVIEW_BYTES {
+        let _ = write!(text, " …（共 {} 字节）", content.len());
+    }
+    text
+}
+
+fn render(node: &Asn1Node, depth: usize, out: &mut String) {
+    let _ = write!(
+        out,
+        "{:>6}: hl={} l={:<5}{}{}",
+        node.offset,
+        node.header_length,
+        node.length,
+        "  ".repeat(depth),
+        node.type_name
+    );
+    if let Some(value) = &node.value {
+        let _ = write!(out, " {}", value);
+    }
+    if node.encapsulated {
+        out.push_str(" {内嵌}");
+    }
+    out.push('\n');
+    for child in &node.children {
+        render(child, depth + 1, out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(input: &str) -> Vec<Asn1Document> {
+        decode_asn1(Asn1DecodeRequest {
+            input: Some(input.to_string()),
+            file_path: None,
+            encoding: None,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_decode_nested_structure() {
+        // SEQUENCE { OID sha256WithRSAEncryption, NULL, [0] { INTEGER -1 },
+        //            OCTET STRING { SEQUENCE { BOOLEAN TRUE } }, UTF8String "中" }
+        let hex = "30 1E 06 09 2A 86 48 86 F7 0D 01 01 0B 05 00 A0 03 02 01 FF \
+                   04 05 30 03 01 01 FF 0C 03 E4 B8 AD";
+        let documents = decode(hex);
+        assert_eq!(documents.len(), 1);
+        let root = &documents[0].nodes[0];
+        assert_eq!(root.type_name, "SEQUENCE");
+        assert_eq!(root.children.len(), 5);
+        assert_eq!(
+            root.children[0].value.as_deref(),
+            Some("1.2.840.113549.1.1.11 (sha256WithRSAEncryption)")
+        );
+        let tagged = &root.children[2];
+        assert_eq!((tagged.type_name.as_str(), tagged.offset), ("[0]", 15));
+        assert_eq!(tagged.children[0].value.as_deref(), Some("-1"));
+        let octets = &root.children[3];
+        assert!(octets.encapsulated);
+        assert_eq!(
+            octets.children[0].children[0].value.as_deref(),
+            Some("true")
+        );
+        assert_eq!(root.children[4].value.as_deref(), Some("中"));
+        assert!(documents[0].text.contains("    15: hl=2 l=3      [0]"));
+        assert!(documents[0].error.is_none());
+    }
+
+    #[test]
+    fn test_decode_pem_and_errors() {
+        let pem = format!(
+            "-----BEGIN TEST-----\n{}\n-----END TEST-----\n",
+            general_purpose::STANDARD.encode([0x30, 0x03, 0x02, 0x01, 0x05])
+        );
+        let documents = decode(&pem);
+        assert_eq!(documents[0].label.as_deref(), Some("TEST"));
+        assert_eq!(
+            documents[0].nodes[0].children[0].value.as_deref(),
+            Some("5")
+        );
+
+        // 声明长度超过实际数据
+        let documents = decode("30 05 02 01 05");
+        assert!(documents[0].error.as_deref().unwrap().contains("超出"));
+        assert!(decode_asn1(Asn1DecodeRequest {
+            input: None,
+            file_path: None,
+            encoding: None,
+        })
+        .is_err());
+    }
+}
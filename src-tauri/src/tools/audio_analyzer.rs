@@ -0,0 +1,359 @@
+use crate::tools::video_converter;
+use serde::{Deserialize, Serialize};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// 波形解码采样率，足够绘制概览波形
+const WAVEFORM_SAMPLE_RATE: u32 = 8000;
+const DEFAULT_WAVEFORM_POINTS: usize = 1000;
+const MAX_WAVEFORM_POINTS: usize = 10000;
+
+const AUDIO_EXTENSIONS: [&str; 14] = [
+    "mp3", "wav", "flac", "aac", "m4a", "ogg", "opus", "wma", "aiff", "aif", "alac", "mp4", "mov",
+    "mkv",
+];
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AudioAnalysisRequest {
+    pub input_path: String,
+    pub waveform_points: Option<usize>, // 波形点数，默认 1000
+    pub skip_loudness: Option<bool>,    // 跳过响度分析（需要完整解码）
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LoudnessStats {
+    pub integrated_lufs: Option<f64>,
+    pub integrated_threshold: Option<f64>,
+    pub loudness_range: Option<f64>, // LRA，单位 LU
+    pub lra_low: Option<f64>,
+    pub lra_high: Option<f64>,
+    pub true_peak_dbtp: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WaveformPoint {
+    pub min: f32,
+    pub max: f32,
+    pub rms: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AudioAnalysis {
+    pub name: String,
+    pub path: String,
+    pub codec: Option<String>,
+    pub codec_long_name: Option<String>,
+    pub duration: String,
+    pub duration_seconds: Option<f64>,
+    pub sample_rate: Option<u64>,
+    pub channels: Option<u64>,
+    pub channel_layout: Option<String>,
+    pub sample_format: Option<String>,
+    pub bit_depth: Option<u32>,
+    pub bit_rate: Option<u64>,
+    pub loudness: Option<LoudnessStats>,
+    pub waveform: Vec<WaveformPoint>,
+    pub warnings: Vec<String>,
+}
+
+/// 分析音频：基本信息、EBU R128 响度、真峰值与波形数据
+#[tauri::command]
+pub async fn analyze_audio(request: AudioAnalysisRequest) -> Result<AudioAnalysis, String> {
+    let input_path = validate_audio_file(&request.input_path)?;
+    let info = video_converter::extract_video_info(&input_path)?;
+    let stream = info
+        .streams
+        .iter()
+        .find(|s| s.codec_type == "audio")
+        .ok_or_else(|| "文件中没有音频流".to_string())?;
+    let duration_seconds = stream.duration_seconds.or(info.container.duration_seconds);
+    let points = request
+        .waveform_points
+        .unwrap_or(DEFAULT_WAVEFORM_POINTS)
+        .clamp(10, MAX_WAVEFORM_POINTS);
+
+    // 响度与波形各需完整解码一次，并行执行
+    let skip_loudness = request.skip_loudness.unwrap_or(false);
+    let (loudness, waveform) = std::thread::scope(|scope| {
+        let loudness = scope.spawn(|| {
+            if skip_loudness {
+                Ok(None)
+            } else {
+                measure_loudness(&input_path).map(Some)
+            }
+        });
+        let waveform = extract_waveform(&input_path, duration_seconds, points);
+        let loudness = loudness
+            .join()
+            .unwrap_or_else(|_| Err("响度分析线程异常退出".to_string()));
+        (loudness, waveform)
+    });
+    let loudness = loudness?;
+    let waveform = waveform?;
+
+    let mut warnings = Vec::new();
+    if let Some(stats) = &loudness {
+        if stats.true_peak_dbtp.is_some_and(|p| p > -1.0) {
+            warnings.push("真峰值高于 -1 dBTP，有损编码后可能削波".to_string());
+        }
+        if let Some(lufs) = stats.integrated_lufs {
+            if lufs > -13.0 {
+                warnings.push(format!(
+                    "整体响度 {:.1} LUFS 高于流媒体平台常用的 -14 LUFS，播放时会被压低",
+                    lufs
+                ));
+            } else if lufs < -20.0 {
+                warnings.push(format!(
+                    "整体响度 {:.1} LUFS 偏低，播客建议约 -16 LUFS",
+                    lufs
+                ));
+            }
+        }
+    }
+    if waveform.iter().any(|p| p.max >= 0.999 || p.min <= -0.999) {
+        warnings.push("检测到满幅采样，音频可能已削波".to_string());
+    }
+
+    Ok(AudioAnalysis {
+        name: info.name.clone(),
+        path: info.path.clone(),
+        codec: stream.codec_name.clone(),
+        codec_long_name: stream.codec_long_name.clone(),
+        duration: duration_seconds
+            .map(video_converter::format_duration)
+            .unwrap_or_else(|| "未知".to_string()),
+        duration_seconds,
+        sample_rate: stream.sample_rate,
+        channels: stream.channels,
+        channel_layout: stream.channel_layout.clone(),
+        sample_format: stream.sample_format.clone(),
+        bit_depth: stream.bit_depth,
+        bit_rate: stream.bit_rate.or(info.container.bit_rate),
+        loudness,
+        waveform,
+        warnings,
+    })
+}
+
+fn validate_audio_file(input_path: &str) -> Result<PathBuf, String> {
+    let path = Path::new(input_path);
+    if !path.is_file() {
+        return Err(format!("输入文件不存在: {}", input_path));
+    }
+    let ext = path
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    if !AUDIO_EXTENSIONS.contains(&ext.as_str()) {
+        return Err(format!("不支持的文件格式: {}", ext));
+    }
+    Ok(path.to_path_buf())
+}
+
+/// 使用 ebur128 滤镜测量响度与真峰值
+fn measure_loudness(input_path: &Path) -> Result<LoudnessStats, String> {
+    let output = Command::new("ffmpeg")
+        .args(["-hide_banner", "-nostats", "-i"])
+        .arg(input_path)
+        .args([
+            "-map",
+            "0:a:0",
+            "-af",
+            "ebur128=peak=true",
+            "-f",
+            "null",
+            "-",
+        ])
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .output()
+        .map_err(|e| format!("启动 FFmpeg 进程失败: {}", e))?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if !output.status.success() {
+        return Err(format!("响度分析失败: {}", stderr));
+    }
+    Ok(parse_ebur128_summary(&stderr))
+}
+
+/// 解析 ebur128 输出末尾的 Summary 段
+fn parse_ebur128_summary(stderr: &str) -> LoudnessStats {
+    let mut stats = LoudnessStats::default();
+    let Some(summary) = stderr.rfind("Summary:").map(|i| &stderr[i..]) else {
+        return stats;
+    };
+    let mut section = "";
+    for line in summary.lines().map(str::trim) {
+        if line.ends_with(':') {
+            section = line;
+            continue;
+        }
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value
+            .split_whitespace()
+            .next()
+            .and_then(|v| v.parse::<f64>().ok());
+        match (section, key.trim()) {
+            ("Integrated loudness:", "I") => stats.integrated_lufs = value,
+            ("Integrated loudness:", "Threshold") => stats.integrated_threshold = value,
+            ("Loudness range:", "LRA") => stats.loudness_range = value,
+            ("Loudness range:", "LRA low") => stats.lra_low = value,
+            ("Loudness range:", "LRA high") => stats.lra_high = value,
+            ("True peak:", "Peak") => stats.true_peak_dbtp = value,
+            _ => {}
+        }
+    }
+    stats
+}
+
+/// 解码为单声道 PCM 并按时间分桶计算峰值，避免将整段音频读入内存
+fn extract_waveform(
+    input_path: &Path,
+    duration_seconds: Option<f64>,
+    points: usize,
+) -> Result<Vec<WaveformPoint>, String> {
+    let mut child = Command::new("ffmpeg")
+        .args(["-hide_banner", "-nostats", "-v", "error", "-i"])
+        .arg(input_path)
+        .args(["-map", "0:a:0", "-ac", "1", "-ar"])
+        .arg(WAVEFORM_SAMPLE_RATE.to_string())
+        .args(["-f", "f32le", "-"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| format!("启动 FFmpeg 进程失败: {}", e))?;
+    let mut stdout = child
+        .stdout
+        .take()
+        .ok_or_else(|| "无法读取 FFmpeg 输出".to_string())?;
+
+    let total_samples = duration_seconds
+        .map(|d| (d * WAVEFORM_SAMPLE_RATE as f64).ceil() as usize)
+        .unwrap_or(points);
+    let mut builder = WaveformBuilder::new(total_samples.div_ceil(points).max(1));
+    let mut buffer = vec![0u8; 64 * 1024];
+    let mut pending: Vec<u8> = Vec::with_capacity(4);
+    loop {
+        let read = stdout
+            .read(&mut buffer)
+            .map_err(|e| format!("读取音频数据失败: {}", e))?;
+        if read == 0 {
+            break;
+        }
+        pending.extend_from_slice(&buffer[..read]);
+        let usable = pending.len() / 4 * 4;
+        for chunk in pending[..usable].chunks_exact(4) {
+            builder.push(f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]));
+        }
+        pending.drain(..usable);
+    }
+    let status = child
+        .wait()
+        .map_err(|e| format!("等待 FFmpeg 进程完成失败: {}", e))?;
+    if !status.success() {
+        return Err("生成波形数据失败".to_string());
+    }
+    Ok(builder.finish())
+}
+
+struct WaveformBuilder {
+    bucket_size: usize,
+    count: usize,
+    min: f32,
+    max: f32,
+    sum_squares: f64,
+    points: Vec<WaveformPoint>,
+}
+
+impl WaveformBuilder {
+    fn new(bucket_size: usize) -> Self {
+        Self {
+            bucket_size,
+            count: 0,
+            min: 0.0,
+            max: 0.0,
+            sum_squares: 0.0,
+            points: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, sample: f32) {
+        self.min = self.min.min(sample);
+        self.max = self.max.max(sample);
+        self.sum_squares += (sample as f64) * (sample as f64);
+        self.count += 1;
+        if self.count == self.bucket_size {
+            self.flush();
+        }
+    }
+
+    fn flush(&mut self) {
+        if self.count == 0 {
+            return;
+        }
+        self.points.push(WaveformPoint {
+            min: self.min,
+            max: self.max,
+            rms: (self.sum_squares / self.count as f64).sqrt() as f32,
+        });
+        self.count = 0;
+        self.min = 0.0;
+        self.max = 0.0;
+        self.sum_squares = 0.0;
+    }
+
+    fn finish(mut self) -> Vec<WaveformPoint> {
+        self.flush();
+        self.points
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ebur128_summary() {
+        let stderr = "[Parsed_ebur128_0 @ 0x600] t: 9.9  TARGET:-23 LUFS    M: -20.1 S: -19.8     I: -19.6 LUFS       LRA:   4.0 LU  FTPK:  -1.2 dBFS  TPK:  -0.9 dBFS
+[Parsed_ebur128_0 @ 0x600] Summary:
+
+  Integrated loudness:
+    I:         -19.5 LUFS
+    Threshold: -29.6 LUFS
+
+  Loudness range:
+    LRA:         5.2 LU
+    Threshold: -39.6 LUFS
+    LRA low:   -23.2 LUFS
+    LRA high:  -18.0 LUFS
+
+  True peak:
+    Peak:       -0.8 dBFS
+";
+        let stats = parse_ebur128_summary(stderr);
+        assert_eq!(stats.integrated_lufs, Some(-19.5));
+        assert_eq!(stats.integrated_threshold, Some(-29.6));
+        assert_eq!(stats.loudness_range, Some(5.2));
+        assert_eq!(stats.lra_low, Some(-23.2));
+        assert_eq!(stats.lra_high, Some(-18.0));
+        assert_eq!(stats.true_peak_dbtp, Some(-0.8));
+        assert!(parse_ebur128_summary("no summary")
+            .integrated_lufs
+            .is_none());
+    }
+
+    #[test]
+    fn test_waveform_builder() {
+        let mut builder = WaveformBuilder::new(4);
+        for sample in [0.5, -0.25, 0.1, 0.0, 1.0, -1.0] {
+            builder.push(sample);
+        }
+        let points = builder.finish();
+        assert_eq!(points.len(), 2);
+        assert_eq!((points[0].min, points[0].max), (-0.25, 0.5));
+        assert_eq!((points[1].min, points[1].max), (-1.0, 1.0));
+        assert!((points[1].rms - 1.0).abs() < 1e-6);
+    }
+}
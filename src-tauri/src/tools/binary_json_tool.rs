@@ -0,0 +1,761 @@
+use base64::{engine::general_purpose, Engine as _};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Number, Value};
+use std::fmt::Write as _;
+use std::io::Cursor;
+
+/// 诊断信息中错误位置前后展示的字节数
+const CONTEXT_BYTES: usize = 16;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BinaryJsonRequest {
+    pub format: String,    // msgpack, cbor, bson
+    pub direction: String, // encode: JSON → 二进制，decode: 二进制 → JSON
+    pub input: String,
+    pub input_encoding: Option<String>, // 解码时的输入编码：auto, hex, base64
+    pub output_encoding: Option<String>, // 编码时的输出编码：hex, base64，默认 hex
+    pub pretty: Option<bool>,           // 解码输出是否格式化，默认 true
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BinaryJsonResponse {
+    pub output: String,
+    pub byte_length: usize,    // 二进制数据的字节数
+    pub value_count: usize,    // 连续存放的值的个数
+    pub warnings: Vec<String>, // 转换中有损或需要注意的地方
+}
+
+/// JSON 与 MessagePack、CBOR、BSON 互相转换
+#[tauri::command]
+pub fn convert_binary_json(request: BinaryJsonRequest) -> Result<BinaryJsonResponse, String> {
+    let format = request.format.trim().to_lowercase();
+    let format = match format.as_str() {
+        "messagepack" | "msgpack" => "msgpack",
+        "cbor" => "cbor",
+        "bson" => "bson",
+        _ => return Err(format!("不支持的格式: {}", request.format)),
+    };
+    let mut warnings = Vec::new();
+
+    match request.direction.as_str() {
+        "encode" => {
+            let json: Value =
+                serde_json::from_str(&request.input).map_err(|e| format!("JSON解析失败: {}", e))?;
+            let bytes = match format {
+                "msgpack" => encode_msgpack(&json)?,
+                "cbor" => encode_cbor(&json)?,
+                _ => encode_bson(json)?,
+            };
+            let output = match request.output_encoding.as_deref().unwrap_or("hex") {
+                "base64" => general_purpose::STANDARD.encode(&bytes),
+                "hex" => hex::encode(&bytes),
+                other => return Err(format!("不支持的输出编码: {}", other)),
+            };
+            Ok(BinaryJsonResponse {
+                output,
+                byte_length: bytes.len(),
+                value_count: 1,
+                warnings,
+            })
+        }
+        "decode" => {
+            let bytes = decode_input(
+                &request.input,
+                request.input_encoding.as_deref().unwrap_or("auto"),
+            )?;
+            if bytes.is_empty() {
+                return Err("输入数据为空".to_string());
+            }
+            let mut values = match format {
+                "msgpack" => decode_msgpack(&bytes, &mut warnings)?,
+                "cbor" => decode_cbor(&bytes, &mut warnings)?,
+                _ => decode_bson(&bytes, &mut warnings)?,
+            };
+            let value_count = values.len();
+            let json = if value_count == 1 {
+                values.remove(0)
+            } else {
+                warnings.push(format!("数据包含 {} 个连续的值，已合并为数组", value_count));
+                Value::Array(values)
+            };
+            let output = if request.pretty.unwrap_or(true) {
+                serde_json::to_string_pretty(&json)
+            } else {
+                serde_json::to_string(&json)
+            }
+            .map_err(|e| format!("JSON序列化失败: {}", e))?;
+            warnings.dedup();
+            Ok(BinaryJsonResponse {
+                output,
+                byte_length: bytes.len(),
+                value_count,
+                warnings,
+            })
+        }
+        other => Err(format!("不支持的转换方向: {}", other)),
+    }
+}
+
+fn decode_input(input: &str, encoding: &str) -> Result<Vec<u8>, String> {
+    let trimmed = input.trim();
+    let hex_text: String = trimmed
+        .replace("0x", "")
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != ':')
+        .collect();
+    let looks_hex = !hex_text.is_empty() && hex_text.chars().all(|c| c.is_ascii_hexdigit());
+    match encoding {
+        "hex" => hex::decode(&hex_text).map_err(|e| format!("十六进制解码失败: {}", e)),
+        "auto" if looks_hex && hex_text.len().is_multiple_of(2) => {
+            hex::decode(&hex_text).map_err(|e| format!("十六进制解码失败: {}", e))
+        }
+        "base64" | "auto" => {
+            let cleaned: String = trimmed.chars().filter(|c| !c.is_whitespace()).collect();
+            general_purpose::STANDARD
+                .decode(&cleaned)
+                .or_else(|_| general_purpose::URL_SAFE_NO_PAD.decode(cleaned.trim_end_matches('=')))
+                .map_err(|e| format!("Base64 解码失败: {}", e))
+        }
+        other => Err(format!("不支持的输入编码: {}", other)),
+    }
+}
+
+/// 生成带十六进制上下文的错误说明，^^ 标出出错的字节
+fn diagnostic(bytes: &[u8], offset: usize, message: &str) -> String {
+    let start = offset.saturating_sub(CONTEXT_BYTES);
+    let end = (offset + CONTEXT_BYTES).min(bytes.len());
+    let mut text = format!("偏移 {} 处解析失败: {}\n", offset, message);
+    let mut marker = String::new();
+    let _ = write!(text, "{:08x}  ", start);
+    for (i, byte) in bytes[start..end].iter().enumerate() {
+        let _ = write!(text, "{:02x} ", byte);
+        marker.push_str(if start + i == offset { "^^ " } else { "   " });
+    }
+    if offset >= bytes.len() {
+        marker.push_str("^^（数据在此处意外结束）");
+    }
+    let _ = write!(text, "\n          {}", marker.trim_end());
+    text
+}
+
+fn json_number(value: f64, warnings: &mut Vec<String>) -> Value {
+    match Number::from_f64(value) {
+        Some(number) => Value::Number(number),
+        None => {
+            warnings.push(format!("浮点数 {} 无法用 JSON 表示，已转为 null", value));
+            Value::Null
+        }
+    }
+}
+
+fn encode_msgpack(json: &Value) -> Result<Vec<u8>, String> {
+    fn convert(json: &Value) -> rmpv::Value {
+        match json {
+            Value::Null => rmpv::Value::Nil,
+            Value::Bool(b) => rmpv::Value::Boolean(*b),
+            Value::Number(n) => {
+                if let Some(u) = n.as_u64() {
+                    rmpv::Value::from(u)
+                } else if let Some(i) = n.as_i64() {
+                    rmpv::Value::from(i)
+                } else {
+                    rmpv::Value::F64(n.as_f64().unwrap_or_default())
+                }
+            }
+            Value::String(s) => rmpv::Value::from(s.as_str()),
+            Value::Array(items) => rmpv::Value::Array(items.iter().map(convert).collect()),
+            Value::Object(map) => rmpv::Value::Map(
+                map.iter()
+                    .map(|(k, v)| (rmpv::Value::from(k.as_str()), convert(v)))
+                    .collect(),
+            ),
+        }
+    }
+    let mut bytes = Vec::new();
+    rmpv::encode::write_value(&mut bytes, &convert(json))
+        .map_err(|e| format!("MessagePack 编码失败: {}", e))?;
+    Ok(bytes)
+}
+
+fn decode_msgpack(bytes: &[u8], warnings: &mut Vec<String>) -> Result<Vec<Value>, String> {
+    fn convert(value: rmpv::Value, warnings: &mut Vec<String>) -> Value {
+        match value {
+            rmpv::Value::Nil => Value::Null,
+            rmpv::Value::Boolean(b) => Value::Bool(b),
+            rmpv::Value::Integer(i) => match (i.as_u64(), i.as_i64()) {
+                (Some(u), _) => Value::from(u),
+                (_, Some(i)) => Value::from(i),
+                _ => Value::Null,
+            },
+            rmpv::Value::F32(f) => json_number(f as f64, warnings),
+            rmpv::Value::F64(f) => json_number(f, warnings),
+            rmpv::Value::String(s) => match s.into_str() {
+                Some(s) => Value::String(s),
+                None => {
+                    warnings.push("存在非 UTF-8 字符串，已按有损方式转换".to_string());
+                    Value::String(String::new())
+                }
+            },
+            rmpv::Value::Binary(data) => {
+                warnings.push("二进制数据已转为 Base64 字符串".to_string());
+                Value::String(general_purpose::STANDARD.encode(data))
+            }
+            rmpv::Value::Array(items) => Value::Array(
+                items
+                    .into_iter()
+                    .map(|item| convert(item, warnings))
+                    .collect(),
+            ),
+            rmpv::Value::Map(entries) => {
+                let mut map = Map::new();
+                for (key, value) in entries {
+                    let key = match key {
+                        rmpv::Value::String(s) if s.is_str() => s.into_str().unwrap_or_default(),
+                        other => {
+                            warnings.push("存在非字符串的键，已转为字符串".to_string());
+                            other.to_string()
+                        }
+                    };
+                    map.insert(key, convert(value, warnings));
+                }
+                Value::Object(map)
+            }
+            rmpv::Value::Ext(kind, data) => {
+                warnings.push(format!(
+                    "扩展类型 {} 已转为 {{\"$ext\", \"data\"}} 对象",
+                    kind
+                ));
+                serde_json::json!({
+                    "$ext": kind,
+                    "data": general_purpose::STANDARD.encode(data),
+                })
+            }
+        }
+    }
+
+    let mut cursor = Cursor::new(bytes);
+    let mut values = Vec::new();
+    while (cursor.position() as usize) < bytes.len() {
+        let start = cursor.position() as usize;
+        let value = rmpv::decode::read_value(&mut cursor).map_err(|e| {
+            // 截断时 cursor 已到末尾，其他错误指向当前值的起始位置
+            let offset = if cursor.position() as usize >= bytes.len() {
+                bytes.len()
+            } else {
+                start
+            };
+            diagnostic(bytes, offset, &e.to_string())
+        })?;
+        values.push(convert(value, warnings));
+    }
+    Ok(values)
+}
+
+fn encode_cbor(json: &Value) -> Result<Vec<u8>, String> {
+    let mut bytes = Vec::new();
+    ciborium::ser::into_writer(json, &mut bytes).map_err(|e| format!("CBOR 编码失败: {}", e))?;
+    Ok(bytes)
+}
+
+fn decode_cbor(bytes: &[u8], warnings: &mut Vec<String>) -> Result<Vec<Value>, String> {
+    fn convert(value: ciborium::value::Value, warnings: &mut Vec<String>) -> Value {
+        use ciborium::value::Value as Cbor;
+        match value {
+            Cbor::Null => Value::Null,
+            Cbor::Bool(b) => Value::Bool(b),
+            Cbor::Integer(i) => {
+                let i = i128::from(i);
+                match (u64::try_from(i), i64::try_from(i)) {
+                    (Ok(u), _) => Value::from(u),
+                    (_, Ok(i)) => Value::from(i),
+                    _ => {
+                        warnings.push(format!("整数 {} 超出 64 位，已转为字符串", i));
+                        Value::String(i.to_string())
+                    }
+                }
+            }
+            Cbor::Float(f) => json_number(f, warnings),
+            Cbor::Text(s) => Value::String(s),
+            Cbor::Bytes(data) => {
+                warnings.push("二进制数据已转为 Base64 字符串".to_string());
+                Value::String(general_purpose::STANDARD.encode(data))
+            }
+            Cbor::Array(items) => Value::Array(
+                items
+                    .into_iter()
+                    .map(|item| convert(item, warnings))
+                    .collect(),
+            ),
+            Cbor::Map(entries) => {
+                let mut map = Map::new();
+                for (key, value) in entries {
+                    let key = match key {
+                        Cbor::Text(s) => s,
+                        other => {
+                            warnings.push("存在非字符串的键，已转为字符串".to_string());
+                            convert(other, warnings).to_string()
+                        }
+                    };
+                    map.insert(key, convert(value, warnings));
+                }
+                Value::Object(map)
+            }
+            Cbor::Tag(tag, inner) => {
+                warnings.push(format!("标签 {} 已忽略，只保留内部的值", tag));
+                convert(*inner, warnings)
+            }
+            _ => Value::Null,
+        }
+    }
+
+    let mut cursor = Cursor::new(bytes);
+    let mut values = Vec::new();
+    while (cursor.position() as usize) < bytes.len() {
+        let start = cursor.position() as usize;
+        let value: ciborium::value::Value =
+            ciborium::de::from_reader(&mut cursor).map_err(|e| {
+                use ciborium::de::Error;
+                let (offset, message) = match e {
+                    Error::Io(e) => (bytes.len(), format!("数据不完整: {}", e)),
+                    Error::Syntax(offset) => (start + offset, "语法错误".to_string()),
+                    Error::Semantic(offset, message) => (start + offset.unwrap_or(0), message),
+                    Error::RecursionLimitExceeded => (start, "嵌套层级过深".to_string()),
+                };
+                diagnostic(bytes, offset, &message)
+            })?;
+        values.push(convert(value, warnings));
+    }
+    Ok(values)
+}
+
+/// BSON 的顶层必须是对象，支持扩展 JSON，如 {"$oid": "..."}、{"$date": "..."}
+fn encode_bson(json: Value) -> Result<Vec<u8>, String> {
+    let Value::Object(map) = json else {
+        return Err("BSON 的顶层必须是对象".to_string());
+    };
+    let mut bytes = Vec::new();
+    write_bson_document(&mut bytes, map.iter().map(|(k, v)| (k.as_str(), v)))?;
+    Ok(bytes)
+}
+
+fn write_bson_document<'a>(
+    out: &mut Vec<u8>,
+    entries: impl Iterator<Item = (&'a str, &'a Value)>,
+) -> Result<(), String> {
+    let start = out.len();
+    out.extend([0; 4]);
+    for (key, value) in entries {
+        if key.contains('\0') {
+            return Err(format!("键 {:?} 不能包含空字符", key));
+        }
+        let type_pos = out.len();
+        out.push(0);
+        out.extend(key.as_bytes());
+        out.push(0);
+        out[type_pos] = write_bson_value(out, value)?;
+    }
+    out.push(0);
+    let length = (out.len() - start) as i32;
+    out[start..start + 4].copy_from_slice(&length.to_le_bytes());
+    Ok(())
+}
+
+fn write_bson_cstring(out: &mut Vec<u8>, text: &str) {
+    out.extend(text.as_bytes());
+    out.push(0);
+}
+
+fn write_bson_string(out: &mut Vec<u8>, text: &str) {
+    out.extend(((text.len() + 1) as i32).to_le_bytes());
+    write_bson_cstring(out, text);
+}
+
+/// 写入值并返回类型字节
+fn write_bson_value(out: &mut Vec<u8>, value: &Value) -> Result<u8, String> {
+    if let Some(kind) = write_bson_extended(out, value)? {
+        return Ok(kind);
+    }
+    Ok(match value {
+        Value::Null => 0x0a,
+        Value::Bool(b) => {
+            out.push(*b as u8);
+            0x08
+        }
+        Value::Number(n) => match (n.as_i64(), n.as_f64()) {
+            (Some(i), _) if i32::try_from(i).is_ok() => {
+                out.extend((i as i32).to_le_bytes());
+                0x10
+            }
+            (Some(i), _) => {
+                out.extend(i.to_le_bytes());
+                0x12
+            }
+            (None, Some(f)) => {
+                out.extend(f.to_le_bytes());
+                0x01
+            }
+            _ => return Err(format!("数字 {} 超出 BSON 支持的范围", n)),
+        },
+        Value::String(s) => {
+            write_bson_string(out, s);
+            0x02
+        }
+        Value::Array(items) => {
+            let keys: Vec<String> = (0..items.len()).map(|i| i.to_string()).collect();
+            write_bson_document(out, keys.iter().map(String::as_str).zip(items.iter()))?;
+            0x04
+        }
+        Value::Object(map) => {
+            write_bson_document(out, map.iter().map(|(k, v)| (k.as_str(), v)))?;
+            0x03
+        }
+    })
+}
+
+/// 识别常用的扩展 JSON 写法，不是扩展 JSON 时返回 None
+fn write_bson_extended(out: &mut Vec<u8>, value: &Value) -> Result<Option<u8>, String> {
+    let Some(map) = value.as_object().filter(|m| m.len() == 1) else {
+        return Ok(None);
+    };
+    let Some((key, inner)) = map.iter().next() else {
+        return Ok(None);
+    };
+    let number_text = |v: &Value| match v {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    };
+    let kind = match key.as_str() {
+        "$oid" => {
+            let oid = inner
+                .as_str()
+                .and_then(|s| hex::decode(s).ok())
+                .filter(|b| b.len() == 12)
+                .ok_or("$oid 必须是 24 位十六进制字符串")?;
+            out.extend(oid);
+            0x07
+        }
+        "$date" => {
+            let millis = match inner {
+                Value::String(s) => chrono::DateTime::parse_from_rfc3339(s)
+                    .map_err(|e| format!("$date 格式无效: {}", e))?
+                    .timestamp_millis(),
+                Value::Object(m) => m
+                    .get("$numberLong")
+                    .and_then(number_text)
+                    .and_then(|s| s.parse().ok())
+                    .ok_or("$date.$numberLong 无效")?,
+                other => other.as_i64().ok_or("$date 格式无效")?,
+            };
+            out.extend(millis.to_le_bytes());
+            0x09
+        }
+        "$numberLong" => {
+            let value: i64 = number_text(inner)
+                .and_then(|s| s.parse().ok())
+                .ok_or("$numberLong 无效")?;
+            out.extend(value.to_le_bytes());
+            0x12
+        }
+        "$numberInt" => {
+            let value: i32 = number_text(inner)
+                .and_then(|s| s.parse().ok())
+                .ok_or("$numberInt 无效")?;
+            out.extend(value.to_le_bytes());
+            0x10
+        }
+        "$numberDouble" => {
+            let value: f64 = number_text(inner)
+                .and_then(|s| s.parse().ok())
+                .ok_or("$numberDouble 无效")?;
+            out.extend(value.to_le_bytes());
+            0x01
+        }
+        "$binary" => {
+            let data = inner
+                .get("base64")
+                .and_then(Value::as_str)
+                .and_then(|s| general_purpose::STANDARD.decode(s).ok())
+                .ok_or("$binary.base64 无效")?;
+            let subtype = inner
+                .get("subType")
+                .and_then(Value::as_str)
+                .and_then(|s| u8::from_str_radix(s, 16).ok())
+                .unwrap_or(0);
+            out.extend((data.len() as i32).to_le_bytes());
+            out.push(subtype);
+            out.extend(data);
+            0x05
+        }
+        "$regularExpression" => {
+            let field = |name: &str| inner.get(name).and_then(Value::as_str).unwrap_or_default();
+            write_bson_cstring(out, field("pattern"));
+            write_bson_cstring(out, field("options"));
+            0x0b
+        }
+        "$timestamp" => {
+            let field = |name: &str| inner.get(name).and_then(Value::as_u64).unwrap_or(0) as u32;
+            out.extend(field("i").to_le_bytes());
+            out.extend(field("t").to_le_bytes());
+            0x11
+        }
+        "$minKey" => 0xff,
+        "$maxKey" => 0x7f,
+        "$undefined" => 0x06,
+        _ => return Ok(None),
+    };
+    Ok(Some(kind))
+}
+
+/// BSON 读取器，错误信息带文件内的绝对偏移
+struct BsonReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    warnings: &'a mut Vec<String>,
+}
+
+impl BsonReader<'_> {
+    fn take(&mut self, length: usize) -> Result<&[u8], String> {
+        let end = self
+            .pos
+            .checked_add(length)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or_else(|| diagnostic(self.bytes, self.bytes.len(), "数据不完整"))?;
+        let slice = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        let mut buf = [0u8; N];
+        buf.copy_from_slice(self.take(N)?);
+        Ok(buf)
+    }
+
+    fn i32(&mut self) -> Result<i32, String> {
+        Ok(i32::from_le_bytes(self.array()?))
+    }
+
+    fn cstring(&mut self) -> Result<String, String> {
+        let start = self.pos;
+        let length = self.bytes[start..]
+            .iter()
+            .position(|b| *b == 0)
+            .ok_or_else(|| diagnostic(self.bytes, start, "字符串缺少结尾的空字符"))?;
+        let text = String::from_utf8(self.take(length)?.to_vec())
+            .map_err(|_| diagnostic(self.bytes, start, "字符串不是有效的 UTF-8"))?;
+        self.pos += 1;
+        Ok(text)
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        let start = self.pos;
+        let length = self.i32()?;
+        if length < 1 {
+            return Err(diagnostic(
+                self.bytes,
+                start,
+                &format!("字符串长度 {} 无效", length),
+            ));
+        }
+        let data = self.take(length as usize)?;
+        if data.last() != Some(&0) {
+            return Err(diagnostic(self.bytes, start, "字符串缺少结尾的空字符"));
+        }
+        String::from_utf8(data[..data.len() - 1].to_vec())
+            .map_err(|_| diagnostic(self.bytes, start + 4, "字符串不是有效的 UTF-8"))
+    }
+
+    /// 读取文档，array 为 true 时输出 JSON 数组
+    fn document(&mut self, array: bool) -> Result<Value, String> {
+        let start = self.pos;
+        let length = self.i32()?;
+        if length < 5 {
+            return Err(diagnostic(
+                self.bytes,
+                start,
+                &format!("文档长度 {} 无效", length),
+            ));
+        }
+        let end = start + length as usize;
+        if end > self.bytes.len() {
+            return Err(diagnostic(
+                self.bytes,
+                self.bytes.len(),
+                &format!(
+                    "文档声明长度 {}，实际只剩 {} 字节",
+                    length,
+                    self.bytes.len() - start
+                ),
+            ));
+        }
+        let mut map = Map::new();
+        let mut items = Vec::new();
+        loop {
+            let type_pos = self.pos;
+            let kind = self.array::<1>()?[0];
+            if kind == 0 {
+                break;
+            }
+            let key = self.cstring()?;
+            let value = self.value(kind, type_pos)?;
+            if array {
+                items.push(value);
+            } else {
+                map.insert(key, value);
+            }
+        }
+        if self.pos != end {
+            return Err(diagnostic(
+                self.bytes,
+                self.pos,
+                &format!("文档结束位置与声明长度 {} 不符", length),
+            ));
+        }
+        Ok(if array {
+            Value::Array(items)
+        } else {
+            Value::Object(map)
+        })
+    }
+
+    /// 按 relaxed 扩展 JSON 输出
+    fn value(&mut self, kind: u8, type_pos: usize) -> Result<Value, String> {
+        Ok(match kind {
+            0x01 => json_number(f64::from_le_bytes(self.array()?), self.warnings),
+            0x02 => Value::String(self.string()?),
+            0x03 => self.document(false)?,
+            0x04 => self.document(true)?,
+            0x05 => {
+                let length = self.i32()?;
+                let subtype = self.array::<1>()?[0];
+                let data = self.take(length.max(0) as usize)?;
+                serde_json::json!({"$binary": {
+                    "base64": general_purpose::STANDARD.encode(data),
+                    "subType": format!("{:02x}", subtype),
+                }})
+            }
+            0x06 => serde_json::json!({"$undefined": true}),
+            0x07 => serde_json::json!({"$oid": hex::encode(self.take(12)?)}),
+            0x08 => Value::Bool(self.array::<1>()?[0] != 0),
+            0x09 => {
+                let millis = i64::from_le_bytes(self.array()?);
+                match chrono::DateTime::from_timestamp_millis(millis) {
+                    Some(date) if (0..=253_402_300_799_999).contains(&millis) => {
+                        serde_json::json!({"$date": date.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string()})
+                    }
+                    _ => serde_json::json!({"$date": {"$numberLong": millis.to_string()}}),
+                }
+            }
+            0x0a => Value::Null,
+            0x0b => {
+                let pattern = self.cstring()?;
+                let options = self.cstring()?;
+                serde_json::json!({"$regularExpression": {"pattern": pattern, "options": options}})
+            }
+            0x0d => serde_json::json!({"$code": self.string()?}),
+            0x10 => Value::from(self.i32()?),
+            0x11 => {
+                let increment = u32::from_le_bytes(self.array()?);
+                let time = u32::from_le_bytes(self.array()?);
+                serde_json::json!({"$timestamp": {"t": time, "i": increment}})
+            }
+            0x12 => Value::from(i64::from_le_bytes(self.array()?)),
+            0x13 => {
+                self.warnings
+                    .push("Decimal128 以原始字节的十六进制输出".to_string());
+                serde_json::json!({"$numberDecimalBytes": hex::encode(self.take(16)?)})
+            }
+            0x7f => serde_json::json!({"$maxKey": 1}),
+            0xff => serde_json::json!({"$minKey": 1}),
+            other => {
+                return Err(diagnostic(
+                    self.bytes,
+                    type_pos,
+                    &format!("不支持的类型 0x{:02x}", other),
+                ))
+            }
+        })
+    }
+}
+
+/// 连续存放的多个文档（如 mongodump 的 .bson 文件）逐个解析
+fn decode_bson(bytes: &[u8], warnings: &mut Vec<String>) -> Result<Vec<Value>, String> {
+    let mut reader = BsonReader {
+        bytes,
+        pos: 0,
+        warnings,
+    };
+    let mut values = Vec::new();
+    while reader.pos < bytes.len() {
+        values.push(reader.document(false)?);
+    }
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(format: &str, direction: &str, input: &str) -> Result<BinaryJsonResponse, String> {
+        convert_binary_json(BinaryJsonRequest {
+            format: format.to_string(),
+            direction: direction.to_string(),
+            input: input.to_string(),
+            input_encoding: None,
+            output_encoding: None,
+            pretty: Some(false),
+        })
+    }
+
+    #[test]
+    fn test_round_trip_formats() {
+        let json = r#"{"a":1,"b":[true,null,-2,1.5],"c":"中文"}"#;
+        for format in ["msgpack", "cbor", "bson"] {
+            let encoded = convert(format, "encode", json).unwrap();
+            let decoded = convert(format, "decode", &encoded.output).unwrap();
+            assert_eq!(decoded.output, json, "{}", format);
+            assert_eq!(decoded.byte_length, encoded.byte_length);
+        }
+        assert_eq!(
+            convert("msgpack", "encode", r#"{"a":1}"#).unwrap().output,
+            "81a16101"
+        );
+
+        let oid = convert(
+            "bson",
+            "encode",
+            r#"{"_id":{"$oid":"65a1b2c3d4e5f60718293a4b"}}"#,
+        )
+        .unwrap();
+        let decoded = convert("bson", "decode", &oid.output).unwrap();
+        assert_eq!(
+            decoded.output,
+            r#"{"_id":{"$oid":"65a1b2c3d4e5f60718293a4b"}}"#
+        );
+        assert!(convert("bson", "encode", "[1]").is_err());
+    }
+
+    #[test]
+    fn test_decode_diagnostics() {
+        // 两个连续的 MessagePack 值，其中一个是二进制
+        let decoded = convert("msgpack", "decode", "01 c4 02 ab cd").unwrap();
+        assert_eq!(decoded.value_count, 2);
+        assert_eq!(decoded.output, r#"[1,"q80="]"#);
+        assert_eq!(decoded.warnings.len(), 2);
+
+        // 数组声明 3 个元素但只有 1 个
+        let err = convert("msgpack", "decode", "93 01").unwrap_err();
+        assert!(err.starts_with("偏移 2 处解析失败"), "{}", err);
+        assert!(err.contains("00000000  93 01"));
+
+        // CBOR 标签 1（时间戳）
+        let decoded = convert("cbor", "decode", "c1 1a 65 a1 b2 c3").unwrap();
+        assert_eq!(decoded.output, "1705095875");
+        let err = convert("cbor", "decode", "82 01").unwrap_err();
+        assert!(err.contains("数据不完整"));
+
+        let err = convert("bson", "decode", "10 00 00 00 00").unwrap_err();
+        assert!(err.contains("文档声明长度 16"));
+    }
+}
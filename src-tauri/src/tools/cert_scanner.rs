@@ -0,0 +1,404 @@
+use crate::tools::certificate_viewer::{cached_parse, CertificateInfo};
+use crate::utils::progress::ProgressReporter;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+use x509_parser::pem::Pem;
+
+/// 按扩展名识别的证书文件
+const CERT_EXTENSIONS: &[&str] = &[
+    "pem",
+    "crt",
+    "cer",
+    "cert",
+    "der",
+    "ca-bundle",
+    "pfx",
+    "p12",
+];
+/// 扫描时跳过的目录
+const SKIPPED_DIRS: &[&str] = &[".git", "node_modules", "target", "vendor", ".venv"];
+/// 证书文件大小上限，超过的文件视为非证书
+const MAX_FILE_SIZE: u64 = 4 * 1024 * 1024;
+/// 最多扫描的文件数
+const MAX_FILES: usize = 10_000;
+
+#[derive(Debug, Deserialize)]
+pub struct CertificateScanRequest {
+    pub directory: String,
+    pub recursive: Option<bool>,      // 默认递归扫描子目录
+    pub expiring_days: Option<i64>,   // 剩余天数低于该值视为即将过期，默认 30
+    pub pfx_password: Option<String>, // PFX/P12 文件的密码，默认尝试空密码
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScannedCertificate {
+    pub path: String,
+    pub format: String, // PEM、DER、PFX
+    pub index: usize,   // 在文件中的序号，从 0 开始
+    pub subject: String,
+    pub issuer: String,
+    pub not_after: String,
+    pub days_until_expiry: i64,
+    pub key_type: String,
+    pub key_size: Option<u32>,
+    pub signature_algorithm: String,
+    pub sha256_fingerprint: String,
+    pub status: String, // expired、expiring、weak、ok
+    pub issues: Vec<String>,
+    pub duplicate_of: Option<String>, // 同一证书首次出现的文件
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CertificateScanFailure {
+    pub path: String,
+    pub error: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CertificateScanReport {
+    pub directory: String,
+    pub files_scanned: usize,
+    pub certificates: Vec<ScannedCertificate>, // 按到期时间升序
+    pub failures: Vec<CertificateScanFailure>,
+    pub expired_count: usize,
+    pub expiring_count: usize,
+    pub weak_count: usize,
+    pub duplicate_count: usize,
+    pub truncated: bool, // 文件数超过上限，只扫描了前面的部分
+}
+
+/// 扫描目录中的证书文件，生成到期时间、密钥强度和重复证书清单
+#[tauri::command]
+pub async fn scan_certificates_directory(
+    app: AppHandle,
+    request: CertificateScanRequest,
+) -> Result<CertificateScanReport, String> {
+    let mut reporter = ProgressReporter::new(&app, "cert_scanner");
+    tokio::task::spawn_blocking(move || {
+        let result = scan_directory(&request, |processed, total, path| {
+            reporter.update(processed, Some(total), Some(path.to_string()));
+        });
+        match &result {
+            Ok(_) => reporter.finish(None),
+            Err(e) => reporter.fail(e),
+        }
+        result
+    })
+    .await
+    .map_err(|e| format!("证书扫描任务失败: {}", e))?
+}
+
+fn scan_directory<F: FnMut(u64, u64, &str)>(
+    request: &CertificateScanRequest,
+    mut on_progress: F,
+) -> Result<CertificateScanReport, String> {
+    let root = PathBuf::from(request.directory.trim());
+    if !root.is_dir() {
+        return Err(format!("目录不存在: {}", root.display()));
+    }
+    let expiring_days = request.expiring_days.unwrap_or(30);
+    let (files, truncated) = collect_files(&root, request.recursive.unwrap_or(true));
+
+    let mut certificates = Vec::new();
+    let mut failures = Vec::new();
+    let mut first_seen: HashMap<String, String> = HashMap::new();
+    for (index, path) in files.iter().enumerate() {
+        let display = path.to_string_lossy().to_string();
+        on_progress(index as u64 + 1, files.len() as u64, &display);
+
+        let (format, ders) = match read_certificates(path, request.pfx_password.as_deref()) {
+            Ok(Some(found)) => found,
+            Ok(None) => continue,
+            Err(error) => {
+                failures.push(CertificateScanFailure {
+                    path: display,
+                    error,
+                });
+                continue;
+            }
+        };
+        for (position, der) in ders.iter().enumerate() {
+            match cached_parse(der, true) {
+                Ok(info) => {
+                    let mut entry = scanned(&display, format, position, &info, expiring_days);
+                    match first_seen.get(&entry.sha256_fingerprint) {
+                        Some(first) => entry.duplicate_of = Some(first.clone()),
+                        None => {
+                            first_seen.insert(entry.sha256_fingerprint.clone(), display.clone());
+                        }
+                    }
+                    certificates.push(entry);
+                }
+                Err(e) => failures.push(CertificateScanFailure {
+                    path: display.clone(),
+                    error: format!("第{}个证书解析失败: {}", position + 1, e),
+                }),
+            }
+        }
+    }
+    certificates.sort_by_key(|c| c.days_until_expiry);
+
+    let count = |status: &str| certificates.iter().filter(|c| c.status == status).count();
+    Ok(CertificateScanReport {
+        directory: root.to_string_lossy().to_string(),
+        files_scanned: files.len(),
+        expired_count: count("expired"),
+        expiring_count: count("expiring"),
+        weak_count: count("weak"),
+        duplicate_count: certificates
+            .iter()
+            .filter(|c| c.duplicate_of.is_some())
+            .count(),
+        certificates,
+        failures,
+        truncated,
+    })
+}
+
+/// 按扩展名收集证书文件，不跟随符号链接
+fn collect_files(root: &Path, recursive: bool) -> (Vec<PathBuf>, bool) {
+    let mut files = Vec::new();
+    let mut dirs = vec![root.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        let mut entries: Vec<_> = entries.flatten().collect();
+        entries.sort_by_key(|entry| entry.file_name());
+        for entry in entries {
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            let path = entry.path();
+            let name = entry.file_name().to_string_lossy().to_lowercase();
+            if file_type.is_dir() {
+                if recursive && !SKIPPED_DIRS.contains(&name.as_str()) {
+                    dirs.push(path);
+                }
+            } else if file_type.is_file() && is_certificate_file(&name) {
+                if files.len() >= MAX_FILES {
+                    return (files, true);
+                }
+                files.push(path);
+            }
+        }
+    }
+    (files, false)
+}
+
+fn is_certificate_file(name: &str) -> bool {
+    CERT_EXTENSIONS
+        .iter()
+        .any(|ext| name.ends_with(&format!(".{}", ext)))
+}
+
+/// 文件格式及其中全部证书的 DER
+type FoundCertificates = (&'static str, Vec<Vec<u8>>);
+
+/// 读取文件中的全部证书，文件中没有证书时返回 None
+fn read_certificates(
+    path: &Path,
+    pfx_password: Option<&str>,
+) -> Result<Option<FoundCertificates>, String> {
+    let size = fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+    if size == 0 || size > MAX_FILE_SIZE {
+        return Ok(None);
+    }
+    let data = fs::read(path).map_err(|e| format!("读取文件失败: {}", e))?;
+    let name = path.to_string_lossy().to_lowercase();
+
+    if name.ends_with(".pfx") || name.ends_with(".p12") {
+        return read_pfx(&data, pfx_password.unwrap_or("")).map(|ders| Some(("PFX", ders)));
+    }
+    if data.windows(10).any(|w| w == b"-----BEGIN") {
+        let ders: Vec<Vec<u8>> = Pem::iter_from_buffer(&data)
+            .filter_map(Result::ok)
+            .filter(|pem| pem.label == "CERTIFICATE")
+            .map(|pem| pem.contents)
+            .collect();
+        // 私钥、CSR 等其他 PEM 文件不计入
+        return Ok((!ders.is_empty()).then_some(("PEM", ders)));
+    }
+    // DER 证书以 SEQUENCE 开头
+    if data.first() == Some(&0x30) {
+        return Ok(Some(("DER", vec![data])));
+    }
+    Ok(None)
+}
+
+fn read_pfx(data: &[u8], password: &str) -> Result<Vec<Vec<u8>>, String> {
+    let pkcs12 =
+        openssl::pkcs12::Pkcs12::from_der(data).map_err(|e| format!("无效的PFX文件格式: {}", e))?;
+    let parsed = pkcs12
+        .parse2(password)
+        .map_err(|_| "PFX文件解析失败，可能需要密码".to_string())?;
+    let mut ders = Vec::new();
+    for cert in parsed
+        .cert
+        .into_iter()
+        .chain(parsed.ca.into_iter().flatten())
+    {
+        ders.push(cert.to_der().map_err(|e| format!("证书转换失败: {}", e))?);
+    }
+    Ok(ders)
+}
+
+fn scanned(
+    path: &str,
+    format: &str,
+    index: usize,
+    info: &CertificateInfo,
+    expiring_days: i64,
+) -> ScannedCertificate {
+    let mut issues = Vec::new();
+    let days = info.validity.days_until_expiry;
+    if days < 0 {
+        issues.push(format!("已过期 {} 天", -days));
+    } else if days < expiring_days {
+        issues.push(format!("{} 天后过期", days));
+    }
+    let weak = weakness(info);
+    let status = if days < 0 {
+        "expired"
+    } else if days < expiring_days {
+        "expiring"
+    } else if !weak.is_empty() {
+        "weak"
+    } else {
+        "ok"
+    };
+    issues.extend(weak);
+
+    ScannedCertificate {
+        path: path.to_string(),
+        format: format.to_string(),
+        index,
+        subject: display_name(&info.subject),
+        issuer: display_name(&info.issuer),
+        not_after: info.validity.not_after.clone(),
+        days_until_expiry: days,
+        key_type: info.public_key_info.key_type.clone(),
+        key_size: info.public_key_info.key_size,
+        signature_algorithm: info.signature_algorithm.clone(),
+        sha256_fingerprint: info.sha256_fingerprint.clone().unwrap_or_default(),
+        status: status.to_string(),
+        issues,
+        duplicate_of: None,
+    }
+}
+
+/// 密钥长度和签名算法的弱点
+fn weakness(info: &CertificateInfo) -> Vec<String> {
+    let mut issues = Vec::new();
+    if let Some(size) = info.public_key_info.key_size {
+        let minimum = match info.public_key_info.key_type.as_str() {
+            "RSA" | "DSA" => 2048,
+            "ECC" => 256,
+            _ => 0,
+        };
+        if size < minimum {
+            issues.push(format!(
+                "{} 密钥长度过短: {} 位",
+                info.public_key_info.key_type, size
+            ));
+        }
+    }
+    let algorithm = info.signature_algorithm.to_lowercase();
+    if algorithm.contains("md5") || algorithm.contains("sha1") || algorithm.contains("sha-1") {
+        issues.push(format!(
+            "使用已弃用的签名算法: {}",
+            info.signature_algorithm
+        ));
+    }
+    issues
+}
+
+fn display_name(name: &HashMap<String, String>) -> String {
+    name.get("通用名称 (CN)")
+        .or_else(|| name.get("组织名称 (O)"))
+        .cloned()
+        .unwrap_or_else(|| {
+            let mut values: Vec<&String> = name.values().collect();
+            values.sort();
+            values.into_iter().cloned().collect::<Vec<_>>().join(", ")
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::key_generator::generate_self_signed_certificate;
+
+    #[test]
+    fn test_scan_directory_inventory() {
+        let dir = std::env::temp_dir().join(format!("devtools-cert-scan-{}", std::process::id()));
+        let nested = dir.join("nginx").join("ssl");
+        fs::create_dir_all(&nested).unwrap();
+        fs::create_dir_all(dir.join("node_modules")).unwrap();
+
+        let (short, key) =
+            generate_self_signed_certificate("short.example", &["short.example".to_string()], 5)
+                .unwrap();
+        let (long, _) =
+            generate_self_signed_certificate("long.example", &["long.example".to_string()], 365)
+                .unwrap();
+        fs::write(dir.join("short.pem"), &short).unwrap();
+        fs::write(nested.join("bundle.crt"), format!("{}\n{}", long, short)).unwrap();
+        fs::write(nested.join("server.key.pem"), &key).unwrap();
+        fs::write(dir.join("broken.pfx"), b"not a pfx").unwrap();
+        fs::write(dir.join("node_modules").join("skip.pem"), &long).unwrap();
+        fs::write(dir.join("readme.txt"), &long).unwrap();
+
+        let request = CertificateScanRequest {
+            directory: dir.to_string_lossy().to_string(),
+            recursive: None,
+            expiring_days: None,
+            pfx_password: None,
+        };
+        let mut calls = 0;
+        let report = scan_directory(&request, |_, _, _| calls += 1).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(report.files_scanned, 4);
+        assert_eq!(calls, 4);
+        assert_eq!(report.certificates.len(), 3);
+        assert_eq!(report.failures.len(), 1);
+        assert!(report.failures[0].path.ends_with("broken.pfx"));
+        assert_eq!(report.expiring_count, 2);
+        assert_eq!(report.duplicate_count, 1);
+        // 按到期时间升序，即将过期的排在前面
+        assert_eq!(report.certificates[0].subject, "short.example");
+        assert_eq!(report.certificates[2].subject, "long.example");
+        assert_eq!(report.certificates[2].status, "ok");
+        assert!(report
+            .certificates
+            .iter()
+            .any(|c| c.path.ends_with("bundle.crt")
+                && c.duplicate_of
+                    .as_deref()
+                    .is_some_and(|p| p.ends_with("short.pem"))));
+    }
+
+    #[test]
+    fn test_weakness() {
+        let (pem, _) =
+            generate_self_signed_certificate("weak.example", &["weak.example".to_string()], 30)
+                .unwrap();
+        let ders: Vec<Vec<u8>> = Pem::iter_from_buffer(pem.as_bytes())
+            .map(|pem| pem.unwrap().contents)
+            .collect();
+        let mut info = cached_parse(&ders[0], true).unwrap();
+        assert!(weakness(&info).is_empty());
+
+        info.public_key_info.key_type = "RSA".to_string();
+        info.public_key_info.key_size = Some(1024);
+        info.signature_algorithm = "sha1WithRSAEncryption".to_string();
+        let entry = scanned("a.pem", "PEM", 0, &info, 7);
+        assert_eq!(entry.status, "weak");
+        assert_eq!(entry.issues.len(), 2);
+        assert!(entry.issues[0].contains("1024"));
+    }
+}
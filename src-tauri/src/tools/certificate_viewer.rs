@@ -1,6 +1,9 @@
+use crate::utils::cert_path;
 use ::time::OffsetDateTime;
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
 use x509_parser::certificate::X509Certificate;
 use x509_parser::extensions::ParsedExtension;
 use x509_parser::oid_registry::Oid;
@@ -22,6 +25,24 @@ pub struct CertificateInfo {
     pub brand: Option<String>,
     pub sha1_fingerprint: Option<String>,
     pub sha256_fingerprint: Option<String>,
+    #[serde(default = "default_true")]
+    pub details_loaded: bool, // 快速模式下为 false，需通过 get_certificate_details 加载扩展信息
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// 解析结果缓存上限，超出后整体清空
+const CERT_CACHE_LIMIT: usize = 4096;
+
+/// 按 DER 的 SHA-256 缓存解析结果，避免重复解析大型证书包
+static CERT_CACHE: Mutex<BTreeMap<String, CachedCertificate>> = Mutex::new(BTreeMap::new());
+
+struct CachedCertificate {
+    der: Vec<u8>,
+    summary: Option<CertificateInfo>,
+    full: Option<CertificateInfo>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -127,6 +148,7 @@ pub fn parse_pfx_certificate(
         .map_err(|e| format!("PFX文件解析失败: {}。可能原因：密码错误或PFX文件损坏", e))?;
 
     let mut certificates = Vec::new();
+    let mut ders = Vec::new();
 
     // 处理主证书
     if let Some(cert) = parsed.cert {
@@ -137,6 +159,7 @@ pub fn parse_pfx_certificate(
 
         let cert_info = parse_certificate(&x509_cert)?;
         certificates.push(cert_info);
+        ders.push(cert_der);
     }
 
     // 处理CA证书链
@@ -151,6 +174,7 @@ pub fn parse_pfx_certificate(
 
             let cert_info = parse_certificate(&x509_cert)?;
             certificates.push(cert_info);
+            ders.push(cert_der);
         }
     }
 
@@ -162,12 +186,15 @@ pub fn parse_pfx_certificate(
     let certificates = deduplicate_certificates(&certificates);
 
     // 分析证书链
-    let chain_analysis = analyze_certificate_chain(&certificates);
+    let chain_analysis = analyze_certificate_chain(&certificates, &ders);
     Ok(chain_analysis)
 }
 
 #[tauri::command]
-pub fn parse_pem_certificate(pem_content: String) -> Result<CertificateChainInfo, String> {
+pub fn parse_pem_certificate(
+    pem_content: String,
+    skip_extensions: Option<bool>,
+) -> Result<CertificateChainInfo, String> {
     // 清理PEM内容
     let cleaned_content = pem_content
         .replace("\r\n", "\n")
@@ -208,7 +235,6 @@ pub fn parse_pem_certificate(pem_content: String) -> Result<CertificateChainInfo
     }
 
     // 使用x509-parser解析多个证书
-    let mut certificates = Vec::new();
     let mut pem_contents = Vec::new();
 
     for (_index, pem_result) in Pem::iter_from_buffer(cleaned_content.as_bytes()).enumerate() {
@@ -228,25 +254,107 @@ pub fn parse_pem_certificate(pem_content: String) -> Result<CertificateChainInfo
         return Err("未找到有效的证书".to_string());
     }
 
-    // 解析证书并构建信息结构
-    for (index, contents) in pem_contents.iter().enumerate() {
-        let (_, cert) = X509Certificate::from_der(contents)
-            .map_err(|e| format!("第{}个证书解析失败: {}", index + 1, e))?;
-
-        let cert_info = parse_certificate(&cert)?;
-        certificates.push(cert_info);
-    }
+    // 并行解析证书，已解析过的证书直接取缓存
+    let summary_only = skip_extensions.unwrap_or(false);
+    let certificates: Vec<CertificateInfo> = pem_contents
+        .par_iter()
+        .enumerate()
+        .map(|(index, contents)| {
+            cached_parse(contents, summary_only)
+                .map_err(|e| format!("第{}个证书解析失败: {}", index + 1, e))
+        })
+        .collect::<Result<_, _>>()?;
 
     // 去除重复的证书（PEM文件中也可能包含重复证书）
     let certificates = deduplicate_certificates(&certificates);
 
     // 分析证书链
-    let chain_analysis = analyze_certificate_chain(&certificates);
+    let chain_analysis = analyze_certificate_chain(&certificates, &pem_contents);
 
     Ok(chain_analysis)
 }
 
-fn parse_certificate(cert: &X509Certificate) -> Result<CertificateInfo, String> {
+/// 加载快速模式下省略的证书详情（扩展信息等）
+#[tauri::command]
+pub fn get_certificate_details(sha256_fingerprint: String) -> Result<CertificateInfo, String> {
+    let fingerprint = sha256_fingerprint.replace(':', "").to_uppercase();
+    let der = {
+        let cache = CERT_CACHE.lock().map_err(|e| e.to_string())?;
+        let entry =
+            cache
+                .values()
+                .find(|entry| {
+                    entry.summary.iter().chain(entry.full.iter()).any(|info| {
+                        info.sha256_fingerprint.as_deref() == Some(fingerprint.as_str())
+                    })
+                })
+                .ok_or_else(|| "证书缓存已失效，请重新解析".to_string())?;
+        if let Some(full) = &entry.full {
+            return Ok(refresh_expiry(full.clone()));
+        }
+        entry.der.clone()
+    };
+    cached_parse(&der, false)
+}
+
+/// 解析单个 DER 证书，结果按 SHA-256 缓存
+pub(crate) fn cached_parse(der: &[u8], summary_only: bool) -> Result<CertificateInfo, String> {
+    let key = crate::utils::crypto::CryptoUtils::calculate_sha256_fingerprint(der);
+    if let Ok(cache) = CERT_CACHE.lock() {
+        if let Some(entry) = cache.get(&key) {
+            // 完整结果也可用于快速模式
+            let hit = if summary_only {
+                entry.full.as_ref().or(entry.summary.as_ref())
+            } else {
+                entry.full.as_ref()
+            };
+            if let Some(info) = hit {
+                return Ok(refresh_expiry(info.clone()));
+            }
+        }
+    }
+
+    let (_, cert) = X509Certificate::from_der(der).map_err(|e| e.to_string())?;
+    let info = parse_certificate_with(&cert, !summary_only)?;
+
+    if let Ok(mut cache) = CERT_CACHE.lock() {
+        if cache.len() >= CERT_CACHE_LIMIT && !cache.contains_key(&key) {
+            cache.clear();
+        }
+        let entry = cache.entry(key).or_insert_with(|| CachedCertificate {
+            der: der.to_vec(),
+            summary: None,
+            full: None,
+        });
+        if summary_only {
+            entry.summary = Some(info.clone());
+        } else {
+            entry.full = Some(info.clone());
+        }
+    }
+    Ok(info)
+}
+
+/// 缓存中的剩余天数需要按当前时间重新计算
+fn refresh_expiry(mut info: CertificateInfo) -> CertificateInfo {
+    if let Ok(not_after) = OffsetDateTime::parse(
+        &info.validity.not_after,
+        &::time::format_description::well_known::Rfc3339,
+    ) {
+        info.validity.days_until_expiry = (not_after - OffsetDateTime::now_utc()).whole_days();
+    }
+    info
+}
+
+pub(crate) fn parse_certificate(cert: &X509Certificate) -> Result<CertificateInfo, String> {
+    parse_certificate_with(cert, true)
+}
+
+/// include_extensions 为 false 时跳过扩展信息解析，用于证书包的快速列表
+fn parse_certificate_with(
+    cert: &X509Certificate,
+    include_extensions: bool,
+) -> Result<CertificateInfo, String> {
     // 解析主题
     let subject = parse_name(&cert.subject)?;
 
@@ -301,7 +409,11 @@ fn parse_certificate(cert: &X509Certificate) -> Result<CertificateInfo, String>
     let sans = extract_sans(cert)?;
 
     // 扩展信息
-    let extensions = parse_extensions(cert);
+    let extensions = if include_extensions {
+        parse_extensions(cert)
+    } else {
+        Vec::new()
+    };
 
     // 确定证书链级别
     let chain_level = determine_chain_level(cert);
@@ -328,6 +440,7 @@ fn parse_certificate(cert: &X509Certificate) -> Result<CertificateInfo, String>
         brand,
         sha1_fingerprint,
         sha256_fingerprint,
+        details_loaded: include_extensions,
     })
 }
 
@@ -579,46 +692,23 @@ fn determine_chain_level(cert: &X509Certificate) -> usize {
     }
 }
 
+/// 主体与颁发者一致且签名可由自身公钥验证
 fn is_self_signed(cert: &X509Certificate) -> bool {
-    let subject = cert.subject();
-    let issuer = cert.issuer();
-
-    let subject_cn = subject
-        .iter_common_name()
-        .next()
-        .and_then(|cn| cn.as_str().ok())
-        .unwrap_or("");
-
-    let issuer_cn = issuer
-        .iter_common_name()
-        .next()
-        .and_then(|cn| cn.as_str().ok())
-        .unwrap_or("");
-
-    subject_cn == issuer_cn && !subject_cn.is_empty()
+    cert_path::is_self_signed(cert.as_ref())
 }
 
 fn fallback_determine_chain_level(cert: &X509Certificate) -> usize {
     // 原有的判断逻辑作为回退
-    let subject = cert.subject();
     let issuer = cert.issuer();
 
-    let subject_cn = subject
-        .iter_common_name()
-        .next()
-        .and_then(|cn| cn.as_str().ok())
-        .unwrap_or("");
-
     let issuer_cn = issuer
         .iter_common_name()
         .next()
         .and_then(|cn| cn.as_str().ok())
         .unwrap_or("");
 
-    let is_self_signed = subject_cn == issuer_cn && !subject_cn.is_empty();
-
     // 检查基本约束（这里简化处理）
-    if is_self_signed {
+    if is_self_signed(cert) {
         2 // 可能是根CA
     } else {
         // 简单的启发式判断
@@ -761,17 +851,26 @@ fn determine_certificate_brand(cert: &X509Certificate) -> Option<String> {
     Some(brand.to_string())
 }
 
-fn analyze_certificate_chain(cert_infos: &[CertificateInfo]) -> CertificateChainInfo {
+/// 证书在排序后证书链中经签名验证的签发关系
+struct ChainLink {
+    issuer: Option<usize>, // 颁发者在排序后证书链中的下标
+    self_signed: bool,
+}
+
+fn analyze_certificate_chain(
+    cert_infos: &[CertificateInfo],
+    ders: &[Vec<u8>],
+) -> CertificateChainInfo {
     let mut is_full_chain = false;
     let mut ca_download_urls = Vec::new();
     let mut missing_ca_info = None;
     let chain_status;
 
-    // 改进的证书链排序逻辑
-    let certificates = build_certificate_chain(cert_infos);
+    // 按签名验证的签发关系排序
+    let (certificates, links) = build_certificate_chain(cert_infos, ders);
 
     // 识别缺失的CA证书
-    let missing_certificates = identify_missing_certificates(&certificates);
+    let missing_certificates = identify_missing_certificates(&certificates, &links);
 
     if certificates.len() == 1 {
         // 只有一个证书，检查是否为自签名根证书
@@ -803,16 +902,16 @@ fn analyze_certificate_chain(cert_infos: &[CertificateInfo]) -> CertificateChain
                 } else {
                     // 终端 + 根，但可能缺少中间CA
                     // 需要检查终端证书的颁发者是否直接是根证书
-                    let end_cert = certificates
+                    let end_index = certificates
                         .iter()
-                        .find(|cert| cert.chain_level == 0)
+                        .position(|cert| cert.chain_level == 0)
                         .unwrap();
-                    let root_cert = certificates
+                    let root_index = certificates
                         .iter()
-                        .find(|cert| cert.chain_level == 2)
+                        .position(|cert| cert.chain_level == 2)
                         .unwrap();
 
-                    if certificates_match_issuer_subject(&root_cert.subject, &end_cert.issuer) {
+                    if links[end_index].issuer == Some(root_index) {
                         is_full_chain = true;
                         chain_status = "完整证书链：终端证书由根CA直接颁发".to_string();
                     } else {
@@ -860,29 +959,55 @@ fn analyze_certificate_chain(cert_infos: &[CertificateInfo]) -> CertificateChain
     }
 }
 
-/// 构建正确排序的证书链
-/// 处理缺少根CA但有多个中间CA的情况，确保证书按正确的颁发顺序排列
-fn build_certificate_chain(cert_infos: &[CertificateInfo]) -> Vec<CertificateInfo> {
-    let mut certificates = cert_infos.to_vec();
-
-    // 如果只有一个证书，直接返回
-    if certificates.len() <= 1 {
-        return certificates;
-    }
-
-    // 尝试按照颁发关系进行排序
-    let ordered = order_certificates_by_chain(&certificates);
-    if !ordered.is_empty() {
-        return ordered;
-    }
-
-    // 回退到按级别排序
-    certificates.sort_by(|a, b| b.chain_level.cmp(&a.chain_level));
-    certificates
+/// 按 AKI/SKI 和签名验证构建证书链，从根CA开始，到终端证书结束
+/// 无法接入证书链的证书排在最后
+fn build_certificate_chain(
+    cert_infos: &[CertificateInfo],
+    ders: &[Vec<u8>],
+) -> (Vec<CertificateInfo>, Vec<ChainLink>) {
+    // 去重后的证书按指纹对应回 DER
+    let fingerprints: Vec<String> = ders
+        .iter()
+        .map(|der| crate::utils::crypto::CryptoUtils::calculate_sha256_fingerprint(der))
+        .collect();
+    let aligned: Vec<Vec<u8>> = cert_infos
+        .iter()
+        .map(|info| {
+            fingerprints
+                .iter()
+                .position(|fp| info.sha256_fingerprint.as_deref() == Some(fp.as_str()))
+                .map(|index| ders[index].clone())
+                .unwrap_or_default()
+        })
+        .collect();
+
+    let path = cert_path::build_path(&aligned, None, &|_| false);
+    let order: Vec<usize> = path
+        .order
+        .iter()
+        .rev()
+        .chain(path.unlinked.iter())
+        .copied()
+        .collect();
+    let links = order
+        .iter()
+        .map(|&index| ChainLink {
+            issuer: path.issuers[index].and_then(|issuer| order.iter().position(|i| *i == issuer)),
+            self_signed: path.self_signed[index],
+        })
+        .collect();
+    let certificates = order
+        .iter()
+        .map(|&index| cert_infos[index].clone())
+        .collect();
+    (certificates, links)
 }
 
 /// 识别缺失的CA证书
-fn identify_missing_certificates(certificates: &[CertificateInfo]) -> Vec<MissingCertificateInfo> {
+fn identify_missing_certificates(
+    certificates: &[CertificateInfo],
+    links: &[ChainLink],
+) -> Vec<MissingCertificateInfo> {
     let mut missing = Vec::new();
 
     if certificates.is_empty() {
@@ -963,18 +1088,14 @@ fn identify_missing_certificates(certificates: &[CertificateInfo]) -> Vec<Missin
     }
 
     // 检查证书链中是否有断裂（即某个证书的颁发者不在链中）
-    for cert in certificates {
+    for (cert, link) in certificates.iter().zip(links) {
         if cert.chain_level == 0 || cert.chain_level == 1 {
             let empty_string = String::new();
             let issuer_cn = cert.issuer.get("通用名称 (CN)").unwrap_or(&empty_string);
             let issuer_o = cert.issuer.get("组织名称 (O)").unwrap_or(&empty_string);
 
-            // 检查是否存在对应的颁发者证书
-            let has_issuer = certificates.iter().any(|other_cert| {
-                certificates_match_issuer_subject(&other_cert.subject, &cert.issuer)
-            });
-
-            if !has_issuer && !is_cert_self_signed(cert) {
+            // 检查是否存在经签名验证的颁发者证书
+            if link.issuer.is_none() && !link.self_signed {
                 let issuer_name = if !issuer_cn.is_empty() {
                     issuer_cn.clone()
                 } else if !issuer_o.is_empty() {
@@ -1005,124 +1126,6 @@ fn identify_missing_certificates(certificates: &[CertificateInfo]) -> Vec<Missin
     missing
 }
 
-/// 按照证书链的颁发关系进行排序
-/// 从根CA开始，到终端证书结束
-fn order_certificates_by_chain(certificates: &[CertificateInfo]) -> Vec<CertificateInfo> {
-    let mut ordered = Vec::new();
-    let mut remaining: Vec<_> = certificates.iter().collect();
-
-    // 首先找到根证书（自签名的证书）
-    if let Some((index, root)) = remaining
-        .iter()
-        .enumerate()
-        .find(|(_, cert)| is_cert_self_signed(cert))
-    {
-        ordered.push((*root).clone());
-        remaining.remove(index);
-    }
-
-    // 如果没有找到根证书，从链级别最高的证书开始
-    if ordered.is_empty() {
-        if let Some((index, highest)) = remaining
-            .iter()
-            .enumerate()
-            .max_by_key(|(_, cert)| cert.chain_level)
-        {
-            ordered.push((*highest).clone());
-            remaining.remove(index);
-        }
-    }
-
-    // 逐步找到被当前证书颁发的下一级证书
-    while !remaining.is_empty() && !ordered.is_empty() {
-        let current_issuer = &ordered.last().unwrap().subject;
-
-        // 寻找由当前证书颁发的证书（即subject匹配current的issuer）
-        let next_index = remaining
-            .iter()
-            .position(|cert| certificates_match_issuer_subject(current_issuer, &cert.issuer));
-
-        if let Some(index) = next_index {
-            let next_cert = remaining.remove(index);
-            ordered.push(next_cert.clone());
-        } else {
-            // 如果找不到匹配的，按链级别选择最低的证书
-            if let Some((index, lowest)) = remaining
-                .iter()
-                .enumerate()
-                .min_by_key(|(_, cert)| cert.chain_level)
-            {
-                ordered.push((*lowest).clone());
-                remaining.remove(index);
-            } else {
-                break;
-            }
-        }
-    }
-
-    // 如果还有剩余证书，按链级别添加
-    while !remaining.is_empty() {
-        if let Some((index, cert)) = remaining
-            .iter()
-            .enumerate()
-            .min_by_key(|(_, cert)| cert.chain_level)
-        {
-            ordered.push((*cert).clone());
-            remaining.remove(index);
-        }
-    }
-
-    ordered
-}
-
-/// 检查证书是否为自签名证书
-fn is_cert_self_signed(cert: &CertificateInfo) -> bool {
-    let empty_string = String::new();
-    let subject_cn = cert.subject.get("通用名称 (CN)").unwrap_or(&empty_string);
-    let issuer_cn = cert.issuer.get("通用名称 (CN)").unwrap_or(&empty_string);
-
-    let subject_o = cert.subject.get("组织名称 (O)").unwrap_or(&empty_string);
-    let issuer_o = cert.issuer.get("组织名称 (O)").unwrap_or(&empty_string);
-
-    // 比较CN和O字段
-    (!subject_cn.is_empty() && subject_cn == issuer_cn)
-        || (!subject_o.is_empty() && subject_o == issuer_o)
-}
-
-/// 检查两个证书的颁发关系（证书A的subject是否与证书B的issuer匹配）
-fn certificates_match_issuer_subject(
-    subject: &std::collections::HashMap<String, String>,
-    issuer: &std::collections::HashMap<String, String>,
-) -> bool {
-    let empty_string = String::new();
-
-    // 比较CN字段
-    let subject_cn = subject.get("通用名称 (CN)").unwrap_or(&empty_string);
-    let issuer_cn = issuer.get("通用名称 (CN)").unwrap_or(&empty_string);
-
-    if !subject_cn.is_empty() && !issuer_cn.is_empty() && subject_cn == issuer_cn {
-        return true;
-    }
-
-    // 比较O字段
-    let subject_o = subject.get("组织名称 (O)").unwrap_or(&empty_string);
-    let issuer_o = issuer.get("组织名称 (O)").unwrap_or(&empty_string);
-
-    if !subject_o.is_empty() && !issuer_o.is_empty() && subject_o == issuer_o {
-        return true;
-    }
-
-    // 比较OU字段
-    let subject_ou = subject.get("组织单位 (OU)").unwrap_or(&empty_string);
-    let issuer_ou = issuer.get("组织单位 (OU)").unwrap_or(&empty_string);
-
-    if !subject_ou.is_empty() && !issuer_ou.is_empty() && subject_ou == issuer_ou {
-        return true;
-    }
-
-    false
-}
-
 fn suggest_ca_download_urls(cert: &CertificateInfo) -> Vec<String> {
     let mut urls = Vec::new();
 
@@ -1239,3 +1242,42 @@ fn suggest_ca_download_urls(cert: &CertificateInfo) -> Vec<String> {
 
     urls
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::key_generator::generate_self_signed_certificate;
+
+    #[test]
+    fn test_parse_pem_bundle_summary_and_details() {
+        let (first, _) =
+            generate_self_signed_certificate("a.example.com", &["a.example.com".to_string()], 30)
+                .unwrap();
+        let (second, _) =
+            generate_self_signed_certificate("b.example.com", &["b.example.com".to_string()], 30)
+                .unwrap();
+        let bundle = format!("{}\n{}", first, second);
+
+        let summary = parse_pem_certificate(bundle.clone(), Some(true)).unwrap();
+        assert_eq!(summary.certificates.len(), 2);
+        assert!(summary
+            .certificates
+            .iter()
+            .all(|c| !c.details_loaded && c.extensions.is_empty()));
+
+        let fingerprint = summary.certificates[0].sha256_fingerprint.clone().unwrap();
+        let details = get_certificate_details(fingerprint.clone()).unwrap();
+        assert!(details.details_loaded);
+        assert!(!details.extensions.is_empty());
+        assert_eq!(details.sha256_fingerprint, Some(fingerprint));
+
+        // 完整解析后快速模式直接复用缓存中的完整结果
+        let again = parse_pem_certificate(bundle, Some(true)).unwrap();
+        assert!(again.certificates.iter().any(|c| c.details_loaded));
+    }
+
+    #[test]
+    fn test_get_certificate_details_requires_cache() {
+        assert!(get_certificate_details("00".repeat(32)).is_err());
+    }
+}
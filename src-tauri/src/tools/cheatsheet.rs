@@ -0,0 +1,1306 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReferenceField {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReferenceEntry {
+    pub category: String,
+    pub key: String,
+    pub title: String,
+    pub description: String,
+    pub fields: Vec<ReferenceField>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReferenceCategory {
+    pub id: String,
+    pub name: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HttpStatusInfo {
+    pub code: u16,
+    pub phrase: String,
+    pub category: String,
+    pub rfc: Option<String>, // 未注册的状态码为 None
+    pub description: String,
+    pub common_causes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MimeMapping {
+    pub extension: String,
+    pub mime_type: String,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MimeLookupResult {
+    pub query: String,
+    pub direction: String, // extension_to_mime, mime_to_extension
+    pub mappings: Vec<MimeMapping>,
+}
+
+/// (分类 ID, 显示名称)
+const CATEGORIES: &[(&str, &str)] = &[
+    ("http_status", "HTTP 状态码"),
+    ("mime", "MIME 类型"),
+    ("port", "常用端口"),
+    ("regex", "正则语法"),
+    ("cron", "Cron 语法"),
+    ("tls_cipher", "TLS 密码套件"),
+];
+
+/// (状态码, 原因短语, RFC, 说明)
+const HTTP_STATUSES: &[(u16, &str, &str, &str)] = &[
+    (100, "Continue", "RFC 9110", "客户端应继续发送请求体"),
+    (
+        101,
+        "Switching Protocols",
+        "RFC 9110",
+        "服务器同意切换协议，如升级到 WebSocket",
+    ),
+    (
+        102,
+        "Processing",
+        "RFC 2518",
+        "WebDAV：服务器已接收请求，仍在处理",
+    ),
+    (
+        103,
+        "Early Hints",
+        "RFC 8297",
+        "在最终响应前提前返回 Link 头以便预加载",
+    ),
+    (200, "OK", "RFC 9110", "请求成功"),
+    (201, "Created", "RFC 9110", "请求成功并创建了新资源"),
+    (202, "Accepted", "RFC 9110", "请求已接受，但尚未处理完成"),
+    (
+        203,
+        "Non-Authoritative Information",
+        "RFC 9110",
+        "响应内容经过代理修改",
+    ),
+    (204, "No Content", "RFC 9110", "请求成功，无响应体"),
+    (
+        205,
+        "Reset Content",
+        "RFC 9110",
+        "请求成功，客户端应重置文档视图",
+    ),
+    (
+        206,
+        "Partial Content",
+        "RFC 9110",
+        "返回 Range 请求的部分内容",
+    ),
+    (
+        207,
+        "Multi-Status",
+        "RFC 4918",
+        "WebDAV：包含多个独立操作的状态",
+    ),
+    (
+        208,
+        "Already Reported",
+        "RFC 5842",
+        "WebDAV：成员已在之前的响应中列出",
+    ),
+    (226, "IM Used", "RFC 3229", "服务器已对资源应用实例操作"),
+    (300, "Multiple Choices", "RFC 9110", "资源有多个可选表示"),
+    (
+        301,
+        "Moved Permanently",
+        "RFC 9110",
+        "资源已永久迁移，可能把 POST 改为 GET",
+    ),
+    (302, "Found", "RFC 9110", "资源临时位于其他 URI"),
+    (
+        303,
+        "See Other",
+        "RFC 9110",
+        "应使用 GET 获取另一个 URI 的结果",
+    ),
+    (
+        304,
+        "Not Modified",
+        "RFC 9110",
+        "条件请求命中缓存，资源未修改",
+    ),
+    (305, "Use Proxy", "RFC 9110", "已弃用：必须通过代理访问"),
+    (
+        307,
+        "Temporary Redirect",
+        "RFC 9110",
+        "临时重定向，保持请求方法和请求体",
+    ),
+    (
+        308,
+        "Permanent Redirect",
+        "RFC 9110",
+        "永久重定向，保持请求方法和请求体",
+    ),
+    (400, "Bad Request", "RFC 9110", "请求语法或参数错误"),
+    (401, "Unauthorized", "RFC 9110", "缺少或无效的认证凭据"),
+    (
+        402,
+        "Payment Required",
+        "RFC 9110",
+        "保留供将来使用，部分服务用于计费限制",
+    ),
+    (403, "Forbidden", "RFC 9110", "服务器理解请求但拒绝授权"),
+    (404, "Not Found", "RFC 9110", "资源不存在"),
+    (
+        405,
+        "Method Not Allowed",
+        "RFC 9110",
+        "资源不支持该请求方法",
+    ),
+    (
+        406,
+        "Not Acceptable",
+        "RFC 9110",
+        "无法提供 Accept 头要求的表示",
+    ),
+    (
+        407,
+        "Proxy Authentication Required",
+        "RFC 9110",
+        "需要代理认证",
+    ),
+    (408, "Request Timeout", "RFC 9110", "服务器等待请求超时"),
+    (409, "Conflict", "RFC 9110", "请求与资源当前状态冲突"),
+    (410, "Gone", "RFC 9110", "资源已永久删除"),
+    (
+        411,
+        "Length Required",
+        "RFC 9110",
+        "请求缺少 Content-Length",
+    ),
+    (
+        412,
+        "Precondition Failed",
+        "RFC 9110",
+        "条件请求头的前置条件不成立",
+    ),
+    (413, "Content Too Large", "RFC 9110", "请求体超过服务器限制"),
+    (414, "URI Too Long", "RFC 9110", "请求 URI 超过服务器限制"),
+    (
+        415,
+        "Unsupported Media Type",
+        "RFC 9110",
+        "不支持请求体的媒体类型",
+    ),
+    (
+        416,
+        "Range Not Satisfiable",
+        "RFC 9110",
+        "Range 超出资源范围",
+    ),
+    (
+        417,
+        "Expectation Failed",
+        "RFC 9110",
+        "无法满足 Expect 请求头",
+    ),
+    (418, "I'm a teapot", "RFC 2324", "愚人节协议，保留状态码"),
+    (
+        421,
+        "Misdirected Request",
+        "RFC 9110",
+        "请求被发送到无法生成响应的服务器",
+    ),
+    (
+        422,
+        "Unprocessable Content",
+        "RFC 9110",
+        "请求格式正确但语义错误",
+    ),
+    (423, "Locked", "RFC 4918", "WebDAV：资源被锁定"),
+    (
+        424,
+        "Failed Dependency",
+        "RFC 4918",
+        "WebDAV：依赖的操作失败",
+    ),
+    (
+        425,
+        "Too Early",
+        "RFC 8470",
+        "服务器不愿处理可能被重放的 0-RTT 请求",
+    ),
+    (426, "Upgrade Required", "RFC 9110", "客户端需要升级协议"),
+    (
+        428,
+        "Precondition Required",
+        "RFC 6585",
+        "服务器要求条件请求",
+    ),
+    (429, "Too Many Requests", "RFC 6585", "请求频率超出限制"),
+    (
+        431,
+        "Request Header Fields Too Large",
+        "RFC 6585",
+        "请求头过大",
+    ),
+    (
+        451,
+        "Unavailable For Legal Reasons",
+        "RFC 7725",
+        "因法律原因不可用",
+    ),
+    (500, "Internal Server Error", "RFC 9110", "服务器内部错误"),
+    (501, "Not Implemented", "RFC 9110", "服务器不支持该功能"),
+    (
+        502,
+        "Bad Gateway",
+        "RFC 9110",
+        "网关或代理从上游收到无效响应",
+    ),
+    (503, "Service Unavailable", "RFC 9110", "服务暂时不可用"),
+    (504, "Gateway Timeout", "RFC 9110", "网关或代理等待上游超时"),
+    (
+        505,
+        "HTTP Version Not Supported",
+        "RFC 9110",
+        "不支持请求使用的 HTTP 版本",
+    ),
+    (
+        506,
+        "Variant Also Negotiates",
+        "RFC 2295",
+        "内容协商配置错误",
+    ),
+    (
+        507,
+        "Insufficient Storage",
+        "RFC 4918",
+        "WebDAV：存储空间不足",
+    ),
+    (508, "Loop Detected", "RFC 5842", "WebDAV：检测到无限循环"),
+    (510, "Not Extended", "RFC 2774", "需要对请求进一步扩展"),
+    (
+        511,
+        "Network Authentication Required",
+        "RFC 6585",
+        "需要网络认证，常见于强制门户",
+    ),
+];
+
+/// (扩展名, MIME 类型, 说明)
+const MIME_TYPES: &[(&str, &str, &str)] = &[
+    ("html", "text/html", "HTML 文档"),
+    ("htm", "text/html", "HTML 文档"),
+    ("css", "text/css", "样式表"),
+    ("js", "text/javascript", "JavaScript"),
+    ("mjs", "text/javascript", "JavaScript 模块"),
+    ("json", "application/json", "JSON 数据"),
+    ("jsonld", "application/ld+json", "JSON-LD"),
+    ("map", "application/json", "Source Map"),
+    ("xml", "application/xml", "XML 文档"),
+    ("txt", "text/plain", "纯文本"),
+    ("csv", "text/csv", "逗号分隔值"),
+    ("tsv", "text/tab-separated-values", "制表符分隔值"),
+    ("md", "text/markdown", "Markdown"),
+    ("yaml", "application/yaml", "YAML"),
+    ("yml", "application/yaml", "YAML"),
+    ("toml", "application/toml", "TOML"),
+    ("ics", "text/calendar", "iCalendar"),
+    ("png", "image/png", "PNG 图片"),
+    ("jpg", "image/jpeg", "JPEG 图片"),
+    ("jpeg", "image/jpeg", "JPEG 图片"),
+    ("gif", "image/gif", "GIF 图片"),
+    ("webp", "image/webp", "WebP 图片"),
+    ("avif", "image/avif", "AVIF 图片"),
+    ("heic", "image/heic", "HEIC 图片"),
+    ("svg", "image/svg+xml", "SVG 矢量图"),
+    ("ico", "image/vnd.microsoft.icon", "图标"),
+    ("bmp", "image/bmp", "位图"),
+    ("tif", "image/tiff", "TIFF 图片"),
+    ("tiff", "image/tiff", "TIFF 图片"),
+    ("mp3", "audio/mpeg", "MP3 音频"),
+    ("wav", "audio/wav", "WAV 音频"),
+    ("ogg", "audio/ogg", "Ogg 音频"),
+    ("flac", "audio/flac", "FLAC 音频"),
+    ("aac", "audio/aac", "AAC 音频"),
+    ("m4a", "audio/mp4", "M4A 音频"),
+    ("mp4", "video/mp4", "MP4 视频"),
+    ("webm", "video/webm", "WebM 视频"),
+    ("mov", "video/quicktime", "QuickTime 视频"),
+    ("avi", "video/x-msvideo", "AVI 视频"),
+    ("mkv", "video/x-matroska", "Matroska 视频"),
+    ("m3u8", "application/vnd.apple.mpegurl", "HLS 播放列表"),
+    ("woff", "font/woff", "WOFF 字体"),
+    ("woff2", "font/woff2", "WOFF2 字体"),
+    ("ttf", "font/ttf", "TrueType 字体"),
+    ("otf", "font/otf", "OpenType 字体"),
+    ("pdf", "application/pdf", "PDF 文档"),
+    ("zip", "application/zip", "ZIP 压缩包"),
+    ("gz", "application/gzip", "Gzip 压缩"),
+    ("tar", "application/x-tar", "Tar 归档"),
+    ("7z", "application/x-7z-compressed", "7-Zip 压缩包"),
+    ("rar", "application/vnd.rar", "RAR 压缩包"),
+    ("bz2", "application/x-bzip2", "Bzip2 压缩"),
+    ("xz", "application/x-xz", "XZ 压缩"),
+    ("zst", "application/zstd", "Zstandard 压缩"),
+    ("wasm", "application/wasm", "WebAssembly"),
+    ("bin", "application/octet-stream", "二进制数据"),
+    (
+        "exe",
+        "application/vnd.microsoft.portable-executable",
+        "Windows 可执行文件",
+    ),
+    ("doc", "application/msword", "Word 文档"),
+    (
+        "docx",
+        "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        "Word 文档",
+    ),
+    ("xls", "application/vnd.ms-excel", "Excel 表格"),
+    (
+        "xlsx",
+        "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        "Excel 表格",
+    ),
+    (
+        "ppt",
+        "application/vnd.ms-powerpoint",
+        "PowerPoint 演示文稿",
+    ),
+    (
+        "pptx",
+        "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+        "PowerPoint 演示文稿",
+    ),
+    ("epub", "application/epub+zip", "电子书"),
+    ("pem", "application/x-pem-file", "PEM 证书或密钥"),
+    ("crt", "application/x-x509-ca-cert", "X.509 证书"),
+    ("der", "application/x-x509-ca-cert", "DER 编码证书"),
+    ("p12", "application/x-pkcs12", "PKCS#12 证书包"),
+    ("pfx", "application/x-pkcs12", "PKCS#12 证书包"),
+    ("proto", "text/plain", "Protocol Buffers 定义"),
+    ("sh", "application/x-sh", "Shell 脚本"),
+];
+
+/// (状态码, 常见原因)
+const HTTP_STATUS_CAUSES: &[(u16, &[&str])] = &[
+    (
+        301,
+        &[
+            "HTTP 跳转到 HTTPS",
+            "域名或路径迁移",
+            "目录 URL 缺少结尾斜杠",
+        ],
+    ),
+    (
+        302,
+        &[
+            "登录后跳转",
+            "未登录时重定向到登录页",
+            "负载均衡或 A/B 测试跳转",
+        ],
+    ),
+    (
+        304,
+        &["请求带有 If-None-Match / If-Modified-Since 且资源未变化"],
+    ),
+    (
+        400,
+        &[
+            "请求体 JSON 格式错误",
+            "缺少必填参数或参数类型错误",
+            "请求头或 Cookie 过大被部分服务器拒绝",
+            "向 HTTPS 端口发送明文 HTTP 请求",
+        ],
+    ),
+    (
+        401,
+        &[
+            "未携带 Authorization 头",
+            "Token 过期或签名无效",
+            "Basic 认证用户名或密码错误",
+        ],
+    ),
+    (
+        403,
+        &[
+            "账号权限不足",
+            "IP 白名单或 WAF 拦截",
+            "文件系统权限不足或目录禁止列出",
+            "CSRF 校验失败",
+            "对象存储签名或 Bucket 策略拒绝",
+        ],
+    ),
+    (
+        404,
+        &[
+            "URL 拼写错误或路由未注册",
+            "资源已被删除",
+            "反向代理 location 或路径前缀配置错误",
+            "单页应用未配置回退到 index.html",
+        ],
+    ),
+    (
+        405,
+        &[
+            "接口不支持该方法",
+            "CORS 预检 OPTIONS 未被处理",
+            "静态文件服务器收到 POST",
+        ],
+    ),
+    (408, &["客户端发送请求过慢", "网络不稳定导致请求未完整到达"]),
+    (
+        409,
+        &[
+            "并发修改同一资源",
+            "唯一约束冲突，如重复创建",
+            "版本号或 ETag 不匹配",
+        ],
+    ),
+    (
+        413,
+        &[
+            "上传文件超过限制，如 nginx client_max_body_size",
+            "网关或框架请求体大小限制",
+        ],
+    ),
+    (
+        415,
+        &["Content-Type 与请求体不符", "接口只接受 application/json"],
+    ),
+    (422, &["参数校验失败", "业务规则不满足"]),
+    (
+        429,
+        &["触发接口限流", "短时间内重试过多", "共享出口 IP 被整体限速"],
+    ),
+    (431, &["Cookie 累积过多", "请求头中携带过长的 Token"]),
+    (
+        500,
+        &[
+            "应用代码未捕获的异常",
+            "数据库或依赖服务错误未正确处理",
+            "配置错误导致启动后逻辑失败",
+        ],
+    ),
+    (
+        502,
+        &[
+            "上游服务未启动或崩溃",
+            "反向代理 upstream 地址或端口错误",
+            "上游返回了无效的 HTTP 响应",
+            "PHP-FPM 等进程池耗尽",
+        ],
+    ),
+    (
+        503,
+        &[
+            "服务维护或正在发布",
+            "负载过高触发熔断或过载保护",
+            "没有健康的后端实例",
+        ],
+    ),
+    (
+        504,
+        &[
+            "上游处理时间超过代理超时时间",
+            "慢查询或外部接口阻塞",
+            "网络不通导致连接上游超时",
+        ],
+    ),
+];
+
+/// (端口, 协议, 服务, 说明)
+const COMMON_PORTS: &[(u16, &str, &str, &str)] = &[
+    (20, "TCP", "FTP-Data", "FTP 数据传输"),
+    (21, "TCP", "FTP", "FTP 控制连接"),
+    (22, "TCP", "SSH", "安全 Shell、SFTP、SCP"),
+    (23, "TCP", "Telnet", "明文远程登录"),
+    (25, "TCP", "SMTP", "邮件传输（服务器间）"),
+    (53, "TCP/UDP", "DNS", "域名解析"),
+    (67, "UDP", "DHCP", "DHCP 服务端"),
+    (68, "UDP", "DHCP", "DHCP 客户端"),
+    (69, "UDP", "TFTP", "简单文件传输"),
+    (80, "TCP", "HTTP", "Web 服务"),
+    (110, "TCP", "POP3", "邮件接收"),
+    (123, "UDP", "NTP", "时间同步"),
+    (143, "TCP", "IMAP", "邮件接收"),
+    (161, "UDP", "SNMP", "网络管理"),
+    (389, "TCP", "LDAP", "目录服务"),
+    (
+        443,
+        "TCP/UDP",
+        "HTTPS",
+        "TLS 加密 Web 服务，UDP 用于 HTTP/3",
+    ),
+    (445, "TCP", "SMB", "Windows 文件共享"),
+    (465, "TCP", "SMTPS", "隐式 TLS 邮件提交"),
+    (514, "UDP", "Syslog", "系统日志"),
+    (587, "TCP", "Submission", "邮件提交（STARTTLS）"),
+    (636, "TCP", "LDAPS", "TLS 加密 LDAP"),
+    (853, "TCP/UDP", "DoT/DoQ", "DNS over TLS / QUIC"),
+    (873, "TCP", "rsync", "文件同步"),
+    (993, "TCP", "IMAPS", "TLS 加密 IMAP"),
+    (995, "TCP", "POP3S", "TLS 加密 POP3"),
+    (1080, "TCP", "SOCKS", "SOCKS 代理"),
+    (1433, "TCP", "MSSQL", "Microsoft SQL Server"),
+    (1521, "TCP", "Oracle", "Oracle 数据库监听"),
+    (1883, "TCP", "MQTT", "MQTT 消息"),
+    (2049, "TCP/UDP", "NFS", "网络文件系统"),
+    (2181, "TCP", "ZooKeeper", "ZooKeeper 客户端"),
+    (2375, "TCP", "Docker", "Docker API（明文）"),
+    (2376, "TCP", "Docker TLS", "Docker API（TLS）"),
+    (2379, "TCP", "etcd", "etcd 客户端"),
+    (3000, "TCP", "Dev Server", "常见开发服务器、Grafana"),
+    (3306, "TCP", "MySQL", "MySQL / MariaDB"),
+    (3389, "TCP", "RDP", "Windows 远程桌面"),
+    (5000, "TCP", "Registry", "Docker Registry、Flask 开发服务器"),
+    (5222, "TCP", "XMPP", "XMPP 客户端"),
+    (5432, "TCP", "PostgreSQL", "PostgreSQL 数据库"),
+    (5672, "TCP", "AMQP", "RabbitMQ"),
+    (5900, "TCP", "VNC", "远程桌面"),
+    (6379, "TCP", "Redis", "Redis"),
+    (6443, "TCP", "Kubernetes API", "Kubernetes API Server"),
+    (8080, "TCP", "HTTP-Alt", "备用 HTTP、代理"),
+    (8443, "TCP", "HTTPS-Alt", "备用 HTTPS"),
+    (8883, "TCP", "MQTTS", "TLS 加密 MQTT"),
+    (9000, "TCP", "MinIO/PHP-FPM", "MinIO、PHP-FPM、SonarQube"),
+    (9090, "TCP", "Prometheus", "Prometheus"),
+    (9092, "TCP", "Kafka", "Kafka Broker"),
+    (9200, "TCP", "Elasticsearch", "Elasticsearch HTTP"),
+    (10250, "TCP", "Kubelet", "Kubelet API"),
+    (11211, "TCP/UDP", "Memcached", "Memcached"),
+    (27017, "TCP", "MongoDB", "MongoDB"),
+];
+
+/// (语法, 名称, 说明, PCRE2, JavaScript, Python, Go (RE2), Rust regex)
+type RegexSyntax = (
+    &'static str,
+    &'static str,
+    &'static str,
+    &'static str,
+    &'static str,
+    &'static str,
+    &'static str,
+    &'static str,
+);
+
+const REGEX_SYNTAX: &[RegexSyntax] = &[
+    (
+        ".",
+        "任意字符",
+        "匹配除换行外的任意字符，s 标志下包含换行",
+        "支持",
+        "支持",
+        "支持",
+        "支持",
+        "支持",
+    ),
+    (
+        "\\d \\w \\s",
+        "字符类简写",
+        "数字、单词字符、空白",
+        "支持",
+        "支持",
+        "支持（默认 Unicode）",
+        "支持（仅 ASCII）",
+        "支持（默认 Unicode）",
+    ),
+    (
+        "\\b",
+        "单词边界",
+        "单词与非单词字符之间的位置",
+        "支持",
+        "支持",
+        "支持",
+        "支持（仅 ASCII）",
+        "支持",
+    ),
+    (
+        "*? +? ??",
+        "惰性量词",
+        "尽可能少地匹配",
+        "支持",
+        "支持",
+        "支持",
+        "支持",
+        "支持",
+    ),
+    (
+        "*+ ++ ?+",
+        "占有量词",
+        "匹配后不回溯",
+        "支持",
+        "不支持",
+        "支持（3.11+）",
+        "不支持",
+        "不支持",
+    ),
+    (
+        "(?>...)",
+        "原子组",
+        "组内匹配后不回溯",
+        "支持",
+        "不支持",
+        "支持（3.11+）",
+        "不支持",
+        "不支持",
+    ),
+    (
+        "(?:...)",
+        "非捕获组",
+        "分组但不捕获",
+        "支持",
+        "支持",
+        "支持",
+        "支持",
+        "支持",
+    ),
+    (
+        "(?<name>...)",
+        "命名捕获组",
+        "按名称引用的捕获组",
+        "支持",
+        "支持",
+        "使用 (?P<name>...)，3.6+ 也支持 (?<name>...)",
+        "支持（1.22+），也支持 (?P<name>...)",
+        "支持，也支持 (?P<name>...)",
+    ),
+    (
+        "\\1 \\k<name>",
+        "反向引用",
+        "引用前面捕获组的内容",
+        "支持",
+        "支持",
+        "支持（命名用 (?P=name)）",
+        "不支持",
+        "不支持",
+    ),
+    (
+        "(?=...) (?!...)",
+        "先行断言",
+        "断言后面是否匹配",
+        "支持",
+        "支持",
+        "支持",
+        "不支持",
+        "不支持",
+    ),
+    (
+        "(?<=...) (?<!...)",
+        "后行断言",
+        "断言前面是否匹配",
+        "支持（需定长分支）",
+        "支持（ES2018，可变长）",
+        "支持（需定长）",
+        "不支持",
+        "不支持",
+    ),
+    (
+        "\\p{L} \\p{Han}",
+        "Unicode 属性",
+        "按 Unicode 类别或脚本匹配",
+        "支持",
+        "支持（需 u 标志）",
+        "不支持（需 regex 第三方库）",
+        "支持",
+        "支持",
+    ),
+    (
+        "(?i) (?m) (?s) (?x)",
+        "内联标志",
+        "忽略大小写、多行、点匹配换行、扩展模式",
+        "支持",
+        "不支持（使用 /i /m /s 标志）",
+        "支持",
+        "支持（无 x）",
+        "支持",
+    ),
+    (
+        "(?R) (?1)",
+        "递归",
+        "递归匹配整个模式或指定组",
+        "支持",
+        "不支持",
+        "不支持",
+        "不支持",
+        "不支持",
+    ),
+    (
+        "\\A \\z \\Z",
+        "字符串锚点",
+        "匹配整个输入的开头或结尾",
+        "支持",
+        "不支持（使用 ^ $ 且不加 m）",
+        "支持 \\A \\Z",
+        "支持 \\A \\z",
+        "支持 \\A \\z",
+    ),
+    (
+        "$1 ${name}",
+        "替换引用",
+        "替换字符串中引用捕获组",
+        "$1 / ${name}",
+        "$1 / $<name>",
+        "\\1 / \\g<name>",
+        "$1 / ${name}",
+        "$1 / ${name}",
+    ),
+    (
+        "线性时间",
+        "匹配复杂度",
+        "是否保证线性时间，避免灾难性回溯",
+        "否（回溯引擎，可启用 JIT）",
+        "否（回溯引擎）",
+        "否（回溯引擎）",
+        "是",
+        "是",
+    ),
+];
+
+/// (语法, 名称, 说明)
+const CRON_SYNTAX: &[(&str, &str, &str)] = &[
+    (
+        "* * * * *",
+        "字段顺序",
+        "分 时 日 月 周；Quartz/Spring 在最前面多一个秒字段",
+    ),
+    ("*", "任意值", "匹配该字段的所有值"),
+    (",", "列表", "1,15,30 表示多个取值"),
+    ("-", "范围", "1-5 表示 1 到 5"),
+    (
+        "/",
+        "步长",
+        "*/15 表示每 15 个单位，10-50/10 表示范围内步进",
+    ),
+    ("?", "不指定", "Quartz：日和周只能指定其一，另一个用 ?"),
+    ("L", "最后", "Quartz：L 表示月末，5L 表示最后一个周五"),
+    ("W", "最近工作日", "Quartz：15W 表示离 15 号最近的工作日"),
+    ("#", "第 N 个", "Quartz：6#3 表示第三个周五"),
+    ("@yearly", "每年", "等同 0 0 1 1 *"),
+    ("@monthly", "每月", "等同 0 0 1 * *"),
+    ("@weekly", "每周", "等同 0 0 * * 0"),
+    ("@daily", "每天", "等同 0 0 * * *"),
+    ("@hourly", "每小时", "等同 0 * * * *"),
+    ("@reboot", "开机时", "系统启动时执行一次"),
+    ("*/5 * * * *", "示例", "每 5 分钟"),
+    ("0 9 * * 1-5", "示例", "工作日上午 9 点"),
+    ("0 0 1 * *", "示例", "每月 1 日零点"),
+    ("30 2 * * 0", "示例", "每周日 2:30"),
+    ("0 */2 * * *", "示例", "每 2 小时整点"),
+    ("日与周", "注意", "标准 cron 中日和周同时指定时为“或”关系"),
+];
+
+/// (IANA 名称, OpenSSL 名称, TLS 版本, 安全评级)
+const TLS_CIPHERS: &[(&str, &str, &str, &str)] = &[
+    (
+        "TLS_AES_128_GCM_SHA256",
+        "TLS_AES_128_GCM_SHA256",
+        "TLS 1.3",
+        "推荐",
+    ),
+    (
+        "TLS_AES_256_GCM_SHA384",
+        "TLS_AES_256_GCM_SHA384",
+        "TLS 1.3",
+        "推荐",
+    ),
+    (
+        "TLS_CHACHA20_POLY1305_SHA256",
+        "TLS_CHACHA20_POLY1305_SHA256",
+        "TLS 1.3",
+        "推荐",
+    ),
+    (
+        "TLS_AES_128_CCM_SHA256",
+        "TLS_AES_128_CCM_SHA256",
+        "TLS 1.3",
+        "安全",
+    ),
+    (
+        "TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256",
+        "ECDHE-ECDSA-AES128-GCM-SHA256",
+        "TLS 1.2",
+        "推荐",
+    ),
+    (
+        "TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384",
+        "ECDHE-ECDSA-AES256-GCM-SHA384",
+        "TLS 1.2",
+        "推荐",
+    ),
+    (
+        "TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256",
+        "ECDHE-ECDSA-CHACHA20-POLY1305",
+        "TLS 1.2",
+        "推荐",
+    ),
+    (
+        "TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256",
+        "ECDHE-RSA-AES128-GCM-SHA256",
+        "TLS 1.2",
+        "推荐",
+    ),
+    (
+        "TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384",
+        "ECDHE-RSA-AES256-GCM-SHA384",
+        "TLS 1.2",
+        "推荐",
+    ),
+    (
+        "TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256",
+        "ECDHE-RSA-CHACHA20-POLY1305",
+        "TLS 1.2",
+        "推荐",
+    ),
+    (
+        "TLS_DHE_RSA_WITH_AES_128_GCM_SHA256",
+        "DHE-RSA-AES128-GCM-SHA256",
+        "TLS 1.2",
+        "安全（DH 参数需 ≥ 2048 位）",
+    ),
+    (
+        "TLS_DHE_RSA_WITH_AES_256_GCM_SHA384",
+        "DHE-RSA-AES256-GCM-SHA384",
+        "TLS 1.2",
+        "安全（DH 参数需 ≥ 2048 位）",
+    ),
+    (
+        "TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA256",
+        "ECDHE-RSA-AES128-SHA256",
+        "TLS 1.2",
+        "弱（CBC 模式）",
+    ),
+    (
+        "TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA384",
+        "ECDHE-RSA-AES256-SHA384",
+        "TLS 1.2",
+        "弱（CBC 模式）",
+    ),
+    (
+        "TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA",
+        "ECDHE-RSA-AES128-SHA",
+        "TLS 1.0-1.2",
+        "弱（CBC + SHA1）",
+    ),
+    (
+        "TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA",
+        "ECDHE-RSA-AES256-SHA",
+        "TLS 1.0-1.2",
+        "弱（CBC + SHA1）",
+    ),
+    (
+        "TLS_RSA_WITH_AES_128_GCM_SHA256",
+        "AES128-GCM-SHA256",
+        "TLS 1.2",
+        "弱（无前向保密）",
+    ),
+    (
+        "TLS_RSA_WITH_AES_256_GCM_SHA384",
+        "AES256-GCM-SHA384",
+        "TLS 1.2",
+        "弱（无前向保密）",
+    ),
+    (
+        "TLS_RSA_WITH_AES_128_CBC_SHA",
+        "AES128-SHA",
+        "SSL 3.0-TLS 1.2",
+        "弱（无前向保密，CBC）",
+    ),
+    (
+        "TLS_RSA_WITH_AES_256_CBC_SHA",
+        "AES256-SHA",
+        "SSL 3.0-TLS 1.2",
+        "弱（无前向保密，CBC）",
+    ),
+    (
+        "TLS_RSA_WITH_3DES_EDE_CBC_SHA",
+        "DES-CBC3-SHA",
+        "SSL 3.0-TLS 1.2",
+        "不安全（Sweet32）",
+    ),
+    (
+        "TLS_RSA_WITH_RC4_128_SHA",
+        "RC4-SHA",
+        "SSL 3.0-TLS 1.2",
+        "不安全（RC4，RFC 7465 禁用）",
+    ),
+    (
+        "TLS_RSA_WITH_RC4_128_MD5",
+        "RC4-MD5",
+        "SSL 3.0-TLS 1.2",
+        "不安全（RC4 + MD5）",
+    ),
+    (
+        "TLS_RSA_WITH_NULL_SHA256",
+        "NULL-SHA256",
+        "TLS 1.2",
+        "不安全（无加密）",
+    ),
+    (
+        "TLS_ECDH_anon_WITH_AES_128_CBC_SHA",
+        "AECDH-AES128-SHA",
+        "TLS 1.0-1.2",
+        "不安全（匿名，无认证）",
+    ),
+];
+
+fn field(name: &str, value: impl Into<String>) -> ReferenceField {
+    ReferenceField {
+        name: name.to_string(),
+        value: value.into(),
+    }
+}
+
+/// 构建全部参考条目
+fn all_entries() -> Vec<ReferenceEntry> {
+    let mut entries = Vec::new();
+
+    for (code, phrase, rfc, description) in HTTP_STATUSES {
+        entries.push(ReferenceEntry {
+            category: "http_status".to_string(),
+            key: code.to_string(),
+            title: phrase.to_string(),
+            description: description.to_string(),
+            fields: vec![field("类别", http_status_class(*code)), field("规范", *rfc)],
+        });
+    }
+
+    for (ext, mime, description) in MIME_TYPES {
+        entries.push(ReferenceEntry {
+            category: "mime".to_string(),
+            key: format!(".{}", ext),
+            title: mime.to_string(),
+            description: description.to_string(),
+            fields: vec![field("扩展名", *ext), field("MIME", *mime)],
+        });
+    }
+
+    for (port, protocol, service, description) in COMMON_PORTS {
+        entries.push(ReferenceEntry {
+            category: "port".to_string(),
+            key: port.to_string(),
+            title: service.to_string(),
+            description: description.to_string(),
+            fields: vec![field("协议", *protocol)],
+        });
+    }
+
+    for (syntax, name, description, pcre2, js, python, go, rust) in REGEX_SYNTAX {
+        entries.push(ReferenceEntry {
+            category: "regex".to_string(),
+            key: syntax.to_string(),
+            title: name.to_string(),
+            description: description.to_string(),
+            fields: vec![
+                field("PCRE2", *pcre2),
+                field("JavaScript", *js),
+                field("Python", *python),
+                field("Go (RE2)", *go),
+                field("Rust regex", *rust),
+            ],
+        });
+    }
+
+    for (syntax, name, description) in CRON_SYNTAX {
+        entries.push(ReferenceEntry {
+            category: "cron".to_string(),
+            key: syntax.to_string(),
+            title: name.to_string(),
+            description: description.to_string(),
+            fields: Vec::new(),
+        });
+    }
+
+    for (iana, openssl, version, rating) in TLS_CIPHERS {
+        entries.push(ReferenceEntry {
+            category: "tls_cipher".to_string(),
+            key: iana.to_string(),
+            title: openssl.to_string(),
+            description: rating.to_string(),
+            fields: vec![
+                field("IANA", *iana),
+                field("OpenSSL", *openssl),
+                field("TLS 版本", *version),
+            ],
+        });
+    }
+
+    entries
+}
+
+fn http_status_class(code: u16) -> &'static str {
+    match code {
+        100..=199 => "1xx 信息",
+        200..=299 => "2xx 成功",
+        300..=399 => "3xx 重定向",
+        400..=499 => "4xx 客户端错误",
+        500..=599 => "5xx 服务器错误",
+        _ => "未知",
+    }
+}
+
+/// 匹配得分，0 表示不匹配
+fn match_score(entry: &ReferenceEntry, terms: &[String]) -> u32 {
+    let key = entry.key.to_lowercase();
+    let title = entry.title.to_lowercase();
+    let mut score = 0;
+
+    for term in terms {
+        let term_score = if key == *term || key.trim_start_matches('.') == term {
+            100
+        } else if title == *term {
+            80
+        } else if key.starts_with(term.as_str()) {
+            60
+        } else if title.starts_with(term.as_str()) {
+            50
+        } else if key.contains(term.as_str()) || title.contains(term.as_str()) {
+            30
+        } else if entry.description.to_lowercase().contains(term.as_str())
+            || entry
+                .fields
+                .iter()
+                .any(|f| f.value.to_lowercase().contains(term.as_str()))
+        {
+            10
+        } else {
+            return 0;
+        };
+        score += term_score;
+    }
+    score
+}
+
+/// 列出离线参考资料分类
+#[tauri::command]
+pub fn list_reference_categories() -> Vec<ReferenceCategory> {
+    let entries = all_entries();
+    CATEGORIES
+        .iter()
+        .map(|(id, name)| ReferenceCategory {
+            id: id.to_string(),
+            name: name.to_string(),
+            count: entries.iter().filter(|e| e.category == *id).count(),
+        })
+        .collect()
+}
+
+/// 搜索离线参考资料，空查询返回分类下全部条目
+#[tauri::command]
+pub fn search_reference(
+    query: String,
+    category: Option<String>,
+    limit: Option<usize>,
+) -> Result<Vec<ReferenceEntry>, String> {
+    if let Some(ref category) = category {
+        if !CATEGORIES.iter().any(|(id, _)| id == category) {
+            return Err(format!("未知的分类: {}", category));
+        }
+    }
+
+    let terms: Vec<String> = query
+        .split_whitespace()
+        .map(|term| term.to_lowercase())
+        .collect();
+    let mut matches: Vec<(u32, usize, ReferenceEntry)> = all_entries()
+        .into_iter()
+        .enumerate()
+        .filter(|(_, entry)| category.as_ref().is_none_or(|c| entry.category == *c))
+        .filter_map(|(index, entry)| {
+            let score = if terms.is_empty() {
+                1
+            } else {
+                match_score(&entry, &terms)
+            };
+            (score > 0).then_some((score, index, entry))
+        })
+        .collect();
+
+    // 得分相同时保持数据表中的顺序
+    matches.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+    Ok(matches
+        .into_iter()
+        .take(limit.unwrap_or(50))
+        .map(|(_, _, entry)| entry)
+        .collect())
+}
+
+/// 查询 HTTP 状态码的说明、类别、规范和常见原因
+#[tauri::command]
+pub fn lookup_http_status(code: u16) -> Result<HttpStatusInfo, String> {
+    if !(100..=599).contains(&code) {
+        return Err(format!("无效的 HTTP 状态码: {}", code));
+    }
+
+    let common_causes = HTTP_STATUS_CAUSES
+        .iter()
+        .find(|(c, _)| *c == code)
+        .map(|(_, causes)| causes.iter().map(|c| c.to_string()).collect())
+        .unwrap_or_default();
+
+    let info = match HTTP_STATUSES.iter().find(|(c, ..)| *c == code) {
+        Some((_, phrase, rfc, description)) => HttpStatusInfo {
+            code,
+            phrase: phrase.to_string(),
+            category: http_status_class(code).to_string(),
+            rfc: Some(rfc.to_string()),
+            description: description.to_string(),
+            common_causes,
+        },
+        None => HttpStatusInfo {
+            code,
+            phrase: "Unassigned".to_string(),
+            category: http_status_class(code).to_string(),
+            rfc: None,
+            description: format!("未在 IANA 注册的状态码，客户端应按 {}xx 处理", code / 100),
+            common_causes,
+        },
+    };
+    Ok(info)
+}
+
+/// 扩展名与 MIME 类型双向查询，支持文件名和带参数的 Content-Type
+#[tauri::command]
+pub fn lookup_mime_type(query: String) -> Result<MimeLookupResult, String> {
+    let trimmed = query.trim().to_lowercase();
+    if trimmed.is_empty() {
+        return Err("请输入扩展名或 MIME 类型".to_string());
+    }
+
+    let to_mapping = |(ext, mime, description): &(&str, &str, &str)| MimeMapping {
+        extension: ext.to_string(),
+        mime_type: mime.to_string(),
+        description: description.to_string(),
+    };
+
+    if trimmed.contains('/') {
+        // 去掉 charset 等参数
+        let mime = trimmed.split(';').next().unwrap_or_default().trim();
+        let mut mappings: Vec<MimeMapping> = MIME_TYPES
+            .iter()
+            .filter(|(_, m, _)| *m == mime)
+            .map(to_mapping)
+            .collect();
+        // 通配类型如 image/*
+        if mappings.is_empty() {
+            if let Some(prefix) = mime.strip_suffix("/*") {
+                mappings = MIME_TYPES
+                    .iter()
+                    .filter(|(_, m, _)| m.split('/').next() == Some(prefix))
+                    .map(to_mapping)
+                    .collect();
+            }
+        }
+        if mappings.is_empty() {
+            return Err(format!("未找到 MIME 类型: {}", mime));
+        }
+        return Ok(MimeLookupResult {
+            query,
+            direction: "mime_to_extension".to_string(),
+            mappings,
+        });
+    }
+
+    // 从文件名中依次尝试多段扩展名，如 archive.tar.gz
+    let name = trimmed.rsplit(['/', '\\']).next().unwrap_or_default();
+    let parts: Vec<&str> = name.trim_start_matches('.').split('.').collect();
+    let candidates: Vec<String> = if parts.len() == 1 {
+        vec![parts[0].to_string()]
+    } else {
+        (1..parts.len()).map(|i| parts[i..].join(".")).collect()
+    };
+    let mappings: Vec<MimeMapping> = candidates
+        .iter()
+        .find_map(|ext| {
+            let found: Vec<MimeMapping> = MIME_TYPES
+                .iter()
+                .filter(|(e, _, _)| e == ext)
+                .map(to_mapping)
+                .collect();
+            (!found.is_empty()).then_some(found)
+        })
+        .ok_or_else(|| format!("未找到扩展名: {}", query.trim()))?;
+
+    Ok(MimeLookupResult {
+        query,
+        direction: "extension_to_mime".to_string(),
+        mappings,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_reference() {
+        let results = search_reference("404".to_string(), None, None).unwrap();
+        assert_eq!(results[0].category, "http_status");
+        assert_eq!(results[0].title, "Not Found");
+
+        let results = search_reference("json".to_string(), Some("mime".to_string()), None).unwrap();
+        assert_eq!(results[0].key, ".json");
+
+        let results =
+            search_reference("后行断言".to_string(), Some("regex".to_string()), None).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].fields[3].value, "不支持");
+
+        let results = search_reference("ecdhe rsa chacha20".to_string(), None, None).unwrap();
+        assert_eq!(results[0].title, "ECDHE-RSA-CHACHA20-POLY1305");
+
+        assert!(search_reference("x".to_string(), Some("nope".to_string()), None).is_err());
+    }
+
+    #[test]
+    fn test_list_reference_categories() {
+        let categories = list_reference_categories();
+        assert_eq!(categories.len(), CATEGORIES.len());
+        assert!(categories.iter().all(|c| c.count > 0));
+    }
+
+    #[test]
+    fn test_lookup_http_status() {
+        let info = lookup_http_status(502).unwrap();
+        assert_eq!(info.phrase, "Bad Gateway");
+        assert_eq!(info.category, "5xx 服务器错误");
+        assert_eq!(info.rfc.as_deref(), Some("RFC 9110"));
+        assert!(!info.common_causes.is_empty());
+
+        let unassigned = lookup_http_status(499).unwrap();
+        assert_eq!(unassigned.rfc, None);
+        assert!(lookup_http_status(600).is_err());
+    }
+
+    #[test]
+    fn test_lookup_mime_type() {
+        let result = lookup_mime_type("backup.TAR.gz".to_string()).unwrap();
+        assert_eq!(result.direction, "extension_to_mime");
+        assert_eq!(result.mappings[0].mime_type, "application/gzip");
+
+        let result = lookup_mime_type(".svg".to_string()).unwrap();
+        assert_eq!(result.mappings[0].mime_type, "image/svg+xml");
+
+        let result = lookup_mime_type("text/html; charset=utf-8".to_string()).unwrap();
+        let extensions: Vec<&str> = result
+            .mappings
+            .iter()
+            .map(|m| m.extension.as_str())
+            .collect();
+        assert_eq!(extensions, vec!["html", "htm"]);
+
+        assert!(
+            lookup_mime_type("video/*".to_string())
+                .unwrap()
+                .mappings
+                .len()
+                > 3
+        );
+        assert!(lookup_mime_type("unknown-ext".to_string()).is_err());
+    }
+}
@@ -0,0 +1,647 @@
+use crate::utils::progress::ProgressReporter;
+use crate::utils::proxy;
+use base64::{engine::general_purpose, Engine as _};
+use openssl::hash::{hash, Hasher, MessageDigest};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+
+const READ_BUFFER_SIZE: usize = 1024 * 1024;
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(200);
+/// SRI 远程资源大小上限
+const SRI_MAX_BYTES: usize = 50 * 1024 * 1024;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerifyChecksumRequest {
+    pub path: String,
+    pub expected: Option<String>,      // 期望的摘要，可带 sha256: 前缀
+    pub manifest_path: Option<String>, // .sha256/.md5 清单或 SHA256SUMS 文件
+    pub algorithm: Option<String>,     // 未指定时按摘要长度或清单文件名推断
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChecksumProgress {
+    pub path: String,
+    pub bytes_processed: u64,
+    pub total_bytes: u64,
+    pub percent: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignatureCheck {
+    pub status: String, // valid, invalid, gpg_unavailable
+    pub signer: Option<String>,
+    pub details: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChecksumResult {
+    pub path: String,
+    pub algorithm: String,
+    pub expected: String,
+    pub actual: String,
+    pub matched: bool,
+    pub file_size: u64,
+    pub elapsed_ms: u64,
+    pub manifest_entry: Option<String>,    // 清单中匹配到的文件名
+    pub signature: Option<SignatureCheck>, // 仅 GPG 签名的清单
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenerateSriRequest {
+    pub source: String,                  // URL 或本地文件路径
+    pub algorithms: Option<Vec<String>>, // sha256/sha384/sha512，默认 sha384
+    pub crossorigin: Option<String>,     // anonymous 或 use-credentials，默认 anonymous
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SriHash {
+    pub algorithm: String,
+    pub integrity: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SriResult {
+    pub source: String,
+    pub resource_type: String, // script, style, other
+    pub content_type: Option<String>,
+    pub size: u64,
+    pub hashes: Vec<SriHash>,
+    pub integrity: String,   // 多个摘要以空格分隔，可直接用于 integrity 属性
+    pub tag: Option<String>, // 可直接粘贴的 <script>/<link> 标签
+    pub warnings: Vec<String>,
+}
+
+/// 生成 Subresource Integrity 摘要及引用标签
+#[tauri::command]
+pub async fn generate_sri(request: GenerateSriRequest) -> Result<SriResult, String> {
+    let source = request.source.trim().to_string();
+    if source.is_empty() {
+        return Err("请输入 URL 或文件路径".to_string());
+    }
+    let algorithms = match request.algorithms {
+        Some(list) if !list.is_empty() => list
+            .iter()
+            .map(|a| a.trim().to_lowercase().replace('-', ""))
+            .collect::<Vec<_>>(),
+        _ => vec!["sha384".to_string()],
+    };
+    for algorithm in &algorithms {
+        if !matches!(algorithm.as_str(), "sha256" | "sha384" | "sha512") {
+            return Err(format!(
+                "SRI 仅支持 sha256/sha384/sha512，不支持: {}",
+                algorithm
+            ));
+        }
+    }
+    let crossorigin = request
+        .crossorigin
+        .map(|c| c.trim().to_string())
+        .filter(|c| !c.is_empty())
+        .unwrap_or_else(|| "anonymous".to_string());
+    if !matches!(crossorigin.as_str(), "anonymous" | "use-credentials") {
+        return Err(format!("无效的 crossorigin 取值: {}", crossorigin));
+    }
+
+    let mut warnings = Vec::new();
+    let is_remote = source.starts_with("http://") || source.starts_with("https://");
+    let (content, content_type) = if is_remote {
+        fetch_sri_resource(&source, &mut warnings).await?
+    } else {
+        let content = std::fs::read(&source).map_err(|e| format!("读取文件失败: {}", e))?;
+        (content, None)
+    };
+
+    let hashes = algorithms
+        .iter()
+        .map(|algorithm| {
+            let digest = hash(message_digest(algorithm)?, &content)
+                .map_err(|e| format!("计算摘要失败: {}", e))?;
+            Ok(SriHash {
+                algorithm: algorithm.clone(),
+                integrity: format!("{}-{}", algorithm, general_purpose::STANDARD.encode(digest)),
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    let integrity = hashes
+        .iter()
+        .map(|h| h.integrity.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let resource_type = sri_resource_type(&source, content_type.as_deref());
+    // 本地文件以文件名作为引用地址，粘贴后按实际部署路径调整
+    let reference = if is_remote {
+        source.clone()
+    } else {
+        Path::new(&source)
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| source.clone())
+    };
+    let tag = sri_tag(resource_type, &reference, &integrity, &crossorigin);
+    if tag.is_none() {
+        warnings.push(
+            "无法识别资源类型，SRI 仅适用于 <script> 和 <link rel=\"stylesheet\">".to_string(),
+        );
+    }
+
+    Ok(SriResult {
+        source,
+        resource_type: resource_type.to_string(),
+        content_type,
+        size: content.len() as u64,
+        hashes,
+        integrity,
+        tag,
+        warnings,
+    })
+}
+
+async fn fetch_sri_resource(
+    url: &str,
+    warnings: &mut Vec<String>,
+) -> Result<(Vec<u8>, Option<String>), String> {
+    let client = proxy::client_builder("checksum_tool")
+        .timeout(Duration::from_secs(30))
+        .build()
+        .map_err(|e| format!("构建 HTTP 客户端失败: {}", e))?;
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| format!("请求失败: {}", e))?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!("请求失败: HTTP {}", status));
+    }
+    if url.starts_with("http://") {
+        warnings.push("资源通过 HTTP 加载，建议使用 HTTPS".to_string());
+    }
+    // 跨域资源缺少 CORS 头时浏览器会拒绝校验，导致资源加载失败
+    if !response
+        .headers()
+        .contains_key("access-control-allow-origin")
+    {
+        warnings.push(
+            "响应缺少 Access-Control-Allow-Origin 头，跨域引用时完整性校验将失败".to_string(),
+        );
+    }
+    let content_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.to_string());
+    if let Some(len) = response.content_length() {
+        if len as usize > SRI_MAX_BYTES {
+            return Err(format!("资源过大: {} 字节", len));
+        }
+    }
+    let content = response
+        .bytes()
+        .await
+        .map_err(|e| format!("读取响应失败: {}", e))?;
+    if content.len() > SRI_MAX_BYTES {
+        return Err(format!("资源过大: {} 字节", content.len()));
+    }
+    Ok((content.to_vec(), content_type))
+}
+
+fn sri_resource_type(source: &str, content_type: Option<&str>) -> &'static str {
+    let path = source
+        .split(['?', '#'])
+        .next()
+        .unwrap_or(source)
+        .to_lowercase();
+    let content_type = content_type.unwrap_or_default().to_lowercase();
+    if path.ends_with(".js") || path.ends_with(".mjs") || content_type.contains("javascript") {
+        "script"
+    } else if path.ends_with(".css") || content_type.contains("text/css") {
+        "style"
+    } else {
+        "other"
+    }
+}
+
+fn sri_tag(resource_type: &str, url: &str, integrity: &str, crossorigin: &str) -> Option<String> {
+    let url = url.replace('&', "&amp;").replace('"', "&quot;");
+    match resource_type {
+        "script" => Some(format!(
+            r#"<script src="{}" integrity="{}" crossorigin="{}"></script>"#,
+            url, integrity, crossorigin
+        )),
+        "style" => Some(format!(
+            r#"<link rel="stylesheet" href="{}" integrity="{}" crossorigin="{}">"#,
+            url, integrity, crossorigin
+        )),
+        _ => None,
+    }
+}
+
+/// 计算文件摘要并与期望值或校验清单比对
+#[tauri::command]
+pub async fn verify_checksum(
+    app: AppHandle,
+    request: VerifyChecksumRequest,
+) -> Result<ChecksumResult, String> {
+    let path = Path::new(&request.path);
+    if !path.is_file() {
+        return Err(format!("文件不存在: {}", request.path));
+    }
+    let file_name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or_default()
+        .to_string();
+
+    let mut manifest_entry = None;
+    let mut signature = None;
+    let mut algorithm = request.algorithm.clone().filter(|a| !a.is_empty());
+
+    let expected = match (&request.expected, &request.manifest_path) {
+        (Some(expected), _) if !expected.trim().is_empty() => {
+            let (prefix, digest) = split_digest_prefix(expected.trim());
+            if algorithm.is_none() {
+                algorithm = prefix;
+            }
+            digest
+        }
+        (_, Some(manifest_path)) => {
+            let content = std::fs::read_to_string(manifest_path)
+                .map_err(|e| format!("读取校验清单失败: {}", e))?;
+            let (body, signed) = strip_clearsign(&content);
+            if signed {
+                let manifest_path = manifest_path.clone();
+                signature =
+                    tokio::task::spawn_blocking(move || verify_gpg_signature(&manifest_path))
+                        .await
+                        .ok();
+            }
+
+            let (entry_algorithm, name, digest) = find_manifest_entry(&body, &file_name)
+                .ok_or_else(|| format!("校验清单中未找到 {} 的摘要", file_name))?;
+            if algorithm.is_none() {
+                algorithm = entry_algorithm.or_else(|| algorithm_from_file_name(manifest_path));
+            }
+            manifest_entry = name;
+            digest
+        }
+        _ => return Err("请提供期望的摘要或校验清单文件".to_string()),
+    };
+
+    if !expected.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(format!("摘要不是有效的十六进制字符串: {}", expected));
+    }
+    let algorithm = match algorithm {
+        Some(algorithm) => algorithm.to_lowercase().replace('-', ""),
+        None => algorithm_from_length(expected.len())
+            .ok_or_else(|| format!("无法根据长度 {} 推断摘要算法", expected.len()))?
+            .to_string(),
+    };
+    let digest = message_digest(&algorithm)?;
+
+    let start = Instant::now();
+    let hash_path = request.path.clone();
+    let (actual, file_size) = tokio::task::spawn_blocking(move || {
+        let mut reporter = ProgressReporter::new(&app, "checksum_tool");
+        let result = hash_file(&app, &mut reporter, &hash_path, digest);
+        match &result {
+            Ok(_) => reporter.finish(None),
+            Err(e) => reporter.fail(e),
+        }
+        result
+    })
+    .await
+    .map_err(|e| format!("计算摘要任务失败: {}", e))??;
+
+    Ok(ChecksumResult {
+        path: request.path,
+        algorithm,
+        matched: actual.eq_ignore_ascii_case(&expected),
+        expected: expected.to_lowercase(),
+        actual,
+        file_size,
+        elapsed_ms: start.elapsed().as_millis() as u64,
+        manifest_entry,
+        signature,
+    })
+}
+
+fn message_digest(algorithm: &str) -> Result<MessageDigest, String> {
+    match algorithm {
+        "md5" => Ok(MessageDigest::md5()),
+        "sha1" => Ok(MessageDigest::sha1()),
+        "sha224" => Ok(MessageDigest::sha224()),
+        "sha256" => Ok(MessageDigest::sha256()),
+        "sha384" => Ok(MessageDigest::sha384()),
+        "sha512" => Ok(MessageDigest::sha512()),
+        "sha3256" => Ok(MessageDigest::sha3_256()),
+        "sha3512" => Ok(MessageDigest::sha3_512()),
+        other => Err(format!("不支持的摘要算法: {}", other)),
+    }
+}
+
+fn algorithm_from_length(len: usize) -> Option<&'static str> {
+    match len {
+        32 => Some("md5"),
+        40 => Some("sha1"),
+        56 => Some("sha224"),
+        64 => Some("sha256"),
+        96 => Some("sha384"),
+        128 => Some("sha512"),
+        _ => None,
+    }
+}
+
+/// 根据清单文件名推断算法，如 foo.iso.sha256、SHA512SUMS
+fn algorithm_from_file_name(path: &str) -> Option<String> {
+    let name = Path::new(path)
+        .file_name()
+        .and_then(|n| n.to_str())?
+        .to_lowercase()
+        .replace('-', "");
+    ["sha512", "sha384", "sha256", "sha224", "sha1", "md5"]
+        .iter()
+        .find(|algorithm| name.contains(*algorithm))
+        .map(|algorithm| algorithm.to_string())
+}
+
+/// 拆分 "sha256:abcd" 形式的摘要
+fn split_digest_prefix(value: &str) -> (Option<String>, String) {
+    match value.split_once(':') {
+        Some((prefix, digest)) => (
+            Some(prefix.to_lowercase().replace('-', "")),
+            digest.trim().to_string(),
+        ),
+        None => (None, value.to_string()),
+    }
+}
+
+/// 去除 PGP 明文签名的封装，返回正文以及是否带签名
+fn strip_clearsign(content: &str) -> (String, bool) {
+    if !content.contains("-----BEGIN PGP SIGNED MESSAGE-----") {
+        return (content.to_string(), false);
+    }
+
+    let mut body = Vec::new();
+    let mut in_headers = false;
+    let mut in_body = false;
+    for line in content.lines() {
+        if line.starts_with("-----BEGIN PGP SIGNED MESSAGE-----") {
+            in_headers = true;
+            continue;
+        }
+        if line.starts_with("-----BEGIN PGP SIGNATURE-----") {
+            break;
+        }
+        if in_headers {
+            // 头部以空行结束
+            if line.trim().is_empty() {
+                in_headers = false;
+                in_body = true;
+            }
+            continue;
+        }
+        if in_body {
+            body.push(line.strip_prefix("- ").unwrap_or(line));
+        }
+    }
+    (body.join("\n"), true)
+}
+
+/// 在清单中查找文件对应的摘要，返回 (算法, 文件名, 摘要)
+///
+/// 支持 GNU 格式 `hash  name` / `hash *name`、BSD 格式 `SHA256 (name) = hash`
+/// 以及只包含一个摘要的文件
+fn find_manifest_entry(
+    content: &str,
+    file_name: &str,
+) -> Option<(Option<String>, Option<String>, String)> {
+    let mut single_digest = None;
+    let mut digest_lines = 0;
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        // BSD 格式
+        if let Some((left, digest)) = line.rsplit_once(") = ") {
+            if let Some((algorithm, name)) = left.split_once(" (") {
+                if Path::new(name).file_name().and_then(|n| n.to_str()) == Some(file_name) {
+                    return Some((
+                        Some(algorithm.to_lowercase().replace('-', "")),
+                        Some(name.to_string()),
+                        digest.trim().to_string(),
+                    ));
+                }
+                continue;
+            }
+        }
+
+        let mut parts = line.splitn(2, char::is_whitespace);
+        let digest = parts.next().unwrap_or_default();
+        if !digest.chars().all(|c| c.is_ascii_hexdigit()) {
+            continue;
+        }
+        digest_lines += 1;
+        match parts.next().map(|name| name.trim().trim_start_matches('*')) {
+            Some(name) if !name.is_empty() => {
+                let base = Path::new(name).file_name().and_then(|n| n.to_str());
+                if name == file_name || base == Some(file_name) {
+                    return Some((None, Some(name.to_string()), digest.to_string()));
+                }
+            }
+            _ => single_digest = Some(digest.to_string()),
+        }
+    }
+
+    if digest_lines == 1 {
+        single_digest.map(|digest| (None, None, digest))
+    } else {
+        None
+    }
+}
+
+/// 调用本机 gpg 验证明文签名
+fn verify_gpg_signature(manifest_path: &str) -> SignatureCheck {
+    let output = Command::new("gpg")
+        .args(["--batch", "--status-fd", "1", "--verify", manifest_path])
+        .output();
+
+    let output = match output {
+        Ok(output) => output,
+        Err(_) => {
+            return SignatureCheck {
+                status: "gpg_unavailable".to_string(),
+                signer: None,
+                details: "未找到 gpg，无法验证签名".to_string(),
+            }
+        }
+    };
+
+    let status_text = String::from_utf8_lossy(&output.stdout);
+    let signer = status_text
+        .lines()
+        .find_map(|line| line.strip_prefix("[GNUPG:] GOODSIG "))
+        .and_then(|rest| rest.split_once(' '))
+        .map(|(_, name)| name.to_string());
+    let details = String::from_utf8_lossy(&output.stderr).trim().to_string();
+
+    SignatureCheck {
+        status: if output.status.success() && signer.is_some() {
+            "valid".to_string()
+        } else {
+            "invalid".to_string()
+        },
+        signer,
+        details,
+    }
+}
+
+fn hash_file(
+    app: &AppHandle,
+    reporter: &mut ProgressReporter,
+    path: &str,
+    digest: MessageDigest,
+) -> Result<(String, u64), String> {
+    let mut file = File::open(path).map_err(|e| format!("打开文件失败: {}", e))?;
+    let total_bytes = file
+        .metadata()
+        .map_err(|e| format!("读取文件信息失败: {}", e))?
+        .len();
+    let mut hasher = Hasher::new(digest).map_err(|e| format!("初始化摘要失败: {}", e))?;
+    let mut buffer = vec![0u8; READ_BUFFER_SIZE];
+    let mut processed = 0u64;
+    let mut last_emit = Instant::now();
+
+    loop {
+        let read = file
+            .read(&mut buffer)
+            .map_err(|e| format!("读取文件失败: {}", e))?;
+        if read == 0 {
+            break;
+        }
+        hasher
+            .update(&buffer[..read])
+            .map_err(|e| format!("计算摘要失败: {}", e))?;
+        processed += read as u64;
+        reporter.update(processed, Some(total_bytes), Some(path.to_string()));
+
+        if last_emit.elapsed() >= PROGRESS_INTERVAL || processed == total_bytes {
+            last_emit = Instant::now();
+            let _ = app.emit(
+                "checksum-progress",
+                ChecksumProgress {
+                    path: path.to_string(),
+                    bytes_processed: processed,
+                    total_bytes,
+                    percent: if total_bytes == 0 {
+                        100.0
+                    } else {
+                        (processed as f64 / total_bytes as f64 * 10000.0).round() / 100.0
+                    },
+                },
+            );
+        }
+    }
+
+    let result = hasher
+        .finish()
+        .map_err(|e| format!("计算摘要失败: {}", e))?;
+    Ok((hex::encode(result), processed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_manifest_entry() {
+        let gnu = "\
+e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855  other.iso
+9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08 *dist/app.iso
+";
+        let (algorithm, name, digest) = find_manifest_entry(gnu, "app.iso").unwrap();
+        assert_eq!(algorithm, None);
+        assert_eq!(name.as_deref(), Some("dist/app.iso"));
+        assert!(digest.starts_with("9f86d0"));
+
+        let bsd =
+            "SHA256 (app.iso) = 9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+        let (algorithm, _, _) = find_manifest_entry(bsd, "app.iso").unwrap();
+        assert_eq!(algorithm.as_deref(), Some("sha256"));
+
+        let single = "d41d8cd98f00b204e9800998ecf8427e\n";
+        assert_eq!(
+            find_manifest_entry(single, "anything.bin").unwrap().2,
+            "d41d8cd98f00b204e9800998ecf8427e"
+        );
+        assert!(find_manifest_entry(gnu, "missing.iso").is_none());
+    }
+
+    #[test]
+    fn test_strip_clearsign() {
+        let signed = "\
+-----BEGIN PGP SIGNED MESSAGE-----
+Hash: SHA256
+
+abc123  app.iso
+- -escaped line
+-----BEGIN PGP SIGNATURE-----
+
+iQEzBAEBCAAdFiEE
+-----END PGP SIGNATURE-----
+";
+        let (body, is_signed) = strip_clearsign(signed);
+        assert!(is_signed);
+        assert_eq!(body, "abc123  app.iso\n-escaped line");
+    }
+
+    #[tokio::test]
+    async fn test_generate_sri_local_file() {
+        let path = std::env::temp_dir().join(format!("devtools-sri-{}.js", std::process::id()));
+        std::fs::write(&path, "alert('Hello, world.');").unwrap();
+        let result = generate_sri(GenerateSriRequest {
+            source: path.to_string_lossy().to_string(),
+            algorithms: Some(vec!["sha384".to_string(), "SHA-256".to_string()]),
+            crossorigin: None,
+        })
+        .await
+        .unwrap();
+        std::fs::remove_file(&path).ok();
+
+        // MDN 示例: alert('Hello, world.'); 的 sha384 摘要
+        assert_eq!(
+            result.hashes[0].integrity,
+            "sha384-H8BRh8j48O9oYatfu5AZzq6A9RINhZO5H16dQZngK7T62em8MUt1FLm52t+eX6xO"
+        );
+        assert!(result.integrity.contains(" sha256-"));
+        assert_eq!(result.resource_type, "script");
+        let tag = result.tag.unwrap();
+        assert!(tag.starts_with("<script src=\"devtools-sri-"));
+        assert!(tag.ends_with(r#"crossorigin="anonymous"></script>"#));
+    }
+
+    #[test]
+    fn test_algorithm_detection() {
+        assert_eq!(algorithm_from_length(64), Some("sha256"));
+        assert_eq!(
+            algorithm_from_file_name("/tmp/SHA512SUMS").as_deref(),
+            Some("sha512")
+        );
+        assert_eq!(
+            algorithm_from_file_name("ubuntu.iso.md5").as_deref(),
+            Some("md5")
+        );
+        assert_eq!(
+            split_digest_prefix("SHA-256:ABCD"),
+            (Some("sha256".to_string()), "ABCD".to_string())
+        );
+    }
+}
@@ -0,0 +1,482 @@
+use crate::tools::network_probe::{ping_host, resolve_target, to_ms, PingRequest};
+use crate::utils::proxy;
+use serde::{Deserialize, Serialize};
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+/// GCP 各区域的 Cloud Run 探测端点由 gcping 维护，前端是 Anycast，需要用 HTTPS 往返时间测量
+const GCPING_ENDPOINTS_URL: &str = "https://global.gcping.com/api/endpoints";
+const SUPPORTED_PROVIDERS: &[&str] = &["aws", "gcp", "azure", "aliyun", "tencent"];
+/// 同时测量的端点数，过多会互相影响结果
+const MAX_PARALLEL: usize = 8;
+
+/// 区域代码和所在地
+type Regions = &'static [(&'static str, &'static str)];
+
+/// 内置端点：云厂商、区域内服务的主机名模板、区域列表
+const CLOUD_REGIONS: &[(&str, &str, Regions)] = &[
+    (
+        "aws",
+        "dynamodb.{region}.amazonaws.com",
+        &[
+            ("us-east-1", "美国弗吉尼亚北部"),
+            ("us-west-2", "美国俄勒冈"),
+            ("eu-west-1", "爱尔兰"),
+            ("eu-central-1", "德国法兰克福"),
+            ("ap-northeast-1", "日本东京"),
+            ("ap-southeast-1", "新加坡"),
+            ("ap-east-1", "中国香港"),
+            ("ap-south-1", "印度孟买"),
+            ("sa-east-1", "巴西圣保罗"),
+        ],
+    ),
+    (
+        "azure",
+        "{region}.api.cognitive.microsoft.com",
+        &[
+            ("eastus", "美国东部"),
+            ("westus2", "美国西部 2"),
+            ("westeurope", "荷兰"),
+            ("southeastasia", "新加坡"),
+            ("eastasia", "中国香港"),
+            ("japaneast", "日本东京"),
+        ],
+    ),
+    (
+        "aliyun",
+        "oss-{region}.aliyuncs.com",
+        &[
+            ("cn-hangzhou", "杭州"),
+            ("cn-shanghai", "上海"),
+            ("cn-beijing", "北京"),
+            ("cn-shenzhen", "深圳"),
+            ("cn-hongkong", "中国香港"),
+            ("ap-southeast-1", "新加坡"),
+            ("us-west-1", "美国硅谷"),
+            ("eu-central-1", "德国法兰克福"),
+        ],
+    ),
+    (
+        "tencent",
+        "cos.{region}.myqcloud.com",
+        &[
+            ("ap-guangzhou", "广州"),
+            ("ap-shanghai", "上海"),
+            ("ap-beijing", "北京"),
+            ("ap-hongkong", "中国香港"),
+            ("ap-singapore", "新加坡"),
+        ],
+    ),
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CloudEndpoint {
+    pub provider: String,
+    pub region: String,
+    pub location: Option<String>,
+    pub host: String, // 主机名或完整 URL
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CloudLatencyRequest {
+    pub providers: Option<Vec<String>>, // aws, gcp, azure, aliyun, tencent，为空时全部测量
+    pub method: Option<String>,         // https（默认，复用连接测往返）/ tcp（握手耗时）/ icmp
+    pub attempts: Option<u32>,          // 每个端点测量次数，默认 3
+    pub timeout_ms: Option<u64>,
+    pub custom_endpoints: Option<Vec<CloudEndpoint>>, // 额外测量的自定义端点
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegionLatency {
+    pub endpoint: CloudEndpoint,
+    pub ip: Option<String>,
+    pub samples: Vec<Option<f64>>, // 每次测量的毫秒数，失败为空
+    pub min_ms: Option<f64>,
+    pub avg_ms: Option<f64>,
+    pub loss_percent: f64,
+    pub rank: Option<usize>, // 按平均延迟的排名，从 1 开始
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CloudLatencyReport {
+    pub method: String,
+    pub results: Vec<RegionLatency>, // 按延迟从低到高排序，不可达的在最后
+    pub fastest: Vec<RegionLatency>, // 每家云厂商延迟最低的区域
+    pub warnings: Vec<String>,
+}
+
+/// 列出内置的云厂商区域端点
+#[tauri::command]
+pub fn list_cloud_endpoints() -> Vec<CloudEndpoint> {
+    CLOUD_REGIONS
+        .iter()
+        .flat_map(|(provider, host, regions)| {
+            regions.iter().map(move |(region, location)| CloudEndpoint {
+                provider: provider.to_string(),
+                region: region.to_string(),
+                location: Some(location.to_string()),
+                host: host.replace("{region}", region),
+            })
+        })
+        .collect()
+}
+
+/// 测量到各云厂商区域的延迟并排名，辅助选择部署区域
+#[tauri::command]
+pub async fn measure_cloud_latency(
+    request: CloudLatencyRequest,
+) -> Result<CloudLatencyReport, String> {
+    let method = request.method.as_deref().unwrap_or("https").to_lowercase();
+    if !matches!(method.as_str(), "https" | "tcp" | "icmp") {
+        return Err(format!("不支持的测量方式: {}", method));
+    }
+    let providers = normalize_providers(request.providers)?;
+    let attempts = request.attempts.unwrap_or(3).clamp(1, 10);
+    let timeout = Duration::from_millis(request.timeout_ms.unwrap_or(3000).clamp(200, 15000));
+    let mut warnings = Vec::new();
+
+    let mut endpoints: Vec<CloudEndpoint> = list_cloud_endpoints()
+        .into_iter()
+        .filter(|e| providers.contains(&e.provider))
+        .collect();
+    if providers.iter().any(|p| p == "gcp") {
+        match fetch_gcp_endpoints().await {
+            Ok(gcp) => endpoints.extend(gcp),
+            Err(e) => warnings.push(format!("获取 GCP 区域列表失败: {}", e)),
+        }
+        if method != "https" {
+            warnings.push(
+                "GCP 端点经 Google 前端 Anycast 接入，TCP/ICMP 测到的是最近的边缘节点而非区域本身"
+                    .to_string(),
+            );
+        }
+    }
+    endpoints.extend(request.custom_endpoints.unwrap_or_default());
+    if endpoints.is_empty() {
+        return Err("没有可测量的端点".to_string());
+    }
+
+    // HTTPS 测量同样走代理设置，需要直连测量时可在设置中为 cloud_latency 关闭代理
+    let client = proxy::client_builder("cloud_latency")
+        .timeout(timeout)
+        .build()
+        .map_err(|e| format!("构建 HTTP 客户端失败: {}", e))?;
+    let semaphore = Arc::new(Semaphore::new(MAX_PARALLEL));
+    let mut tasks = JoinSet::new();
+    for (index, endpoint) in endpoints.into_iter().enumerate() {
+        let semaphore = semaphore.clone();
+        let client = client.clone();
+        let method = method.clone();
+        tasks.spawn(async move {
+            let _permit = semaphore.acquire_owned().await;
+            let outcome = match method.as_str() {
+                "tcp" => measure_tcp(&endpoint.host, attempts, timeout).await,
+                "icmp" => measure_icmp(&endpoint.host, attempts, timeout).await,
+                _ => measure_https(&client, &endpoint.host, attempts).await,
+            };
+            (index, endpoint, outcome)
+        });
+    }
+    let mut finished = tasks.join_all().await;
+    finished.sort_by_key(|(index, ..)| *index);
+
+    let mut results: Vec<RegionLatency> = finished
+        .into_iter()
+        .map(|(_, endpoint, outcome)| match outcome {
+            Ok((ip, samples)) => summarize(endpoint, ip, samples),
+            Err(error) => RegionLatency {
+                endpoint,
+                ip: None,
+                samples: Vec::new(),
+                min_ms: None,
+                avg_ms: None,
+                loss_percent: 100.0,
+                rank: None,
+                error: Some(error),
+            },
+        })
+        .collect();
+    rank_results(&mut results);
+
+    let mut fastest: Vec<RegionLatency> = Vec::new();
+    for result in results.iter().filter(|r| r.rank.is_some()) {
+        if !fastest
+            .iter()
+            .any(|f| f.endpoint.provider == result.endpoint.provider)
+        {
+            fastest.push(result.clone());
+        }
+    }
+
+    Ok(CloudLatencyReport {
+        method,
+        results,
+        fastest,
+        warnings,
+    })
+}
+
+fn normalize_providers(providers: Option<Vec<String>>) -> Result<Vec<String>, String> {
+    let providers: Vec<String> = providers
+        .unwrap_or_default()
+        .iter()
+        .map(|p| p.trim().to_lowercase())
+        .filter(|p| !p.is_empty())
+        .collect();
+    if providers.is_empty() {
+        return Ok(SUPPORTED_PROVIDERS.iter().map(|p| p.to_string()).collect());
+    }
+    for provider in &providers {
+        if !SUPPORTED_PROVIDERS.contains(&provider.as_str()) {
+            return Err(format!("不支持的云厂商: {}", provider));
+        }
+    }
+    Ok(providers)
+}
+
+async fn fetch_gcp_endpoints() -> Result<Vec<CloudEndpoint>, String> {
+    let client = proxy::client_builder("cloud_latency")
+        .timeout(Duration::from_secs(10))
+        .build()
+        .map_err(|e| format!("构建 HTTP 客户端失败: {}", e))?;
+    let body = client
+        .get(GCPING_ENDPOINTS_URL)
+        .send()
+        .await
+        .map_err(|e| format!("请求失败: {}", e))?
+        .text()
+        .await
+        .map_err(|e| format!("读取响应失败: {}", e))?;
+    parse_gcping_endpoints(&body)
+}
+
+/// 解析 gcping 的区域列表：{"us-central1": {"Region": ..., "RegionName": ..., "URL": ...}}
+fn parse_gcping_endpoints(body: &str) -> Result<Vec<CloudEndpoint>, String> {
+    let value: serde_json::Value =
+        serde_json::from_str(body).map_err(|e| format!("解析区域列表失败: {}", e))?;
+    let map = value.as_object().ok_or("区域列表格式无效")?;
+    let mut endpoints: Vec<CloudEndpoint> = map
+        .iter()
+        .filter(|(region, _)| region.as_str() != "global")
+        .filter_map(|(region, info)| {
+            let url = info.get("URL").and_then(|v| v.as_str())?;
+            Some(CloudEndpoint {
+                provider: "gcp".to_string(),
+                region: region.clone(),
+                location: info
+                    .get("RegionName")
+                    .and_then(|v| v.as_str())
+                    .map(String::from),
+                host: format!("{}/api/ping", url.trim_end_matches('/')),
+            })
+        })
+        .collect();
+    endpoints.sort_by(|a, b| a.region.cmp(&b.region));
+    Ok(endpoints)
+}
+
+fn host_of(target: &str) -> String {
+    match reqwest::Url::parse(target) {
+        Ok(url) if target.contains("://") => url.host_str().unwrap_or(target).to_string(),
+        _ => target.to_string(),
+    }
+}
+
+async fn measure_tcp(
+    target: &str,
+    attempts: u32,
+    timeout: Duration,
+) -> Result<(Option<IpAddr>, Vec<Option<f64>>), String> {
+    let host = host_of(target);
+    let ip = tokio::task::spawn_blocking(move || resolve_target(&host, None))
+        .await
+        .map_err(|e| format!("解析任务失败: {}", e))??;
+    let mut samples = Vec::new();
+    for _ in 0..attempts {
+        let start = Instant::now();
+        let connected = tokio::time::timeout(
+            timeout,
+            tokio::net::TcpStream::connect(SocketAddr::new(ip, 443)),
+        )
+        .await;
+        samples.push(match connected {
+            Ok(Ok(_)) => Some(to_ms(start.elapsed())),
+            _ => None,
+        });
+    }
+    Ok((Some(ip), samples))
+}
+
+async fn measure_icmp(
+    target: &str,
+    attempts: u32,
+    timeout: Duration,
+) -> Result<(Option<IpAddr>, Vec<Option<f64>>), String> {
+    let result = ping_host(PingRequest {
+        host: host_of(target),
+        protocol: None,
+        count: Some(attempts),
+        interval_ms: Some(200),
+        timeout_ms: Some(timeout.as_millis() as u64),
+        payload_size: None,
+        port: None,
+        ip_version: None,
+    })
+    .await?;
+    let samples = result
+        .replies
+        .iter()
+        .map(|r| r.rtt_ms.filter(|_| r.status == "reply"))
+        .collect();
+    Ok((result.ip.parse().ok(), samples))
+}
+
+/// 先发一次请求建立连接，之后在同一连接上测量请求往返时间
+async fn measure_https(
+    client: &reqwest::Client,
+    target: &str,
+    attempts: u32,
+) -> Result<(Option<IpAddr>, Vec<Option<f64>>), String> {
+    let url = if target.contains("://") {
+        target.to_string()
+    } else {
+        format!("https://{}/", target)
+    };
+    let warmup = client
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| format!("连接失败: {}", e))?;
+    let ip = warmup.remote_addr().map(|addr| addr.ip());
+    let _ = warmup.bytes().await;
+
+    let mut samples = Vec::new();
+    for _ in 0..attempts {
+        let start = Instant::now();
+        let sample = match client.get(&url).send().await {
+            Ok(response) => {
+                let _ = response.bytes().await;
+                Some(to_ms(start.elapsed()))
+            }
+            Err(_) => None,
+        };
+        samples.push(sample);
+    }
+    Ok((ip, samples))
+}
+
+fn summarize(
+    endpoint: CloudEndpoint,
+    ip: Option<IpAddr>,
+    samples: Vec<Option<f64>>,
+) -> RegionLatency {
+    let values: Vec<f64> = samples.iter().flatten().copied().collect();
+    let round = |v: f64| (v * 1000.0).round() / 1000.0;
+    let avg_ms =
+        (!values.is_empty()).then(|| round(values.iter().sum::<f64>() / values.len() as f64));
+    let loss_percent = if samples.is_empty() {
+        100.0
+    } else {
+        ((samples.len() - values.len()) as f64 / samples.len() as f64 * 10000.0).round() / 100.0
+    };
+    RegionLatency {
+        endpoint,
+        ip: ip.map(|ip| ip.to_string()),
+        min_ms: values.iter().copied().reduce(f64::min),
+        avg_ms,
+        loss_percent,
+        rank: None,
+        error: avg_ms.is_none().then(|| "所有测量均超时或失败".to_string()),
+        samples,
+    }
+}
+
+/// 按平均延迟排序并编号，不可达的端点排在最后且没有排名
+fn rank_results(results: &mut [RegionLatency]) {
+    results.sort_by(|a, b| match (a.avg_ms, b.avg_ms) {
+        (Some(x), Some(y)) => x.total_cmp(&y),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    });
+    for (index, result) in results.iter_mut().enumerate() {
+        result.rank = result.avg_ms.map(|_| index + 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoints_and_providers() {
+        let endpoints = list_cloud_endpoints();
+        for provider in ["aws", "azure", "aliyun", "tencent"] {
+            assert!(endpoints.iter().any(|e| e.provider == provider));
+        }
+        for endpoint in &endpoints {
+            assert!(SUPPORTED_PROVIDERS.contains(&endpoint.provider.as_str()));
+            assert!(endpoint.host.contains(&endpoint.region));
+        }
+
+        assert_eq!(
+            normalize_providers(None).unwrap().len(),
+            SUPPORTED_PROVIDERS.len()
+        );
+        assert_eq!(
+            normalize_providers(Some(vec![" AWS ".to_string()])).unwrap(),
+            vec!["aws"]
+        );
+        assert!(normalize_providers(Some(vec!["oracle".to_string()])).is_err());
+
+        let gcp = parse_gcping_endpoints(
+            r#"{"us-central1": {"Region": "us-central1", "RegionName": "Iowa", "URL": "https://us-central1-abc.a.run.app"},
+                "global": {"Region": "global", "URL": "https://global.gcping.com"},
+                "asia-east1": {"Region": "asia-east1", "RegionName": "Taiwan", "URL": "https://asia-east1-abc.a.run.app/"}}"#,
+        )
+        .unwrap();
+        assert_eq!(gcp.len(), 2);
+        assert_eq!(gcp[0].region, "asia-east1");
+        assert_eq!(gcp[0].host, "https://asia-east1-abc.a.run.app/api/ping");
+        assert_eq!(host_of(&gcp[1].host), "us-central1-abc.a.run.app");
+        assert_eq!(
+            host_of("oss-cn-hangzhou.aliyuncs.com"),
+            "oss-cn-hangzhou.aliyuncs.com"
+        );
+    }
+
+    #[test]
+    fn test_summarize_and_rank() {
+        let endpoint = |region: &str| CloudEndpoint {
+            provider: "aws".to_string(),
+            region: region.to_string(),
+            location: None,
+            host: format!("dynamodb.{}.amazonaws.com", region),
+        };
+        let mut results = vec![
+            summarize(endpoint("us-east-1"), None, vec![Some(210.0), Some(190.0)]),
+            summarize(endpoint("ap-east-1"), None, vec![None, None]),
+            summarize(
+                endpoint("ap-northeast-1"),
+                None,
+                vec![Some(40.0), None, Some(50.0), Some(45.0)],
+            ),
+        ];
+        assert_eq!(results[0].avg_ms, Some(200.0));
+        assert_eq!(results[0].min_ms, Some(190.0));
+        assert_eq!(results[1].loss_percent, 100.0);
+        assert!(results[1].error.is_some());
+        assert_eq!(results[2].loss_percent, 25.0);
+
+        rank_results(&mut results);
+        assert_eq!(results[0].endpoint.region, "ap-northeast-1");
+        assert_eq!(results[0].rank, Some(1));
+        assert_eq!(results[1].rank, Some(2));
+        assert_eq!(results[2].endpoint.region, "ap-east-1");
+        assert_eq!(results[2].rank, None);
+    }
+}
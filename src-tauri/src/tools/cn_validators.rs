@@ -0,0 +1,527 @@
+use crate::tools::fake_finance::{
+    card_brand, luhn_check_digit, org_code_check_char, uscc_check_char, USCC_CHARSET,
+};
+use crate::utils::proxy;
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// 身份证号前 17 位的加权系数
+const ID_WEIGHTS: [u32; 17] = [7, 9, 10, 5, 8, 4, 2, 1, 6, 3, 7, 9, 10, 5, 8, 4, 2];
+/// 加权和模 11 后对应的校验码
+const ID_CHECK_CHARS: &[u8; 11] = b"10X98765432";
+
+/// 省级行政区划代码（身份证和统一社会信用代码通用）
+const PROVINCES: &[(&str, &str)] = &[
+    ("11", "北京市"),
+    ("12", "天津市"),
+    ("13", "河北省"),
+    ("14", "山西省"),
+    ("15", "内蒙古自治区"),
+    ("21", "辽宁省"),
+    ("22", "吉林省"),
+    ("23", "黑龙江省"),
+    ("31", "上海市"),
+    ("32", "江苏省"),
+    ("33", "浙江省"),
+    ("34", "安徽省"),
+    ("35", "福建省"),
+    ("36", "江西省"),
+    ("37", "山东省"),
+    ("41", "河南省"),
+    ("42", "湖北省"),
+    ("43", "湖南省"),
+    ("44", "广东省"),
+    ("45", "广西壮族自治区"),
+    ("46", "海南省"),
+    ("50", "重庆市"),
+    ("51", "四川省"),
+    ("52", "贵州省"),
+    ("53", "云南省"),
+    ("54", "西藏自治区"),
+    ("61", "陕西省"),
+    ("62", "甘肃省"),
+    ("63", "青海省"),
+    ("64", "宁夏回族自治区"),
+    ("65", "新疆维吾尔自治区"),
+    ("71", "台湾省"),
+    ("81", "香港特别行政区"),
+    ("82", "澳门特别行政区"),
+    ("83", "台湾居民居住证"),
+];
+
+/// 机构类别代码和名称
+type OrgTypes = &'static [(char, &'static str)];
+
+/// 统一社会信用代码的登记管理部门与机构类别（GB 32100 附录）
+const USCC_AUTHORITIES: &[(char, &str, OrgTypes)] = &[
+    (
+        '1',
+        "机构编制",
+        &[
+            ('1', "机关"),
+            ('2', "事业单位"),
+            ('3', "中央编办直接管理机构编制的群众团体"),
+            ('9', "其他"),
+        ],
+    ),
+    ('2', "外交", &[('1', "外国常驻新闻机构"), ('9', "其他")]),
+    (
+        '3',
+        "司法行政",
+        &[
+            ('1', "律师执业机构"),
+            ('2', "公证处"),
+            ('3', "基层法律服务所"),
+            ('4', "司法鉴定机构"),
+            ('5', "仲裁委员会"),
+            ('9', "其他"),
+        ],
+    ),
+    ('4', "文化", &[('1', "外国在华文化中心"), ('9', "其他")]),
+    (
+        '5',
+        "民政",
+        &[
+            ('1', "社会团体"),
+            ('2', "民办非企业单位"),
+            ('3', "基金会"),
+            ('9', "其他"),
+        ],
+    ),
+    (
+        '6',
+        "旅游",
+        &[
+            ('1', "外国旅游部门常驻代表机构"),
+            ('2', "港澳台地区旅游部门常驻内地（大陆）代表机构"),
+            ('9', "其他"),
+        ],
+    ),
+    (
+        '7',
+        "宗教",
+        &[('1', "宗教活动场所"), ('2', "宗教院校"), ('9', "其他")],
+    ),
+    ('8', "工会", &[('1', "基层工会"), ('9', "其他")]),
+    (
+        '9',
+        "工商",
+        &[('1', "企业"), ('2', "个体工商户"), ('3', "农民专业合作社")],
+    ),
+    (
+        'A',
+        "中央军委改革和编制办公室",
+        &[('1', "军队事业单位"), ('9', "其他")],
+    ),
+    (
+        'N',
+        "农业",
+        &[
+            ('1', "组级集体经济组织"),
+            ('2', "村级集体经济组织"),
+            ('3', "乡镇级集体经济组织"),
+            ('9', "其他"),
+        ],
+    ),
+    ('Y', "其他", &[('1', "其他")]),
+];
+
+/// 常见借记卡 BIN 与发卡行，联网查询不可用时兜底
+const BANK_BINS: &[(&str, &str, &str)] = &[
+    ("622202", "ICBC", "借记卡"),
+    ("621226", "ICBC", "借记卡"),
+    ("622848", "ABC", "借记卡"),
+    ("622700", "CCB", "借记卡"),
+    ("621700", "CCB", "借记卡"),
+    ("436742", "CCB", "借记卡"),
+    ("456351", "BOC", "借记卡"),
+    ("601382", "BOC", "借记卡"),
+    ("622260", "COMM", "借记卡"),
+    ("622588", "CMB", "借记卡"),
+    ("621483", "CMB", "借记卡"),
+    ("621799", "PSBC", "借记卡"),
+    ("622908", "CIB", "借记卡"),
+];
+
+/// 银行代码与名称
+const BANK_NAMES: &[(&str, &str)] = &[
+    ("ICBC", "中国工商银行"),
+    ("ABC", "中国农业银行"),
+    ("CCB", "中国建设银行"),
+    ("BOC", "中国银行"),
+    ("COMM", "交通银行"),
+    ("CMB", "招商银行"),
+    ("PSBC", "中国邮政储蓄银行"),
+    ("CIB", "兴业银行"),
+    ("SPDB", "浦发银行"),
+    ("CMBC", "中国民生银行"),
+    ("CITIC", "中信银行"),
+    ("CEB", "中国光大银行"),
+    ("HXBANK", "华夏银行"),
+    ("GDB", "广发银行"),
+    ("SPABANK", "平安银行"),
+    ("BJBANK", "北京银行"),
+    ("SHBANK", "上海银行"),
+];
+
+/// 支付宝公开的卡 BIN 查询接口
+const CARD_BIN_API: &str = "https://ccdcapi.alipay.com/validateAndCacheCardInfo.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CnIdCardInfo {
+    pub normalized: String, // 18 位号码，15 位老号码会升位
+    pub valid: bool,
+    pub upgraded_from_15: bool,
+    pub region_code: String,
+    pub province: Option<String>,
+    pub birthday: Option<String>, // YYYY-MM-DD
+    pub age: Option<u32>,
+    pub gender: Option<String>,
+    pub check_digit: Option<String>,
+    pub expected_check_digit: Option<String>,
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UsccInfo {
+    pub normalized: String,
+    pub valid: bool,
+    pub authority: Option<String>, // 登记管理部门
+    pub org_type: Option<String>,  // 机构类别
+    pub region_code: String,
+    pub province: Option<String>,
+    pub org_code: String, // 组织机构代码，格式 XXXXXXXX-X
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BankCardRequest {
+    pub card_number: String,
+    pub online: Option<bool>, // 是否联网查询发卡行，会把卡号发送到第三方接口，默认关闭
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BankCardInfo {
+    pub normalized: String,
+    pub luhn_valid: bool,
+    pub network: Option<String>, // unionpay, visa 等卡组织
+    pub bank_code: Option<String>,
+    pub bank_name: Option<String>,
+    pub card_type: Option<String>,
+    pub source: Option<String>, // local 或 online
+    pub errors: Vec<String>,
+}
+
+/// 校验身份证号并解析地区、出生日期、性别
+#[tauri::command]
+pub fn validate_cn_id_card(id: String) -> Result<CnIdCardInfo, String> {
+    let mut normalized: String = id
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_uppercase();
+    if !normalized.is_ascii() {
+        return Err("身份证号只能包含数字和 X".to_string());
+    }
+    let upgraded_from_15 = normalized.len() == 15;
+    if upgraded_from_15 {
+        if !normalized.chars().all(|c| c.is_ascii_digit()) {
+            return Err("15 位身份证号只能包含数字".to_string());
+        }
+        // 15 位号码的年份只有两位，且都在 1900 年代
+        let body = format!("{}19{}", &normalized[..6], &normalized[6..]);
+        normalized = format!("{}{}", body, id_check_char(&body).unwrap());
+    }
+    if normalized.len() != 18 {
+        return Err("身份证号应为 18 位或 15 位".to_string());
+    }
+    if !normalized[..17].chars().all(|c| c.is_ascii_digit())
+        || !normalized[17..]
+            .chars()
+            .all(|c| c.is_ascii_digit() || c == 'X')
+    {
+        return Err("身份证号前 17 位应为数字，最后一位为数字或 X".to_string());
+    }
+
+    let mut errors = Vec::new();
+    let region_code = normalized[..6].to_string();
+    let province = province_name(&region_code);
+    if province.is_none() {
+        errors.push(format!("{} 不是有效的省级行政区划代码", &region_code[..2]));
+    }
+
+    let today = chrono::Local::now().date_naive();
+    let birthday = NaiveDate::parse_from_str(&normalized[6..14], "%Y%m%d").ok();
+    match birthday {
+        None => errors.push(format!("出生日期 {} 无效", &normalized[6..14])),
+        Some(date) if date > today => errors.push("出生日期晚于今天".to_string()),
+        Some(date) if date.year() < 1900 => errors.push("出生日期早于 1900 年".to_string()),
+        Some(_) => {}
+    }
+    let age = birthday.filter(|d| *d <= today).map(|d| {
+        let mut age = today.year() - d.year();
+        if (today.month(), today.day()) < (d.month(), d.day()) {
+            age -= 1;
+        }
+        age as u32
+    });
+
+    let gender = normalized[16..17]
+        .parse::<u32>()
+        .ok()
+        .map(|d| if d % 2 == 1 { "男" } else { "女" }.to_string());
+    let expected = id_check_char(&normalized[..17]);
+    let check_digit = normalized[17..].to_string();
+    if expected.map(|c| c.to_string()) != Some(check_digit.clone()) {
+        errors.push("校验码不正确".to_string());
+    }
+
+    Ok(CnIdCardInfo {
+        valid: errors.is_empty(),
+        upgraded_from_15,
+        region_code,
+        province,
+        birthday: birthday.map(|d| d.format("%Y-%m-%d").to_string()),
+        age,
+        gender,
+        check_digit: Some(check_digit),
+        expected_check_digit: expected.map(|c| c.to_string()),
+        errors,
+        normalized,
+    })
+}
+
+/// 校验统一社会信用代码并解析登记部门、机构类别和地区
+#[tauri::command]
+pub fn decode_uscc(code: String) -> Result<UsccInfo, String> {
+    let normalized: String = code
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .collect::<String>()
+        .to_uppercase();
+    if normalized.chars().count() != 18 {
+        return Err("统一社会信用代码应为 18 位".to_string());
+    }
+    if !normalized.is_ascii() {
+        return Err("统一社会信用代码只能包含数字和大写字母".to_string());
+    }
+    if let Some(c) = normalized.chars().find(|c| !USCC_CHARSET.contains(*c)) {
+        return Err(format!(
+            "包含不允许的字符 {}（代码中不使用 I、O、Z、S、V）",
+            c
+        ));
+    }
+
+    let mut errors = Vec::new();
+    let mut chars = normalized.chars();
+    let authority_char = chars.next().unwrap();
+    let type_char = chars.next().unwrap();
+    let authority = USCC_AUTHORITIES
+        .iter()
+        .find(|(code, ..)| *code == authority_char);
+    let org_type = authority.and_then(|(_, _, types)| {
+        types
+            .iter()
+            .find(|(code, _)| *code == type_char)
+            .map(|(_, name)| name.to_string())
+    });
+    match authority {
+        None => errors.push(format!("{} 不是有效的登记管理部门代码", authority_char)),
+        Some((_, name, _)) if org_type.is_none() => {
+            errors.push(format!("{} 不是{}部门下有效的机构类别", type_char, name))
+        }
+        _ => {}
+    }
+
+    let region_code = normalized[2..8].to_string();
+    let province = province_name(&region_code);
+    if !region_code.chars().all(|c| c.is_ascii_digit()) || province.is_none() {
+        errors.push(format!("{} 不是有效的行政区划代码", region_code));
+    }
+    if org_code_check_char(&normalized[8..16]) != normalized[16..17].chars().next() {
+        errors.push("组织机构代码校验位不正确".to_string());
+    }
+    if uscc_check_char(&normalized[..17]) != normalized[17..].chars().next() {
+        errors.push("统一社会信用代码校验位不正确".to_string());
+    }
+
+    Ok(UsccInfo {
+        valid: errors.is_empty(),
+        authority: authority.map(|(_, name, _)| name.to_string()),
+        org_type,
+        org_code: format!("{}-{}", &normalized[8..16], &normalized[16..17]),
+        region_code,
+        province,
+        errors,
+        normalized,
+    })
+}
+
+/// 校验银行卡号并查询发卡行，优先使用内置 BIN 表
+#[tauri::command]
+pub async fn lookup_bank_card(request: BankCardRequest) -> Result<BankCardInfo, String> {
+    let normalized: String = request
+        .card_number
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .collect();
+    if !normalized.chars().all(|c| c.is_ascii_digit()) || !(12..=19).contains(&normalized.len()) {
+        return Err("银行卡号应为 12~19 位数字".to_string());
+    }
+    let mut info = lookup_local_bin(&normalized);
+
+    if request.online.unwrap_or(false) {
+        match lookup_online_bin(&normalized).await {
+            Ok(Some((bank_code, card_type))) => {
+                info.bank_name = bank_name(&bank_code).or(info.bank_name);
+                info.bank_code = Some(bank_code);
+                info.card_type = Some(card_type);
+                info.source = Some("online".to_string());
+            }
+            Ok(None) => info.errors.push("联网查询未找到该卡号的发卡行".to_string()),
+            Err(e) => info.errors.push(format!("联网查询失败: {}", e)),
+        }
+    }
+    Ok(info)
+}
+
+fn id_check_char(body: &str) -> Option<char> {
+    let mut sum = 0;
+    for (c, weight) in body.chars().zip(ID_WEIGHTS) {
+        sum += c.to_digit(10)? * weight;
+    }
+    Some(char::from(ID_CHECK_CHARS[(sum % 11) as usize]))
+}
+
+fn province_name(region_code: &str) -> Option<String> {
+    PROVINCES
+        .iter()
+        .find(|(code, _)| region_code.starts_with(code))
+        .map(|(_, name)| name.to_string())
+}
+
+fn bank_name(code: &str) -> Option<String> {
+    BANK_NAMES
+        .iter()
+        .find(|(c, _)| *c == code)
+        .map(|(_, name)| name.to_string())
+}
+
+fn lookup_local_bin(number: &str) -> BankCardInfo {
+    let (body, check) = number.split_at(number.len() - 1);
+    let luhn_valid = luhn_check_digit(body).to_string() == check;
+    let mut errors = Vec::new();
+    if !luhn_valid {
+        // 部分银联借记卡不遵循 Luhn，只作提示
+        errors.push("Luhn 校验未通过，卡号可能有误".to_string());
+    }
+    let bin = BANK_BINS.iter().find(|(bin, ..)| number.starts_with(bin));
+    BankCardInfo {
+        normalized: number.to_string(),
+        luhn_valid,
+        network: card_brand(number).map(|(name, _)| name.to_string()),
+        bank_code: bin.map(|(_, code, _)| code.to_string()),
+        bank_name: bin.and_then(|(_, code, _)| bank_name(code)),
+        card_type: bin.map(|(.., card_type)| card_type.to_string()),
+        source: bin.map(|_| "local".to_string()),
+        errors,
+    }
+}
+
+async fn lookup_online_bin(number: &str) -> Result<Option<(String, String)>, String> {
+    let client = proxy::client_builder("cn_validators")
+        .timeout(Duration::from_secs(10))
+        .build()
+        .map_err(|e| format!("构建 HTTP 客户端失败: {}", e))?;
+    let value: serde_json::Value = client
+        .get(CARD_BIN_API)
+        .query(&[
+            ("_input_charset", "utf-8"),
+            ("cardNo", number),
+            ("cardBinCheck", "true"),
+        ])
+        .send()
+        .await
+        .map_err(|e| format!("请求失败: {}", e))?
+        .json()
+        .await
+        .map_err(|e| format!("解析响应失败: {}", e))?;
+    Ok(parse_card_bin_response(&value))
+}
+
+fn parse_card_bin_response(value: &serde_json::Value) -> Option<(String, String)> {
+    if value.get("validated").and_then(|v| v.as_bool()) != Some(true) {
+        return None;
+    }
+    let bank = value.get("bank").and_then(|v| v.as_str())?;
+    let card_type = match value.get("cardType").and_then(|v| v.as_str()) {
+        Some("DC") => "借记卡",
+        Some("CC") => "信用卡",
+        Some("SCC") => "准贷记卡",
+        Some("PC") => "预付费卡",
+        _ => "未知",
+    };
+    Some((bank.to_string(), card_type.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_cn_id_card() {
+        let info = validate_cn_id_card("11010519491231002X".to_string()).unwrap();
+        assert!(info.valid, "{:?}", info.errors);
+        assert_eq!(info.province.as_deref(), Some("北京市"));
+        assert_eq!(info.birthday.as_deref(), Some("1949-12-31"));
+        assert_eq!(info.gender.as_deref(), Some("女"));
+        assert!(info.age.unwrap() >= 75);
+
+        let bad = validate_cn_id_card("110105194912310021".to_string()).unwrap();
+        assert!(!bad.valid);
+        assert_eq!(bad.expected_check_digit.as_deref(), Some("X"));
+
+        let old = validate_cn_id_card("440524800101001".to_string()).unwrap();
+        assert!(old.upgraded_from_15);
+        assert_eq!(old.birthday.as_deref(), Some("1980-01-01"));
+        assert_eq!(old.gender.as_deref(), Some("男"));
+        assert!(old.valid, "{:?}", old.errors);
+        assert_eq!(old.normalized.len(), 18);
+
+        let invalid_date = validate_cn_id_card("110105194902300020".to_string()).unwrap();
+        assert!(invalid_date.errors.iter().any(|e| e.contains("出生日期")));
+        assert!(validate_cn_id_card("12345".to_string()).is_err());
+        assert!(validate_cn_id_card("1234567890123456中".to_string()).is_err());
+        assert!(validate_cn_id_card("12345678901234中".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_decode_uscc_and_bank_card() {
+        let info = decode_uscc("91350100M000100Y43".to_string()).unwrap();
+        assert!(info.valid, "{:?}", info.errors);
+        assert_eq!(info.authority.as_deref(), Some("工商"));
+        assert_eq!(info.org_type.as_deref(), Some("企业"));
+        assert_eq!(info.province.as_deref(), Some("福建省"));
+        assert_eq!(info.org_code, "M000100Y-4");
+        assert!(!decode_uscc("91350100M000100Y44".to_string()).unwrap().valid);
+        assert!(decode_uscc("91350100M000100YI3".to_string()).is_err());
+        assert!(decode_uscc("91350100M000100中3".to_string()).is_err());
+
+        let card = lookup_local_bin("6222020200112233445");
+        assert_eq!(card.bank_name.as_deref(), Some("中国工商银行"));
+        assert_eq!(card.network.as_deref(), Some("unionpay"));
+        assert!(lookup_local_bin("4111111111111111").luhn_valid);
+
+        let online =
+            serde_json::json!({"cardType": "CC", "bank": "CMB", "validated": true, "stat": "ok"});
+        assert_eq!(
+            parse_card_bin_response(&online),
+            Some(("CMB".to_string(), "信用卡".to_string()))
+        );
+        assert_eq!(
+            parse_card_bin_response(&serde_json::json!({"validated": false})),
+            None
+        );
+    }
+}
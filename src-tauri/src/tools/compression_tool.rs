@@ -0,0 +1,451 @@
+use base64::{engine::general_purpose, Engine as _};
+use flate2::read::{DeflateDecoder, GzDecoder, ZlibDecoder};
+use flate2::write::{GzEncoder, ZlibEncoder};
+use flate2::Compression;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::time::Instant;
+
+/// 解压结果的最大字节数，防止压缩炸弹占满内存
+const MAX_DECOMPRESSED_SIZE: u64 = 256 * 1024 * 1024;
+
+const ALGORITHMS: [&str; 5] = ["gzip", "deflate", "brotli", "zstd", "lz4"];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompressDataRequest {
+    pub direction: String,              // compress 或 decompress
+    pub input: Option<String>,          // 粘贴的内容，与 file_path 二选一
+    pub input_encoding: Option<String>, // text, hex, base64；压缩默认 text，解压默认 base64
+    pub file_path: Option<String>,
+    pub algorithms: Option<Vec<String>>, // 压缩时参与对比的算法，默认全部；解压时取第一个，auto 表示按魔数识别
+    pub levels: Option<HashMap<String, i32>>, // 按算法指定的压缩级别
+    pub include_output: Option<bool>,    // 压缩时是否返回 base64 编码的结果，默认 false
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodecResult {
+    pub algorithm: String,
+    pub content_encoding: Option<String>, // 对应的 HTTP Content-Encoding
+    pub level: Option<i32>,               // lz4 不支持级别
+    pub input_size: usize,
+    pub output_size: usize,
+    pub ratio: f64,                 // 压缩后大小 / 原始大小
+    pub duration_ms: f64,           // 本次压缩或解压耗时
+    pub round_trip_ms: Option<f64>, // 压缩后再解压校验的耗时
+    pub output: Option<String>,
+    pub output_encoding: Option<String>, // text 或 base64
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompressDataResult {
+    pub direction: String,
+    pub input_size: usize,
+    pub results: Vec<CodecResult>,
+    pub smallest: Option<String>, // 压缩后体积最小的算法
+    pub fastest: Option<String>,  // 压缩最快的算法
+    pub warnings: Vec<String>,
+}
+
+/// 用多种算法压缩或解压数据，并对比体积与耗时
+#[tauri::command]
+pub async fn compress_data(request: CompressDataRequest) -> Result<CompressDataResult, String> {
+    tokio::task::spawn_blocking(move || compress_data_blocking(&request))
+        .await
+        .map_err(|e| format!("压缩任务失败: {}", e))?
+}
+
+fn compress_data_blocking(request: &CompressDataRequest) -> Result<CompressDataResult, String> {
+    let compress = match request.direction.as_str() {
+        "compress" => true,
+        "decompress" => false,
+        other => return Err(format!("不支持的操作: {}", other)),
+    };
+    let data = read_input(request, compress)?;
+    let mut warnings = Vec::new();
+
+    let results = if compress {
+        let algorithms = match &request.algorithms {
+            Some(list) if !list.is_empty() => list.iter().map(|a| normalize(a)).collect(),
+            _ => ALGORITHMS.iter().map(|a| a.to_string()).collect::<Vec<_>>(),
+        };
+        let levels = request.levels.clone().unwrap_or_default();
+        algorithms
+            .iter()
+            .map(|algorithm| {
+                let level = levels
+                    .get(algorithm)
+                    .copied()
+                    .map(|l| clamp_level(algorithm, l, &mut warnings))
+                    .unwrap_or_else(|| default_level(algorithm));
+                compress_one(
+                    algorithm,
+                    level,
+                    &data,
+                    request.include_output.unwrap_or(false),
+                )
+            })
+            .collect::<Vec<_>>()
+    } else {
+        let algorithm = request
+            .algorithms
+            .as_ref()
+            .and_then(|list| list.first())
+            .map(|a| normalize(a))
+            .unwrap_or_else(|| "auto".to_string());
+        let algorithm = if algorithm == "auto" {
+            let detected = detect_algorithm(&data);
+            warnings.push(format!("按数据头识别为 {}", detected));
+            detected.to_string()
+        } else {
+            algorithm
+        };
+        vec![decompress_one(&algorithm, &data)]
+    };
+
+    let succeeded = || results.iter().filter(|r| r.error.is_none());
+    let (smallest, fastest) = if compress {
+        (
+            succeeded()
+                .min_by_key(|r| r.output_size)
+                .map(|r| r.algorithm.clone()),
+            succeeded()
+                .min_by(|a, b| a.duration_ms.total_cmp(&b.duration_ms))
+                .map(|r| r.algorithm.clone()),
+        )
+    } else {
+        (None, None)
+    };
+
+    Ok(CompressDataResult {
+        direction: request.direction.clone(),
+        input_size: data.len(),
+        results,
+        smallest,
+        fastest,
+        warnings,
+    })
+}
+
+fn read_input(request: &CompressDataRequest, compress: bool) -> Result<Vec<u8>, String> {
+    if let Some(path) = request.file_path.as_deref().filter(|p| !p.is_empty()) {
+        return std::fs::read(path).map_err(|e| format!("读取文件失败: {}", e));
+    }
+    let input = request.input.as_deref().ok_or("请提供输入内容或文件路径")?;
+    let default = if compress { "text" } else { "base64" };
+    match request.input_encoding.as_deref().unwrap_or(default) {
+        "text" => Ok(input.as_bytes().to_vec()),
+        "hex" => {
+            let compact: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+            hex::decode(compact).map_err(|e| format!("十六进制解析失败: {}", e))
+        }
+        "base64" => {
+            let compact: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+            general_purpose::STANDARD
+                .decode(compact)
+                .map_err(|e| format!("Base64解析失败: {}", e))
+        }
+        other => Err(format!("不支持的输入编码: {}", other)),
+    }
+}
+
+fn normalize(algorithm: &str) -> String {
+    match algorithm.trim().to_lowercase().as_str() {
+        "gz" => "gzip".to_string(),
+        "zlib" => "deflate".to_string(),
+        "br" => "brotli".to_string(),
+        "zst" => "zstd".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn level_range(algorithm: &str) -> Option<(i32, i32)> {
+    match algorithm {
+        "gzip" | "deflate" => Some((0, 9)),
+        "brotli" => Some((0, 11)),
+        "zstd" => Some((1, 22)),
+        _ => None,
+    }
+}
+
+/// 各算法的默认级别，与 gzip、brotli、zstd 命令行工具一致
+fn default_level(algorithm: &str) -> Option<i32> {
+    match algorithm {
+        "gzip" | "deflate" => Some(6),
+        "brotli" => Some(11),
+        "zstd" => Some(3),
+        _ => None,
+    }
+}
+
+fn clamp_level(algorithm: &str, level: i32, warnings: &mut Vec<String>) -> Option<i32> {
+    let Some((min, max)) = level_range(algorithm) else {
+        warnings.push(format!("{} 不支持压缩级别，已忽略", algorithm));
+        return None;
+    };
+    let clamped = level.clamp(min, max);
+    if clamped != level {
+        warnings.push(format!(
+            "{} 的级别范围为 {}-{}，{} 已调整为 {}",
+            algorithm, min, max, level, clamped
+        ));
+    }
+    Some(clamped)
+}
+
+fn content_encoding(algorithm: &str) -> Option<String> {
+    match algorithm {
+        "gzip" | "deflate" | "zstd" => Some(algorithm.to_string()),
+        "brotli" => Some("br".to_string()),
+        _ => None,
+    }
+}
+
+fn compress_one(
+    algorithm: &str,
+    level: Option<i32>,
+    data: &[u8],
+    include_output: bool,
+) -> CodecResult {
+    let mut result = CodecResult {
+        algorithm: algorithm.to_string(),
+        content_encoding: content_encoding(algorithm),
+        level,
+        input_size: data.len(),
+        output_size: 0,
+        ratio: 0.0,
+        duration_ms: 0.0,
+        round_trip_ms: None,
+        output: None,
+        output_encoding: None,
+        error: None,
+    };
+
+    let started = Instant::now();
+    let compressed = match encode(algorithm, level, data) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            result.error = Some(e);
+            return result;
+        }
+    };
+    result.duration_ms = elapsed_ms(started);
+    result.output_size = compressed.len();
+    result.ratio = if data.is_empty() {
+        0.0
+    } else {
+        compressed.len() as f64 / data.len() as f64
+    };
+
+    let started = Instant::now();
+    match decode(algorithm, &compressed) {
+        Ok(restored) if restored == data => result.round_trip_ms = Some(elapsed_ms(started)),
+        Ok(_) => result.error = Some("解压校验失败：结果与原始数据不一致".to_string()),
+        Err(e) => result.error = Some(format!("解压校验失败: {}", e)),
+    }
+
+    if include_output {
+        result.output = Some(general_purpose::STANDARD.encode(&compressed));
+        result.output_encoding = Some("base64".to_string());
+    }
+    result
+}
+
+fn decompress_one(algorithm: &str, data: &[u8]) -> CodecResult {
+    let mut result = CodecResult {
+        algorithm: algorithm.to_string(),
+        content_encoding: content_encoding(algorithm),
+        level: None,
+        input_size: data.len(),
+        output_size: 0,
+        ratio: 0.0,
+        duration_ms: 0.0,
+        round_trip_ms: None,
+        output: None,
+        output_encoding: None,
+        error: None,
+    };
+
+    let started = Instant::now();
+    match decode(algorithm, data) {
+        Ok(bytes) => {
+            result.duration_ms = elapsed_ms(started);
+            result.output_size = bytes.len();
+            result.ratio = if bytes.is_empty() {
+                0.0
+            } else {
+                data.len() as f64 / bytes.len() as f64
+            };
+            let (output, encoding) = match String::from_utf8(bytes) {
+                Ok(text) => (text, "text"),
+                Err(e) => (general_purpose::STANDARD.encode(e.into_bytes()), "base64"),
+            };
+            result.output = Some(output);
+            result.output_encoding = Some(encoding.to_string());
+        }
+        Err(e) => result.error = Some(e),
+    }
+    result
+}
+
+fn elapsed_ms(started: Instant) -> f64 {
+    started.elapsed().as_secs_f64() * 1000.0
+}
+
+fn encode(algorithm: &str, level: Option<i32>, data: &[u8]) -> Result<Vec<u8>, String> {
+    let level = level.or_else(|| default_level(algorithm)).unwrap_or(0);
+    let io_error = |e: std::io::Error| format!("{} 压缩失败: {}", algorithm, e);
+    match algorithm {
+        "gzip" => {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::new(level as u32));
+            encoder.write_all(data).map_err(io_error)?;
+            encoder.finish().map_err(io_error)
+        }
+        "deflate" => {
+            let mut encoder = ZlibEncoder::new(Vec::new(), Compression::new(level as u32));
+            encoder.write_all(data).map_err(io_error)?;
+            encoder.finish().map_err(io_error)
+        }
+        "brotli" => {
+            let mut output = Vec::new();
+            {
+                let mut writer = brotli::CompressorWriter::new(&mut output, 4096, level as u32, 22);
+                writer.write_all(data).map_err(io_error)?;
+            }
+            Ok(output)
+        }
+        "zstd" => zstd::stream::encode_all(data, level).map_err(io_error),
+        "lz4" => {
+            let mut encoder = lz4_flex::frame::FrameEncoder::new(Vec::new());
+            encoder.write_all(data).map_err(io_error)?;
+            encoder
+                .finish()
+                .map_err(|e| format!("{} 压缩失败: {}", algorithm, e))
+        }
+        other => Err(format!("不支持的算法: {}", other)),
+    }
+}
+
+fn decode(algorithm: &str, data: &[u8]) -> Result<Vec<u8>, String> {
+    match algorithm {
+        "gzip" => read_limited(GzDecoder::new(data), algorithm),
+        // HTTP 的 deflate 实际是 zlib 格式，也兼容不带头的原始 deflate 流
+        "deflate" => read_limited(ZlibDecoder::new(data), algorithm)
+            .or_else(|_| read_limited(DeflateDecoder::new(data), algorithm)),
+        "brotli" => read_limited(brotli::Decompressor::new(data, 4096), algorithm),
+        "zstd" => {
+            let decoder = zstd::stream::read::Decoder::new(data)
+                .map_err(|e| format!("{} 解压失败: {}", algorithm, e))?;
+            read_limited(decoder, algorithm)
+        }
+        "lz4" => read_limited(lz4_flex::frame::FrameDecoder::new(data), algorithm),
+        other => Err(format!("不支持的算法: {}", other)),
+    }
+}
+
+fn read_limited(reader: impl Read, algorithm: &str) -> Result<Vec<u8>, String> {
+    let mut output = Vec::new();
+    reader
+        .take(MAX_DECOMPRESSED_SIZE + 1)
+        .read_to_end(&mut output)
+        .map_err(|e| format!("{} 解压失败: {}", algorithm, e))?;
+    if output.len() as u64 > MAX_DECOMPRESSED_SIZE {
+        return Err(format!(
+            "解压后超过 {} MB，已停止",
+            MAX_DECOMPRESSED_SIZE / 1024 / 1024
+        ));
+    }
+    Ok(output)
+}
+
+/// 按魔数识别压缩格式，brotli 没有魔数，无法识别时按 brotli 尝试
+fn detect_algorithm(data: &[u8]) -> &'static str {
+    match data {
+        [0x1f, 0x8b, ..] => "gzip",
+        [0x28, 0xb5, 0x2f, 0xfd, ..] => "zstd",
+        [0x04, 0x22, 0x4d, 0x18, ..] => "lz4",
+        [cmf, flg, ..]
+            if cmf & 0x0f == 8 && (u16::from(*cmf) * 256 + u16::from(*flg)) % 31 == 0 =>
+        {
+            "deflate"
+        }
+        _ => "brotli",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(direction: &str, input: &str, encoding: &str) -> CompressDataRequest {
+        CompressDataRequest {
+            direction: direction.to_string(),
+            input: Some(input.to_string()),
+            input_encoding: Some(encoding.to_string()),
+            file_path: None,
+            algorithms: None,
+            levels: None,
+            include_output: Some(true),
+        }
+    }
+
+    #[test]
+    fn test_compress_all_and_decompress_auto() {
+        let text = "hello devtools ".repeat(200);
+        let report = compress_data_blocking(&request("compress", &text, "text")).unwrap();
+        assert_eq!(report.input_size, text.len());
+        assert_eq!(report.results.len(), 5);
+        for result in &report.results {
+            assert!(result.error.is_none(), "{:?}", result);
+            assert!(result.output_size < text.len());
+            assert!(result.round_trip_ms.is_some());
+        }
+        assert!(report.smallest.is_some());
+        let brotli = &report.results[2];
+        assert_eq!(brotli.content_encoding.as_deref(), Some("br"));
+        assert_eq!(brotli.level, Some(11));
+
+        for result in &report.results {
+            let encoded = result.output.clone().unwrap();
+            let decoded =
+                compress_data_blocking(&request("decompress", &encoded, "base64")).unwrap();
+            let restored = &decoded.results[0];
+            assert_eq!(restored.algorithm, result.algorithm);
+            assert_eq!(restored.output.as_deref(), Some(text.as_str()));
+            assert_eq!(restored.output_encoding.as_deref(), Some("text"));
+        }
+    }
+
+    #[test]
+    fn test_levels_and_errors() {
+        let mut req = request("compress", "abcabcabc", "text");
+        req.algorithms = Some(vec![
+            "gz".to_string(),
+            "zstd".to_string(),
+            "lz4".to_string(),
+        ]);
+        req.levels = Some(HashMap::from([
+            ("gzip".to_string(), 12),
+            ("zstd".to_string(), 19),
+            ("lz4".to_string(), 1),
+        ]));
+        let report = compress_data_blocking(&req).unwrap();
+        assert_eq!(report.results[0].level, Some(9));
+        assert_eq!(report.results[1].level, Some(19));
+        assert_eq!(report.results[2].level, None);
+        assert_eq!(report.warnings.len(), 2);
+        assert!(report.warnings[0].contains("0-9"));
+
+        let mut bad = request("decompress", "1f8b0000", "hex");
+        bad.algorithms = Some(vec!["gzip".to_string()]);
+        let report = compress_data_blocking(&bad).unwrap();
+        assert!(report.results[0]
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("gzip 解压失败"));
+        assert!(compress_data_blocking(&request("pack", "a", "text")).is_err());
+        assert_eq!(detect_algorithm(&[0x78, 0x9c]), "deflate");
+    }
+}
@@ -0,0 +1,766 @@
+use crate::utils::string_utils::StringUtils;
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Number, Value};
+use std::collections::HashSet;
+use std::path::Path;
+
+/// 自动检测时参与比较的分隔符
+const DELIMITER_CANDIDATES: &[u8] = b",\t;|";
+/// 检测分隔符时采样的行数
+const SNIFF_LINES: usize = 20;
+const DEFAULT_PREVIEW_ROWS: usize = 100;
+const DEFAULT_BATCH_SIZE: usize = 100;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CsvSource {
+    pub path: Option<String>,
+    pub content: Option<String>,   // 与 path 二选一，用于粘贴的文本
+    pub delimiter: Option<String>, // 为空时自动检测，支持 "\t"
+    pub quote: Option<String>,
+    pub has_header: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ColumnType {
+    Integer,
+    Float,
+    Boolean,
+    Date,
+    DateTime,
+    Text,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CsvColumn {
+    pub name: String,
+    pub column_type: ColumnType,
+    pub nullable: bool,
+    pub max_length: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CsvPreview {
+    pub delimiter: String,
+    pub quote: String,
+    pub has_header: bool,
+    pub columns: Vec<CsvColumn>,
+    pub rows: Vec<Vec<String>>,
+    pub total_rows: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConvertCsvRequest {
+    pub source: CsvSource,
+    pub format: String,              // json, xlsx, markdown
+    pub output_path: Option<String>, // xlsx 必填，其余格式为空时直接返回内容
+    pub typed: Option<bool>,         // JSON 按推断类型输出数字、布尔和 null
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConvertCsvResult {
+    pub content: Option<String>,
+    pub output_path: Option<String>,
+    pub rows: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CsvToSqlRequest {
+    pub source: CsvSource,
+    pub table_name: Option<String>,
+    pub dialect: Option<String>, // mysql, postgresql, sqlite
+    pub batch_size: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CsvToSqlResult {
+    pub table_name: String,
+    pub create_table: String,
+    pub inserts: String,
+    pub columns: Vec<CsvColumn>,
+    pub rows: usize,
+}
+
+/// 解析后的表格数据
+pub(crate) struct CsvTable {
+    pub(crate) delimiter: u8,
+    pub(crate) quote: u8,
+    pub(crate) has_header: bool,
+    pub(crate) columns: Vec<CsvColumn>,
+    pub(crate) rows: Vec<Vec<String>>,
+}
+
+/// 解析 CSV/TSV 并推断列类型
+#[tauri::command]
+pub async fn preview_csv(source: CsvSource, limit: Option<usize>) -> Result<CsvPreview, String> {
+    let table = parse_table(&source)?;
+    Ok(CsvPreview {
+        delimiter: display_byte(table.delimiter),
+        quote: display_byte(table.quote),
+        has_header: table.has_header,
+        columns: table.columns,
+        total_rows: table.rows.len(),
+        rows: table
+            .rows
+            .into_iter()
+            .take(limit.unwrap_or(DEFAULT_PREVIEW_ROWS))
+            .collect(),
+    })
+}
+
+/// 将 CSV 转换为 JSON、Excel 或 Markdown 表格
+#[tauri::command]
+pub async fn convert_csv(request: ConvertCsvRequest) -> Result<ConvertCsvResult, String> {
+    let table = parse_table(&request.source)?;
+    let rows = table.rows.len();
+
+    let content = match request.format.to_lowercase().as_str() {
+        "json" => {
+            let value = to_json(&table, request.typed.unwrap_or(true));
+            serde_json::to_string_pretty(&value).map_err(|e| format!("生成 JSON 失败: {}", e))?
+        }
+        "markdown" | "md" => to_markdown(&table),
+        "xlsx" | "excel" => {
+            let output = request
+                .output_path
+                .filter(|p| !p.trim().is_empty())
+                .ok_or("导出 Excel 需要指定输出文件")?;
+            write_xlsx(&table, &output)?;
+            return Ok(ConvertCsvResult {
+                content: None,
+                output_path: Some(output),
+                rows,
+            });
+        }
+        other => return Err(format!("不支持的输出格式: {}", other)),
+    };
+
+    match request.output_path.filter(|p| !p.trim().is_empty()) {
+        Some(output) => {
+            std::fs::write(&output, &content).map_err(|e| format!("写入文件失败: {}", e))?;
+            Ok(ConvertCsvResult {
+                content: None,
+                output_path: Some(output),
+                rows,
+            })
+        }
+        None => Ok(ConvertCsvResult {
+            content: Some(content),
+            output_path: None,
+            rows,
+        }),
+    }
+}
+
+/// 根据 CSV 生成 CREATE TABLE 和 INSERT 语句，可直接交给 SQL 转 Go 使用
+#[tauri::command]
+pub async fn csv_to_sql(request: CsvToSqlRequest) -> Result<CsvToSqlResult, String> {
+    let table = parse_table(&request.source)?;
+    let dialect = SqlDialect::parse(request.dialect.as_deref().unwrap_or("mysql"))?;
+
+    let default_name = request
+        .source
+        .path
+        .as_deref()
+        .and_then(|p| Path::new(p).file_stem())
+        .and_then(|s| s.to_str())
+        .unwrap_or("csv_data")
+        .to_string();
+    let table_name = sanitize_identifier(
+        request
+            .table_name
+            .as_deref()
+            .filter(|s| !s.trim().is_empty())
+            .unwrap_or(&default_name),
+        "csv_data",
+    );
+
+    let batch_size = request.batch_size.unwrap_or(DEFAULT_BATCH_SIZE).max(1);
+    Ok(CsvToSqlResult {
+        create_table: generate_create_table(&table, &table_name, dialect),
+        inserts: generate_inserts(&table, &table_name, dialect, batch_size),
+        rows: table.rows.len(),
+        columns: table.columns,
+        table_name,
+    })
+}
+
+pub(crate) fn parse_table(source: &CsvSource) -> Result<CsvTable, String> {
+    let content = read_source(source)?;
+    let delimiter = match source.delimiter.as_deref().filter(|d| !d.is_empty()) {
+        Some(d) => parse_byte_option(d, "分隔符")?,
+        None => detect_delimiter(&content),
+    };
+    let quote = match source.quote.as_deref().filter(|q| !q.is_empty()) {
+        Some(q) => parse_byte_option(q, "引号")?,
+        None => detect_quote(&content, delimiter),
+    };
+
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .quote(quote)
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(content.as_bytes());
+    let mut records = Vec::new();
+    for (index, record) in reader.records().enumerate() {
+        let record = record.map_err(|e| format!("第 {} 行解析失败: {}", index + 1, e))?;
+        // 跳过空行
+        if record.iter().all(|field| field.is_empty()) {
+            continue;
+        }
+        records.push(record.iter().map(str::to_string).collect::<Vec<_>>());
+    }
+    if records.is_empty() {
+        return Err("CSV 内容为空".to_string());
+    }
+
+    let has_header = source
+        .has_header
+        .unwrap_or_else(|| looks_like_header(&records));
+    let width = records.iter().map(Vec::len).max().unwrap_or(0);
+    let headers = if has_header {
+        records.remove(0)
+    } else {
+        Vec::new()
+    };
+    // 补齐列数不一致的行
+    for row in records.iter_mut() {
+        row.resize(width, String::new());
+    }
+
+    let columns = (0..width)
+        .map(|index| {
+            let name = headers
+                .get(index)
+                .map(|h| h.trim().to_string())
+                .filter(|h| !h.is_empty())
+                .unwrap_or_else(|| format!("column_{}", index + 1));
+            infer_column(name, records.iter().map(|row| row[index].as_str()))
+        })
+        .collect();
+
+    Ok(CsvTable {
+        delimiter,
+        quote,
+        has_header,
+        columns,
+        rows: records,
+    })
+}
+
+fn read_source(source: &CsvSource) -> Result<String, String> {
+    let content = match (&source.content, &source.path) {
+        (Some(content), _) if !content.is_empty() => content.clone(),
+        (_, Some(path)) if !path.is_empty() => {
+            let bytes = std::fs::read(path).map_err(|e| format!("读取文件失败: {}", e))?;
+            String::from_utf8(bytes)
+                .map_err(|_| "文件不是 UTF-8 编码，请先使用编码转换工具转换".to_string())?
+        }
+        _ => return Err("请提供 CSV 文件或内容".to_string()),
+    };
+    Ok(content.trim_start_matches('\u{feff}').to_string())
+}
+
+fn parse_byte_option(value: &str, label: &str) -> Result<u8, String> {
+    match value {
+        "\\t" | "tab" | "\t" => Ok(b'\t'),
+        _ if value.len() == 1 => Ok(value.as_bytes()[0]),
+        _ => Err(format!("{}必须是单个 ASCII 字符: {}", label, value)),
+    }
+}
+
+fn display_byte(byte: u8) -> String {
+    if byte == b'\t' {
+        "\\t".to_string()
+    } else {
+        (byte as char).to_string()
+    }
+}
+
+/// 选择在采样行中出现次数最稳定且最多的分隔符
+fn detect_delimiter(content: &str) -> u8 {
+    let lines: Vec<&str> = content
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .take(SNIFF_LINES)
+        .collect();
+
+    let mut best = (b',', 0usize);
+    for &candidate in DELIMITER_CANDIDATES {
+        let counts: Vec<usize> = lines
+            .iter()
+            .map(|line| count_unquoted(line, candidate))
+            .collect();
+        let Some(&first) = counts.first() else {
+            continue;
+        };
+        let consistent = counts.iter().filter(|&&c| c == first).count();
+        // 各行数量一致的分隔符优先，其次比较出现次数
+        let score = if first == 0 {
+            0
+        } else {
+            consistent * 1000 + first
+        };
+        if score > best.1 {
+            best = (candidate, score);
+        }
+    }
+    best.0
+}
+
+fn count_unquoted(line: &str, delimiter: u8) -> usize {
+    let mut in_quotes = false;
+    let mut count = 0;
+    for &b in line.as_bytes() {
+        if b == b'"' {
+            in_quotes = !in_quotes;
+        } else if b == delimiter && !in_quotes {
+            count += 1;
+        }
+    }
+    count
+}
+
+/// 字段以单引号包裹且没有双引号包裹时使用单引号
+fn detect_quote(content: &str, delimiter: u8) -> u8 {
+    let delimiter = delimiter as char;
+    let (mut double, mut single) = (0, 0);
+    for line in content.lines().take(SNIFF_LINES) {
+        for field in line.split(delimiter) {
+            let field = field.trim();
+            if field.len() >= 2 && field.starts_with('"') {
+                double += 1;
+            } else if field.len() >= 2 && field.starts_with('\'') && field.ends_with('\'') {
+                single += 1;
+            }
+        }
+    }
+    if single > 0 && double == 0 {
+        b'\''
+    } else {
+        b'"'
+    }
+}
+
+/// 第一行全部为非空、不重复的非数值文本时视为表头
+fn looks_like_header(records: &[Vec<String>]) -> bool {
+    let mut seen = HashSet::new();
+    records[0].iter().all(|value| {
+        let value = value.trim();
+        !value.is_empty()
+            && seen.insert(value.to_lowercase())
+            && classify_value(value) == ColumnType::Text
+    })
+}
+
+fn classify_value(value: &str) -> ColumnType {
+    let value = value.trim();
+    let digits = value.trim_start_matches(['-', '+']);
+    // 带前导零的编号（邮编、工号）保留为文本
+    let leading_zero = digits.len() > 1 && digits.starts_with('0') && !digits.starts_with("0.");
+
+    if !leading_zero && value.parse::<i64>().is_ok() {
+        ColumnType::Integer
+    } else if !leading_zero
+        && value.chars().any(|c| c.is_ascii_digit())
+        && value.parse::<f64>().is_ok_and(f64::is_finite)
+    {
+        ColumnType::Float
+    } else if value.eq_ignore_ascii_case("true") || value.eq_ignore_ascii_case("false") {
+        ColumnType::Boolean
+    } else if ["%Y-%m-%d", "%Y/%m/%d"]
+        .iter()
+        .any(|f| NaiveDate::parse_from_str(value, f).is_ok())
+    {
+        ColumnType::Date
+    } else if DateTime::parse_from_rfc3339(value).is_ok()
+        || [
+            "%Y-%m-%d %H:%M:%S",
+            "%Y-%m-%dT%H:%M:%S",
+            "%Y/%m/%d %H:%M:%S",
+        ]
+        .iter()
+        .any(|f| NaiveDateTime::parse_from_str(value, f).is_ok())
+    {
+        ColumnType::DateTime
+    } else {
+        ColumnType::Text
+    }
+}
+
+fn merge_types(a: ColumnType, b: ColumnType) -> ColumnType {
+    use ColumnType::*;
+    match (a, b) {
+        _ if a == b => a,
+        (Integer, Float) | (Float, Integer) => Float,
+        (Date, DateTime) | (DateTime, Date) => DateTime,
+        _ => Text,
+    }
+}
+
+fn infer_column<'a>(name: String, values: impl Iterator<Item = &'a str>) -> CsvColumn {
+    let mut column_type = None;
+    let mut nullable = false;
+    let mut max_length = 0;
+    for value in values {
+        max_length = max_length.max(value.chars().count());
+        if value.trim().is_empty() {
+            nullable = true;
+            continue;
+        }
+        let value_type = classify_value(value);
+        column_type = Some(match column_type {
+            Some(current) => merge_types(current, value_type),
+            None => value_type,
+        });
+    }
+    CsvColumn {
+        name,
+        column_type: column_type.unwrap_or(ColumnType::Text),
+        nullable,
+        max_length,
+    }
+}
+
+pub(crate) fn typed_value(value: &str, column_type: ColumnType) -> Value {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        return Value::Null;
+    }
+    match column_type {
+        ColumnType::Integer => trimmed
+            .parse::<i64>()
+            .map(Value::from)
+            .unwrap_or_else(|_| Value::String(value.to_string())),
+        ColumnType::Float => trimmed
+            .parse::<f64>()
+            .ok()
+            .and_then(Number::from_f64)
+            .map(Value::Number)
+            .unwrap_or_else(|| Value::String(value.to_string())),
+        ColumnType::Boolean => Value::Bool(trimmed.eq_ignore_ascii_case("true")),
+        _ => Value::String(value.to_string()),
+    }
+}
+
+fn to_json(table: &CsvTable, typed: bool) -> Value {
+    Value::Array(
+        table
+            .rows
+            .iter()
+            .map(|row| {
+                let mut object = Map::new();
+                for (column, value) in table.columns.iter().zip(row) {
+                    let value = if typed {
+                        typed_value(value, column.column_type)
+                    } else {
+                        Value::String(value.clone())
+                    };
+                    object.insert(column.name.clone(), value);
+                }
+                Value::Object(object)
+            })
+            .collect(),
+    )
+}
+
+fn to_markdown(table: &CsvTable) -> String {
+    let escape = |s: &str| {
+        s.replace('|', "\\|")
+            .replace("\r\n", "<br>")
+            .replace('\n', "<br>")
+    };
+    let mut lines = Vec::with_capacity(table.rows.len() + 2);
+    lines.push(format!(
+        "| {} |",
+        table
+            .columns
+            .iter()
+            .map(|c| escape(&c.name))
+            .collect::<Vec<_>>()
+            .join(" | ")
+    ));
+    lines.push(format!(
+        "| {} |",
+        table
+            .columns
+            .iter()
+            .map(|c| match c.column_type {
+                // 数值列右对齐
+                ColumnType::Integer | ColumnType::Float => "---:",
+                _ => "---",
+            })
+            .collect::<Vec<_>>()
+            .join(" | ")
+    ));
+    for row in &table.rows {
+        lines.push(format!(
+            "| {} |",
+            row.iter()
+                .map(|v| escape(v))
+                .collect::<Vec<_>>()
+                .join(" | ")
+        ));
+    }
+    lines.join("\n") + "\n"
+}
+
+fn write_xlsx(table: &CsvTable, output: &str) -> Result<(), String> {
+    use rust_xlsxwriter::{Format, Workbook};
+
+    let mut workbook = Workbook::new();
+    let sheet = workbook.add_worksheet();
+    let header_format = Format::new().set_bold();
+    let xlsx_err = |e: rust_xlsxwriter::XlsxError| format!("写入 Excel 失败: {}", e);
+
+    for (col, column) in table.columns.iter().enumerate() {
+        sheet
+            .write_string_with_format(0, col as u16, &column.name, &header_format)
+            .map_err(xlsx_err)?;
+    }
+    for (row_index, row) in table.rows.iter().enumerate() {
+        let row_num = row_index as u32 + 1;
+        for (col, (column, value)) in table.columns.iter().zip(row).enumerate() {
+            let col = col as u16;
+            match typed_value(value, column.column_type) {
+                Value::Null => continue,
+                Value::Number(n) => sheet.write_number(row_num, col, n.as_f64().unwrap_or(0.0)),
+                Value::Bool(b) => sheet.write_boolean(row_num, col, b),
+                _ => sheet.write_string(row_num, col, value),
+            }
+            .map_err(xlsx_err)?;
+        }
+    }
+    sheet.set_freeze_panes(1, 0).map_err(xlsx_err)?;
+    sheet.autofit();
+    workbook.save(output).map_err(xlsx_err)?;
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SqlDialect {
+    MySql,
+    PostgreSql,
+    Sqlite,
+}
+
+impl SqlDialect {
+    fn parse(value: &str) -> Result<Self, String> {
+        match value.to_lowercase().as_str() {
+            "mysql" | "mariadb" => Ok(SqlDialect::MySql),
+            "postgres" | "postgresql" | "pg" => Ok(SqlDialect::PostgreSql),
+            "sqlite" => Ok(SqlDialect::Sqlite),
+            other => Err(format!("不支持的数据库类型: {}", other)),
+        }
+    }
+
+    fn quote_identifier(&self, name: &str) -> String {
+        match self {
+            SqlDialect::MySql => format!("`{}`", name),
+            _ => format!("\"{}\"", name),
+        }
+    }
+
+    fn column_type(&self, column: &CsvColumn) -> String {
+        match (self, column.column_type) {
+            (SqlDialect::Sqlite, ColumnType::Integer | ColumnType::Boolean) => "INTEGER".into(),
+            (SqlDialect::Sqlite, ColumnType::Float) => "REAL".into(),
+            (SqlDialect::Sqlite, _) => "TEXT".into(),
+            (_, ColumnType::Integer) => "BIGINT".into(),
+            (SqlDialect::PostgreSql, ColumnType::Float) => "DOUBLE PRECISION".into(),
+            (_, ColumnType::Float) => "DOUBLE".into(),
+            (SqlDialect::MySql, ColumnType::Boolean) => "TINYINT(1)".into(),
+            (_, ColumnType::Boolean) => "BOOLEAN".into(),
+            (_, ColumnType::Date) => "DATE".into(),
+            (SqlDialect::PostgreSql, ColumnType::DateTime) => "TIMESTAMP".into(),
+            (_, ColumnType::DateTime) => "DATETIME".into(),
+            (_, ColumnType::Text) if column.max_length > 1024 => "TEXT".into(),
+            // 预留余量，避免后续数据略长时插入失败
+            (_, ColumnType::Text) => {
+                format!("VARCHAR({})", (column.max_length * 2).clamp(32, 1024))
+            }
+        }
+    }
+
+    fn literal(&self, value: &str, column_type: ColumnType) -> String {
+        let trimmed = value.trim();
+        if trimmed.is_empty() {
+            return "NULL".to_string();
+        }
+        match column_type {
+            ColumnType::Integer | ColumnType::Float => trimmed.to_string(),
+            ColumnType::Boolean => {
+                let truthy = trimmed.eq_ignore_ascii_case("true");
+                match self {
+                    SqlDialect::PostgreSql => if truthy { "TRUE" } else { "FALSE" }.to_string(),
+                    _ => if truthy { "1" } else { "0" }.to_string(),
+                }
+            }
+            _ => {
+                let mut escaped = value.replace('\'', "''");
+                if *self == SqlDialect::MySql {
+                    escaped = escaped.replace('\\', "\\\\");
+                }
+                format!("'{}'", escaped)
+            }
+        }
+    }
+}
+
+/// 转为合法的 snake_case 标识符
+fn sanitize_identifier(name: &str, fallback: &str) -> String {
+    let snake = StringUtils::to_snake_case(name.trim());
+    let mut result: String = snake
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect();
+    result = result.trim_matches('_').to_string();
+    if result.is_empty() {
+        return fallback.to_string();
+    }
+    if result.starts_with(|c: char| c.is_ascii_digit()) {
+        result.insert(0, '_');
+    }
+    result
+}
+
+fn column_identifiers(table: &CsvTable) -> Vec<String> {
+    let mut seen = HashSet::new();
+    table
+        .columns
+        .iter()
+        .enumerate()
+        .map(|(index, column)| {
+            let base = sanitize_identifier(&column.name, &format!("column_{}", index + 1));
+            let mut name = base.clone();
+            let mut suffix = 2;
+            while !seen.insert(name.clone()) {
+                name = format!("{}_{}", base, suffix);
+                suffix += 1;
+            }
+            name
+        })
+        .collect()
+}
+
+fn generate_create_table(table: &CsvTable, table_name: &str, dialect: SqlDialect) -> String {
+    let definitions: Vec<String> = table
+        .columns
+        .iter()
+        .zip(column_identifiers(table))
+        .map(|(column, name)| {
+            format!(
+                "  {} {}{}",
+                dialect.quote_identifier(&name),
+                dialect.column_type(column),
+                if column.nullable { "" } else { " NOT NULL" }
+            )
+        })
+        .collect();
+    format!(
+        "CREATE TABLE {} (\n{}\n);\n",
+        dialect.quote_identifier(table_name),
+        definitions.join(",\n")
+    )
+}
+
+fn generate_inserts(
+    table: &CsvTable,
+    table_name: &str,
+    dialect: SqlDialect,
+    batch_size: usize,
+) -> String {
+    let columns = column_identifiers(table)
+        .iter()
+        .map(|name| dialect.quote_identifier(name))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut sql = String::new();
+    for batch in table.rows.chunks(batch_size) {
+        let values: Vec<String> = batch
+            .iter()
+            .map(|row| {
+                let literals: Vec<String> = table
+                    .columns
+                    .iter()
+                    .zip(row)
+                    .map(|(column, value)| dialect.literal(value, column.column_type))
+                    .collect();
+                format!("  ({})", literals.join(", "))
+            })
+            .collect();
+        sql.push_str(&format!(
+            "INSERT INTO {} ({}) VALUES\n{};\n",
+            dialect.quote_identifier(table_name),
+            columns,
+            values.join(",\n")
+        ));
+    }
+    sql
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(content: &str) -> CsvSource {
+        CsvSource {
+            path: None,
+            content: Some(content.to_string()),
+            delimiter: None,
+            quote: None,
+            has_header: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_and_infer() {
+        let table = parse_table(&source(
+            "id\tName\tscore\tactive\tzip\tcreated\n1\tAlice\t9.5\ttrue\t01234\t2024-01-02\n2\t\"Bob\tJr\"\t7\tfalse\t\t2024-02-03 10:00:00\n",
+        ))
+        .unwrap();
+        assert_eq!(table.delimiter, b'\t');
+        assert!(table.has_header);
+        assert_eq!(table.rows.len(), 2);
+        assert_eq!(table.rows[1][1], "Bob\tJr");
+
+        let types: Vec<ColumnType> = table.columns.iter().map(|c| c.column_type).collect();
+        assert_eq!(
+            types,
+            vec![
+                ColumnType::Integer,
+                ColumnType::Text,
+                ColumnType::Float,
+                ColumnType::Boolean,
+                ColumnType::Text,
+                ColumnType::DateTime,
+            ]
+        );
+        assert!(table.columns[4].nullable);
+
+        let headerless = parse_table(&source("1;2\n3;4\n")).unwrap();
+        assert_eq!(headerless.delimiter, b';');
+        assert!(!headerless.has_header);
+        assert_eq!(headerless.columns[0].name, "column_1");
+    }
+
+    #[test]
+    fn test_outputs() {
+        let table = parse_table(&source("User ID,Comment\n1,it's a|b\n2,\n")).unwrap();
+
+        let json = to_json(&table, true);
+        assert_eq!(json[0]["User ID"], 1);
+        assert_eq!(json[1]["Comment"], Value::Null);
+
+        assert!(to_markdown(&table).contains("| 1 | it's a\\|b |"));
+
+        let create = generate_create_table(&table, "users", SqlDialect::PostgreSql);
+        assert!(create.contains("\"user_id\" BIGINT NOT NULL"));
+        let inserts = generate_inserts(&table, "users", SqlDialect::MySql, 1);
+        assert_eq!(inserts.matches("INSERT INTO").count(), 2);
+        assert!(inserts.contains("'it''s a|b'"));
+        assert!(inserts.contains("(2, NULL)"));
+    }
+}
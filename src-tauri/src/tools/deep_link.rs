@@ -0,0 +1,170 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_deep_link::DeepLinkExt;
+use url::Url;
+
+/// 应用注册的 URL scheme
+pub const DEEP_LINK_SCHEME: &str = "devtools";
+/// 通知前端打开工具的事件
+pub const DEEP_LINK_EVENT: &str = "deep-link-open";
+/// 参数总长度上限，避免超长链接塞满输入框
+const MAX_PARAMS_LEN: usize = 64 * 1024;
+
+/// 链接中的路由别名与前端工具 id 的对应关系
+const ROUTE_ALIASES: &[(&str, &str)] = &[
+    ("ssl-check", "sslchecker"),
+    ("ssl", "sslchecker"),
+    ("cert", "certificate"),
+    ("regex", "regextester"),
+    ("json", "jsonformatter"),
+    ("json-to-go", "jsontogo"),
+    ("sql-to-go", "sqltogo"),
+    ("sql-to-ent", "sqltoent"),
+    ("base64", "base64converter"),
+    ("url", "urlencoderdecoder"),
+    ("jwt", "jwtdecode"),
+    ("ip", "ipinfo"),
+    ("subnet", "subnetcalculator"),
+];
+
+/// 前端加载完成前收到的链接，等待前端取走
+static PENDING: Mutex<Option<DeepLinkTarget>> = Mutex::new(None);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeepLinkTarget {
+    pub tool_id: String,
+    pub params: BTreeMap<String, String>, // 用于预填的输入，如 domain、pattern
+    pub url: String,
+}
+
+/// 取走启动时收到的链接，只返回一次
+#[tauri::command]
+pub fn take_pending_deep_link() -> Option<DeepLinkTarget> {
+    PENDING.lock().unwrap().take()
+}
+
+/// 解析 devtools:// 链接，不打开窗口，便于前端校验或生成分享链接
+#[tauri::command]
+pub fn parse_deep_link(url: String) -> Result<DeepLinkTarget, String> {
+    let parsed = Url::parse(url.trim()).map_err(|e| format!("无效的链接: {}", e))?;
+    resolve_target(&parsed)
+}
+
+/// 监听系统转交的链接，并处理启动参数中的链接
+pub fn setup_deep_link(app: &AppHandle) {
+    // Linux 和 Windows 开发模式下需要在运行时注册 scheme
+    #[cfg(any(target_os = "linux", all(debug_assertions, windows)))]
+    if let Err(e) = app.deep_link().register_all() {
+        eprintln!("Failed to register deep link scheme: {}", e);
+    }
+
+    let handle = app.clone();
+    app.deep_link().on_open_url(move |event| {
+        handle_urls(&handle, &event.urls());
+    });
+    if let Ok(Some(urls)) = app.deep_link().get_current() {
+        handle_urls(app, &urls);
+    }
+}
+
+fn handle_urls(app: &AppHandle, urls: &[Url]) {
+    for url in urls {
+        let target = match resolve_target(url) {
+            Ok(target) => target,
+            Err(e) => {
+                eprintln!("Ignored deep link {}: {}", url, e);
+                continue;
+            }
+        };
+        if let Some(window) = app.get_webview_window("main") {
+            let _ = window.show();
+            let _ = window.unminimize();
+            let _ = window.set_focus();
+        }
+        // 前端可能尚未加载，同时保存一份供其启动后读取
+        *PENDING.lock().unwrap() = Some(target.clone());
+        let _ = app.emit(DEEP_LINK_EVENT, target);
+    }
+}
+
+/// devtools://ssl-check?domain=example.com 与 devtools:///ssl-check?... 均可
+fn resolve_target(url: &Url) -> Result<DeepLinkTarget, String> {
+    if url.scheme() != DEEP_LINK_SCHEME {
+        return Err(format!("不支持的链接协议: {}", url.scheme()));
+    }
+    let route = match url.host_str().filter(|h| !h.is_empty()) {
+        Some(host) => host.to_string(),
+        None => url
+            .path_segments()
+            .and_then(|mut segments| segments.find(|s| !s.is_empty()))
+            .map(str::to_string)
+            .ok_or_else(|| "链接中缺少工具名称".to_string())?,
+    };
+    let route = route.to_lowercase();
+    let tool_id = ROUTE_ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == route)
+        .map(|(_, tool_id)| tool_id.to_string())
+        .unwrap_or(route);
+    if !tool_id
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(format!("无效的工具名称: {}", tool_id));
+    }
+
+    let mut params = BTreeMap::new();
+    let mut total = 0;
+    for (key, value) in url.query_pairs() {
+        total += key.len() + value.len();
+        if total > MAX_PARAMS_LEN {
+            return Err("链接参数过长".to_string());
+        }
+        // 重复的参数只保留第一个
+        params
+            .entry(key.to_string())
+            .or_insert_with(|| value.to_string());
+    }
+
+    Ok(DeepLinkTarget {
+        tool_id,
+        params,
+        url: url.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_target() {
+        let target =
+            parse_deep_link("devtools://ssl-check?domain=example.com".to_string()).unwrap();
+        assert_eq!(target.tool_id, "sslchecker");
+        assert_eq!(target.params["domain"], "example.com");
+
+        // 查询参数按百分号编码解码
+        let target =
+            parse_deep_link("devtools:///regex?pattern=%5Cd%2B&flags=gi&flags=m".to_string())
+                .unwrap();
+        assert_eq!(target.tool_id, "regextester");
+        assert_eq!(target.params["pattern"], "\\d+");
+        assert_eq!(target.params["flags"], "gi");
+
+        let target = parse_deep_link("devtools://whois".to_string()).unwrap();
+        assert_eq!(target.tool_id, "whois");
+        assert!(target.params.is_empty());
+    }
+
+    #[test]
+    fn test_resolve_target_rejects_invalid() {
+        assert!(parse_deep_link("https://ssl-check?domain=a.com".to_string()).is_err());
+        assert!(parse_deep_link("devtools://".to_string()).is_err());
+        assert!(parse_deep_link("devtools:///%3Cscript%3E".to_string()).is_err());
+        let long = format!("devtools://json?input={}", "a".repeat(MAX_PARAMS_LEN + 1));
+        assert_eq!(parse_deep_link(long).unwrap_err(), "链接参数过长");
+    }
+}
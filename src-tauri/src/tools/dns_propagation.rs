@@ -0,0 +1,279 @@
+use crate::utils::dns::DnsClient;
+use hickory_resolver::proto::rr::RecordType;
+use serde::{Deserialize, Serialize};
+use std::net::{IpAddr, SocketAddr};
+use std::str::FromStr;
+use std::time::Instant;
+use tokio::task::JoinSet;
+
+/// 默认查询的公共解析器：名称、地址、所在地区
+const PUBLIC_RESOLVERS: &[(&str, &str, &str)] = &[
+    ("Google", "8.8.8.8", "全球 Anycast"),
+    ("Google Secondary", "8.8.4.4", "全球 Anycast"),
+    ("Cloudflare", "1.1.1.1", "全球 Anycast"),
+    ("Quad9", "9.9.9.9", "全球 Anycast"),
+    ("OpenDNS", "208.67.222.222", "美国"),
+    ("Level3", "4.2.2.1", "美国"),
+    ("Hurricane Electric", "74.82.42.42", "美国"),
+    ("Yandex", "77.88.8.8", "俄罗斯"),
+    ("AdGuard", "94.140.14.140", "欧洲"),
+    ("AliDNS", "223.5.5.5", "中国"),
+    ("DNSPod", "119.29.29.29", "中国"),
+    ("114DNS", "114.114.114.114", "中国"),
+    ("Baidu", "180.76.76.76", "中国"),
+];
+
+const SUPPORTED_TYPES: &[&str] = &[
+    "A", "AAAA", "CNAME", "MX", "TXT", "NS", "CAA", "SOA", "SRV", "PTR",
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PublicResolver {
+    pub name: String,
+    pub address: String, // IP 或 IP:端口，默认 53 端口
+    pub location: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DnsPropagationRequest {
+    pub domain: String,
+    pub record_type: String,
+    pub resolvers: Option<Vec<PublicResolver>>, // 为空时使用内置的公共解析器列表
+    pub expected: Option<String>,               // 期望出现在结果中的值，如新的 IP
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResolverAnswer {
+    pub resolver: PublicResolver,
+    pub answers: Vec<String>, // 已排序，便于比较
+    pub min_ttl: Option<u32>,
+    pub elapsed_ms: u64,
+    pub error: Option<String>,
+    pub matches_consensus: bool,
+    pub matches_expected: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnswerGroup {
+    pub answers: Vec<String>,
+    pub resolvers: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DnsPropagationReport {
+    pub domain: String,
+    pub record_type: String,
+    pub results: Vec<ResolverAnswer>,
+    pub groups: Vec<AnswerGroup>,       // 按结果分组，解析器多的在前
+    pub consensus: Option<Vec<String>>, // 多数解析器返回的结果
+    pub responded: usize,
+    pub agreeing: usize,
+    pub fully_propagated: bool, // 所有成功响应的解析器结果一致（指定 expected 时还需包含该值）
+}
+
+/// 列出内置的公共解析器
+#[tauri::command]
+pub fn list_public_resolvers() -> Vec<PublicResolver> {
+    PUBLIC_RESOLVERS
+        .iter()
+        .map(|(name, address, location)| PublicResolver {
+            name: name.to_string(),
+            address: address.to_string(),
+            location: Some(location.to_string()),
+        })
+        .collect()
+}
+
+/// 并发向多个公共解析器查询同一条记录，对比结果和 TTL
+#[tauri::command]
+pub async fn check_dns_propagation(
+    request: DnsPropagationRequest,
+) -> Result<DnsPropagationReport, String> {
+    let domain = request.domain.trim().trim_end_matches('.').to_lowercase();
+    if domain.is_empty() {
+        return Err("请输入域名".to_string());
+    }
+    let record_type_name = request.record_type.trim().to_uppercase();
+    if !SUPPORTED_TYPES.contains(&record_type_name.as_str()) {
+        return Err(format!("不支持的记录类型: {}", request.record_type));
+    }
+    let record_type =
+        RecordType::from_str(&record_type_name).map_err(|e| format!("不支持的记录类型: {}", e))?;
+    let resolvers = match request.resolvers {
+        Some(list) if !list.is_empty() => list,
+        _ => list_public_resolvers(),
+    };
+
+    let mut tasks = JoinSet::new();
+    for (index, resolver) in resolvers.into_iter().enumerate() {
+        let domain = domain.clone();
+        tasks.spawn(async move {
+            let started = Instant::now();
+            let outcome = match parse_resolver_address(&resolver.address) {
+                Ok(addr) => {
+                    let client = DnsClient::resolver_for(addr.ip(), addr.port());
+                    DnsClient::lookup_with_ttl(&client, &domain, record_type).await
+                }
+                Err(e) => Err(e),
+            };
+            (
+                index,
+                resolver,
+                outcome,
+                started.elapsed().as_millis() as u64,
+            )
+        });
+    }
+    let mut finished = tasks.join_all().await;
+    finished.sort_by_key(|(index, ..)| *index);
+
+    let mut results: Vec<ResolverAnswer> = finished
+        .into_iter()
+        .map(|(_, resolver, outcome, elapsed_ms)| match outcome {
+            Ok(records) => {
+                let mut answers: Vec<String> =
+                    records.iter().map(|(value, _)| value.clone()).collect();
+                answers.sort();
+                answers.dedup();
+                ResolverAnswer {
+                    resolver,
+                    answers,
+                    min_ttl: records.iter().map(|(_, ttl)| *ttl).min(),
+                    elapsed_ms,
+                    error: None,
+                    matches_consensus: false,
+                    matches_expected: None,
+                }
+            }
+            Err(error) => ResolverAnswer {
+                resolver,
+                answers: Vec::new(),
+                min_ttl: None,
+                elapsed_ms,
+                error: Some(error),
+                matches_consensus: false,
+                matches_expected: None,
+            },
+        })
+        .collect();
+
+    let groups = group_answers(&results);
+    let consensus = groups.first().map(|g| g.answers.clone());
+    let expected = request
+        .expected
+        .as_deref()
+        .map(|e| e.trim().trim_end_matches('.').to_lowercase())
+        .filter(|e| !e.is_empty());
+    for result in results.iter_mut().filter(|r| r.error.is_none()) {
+        result.matches_consensus = consensus.as_ref() == Some(&result.answers);
+        result.matches_expected = expected.as_ref().map(|expected| {
+            result
+                .answers
+                .iter()
+                .any(|a| a.trim_end_matches('.').to_lowercase().contains(expected))
+        });
+    }
+
+    let responded = results.iter().filter(|r| r.error.is_none()).count();
+    let agreeing = results.iter().filter(|r| r.matches_consensus).count();
+    let fully_propagated = responded > 0
+        && agreeing == responded
+        && results
+            .iter()
+            .filter(|r| r.error.is_none())
+            .all(|r| r.matches_expected != Some(false));
+
+    Ok(DnsPropagationReport {
+        domain,
+        record_type: record_type_name,
+        results,
+        groups,
+        consensus,
+        responded,
+        agreeing,
+        fully_propagated,
+    })
+}
+
+fn parse_resolver_address(address: &str) -> Result<SocketAddr, String> {
+    let address = address.trim();
+    if let Ok(ip) = address.parse::<IpAddr>() {
+        return Ok(SocketAddr::new(ip, 53));
+    }
+    address
+        .parse::<SocketAddr>()
+        .map_err(|_| format!("解析器地址无效: {}", address))
+}
+
+/// 把成功响应的解析器按结果分组，解析器数量相同时按首次出现的顺序
+fn group_answers(results: &[ResolverAnswer]) -> Vec<AnswerGroup> {
+    let mut groups: Vec<AnswerGroup> = Vec::new();
+    for result in results.iter().filter(|r| r.error.is_none()) {
+        match groups.iter_mut().find(|g| g.answers == result.answers) {
+            Some(group) => group.resolvers.push(result.resolver.name.clone()),
+            None => groups.push(AnswerGroup {
+                answers: result.answers.clone(),
+                resolvers: vec![result.resolver.name.clone()],
+            }),
+        }
+    }
+    groups.sort_by_key(|g| std::cmp::Reverse(g.resolvers.len()));
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answer(name: &str, answers: &[&str], error: Option<&str>) -> ResolverAnswer {
+        ResolverAnswer {
+            resolver: PublicResolver {
+                name: name.to_string(),
+                address: "192.0.2.1".to_string(),
+                location: None,
+            },
+            answers: answers.iter().map(|a| a.to_string()).collect(),
+            min_ttl: Some(300),
+            elapsed_ms: 10,
+            error: error.map(String::from),
+            matches_consensus: false,
+            matches_expected: None,
+        }
+    }
+
+    #[test]
+    fn test_group_answers() {
+        let results = vec![
+            answer("old", &["192.0.2.1"], None),
+            answer("a", &["198.51.100.7"], None),
+            answer("b", &["198.51.100.7"], None),
+            answer("down", &[], Some("超时")),
+            answer("c", &["198.51.100.7"], None),
+        ];
+        let groups = group_answers(&results);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].answers, vec!["198.51.100.7"]);
+        assert_eq!(groups[0].resolvers, vec!["a", "b", "c"]);
+        assert_eq!(groups[1].resolvers, vec!["old"]);
+    }
+
+    #[test]
+    fn test_resolver_list_and_addresses() {
+        let resolvers = list_public_resolvers();
+        assert!(resolvers.len() >= 10);
+        for resolver in &resolvers {
+            assert!(parse_resolver_address(&resolver.address).is_ok());
+        }
+        assert_eq!(
+            parse_resolver_address("1.1.1.1").unwrap(),
+            "1.1.1.1:53".parse::<SocketAddr>().unwrap()
+        );
+        assert_eq!(
+            parse_resolver_address("[2606:4700:4700::1111]:5353")
+                .unwrap()
+                .port(),
+            5353
+        );
+        assert!(parse_resolver_address("dns.google").is_err());
+    }
+}
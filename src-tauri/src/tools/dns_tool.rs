@@ -0,0 +1,808 @@
+use crate::utils::dns::DnsClient;
+use base64::{engine::general_purpose, Engine as _};
+use hickory_resolver::proto::rr::RecordType;
+use hickory_resolver::TokioAsyncResolver;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::future::Future;
+use std::pin::Pin;
+
+/// RFC 7208 规定的 DNS 查询次数上限
+const SPF_LOOKUP_LIMIT: usize = 10;
+const SPF_VOID_LOOKUP_LIMIT: usize = 2;
+
+const COMMON_DKIM_SELECTORS: &[&str] = &[
+    "default",
+    "dkim",
+    "mail",
+    "smtp",
+    "google",
+    "selector1",
+    "selector2",
+    "k1",
+    "k2",
+    "s1",
+    "s2",
+    "mxvault",
+    "everlytickey1",
+    "zoho",
+    "qq",
+    "aliyun",
+    "sendgrid",
+    "mandrill",
+    "amazonses",
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolicyIssue {
+    pub severity: String, // error / warning / info
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpfMechanism {
+    pub qualifier: String, // + - ~ ?
+    pub mechanism: String,
+    pub value: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpfNode {
+    pub domain: String,
+    pub record: Option<String>,
+    pub mechanisms: Vec<SpfMechanism>,
+    pub children: Vec<SpfNode>, // include / redirect 展开结果
+    pub lookups: usize,         // 本节点（含子节点）消耗的 DNS 查询次数
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpfReport {
+    pub found: bool,
+    pub record: Option<String>,
+    pub tree: Option<SpfNode>,
+    pub lookup_count: usize,
+    pub void_lookup_count: usize,
+    pub issues: Vec<PolicyIssue>,
+    pub suggested_record: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DkimSelectorResult {
+    pub selector: String,
+    pub record: String,
+    pub key_type: String,
+    pub key_bits: Option<u32>,
+    pub testing: bool,
+    pub revoked: bool,
+    pub issues: Vec<PolicyIssue>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DkimReport {
+    pub checked_selectors: Vec<String>,
+    pub selectors: Vec<DkimSelectorResult>,
+    pub issues: Vec<PolicyIssue>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DmarcReport {
+    pub found: bool,
+    pub record: Option<String>,
+    pub inherited_from: Option<String>, // 子域名继承组织域策略时的来源
+    pub policy: Option<String>,
+    pub subdomain_policy: Option<String>,
+    pub pct: Option<u32>,
+    pub rua: Vec<String>,
+    pub ruf: Vec<String>,
+    pub adkim: String,
+    pub aspf: String,
+    pub issues: Vec<PolicyIssue>,
+    pub suggested_record: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmailSecurityResult {
+    pub domain: String,
+    pub mx_records: Vec<String>,
+    pub spf: SpfReport,
+    pub dkim: DkimReport,
+    pub dmarc: DmarcReport,
+}
+
+fn issue(severity: &str, message: impl Into<String>) -> PolicyIssue {
+    PolicyIssue {
+        severity: severity.to_string(),
+        message: message.into(),
+    }
+}
+
+fn parse_spf_terms(record: &str) -> Vec<SpfMechanism> {
+    record
+        .split_whitespace()
+        .skip(1) // v=spf1
+        .map(|term| {
+            // 修饰符 redirect= / exp=
+            if let Some((name, value)) = term.split_once('=') {
+                return SpfMechanism {
+                    qualifier: String::new(),
+                    mechanism: name.to_lowercase(),
+                    value: Some(value.to_string()),
+                };
+            }
+            let (qualifier, rest) = match term.chars().next() {
+                Some(q @ ('+' | '-' | '~' | '?')) => (q.to_string(), &term[1..]),
+                _ => ("+".to_string(), term),
+            };
+            let (mechanism, value) = match rest.split_once([':', '/']) {
+                Some((m, _)) => (m, Some(rest[m.len()..].trim_start_matches(':').to_string())),
+                None => (rest, None),
+            };
+            SpfMechanism {
+                qualifier,
+                mechanism: mechanism.to_lowercase(),
+                value,
+            }
+        })
+        .collect()
+}
+
+fn find_spf_record(records: &[String]) -> Result<Option<String>, String> {
+    let spf: Vec<&String> = records
+        .iter()
+        .filter(|r| {
+            let lower = r.trim().to_lowercase();
+            lower == "v=spf1" || lower.starts_with("v=spf1 ")
+        })
+        .collect();
+    match spf.len() {
+        0 => Ok(None),
+        1 => Ok(Some(spf[0].trim().to_string())),
+        n => Err(format!("存在 {} 条 SPF 记录，SPF 要求只能有一条", n)),
+    }
+}
+
+struct SpfContext<'a> {
+    resolver: &'a TokioAsyncResolver,
+    visited: HashSet<String>,
+    void_lookups: usize,
+    issues: Vec<PolicyIssue>,
+}
+
+/// 递归展开 SPF 记录并统计 DNS 查询次数
+fn expand_spf<'a>(
+    ctx: &'a mut SpfContext<'_>,
+    domain: String,
+    depth: usize,
+) -> Pin<Box<dyn Future<Output = SpfNode> + Send + 'a>> {
+    Box::pin(async move {
+        let mut node = SpfNode {
+            domain: domain.clone(),
+            record: None,
+            mechanisms: Vec::new(),
+            children: Vec::new(),
+            lookups: 0,
+            error: None,
+        };
+        if depth > SPF_LOOKUP_LIMIT {
+            node.error = Some("嵌套层级过深".to_string());
+            return node;
+        }
+        if !ctx.visited.insert(domain.to_lowercase()) {
+            node.error = Some("检测到循环引用".to_string());
+            ctx.issues
+                .push(issue("error", format!("SPF 循环引用: {}", domain)));
+            return node;
+        }
+
+        let records = match DnsClient::lookup_txt(ctx.resolver, &domain).await {
+            Ok(records) => records,
+            Err(e) => {
+                node.error = Some(e);
+                return node;
+            }
+        };
+        let record = match find_spf_record(&records) {
+            Ok(Some(record)) => record,
+            Ok(None) => {
+                if depth > 0 {
+                    ctx.void_lookups += 1;
+                    ctx.issues.push(issue(
+                        "error",
+                        format!("被引用的域名 {} 没有 SPF 记录（PermError）", domain),
+                    ));
+                }
+                node.error = Some("未找到 SPF 记录".to_string());
+                return node;
+            }
+            Err(e) => {
+                ctx.issues
+                    .push(issue("error", format!("{}: {}", domain, e)));
+                node.error = Some(e);
+                return node;
+            }
+        };
+
+        node.mechanisms = parse_spf_terms(&record);
+        node.record = Some(record);
+        let mechanisms = node.mechanisms.clone();
+        for term in &mechanisms {
+            match term.mechanism.as_str() {
+                "include" | "redirect" => {
+                    node.lookups += 1;
+                    if let Some(target) = term.value.clone().filter(|v| !v.is_empty()) {
+                        let child = expand_spf(ctx, target, depth + 1).await;
+                        node.lookups += child.lookups;
+                        node.children.push(child);
+                    }
+                }
+                "a" | "mx" | "exists" => {
+                    node.lookups += 1;
+                    // 检查是否为空查询（void lookup）
+                    let target = term
+                        .value
+                        .as_deref()
+                        .map(|v| v.split('/').next().unwrap_or(v))
+                        .filter(|v| !v.is_empty() && !v.contains('%'))
+                        .unwrap_or(&domain)
+                        .to_string();
+                    let record_type = match term.mechanism.as_str() {
+                        "mx" => RecordType::MX,
+                        _ => RecordType::A,
+                    };
+                    if let Ok(found) = DnsClient::lookup(ctx.resolver, &target, record_type).await {
+                        let aaaa_empty = found.is_empty()
+                            && record_type == RecordType::A
+                            && DnsClient::lookup(ctx.resolver, &target, RecordType::AAAA)
+                                .await
+                                .map(|r| r.is_empty())
+                                .unwrap_or(false);
+                        if (record_type == RecordType::MX && found.is_empty()) || aaaa_empty {
+                            ctx.void_lookups += 1;
+                        }
+                    }
+                }
+                "ptr" => {
+                    node.lookups += 1;
+                    ctx.issues.push(issue(
+                        "warning",
+                        format!("{} 使用了已不推荐的 ptr 机制", domain),
+                    ));
+                }
+                _ => {}
+            }
+        }
+        node
+    })
+}
+
+async fn check_spf(resolver: &TokioAsyncResolver, domain: &str, has_mx: bool) -> SpfReport {
+    let mut ctx = SpfContext {
+        resolver,
+        visited: HashSet::new(),
+        void_lookups: 0,
+        issues: Vec::new(),
+    };
+    let tree = expand_spf(&mut ctx, domain.to_string(), 0).await;
+    let mut issues = ctx.issues;
+
+    let Some(record) = tree.record.clone() else {
+        let lookup_error = tree.error.clone().filter(|e| e != "未找到 SPF 记录");
+        match lookup_error {
+            Some(e) if issues.is_empty() => issues.push(issue("error", e)),
+            Some(_) => {}
+            None => issues.push(issue("error", "未找到 SPF 记录")),
+        }
+        let suggested = if has_mx {
+            "v=spf1 mx -all"
+        } else {
+            // 不发信的域名应显式拒绝
+            "v=spf1 -all"
+        };
+        return SpfReport {
+            found: false,
+            record: None,
+            tree: Some(tree),
+            lookup_count: 0,
+            void_lookup_count: 0,
+            issues,
+            suggested_record: Some(suggested.to_string()),
+        };
+    };
+
+    let lookup_count = tree.lookups;
+    if lookup_count > SPF_LOOKUP_LIMIT {
+        issues.push(issue(
+            "error",
+            format!(
+                "DNS 查询次数为 {}，超过上限 {}，接收方会返回 PermError",
+                lookup_count, SPF_LOOKUP_LIMIT
+            ),
+        ));
+    } else if lookup_count >= SPF_LOOKUP_LIMIT - 2 {
+        issues.push(issue(
+            "warning",
+            format!(
+                "DNS 查询次数为 {}，已接近上限 {}",
+                lookup_count, SPF_LOOKUP_LIMIT
+            ),
+        ));
+    }
+    if ctx.void_lookups > SPF_VOID_LOOKUP_LIMIT {
+        issues.push(issue(
+            "error",
+            format!(
+                "空查询次数为 {}，超过上限 {}",
+                ctx.void_lookups, SPF_VOID_LOOKUP_LIMIT
+            ),
+        ));
+    }
+
+    let all = tree.mechanisms.iter().find(|m| m.mechanism == "all");
+    let has_redirect = tree.mechanisms.iter().any(|m| m.mechanism == "redirect");
+    match all.map(|m| m.qualifier.as_str()) {
+        Some("+") => issues.push(issue(
+            "error",
+            "使用了 +all，任何服务器都可以冒充该域名发信",
+        )),
+        Some("?") => issues.push(issue("warning", "使用了 ?all（中立），SPF 基本不起作用")),
+        Some("~") => issues.push(issue(
+            "info",
+            "使用了 ~all（软失败），确认发信源完整后建议改为 -all",
+        )),
+        Some(_) => {}
+        None if !has_redirect => {
+            issues.push(issue("warning", "记录末尾缺少 all 机制，默认结果为中立"))
+        }
+        None => {}
+    }
+    if let Some(position) = tree.mechanisms.iter().position(|m| m.mechanism == "all") {
+        if position + 1 < tree.mechanisms.len()
+            && tree.mechanisms[position + 1..]
+                .iter()
+                .any(|m| m.mechanism != "exp")
+        {
+            issues.push(issue("warning", "all 之后的机制永远不会被执行"));
+        }
+    }
+    let known = [
+        "all", "include", "a", "mx", "ptr", "ip4", "ip6", "exists", "redirect", "exp",
+    ];
+    for term in &tree.mechanisms {
+        if !known.contains(&term.mechanism.as_str()) {
+            issues.push(issue("error", format!("未知的机制: {}", term.mechanism)));
+        }
+    }
+    if record.len() > 450 {
+        issues.push(issue(
+            "warning",
+            "记录过长，可能导致 DNS 响应超出 UDP 512 字节",
+        ));
+    }
+
+    // 替换 +all/?all，给出建议记录
+    let suggested_record = if issues.iter().any(|i| i.severity != "info") {
+        let terms: Vec<String> = tree
+            .mechanisms
+            .iter()
+            .filter(|m| m.mechanism != "ptr" && m.mechanism != "all")
+            .map(|m| {
+                let qualifier = if m.qualifier == "+" { "" } else { &m.qualifier };
+                let separator = match m.mechanism.as_str() {
+                    "redirect" | "exp" => "=",
+                    _ if m.value.as_deref().is_some_and(|v| v.starts_with('/')) => "",
+                    _ => ":",
+                };
+                match &m.value {
+                    Some(v) => format!("{}{}{}{}", qualifier, m.mechanism, separator, v),
+                    None => format!("{}{}", qualifier, m.mechanism),
+                }
+            })
+            .collect();
+        let tail = if has_redirect { "" } else { " -all" };
+        Some(format!("v=spf1 {}{}", terms.join(" "), tail).replace("  ", " "))
+    } else {
+        None
+    };
+
+    SpfReport {
+        found: true,
+        record: Some(record),
+        lookup_count,
+        void_lookup_count: ctx.void_lookups,
+        tree: Some(tree),
+        issues,
+        suggested_record,
+    }
+}
+
+fn parse_tags(record: &str) -> Vec<(String, String)> {
+    record
+        .split(';')
+        .filter_map(|tag| {
+            let (key, value) = tag.split_once('=')?;
+            Some((key.trim().to_lowercase(), value.trim().to_string()))
+        })
+        .collect()
+}
+
+fn tag<'a>(tags: &'a [(String, String)], key: &str) -> Option<&'a str> {
+    tags.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str())
+}
+
+fn analyze_dkim_record(selector: &str, record: &str) -> DkimSelectorResult {
+    let tags = parse_tags(record);
+    let key_type = tag(&tags, "k").unwrap_or("rsa").to_lowercase();
+    let public_key: String = tag(&tags, "p")
+        .unwrap_or_default()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    let testing = tag(&tags, "t").is_some_and(|t| t.split(':').any(|f| f.trim() == "y"));
+    let revoked = public_key.is_empty();
+    let mut issues = Vec::new();
+
+    let key_bits = if revoked {
+        issues.push(issue("warning", "公钥为空，该选择器已被吊销"));
+        None
+    } else {
+        match general_purpose::STANDARD.decode(&public_key) {
+            Ok(der) if key_type == "ed25519" => Some((der.len() * 8) as u32),
+            Ok(der) => match openssl::pkey::PKey::public_key_from_der(&der).or_else(|_| {
+                openssl::rsa::Rsa::public_key_from_der_pkcs1(&der)
+                    .and_then(openssl::pkey::PKey::from_rsa)
+            }) {
+                Ok(key) => Some(key.bits()),
+                Err(_) => {
+                    issues.push(issue("error", "无法解析公钥"));
+                    None
+                }
+            },
+            Err(_) => {
+                issues.push(issue("error", "公钥不是有效的 Base64"));
+                None
+            }
+        }
+    };
+    if key_type == "rsa" {
+        match key_bits {
+            Some(bits) if bits < 1024 => issues.push(issue(
+                "error",
+                format!("RSA 密钥仅 {} 位，多数接收方会拒绝验证", bits),
+            )),
+            Some(bits) if bits < 2048 => issues.push(issue(
+                "warning",
+                format!("RSA 密钥为 {} 位，建议升级到 2048 位", bits),
+            )),
+            _ => {}
+        }
+    }
+    if testing {
+        issues.push(issue(
+            "warning",
+            "处于测试模式（t=y），接收方可能忽略验证结果",
+        ));
+    }
+    if tag(&tags, "v").is_some_and(|v| v != "DKIM1") {
+        issues.push(issue("error", "版本标签 v 必须为 DKIM1"));
+    }
+    if tag(&tags, "h").is_some_and(|h| h.to_lowercase().contains("sha1")) {
+        issues.push(issue("warning", "允许使用 SHA-1 哈希算法"));
+    }
+
+    DkimSelectorResult {
+        selector: selector.to_string(),
+        record: record.to_string(),
+        key_type,
+        key_bits,
+        testing,
+        revoked,
+        issues,
+    }
+}
+
+async fn check_dkim(
+    resolver: &TokioAsyncResolver,
+    domain: &str,
+    selectors: Option<Vec<String>>,
+) -> DkimReport {
+    let custom = selectors
+        .map(|s| {
+            s.into_iter()
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect::<Vec<_>>()
+        })
+        .filter(|s| !s.is_empty());
+    let checked_selectors: Vec<String> = custom.clone().unwrap_or_else(|| {
+        COMMON_DKIM_SELECTORS
+            .iter()
+            .map(|s| s.to_string())
+            .collect()
+    });
+
+    // 并发查询各选择器
+    let handles: Vec<_> = checked_selectors
+        .iter()
+        .map(|selector| {
+            let resolver = resolver.clone();
+            let selector = selector.clone();
+            let name = format!("{}._domainkey.{}", selector, domain);
+            tokio::spawn(async move {
+                let records = DnsClient::lookup_txt(&resolver, &name)
+                    .await
+                    .unwrap_or_default();
+                records
+                    .into_iter()
+                    .find(|r| r.contains("p=") || r.to_lowercase().contains("v=dkim1"))
+                    .map(|record| analyze_dkim_record(&selector, &record))
+            })
+        })
+        .collect();
+    let mut selectors = Vec::new();
+    for handle in handles {
+        if let Ok(Some(result)) = handle.await {
+            selectors.push(result);
+        }
+    }
+
+    let mut issues = Vec::new();
+    if selectors.is_empty() {
+        let message = if custom.is_some() {
+            "指定的选择器均未找到 DKIM 记录".to_string()
+        } else {
+            "常见选择器中未找到 DKIM 记录，如使用自定义选择器请手动指定".to_string()
+        };
+        issues.push(issue("warning", message));
+    }
+
+    DkimReport {
+        checked_selectors,
+        selectors,
+        issues,
+    }
+}
+
+fn organizational_domain(domain: &str) -> Option<String> {
+    let labels: Vec<&str> = domain.split('.').collect();
+    if labels.len() <= 2 {
+        return None;
+    }
+    let second_level = ["com", "net", "org", "gov", "edu", "co", "ac"];
+    let take = if labels.len() >= 3 && second_level.contains(&labels[labels.len() - 2]) {
+        3
+    } else {
+        2
+    };
+    (labels.len() > take).then(|| labels[labels.len() - take..].join("."))
+}
+
+async fn check_dmarc(resolver: &TokioAsyncResolver, domain: &str) -> DmarcReport {
+    let find = |records: Vec<String>| {
+        records
+            .into_iter()
+            .filter(|r| r.trim().to_uppercase().starts_with("V=DMARC1"))
+            .collect::<Vec<_>>()
+    };
+    let mut issues = Vec::new();
+    let mut records = find(
+        DnsClient::lookup_txt(resolver, &format!("_dmarc.{}", domain))
+            .await
+            .unwrap_or_default(),
+    );
+    let mut inherited_from = None;
+    if records.is_empty() {
+        if let Some(org) = organizational_domain(domain) {
+            records = find(
+                DnsClient::lookup_txt(resolver, &format!("_dmarc.{}", org))
+                    .await
+                    .unwrap_or_default(),
+            );
+            if !records.is_empty() {
+                inherited_from = Some(org);
+            }
+        }
+    }
+
+    let suggested = format!(
+        "v=DMARC1; p=quarantine; rua=mailto:dmarc@{}; adkim=r; aspf=r",
+        domain
+    );
+    if records.is_empty() {
+        issues.push(issue("error", "未找到 DMARC 记录，无法防止发件人伪造"));
+        return DmarcReport {
+            found: false,
+            record: None,
+            inherited_from: None,
+            policy: None,
+            subdomain_policy: None,
+            pct: None,
+            rua: Vec::new(),
+            ruf: Vec::new(),
+            adkim: "r".to_string(),
+            aspf: "r".to_string(),
+            issues,
+            suggested_record: Some(format!("v=DMARC1; p=none; rua=mailto:dmarc@{}", domain)),
+        };
+    }
+    if records.len() > 1 {
+        issues.push(issue("error", "存在多条 DMARC 记录，接收方将忽略全部记录"));
+    }
+
+    let record = records[0].trim().to_string();
+    let tags = parse_tags(&record);
+    let policy = tag(&tags, "p").map(|p| p.to_lowercase());
+    let subdomain_policy = tag(&tags, "sp").map(|p| p.to_lowercase());
+    let pct = tag(&tags, "pct").and_then(|p| p.parse::<u32>().ok());
+    let split_uris = |key: &str| -> Vec<String> {
+        tag(&tags, key)
+            .map(|v| {
+                v.split(',')
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    let rua = split_uris("rua");
+    let ruf = split_uris("ruf");
+
+    // 继承组织域策略时，子域名适用 sp
+    let effective = if inherited_from.is_some() {
+        subdomain_policy.clone().or(policy.clone())
+    } else {
+        policy.clone()
+    };
+    match effective.as_deref() {
+        None => issues.push(issue("error", "缺少必需的 p 标签")),
+        Some("none") => issues.push(issue(
+            "warning",
+            "策略为 p=none，仅监控不拦截，确认报告无误后建议改为 quarantine 或 reject",
+        )),
+        Some("quarantine") | Some("reject") => {}
+        Some(other) => issues.push(issue("error", format!("无效的策略: {}", other))),
+    }
+    if let Some(pct) = pct {
+        if pct < 100 {
+            issues.push(issue(
+                "warning",
+                format!("pct={}，策略只作用于部分邮件", pct),
+            ));
+        }
+    }
+    if rua.is_empty() {
+        issues.push(issue(
+            "warning",
+            "未配置 rua 汇总报告地址，无法了解认证失败情况",
+        ));
+    }
+    for uri in rua.iter().chain(ruf.iter()) {
+        if !uri.to_lowercase().starts_with("mailto:") {
+            issues.push(issue(
+                "error",
+                format!("报告地址应以 mailto: 开头: {}", uri),
+            ));
+        } else if let Some(target) = uri[7..].split('!').next().and_then(|a| a.rsplit_once('@')) {
+            let report_domain = target.1.to_lowercase();
+            if !report_domain.ends_with(domain) && !domain.ends_with(&report_domain) {
+                issues.push(issue(
+                    "info",
+                    format!(
+                        "报告发送到外部域名 {}，需要其发布 {}._report._dmarc.{} 授权记录",
+                        report_domain, domain, report_domain
+                    ),
+                ));
+            }
+        }
+    }
+
+    let has_error = issues.iter().any(|i| i.severity != "info");
+    DmarcReport {
+        found: true,
+        record: Some(record),
+        inherited_from,
+        policy,
+        subdomain_policy,
+        pct,
+        rua,
+        ruf,
+        adkim: tag(&tags, "adkim").unwrap_or("r").to_lowercase(),
+        aspf: tag(&tags, "aspf").unwrap_or("r").to_lowercase(),
+        issues,
+        suggested_record: has_error.then_some(suggested),
+    }
+}
+
+/// 检查域名的 SPF、DKIM 与 DMARC 配置
+#[tauri::command]
+pub async fn check_email_security(
+    domain: String,
+    dkim_selectors: Option<Vec<String>>,
+) -> Result<EmailSecurityResult, String> {
+    let domain = domain
+        .trim()
+        .trim_end_matches('.')
+        .trim_start_matches("http://")
+        .trim_start_matches("https://")
+        .split('/')
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+    if domain.is_empty() || !domain.contains('.') {
+        return Err("请输入有效的域名".to_string());
+    }
+
+    let resolver = DnsClient::system_resolver();
+    let mut mx_records = DnsClient::lookup(&resolver, &domain, RecordType::MX).await?;
+    mx_records.sort();
+    let has_mx = !mx_records.is_empty();
+
+    let (spf, dkim, dmarc) = tokio::join!(
+        check_spf(&resolver, &domain, has_mx),
+        check_dkim(&resolver, &domain, dkim_selectors),
+        check_dmarc(&resolver, &domain),
+    );
+
+    Ok(EmailSecurityResult {
+        domain,
+        mx_records,
+        spf,
+        dkim,
+        dmarc,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_spf_terms() {
+        let terms = parse_spf_terms(
+            "v=spf1 ip4:192.0.2.0/24 a/24 -mx:mail.example.com ~all redirect=_spf.example.com",
+        );
+        let summary: Vec<(&str, &str, Option<&str>)> = terms
+            .iter()
+            .map(|t| {
+                (
+                    t.qualifier.as_str(),
+                    t.mechanism.as_str(),
+                    t.value.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("+", "ip4", Some("192.0.2.0/24")),
+                ("+", "a", Some("/24")),
+                ("-", "mx", Some("mail.example.com")),
+                ("~", "all", None),
+                ("", "redirect", Some("_spf.example.com")),
+            ]
+        );
+        assert!(
+            find_spf_record(&["v=spf1 -all".to_string(), "v=spf1 mx -all".to_string()]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_analyze_dkim_record() {
+        let rsa = openssl::rsa::Rsa::generate(1024).unwrap();
+        let der = openssl::pkey::PKey::from_rsa(rsa)
+            .unwrap()
+            .public_key_to_der()
+            .unwrap();
+        let record = format!(
+            "v=DKIM1; k=rsa; t=y; p={}",
+            general_purpose::STANDARD.encode(der)
+        );
+        let result = analyze_dkim_record("s1", &record);
+        assert_eq!(result.key_bits, Some(1024));
+        assert!(result.testing);
+        assert_eq!(result.issues.len(), 2);
+
+        let revoked = analyze_dkim_record("old", "v=DKIM1; p=");
+        assert!(revoked.revoked);
+    }
+}
@@ -0,0 +1,1025 @@
+use crate::utils::proxy;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+const DOCKER_HUB_REGISTRY: &str = "registry-1.docker.io";
+const MANIFEST_ACCEPT: &str = "application/vnd.oci.image.index.v1+json, \
+application/vnd.docker.distribution.manifest.list.v2+json, \
+application/vnd.oci.image.manifest.v1+json, \
+application/vnd.docker.distribution.manifest.v2+json";
+
+/// 会产生镜像层的指令
+const LAYER_INSTRUCTIONS: &[&str] = &["RUN", "COPY", "ADD"];
+
+/// 依赖安装命令，位于 `COPY . .` 之后会导致每次源码变更都重新安装依赖
+const DEPENDENCY_COMMANDS: &[&str] = &[
+    "npm install",
+    "npm ci",
+    "yarn install",
+    "pnpm install",
+    "pip install",
+    "poetry install",
+    "go mod download",
+    "bundle install",
+    "composer install",
+    "mvn dependency",
+    "gradle dependencies",
+    "cargo fetch",
+];
+
+/// 构建工具链，单阶段镜像中出现说明可以拆分多阶段构建
+const BUILD_COMMANDS: &[&str] = &[
+    "go build",
+    "cargo build",
+    "npm run build",
+    "yarn build",
+    "mvn package",
+    "gradle build",
+    "make",
+    "gcc ",
+    "build-essential",
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DockerInstruction {
+    pub line_number: usize,
+    pub instruction: String,
+    pub arguments: String,
+    pub stage_index: usize,
+    pub creates_layer: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DockerStage {
+    pub index: usize,
+    pub name: Option<String>,
+    pub base_image: String,
+    pub line_number: usize,
+    pub layer_count: usize,
+    pub user: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DockerFinding {
+    pub line_number: Option<usize>,
+    pub severity: String, // error / warning / info
+    pub rule: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DockerfileAnalysis {
+    pub stages: Vec<DockerStage>,
+    pub instructions: Vec<DockerInstruction>,
+    pub findings: Vec<DockerFinding>,
+    pub total_layers: usize,
+    pub final_user: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageReference {
+    pub registry: String,
+    pub repository: String,
+    pub reference: String, // tag 或 digest
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageTagsResult {
+    pub image: ImageReference,
+    pub tags: Vec<String>,
+    pub has_more: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImagePlatform {
+    pub os: String,
+    pub architecture: String,
+    pub variant: Option<String>,
+    pub digest: String,
+    pub size: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageLayer {
+    pub digest: String,
+    pub size: u64,
+    pub media_type: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageManifestResult {
+    pub image: ImageReference,
+    pub digest: Option<String>,
+    pub media_type: String,
+    pub is_index: bool,
+    pub platforms: Vec<ImagePlatform>,
+    pub layers: Vec<ImageLayer>,
+    pub total_size: Option<u64>,
+    pub config_digest: Option<String>,
+    pub created: Option<String>,
+}
+
+/// 合并续行并跳过注释，返回 (起始行号, 逻辑行)
+fn logical_lines(content: &str) -> Vec<(usize, String)> {
+    // 解析器指令 `# escape=` 可将续行符改为反引号
+    let escape = content
+        .lines()
+        .take_while(|l| l.trim_start().starts_with('#'))
+        .find_map(|l| {
+            let directive = l.trim_start_matches('#').trim().to_lowercase();
+            directive
+                .strip_prefix("escape=")
+                .and_then(|v| v.trim().chars().next())
+        })
+        .unwrap_or('\\');
+
+    let mut result = Vec::new();
+    let mut current = String::new();
+    let mut start = 0;
+    for (index, raw) in content.lines().enumerate() {
+        let line = raw.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if current.is_empty() {
+            start = index + 1;
+        }
+        match line.strip_suffix(escape) {
+            Some(rest) => {
+                current.push_str(rest.trim_end());
+                current.push(' ');
+            }
+            None => {
+                current.push_str(line);
+                result.push((start, std::mem::take(&mut current)));
+            }
+        }
+    }
+    if !current.trim().is_empty() {
+        result.push((start, current.trim().to_string()));
+    }
+    result
+}
+
+fn finding(line: Option<usize>, severity: &str, rule: &str, message: String) -> DockerFinding {
+    DockerFinding {
+        line_number: line,
+        severity: severity.to_string(),
+        rule: rule.to_string(),
+        message,
+    }
+}
+
+/// 解析 FROM 参数：[--platform=...] image [AS name]
+fn parse_from(arguments: &str) -> (String, Option<String>) {
+    let tokens: Vec<&str> = arguments
+        .split_whitespace()
+        .filter(|t| !t.starts_with("--"))
+        .collect();
+    let image = tokens.first().unwrap_or(&"").to_string();
+    let name = match tokens.get(1) {
+        Some(t) if t.eq_ignore_ascii_case("as") => tokens.get(2).map(|n| n.to_lowercase()),
+        _ => None,
+    };
+    (image, name)
+}
+
+fn image_tag(image: &str) -> Option<&str> {
+    if image.contains('@') {
+        return Some("digest");
+    }
+    let last = image.rsplit('/').next().unwrap_or(image);
+    last.split_once(':').map(|(_, tag)| tag)
+}
+
+fn check_run(line: usize, args: &str, findings: &mut Vec<DockerFinding>) {
+    let lower = args.to_lowercase();
+    let has_apt_install = lower.contains("apt-get install") || lower.contains("apt install");
+    if lower.contains("apt-get update") && !has_apt_install {
+        findings.push(finding(
+            Some(line),
+            "warning",
+            "apt_update_alone",
+            "apt-get update 单独成层会被缓存，后续 install 可能安装到过期的包，请与 install 合并在同一个 RUN 中".to_string(),
+        ));
+    }
+    if has_apt_install {
+        if !lower.contains("--no-install-recommends") {
+            findings.push(finding(
+                Some(line),
+                "info",
+                "apt_recommends",
+                "apt-get install 建议添加 --no-install-recommends 以减小镜像体积".to_string(),
+            ));
+        }
+        if !lower.contains("/var/lib/apt/lists") {
+            findings.push(finding(
+                Some(line),
+                "info",
+                "apt_cache",
+                "安装后未清理 /var/lib/apt/lists，缓存会残留在镜像层中".to_string(),
+            ));
+        }
+    }
+    if lower.contains("apk add") && !lower.contains("--no-cache") {
+        findings.push(finding(
+            Some(line),
+            "info",
+            "apk_cache",
+            "apk add 建议使用 --no-cache".to_string(),
+        ));
+    }
+    if (lower.contains("curl") || lower.contains("wget"))
+        && (lower.contains("| sh") || lower.contains("| bash") || lower.contains("|sh"))
+    {
+        findings.push(finding(
+            Some(line),
+            "warning",
+            "pipe_to_shell",
+            "通过管道直接执行远程脚本存在供应链风险，建议下载后校验哈希再执行".to_string(),
+        ));
+    }
+    if lower.split_whitespace().any(|t| t == "sudo") {
+        findings.push(finding(
+            Some(line),
+            "warning",
+            "sudo",
+            "构建阶段不需要 sudo，可使用 USER 切换用户".to_string(),
+        ));
+    }
+    if lower.contains("pip install") && !lower.contains("--no-cache-dir") {
+        findings.push(finding(
+            Some(line),
+            "info",
+            "pip_cache",
+            "pip install 建议添加 --no-cache-dir".to_string(),
+        ));
+    }
+}
+
+fn looks_like_secret(name: &str) -> bool {
+    let upper = name.to_uppercase();
+    [
+        "PASSWORD",
+        "PASSWD",
+        "SECRET",
+        "TOKEN",
+        "API_KEY",
+        "PRIVATE_KEY",
+    ]
+    .iter()
+    .any(|k| upper.contains(k))
+}
+
+fn analyze_dockerfile_content(content: &str) -> Result<DockerfileAnalysis, String> {
+    let lines = logical_lines(content);
+    if lines.is_empty() {
+        return Err("Dockerfile 内容不能为空".to_string());
+    }
+
+    let mut stages: Vec<DockerStage> = Vec::new();
+    let mut instructions = Vec::new();
+    let mut findings = Vec::new();
+    let mut stage_names: HashSet<String> = HashSet::new();
+    let mut copied_context: HashMap<usize, usize> = HashMap::new(); // stage -> COPY . 所在行
+    let mut previous_instruction = String::new();
+    let mut consecutive_runs = 0;
+
+    for (line_number, line) in &lines {
+        let (instruction, arguments) = match line.split_once(char::is_whitespace) {
+            Some((i, a)) => (i.to_uppercase(), a.trim().to_string()),
+            None => (line.to_uppercase(), String::new()),
+        };
+        let line_number = *line_number;
+
+        if instruction == "FROM" {
+            let (image, name) = parse_from(&arguments);
+            let lower_image = image.to_lowercase();
+            if lower_image != "scratch"
+                && !image.contains('$')
+                && !stage_names.contains(&lower_image)
+            {
+                match image_tag(&image) {
+                    None => findings.push(finding(
+                        Some(line_number),
+                        "warning",
+                        "latest_tag",
+                        format!(
+                            "基础镜像 {} 未指定标签，将隐式使用 latest，构建结果不可复现",
+                            image
+                        ),
+                    )),
+                    Some("latest") => findings.push(finding(
+                        Some(line_number),
+                        "warning",
+                        "latest_tag",
+                        format!("基础镜像 {} 使用 latest 标签，构建结果不可复现", image),
+                    )),
+                    _ => {}
+                }
+            }
+            if let Some(n) = &name {
+                stage_names.insert(n.clone());
+            }
+            stages.push(DockerStage {
+                index: stages.len(),
+                name,
+                base_image: image,
+                line_number,
+                layer_count: 0,
+                user: None,
+            });
+            consecutive_runs = 0;
+            previous_instruction = instruction;
+            continue;
+        }
+
+        if stages.is_empty() && instruction != "ARG" {
+            findings.push(finding(
+                Some(line_number),
+                "error",
+                "missing_from",
+                format!("{} 出现在 FROM 之前", instruction),
+            ));
+        }
+        let stage_index = stages.len().saturating_sub(1);
+        let creates_layer = LAYER_INSTRUCTIONS.contains(&instruction.as_str());
+        if let Some(stage) = stages.last_mut() {
+            if creates_layer {
+                stage.layer_count += 1;
+            }
+        }
+
+        match instruction.as_str() {
+            "RUN" => {
+                check_run(line_number, &arguments, &mut findings);
+                let lower = arguments.to_lowercase();
+                if let Some(copy_line) = copied_context.get(&stage_index) {
+                    if DEPENDENCY_COMMANDS.iter().any(|c| lower.contains(c)) {
+                        findings.push(finding(
+                            Some(line_number),
+                            "warning",
+                            "cache_bust",
+                            format!(
+                                "第 {} 行复制了整个构建上下文，任何源码变更都会使此处的依赖安装缓存失效；建议先只复制依赖清单（package.json、go.mod 等）再安装",
+                                copy_line
+                            ),
+                        ));
+                    }
+                }
+                consecutive_runs = if previous_instruction == "RUN" {
+                    consecutive_runs + 1
+                } else {
+                    1
+                };
+                if consecutive_runs == 3 {
+                    findings.push(finding(
+                        Some(line_number),
+                        "info",
+                        "merge_runs",
+                        "连续多个 RUN 指令，可考虑使用 && 合并以减少镜像层数".to_string(),
+                    ));
+                }
+            }
+            "COPY" | "ADD" => {
+                let sources: Vec<&str> = arguments
+                    .split_whitespace()
+                    .filter(|t| !t.starts_with("--"))
+                    .collect();
+                let from_stage = arguments.contains("--from=");
+                if !from_stage
+                    && sources.len() >= 2
+                    && sources[..sources.len() - 1]
+                        .iter()
+                        .any(|s| *s == "." || *s == "./")
+                {
+                    copied_context.entry(stage_index).or_insert(line_number);
+                }
+                if instruction == "ADD" {
+                    let remote = sources.first().is_some_and(|s| s.contains("://"));
+                    let archive = sources.first().is_some_and(|s| {
+                        [".tar", ".tar.gz", ".tgz", ".tar.bz2", ".tar.xz"]
+                            .iter()
+                            .any(|ext| s.ends_with(ext))
+                    });
+                    if remote {
+                        findings.push(finding(
+                            Some(line_number),
+                            "warning",
+                            "add_remote",
+                            "ADD 远程文件无法利用缓存且不校验内容，建议在 RUN 中使用 curl 下载并校验".to_string(),
+                        ));
+                    } else if !archive {
+                        findings.push(finding(
+                            Some(line_number),
+                            "info",
+                            "prefer_copy",
+                            "复制普通文件时建议使用 COPY 代替 ADD".to_string(),
+                        ));
+                    }
+                }
+            }
+            "USER" => {
+                if let Some(stage) = stages.last_mut() {
+                    stage.user = Some(arguments.clone());
+                }
+            }
+            "ENV" | "ARG" => {
+                let name = arguments
+                    .split(['=', ' '])
+                    .next()
+                    .unwrap_or_default()
+                    .to_string();
+                if looks_like_secret(&name) {
+                    findings.push(finding(
+                        Some(line_number),
+                        "warning",
+                        "secret_in_build",
+                        format!(
+                            "{} 中的 {} 疑似敏感信息，会保留在镜像历史中，建议使用 BuildKit secret 挂载",
+                            instruction, name
+                        ),
+                    ));
+                }
+            }
+            "WORKDIR" if !arguments.starts_with('/') && !arguments.starts_with('$') => {
+                findings.push(finding(
+                    Some(line_number),
+                    "info",
+                    "relative_workdir",
+                    "WORKDIR 建议使用绝对路径".to_string(),
+                ));
+            }
+            "MAINTAINER" => findings.push(finding(
+                Some(line_number),
+                "info",
+                "deprecated_maintainer",
+                "MAINTAINER 已废弃，请使用 LABEL maintainer=...".to_string(),
+            )),
+            "CMD" | "ENTRYPOINT" if !arguments.starts_with('[') => {
+                findings.push(finding(
+                    Some(line_number),
+                    "info",
+                    "shell_form",
+                    format!(
+                        "{} 使用 shell 形式，进程不会以 PID 1 运行，无法正确接收信号；建议使用 JSON 数组形式",
+                        instruction
+                    ),
+                ));
+            }
+            _ => {}
+        }
+
+        instructions.push(DockerInstruction {
+            line_number,
+            instruction: instruction.clone(),
+            arguments,
+            stage_index,
+            creates_layer,
+        });
+        previous_instruction = instruction;
+    }
+
+    if stages.is_empty() {
+        return Err("未找到 FROM 指令，不是有效的 Dockerfile".to_string());
+    }
+
+    let final_user = stages.last().and_then(|s| s.user.clone());
+    let runs_as_root = match final_user.as_deref() {
+        None => stages.last().is_some_and(|s| s.base_image != "scratch"),
+        Some(user) => {
+            let name = user.split(':').next().unwrap_or(user);
+            name == "root" || name == "0"
+        }
+    };
+    if runs_as_root {
+        findings.push(finding(
+            None,
+            "warning",
+            "root_user",
+            "最终镜像以 root 用户运行，建议创建非特权用户并使用 USER 切换".to_string(),
+        ));
+    }
+
+    if stages.len() == 1 {
+        let has_build = instructions.iter().any(|i| {
+            let lower = i.arguments.to_lowercase();
+            i.instruction == "RUN" && BUILD_COMMANDS.iter().any(|c| lower.contains(c))
+        });
+        if has_build {
+            findings.push(finding(
+                None,
+                "info",
+                "multi_stage",
+                "单阶段镜像中包含编译/构建步骤，建议使用多阶段构建只保留运行时产物".to_string(),
+            ));
+        }
+    }
+    if !instructions.iter().any(|i| i.instruction == "HEALTHCHECK") {
+        findings.push(finding(
+            None,
+            "info",
+            "no_healthcheck",
+            "未声明 HEALTHCHECK".to_string(),
+        ));
+    }
+
+    Ok(DockerfileAnalysis {
+        total_layers: stages.last().map_or(0, |s| s.layer_count),
+        stages,
+        instructions,
+        findings,
+        final_user,
+    })
+}
+
+/// 分析 Dockerfile 的分层与常见问题
+#[tauri::command]
+pub fn analyze_dockerfile(content: String) -> Result<DockerfileAnalysis, String> {
+    analyze_dockerfile_content(&content)
+}
+
+/// 解析镜像引用，如 nginx:1.25、ghcr.io/owner/app@sha256:...
+fn parse_image_reference(image: &str) -> Result<ImageReference, String> {
+    let image = image
+        .trim()
+        .trim_start_matches("docker://")
+        .trim_start_matches("https://");
+    if image.is_empty() {
+        return Err("镜像名称不能为空".to_string());
+    }
+
+    let (name, reference) = match image.split_once('@') {
+        Some((n, digest)) => (n, digest.to_string()),
+        None => {
+            let last_slash = image.rfind('/').map_or(0, |i| i + 1);
+            match image[last_slash..].rfind(':') {
+                Some(i) => (
+                    &image[..last_slash + i],
+                    image[last_slash + i + 1..].to_string(),
+                ),
+                None => (image, "latest".to_string()),
+            }
+        }
+    };
+
+    // 首段包含 . 或 : 或为 localhost 时视为仓库地址
+    let (registry, repository) = match name.split_once('/') {
+        Some((first, rest))
+            if first.contains('.') || first.contains(':') || first == "localhost" =>
+        {
+            (first.to_string(), rest.to_string())
+        }
+        _ => (DOCKER_HUB_REGISTRY.to_string(), name.to_string()),
+    };
+    let registry = if registry == "docker.io" || registry == "index.docker.io" {
+        DOCKER_HUB_REGISTRY.to_string()
+    } else {
+        registry
+    };
+    let repository = if registry == DOCKER_HUB_REGISTRY && !repository.contains('/') {
+        format!("library/{}", repository)
+    } else {
+        repository
+    };
+
+    Ok(ImageReference {
+        registry,
+        repository: repository.to_lowercase(),
+        reference,
+    })
+}
+
+fn build_client() -> Result<reqwest::Client, String> {
+    proxy::client_builder("docker_tool")
+        .timeout(Duration::from_secs(15))
+        .build()
+        .map_err(|e| format!("构建 HTTP 客户端失败: {}", e))
+}
+
+/// 解析 WWW-Authenticate: Bearer realm="...",service="...",scope="..."
+fn parse_bearer_challenge(header: &str) -> HashMap<String, String> {
+    let body = header.trim().trim_start_matches("Bearer").trim();
+    let mut params = HashMap::new();
+    let mut rest = body;
+    while let Some((key, after)) = rest.split_once('=') {
+        let key = key.trim().trim_start_matches(',').trim().to_lowercase();
+        let after = after.trim_start();
+        let (value, remaining) = match after.strip_prefix('"') {
+            Some(quoted) => match quoted.split_once('"') {
+                Some((v, r)) => (v.to_string(), r),
+                None => (quoted.to_string(), ""),
+            },
+            None => match after.split_once(',') {
+                Some((v, r)) => (v.trim().to_string(), r),
+                None => (after.trim().to_string(), ""),
+            },
+        };
+        params.insert(key, value);
+        rest = remaining;
+    }
+    params
+}
+
+/// 按仓库返回的认证质询获取匿名 pull 令牌
+async fn fetch_registry_token(
+    client: &reqwest::Client,
+    image: &ImageReference,
+) -> Result<Option<String>, String> {
+    let response = client
+        .get(format!("https://{}/v2/", image.registry))
+        .send()
+        .await
+        .map_err(|e| format!("连接镜像仓库失败: {}", e))?;
+    if response.status() != reqwest::StatusCode::UNAUTHORIZED {
+        return Ok(None);
+    }
+    let challenge = response
+        .headers()
+        .get("www-authenticate")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    if !challenge.to_lowercase().starts_with("bearer") {
+        return Err("该镜像仓库需要 Basic 认证，暂不支持".to_string());
+    }
+    let params = parse_bearer_challenge(&challenge);
+    let realm = params.get("realm").ok_or("镜像仓库认证质询缺少 realm")?;
+
+    let mut query = vec![(
+        "scope".to_string(),
+        format!("repository:{}:pull", image.repository),
+    )];
+    if let Some(service) = params.get("service") {
+        query.push(("service".to_string(), service.clone()));
+    }
+    let token: serde_json::Value = client
+        .get(realm)
+        .query(&query)
+        .send()
+        .await
+        .map_err(|e| format!("获取仓库令牌失败: {}", e))?
+        .json()
+        .await
+        .map_err(|e| format!("解析仓库令牌失败: {}", e))?;
+    Ok(token
+        .get("token")
+        .or_else(|| token.get("access_token"))
+        .and_then(|t| t.as_str())
+        .map(str::to_string))
+}
+
+async fn registry_get(
+    client: &reqwest::Client,
+    token: Option<&str>,
+    url: &str,
+    accept: Option<&str>,
+) -> Result<reqwest::Response, String> {
+    let mut request = client.get(url);
+    if let Some(t) = token {
+        request = request.bearer_auth(t);
+    }
+    if let Some(a) = accept {
+        request = request.header("Accept", a);
+    }
+    let response = request
+        .send()
+        .await
+        .map_err(|e| format!("镜像仓库请求失败: {}", e))?;
+    match response.status() {
+        s if s.is_success() => Ok(response),
+        reqwest::StatusCode::NOT_FOUND => Err("镜像或标签不存在".to_string()),
+        reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN => {
+            Err("无权访问该镜像，私有镜像暂不支持".to_string())
+        }
+        s => Err(format!("镜像仓库响应状态异常: {}", s)),
+    }
+}
+
+/// 查询镜像仓库中的标签列表
+#[tauri::command]
+pub async fn list_image_tags(
+    image: String,
+    limit: Option<usize>,
+) -> Result<ImageTagsResult, String> {
+    let image = parse_image_reference(&image)?;
+    let limit = limit.unwrap_or(100).clamp(1, 1000);
+    let client = build_client()?;
+    let token = fetch_registry_token(&client, &image).await?;
+
+    let url = format!(
+        "https://{}/v2/{}/tags/list?n={}",
+        image.registry, image.repository, limit
+    );
+    let response = registry_get(&client, token.as_deref(), &url, None).await?;
+    let has_more = response.headers().contains_key("link");
+    let body: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("解析标签列表失败: {}", e))?;
+    let mut tags: Vec<String> = body
+        .get("tags")
+        .and_then(|t| t.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|t| t.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default();
+    let has_more = has_more || tags.len() > limit;
+    tags.truncate(limit);
+
+    Ok(ImageTagsResult {
+        image,
+        tags,
+        has_more,
+    })
+}
+
+/// 查询镜像 manifest：摘要、平台变体与层信息
+#[tauri::command]
+pub async fn inspect_image_manifest(image: String) -> Result<ImageManifestResult, String> {
+    let image = parse_image_reference(&image)?;
+    let client = build_client()?;
+    let token = fetch_registry_token(&client, &image).await?;
+    let base = format!("https://{}/v2/{}", image.registry, image.repository);
+
+    let response = registry_get(
+        &client,
+        token.as_deref(),
+        &format!("{}/manifests/{}", base, image.reference),
+        Some(MANIFEST_ACCEPT),
+    )
+    .await?;
+    let digest = response
+        .headers()
+        .get("docker-content-digest")
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    let header_media_type = response
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    let manifest: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("解析 manifest 失败: {}", e))?;
+
+    let media_type = manifest
+        .get("mediaType")
+        .and_then(|v| v.as_str())
+        .map(str::to_string)
+        .unwrap_or(header_media_type);
+    let str_field = |v: &serde_json::Value, key: &str| {
+        v.get(key)
+            .and_then(|x| x.as_str())
+            .unwrap_or_default()
+            .to_string()
+    };
+
+    if let Some(entries) = manifest.get("manifests").and_then(|m| m.as_array()) {
+        let platforms = entries
+            .iter()
+            .map(|entry| {
+                let platform = entry.get("platform").cloned().unwrap_or_default();
+                ImagePlatform {
+                    os: str_field(&platform, "os"),
+                    architecture: str_field(&platform, "architecture"),
+                    variant: platform
+                        .get("variant")
+                        .and_then(|v| v.as_str())
+                        .map(str::to_string),
+                    digest: str_field(entry, "digest"),
+                    size: entry.get("size").and_then(|s| s.as_u64()),
+                }
+            })
+            // 过滤 BuildKit 生成的 attestation 清单
+            .filter(|p| p.os != "unknown")
+            .collect();
+        return Ok(ImageManifestResult {
+            image,
+            digest,
+            media_type,
+            is_index: true,
+            platforms,
+            layers: Vec::new(),
+            total_size: None,
+            config_digest: None,
+            created: None,
+        });
+    }
+
+    let layers: Vec<ImageLayer> = manifest
+        .get("layers")
+        .and_then(|l| l.as_array())
+        .map(|arr| {
+            arr.iter()
+                .map(|layer| ImageLayer {
+                    digest: str_field(layer, "digest"),
+                    size: layer.get("size").and_then(|s| s.as_u64()).unwrap_or(0),
+                    media_type: str_field(layer, "mediaType"),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let config_digest = manifest
+        .get("config")
+        .and_then(|c| c.get("digest"))
+        .and_then(|d| d.as_str())
+        .map(str::to_string);
+
+    // 单平台镜像从 config blob 中读取平台与创建时间
+    let mut platforms = Vec::new();
+    let mut created = None;
+    if let Some(config_digest) = &config_digest {
+        if let Ok(response) = registry_get(
+            &client,
+            token.as_deref(),
+            &format!("{}/blobs/{}", base, config_digest),
+            None,
+        )
+        .await
+        {
+            if let Ok(config) = response.json::<serde_json::Value>().await {
+                created = config
+                    .get("created")
+                    .and_then(|c| c.as_str())
+                    .map(str::to_string);
+                platforms.push(ImagePlatform {
+                    os: str_field(&config, "os"),
+                    architecture: str_field(&config, "architecture"),
+                    variant: config
+                        .get("variant")
+                        .and_then(|v| v.as_str())
+                        .map(str::to_string),
+                    digest: digest.clone().unwrap_or_default(),
+                    size: None,
+                });
+            }
+        }
+    }
+
+    Ok(ImageManifestResult {
+        image,
+        digest,
+        media_type,
+        is_index: false,
+        platforms,
+        total_size: Some(layers.iter().map(|l| l.size).sum()),
+        layers,
+        config_digest,
+        created,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(analysis: &DockerfileAnalysis) -> Vec<&str> {
+        analysis.findings.iter().map(|f| f.rule.as_str()).collect()
+    }
+
+    #[test]
+    fn test_analyze_multi_stage_dockerfile() {
+        let content = r#"# syntax=docker/dockerfile:1
+FROM golang:1.22 AS build
+WORKDIR /src
+COPY go.mod go.sum ./
+RUN go mod download
+COPY . .
+RUN go build \
+    -o /out/app ./cmd/app
+
+FROM gcr.io/distroless/static:nonroot
+COPY --from=build /out/app /app
+USER nonroot
+HEALTHCHECK CMD ["/app", "health"]
+ENTRYPOINT ["/app"]
+"#;
+        let analysis = analyze_dockerfile_content(content).unwrap();
+        assert_eq!(analysis.stages.len(), 2);
+        assert_eq!(analysis.stages[0].name.as_deref(), Some("build"));
+        assert_eq!(analysis.stages[0].layer_count, 4);
+        assert_eq!(
+            analysis.stages[1].base_image,
+            "gcr.io/distroless/static:nonroot"
+        );
+        assert_eq!(analysis.total_layers, 1);
+        assert_eq!(analysis.final_user.as_deref(), Some("nonroot"));
+
+        // 续行合并为一条指令，行号取起始行
+        let build = analysis
+            .instructions
+            .iter()
+            .find(|i| i.arguments.starts_with("go build"))
+            .unwrap();
+        assert_eq!(build.line_number, 7);
+        assert_eq!(build.arguments, "go build -o /out/app ./cmd/app");
+        assert!(rules(&analysis).is_empty(), "{:?}", rules(&analysis));
+    }
+
+    #[test]
+    fn test_analyze_reports_findings() {
+        let content = "FROM ubuntu\n\
+            ENV DB_PASSWORD=secret\n\
+            COPY . /app\n\
+            RUN apt-get update\n\
+            RUN apt-get install -y curl && npm install\n\
+            RUN curl -fsSL https://example.com/install.sh | sh\n\
+            ADD config.json /etc/app/\n\
+            CMD node server.js\n";
+        let analysis = analyze_dockerfile_content(content).unwrap();
+        let found = rules(&analysis);
+        for rule in [
+            "latest_tag",
+            "secret_in_build",
+            "apt_update_alone",
+            "apt_recommends",
+            "apt_cache",
+            "cache_bust",
+            "merge_runs",
+            "pipe_to_shell",
+            "prefer_copy",
+            "shell_form",
+            "root_user",
+            "no_healthcheck",
+        ] {
+            assert!(found.contains(&rule), "缺少 {}: {:?}", rule, found);
+        }
+        assert_eq!(analysis.stages[0].layer_count, 5);
+    }
+
+    #[test]
+    fn test_escape_directive() {
+        let content = "# escape=`\nFROM mcr.microsoft.com/windows/nanoserver:ltsc2022\nRUN echo a `\n    && echo b\nUSER ContainerUser\n";
+        let analysis = analyze_dockerfile_content(content).unwrap();
+        assert_eq!(analysis.instructions[0].arguments, "echo a && echo b");
+        assert!(!rules(&analysis).contains(&"root_user"));
+    }
+
+    #[test]
+    fn test_analyze_malformed_dockerfile() {
+        assert_eq!(
+            analyze_dockerfile_content("# 只有注释\n\n").unwrap_err(),
+            "Dockerfile 内容不能为空"
+        );
+        assert_eq!(
+            analyze_dockerfile_content("RUN echo hi\n").unwrap_err(),
+            "未找到 FROM 指令，不是有效的 Dockerfile"
+        );
+        let analysis =
+            analyze_dockerfile_content("ARG VERSION=1\nRUN echo hi\nFROM alpine:3.20\n").unwrap();
+        let missing: Vec<_> = analysis
+            .findings
+            .iter()
+            .filter(|f| f.rule == "missing_from")
+            .collect();
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].line_number, Some(2));
+        assert_eq!(missing[0].severity, "error");
+    }
+
+    #[test]
+    fn test_parse_image_reference() {
+        let reference = parse_image_reference("nginx").unwrap();
+        assert_eq!(reference.registry, DOCKER_HUB_REGISTRY);
+        assert_eq!(reference.repository, "library/nginx");
+        assert_eq!(reference.reference, "latest");
+
+        let reference = parse_image_reference("docker.io/Bitnami/redis:7.2").unwrap();
+        assert_eq!(reference.registry, DOCKER_HUB_REGISTRY);
+        assert_eq!(reference.repository, "bitnami/redis");
+        assert_eq!(reference.reference, "7.2");
+
+        let reference = parse_image_reference("localhost:5000/app:dev").unwrap();
+        assert_eq!(reference.registry, "localhost:5000");
+        assert_eq!(reference.repository, "app");
+        assert_eq!(reference.reference, "dev");
+
+        let reference = parse_image_reference("ghcr.io/owner/app@sha256:abc").unwrap();
+        assert_eq!(reference.registry, "ghcr.io");
+        assert_eq!(reference.repository, "owner/app");
+        assert_eq!(reference.reference, "sha256:abc");
+
+        assert!(parse_image_reference("  ").is_err());
+    }
+
+    #[test]
+    fn test_parse_bearer_challenge() {
+        let params = parse_bearer_challenge(
+            r#"Bearer realm="https://auth.docker.io/token",service="registry.docker.io",scope="repository:library/nginx:pull""#,
+        );
+        assert_eq!(params["realm"], "https://auth.docker.io/token");
+        assert_eq!(params["service"], "registry.docker.io");
+        assert_eq!(params["scope"], "repository:library/nginx:pull");
+
+        let params = parse_bearer_challenge("Bearer realm=https://r.example/token, service=r");
+        assert_eq!(params["realm"], "https://r.example/token");
+        assert_eq!(params["service"], "r");
+        assert!(parse_bearer_challenge("Bearer").is_empty());
+    }
+}
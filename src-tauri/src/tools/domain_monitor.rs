@@ -0,0 +1,508 @@
+use crate::tools::whois::{self, WhoisParsed};
+use crate::utils::i18n;
+use crate::utils::notification::{self, NotificationKind};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter};
+
+/// 默认提前提醒天数
+const DEFAULT_NOTIFY_DAYS: i64 = 30;
+/// 默认后台检查间隔（小时）
+const DEFAULT_INTERVAL_HOURS: u64 = 24;
+
+/// 串行化监控列表文件的读写
+static WATCHLIST_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainWatch {
+    #[serde(default)]
+    pub id: String,
+    pub domain: String,
+    #[serde(default = "default_notify_days")]
+    pub notify_days: i64, // 到期前多少天开始提醒
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    #[serde(default)]
+    pub note: Option<String>,
+    #[serde(default)]
+    pub expires: Option<String>, // 最近一次查询到的到期时间（RFC3339）
+    #[serde(default)]
+    pub source: Option<String>, // 最近一次查询的数据来源
+    #[serde(default)]
+    pub last_checked: Option<String>,
+    #[serde(default)]
+    pub last_error: Option<String>,
+    #[serde(default)]
+    pub last_notified: Option<String>, // 最近一次提醒的日期，每天最多提醒一次
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainWatchlist {
+    #[serde(default = "default_interval_hours")]
+    pub interval_hours: u64, // 后台检查间隔，0 表示关闭后台检查
+    #[serde(default)]
+    pub domains: Vec<DomainWatch>,
+}
+
+impl Default for DomainWatchlist {
+    fn default() -> Self {
+        Self {
+            interval_hours: DEFAULT_INTERVAL_HOURS,
+            domains: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainExpiryStatus {
+    pub id: String,
+    pub domain: String,
+    pub status: String, // ok / warning / expired / unknown / error
+    pub expires: Option<String>,
+    pub days_remaining: Option<i64>,
+    pub notify_days: i64,
+    pub registrar: Option<String>,
+    pub source: Option<String>,
+    pub error: Option<String>,
+}
+
+fn default_notify_days() -> i64 {
+    DEFAULT_NOTIFY_DAYS
+}
+
+fn default_interval_hours() -> u64 {
+    DEFAULT_INTERVAL_HOURS
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// 获取域名到期监控列表
+#[tauri::command]
+pub async fn get_domain_watchlist() -> Result<DomainWatchlist, String> {
+    let _guard = WATCHLIST_LOCK.lock().unwrap();
+    Ok(load_watchlist(&get_watchlist_path()))
+}
+
+/// 新增或更新监控域名（按 id 匹配，id 为空时新增）
+#[tauri::command]
+pub async fn save_domain_watch(watch: DomainWatch) -> Result<DomainWatch, String> {
+    let domain = normalize_domain(&watch.domain)?;
+    if watch.notify_days < 0 {
+        return Err(i18n::t("domain_monitor.negative_notify_days"));
+    }
+
+    let path = get_watchlist_path();
+    let _guard = WATCHLIST_LOCK.lock().unwrap();
+    let mut list = load_watchlist(&path);
+    if list
+        .domains
+        .iter()
+        .any(|w| w.domain == domain && w.id != watch.id)
+    {
+        return Err(i18n::tr("domain_monitor.already_watched", &[&domain]));
+    }
+
+    let saved = match list.domains.iter_mut().find(|w| w.id == watch.id) {
+        Some(existing) if !watch.id.is_empty() => {
+            // 更换域名后历史查询结果不再有效
+            if existing.domain != domain {
+                existing.expires = None;
+                existing.source = None;
+                existing.last_checked = None;
+                existing.last_error = None;
+                existing.last_notified = None;
+            }
+            existing.domain = domain;
+            existing.notify_days = watch.notify_days;
+            existing.enabled = watch.enabled;
+            existing.note = watch.note;
+            existing.clone()
+        }
+        _ => {
+            let created = DomainWatch {
+                id: new_watch_id(),
+                domain,
+                notify_days: watch.notify_days,
+                enabled: watch.enabled,
+                note: watch.note,
+                expires: None,
+                source: None,
+                last_checked: None,
+                last_error: None,
+                last_notified: None,
+            };
+            list.domains.push(created.clone());
+            created
+        }
+    };
+    save_watchlist(&path, &list)?;
+    Ok(saved)
+}
+
+/// 从监控列表中移除域名
+#[tauri::command]
+pub async fn remove_domain_watch(id: String) -> Result<(), String> {
+    let path = get_watchlist_path();
+    let _guard = WATCHLIST_LOCK.lock().unwrap();
+    let mut list = load_watchlist(&path);
+    let before = list.domains.len();
+    list.domains.retain(|w| w.id != id);
+    if list.domains.len() == before {
+        return Err(i18n::t("domain_monitor.watch_not_found"));
+    }
+    save_watchlist(&path, &list)
+}
+
+/// 设置后台检查间隔（小时），0 表示关闭
+#[tauri::command]
+pub async fn set_domain_monitor_interval(hours: u64) -> Result<(), String> {
+    let path = get_watchlist_path();
+    let _guard = WATCHLIST_LOCK.lock().unwrap();
+    let mut list = load_watchlist(&path);
+    list.interval_hours = hours;
+    save_watchlist(&path, &list)
+}
+
+/// 立即检查域名到期情况，ids 为空时检查全部启用的域名
+#[tauri::command]
+pub async fn check_domain_expiry(
+    app: AppHandle,
+    ids: Option<Vec<String>>,
+    force_refresh: Option<bool>,
+) -> Result<Vec<DomainExpiryStatus>, String> {
+    run_expiry_check(&app, ids, force_refresh.unwrap_or(false)).await
+}
+
+/// 检查距上次检查已超过监控列表间隔的域名，由后台调度循环定期调用
+pub(crate) async fn run_due_checks(app: AppHandle) {
+    let due = {
+        let _guard = WATCHLIST_LOCK.lock().unwrap();
+        due_watches(&load_watchlist(&get_watchlist_path()), Utc::now())
+    };
+    if due.is_empty() {
+        return;
+    }
+    if let Err(e) = run_expiry_check(&app, Some(due), false).await {
+        eprintln!("Domain expiry check failed: {}", e);
+    }
+}
+
+/// 筛选需要后台检查的启用域名，间隔为 0 表示关闭后台检查
+fn due_watches(list: &DomainWatchlist, now: DateTime<Utc>) -> Vec<String> {
+    if list.interval_hours == 0 {
+        return Vec::new();
+    }
+    let interval_secs = (list.interval_hours * 3600) as i64;
+    list.domains
+        .iter()
+        .filter(|w| w.enabled)
+        .filter(|w| {
+            let last = w
+                .last_checked
+                .as_deref()
+                .and_then(|t| DateTime::parse_from_rfc3339(t).ok());
+            last.is_none_or(|t| (now - t.to_utc()).num_seconds() >= interval_secs)
+        })
+        .map(|w| w.id.clone())
+        .collect()
+}
+
+async fn run_expiry_check(
+    app: &AppHandle,
+    ids: Option<Vec<String>>,
+    force_refresh: bool,
+) -> Result<Vec<DomainExpiryStatus>, String> {
+    let path = get_watchlist_path();
+    let targets: Vec<DomainWatch> = {
+        let _guard = WATCHLIST_LOCK.lock().unwrap();
+        load_watchlist(&path)
+            .domains
+            .into_iter()
+            .filter(|w| match &ids {
+                Some(ids) => ids.contains(&w.id),
+                None => w.enabled,
+            })
+            .collect()
+    };
+
+    let now = Utc::now();
+    let mut statuses = Vec::with_capacity(targets.len());
+    for watch in &targets {
+        let lookup = lookup_expiry(&watch.domain, force_refresh).await;
+        statuses.push(evaluate_watch(watch, lookup, now));
+    }
+
+    // 写回查询结果，并筛选出今天尚未提醒过的告警
+    let today = now.with_timezone(&chrono::Local).date_naive().to_string();
+    let mut alerts = Vec::new();
+    {
+        let _guard = WATCHLIST_LOCK.lock().unwrap();
+        let mut list = load_watchlist(&path);
+        for status in &statuses {
+            let Some(watch) = list.domains.iter_mut().find(|w| w.id == status.id) else {
+                continue;
+            };
+            watch.last_checked = Some(now.to_rfc3339());
+            watch.last_error = status.error.clone();
+            if status.expires.is_some() {
+                watch.expires = status.expires.clone();
+                watch.source = status.source.clone();
+            }
+            let alerting = matches!(status.status.as_str(), "warning" | "expired");
+            if alerting && watch.last_notified.as_deref() != Some(today.as_str()) {
+                watch.last_notified = Some(today.clone());
+                alerts.push(status.clone());
+            }
+        }
+        save_watchlist(&path, &list)?;
+    }
+
+    if !alerts.is_empty() {
+        let _ = app.emit("domain-expiry-alert", &alerts);
+        let lines: Vec<String> = alerts
+            .iter()
+            .map(|s| match s.days_remaining {
+                Some(days) if days > 0 => i18n::tr(
+                    "domain_monitor.days_remaining",
+                    &[&s.domain, &days.to_string()],
+                ),
+                _ => i18n::tr("domain_monitor.expired", &[&s.domain]),
+            })
+            .collect();
+        notification::notify(app, NotificationKind::DomainExpiring, &lines.join("\n"));
+    }
+    Ok(statuses)
+}
+
+/// 优先使用 RDAP，失败时回退到 WHOIS
+pub(crate) async fn lookup_expiry(
+    domain: &str,
+    force_refresh: bool,
+) -> Result<WhoisParsed, String> {
+    let force_refresh = Some(force_refresh);
+    match whois::query_rdap(domain.to_string(), force_refresh).await {
+        Ok(parsed) if parsed.expires.is_some() => Ok(parsed),
+        rdap => match whois::query_whois(domain.to_string(), force_refresh).await {
+            Ok(parsed) => Ok(parsed),
+            Err(e) => rdap.map_err(|rdap_err| format!("{}; {}", rdap_err, e)),
+        },
+    }
+}
+
+fn evaluate_watch(
+    watch: &DomainWatch,
+    lookup: Result<WhoisParsed, String>,
+    now: DateTime<Utc>,
+) -> DomainExpiryStatus {
+    let mut status = DomainExpiryStatus {
+        id: watch.id.clone(),
+        domain: watch.domain.clone(),
+        status: "unknown".to_string(),
+        expires: None,
+        days_remaining: None,
+        notify_days: watch.notify_days,
+        registrar: None,
+        source: None,
+        error: None,
+    };
+
+    let parsed = match lookup {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            status.status = "error".to_string();
+            status.error = Some(e);
+            return status;
+        }
+    };
+    status.registrar = parsed.registrar.clone();
+    status.source = Some(parsed.source.clone());
+
+    let Some(expires) = parsed.expires.as_deref().and_then(parse_expiry_date) else {
+        status.error = parsed
+            .expires
+            .map(|raw| i18n::tr("domain_monitor.unparsable_expiry", &[&raw]))
+            .or_else(|| Some(i18n::t("domain_monitor.expiry_not_found")));
+        return status;
+    };
+
+    let days = (expires - now).num_days();
+    status.expires = Some(expires.to_rfc3339());
+    status.days_remaining = Some(days);
+    status.status = if expires <= now {
+        "expired"
+    } else if days <= watch.notify_days {
+        "warning"
+    } else {
+        "ok"
+    }
+    .to_string();
+    status
+}
+
+/// 解析 RDAP/WHOIS 中常见的到期时间格式
+pub(crate) fn parse_expiry_date(raw: &str) -> Option<DateTime<Utc>> {
+    let value = raw.trim();
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    // 部分注册局在时间后附带时区说明，如 "2025-01-01 00:00:00 (UTC+8)"
+    let value = value.split(" (").next().unwrap_or(value).trim();
+    let value = value.trim_end_matches(" UTC").trim_end_matches('Z');
+
+    const DATETIME_FORMATS: [&str; 4] = [
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y.%m.%d %H:%M:%S",
+        "%d-%b-%Y %H:%M:%S",
+    ];
+    const DATE_FORMATS: [&str; 6] = [
+        "%Y-%m-%d", "%Y.%m.%d", "%Y/%m/%d", "%d-%b-%Y", "%d.%m.%Y", "%d/%m/%Y",
+    ];
+
+    DATETIME_FORMATS
+        .iter()
+        .find_map(|f| NaiveDateTime::parse_from_str(value, f).ok())
+        .or_else(|| {
+            DATE_FORMATS
+                .iter()
+                .find_map(|f| NaiveDate::parse_from_str(value, f).ok())
+                .and_then(|d| d.and_hms_opt(0, 0, 0))
+        })
+        .map(|dt| dt.and_utc())
+}
+
+fn normalize_domain(domain: &str) -> Result<String, String> {
+    let domain = domain.trim().trim_end_matches('.').to_lowercase();
+    if domain.is_empty() {
+        return Err(i18n::t("common.domain_required"));
+    }
+    if !domain.contains('.') || domain.contains(char::is_whitespace) || domain.contains('/') {
+        return Err(i18n::tr("common.invalid_domain", &[&domain]));
+    }
+    Ok(domain)
+}
+
+fn new_watch_id() -> String {
+    format!("{:x}", Utc::now().timestamp_nanos_opt().unwrap_or_default())
+}
+
+fn get_watchlist_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("devtools")
+        .join("domain-watchlist.json")
+}
+
+fn load_watchlist(path: &Path) -> DomainWatchlist {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_watchlist(path: &Path, list: &DomainWatchlist) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| i18n::tr("common.create_dir_failed", &[&e.to_string()]))?;
+    }
+    let content = serde_json::to_string_pretty(list)
+        .map_err(|e| i18n::tr("common.serialize_failed", &[&e.to_string()]))?;
+    fs::write(path, content).map_err(|e| i18n::tr("domain_monitor.save_failed", &[&e.to_string()]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_expiry_date_formats() {
+        let expected = "2030-08-13T00:00:00+00:00";
+        for raw in [
+            "2030-08-13T00:00:00Z",
+            "2030-08-13T00:00:00.000Z",
+            "2030-08-13 00:00:00",
+            "2030-08-13",
+            "13-aug-2030",
+            "2030.08.13",
+        ] {
+            let parsed = parse_expiry_date(raw).unwrap_or_else(|| panic!("{}", raw));
+            assert_eq!(parsed.to_rfc3339(), expected, "{}", raw);
+        }
+        assert!(parse_expiry_date("not a date").is_none());
+    }
+
+    #[test]
+    fn test_due_watches() {
+        let now = DateTime::parse_from_rfc3339("2030-01-02T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let watch = |id: &str, enabled: bool, last_checked: Option<&str>| DomainWatch {
+            id: id.to_string(),
+            domain: format!("{}.example.com", id),
+            notify_days: 30,
+            enabled,
+            note: None,
+            expires: None,
+            source: None,
+            last_checked: last_checked.map(str::to_string),
+            last_error: None,
+            last_notified: None,
+        };
+        let mut list = DomainWatchlist {
+            interval_hours: 24,
+            domains: vec![
+                watch("never", true, None),
+                watch("stale", true, Some("2030-01-01T00:00:00Z")),
+                watch("fresh", true, Some("2030-01-01T12:00:00Z")),
+                watch("disabled", false, None),
+            ],
+        };
+        assert_eq!(due_watches(&list, now), vec!["never", "stale"]);
+        list.interval_hours = 0;
+        assert!(due_watches(&list, now).is_empty());
+    }
+
+    #[test]
+    fn test_evaluate_watch_thresholds() {
+        let now = DateTime::parse_from_rfc3339("2030-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let watch = DomainWatch {
+            id: "1".to_string(),
+            domain: "example.com".to_string(),
+            notify_days: 30,
+            enabled: true,
+            note: None,
+            expires: None,
+            source: None,
+            last_checked: None,
+            last_error: None,
+            last_notified: None,
+        };
+        let lookup = |expires: Option<&str>| {
+            let mut parsed =
+                whois::parse_whois_text("example.com", "whois.test", "Registrar: Test\n");
+            parsed.expires = expires.map(|s| s.to_string());
+            Ok(parsed)
+        };
+
+        let ok = evaluate_watch(&watch, lookup(Some("2030-06-01T00:00:00Z")), now);
+        assert_eq!(ok.status, "ok");
+        let warning = evaluate_watch(&watch, lookup(Some("2030-01-20")), now);
+        assert_eq!(warning.status, "warning");
+        assert_eq!(warning.days_remaining, Some(19));
+        let expired = evaluate_watch(&watch, lookup(Some("2029-12-01")), now);
+        assert_eq!(expired.status, "expired");
+        assert_eq!(evaluate_watch(&watch, lookup(None), now).status, "unknown");
+        let error = evaluate_watch(&watch, Err("timeout".to_string()), now);
+        assert_eq!(error.status, "error");
+    }
+}
@@ -0,0 +1,1065 @@
+use crate::tools::ssl_checker::webpki_tls_connector;
+use base64::{engine::general_purpose, Engine as _};
+use mail_parser::{Address, HeaderValue, Message, MessageParser, MimeHeaders, PartType};
+use rustls::pki_types::ServerName;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmailHeader {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReceivedHop {
+    pub index: usize,
+    pub from: Option<String>,
+    pub by: Option<String>,
+    pub protocol: Option<String>,
+    pub date: Option<String>,
+    pub delay_seconds: Option<i64>, // 相对上一跳的耗时
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthResultEntry {
+    pub method: String, // spf / dkim / dmarc / arc ...
+    pub result: String,
+    pub properties: Vec<EmailHeader>,
+    pub source: String, // 来源头部
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DkimSignatureInfo {
+    pub domain: Option<String>,
+    pub selector: Option<String>,
+    pub algorithm: Option<String>,
+    pub canonicalization: Option<String>,
+    pub signed_headers: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmailAuthAnalysis {
+    pub results: Vec<AuthResultEntry>,
+    pub spf: Option<String>,
+    pub dkim: Option<String>,
+    pub dmarc: Option<String>,
+    pub dkim_signatures: Vec<DkimSignatureInfo>,
+    pub from_domain: Option<String>,
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MimePartInfo {
+    pub id: usize,
+    pub depth: usize,
+    pub content_type: String,
+    pub charset: Option<String>,
+    pub transfer_encoding: Option<String>,
+    pub disposition: Option<String>,
+    pub filename: Option<String>,
+    pub size: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmailAttachment {
+    pub filename: String,
+    pub content_type: String,
+    pub size: usize,
+    pub content_id: Option<String>,
+    pub sha256: String,
+    pub inline: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmailParseResult {
+    pub subject: Option<String>,
+    pub from: Vec<String>,
+    pub to: Vec<String>,
+    pub cc: Vec<String>,
+    pub reply_to: Vec<String>,
+    pub date: Option<String>,
+    pub message_id: Option<String>,
+    pub headers: Vec<EmailHeader>,
+    pub received: Vec<ReceivedHop>,
+    pub authentication: EmailAuthAnalysis,
+    pub mime_structure: Vec<MimePartInfo>,
+    pub attachments: Vec<EmailAttachment>,
+    pub text_body: Option<String>,
+    pub html_body: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmtpTestRequest {
+    pub host: String,
+    pub port: u16,
+    pub security: String, // none / starttls / tls
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub from: String,
+    pub to: Vec<String>,
+    pub subject: Option<String>,
+    pub body: Option<String>,
+    pub timeout_secs: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmtpTranscriptLine {
+    pub direction: String, // client / server
+    pub line: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmtpTestResult {
+    pub success: bool,
+    pub error: Option<String>,
+    pub banner: Option<String>,
+    pub capabilities: Vec<String>,
+    pub tls_version: Option<String>,
+    pub cipher_suite: Option<String>,
+    pub authenticated: bool,
+    pub message_id: Option<String>,
+    pub elapsed_ms: u64,
+    pub transcript: Vec<SmtpTranscriptLine>,
+}
+
+fn format_address(address: Option<&Address>) -> Vec<String> {
+    address
+        .map(|a| {
+            a.iter()
+                .map(|addr| match (addr.name(), addr.address()) {
+                    (Some(name), Some(email)) => format!("{} <{}>", name, email),
+                    (None, Some(email)) => email.to_string(),
+                    (Some(name), None) => name.to_string(),
+                    (None, None) => String::new(),
+                })
+                .filter(|s| !s.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn unfold(value: &str) -> String {
+    value
+        .split(['\r', '\n'])
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn header_display_value(message: &Message, index: usize) -> String {
+    let header = &message.headers()[index];
+    let decoded = match header.value() {
+        HeaderValue::Text(text) => Some(unfold(text)),
+        HeaderValue::TextList(list) => Some(list.join(", ")),
+        HeaderValue::Address(address) => Some(format_address(Some(address)).join(", ")),
+        HeaderValue::DateTime(dt) => Some(dt.to_rfc3339()),
+        _ => None,
+    };
+    decoded.unwrap_or_else(|| {
+        let raw = message
+            .raw_message()
+            .get(header.offset_start() as usize..header.offset_end() as usize)
+            .unwrap_or_default();
+        unfold(&String::from_utf8_lossy(raw))
+    })
+}
+
+/// 解析 `a=1; b=2` 形式的标签列表
+fn parse_tag_list(value: &str) -> Vec<(String, String)> {
+    value
+        .split(';')
+        .filter_map(|tag| {
+            let (key, val) = tag.split_once('=')?;
+            Some((
+                key.trim().to_lowercase(),
+                val.split_whitespace().collect::<String>(),
+            ))
+        })
+        .collect()
+}
+
+fn domain_of(address: &str) -> Option<String> {
+    address
+        .trim()
+        .trim_matches(['<', '>'])
+        .rsplit_once('@')
+        .map(|(_, domain)| domain.to_lowercase())
+        .or_else(|| Some(address.trim().to_lowercase()).filter(|d| d.contains('.')))
+}
+
+/// 粗略取组织域名（末两级），用于宽松对齐判断
+fn organizational_domain(domain: &str) -> String {
+    let labels: Vec<&str> = domain.trim_end_matches('.').split('.').collect();
+    let second_level = ["com", "net", "org", "gov", "edu", "co", "ac"];
+    let take = if labels.len() >= 3 && second_level.contains(&labels[labels.len() - 2]) {
+        3
+    } else {
+        2
+    };
+    labels[labels.len().saturating_sub(take)..].join(".")
+}
+
+fn parse_authentication_results(value: &str, source: &str) -> Vec<AuthResultEntry> {
+    // 去除括号注释
+    let mut cleaned = String::new();
+    let mut depth = 0;
+    for c in value.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth > 0 => depth -= 1,
+            _ if depth == 0 => cleaned.push(c),
+            _ => {}
+        }
+    }
+    cleaned
+        .split(';')
+        .skip(1) // authserv-id
+        .filter_map(|clause| {
+            let mut tokens = clause.split_whitespace();
+            let (method, result) = tokens.next()?.split_once('=')?;
+            let properties = tokens
+                .filter_map(|t| t.split_once('='))
+                .map(|(k, v)| EmailHeader {
+                    name: k.to_string(),
+                    value: v.trim_matches('"').to_string(),
+                })
+                .collect();
+            Some(AuthResultEntry {
+                method: method.to_lowercase(),
+                result: result.to_lowercase(),
+                properties,
+                source: source.to_string(),
+            })
+        })
+        .collect()
+}
+
+fn analyze_authentication(headers: &[EmailHeader], from: &[String]) -> EmailAuthAnalysis {
+    let mut results = Vec::new();
+    let mut dkim_signatures = Vec::new();
+    for header in headers {
+        match header.name.to_lowercase().as_str() {
+            "authentication-results" | "arc-authentication-results" => {
+                results.extend(parse_authentication_results(&header.value, &header.name));
+            }
+            "received-spf" => {
+                if let Some(result) = header.value.split_whitespace().next() {
+                    results.push(AuthResultEntry {
+                        method: "spf".to_string(),
+                        result: result.to_lowercase(),
+                        properties: Vec::new(),
+                        source: header.name.clone(),
+                    });
+                }
+            }
+            "dkim-signature" => {
+                let tags = parse_tag_list(&header.value);
+                let get = |key: &str| tags.iter().find(|(k, _)| k == key).map(|(_, v)| v.clone());
+                dkim_signatures.push(DkimSignatureInfo {
+                    domain: get("d"),
+                    selector: get("s"),
+                    algorithm: get("a"),
+                    canonicalization: get("c"),
+                    signed_headers: get("h")
+                        .map(|h| h.split(':').map(|s| s.trim().to_lowercase()).collect())
+                        .unwrap_or_default(),
+                });
+            }
+            _ => {}
+        }
+    }
+
+    // 优先使用第一个 Authentication-Results（最靠近收件方的验证结果）
+    let pick = |method: &str| {
+        results
+            .iter()
+            .filter(|r| r.method == method)
+            .min_by_key(|r| r.source.to_lowercase().starts_with("arc-"))
+            .map(|r| r.result.clone())
+    };
+    let spf = pick("spf");
+    let dkim = pick("dkim");
+    let dmarc = pick("dmarc");
+
+    let from_domain = from.first().and_then(|f| {
+        let email = f.rsplit_once('<').map(|(_, e)| e).unwrap_or(f);
+        domain_of(email)
+    });
+
+    let mut warnings = Vec::new();
+    if results.is_empty() {
+        warnings
+            .push("邮件中没有 Authentication-Results 头，无法确认收件服务器的验证结果".to_string());
+    }
+    for (name, value) in [("SPF", &spf), ("DKIM", &dkim), ("DMARC", &dmarc)] {
+        if let Some(v) = value {
+            if v != "pass" {
+                warnings.push(format!("{} 验证结果为 {}", name, v));
+            }
+        }
+    }
+    if dkim_signatures.is_empty() {
+        warnings.push("邮件未包含 DKIM 签名".to_string());
+    }
+    if let Some(from_domain) = &from_domain {
+        let org = organizational_domain(from_domain);
+        let aligned = dkim_signatures.iter().any(|s| {
+            s.domain
+                .as_deref()
+                .is_some_and(|d| organizational_domain(&d.to_lowercase()) == org)
+        });
+        if !dkim_signatures.is_empty() && !aligned {
+            warnings.push(format!(
+                "DKIM 签名域与发件人域 {} 不对齐，DMARC 只能依赖 SPF",
+                from_domain
+            ));
+        }
+        let mailfrom = results
+            .iter()
+            .filter(|r| r.method == "spf")
+            .flat_map(|r| r.properties.iter())
+            .find(|p| p.name == "smtp.mailfrom")
+            .and_then(|p| domain_of(&p.value));
+        if let Some(mailfrom) = mailfrom {
+            if organizational_domain(&mailfrom) != org {
+                warnings.push(format!(
+                    "SPF 验证的信封发件域 {} 与发件人域 {} 不对齐",
+                    mailfrom, from_domain
+                ));
+            }
+        }
+        for signature in &dkim_signatures {
+            if !signature.signed_headers.iter().any(|h| h == "from") {
+                warnings.push("DKIM 签名未覆盖 From 头".to_string());
+            }
+        }
+    }
+
+    EmailAuthAnalysis {
+        results,
+        spf,
+        dkim,
+        dmarc,
+        dkim_signatures,
+        from_domain,
+        warnings,
+    }
+}
+
+fn parse_received_hops(headers: &[EmailHeader]) -> Vec<ReceivedHop> {
+    let word_after = |value: &str, keyword: &str| {
+        let mut tokens = value.split_whitespace();
+        while let Some(token) = tokens.next() {
+            if token.eq_ignore_ascii_case(keyword) {
+                return tokens.next().map(|t| t.trim_end_matches(';').to_string());
+            }
+        }
+        None
+    };
+
+    // Received 头按从新到旧排列，反转后按投递顺序展示
+    let mut hops: Vec<ReceivedHop> = Vec::new();
+    let mut previous: Option<chrono::DateTime<chrono::FixedOffset>> = None;
+    for (index, header) in headers
+        .iter()
+        .filter(|h| h.name.eq_ignore_ascii_case("received"))
+        .rev()
+        .enumerate()
+    {
+        let date_text = header
+            .value
+            .rsplit_once(';')
+            .map(|(_, d)| d.trim().to_string());
+        let parsed = date_text.as_deref().and_then(|d| {
+            // 去除末尾的时区注释，如 "(UTC)"
+            let d = d.split(" (").next().unwrap_or(d);
+            chrono::DateTime::parse_from_rfc2822(d).ok()
+        });
+        let delay_seconds = match (previous, parsed) {
+            (Some(prev), Some(current)) => Some((current - prev).num_seconds()),
+            _ => None,
+        };
+        if parsed.is_some() {
+            previous = parsed;
+        }
+        hops.push(ReceivedHop {
+            index: index + 1,
+            from: word_after(&header.value, "from"),
+            by: word_after(&header.value, "by"),
+            protocol: word_after(&header.value, "with"),
+            date: parsed.map(|d| d.to_rfc3339()).or(date_text),
+            delay_seconds,
+        });
+    }
+    hops
+}
+
+fn collect_mime_parts(
+    message: &Message,
+    part_id: usize,
+    depth: usize,
+    out: &mut Vec<MimePartInfo>,
+) {
+    let Some(part) = message.parts.get(part_id) else {
+        return;
+    };
+    let content_type = part
+        .content_type()
+        .map(|ct| match ct.subtype() {
+            Some(sub) => format!("{}/{}", ct.ctype(), sub),
+            None => ct.ctype().to_string(),
+        })
+        .unwrap_or_else(|| "text/plain".to_string());
+    out.push(MimePartInfo {
+        id: part_id,
+        depth,
+        content_type,
+        charset: part
+            .content_type()
+            .and_then(|ct| ct.attribute("charset"))
+            .map(str::to_string),
+        transfer_encoding: part.content_transfer_encoding().map(str::to_string),
+        disposition: part.content_disposition().map(|d| d.ctype().to_string()),
+        filename: part.attachment_name().map(str::to_string),
+        size: part.len(),
+    });
+    if let PartType::Multipart(children) = &part.body {
+        for child in children {
+            collect_mime_parts(message, *child as usize, depth + 1, out);
+        }
+    }
+}
+
+fn parse_eml_bytes(data: &[u8]) -> Result<EmailParseResult, String> {
+    if data.starts_with(&[0xD0, 0xCF, 0x11, 0xE0]) {
+        return Err("暂不支持 Outlook .msg 格式，请先导出为 .eml".to_string());
+    }
+    let message = MessageParser::default()
+        .parse(data)
+        .ok_or("无法解析邮件内容".to_string())?;
+    if message.headers().is_empty() {
+        return Err("未找到邮件头，内容可能不是 EML 格式".to_string());
+    }
+
+    let headers: Vec<EmailHeader> = message
+        .headers()
+        .iter()
+        .enumerate()
+        .map(|(i, h)| EmailHeader {
+            name: h.name().to_string(),
+            value: header_display_value(&message, i),
+        })
+        .collect();
+
+    let from = format_address(message.from());
+    let authentication = analyze_authentication(&headers, &from);
+
+    let mut mime_structure = Vec::new();
+    collect_mime_parts(&message, 0, 0, &mut mime_structure);
+
+    let attachments = message
+        .attachments()
+        .map(|part| {
+            let contents = part.contents();
+            EmailAttachment {
+                filename: part.attachment_name().unwrap_or("(未命名)").to_string(),
+                content_type: part
+                    .content_type()
+                    .map(|ct| match ct.subtype() {
+                        Some(sub) => format!("{}/{}", ct.ctype(), sub),
+                        None => ct.ctype().to_string(),
+                    })
+                    .unwrap_or_else(|| "application/octet-stream".to_string()),
+                size: contents.len(),
+                content_id: part.content_id().map(str::to_string),
+                sha256: hex::encode(Sha256::digest(contents)),
+                inline: part.content_disposition().is_some_and(|d| d.is_inline()),
+            }
+        })
+        .collect();
+
+    Ok(EmailParseResult {
+        subject: message.subject().map(str::to_string),
+        to: format_address(message.to()),
+        cc: format_address(message.cc()),
+        reply_to: format_address(message.reply_to()),
+        date: message.date().map(|d| d.to_rfc3339()),
+        message_id: message.message_id().map(str::to_string),
+        received: parse_received_hops(&headers),
+        text_body: message.body_text(0).map(|b| b.into_owned()),
+        html_body: message.body_html(0).map(|b| b.into_owned()),
+        from,
+        headers,
+        authentication,
+        mime_structure,
+        attachments,
+    })
+}
+
+/// 解析 EML 邮件：头部、投递路径、SPF/DKIM/DMARC 结果、MIME 结构与附件
+#[tauri::command]
+pub fn parse_eml(eml_data: Vec<u8>) -> Result<EmailParseResult, String> {
+    if eml_data.is_empty() {
+        return Err("邮件内容不能为空".to_string());
+    }
+    parse_eml_bytes(&eml_data)
+}
+
+struct SmtpSession {
+    transcript: Vec<SmtpTranscriptLine>,
+}
+
+impl SmtpSession {
+    async fn read_reply<S: AsyncRead + AsyncWrite + Unpin>(
+        &mut self,
+        stream: &mut BufReader<S>,
+    ) -> Result<(u16, Vec<String>), String> {
+        let mut lines = Vec::new();
+        loop {
+            let mut line = String::new();
+            let n = stream
+                .read_line(&mut line)
+                .await
+                .map_err(|e| format!("读取服务器响应失败: {}", e))?;
+            if n == 0 {
+                return Err("服务器关闭了连接".to_string());
+            }
+            let line = line.trim_end().to_string();
+            self.transcript.push(SmtpTranscriptLine {
+                direction: "server".to_string(),
+                line: line.clone(),
+            });
+            let code = line
+                .get(..3)
+                .and_then(|c| c.parse::<u16>().ok())
+                .ok_or_else(|| format!("无效的 SMTP 响应: {}", line))?;
+            let last = line.as_bytes().get(3) != Some(&b'-');
+            lines.push(line.get(4..).unwrap_or_default().to_string());
+            if last {
+                return Ok((code, lines));
+            }
+        }
+    }
+
+    async fn command<S: AsyncRead + AsyncWrite + Unpin>(
+        &mut self,
+        stream: &mut BufReader<S>,
+        command: &str,
+        logged: Option<&str>,
+    ) -> Result<(u16, Vec<String>), String> {
+        self.transcript.push(SmtpTranscriptLine {
+            direction: "client".to_string(),
+            line: logged.unwrap_or(command).to_string(),
+        });
+        stream
+            .get_mut()
+            .write_all(format!("{}\r\n", command).as_bytes())
+            .await
+            .map_err(|e| format!("发送命令失败: {}", e))?;
+        self.read_reply(stream).await
+    }
+
+    async fn expect<S: AsyncRead + AsyncWrite + Unpin>(
+        &mut self,
+        stream: &mut BufReader<S>,
+        command: &str,
+        logged: Option<&str>,
+        expected: &[u16],
+    ) -> Result<Vec<String>, String> {
+        let (code, lines) = self.command(stream, command, logged).await?;
+        if !expected.contains(&code) {
+            return Err(format!(
+                "{} 失败: {} {}",
+                logged
+                    .unwrap_or(command)
+                    .split_whitespace()
+                    .next()
+                    .unwrap_or(command),
+                code,
+                lines.join(" ")
+            ));
+        }
+        Ok(lines)
+    }
+}
+
+fn encode_header_word(value: &str) -> String {
+    if value.is_ascii() {
+        value.to_string()
+    } else {
+        format!("=?UTF-8?B?{}?=", general_purpose::STANDARD.encode(value))
+    }
+}
+
+fn build_test_message(request: &SmtpTestRequest, message_id: &str) -> String {
+    let subject = request
+        .subject
+        .clone()
+        .filter(|s| !s.trim().is_empty())
+        .unwrap_or_else(|| "DevTools SMTP 测试邮件".to_string());
+    let body = request.body.clone().unwrap_or_else(|| {
+        format!(
+            "这是一封由 DevTools 发送的测试邮件。\r\n服务器: {}:{}\r\n时间: {}",
+            request.host,
+            request.port,
+            chrono::Local::now().format("%Y-%m-%d %H:%M:%S")
+        )
+    });
+    let encoded_body = general_purpose::STANDARD.encode(body.as_bytes());
+    let wrapped: Vec<&str> = encoded_body
+        .as_bytes()
+        .chunks(76)
+        .map(|c| std::str::from_utf8(c).unwrap_or_default())
+        .collect();
+
+    [
+        format!("From: {}", request.from),
+        format!("To: {}", request.to.join(", ")),
+        format!("Subject: {}", encode_header_word(&subject)),
+        format!("Date: {}", chrono::Local::now().to_rfc2822()),
+        format!("Message-ID: {}", message_id),
+        "MIME-Version: 1.0".to_string(),
+        "Content-Type: text/plain; charset=UTF-8".to_string(),
+        "Content-Transfer-Encoding: base64".to_string(),
+        String::new(),
+        wrapped.join("\r\n"),
+    ]
+    .join("\r\n")
+}
+
+fn parse_capabilities(lines: &[String]) -> Vec<String> {
+    lines.iter().skip(1).map(|l| l.trim().to_string()).collect()
+}
+
+/// EHLO 之后的认证与投递流程
+async fn smtp_deliver<S: AsyncRead + AsyncWrite + Unpin>(
+    session: &mut SmtpSession,
+    stream: &mut BufReader<S>,
+    request: &SmtpTestRequest,
+    capabilities: &[String],
+    message_id: &str,
+) -> Result<bool, String> {
+    let mut authenticated = false;
+    if let Some(username) = request.username.as_deref().filter(|u| !u.is_empty()) {
+        let password = request.password.clone().unwrap_or_default();
+        let auth_line = capabilities
+            .iter()
+            .find(|c| c.to_uppercase().starts_with("AUTH"))
+            .map(|c| c.to_uppercase())
+            .unwrap_or_default();
+        if auth_line.contains("PLAIN") || !auth_line.contains("LOGIN") {
+            let token = general_purpose::STANDARD.encode(format!("\0{}\0{}", username, password));
+            session
+                .expect(
+                    stream,
+                    &format!("AUTH PLAIN {}", token),
+                    Some("AUTH PLAIN ******"),
+                    &[235],
+                )
+                .await?;
+        } else {
+            session.expect(stream, "AUTH LOGIN", None, &[334]).await?;
+            session
+                .expect(
+                    stream,
+                    &general_purpose::STANDARD.encode(username),
+                    Some("****** (username)"),
+                    &[334],
+                )
+                .await?;
+            session
+                .expect(
+                    stream,
+                    &general_purpose::STANDARD.encode(&password),
+                    Some("****** (password)"),
+                    &[235],
+                )
+                .await?;
+        }
+        authenticated = true;
+    }
+
+    let envelope_from = request
+        .from
+        .rsplit_once('<')
+        .map(|(_, e)| e.trim_end_matches('>'))
+        .unwrap_or(&request.from)
+        .trim();
+    session
+        .expect(
+            stream,
+            &format!("MAIL FROM:<{}>", envelope_from),
+            None,
+            &[250],
+        )
+        .await?;
+    for recipient in &request.to {
+        let address = recipient
+            .rsplit_once('<')
+            .map(|(_, e)| e.trim_end_matches('>'))
+            .unwrap_or(recipient)
+            .trim();
+        session
+            .expect(stream, &format!("RCPT TO:<{}>", address), None, &[250, 251])
+            .await?;
+    }
+    session.expect(stream, "DATA", None, &[354]).await?;
+
+    let message = build_test_message(request, message_id);
+    session.transcript.push(SmtpTranscriptLine {
+        direction: "client".to_string(),
+        line: format!("<邮件内容 {} 字节>", message.len()),
+    });
+    stream
+        .get_mut()
+        .write_all(format!("{}\r\n.\r\n", message).as_bytes())
+        .await
+        .map_err(|e| format!("发送邮件内容失败: {}", e))?;
+    let (code, lines) = session.read_reply(stream).await?;
+    if code != 250 {
+        return Err(format!("邮件被拒绝: {} {}", code, lines.join(" ")));
+    }
+    // QUIT 失败不影响结果
+    let _ = session.command(stream, "QUIT", None).await;
+    Ok(authenticated)
+}
+
+async fn run_smtp_test(
+    request: &SmtpTestRequest,
+    session: &mut SmtpSession,
+    result: &mut SmtpTestResult,
+) -> Result<(), String> {
+    let security = request.security.to_lowercase();
+    let ehlo = "EHLO devtools.local";
+    let message_id = format!(
+        "<{}.devtools@{}>",
+        chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default(),
+        domain_of(&request.from).unwrap_or_else(|| "localhost".to_string())
+    );
+    let server_name = ServerName::try_from(request.host.clone())
+        .map_err(|_| format!("无效的主机名: {}", request.host))?;
+
+    let tcp = TcpStream::connect((request.host.as_str(), request.port))
+        .await
+        .map_err(|e| format!("连接 {}:{} 失败: {}", request.host, request.port, e))?;
+
+    match security.as_str() {
+        "tls" => {
+            let tls = webpki_tls_connector()
+                .connect(server_name, tcp)
+                .await
+                .map_err(|e| format!("TLS 握手失败: {}", e))?;
+            let (_, connection) = tls.get_ref();
+            result.tls_version = connection.protocol_version().map(|v| format!("{:?}", v));
+            result.cipher_suite = connection
+                .negotiated_cipher_suite()
+                .map(|c| format!("{:?}", c.suite()));
+            let mut stream = BufReader::new(tls);
+            let (_, greeting) = session.read_reply(&mut stream).await?;
+            result.banner = greeting.first().cloned();
+            let lines = session.expect(&mut stream, ehlo, None, &[250]).await?;
+            result.capabilities = parse_capabilities(&lines);
+            result.authenticated = smtp_deliver(
+                session,
+                &mut stream,
+                request,
+                &result.capabilities,
+                &message_id,
+            )
+            .await?;
+        }
+        "starttls" | "none" => {
+            let mut stream = BufReader::new(tcp);
+            let (_, greeting) = session.read_reply(&mut stream).await?;
+            result.banner = greeting.first().cloned();
+            let lines = session.expect(&mut stream, ehlo, None, &[250]).await?;
+            result.capabilities = parse_capabilities(&lines);
+
+            if security == "none" {
+                result.authenticated = smtp_deliver(
+                    session,
+                    &mut stream,
+                    request,
+                    &result.capabilities,
+                    &message_id,
+                )
+                .await?;
+            } else {
+                if !result
+                    .capabilities
+                    .iter()
+                    .any(|c| c.eq_ignore_ascii_case("STARTTLS"))
+                {
+                    return Err("服务器未声明支持 STARTTLS".to_string());
+                }
+                session
+                    .expect(&mut stream, "STARTTLS", None, &[220])
+                    .await?;
+                let tls = webpki_tls_connector()
+                    .connect(server_name, stream.into_inner())
+                    .await
+                    .map_err(|e| format!("STARTTLS 握手失败: {}", e))?;
+                let (_, connection) = tls.get_ref();
+                result.tls_version = connection.protocol_version().map(|v| format!("{:?}", v));
+                result.cipher_suite = connection
+                    .negotiated_cipher_suite()
+                    .map(|c| format!("{:?}", c.suite()));
+                let mut stream = BufReader::new(tls);
+                // 升级后需重新 EHLO
+                let lines = session.expect(&mut stream, ehlo, None, &[250]).await?;
+                result.capabilities = parse_capabilities(&lines);
+                result.authenticated = smtp_deliver(
+                    session,
+                    &mut stream,
+                    request,
+                    &result.capabilities,
+                    &message_id,
+                )
+                .await?;
+            }
+        }
+        other => return Err(format!("不支持的加密方式: {}", other)),
+    }
+    result.message_id = Some(message_id);
+    Ok(())
+}
+
+/// 通过 SMTP 服务器发送测试邮件，返回完整会话记录以便排查配置问题
+#[tauri::command]
+pub async fn send_test_email(request: SmtpTestRequest) -> Result<SmtpTestResult, String> {
+    if request.host.trim().is_empty() {
+        return Err("SMTP 服务器地址不能为空".to_string());
+    }
+    if request.from.trim().is_empty() || request.to.is_empty() {
+        return Err("发件人和收件人不能为空".to_string());
+    }
+
+    let start = Instant::now();
+    let timeout = Duration::from_secs(request.timeout_secs.unwrap_or(30));
+    let mut session = SmtpSession {
+        transcript: Vec::new(),
+    };
+    let mut result = SmtpTestResult {
+        success: false,
+        error: None,
+        banner: None,
+        capabilities: Vec::new(),
+        tls_version: None,
+        cipher_suite: None,
+        authenticated: false,
+        message_id: None,
+        elapsed_ms: 0,
+        transcript: Vec::new(),
+    };
+
+    let outcome = tokio::time::timeout(timeout, run_smtp_test(&request, &mut session, &mut result))
+        .await
+        .unwrap_or_else(|_| Err(format!("操作超时（{} 秒）", timeout.as_secs())));
+    match outcome {
+        Ok(()) => result.success = true,
+        Err(e) => result.error = Some(e),
+    }
+    result.elapsed_ms = start.elapsed().as_millis() as u64;
+    result.transcript = session.transcript;
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_EML: &str = "Received: from mx.example.net (mx.example.net [192.0.2.10])\r
+\tby mail.example.org with ESMTPS id abc123;\r
+\tMon, 6 Jan 2025 10:00:05 +0000\r
+Received: from client.example.com (client.example.com [198.51.100.7])\r
+\tby mx.example.net with ESMTP id def456;\r
+\tMon, 6 Jan 2025 10:00:00 +0000 (UTC)\r
+Authentication-Results: mail.example.org;\r
+\tspf=pass (sender IP is 198.51.100.7) smtp.mailfrom=bounce.example.com;\r
+\tdkim=pass header.d=example.com;\r
+\tdmarc=pass header.from=example.com\r
+DKIM-Signature: v=1; a=rsa-sha256; c=relaxed/relaxed; d=example.com;\r
+\ts=selector1; h=From:To:Subject:Date; bh=abc=; b=def=\r
+From: =?UTF-8?B?5byg5LiJ?= <zhangsan@example.com>\r
+To: Li Si <lisi@example.org>, ops@example.org\r
+Subject: =?UTF-8?B?5rWL6K+V6YKu5Lu2?=\r
+Date: Mon, 6 Jan 2025 10:00:00 +0000\r
+Message-ID: <test-1@example.com>\r
+MIME-Version: 1.0\r
+Content-Type: multipart/mixed; boundary=\"outer\"\r
+\r
+--outer\r
+Content-Type: multipart/alternative; boundary=\"inner\"\r
+\r
+--inner\r
+Content-Type: text/plain; charset=utf-8\r
+Content-Transfer-Encoding: quoted-printable\r
+\r
+Hello =E4=B8=96=E7=95=8C\r
+--inner\r
+Content-Type: text/html; charset=utf-8\r
+\r
+<p>Hello</p>\r
+--inner--\r
+--outer\r
+Content-Type: application/octet-stream\r
+Content-Disposition: attachment; filename=\"data.bin\"\r
+Content-Transfer-Encoding: base64\r
+\r
+aGVsbG8=\r
+--outer--\r
+";
+
+    #[test]
+    fn test_parse_eml_headers_and_addresses() {
+        let result = parse_eml_bytes(SAMPLE_EML.as_bytes()).unwrap();
+        assert_eq!(result.subject.as_deref(), Some("测试邮件"));
+        assert_eq!(result.from, vec!["张三 <zhangsan@example.com>"]);
+        assert_eq!(
+            result.to,
+            vec!["Li Si <lisi@example.org>", "ops@example.org"]
+        );
+        assert_eq!(result.message_id.as_deref(), Some("test-1@example.com"));
+        assert_eq!(result.date.as_deref(), Some("2025-01-06T10:00:00Z"));
+        // 折叠的头部值展开为一行
+        let dkim = result
+            .headers
+            .iter()
+            .find(|h| h.name == "DKIM-Signature")
+            .unwrap();
+        assert!(!dkim.value.contains('\n'));
+
+        assert_eq!(result.received.len(), 2);
+        assert_eq!(
+            result.received[0].from.as_deref(),
+            Some("client.example.com")
+        );
+        assert_eq!(result.received[0].by.as_deref(), Some("mx.example.net"));
+        assert_eq!(result.received[0].delay_seconds, None);
+        assert_eq!(result.received[1].protocol.as_deref(), Some("ESMTPS"));
+        assert_eq!(result.received[1].delay_seconds, Some(5));
+    }
+
+    #[test]
+    fn test_parse_eml_authentication() {
+        let auth = parse_eml_bytes(SAMPLE_EML.as_bytes())
+            .unwrap()
+            .authentication;
+        assert_eq!(auth.spf.as_deref(), Some("pass"));
+        assert_eq!(auth.dkim.as_deref(), Some("pass"));
+        assert_eq!(auth.dmarc.as_deref(), Some("pass"));
+        assert_eq!(auth.from_domain.as_deref(), Some("example.com"));
+        let signature = &auth.dkim_signatures[0];
+        assert_eq!(signature.domain.as_deref(), Some("example.com"));
+        assert_eq!(signature.selector.as_deref(), Some("selector1"));
+        assert_eq!(signature.signed_headers, ["from", "to", "subject", "date"]);
+        // 信封发件域 bounce.example.com 与 example.com 同属一个组织域
+        assert!(auth.warnings.is_empty(), "{:?}", auth.warnings);
+    }
+
+    #[test]
+    fn test_parse_eml_mime_structure_and_attachments() {
+        let result = parse_eml_bytes(SAMPLE_EML.as_bytes()).unwrap();
+        let structure: Vec<(usize, &str)> = result
+            .mime_structure
+            .iter()
+            .map(|p| (p.depth, p.content_type.as_str()))
+            .collect();
+        assert_eq!(
+            structure,
+            vec![
+                (0, "multipart/mixed"),
+                (1, "multipart/alternative"),
+                (2, "text/plain"),
+                (2, "text/html"),
+                (1, "application/octet-stream"),
+            ]
+        );
+        assert_eq!(result.mime_structure[2].charset.as_deref(), Some("utf-8"));
+        assert_eq!(
+            result.mime_structure[2].transfer_encoding.as_deref(),
+            Some("quoted-printable")
+        );
+        assert_eq!(
+            result.text_body.as_deref().map(str::trim),
+            Some("Hello 世界")
+        );
+        assert_eq!(
+            result.html_body.as_deref().map(str::trim),
+            Some("<p>Hello</p>")
+        );
+
+        assert_eq!(result.attachments.len(), 1);
+        let attachment = &result.attachments[0];
+        assert_eq!(attachment.filename, "data.bin");
+        assert_eq!(attachment.size, 5);
+        assert!(!attachment.inline);
+        assert_eq!(
+            attachment.sha256,
+            "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+        );
+    }
+
+    #[test]
+    fn test_authentication_warnings() {
+        let headers = vec![
+            EmailHeader {
+                name: "Authentication-Results".to_string(),
+                value: "mx.example.org; spf=softfail smtp.mailfrom=other.net; dkim=pass"
+                    .to_string(),
+            },
+            EmailHeader {
+                name: "DKIM-Signature".to_string(),
+                value: "v=1; d=mailer.net; s=s1; h=to:subject".to_string(),
+            },
+        ];
+        let auth = analyze_authentication(&headers, &["Ops <ops@example.co.uk>".to_string()]);
+        assert_eq!(auth.from_domain.as_deref(), Some("example.co.uk"));
+        assert_eq!(auth.spf.as_deref(), Some("softfail"));
+        assert!(auth.dmarc.is_none());
+        assert_eq!(
+            auth.warnings,
+            vec![
+                "SPF 验证结果为 softfail",
+                "DKIM 签名域与发件人域 example.co.uk 不对齐，DMARC 只能依赖 SPF",
+                "SPF 验证的信封发件域 other.net 与发件人域 example.co.uk 不对齐",
+                "DKIM 签名未覆盖 From 头",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_eml_rejects_invalid_input() {
+        assert!(parse_eml(Vec::new()).is_err());
+        assert!(parse_eml_bytes(&[0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1])
+            .unwrap_err()
+            .contains(".msg"));
+    }
+
+    #[test]
+    fn test_build_test_message_round_trip() {
+        let request = SmtpTestRequest {
+            host: "smtp.example.com".to_string(),
+            port: 587,
+            security: "starttls".to_string(),
+            username: None,
+            password: None,
+            from: "ops@example.com".to_string(),
+            to: vec!["a@example.org".to_string(), "b@example.org".to_string()],
+            subject: Some("连通性测试".to_string()),
+            body: Some("正文内容".to_string()),
+            timeout_secs: None,
+        };
+        let message = build_test_message(&request, "<id-1@example.com>");
+        let parsed = parse_eml_bytes(message.as_bytes()).unwrap();
+        assert_eq!(parsed.subject.as_deref(), Some("连通性测试"));
+        assert_eq!(parsed.to, vec!["a@example.org", "b@example.org"]);
+        assert_eq!(parsed.text_body.as_deref(), Some("正文内容"));
+        assert_eq!(parsed.message_id.as_deref(), Some("id-1@example.com"));
+    }
+}
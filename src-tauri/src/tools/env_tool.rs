@@ -0,0 +1,707 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+const SECRET_KEYWORDS: &[&str] = &[
+    "SECRET",
+    "PASSWORD",
+    "PASSWD",
+    "PWD",
+    "TOKEN",
+    "API_KEY",
+    "APIKEY",
+    "PRIVATE",
+    "CREDENTIAL",
+    "AUTH",
+    "ACCESS_KEY",
+    "SALT",
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvEntry {
+    pub key: String,
+    pub value: String,
+    pub line_number: usize,
+    pub exported: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvParseWarning {
+    pub line_number: usize,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvParseResult {
+    pub entries: Vec<EnvEntry>,
+    pub warnings: Vec<EnvParseWarning>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvDiffRequest {
+    pub left: String,
+    pub right: String,
+    pub mask_secrets: Option<bool>,
+    pub secret_keys: Option<Vec<String>>, // 额外视为敏感的键名
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvDiffEntry {
+    pub key: String,
+    pub status: String, // missing（仅左侧）/ extra（仅右侧）/ changed / same
+    pub left_value: Option<String>,
+    pub right_value: Option<String>,
+    pub is_secret: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvDiffResult {
+    pub entries: Vec<EnvDiffEntry>,
+    pub missing_count: usize,
+    pub extra_count: usize,
+    pub changed_count: usize,
+    pub same_count: usize,
+    pub warnings: Vec<EnvParseWarning>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvSchemaField {
+    pub key: String,
+    pub required: Option<bool>,
+    pub value_type: Option<String>, // string / integer / number / boolean / url / port / email
+    pub pattern: Option<String>,
+    pub allowed_values: Option<Vec<String>>,
+    pub allow_empty: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvValidationIssue {
+    pub key: String,
+    pub severity: String, // error / warning
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvValidationResult {
+    pub valid: bool,
+    pub issues: Vec<EnvValidationIssue>,
+    pub undeclared_keys: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvMergeRequest {
+    pub base: String,
+    pub overlay: String,
+    pub prefer: Option<String>, // overlay（默认）/ base：冲突时保留哪一侧
+    pub include_new_keys: Option<bool>, // 是否追加仅存在于 overlay 的键，默认 true
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvMergeResult {
+    pub output: String,
+    pub added_keys: Vec<String>,
+    pub overridden_keys: Vec<String>,
+    pub conflict_keys: Vec<String>,
+}
+
+/// 解析 .env 内容，支持 export 前缀、单/双引号、行内注释与多行双引号值
+pub fn parse_env_content(content: &str) -> EnvParseResult {
+    let key_regex = Regex::new(r"^[A-Za-z_][A-Za-z0-9_.\-]*$").unwrap();
+    let mut entries: Vec<EnvEntry> = Vec::new();
+    let mut warnings = Vec::new();
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    let lines: Vec<&str> = content.lines().collect();
+
+    let mut i = 0;
+    while i < lines.len() {
+        let line_number = i + 1;
+        let line = lines[i].trim();
+        i += 1;
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (exported, line) = match line.strip_prefix("export ") {
+            Some(rest) => (true, rest.trim_start()),
+            None => (false, line),
+        };
+        let Some((key, raw_value)) = line.split_once('=') else {
+            warnings.push(EnvParseWarning {
+                line_number,
+                message: format!("无法解析的行（缺少 '='）: {}", line),
+            });
+            continue;
+        };
+        let key = key.trim().to_string();
+        if !key_regex.is_match(&key) {
+            warnings.push(EnvParseWarning {
+                line_number,
+                message: format!("键名不合法: {}", key),
+            });
+            continue;
+        }
+
+        let raw_value = raw_value.trim_start();
+        let value = match raw_value.chars().next() {
+            Some(quote @ ('"' | '\'' | '`')) => {
+                // 引号值可跨行，直到遇到匹配的结束引号
+                let mut buffer = raw_value[1..].to_string();
+                loop {
+                    if let Some(end) = find_closing_quote(&buffer, quote) {
+                        buffer.truncate(end);
+                        break;
+                    }
+                    if i >= lines.len() {
+                        warnings.push(EnvParseWarning {
+                            line_number,
+                            message: format!("{} 的引号未闭合", key),
+                        });
+                        break;
+                    }
+                    buffer.push('\n');
+                    buffer.push_str(lines[i]);
+                    i += 1;
+                }
+                if quote == '"' {
+                    unescape_double_quoted(&buffer)
+                } else {
+                    buffer
+                }
+            }
+            _ => strip_inline_comment(raw_value).trim_end().to_string(),
+        };
+
+        if let Some(first) = seen.get(&key) {
+            warnings.push(EnvParseWarning {
+                line_number,
+                message: format!("{} 重复定义（首次出现在第 {} 行），以后者为准", key, first),
+            });
+            entries.retain(|e| e.key != key);
+        }
+        seen.insert(key.clone(), line_number);
+        entries.push(EnvEntry {
+            key,
+            value,
+            line_number,
+            exported,
+        });
+    }
+
+    EnvParseResult { entries, warnings }
+}
+
+fn find_closing_quote(text: &str, quote: char) -> Option<usize> {
+    let mut escaped = false;
+    for (index, c) in text.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' && quote == '"' {
+            escaped = true;
+        } else if c == quote {
+            return Some(index);
+        }
+    }
+    None
+}
+
+fn unescape_double_quoted(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('r') => result.push('\r'),
+            Some('t') => result.push('\t'),
+            Some(other) => result.push(other),
+            None => result.push('\\'),
+        }
+    }
+    result
+}
+
+/// 未加引号的值中，空白后的 # 视为注释
+fn strip_inline_comment(value: &str) -> &str {
+    let bytes = value.as_bytes();
+    for (index, &b) in bytes.iter().enumerate() {
+        if b == b'#' && (index == 0 || bytes[index - 1].is_ascii_whitespace()) {
+            return &value[..index];
+        }
+    }
+    value
+}
+
+fn is_secret_key(key: &str, extra: &HashSet<String>) -> bool {
+    let upper = key.to_uppercase();
+    extra.contains(&upper) || SECRET_KEYWORDS.iter().any(|k| upper.contains(k))
+}
+
+/// 掩码敏感值，仅保留前两位以便区分
+fn mask_value(value: &str) -> String {
+    let count = value.chars().count();
+    if count <= 4 {
+        return "*".repeat(count.max(4));
+    }
+    let prefix: String = value.chars().take(2).collect();
+    format!("{}{}", prefix, "*".repeat((count - 2).min(8)))
+}
+
+/// 按需为值加引号，保证重新解析后与原值一致
+fn format_env_value(value: &str) -> String {
+    let needs_quotes = value.is_empty()
+        || value
+            .chars()
+            .any(|c| c.is_whitespace() || matches!(c, '#' | '"' | '\'' | '`' | '\\' | '$'));
+    if !needs_quotes {
+        return value.to_string();
+    }
+    if value.is_empty() {
+        return String::new();
+    }
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+        .replace('\t', "\\t");
+    format!("\"{}\"", escaped)
+}
+
+/// 解析 .env 内容
+#[tauri::command]
+pub fn parse_env_file(content: String) -> Result<EnvParseResult, String> {
+    Ok(parse_env_content(&content))
+}
+
+/// 对比两份环境配置，列出缺失、多余与变更的键
+#[tauri::command]
+pub fn compare_env_files(request: EnvDiffRequest) -> Result<EnvDiffResult, String> {
+    let left = parse_env_content(&request.left);
+    let right = parse_env_content(&request.right);
+    let mask = request.mask_secrets.unwrap_or(true);
+    let extra_secrets: HashSet<String> = request
+        .secret_keys
+        .unwrap_or_default()
+        .iter()
+        .map(|k| k.trim().to_uppercase())
+        .collect();
+
+    let right_map: HashMap<&str, &str> = right
+        .entries
+        .iter()
+        .map(|e| (e.key.as_str(), e.value.as_str()))
+        .collect();
+    let left_keys: HashSet<&str> = left.entries.iter().map(|e| e.key.as_str()).collect();
+
+    let display = |key: &str, value: &str| {
+        if mask && is_secret_key(key, &extra_secrets) {
+            mask_value(value)
+        } else {
+            value.to_string()
+        }
+    };
+
+    let mut entries = Vec::new();
+    for entry in &left.entries {
+        let status = match right_map.get(entry.key.as_str()) {
+            None => "missing",
+            Some(v) if *v == entry.value => "same",
+            Some(_) => "changed",
+        };
+        entries.push(EnvDiffEntry {
+            key: entry.key.clone(),
+            status: status.to_string(),
+            left_value: Some(display(&entry.key, &entry.value)),
+            right_value: right_map
+                .get(entry.key.as_str())
+                .map(|v| display(&entry.key, v)),
+            is_secret: is_secret_key(&entry.key, &extra_secrets),
+        });
+    }
+    for entry in right
+        .entries
+        .iter()
+        .filter(|e| !left_keys.contains(e.key.as_str()))
+    {
+        entries.push(EnvDiffEntry {
+            key: entry.key.clone(),
+            status: "extra".to_string(),
+            left_value: None,
+            right_value: Some(display(&entry.key, &entry.value)),
+            is_secret: is_secret_key(&entry.key, &extra_secrets),
+        });
+    }
+
+    let count = |status: &str| entries.iter().filter(|e| e.status == status).count();
+    let mut warnings = left.warnings;
+    warnings.extend(right.warnings.into_iter().map(|w| EnvParseWarning {
+        line_number: w.line_number,
+        message: format!("[右侧] {}", w.message),
+    }));
+
+    Ok(EnvDiffResult {
+        missing_count: count("missing"),
+        extra_count: count("extra"),
+        changed_count: count("changed"),
+        same_count: count("same"),
+        entries,
+        warnings,
+    })
+}
+
+fn validate_value_type(value_type: &str, value: &str) -> Option<String> {
+    let ok = match value_type {
+        "string" => true,
+        "integer" => value.parse::<i64>().is_ok(),
+        "number" => value.parse::<f64>().is_ok(),
+        "boolean" => matches!(
+            value.to_lowercase().as_str(),
+            "true" | "false" | "1" | "0" | "yes" | "no" | "on" | "off"
+        ),
+        "port" => value.parse::<u16>().is_ok_and(|p| p > 0),
+        "url" => reqwest::Url::parse(value).is_ok(),
+        "email" => Regex::new(r"^[^@\s]+@[^@\s]+\.[^@\s]+$")
+            .unwrap()
+            .is_match(value),
+        other => return Some(format!("未知的类型声明: {}", other)),
+    };
+    if ok {
+        None
+    } else {
+        Some(format!("值 '{}' 不是有效的 {}", value, value_type))
+    }
+}
+
+/// 按声明的 schema 校验环境配置
+#[tauri::command]
+pub fn validate_env_schema(
+    content: String,
+    schema: Vec<EnvSchemaField>,
+) -> Result<EnvValidationResult, String> {
+    let parsed = parse_env_content(&content);
+    let values: HashMap<&str, &str> = parsed
+        .entries
+        .iter()
+        .map(|e| (e.key.as_str(), e.value.as_str()))
+        .collect();
+    let declared: HashSet<&str> = schema.iter().map(|f| f.key.as_str()).collect();
+
+    let mut issues = Vec::new();
+    let mut push = |key: &str, severity: &str, message: String| {
+        issues.push(EnvValidationIssue {
+            key: key.to_string(),
+            severity: severity.to_string(),
+            message,
+        })
+    };
+
+    for field in &schema {
+        let key = field.key.as_str();
+        let Some(value) = values.get(key) else {
+            if field.required.unwrap_or(true) {
+                push(key, "error", "缺少必填项".to_string());
+            }
+            continue;
+        };
+        if value.is_empty() {
+            if !field.allow_empty.unwrap_or(false) {
+                push(key, "error", "值不能为空".to_string());
+            }
+            continue;
+        }
+        if let Some(message) = field
+            .value_type
+            .as_deref()
+            .and_then(|t| validate_value_type(t, value))
+        {
+            push(key, "error", message);
+        }
+        if let Some(pattern) = field.pattern.as_deref() {
+            match Regex::new(pattern) {
+                Ok(re) if !re.is_match(value) => {
+                    push(key, "error", format!("值不匹配规则: {}", pattern))
+                }
+                Err(e) => push(key, "warning", format!("schema 中的正则无效: {}", e)),
+                _ => {}
+            }
+        }
+        if let Some(allowed) = &field.allowed_values {
+            if !allowed.iter().any(|a| a == value) {
+                push(
+                    key,
+                    "error",
+                    format!("值必须为以下之一: {}", allowed.join(", ")),
+                );
+            }
+        }
+    }
+
+    for warning in &parsed.warnings {
+        push(
+            "",
+            "warning",
+            format!("第 {} 行: {}", warning.line_number, warning.message),
+        );
+    }
+
+    let undeclared_keys: Vec<String> = parsed
+        .entries
+        .iter()
+        .filter(|e| !declared.contains(e.key.as_str()))
+        .map(|e| e.key.clone())
+        .collect();
+
+    Ok(EnvValidationResult {
+        valid: !issues.iter().any(|i| i.severity == "error"),
+        issues,
+        undeclared_keys,
+    })
+}
+
+/// 合并两份环境配置，保留 base 的行顺序与注释
+#[tauri::command]
+pub fn merge_env_files(request: EnvMergeRequest) -> Result<EnvMergeResult, String> {
+    let base = parse_env_content(&request.base);
+    let overlay = parse_env_content(&request.overlay);
+    let prefer_overlay = request.prefer.as_deref().unwrap_or("overlay") != "base";
+    let include_new = request.include_new_keys.unwrap_or(true);
+
+    let overlay_map: HashMap<&str, &EnvEntry> = overlay
+        .entries
+        .iter()
+        .map(|e| (e.key.as_str(), e))
+        .collect();
+    let base_by_line: HashMap<usize, &EnvEntry> =
+        base.entries.iter().map(|e| (e.line_number, e)).collect();
+    let base_keys: HashSet<&str> = base.entries.iter().map(|e| e.key.as_str()).collect();
+
+    let mut output = String::new();
+    let mut overridden_keys = Vec::new();
+    let mut conflict_keys = Vec::new();
+    let base_lines: Vec<&str> = request.base.lines().collect();
+    let mut skip_until = 0;
+
+    for (index, line) in base_lines.iter().enumerate() {
+        let line_number = index + 1;
+        if line_number <= skip_until {
+            continue;
+        }
+        let Some(entry) = base_by_line.get(&line_number) else {
+            // 重复定义中被覆盖的旧行不输出
+            let is_shadowed = line
+                .trim()
+                .trim_start_matches("export ")
+                .split_once('=')
+                .is_some_and(|(k, _)| base_keys.contains(k.trim()));
+            if !is_shadowed {
+                output.push_str(line);
+                output.push('\n');
+            }
+            continue;
+        };
+
+        // 多行引号值占用的后续行一并跳过
+        let value_lines = entry.value.matches('\n').count();
+        skip_until = line_number + value_lines;
+
+        match overlay_map.get(entry.key.as_str()) {
+            Some(other) if other.value != entry.value => {
+                conflict_keys.push(entry.key.clone());
+                if prefer_overlay {
+                    overridden_keys.push(entry.key.clone());
+                    let prefix = if entry.exported { "export " } else { "" };
+                    output.push_str(&format!(
+                        "{}{}={}\n",
+                        prefix,
+                        entry.key,
+                        format_env_value(&other.value)
+                    ));
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        for raw in &base_lines[index..=index + value_lines] {
+            output.push_str(raw);
+            output.push('\n');
+        }
+    }
+
+    let mut added_keys = Vec::new();
+    if include_new {
+        let new_entries: Vec<&EnvEntry> = overlay
+            .entries
+            .iter()
+            .filter(|e| !base_keys.contains(e.key.as_str()))
+            .collect();
+        if !new_entries.is_empty() {
+            if !output.is_empty() && !output.ends_with("\n\n") {
+                output.push('\n');
+            }
+            output.push_str("# 合并新增\n");
+            for entry in new_entries {
+                added_keys.push(entry.key.clone());
+                output.push_str(&format!(
+                    "{}={}\n",
+                    entry.key,
+                    format_env_value(&entry.value)
+                ));
+            }
+        }
+    }
+
+    Ok(EnvMergeResult {
+        output,
+        added_keys,
+        overridden_keys,
+        conflict_keys,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value_of<'a>(result: &'a EnvParseResult, key: &str) -> Option<&'a str> {
+        result
+            .entries
+            .iter()
+            .find(|e| e.key == key)
+            .map(|e| e.value.as_str())
+    }
+
+    #[test]
+    fn test_parse_env_content() {
+        let content = "# 注释\n\
+            export APP_NAME=demo # 行内注释\n\
+            URL=http://a.com/#anchor\n\
+            SINGLE='keep $HOME \\n'\n\
+            DOUBLE=\"line1\\nline2\"\n\
+            MULTI=\"first\n\
+            second\"\n\
+            EMPTY=\n";
+        let result = parse_env_content(content);
+        assert!(result.warnings.is_empty());
+        assert_eq!(result.entries.len(), 6);
+        assert!(result.entries[0].exported);
+        assert_eq!(value_of(&result, "APP_NAME"), Some("demo"));
+        assert_eq!(value_of(&result, "URL"), Some("http://a.com/#anchor"));
+        assert_eq!(value_of(&result, "SINGLE"), Some("keep $HOME \\n"));
+        assert_eq!(value_of(&result, "DOUBLE"), Some("line1\nline2"));
+        assert_eq!(value_of(&result, "MULTI"), Some("first\nsecond"));
+        assert_eq!(value_of(&result, "EMPTY"), Some(""));
+        assert_eq!(result.entries[5].line_number, 8);
+    }
+
+    #[test]
+    fn test_format_value_round_trip() {
+        for value in [
+            "plain",
+            "with space",
+            "quote \" and 'single'",
+            "back\\slash",
+            "multi\nline\ttab",
+            "hash # sign",
+            "$VAR",
+            "",
+        ] {
+            let line = format!("KEY={}", format_env_value(value));
+            let result = parse_env_content(&line);
+            assert!(result.warnings.is_empty(), "{}", line);
+            assert_eq!(value_of(&result, "KEY"), Some(value), "{}", line);
+        }
+    }
+
+    #[test]
+    fn test_parse_malformed_lines() {
+        let result = parse_env_content("NO_EQUALS\n1BAD=x\nA=1\nA=2\nOPEN=\"never closed\nnext");
+        let messages: Vec<&str> = result.warnings.iter().map(|w| w.message.as_str()).collect();
+        assert_eq!(result.warnings[0].line_number, 1);
+        assert!(messages[0].contains("缺少 '='"));
+        assert_eq!(messages[1], "键名不合法: 1BAD");
+        assert!(messages[2].starts_with("A 重复定义（首次出现在第 3 行）"));
+        assert_eq!(messages[3], "OPEN 的引号未闭合");
+        assert_eq!(value_of(&result, "A"), Some("2"));
+        assert_eq!(result.entries.iter().filter(|e| e.key == "A").count(), 1);
+    }
+
+    #[test]
+    fn test_compare_masks_secrets() {
+        let result = compare_env_files(EnvDiffRequest {
+            left: "HOST=a\nDB_PASSWORD=hunter2\nONLY_LEFT=1".to_string(),
+            right: "HOST=a\nDB_PASSWORD=changed!\nONLY_RIGHT=2".to_string(),
+            mask_secrets: None,
+            secret_keys: None,
+        })
+        .unwrap();
+        assert_eq!(
+            (
+                result.same_count,
+                result.changed_count,
+                result.missing_count,
+                result.extra_count
+            ),
+            (1, 1, 1, 1)
+        );
+        let password = &result.entries[1];
+        assert!(password.is_secret);
+        assert_eq!(password.left_value.as_deref(), Some("hu*****"));
+    }
+
+    #[test]
+    fn test_validate_env_schema() {
+        let field = |key: &str, value_type: &str| EnvSchemaField {
+            key: key.to_string(),
+            required: None,
+            value_type: Some(value_type.to_string()),
+            pattern: None,
+            allowed_values: None,
+            allow_empty: None,
+        };
+        let result = validate_env_schema(
+            "PORT=70000\nDEBUG=yes\nEXTRA=1".to_string(),
+            vec![
+                field("PORT", "port"),
+                field("DEBUG", "boolean"),
+                field("API_URL", "url"),
+            ],
+        )
+        .unwrap();
+        assert!(!result.valid);
+        let keys: Vec<&str> = result.issues.iter().map(|i| i.key.as_str()).collect();
+        assert_eq!(keys, vec!["PORT", "API_URL"]);
+        assert_eq!(result.issues[1].message, "缺少必填项");
+        assert_eq!(result.undeclared_keys, vec!["EXTRA"]);
+    }
+
+    #[test]
+    fn test_merge_env_files() {
+        let result = merge_env_files(EnvMergeRequest {
+            base: "# 基础配置\nexport HOST=a\nPORT=80\nNOTE=\"x\ny\"\n".to_string(),
+            overlay: "HOST=b c\nNOTE=\"x\ny\"\nNEW=1\n".to_string(),
+            prefer: None,
+            include_new_keys: None,
+        })
+        .unwrap();
+        assert_eq!(
+            result.output,
+            "# 基础配置\nexport HOST=\"b c\"\nPORT=80\nNOTE=\"x\ny\"\n\n# 合并新增\nNEW=1\n"
+        );
+        assert_eq!(result.overridden_keys, vec!["HOST"]);
+        assert_eq!(result.added_keys, vec!["NEW"]);
+
+        let kept = merge_env_files(EnvMergeRequest {
+            base: "HOST=a\n".to_string(),
+            overlay: "HOST=b\n".to_string(),
+            prefer: Some("base".to_string()),
+            include_new_keys: Some(false),
+        })
+        .unwrap();
+        assert_eq!(kept.output, "HOST=a\n");
+        assert_eq!(kept.conflict_keys, vec!["HOST"]);
+        assert!(kept.overridden_keys.is_empty());
+    }
+}
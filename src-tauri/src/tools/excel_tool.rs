@@ -0,0 +1,564 @@
+use crate::tools::csv_tool::{self, CsvSource};
+use calamine::{open_workbook_auto, Data, Reader};
+use chrono::{NaiveDate, NaiveDateTime, Timelike};
+use rust_xlsxwriter::{Format, Workbook, Worksheet, XlsxError};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Number, Value};
+use std::collections::HashSet;
+
+const DEFAULT_DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExcelSheetInfo {
+    pub name: String,
+    pub rows: usize,
+    pub columns: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadExcelRequest {
+    pub path: String,
+    pub sheet: Option<String>,       // 为空时读取第一个工作表
+    pub header_row: Option<usize>,   // 表头所在行（从 1 开始），0 表示没有表头
+    pub format: Option<String>,      // json, csv
+    pub date_format: Option<String>, // chrono 格式，默认 %Y-%m-%d %H:%M:%S
+    pub output_path: Option<String>, // 为空时直接返回内容
+    pub skip_empty_rows: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadExcelResult {
+    pub sheet: String,
+    pub headers: Vec<String>,
+    pub rows: usize,
+    pub content: Option<String>,
+    pub output_path: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExcelSheetData {
+    pub name: String,
+    pub format: String, // json, csv
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WriteExcelRequest {
+    pub output_path: String,
+    pub sheets: Vec<ExcelSheetData>,
+    pub parse_dates: Option<bool>, // 将日期字符串写为 Excel 日期
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WriteExcelResult {
+    pub output_path: String,
+    pub sheets: Vec<ExcelSheetInfo>,
+}
+
+/// 列出工作簿中的工作表
+#[tauri::command]
+pub async fn list_excel_sheets(path: String) -> Result<Vec<ExcelSheetInfo>, String> {
+    tokio::task::spawn_blocking(move || {
+        let mut workbook = open_workbook_auto(&path).map_err(|e| format!("打开表格失败: {}", e))?;
+        let names = workbook.sheet_names();
+        names
+            .into_iter()
+            .map(|name| {
+                let range = workbook
+                    .worksheet_range(&name)
+                    .map_err(|e| format!("读取工作表 {} 失败: {}", name, e))?;
+                let (rows, columns) = range.get_size();
+                Ok(ExcelSheetInfo {
+                    name,
+                    rows,
+                    columns,
+                })
+            })
+            .collect()
+    })
+    .await
+    .map_err(|e| format!("读取表格任务失败: {}", e))?
+}
+
+/// 将工作表转换为 JSON 数组或 CSV
+#[tauri::command]
+pub async fn read_excel(request: ReadExcelRequest) -> Result<ReadExcelResult, String> {
+    tokio::task::spawn_blocking(move || read_excel_blocking(request))
+        .await
+        .map_err(|e| format!("读取表格任务失败: {}", e))?
+}
+
+/// 将 JSON 数组或 CSV 写入 xlsx，每项对应一个工作表
+#[tauri::command]
+pub async fn write_excel(request: WriteExcelRequest) -> Result<WriteExcelResult, String> {
+    tokio::task::spawn_blocking(move || write_excel_blocking(request))
+        .await
+        .map_err(|e| format!("写入表格任务失败: {}", e))?
+}
+
+/// 读取后的工作表数据
+struct SheetTable {
+    name: String,
+    headers: Vec<String>,
+    rows: Vec<Vec<Value>>,
+}
+
+fn read_excel_blocking(request: ReadExcelRequest) -> Result<ReadExcelResult, String> {
+    let table = read_sheet(&request)?;
+    let content = match request.format.as_deref().unwrap_or("json") {
+        "json" => serde_json::to_string_pretty(&rows_to_json(&table))
+            .map_err(|e| format!("生成 JSON 失败: {}", e))?,
+        "csv" => rows_to_csv(&table)?,
+        other => return Err(format!("不支持的输出格式: {}", other)),
+    };
+
+    let output_path = request.output_path.filter(|p| !p.trim().is_empty());
+    if let Some(path) = &output_path {
+        std::fs::write(path, &content).map_err(|e| format!("写入文件失败: {}", e))?;
+    }
+    Ok(ReadExcelResult {
+        sheet: table.name,
+        headers: table.headers,
+        rows: table.rows.len(),
+        content: output_path.is_none().then_some(content),
+        output_path,
+    })
+}
+
+fn read_sheet(request: &ReadExcelRequest) -> Result<SheetTable, String> {
+    let mut workbook =
+        open_workbook_auto(&request.path).map_err(|e| format!("打开表格失败: {}", e))?;
+    let name = match request.sheet.as_deref().filter(|s| !s.is_empty()) {
+        Some(sheet) => sheet.to_string(),
+        None => workbook
+            .sheet_names()
+            .into_iter()
+            .next()
+            .ok_or("工作簿中没有工作表")?,
+    };
+    let range = workbook
+        .worksheet_range(&name)
+        .map_err(|e| format!("读取工作表 {} 失败: {}", name, e))?;
+
+    let date_format = request
+        .date_format
+        .as_deref()
+        .filter(|f| !f.is_empty())
+        .unwrap_or(DEFAULT_DATETIME_FORMAT);
+    let skip_empty = request.skip_empty_rows.unwrap_or(true);
+    let header_row = request.header_row.unwrap_or(1);
+    let width = range.width();
+
+    // range 从第一个非空单元格开始，需换算为工作表中的行号
+    let first_row = range.start().map(|(row, _)| row as usize + 1).unwrap_or(1);
+    let mut headers = Vec::new();
+    let mut rows = Vec::new();
+    for (index, row) in range.rows().enumerate() {
+        let row_number = first_row + index;
+        if header_row > 0 && row_number < header_row {
+            continue;
+        }
+        if row_number == header_row {
+            headers = row
+                .iter()
+                .map(|cell| cell_to_string(cell, date_format))
+                .collect();
+            continue;
+        }
+        if skip_empty && row.iter().all(|cell| matches!(cell, Data::Empty)) {
+            continue;
+        }
+        rows.push(
+            row.iter()
+                .map(|cell| cell_to_value(cell, date_format))
+                .collect(),
+        );
+    }
+
+    Ok(SheetTable {
+        name,
+        headers: normalize_headers(&headers, width),
+        rows,
+    })
+}
+
+/// 补齐空白表头并为重复列名添加序号
+fn normalize_headers(headers: &[String], width: usize) -> Vec<String> {
+    let mut seen = HashSet::new();
+    (0..width)
+        .map(|index| {
+            let base = headers
+                .get(index)
+                .map(|h| h.trim().to_string())
+                .filter(|h| !h.is_empty())
+                .unwrap_or_else(|| format!("column_{}", index + 1));
+            let mut name = base.clone();
+            let mut suffix = 2;
+            while !seen.insert(name.clone()) {
+                name = format!("{}_{}", base, suffix);
+                suffix += 1;
+            }
+            name
+        })
+        .collect()
+}
+
+fn format_datetime(datetime: NaiveDateTime, format: &str) -> String {
+    // 没有时间部分且使用默认格式时只输出日期
+    if format == DEFAULT_DATETIME_FORMAT && datetime.num_seconds_from_midnight() == 0 {
+        datetime.format(DEFAULT_DATE_FORMAT).to_string()
+    } else {
+        datetime.format(format).to_string()
+    }
+}
+
+fn cell_to_value(cell: &Data, date_format: &str) -> Value {
+    match cell {
+        Data::Empty => Value::Null,
+        Data::Int(i) => Value::from(*i),
+        // Excel 中的整数以浮点数保存
+        Data::Float(f) if f.fract() == 0.0 && f.abs() < 9_007_199_254_740_992.0 => {
+            Value::from(*f as i64)
+        }
+        Data::Float(f) => Number::from_f64(*f)
+            .map(Value::Number)
+            .unwrap_or(Value::Null),
+        Data::Bool(b) => Value::Bool(*b),
+        _ => Value::String(cell_to_string(cell, date_format)),
+    }
+}
+
+fn cell_to_string(cell: &Data, date_format: &str) -> String {
+    match cell {
+        Data::Empty => String::new(),
+        Data::String(s) | Data::DateTimeIso(s) | Data::DurationIso(s) => s.clone(),
+        Data::DateTime(dt) if dt.is_duration() => dt
+            .as_duration()
+            .map(|d| {
+                let secs = d.num_seconds();
+                format!(
+                    "{:02}:{:02}:{:02}",
+                    secs / 3600,
+                    secs % 3600 / 60,
+                    secs % 60
+                )
+            })
+            .unwrap_or_else(|| dt.as_f64().to_string()),
+        Data::DateTime(dt) => dt
+            .as_datetime()
+            .map(|d| format_datetime(d, date_format))
+            .unwrap_or_else(|| dt.as_f64().to_string()),
+        Data::Error(e) => e.to_string(),
+        Data::Float(_) | Data::Int(_) | Data::Bool(_) => match cell_to_value(cell, date_format) {
+            Value::Null => String::new(),
+            value => value.to_string(),
+        },
+    }
+}
+
+fn rows_to_json(table: &SheetTable) -> Value {
+    Value::Array(
+        table
+            .rows
+            .iter()
+            .map(|row| {
+                let object: Map<String, Value> = table
+                    .headers
+                    .iter()
+                    .cloned()
+                    .zip(row.iter().cloned())
+                    .collect();
+                Value::Object(object)
+            })
+            .collect(),
+    )
+}
+
+fn rows_to_csv(table: &SheetTable) -> Result<String, String> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    let csv_err = |e: csv::Error| format!("生成 CSV 失败: {}", e);
+    writer.write_record(&table.headers).map_err(csv_err)?;
+    for row in &table.rows {
+        writer
+            .write_record(row.iter().map(|value| match value {
+                Value::Null => String::new(),
+                Value::String(s) => s.clone(),
+                other => other.to_string(),
+            }))
+            .map_err(csv_err)?;
+    }
+    let bytes = writer.into_inner().map_err(|e| e.to_string())?;
+    String::from_utf8(bytes).map_err(|e| e.to_string())
+}
+
+fn write_excel_blocking(request: WriteExcelRequest) -> Result<WriteExcelResult, String> {
+    if request.sheets.is_empty() {
+        return Err("请至少提供一个工作表".to_string());
+    }
+    let parse_dates = request.parse_dates.unwrap_or(true);
+    let mut workbook = Workbook::new();
+    let mut infos = Vec::with_capacity(request.sheets.len());
+
+    for sheet_data in &request.sheets {
+        let table = match sheet_data.format.to_lowercase().as_str() {
+            "json" => json_to_table(&sheet_data.name, &sheet_data.content)?,
+            "csv" | "tsv" => csv_to_table(&sheet_data.name, &sheet_data.content)?,
+            other => return Err(format!("不支持的输入格式: {}", other)),
+        };
+        let sheet = workbook.add_worksheet();
+        if !table.name.trim().is_empty() {
+            sheet
+                .set_name(table.name.trim())
+                .map_err(|e| format!("工作表名称 {} 无效: {}", table.name, e))?;
+        }
+        write_table(sheet, &table, parse_dates).map_err(|e| format!("写入工作表失败: {}", e))?;
+        infos.push(ExcelSheetInfo {
+            name: table.name,
+            rows: table.rows.len(),
+            columns: table.headers.len(),
+        });
+    }
+
+    workbook
+        .save(&request.output_path)
+        .map_err(|e| format!("保存表格失败: {}", e))?;
+    Ok(WriteExcelResult {
+        output_path: request.output_path,
+        sheets: infos,
+    })
+}
+
+/// 支持对象数组（键合并为表头）和二维数组（第一行为表头）
+fn json_to_table(name: &str, content: &str) -> Result<SheetTable, String> {
+    let value: Value =
+        serde_json::from_str(content).map_err(|e| format!("JSON 格式错误: {}", e))?;
+    let items = match value {
+        Value::Array(items) => items,
+        Value::Object(_) => vec![value],
+        _ => return Err("JSON 必须是数组或对象".to_string()),
+    };
+
+    if items.iter().all(Value::is_array) {
+        let mut rows: Vec<Vec<Value>> = items
+            .into_iter()
+            .map(|item| match item {
+                Value::Array(values) => values,
+                _ => unreachable!(),
+            })
+            .collect();
+        let headers = if rows.is_empty() {
+            Vec::new()
+        } else {
+            rows.remove(0).iter().map(value_to_text).collect()
+        };
+        return Ok(SheetTable {
+            name: name.to_string(),
+            headers,
+            rows,
+        });
+    }
+
+    let mut headers: Vec<String> = Vec::new();
+    let mut seen = HashSet::new();
+    for item in &items {
+        let object = item.as_object().ok_or("JSON 数组中的元素必须都是对象")?;
+        for key in object.keys() {
+            if seen.insert(key.clone()) {
+                headers.push(key.clone());
+            }
+        }
+    }
+    let rows = items
+        .iter()
+        .filter_map(Value::as_object)
+        .map(|object| {
+            headers
+                .iter()
+                .map(|key| object.get(key).cloned().unwrap_or(Value::Null))
+                .collect()
+        })
+        .collect();
+    Ok(SheetTable {
+        name: name.to_string(),
+        headers,
+        rows,
+    })
+}
+
+fn csv_to_table(name: &str, content: &str) -> Result<SheetTable, String> {
+    let table = csv_tool::parse_table(&CsvSource {
+        path: None,
+        content: Some(content.to_string()),
+        delimiter: None,
+        quote: None,
+        has_header: Some(true),
+    })?;
+    let rows = table
+        .rows
+        .iter()
+        .map(|row| {
+            table
+                .columns
+                .iter()
+                .zip(row)
+                .map(|(column, value)| csv_tool::typed_value(value, column.column_type))
+                .collect()
+        })
+        .collect();
+    Ok(SheetTable {
+        name: name.to_string(),
+        headers: table.columns.iter().map(|c| c.name.clone()).collect(),
+        rows,
+    })
+}
+
+fn value_to_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+fn parse_date_string(value: &str) -> Option<(NaiveDateTime, bool)> {
+    if let Ok(date) = NaiveDate::parse_from_str(value, DEFAULT_DATE_FORMAT) {
+        return date.and_hms_opt(0, 0, 0).map(|d| (d, false));
+    }
+    if let Ok(datetime) = chrono::DateTime::parse_from_rfc3339(value) {
+        return Some((datetime.naive_local(), true));
+    }
+    ["%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M:%S"]
+        .iter()
+        .find_map(|f| NaiveDateTime::parse_from_str(value, f).ok())
+        .map(|d| (d, true))
+}
+
+fn write_table(
+    sheet: &mut Worksheet,
+    table: &SheetTable,
+    parse_dates: bool,
+) -> Result<(), XlsxError> {
+    let header_format = Format::new().set_bold();
+    let date_format = Format::new().set_num_format("yyyy-mm-dd");
+    let datetime_format = Format::new().set_num_format("yyyy-mm-dd hh:mm:ss");
+
+    for (col, header) in table.headers.iter().enumerate() {
+        sheet.write_string_with_format(0, col as u16, header, &header_format)?;
+    }
+    for (index, row) in table.rows.iter().enumerate() {
+        let row_num = index as u32 + 1;
+        for (col, value) in row.iter().enumerate() {
+            let col = col as u16;
+            match value {
+                Value::Null => {}
+                Value::Bool(b) => {
+                    sheet.write_boolean(row_num, col, *b)?;
+                }
+                Value::Number(n) => {
+                    sheet.write_number(row_num, col, n.as_f64().unwrap_or(0.0))?;
+                }
+                Value::String(s) => match parse_dates.then(|| parse_date_string(s)).flatten() {
+                    Some((datetime, true)) => {
+                        sheet.write_datetime_with_format(
+                            row_num,
+                            col,
+                            datetime,
+                            &datetime_format,
+                        )?;
+                    }
+                    Some((datetime, false)) => {
+                        sheet.write_datetime_with_format(row_num, col, datetime, &date_format)?;
+                    }
+                    None => {
+                        sheet.write_string(row_num, col, s)?;
+                    }
+                },
+                // 嵌套对象和数组以 JSON 文本写入
+                other => {
+                    sheet.write_string(row_num, col, other.to_string())?;
+                }
+            }
+        }
+    }
+    if !table.headers.is_empty() {
+        sheet.set_freeze_panes(1, 0)?;
+    }
+    sheet.autofit();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_headers() {
+        let headers = vec!["id".to_string(), "".to_string(), "id".to_string()];
+        assert_eq!(
+            normalize_headers(&headers, 4),
+            vec!["id", "column_2", "id_2", "column_4"]
+        );
+    }
+
+    #[test]
+    fn test_write_and_read_round_trip() {
+        let path = std::env::temp_dir().join(format!("devtools-excel-{}.xlsx", std::process::id()));
+        let path = path.to_string_lossy().to_string();
+
+        write_excel_blocking(WriteExcelRequest {
+            output_path: path.clone(),
+            sheets: vec![
+                ExcelSheetData {
+                    name: "users".to_string(),
+                    format: "json".to_string(),
+                    content: r#"[{"id":1,"name":"Alice","joined":"2024-03-01"},{"id":2,"name":"Bob","score":9.5,"active":true}]"#.to_string(),
+                },
+                ExcelSheetData {
+                    name: "orders".to_string(),
+                    format: "csv".to_string(),
+                    content: "order_id,amount\n1001,25.5\n".to_string(),
+                },
+            ],
+            parse_dates: None,
+        })
+        .unwrap();
+
+        let result = read_excel_blocking(ReadExcelRequest {
+            path: path.clone(),
+            sheet: None,
+            header_row: None,
+            format: None,
+            date_format: None,
+            output_path: None,
+            skip_empty_rows: None,
+        })
+        .unwrap();
+        assert_eq!(result.sheet, "users");
+        // serde_json 未启用 preserve_order，对象键按字母序合并
+        assert_eq!(
+            result.headers,
+            vec!["id", "joined", "name", "active", "score"]
+        );
+
+        let rows: Value = serde_json::from_str(&result.content.unwrap()).unwrap();
+        assert_eq!(rows[0]["id"], 1);
+        assert_eq!(rows[0]["joined"], "2024-03-01");
+        assert_eq!(rows[0]["score"], Value::Null);
+        assert_eq!(rows[1]["score"], 9.5);
+        assert_eq!(rows[1]["active"], true);
+
+        let csv = read_excel_blocking(ReadExcelRequest {
+            path: path.clone(),
+            sheet: Some("orders".to_string()),
+            header_row: Some(1),
+            format: Some("csv".to_string()),
+            date_format: None,
+            output_path: None,
+            skip_empty_rows: None,
+        })
+        .unwrap();
+        assert_eq!(csv.content.unwrap(), "order_id,amount\n1001,25.5\n");
+        let _ = std::fs::remove_file(&path);
+    }
+}
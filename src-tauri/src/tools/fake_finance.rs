@@ -0,0 +1,567 @@
+use crate::tools::mock_server::random_bytes;
+use serde::{Deserialize, Serialize};
+
+/// 所有生成结果附带的提示
+const TEST_NOTICE: &str = "仅供测试使用：号码只满足格式和校验位规则，不对应真实账户";
+const MAX_COUNT: usize = 1000;
+
+/// 卡号前缀区间，闭区间
+type PrefixRanges = &'static [(u32, u32)];
+
+/// 卡组织：名称、可用前缀区间、卡号长度
+const CARD_BRANDS: &[(&str, PrefixRanges, usize)] = &[
+    ("visa", &[(4, 4)], 16),
+    ("mastercard", &[(51, 55), (2221, 2720)], 16),
+    ("amex", &[(34, 34), (37, 37)], 15),
+    ("discover", &[(6011, 6011), (644, 649), (65, 65)], 16),
+    ("jcb", &[(3528, 3589)], 16),
+    ("unionpay", &[(62, 62)], 16),
+    ("diners", &[(300, 305), (36, 36), (38, 39)], 14),
+];
+
+/// IBAN 国家代码、总长度和 BBAN 结构（n: 数字，a: 大写字母，c: 字母或数字）
+const IBAN_COUNTRIES: &[(&str, usize, &str)] = &[
+    ("DE", 22, "8n10n"),
+    ("GB", 22, "4a6n8n"),
+    ("FR", 27, "5n5n11n2n"),
+    ("NL", 18, "4a10n"),
+    ("BE", 16, "3n7n2n"),
+    ("CH", 21, "5n12c"),
+    ("AT", 20, "5n11n"),
+    ("PL", 28, "8n16n"),
+    ("ES", 24, "4n4n1n1n10n"),
+    ("IT", 27, "1a5n5n12c"),
+    ("AE", 23, "3n16n"),
+    ("SA", 24, "2n18c"),
+];
+
+/// 大陆手机号段与运营商
+const PHONE_SEGMENTS: &[(&str, &[u16])] = &[
+    (
+        "中国移动",
+        &[
+            134, 135, 136, 137, 138, 139, 147, 150, 151, 152, 157, 158, 159, 172, 178, 182, 183,
+            184, 187, 188, 195, 197, 198,
+        ],
+    ),
+    (
+        "中国联通",
+        &[
+            130, 131, 132, 145, 155, 156, 166, 167, 171, 175, 176, 185, 186, 196,
+        ],
+    ),
+    (
+        "中国电信",
+        &[133, 149, 153, 173, 177, 180, 181, 189, 190, 191, 193, 199],
+    ),
+    ("中国广电", &[192]),
+];
+
+/// 统一社会信用代码使用的字符集，不含 I、O、Z、S、V
+pub(crate) const USCC_CHARSET: &str = "0123456789ABCDEFGHJKLMNPQRTUWXY";
+const USCC_WEIGHTS: [u32; 17] = [
+    1, 3, 9, 27, 19, 26, 16, 17, 20, 29, 25, 13, 8, 24, 10, 30, 28,
+];
+/// 生成信用代码时使用的行政区划代码
+const USCC_REGIONS: &[&str] = &["110105", "310115", "440300", "330106", "510107", "420106"];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FakeFinanceRequest {
+    pub kind: String,            // credit_card, iban, cn_phone, uscc
+    pub brand: Option<String>,   // 信用卡卡组织，为空时随机
+    pub country: Option<String>, // IBAN 国家代码，为空时随机
+    pub count: Option<usize>,    // 默认 10，上限 1000
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneratedNumber {
+    pub value: String,
+    pub formatted: String, // 按惯例分组显示
+    pub detail: String,    // 卡组织、国家或运营商
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FakeFinanceResult {
+    pub kind: String,
+    pub items: Vec<GeneratedNumber>,
+    pub notice: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidateNumberRequest {
+    pub value: String,
+    pub kind: Option<String>, // 为空时根据格式自动识别
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NumberValidation {
+    pub kind: String,
+    pub normalized: String,
+    pub valid: bool,
+    pub detail: Option<String>,
+    pub errors: Vec<String>,
+}
+
+/// 生成满足校验位规则的测试用卡号、IBAN、手机号或统一社会信用代码
+#[tauri::command]
+pub fn generate_test_numbers(request: FakeFinanceRequest) -> Result<FakeFinanceResult, String> {
+    let count = request.count.unwrap_or(10).clamp(1, MAX_COUNT);
+    let kind = request.kind.trim().to_lowercase();
+    let items = (0..count)
+        .map(|_| match kind.as_str() {
+            "credit_card" => generate_card(request.brand.as_deref()),
+            "iban" => generate_iban(request.country.as_deref()),
+            "cn_phone" => Ok(generate_phone()),
+            "uscc" => Ok(generate_uscc()),
+            other => Err(format!("不支持的号码类型: {}", other)),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(FakeFinanceResult {
+        kind,
+        items,
+        notice: TEST_NOTICE.to_string(),
+    })
+}
+
+/// 校验粘贴的卡号、IBAN、手机号或统一社会信用代码
+#[tauri::command]
+pub fn validate_test_number(request: ValidateNumberRequest) -> Result<NumberValidation, String> {
+    let normalized: String = request
+        .value
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .collect::<String>()
+        .to_uppercase();
+    if normalized.is_empty() {
+        return Err("请输入要校验的号码".to_string());
+    }
+    let kind = match request.kind.as_deref().map(|k| k.trim().to_lowercase()) {
+        Some(kind) if !kind.is_empty() => kind,
+        _ => detect_kind(&normalized).ok_or("无法识别号码类型，请手动指定")?,
+    };
+    let (detail, errors) = match kind.as_str() {
+        "credit_card" => validate_card(&normalized),
+        "iban" => validate_iban(&normalized),
+        "cn_phone" => validate_phone(&normalized),
+        "uscc" => validate_uscc(&normalized),
+        other => return Err(format!("不支持的号码类型: {}", other)),
+    };
+    Ok(NumberValidation {
+        kind,
+        normalized,
+        valid: errors.is_empty(),
+        detail,
+        errors,
+    })
+}
+
+fn random_below(bound: u64) -> u64 {
+    u64::from_le_bytes(random_bytes::<8>()) % bound
+}
+
+fn random_digits(len: usize) -> String {
+    (0..len)
+        .map(|_| char::from(b'0' + random_below(10) as u8))
+        .collect()
+}
+
+fn pick<T: Copy>(items: &[T]) -> T {
+    items[random_below(items.len() as u64) as usize]
+}
+
+fn detect_kind(value: &str) -> Option<String> {
+    if !value.is_ascii() {
+        return None;
+    }
+    let kind = if value.len() >= 15
+        && value[..2].chars().all(|c| c.is_ascii_uppercase())
+        && value[2..4].chars().all(|c| c.is_ascii_digit())
+    {
+        "iban"
+    } else if value.len() == 18 && !value.chars().all(|c| c.is_ascii_digit()) {
+        "uscc"
+    } else if value.len() == 11
+        && value.starts_with('1')
+        && value.chars().all(|c| c.is_ascii_digit())
+    {
+        "cn_phone"
+    } else if (12..=19).contains(&value.len()) && value.chars().all(|c| c.is_ascii_digit()) {
+        "credit_card"
+    } else if value.len() == 18 {
+        "uscc"
+    } else {
+        return None;
+    };
+    Some(kind.to_string())
+}
+
+/// Luhn 校验位，digits 为不含校验位的数字串
+pub(crate) fn luhn_check_digit(digits: &str) -> u32 {
+    let sum: u32 = digits
+        .chars()
+        .rev()
+        .filter_map(|c| c.to_digit(10))
+        .enumerate()
+        .map(|(i, d)| {
+            if i % 2 == 0 {
+                let doubled = d * 2;
+                if doubled > 9 {
+                    doubled - 9
+                } else {
+                    doubled
+                }
+            } else {
+                d
+            }
+        })
+        .sum();
+    (10 - sum % 10) % 10
+}
+
+fn generate_card(brand: Option<&str>) -> Result<GeneratedNumber, String> {
+    let (name, ranges, length) = match brand.map(|b| b.trim().to_lowercase()) {
+        Some(brand) if !brand.is_empty() => *CARD_BRANDS
+            .iter()
+            .find(|(name, ..)| *name == brand)
+            .ok_or_else(|| format!("不支持的卡组织: {}", brand))?,
+        _ => pick(CARD_BRANDS),
+    };
+    let (start, end) = pick(ranges);
+    let prefix = (start as u64 + random_below((end - start + 1) as u64)).to_string();
+    let mut number = prefix.clone();
+    number.push_str(&random_digits(length - prefix.len() - 1));
+    number.push(char::from_digit(luhn_check_digit(&number), 10).unwrap());
+    let formatted = if name == "amex" {
+        format!("{} {} {}", &number[..4], &number[4..10], &number[10..])
+    } else {
+        group(&number, 4)
+    };
+    Ok(GeneratedNumber {
+        value: number,
+        formatted,
+        detail: name.to_string(),
+    })
+}
+
+pub(crate) fn card_brand(number: &str) -> Option<(&'static str, usize)> {
+    CARD_BRANDS.iter().find_map(|(name, ranges, length)| {
+        ranges
+            .iter()
+            .any(|(start, end)| {
+                let width = start.to_string().len();
+                number
+                    .get(..width)
+                    .and_then(|p| p.parse::<u32>().ok())
+                    .is_some_and(|p| (*start..=*end).contains(&p))
+            })
+            .then_some((*name, *length))
+    })
+}
+
+fn validate_card(number: &str) -> (Option<String>, Vec<String>) {
+    let mut errors = Vec::new();
+    if !number.chars().all(|c| c.is_ascii_digit()) {
+        return (None, vec!["卡号只能包含数字".to_string()]);
+    }
+    if !(12..=19).contains(&number.len()) {
+        errors.push(format!("卡号长度 {} 不在 12~19 位之间", number.len()));
+    }
+    let (body, check) = number.split_at(number.len() - 1);
+    if luhn_check_digit(body).to_string() != check {
+        errors.push("Luhn 校验位不正确".to_string());
+    }
+    let brand = card_brand(number);
+    if let Some((name, length)) = brand {
+        if number.len() != length {
+            errors.push(format!("{} 卡号通常为 {} 位", name, length));
+        }
+    }
+    (brand.map(|(name, _)| name.to_string()), errors)
+}
+
+/// 按 ISO 7064 MOD 97-10 计算余数，字母按 A=10 … Z=35 展开
+fn mod97(value: &str) -> Option<u32> {
+    let mut remainder = 0u32;
+    for c in value.chars() {
+        let digit = c.to_digit(36)?;
+        remainder = if digit >= 10 {
+            (remainder * 100 + digit) % 97
+        } else {
+            (remainder * 10 + digit) % 97
+        };
+    }
+    Some(remainder)
+}
+
+fn generate_bban(structure: &str) -> String {
+    let mut bban = String::new();
+    let mut len = 0usize;
+    for c in structure.chars() {
+        if let Some(d) = c.to_digit(10) {
+            len = len * 10 + d as usize;
+            continue;
+        }
+        for _ in 0..len {
+            let pool: &[u8] = match c {
+                'n' => b"0123456789",
+                'a' => b"ABCDEFGHIJKLMNOPQRSTUVWXYZ",
+                _ => b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ",
+            };
+            bban.push(char::from(pick(pool)));
+        }
+        len = 0;
+    }
+    bban
+}
+
+fn bban_matches(bban: &str, structure: &str) -> bool {
+    let mut chars = bban.chars();
+    let mut len = 0usize;
+    for c in structure.chars() {
+        if let Some(d) = c.to_digit(10) {
+            len = len * 10 + d as usize;
+            continue;
+        }
+        for _ in 0..len {
+            let ok = match (c, chars.next()) {
+                ('n', Some(x)) => x.is_ascii_digit(),
+                ('a', Some(x)) => x.is_ascii_uppercase(),
+                (_, Some(x)) => x.is_ascii_alphanumeric(),
+                (_, None) => false,
+            };
+            if !ok {
+                return false;
+            }
+        }
+        len = 0;
+    }
+    chars.next().is_none()
+}
+
+fn generate_iban(country: Option<&str>) -> Result<GeneratedNumber, String> {
+    let (code, _, structure) = match country.map(|c| c.trim().to_uppercase()) {
+        Some(country) if !country.is_empty() => *IBAN_COUNTRIES
+            .iter()
+            .find(|(code, ..)| *code == country)
+            .ok_or_else(|| format!("不支持的 IBAN 国家: {}", country))?,
+        _ => pick(IBAN_COUNTRIES),
+    };
+    let mut bban = generate_bban(structure);
+    // 比利时和法国的 BBAN 自带国内校验位，一并算好避免被银行系统拒绝
+    match code {
+        "BE" => {
+            let base: u64 = bban[..10].parse().unwrap();
+            let check = match base % 97 {
+                0 => 97,
+                r => r,
+            };
+            bban.replace_range(10.., &format!("{:02}", check));
+        }
+        "FR" => {
+            let base: u128 = bban[..21].parse().unwrap();
+            let key = 97 - (base * 100) % 97;
+            bban.replace_range(21.., &format!("{:02}", key));
+        }
+        _ => {}
+    }
+    let remainder = mod97(&format!("{}{}00", bban, code)).unwrap();
+    let iban = format!("{}{:02}{}", code, 98 - remainder, bban);
+    Ok(GeneratedNumber {
+        formatted: group(&iban, 4),
+        value: iban,
+        detail: code.to_string(),
+    })
+}
+
+fn validate_iban(iban: &str) -> (Option<String>, Vec<String>) {
+    let mut errors = Vec::new();
+    if iban.len() < 15 || !iban.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return (
+            None,
+            vec!["IBAN 只能由字母和数字组成，且至少 15 位".to_string()],
+        );
+    }
+    let country = &iban[..2];
+    let spec = IBAN_COUNTRIES.iter().find(|(code, ..)| *code == country);
+    match spec {
+        Some((_, length, structure)) => {
+            if iban.len() != *length {
+                errors.push(format!(
+                    "{} 的 IBAN 应为 {} 位，实际 {} 位",
+                    country,
+                    length,
+                    iban.len()
+                ));
+            } else if !bban_matches(&iban[4..], structure) {
+                errors.push(format!("BBAN 部分不符合 {} 的格式 {}", country, structure));
+            }
+        }
+        None => errors.push(format!("未内置 {} 的 IBAN 格式，只校验了 MOD 97", country)),
+    }
+    let rearranged = format!("{}{}", &iban[4..], &iban[..4]);
+    if mod97(&rearranged) != Some(1) {
+        errors.push("MOD 97 校验位不正确".to_string());
+    }
+    // 未内置格式只是提示，不影响校验结论
+    if spec.is_none() && errors.len() == 1 {
+        errors.clear();
+    }
+    (Some(country.to_string()), errors)
+}
+
+fn generate_phone() -> GeneratedNumber {
+    let (carrier, segments) = pick(PHONE_SEGMENTS);
+    let number = format!("{}{}", pick(segments), random_digits(8));
+    GeneratedNumber {
+        formatted: format!("{} {} {}", &number[..3], &number[3..7], &number[7..]),
+        value: number,
+        detail: carrier.to_string(),
+    }
+}
+
+fn validate_phone(number: &str) -> (Option<String>, Vec<String>) {
+    let number = number.strip_prefix("+86").unwrap_or(number);
+    if number.len() != 11 || !number.chars().all(|c| c.is_ascii_digit()) {
+        return (None, vec!["大陆手机号应为 11 位数字".to_string()]);
+    }
+    let segment: u16 = number[..3].parse().unwrap();
+    match PHONE_SEGMENTS
+        .iter()
+        .find(|(_, segments)| segments.contains(&segment))
+    {
+        Some((carrier, _)) => (Some(carrier.to_string()), Vec::new()),
+        None => (None, vec![format!("{} 不是已分配的手机号段", segment)]),
+    }
+}
+
+/// 组织机构代码校验位（GB 11714），code 为前 8 位本体代码
+pub(crate) fn org_code_check_char(code: &str) -> Option<char> {
+    const WEIGHTS: [u32; 8] = [3, 7, 9, 10, 5, 8, 4, 2];
+    let mut sum = 0;
+    for (c, weight) in code.chars().zip(WEIGHTS) {
+        sum += c.to_digit(36)? * weight;
+    }
+    Some(match 11 - sum % 11 {
+        10 => 'X',
+        11 => '0',
+        d => char::from_digit(d, 10).unwrap(),
+    })
+}
+
+/// 统一社会信用代码校验位（GB 32100），code 为前 17 位
+pub(crate) fn uscc_check_char(code: &str) -> Option<char> {
+    let mut sum = 0;
+    for (c, weight) in code.chars().zip(USCC_WEIGHTS) {
+        sum += USCC_CHARSET.find(c)? as u32 * weight;
+    }
+    USCC_CHARSET.chars().nth(((31 - sum % 31) % 31) as usize)
+}
+
+fn generate_uscc() -> GeneratedNumber {
+    let org_body = random_digits(8);
+    let org_check = org_code_check_char(&org_body).unwrap();
+    let mut code = format!("91{}{}{}", pick(USCC_REGIONS), org_body, org_check);
+    code.push(uscc_check_char(&code).unwrap());
+    GeneratedNumber {
+        formatted: code.clone(),
+        value: code,
+        detail: "工商登记的企业".to_string(),
+    }
+}
+
+fn validate_uscc(code: &str) -> (Option<String>, Vec<String>) {
+    if code.len() != 18 {
+        return (None, vec!["统一社会信用代码应为 18 位".to_string()]);
+    }
+    if let Some(c) = code.chars().find(|c| !USCC_CHARSET.contains(*c)) {
+        return (None, vec![format!("包含不允许的字符 {}", c)]);
+    }
+    let mut errors = Vec::new();
+    if org_code_check_char(&code[8..16]) != code[16..17].chars().next() {
+        errors.push("组织机构代码校验位不正确".to_string());
+    }
+    if uscc_check_char(&code[..17]) != code[17..].chars().next() {
+        errors.push("统一社会信用代码校验位不正确".to_string());
+    }
+    (Some(format!("登记管理部门代码 {}", &code[..1])), errors)
+}
+
+fn group(value: &str, size: usize) -> String {
+    value
+        .as_bytes()
+        .chunks(size)
+        .map(|chunk| String::from_utf8_lossy(chunk).to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validate(value: &str) -> NumberValidation {
+        validate_test_number(ValidateNumberRequest {
+            value: value.to_string(),
+            kind: None,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_validate_known_numbers() {
+        let card = validate("4111 1111 1111 1111");
+        assert_eq!(card.kind, "credit_card");
+        assert!(card.valid);
+        assert_eq!(card.detail.as_deref(), Some("visa"));
+        assert!(!validate("4111111111111112").valid);
+        assert_eq!(validate("378282246310005").detail.as_deref(), Some("amex"));
+
+        let iban = validate("GB82 WEST 1234 5698 7654 32");
+        assert_eq!(iban.kind, "iban");
+        assert!(iban.valid, "{:?}", iban.errors);
+        assert!(validate("DE89370400440532013000").valid);
+        assert!(!validate("DE89370400440532013001").valid);
+
+        let phone = validate("138-0013-8000");
+        assert_eq!(phone.kind, "cn_phone");
+        assert_eq!(phone.detail.as_deref(), Some("中国移动"));
+        assert!(!validate("10012345678").valid);
+
+        let uscc = validate("91350100M000100Y43");
+        assert_eq!(uscc.kind, "uscc");
+        assert!(uscc.valid, "{:?}", uscc.errors);
+        assert!(!validate("91350100M000100Y44").valid);
+
+        let cjk = ValidateNumberRequest {
+            value: "中文中文中文中文中文".to_string(),
+            kind: None,
+        };
+        assert!(validate_test_number(cjk).is_err());
+    }
+
+    #[test]
+    fn test_generated_numbers_validate() {
+        for kind in ["credit_card", "iban", "cn_phone", "uscc"] {
+            let result = generate_test_numbers(FakeFinanceRequest {
+                kind: kind.to_string(),
+                brand: None,
+                country: None,
+                count: Some(50),
+            })
+            .unwrap();
+            assert_eq!(result.items.len(), 50);
+            for item in &result.items {
+                let validation = validate_test_number(ValidateNumberRequest {
+                    value: item.formatted.clone(),
+                    kind: Some(kind.to_string()),
+                })
+                .unwrap();
+                assert!(validation.valid, "{} {:?}", item.value, validation.errors);
+            }
+        }
+        let amex = generate_card(Some("amex")).unwrap();
+        assert_eq!(amex.value.len(), 15);
+        assert!(generate_card(Some("paypal")).is_err());
+        assert_eq!(generate_iban(Some("nl")).unwrap().value.len(), 18);
+    }
+}
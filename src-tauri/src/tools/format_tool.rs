@@ -0,0 +1,267 @@
+use crate::utils::code_formatter::CodeFormatter;
+use lightningcss::stylesheet::{ParserOptions, PrinterOptions, StyleSheet};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+const LANGUAGES: [&str; 6] = ["go", "rust", "json", "sql", "xml", "css"];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FormatCodeRequest {
+    pub code: String,
+    pub language: String,                 // go, rust, json, sql, xml, css
+    pub indent: Option<usize>,            // 缩进空格数，Go 固定使用 Tab
+    pub uppercase_keywords: Option<bool>, // SQL 关键字大写，默认 true
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FormatCodeResponse {
+    pub formatted: String,
+    pub language: String,
+    pub formatter: String, // 实际使用的格式化器
+    pub changed: bool,
+    pub warnings: Vec<String>,
+}
+
+/// 按语言格式化代码
+///
+/// Go 和 Rust 优先调用本机的 gofmt / rustfmt，未安装时退回内置实现，
+/// 其余语言使用内置格式化器。
+#[tauri::command]
+pub async fn format_code(request: FormatCodeRequest) -> Result<FormatCodeResponse, String> {
+    tokio::task::spawn_blocking(move || format_code_blocking(&request))
+        .await
+        .map_err(|e| format!("格式化任务失败: {}", e))?
+}
+
+fn format_code_blocking(request: &FormatCodeRequest) -> Result<FormatCodeResponse, String> {
+    let language = request.language.trim().to_lowercase();
+    let language = match language.as_str() {
+        "golang" => "go".to_string(),
+        "rs" => "rust".to_string(),
+        _ => language,
+    };
+    if !LANGUAGES.contains(&language.as_str()) {
+        return Err(format!("不支持的语言: {}", request.language));
+    }
+    if request.code.trim().is_empty() {
+        return Err("代码不能为空".to_string());
+    }
+    let indent = request.indent.unwrap_or(2).clamp(1, 8);
+    let mut warnings = Vec::new();
+
+    let (formatted, formatter) = match language.as_str() {
+        "go" => match run_external("gofmt", &[], &request.code) {
+            Some(result) => (result?, "gofmt"),
+            None => {
+                warnings.push("未找到 gofmt，使用内置缩进整理，不会调整表达式内部格式".to_string());
+                (CodeFormatter::format_go_code(&request.code), "builtin")
+            }
+        },
+        "rust" => {
+            let edition = ["--edition", "2021", "--emit", "stdout", "--quiet"];
+            match run_external("rustfmt", &edition, &request.code) {
+                Some(result) => (result?, "rustfmt"),
+                None => {
+                    warnings.push(
+                        "未找到 rustfmt，使用 prettyplease 格式化，普通注释会被移除".to_string(),
+                    );
+                    (format_rust(&request.code)?, "prettyplease")
+                }
+            }
+        }
+        "json" => (format_json(&request.code, indent)?, "builtin"),
+        "sql" => (
+            format_sql(
+                &request.code,
+                indent,
+                request.uppercase_keywords.unwrap_or(true),
+            ),
+            "sqlformat",
+        ),
+        "xml" => (CodeFormatter::format_xml_code(&request.code), "builtin"),
+        "css" => (format_css(&request.code)?, "lightningcss"),
+        _ => return Err(format!("不支持的语言: {}", request.language)),
+    };
+
+    Ok(FormatCodeResponse {
+        changed: formatted != request.code,
+        formatted,
+        language,
+        formatter: formatter.to_string(),
+        warnings,
+    })
+}
+
+/// 通过标准输入调用外部格式化器，程序不存在时返回 None
+fn run_external(program: &str, args: &[&str], code: &str) -> Option<Result<String, String>> {
+    let mut child = match Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return None,
+        Err(e) => return Some(Err(format!("启动 {} 失败: {}", program, e))),
+    };
+
+    // 在独立线程写入，避免输出较大时双方互相阻塞
+    let mut stdin = child.stdin.take()?;
+    let input = code.to_string();
+    let writer = std::thread::spawn(move || stdin.write_all(input.as_bytes()));
+    let output = match child.wait_with_output() {
+        Ok(output) => output,
+        Err(e) => return Some(Err(format!("{} 执行失败: {}", program, e))),
+    };
+    let _ = writer.join();
+
+    if !output.status.success() {
+        return Some(Err(format!(
+            "{} 格式化失败: {}",
+            program,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Some(Ok(String::from_utf8_lossy(&output.stdout).to_string()))
+}
+
+fn format_rust(code: &str) -> Result<String, String> {
+    let file = syn::parse_file(code).map_err(|e| format!("Rust 语法错误: {}", e))?;
+    Ok(prettyplease::unparse(&file))
+}
+
+/// 重新缩进 JSON，保留原有的键顺序和数字写法
+fn format_json(code: &str, indent: usize) -> Result<String, String> {
+    serde_json::from_str::<serde::de::IgnoredAny>(code)
+        .map_err(|e| format!("JSON解析失败: {}", e))?;
+
+    let unit = " ".repeat(indent);
+    let mut output = String::with_capacity(code.len() * 2);
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut chars = code.chars().peekable();
+    let newline = |output: &mut String, depth: usize| {
+        output.push('\n');
+        output.push_str(&unit.repeat(depth));
+    };
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            output.push(c);
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+        match c {
+            '"' => {
+                in_string = true;
+                output.push(c);
+            }
+            '{' | '[' => {
+                output.push(c);
+                // 空对象和空数组保持在一行
+                while chars.peek().is_some_and(|n| n.is_whitespace()) {
+                    chars.next();
+                }
+                if matches!(chars.peek(), Some('}') | Some(']')) {
+                    if let Some(close) = chars.next() {
+                        output.push(close);
+                    }
+                } else {
+                    depth += 1;
+                    newline(&mut output, depth);
+                }
+            }
+            '}' | ']' => {
+                depth = depth.saturating_sub(1);
+                newline(&mut output, depth);
+                output.push(c);
+            }
+            ',' => {
+                output.push(c);
+                newline(&mut output, depth);
+            }
+            ':' => output.push_str(": "),
+            c if c.is_whitespace() => {}
+            c => output.push(c),
+        }
+    }
+    Ok(output)
+}
+
+fn format_sql(code: &str, indent: usize, uppercase: bool) -> String {
+    sqlformat::format(
+        code,
+        &sqlformat::QueryParams::None,
+        sqlformat::FormatOptions {
+            indent: sqlformat::Indent::Spaces(indent as u8),
+            uppercase,
+            lines_between_queries: 2,
+        },
+    )
+}
+
+fn format_css(code: &str) -> Result<String, String> {
+    let sheet = StyleSheet::parse(code, ParserOptions::default())
+        .map_err(|e| format!("CSS 解析失败: {}", e))?;
+    sheet
+        .to_css(PrinterOptions::default())
+        .map(|result| result.code)
+        .map_err(|e| format!("CSS 输出失败: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(language: &str, code: &str) -> FormatCodeRequest {
+        FormatCodeRequest {
+            code: code.to_string(),
+            language: language.to_string(),
+            indent: Some(2),
+            uppercase_keywords: None,
+        }
+    }
+
+    #[test]
+    fn test_format_json_keeps_order() {
+        let formatted =
+            format_json(r#"{"b":1,"a":[1, 2.50,{}],"s":"x, \"y\": {z}","e":[ ]}"#, 2).unwrap();
+        assert_eq!(
+            formatted,
+            "{\n  \"b\": 1,\n  \"a\": [\n    1,\n    2.50,\n    {}\n  ],\n  \"s\": \"x, \\\"y\\\": {z}\",\n  \"e\": []\n}"
+        );
+        assert!(format_json("{\"a\":", 2).is_err());
+    }
+
+    #[test]
+    fn test_format_builtin_languages() {
+        let sql =
+            format_code_blocking(&request("sql", "select id,name from users where id=1")).unwrap();
+        assert_eq!(sql.formatter, "sqlformat");
+        assert!(sql.formatted.starts_with("SELECT\n  id,\n  name\nFROM"));
+
+        let css = format_code_blocking(&request("css", "a{color:red;margin:0 auto}")).unwrap();
+        assert!(css.formatted.contains("a {\n  color: red;"));
+
+        assert_eq!(
+            format_rust("fn main(){let x=1;}").unwrap(),
+            "fn main() {\n    let x = 1;\n}\n"
+        );
+        assert!(format_rust("fn main(").is_err());
+        assert!(format_code_blocking(&request("cobol", "x")).is_err());
+        // 没有内置格式化器的语言不在支持列表中
+        assert_eq!(
+            format_code_blocking(&request("javascript", "let a=1")).unwrap_err(),
+            "不支持的语言: javascript"
+        );
+    }
+}
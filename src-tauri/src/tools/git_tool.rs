@@ -0,0 +1,729 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+const DEFAULT_COMMIT_TYPES: &[&str] = &[
+    "feat", "fix", "docs", "style", "refactor", "perf", "test", "build", "ci", "chore", "revert",
+];
+
+/// 常见的错误类型写法及其规范写法
+const COMMIT_TYPE_ALIASES: &[(&str, &str)] = &[
+    ("feature", "feat"),
+    ("features", "feat"),
+    ("add", "feat"),
+    ("bugfix", "fix"),
+    ("hotfix", "fix"),
+    ("fixed", "fix"),
+    ("fixes", "fix"),
+    ("doc", "docs"),
+    ("documentation", "docs"),
+    ("tests", "test"),
+    ("testing", "test"),
+    ("refact", "refactor"),
+    ("refactoring", "refactor"),
+    ("performance", "perf"),
+    ("chores", "chore"),
+    ("deps", "build"),
+    ("format", "style"),
+];
+
+/// (模板 ID, 显示名称, 分类, 规则)
+type GitignoreTemplate = (
+    &'static str,
+    &'static str,
+    &'static str,
+    &'static [&'static str],
+);
+
+const GITIGNORE_TEMPLATES: &[GitignoreTemplate] = &[
+    (
+        "rust",
+        "Rust",
+        "language",
+        &["/target/", "**/*.rs.bk", "*.pdb"],
+    ),
+    (
+        "go",
+        "Go",
+        "language",
+        &[
+            "*.exe",
+            "*.exe~",
+            "*.dll",
+            "*.so",
+            "*.dylib",
+            "*.test",
+            "*.out",
+            "go.work",
+            "go.work.sum",
+            "vendor/",
+        ],
+    ),
+    (
+        "node",
+        "Node.js",
+        "language",
+        &[
+            "node_modules/",
+            "npm-debug.log*",
+            "yarn-debug.log*",
+            "yarn-error.log*",
+            "pnpm-debug.log*",
+            ".npm",
+            ".yarn/cache",
+            ".pnp.*",
+            "dist/",
+            "coverage/",
+            ".env",
+            ".env.local",
+            ".env.*.local",
+            "*.tsbuildinfo",
+        ],
+    ),
+    (
+        "python",
+        "Python",
+        "language",
+        &[
+            "__pycache__/",
+            "*.py[cod]",
+            "*.so",
+            ".Python",
+            "build/",
+            "dist/",
+            "*.egg-info/",
+            ".eggs/",
+            ".venv/",
+            "venv/",
+            ".env",
+            ".pytest_cache/",
+            ".mypy_cache/",
+            ".ruff_cache/",
+            ".coverage",
+            "htmlcov/",
+        ],
+    ),
+    (
+        "java",
+        "Java",
+        "language",
+        &[
+            "*.class",
+            "*.jar",
+            "*.war",
+            "*.ear",
+            "hs_err_pid*",
+            "target/",
+            ".gradle/",
+            "build/",
+            "out/",
+        ],
+    ),
+    (
+        "dotnet",
+        ".NET",
+        "language",
+        &[
+            "[Bb]in/",
+            "[Oo]bj/",
+            "*.user",
+            "*.suo",
+            "*.userprefs",
+            "[Dd]ebug/",
+            "[Rr]elease/",
+            "*.nupkg",
+            "TestResults/",
+        ],
+    ),
+    (
+        "c",
+        "C/C++",
+        "language",
+        &[
+            "*.o",
+            "*.obj",
+            "*.a",
+            "*.lib",
+            "*.so",
+            "*.dll",
+            "*.dylib",
+            "*.exe",
+            "*.out",
+            "build/",
+            "cmake-build-*/",
+            "CMakeCache.txt",
+            "CMakeFiles/",
+        ],
+    ),
+    (
+        "php",
+        "PHP",
+        "language",
+        &["vendor/", "composer.phar", ".phpunit.result.cache", ".env"],
+    ),
+    (
+        "ruby",
+        "Ruby",
+        "language",
+        &[
+            "*.gem",
+            ".bundle/",
+            "vendor/bundle",
+            "log/",
+            "tmp/",
+            ".byebug_history",
+        ],
+    ),
+    (
+        "swift",
+        "Swift",
+        "language",
+        &[
+            ".build/",
+            "DerivedData/",
+            "*.xcuserstate",
+            "Packages/",
+            "xcuserdata/",
+        ],
+    ),
+    (
+        "vscode",
+        "VS Code",
+        "ide",
+        &[
+            ".vscode/*",
+            "!.vscode/settings.json",
+            "!.vscode/tasks.json",
+            "!.vscode/launch.json",
+            "!.vscode/extensions.json",
+            "*.code-workspace",
+        ],
+    ),
+    (
+        "jetbrains",
+        "JetBrains",
+        "ide",
+        &[".idea/", "*.iml", "*.ipr", "*.iws", "out/"],
+    ),
+    (
+        "vim",
+        "Vim",
+        "ide",
+        &[
+            "[._]*.s[a-v][a-z]",
+            "[._]*.sw[a-p]",
+            "Session.vim",
+            "tags",
+            "*~",
+        ],
+    ),
+    (
+        "emacs",
+        "Emacs",
+        "ide",
+        &["*~", "\\#*\\#", ".\\#*", "auto-save-list", "*.elc"],
+    ),
+    (
+        "eclipse",
+        "Eclipse",
+        "ide",
+        &[".metadata", ".project", ".classpath", ".settings/", "bin/"],
+    ),
+    (
+        "macos",
+        "macOS",
+        "os",
+        &[
+            ".DS_Store",
+            ".AppleDouble",
+            ".LSOverride",
+            "._*",
+            ".Spotlight-V100",
+            ".Trashes",
+        ],
+    ),
+    (
+        "windows",
+        "Windows",
+        "os",
+        &[
+            "Thumbs.db",
+            "ehthumbs.db",
+            "Desktop.ini",
+            "$RECYCLE.BIN/",
+            "*.lnk",
+        ],
+    ),
+    (
+        "linux",
+        "Linux",
+        "os",
+        &["*~", ".fuse_hidden*", ".directory", ".Trash-*", ".nfs*"],
+    ),
+    (
+        "terraform",
+        "Terraform",
+        "tool",
+        &[
+            "**/.terraform/*",
+            "*.tfstate",
+            "*.tfstate.*",
+            "crash.log",
+            "*.tfvars",
+            ".terraformrc",
+            "terraform.rc",
+        ],
+    ),
+    (
+        "tauri",
+        "Tauri",
+        "tool",
+        &[
+            "src-tauri/target/",
+            "src-tauri/gen/schemas/",
+            "dist/",
+            "node_modules/",
+        ],
+    ),
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitignoreTemplateInfo {
+    pub id: String,
+    pub name: String,
+    pub category: String, // language / ide / os / tool
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitignoreResult {
+    pub content: String,
+    pub templates: Vec<String>,
+    pub rule_count: usize,
+    pub duplicates_removed: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitLintOptions {
+    pub allowed_types: Option<Vec<String>>,
+    pub max_header_length: Option<usize>, // 默认 72
+    pub require_scope: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitFooter {
+    pub token: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitLintIssue {
+    pub severity: String, // error / warning
+    pub rule: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitLintResult {
+    pub valid: bool,
+    pub commit_type: Option<String>,
+    pub scope: Option<String>,
+    pub breaking: bool,
+    pub description: Option<String>,
+    pub body: Option<String>,
+    pub footers: Vec<CommitFooter>,
+    pub issues: Vec<CommitLintIssue>,
+    pub suggested_header: Option<String>,
+}
+
+/// 列出内置的 .gitignore 模板
+#[tauri::command]
+pub fn list_gitignore_templates() -> Vec<GitignoreTemplateInfo> {
+    GITIGNORE_TEMPLATES
+        .iter()
+        .map(|(id, name, category, _)| GitignoreTemplateInfo {
+            id: id.to_string(),
+            name: name.to_string(),
+            category: category.to_string(),
+        })
+        .collect()
+}
+
+/// 合并多个模板生成 .gitignore，跨模板重复的规则只保留首次出现
+#[tauri::command]
+pub fn generate_gitignore(
+    templates: Vec<String>,
+    custom_rules: Option<String>,
+) -> Result<GitignoreResult, String> {
+    if templates.is_empty() && custom_rules.as_deref().is_none_or(|r| r.trim().is_empty()) {
+        return Err("请至少选择一个模板".to_string());
+    }
+
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut sections = Vec::new();
+    let mut used = Vec::new();
+    let mut duplicates_removed = 0;
+
+    let mut push_section = |title: &str, rules: Vec<String>| {
+        let mut lines = Vec::new();
+        for rule in rules {
+            let rule = rule.trim().to_string();
+            if rule.is_empty() || rule.starts_with('#') {
+                continue;
+            }
+            if seen.insert(rule.clone()) {
+                lines.push(rule);
+            } else {
+                duplicates_removed += 1;
+            }
+        }
+        if !lines.is_empty() {
+            sections.push(format!("### {} ###\n{}", title, lines.join("\n")));
+        }
+    };
+
+    for id in &templates {
+        let id = id.trim().to_lowercase();
+        let (_, name, _, rules) = GITIGNORE_TEMPLATES
+            .iter()
+            .find(|(tid, _, _, _)| *tid == id)
+            .ok_or_else(|| format!("未知的模板: {}", id))?;
+        if used.contains(&name.to_string()) {
+            continue;
+        }
+        used.push(name.to_string());
+        push_section(name, rules.iter().map(|r| r.to_string()).collect());
+    }
+    if let Some(custom) = &custom_rules {
+        push_section("Custom", custom.lines().map(str::to_string).collect());
+    }
+
+    let rule_count = seen.len();
+    Ok(GitignoreResult {
+        content: format!("{}\n", sections.join("\n\n")),
+        templates: used,
+        rule_count,
+        duplicates_removed,
+    })
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b_chars.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b_chars.iter().enumerate() {
+            let cost = if ca == *cb { 0 } else { 1 };
+            current.push(
+                (previous[j] + cost)
+                    .min(previous[j + 1] + 1)
+                    .min(current[j] + 1),
+            );
+        }
+        previous = current;
+    }
+    previous[b_chars.len()]
+}
+
+/// 为不规范的类型推荐最接近的合法类型
+fn suggest_type(commit_type: &str, allowed: &[String]) -> Option<String> {
+    let lower = commit_type.to_lowercase();
+    if allowed.contains(&lower) {
+        return Some(lower);
+    }
+    if let Some((_, target)) = COMMIT_TYPE_ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == lower)
+    {
+        if allowed.iter().any(|a| a == target) {
+            return Some(target.to_string());
+        }
+    }
+    allowed
+        .iter()
+        .map(|t| (levenshtein(&lower, t), t))
+        .filter(|(distance, _)| *distance <= 2)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, t)| t.clone())
+}
+
+/// 按约定式提交（Conventional Commits 1.0）规范检查提交信息
+#[tauri::command]
+pub fn lint_commit_message(
+    message: String,
+    options: Option<CommitLintOptions>,
+) -> Result<CommitLintResult, String> {
+    let message = message.trim_end();
+    if message.trim().is_empty() {
+        return Err("提交信息不能为空".to_string());
+    }
+    let allowed: Vec<String> = options
+        .as_ref()
+        .and_then(|o| o.allowed_types.clone())
+        .filter(|t| !t.is_empty())
+        .unwrap_or_else(|| DEFAULT_COMMIT_TYPES.iter().map(|t| t.to_string()).collect());
+    let max_header = options
+        .as_ref()
+        .and_then(|o| o.max_header_length)
+        .unwrap_or(72);
+    let require_scope = options
+        .as_ref()
+        .and_then(|o| o.require_scope)
+        .unwrap_or(false);
+
+    let mut issues = Vec::new();
+    let mut push = |severity: &str, rule: &str, message: String| {
+        issues.push(CommitLintIssue {
+            severity: severity.to_string(),
+            rule: rule.to_string(),
+            message,
+        })
+    };
+
+    // 去掉 git 注释行（# 开头）
+    let lines: Vec<&str> = message.lines().filter(|l| !l.starts_with('#')).collect();
+    let header = lines.first().copied().unwrap_or_default().trim_end();
+
+    let header_regex = Regex::new(
+        r"^(?P<type>[A-Za-z]+)(?:\((?P<scope>[^()]*)\))?(?P<breaking>!)?: ?(?P<desc>.*)$",
+    )
+    .unwrap();
+    let captures = header_regex.captures(header);
+
+    let (commit_type, scope, header_breaking, description) = match &captures {
+        Some(c) => (
+            c.name("type").map(|m| m.as_str().to_string()),
+            c.name("scope").map(|m| m.as_str().to_string()),
+            c.name("breaking").is_some(),
+            c.name("desc").map(|m| m.as_str().to_string()),
+        ),
+        None => {
+            push(
+                "error",
+                "header-format",
+                "标题格式应为 type(scope): description".to_string(),
+            );
+            (None, None, false, None)
+        }
+    };
+
+    let mut suggested_type = None;
+    if let Some(t) = &commit_type {
+        if !allowed.contains(t) {
+            suggested_type = suggest_type(t, &allowed);
+            let hint = suggested_type
+                .as_ref()
+                .map(|s| format!("，是否应为 {}？", s))
+                .unwrap_or_default();
+            push(
+                "error",
+                "type-enum",
+                format!(
+                    "类型 '{}' 不在允许列表 [{}] 中{}",
+                    t,
+                    allowed.join(", "),
+                    hint
+                ),
+            );
+        }
+    }
+    if captures.is_some() && !header.contains(": ") {
+        push("error", "header-space", "冒号后需要一个空格".to_string());
+    }
+    match &scope {
+        Some(s) if s.trim().is_empty() => push(
+            "error",
+            "scope-empty",
+            "括号中的 scope 不能为空".to_string(),
+        ),
+        None if require_scope && captures.is_some() => {
+            push("error", "scope-required", "缺少 scope".to_string())
+        }
+        _ => {}
+    }
+
+    let description_trimmed = description.as_deref().map(str::trim).unwrap_or_default();
+    if captures.is_some() && description_trimmed.is_empty() {
+        push("error", "subject-empty", "缺少描述".to_string());
+    }
+    if description_trimmed.ends_with('.') || description_trimmed.ends_with('。') {
+        push(
+            "warning",
+            "subject-full-stop",
+            "描述末尾不应有句号".to_string(),
+        );
+    }
+    if description_trimmed
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_uppercase())
+    {
+        push(
+            "warning",
+            "subject-case",
+            "描述建议以小写字母开头".to_string(),
+        );
+    }
+    let header_length = header.chars().count();
+    if header_length > max_header {
+        push(
+            "error",
+            "header-max-length",
+            format!("标题长度 {} 超过上限 {}", header_length, max_header),
+        );
+    }
+
+    // 正文与脚注：标题后必须空一行
+    if lines.len() > 1 && !lines[1].trim().is_empty() {
+        push(
+            "error",
+            "body-leading-blank",
+            "标题与正文之间需要空一行".to_string(),
+        );
+    }
+    let footer_regex =
+        Regex::new(r"^(?P<token>BREAKING[ -]CHANGE|[A-Za-z][\w-]*)(?::\s|\s#)(?P<value>.*)$")
+            .unwrap();
+    let rest: Vec<&str> = lines.iter().skip(1).copied().collect();
+    let footer_start = rest
+        .iter()
+        .rposition(|l| l.trim().is_empty())
+        .map(|i| i + 1)
+        .filter(|i| rest[*i..].first().is_some_and(|l| footer_regex.is_match(l)))
+        .unwrap_or(rest.len());
+
+    let mut footers: Vec<CommitFooter> = Vec::new();
+    for line in &rest[footer_start..] {
+        match footer_regex.captures(line) {
+            Some(c) => footers.push(CommitFooter {
+                token: c["token"].to_string(),
+                value: c["value"].to_string(),
+            }),
+            // 脚注值可跨行
+            None => {
+                if let Some(last) = footers.last_mut() {
+                    last.value.push('\n');
+                    last.value.push_str(line);
+                }
+            }
+        }
+    }
+    for footer in &footers {
+        let upper = footer.token.to_uppercase();
+        if (upper == "BREAKING CHANGE" || upper == "BREAKING-CHANGE")
+            && footer.token != "BREAKING CHANGE"
+            && footer.token != "BREAKING-CHANGE"
+        {
+            push(
+                "error",
+                "footer-breaking-case",
+                "BREAKING CHANGE 必须大写".to_string(),
+            );
+        }
+    }
+    let body_text = rest[..footer_start].join("\n").trim().to_string();
+    for line in body_text.lines() {
+        if line.chars().count() > 100 {
+            push(
+                "warning",
+                "body-max-line-length",
+                "正文单行建议不超过 100 个字符".to_string(),
+            );
+            break;
+        }
+    }
+
+    let breaking = header_breaking
+        || footers
+            .iter()
+            .any(|f| f.token == "BREAKING CHANGE" || f.token == "BREAKING-CHANGE");
+
+    // 根据问题生成修正后的标题
+    let suggested_header = if issues.iter().any(|i| i.rule != "body-max-line-length") {
+        commit_type.as_ref().map(|t| {
+            let fixed_type = suggested_type.clone().unwrap_or_else(|| t.to_lowercase());
+            let scope_part = scope
+                .as_deref()
+                .filter(|s| !s.trim().is_empty())
+                .map(|s| format!("({})", s.trim()))
+                .unwrap_or_default();
+            let mut desc = description_trimmed
+                .trim_end_matches(['.', '。'])
+                .to_string();
+            if let Some(first) = desc.chars().next() {
+                if first.is_ascii_uppercase()
+                    && !desc.chars().nth(1).is_some_and(|c| c.is_ascii_uppercase())
+                {
+                    desc = first.to_ascii_lowercase().to_string() + &desc[first.len_utf8()..];
+                }
+            }
+            format!(
+                "{}{}{}: {}",
+                fixed_type,
+                scope_part,
+                if header_breaking { "!" } else { "" },
+                desc
+            )
+        })
+    } else {
+        None
+    };
+
+    Ok(CommitLintResult {
+        valid: !issues.iter().any(|i| i.severity == "error"),
+        commit_type,
+        scope,
+        breaking,
+        description,
+        body: (!body_text.is_empty()).then_some(body_text),
+        footers,
+        issues,
+        suggested_header,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lint_valid_commit() {
+        let message = "feat(parser)!: support nested arrays\n\nAdd recursive parsing.\n\nBREAKING CHANGE: output format changed\nRefs: #123";
+        let result = lint_commit_message(message.to_string(), None).unwrap();
+        assert!(result.valid);
+        assert!(result.breaking);
+        assert_eq!(result.scope.as_deref(), Some("parser"));
+        assert_eq!(result.body.as_deref(), Some("Add recursive parsing."));
+        assert_eq!(result.footers.len(), 2);
+        assert_eq!(result.footers[1].token, "Refs");
+    }
+
+    #[test]
+    fn test_lint_suggestions() {
+        let result = lint_commit_message("feature: Add login page.".to_string(), None).unwrap();
+        assert!(!result.valid);
+        assert_eq!(
+            result.suggested_header.as_deref(),
+            Some("feat: add login page")
+        );
+
+        let result = lint_commit_message("fxi: typo".to_string(), None).unwrap();
+        assert_eq!(result.suggested_header.as_deref(), Some("fix: typo"));
+    }
+
+    #[test]
+    fn test_generate_gitignore_dedup() {
+        let result = generate_gitignore(
+            vec![
+                "node".to_string(),
+                "python".to_string(),
+                "macos".to_string(),
+            ],
+            Some(".env\n*.local".to_string()),
+        )
+        .unwrap();
+        assert!(result.content.contains("### Node.js ###"));
+        assert_eq!(result.content.matches("\n.env\n").count(), 1);
+        assert!(result.duplicates_removed >= 2);
+        assert!(generate_gitignore(vec!["cobol".to_string()], None).is_err());
+    }
+}
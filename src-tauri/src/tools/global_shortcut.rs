@@ -13,6 +13,8 @@ pub struct HotKeyConfig {
 pub struct GlobalShortcutConfig {
     pub hotkey: HotKeyConfig,
     pub enabled: bool,
+    #[serde(default)]
+    pub screenshot_hotkey: Option<HotKeyConfig>, // 截图快捷键，未设置时不注册
 }
 
 impl Default for GlobalShortcutConfig {
@@ -27,6 +29,7 @@ impl Default for GlobalShortcutConfig {
                 key: "Space".to_string(),
             },
             enabled: true,
+            screenshot_hotkey: None,
         }
     }
 }
@@ -34,6 +37,7 @@ impl Default for GlobalShortcutConfig {
 pub struct GlobalShortcutState {
     pub config: Arc<Mutex<GlobalShortcutConfig>>,
     pub current_shortcut: Arc<Mutex<Option<Shortcut>>>,
+    pub screenshot_shortcut: Arc<Mutex<Option<Shortcut>>>,
 }
 
 impl GlobalShortcutState {
@@ -41,6 +45,7 @@ impl GlobalShortcutState {
         Self {
             config: Arc::new(Mutex::new(GlobalShortcutConfig::default())),
             current_shortcut: Arc::new(Mutex::new(None)),
+            screenshot_shortcut: Arc::new(Mutex::new(None)),
         }
     }
 }
@@ -244,11 +249,61 @@ pub fn set_global_shortcut_enabled(
     Ok(true)
 }
 
+/// 设置截图快捷键，传入 None 时取消
+#[tauri::command]
+pub fn set_screenshot_shortcut(
+    app: AppHandle,
+    config: Option<HotKeyConfig>,
+    state: State<'_, GlobalShortcutState>,
+) -> Result<bool, String> {
+    let shortcut = match &config {
+        Some(hotkey) => {
+            let shortcut = create_shortcut(hotkey)?;
+            if *state.current_shortcut.lock().unwrap() == Some(shortcut) {
+                return Err("截图快捷键不能与显示窗口的快捷键相同".to_string());
+            }
+            Some(shortcut)
+        }
+        None => None,
+    };
+
+    // 先取消已注册的截图快捷键
+    {
+        let mut screenshot_shortcut = state.screenshot_shortcut.lock().unwrap();
+        if let Some(existing) = screenshot_shortcut.take() {
+            if let Err(e) = app.global_shortcut().unregister(existing) {
+                eprintln!("Failed to unregister screenshot shortcut: {}", e);
+            }
+        }
+    }
+
+    if let Some(shortcut) = shortcut {
+        if let Err(e) = app.global_shortcut().register(shortcut) {
+            return Err(format!("Failed to register screenshot shortcut: {}", e));
+        }
+        *state.screenshot_shortcut.lock().unwrap() = Some(shortcut);
+    }
+
+    state.config.lock().unwrap().screenshot_hotkey = config;
+    Ok(true)
+}
+
 pub fn handle_global_shortcut_triggered(
     app: &AppHandle,
-    _shortcut: &Shortcut,
+    shortcut: &Shortcut,
     _event: &tauri_plugin_global_shortcut::ShortcutEvent,
 ) {
+    // 截图快捷键直接进入截图流程
+    let is_screenshot = app
+        .state::<GlobalShortcutState>()
+        .screenshot_shortcut
+        .lock()
+        .unwrap()
+        .is_some_and(|s| s == *shortcut);
+    if is_screenshot {
+        crate::tools::screenshot::spawn_shortcut_capture(app);
+        return;
+    }
     toggle_window_visibility(app);
 }
 
@@ -282,5 +337,13 @@ pub fn initialize_global_shortcut(
         }
     }
 
+    if let Some(hotkey) = &config.screenshot_hotkey {
+        let shortcut = create_shortcut(hotkey)?;
+        if let Err(e) = app.global_shortcut().register(shortcut) {
+            return Err(format!("Failed to register screenshot shortcut: {}", e));
+        }
+        *state.screenshot_shortcut.lock().unwrap() = Some(shortcut);
+    }
+
     Ok(())
 }
@@ -0,0 +1,1335 @@
+use crate::utils::string_utils::StringUtils;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use tauri::command;
+
+/// Options for generating a JSON example from Go structs
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GoToJsonOptions {
+    pub root_struct: Option<String>, // None picks the structs not referenced by others
+    #[serde(default)]
+    pub null_pointers: bool, // pointer fields become null instead of an example value
+}
+
+/// Options for generating CREATE TABLE DDL from Go structs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoToSqlOptions {
+    pub dialect: String, // "mysql", "postgres"
+    #[serde(default = "default_true")]
+    pub pluralize_table: bool,
+    #[serde(default)]
+    pub if_not_exists: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for GoToSqlOptions {
+    fn default() -> Self {
+        Self {
+            dialect: "mysql".to_string(),
+            pluralize_table: true,
+            if_not_exists: false,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct GoToSqlOutput {
+    pub sql: String,
+    pub table_names: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+/// A parsed Go type expression
+#[derive(Debug, Clone, PartialEq)]
+pub enum GoType {
+    Named(String),
+    Pointer(Box<GoType>),
+    Slice(Box<GoType>),
+    Map(Box<GoType>, Box<GoType>),
+    Struct(Vec<GoStructField>),
+    Interface,
+}
+
+/// A field of a Go struct, embedded fields have an empty name
+#[derive(Debug, Clone, PartialEq)]
+pub struct GoStructField {
+    pub name: String,
+    pub go_type: GoType,
+    pub embedded: bool,
+    pub tags: Vec<(String, String)>,
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GoStructDefinition {
+    pub name: String,
+    pub fields: Vec<GoStructField>,
+    pub table_name: Option<String>,
+}
+
+/// Generate a JSON example document from Go struct definitions
+#[command]
+pub fn convert_go_to_json(
+    go_code: &str,
+    options: Option<GoToJsonOptions>,
+) -> Result<String, String> {
+    let options = options.unwrap_or_default();
+    let structs = GoStructParser::parse(go_code)?;
+    let value = GoJsonGenerator::generate(&structs, &options)?;
+    serde_json::to_string_pretty(&value).map_err(|e| format!("JSON序列化失败: {}", e))
+}
+
+/// Generate CREATE TABLE statements from Go struct definitions
+#[command]
+pub fn convert_go_to_sql(
+    go_code: &str,
+    options: Option<GoToSqlOptions>,
+) -> Result<GoToSqlOutput, String> {
+    let options = options.unwrap_or_default();
+    let structs = GoStructParser::parse(go_code)?;
+    GoSqlGenerator::generate(&structs, &options)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Punct(char),
+    Tag(String),
+    Str(String),
+    Number(String),
+    Comment(String),
+    Newline,
+}
+
+/// Minimal parser for Go type declarations
+pub struct GoStructParser;
+
+impl GoStructParser {
+    /// Parse all struct type declarations and TableName methods
+    pub fn parse(code: &str) -> Result<Vec<GoStructDefinition>, String> {
+        let tokens = Self::tokenize(code)?;
+        let mut structs = Vec::new();
+        let mut table_names: HashMap<String, String> = HashMap::new();
+        let mut pos = 0;
+
+        while pos < tokens.len() {
+            match &tokens[pos] {
+                Token::Ident(word) if word == "type" => {
+                    pos += 1;
+                    if tokens.get(pos) == Some(&Token::Punct('(')) {
+                        pos += 1;
+                        loop {
+                            pos = Self::skip_blank(&tokens, pos);
+                            match tokens.get(pos) {
+                                Some(Token::Punct(')')) | None => break,
+                                _ => pos = Self::parse_type_spec(&tokens, pos, &mut structs)?,
+                            }
+                        }
+                    } else {
+                        pos = Self::parse_type_spec(&tokens, pos, &mut structs)?;
+                    }
+                }
+                Token::Ident(word) if word == "func" => {
+                    if let Some((receiver, table)) = Self::parse_table_name_method(&tokens, pos) {
+                        table_names.insert(receiver, table);
+                    }
+                    pos += 1;
+                }
+                _ => pos += 1,
+            }
+        }
+
+        if structs.is_empty() {
+            return Err("未找到 struct 类型定义".to_string());
+        }
+        for def in &mut structs {
+            def.table_name = table_names.get(&def.name).cloned();
+        }
+        Ok(structs)
+    }
+
+    fn tokenize(code: &str) -> Result<Vec<Token>, String> {
+        let chars: Vec<char> = code.chars().collect();
+        let mut tokens = Vec::new();
+        let mut i = 0;
+
+        while i < chars.len() {
+            let c = chars[i];
+            match c {
+                '\n' => {
+                    tokens.push(Token::Newline);
+                    i += 1;
+                }
+                c if c.is_whitespace() => i += 1,
+                '/' if chars.get(i + 1) == Some(&'/') => {
+                    let start = i + 2;
+                    while i < chars.len() && chars[i] != '\n' {
+                        i += 1;
+                    }
+                    let text: String = chars[start..i].iter().collect();
+                    tokens.push(Token::Comment(text.trim().to_string()));
+                }
+                '/' if chars.get(i + 1) == Some(&'*') => {
+                    i += 2;
+                    while i + 1 < chars.len() && !(chars[i] == '*' && chars[i + 1] == '/') {
+                        i += 1;
+                    }
+                    i += 2;
+                }
+                '`' => {
+                    let start = i + 1;
+                    i += 1;
+                    while i < chars.len() && chars[i] != '`' {
+                        i += 1;
+                    }
+                    if i >= chars.len() {
+                        return Err("结构体标签缺少结束的反引号".to_string());
+                    }
+                    tokens.push(Token::Tag(chars[start..i].iter().collect()));
+                    i += 1;
+                }
+                '"' => {
+                    let mut text = String::new();
+                    i += 1;
+                    while i < chars.len() && chars[i] != '"' {
+                        if chars[i] == '\\' && i + 1 < chars.len() {
+                            i += 1;
+                        }
+                        text.push(chars[i]);
+                        i += 1;
+                    }
+                    tokens.push(Token::Str(text));
+                    i += 1;
+                }
+                c if c.is_alphabetic() || c == '_' => {
+                    let start = i;
+                    while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                        i += 1;
+                    }
+                    tokens.push(Token::Ident(chars[start..i].iter().collect()));
+                }
+                c if c.is_ascii_digit() => {
+                    let start = i;
+                    while i < chars.len() && chars[i].is_ascii_alphanumeric() {
+                        i += 1;
+                    }
+                    tokens.push(Token::Number(chars[start..i].iter().collect()));
+                }
+                _ => {
+                    tokens.push(Token::Punct(c));
+                    i += 1;
+                }
+            }
+        }
+        Ok(tokens)
+    }
+
+    fn skip_blank(tokens: &[Token], mut pos: usize) -> usize {
+        while matches!(
+            tokens.get(pos),
+            Some(Token::Newline | Token::Comment(_) | Token::Punct(';'))
+        ) {
+            pos += 1;
+        }
+        pos
+    }
+
+    fn skip_line(tokens: &[Token], mut pos: usize) -> usize {
+        let mut depth = 0i32;
+        while let Some(token) = tokens.get(pos) {
+            match token {
+                Token::Punct('{') | Token::Punct('(') => depth += 1,
+                Token::Punct('}') | Token::Punct(')') if depth > 0 => depth -= 1,
+                Token::Punct(')') => return pos,
+                Token::Newline if depth == 0 => return pos,
+                _ => {}
+            }
+            pos += 1;
+        }
+        pos
+    }
+
+    /// Parse `Name struct { ... }`; other type specs are skipped
+    fn parse_type_spec(
+        tokens: &[Token],
+        pos: usize,
+        structs: &mut Vec<GoStructDefinition>,
+    ) -> Result<usize, String> {
+        let Some(Token::Ident(name)) = tokens.get(pos) else {
+            return Ok(Self::skip_line(tokens, pos + 1));
+        };
+        let mut type_pos = pos + 1;
+        // Skip generic type parameters
+        if tokens.get(type_pos) == Some(&Token::Punct('[')) {
+            while type_pos < tokens.len() && tokens[type_pos] != Token::Punct(']') {
+                type_pos += 1;
+            }
+            type_pos += 1;
+        }
+        if tokens.get(type_pos) == Some(&Token::Ident("struct".to_string())) {
+            let (fields, next) = Self::parse_struct_body(tokens, type_pos + 1)?;
+            structs.push(GoStructDefinition {
+                name: name.clone(),
+                fields,
+                table_name: None,
+            });
+            Ok(next)
+        } else {
+            Ok(Self::skip_line(tokens, type_pos))
+        }
+    }
+
+    /// Parse `{ fields }` starting at the opening brace
+    fn parse_struct_body(
+        tokens: &[Token],
+        pos: usize,
+    ) -> Result<(Vec<GoStructField>, usize), String> {
+        if tokens.get(pos) != Some(&Token::Punct('{')) {
+            return Err("struct 定义缺少 '{'".to_string());
+        }
+        let mut pos = pos + 1;
+        let mut fields = Vec::new();
+
+        loop {
+            pos = Self::skip_blank(tokens, pos);
+            match tokens.get(pos) {
+                None => return Err("struct 定义缺少 '}'".to_string()),
+                Some(Token::Punct('}')) => return Ok((fields, pos + 1)),
+                _ => {}
+            }
+
+            let (mut line_fields, next) = Self::parse_field_line(tokens, pos)?;
+            pos = next;
+
+            // Tag and trailing comment apply to every name on the line
+            let mut tags = Vec::new();
+            if let Some(Token::Tag(tag) | Token::Str(tag)) = tokens.get(pos) {
+                tags = Self::parse_tag(tag);
+                pos += 1;
+            }
+            let comment = match tokens.get(pos) {
+                Some(Token::Comment(text)) => {
+                    pos += 1;
+                    Some(text.clone())
+                }
+                _ => None,
+            };
+            for field in &mut line_fields {
+                field.tags = tags.clone();
+                field.comment = comment.clone();
+            }
+            fields.extend(line_fields);
+
+            match tokens.get(pos) {
+                Some(Token::Newline | Token::Punct(';') | Token::Punct('}')) | None => {}
+                Some(other) => return Err(format!("无法解析的字段定义: {:?}", other)),
+            }
+        }
+    }
+
+    /// Parse field names and type, or an embedded type
+    fn parse_field_line(
+        tokens: &[Token],
+        pos: usize,
+    ) -> Result<(Vec<GoStructField>, usize), String> {
+        let ends_field = |token: Option<&Token>| {
+            matches!(
+                token,
+                None | Some(
+                    Token::Newline
+                        | Token::Tag(_)
+                        | Token::Str(_)
+                        | Token::Comment(_)
+                        | Token::Punct(';')
+                        | Token::Punct('}')
+                )
+            )
+        };
+
+        // Embedded: `Base`, `*Base`, `pkg.Base`
+        let mut probe = pos;
+        if tokens.get(probe) == Some(&Token::Punct('*')) {
+            probe += 1;
+        }
+        if let Some(Token::Ident(_)) = tokens.get(probe) {
+            let mut end = probe + 1;
+            if tokens.get(end) == Some(&Token::Punct('.')) {
+                end += 2;
+            }
+            if ends_field(tokens.get(end)) {
+                let (go_type, next) = Self::parse_type(tokens, pos)?;
+                return Ok((
+                    vec![GoStructField {
+                        name: String::new(),
+                        go_type,
+                        embedded: true,
+                        tags: Vec::new(),
+                        comment: None,
+                    }],
+                    next,
+                ));
+            }
+        }
+
+        let mut names = Vec::new();
+        let mut pos = pos;
+        loop {
+            match tokens.get(pos) {
+                Some(Token::Ident(name)) => names.push(name.clone()),
+                other => return Err(format!("字段名无效: {:?}", other)),
+            }
+            pos += 1;
+            if tokens.get(pos) == Some(&Token::Punct(',')) {
+                pos += 1;
+            } else {
+                break;
+            }
+        }
+
+        let (go_type, next) = Self::parse_type(tokens, pos)?;
+        let fields = names
+            .into_iter()
+            .map(|name| GoStructField {
+                name,
+                go_type: go_type.clone(),
+                embedded: false,
+                tags: Vec::new(),
+                comment: None,
+            })
+            .collect();
+        Ok((fields, next))
+    }
+
+    /// Parse a type expression
+    fn parse_type(tokens: &[Token], pos: usize) -> Result<(GoType, usize), String> {
+        match tokens.get(pos) {
+            Some(Token::Punct('*')) => {
+                let (inner, next) = Self::parse_type(tokens, pos + 1)?;
+                Ok((GoType::Pointer(Box::new(inner)), next))
+            }
+            Some(Token::Punct('[')) => {
+                // []T and [N]T are both treated as lists
+                let mut next = pos + 1;
+                while next < tokens.len() && tokens[next] != Token::Punct(']') {
+                    next += 1;
+                }
+                let (inner, next) = Self::parse_type(tokens, next + 1)?;
+                Ok((GoType::Slice(Box::new(inner)), next))
+            }
+            Some(Token::Ident(word)) if word == "map" => {
+                if tokens.get(pos + 1) != Some(&Token::Punct('[')) {
+                    return Err("map 类型缺少 '['".to_string());
+                }
+                let (key, next) = Self::parse_type(tokens, pos + 2)?;
+                if tokens.get(next) != Some(&Token::Punct(']')) {
+                    return Err("map 类型缺少 ']'".to_string());
+                }
+                let (value, next) = Self::parse_type(tokens, next + 1)?;
+                Ok((GoType::Map(Box::new(key), Box::new(value)), next))
+            }
+            Some(Token::Ident(word)) if word == "struct" => {
+                let (fields, next) = Self::parse_struct_body(tokens, pos + 1)?;
+                Ok((GoType::Struct(fields), next))
+            }
+            Some(Token::Ident(word)) if word == "interface" => {
+                let mut next = pos + 1;
+                let mut depth = 0;
+                while let Some(token) = tokens.get(next) {
+                    match token {
+                        Token::Punct('{') => depth += 1,
+                        Token::Punct('}') => {
+                            depth -= 1;
+                            if depth == 0 {
+                                break;
+                            }
+                        }
+                        _ => {}
+                    }
+                    next += 1;
+                }
+                Ok((GoType::Interface, next + 1))
+            }
+            Some(Token::Ident(word)) if word == "any" => Ok((GoType::Interface, pos + 1)),
+            Some(Token::Ident(word)) if word == "chan" || word == "func" => {
+                Ok((GoType::Interface, Self::skip_type_tokens(tokens, pos)))
+            }
+            Some(Token::Ident(word)) => {
+                let mut name = word.clone();
+                let mut next = pos + 1;
+                if tokens.get(next) == Some(&Token::Punct('.')) {
+                    if let Some(Token::Ident(member)) = tokens.get(next + 1) {
+                        name = format!("{}.{}", name, member);
+                        next += 2;
+                    }
+                }
+                // Generic instantiation such as Page[User]
+                if tokens.get(next) == Some(&Token::Punct('[')) {
+                    while next < tokens.len() && tokens[next] != Token::Punct(']') {
+                        next += 1;
+                    }
+                    next += 1;
+                }
+                Ok((GoType::Named(name), next))
+            }
+            other => Err(format!("无法解析的类型: {:?}", other)),
+        }
+    }
+
+    fn skip_type_tokens(tokens: &[Token], mut pos: usize) -> usize {
+        while let Some(token) = tokens.get(pos) {
+            if matches!(
+                token,
+                Token::Newline | Token::Tag(_) | Token::Comment(_) | Token::Punct(';')
+            ) {
+                break;
+            }
+            pos += 1;
+        }
+        pos
+    }
+
+    /// Parse `func (u User) TableName() string { return "users" }`
+    fn parse_table_name_method(tokens: &[Token], pos: usize) -> Option<(String, String)> {
+        if tokens.get(pos + 1) != Some(&Token::Punct('(')) {
+            return None;
+        }
+        let mut next = pos + 2;
+        let mut receiver = None;
+        while let Some(token) = tokens.get(next) {
+            match token {
+                Token::Ident(name) => receiver = Some(name.clone()),
+                Token::Punct(')') => break,
+                _ => {}
+            }
+            next += 1;
+        }
+        if tokens.get(next + 1) != Some(&Token::Ident("TableName".to_string())) {
+            return None;
+        }
+        let body = &tokens[next..tokens.len().min(next + 16)];
+        let table = body.iter().find_map(|token| match token {
+            Token::Str(value) => Some(value.clone()),
+            _ => None,
+        })?;
+        Some((receiver?, table))
+    }
+
+    /// Parse a struct tag like `json:"name,omitempty" gorm:"column:name"`
+    pub fn parse_tag(tag: &str) -> Vec<(String, String)> {
+        let mut result = Vec::new();
+        let mut rest = tag.trim();
+        while let Some(colon) = rest.find(":\"") {
+            let key = rest[..colon].trim().to_string();
+            let after = &rest[colon + 2..];
+            let mut end = None;
+            let mut escaped = false;
+            for (i, c) in after.char_indices() {
+                match c {
+                    '\\' if !escaped => escaped = true,
+                    '"' if !escaped => {
+                        end = Some(i);
+                        break;
+                    }
+                    _ => escaped = false,
+                }
+            }
+            let Some(end) = end else {
+                break;
+            };
+            result.push((key, after[..end].replace("\\\"", "\"")));
+            rest = after[end + 1..].trim_start();
+        }
+        result
+    }
+}
+
+impl GoStructField {
+    fn tag(&self, key: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Parse the gorm tag into lowercase keys and their values
+    fn gorm_settings(&self) -> Vec<(String, String)> {
+        self.tag("gorm")
+            .map(|tag| {
+                tag.split(';')
+                    .filter(|part| !part.trim().is_empty())
+                    .map(|part| match part.split_once(':') {
+                        Some((key, value)) => (key.trim().to_lowercase(), value.trim().to_string()),
+                        None => (part.trim().to_lowercase(), String::new()),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Fields contributed by gorm.Model
+fn gorm_model_fields() -> Vec<GoStructField> {
+    let field = |name: &str, go_type: GoType, gorm: &str| GoStructField {
+        name: name.to_string(),
+        go_type,
+        embedded: false,
+        tags: vec![("gorm".to_string(), gorm.to_string())],
+        comment: None,
+    };
+    let time = || GoType::Named("time.Time".to_string());
+    vec![
+        field("ID", GoType::Named("uint".to_string()), "primaryKey"),
+        field("CreatedAt", time(), ""),
+        field("UpdatedAt", time(), ""),
+        field(
+            "DeletedAt",
+            GoType::Named("gorm.DeletedAt".to_string()),
+            "index",
+        ),
+    ]
+}
+
+/// JSON example generator
+pub struct GoJsonGenerator;
+
+impl GoJsonGenerator {
+    pub fn generate(
+        structs: &[GoStructDefinition],
+        options: &GoToJsonOptions,
+    ) -> Result<Value, String> {
+        let lookup: HashMap<&str, &GoStructDefinition> =
+            structs.iter().map(|s| (s.name.as_str(), s)).collect();
+
+        if let Some(root) = options.root_struct.as_deref().filter(|r| !r.is_empty()) {
+            let def = lookup
+                .get(root)
+                .ok_or_else(|| format!("未找到结构体: {}", root))?;
+            return Ok(Self::struct_example(
+                &def.fields,
+                &lookup,
+                options,
+                &mut vec![def.name.clone()],
+            ));
+        }
+
+        let roots: Vec<&GoStructDefinition> = structs
+            .iter()
+            .filter(|candidate| {
+                !structs.iter().any(|s| {
+                    s.name != candidate.name && Self::references(&s.fields, &candidate.name)
+                })
+            })
+            .collect();
+        let roots = if roots.is_empty() {
+            vec![&structs[0]]
+        } else {
+            roots
+        };
+
+        if roots.len() == 1 {
+            let def = roots[0];
+            Ok(Self::struct_example(
+                &def.fields,
+                &lookup,
+                options,
+                &mut vec![def.name.clone()],
+            ))
+        } else {
+            let mut map = Map::new();
+            for def in roots {
+                let value = Self::struct_example(
+                    &def.fields,
+                    &lookup,
+                    options,
+                    &mut vec![def.name.clone()],
+                );
+                map.insert(def.name.clone(), value);
+            }
+            Ok(Value::Object(map))
+        }
+    }
+
+    fn references(fields: &[GoStructField], name: &str) -> bool {
+        fn type_refs(go_type: &GoType, name: &str) -> bool {
+            match go_type {
+                GoType::Named(n) => n == name,
+                GoType::Pointer(inner) | GoType::Slice(inner) => type_refs(inner, name),
+                GoType::Map(k, v) => type_refs(k, name) || type_refs(v, name),
+                GoType::Struct(fields) => fields.iter().any(|f| type_refs(&f.go_type, name)),
+                GoType::Interface => false,
+            }
+        }
+        fields.iter().any(|f| type_refs(&f.go_type, name))
+    }
+
+    fn struct_example(
+        fields: &[GoStructField],
+        lookup: &HashMap<&str, &GoStructDefinition>,
+        options: &GoToJsonOptions,
+        stack: &mut Vec<String>,
+    ) -> Value {
+        let mut map = Map::new();
+
+        for field in fields {
+            let json_tag = field.tag("json").unwrap_or("");
+            let mut parts = json_tag.split(',');
+            let tag_name = parts.next().unwrap_or("");
+            let as_string = parts.any(|opt| opt == "string");
+            if tag_name == "-" && !json_tag.starts_with("-,") {
+                continue;
+            }
+
+            // Embedded structs without a JSON name are flattened
+            if field.embedded && tag_name.is_empty() {
+                let embedded_name = Self::base_name(&field.go_type);
+                if embedded_name == "gorm.Model" {
+                    if let Value::Object(inner) =
+                        Self::struct_example(&gorm_model_fields(), lookup, options, stack)
+                    {
+                        map.extend(inner);
+                    }
+                    continue;
+                }
+                if let Some(def) = lookup.get(embedded_name.as_str()) {
+                    if !stack.contains(&def.name) {
+                        stack.push(def.name.clone());
+                        if let Value::Object(inner) =
+                            Self::struct_example(&def.fields, lookup, options, stack)
+                        {
+                            map.extend(inner);
+                        }
+                        stack.pop();
+                    }
+                    continue;
+                }
+            }
+
+            let key = if !tag_name.is_empty() {
+                tag_name.to_string()
+            } else if field.embedded {
+                Self::base_name(&field.go_type)
+                    .rsplit('.')
+                    .next()
+                    .unwrap_or_default()
+                    .to_string()
+            } else {
+                field.name.clone()
+            };
+
+            let mut value = Self::type_example(&field.go_type, &key, lookup, options, stack);
+            if as_string && !value.is_string() && !value.is_null() {
+                value = Value::String(value.to_string());
+            }
+            map.insert(key, value);
+        }
+
+        Value::Object(map)
+    }
+
+    fn base_name(go_type: &GoType) -> String {
+        match go_type {
+            GoType::Named(name) => name.clone(),
+            GoType::Pointer(inner) => Self::base_name(inner),
+            _ => String::new(),
+        }
+    }
+
+    fn type_example(
+        go_type: &GoType,
+        key: &str,
+        lookup: &HashMap<&str, &GoStructDefinition>,
+        options: &GoToJsonOptions,
+        stack: &mut Vec<String>,
+    ) -> Value {
+        match go_type {
+            GoType::Pointer(_) if options.null_pointers => Value::Null,
+            GoType::Pointer(inner) => Self::type_example(inner, key, lookup, options, stack),
+            GoType::Slice(inner) if **inner == GoType::Named("byte".to_string()) => {
+                json!("")
+            }
+            GoType::Slice(inner) => {
+                json!([Self::type_example(inner, key, lookup, options, stack)])
+            }
+            GoType::Map(_, value) => {
+                json!({ "key": Self::type_example(value, key, lookup, options, stack) })
+            }
+            GoType::Struct(fields) => Self::struct_example(fields, lookup, options, stack),
+            GoType::Interface => Value::Null,
+            GoType::Named(name) => {
+                if let Some(def) = lookup.get(name.as_str()) {
+                    if stack.contains(&def.name) {
+                        return Value::Null;
+                    }
+                    stack.push(def.name.clone());
+                    let value = Self::struct_example(&def.fields, lookup, options, stack);
+                    stack.pop();
+                    return value;
+                }
+                Self::named_example(name, key)
+            }
+        }
+    }
+
+    /// Example value for builtin and common library types
+    fn named_example(name: &str, key: &str) -> Value {
+        match name {
+            "string" => Self::string_example(key),
+            "bool" => json!(false),
+            "int" | "int8" | "int16" | "int32" | "int64" | "uint" | "uint8" | "uint16"
+            | "uint32" | "uint64" | "uintptr" | "byte" | "rune" => json!(0),
+            "float32" | "float64" | "decimal.Decimal" => json!(0.0),
+            "time.Time" | "gorm.DeletedAt" | "sql.NullTime" => json!("2024-01-01T00:00:00Z"),
+            "time.Duration" => json!(0),
+            "uuid.UUID" => json!("00000000-0000-0000-0000-000000000000"),
+            "sql.NullString" => Self::string_example(key),
+            "sql.NullInt64" | "sql.NullInt32" | "sql.NullInt16" | "sql.NullByte" => json!(0),
+            "sql.NullFloat64" => json!(0.0),
+            "sql.NullBool" => json!(false),
+            "json.RawMessage" | "datatypes.JSON" => json!({}),
+            _ => Value::Null,
+        }
+    }
+
+    fn string_example(key: &str) -> Value {
+        let lower = key.to_lowercase();
+        let example = if lower.contains("email") {
+            "user@example.com"
+        } else if lower.contains("url") || lower.contains("link") || lower.contains("website") {
+            "https://example.com"
+        } else if lower.contains("phone") || lower.contains("mobile") {
+            "13800000000"
+        } else if lower.contains("uuid") {
+            "00000000-0000-0000-0000-000000000000"
+        } else if lower.ends_with("_at") || lower.ends_with("time") || lower.contains("date") {
+            "2024-01-01T00:00:00Z"
+        } else {
+            "string"
+        };
+        json!(example)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ColumnSpec {
+    name: String,
+    sql_type: String,
+    nullable: bool,
+    primary_key: bool,
+    auto_increment: bool,
+    auto_increment_disabled: bool,
+    unique: bool,
+    default: Option<String>,
+    comment: Option<String>,
+}
+
+/// CREATE TABLE generator
+pub struct GoSqlGenerator;
+
+impl GoSqlGenerator {
+    pub fn generate(
+        structs: &[GoStructDefinition],
+        options: &GoToSqlOptions,
+    ) -> Result<GoToSqlOutput, String> {
+        let postgres = match options.dialect.to_lowercase().as_str() {
+            "mysql" => false,
+            "postgres" | "postgresql" => true,
+            other => return Err(format!("不支持的数据库方言: {}", other)),
+        };
+        let lookup: HashMap<&str, &GoStructDefinition> =
+            structs.iter().map(|s| (s.name.as_str(), s)).collect();
+
+        let mut statements = Vec::new();
+        let mut table_names = Vec::new();
+        let mut warnings = Vec::new();
+
+        for def in structs {
+            let table = def.table_name.clone().unwrap_or_else(|| {
+                let snake = StringUtils::to_snake_case(&def.name);
+                if options.pluralize_table {
+                    Self::pluralize(&snake)
+                } else {
+                    snake
+                }
+            });
+
+            let mut columns = Vec::new();
+            let mut indexes: Vec<(String, bool, Vec<String>)> = Vec::new();
+            Self::collect_columns(
+                &def.fields,
+                "",
+                &lookup,
+                postgres,
+                &mut columns,
+                &mut indexes,
+                &mut warnings,
+                &def.name,
+            );
+            if columns.is_empty() {
+                warnings.push(format!("{} 没有可映射的列，已跳过", def.name));
+                continue;
+            }
+
+            // Follow gorm's convention: a field named ID is the primary key
+            if !columns.iter().any(|c| c.primary_key) {
+                if let Some(id) = columns.iter_mut().find(|c| c.name == "id") {
+                    id.primary_key = true;
+                    id.nullable = false;
+                }
+            }
+            // A single integer primary key auto-increments unless disabled
+            if columns.iter().filter(|c| c.primary_key).count() == 1 {
+                if let Some(pk) = columns.iter_mut().find(|c| c.primary_key) {
+                    if !pk.auto_increment_disabled && Self::is_integer_type(&pk.sql_type) {
+                        pk.auto_increment = true;
+                    }
+                }
+            }
+
+            statements.push(Self::render_table(
+                &table, &columns, &indexes, postgres, options,
+            ));
+            table_names.push(table);
+        }
+
+        Ok(GoToSqlOutput {
+            sql: statements.join("\n\n"),
+            table_names,
+            warnings,
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn collect_columns(
+        fields: &[GoStructField],
+        prefix: &str,
+        lookup: &HashMap<&str, &GoStructDefinition>,
+        postgres: bool,
+        columns: &mut Vec<ColumnSpec>,
+        indexes: &mut Vec<(String, bool, Vec<String>)>,
+        warnings: &mut Vec<String>,
+        struct_name: &str,
+    ) {
+        for field in fields {
+            let gorm = field.gorm_settings();
+            let setting = |key: &str| gorm.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str());
+            if setting("-").is_some() || field.tag("db") == Some("-") {
+                continue;
+            }
+
+            let base = GoJsonGenerator::base_name(&field.go_type);
+            if field.embedded || setting("embedded").is_some() {
+                let embedded_prefix =
+                    format!("{}{}", prefix, setting("embeddedprefix").unwrap_or(""));
+                if base == "gorm.Model" {
+                    Self::collect_columns(
+                        &gorm_model_fields(),
+                        &embedded_prefix,
+                        lookup,
+                        postgres,
+                        columns,
+                        indexes,
+                        warnings,
+                        struct_name,
+                    );
+                } else if let Some(def) = lookup.get(base.as_str()) {
+                    Self::collect_columns(
+                        &def.fields,
+                        &embedded_prefix,
+                        lookup,
+                        postgres,
+                        columns,
+                        indexes,
+                        warnings,
+                        struct_name,
+                    );
+                } else {
+                    warnings.push(format!("{}: 未知的嵌入类型 {}，已跳过", struct_name, base));
+                }
+                continue;
+            }
+
+            let column_name = setting("column")
+                .map(str::to_string)
+                .or_else(|| {
+                    field
+                        .tag("db")
+                        .map(|db| db.split(',').next().unwrap_or(db).to_string())
+                })
+                .unwrap_or_else(|| StringUtils::to_snake_case(&field.name));
+            let column_name = format!("{}{}", prefix, column_name);
+
+            let serializer = setting("serializer").is_some();
+            let sql_type = match setting("type") {
+                Some(explicit) => explicit.to_uppercase(),
+                None => match Self::map_type(
+                    &field.go_type,
+                    setting("size"),
+                    lookup,
+                    postgres,
+                    serializer,
+                ) {
+                    Some(sql_type) => sql_type,
+                    None => {
+                        warnings.push(format!(
+                            "{}.{}: 关联或不支持的类型，已跳过",
+                            struct_name, field.name
+                        ));
+                        continue;
+                    }
+                },
+            };
+
+            let primary_key = setting("primarykey").is_some() || setting("primary_key").is_some();
+            let auto_increment = setting("autoincrement").is_some_and(|v| v != "false");
+            let nullable_type = matches!(field.go_type, GoType::Pointer(_))
+                || base.starts_with("sql.Null")
+                || base == "gorm.DeletedAt";
+            let not_null = setting("not null").is_some() || setting("not_null").is_some();
+
+            let mut column = ColumnSpec {
+                name: column_name.clone(),
+                sql_type,
+                nullable: !primary_key && !not_null && nullable_type,
+                primary_key,
+                auto_increment,
+                auto_increment_disabled: setting("autoincrement") == Some("false"),
+                unique: setting("unique").is_some(),
+                default: setting("default").map(str::to_string),
+                comment: setting("comment")
+                    .map(|c| c.trim_matches('\'').to_string())
+                    .or_else(|| field.comment.clone()),
+            };
+            if column.primary_key
+                && column.auto_increment
+                && !Self::is_integer_type(&column.sql_type)
+            {
+                column.auto_increment = false;
+            }
+
+            for (key, value) in &gorm {
+                let unique = match key.as_str() {
+                    "index" => false,
+                    "uniqueindex" => true,
+                    _ => continue,
+                };
+                let mut parts = value.split(',');
+                let name = parts.next().unwrap_or("").trim();
+                let unique = unique || parts.any(|p| p.trim().eq_ignore_ascii_case("unique"));
+                let index_name = if name.is_empty() {
+                    format!("{}_{}", if unique { "uk" } else { "idx" }, column_name)
+                } else {
+                    name.to_string()
+                };
+                match indexes.iter_mut().find(|(n, _, _)| *n == index_name) {
+                    Some((_, _, cols)) => cols.push(column_name.clone()),
+                    None => indexes.push((index_name, unique, vec![column_name.clone()])),
+                }
+            }
+
+            columns.push(column);
+        }
+    }
+
+    /// Map a Go type to a column type; None for associations
+    fn map_type(
+        go_type: &GoType,
+        size: Option<&str>,
+        lookup: &HashMap<&str, &GoStructDefinition>,
+        postgres: bool,
+        serializer: bool,
+    ) -> Option<String> {
+        let json_type = if postgres { "JSONB" } else { "JSON" };
+        match go_type {
+            GoType::Pointer(inner) => Self::map_type(inner, size, lookup, postgres, serializer),
+            GoType::Slice(inner) if **inner == GoType::Named("byte".to_string()) => {
+                Some(if postgres { "BYTEA" } else { "BLOB" }.to_string())
+            }
+            GoType::Slice(inner) => {
+                let element = GoJsonGenerator::base_name(inner);
+                if lookup.contains_key(element.as_str()) && !serializer {
+                    None
+                } else {
+                    Some(json_type.to_string())
+                }
+            }
+            GoType::Map(_, _) | GoType::Struct(_) | GoType::Interface => {
+                Some(json_type.to_string())
+            }
+            GoType::Named(name) => {
+                if lookup.contains_key(name.as_str()) {
+                    return serializer.then(|| json_type.to_string());
+                }
+                let varchar = || match size {
+                    Some(size) => format!("VARCHAR({})", size),
+                    None if postgres => "TEXT".to_string(),
+                    None => "VARCHAR(255)".to_string(),
+                };
+                let sql_type = match (name.as_str(), postgres) {
+                    ("string" | "sql.NullString", _) => varchar(),
+                    ("bool" | "sql.NullBool", false) => "TINYINT(1)".to_string(),
+                    ("bool" | "sql.NullBool", true) => "BOOLEAN".to_string(),
+                    ("int8", false) => "TINYINT".to_string(),
+                    ("uint8" | "byte", false) => "TINYINT UNSIGNED".to_string(),
+                    ("int16" | "sql.NullInt16", false) => "SMALLINT".to_string(),
+                    ("uint16", false) => "SMALLINT UNSIGNED".to_string(),
+                    ("int32" | "rune" | "sql.NullInt32", false) => "INT".to_string(),
+                    ("uint32", false) => "INT UNSIGNED".to_string(),
+                    ("int" | "int64" | "sql.NullInt64" | "time.Duration", false) => {
+                        "BIGINT".to_string()
+                    }
+                    ("uint" | "uint64", false) => "BIGINT UNSIGNED".to_string(),
+                    ("int8" | "uint8" | "byte" | "int16" | "sql.NullInt16", true) => {
+                        "SMALLINT".to_string()
+                    }
+                    ("uint16" | "int32" | "rune" | "sql.NullInt32", true) => "INTEGER".to_string(),
+                    (
+                        "uint32" | "int" | "int64" | "uint" | "sql.NullInt64" | "time.Duration",
+                        true,
+                    ) => "BIGINT".to_string(),
+                    ("uint64", true) => "NUMERIC(20)".to_string(),
+                    ("float32", false) => "FLOAT".to_string(),
+                    ("float32", true) => "REAL".to_string(),
+                    ("float64" | "sql.NullFloat64", false) => "DOUBLE".to_string(),
+                    ("float64" | "sql.NullFloat64", true) => "DOUBLE PRECISION".to_string(),
+                    ("decimal.Decimal", _) => "DECIMAL(20,8)".to_string(),
+                    ("time.Time" | "sql.NullTime" | "gorm.DeletedAt", false) => {
+                        "DATETIME(3)".to_string()
+                    }
+                    ("time.Time" | "sql.NullTime" | "gorm.DeletedAt", true) => {
+                        "TIMESTAMPTZ".to_string()
+                    }
+                    ("uuid.UUID", false) => "CHAR(36)".to_string(),
+                    ("uuid.UUID", true) => "UUID".to_string(),
+                    ("json.RawMessage" | "datatypes.JSON", _) => json_type.to_string(),
+                    // Unknown named types are usually string-based enums
+                    _ => varchar(),
+                };
+                Some(sql_type)
+            }
+        }
+    }
+
+    fn is_integer_type(sql_type: &str) -> bool {
+        let upper = sql_type.to_uppercase();
+        ["INT", "SERIAL"].iter().any(|t| upper.contains(t))
+    }
+
+    fn pluralize(word: &str) -> String {
+        if word.ends_with('y')
+            && !["ay", "ey", "oy", "uy"]
+                .iter()
+                .any(|suffix| word.ends_with(suffix))
+        {
+            format!("{}ies", &word[..word.len() - 1])
+        } else if ["s", "x", "z", "ch", "sh"]
+            .iter()
+            .any(|suffix| word.ends_with(suffix))
+        {
+            format!("{}es", word)
+        } else {
+            format!("{}s", word)
+        }
+    }
+
+    fn quote(identifier: &str, postgres: bool) -> String {
+        if postgres {
+            format!("\"{}\"", identifier)
+        } else {
+            format!("`{}`", identifier)
+        }
+    }
+
+    fn render_table(
+        table: &str,
+        columns: &[ColumnSpec],
+        indexes: &[(String, bool, Vec<String>)],
+        postgres: bool,
+        options: &GoToSqlOptions,
+    ) -> String {
+        let mut lines = Vec::new();
+        let primary_keys: Vec<&ColumnSpec> = columns.iter().filter(|c| c.primary_key).collect();
+
+        for column in columns {
+            let mut sql_type = column.sql_type.clone();
+            if postgres && column.auto_increment {
+                sql_type = if sql_type.contains("BIGINT") {
+                    "BIGSERIAL".to_string()
+                } else {
+                    "SERIAL".to_string()
+                };
+            }
+            let mut line = format!("  {} {}", Self::quote(&column.name, postgres), sql_type);
+            if !column.nullable || column.primary_key {
+                line.push_str(" NOT NULL");
+            }
+            if column.auto_increment && !postgres {
+                line.push_str(" AUTO_INCREMENT");
+            }
+            if column.unique {
+                line.push_str(" UNIQUE");
+            }
+            if let Some(default) = &column.default {
+                line.push_str(&format!(" DEFAULT {}", default));
+            }
+            if let (Some(comment), false) = (&column.comment, postgres) {
+                line.push_str(&format!(" COMMENT '{}'", comment.replace('\'', "''")));
+            }
+            lines.push(line);
+        }
+        if !primary_keys.is_empty() {
+            let keys: Vec<String> = primary_keys
+                .iter()
+                .map(|c| Self::quote(&c.name, postgres))
+                .collect();
+            lines.push(format!("  PRIMARY KEY ({})", keys.join(", ")));
+        }
+
+        let mut sql = format!(
+            "CREATE TABLE {}{} (\n{}\n)",
+            if options.if_not_exists {
+                "IF NOT EXISTS "
+            } else {
+                ""
+            },
+            Self::quote(table, postgres),
+            lines.join(",\n")
+        );
+        if !postgres {
+            sql.push_str(" ENGINE=InnoDB DEFAULT CHARSET=utf8mb4");
+        }
+        sql.push(';');
+
+        for (name, unique, index_columns) in indexes {
+            let cols: Vec<String> = index_columns
+                .iter()
+                .map(|c| Self::quote(c, postgres))
+                .collect();
+            sql.push_str(&format!(
+                "\nCREATE {}INDEX {} ON {} ({});",
+                if *unique { "UNIQUE " } else { "" },
+                Self::quote(name, postgres),
+                Self::quote(table, postgres),
+                cols.join(", ")
+            ));
+        }
+
+        if postgres {
+            for column in columns {
+                if let Some(comment) = &column.comment {
+                    sql.push_str(&format!(
+                        "\nCOMMENT ON COLUMN {}.{} IS '{}';",
+                        Self::quote(table, postgres),
+                        Self::quote(&column.name, postgres),
+                        comment.replace('\'', "''")
+                    ));
+                }
+            }
+        }
+
+        sql
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+package model
+
+import "time"
+
+// User 用户
+type User struct {
+	gorm.Model
+	Name     string    `json:"name" gorm:"column:name;size:64;not null;index:idx_name_email"`
+	Email    *string   `json:"email,omitempty" gorm:"uniqueIndex"`
+	Age, Score int     `json:"-"`
+	Tags     []string  `json:"tags"`
+	Profile  Profile   `json:"profile"`
+	Orders   []Order   `json:"orders"`
+	Balance  int64     `json:"balance,string"` // 余额（分）
+}
+
+type Profile struct {
+	Bio      string `json:"bio"`
+	Website  string `json:"website"`
+}
+
+type Order struct {
+	ID        uint64    `json:"id"`
+	UserID    uint      `json:"user_id"`
+	CreatedAt time.Time `json:"created_at"`
+}
+
+func (Order) TableName() string {
+	return "t_order"
+}
+"#;
+
+    #[test]
+    fn test_parse_structs() {
+        let structs = GoStructParser::parse(SAMPLE).unwrap();
+        assert_eq!(structs.len(), 3);
+        let user = &structs[0];
+        assert!(user.fields[0].embedded);
+        assert_eq!(
+            user.fields
+                .iter()
+                .filter(|f| f.name == "Age" || f.name == "Score")
+                .count(),
+            2
+        );
+        assert_eq!(
+            user.fields.last().unwrap().comment.as_deref(),
+            Some("余额（分）")
+        );
+        assert_eq!(structs[2].table_name.as_deref(), Some("t_order"));
+    }
+
+    #[test]
+    fn test_convert_go_to_json() {
+        let json = convert_go_to_json(SAMPLE, None).unwrap();
+        let value: Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value["name"], "string");
+        assert_eq!(value["balance"], "0");
+        assert_eq!(value["profile"]["website"], "https://example.com");
+        assert_eq!(value["orders"][0]["created_at"], "2024-01-01T00:00:00Z");
+        assert!(value.get("ID").is_some());
+        assert!(value.get("Age").is_none());
+    }
+
+    #[test]
+    fn test_convert_go_to_sql() {
+        let output = convert_go_to_sql(SAMPLE, None).unwrap();
+        assert_eq!(output.table_names, vec!["users", "profiles", "t_order"]);
+        assert!(output
+            .sql
+            .contains("`id` BIGINT UNSIGNED NOT NULL AUTO_INCREMENT"));
+        assert!(output.sql.contains("`name` VARCHAR(64) NOT NULL"));
+        assert!(output.sql.contains("`email` VARCHAR(255),"));
+        assert!(output
+            .sql
+            .contains("`balance` BIGINT NOT NULL COMMENT '余额（分）'"));
+        assert!(output
+            .sql
+            .contains("CREATE UNIQUE INDEX `uk_email` ON `users` (`email`);"));
+        assert!(output.warnings.iter().any(|w| w.contains("User.Orders")));
+
+        let postgres = convert_go_to_sql(
+            SAMPLE,
+            Some(GoToSqlOptions {
+                dialect: "postgres".to_string(),
+                ..GoToSqlOptions::default()
+            }),
+        )
+        .unwrap();
+        assert!(postgres.sql.contains("\"id\" BIGSERIAL NOT NULL"));
+        assert!(postgres.sql.contains("\"created_at\" TIMESTAMPTZ NOT NULL"));
+    }
+}
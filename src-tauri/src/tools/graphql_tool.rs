@@ -0,0 +1,1869 @@
+use crate::tools::http_collection::KeyValue;
+use crate::utils::proxy;
+use graphql_parser::query::{self, Definition, OperationDefinition, Selection, SelectionSet};
+use graphql_parser::schema::{self, Type, TypeDefinition, TypeExtension};
+use graphql_parser::{Pos, Style};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+/// 内置标量，生成代码和输出 SDL 时不重复定义
+const BUILTIN_SCALARS: &[&str] = &["ID", "String", "Int", "Float", "Boolean"];
+/// 内置指令，输出 SDL 时不重复定义
+const BUILTIN_DIRECTIVES: &[&str] = &["include", "skip", "deprecated", "specifiedBy", "oneOf"];
+/// Go 字段名中需要全大写的缩写
+const GO_INITIALISMS: &[&str] = &[
+    "ACL", "API", "CPU", "CSS", "DNS", "HTML", "HTTP", "HTTPS", "ID", "IP", "JSON", "SQL", "SSH",
+    "TLS", "TTL", "UI", "URI", "URL", "UUID", "XML",
+];
+
+/// 标准内省查询，与 graphql-js 的 getIntrospectionQuery 一致
+const INTROSPECTION_QUERY: &str = r#"query IntrospectionQuery {
+  __schema {
+    queryType { name }
+    mutationType { name }
+    subscriptionType { name }
+    types { ...FullType }
+    directives {
+      name
+      description
+      locations
+      isRepeatable
+      args { ...InputValue }
+    }
+  }
+}
+
+fragment FullType on __Type {
+  kind
+  name
+  description
+  fields(includeDeprecated: true) {
+    name
+    description
+    args { ...InputValue }
+    type { ...TypeRef }
+    isDeprecated
+    deprecationReason
+  }
+  inputFields { ...InputValue }
+  interfaces { ...TypeRef }
+  enumValues(includeDeprecated: true) {
+    name
+    description
+    isDeprecated
+    deprecationReason
+  }
+  possibleTypes { ...TypeRef }
+}
+
+fragment InputValue on __InputValue {
+  name
+  description
+  type { ...TypeRef }
+  defaultValue
+}
+
+fragment TypeRef on __Type {
+  kind
+  name
+  ofType {
+    kind
+    name
+    ofType {
+      kind
+      name
+      ofType {
+        kind
+        name
+        ofType {
+          kind
+          name
+          ofType {
+            kind
+            name
+            ofType {
+              kind
+              name
+              ofType { kind name }
+            }
+          }
+        }
+      }
+    }
+  }
+}"#;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GraphqlCodegenRequest {
+    pub sdl: String,
+    pub language: String,             // go, typescript
+    pub package_name: Option<String>, // Go 包名，默认 model
+    #[serde(default)]
+    pub scalar_types: HashMap<String, String>, // 自定义标量映射，如 DateTime -> time.Time
+    pub nullable_pointers: Option<bool>, // Go 可空标量和枚举使用指针，默认 true
+    pub include_root_types: Option<bool>, // 是否为 Query/Mutation/Subscription 生成类型，默认 false
+    pub include_descriptions: Option<bool>, // 是否输出描述注释，默认 true
+    pub enum_style: Option<String>,   // TypeScript 枚举风格：union（默认）/ enum
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphqlCodegenResponse {
+    pub code: String,
+    pub language: String,
+    pub type_count: usize,
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GraphqlQueryRequest {
+    pub query: String,
+    pub schema: Option<String>, // 提供 SDL 时按 schema 校验字段、参数和类型
+    pub indent: Option<u32>,    // 缩进空格数，默认 2
+    pub minify: Option<bool>,   // 压缩为单行
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GraphqlQueryError {
+    pub message: String,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphqlOperationInfo {
+    pub kind: String, // query, mutation, subscription
+    pub name: Option<String>,
+    pub variables: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphqlQueryResponse {
+    pub formatted: String,
+    pub valid: bool,
+    pub errors: Vec<GraphqlQueryError>,
+    pub operations: Vec<GraphqlOperationInfo>,
+    pub fragments: Vec<String>,
+    pub schema_checked: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphqlIntrospectRequest {
+    pub url: String,
+    #[serde(default)]
+    pub headers: Vec<KeyValue>,
+    pub timeout_secs: Option<u64>,
+    pub ignore_tls_errors: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphqlIntrospectResponse {
+    pub sdl: String,
+    pub type_count: usize,
+    pub query_type: Option<String>,
+    pub mutation_type: Option<String>,
+    pub subscription_type: Option<String>,
+    pub elapsed_ms: u64,
+}
+
+/// 根据 GraphQL SDL 生成 Go 或 TypeScript 类型
+#[tauri::command]
+pub async fn graphql_sdl_to_code(
+    request: GraphqlCodegenRequest,
+) -> Result<GraphqlCodegenResponse, String> {
+    generate_code(&request)
+}
+
+/// 格式化并校验 GraphQL 查询，提供 schema 时同时校验字段和参数
+#[tauri::command]
+pub async fn graphql_format_query(
+    request: GraphqlQueryRequest,
+) -> Result<GraphqlQueryResponse, String> {
+    format_query(&request)
+}
+
+/// 对 GraphQL 端点执行内省查询并转换为 SDL
+#[tauri::command]
+pub async fn graphql_introspect(
+    request: GraphqlIntrospectRequest,
+) -> Result<GraphqlIntrospectResponse, String> {
+    let url = request.url.trim();
+    reqwest::Url::parse(url).map_err(|e| format!("无效的 URL: {}", e))?;
+    let client = proxy::client_builder("graphql_tool")
+        .timeout(Duration::from_secs(request.timeout_secs.unwrap_or(30)))
+        .danger_accept_invalid_certs(request.ignore_tls_errors.unwrap_or(false))
+        .build()
+        .map_err(|e| format!("创建 HTTP 客户端失败: {}", e))?;
+
+    let mut builder =
+        client
+            .post(url)
+            .header("Accept", "application/json")
+            .json(&serde_json::json!({
+                "operationName": "IntrospectionQuery",
+                "query": INTROSPECTION_QUERY,
+            }));
+    for header in request.headers.iter().filter(|h| h.enabled) {
+        builder = builder.header(header.key.as_str(), header.value.as_str());
+    }
+
+    let start = Instant::now();
+    let response = builder
+        .send()
+        .await
+        .map_err(|e| format!("请求失败: {}", e))?;
+    let status = response.status();
+    let body = response
+        .text()
+        .await
+        .map_err(|e| format!("读取响应失败: {}", e))?;
+    let elapsed_ms = start.elapsed().as_millis() as u64;
+    let json: Value = serde_json::from_str(&body).map_err(|_| {
+        format!(
+            "HTTP {}，响应不是 JSON: {}",
+            status,
+            body.chars().take(200).collect::<String>()
+        )
+    })?;
+    if let Some(errors) = json.get("errors").and_then(|e| e.as_array()) {
+        if json.pointer("/data/__schema").is_none() {
+            let messages: Vec<&str> = errors
+                .iter()
+                .filter_map(|e| e.get("message").and_then(|m| m.as_str()))
+                .collect();
+            return Err(format!("内省查询失败: {}", messages.join("; ")));
+        }
+    }
+    if !status.is_success() {
+        return Err(format!("HTTP {}", status));
+    }
+
+    let schema = json
+        .pointer("/data/__schema")
+        .ok_or("响应中没有 data.__schema，端点可能禁用了内省")?;
+    let sdl = introspection_to_sdl(schema)?;
+    let root_name = |key: &str| {
+        schema
+            .pointer(&format!("/{}/name", key))
+            .and_then(|n| n.as_str())
+            .map(str::to_string)
+    };
+    Ok(GraphqlIntrospectResponse {
+        type_count: schema
+            .get("types")
+            .and_then(|t| t.as_array())
+            .map(|types| types.iter().filter(|t| is_user_type(t)).count())
+            .unwrap_or(0),
+        query_type: root_name("queryType"),
+        mutation_type: root_name("mutationType"),
+        subscription_type: root_name("subscriptionType"),
+        sdl,
+        elapsed_ms,
+    })
+}
+
+fn generate_code(request: &GraphqlCodegenRequest) -> Result<GraphqlCodegenResponse, String> {
+    if request.sdl.trim().is_empty() {
+        return Err("Schema 不能为空".to_string());
+    }
+    let document = schema::parse_schema::<String>(&request.sdl)
+        .map_err(|e| format!("Schema 解析失败: {}", e))?;
+    let model = SchemaModel::from_document(&document);
+    let language = match request.language.trim().to_lowercase().as_str() {
+        "" | "go" | "golang" => "go",
+        "ts" | "typescript" => "typescript",
+        _ => return Err(format!("不支持的语言: {}", request.language)),
+    };
+
+    let mut generator = CodeGenerator {
+        request,
+        model: &model,
+        warnings: Vec::new(),
+        warned_scalars: HashSet::new(),
+    };
+    let types: Vec<&TypeDefinition<String>> = model
+        .definitions
+        .iter()
+        .filter(|t| request.include_root_types.unwrap_or(false) || !model.is_root(type_name(t)))
+        .collect();
+    let code = if language == "go" {
+        generator.generate_go(&types)
+    } else {
+        generator.generate_typescript(&types)
+    };
+    Ok(GraphqlCodegenResponse {
+        code,
+        language: language.to_string(),
+        type_count: types
+            .iter()
+            .filter(|t| !matches!(t, TypeDefinition::Scalar(_)))
+            .count(),
+        warnings: generator.warnings,
+    })
+}
+
+fn type_name<'a>(definition: &'a TypeDefinition<String>) -> &'a str {
+    match definition {
+        TypeDefinition::Scalar(t) => &t.name,
+        TypeDefinition::Object(t) => &t.name,
+        TypeDefinition::Interface(t) => &t.name,
+        TypeDefinition::Union(t) => &t.name,
+        TypeDefinition::Enum(t) => &t.name,
+        TypeDefinition::InputObject(t) => &t.name,
+    }
+}
+
+/// 合并扩展后的 schema 定义，按出现顺序保存
+struct SchemaModel<'d> {
+    definitions: Vec<TypeDefinition<'d, String>>,
+    index: HashMap<String, usize>,
+    query_type: String,
+    mutation_type: String,
+    subscription_type: String,
+}
+
+impl<'d> SchemaModel<'d> {
+    fn from_document(document: &schema::Document<'d, String>) -> Self {
+        let mut model = SchemaModel {
+            definitions: Vec::new(),
+            index: HashMap::new(),
+            query_type: "Query".to_string(),
+            mutation_type: "Mutation".to_string(),
+            subscription_type: "Subscription".to_string(),
+        };
+        let mut extensions = Vec::new();
+        for definition in &document.definitions {
+            match definition {
+                schema::Definition::SchemaDefinition(root) => {
+                    if let Some(name) = &root.query {
+                        model.query_type = name.clone();
+                    }
+                    if let Some(name) = &root.mutation {
+                        model.mutation_type = name.clone();
+                    }
+                    if let Some(name) = &root.subscription {
+                        model.subscription_type = name.clone();
+                    }
+                }
+                schema::Definition::TypeDefinition(definition) => {
+                    let name = type_name(definition).to_string();
+                    if !model.index.contains_key(&name) {
+                        model.index.insert(name, model.definitions.len());
+                        model.definitions.push(definition.clone());
+                    }
+                }
+                schema::Definition::TypeExtension(extension) => extensions.push(extension),
+                schema::Definition::DirectiveDefinition(_) => {}
+            }
+        }
+        for extension in extensions {
+            model.apply_extension(extension);
+        }
+        model
+    }
+
+    fn apply_extension(&mut self, extension: &TypeExtension<'d, String>) {
+        let name = match extension {
+            TypeExtension::Scalar(e) => &e.name,
+            TypeExtension::Object(e) => &e.name,
+            TypeExtension::Interface(e) => &e.name,
+            TypeExtension::Union(e) => &e.name,
+            TypeExtension::Enum(e) => &e.name,
+            TypeExtension::InputObject(e) => &e.name,
+        };
+        let Some(&index) = self.index.get(name) else {
+            return;
+        };
+        match (&mut self.definitions[index], extension) {
+            (TypeDefinition::Object(t), TypeExtension::Object(e)) => {
+                t.implements_interfaces
+                    .extend(e.implements_interfaces.iter().cloned());
+                t.fields.extend(e.fields.iter().cloned());
+            }
+            (TypeDefinition::Interface(t), TypeExtension::Interface(e)) => {
+                t.implements_interfaces
+                    .extend(e.implements_interfaces.iter().cloned());
+                t.fields.extend(e.fields.iter().cloned());
+            }
+            (TypeDefinition::Union(t), TypeExtension::Union(e)) => {
+                t.types.extend(e.types.iter().cloned())
+            }
+            (TypeDefinition::Enum(t), TypeExtension::Enum(e)) => {
+                t.values.extend(e.values.iter().cloned())
+            }
+            (TypeDefinition::InputObject(t), TypeExtension::InputObject(e)) => {
+                t.fields.extend(e.fields.iter().cloned())
+            }
+            _ => {}
+        }
+    }
+
+    fn get(&self, name: &str) -> Option<&TypeDefinition<'d, String>> {
+        self.index.get(name).map(|&i| &self.definitions[i])
+    }
+
+    fn is_root(&self, name: &str) -> bool {
+        name == self.query_type || name == self.mutation_type || name == self.subscription_type
+    }
+
+    /// 联合类型成员和接口实现者都需要实现对应的标记方法
+    fn abstract_types_of(&self, object: &str) -> Vec<String> {
+        let mut result = Vec::new();
+        for definition in &self.definitions {
+            match definition {
+                TypeDefinition::Object(t) if t.name == object => {
+                    result.extend(t.implements_interfaces.iter().cloned())
+                }
+                TypeDefinition::Union(u) if u.types.iter().any(|m| m == object) => {
+                    result.push(u.name.clone())
+                }
+                _ => {}
+            }
+        }
+        result
+    }
+}
+
+struct CodeGenerator<'r, 'm, 'd> {
+    request: &'r GraphqlCodegenRequest,
+    model: &'m SchemaModel<'d>,
+    warnings: Vec<String>,
+    warned_scalars: HashSet<String>,
+}
+
+impl CodeGenerator<'_, '_, '_> {
+    fn descriptions(&self) -> bool {
+        self.request.include_descriptions.unwrap_or(true)
+    }
+
+    fn scalar_type(&mut self, name: &str, go: bool) -> String {
+        if let Some(mapped) = self.request.scalar_types.get(name) {
+            return mapped.clone();
+        }
+        let builtin = match (name, go) {
+            ("ID" | "String", _) => Some("string"),
+            ("Int", true) => Some("int"),
+            ("Float", true) => Some("float64"),
+            ("Int" | "Float", false) => Some("number"),
+            ("Boolean", true) => Some("bool"),
+            ("Boolean", false) => Some("boolean"),
+            ("DateTime" | "Time" | "Timestamp", true) => Some("time.Time"),
+            ("DateTime" | "Time" | "Timestamp" | "Date", false) => Some("string"),
+            ("JSON" | "JSONObject", true) => Some("json.RawMessage"),
+            ("JSON" | "JSONObject", false) => Some("unknown"),
+            _ => None,
+        };
+        if let Some(builtin) = builtin {
+            return builtin.to_string();
+        }
+        let fallback = if go { "string" } else { "unknown" };
+        if self.warned_scalars.insert(name.to_string()) {
+            self.warnings.push(format!(
+                "自定义标量 {} 未配置映射，按 {} 处理",
+                name, fallback
+            ));
+        }
+        fallback.to_string()
+    }
+
+    fn generate_go(&mut self, types: &[&TypeDefinition<String>]) -> String {
+        let mut blocks = Vec::new();
+        for definition in types {
+            let block = match definition {
+                TypeDefinition::Scalar(_) => continue,
+                TypeDefinition::Object(t) => {
+                    let mut block =
+                        self.go_struct(&t.name, &t.description, &output_fields(&t.fields));
+                    for abstract_type in self.model.abstract_types_of(&t.name) {
+                        block
+                            .push_str(&format!("\n\nfunc ({}) Is{}() {{}}", t.name, abstract_type));
+                    }
+                    block
+                }
+                TypeDefinition::InputObject(t) => {
+                    self.go_struct(&t.name, &t.description, &input_fields(&t.fields))
+                }
+                TypeDefinition::Interface(t) => self.go_marker_interface(&t.name, &t.description),
+                TypeDefinition::Union(t) => self.go_marker_interface(&t.name, &t.description),
+                TypeDefinition::Enum(t) => self.go_enum(t),
+            };
+            blocks.push(block);
+        }
+
+        let body = blocks.join("\n\n");
+        let mut imports = Vec::new();
+        if body.contains("json.RawMessage") {
+            imports.push("\"encoding/json\"");
+        }
+        if body.contains("time.Time") {
+            imports.push("\"time\"");
+        }
+        let package = self
+            .request
+            .package_name
+            .as_deref()
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .unwrap_or("model");
+        let mut code = format!("package {}\n\n", package);
+        match imports.len() {
+            0 => {}
+            1 => code.push_str(&format!("import {}\n\n", imports[0])),
+            _ => code.push_str(&format!("import (\n\t{}\n)\n\n", imports.join("\n\t"))),
+        }
+        code.push_str(&body);
+        code.push('\n');
+        code
+    }
+
+    fn go_struct(
+        &mut self,
+        name: &str,
+        description: &Option<String>,
+        fields: &[FieldRef],
+    ) -> String {
+        let mut lines = Vec::new();
+        for &(field_name, field_type, field_description) in fields {
+            if self.descriptions() {
+                lines.extend(go_comment(field_description, "\t"));
+            }
+            let nullable = !matches!(field_type, Type::NonNullType(_));
+            lines.push(format!(
+                "\t{} {} `json:\"{}{}\"`",
+                go_name(field_name),
+                self.go_type(field_type, nullable),
+                field_name,
+                if nullable { ",omitempty" } else { "" }
+            ));
+        }
+        let mut block = String::new();
+        if self.descriptions() {
+            for line in go_comment(description, "") {
+                block.push_str(&line);
+                block.push('\n');
+            }
+        }
+        block.push_str(&format!("type {} struct {{\n", name));
+        for line in align_go_fields(&lines) {
+            block.push_str(&line);
+            block.push('\n');
+        }
+        block.push('}');
+        block
+    }
+
+    fn go_type(&mut self, field_type: &Type<String>, nullable: bool) -> String {
+        match field_type {
+            Type::NonNullType(inner) => self.go_type(inner, false),
+            Type::ListType(inner) => {
+                let inner_nullable = !matches!(**inner, Type::NonNullType(_));
+                format!("[]{}", self.go_type(inner, inner_nullable))
+            }
+            Type::NamedType(name) => match self.model.get(name) {
+                Some(TypeDefinition::Object(_) | TypeDefinition::InputObject(_)) => {
+                    // 结构体统一使用指针，避免自引用类型无法编译
+                    format!("*{}", name)
+                }
+                Some(TypeDefinition::Interface(_) | TypeDefinition::Union(_)) => name.clone(),
+                Some(TypeDefinition::Enum(_)) => self.go_nullable(name.clone(), nullable),
+                _ => {
+                    let scalar = self.scalar_type(name, true);
+                    self.go_nullable(scalar, nullable)
+                }
+            },
+        }
+    }
+
+    fn go_nullable(&self, go_type: String, nullable: bool) -> String {
+        let pointer = nullable
+            && self.request.nullable_pointers.unwrap_or(true)
+            && !go_type.starts_with("[]")
+            && !go_type.starts_with('*')
+            && go_type != "json.RawMessage";
+        if pointer {
+            format!("*{}", go_type)
+        } else {
+            go_type
+        }
+    }
+
+    fn go_marker_interface(&self, name: &str, description: &Option<String>) -> String {
+        let mut block = String::new();
+        if self.descriptions() {
+            for line in go_comment(description, "") {
+                block.push_str(&line);
+                block.push('\n');
+            }
+        }
+        block.push_str(&format!("type {} interface {{\n\tIs{}()\n}}", name, name));
+        block
+    }
+
+    fn go_enum(&self, enum_type: &schema::EnumType<String>) -> String {
+        let name = &enum_type.name;
+        let const_names: Vec<String> = enum_type
+            .values
+            .iter()
+            .map(|v| format!("{}{}", name, go_name(&v.name)))
+            .collect();
+        let width = const_names.iter().map(|n| n.len()).max().unwrap_or(0);
+
+        let mut block = String::new();
+        if self.descriptions() {
+            for line in go_comment(&enum_type.description, "") {
+                block.push_str(&line);
+                block.push('\n');
+            }
+        }
+        block.push_str(&format!("type {} string\n\nconst (\n", name));
+        for (const_name, value) in const_names.iter().zip(&enum_type.values) {
+            if self.descriptions() {
+                for line in go_comment(&value.description, "\t") {
+                    block.push_str(&line);
+                    block.push('\n');
+                }
+            }
+            block.push_str(&format!(
+                "\t{:width$} {} = \"{}\"\n",
+                const_name,
+                name,
+                value.name,
+                width = width
+            ));
+        }
+        block.push_str(")\n\n");
+        block.push_str(&format!(
+            "// IsValid reports whether the value is one of the defined values\n\
+             func (e {name}) IsValid() bool {{\n\
+             \tswitch e {{\n\
+             \tcase {cases}:\n\
+             \t\treturn true\n\
+             \t}}\n\
+             \treturn false\n\
+             }}",
+            name = name,
+            cases = const_names.join(", ")
+        ));
+        block
+    }
+
+    fn generate_typescript(&mut self, types: &[&TypeDefinition<String>]) -> String {
+        let mut blocks = Vec::new();
+        for definition in types {
+            let block = match definition {
+                TypeDefinition::Scalar(_) => continue,
+                TypeDefinition::Object(t) => {
+                    let mut fields = vec![format!("  __typename?: \"{}\";", t.name)];
+                    fields.extend(self.ts_fields(&output_fields(&t.fields), false));
+                    self.ts_interface(&t.name, &t.description, &t.implements_interfaces, fields)
+                }
+                TypeDefinition::Interface(t) => {
+                    let fields = self.ts_fields(&output_fields(&t.fields), false);
+                    self.ts_interface(&t.name, &t.description, &t.implements_interfaces, fields)
+                }
+                TypeDefinition::InputObject(t) => {
+                    let fields = self.ts_fields(&input_fields(&t.fields), true);
+                    self.ts_interface(&t.name, &t.description, &[], fields)
+                }
+                TypeDefinition::Union(t) => format!(
+                    "{}export type {} = {};",
+                    self.ts_doc(&t.description, ""),
+                    t.name,
+                    if t.types.is_empty() {
+                        "never".to_string()
+                    } else {
+                        t.types.join(" | ")
+                    }
+                ),
+                TypeDefinition::Enum(t) => self.ts_enum(t),
+            };
+            blocks.push(block);
+        }
+        let mut code = blocks.join("\n\n");
+        code.push('\n');
+        code
+    }
+
+    fn ts_fields(&mut self, fields: &[FieldRef], input: bool) -> Vec<String> {
+        let mut lines = Vec::new();
+        for &(name, field_type, description) in fields {
+            let doc = self.ts_doc(description, "  ");
+            if !doc.is_empty() {
+                lines.push(doc.trim_end().to_string());
+            }
+            let nullable = !matches!(field_type, Type::NonNullType(_));
+            // 输入类型的可空字段可以省略，输出类型的字段始终存在但可能为 null
+            lines.push(format!(
+                "  {}{}: {};",
+                name,
+                if nullable && input { "?" } else { "" },
+                self.ts_type(field_type, nullable)
+            ));
+        }
+        lines
+    }
+
+    fn ts_type(&mut self, field_type: &Type<String>, nullable: bool) -> String {
+        let base = match field_type {
+            Type::NonNullType(inner) => return self.ts_type(inner, false),
+            Type::ListType(inner) => {
+                let inner_nullable = !matches!(**inner, Type::NonNullType(_));
+                let item = self.ts_type(inner, inner_nullable);
+                if inner_nullable {
+                    format!("({})[]", item)
+                } else {
+                    format!("{}[]", item)
+                }
+            }
+            Type::NamedType(name) => match self.model.get(name) {
+                Some(TypeDefinition::Scalar(_)) | None => self.scalar_type(name, false),
+                Some(_) => name.clone(),
+            },
+        };
+        if nullable {
+            format!("{} | null", base)
+        } else {
+            base
+        }
+    }
+
+    fn ts_interface(
+        &self,
+        name: &str,
+        description: &Option<String>,
+        extends: &[String],
+        fields: Vec<String>,
+    ) -> String {
+        let extends = if extends.is_empty() {
+            String::new()
+        } else {
+            format!(" extends {}", extends.join(", "))
+        };
+        format!(
+            "{}export interface {}{} {{\n{}\n}}",
+            self.ts_doc(description, ""),
+            name,
+            extends,
+            fields.join("\n")
+        )
+    }
+
+    fn ts_enum(&self, enum_type: &schema::EnumType<String>) -> String {
+        let doc = self.ts_doc(&enum_type.description, "");
+        if self.request.enum_style.as_deref() == Some("enum") {
+            let members: Vec<String> = enum_type
+                .values
+                .iter()
+                .map(|v| {
+                    format!(
+                        "{}  {} = \"{}\",",
+                        self.ts_doc(&v.description, "  "),
+                        go_name(&v.name),
+                        v.name
+                    )
+                })
+                .collect();
+            format!(
+                "{}export enum {} {{\n{}\n}}",
+                doc,
+                enum_type.name,
+                members.join("\n")
+            )
+        } else {
+            let members: Vec<String> = enum_type
+                .values
+                .iter()
+                .map(|v| format!("\"{}\"", v.name))
+                .collect();
+            format!(
+                "{}export type {} = {};",
+                doc,
+                enum_type.name,
+                if members.is_empty() {
+                    "never".to_string()
+                } else {
+                    members.join(" | ")
+                }
+            )
+        }
+    }
+
+    fn ts_doc(&self, description: &Option<String>, indent: &str) -> String {
+        let Some(description) = description.as_deref().map(str::trim) else {
+            return String::new();
+        };
+        if !self.descriptions() || description.is_empty() {
+            return String::new();
+        }
+        let lines: Vec<&str> = description.lines().collect();
+        if lines.len() == 1 {
+            return format!("{}/** {} */\n", indent, lines[0].replace("*/", "*\\/"));
+        }
+        let mut doc = format!("{}/**\n", indent);
+        for line in lines {
+            doc.push_str(
+                &format!("{} * {}\n", indent, line.replace("*/", "*\\/")).replace(" * \n", " *\n"),
+            );
+        }
+        doc.push_str(&format!("{} */\n", indent));
+        doc
+    }
+}
+
+/// 输出类型字段和输入类型字段的统一视图：名称、类型、描述
+type FieldRef<'a, 'd> = (&'a str, &'a Type<'d, String>, &'a Option<String>);
+
+fn output_fields<'a, 'd>(fields: &'a [schema::Field<'d, String>]) -> Vec<FieldRef<'a, 'd>> {
+    fields
+        .iter()
+        .map(|f| (f.name.as_str(), &f.field_type, &f.description))
+        .collect()
+}
+
+fn input_fields<'a, 'd>(fields: &'a [schema::InputValue<'d, String>]) -> Vec<FieldRef<'a, 'd>> {
+    fields
+        .iter()
+        .map(|f| (f.name.as_str(), &f.value_type, &f.description))
+        .collect()
+}
+
+fn go_comment(description: &Option<String>, indent: &str) -> Vec<String> {
+    description
+        .as_deref()
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+        .map(|d| {
+            d.lines()
+                .map(|line| format!("{}// {}", indent, line).trim_end().to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// 对齐字段名和类型，与 gofmt 的输出一致
+fn align_go_fields(lines: &[String]) -> Vec<String> {
+    let fields: Vec<Option<Vec<&str>>> = lines
+        .iter()
+        .map(|line| {
+            if line.trim_start().starts_with("//") {
+                None
+            } else {
+                Some(line.trim_start().splitn(3, ' ').collect())
+            }
+        })
+        .collect();
+    let name_width = fields
+        .iter()
+        .flatten()
+        .map(|f| f[0].len())
+        .max()
+        .unwrap_or(0);
+    let type_width = fields
+        .iter()
+        .flatten()
+        .map(|f| f[1].len())
+        .max()
+        .unwrap_or(0);
+    lines
+        .iter()
+        .zip(&fields)
+        .map(|(line, parts)| match parts {
+            Some(parts) => format!(
+                "\t{:nw$} {:tw$} {}",
+                parts[0],
+                parts[1],
+                parts[2],
+                nw = name_width,
+                tw = type_width
+            ),
+            None => line.clone(),
+        })
+        .collect()
+}
+
+/// 把 GraphQL 名称转为导出的 Go 标识符，如 userId -> UserID，IN_PROGRESS -> InProgress
+fn go_name(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_ascii_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        if c.is_uppercase() && !current.is_empty() {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if prev.is_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_uppercase() && next_is_lower)
+            {
+                words.push(std::mem::take(&mut current));
+            }
+        }
+        current.push(c);
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    let mut result = String::new();
+    for word in words {
+        let upper = word.to_uppercase();
+        if GO_INITIALISMS.contains(&upper.as_str()) {
+            result.push_str(&upper);
+        } else {
+            let mut chars = word.chars();
+            if let Some(first) = chars.next() {
+                result.push(first.to_ascii_uppercase());
+                result.push_str(&chars.as_str().to_lowercase());
+            }
+        }
+    }
+    if result.is_empty() {
+        return "Field".to_string();
+    }
+    if result.starts_with(|c: char| c.is_ascii_digit()) {
+        result.insert(0, 'X');
+    }
+    result
+}
+
+fn format_query(request: &GraphqlQueryRequest) -> Result<GraphqlQueryResponse, String> {
+    if request.query.trim().is_empty() {
+        return Err("查询不能为空".to_string());
+    }
+    let document = match query::parse_query::<String>(&request.query) {
+        Ok(document) => document,
+        Err(e) => {
+            let message = e.to_string();
+            let (line, column) = parse_error_position(&message);
+            return Ok(GraphqlQueryResponse {
+                formatted: request.query.clone(),
+                valid: false,
+                errors: vec![GraphqlQueryError {
+                    message: format!("语法错误: {}", message.trim()),
+                    line,
+                    column,
+                }],
+                operations: Vec::new(),
+                fragments: Vec::new(),
+                schema_checked: false,
+            });
+        }
+    };
+
+    let formatted = if request.minify.unwrap_or(false) {
+        query::minify_query(request.query.clone()).map_err(|e| format!("压缩失败: {}", e))?
+    } else {
+        let mut style = Style::default();
+        style.indent(request.indent.unwrap_or(2));
+        document.format(&style)
+    };
+
+    let schema_document = match request.schema.as_deref().map(str::trim) {
+        Some(sdl) if !sdl.is_empty() => Some(
+            schema::parse_schema::<String>(sdl).map_err(|e| format!("Schema 解析失败: {}", e))?,
+        ),
+        _ => None,
+    };
+    let model = schema_document.as_ref().map(SchemaModel::from_document);
+    let mut validator = QueryValidator::new(&document, model.as_ref());
+    validator.validate();
+
+    let mut operations = Vec::new();
+    let mut fragments = Vec::new();
+    for definition in &document.definitions {
+        match definition {
+            Definition::Operation(operation) => {
+                let (kind, name, variables) = operation_parts(operation);
+                operations.push(GraphqlOperationInfo {
+                    kind: kind.to_string(),
+                    name: name.cloned(),
+                    variables: variables.iter().map(|v| v.name.clone()).collect(),
+                })
+            }
+            Definition::Fragment(fragment) => fragments.push(fragment.name.clone()),
+        }
+    }
+
+    Ok(GraphqlQueryResponse {
+        formatted,
+        valid: validator.errors.is_empty(),
+        errors: validator.errors,
+        operations,
+        fragments,
+        schema_checked: model.is_some(),
+    })
+}
+
+/// 从 graphql-parser 的错误信息中提取 "query parse error: Parse error at 1:5" 中的位置
+fn parse_error_position(message: &str) -> (Option<usize>, Option<usize>) {
+    let position = message
+        .split("error at ")
+        .nth(1)
+        .and_then(|rest| rest.split_whitespace().next())
+        .and_then(|pos| pos.split_once(':'));
+    match position {
+        Some((line, column)) => (line.parse().ok(), column.parse().ok()),
+        None => (None, None),
+    }
+}
+
+type Variables<'a, 'd> = &'a [query::VariableDefinition<'d, String>];
+
+fn operation_parts<'a, 'd>(
+    operation: &'a OperationDefinition<'d, String>,
+) -> (&'static str, Option<&'a String>, Variables<'a, 'd>) {
+    match operation {
+        OperationDefinition::SelectionSet(_) => ("query", None, &[]),
+        OperationDefinition::Query(q) => ("query", q.name.as_ref(), &q.variable_definitions),
+        OperationDefinition::Mutation(m) => ("mutation", m.name.as_ref(), &m.variable_definitions),
+        OperationDefinition::Subscription(s) => {
+            ("subscription", s.name.as_ref(), &s.variable_definitions)
+        }
+    }
+}
+
+fn operation_selection<'a, 'd>(
+    operation: &'a OperationDefinition<'d, String>,
+) -> (&'a SelectionSet<'d, String>, Pos) {
+    match operation {
+        OperationDefinition::SelectionSet(s) => (s, s.span.0),
+        OperationDefinition::Query(q) => (&q.selection_set, q.position),
+        OperationDefinition::Mutation(m) => (&m.selection_set, m.position),
+        OperationDefinition::Subscription(s) => (&s.selection_set, s.position),
+    }
+}
+
+fn named_type<'a>(field_type: &'a Type<String>) -> &'a str {
+    match field_type {
+        Type::NamedType(name) => name,
+        Type::ListType(inner) | Type::NonNullType(inner) => named_type(inner),
+    }
+}
+
+struct QueryValidator<'a, 'd> {
+    document: &'a query::Document<'d, String>,
+    model: Option<&'a SchemaModel<'d>>,
+    fragments: HashMap<&'a str, &'a query::FragmentDefinition<'d, String>>,
+    errors: Vec<GraphqlQueryError>,
+}
+
+impl<'a, 'd> QueryValidator<'a, 'd> {
+    fn new(document: &'a query::Document<'d, String>, model: Option<&'a SchemaModel<'d>>) -> Self {
+        Self {
+            document,
+            model,
+            fragments: HashMap::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    fn error(&mut self, message: String, position: Option<Pos>) {
+        self.errors.push(GraphqlQueryError {
+            message,
+            line: position.map(|p| p.line),
+            column: position.map(|p| p.column),
+        });
+    }
+
+    fn validate(&mut self) {
+        let document = self.document;
+        let operation_count = document
+            .definitions
+            .iter()
+            .filter(|d| matches!(d, Definition::Operation(_)))
+            .count();
+        let mut operation_names = HashSet::new();
+        for definition in &document.definitions {
+            match definition {
+                Definition::Fragment(fragment) => {
+                    if self.fragments.insert(&fragment.name, fragment).is_some() {
+                        self.error(
+                            format!("片段 {} 重复定义", fragment.name),
+                            Some(fragment.position),
+                        );
+                    }
+                }
+                Definition::Operation(operation) => {
+                    let (_, name, _) = operation_parts(operation);
+                    let (_, position) = operation_selection(operation);
+                    match name {
+                        Some(name) if !operation_names.insert(name.as_str()) => {
+                            self.error(format!("操作 {} 重复定义", name), Some(position))
+                        }
+                        None if operation_count > 1 => {
+                            self.error("存在多个操作时不能使用匿名操作".to_string(), Some(position))
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        let mut used_fragments = HashSet::new();
+        for definition in &document.definitions {
+            if let Definition::Operation(operation) = definition {
+                self.validate_operation(operation, &mut used_fragments);
+            }
+        }
+        for definition in &document.definitions {
+            if let Definition::Fragment(fragment) = definition {
+                if !used_fragments.contains(fragment.name.as_str()) {
+                    self.error(
+                        format!("片段 {} 未被使用", fragment.name),
+                        Some(fragment.position),
+                    );
+                }
+                if let Some(model) = self.model {
+                    let query::TypeCondition::On(on) = &fragment.type_condition;
+                    if model.get(on).is_some() {
+                        self.validate_selection_set(on, &fragment.selection_set);
+                    } else {
+                        self.error(
+                            format!("片段 {} 的类型 {} 不存在", fragment.name, on),
+                            Some(fragment.position),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    fn validate_operation(
+        &mut self,
+        operation: &'a OperationDefinition<'d, String>,
+        used_fragments: &mut HashSet<&'a str>,
+    ) {
+        let (kind, name, variables) = operation_parts(operation);
+        let (selection_set, position) = operation_selection(operation);
+        let label = name.map_or_else(|| "匿名操作".to_string(), |n| format!("操作 {}", n));
+
+        let mut used_variables = HashSet::new();
+        let mut visited = HashSet::new();
+        self.collect_usage(
+            selection_set,
+            &mut used_variables,
+            used_fragments,
+            &mut visited,
+        );
+        let defined: HashSet<&str> = variables.iter().map(|v| v.name.as_str()).collect();
+        let mut undefined: Vec<&String> = used_variables
+            .iter()
+            .filter(|v| !defined.contains(v.as_str()))
+            .collect();
+        undefined.sort();
+        for variable in undefined {
+            self.error(
+                format!("{} 使用了未定义的变量 ${}", label, variable),
+                Some(position),
+            );
+        }
+        for variable in variables {
+            if !used_variables.contains(&variable.name) {
+                self.error(
+                    format!("{} 的变量 ${} 未被使用", label, variable.name),
+                    Some(variable.position),
+                );
+            }
+        }
+
+        let Some(model) = self.model else {
+            return;
+        };
+        for variable in variables {
+            let type_name = named_type(&variable.var_type);
+            let is_input = BUILTIN_SCALARS.contains(&type_name)
+                || matches!(
+                    model.get(type_name),
+                    Some(
+                        TypeDefinition::Scalar(_)
+                            | TypeDefinition::Enum(_)
+                            | TypeDefinition::InputObject(_)
+                    )
+                );
+            if !is_input {
+                self.error(
+                    format!("变量 ${} 的类型 {} 不是输入类型", variable.name, type_name),
+                    Some(variable.position),
+                );
+            }
+        }
+        let root = match kind {
+            "mutation" => &model.mutation_type,
+            "subscription" => &model.subscription_type,
+            _ => &model.query_type,
+        };
+        if model.get(root).is_none() {
+            self.error(format!("Schema 不支持 {} 操作", kind), Some(position));
+            return;
+        }
+        let root = root.clone();
+        self.validate_selection_set(&root, selection_set);
+    }
+
+    /// 收集选择集（含引用的片段）中使用的变量和片段
+    fn collect_usage(
+        &mut self,
+        selection_set: &'a SelectionSet<'d, String>,
+        variables: &mut HashSet<String>,
+        used_fragments: &mut HashSet<&'a str>,
+        visited: &mut HashSet<&'a str>,
+    ) {
+        for selection in &selection_set.items {
+            match selection {
+                Selection::Field(field) => {
+                    for (_, value) in &field.arguments {
+                        collect_variables(value, variables);
+                    }
+                    for directive in &field.directives {
+                        for (_, value) in &directive.arguments {
+                            collect_variables(value, variables);
+                        }
+                    }
+                    self.collect_usage(&field.selection_set, variables, used_fragments, visited);
+                }
+                Selection::InlineFragment(fragment) => {
+                    for directive in &fragment.directives {
+                        for (_, value) in &directive.arguments {
+                            collect_variables(value, variables);
+                        }
+                    }
+                    self.collect_usage(&fragment.selection_set, variables, used_fragments, visited);
+                }
+                Selection::FragmentSpread(spread) => {
+                    used_fragments.insert(spread.fragment_name.as_str());
+                    if !visited.insert(spread.fragment_name.as_str()) {
+                        continue;
+                    }
+                    match self.fragments.get(spread.fragment_name.as_str()) {
+                        Some(fragment) => {
+                            self.collect_usage(
+                                &fragment.selection_set,
+                                variables,
+                                used_fragments,
+                                visited,
+                            );
+                        }
+                        None => self.error(
+                            format!("片段 {} 未定义", spread.fragment_name),
+                            Some(spread.position),
+                        ),
+                    }
+                }
+            }
+        }
+    }
+
+    fn validate_selection_set(&mut self, parent: &str, selection_set: &SelectionSet<'d, String>) {
+        let Some(model) = self.model else {
+            return;
+        };
+        for selection in &selection_set.items {
+            match selection {
+                Selection::Field(field) => self.validate_field(parent, field),
+                Selection::InlineFragment(fragment) => {
+                    let on = match &fragment.type_condition {
+                        Some(query::TypeCondition::On(on)) => on.as_str(),
+                        None => parent,
+                    };
+                    if model.get(on).is_none() {
+                        self.error(format!("类型 {} 不存在", on), Some(fragment.position));
+                        continue;
+                    }
+                    self.validate_selection_set(on, &fragment.selection_set);
+                }
+                Selection::FragmentSpread(_) => {}
+            }
+        }
+    }
+
+    fn validate_field(&mut self, parent: &str, field: &query::Field<'d, String>) {
+        let Some(model) = self.model else {
+            return;
+        };
+        let position = Some(field.position);
+        if field.name == "__typename" {
+            return;
+        }
+        if (field.name == "__schema" || field.name == "__type") && parent == model.query_type {
+            return;
+        }
+        let fields = match model.get(parent) {
+            Some(TypeDefinition::Object(t)) => &t.fields,
+            Some(TypeDefinition::Interface(t)) => &t.fields,
+            Some(TypeDefinition::Union(_)) => {
+                self.error(
+                    format!("联合类型 {} 只能通过片段选择字段 {}", parent, field.name),
+                    position,
+                );
+                return;
+            }
+            _ => {
+                self.error(format!("类型 {} 不能选择字段", parent), position);
+                return;
+            }
+        };
+        let Some(definition) = fields.iter().find(|f| f.name == field.name) else {
+            self.error(format!("类型 {} 没有字段 {}", parent, field.name), position);
+            return;
+        };
+
+        for (name, _) in &field.arguments {
+            if !definition.arguments.iter().any(|a| &a.name == name) {
+                self.error(
+                    format!("字段 {}.{} 没有参数 {}", parent, field.name, name),
+                    position,
+                );
+            }
+        }
+        for argument in &definition.arguments {
+            let required = matches!(argument.value_type, Type::NonNullType(_))
+                && argument.default_value.is_none();
+            if required && !field.arguments.iter().any(|(n, _)| n == &argument.name) {
+                self.error(
+                    format!(
+                        "字段 {}.{} 缺少必填参数 {}",
+                        parent, field.name, argument.name
+                    ),
+                    position,
+                );
+            }
+        }
+
+        let field_type = named_type(&definition.field_type).to_string();
+        let composite = matches!(
+            model.get(&field_type),
+            Some(
+                TypeDefinition::Object(_) | TypeDefinition::Interface(_) | TypeDefinition::Union(_)
+            )
+        );
+        if composite && field.selection_set.items.is_empty() {
+            self.error(
+                format!(
+                    "字段 {}.{} 的类型 {} 需要选择子字段",
+                    parent, field.name, field_type
+                ),
+                position,
+            );
+        } else if !composite && !field.selection_set.items.is_empty() {
+            self.error(
+                format!(
+                    "字段 {}.{} 的类型 {} 不能选择子字段",
+                    parent, field.name, field_type
+                ),
+                position,
+            );
+        } else if composite {
+            self.validate_selection_set(&field_type, &field.selection_set);
+        }
+    }
+}
+
+fn collect_variables(value: &query::Value<String>, variables: &mut HashSet<String>) {
+    match value {
+        query::Value::Variable(name) => {
+            variables.insert(name.clone());
+        }
+        query::Value::List(items) => {
+            for item in items {
+                collect_variables(item, variables);
+            }
+        }
+        query::Value::Object(fields) => {
+            for item in fields.values() {
+                collect_variables(item, variables);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn is_user_type(t: &Value) -> bool {
+    let name = t.get("name").and_then(|n| n.as_str()).unwrap_or_default();
+    !name.starts_with("__") && !BUILTIN_SCALARS.contains(&name)
+}
+
+/// 把内省结果转换为 SDL，并经 graphql-parser 重新格式化
+fn introspection_to_sdl(schema: &Value) -> Result<String, String> {
+    let root_name = |key: &str| {
+        schema
+            .pointer(&format!("/{}/name", key))
+            .and_then(|n| n.as_str())
+    };
+    let roots = [
+        ("query", root_name("queryType"), "Query"),
+        ("mutation", root_name("mutationType"), "Mutation"),
+        (
+            "subscription",
+            root_name("subscriptionType"),
+            "Subscription",
+        ),
+    ];
+
+    let mut blocks = Vec::new();
+    if roots
+        .iter()
+        .any(|(_, name, default)| name.is_some_and(|n| n != *default))
+    {
+        let fields: Vec<String> = roots
+            .iter()
+            .filter_map(|(key, name, _)| name.map(|n| format!("  {}: {}", key, n)))
+            .collect();
+        blocks.push(format!("schema {{\n{}\n}}", fields.join("\n")));
+    }
+
+    for directive in schema
+        .get("directives")
+        .and_then(|d| d.as_array())
+        .into_iter()
+        .flatten()
+    {
+        let name = directive
+            .get("name")
+            .and_then(|n| n.as_str())
+            .unwrap_or_default();
+        if name.is_empty() || BUILTIN_DIRECTIVES.contains(&name) {
+            continue;
+        }
+        let locations: Vec<&str> = directive
+            .get("locations")
+            .and_then(|l| l.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|l| l.as_str())
+            .collect();
+        blocks.push(format!(
+            "{}directive @{}{}{} on {}",
+            sdl_description(directive, ""),
+            name,
+            sdl_arguments(directive),
+            if directive.get("isRepeatable").and_then(|r| r.as_bool()) == Some(true) {
+                " repeatable"
+            } else {
+                ""
+            },
+            locations.join(" | ")
+        ));
+    }
+
+    let mut types: Vec<&Value> = schema
+        .get("types")
+        .and_then(|t| t.as_array())
+        .ok_or("内省结果缺少 types")?
+        .iter()
+        .filter(|t| is_user_type(t))
+        .collect();
+    types.sort_by_key(|t| t.get("name").and_then(|n| n.as_str()).unwrap_or_default());
+    for t in types {
+        let name = t.get("name").and_then(|n| n.as_str()).unwrap_or_default();
+        let description = sdl_description(t, "");
+        let block = match t.get("kind").and_then(|k| k.as_str()).unwrap_or_default() {
+            "SCALAR" => format!("{}scalar {}", description, name),
+            "OBJECT" | "INTERFACE" => {
+                let keyword = if t["kind"] == "OBJECT" {
+                    "type"
+                } else {
+                    "interface"
+                };
+                let interfaces: Vec<String> = t
+                    .get("interfaces")
+                    .and_then(|i| i.as_array())
+                    .into_iter()
+                    .flatten()
+                    .map(type_ref)
+                    .collect();
+                let implements = if interfaces.is_empty() {
+                    String::new()
+                } else {
+                    format!(" implements {}", interfaces.join(" & "))
+                };
+                let fields: Vec<String> = t
+                    .get("fields")
+                    .and_then(|f| f.as_array())
+                    .into_iter()
+                    .flatten()
+                    .map(|f| {
+                        format!(
+                            "{}  {}{}: {}{}",
+                            sdl_description(f, "  "),
+                            f["name"].as_str().unwrap_or_default(),
+                            sdl_arguments(f),
+                            type_ref(&f["type"]),
+                            sdl_deprecated(f)
+                        )
+                    })
+                    .collect();
+                format!(
+                    "{}{} {}{} {{\n{}\n}}",
+                    description,
+                    keyword,
+                    name,
+                    implements,
+                    fields.join("\n")
+                )
+            }
+            "UNION" => {
+                let members: Vec<String> = t
+                    .get("possibleTypes")
+                    .and_then(|p| p.as_array())
+                    .into_iter()
+                    .flatten()
+                    .map(type_ref)
+                    .collect();
+                format!("{}union {} = {}", description, name, members.join(" | "))
+            }
+            "ENUM" => {
+                let values: Vec<String> = t
+                    .get("enumValues")
+                    .and_then(|v| v.as_array())
+                    .into_iter()
+                    .flatten()
+                    .map(|v| {
+                        format!(
+                            "{}  {}{}",
+                            sdl_description(v, "  "),
+                            v["name"].as_str().unwrap_or_default(),
+                            sdl_deprecated(v)
+                        )
+                    })
+                    .collect();
+                format!("{}enum {} {{\n{}\n}}", description, name, values.join("\n"))
+            }
+            "INPUT_OBJECT" => {
+                let fields: Vec<String> = t
+                    .get("inputFields")
+                    .and_then(|f| f.as_array())
+                    .into_iter()
+                    .flatten()
+                    .map(|f| format!("{}  {}", sdl_description(f, "  "), sdl_input_value(f)))
+                    .collect();
+                format!(
+                    "{}input {} {{\n{}\n}}",
+                    description,
+                    name,
+                    fields.join("\n")
+                )
+            }
+            kind => return Err(format!("未知的类型种类 {}: {}", kind, name)),
+        };
+        blocks.push(block);
+    }
+
+    let sdl = blocks.join("\n\n");
+    let document = schema::parse_schema::<String>(&sdl)
+        .map_err(|e| format!("内省结果无法转换为有效的 SDL: {}", e))?;
+    Ok(document.to_string())
+}
+
+fn type_ref(t: &Value) -> String {
+    match t.get("kind").and_then(|k| k.as_str()) {
+        Some("NON_NULL") => format!("{}!", type_ref(&t["ofType"])),
+        Some("LIST") => format!("[{}]", type_ref(&t["ofType"])),
+        _ => t["name"].as_str().unwrap_or_default().to_string(),
+    }
+}
+
+fn sdl_description(t: &Value, indent: &str) -> String {
+    match t.get("description").and_then(|d| d.as_str()) {
+        Some(description) if !description.is_empty() => format!(
+            "{}\"\"\"{}\"\"\"\n",
+            indent,
+            description.replace("\"\"\"", "\\\"\"\"")
+        ),
+        _ => String::new(),
+    }
+}
+
+fn sdl_input_value(v: &Value) -> String {
+    let default = v
+        .get("defaultValue")
+        .and_then(|d| d.as_str())
+        .map(|d| format!(" = {}", d))
+        .unwrap_or_default();
+    format!(
+        "{}: {}{}",
+        v["name"].as_str().unwrap_or_default(),
+        type_ref(&v["type"]),
+        default
+    )
+}
+
+fn sdl_arguments(t: &Value) -> String {
+    let args: Vec<String> = t
+        .get("args")
+        .and_then(|a| a.as_array())
+        .into_iter()
+        .flatten()
+        .map(|a| {
+            format!(
+                "{}{}",
+                sdl_description(a, "").replace('\n', " "),
+                sdl_input_value(a)
+            )
+        })
+        .collect();
+    if args.is_empty() {
+        String::new()
+    } else {
+        format!("({})", args.join(", "))
+    }
+}
+
+fn sdl_deprecated(t: &Value) -> String {
+    if t.get("isDeprecated").and_then(|d| d.as_bool()) != Some(true) {
+        return String::new();
+    }
+    match t.get("deprecationReason").and_then(|r| r.as_str()) {
+        Some(reason) if reason != "No longer supported" => format!(
+            " @deprecated(reason: {})",
+            serde_json::to_string(reason).unwrap_or_default()
+        ),
+        _ => " @deprecated".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SDL: &str = r#"
+        scalar DateTime
+
+        "A node with a global ID"
+        interface Node {
+          id: ID!
+        }
+
+        enum Role {
+          ADMIN
+          IN_PROGRESS
+        }
+
+        type User implements Node {
+          id: ID!
+          "Display name"
+          name: String
+          role: Role!
+          avatarUrl: String
+          createdAt: DateTime!
+          friends: [User!]!
+          manager: User
+        }
+
+        type Post implements Node {
+          id: ID!
+          title: String!
+          author: User!
+        }
+
+        union SearchResult = User | Post
+
+        input UserFilter {
+          role: Role
+          nameContains: String
+        }
+
+        type Query {
+          user(id: ID!): User
+          search(term: String!, first: Int = 10): [SearchResult!]!
+        }
+
+        extend type Post {
+          tags: [String]
+        }
+    "#;
+
+    fn codegen(language: &str) -> GraphqlCodegenResponse {
+        generate_code(&GraphqlCodegenRequest {
+            sdl: SDL.to_string(),
+            language: language.to_string(),
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_go_codegen() {
+        let result = codegen("go");
+        let code = &result.code;
+        assert!(code.starts_with("package model\n\nimport \"time\"\n"));
+        assert!(code.contains("// A node with a global ID\ntype Node interface {\n\tIsNode()\n}"));
+        assert!(code.contains(
+            "\tRoleAdmin      Role = \"ADMIN\"\n\tRoleInProgress Role = \"IN_PROGRESS\""
+        ));
+        assert!(code.contains("\tID        string    `json:\"id\"`"));
+        assert!(code.contains("\t// Display name\n\tName      *string   `json:\"name,omitempty\"`"));
+        assert!(code.contains("\tAvatarURL *string   `json:\"avatarUrl,omitempty\"`"));
+        assert!(code.contains("\tCreatedAt time.Time `json:\"createdAt\"`"));
+        assert!(code.contains("\tFriends   []*User   `json:\"friends\"`"));
+        assert!(code.contains("func (User) IsNode() {}\n\nfunc (User) IsSearchResult() {}"));
+        assert!(code.contains("\tTags   []*string `json:\"tags,omitempty\"`"));
+        assert!(code.contains("type SearchResult interface {\n\tIsSearchResult()\n}"));
+        assert!(code.contains(
+            "type UserFilter struct {\n\tRole         *Role   `json:\"role,omitempty\"`"
+        ));
+        assert!(!code.contains("type Query struct"));
+        assert_eq!(result.type_count, 6);
+        assert!(result.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_typescript_codegen() {
+        let result = codegen("typescript");
+        let code = &result.code;
+        assert!(code
+            .contains("/** A node with a global ID */\nexport interface Node {\n  id: string;\n}"));
+        assert!(code.contains("export type Role = \"ADMIN\" | \"IN_PROGRESS\";"));
+        assert!(code.contains("export interface User extends Node {\n  __typename?: \"User\";"));
+        assert!(code.contains("  /** Display name */\n  name: string | null;"));
+        assert!(code.contains("  createdAt: string;"));
+        assert!(code.contains("  friends: User[];"));
+        assert!(code.contains("  tags: (string | null)[] | null;"));
+        assert!(code.contains("export type SearchResult = User | Post;"));
+        assert!(code.contains("  nameContains?: string | null;"));
+    }
+
+    #[test]
+    fn test_custom_scalar_mapping() {
+        let mut scalar_types = HashMap::new();
+        scalar_types.insert("Money".to_string(), "decimal.Decimal".to_string());
+        let result = generate_code(&GraphqlCodegenRequest {
+            sdl: "scalar Money\nscalar Upload\ntype Order { total: Money!, file: Upload }"
+                .to_string(),
+            language: "go".to_string(),
+            package_name: Some("api".to_string()),
+            scalar_types,
+            ..Default::default()
+        })
+        .unwrap();
+        assert!(result.code.starts_with("package api\n\ntype Order struct"));
+        assert!(result
+            .code
+            .contains("\tTotal decimal.Decimal `json:\"total\"`"));
+        assert!(result
+            .code
+            .contains("\tFile  *string         `json:\"file,omitempty\"`"));
+        assert_eq!(
+            result.warnings,
+            vec!["自定义标量 Upload 未配置映射，按 string 处理"]
+        );
+    }
+
+    #[test]
+    fn test_go_name() {
+        assert_eq!(go_name("userId"), "UserID");
+        assert_eq!(go_name("avatarURL"), "AvatarURL");
+        assert_eq!(go_name("IN_PROGRESS"), "InProgress");
+        assert_eq!(go_name("httpServer"), "HTTPServer");
+        assert_eq!(go_name("2fa"), "X2fa");
+    }
+
+    #[test]
+    fn test_format_and_validate_query() {
+        let result = format_query(&GraphqlQueryRequest {
+            query: "query Find($id: ID!, $unused: Int) { user(id: $id) { ...UserParts avatar } \
+                    search { ... on Post { title } } }\n\
+                    fragment UserParts on User { name friends }\n\
+                    fragment Orphan on Post { title }"
+                .to_string(),
+            schema: Some(SDL.to_string()),
+            ..Default::default()
+        })
+        .unwrap();
+        assert!(result.formatted.starts_with(
+            "query Find($id: ID!, $unused: Int) {\n  user(id: $id) {\n    ...UserParts\n    avatar\n  }\n"
+        ));
+        assert!(!result.valid);
+        assert!(result.schema_checked);
+        let messages: Vec<&str> = result.errors.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "操作 Find 的变量 $unused 未被使用",
+                "类型 User 没有字段 avatar",
+                "字段 Query.search 缺少必填参数 term",
+                "字段 User.friends 的类型 User 需要选择子字段",
+                "片段 Orphan 未被使用",
+            ]
+        );
+        assert_eq!(result.operations[0].variables, vec!["id", "unused"]);
+        assert_eq!(result.fragments, vec!["UserParts", "Orphan"]);
+    }
+
+    #[test]
+    fn test_query_without_schema() {
+        let result = format_query(&GraphqlQueryRequest {
+            query: "{ a(x: $x) { ...Missing } } { b }".to_string(),
+            minify: Some(true),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(result.formatted, "{a(x:$x){...Missing}}{b}");
+        assert!(!result.schema_checked);
+        let messages: Vec<&str> = result.errors.iter().map(|e| e.message.as_str()).collect();
+        assert!(messages.contains(&"存在多个操作时不能使用匿名操作"));
+        assert!(messages.contains(&"匿名操作 使用了未定义的变量 $x"));
+        assert!(messages.contains(&"片段 Missing 未定义"));
+
+        let result = format_query(&GraphqlQueryRequest {
+            query: "query { user(id: 1) { name }".to_string(),
+            ..Default::default()
+        })
+        .unwrap();
+        assert!(!result.valid);
+        assert_eq!(result.errors[0].line, Some(1));
+    }
+
+    #[test]
+    fn test_introspection_to_sdl() {
+        let schema = serde_json::json!({
+            "queryType": {"name": "Query"},
+            "mutationType": null,
+            "subscriptionType": null,
+            "directives": [
+                {"name": "include", "locations": ["FIELD"], "args": []},
+                {"name": "auth", "description": null, "locations": ["FIELD_DEFINITION", "OBJECT"],
+                 "isRepeatable": false,
+                 "args": [{"name": "role", "type": {"kind": "SCALAR", "name": "String", "ofType": null}, "defaultValue": "\"admin\""}]}
+            ],
+            "types": [
+                {"kind": "SCALAR", "name": "String"},
+                {"kind": "OBJECT", "name": "__Schema", "fields": []},
+                {"kind": "OBJECT", "name": "Query", "description": "Root query",
+                 "interfaces": [],
+                 "fields": [
+                    {"name": "users", "args": [
+                        {"name": "first", "type": {"kind": "SCALAR", "name": "Int", "ofType": null}, "defaultValue": "10"}
+                     ],
+                     "type": {"kind": "NON_NULL", "name": null, "ofType": {"kind": "LIST", "name": null,
+                              "ofType": {"kind": "NON_NULL", "name": null, "ofType": {"kind": "OBJECT", "name": "User", "ofType": null}}}},
+                     "isDeprecated": false},
+                    {"name": "me", "args": [], "type": {"kind": "OBJECT", "name": "User", "ofType": null},
+                     "isDeprecated": true, "deprecationReason": "Use viewer"}
+                 ]},
+                {"kind": "OBJECT", "name": "User", "interfaces": [], "fields": [
+                    {"name": "id", "args": [], "type": {"kind": "NON_NULL", "name": null, "ofType": {"kind": "SCALAR", "name": "ID", "ofType": null}}},
+                    {"name": "status", "args": [], "type": {"kind": "ENUM", "name": "Status", "ofType": null}}
+                ]},
+                {"kind": "ENUM", "name": "Status", "enumValues": [
+                    {"name": "ACTIVE", "isDeprecated": false},
+                    {"name": "BANNED", "isDeprecated": true, "deprecationReason": "No longer supported"}
+                ]}
+            ]
+        });
+        let sdl = introspection_to_sdl(&schema).unwrap();
+        assert!(
+            sdl.contains("directive @auth(role: String = \"admin\") on FIELD_DEFINITION | OBJECT")
+        );
+        assert!(!sdl.contains("@include"));
+        assert!(sdl.contains("\"Root query\"\ntype Query {\n  users(first: Int = 10): [User!]!\n  me: User @deprecated(reason: \"Use viewer\")\n}"));
+        assert!(sdl.contains("enum Status {\n  ACTIVE\n  BANNED @deprecated\n}"));
+        assert!(!sdl.contains("__Schema"));
+        assert!(!sdl.contains("schema {"));
+    }
+}
@@ -0,0 +1,564 @@
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+
+const DEFAULT_PAGE_SIZE: usize = 4096;
+const MAX_PAGE_SIZE: usize = 64 * 1024;
+/// 结构模板最多返回的字段数，避免分块很多的文件一次返回过多数据
+const MAX_TEMPLATE_FIELDS: usize = 2000;
+const MAX_PNG_CHUNKS: usize = 256;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HexReadRequest {
+    pub file_path: String,
+    pub offset: Option<u64>,
+    pub length: Option<usize>,        // 每页字节数，默认 4096，最大 64KB
+    pub bytes_per_row: Option<usize>, // 默认 16
+    pub template: Option<String>,     // auto, png, zip, elf, macho, none，默认 auto
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HexRow {
+    pub offset: u64,
+    pub hex: String,
+    pub ascii: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HexField {
+    pub name: String,
+    pub offset: u64, // 文件内的绝对偏移
+    pub length: u64,
+    pub value: String,
+    pub group: String, // 所属结构，如 IHDR、Local File Header
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HexPage {
+    pub file_size: u64,
+    pub offset: u64,
+    pub length: usize,
+    pub bytes_per_row: usize,
+    pub rows: Vec<HexRow>,
+    pub next_offset: Option<u64>,
+    pub format: Option<String>, // 识别出的文件格式
+    pub fields: Vec<HexField>,  // 只包含与当前页有交集的字段
+    pub template_error: Option<String>,
+}
+
+/// 分页读取文件的十六进制和 ASCII 视图，并按结构模板标注文件头字段
+#[tauri::command]
+pub async fn read_file_hex(request: HexReadRequest) -> Result<HexPage, String> {
+    tokio::task::spawn_blocking(move || read_file_hex_blocking(&request))
+        .await
+        .map_err(|e| format!("读取文件任务失败: {}", e))?
+}
+
+fn read_file_hex_blocking(request: &HexReadRequest) -> Result<HexPage, String> {
+    let mut file = File::open(&request.file_path).map_err(|e| format!("打开文件失败: {}", e))?;
+    let file_size = file
+        .metadata()
+        .map_err(|e| format!("读取文件信息失败: {}", e))?
+        .len();
+    let bytes_per_row = request.bytes_per_row.unwrap_or(16).clamp(4, 64);
+    let length = request
+        .length
+        .unwrap_or(DEFAULT_PAGE_SIZE)
+        .clamp(1, MAX_PAGE_SIZE);
+    let offset = request.offset.unwrap_or(0).min(file_size);
+
+    let data = read_at(&mut file, offset, length)?;
+    let rows = data
+        .chunks(bytes_per_row)
+        .enumerate()
+        .map(|(index, chunk)| HexRow {
+            offset: offset + (index * bytes_per_row) as u64,
+            hex: chunk
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect::<Vec<_>>()
+                .join(" "),
+            ascii: chunk
+                .iter()
+                .map(|&b| {
+                    if b.is_ascii_graphic() || b == b' ' {
+                        b as char
+                    } else {
+                        '.'
+                    }
+                })
+                .collect(),
+        })
+        .collect();
+    let end = offset + data.len() as u64;
+
+    let template = request.template.as_deref().unwrap_or("auto").to_lowercase();
+    let format = match template.as_str() {
+        "none" => None,
+        "auto" => detect_format(&mut file)?,
+        "png" | "zip" | "elf" | "macho" => Some(template.clone()),
+        other => return Err(format!("不支持的结构模板: {}", other)),
+    };
+    let (fields, template_error) = match &format {
+        Some(format) => match parse_template(&mut file, file_size, format) {
+            Ok(fields) => (fields, None),
+            Err(e) => (Vec::new(), Some(e)),
+        },
+        None => (Vec::new(), None),
+    };
+    let fields = fields
+        .into_iter()
+        .filter(|f| f.offset < end.max(offset + 1) && f.offset + f.length.max(1) > offset)
+        .collect();
+
+    Ok(HexPage {
+        file_size,
+        offset,
+        length: data.len(),
+        bytes_per_row,
+        rows,
+        next_offset: (end < file_size).then_some(end),
+        format,
+        fields,
+        template_error,
+    })
+}
+
+fn read_at(file: &mut File, offset: u64, length: usize) -> Result<Vec<u8>, String> {
+    file.seek(SeekFrom::Start(offset))
+        .map_err(|e| format!("定位文件失败: {}", e))?;
+    let mut data = Vec::with_capacity(length);
+    file.take(length as u64)
+        .read_to_end(&mut data)
+        .map_err(|e| format!("读取文件失败: {}", e))?;
+    Ok(data)
+}
+
+fn detect_format(file: &mut File) -> Result<Option<String>, String> {
+    let magic = read_at(file, 0, 8)?;
+    let format = match magic.as_slice() {
+        [0x89, b'P', b'N', b'G', ..] => Some("png"),
+        [b'P', b'K', 0x03, 0x04, ..] | [b'P', b'K', 0x05, 0x06, ..] => Some("zip"),
+        [0x7f, b'E', b'L', b'F', ..] => Some("elf"),
+        [0xfe, 0xed, 0xfa, 0xce | 0xcf, ..] | [0xce | 0xcf, 0xfa, 0xed, 0xfe, ..] => Some("macho"),
+        // Java class 文件同样以 CAFEBABE 开头，其后是版本号（通常大于 40）
+        [0xca, 0xfe, 0xba, 0xbe, 0, 0, 0, n, ..] if *n > 0 && *n < 30 => Some("macho"),
+        _ => None,
+    };
+    Ok(format.map(str::to_string))
+}
+
+/// 结构字段的收集器，记录当前结构名和字节序
+struct FieldWriter<'a> {
+    data: &'a [u8],
+    base: u64, // data 在文件中的起始偏移
+    big_endian: bool,
+    group: String,
+    fields: Vec<HexField>,
+}
+
+impl<'a> FieldWriter<'a> {
+    fn new(data: &'a [u8], base: u64, group: &str) -> Self {
+        Self {
+            data,
+            base,
+            big_endian: false,
+            group: group.to_string(),
+            fields: Vec::new(),
+        }
+    }
+
+    fn bytes(&self, pos: usize, len: usize) -> Result<&'a [u8], String> {
+        self.data
+            .get(pos..pos + len)
+            .ok_or_else(|| format!("{} 结构不完整", self.group))
+    }
+
+    fn uint(&self, pos: usize, len: usize) -> Result<u64, String> {
+        let bytes = self.bytes(pos, len)?;
+        let fold = |acc: u64, b: &u8| (acc << 8) | *b as u64;
+        Ok(if self.big_endian {
+            bytes.iter().fold(0, fold)
+        } else {
+            bytes.iter().rev().fold(0, fold)
+        })
+    }
+
+    fn push(&mut self, name: &str, pos: usize, len: usize, value: String) {
+        self.fields.push(HexField {
+            name: name.to_string(),
+            offset: self.base + pos as u64,
+            length: len as u64,
+            value,
+            group: self.group.clone(),
+        });
+    }
+
+    /// 读取整数字段，可用 describe 把数值翻译成说明
+    fn int(
+        &mut self,
+        name: &str,
+        pos: usize,
+        len: usize,
+        describe: fn(u64) -> Option<&'static str>,
+    ) -> Result<u64, String> {
+        let value = self.uint(pos, len)?;
+        let text = match describe(value) {
+            Some(desc) => format!("{} ({})", value, desc),
+            None => value.to_string(),
+        };
+        self.push(name, pos, len, text);
+        Ok(value)
+    }
+
+    fn hex(&mut self, name: &str, pos: usize, len: usize) -> Result<u64, String> {
+        let value = self.uint(pos, len)?;
+        self.push(
+            name,
+            pos,
+            len,
+            format!("0x{:0width$x}", value, width = len * 2),
+        );
+        Ok(value)
+    }
+
+    fn text(&mut self, name: &str, pos: usize, len: usize) -> Result<(), String> {
+        let value = String::from_utf8_lossy(self.bytes(pos, len)?).to_string();
+        self.push(name, pos, len, value);
+        Ok(())
+    }
+}
+
+fn none(_: u64) -> Option<&'static str> {
+    None
+}
+
+fn parse_template(file: &mut File, file_size: u64, format: &str) -> Result<Vec<HexField>, String> {
+    let mut fields = match format {
+        "png" => parse_png(file, file_size)?,
+        "zip" => parse_zip(file, file_size)?,
+        "elf" => parse_elf(&read_at(file, 0, 64)?)?,
+        _ => parse_macho(&read_at(file, 0, 4096)?)?,
+    };
+    fields.truncate(MAX_TEMPLATE_FIELDS);
+    Ok(fields)
+}
+
+fn parse_png(file: &mut File, file_size: u64) -> Result<Vec<HexField>, String> {
+    let header = read_at(file, 0, 8)?;
+    let mut w = FieldWriter::new(&header, 0, "Signature");
+    w.hex("PNG 签名", 0, 8)?;
+    let mut fields = w.fields;
+
+    let mut offset = 8u64;
+    for _ in 0..MAX_PNG_CHUNKS {
+        if offset + 12 > file_size {
+            break;
+        }
+        let head = read_at(file, offset, 8)?;
+        let length = u32::from_be_bytes([head[0], head[1], head[2], head[3]]) as u64;
+        let chunk_type = String::from_utf8_lossy(&head[4..8]).to_string();
+        // 只读取 IHDR 的数据，其他分块只标注长度、类型和 CRC
+        let data = if chunk_type == "IHDR" {
+            read_at(file, offset, 8 + length as usize)?
+        } else {
+            head.clone()
+        };
+        let mut w = FieldWriter::new(&data, offset, &chunk_type);
+        w.big_endian = true;
+        w.int("Length", 0, 4, none)?;
+        w.text("Chunk Type", 4, 4)?;
+        if chunk_type == "IHDR" {
+            w.int("Width", 8, 4, none)?;
+            w.int("Height", 12, 4, none)?;
+            w.int("Bit Depth", 16, 1, none)?;
+            w.int("Color Type", 17, 1, |v| match v {
+                0 => Some("灰度"),
+                2 => Some("RGB"),
+                3 => Some("索引色"),
+                4 => Some("灰度+Alpha"),
+                6 => Some("RGBA"),
+                _ => None,
+            })?;
+            w.int("Compression", 18, 1, none)?;
+            w.int("Filter", 19, 1, none)?;
+            w.int("Interlace", 20, 1, |v| match v {
+                0 => Some("无"),
+                1 => Some("Adam7"),
+                _ => None,
+            })?;
+        } else if length > 0 {
+            w.push("Data", 8, length as usize, format!("{} 字节", length));
+        }
+        fields.extend(w.fields);
+
+        let crc_offset = offset + 8 + length;
+        let crc = read_at(file, crc_offset, 4)?;
+        let mut w = FieldWriter::new(&crc, crc_offset, &chunk_type);
+        w.big_endian = true;
+        if w.hex("CRC", 0, 4).is_err() {
+            break;
+        }
+        fields.extend(w.fields);
+        offset = crc_offset + 4;
+        if chunk_type == "IEND" {
+            break;
+        }
+    }
+    Ok(fields)
+}
+
+fn zip_compression(v: u64) -> Option<&'static str> {
+    match v {
+        0 => Some("Stored"),
+        8 => Some("Deflate"),
+        9 => Some("Deflate64"),
+        12 => Some("BZIP2"),
+        14 => Some("LZMA"),
+        93 => Some("Zstandard"),
+        _ => None,
+    }
+}
+
+fn parse_zip(file: &mut File, file_size: u64) -> Result<Vec<HexField>, String> {
+    let mut fields = Vec::new();
+    let header = read_at(file, 0, 30)?;
+    if header.starts_with(b"PK\x03\x04") {
+        let mut w = FieldWriter::new(&header, 0, "Local File Header");
+        w.hex("Signature", 0, 4)?;
+        w.int("Version Needed", 4, 2, none)?;
+        w.hex("Flags", 6, 2)?;
+        w.int("Compression", 8, 2, zip_compression)?;
+        w.hex("Modified Time", 10, 2)?;
+        w.hex("Modified Date", 12, 2)?;
+        w.hex("CRC-32", 14, 4)?;
+        w.int("Compressed Size", 18, 4, none)?;
+        w.int("Uncompressed Size", 22, 4, none)?;
+        let name_len = w.int("File Name Length", 26, 2, none)?;
+        w.int("Extra Field Length", 28, 2, none)?;
+        fields.extend(w.fields);
+        let name = read_at(file, 30, name_len as usize)?;
+        let mut w = FieldWriter::new(&name, 30, "Local File Header");
+        w.text("File Name", 0, name.len())?;
+        fields.extend(w.fields);
+    }
+
+    // 中央目录结束记录位于文件末尾，之后最多有 65535 字节注释
+    let tail_len = file_size.min(22 + 65535);
+    let tail_start = file_size - tail_len;
+    let tail = read_at(file, tail_start, tail_len as usize)?;
+    if let Some(pos) = tail.windows(4).rposition(|w| w == b"PK\x05\x06") {
+        let mut w = FieldWriter::new(
+            &tail[pos..],
+            tail_start + pos as u64,
+            "End of Central Directory",
+        );
+        w.hex("Signature", 0, 4)?;
+        w.int("Disk Number", 4, 2, none)?;
+        w.int("Central Directory Disk", 6, 2, none)?;
+        w.int("Entries on Disk", 8, 2, none)?;
+        w.int("Total Entries", 10, 2, none)?;
+        w.int("Central Directory Size", 12, 4, none)?;
+        w.hex("Central Directory Offset", 16, 4)?;
+        w.int("Comment Length", 20, 2, none)?;
+        fields.extend(w.fields);
+    } else if fields.is_empty() {
+        return Err("未找到 ZIP 文件头或中央目录".to_string());
+    }
+    Ok(fields)
+}
+
+fn elf_type(v: u64) -> Option<&'static str> {
+    match v {
+        1 => Some("REL 可重定位文件"),
+        2 => Some("EXEC 可执行文件"),
+        3 => Some("DYN 共享对象"),
+        4 => Some("CORE 核心转储"),
+        _ => None,
+    }
+}
+
+fn elf_machine(v: u64) -> Option<&'static str> {
+    match v {
+        3 => Some("x86"),
+        8 => Some("MIPS"),
+        20 => Some("PowerPC"),
+        40 => Some("ARM"),
+        62 => Some("x86-64"),
+        183 => Some("AArch64"),
+        243 => Some("RISC-V"),
+        258 => Some("LoongArch"),
+        _ => None,
+    }
+}
+
+fn parse_elf(data: &[u8]) -> Result<Vec<HexField>, String> {
+    let mut w = FieldWriter::new(data, 0, "ELF Header");
+    w.hex("Magic", 0, 4)?;
+    let class = w.int("Class", 4, 1, |v| match v {
+        1 => Some("ELF32"),
+        2 => Some("ELF64"),
+        _ => None,
+    })?;
+    let endian = w.int("Data", 5, 1, |v| match v {
+        1 => Some("小端"),
+        2 => Some("大端"),
+        _ => None,
+    })?;
+    w.big_endian = endian == 2;
+    w.int("Version", 6, 1, none)?;
+    w.int("OS/ABI", 7, 1, |v| match v {
+        0 => Some("System V"),
+        3 => Some("Linux"),
+        9 => Some("FreeBSD"),
+        _ => None,
+    })?;
+    w.int("Type", 16, 2, elf_type)?;
+    w.int("Machine", 18, 2, elf_machine)?;
+    w.int("ELF Version", 20, 4, none)?;
+    // 32 位和 64 位的地址字段宽度不同，之后的偏移随之变化
+    let addr = if class == 2 { 8 } else { 4 };
+    let mut pos = 24;
+    for name in [
+        "Entry Point",
+        "Program Header Offset",
+        "Section Header Offset",
+    ] {
+        w.hex(name, pos, addr)?;
+        pos += addr;
+    }
+    w.hex("Flags", pos, 4)?;
+    pos += 4;
+    for name in [
+        "ELF Header Size",
+        "Program Header Entry Size",
+        "Program Header Count",
+        "Section Header Entry Size",
+        "Section Header Count",
+        "Section Name Index",
+    ] {
+        w.int(name, pos, 2, none)?;
+        pos += 2;
+    }
+    Ok(w.fields)
+}
+
+fn macho_cpu(v: u64) -> Option<&'static str> {
+    match v {
+        7 => Some("x86"),
+        0x0100_0007 => Some("x86-64"),
+        12 => Some("ARM"),
+        0x0100_000c => Some("ARM64"),
+        0x0200_000c => Some("ARM64_32"),
+        _ => None,
+    }
+}
+
+fn parse_macho(data: &[u8]) -> Result<Vec<HexField>, String> {
+    let mut w = FieldWriter::new(data, 0, "Mach-O Header");
+    w.big_endian = true;
+    let magic = w.hex("Magic", 0, 4)?;
+    if magic == 0xcafe_babe {
+        w.group = "Fat Header".to_string();
+        let count = w.int("Architecture Count", 4, 4, none)?;
+        for index in 0..count.min(32) as usize {
+            let pos = 8 + index * 20;
+            w.group = format!("Fat Arch {}", index);
+            w.int("CPU Type", pos, 4, macho_cpu)?;
+            w.hex("CPU Subtype", pos + 4, 4)?;
+            w.hex("Offset", pos + 8, 4)?;
+            w.int("Size", pos + 12, 4, none)?;
+            w.int("Align", pos + 16, 4, none)?;
+        }
+        return Ok(w.fields);
+    }
+
+    // CEFAEDFE / CFFAEDFE 表示小端存储
+    w.big_endian = matches!(magic, 0xfeed_face | 0xfeed_facf);
+    let is_64 = matches!(magic, 0xfeed_facf | 0xcffa_edfe);
+    w.int("CPU Type", 4, 4, macho_cpu)?;
+    w.hex("CPU Subtype", 8, 4)?;
+    w.int("File Type", 12, 4, |v| match v {
+        1 => Some("MH_OBJECT"),
+        2 => Some("MH_EXECUTE"),
+        6 => Some("MH_DYLIB"),
+        8 => Some("MH_BUNDLE"),
+        _ => None,
+    })?;
+    w.int("Load Command Count", 16, 4, none)?;
+    w.int("Load Commands Size", 20, 4, none)?;
+    w.hex("Flags", 24, 4)?;
+    if is_64 {
+        w.hex("Reserved", 28, 4)?;
+    }
+    Ok(w.fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_temp(name: &str, data: &[u8]) -> String {
+        let path = std::env::temp_dir().join(format!("hex-{}-{}", std::process::id(), name));
+        std::fs::write(&path, data).unwrap();
+        path.to_string_lossy().to_string()
+    }
+
+    fn request(path: &str, offset: u64, length: usize) -> HexReadRequest {
+        HexReadRequest {
+            file_path: path.to_string(),
+            offset: Some(offset),
+            length: Some(length),
+            bytes_per_row: Some(8),
+            template: None,
+        }
+    }
+
+    #[test]
+    fn test_png_template_and_paging() {
+        let mut png = vec![0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a];
+        png.extend([0, 0, 0, 13]);
+        png.extend(b"IHDR");
+        png.extend([0, 0, 1, 0, 0, 0, 0, 128, 8, 6, 0, 0, 0]);
+        png.extend([0xde, 0xad, 0xbe, 0xef]);
+        png.extend([0, 0, 0, 0]);
+        png.extend(b"IEND");
+        png.extend([0xae, 0x42, 0x60, 0x82]);
+        let path = write_temp("a.png", &png);
+
+        let page = read_file_hex_blocking(&request(&path, 0, 16)).unwrap();
+        assert_eq!(page.format.as_deref(), Some("png"));
+        assert_eq!(page.rows.len(), 2);
+        assert_eq!(page.rows[0].hex, "89 50 4e 47 0d 0a 1a 0a");
+        assert_eq!(page.rows[1].ascii, "....IHDR");
+        assert_eq!(page.next_offset, Some(16));
+        assert!(page.fields.iter().all(|f| f.offset < 16));
+
+        let page = read_file_hex_blocking(&request(&path, 16, 64)).unwrap();
+        let field = |name: &str| page.fields.iter().find(|f| f.name == name).unwrap();
+        assert_eq!(field("Width").value, "256");
+        assert_eq!(field("Color Type").value, "6 (RGBA)");
+        assert_eq!(field("CRC").value, "0xdeadbeef");
+        assert!(page.fields.iter().any(|f| f.group == "IEND"));
+        assert_eq!(page.next_offset, None);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_elf_header_fields() {
+        let mut elf = vec![0u8; 64];
+        elf[..8].copy_from_slice(&[0x7f, b'E', b'L', b'F', 2, 1, 1, 0]);
+        elf[16] = 3; // ET_DYN
+        elf[18] = 62; // x86-64
+        elf[24..32].copy_from_slice(&0x401000u64.to_le_bytes());
+        elf[60] = 30;
+        let fields = parse_elf(&elf).unwrap();
+        let field = |name: &str| fields.iter().find(|f| f.name == name).unwrap();
+        assert_eq!(field("Class").value, "2 (ELF64)");
+        assert_eq!(field("Machine").value, "62 (x86-64)");
+        assert_eq!(field("Entry Point").value, "0x0000000000401000");
+        assert_eq!(field("Section Header Count").offset, 60);
+        assert_eq!(field("Section Header Count").value, "30");
+        assert!(parse_elf(&elf[..20]).is_err());
+    }
+}
@@ -0,0 +1,336 @@
+use crate::tools::ssl_checker::EndpointRecordingVerifier;
+use crate::utils::proxy;
+use serde::{Deserialize, Serialize};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::time::timeout;
+use tokio_rustls::rustls;
+
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+const SETTINGS_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// 依次尝试的 QUIC 版本及对应的 ALPN
+const QUIC_VERSIONS: [(u32, &str, &[u8]); 2] = [
+    (0x0000_0001, "QUIC v1 (RFC 9000)", b"h3"),
+    (0xff00_001d, "QUIC draft-29", b"h3-29"),
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Http3Probe {
+    pub host: String,
+    pub port: u16,
+    pub address: Option<String>,
+    pub supported: bool,
+    pub quic_version: Option<String>,
+    pub alpn: Option<String>,
+    pub handshake_ms: Option<u64>,
+    pub h3_settings: Option<Vec<String>>, // 服务器控制流中的 SETTINGS 帧
+    pub zero_rtt_supported: Option<bool>, // 会话票据是否允许 0-RTT
+    pub zero_rtt_accepted: Option<bool>,  // 服务器是否接受了 0-RTT 数据
+    pub alt_svc: Option<String>,
+    pub alt_svc_advertises_h3: bool,
+    pub certificate_error: Option<String>,
+    pub error: Option<String>,
+    pub notes: Vec<String>,
+}
+
+/// 成功握手后的连接信息
+pub(crate) struct QuicHandshake {
+    endpoint: quinn::Endpoint,
+    connection: quinn::Connection,
+    client_config: quinn::ClientConfig,
+    address: SocketAddr,
+    version_name: &'static str,
+    alpn: Option<String>,
+    handshake: Duration,
+    verifier: Arc<EndpointRecordingVerifier>,
+}
+
+/// 通过真实 QUIC 握手检测 HTTP/3 支持，并测试 0-RTT
+#[tauri::command]
+pub async fn probe_http3(host: String, port: Option<u16>) -> Result<Http3Probe, String> {
+    let host = host.trim().to_string();
+    if host.is_empty() {
+        return Err("主机名不能为空".to_string());
+    }
+    let port = port.unwrap_or(443);
+    let mut probe = Http3Probe {
+        host: host.clone(),
+        port,
+        address: None,
+        supported: false,
+        quic_version: None,
+        alpn: None,
+        handshake_ms: None,
+        h3_settings: None,
+        zero_rtt_supported: None,
+        zero_rtt_accepted: None,
+        alt_svc: None,
+        alt_svc_advertises_h3: false,
+        certificate_error: None,
+        error: None,
+        notes: Vec::new(),
+    };
+
+    let (handshake, alt_svc) =
+        tokio::join!(quic_handshake(&host, port), fetch_alt_svc(&host, port));
+    probe.alt_svc_advertises_h3 = alt_svc.as_deref().is_some_and(alt_svc_has_h3);
+    probe.alt_svc = alt_svc;
+
+    match handshake {
+        Ok(handshake) => {
+            probe.supported = true;
+            probe.address = Some(handshake.address.to_string());
+            probe.quic_version = Some(handshake.version_name.to_string());
+            probe.alpn = handshake.alpn.clone();
+            probe.handshake_ms = Some(handshake.handshake.as_millis() as u64);
+            probe.certificate_error = handshake.verifier.verify_error();
+            probe.h3_settings = read_h3_settings(&handshake.connection).await;
+            if probe.h3_settings.is_none() {
+                probe
+                    .notes
+                    .push("握手成功但未收到服务器的 HTTP/3 SETTINGS 帧".to_string());
+            }
+
+            let (supported, accepted) = test_zero_rtt(&handshake, &host).await;
+            probe.zero_rtt_supported = Some(supported);
+            probe.zero_rtt_accepted = accepted;
+            handshake.endpoint.close(0u32.into(), b"");
+            let _ = timeout(Duration::from_secs(1), handshake.endpoint.wait_idle()).await;
+        }
+        Err(e) => probe.error = Some(e),
+    }
+
+    match (probe.supported, probe.alt_svc_advertises_h3) {
+        (true, false) => probe
+            .notes
+            .push("服务器支持 HTTP/3 但未通过 Alt-Svc 声明，浏览器不会自动升级".to_string()),
+        (false, true) => probe.notes.push(
+            "Alt-Svc 声明了 h3 但 QUIC 握手失败，可能是 UDP 被拦截或 Alt-Svc 已过期".to_string(),
+        ),
+        _ => {}
+    }
+    Ok(probe)
+}
+
+/// 尝试建立 QUIC 连接（ALPN h3），依次尝试 v1 与 draft-29
+pub(crate) async fn quic_handshake(host: &str, port: u16) -> Result<QuicHandshake, String> {
+    let address = tokio::net::lookup_host((host, port))
+        .await
+        .map_err(|e| format!("DNS 解析失败: {}", e))?
+        .next()
+        .ok_or_else(|| "未解析到 IP 地址".to_string())?;
+    let bind: SocketAddr = match address.ip() {
+        IpAddr::V4(_) => (Ipv4Addr::UNSPECIFIED, 0).into(),
+        IpAddr::V6(_) => (Ipv6Addr::UNSPECIFIED, 0).into(),
+    };
+
+    let mut errors = Vec::new();
+    for (version, version_name, alpn) in QUIC_VERSIONS {
+        let verifier = Arc::new(EndpointRecordingVerifier::with_webpki_roots()?);
+        let client_config = quic_client_config(version, alpn, verifier.clone())?;
+        let mut endpoint =
+            quinn::Endpoint::client(bind).map_err(|e| format!("创建 UDP 端点失败: {}", e))?;
+        endpoint.set_default_client_config(client_config.clone());
+
+        let start = Instant::now();
+        let connecting = endpoint
+            .connect(address, host)
+            .map_err(|e| format!("发起 QUIC 连接失败: {}", e))?;
+        match timeout(HANDSHAKE_TIMEOUT, connecting).await {
+            Ok(Ok(connection)) => {
+                let handshake = start.elapsed();
+                let alpn = connection
+                    .handshake_data()
+                    .and_then(|data| data.downcast::<quinn::crypto::rustls::HandshakeData>().ok())
+                    .and_then(|data| data.protocol)
+                    .map(|p| String::from_utf8_lossy(&p).into_owned());
+                return Ok(QuicHandshake {
+                    endpoint,
+                    connection,
+                    client_config,
+                    address,
+                    version_name,
+                    alpn,
+                    handshake,
+                    verifier,
+                });
+            }
+            Ok(Err(e)) => errors.push(format!("{}: {}", version_name, e)),
+            Err(_) => errors.push(format!("{}: 握手超时", version_name)),
+        }
+        endpoint.close(0u32.into(), b"");
+    }
+    Err(format!("QUIC 握手失败: {}", errors.join("; ")))
+}
+
+fn quic_client_config(
+    version: u32,
+    alpn: &[u8],
+    verifier: Arc<EndpointRecordingVerifier>,
+) -> Result<quinn::ClientConfig, String> {
+    let mut tls = rustls::ClientConfig::builder_with_provider(Arc::new(
+        rustls::crypto::ring::default_provider(),
+    ))
+    .with_protocol_versions(&[&rustls::version::TLS13])
+    .map_err(|e| format!("构建 TLS 配置失败: {}", e))?
+    .dangerous()
+    .with_custom_certificate_verifier(verifier)
+    .with_no_client_auth();
+    tls.alpn_protocols = vec![alpn.to_vec()];
+    tls.enable_early_data = true;
+
+    let crypto = quinn::crypto::rustls::QuicClientConfig::try_from(tls)
+        .map_err(|e| format!("构建 QUIC 配置失败: {}", e))?;
+    let mut transport = quinn::TransportConfig::default();
+    transport.max_idle_timeout(HANDSHAKE_TIMEOUT.try_into().ok());
+    let mut config = quinn::ClientConfig::new(Arc::new(crypto));
+    config
+        .version(version)
+        .transport_config(Arc::new(transport));
+    Ok(config)
+}
+
+/// 读取服务器控制流（类型 0x00）中的 SETTINGS 帧
+async fn read_h3_settings(connection: &quinn::Connection) -> Option<Vec<String>> {
+    let read = async {
+        // 服务器还可能先打开 QPACK 编码/解码流
+        for _ in 0..3 {
+            let mut stream = connection.accept_uni().await.ok()?;
+            let mut buf = vec![0u8; 1024];
+            let n = stream.read(&mut buf).await.ok()??;
+            if let Some(settings) = parse_control_stream(&buf[..n]) {
+                return Some(settings);
+            }
+        }
+        None
+    };
+    timeout(SETTINGS_TIMEOUT, read).await.ok().flatten()
+}
+
+/// 使用首次连接获得的会话票据重连，测试 0-RTT
+async fn test_zero_rtt(handshake: &QuicHandshake, host: &str) -> (bool, Option<bool>) {
+    handshake.connection.close(0u32.into(), b"");
+    let connecting = match handshake.endpoint.connect_with(
+        handshake.client_config.clone(),
+        handshake.address,
+        host,
+    ) {
+        Ok(connecting) => connecting,
+        Err(_) => return (false, None),
+    };
+    match connecting.into_0rtt() {
+        Ok((connection, accepted)) => {
+            let accepted = timeout(HANDSHAKE_TIMEOUT, accepted).await.ok();
+            connection.close(0u32.into(), b"");
+            (true, accepted)
+        }
+        Err(connecting) => {
+            // 不支持 0-RTT 时完成普通握手后关闭
+            if let Ok(Ok(connection)) = timeout(HANDSHAKE_TIMEOUT, connecting).await {
+                connection.close(0u32.into(), b"");
+            }
+            (false, None)
+        }
+    }
+}
+
+async fn fetch_alt_svc(host: &str, port: u16) -> Option<String> {
+    let client = proxy::client_builder("http3_probe")
+        .timeout(Duration::from_secs(10))
+        .danger_accept_invalid_certs(true)
+        .build()
+        .ok()?;
+    let url = if port == 443 {
+        format!("https://{}/", host)
+    } else {
+        format!("https://{}:{}/", host, port)
+    };
+    let response = client.head(&url).send().await.ok()?;
+    response
+        .headers()
+        .get("alt-svc")
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.to_string())
+}
+
+fn alt_svc_has_h3(alt_svc: &str) -> bool {
+    alt_svc.split(',').any(|entry| {
+        let protocol = entry.trim().split('=').next().unwrap_or_default();
+        protocol == "h3" || protocol.starts_with("h3-")
+    })
+}
+
+fn parse_control_stream(data: &[u8]) -> Option<Vec<String>> {
+    let (stream_type, mut pos) = read_varint(data, 0)?;
+    if stream_type != 0x00 {
+        return None;
+    }
+    let (frame_type, next) = read_varint(data, pos)?;
+    if frame_type != 0x04 {
+        return None;
+    }
+    let (length, next) = read_varint(data, next)?;
+    pos = next;
+    let end = (pos + length as usize).min(data.len());
+
+    let mut settings = Vec::new();
+    while pos < end {
+        let (id, next) = read_varint(data, pos)?;
+        let (value, next) = read_varint(data, next)?;
+        pos = next;
+        let name = match id {
+            0x01 => "QPACK_MAX_TABLE_CAPACITY".to_string(),
+            0x06 => "MAX_FIELD_SECTION_SIZE".to_string(),
+            0x07 => "QPACK_BLOCKED_STREAMS".to_string(),
+            0x08 => "ENABLE_CONNECT_PROTOCOL".to_string(),
+            0x33 => "H3_DATAGRAM".to_string(),
+            // RFC 9114 预留的 GREASE 标识
+            id if id >= 0x21 && (id - 0x21) % 0x1f == 0 => continue,
+            id => format!("0x{:x}", id),
+        };
+        settings.push(format!("{}={}", name, value));
+    }
+    Some(settings)
+}
+
+/// 解析 QUIC 变长整数，返回值与下一个位置
+fn read_varint(data: &[u8], pos: usize) -> Option<(u64, usize)> {
+    let first = *data.get(pos)?;
+    let len = 1usize << (first >> 6);
+    let bytes = data.get(pos..pos + len)?;
+    let mut value = (first & 0x3f) as u64;
+    for b in &bytes[1..] {
+        value = (value << 8) | *b as u64;
+    }
+    Some((value, pos + len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_control_stream_settings() {
+        // stream type 0x00, SETTINGS frame: QPACK_MAX_TABLE_CAPACITY=4096, GREASE, H3_DATAGRAM=1
+        let data = [0x00, 0x04, 0x07, 0x01, 0x50, 0x00, 0x21, 0x00, 0x33, 0x01];
+        let settings = parse_control_stream(&data).unwrap();
+        assert_eq!(
+            settings,
+            vec!["QPACK_MAX_TABLE_CAPACITY=4096", "H3_DATAGRAM=1"]
+        );
+
+        // QPACK encoder stream
+        assert!(parse_control_stream(&[0x02]).is_none());
+        assert_eq!(read_varint(&[0x7b, 0xbd], 0), Some((15293, 2)));
+    }
+
+    #[test]
+    fn test_alt_svc_has_h3() {
+        assert!(alt_svc_has_h3(r#"h3=":443"; ma=86400, h3-29=":443""#));
+        assert!(alt_svc_has_h3(r#"h3-29=":443"; ma=86400"#));
+        assert!(!alt_svc_has_h3(r#"h2=":443"; ma=86400"#));
+        assert!(!alt_svc_has_h3("clear"));
+    }
+}
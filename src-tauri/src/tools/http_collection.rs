@@ -0,0 +1,1114 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::fs;
+use std::path::PathBuf;
+
+const POSTMAN_SCHEMA_V21: &str =
+    "https://schema.getpostman.com/json/collection/v2.1.0/collection.json";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeyValue {
+    pub key: String,
+    pub value: String,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// 认证配置，参数沿用 Postman 的键值形式以便无损往返
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HttpAuth {
+    pub auth_type: String, // noauth, basic, bearer, apikey, oauth2 ...
+    #[serde(default)]
+    pub params: Vec<KeyValue>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HttpBody {
+    pub mode: String, // raw, urlencoded, formdata, graphql, file
+    pub content_type: Option<String>,
+    pub raw: Option<String>,
+    #[serde(default)]
+    pub fields: Vec<KeyValue>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedHttpRequest {
+    pub name: String,
+    pub method: String,
+    pub url: String,
+    #[serde(default)]
+    pub headers: Vec<KeyValue>,
+    #[serde(default)]
+    pub query: Vec<KeyValue>,
+    pub body: Option<HttpBody>,
+    pub auth: Option<HttpAuth>, // None 表示继承上级配置
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HttpFolder {
+    pub name: String,
+    pub description: Option<String>,
+    pub auth: Option<HttpAuth>,
+    #[serde(default)]
+    pub items: Vec<HttpCollectionItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum HttpCollectionItem {
+    Folder(HttpFolder),
+    Request(SavedHttpRequest),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HttpCollection {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub source: String, // postman, openapi, swagger
+    #[serde(default)]
+    pub variables: Vec<KeyValue>,
+    pub auth: Option<HttpAuth>,
+    #[serde(default)]
+    pub items: Vec<HttpCollectionItem>,
+}
+
+/// 导入 Postman v2.1 集合或 OpenAPI/Swagger 文档并保存
+#[tauri::command]
+pub async fn import_http_collection(
+    content: String,
+    format: Option<String>,
+) -> Result<HttpCollection, String> {
+    let document = parse_document(&content)?;
+    let format = match format.as_deref() {
+        Some("auto") | None => detect_format(&document)?,
+        Some(other) => other.to_string(),
+    };
+
+    let mut collection = match format.as_str() {
+        "postman" => import_postman(&document)?,
+        "openapi" | "swagger" => import_openapi(&document)?,
+        other => return Err(format!("不支持的导入格式: {}", other)),
+    };
+    collection.id = new_collection_id();
+
+    let mut collections = load_collections().map_err(|e| e.to_string())?;
+    collections.push(collection.clone());
+    save_collections(&collections).map_err(|e| e.to_string())?;
+    Ok(collection)
+}
+
+/// 列出已保存的请求集合
+#[tauri::command]
+pub async fn list_http_collections() -> Result<Vec<HttpCollection>, String> {
+    load_collections().map_err(|e| e.to_string())
+}
+
+/// 保存（新增或覆盖）请求集合
+#[tauri::command]
+pub async fn save_http_collection(collection: HttpCollection) -> Result<HttpCollection, String> {
+    let mut collections = load_collections().map_err(|e| e.to_string())?;
+    let mut collection = collection;
+    if collection.id.is_empty() {
+        collection.id = new_collection_id();
+    }
+    match collections.iter_mut().find(|c| c.id == collection.id) {
+        Some(existing) => *existing = collection.clone(),
+        None => collections.push(collection.clone()),
+    }
+    save_collections(&collections).map_err(|e| e.to_string())?;
+    Ok(collection)
+}
+
+/// 删除请求集合
+#[tauri::command]
+pub async fn delete_http_collection(id: String) -> Result<(), String> {
+    let mut collections = load_collections().map_err(|e| e.to_string())?;
+    let before = collections.len();
+    collections.retain(|c| c.id != id);
+    if collections.len() == before {
+        return Err(format!("未找到集合: {}", id));
+    }
+    save_collections(&collections).map_err(|e| e.to_string())
+}
+
+/// 将请求集合导出为 Postman v2.1 JSON
+#[tauri::command]
+pub async fn export_http_collection(id: String) -> Result<String, String> {
+    let collections = load_collections().map_err(|e| e.to_string())?;
+    let collection = collections
+        .iter()
+        .find(|c| c.id == id)
+        .ok_or_else(|| format!("未找到集合: {}", id))?;
+    serde_json::to_string_pretty(&export_postman(collection))
+        .map_err(|e| format!("JSON序列化失败: {}", e))
+}
+
+fn get_collections_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("devtools")
+        .join("http-collections.json")
+}
+
+fn load_collections() -> Result<Vec<HttpCollection>, Box<dyn std::error::Error>> {
+    let path = get_collections_path();
+    if path.exists() {
+        let content = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)?)
+    } else {
+        Ok(Vec::new())
+    }
+}
+
+fn save_collections(collections: &[HttpCollection]) -> Result<(), Box<dyn std::error::Error>> {
+    let path = get_collections_path();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, serde_json::to_string_pretty(collections)?)?;
+    Ok(())
+}
+
+fn new_collection_id() -> String {
+    format!(
+        "{:x}",
+        chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+    )
+}
+
+/// JSON 优先，失败时按 YAML 解析
+fn parse_document(content: &str) -> Result<Value, String> {
+    serde_json::from_str(content).or_else(|_| {
+        serde_yaml::from_str::<Value>(content).map_err(|e| format!("无法解析文档: {}", e))
+    })
+}
+
+fn detect_format(document: &Value) -> Result<String, String> {
+    if document.get("openapi").is_some() {
+        Ok("openapi".to_string())
+    } else if document.get("swagger").is_some() {
+        Ok("swagger".to_string())
+    } else if document.get("info").is_some() && document.get("item").is_some() {
+        Ok("postman".to_string())
+    } else {
+        Err("无法识别的文档格式，仅支持 Postman v2.1 和 OpenAPI/Swagger".to_string())
+    }
+}
+
+fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+fn text(value: &Value, key: &str) -> Option<String> {
+    value.get(key).and_then(Value::as_str).map(str::to_string)
+}
+
+// ---------------- Postman ----------------
+
+fn postman_key_values(value: Option<&Value>) -> Vec<KeyValue> {
+    value
+        .and_then(Value::as_array)
+        .map(|entries| {
+            entries
+                .iter()
+                .filter_map(|entry| {
+                    Some(KeyValue {
+                        key: entry.get("key")?.as_str()?.to_string(),
+                        value: entry.get("value").map(value_to_string).unwrap_or_default(),
+                        enabled: !entry
+                            .get("disabled")
+                            .and_then(Value::as_bool)
+                            .unwrap_or(false),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn postman_description(value: &Value) -> Option<String> {
+    match value.get("description") {
+        Some(Value::String(s)) => Some(s.clone()),
+        Some(Value::Object(obj)) => obj
+            .get("content")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        _ => None,
+    }
+}
+
+fn postman_auth(value: Option<&Value>) -> Option<HttpAuth> {
+    let auth = value?;
+    let auth_type = auth.get("type")?.as_str()?.to_string();
+    let params = postman_key_values(auth.get(&auth_type));
+    Some(HttpAuth { auth_type, params })
+}
+
+fn postman_body(value: Option<&Value>) -> Option<HttpBody> {
+    let body = value?;
+    let mode = body.get("mode")?.as_str()?.to_string();
+    let mut result = HttpBody {
+        mode: mode.clone(),
+        content_type: None,
+        raw: None,
+        fields: Vec::new(),
+    };
+    match mode.as_str() {
+        "raw" => {
+            result.raw = text(body, "raw");
+            result.content_type = body
+                .pointer("/options/raw/language")
+                .and_then(Value::as_str)
+                .map(|language| match language {
+                    "json" => "application/json".to_string(),
+                    "xml" => "application/xml".to_string(),
+                    "html" => "text/html".to_string(),
+                    "javascript" => "application/javascript".to_string(),
+                    _ => "text/plain".to_string(),
+                });
+        }
+        "urlencoded" | "formdata" => result.fields = postman_key_values(body.get(&mode)),
+        // graphql / file 等模式原样保留
+        _ => result.raw = body.get(&mode).map(|v| v.to_string()),
+    }
+    Some(result)
+}
+
+fn postman_request(name: String, request: &Value) -> SavedHttpRequest {
+    // request 可以直接是 URL 字符串
+    if let Value::String(url) = request {
+        return SavedHttpRequest {
+            name,
+            method: "GET".to_string(),
+            url: url.clone(),
+            headers: Vec::new(),
+            query: Vec::new(),
+            body: None,
+            auth: None,
+            description: None,
+        };
+    }
+
+    let (url, query) = match request.get("url") {
+        Some(Value::String(url)) => (url.clone(), Vec::new()),
+        Some(url) => {
+            let query = postman_key_values(url.get("query"));
+            let raw = text(url, "raw").unwrap_or_default();
+            // 查询参数单独保存，URL 中去掉查询串
+            let base = raw.split('?').next().unwrap_or_default().to_string();
+            (if query.is_empty() { raw } else { base }, query)
+        }
+        None => (String::new(), Vec::new()),
+    };
+
+    SavedHttpRequest {
+        name,
+        method: text(request, "method").unwrap_or_else(|| "GET".to_string()),
+        url,
+        headers: postman_key_values(request.get("header")),
+        query,
+        body: postman_body(request.get("body")),
+        auth: postman_auth(request.get("auth")),
+        description: postman_description(request),
+    }
+}
+
+fn postman_items(items: Option<&Value>) -> Vec<HttpCollectionItem> {
+    let Some(items) = items.and_then(Value::as_array) else {
+        return Vec::new();
+    };
+    items
+        .iter()
+        .filter_map(|item| {
+            let name = text(item, "name").unwrap_or_default();
+            if item.get("item").is_some() {
+                Some(HttpCollectionItem::Folder(HttpFolder {
+                    name,
+                    description: postman_description(item),
+                    auth: postman_auth(item.get("auth")),
+                    items: postman_items(item.get("item")),
+                }))
+            } else {
+                let request = item.get("request")?;
+                Some(HttpCollectionItem::Request(postman_request(name, request)))
+            }
+        })
+        .collect()
+}
+
+/// 解析 Postman v2.1 集合
+pub fn import_postman(document: &Value) -> Result<HttpCollection, String> {
+    let info = document
+        .get("info")
+        .ok_or_else(|| "Postman 集合缺少 info 字段".to_string())?;
+    if let Some(schema) = info.get("schema").and_then(Value::as_str) {
+        if schema.contains("v1.0.0") {
+            return Err("不支持 Postman v1 集合，请导出为 v2.1".to_string());
+        }
+    }
+
+    Ok(HttpCollection {
+        id: String::new(),
+        name: text(info, "name").unwrap_or_else(|| "Postman Collection".to_string()),
+        description: postman_description(info),
+        source: "postman".to_string(),
+        variables: postman_key_values(document.get("variable")),
+        auth: postman_auth(document.get("auth")),
+        items: postman_items(document.get("item")),
+    })
+}
+
+fn export_key_values(entries: &[KeyValue]) -> Value {
+    Value::Array(
+        entries
+            .iter()
+            .map(|kv| {
+                let mut entry = json!({ "key": kv.key, "value": kv.value });
+                if !kv.enabled {
+                    entry["disabled"] = json!(true);
+                }
+                entry
+            })
+            .collect(),
+    )
+}
+
+fn export_auth(auth: &HttpAuth) -> Value {
+    let mut value = json!({ "type": auth.auth_type });
+    if !auth.params.is_empty() {
+        let params: Vec<Value> = auth
+            .params
+            .iter()
+            .map(|kv| json!({ "key": kv.key, "value": kv.value, "type": "string" }))
+            .collect();
+        value[&auth.auth_type] = Value::Array(params);
+    }
+    value
+}
+
+fn export_body(body: &HttpBody) -> Value {
+    let mut value = json!({ "mode": body.mode });
+    match body.mode.as_str() {
+        "raw" => {
+            value["raw"] = json!(body.raw.clone().unwrap_or_default());
+            let language = match body.content_type.as_deref() {
+                Some(ct) if ct.contains("json") => "json",
+                Some(ct) if ct.contains("xml") => "xml",
+                Some(ct) if ct.contains("html") => "html",
+                Some(ct) if ct.contains("javascript") => "javascript",
+                _ => "text",
+            };
+            value["options"] = json!({ "raw": { "language": language } });
+        }
+        "urlencoded" | "formdata" => value[&body.mode] = export_key_values(&body.fields),
+        mode => {
+            let raw = body.raw.as_deref().unwrap_or("null");
+            value[mode] = serde_json::from_str(raw).unwrap_or(Value::Null);
+        }
+    }
+    value
+}
+
+fn export_request(request: &SavedHttpRequest) -> Value {
+    let mut raw = request.url.clone();
+    let enabled: Vec<String> = request
+        .query
+        .iter()
+        .filter(|kv| kv.enabled)
+        .map(|kv| format!("{}={}", kv.key, kv.value))
+        .collect();
+    if !enabled.is_empty() {
+        raw = format!("{}?{}", raw, enabled.join("&"));
+    }
+
+    let mut url = json!({ "raw": raw });
+    if !request.query.is_empty() {
+        url["query"] = export_key_values(&request.query);
+    }
+    let mut value = json!({
+        "method": request.method,
+        "header": export_key_values(&request.headers),
+        "url": url,
+    });
+    if let Some(body) = &request.body {
+        value["body"] = export_body(body);
+    }
+    if let Some(auth) = &request.auth {
+        value["auth"] = export_auth(auth);
+    }
+    if let Some(description) = &request.description {
+        value["description"] = json!(description);
+    }
+    value
+}
+
+fn export_items(items: &[HttpCollectionItem]) -> Value {
+    Value::Array(
+        items
+            .iter()
+            .map(|item| match item {
+                HttpCollectionItem::Folder(folder) => {
+                    let mut value = json!({
+                        "name": folder.name,
+                        "item": export_items(&folder.items),
+                    });
+                    if let Some(description) = &folder.description {
+                        value["description"] = json!(description);
+                    }
+                    if let Some(auth) = &folder.auth {
+                        value["auth"] = export_auth(auth);
+                    }
+                    value
+                }
+                HttpCollectionItem::Request(request) => json!({
+                    "name": request.name,
+                    "request": export_request(request),
+                }),
+            })
+            .collect(),
+    )
+}
+
+/// 生成 Postman v2.1 集合
+pub fn export_postman(collection: &HttpCollection) -> Value {
+    let mut info = json!({
+        "_postman_id": collection.id,
+        "name": collection.name,
+        "schema": POSTMAN_SCHEMA_V21,
+    });
+    if let Some(description) = &collection.description {
+        info["description"] = json!(description);
+    }
+
+    let mut value = json!({
+        "info": info,
+        "item": export_items(&collection.items),
+    });
+    if !collection.variables.is_empty() {
+        value["variable"] = export_key_values(&collection.variables);
+    }
+    if let Some(auth) = &collection.auth {
+        value["auth"] = export_auth(auth);
+    }
+    value
+}
+
+// ---------------- OpenAPI / Swagger ----------------
+
+const HTTP_METHODS: [&str; 8] = [
+    "get", "post", "put", "patch", "delete", "head", "options", "trace",
+];
+
+/// 解析本地 $ref 引用
+fn resolve_ref<'a>(document: &'a Value, value: &'a Value) -> &'a Value {
+    let mut current = value;
+    for _ in 0..16 {
+        match current.get("$ref").and_then(Value::as_str) {
+            Some(reference) if reference.starts_with("#/") => {
+                match document.pointer(&reference[1..]) {
+                    Some(target) => current = target,
+                    None => break,
+                }
+            }
+            _ => break,
+        }
+    }
+    current
+}
+
+/// 根据 schema 生成示例值
+fn schema_example(document: &Value, schema: &Value, depth: usize) -> Value {
+    let schema = resolve_ref(document, schema);
+    if depth > 8 {
+        return Value::Null;
+    }
+    if let Some(example) = schema.get("example").or_else(|| schema.get("default")) {
+        return example.clone();
+    }
+    if let Some(first) = schema
+        .get("enum")
+        .and_then(Value::as_array)
+        .and_then(|v| v.first())
+    {
+        return first.clone();
+    }
+    for combinator in ["allOf", "oneOf", "anyOf"] {
+        if let Some(parts) = schema.get(combinator).and_then(Value::as_array) {
+            if combinator != "allOf" {
+                return parts
+                    .first()
+                    .map(|part| schema_example(document, part, depth + 1))
+                    .unwrap_or(Value::Null);
+            }
+            let mut merged = Map::new();
+            for part in parts {
+                if let Value::Object(obj) = schema_example(document, part, depth + 1) {
+                    merged.extend(obj);
+                }
+            }
+            return Value::Object(merged);
+        }
+    }
+
+    let schema_type = schema.get("type").and_then(Value::as_str).unwrap_or(
+        if schema.get("properties").is_some() {
+            "object"
+        } else {
+            ""
+        },
+    );
+    match schema_type {
+        "object" => {
+            let mut map = Map::new();
+            if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+                for (name, property) in properties {
+                    map.insert(name.clone(), schema_example(document, property, depth + 1));
+                }
+            }
+            Value::Object(map)
+        }
+        "array" => match schema.get("items") {
+            Some(items) => json!([schema_example(document, items, depth + 1)]),
+            None => json!([]),
+        },
+        "integer" => json!(0),
+        "number" => json!(0.0),
+        "boolean" => json!(false),
+        "string" => match schema.get("format").and_then(Value::as_str) {
+            Some("date-time") => json!("2024-01-01T00:00:00Z"),
+            Some("date") => json!("2024-01-01"),
+            Some("uuid") => json!("00000000-0000-0000-0000-000000000000"),
+            Some("email") => json!("user@example.com"),
+            _ => json!("string"),
+        },
+        _ => Value::Null,
+    }
+}
+
+fn openapi_base_url(document: &Value) -> String {
+    if let Some(server) = document
+        .get("servers")
+        .and_then(Value::as_array)
+        .and_then(|s| s.first())
+    {
+        let mut url = text(server, "url").unwrap_or_default();
+        if let Some(variables) = server.get("variables").and_then(Value::as_object) {
+            for (name, variable) in variables {
+                let default = variable
+                    .get("default")
+                    .map(value_to_string)
+                    .unwrap_or_default();
+                url = url.replace(&format!("{{{}}}", name), &default);
+            }
+        }
+        return url.trim_end_matches('/').to_string();
+    }
+    // Swagger 2.0
+    let host = text(document, "host").unwrap_or_else(|| "localhost".to_string());
+    let scheme = document
+        .get("schemes")
+        .and_then(Value::as_array)
+        .and_then(|s| s.first())
+        .and_then(Value::as_str)
+        .unwrap_or("https");
+    let base_path = text(document, "basePath").unwrap_or_default();
+    format!("{}://{}{}", scheme, host, base_path.trim_end_matches('/'))
+}
+
+/// 将 security 要求转换为认证配置，无法识别的方案返回 None
+fn openapi_auth(document: &Value, requirements: &Value) -> Option<HttpAuth> {
+    let requirements = requirements.as_array()?;
+    if requirements.is_empty() {
+        return Some(HttpAuth {
+            auth_type: "noauth".to_string(),
+            params: Vec::new(),
+        });
+    }
+    let scheme_name = requirements.first()?.as_object()?.keys().next()?.clone();
+    let schemes = document
+        .pointer("/components/securitySchemes")
+        .or_else(|| document.get("securityDefinitions"))?;
+    let scheme = resolve_ref(document, schemes.get(&scheme_name)?);
+
+    let param = |key: &str, value: String| KeyValue {
+        key: key.to_string(),
+        value,
+        enabled: true,
+    };
+    let scheme_type = text(scheme, "type").unwrap_or_default();
+    let http_scheme = text(scheme, "scheme").unwrap_or_default().to_lowercase();
+    let auth = match (scheme_type.as_str(), http_scheme.as_str()) {
+        ("http", "bearer") => HttpAuth {
+            auth_type: "bearer".to_string(),
+            params: vec![param("token", "{{bearerToken}}".to_string())],
+        },
+        ("http", "basic") | ("basic", _) => HttpAuth {
+            auth_type: "basic".to_string(),
+            params: vec![
+                param("username", "{{username}}".to_string()),
+                param("password", "{{password}}".to_string()),
+            ],
+        },
+        ("apiKey", _) => HttpAuth {
+            auth_type: "apikey".to_string(),
+            params: vec![
+                param("key", text(scheme, "name").unwrap_or_default()),
+                param("value", "{{apiKey}}".to_string()),
+                param(
+                    "in",
+                    text(scheme, "in").unwrap_or_else(|| "header".to_string()),
+                ),
+            ],
+        },
+        ("oauth2" | "openIdConnect", _) => HttpAuth {
+            auth_type: "oauth2".to_string(),
+            params: vec![param("accessToken", "{{accessToken}}".to_string())],
+        },
+        _ => return None,
+    };
+    Some(auth)
+}
+
+fn openapi_request(
+    document: &Value,
+    path: &str,
+    method: &str,
+    operation: &Value,
+    path_parameters: &[Value],
+) -> SavedHttpRequest {
+    let name = text(operation, "summary")
+        .or_else(|| text(operation, "operationId"))
+        .unwrap_or_else(|| format!("{} {}", method.to_uppercase(), path));
+
+    // {id} 转换为 Postman 路径变量 :id
+    let url_path = path
+        .split('/')
+        .map(|segment| {
+            if segment.starts_with('{') && segment.ends_with('}') {
+                format!(":{}", &segment[1..segment.len() - 1])
+            } else {
+                segment.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/");
+
+    let mut headers = Vec::new();
+    let mut query = Vec::new();
+    let mut form_fields = Vec::new();
+    let mut body = None;
+
+    let parameters = operation
+        .get("parameters")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .chain(path_parameters.iter());
+    for parameter in parameters {
+        let parameter = resolve_ref(document, parameter);
+        let Some(name) = text(parameter, "name") else {
+            continue;
+        };
+        let example = parameter
+            .get("example")
+            .cloned()
+            .or_else(|| {
+                parameter
+                    .get("schema")
+                    .map(|s| schema_example(document, s, 0))
+            })
+            .or_else(|| parameter.get("default").cloned())
+            .map(|v| value_to_string(&v))
+            .unwrap_or_default();
+        let required = parameter
+            .get("required")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+        let entry = KeyValue {
+            key: name,
+            value: example,
+            enabled: required,
+        };
+        match text(parameter, "in").as_deref() {
+            Some("query") if !query.iter().any(|q: &KeyValue| q.key == entry.key) => {
+                query.push(entry)
+            }
+            Some("header") if !headers.iter().any(|h: &KeyValue| h.key == entry.key) => {
+                headers.push(entry)
+            }
+            Some("formData") => form_fields.push(KeyValue {
+                enabled: true,
+                ..entry
+            }),
+            // Swagger 2.0 请求体
+            Some("body") => {
+                let example = parameter
+                    .get("schema")
+                    .map(|s| schema_example(document, s, 0))
+                    .unwrap_or(Value::Null);
+                body = Some(HttpBody {
+                    mode: "raw".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    raw: serde_json::to_string_pretty(&example).ok(),
+                    fields: Vec::new(),
+                });
+            }
+            _ => {}
+        }
+    }
+    if !form_fields.is_empty() {
+        body = Some(HttpBody {
+            mode: "urlencoded".to_string(),
+            content_type: Some("application/x-www-form-urlencoded".to_string()),
+            raw: None,
+            fields: form_fields,
+        });
+    }
+
+    if let Some(content) = operation
+        .get("requestBody")
+        .map(|b| resolve_ref(document, b))
+        .and_then(|b| b.get("content"))
+        .and_then(Value::as_object)
+    {
+        let preferred = content
+            .keys()
+            .find(|ct| ct.contains("json"))
+            .or_else(|| content.keys().next());
+        if let Some(content_type) = preferred {
+            let media = &content[content_type];
+            let example = media
+                .get("example")
+                .cloned()
+                .or_else(|| {
+                    media
+                        .get("examples")
+                        .and_then(Value::as_object)
+                        .and_then(|examples| examples.values().next())
+                        .map(|e| resolve_ref(document, e))
+                        .and_then(|e| e.get("value").cloned())
+                })
+                .or_else(|| media.get("schema").map(|s| schema_example(document, s, 0)))
+                .unwrap_or(Value::Null);
+
+            body = Some(
+                if content_type == "application/x-www-form-urlencoded"
+                    || content_type == "multipart/form-data"
+                {
+                    let fields = example
+                        .as_object()
+                        .map(|obj| {
+                            obj.iter()
+                                .map(|(key, value)| KeyValue {
+                                    key: key.clone(),
+                                    value: value_to_string(value),
+                                    enabled: true,
+                                })
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    HttpBody {
+                        mode: if content_type == "multipart/form-data" {
+                            "formdata".to_string()
+                        } else {
+                            "urlencoded".to_string()
+                        },
+                        content_type: Some(content_type.clone()),
+                        raw: None,
+                        fields,
+                    }
+                } else {
+                    HttpBody {
+                        mode: "raw".to_string(),
+                        content_type: Some(content_type.clone()),
+                        raw: match &example {
+                            Value::String(s) => Some(s.clone()),
+                            other => serde_json::to_string_pretty(other).ok(),
+                        },
+                        fields: Vec::new(),
+                    }
+                },
+            );
+        }
+    }
+
+    if let Some(content_type) = body.as_ref().and_then(|b| b.content_type.clone()) {
+        if !headers
+            .iter()
+            .any(|h| h.key.eq_ignore_ascii_case("content-type"))
+        {
+            headers.push(KeyValue {
+                key: "Content-Type".to_string(),
+                value: content_type,
+                enabled: true,
+            });
+        }
+    }
+
+    SavedHttpRequest {
+        name,
+        method: method.to_uppercase(),
+        url: format!("{{{{baseUrl}}}}{}", url_path),
+        headers,
+        query,
+        body,
+        auth: operation
+            .get("security")
+            .and_then(|security| openapi_auth(document, security)),
+        description: text(operation, "description"),
+    }
+}
+
+/// 解析 OpenAPI 3.x / Swagger 2.0 文档，按第一个 tag 分组
+pub fn import_openapi(document: &Value) -> Result<HttpCollection, String> {
+    let paths = document
+        .get("paths")
+        .and_then(Value::as_object)
+        .ok_or_else(|| "OpenAPI 文档缺少 paths 字段".to_string())?;
+    let info = document.get("info").cloned().unwrap_or(Value::Null);
+
+    let mut folders: Vec<HttpFolder> = Vec::new();
+    let mut root_items = Vec::new();
+
+    for (path, path_item) in paths {
+        let path_item = resolve_ref(document, path_item);
+        let path_parameters: Vec<Value> = path_item
+            .get("parameters")
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default();
+
+        for method in HTTP_METHODS {
+            let Some(operation) = path_item.get(method) else {
+                continue;
+            };
+            let request = openapi_request(document, path, method, operation, &path_parameters);
+            let tag = operation
+                .get("tags")
+                .and_then(Value::as_array)
+                .and_then(|tags| tags.first())
+                .and_then(Value::as_str);
+            match tag {
+                Some(tag) => {
+                    let index = match folders.iter().position(|f| f.name == tag) {
+                        Some(index) => index,
+                        None => {
+                            let description = document
+                                .get("tags")
+                                .and_then(Value::as_array)
+                                .and_then(|tags| {
+                                    tags.iter().find(|t| {
+                                        t.get("name").and_then(Value::as_str) == Some(tag)
+                                    })
+                                })
+                                .and_then(|t| text(t, "description"));
+                            folders.push(HttpFolder {
+                                name: tag.to_string(),
+                                description,
+                                auth: None,
+                                items: Vec::new(),
+                            });
+                            folders.len() - 1
+                        }
+                    };
+                    folders[index]
+                        .items
+                        .push(HttpCollectionItem::Request(request));
+                }
+                None => root_items.push(HttpCollectionItem::Request(request)),
+            }
+        }
+    }
+
+    let mut items: Vec<HttpCollectionItem> = folders
+        .into_iter()
+        .map(HttpCollectionItem::Folder)
+        .collect();
+    items.extend(root_items);
+
+    let mut variables = vec![KeyValue {
+        key: "baseUrl".to_string(),
+        value: openapi_base_url(document),
+        enabled: true,
+    }];
+    let auth = document
+        .get("security")
+        .and_then(|security| openapi_auth(document, security));
+    // 为认证占位符生成集合变量
+    let mut placeholders = Vec::new();
+    collect_auth_placeholders(auth.as_ref(), &items, &mut placeholders);
+    for placeholder in placeholders {
+        variables.push(KeyValue {
+            key: placeholder,
+            value: String::new(),
+            enabled: true,
+        });
+    }
+
+    Ok(HttpCollection {
+        id: String::new(),
+        name: text(&info, "title").unwrap_or_else(|| "OpenAPI".to_string()),
+        description: text(&info, "description"),
+        source: if document.get("swagger").is_some() {
+            "swagger".to_string()
+        } else {
+            "openapi".to_string()
+        },
+        variables,
+        auth,
+        items,
+    })
+}
+
+fn collect_auth_placeholders(
+    auth: Option<&HttpAuth>,
+    items: &[HttpCollectionItem],
+    placeholders: &mut Vec<String>,
+) {
+    if let Some(auth) = auth {
+        for param in &auth.params {
+            if let Some(name) = param
+                .value
+                .strip_prefix("{{")
+                .and_then(|v| v.strip_suffix("}}"))
+            {
+                if !placeholders.iter().any(|p| p == name) {
+                    placeholders.push(name.to_string());
+                }
+            }
+        }
+    }
+    for item in items {
+        match item {
+            HttpCollectionItem::Folder(folder) => {
+                collect_auth_placeholders(folder.auth.as_ref(), &folder.items, placeholders)
+            }
+            HttpCollectionItem::Request(request) => {
+                collect_auth_placeholders(request.auth.as_ref(), &[], placeholders)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_postman_round_trip() {
+        let document = json!({
+            "info": { "name": "Demo", "schema": POSTMAN_SCHEMA_V21 },
+            "variable": [{ "key": "host", "value": "https://api.example.com" }],
+            "auth": { "type": "bearer", "bearer": [{ "key": "token", "value": "{{token}}", "type": "string" }] },
+            "item": [{
+                "name": "Users",
+                "item": [{
+                    "name": "Create user",
+                    "request": {
+                        "method": "POST",
+                        "header": [{ "key": "X-Trace", "value": "1", "disabled": true }],
+                        "url": {
+                            "raw": "{{host}}/users?verbose=true",
+                            "query": [{ "key": "verbose", "value": "true" }]
+                        },
+                        "body": { "mode": "raw", "raw": "{\"name\":\"a\"}", "options": { "raw": { "language": "json" } } }
+                    }
+                }]
+            }]
+        });
+
+        let collection = import_postman(&document).unwrap();
+        assert_eq!(collection.variables[0].key, "host");
+        assert_eq!(collection.auth.as_ref().unwrap().auth_type, "bearer");
+        let HttpCollectionItem::Folder(folder) = &collection.items[0] else {
+            panic!("expected folder");
+        };
+        let HttpCollectionItem::Request(request) = &folder.items[0] else {
+            panic!("expected request");
+        };
+        assert_eq!(request.url, "{{host}}/users");
+        assert!(!request.headers[0].enabled);
+        assert_eq!(
+            request.body.as_ref().unwrap().content_type.as_deref(),
+            Some("application/json")
+        );
+
+        let reimported = import_postman(&export_postman(&collection)).unwrap();
+        assert_eq!(reimported, collection);
+    }
+
+    #[test]
+    fn test_import_openapi() {
+        let yaml = r#"
+openapi: 3.0.0
+info:
+  title: Pet Store
+servers:
+  - url: https://{env}.example.com/v1
+    variables:
+      env:
+        default: api
+security:
+  - token: []
+components:
+  securitySchemes:
+    token:
+      type: http
+      scheme: bearer
+  schemas:
+    Pet:
+      type: object
+      properties:
+        name: { type: string, example: kitty }
+        age: { type: integer }
+paths:
+  /pets/{petId}:
+    put:
+      tags: [pets]
+      summary: Update pet
+      parameters:
+        - { name: petId, in: path, required: true, schema: { type: string } }
+        - { name: dryRun, in: query, schema: { type: boolean } }
+      requestBody:
+        content:
+          application/json:
+            schema: { $ref: '#/components/schemas/Pet' }
+  /health:
+    get:
+      security: []
+"#;
+        let document = parse_document(yaml).unwrap();
+        assert_eq!(detect_format(&document).unwrap(), "openapi");
+        let collection = import_openapi(&document).unwrap();
+
+        assert_eq!(collection.variables[0].value, "https://api.example.com/v1");
+        assert!(collection.variables.iter().any(|v| v.key == "bearerToken"));
+        let HttpCollectionItem::Folder(folder) = &collection.items[0] else {
+            panic!("expected folder");
+        };
+        let HttpCollectionItem::Request(request) = &folder.items[0] else {
+            panic!("expected request");
+        };
+        assert_eq!(request.method, "PUT");
+        assert_eq!(request.url, "{{baseUrl}}/pets/:petId");
+        assert_eq!(request.query[0].key, "dryRun");
+        let body: Value =
+            serde_json::from_str(request.body.as_ref().unwrap().raw.as_ref().unwrap()).unwrap();
+        assert_eq!(body, json!({ "name": "kitty", "age": 0 }));
+
+        let HttpCollectionItem::Request(health) = &collection.items[1] else {
+            panic!("expected request");
+        };
+        assert_eq!(health.auth.as_ref().unwrap().auth_type, "noauth");
+    }
+}
@@ -0,0 +1,529 @@
+use chrono::{DateTime, FixedOffset, Local, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// ULID 使用的 Crockford Base32 字母表
+const CROCKFORD: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+/// UUIDv1/v6 时间戳起点（1582-10-15）与 Unix 纪元之间的 100ns 间隔数
+const GREGORIAN_OFFSET: u64 = 0x01B2_1DD2_1381_4000;
+/// 直方图最多保留的桶数量
+const MAX_BUCKETS: i64 = 500;
+const MAX_GAPS: usize = 5;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdTimestampAuditRequest {
+    pub ids: Vec<String>,                 // 每项可包含多个以空白或逗号分隔的 ID
+    pub id_type: Option<String>,          // auto（默认）/ snowflake / ulid / objectid / uuid
+    pub snowflake_preset: Option<String>, // twitter（默认）/ discord / instagram / custom
+    pub snowflake_epoch_ms: Option<i64>,  // custom 时的纪元（毫秒）
+    pub snowflake_shift: Option<u32>,     // custom 时时间戳左移位数，默认 22
+    pub bucket: Option<String>,           // auto（默认）/ second / minute / hour / day
+    pub timezone: Option<String>,         // IANA 时区，默认本地时区
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdTimestamp {
+    pub input: String,
+    pub id_type: Option<String>,
+    pub timestamp_ms: Option<i64>,
+    pub datetime: Option<String>,
+    pub details: BTreeMap<String, String>, // 机器号、序列号等附加字段
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistogramBucket {
+    pub start: String,
+    pub start_ms: i64,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimestampGap {
+    pub from_id: String,
+    pub to_id: String,
+    pub from: String,
+    pub to: String,
+    pub gap_ms: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdTimestampAudit {
+    pub items: Vec<IdTimestamp>,
+    pub valid_count: usize,
+    pub invalid_count: usize,
+    pub earliest: Option<String>,
+    pub latest: Option<String>,
+    pub span_ms: Option<i64>,
+    pub bucket: String,
+    pub bucket_ms: i64,
+    pub histogram: Vec<HistogramBucket>,
+    pub largest_gaps: Vec<TimestampGap>,
+}
+
+/// 解析 ID 中内嵌的时间戳，并生成时间分布
+#[tauri::command]
+pub async fn audit_id_timestamps(
+    request: IdTimestampAuditRequest,
+) -> Result<IdTimestampAudit, String> {
+    let timezone = parse_timezone(request.timezone.as_deref())?;
+    let snowflake = SnowflakeLayout::from_request(&request)?;
+    let id_type = request.id_type.as_deref().unwrap_or("auto").to_lowercase();
+
+    let inputs: Vec<&str> = request
+        .ids
+        .iter()
+        .flat_map(|line| line.split(|c: char| c.is_whitespace() || c == ','))
+        .map(|s| s.trim_matches(|c| c == '"' || c == '\''))
+        .filter(|s| !s.is_empty())
+        .collect();
+    if inputs.is_empty() {
+        return Err("请输入至少一个 ID".to_string());
+    }
+
+    let items: Vec<IdTimestamp> = inputs
+        .iter()
+        .map(|input| {
+            let parsed = match id_type.as_str() {
+                "auto" => decode_auto(input, &snowflake),
+                "snowflake" => decode_snowflake(input, &snowflake),
+                "ulid" => decode_ulid(input),
+                "objectid" => decode_object_id(input),
+                "uuid" => decode_uuid(input),
+                other => Err(format!("不支持的 ID 类型: {}", other)),
+            };
+            match parsed {
+                Ok(decoded) => IdTimestamp {
+                    input: input.to_string(),
+                    id_type: Some(decoded.id_type.to_string()),
+                    timestamp_ms: Some(decoded.timestamp_ms),
+                    datetime: Some(format_ms(decoded.timestamp_ms, &timezone)),
+                    details: decoded.details,
+                    error: None,
+                },
+                Err(e) => IdTimestamp {
+                    input: input.to_string(),
+                    id_type: None,
+                    timestamp_ms: None,
+                    datetime: None,
+                    details: BTreeMap::new(),
+                    error: Some(e),
+                },
+            }
+        })
+        .collect();
+
+    let mut timeline: Vec<(i64, &str)> = items
+        .iter()
+        .filter_map(|item| item.timestamp_ms.map(|ms| (ms, item.input.as_str())))
+        .collect();
+    timeline.sort();
+
+    let (earliest, latest) = match (timeline.first(), timeline.last()) {
+        (Some(first), Some(last)) => (Some(first.0), Some(last.0)),
+        _ => (None, None),
+    };
+    let span_ms = earliest.zip(latest).map(|(a, b)| b - a);
+    let bucket = choose_bucket(request.bucket.as_deref(), span_ms.unwrap_or(0))?;
+    // 跨度极大时（如混入异常 ID）按天的整数倍合并
+    let bucket_ms = bucket.millis() * (span_ms.unwrap_or(0) / bucket.millis() / MAX_BUCKETS + 1);
+    let histogram = build_histogram(&timeline, bucket_ms, &timezone);
+
+    let mut largest_gaps: Vec<TimestampGap> = timeline
+        .windows(2)
+        .filter(|pair| pair[1].0 > pair[0].0)
+        .map(|pair| TimestampGap {
+            from_id: pair[0].1.to_string(),
+            to_id: pair[1].1.to_string(),
+            from: format_ms(pair[0].0, &timezone),
+            to: format_ms(pair[1].0, &timezone),
+            gap_ms: pair[1].0 - pair[0].0,
+        })
+        .collect();
+    largest_gaps.sort_by_key(|gap| std::cmp::Reverse(gap.gap_ms));
+    largest_gaps.truncate(MAX_GAPS);
+
+    let valid_count = timeline.len();
+    Ok(IdTimestampAudit {
+        invalid_count: items.len() - valid_count,
+        valid_count,
+        earliest: earliest.map(|ms| format_ms(ms, &timezone)),
+        latest: latest.map(|ms| format_ms(ms, &timezone)),
+        span_ms,
+        bucket: bucket.name().to_string(),
+        bucket_ms,
+        histogram,
+        largest_gaps,
+        items,
+    })
+}
+
+#[derive(Debug, Clone)]
+enum Zone {
+    Local,
+    Named(chrono_tz::Tz),
+}
+
+fn parse_timezone(name: Option<&str>) -> Result<Zone, String> {
+    match name.map(str::trim).filter(|n| !n.is_empty()) {
+        None => Ok(Zone::Local),
+        Some(name) if name.eq_ignore_ascii_case("local") => Ok(Zone::Local),
+        Some(name) => name
+            .parse::<chrono_tz::Tz>()
+            .map(Zone::Named)
+            .map_err(|_| format!("无效的时区: {}", name)),
+    }
+}
+
+fn to_zoned(ms: i64, zone: &Zone) -> DateTime<FixedOffset> {
+    let utc = Utc
+        .timestamp_millis_opt(ms)
+        .single()
+        .unwrap_or_else(|| DateTime::<Utc>::from_timestamp(0, 0).unwrap());
+    match zone {
+        Zone::Local => utc.with_timezone(&Local).fixed_offset(),
+        Zone::Named(tz) => utc.with_timezone(tz).fixed_offset(),
+    }
+}
+
+fn format_ms(ms: i64, zone: &Zone) -> String {
+    to_zoned(ms, zone)
+        .format("%Y-%m-%dT%H:%M:%S%.3f%:z")
+        .to_string()
+}
+
+struct DecodedId {
+    id_type: &'static str,
+    timestamp_ms: i64,
+    details: BTreeMap<String, String>,
+}
+
+/// Snowflake 布局：纪元、时间戳偏移位数及各字段划分
+struct SnowflakeLayout {
+    name: String,
+    epoch_ms: i64,
+    shift: u32,
+}
+
+impl SnowflakeLayout {
+    fn from_request(request: &IdTimestampAuditRequest) -> Result<Self, String> {
+        let preset = request
+            .snowflake_preset
+            .as_deref()
+            .unwrap_or("twitter")
+            .to_lowercase();
+        let (epoch_ms, shift) = match preset.as_str() {
+            "twitter" => (1_288_834_974_657, 22),
+            "discord" => (1_420_070_400_000, 22),
+            "instagram" => (1_314_220_021_721, 23),
+            "custom" => (
+                request
+                    .snowflake_epoch_ms
+                    .ok_or_else(|| "自定义 Snowflake 需要提供纪元".to_string())?,
+                request.snowflake_shift.unwrap_or(22),
+            ),
+            other => return Err(format!("不支持的 Snowflake 预设: {}", other)),
+        };
+        if !(1..=63).contains(&shift) {
+            return Err(format!("无效的时间戳偏移位数: {}", shift));
+        }
+        Ok(Self {
+            name: preset,
+            epoch_ms,
+            shift,
+        })
+    }
+}
+
+fn decode_auto(input: &str, snowflake: &SnowflakeLayout) -> Result<DecodedId, String> {
+    let compact = input.replace('-', "");
+    let is_hex = |s: &str| s.chars().all(|c| c.is_ascii_hexdigit());
+    if input.chars().all(|c| c.is_ascii_digit()) {
+        decode_snowflake(input, snowflake)
+    } else if input.len() == 26 {
+        decode_ulid(input)
+    } else if input.len() == 24 && is_hex(input) {
+        decode_object_id(input)
+    } else if compact.len() == 32 && is_hex(&compact) {
+        decode_uuid(input)
+    } else {
+        Err("无法识别的 ID 格式".to_string())
+    }
+}
+
+fn decode_snowflake(input: &str, layout: &SnowflakeLayout) -> Result<DecodedId, String> {
+    let id: u64 = input
+        .parse()
+        .map_err(|_| "Snowflake ID 必须是 64 位无符号整数".to_string())?;
+    let timestamp_ms = (id >> layout.shift) as i64 + layout.epoch_ms;
+
+    let mut details = BTreeMap::new();
+    details.insert("preset".to_string(), layout.name.clone());
+    match layout.name.as_str() {
+        "twitter" => {
+            details.insert("datacenter_id".to_string(), ((id >> 17) & 0x1f).to_string());
+            details.insert("worker_id".to_string(), ((id >> 12) & 0x1f).to_string());
+            details.insert("sequence".to_string(), (id & 0xfff).to_string());
+        }
+        "discord" => {
+            details.insert("worker_id".to_string(), ((id >> 17) & 0x1f).to_string());
+            details.insert("process_id".to_string(), ((id >> 12) & 0x1f).to_string());
+            details.insert("increment".to_string(), (id & 0xfff).to_string());
+        }
+        "instagram" => {
+            details.insert("shard_id".to_string(), ((id >> 10) & 0x1fff).to_string());
+            details.insert("sequence".to_string(), (id & 0x3ff).to_string());
+        }
+        _ => {
+            let low_bits = id & ((1u64 << layout.shift) - 1);
+            details.insert("low_bits".to_string(), low_bits.to_string());
+        }
+    }
+    Ok(DecodedId {
+        id_type: "snowflake",
+        timestamp_ms,
+        details,
+    })
+}
+
+fn decode_ulid(input: &str) -> Result<DecodedId, String> {
+    if input.len() != 26 {
+        return Err("ULID 长度必须为 26 个字符".to_string());
+    }
+    if !input.is_ascii() {
+        return Err("ULID 包含无效字符".to_string());
+    }
+    let mut timestamp: u64 = 0;
+    for c in input[..10].chars() {
+        let value = CROCKFORD
+            .find(c.to_ascii_uppercase())
+            .ok_or_else(|| format!("ULID 包含无效字符: {}", c))?;
+        timestamp = (timestamp << 5) | value as u64;
+    }
+    if input[10..]
+        .chars()
+        .any(|c| !CROCKFORD.contains(c.to_ascii_uppercase()))
+    {
+        return Err("ULID 包含无效字符".to_string());
+    }
+    // 首字符最大为 7，时间戳不超过 48 位
+    if timestamp >> 48 != 0 {
+        return Err("ULID 时间戳超出范围".to_string());
+    }
+
+    let mut details = BTreeMap::new();
+    details.insert("randomness".to_string(), input[10..].to_uppercase());
+    Ok(DecodedId {
+        id_type: "ulid",
+        timestamp_ms: timestamp as i64,
+        details,
+    })
+}
+
+fn decode_object_id(input: &str) -> Result<DecodedId, String> {
+    let bytes = hex::decode(input).map_err(|_| "ObjectID 必须是 24 位十六进制".to_string())?;
+    if bytes.len() != 12 {
+        return Err("ObjectID 必须是 24 位十六进制".to_string());
+    }
+    let seconds = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    let counter = u32::from_be_bytes([0, bytes[9], bytes[10], bytes[11]]);
+
+    let mut details = BTreeMap::new();
+    details.insert("random".to_string(), hex::encode(&bytes[4..9]));
+    details.insert("counter".to_string(), counter.to_string());
+    Ok(DecodedId {
+        id_type: "objectid",
+        timestamp_ms: seconds as i64 * 1000,
+        details,
+    })
+}
+
+fn decode_uuid(input: &str) -> Result<DecodedId, String> {
+    let compact: String = input.chars().filter(|c| *c != '-').collect();
+    let bytes = hex::decode(&compact).map_err(|_| "UUID 格式无效".to_string())?;
+    if bytes.len() != 16 {
+        return Err("UUID 格式无效".to_string());
+    }
+    let version = bytes[6] >> 4;
+    let mut details = BTreeMap::new();
+    details.insert("version".to_string(), version.to_string());
+
+    let timestamp_ms = match version {
+        7 => {
+            let mut ms = [0u8; 8];
+            ms[2..].copy_from_slice(&bytes[..6]);
+            u64::from_be_bytes(ms) as i64
+        }
+        1 | 6 => {
+            let ticks = if version == 1 {
+                let low = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as u64;
+                let mid = u16::from_be_bytes([bytes[4], bytes[5]]) as u64;
+                let high = (u16::from_be_bytes([bytes[6], bytes[7]]) & 0x0fff) as u64;
+                (high << 48) | (mid << 32) | low
+            } else {
+                let high = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as u64;
+                let mid = u16::from_be_bytes([bytes[4], bytes[5]]) as u64;
+                let low = (u16::from_be_bytes([bytes[6], bytes[7]]) & 0x0fff) as u64;
+                (high << 28) | (mid << 12) | low
+            };
+            details.insert("node".to_string(), hex::encode(&bytes[10..]));
+            let clock_seq = u16::from_be_bytes([bytes[8], bytes[9]]) & 0x3fff;
+            details.insert("clock_sequence".to_string(), clock_seq.to_string());
+            (ticks as i64 - GREGORIAN_OFFSET as i64) / 10_000
+        }
+        other => return Err(format!("UUIDv{} 不包含时间戳", other)),
+    };
+    Ok(DecodedId {
+        id_type: match version {
+            1 => "uuidv1",
+            6 => "uuidv6",
+            _ => "uuidv7",
+        },
+        timestamp_ms,
+        details,
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Bucket {
+    Second,
+    Minute,
+    Hour,
+    Day,
+}
+
+impl Bucket {
+    fn millis(self) -> i64 {
+        match self {
+            Bucket::Second => 1_000,
+            Bucket::Minute => 60_000,
+            Bucket::Hour => 3_600_000,
+            Bucket::Day => 86_400_000,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Bucket::Second => "second",
+            Bucket::Minute => "minute",
+            Bucket::Hour => "hour",
+            Bucket::Day => "day",
+        }
+    }
+}
+
+fn choose_bucket(name: Option<&str>, span_ms: i64) -> Result<Bucket, String> {
+    let all = [Bucket::Second, Bucket::Minute, Bucket::Hour, Bucket::Day];
+    match name.unwrap_or("auto").to_lowercase().as_str() {
+        "auto" => Ok(all
+            .into_iter()
+            .find(|b| span_ms / b.millis() < 120)
+            .unwrap_or(Bucket::Day)),
+        other => {
+            let bucket = all
+                .into_iter()
+                .find(|b| b.name() == other)
+                .ok_or_else(|| format!("不支持的时间粒度: {}", other))?;
+            // 桶过多时自动放大粒度
+            Ok(all
+                .into_iter()
+                .filter(|b| b.millis() >= bucket.millis())
+                .find(|b| span_ms / b.millis() < MAX_BUCKETS)
+                .unwrap_or(Bucket::Day))
+        }
+    }
+}
+
+/// 按时区对齐分桶，空桶同样保留以便绘制连续的时间轴
+fn build_histogram(timeline: &[(i64, &str)], size: i64, zone: &Zone) -> Vec<HistogramBucket> {
+    let (Some(first), Some(last)) = (timeline.first(), timeline.last()) else {
+        return Vec::new();
+    };
+    let offset_ms = to_zoned(first.0, zone).offset().local_minus_utc() as i64 * 1000;
+    let align = |ms: i64| (ms + offset_ms).div_euclid(size) * size - offset_ms;
+
+    let start = align(first.0);
+    let count = (align(last.0) - start) / size + 1;
+    let mut counts = vec![0usize; count as usize];
+    for (ms, _) in timeline {
+        counts[((align(*ms) - start) / size) as usize] += 1;
+    }
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(index, count)| {
+            let start_ms = start + index as i64 * size;
+            HistogramBucket {
+                start: format_ms(start_ms, zone),
+                start_ms,
+                count,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_ids() {
+        let twitter = SnowflakeLayout {
+            name: "twitter".to_string(),
+            epoch_ms: 1_288_834_974_657,
+            shift: 22,
+        };
+        let snowflake = decode_auto("1212161629282037760", &twitter).unwrap();
+        assert_eq!(snowflake.timestamp_ms, 1_577_836_827_951);
+        assert_eq!(snowflake.details["sequence"], "0");
+
+        let ulid = decode_auto("01ARYZ6S41TSV4RRFFQ69G5FAV", &twitter).unwrap();
+        assert_eq!(ulid.id_type, "ulid");
+        assert_eq!(ulid.timestamp_ms, 1_469_918_176_385);
+
+        let object_id = decode_auto("507f1f77bcf86cd799439011", &twitter).unwrap();
+        assert_eq!(object_id.timestamp_ms, 1_350_508_407_000);
+        assert_eq!(object_id.details["counter"], "4427793");
+
+        let v7 = decode_auto("017f22e2-79b0-7cc3-98c4-dc0c0c07398f", &twitter).unwrap();
+        assert_eq!(v7.id_type, "uuidv7");
+        assert_eq!(v7.timestamp_ms, 1_645_557_742_000);
+
+        let v1 = decode_auto("c232ab00-9414-11ec-b3c8-9f6bdeced846", &twitter).unwrap();
+        assert_eq!(v1.id_type, "uuidv1");
+        assert_eq!(v1.timestamp_ms, 1_645_557_742_000);
+
+        assert!(decode_auto("550e8400-e29b-41d4-a716-446655440000", &twitter).is_err());
+        // 26 字节但包含多字节字符
+        assert!(decode_auto("012345678中ABCDEFGHJKMNPQ", &twitter).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_audit_histogram() {
+        let request = IdTimestampAuditRequest {
+            ids: vec![
+                "507f1f77bcf86cd799439011, 507f1f79bcf86cd799439012".to_string(),
+                "507f1fb3bcf86cd799439013\nnot-an-id".to_string(),
+            ],
+            id_type: None,
+            snowflake_preset: None,
+            snowflake_epoch_ms: None,
+            snowflake_shift: None,
+            bucket: None,
+            timezone: Some("UTC".to_string()),
+        };
+        let audit = audit_id_timestamps(request).await.unwrap();
+        assert_eq!(audit.valid_count, 3);
+        assert_eq!(audit.invalid_count, 1);
+        assert_eq!(audit.span_ms, Some(60_000));
+        assert_eq!(audit.bucket, "second");
+        assert_eq!(
+            audit.earliest.as_deref(),
+            Some("2012-10-17T21:13:27.000+00:00")
+        );
+        assert_eq!(audit.histogram.len(), 61);
+        assert_eq!(audit.histogram[0].count, 1);
+        assert_eq!(audit.histogram[60].count, 1);
+        assert_eq!(audit.largest_gaps[0].gap_ms, 58_000);
+    }
+}
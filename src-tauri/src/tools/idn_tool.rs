@@ -0,0 +1,447 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// 常见被仿冒的品牌域名，未提供参考域名时用于比对
+const DEFAULT_REFERENCE_DOMAINS: [&str; 24] = [
+    "google.com",
+    "youtube.com",
+    "apple.com",
+    "icloud.com",
+    "microsoft.com",
+    "office.com",
+    "live.com",
+    "amazon.com",
+    "paypal.com",
+    "facebook.com",
+    "instagram.com",
+    "github.com",
+    "gitlab.com",
+    "cloudflare.com",
+    "binance.com",
+    "coinbase.com",
+    "baidu.com",
+    "qq.com",
+    "weixin.qq.com",
+    "taobao.com",
+    "alipay.com",
+    "jd.com",
+    "aliyun.com",
+    "tencent.com",
+];
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdnLabel {
+    pub ascii: String,
+    pub unicode: String,
+    pub is_punycode: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdnConversion {
+    pub input: String,
+    pub ascii: String,
+    pub unicode: String,
+    pub is_idn: bool,
+    pub labels: Vec<IdnLabel>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HomographRequest {
+    pub domain: String,
+    pub reference_domains: Option<Vec<String>>, // 需要比对的正规域名，为空时使用内置列表
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfusableChar {
+    pub label: String,
+    pub position: usize,
+    pub character: String,
+    pub code_point: String,
+    pub script: String,
+    pub looks_like: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HomographAnalysis {
+    pub domain: String,
+    pub ascii: String,
+    pub unicode: String,
+    pub is_idn: bool,
+    pub scripts: Vec<String>,
+    pub mixed_script_labels: Vec<String>,
+    pub whole_script_confusable_labels: Vec<String>,
+    pub confusables: Vec<ConfusableChar>,
+    pub skeleton: String,
+    pub matched_references: Vec<String>,
+    pub risk_score: u32,
+    pub risk_level: String, // low / medium / high
+    pub findings: Vec<String>,
+}
+
+/// Unicode 与 Punycode（xn--）域名互转
+#[tauri::command]
+pub async fn convert_punycode(input: String) -> Result<IdnConversion, String> {
+    to_idn_conversion(&input)
+}
+
+fn to_idn_conversion(input: &str) -> Result<IdnConversion, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err("域名不能为空".to_string());
+    }
+    let domain = strip_to_host(input);
+    let ascii = idna::domain_to_ascii(&domain).map_err(|e| format!("域名转换失败: {}", e))?;
+    let (unicode, result) = idna::domain_to_unicode(&ascii);
+    result.map_err(|e| format!("Punycode 解码失败: {}", e))?;
+
+    let labels: Vec<IdnLabel> = ascii
+        .split('.')
+        .zip(unicode.split('.'))
+        .map(|(a, u)| IdnLabel {
+            ascii: a.to_string(),
+            unicode: u.to_string(),
+            is_punycode: a.starts_with("xn--"),
+        })
+        .collect();
+
+    Ok(IdnConversion {
+        input: input.to_string(),
+        is_idn: labels.iter().any(|l| l.is_punycode),
+        ascii,
+        unicode,
+        labels,
+    })
+}
+
+/// 分析域名中的混合脚本与形近字符，识别可能的同形异义（homograph）仿冒
+#[tauri::command]
+pub async fn analyze_homograph(request: HomographRequest) -> Result<HomographAnalysis, String> {
+    let conversion = to_idn_conversion(&request.domain)?;
+    let references: Vec<String> = match request.reference_domains {
+        Some(list) if !list.is_empty() => list
+            .iter()
+            .map(|d| d.trim().to_lowercase())
+            .filter(|d| !d.is_empty())
+            .collect(),
+        _ => DEFAULT_REFERENCE_DOMAINS
+            .iter()
+            .map(|d| d.to_string())
+            .collect(),
+    };
+    Ok(analyze_domain(conversion, &references))
+}
+
+fn analyze_domain(conversion: IdnConversion, references: &[String]) -> HomographAnalysis {
+    let mut scripts = BTreeSet::new();
+    let mut mixed_script_labels = Vec::new();
+    let mut whole_script_labels = Vec::new();
+    let mut confusables = Vec::new();
+    let mut findings = Vec::new();
+
+    for label in &conversion.labels {
+        let mut label_scripts = BTreeSet::new();
+        let mut confusable_count = 0;
+        let mut letter_count = 0;
+
+        for (position, c) in label.unicode.chars().enumerate() {
+            let script = char_script(c);
+            if script != "Common" {
+                label_scripts.insert(script);
+                scripts.insert(script);
+                letter_count += 1;
+            }
+            if let Some(looks_like) = confusable_latin(c) {
+                confusable_count += 1;
+                confusables.push(ConfusableChar {
+                    label: label.unicode.clone(),
+                    position,
+                    character: c.to_string(),
+                    code_point: format!("U+{:04X}", c as u32),
+                    script: script.to_string(),
+                    looks_like: looks_like.to_string(),
+                });
+            }
+        }
+
+        if !is_allowed_script_mix(&label_scripts) {
+            mixed_script_labels.push(label.unicode.clone());
+            findings.push(format!(
+                "标签 {} 混用了多种文字: {}",
+                label.unicode,
+                label_scripts.iter().cloned().collect::<Vec<_>>().join(", ")
+            ));
+        }
+        // 整个标签由非拉丁字母组成，且全部能伪装成拉丁字母
+        if letter_count > 0 && confusable_count == letter_count && !label_scripts.contains("Latin")
+        {
+            whole_script_labels.push(label.unicode.clone());
+            findings.push(format!(
+                "标签 {} 全部由形似拉丁字母的 {} 字符组成",
+                label.unicode,
+                label_scripts.iter().cloned().collect::<Vec<_>>().join(", ")
+            ));
+        }
+    }
+
+    let visual_skeleton = skeleton(&conversion.unicode);
+    let matched_references: Vec<String> = references
+        .iter()
+        .filter(|r| {
+            let r_unicode = idna::domain_to_unicode(r).0;
+            r_unicode != conversion.unicode && skeleton(&r_unicode) == visual_skeleton
+        })
+        .cloned()
+        .collect();
+    for reference in &matched_references {
+        findings.push(format!("域名在视觉上与 {} 相同", reference));
+    }
+    if !confusables.is_empty() && matched_references.is_empty() {
+        findings.push(format!(
+            "包含 {} 个形近字符，视觉骨架为 {}",
+            confusables.len(),
+            visual_skeleton
+        ));
+    }
+
+    let mut risk_score = 0u32;
+    if conversion.is_idn {
+        risk_score += 10;
+    }
+    if !mixed_script_labels.is_empty() {
+        risk_score += 35;
+    }
+    if !whole_script_labels.is_empty() {
+        risk_score += 35;
+    }
+    risk_score += (confusables.len() as u32 * 5).min(20);
+    if !matched_references.is_empty() {
+        risk_score += 40;
+    }
+    let risk_score = risk_score.min(100);
+    let risk_level = match risk_score {
+        0..=24 => "low",
+        25..=59 => "medium",
+        _ => "high",
+    }
+    .to_string();
+
+    HomographAnalysis {
+        domain: conversion.input,
+        ascii: conversion.ascii,
+        unicode: conversion.unicode,
+        is_idn: conversion.is_idn,
+        scripts: scripts.into_iter().map(|s| s.to_string()).collect(),
+        mixed_script_labels,
+        whole_script_confusable_labels: whole_script_labels,
+        confusables,
+        skeleton: visual_skeleton,
+        matched_references,
+        risk_score,
+        risk_level,
+        findings,
+    }
+}
+
+/// 允许 URL 形式输入，只保留主机名部分
+fn strip_to_host(input: &str) -> String {
+    let without_scheme = input.split("://").last().unwrap_or(input);
+    let host = without_scheme
+        .split(['/', '?', '#'])
+        .next()
+        .unwrap_or(without_scheme);
+    let host = host.rsplit('@').next().unwrap_or(host);
+    let host = host.split(':').next().unwrap_or(host);
+    host.trim_end_matches('.').to_string()
+}
+
+/// 同一标签内允许组合的文字（参考 UTS #39 Highly Restrictive）
+fn is_allowed_script_mix(scripts: &BTreeSet<&str>) -> bool {
+    if scripts.len() <= 1 {
+        return true;
+    }
+    let allowed_sets: [&[&str]; 3] = [
+        &["Latin", "Han", "Hiragana", "Katakana"],
+        &["Latin", "Han", "Bopomofo"],
+        &["Latin", "Han", "Hangul"],
+    ];
+    allowed_sets
+        .iter()
+        .any(|set| scripts.iter().all(|s| set.contains(s)))
+}
+
+fn char_script(c: char) -> &'static str {
+    match c as u32 {
+        0x30..=0x39 | 0x2D | 0x5F | 0x2E => "Common",
+        0x41..=0x5A | 0x61..=0x7A | 0xC0..=0x24F | 0x1E00..=0x1EFF | 0x250..=0x2AF => "Latin",
+        0xFF21..=0xFF3A | 0xFF41..=0xFF5A => "Latin",
+        0x300..=0x36F => "Common",
+        0x370..=0x3FF | 0x1F00..=0x1FFF => "Greek",
+        0x400..=0x52F | 0x2DE0..=0x2DFF | 0xA640..=0xA69F => "Cyrillic",
+        0x530..=0x58F => "Armenian",
+        0x590..=0x5FF => "Hebrew",
+        0x600..=0x6FF | 0x750..=0x77F => "Arabic",
+        0x900..=0x97F => "Devanagari",
+        0xE00..=0xE7F => "Thai",
+        0x10A0..=0x10FF => "Georgian",
+        0x13A0..=0x13FF => "Cherokee",
+        0x3040..=0x309F => "Hiragana",
+        0x30A0..=0x30FF | 0x31F0..=0x31FF => "Katakana",
+        0x3100..=0x312F => "Bopomofo",
+        0x1100..=0x11FF | 0x3130..=0x318F | 0xAC00..=0xD7AF => "Hangul",
+        0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xF900..=0xFAFF => "Han",
+        0x20000..=0x2FFFF => "Han",
+        _ => "Other",
+    }
+}
+
+/// 与拉丁字母或数字形近的字符（取自 Unicode confusables.txt 的常见子集）
+fn confusable_latin(c: char) -> Option<&'static str> {
+    let mapped = match c {
+        // Cyrillic
+        'а' => "a",
+        'с' => "c",
+        'ԁ' => "d",
+        'е' | 'ё' => "e",
+        'һ' => "h",
+        'і' => "i",
+        'ї' => "i",
+        'ј' => "j",
+        'к' => "k",
+        'ӏ' => "l",
+        'о' => "o",
+        'р' => "p",
+        'ԛ' => "q",
+        'ѕ' => "s",
+        'џ' => "u",
+        'ѵ' => "v",
+        'ԝ' => "w",
+        'х' => "x",
+        'у' => "y",
+        'ү' => "y",
+        'ʒ' | 'з' => "3",
+        // Greek
+        'α' => "a",
+        'β' => "b",
+        'ε' => "e",
+        'η' => "n",
+        'ι' => "i",
+        'κ' => "k",
+        'ν' => "v",
+        'ο' => "o",
+        'ρ' => "p",
+        'τ' => "t",
+        'υ' => "u",
+        'χ' => "x",
+        'γ' => "y",
+        'ω' => "w",
+        // Armenian / Cherokee
+        'օ' => "o",
+        'ս' => "u",
+        'ց' => "g",
+        'հ' => "h",
+        'ꮪ' => "s",
+        // Latin lookalikes
+        'ı' => "i",
+        'ɑ' => "a",
+        'ɡ' => "g",
+        'ɩ' => "i",
+        'ʟ' => "l",
+        'ǀ' => "l",
+        'ℓ' => "l",
+        'ạ' | 'á' | 'à' | 'ä' | 'â' | 'ã' | 'å' => "a",
+        'é' | 'è' | 'ê' | 'ë' | 'ẹ' => "e",
+        'í' | 'ì' | 'î' | 'ï' | 'ị' => "i",
+        'ó' | 'ò' | 'ô' | 'ö' | 'õ' | 'ọ' | 'ø' => "o",
+        'ú' | 'ù' | 'û' | 'ü' | 'ụ' => "u",
+        'ç' => "c",
+        'ñ' => "n",
+        'ý' | 'ÿ' => "y",
+        _ => {
+            // 全角字母与数字
+            let code = c as u32;
+            return match code {
+                0xFF10..=0xFF19 | 0xFF21..=0xFF3A | 0xFF41..=0xFF5A => {
+                    FULLWIDTH.get((code - 0xFF10) as usize).copied()
+                }
+                _ => None,
+            };
+        }
+    };
+    Some(mapped)
+}
+
+/// 全角字符 U+FF10 起对应的半角字符
+const FULLWIDTH: [&str; 75] = [
+    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", ":", ";", "<", "=", ">", "?", "@", "a", "b",
+    "c", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m", "n", "o", "p", "q", "r", "s", "t", "u",
+    "v", "w", "x", "y", "z", "[", "\\", "]", "^", "_", "`", "a", "b", "c", "d", "e", "f", "g", "h",
+    "i", "j", "k", "l", "m", "n", "o", "p", "q", "r", "s", "t", "u", "v", "w", "x", "y", "z",
+];
+
+/// 计算视觉骨架：形近字符替换为拉丁字母，再折叠易混淆的字母组合
+fn skeleton(domain: &str) -> String {
+    let mapped: String = domain
+        .to_lowercase()
+        .chars()
+        .map(|c| match confusable_latin(c) {
+            Some(s) => s.to_string(),
+            None => c.to_string(),
+        })
+        .collect();
+    mapped
+        .replace("rn", "m")
+        .replace("vv", "w")
+        .replace('0', "o")
+        .replace(['1', 'i'], "l")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(domain: &str) -> HomographAnalysis {
+        let conversion = to_idn_conversion(domain).unwrap();
+        let references: Vec<String> = DEFAULT_REFERENCE_DOMAINS
+            .iter()
+            .map(|d| d.to_string())
+            .collect();
+        analyze_domain(conversion, &references)
+    }
+
+    #[test]
+    fn test_convert_idn_roundtrip() {
+        let conversion = to_idn_conversion("https://中文.例子.com/path").unwrap();
+        assert_eq!(conversion.ascii, "xn--fiq228c.xn--fsqu00a.com");
+        assert_eq!(conversion.unicode, "中文.例子.com");
+        assert!(conversion.is_idn);
+
+        let back = to_idn_conversion("xn--fiq228c.xn--fsqu00a.com").unwrap();
+        assert_eq!(back.unicode, "中文.例子.com");
+        assert!(!back.labels[2].is_punycode);
+    }
+
+    #[test]
+    fn test_detect_homograph_spoofing() {
+        // Cyrillic "а" in "аpple.com"
+        let mixed = analyze("xn--pple-43d.com");
+        assert_eq!(mixed.unicode, "аpple.com");
+        assert_eq!(mixed.mixed_script_labels, vec!["аpple"]);
+        assert_eq!(mixed.matched_references, vec!["apple.com"]);
+        assert_eq!(mixed.risk_level, "high");
+
+        // 整个标签均为西里尔字母
+        let whole = analyze("аррӏе.com");
+        assert_eq!(whole.whole_script_confusable_labels, vec!["аррӏе"]);
+        assert!(whole.matched_references.contains(&"apple.com".to_string()));
+
+        let clean = analyze("example.com");
+        assert_eq!(clean.risk_level, "low");
+        assert!(clean.confusables.is_empty());
+        assert!(analyze("中文.com").mixed_script_labels.is_empty());
+    }
+}
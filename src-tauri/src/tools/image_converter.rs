@@ -1,9 +1,11 @@
+use crate::utils::progress::ProgressReporter;
 use image::{self, DynamicImage, ImageFormat};
 use libheif_sys::*;
 use nom_exif::{EntryValue, ExifIter, MediaParser, MediaSource};
 use serde::{Deserialize, Serialize};
 use std::fs::File;
 use std::path::{Path, PathBuf};
+use tauri::AppHandle;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImageConversionRequest {
@@ -27,6 +29,13 @@ pub struct ImageConversionResponse {
     pub compression_ratio: Option<f64>, // 压缩比例
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchImageConversionItem {
+    pub input_path: String,
+    pub response: Option<ImageConversionResponse>,
+    pub error: Option<String>, // 单个文件失败不影响其余文件
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImageInfo {
     pub name: String,
@@ -581,7 +590,54 @@ pub async fn convert_image(
 ) -> Result<ImageConversionResponse, String> {
     // 检查 image 库支持
     check_image_library_support()?;
+    convert_single_image(&request)
+}
+
+/// Tauri 命令：批量转换图片，通过 task-progress 事件上报每个文件的进度
+#[tauri::command]
+pub async fn convert_images(
+    app: AppHandle,
+    requests: Vec<ImageConversionRequest>,
+) -> Result<Vec<BatchImageConversionItem>, String> {
+    check_image_library_support()?;
+    let mut reporter = ProgressReporter::new(&app, "image_converter");
+    tokio::task::spawn_blocking(move || {
+        let total = requests.len() as u64;
+        let mut items = Vec::with_capacity(requests.len());
+        let mut failed = 0;
+        for (index, request) in requests.iter().enumerate() {
+            let (response, error) = match convert_single_image(request) {
+                Ok(response) => (Some(response), None),
+                Err(e) => {
+                    failed += 1;
+                    (None, Some(e))
+                }
+            };
+            items.push(BatchImageConversionItem {
+                input_path: request.input_path.clone(),
+                response,
+                error,
+            });
+            reporter.update(
+                index as u64 + 1,
+                Some(total),
+                Some(request.input_path.clone()),
+            );
+        }
+        reporter.finish(Some(format!(
+            "完成 {} 个，失败 {} 个",
+            items.len() - failed,
+            failed
+        )));
+        items
+    })
+    .await
+    .map_err(|e| format!("批量转换任务失败: {}", e))
+}
 
+fn convert_single_image(
+    request: &ImageConversionRequest,
+) -> Result<ImageConversionResponse, String> {
     // 验证输入文件
     let input_path = validate_input_file(&request.input_path)?;
 
@@ -595,7 +651,7 @@ pub async fn convert_image(
     let (original_size, converted_size) = convert_image_with_image_library(
         &input_path,
         &PathBuf::from(&request.output_path),
-        &request,
+        request,
     )?;
 
     // 计算压缩比例
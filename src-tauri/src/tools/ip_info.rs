@@ -1,4 +1,10 @@
+use crate::utils::dns::DnsClient;
+use crate::utils::{proxy, rate_limit};
+use hickory_resolver::proto::rr::RecordType;
+use hickory_resolver::TokioAsyncResolver;
 use serde::{Deserialize, Serialize};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, ToSocketAddrs, UdpSocket};
+use std::time::{Duration, Instant};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -16,6 +22,7 @@ pub struct SourceInfo {
 #[serde(rename_all = "camelCase")]
 pub struct IpLookupResponse {
     pub infos: Vec<SourceInfo>,
+    pub reputation: Option<IpReputation>, // 反向解析、ASN、黑名单与滥用联系人
     pub error: Option<String>,
 }
 
@@ -45,7 +52,9 @@ struct IpApiComRaw {
 
 #[tauri::command]
 pub async fn query_ip_info(ip: Option<String>) -> Result<IpLookupResponse, String> {
-    let client = reqwest::Client::new();
+    let client = proxy::client_builder("ip_info")
+        .build()
+        .map_err(|e| format!("构建 HTTP 客户端失败: {}", e))?;
 
     // Build endpoints
     let ipinfo_url = match ip.as_deref() {
@@ -64,7 +73,7 @@ pub async fn query_ip_info(ip: Option<String>) -> Result<IpLookupResponse, Strin
     let mut errors: Vec<String> = Vec::new();
 
     // Fetch from ipinfo.io
-    match client.get(&ipinfo_url).send().await {
+    match rate_limit::send_with_retry("ipinfo.io", || client.get(&ipinfo_url)).await {
         Ok(resp) => match resp.error_for_status() {
             Ok(ok) => match ok.json::<serde_json::Value>().await {
                 Ok(val) => {
@@ -93,7 +102,7 @@ pub async fn query_ip_info(ip: Option<String>) -> Result<IpLookupResponse, Strin
     }
 
     // Fetch from ip-api.com
-    match client.get(&ipapi_url).send().await {
+    match rate_limit::send_with_retry("ip-api.com", || client.get(&ipapi_url)).await {
         Ok(resp) => match resp.error_for_status() {
             Ok(ok) => match ok.json::<IpApiComRaw>().await {
                 Ok(raw) => {
@@ -118,11 +127,1013 @@ pub async fn query_ip_info(ip: Option<String>) -> Result<IpLookupResponse, Strin
         Err(e) => errors.push(format!("请求 ip-api.com 出错: {}", e)),
     }
 
+    // 未指定 IP 时以查询到的出口 IP 为准
+    let target = ip
+        .as_deref()
+        .and_then(|s| s.trim().parse::<IpAddr>().ok())
+        .or_else(|| {
+            infos
+                .iter()
+                .find_map(|info| info.ip.as_deref().and_then(|s| s.parse().ok()))
+        });
+    let reputation = match target {
+        Some(target) => Some(lookup_ip_reputation(&client, target).await),
+        None => None,
+    };
+
     let error = if errors.is_empty() {
         None
     } else {
         Some(errors.join("；"))
     };
 
-    Ok(IpLookupResponse { infos, error })
+    Ok(IpLookupResponse {
+        infos,
+        reputation,
+        error,
+    })
+}
+
+/// (区域, 名称, 是否支持 IPv6)
+const DNSBL_ZONES: &[(&str, &str, bool)] = &[
+    ("zen.spamhaus.org", "Spamhaus ZEN", true),
+    ("bl.spamcop.net", "SpamCop", false),
+    ("b.barracudacentral.org", "Barracuda", false),
+    ("psbl.surriel.com", "PSBL", false),
+    ("bl.mailspike.net", "Mailspike", false),
+    ("dnsbl-1.uceprotect.net", "UCEPROTECT L1", false),
+];
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DnsblResult {
+    pub zone: String,
+    pub name: String,
+    pub listed: bool,
+    pub return_codes: Vec<String>,
+    pub meaning: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AbuseContact {
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub network_name: Option<String>,
+    pub network_range: Option<String>,
+    pub whois_server: Option<String>, // RDAP port43，可判断所属 RIR
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct IpReputation {
+    pub ip: String,
+    pub ptr: Vec<String>,
+    pub forward_confirmed: Option<bool>, // PTR 域名正向解析是否指回该 IP
+    pub asn: Option<String>,
+    pub as_name: Option<String>,
+    pub prefix: Option<String>,
+    pub registry: Option<String>,
+    pub allocated: Option<String>,
+    pub country: Option<String>,
+    pub abuse: Option<AbuseContact>,
+    pub blocklists: Vec<DnsblResult>,
+    pub listed_count: usize,
+    pub errors: Vec<String>,
+}
+
+/// 反向解析用的标签序列，IPv4 为倒序八位组，IPv6 为倒序半字节
+fn reverse_labels(ip: IpAddr) -> String {
+    match ip {
+        IpAddr::V4(v4) => {
+            let o = v4.octets();
+            format!("{}.{}.{}.{}", o[3], o[2], o[1], o[0])
+        }
+        IpAddr::V6(v6) => v6
+            .octets()
+            .iter()
+            .rev()
+            .flat_map(|b| [b & 0x0f, b >> 4])
+            .map(|n| format!("{:x}", n))
+            .collect::<Vec<_>>()
+            .join("."),
+    }
+}
+
+fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => {
+            !(v4.is_private()
+                || v4.is_loopback()
+                || v4.is_link_local()
+                || v4.is_unspecified()
+                || v4.is_broadcast()
+                || v4.is_documentation()
+                || v4.octets()[0] == 100 && (v4.octets()[1] & 0xc0) == 64)
+        }
+        IpAddr::V6(v6) => {
+            let first = v6.segments()[0];
+            !(v6.is_loopback()
+                || v6.is_unspecified()
+                || (first & 0xfe00) == 0xfc00
+                || (first & 0xffc0) == 0xfe80
+                || first == 0x2001 && v6.segments()[1] == 0x0db8)
+        }
+    }
+}
+
+/// 汇总 IP 的反向解析、ASN 归属、DNSBL 与 RDAP 滥用联系人
+async fn lookup_ip_reputation(client: &reqwest::Client, ip: IpAddr) -> IpReputation {
+    let mut reputation = IpReputation {
+        ip: ip.to_string(),
+        ..Default::default()
+    };
+    if !is_public_ip(ip) {
+        reputation
+            .errors
+            .push("内网或保留地址，跳过信誉查询".to_string());
+        return reputation;
+    }
+
+    let resolver = DnsClient::system_resolver();
+    let (ptr, origin, blocklists, abuse) = tokio::join!(
+        lookup_ptr(&resolver, ip),
+        lookup_origin(&resolver, ip),
+        check_dnsbls(&resolver, ip),
+        lookup_abuse_contact(client, ip),
+    );
+
+    match ptr {
+        Ok((names, confirmed)) => {
+            reputation.ptr = names;
+            reputation.forward_confirmed = confirmed;
+        }
+        Err(e) => reputation.errors.push(e),
+    }
+    match origin {
+        Ok(origin) => {
+            reputation.asn = origin.asn;
+            reputation.as_name = origin.as_name;
+            reputation.prefix = origin.prefix;
+            reputation.registry = origin.registry;
+            reputation.allocated = origin.allocated;
+            reputation.country = origin.country;
+        }
+        Err(e) => reputation.errors.push(e),
+    }
+    match abuse {
+        Ok(abuse) => reputation.abuse = Some(abuse),
+        Err(e) => reputation.errors.push(e),
+    }
+    reputation.listed_count = blocklists.iter().filter(|b| b.listed).count();
+    reputation.blocklists = blocklists;
+    reputation
+}
+
+async fn lookup_ptr(
+    resolver: &TokioAsyncResolver,
+    ip: IpAddr,
+) -> Result<(Vec<String>, Option<bool>), String> {
+    let suffix = if ip.is_ipv4() {
+        "in-addr.arpa."
+    } else {
+        "ip6.arpa."
+    };
+    let name = format!("{}.{}", reverse_labels(ip), suffix);
+    let names: Vec<String> = DnsClient::lookup(resolver, &name, RecordType::PTR)
+        .await?
+        .into_iter()
+        .map(|n| n.trim_end_matches('.').to_string())
+        .collect();
+    let Some(first) = names.first() else {
+        return Ok((names, None));
+    };
+
+    let record_type = if ip.is_ipv4() {
+        RecordType::A
+    } else {
+        RecordType::AAAA
+    };
+    let confirmed = DnsClient::lookup(resolver, first, record_type)
+        .await
+        .ok()
+        .map(|addrs| addrs.iter().any(|a| a.parse::<IpAddr>().ok() == Some(ip)));
+    Ok((names, confirmed))
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct OriginInfo {
+    asn: Option<String>,
+    as_name: Option<String>,
+    prefix: Option<String>,
+    registry: Option<String>,
+    allocated: Option<String>,
+    country: Option<String>,
+}
+
+/// 解析 Team Cymru 的 origin TXT 记录，多条记录时取最长前缀
+fn parse_cymru_origin(records: &[String]) -> Option<OriginInfo> {
+    records
+        .iter()
+        .filter_map(|record| {
+            let fields: Vec<&str> = record.split('|').map(str::trim).collect();
+            if fields.len() < 3 {
+                return None;
+            }
+            let prefix_len = fields[1]
+                .split('/')
+                .nth(1)
+                .and_then(|l| l.parse::<u8>().ok())
+                .unwrap_or(0);
+            let field = |i: usize| {
+                fields
+                    .get(i)
+                    .filter(|v| !v.is_empty())
+                    .map(|v| v.to_string())
+            };
+            let info = OriginInfo {
+                asn: fields[0]
+                    .split_whitespace()
+                    .next()
+                    .map(|asn| format!("AS{}", asn)),
+                as_name: None,
+                prefix: field(1),
+                country: field(2),
+                registry: field(3),
+                allocated: field(4),
+            };
+            Some((prefix_len, info))
+        })
+        .max_by_key(|(prefix_len, _)| *prefix_len)
+        .map(|(_, info)| info)
+}
+
+async fn lookup_origin(resolver: &TokioAsyncResolver, ip: IpAddr) -> Result<OriginInfo, String> {
+    let zone = if ip.is_ipv4() {
+        "origin.asn.cymru.com"
+    } else {
+        "origin6.asn.cymru.com"
+    };
+    let records =
+        DnsClient::lookup_txt(resolver, &format!("{}.{}", reverse_labels(ip), zone)).await?;
+    let mut origin =
+        parse_cymru_origin(&records).ok_or_else(|| "未找到该 IP 的 ASN 归属".to_string())?;
+
+    // AS 名称记录形如 "15169 | US | arin | 2000-03-30 | GOOGLE, US"
+    if let Some(asn) = &origin.asn {
+        let records = DnsClient::lookup_txt(resolver, &format!("{}.asn.cymru.com", asn)).await?;
+        origin.as_name = records
+            .first()
+            .and_then(|r| r.split('|').nth(4))
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty());
+    }
+    Ok(origin)
+}
+
+/// 返回码含义，目前仅解释 Spamhaus ZEN
+fn dnsbl_meaning(zone: &str, code: &str) -> Option<&'static str> {
+    if zone != "zen.spamhaus.org" {
+        return None;
+    }
+    match code {
+        "127.0.0.2" => Some("SBL：已知垃圾邮件来源"),
+        "127.0.0.3" => Some("CSS：垃圾邮件发送行为"),
+        "127.0.0.4" | "127.0.0.5" | "127.0.0.6" | "127.0.0.7" => Some("XBL：被入侵主机或僵尸网络"),
+        "127.0.0.9" => Some("DROP：被劫持或恶意网段"),
+        "127.0.0.10" | "127.0.0.11" => Some("PBL：动态或不应直连发信的地址"),
+        _ => None,
+    }
+}
+
+async fn check_dnsbls(resolver: &TokioAsyncResolver, ip: IpAddr) -> Vec<DnsblResult> {
+    let reversed = reverse_labels(ip);
+    let mut tasks = tokio::task::JoinSet::new();
+    for (index, (zone, name, ipv6)) in DNSBL_ZONES.iter().enumerate() {
+        if ip.is_ipv6() && !ipv6 {
+            continue;
+        }
+        let resolver = resolver.clone();
+        let query = format!("{}.{}.", reversed, zone);
+        tasks.spawn(async move {
+            let mut result = DnsblResult {
+                zone: zone.to_string(),
+                name: name.to_string(),
+                listed: false,
+                return_codes: Vec::new(),
+                meaning: None,
+                error: None,
+            };
+            match DnsClient::lookup(&resolver, &query, RecordType::A).await {
+                Ok(codes) => {
+                    // 127.255.255.x 表示查询被拒绝，常见于使用公共 DNS 时
+                    if codes.iter().any(|c| c.starts_with("127.255.255.")) {
+                        result.error =
+                            Some("黑名单拒绝了查询（可能使用了公共 DNS 解析器）".to_string());
+                    } else {
+                        result.listed = codes.iter().any(|c| c.starts_with("127."));
+                        let meanings: Vec<&str> = codes
+                            .iter()
+                            .filter_map(|c| dnsbl_meaning(zone, c))
+                            .collect();
+                        if !meanings.is_empty() {
+                            result.meaning = Some(meanings.join("；"));
+                        }
+                    }
+                    result.return_codes = codes;
+                }
+                Err(e) => result.error = Some(e),
+            }
+            (index, result)
+        });
+    }
+
+    let mut results = tasks.join_all().await;
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+fn vcard_property(entity: &serde_json::Value, property: &str) -> Option<String> {
+    entity
+        .get("vcardArray")?
+        .get(1)?
+        .as_array()?
+        .iter()
+        .find(|item| item.get(0).and_then(|v| v.as_str()) == Some(property))
+        .and_then(|item| item.get(3))
+        .and_then(|v| v.as_str())
+        .map(|v| v.trim_start_matches("mailto:").to_string())
+}
+
+/// 递归查找具有 abuse 角色的实体（ARIN 等会嵌套在注册人实体下）
+fn find_abuse_entity(value: &serde_json::Value) -> Option<&serde_json::Value> {
+    let entities = value.get("entities")?.as_array()?;
+    entities
+        .iter()
+        .find(|entity| {
+            entity
+                .get("roles")
+                .and_then(|r| r.as_array())
+                .is_some_and(|roles| roles.iter().any(|r| r.as_str() == Some("abuse")))
+        })
+        .or_else(|| entities.iter().find_map(find_abuse_entity))
+}
+
+fn parse_rdap_abuse(value: &serde_json::Value) -> AbuseContact {
+    let text = |key: &str| value.get(key).and_then(|v| v.as_str()).map(str::to_string);
+    let network_range = value
+        .get("cidr0_cidrs")
+        .and_then(|c| c.as_array())
+        .map(|cidrs| {
+            cidrs
+                .iter()
+                .filter_map(|c| {
+                    let prefix = c.get("v4prefix").or_else(|| c.get("v6prefix"))?.as_str()?;
+                    Some(format!("{}/{}", prefix, c.get("length")?.as_u64()?))
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        })
+        .filter(|r| !r.is_empty())
+        .or_else(|| match (text("startAddress"), text("endAddress")) {
+            (Some(start), Some(end)) => Some(format!("{} - {}", start, end)),
+            _ => None,
+        });
+    let abuse = find_abuse_entity(value);
+    AbuseContact {
+        name: abuse.and_then(|e| vcard_property(e, "fn")),
+        email: abuse.and_then(|e| vcard_property(e, "email")),
+        network_name: text("name"),
+        network_range,
+        whois_server: text("port43"),
+    }
+}
+
+async fn lookup_abuse_contact(
+    client: &reqwest::Client,
+    ip: IpAddr,
+) -> Result<AbuseContact, String> {
+    let url = format!("https://rdap.org/ip/{}", ip);
+    let value: serde_json::Value = rate_limit::send_with_retry("rdap.org", || {
+        client
+            .get(&url)
+            .header("Accept", "application/rdap+json")
+            .timeout(Duration::from_secs(10))
+    })
+    .await
+    .map_err(|e| format!("RDAP 请求失败: {}", e))?
+    .error_for_status()
+    .map_err(|e| format!("RDAP 请求失败: {}", e))?
+    .json()
+    .await
+    .map_err(|e| format!("解析 RDAP 响应失败: {}", e))?;
+    Ok(parse_rdap_abuse(&value))
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IpNetworkInfo {
+    pub ip: String,
+    pub asn: Option<String>,
+    pub as_name: Option<String>,
+    pub org: Option<String>,
+    pub country: Option<String>,
+    pub city: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct IpApiNetworkRaw {
+    status: Option<String>,
+    message: Option<String>,
+    country: Option<String>,
+    city: Option<String>,
+    isp: Option<String>,
+    org: Option<String>,
+    #[serde(rename = "as")]
+    as_field: Option<String>,
+    asname: Option<String>,
+}
+
+/// 查询 IP 所属 ASN、运营商与地理位置（基于 ip-api.com）
+pub(crate) async fn lookup_ip_network(
+    client: &reqwest::Client,
+    ip: &str,
+) -> Result<IpNetworkInfo, String> {
+    let url = format!(
+        "http://ip-api.com/json/{}?fields=status,message,country,city,isp,org,as,asname&lang=zh-CN",
+        ip
+    );
+    let raw: IpApiNetworkRaw = rate_limit::send_with_retry("ip-api.com", || client.get(&url))
+        .await
+        .map_err(|e| format!("请求 ip-api.com 出错: {}", e))?
+        .json()
+        .await
+        .map_err(|e| format!("解析 ip-api.com 响应失败: {}", e))?;
+    if matches!(raw.status.as_deref(), Some("fail")) {
+        return Err(raw.message.unwrap_or_else(|| "ip-api.com 查询失败".into()));
+    }
+
+    // "as" 字段形如 "AS15169 Google LLC"
+    let asn = raw
+        .as_field
+        .as_deref()
+        .and_then(|s| s.split_whitespace().next())
+        .filter(|s| s.starts_with("AS"))
+        .map(str::to_string);
+    Ok(IpNetworkInfo {
+        ip: ip.to_string(),
+        asn,
+        as_name: raw.asname,
+        org: raw.org.filter(|o| !o.is_empty()).or(raw.isp),
+        country: raw.country,
+        city: raw.city,
+    })
+}
+
+const IPV4_SOURCES: &[(&str, &str)] = &[
+    ("ipify.org", "https://api.ipify.org"),
+    ("icanhazip.com", "https://ipv4.icanhazip.com"),
+    ("ident.me", "https://4.ident.me"),
+];
+
+const IPV6_SOURCES: &[(&str, &str)] = &[
+    ("ipify.org", "https://api6.ipify.org"),
+    ("icanhazip.com", "https://ipv6.icanhazip.com"),
+    ("ident.me", "https://6.ident.me"),
+];
+
+const STUN_SERVERS: &[&str] = &[
+    "stun.l.google.com:19302",
+    "stun.cloudflare.com:3478",
+    "stun.miwifi.com:3478",
+];
+
+const STUN_MAGIC_COOKIE: u32 = 0x2112_A442;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PublicIpSource {
+    pub source: String,
+    pub family: String, // ipv4 / ipv6
+    pub ip: Option<String>,
+    pub latency_ms: Option<u64>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StunBinding {
+    pub server: String,
+    pub mapped_address: Option<String>,
+    pub latency_ms: Option<u64>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalInterface {
+    pub name: String,
+    pub ip: String,
+    pub family: String,
+    pub prefix_len: u8,
+    pub is_loopback: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MyIpResponse {
+    pub public_ipv4: Option<String>,
+    pub public_ipv6: Option<String>,
+    pub consistent: bool, // 各来源返回的地址是否一致
+    pub nat_type: String, // open_internet / endpoint_independent / symmetric / unknown / udp_blocked
+    pub nat_description: String,
+    pub primary_local_ip: Option<String>,
+    pub sources: Vec<PublicIpSource>,
+    pub stun: Vec<StunBinding>,
+    pub local_interfaces: Vec<LocalInterface>,
+}
+
+async fn query_public_ip(
+    client: &reqwest::Client,
+    source: &str,
+    url: &str,
+    family: &str,
+) -> PublicIpSource {
+    let start = Instant::now();
+    let result = async {
+        let text = client
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| format!("请求失败: {}", e))?
+            .error_for_status()
+            .map_err(|e| format!("请求失败: {}", e))?
+            .text()
+            .await
+            .map_err(|e| format!("读取响应失败: {}", e))?;
+        let ip: IpAddr = text
+            .trim()
+            .parse()
+            .map_err(|_| format!("返回内容不是有效的 IP: {}", text.trim()))?;
+        Ok::<_, String>(ip.to_string())
+    }
+    .await;
+    PublicIpSource {
+        source: source.to_string(),
+        family: family.to_string(),
+        latency_ms: result.is_ok().then(|| start.elapsed().as_millis() as u64),
+        ip: result.as_ref().ok().cloned(),
+        error: result.err(),
+    }
+}
+
+/// 解析 STUN Binding 成功响应；事务 ID 不匹配时返回 None，
+/// 匹配但没有映射地址属性时返回 Some(None)
+fn parse_stun_response(data: &[u8], transaction_id: &[u8; 12]) -> Option<Option<SocketAddr>> {
+    if data.len() < 20 || data[0..2] != [0x01, 0x01] || data[8..20] != transaction_id[..] {
+        return None;
+    }
+    let len = data.len();
+    let mut mapped = None;
+    let mut offset = 20;
+    while offset + 4 <= len {
+        let attr_type = u16::from_be_bytes([data[offset], data[offset + 1]]);
+        let attr_len = u16::from_be_bytes([data[offset + 2], data[offset + 3]]) as usize;
+        let Some(value) = data.get(offset + 4..offset + 4 + attr_len) else {
+            break;
+        };
+        let xor = attr_type == 0x0020 || attr_type == 0x8020;
+        if (xor || attr_type == 0x0001) && value.len() >= 8 {
+            let mut port = u16::from_be_bytes([value[2], value[3]]);
+            let ip = match value[1] {
+                0x01 => {
+                    let mut raw = u32::from_be_bytes([value[4], value[5], value[6], value[7]]);
+                    if xor {
+                        port ^= (STUN_MAGIC_COOKIE >> 16) as u16;
+                        raw ^= STUN_MAGIC_COOKIE;
+                    }
+                    Some(IpAddr::V4(Ipv4Addr::from(raw)))
+                }
+                0x02 if value.len() >= 20 => {
+                    let mut raw = [0u8; 16];
+                    raw.copy_from_slice(&value[4..20]);
+                    if xor {
+                        port ^= (STUN_MAGIC_COOKIE >> 16) as u16;
+                        let mut key = STUN_MAGIC_COOKIE.to_be_bytes().to_vec();
+                        key.extend_from_slice(transaction_id);
+                        raw.iter_mut().zip(key).for_each(|(b, k)| *b ^= k);
+                    }
+                    Some(IpAddr::V6(Ipv6Addr::from(raw)))
+                }
+                _ => None,
+            };
+            if let Some(ip) = ip {
+                // XOR-MAPPED-ADDRESS 优先
+                if xor || mapped.is_none() {
+                    mapped = Some(SocketAddr::new(ip, port));
+                }
+            }
+        }
+        offset += 4 + attr_len.div_ceil(4) * 4;
+    }
+    Some(mapped)
+}
+
+/// 发送 STUN Binding 请求，返回 NAT 映射后的公网地址
+fn stun_binding(
+    socket: &UdpSocket,
+    server: &str,
+    sequence: u8,
+) -> Result<(SocketAddr, Duration), String> {
+    let target = server
+        .to_socket_addrs()
+        .map_err(|e| format!("解析 STUN 服务器失败: {}", e))?
+        .find(|addr| addr.is_ipv4())
+        .ok_or("STUN 服务器没有 IPv4 地址")?;
+
+    let mut transaction_id = [0u8; 12];
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+    transaction_id[..8].copy_from_slice(&(nanos as u64).to_be_bytes());
+    transaction_id[8..11].copy_from_slice(&std::process::id().to_be_bytes()[1..]);
+    transaction_id[11] = sequence;
+
+    let mut request = Vec::with_capacity(20);
+    request.extend_from_slice(&0x0001u16.to_be_bytes()); // Binding Request
+    request.extend_from_slice(&0u16.to_be_bytes());
+    request.extend_from_slice(&STUN_MAGIC_COOKIE.to_be_bytes());
+    request.extend_from_slice(&transaction_id);
+
+    let start = Instant::now();
+    let timeout = Duration::from_secs(3);
+    let mut buf = [0u8; 1024];
+    // UDP 可能丢包，最多重传 2 次
+    for _ in 0..3 {
+        socket
+            .send_to(&request, target)
+            .map_err(|e| format!("发送 STUN 请求失败: {}", e))?;
+        let deadline = Instant::now() + timeout / 3;
+        while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+            socket
+                .set_read_timeout(Some(remaining.max(Duration::from_millis(1))))
+                .map_err(|e| format!("设置超时失败: {}", e))?;
+            let len = match socket.recv_from(&mut buf) {
+                Ok((len, _)) => len,
+                Err(_) => break,
+            };
+            let Some(mapped) = parse_stun_response(&buf[..len], &transaction_id) else {
+                continue;
+            };
+            return mapped
+                .map(|addr| (addr, start.elapsed()))
+                .ok_or_else(|| "STUN 响应中没有映射地址".to_string());
+        }
+    }
+    Err("STUN 服务器无响应".to_string())
+}
+
+/// 通过路由选择获取本机默认出口地址（不会真正发送数据）
+fn primary_local_ip() -> Option<IpAddr> {
+    let socket = UdpSocket::bind("0.0.0.0:0").ok()?;
+    socket.connect("8.8.8.8:53").ok()?;
+    socket.local_addr().ok().map(|addr| addr.ip())
+}
+
+fn classify_nat(
+    bindings: &[(String, Option<SocketAddr>)],
+    local_port: Option<u16>,
+    local_ips: &[IpAddr],
+) -> (String, String) {
+    let mapped: Vec<SocketAddr> = bindings.iter().filter_map(|(_, m)| *m).collect();
+    if mapped.is_empty() {
+        return (
+            "udp_blocked".to_string(),
+            "所有 STUN 服务器均无响应，UDP 可能被防火墙阻断".to_string(),
+        );
+    }
+    if mapped
+        .iter()
+        .any(|m| local_ips.contains(&m.ip()) && Some(m.port()) == local_port)
+    {
+        return (
+            "open_internet".to_string(),
+            "本机直接使用公网地址，未经过 NAT".to_string(),
+        );
+    }
+    if mapped.len() < 2 {
+        return (
+            "unknown".to_string(),
+            "位于 NAT 之后，但可用的 STUN 服务器不足，无法判断映射类型".to_string(),
+        );
+    }
+    let cgnat = local_ips.iter().any(|ip| match ip {
+        IpAddr::V4(v4) => v4.octets()[0] == 100 && (64..128).contains(&v4.octets()[1]),
+        _ => false,
+    });
+    let suffix = if cgnat {
+        "；本机地址位于 100.64.0.0/10，可能处于运营商级 NAT（CGNAT）之后"
+    } else {
+        ""
+    };
+    if mapped.iter().all(|m| *m == mapped[0]) {
+        let port_note = if Some(mapped[0].port()) == local_port {
+            "，且保留了本地端口"
+        } else {
+            ""
+        };
+        (
+            "endpoint_independent".to_string(),
+            format!(
+                "锥形 NAT（映射与目标无关{}），P2P 打洞通常可以成功{}",
+                port_note, suffix
+            ),
+        )
+    } else {
+        (
+            "symmetric".to_string(),
+            format!(
+                "对称型 NAT（不同目标使用不同的映射端口），P2P 连接通常需要中继{}",
+                suffix
+            ),
+        )
+    }
+}
+
+/// 查询本机公网 IPv4/IPv6 地址、NAT 类型与本地网卡地址
+#[tauri::command]
+pub async fn get_my_ip() -> Result<MyIpResponse, String> {
+    let build_client = |local: IpAddr| {
+        proxy::client_builder("ip_info")
+            .local_address(local)
+            .timeout(Duration::from_secs(6))
+            .build()
+            .map_err(|e| format!("构建 HTTP 客户端失败: {}", e))
+    };
+    let client_v4 = build_client(IpAddr::V4(Ipv4Addr::UNSPECIFIED))?;
+    let client_v6 = build_client(IpAddr::V6(Ipv6Addr::UNSPECIFIED))?;
+
+    let http_lookups = async {
+        let mut handles = Vec::new();
+        for (source, url) in IPV4_SOURCES {
+            let client = client_v4.clone();
+            handles.push(tokio::spawn(async move {
+                query_public_ip(&client, source, url, "ipv4").await
+            }));
+        }
+        for (source, url) in IPV6_SOURCES {
+            let client = client_v6.clone();
+            handles.push(tokio::spawn(async move {
+                query_public_ip(&client, source, url, "ipv6").await
+            }));
+        }
+        let mut sources = Vec::new();
+        for handle in handles {
+            if let Ok(source) = handle.await {
+                sources.push(source);
+            }
+        }
+        sources
+    };
+
+    // 所有 STUN 请求共用同一个本地端口，以便比较不同服务器看到的映射
+    let stun_lookups = tokio::task::spawn_blocking(|| {
+        let socket = UdpSocket::bind("0.0.0.0:0").ok();
+        let local_port = socket
+            .as_ref()
+            .and_then(|s| s.local_addr().ok())
+            .map(|a| a.port());
+        let results: Vec<(StunBinding, Option<SocketAddr>)> = STUN_SERVERS
+            .iter()
+            .enumerate()
+            .map(|(i, server)| {
+                let outcome = match &socket {
+                    Some(socket) => stun_binding(socket, server, i as u8),
+                    None => Err("创建 UDP 套接字失败".to_string()),
+                };
+                match outcome {
+                    Ok((mapped, latency)) => (
+                        StunBinding {
+                            server: server.to_string(),
+                            mapped_address: Some(mapped.to_string()),
+                            latency_ms: Some(latency.as_millis() as u64),
+                            error: None,
+                        },
+                        Some(mapped),
+                    ),
+                    Err(e) => (
+                        StunBinding {
+                            server: server.to_string(),
+                            mapped_address: None,
+                            latency_ms: None,
+                            error: Some(e),
+                        },
+                        None,
+                    ),
+                }
+            })
+            .collect();
+        (results, local_port)
+    });
+
+    let (sources, stun_result) = tokio::join!(http_lookups, stun_lookups);
+    let (stun_results, local_port) = stun_result.map_err(|e| format!("STUN 检测失败: {}", e))?;
+
+    let local_interfaces: Vec<LocalInterface> = if_addrs::get_if_addrs()
+        .map(|interfaces| {
+            interfaces
+                .into_iter()
+                .map(|iface| {
+                    let (family, prefix_len) = match &iface.addr {
+                        if_addrs::IfAddr::V4(v4) => ("ipv4", v4.prefixlen),
+                        if_addrs::IfAddr::V6(v6) => ("ipv6", v6.prefixlen),
+                    };
+                    LocalInterface {
+                        ip: iface.ip().to_string(),
+                        is_loopback: iface.is_loopback(),
+                        name: iface.name,
+                        family: family.to_string(),
+                        prefix_len,
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let mut local_ips: Vec<IpAddr> = local_interfaces
+        .iter()
+        .filter_map(|iface| iface.ip.parse().ok())
+        .collect();
+    let primary_local_ip = primary_local_ip();
+    local_ips.extend(primary_local_ip);
+
+    let bindings: Vec<(String, Option<SocketAddr>)> = stun_results
+        .iter()
+        .map(|(binding, mapped)| (binding.server.clone(), *mapped))
+        .collect();
+    let (nat_type, nat_description) = classify_nat(&bindings, local_port, &local_ips);
+
+    // 以多数来源一致的结果为准
+    let pick = |family: &str| -> (Option<String>, bool) {
+        let mut ips: Vec<String> = sources
+            .iter()
+            .filter(|s| s.family == family)
+            .filter_map(|s| s.ip.clone())
+            .collect();
+        if family == "ipv4" {
+            ips.extend(
+                bindings
+                    .iter()
+                    .filter_map(|(_, m)| m.map(|addr| addr.ip().to_string())),
+            );
+        }
+        let mut counts: Vec<(String, usize)> = Vec::new();
+        for ip in &ips {
+            match counts.iter_mut().find(|(existing, _)| existing == ip) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((ip.clone(), 1)),
+            }
+        }
+        counts.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+        (counts.first().map(|(ip, _)| ip.clone()), counts.len() <= 1)
+    };
+    let (public_ipv4, v4_consistent) = pick("ipv4");
+    let (public_ipv6, v6_consistent) = pick("ipv6");
+
+    Ok(MyIpResponse {
+        public_ipv4,
+        public_ipv6,
+        consistent: v4_consistent && v6_consistent,
+        nat_type,
+        nat_description,
+        primary_local_ip: primary_local_ip.map(|ip| ip.to_string()),
+        sources,
+        stun: stun_results
+            .into_iter()
+            .map(|(binding, _)| binding)
+            .collect(),
+        local_interfaces,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stun_response(transaction_id: &[u8; 12], attrs: &[(u16, Vec<u8>)]) -> Vec<u8> {
+        let body: Vec<u8> = attrs
+            .iter()
+            .flat_map(|(attr_type, value)| {
+                let mut attr = attr_type.to_be_bytes().to_vec();
+                attr.extend_from_slice(&(value.len() as u16).to_be_bytes());
+                attr.extend_from_slice(value);
+                attr
+            })
+            .collect();
+        let mut data = vec![0x01, 0x01];
+        data.extend_from_slice(&(body.len() as u16).to_be_bytes());
+        data.extend_from_slice(&STUN_MAGIC_COOKIE.to_be_bytes());
+        data.extend_from_slice(transaction_id);
+        data.extend_from_slice(&body);
+        data
+    }
+
+    #[test]
+    fn test_parse_stun_xor_mapped_address() {
+        let tid = [7u8; 12];
+        // 203.0.113.5:54321 经 XOR 编码
+        let port = 54321u16 ^ (STUN_MAGIC_COOKIE >> 16) as u16;
+        let ip = u32::from(Ipv4Addr::new(203, 0, 113, 5)) ^ STUN_MAGIC_COOKIE;
+        let mut value = vec![0x00, 0x01];
+        value.extend_from_slice(&port.to_be_bytes());
+        value.extend_from_slice(&ip.to_be_bytes());
+        let mapped = vec![0x00, 0x01, 0x04, 0xD2, 10, 0, 0, 1];
+
+        let data = stun_response(&tid, &[(0x0020, value), (0x0001, mapped)]);
+        assert_eq!(
+            parse_stun_response(&data, &tid),
+            Some(Some("203.0.113.5:54321".parse().unwrap()))
+        );
+    }
+
+    #[test]
+    fn test_parse_stun_rejects_other_transaction() {
+        let data = stun_response(&[1u8; 12], &[]);
+        assert_eq!(parse_stun_response(&data, &[2u8; 12]), None);
+        assert_eq!(parse_stun_response(&data, &[1u8; 12]), Some(None));
+    }
+
+    #[test]
+    fn test_reverse_labels_and_cymru_origin() {
+        assert_eq!(reverse_labels("8.8.4.4".parse().unwrap()), "4.4.8.8");
+        assert!(reverse_labels("2001:db8::1".parse().unwrap()).starts_with("1.0.0.0.0.0.0.0"));
+        assert!(reverse_labels("2001:db8::1".parse().unwrap()).ends_with("8.b.d.0.1.0.0.2"));
+        assert!(!is_public_ip("100.64.1.1".parse().unwrap()));
+        assert!(is_public_ip("8.8.8.8".parse().unwrap()));
+
+        let records = vec![
+            "15169 | 8.0.0.0/9 | US | arin | 1992-12-01".to_string(),
+            "15169 | 8.8.8.0/24 | US | arin | 2014-03-14".to_string(),
+        ];
+        let origin = parse_cymru_origin(&records).unwrap();
+        assert_eq!(origin.asn.as_deref(), Some("AS15169"));
+        assert_eq!(origin.prefix.as_deref(), Some("8.8.8.0/24"));
+        assert_eq!(origin.allocated.as_deref(), Some("2014-03-14"));
+    }
+
+    #[test]
+    fn test_parse_rdap_abuse() {
+        let value = serde_json::json!({
+            "name": "GOGL",
+            "port43": "whois.arin.net",
+            "cidr0_cidrs": [{ "v4prefix": "8.8.8.0", "length": 24 }],
+            "entities": [{
+                "roles": ["registrant"],
+                "entities": [{
+                    "roles": ["abuse"],
+                    "vcardArray": ["vcard", [
+                        ["version", {}, "text", "4.0"],
+                        ["fn", {}, "text", "Abuse"],
+                        ["email", {}, "text", "network-abuse@google.com"]
+                    ]]
+                }]
+            }]
+        });
+        assert_eq!(
+            parse_rdap_abuse(&value),
+            AbuseContact {
+                name: Some("Abuse".to_string()),
+                email: Some("network-abuse@google.com".to_string()),
+                network_name: Some("GOGL".to_string()),
+                network_range: Some("8.8.8.0/24".to_string()),
+                whois_server: Some("whois.arin.net".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_classify_nat() {
+        let a: SocketAddr = "198.51.100.1:40000".parse().unwrap();
+        let b: SocketAddr = "198.51.100.1:40001".parse().unwrap();
+        let local = ["192.168.1.2".parse().unwrap()];
+
+        let same = [("s1".to_string(), Some(a)), ("s2".to_string(), Some(a))];
+        assert_eq!(
+            classify_nat(&same, Some(5000), &local).0,
+            "endpoint_independent"
+        );
+
+        let diff = [("s1".to_string(), Some(a)), ("s2".to_string(), Some(b))];
+        assert_eq!(classify_nat(&diff, Some(5000), &local).0, "symmetric");
+
+        let none = [("s1".to_string(), None)];
+        assert_eq!(classify_nat(&none, Some(5000), &local).0, "udp_blocked");
+
+        let direct = [("s1".to_string(), Some(a))];
+        assert_eq!(
+            classify_nat(&direct, Some(40000), &[a.ip()]).0,
+            "open_internet"
+        );
+    }
 }
@@ -4,6 +4,11 @@ use serde_json::Value;
 use std::collections::{HashMap, HashSet};
 use tauri::command;
 
+/// Tag types in the order they are emitted
+const TAG_ORDER: &[&str] = &[
+    "json", "yaml", "bson", "msgpack", "gorm", "db", "sql", "toml", "env", "ini",
+];
+
 /// Configuration options for JSON to Go conversion
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JsonToGoOptions {
@@ -12,6 +17,22 @@ pub struct JsonToGoOptions {
     pub is_go_118_or_above: bool,
     pub is_go_124_or_above: bool,
     pub selected_tags: HashMap<String, bool>,
+    #[serde(default = "default_nested_mode")]
+    pub nested_mode: String, // "separate", "inline"
+    #[serde(default)]
+    pub detect_time: bool, // RFC3339 strings become time.Time
+    #[serde(default = "default_number_mode")]
+    pub number_mode: String, // "auto", "int64", "float64"
+    #[serde(default)]
+    pub json_null_handling: Option<String>, // "none", "omitempty", "omitzero"; None follows the Go version
+}
+
+fn default_nested_mode() -> String {
+    "separate".to_string()
+}
+
+fn default_number_mode() -> String {
+    "auto".to_string()
 }
 
 impl Default for JsonToGoOptions {
@@ -19,6 +40,8 @@ impl Default for JsonToGoOptions {
         let mut selected_tags = HashMap::new();
         selected_tags.insert("json".to_string(), true);
         selected_tags.insert("yaml".to_string(), false);
+        selected_tags.insert("bson".to_string(), false);
+        selected_tags.insert("msgpack".to_string(), false);
         selected_tags.insert("gorm".to_string(), false);
         selected_tags.insert("db".to_string(), false);
         selected_tags.insert("sql".to_string(), false);
@@ -32,6 +55,10 @@ impl Default for JsonToGoOptions {
             is_go_118_or_above: true,
             is_go_124_or_above: false,
             selected_tags,
+            nested_mode: default_nested_mode(),
+            detect_time: false,
+            number_mode: default_number_mode(),
+            json_null_handling: None,
         }
     }
 }
@@ -45,6 +72,30 @@ pub struct GoField {
     pub is_nested: bool,
     pub is_array: bool,
     pub nested_fields: Option<Vec<GoField>>,
+    pub optional: bool, // missing or null in some samples; rendered as a pointer with omitempty
+}
+
+/// Result of merging several JSON documents into one set of Go structs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonSamplesToGoResult {
+    pub code: String,
+    pub sample_count: usize,
+    pub optional_fields: Vec<String>, // paths missing or null in some samples
+    pub conflicts: Vec<TypeConflict>,
+}
+
+/// A field whose samples disagree on its type, emitted as json.RawMessage
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TypeConflict {
+    pub path: String,       // dotted path, "[]" marks array elements
+    pub types: Vec<String>, // distinct Go types in the order they were seen
+}
+
+/// Paths collected while merging samples
+#[derive(Debug, Default)]
+struct SampleMergeReport {
+    optional_fields: Vec<String>,
+    conflicts: Vec<TypeConflict>,
 }
 
 /// Main converter service for JSON to Go struct transformation
@@ -59,6 +110,15 @@ pub fn convert_json_to_go(json_str: &str, options: JsonToGoOptions) -> Result<St
     }
 }
 
+/// Merge several JSON documents (e.g. responses from the same endpoint) into one set of structs
+#[command]
+pub fn convert_json_samples_to_go(
+    samples: Vec<String>,
+    options: JsonToGoOptions,
+) -> Result<JsonSamplesToGoResult, String> {
+    JsonToGoConverter::convert_json_samples(&samples, options)
+}
+
 impl JsonToGoConverter {
     /// Primary conversion method that orchestrates the entire process
     pub fn convert_json_to_go_structs(
@@ -66,11 +126,50 @@ impl JsonToGoConverter {
         options: JsonToGoOptions,
     ) -> Result<String, String> {
         let json_value = Self::parse_json_string(json_str)?;
-        let fields = Self::extract_fields_from_json(&json_value, &options.struct_name)?;
+        let fields = Self::extract_fields_from_json(&json_value, &options.struct_name, &options)?;
         let go_code = Self::generate_go_structs(&fields, &options)?;
         Ok(go_code)
     }
 
+    /// Convert several JSON samples, reporting optional fields and type conflicts
+    pub fn convert_json_samples(
+        samples: &[String],
+        options: JsonToGoOptions,
+    ) -> Result<JsonSamplesToGoResult, String> {
+        if samples.is_empty() {
+            return Err("至少需要一个JSON样本".to_string());
+        }
+
+        let mut lists = Vec::with_capacity(samples.len());
+        for (index, sample) in samples.iter().enumerate() {
+            let value = Self::parse_json_string(sample)
+                .map_err(|e| format!("第{}个样本: {}", index + 1, e))?;
+            let has_object = match &value {
+                Value::Object(_) => true,
+                Value::Array(array) => array.iter().any(|item| item.is_object()),
+                _ => false,
+            };
+            if !has_object {
+                return Err(format!("第{}个样本不是JSON对象", index + 1));
+            }
+            lists.push(Self::extract_fields_from_json(
+                &value,
+                &options.struct_name,
+                &options,
+            )?);
+        }
+
+        let mut report = SampleMergeReport::default();
+        let fields = Self::merge_sample_lists(&lists, "", &mut report);
+        let code = Self::generate_go_structs(&fields, &options)?;
+        Ok(JsonSamplesToGoResult {
+            code,
+            sample_count: samples.len(),
+            optional_fields: report.optional_fields,
+            conflicts: report.conflicts,
+        })
+    }
+
     /// Legacy method for backward compatibility
     pub fn convert(json_str: &str, options: JsonToGoOptions) -> Result<String, String> {
         Self::convert_json_to_go_structs(json_str, options)
@@ -88,10 +187,11 @@ impl JsonToGoConverter {
     fn extract_fields_from_json(
         json_value: &Value,
         parent_name: &str,
+        options: &JsonToGoOptions,
     ) -> Result<Vec<GoField>, String> {
         match json_value {
-            Value::Object(map) => Self::extract_fields_from_object(map, parent_name),
-            Value::Array(array) => Self::extract_fields_from_array(array, parent_name),
+            Value::Object(map) => Self::extract_fields_from_object(map, parent_name, options),
+            Value::Array(array) => Self::extract_fields_from_array(array, parent_name, options),
             _ => Ok(vec![]),
         }
     }
@@ -100,42 +200,55 @@ impl JsonToGoConverter {
     fn extract_fields_from_object(
         map: &serde_json::Map<String, Value>,
         parent_name: &str,
+        options: &JsonToGoOptions,
     ) -> Result<Vec<GoField>, String> {
         let mut fields = Vec::new();
         for (key, value) in map {
-            let field = Self::create_field_from_json_value(key, value, parent_name)?;
+            let field = Self::create_field_from_json_value(key, value, parent_name, options)?;
             fields.push(field);
         }
         Ok(fields)
     }
 
-    /// Extract fields from JSON array
+    /// Extract fields from JSON array, treating every object element as a sample
     fn extract_fields_from_array(
         array: &[Value],
         parent_name: &str,
+        options: &JsonToGoOptions,
     ) -> Result<Vec<GoField>, String> {
         if array.is_empty() {
             return Ok(vec![]);
         }
 
-        if let Some(first_item) = array.first() {
-            if first_item.is_object() {
-                Self::extract_fields_from_json(first_item, parent_name)
-            } else {
-                let field_type = Self::infer_go_type_from_json_value(first_item);
-                Ok(vec![GoField {
-                    name: "Item".to_string(),
-                    field_type: format!("[]{}", field_type),
-                    json_key: "item".to_string(),
-                    is_nested: false,
-                    is_array: true,
-                    nested_fields: None,
-                }])
-            }
+        if array.iter().any(|item| item.is_object()) {
+            Self::extract_merged_object_fields(array, parent_name, options)
         } else {
-            Ok(vec![])
+            let field_type = Self::infer_array_element_type(array, options);
+            Ok(vec![GoField {
+                name: "Item".to_string(),
+                field_type: format!("[]{}", field_type),
+                json_key: "item".to_string(),
+                is_nested: false,
+                is_array: true,
+                nested_fields: None,
+                optional: false,
+            }])
         }
     }
+
+    /// Extract fields from every object element and merge them into one schema
+    fn extract_merged_object_fields(
+        array: &[Value],
+        parent_name: &str,
+        options: &JsonToGoOptions,
+    ) -> Result<Vec<GoField>, String> {
+        let mut merged: Vec<GoField> = Vec::new();
+        for item in array.iter().filter(|item| item.is_object()) {
+            let fields = Self::extract_fields_from_json(item, parent_name, options)?;
+            merged = Self::merge_field_lists(merged, fields);
+        }
+        Ok(merged)
+    }
 }
 
 // Field Creation Module
@@ -145,11 +258,12 @@ impl JsonToGoConverter {
         key: &str,
         value: &Value,
         parent_name: &str,
+        options: &JsonToGoOptions,
     ) -> Result<GoField, String> {
         match value {
-            Value::Object(_) => Self::create_object_field(key, value, parent_name),
-            Value::Array(array) => Self::create_array_field(key, array),
-            _ => Self::create_primitive_field(key, value),
+            Value::Object(_) => Self::create_object_field(key, value, parent_name, options),
+            Value::Array(array) => Self::create_array_field(key, array, options),
+            _ => Self::create_primitive_field(key, value, options),
         }
     }
 
@@ -158,9 +272,10 @@ impl JsonToGoConverter {
         key: &str,
         value: &Value,
         _parent_name: &str,
+        options: &JsonToGoOptions,
     ) -> Result<GoField, String> {
         let nested_name = StringUtils::to_pascal_case(key);
-        let nested_fields = Self::extract_fields_from_json(value, &nested_name)?;
+        let nested_fields = Self::extract_fields_from_json(value, &nested_name, options)?;
 
         Ok(GoField {
             name: key.to_string(),
@@ -169,29 +284,34 @@ impl JsonToGoConverter {
             is_nested: true,
             is_array: false,
             nested_fields: Some(nested_fields),
+            optional: false,
         })
     }
 
     /// Create a field for JSON array
-    fn create_array_field(key: &str, array: &[Value]) -> Result<GoField, String> {
+    fn create_array_field(
+        key: &str,
+        array: &[Value],
+        options: &JsonToGoOptions,
+    ) -> Result<GoField, String> {
         if array.is_empty() {
             return Self::create_empty_array_field(key);
         }
 
-        if let Some(first_item) = array.first() {
-            if first_item.is_object() {
-                Self::create_object_array_field(key, first_item)
-            } else {
-                Self::create_primitive_array_field(key, first_item)
-            }
+        if array.iter().any(|item| item.is_object()) {
+            Self::create_object_array_field(key, array, options)
         } else {
-            Self::create_empty_array_field(key)
+            Self::create_primitive_array_field(key, array, options)
         }
     }
 
     /// Create a field for primitive JSON value
-    fn create_primitive_field(key: &str, value: &Value) -> Result<GoField, String> {
-        let field_type = Self::infer_go_type_from_json_value(value);
+    fn create_primitive_field(
+        key: &str,
+        value: &Value,
+        options: &JsonToGoOptions,
+    ) -> Result<GoField, String> {
+        let field_type = Self::infer_go_type_with_options(value, options);
 
         Ok(GoField {
             name: key.to_string(),
@@ -200,6 +320,7 @@ impl JsonToGoConverter {
             is_nested: false,
             is_array: false,
             nested_fields: None,
+            optional: false,
         })
     }
 
@@ -212,13 +333,18 @@ impl JsonToGoConverter {
             is_nested: false,
             is_array: true,
             nested_fields: None,
+            optional: false,
         })
     }
 
-    /// Create a field for array of objects
-    fn create_object_array_field(key: &str, first_item: &Value) -> Result<GoField, String> {
+    /// Create a field for array of objects, merging the fields of all elements
+    fn create_object_array_field(
+        key: &str,
+        array: &[Value],
+        options: &JsonToGoOptions,
+    ) -> Result<GoField, String> {
         let nested_name = StringUtils::to_pascal_case(key);
-        let nested_fields = Self::extract_fields_from_json(first_item, &nested_name)?;
+        let nested_fields = Self::extract_merged_object_fields(array, &nested_name, options)?;
 
         Ok(GoField {
             name: key.to_string(),
@@ -227,12 +353,17 @@ impl JsonToGoConverter {
             is_nested: true,
             is_array: true,
             nested_fields: Some(nested_fields),
+            optional: false,
         })
     }
 
     /// Create a field for array of primitive values
-    fn create_primitive_array_field(key: &str, first_item: &Value) -> Result<GoField, String> {
-        let element_type = Self::infer_go_type_from_json_value(first_item);
+    fn create_primitive_array_field(
+        key: &str,
+        array: &[Value],
+        options: &JsonToGoOptions,
+    ) -> Result<GoField, String> {
+        let element_type = Self::infer_array_element_type(array, options);
 
         Ok(GoField {
             name: key.to_string(),
@@ -241,17 +372,218 @@ impl JsonToGoConverter {
             is_nested: false,
             is_array: true,
             nested_fields: None,
+            optional: false,
         })
     }
 }
 
+// Schema Merge Module
+impl JsonToGoConverter {
+    /// Merge two field lists by JSON key, keeping first-seen order
+    fn merge_field_lists(existing: Vec<GoField>, incoming: Vec<GoField>) -> Vec<GoField> {
+        let mut merged = existing;
+        for field in incoming {
+            match merged.iter().position(|f| f.json_key == field.json_key) {
+                Some(index) => {
+                    let current = merged[index].clone();
+                    merged[index] = Self::merge_fields(current, field);
+                }
+                None => merged.push(field),
+            }
+        }
+        merged
+    }
+
+    /// Merge two observations of the same field
+    fn merge_fields(a: GoField, b: GoField) -> GoField {
+        if a.is_nested && b.is_nested && a.is_array == b.is_array {
+            let nested = Self::merge_field_lists(
+                a.nested_fields.clone().unwrap_or_default(),
+                b.nested_fields.unwrap_or_default(),
+            );
+            return GoField {
+                nested_fields: Some(nested),
+                ..a
+            };
+        }
+
+        // A null or empty array sample carries no type information
+        if Self::is_unknown_type(&a) {
+            return b;
+        }
+        if Self::is_unknown_type(&b) {
+            return a;
+        }
+
+        GoField {
+            field_type: Self::merge_go_types(&a.field_type, &b.field_type),
+            is_nested: false,
+            nested_fields: None,
+            ..a
+        }
+    }
+
+    /// Whether a field was inferred from null or an empty array
+    fn is_unknown_type(field: &GoField) -> bool {
+        !field.is_nested
+            && (field.field_type == "interface{}" || field.field_type == "[]interface{}")
+    }
+
+    /// Merge two inferred Go types; numbers widen to float64, conflicts fall back to interface{}
+    fn merge_go_types(a: &str, b: &str) -> String {
+        if a == b {
+            return a.to_string();
+        }
+        if let (Some(a_elem), Some(b_elem)) = (a.strip_prefix("[]"), b.strip_prefix("[]")) {
+            return format!("[]{}", Self::merge_go_types(a_elem, b_elem));
+        }
+
+        let numeric = ["int", "int64", "float64"];
+        if numeric.contains(&a) && numeric.contains(&b) {
+            return if a == "float64" || b == "float64" {
+                "float64".to_string()
+            } else {
+                "int64".to_string()
+            };
+        }
+
+        // time.Time only when every sample is a timestamp
+        if (a == "time.Time" && b == "string") || (a == "string" && b == "time.Time") {
+            return "string".to_string();
+        }
+
+        if a.starts_with("[]") || b.starts_with("[]") {
+            "[]interface{}".to_string()
+        } else {
+            "interface{}".to_string()
+        }
+    }
+}
+
+// Multi-Sample Merge Module
+impl JsonToGoConverter {
+    /// Merge the field lists of several samples by JSON key, keeping first-seen order
+    fn merge_sample_lists(
+        lists: &[Vec<GoField>],
+        path: &str,
+        report: &mut SampleMergeReport,
+    ) -> Vec<GoField> {
+        let mut keys: Vec<&str> = Vec::new();
+        for field in lists.iter().flatten() {
+            if !keys.contains(&field.json_key.as_str()) {
+                keys.push(&field.json_key);
+            }
+        }
+
+        keys.into_iter()
+            .map(|key| {
+                let observed: Vec<&GoField> = lists
+                    .iter()
+                    .filter_map(|list| list.iter().find(|f| f.json_key == key))
+                    .collect();
+                let field_path = if path.is_empty() {
+                    key.to_string()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+
+                let mut field = Self::merge_sample_field(&observed, &field_path, report);
+                let missing = observed.len() < lists.len();
+                let nullable = !Self::is_unknown_type(&field)
+                    && observed.iter().any(|f| Self::is_unknown_type(f));
+                if missing || nullable {
+                    field.optional = true;
+                    report.optional_fields.push(field_path);
+                }
+                field
+            })
+            .collect()
+    }
+
+    /// Merge every observation of one field; incompatible types become json.RawMessage
+    fn merge_sample_field(
+        observed: &[&GoField],
+        path: &str,
+        report: &mut SampleMergeReport,
+    ) -> GoField {
+        // A null or empty array sample carries no type information
+        let known: Vec<&GoField> = observed
+            .iter()
+            .copied()
+            .filter(|f| !Self::is_unknown_type(f))
+            .collect();
+        let Some(first) = known.first() else {
+            return observed[0].clone();
+        };
+
+        if known
+            .iter()
+            .all(|f| f.is_nested && f.is_array == first.is_array)
+        {
+            let nested_path = if first.is_array {
+                format!("{}[]", path)
+            } else {
+                path.to_string()
+            };
+            let nested_lists: Vec<Vec<GoField>> = known
+                .iter()
+                .map(|f| f.nested_fields.clone().unwrap_or_default())
+                .collect();
+            return GoField {
+                nested_fields: Some(Self::merge_sample_lists(
+                    &nested_lists,
+                    &nested_path,
+                    report,
+                )),
+                ..(*first).clone()
+            };
+        }
+
+        let mut types: Vec<String> = Vec::new();
+        for field in &known {
+            if !types.contains(&field.field_type) {
+                types.push(field.field_type.clone());
+            }
+        }
+        let merged = types
+            .iter()
+            .cloned()
+            .reduce(|a, b| Self::merge_go_types(&a, &b))
+            .unwrap_or_default();
+        let widened_to_any =
+            merged.contains("interface{}") && !types.iter().any(|t| t.contains("interface{}"));
+
+        if known.iter().any(|f| f.is_nested) || widened_to_any {
+            report.conflicts.push(TypeConflict {
+                path: path.to_string(),
+                types,
+            });
+            return GoField {
+                field_type: "json.RawMessage".to_string(),
+                is_nested: false,
+                is_array: false,
+                nested_fields: None,
+                ..(*first).clone()
+            };
+        }
+
+        GoField {
+            field_type: merged,
+            ..(*first).clone()
+        }
+    }
+}
+
 // Type Inference Module
 impl JsonToGoConverter {
-    /// Infer Go type from JSON value
-    fn infer_go_type_from_json_value(value: &Value) -> String {
+    /// Infer Go type from JSON value honoring number and time options
+    fn infer_go_type_with_options(value: &Value, options: &JsonToGoOptions) -> String {
         match value {
+            Value::String(s) if options.detect_time && Self::is_rfc3339_time(s) => {
+                "time.Time".to_string()
+            }
             Value::String(_) => "string".to_string(),
-            Value::Number(num) => Self::infer_number_type(num),
+            Value::Number(num) => Self::infer_number_type(num, options),
             Value::Bool(_) => "bool".to_string(),
             Value::Null => "interface{}".to_string(),
             Value::Array(_) => "[]interface{}".to_string(),
@@ -259,14 +591,31 @@ impl JsonToGoConverter {
         }
     }
 
+    /// Infer the element type of a primitive array from all its elements
+    fn infer_array_element_type(array: &[Value], options: &JsonToGoOptions) -> String {
+        array
+            .iter()
+            .filter(|item| !item.is_null())
+            .map(|item| Self::infer_go_type_with_options(item, options))
+            .reduce(|a, b| Self::merge_go_types(&a, &b))
+            .unwrap_or_else(|| "interface{}".to_string())
+    }
+
     /// Infer Go numeric type from JSON number
-    fn infer_number_type(num: &serde_json::Number) -> String {
-        if num.is_i64() || num.is_u64() {
-            "int".to_string()
-        } else {
-            "float64".to_string()
+    fn infer_number_type(num: &serde_json::Number, options: &JsonToGoOptions) -> String {
+        let is_whole = num.is_i64() || num.is_u64();
+        match options.number_mode.as_str() {
+            "float64" => "float64".to_string(),
+            "int64" if is_whole => "int64".to_string(),
+            _ if is_whole => "int".to_string(),
+            _ => "float64".to_string(),
         }
     }
+
+    /// Check whether a string is an RFC3339 timestamp
+    fn is_rfc3339_time(s: &str) -> bool {
+        chrono::DateTime::parse_from_rfc3339(s).is_ok()
+    }
 }
 
 // Struct Generation Module
@@ -304,6 +653,21 @@ impl JsonToGoConverter {
             index += 1;
         }
 
+        let imports: Vec<&str> = [("time.Time", "time"), ("json.RawMessage", "encoding/json")]
+            .iter()
+            .filter(|(usage, _)| all_structs.contains(usage))
+            .map(|(_, package)| *package)
+            .collect();
+        match imports.as_slice() {
+            [] => {}
+            [package] => all_structs = format!("import \"{}\"\n\n{}", package, all_structs),
+            packages => {
+                let lines: Vec<String> =
+                    packages.iter().map(|p| format!("\t\"{}\"\n", p)).collect();
+                all_structs = format!("import (\n{})\n\n{}", lines.concat(), all_structs);
+            }
+        }
+
         Ok(CodeFormatter::format_go_code(&all_structs))
     }
 
@@ -315,6 +679,22 @@ impl JsonToGoConverter {
         structs_to_generate: &mut Vec<(String, Vec<GoField>)>,
     ) -> Result<String, String> {
         let mut result = format!("type {} struct {{\n", struct_name);
+        result.push_str(&Self::generate_struct_body(
+            fields,
+            options,
+            structs_to_generate,
+        ));
+        result.push('}');
+        Ok(result)
+    }
+
+    /// Generate the field lines of a struct body
+    fn generate_struct_body(
+        fields: &[GoField],
+        options: &JsonToGoOptions,
+        structs_to_generate: &mut Vec<(String, Vec<GoField>)>,
+    ) -> String {
+        let mut result = String::new();
 
         if fields.is_empty() {
             result.push_str("\t// Empty struct\n");
@@ -326,10 +706,12 @@ impl JsonToGoConverter {
                 .max()
                 .unwrap_or(0);
 
-            // Calculate maximum field type length for alignment
+            // Calculate maximum field type length for alignment, ignoring inline structs
             let max_field_type_length = fields
                 .iter()
-                .map(|field| Self::determine_field_type(field, options, structs_to_generate).len())
+                .map(|field| Self::determine_field_type(field, options, structs_to_generate))
+                .filter(|field_type| !field_type.contains('\n'))
+                .map(|field_type| field_type.len())
                 .max()
                 .unwrap_or(0);
 
@@ -350,8 +732,7 @@ impl JsonToGoConverter {
             }
         }
 
-        result.push_str("}");
-        Ok(result)
+        result
     }
 
     /// Generate field definition with double alignment (field name and field type)
@@ -370,8 +751,12 @@ impl JsonToGoConverter {
         let name_padding = name_alignment.saturating_sub(field_name.len());
         let name_padding_str = " ".repeat(name_padding);
 
-        // Calculate padding needed for field type alignment
-        let type_padding = type_alignment.saturating_sub(final_type.len());
+        // Inline struct types end with a closing brace, so the tag follows it directly
+        let type_padding = if final_type.contains('\n') {
+            1
+        } else {
+            type_alignment.saturating_sub(final_type.len())
+        };
         let type_padding_str = " ".repeat(type_padding);
 
         if tags.is_empty() {
@@ -406,10 +791,29 @@ impl JsonToGoConverter {
             field.field_type.clone()
         };
 
-        if field.is_nested && field.nested_fields.is_some() {
-            Self::handle_nested_field_type(field, structs_to_generate)
+        let field_type = if field.is_nested && field.nested_fields.is_some() {
+            if options.nested_mode == "inline" {
+                Self::handle_inline_field_type(field, options, structs_to_generate)
+            } else {
+                Self::handle_nested_field_type(field, structs_to_generate)
+            }
         } else {
             Self::handle_simple_field_type(field, base_type)
+        };
+        Self::apply_optional_pointer(field, field_type)
+    }
+
+    /// Optional fields become pointers; slices and catch-all types are already nilable
+    fn apply_optional_pointer(field: &GoField, field_type: String) -> String {
+        let nilable = field_type.starts_with("[]")
+            || matches!(
+                field_type.as_str(),
+                "any" | "interface{}" | "json.RawMessage"
+            );
+        if field.optional && !nilable {
+            format!("*{}", field_type)
+        } else {
+            field_type
         }
     }
 
@@ -438,6 +842,18 @@ impl JsonToGoConverter {
         }
     }
 
+    /// Handle nested field type as an inline anonymous struct
+    fn handle_inline_field_type(
+        field: &GoField,
+        options: &JsonToGoOptions,
+        structs_to_generate: &mut Vec<(String, Vec<GoField>)>,
+    ) -> String {
+        let nested_fields = field.nested_fields.as_deref().unwrap_or_default();
+        let body = Self::generate_struct_body(nested_fields, options, structs_to_generate);
+        let prefix = if field.is_array { "[]" } else { "" };
+        format!("{}struct {{\n{}}}", prefix, body)
+    }
+
     /// Handle simple field type generation
     fn handle_simple_field_type(field: &GoField, base_type: String) -> String {
         if field.is_array && !base_type.starts_with("[]") {
@@ -454,14 +870,21 @@ impl JsonToGoConverter {
     fn generate_field_tags(field: &GoField, options: &JsonToGoOptions) -> String {
         let mut tags = Vec::new();
 
-        for (tag_type, &is_selected) in &options.selected_tags {
-            if !is_selected {
+        for tag_type in TAG_ORDER {
+            if !options
+                .selected_tags
+                .get(*tag_type)
+                .copied()
+                .unwrap_or(false)
+            {
                 continue;
             }
 
-            let tag = match tag_type.as_str() {
+            let tag = match *tag_type {
                 "json" => Self::create_json_tag(field, options),
-                "yaml" => Self::create_simple_tag("yaml", &field.json_key),
+                "yaml" => Self::create_omittable_tag("yaml", field, options),
+                "bson" => Self::create_omittable_tag("bson", field, options),
+                "msgpack" => Self::create_simple_tag("msgpack", &field.json_key),
                 "gorm" => Self::create_gorm_tag(field),
                 "db" => Self::create_simple_tag("db", &field.json_key),
                 "sql" => Self::create_simple_tag("sql", &field.json_key),
@@ -481,15 +904,38 @@ impl JsonToGoConverter {
         }
     }
 
-    /// Create JSON tag with version-specific options
+    /// Create JSON tag with the configured omit strategy
     fn create_json_tag(field: &GoField, options: &JsonToGoOptions) -> String {
-        let omitempty_option = if options.is_go_124_or_above {
-            ",omitzero"
-        } else {
-            ",omitempty"
+        let omit_option = match options.json_null_handling.as_deref() {
+            // Fields missing from some samples must round-trip as absent
+            _ if field.optional => ",omitempty",
+            Some("none") => "",
+            Some("omitempty") => ",omitempty",
+            // omitzero only affects struct and time values in a useful way, fall back otherwise
+            Some("omitzero") if Self::supports_omitzero(field, options) => ",omitzero",
+            Some("omitzero") => ",omitempty",
+            _ if options.is_go_124_or_above => ",omitzero",
+            _ => ",omitempty",
         };
 
-        format!("json:\"{}{}\"", field.json_key, omitempty_option)
+        format!("json:\"{}{}\"", field.json_key, omit_option)
+    }
+
+    /// Create a yaml/bson tag that follows the omit strategy when one is configured
+    fn create_omittable_tag(tag_name: &str, field: &GoField, options: &JsonToGoOptions) -> String {
+        match options.json_null_handling.as_deref() {
+            Some("omitempty") | Some("omitzero") => {
+                format!("{}:\"{},omitempty\"", tag_name, field.json_key)
+            }
+            _ => Self::create_simple_tag(tag_name, &field.json_key),
+        }
+    }
+
+    /// Whether omitzero is meaningful for the field (requires Go 1.24)
+    fn supports_omitzero(field: &GoField, options: &JsonToGoOptions) -> bool {
+        options.is_go_124_or_above
+            && !field.is_array
+            && (field.is_nested || field.field_type == "time.Time")
     }
 
     /// Create a simple tag
@@ -538,23 +984,24 @@ mod tests {
     #[test]
     fn test_type_inference() {
         let json_str = r#""test""#;
+        let options = JsonToGoOptions::default();
         let json_value: Value = serde_json::from_str(json_str).unwrap();
         assert_eq!(
-            JsonToGoConverter::infer_go_type_from_json_value(&json_value),
+            JsonToGoConverter::infer_go_type_with_options(&json_value, &options),
             "string"
         );
 
         let json_num = r#"42"#;
         let json_value: Value = serde_json::from_str(json_num).unwrap();
         assert_eq!(
-            JsonToGoConverter::infer_go_type_from_json_value(&json_value),
+            JsonToGoConverter::infer_go_type_with_options(&json_value, &options),
             "int"
         );
 
         let json_bool = r#"true"#;
         let json_value: Value = serde_json::from_str(json_bool).unwrap();
         assert_eq!(
-            JsonToGoConverter::infer_go_type_from_json_value(&json_value),
+            JsonToGoConverter::infer_go_type_with_options(&json_value, &options),
             "bool"
         );
     }
@@ -681,4 +1128,94 @@ mod tests {
         assert!(go_code.contains(r#"json:"email.address,omitempty""#));
         assert!(go_code.contains(r#"json:"phone.number,omitempty""#));
     }
+
+    #[test]
+    fn test_merge_array_samples() {
+        let json = r#"[
+            {"id": 1, "score": 10, "tags": []},
+            {"id": 2, "score": 9.5, "name": "b", "tags": ["x"]},
+            {"id": 3, "score": null, "items": [{"sku": "a"}, {"sku": "b", "qty": 2}]}
+        ]"#;
+
+        let go_code =
+            JsonToGoConverter::convert_json_to_go_structs(json, JsonToGoOptions::default())
+                .unwrap();
+
+        assert!(go_code.contains("Score   float64"));
+        assert!(go_code.contains("Name    string"));
+        assert!(go_code.contains("Tags    []string"));
+        assert!(go_code.contains("Items   []Items"));
+        assert!(go_code.contains("type Items struct"));
+        assert!(go_code.contains("Qty     int"));
+    }
+
+    #[test]
+    fn test_convert_json_samples() {
+        let samples = vec![
+            r#"{"id": 1, "name": "a", "meta": {"v": 1}, "data": 1, "tags": ["x"]}"#.to_string(),
+            r#"{"id": 2, "name": null, "meta": {"v": 2, "note": "n"}, "data": "s"}"#.to_string(),
+        ];
+
+        let result =
+            JsonToGoConverter::convert_json_samples(&samples, JsonToGoOptions::default()).unwrap();
+        let go_code = result.code;
+
+        assert_eq!(result.sample_count, 2);
+        assert!(go_code.starts_with("import \"encoding/json\""));
+        assert!(go_code.contains("Id      int "));
+        assert!(go_code.contains("Name    *string"));
+        assert!(go_code.contains("Meta    Meta"));
+        assert!(go_code.contains("Note    *string"));
+        assert!(go_code.contains("Data    json.RawMessage"));
+        // Slices are already nilable, only omitempty is added
+        assert!(go_code.contains("Tags    []string"));
+        assert_eq!(result.optional_fields, vec!["meta.note", "name", "tags"]);
+        assert_eq!(
+            result.conflicts,
+            vec![TypeConflict {
+                path: "data".to_string(),
+                types: vec!["int".to_string(), "string".to_string()],
+            }]
+        );
+
+        let invalid = vec!["[1, 2]".to_string()];
+        assert!(
+            JsonToGoConverter::convert_json_samples(&invalid, JsonToGoOptions::default()).is_err()
+        );
+    }
+
+    #[test]
+    fn test_number_and_time_options() {
+        let json = r#"{"count": 3, "created_at": "2024-01-02T03:04:05Z"}"#;
+        let options = JsonToGoOptions {
+            detect_time: true,
+            number_mode: "int64".to_string(),
+            ..JsonToGoOptions::default()
+        };
+
+        let go_code = JsonToGoConverter::convert_json_to_go_structs(json, options).unwrap();
+
+        assert!(go_code.starts_with("import \"time\""));
+        assert!(go_code.contains("Count     int64"));
+        assert!(go_code.contains("CreatedAt time.Time"));
+    }
+
+    #[test]
+    fn test_inline_nested_and_tag_options() {
+        let json = r#"{"profile": {"nick": "n"}}"#;
+        let mut options = JsonToGoOptions {
+            nested_mode: "inline".to_string(),
+            json_null_handling: Some("none".to_string()),
+            ..JsonToGoOptions::default()
+        };
+        options.selected_tags.insert("bson".to_string(), true);
+        options.selected_tags.insert("msgpack".to_string(), true);
+
+        let go_code = JsonToGoConverter::convert_json_to_go_structs(json, options).unwrap();
+
+        assert!(!go_code.contains("type Profile struct"));
+        assert!(go_code.contains("Profile struct {"));
+        assert!(go_code.contains(r#"json:"nick" bson:"nick" msgpack:"nick""#));
+        assert!(go_code.contains(r#"} `json:"profile" bson:"profile" msgpack:"profile"`"#));
+    }
 }
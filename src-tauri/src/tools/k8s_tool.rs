@@ -0,0 +1,1213 @@
+use crate::tools::certificate_viewer::{parse_certificate, CertificateInfo};
+use base64::{engine::general_purpose, Engine as _};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use x509_parser::pem::Pem;
+
+/// 内置 schema 支持的 Kubernetes 版本
+const SUPPORTED_VERSIONS: &[&str] = &[
+    "1.20", "1.21", "1.22", "1.23", "1.24", "1.25", "1.26", "1.27", "1.28", "1.29", "1.30", "1.31",
+    "1.32", "1.33", "1.34",
+];
+
+/// 证书剩余天数低于该值时给出提醒
+const CERT_EXPIRY_WARNING_DAYS: i64 = 30;
+
+/// 已废弃的 apiVersion：(apiVersion, kind, 废弃版本, 移除版本, 替代版本)
+type DeprecatedApi = (
+    &'static str,
+    &'static str,
+    &'static str,
+    &'static str,
+    &'static str,
+);
+
+#[rustfmt::skip]
+const DEPRECATED_APIS: &[DeprecatedApi] = &[
+    ("extensions/v1beta1", "Deployment", "1.9", "1.16", "apps/v1"),
+    ("extensions/v1beta1", "DaemonSet", "1.9", "1.16", "apps/v1"),
+    ("extensions/v1beta1", "ReplicaSet", "1.9", "1.16", "apps/v1"),
+    ("extensions/v1beta1", "NetworkPolicy", "1.9", "1.16", "networking.k8s.io/v1"),
+    ("extensions/v1beta1", "PodSecurityPolicy", "1.10", "1.16", "policy/v1beta1"),
+    ("extensions/v1beta1", "Ingress", "1.14", "1.22", "networking.k8s.io/v1"),
+    ("apps/v1beta1", "*", "1.9", "1.16", "apps/v1"),
+    ("apps/v1beta2", "*", "1.9", "1.16", "apps/v1"),
+    ("networking.k8s.io/v1beta1", "Ingress", "1.19", "1.22", "networking.k8s.io/v1"),
+    ("networking.k8s.io/v1beta1", "IngressClass", "1.19", "1.22", "networking.k8s.io/v1"),
+    ("rbac.authorization.k8s.io/v1beta1", "*", "1.17", "1.22", "rbac.authorization.k8s.io/v1"),
+    ("apiextensions.k8s.io/v1beta1", "CustomResourceDefinition", "1.16", "1.22", "apiextensions.k8s.io/v1"),
+    ("admissionregistration.k8s.io/v1beta1", "*", "1.16", "1.22", "admissionregistration.k8s.io/v1"),
+    ("apiregistration.k8s.io/v1beta1", "APIService", "1.19", "1.22", "apiregistration.k8s.io/v1"),
+    ("certificates.k8s.io/v1beta1", "CertificateSigningRequest", "1.19", "1.22", "certificates.k8s.io/v1"),
+    ("coordination.k8s.io/v1beta1", "Lease", "1.19", "1.22", "coordination.k8s.io/v1"),
+    ("scheduling.k8s.io/v1beta1", "PriorityClass", "1.14", "1.22", "scheduling.k8s.io/v1"),
+    ("storage.k8s.io/v1beta1", "CSIDriver", "1.19", "1.22", "storage.k8s.io/v1"),
+    ("storage.k8s.io/v1beta1", "CSINode", "1.17", "1.22", "storage.k8s.io/v1"),
+    ("storage.k8s.io/v1beta1", "StorageClass", "1.19", "1.22", "storage.k8s.io/v1"),
+    ("storage.k8s.io/v1beta1", "VolumeAttachment", "1.19", "1.22", "storage.k8s.io/v1"),
+    ("storage.k8s.io/v1beta1", "CSIStorageCapacity", "1.24", "1.27", "storage.k8s.io/v1"),
+    ("batch/v1beta1", "CronJob", "1.21", "1.25", "batch/v1"),
+    ("policy/v1beta1", "PodDisruptionBudget", "1.21", "1.25", "policy/v1"),
+    ("policy/v1beta1", "PodSecurityPolicy", "1.21", "1.25", ""),
+    ("discovery.k8s.io/v1beta1", "EndpointSlice", "1.21", "1.25", "discovery.k8s.io/v1"),
+    ("events.k8s.io/v1beta1", "Event", "1.19", "1.25", "events.k8s.io/v1"),
+    ("autoscaling/v2beta1", "HorizontalPodAutoscaler", "1.22", "1.25", "autoscaling/v2"),
+    ("autoscaling/v2beta2", "HorizontalPodAutoscaler", "1.23", "1.26", "autoscaling/v2"),
+    ("flowcontrol.apiserver.k8s.io/v1beta1", "*", "1.23", "1.26", "flowcontrol.apiserver.k8s.io/v1"),
+    ("flowcontrol.apiserver.k8s.io/v1beta2", "*", "1.26", "1.29", "flowcontrol.apiserver.k8s.io/v1"),
+    ("flowcontrol.apiserver.k8s.io/v1beta3", "*", "1.29", "1.32", "flowcontrol.apiserver.k8s.io/v1"),
+];
+
+/// 内置资源 schema：(apiVersion, kind, 引入版本, 必填字段路径)
+#[rustfmt::skip]
+const RESOURCE_SCHEMAS: &[(&str, &str, &str, &[&str])] = &[
+    ("v1", "Pod", "1.0", &["spec.containers"]),
+    ("v1", "Service", "1.0", &["spec"]),
+    ("v1", "ConfigMap", "1.0", &[]),
+    ("v1", "Secret", "1.0", &[]),
+    ("v1", "Namespace", "1.0", &[]),
+    ("v1", "ServiceAccount", "1.0", &[]),
+    ("v1", "PersistentVolumeClaim", "1.0", &["spec.accessModes", "spec.resources.requests.storage"]),
+    ("v1", "PersistentVolume", "1.0", &["spec.capacity.storage", "spec.accessModes"]),
+    ("v1", "LimitRange", "1.0", &["spec.limits"]),
+    ("v1", "ResourceQuota", "1.0", &["spec"]),
+    ("apps/v1", "Deployment", "1.9", &["spec.selector", "spec.template.spec.containers"]),
+    ("apps/v1", "StatefulSet", "1.9", &["spec.selector", "spec.template.spec.containers"]),
+    ("apps/v1", "DaemonSet", "1.9", &["spec.selector", "spec.template.spec.containers"]),
+    ("apps/v1", "ReplicaSet", "1.9", &["spec.selector", "spec.template.spec.containers"]),
+    ("batch/v1", "Job", "1.0", &["spec.template.spec.containers"]),
+    ("batch/v1", "CronJob", "1.21", &["spec.schedule", "spec.jobTemplate.spec.template.spec.containers"]),
+    ("networking.k8s.io/v1", "Ingress", "1.19", &["spec"]),
+    ("networking.k8s.io/v1", "IngressClass", "1.19", &["spec.controller"]),
+    ("networking.k8s.io/v1", "NetworkPolicy", "1.7", &["spec.podSelector"]),
+    ("autoscaling/v1", "HorizontalPodAutoscaler", "1.2", &["spec.scaleTargetRef", "spec.maxReplicas"]),
+    ("autoscaling/v2", "HorizontalPodAutoscaler", "1.23", &["spec.scaleTargetRef", "spec.maxReplicas"]),
+    ("policy/v1", "PodDisruptionBudget", "1.21", &["spec"]),
+    ("rbac.authorization.k8s.io/v1", "Role", "1.8", &[]),
+    ("rbac.authorization.k8s.io/v1", "ClusterRole", "1.8", &[]),
+    ("rbac.authorization.k8s.io/v1", "RoleBinding", "1.8", &["roleRef.kind", "roleRef.name"]),
+    ("rbac.authorization.k8s.io/v1", "ClusterRoleBinding", "1.8", &["roleRef.kind", "roleRef.name"]),
+    ("storage.k8s.io/v1", "StorageClass", "1.6", &["provisioner"]),
+    ("apiextensions.k8s.io/v1", "CustomResourceDefinition", "1.16", &["spec.group", "spec.names.kind", "spec.versions"]),
+    ("scheduling.k8s.io/v1", "PriorityClass", "1.14", &["value"]),
+    ("flowcontrol.apiserver.k8s.io/v1", "FlowSchema", "1.29", &["spec.priorityLevelConfiguration"]),
+    ("flowcontrol.apiserver.k8s.io/v1", "PriorityLevelConfiguration", "1.29", &["spec.type"]),
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct K8sValidateRequest {
+    pub content: String,
+    pub target_version: Option<String>, // 如 "1.29"，默认最新
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct K8sIssue {
+    pub severity: String, // error / warning / info
+    pub path: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct K8sDeprecation {
+    pub deprecated_in: String,
+    pub removed_in: String,
+    pub replacement: Option<String>,
+    pub removed_in_target: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct K8sResourceReport {
+    pub document_index: usize,
+    pub api_version: String,
+    pub kind: String,
+    pub name: Option<String>,
+    pub namespace: Option<String>,
+    pub schema_known: bool,
+    pub deprecation: Option<K8sDeprecation>,
+    pub issues: Vec<K8sIssue>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct K8sValidateResult {
+    pub target_version: String,
+    pub supported_versions: Vec<String>,
+    pub resources: Vec<K8sResourceReport>,
+    pub error_count: usize,
+    pub warning_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KubeContext {
+    pub name: String,
+    pub cluster: String,
+    pub user: String,
+    pub namespace: Option<String>,
+    pub is_current: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KubeCluster {
+    pub name: String,
+    pub server: String,
+    pub insecure_skip_tls_verify: bool,
+    pub certificate_authority_path: Option<String>,
+    pub certificate_authority: Option<CertificateInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KubeUser {
+    pub name: String,
+    pub auth_type: String, // client-certificate / token / exec / auth-provider / basic / none
+    pub client_certificate_path: Option<String>,
+    pub client_certificate: Option<CertificateInfo>,
+    pub exec_command: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KubeconfigInfo {
+    pub current_context: Option<String>,
+    pub contexts: Vec<KubeContext>,
+    pub clusters: Vec<KubeCluster>,
+    pub users: Vec<KubeUser>,
+    pub warnings: Vec<String>,
+}
+
+/// 将 "1.29" 解析为可比较的 (主版本, 次版本)
+fn parse_version(version: &str) -> Option<(u32, u32)> {
+    let trimmed = version.trim().trim_start_matches('v');
+    let mut parts = trimmed.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next().unwrap_or("0").parse().ok()?;
+    Some((major, minor))
+}
+
+fn version_at_least(target: &str, required: &str) -> bool {
+    match (parse_version(target), parse_version(required)) {
+        (Some(t), Some(r)) => t >= r,
+        _ => true,
+    }
+}
+
+/// 按 "spec.template.spec.containers" 形式的路径取值
+fn get_path<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .try_fold(value, |current, key| current.get(key))
+        .filter(|v| !v.is_null())
+}
+
+fn issue(severity: &str, path: &str, message: String) -> K8sIssue {
+    K8sIssue {
+        severity: severity.to_string(),
+        path: path.to_string(),
+        message,
+    }
+}
+
+fn is_dns_subdomain(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= 253
+        && name.split('.').all(|label| {
+            !label.is_empty()
+                && label
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+        })
+}
+
+fn is_dns_label(name: &str) -> bool {
+    name.len() <= 63 && !name.contains('.') && is_dns_subdomain(name)
+}
+
+fn validate_metadata(resource: &Value, issues: &mut Vec<K8sIssue>) {
+    let metadata = resource.get("metadata");
+    let name = metadata
+        .and_then(|m| m.get("name"))
+        .and_then(|n| n.as_str());
+    let generate_name = metadata.and_then(|m| m.get("generateName"));
+    match name {
+        None if generate_name.is_none() => issues.push(issue(
+            "error",
+            "metadata.name",
+            "缺少 metadata.name".to_string(),
+        )),
+        Some(n) if !is_dns_subdomain(n) => issues.push(issue(
+            "error",
+            "metadata.name",
+            format!(
+                "名称 '{}' 不符合 DNS-1123 子域名规范（小写字母、数字、- 和 .）",
+                n
+            ),
+        )),
+        _ => {}
+    }
+
+    if let Some(labels) = metadata.and_then(|m| m.get("labels")) {
+        match labels.as_object() {
+            Some(map) => {
+                for (key, value) in map {
+                    match value.as_str() {
+                        Some(v) if v.len() > 63 => issues.push(issue(
+                            "error",
+                            &format!("metadata.labels.{}", key),
+                            "标签值长度不能超过 63 个字符".to_string(),
+                        )),
+                        Some(_) => {}
+                        None => issues.push(issue(
+                            "error",
+                            &format!("metadata.labels.{}", key),
+                            "标签值必须为字符串（数字或布尔值需加引号）".to_string(),
+                        )),
+                    }
+                }
+            }
+            None => issues.push(issue(
+                "error",
+                "metadata.labels",
+                "labels 必须为键值映射".to_string(),
+            )),
+        }
+    }
+}
+
+fn validate_containers(pod_spec: &Value, base_path: &str, issues: &mut Vec<K8sIssue>) {
+    for field in ["initContainers", "containers"] {
+        let Some(containers) = pod_spec.get(field) else {
+            continue;
+        };
+        let path = format!("{}.{}", base_path, field);
+        let Some(list) = containers.as_array() else {
+            issues.push(issue("error", &path, format!("{} 必须为数组", field)));
+            continue;
+        };
+        if field == "containers" && list.is_empty() {
+            issues.push(issue("error", &path, "至少需要一个容器".to_string()));
+        }
+
+        let mut names = HashSet::new();
+        for (index, container) in list.iter().enumerate() {
+            let item_path = format!("{}[{}]", path, index);
+            match container.get("name").and_then(|n| n.as_str()) {
+                None => issues.push(issue(
+                    "error",
+                    &format!("{}.name", item_path),
+                    "缺少容器名称".to_string(),
+                )),
+                Some(name) => {
+                    if !is_dns_label(name) {
+                        issues.push(issue(
+                            "error",
+                            &format!("{}.name", item_path),
+                            format!("容器名称 '{}' 不符合 DNS-1123 标签规范", name),
+                        ));
+                    }
+                    if !names.insert(name.to_string()) {
+                        issues.push(issue(
+                            "error",
+                            &format!("{}.name", item_path),
+                            format!("容器名称 '{}' 重复", name),
+                        ));
+                    }
+                }
+            }
+
+            match container.get("image").and_then(|i| i.as_str()) {
+                None => issues.push(issue(
+                    "error",
+                    &format!("{}.image", item_path),
+                    "缺少镜像".to_string(),
+                )),
+                Some(image) => {
+                    let last = image.rsplit('/').next().unwrap_or(image);
+                    if !image.contains('@') && (!last.contains(':') || last.ends_with(":latest")) {
+                        issues.push(issue(
+                            "warning",
+                            &format!("{}.image", item_path),
+                            format!("镜像 {} 使用 latest 标签，部署结果不可复现", image),
+                        ));
+                    }
+                }
+            }
+
+            if field == "containers" && get_path(container, "resources.limits").is_none() {
+                issues.push(issue(
+                    "info",
+                    &format!("{}.resources", item_path),
+                    "未设置资源限制 (resources.limits)".to_string(),
+                ));
+            }
+            if let Some(ports) = container.get("ports").and_then(|p| p.as_array()) {
+                for (port_index, port) in ports.iter().enumerate() {
+                    let valid = port
+                        .get("containerPort")
+                        .and_then(|p| p.as_u64())
+                        .is_some_and(|p| (1..=65535).contains(&p));
+                    if !valid {
+                        issues.push(issue(
+                            "error",
+                            &format!("{}.ports[{}].containerPort", item_path, port_index),
+                            "containerPort 必须为 1-65535 之间的整数".to_string(),
+                        ));
+                    }
+                }
+            }
+            if get_path(container, "securityContext.privileged").and_then(|p| p.as_bool())
+                == Some(true)
+            {
+                issues.push(issue(
+                    "warning",
+                    &format!("{}.securityContext.privileged", item_path),
+                    "容器以特权模式运行".to_string(),
+                ));
+            }
+        }
+    }
+}
+
+/// 各资源类型的专项校验
+fn validate_kind(kind: &str, resource: &Value, issues: &mut Vec<K8sIssue>) {
+    match kind {
+        "Pod" => {
+            if let Some(spec) = resource.get("spec") {
+                validate_containers(spec, "spec", issues);
+            }
+        }
+        "Deployment" | "StatefulSet" | "DaemonSet" | "ReplicaSet" | "Job" => {
+            if let Some(pod_spec) = get_path(resource, "spec.template.spec") {
+                validate_containers(pod_spec, "spec.template.spec", issues);
+            }
+            if kind == "Job" {
+                let policy =
+                    get_path(resource, "spec.template.spec.restartPolicy").and_then(|p| p.as_str());
+                if !matches!(policy, Some("Never") | Some("OnFailure")) {
+                    issues.push(issue(
+                        "error",
+                        "spec.template.spec.restartPolicy",
+                        "Job 的 restartPolicy 必须为 Never 或 OnFailure".to_string(),
+                    ));
+                }
+                return;
+            }
+            // selector.matchLabels 必须能匹配 Pod 模板标签
+            let match_labels =
+                get_path(resource, "spec.selector.matchLabels").and_then(|m| m.as_object());
+            let template_labels =
+                get_path(resource, "spec.template.metadata.labels").and_then(|m| m.as_object());
+            if let Some(selector) = match_labels {
+                let matched = selector
+                    .iter()
+                    .all(|(k, v)| template_labels.is_some_and(|labels| labels.get(k) == Some(v)));
+                if !matched {
+                    issues.push(issue(
+                        "error",
+                        "spec.selector.matchLabels",
+                        "selector 与 spec.template.metadata.labels 不匹配".to_string(),
+                    ));
+                }
+            }
+            if kind == "StatefulSet" && get_path(resource, "spec.serviceName").is_none() {
+                issues.push(issue(
+                    "info",
+                    "spec.serviceName",
+                    "StatefulSet 未指定 serviceName，Pod 将没有稳定的网络标识".to_string(),
+                ));
+            }
+        }
+        "CronJob" => {
+            if let Some(schedule) = get_path(resource, "spec.schedule").and_then(|s| s.as_str()) {
+                let fields = schedule.split_whitespace().count();
+                if fields != 5 && !schedule.starts_with('@') {
+                    issues.push(issue(
+                        "error",
+                        "spec.schedule",
+                        format!("cron 表达式应包含 5 个字段，当前为 {} 个", fields),
+                    ));
+                }
+            }
+            if let Some(pod_spec) = get_path(resource, "spec.jobTemplate.spec.template.spec") {
+                validate_containers(pod_spec, "spec.jobTemplate.spec.template.spec", issues);
+            }
+        }
+        "Service" => {
+            let service_type = get_path(resource, "spec.type")
+                .and_then(|t| t.as_str())
+                .unwrap_or("ClusterIP");
+            if !["ClusterIP", "NodePort", "LoadBalancer", "ExternalName"].contains(&service_type) {
+                issues.push(issue(
+                    "error",
+                    "spec.type",
+                    format!("未知的 Service 类型: {}", service_type),
+                ));
+            }
+            if service_type == "ExternalName" {
+                if get_path(resource, "spec.externalName").is_none() {
+                    issues.push(issue(
+                        "error",
+                        "spec.externalName",
+                        "ExternalName 类型必须设置 externalName".to_string(),
+                    ));
+                }
+                return;
+            }
+            match get_path(resource, "spec.ports").and_then(|p| p.as_array()) {
+                None => issues.push(issue(
+                    "error",
+                    "spec.ports",
+                    "Service 需要至少一个端口".to_string(),
+                )),
+                Some(ports) => {
+                    for (index, port) in ports.iter().enumerate() {
+                        let valid = port
+                            .get("port")
+                            .and_then(|p| p.as_u64())
+                            .is_some_and(|p| (1..=65535).contains(&p));
+                        if !valid {
+                            issues.push(issue(
+                                "error",
+                                &format!("spec.ports[{}].port", index),
+                                "port 必须为 1-65535 之间的整数".to_string(),
+                            ));
+                        }
+                        if ports.len() > 1 && port.get("name").is_none() {
+                            issues.push(issue(
+                                "error",
+                                &format!("spec.ports[{}].name", index),
+                                "多端口 Service 的每个端口都必须命名".to_string(),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+        "Secret" => {
+            if let Some(data) = resource.get("data").and_then(|d| d.as_object()) {
+                for (key, value) in data {
+                    let valid = value
+                        .as_str()
+                        .is_some_and(|v| general_purpose::STANDARD.decode(v.trim()).is_ok());
+                    if !valid {
+                        issues.push(issue(
+                            "error",
+                            &format!("data.{}", key),
+                            "Secret data 的值必须为 Base64 编码，明文请使用 stringData".to_string(),
+                        ));
+                    }
+                }
+            }
+        }
+        "ConfigMap" => {
+            if let Some(data) = resource.get("data").and_then(|d| d.as_object()) {
+                for (key, value) in data {
+                    if !value.is_string() {
+                        issues.push(issue(
+                            "error",
+                            &format!("data.{}", key),
+                            "ConfigMap data 的值必须为字符串".to_string(),
+                        ));
+                    }
+                }
+            }
+        }
+        "Ingress" => {
+            let rules = get_path(resource, "spec.rules")
+                .and_then(|r| r.as_array())
+                .cloned()
+                .unwrap_or_default();
+            for (rule_index, rule) in rules.iter().enumerate() {
+                let paths = get_path(rule, "http.paths")
+                    .and_then(|p| p.as_array())
+                    .cloned()
+                    .unwrap_or_default();
+                for (path_index, path) in paths.iter().enumerate() {
+                    let base = format!("spec.rules[{}].http.paths[{}]", rule_index, path_index);
+                    let path_type = path.get("pathType").and_then(|p| p.as_str());
+                    if !matches!(
+                        path_type,
+                        Some("Exact") | Some("Prefix") | Some("ImplementationSpecific")
+                    ) {
+                        issues.push(issue(
+                            "error",
+                            &format!("{}.pathType", base),
+                            "pathType 必须为 Exact、Prefix 或 ImplementationSpecific".to_string(),
+                        ));
+                    }
+                    if get_path(path, "backend.service.name").is_none()
+                        && get_path(path, "backend.resource").is_none()
+                    {
+                        issues.push(issue(
+                            "error",
+                            &format!("{}.backend", base),
+                            "backend 需要指定 service.name 或 resource".to_string(),
+                        ));
+                    }
+                }
+            }
+        }
+        "HorizontalPodAutoscaler" => {
+            let min = get_path(resource, "spec.minReplicas")
+                .and_then(|m| m.as_u64())
+                .unwrap_or(1);
+            let max = get_path(resource, "spec.maxReplicas").and_then(|m| m.as_u64());
+            if max.is_some_and(|max| max < min) {
+                issues.push(issue(
+                    "error",
+                    "spec.maxReplicas",
+                    "maxReplicas 不能小于 minReplicas".to_string(),
+                ));
+            }
+        }
+        _ => {}
+    }
+}
+
+fn find_deprecation(api_version: &str, kind: &str) -> Option<&'static DeprecatedApi> {
+    DEPRECATED_APIS
+        .iter()
+        .find(|(v, k, _, _, _)| *v == api_version && (*k == kind || *k == "*"))
+}
+
+fn validate_resource(
+    document_index: usize,
+    resource: &Value,
+    target_version: &str,
+) -> K8sResourceReport {
+    let api_version = resource
+        .get("apiVersion")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+    let kind = resource
+        .get("kind")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+    let mut issues = Vec::new();
+
+    if api_version.is_empty() {
+        issues.push(issue("error", "apiVersion", "缺少 apiVersion".to_string()));
+    }
+    if kind.is_empty() {
+        issues.push(issue("error", "kind", "缺少 kind".to_string()));
+    }
+    validate_metadata(resource, &mut issues);
+
+    let deprecation = find_deprecation(&api_version, &kind).map(
+        |(_, _, deprecated_in, removed_in, replacement)| {
+            let removed_in_target = version_at_least(target_version, removed_in);
+            let replacement = (!replacement.is_empty()).then(|| replacement.to_string());
+            let suggestion = replacement
+                .as_deref()
+                .map(|r| format!("，请迁移到 {}", r))
+                .unwrap_or_default();
+            if removed_in_target {
+                issues.push(issue(
+                    "error",
+                    "apiVersion",
+                    format!(
+                        "{} {} 已在 {} 中移除{}",
+                        api_version, kind, removed_in, suggestion
+                    ),
+                ));
+            } else {
+                issues.push(issue(
+                    "warning",
+                    "apiVersion",
+                    format!(
+                        "{} {} 自 {} 起废弃，将在 {} 中移除{}",
+                        api_version, kind, deprecated_in, removed_in, suggestion
+                    ),
+                ));
+            }
+            K8sDeprecation {
+                deprecated_in: deprecated_in.to_string(),
+                removed_in: removed_in.to_string(),
+                replacement,
+                removed_in_target,
+            }
+        },
+    );
+
+    let schema = RESOURCE_SCHEMAS
+        .iter()
+        .find(|(v, k, _, _)| *v == api_version && *k == kind);
+    match schema {
+        Some((_, _, introduced, required)) => {
+            if !version_at_least(target_version, introduced) {
+                issues.push(issue(
+                    "error",
+                    "apiVersion",
+                    format!("{} {} 自 {} 起才可用", api_version, kind, introduced),
+                ));
+            }
+            for path in required.iter() {
+                if get_path(resource, path).is_none() {
+                    issues.push(issue("error", path, format!("缺少必填字段 {}", path)));
+                }
+            }
+            validate_kind(&kind, resource, &mut issues);
+        }
+        None if deprecation.is_none() && !kind.is_empty() => issues.push(issue(
+            "info",
+            "kind",
+            format!("未内置 {} {} 的 schema，仅校验通用字段", api_version, kind),
+        )),
+        None => {}
+    }
+
+    let metadata = resource.get("metadata");
+    K8sResourceReport {
+        document_index,
+        name: metadata
+            .and_then(|m| m.get("name"))
+            .and_then(|n| n.as_str())
+            .map(str::to_string),
+        namespace: metadata
+            .and_then(|m| m.get("namespace"))
+            .and_then(|n| n.as_str())
+            .map(str::to_string),
+        api_version,
+        kind,
+        schema_known: schema.is_some(),
+        deprecation,
+        issues,
+    }
+}
+
+/// 校验 Kubernetes 清单（支持多文档与 kind: List）
+#[tauri::command]
+pub fn validate_k8s_manifest(request: K8sValidateRequest) -> Result<K8sValidateResult, String> {
+    if request.content.trim().is_empty() {
+        return Err("清单内容不能为空".to_string());
+    }
+    let target_version = match request.target_version.as_deref().map(str::trim) {
+        Some(v) if !v.is_empty() => {
+            if parse_version(v).is_none() {
+                return Err(format!("无效的 Kubernetes 版本: {}", v));
+            }
+            v.trim_start_matches('v').to_string()
+        }
+        _ => SUPPORTED_VERSIONS.last().unwrap_or(&"1.34").to_string(),
+    };
+
+    let mut resources = Vec::new();
+    for (index, document) in serde_yaml::Deserializer::from_str(&request.content).enumerate() {
+        let yaml = serde_yaml::Value::deserialize(document)
+            .map_err(|e| format!("第 {} 个文档 YAML 解析失败: {}", index + 1, e))?;
+        if yaml.is_null() {
+            continue;
+        }
+        let value = serde_json::to_value(&yaml)
+            .map_err(|e| format!("第 {} 个文档结构无效: {}", index + 1, e))?;
+
+        let items = match (
+            value.get("kind").and_then(|k| k.as_str()),
+            value.get("items"),
+        ) {
+            (Some(kind), Some(Value::Array(items))) if kind.ends_with("List") => items.clone(),
+            _ => vec![value],
+        };
+        for item in &items {
+            resources.push(validate_resource(index + 1, item, &target_version));
+        }
+    }
+    if resources.is_empty() {
+        return Err("未找到任何 Kubernetes 资源".to_string());
+    }
+
+    let count = |severity: &str| {
+        resources
+            .iter()
+            .flat_map(|r| r.issues.iter())
+            .filter(|i| i.severity == severity)
+            .count()
+    };
+    Ok(K8sValidateResult {
+        error_count: count("error"),
+        warning_count: count("warning"),
+        target_version,
+        supported_versions: SUPPORTED_VERSIONS.iter().map(|v| v.to_string()).collect(),
+        resources,
+    })
+}
+
+fn expand_home(path: &str) -> String {
+    match path.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir()
+            .map(|home| home.join(rest).to_string_lossy().to_string())
+            .unwrap_or_else(|| path.to_string()),
+        None => path.to_string(),
+    }
+}
+
+/// 解析 PEM 证书内容并复用证书查看器的解析逻辑
+fn parse_pem_info(pem_bytes: &[u8]) -> Result<CertificateInfo, String> {
+    let pem = Pem::iter_from_buffer(pem_bytes)
+        .next()
+        .ok_or("未找到 PEM 证书")?
+        .map_err(|e| format!("PEM 解析失败: {}", e))?;
+    let cert = pem
+        .parse_x509()
+        .map_err(|e| format!("证书解析失败: {}", e))?;
+    parse_certificate(&cert)
+}
+
+/// 读取内嵌的 *-data 字段或引用的证书文件
+fn load_embedded_certificate(
+    entry: &Value,
+    data_key: &str,
+    path_key: &str,
+    owner: &str,
+    warnings: &mut Vec<String>,
+) -> (Option<String>, Option<CertificateInfo>) {
+    let path = entry
+        .get(path_key)
+        .and_then(|p| p.as_str())
+        .map(str::to_string);
+    let pem = if let Some(data) = entry.get(data_key).and_then(|d| d.as_str()) {
+        match general_purpose::STANDARD.decode(data.trim()) {
+            Ok(bytes) => Some(bytes),
+            Err(e) => {
+                warnings.push(format!("{} 的 {} Base64 解码失败: {}", owner, data_key, e));
+                None
+            }
+        }
+    } else if let Some(p) = &path {
+        match std::fs::read(expand_home(p)) {
+            Ok(bytes) => Some(bytes),
+            Err(e) => {
+                warnings.push(format!("{} 无法读取证书文件 {}: {}", owner, p, e));
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    let info = pem.and_then(|bytes| match parse_pem_info(&bytes) {
+        Ok(info) => Some(info),
+        Err(e) => {
+            warnings.push(format!("{} 的证书解析失败: {}", owner, e));
+            None
+        }
+    });
+    if let Some(cert) = &info {
+        let days = cert.validity.days_until_expiry;
+        if days < 0 {
+            warnings.push(format!(
+                "{} 的证书已于 {} 过期",
+                owner, cert.validity.not_after
+            ));
+        } else if days < CERT_EXPIRY_WARNING_DAYS {
+            warnings.push(format!("{} 的证书将在 {} 天后过期", owner, days));
+        }
+    }
+    (path, info)
+}
+
+/// 解析 kubeconfig，列出上下文、集群、用户及内嵌证书有效期
+#[tauri::command]
+pub fn parse_kubeconfig(content: String) -> Result<KubeconfigInfo, String> {
+    if content.trim().is_empty() {
+        return Err("kubeconfig 内容不能为空".to_string());
+    }
+    let yaml: serde_yaml::Value =
+        serde_yaml::from_str(&content).map_err(|e| format!("YAML 解析失败: {}", e))?;
+    let config = serde_json::to_value(&yaml).map_err(|e| format!("kubeconfig 结构无效: {}", e))?;
+    if config.get("kind").and_then(|k| k.as_str()) != Some("Config") {
+        return Err("不是有效的 kubeconfig 文件（kind 应为 Config）".to_string());
+    }
+
+    let mut warnings = Vec::new();
+    let list = |key: &str| {
+        config
+            .get(key)
+            .and_then(|v| v.as_array())
+            .cloned()
+            .unwrap_or_default()
+    };
+    let str_of = |v: &Value, path: &str| {
+        get_path(v, path)
+            .and_then(|s| s.as_str())
+            .unwrap_or_default()
+            .to_string()
+    };
+    let current_context = config
+        .get("current-context")
+        .and_then(|c| c.as_str())
+        .filter(|c| !c.is_empty())
+        .map(str::to_string);
+
+    let mut clusters = Vec::new();
+    for entry in list("clusters") {
+        let name = str_of(&entry, "name");
+        let cluster = entry.get("cluster").cloned().unwrap_or_default();
+        let insecure = cluster
+            .get("insecure-skip-tls-verify")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        if insecure {
+            warnings.push(format!("集群 {} 跳过了 TLS 证书校验", name));
+        }
+        let (certificate_authority_path, certificate_authority) = load_embedded_certificate(
+            &cluster,
+            "certificate-authority-data",
+            "certificate-authority",
+            &format!("集群 {}", name),
+            &mut warnings,
+        );
+        clusters.push(KubeCluster {
+            server: str_of(&cluster, "server"),
+            name,
+            insecure_skip_tls_verify: insecure,
+            certificate_authority_path,
+            certificate_authority,
+        });
+    }
+
+    let mut users = Vec::new();
+    for entry in list("users") {
+        let name = str_of(&entry, "name");
+        let user = entry.get("user").cloned().unwrap_or_default();
+        let (client_certificate_path, client_certificate) = load_embedded_certificate(
+            &user,
+            "client-certificate-data",
+            "client-certificate",
+            &format!("用户 {}", name),
+            &mut warnings,
+        );
+        let auth_type = if client_certificate.is_some() || client_certificate_path.is_some() {
+            "client-certificate"
+        } else if user.get("token").is_some() || user.get("tokenFile").is_some() {
+            "token"
+        } else if user.get("exec").is_some() {
+            "exec"
+        } else if user.get("auth-provider").is_some() {
+            "auth-provider"
+        } else if user.get("username").is_some() {
+            "basic"
+        } else {
+            "none"
+        };
+        if auth_type == "basic" {
+            warnings.push(format!(
+                "用户 {} 使用 Basic 认证，新版本集群已不再支持",
+                name
+            ));
+        }
+        let exec_command = get_path(&user, "exec.command")
+            .and_then(|c| c.as_str())
+            .map(|command| {
+                let args: Vec<String> = get_path(&user, "exec.args")
+                    .and_then(|a| a.as_array())
+                    .map(|a| {
+                        a.iter()
+                            .filter_map(|x| x.as_str().map(str::to_string))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                std::iter::once(command.to_string())
+                    .chain(args)
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            });
+        users.push(KubeUser {
+            name,
+            auth_type: auth_type.to_string(),
+            client_certificate_path,
+            client_certificate,
+            exec_command,
+        });
+    }
+
+    let cluster_names: HashSet<&str> = clusters.iter().map(|c| c.name.as_str()).collect();
+    let user_names: HashSet<&str> = users.iter().map(|u| u.name.as_str()).collect();
+    let mut contexts = Vec::new();
+    for entry in list("contexts") {
+        let name = str_of(&entry, "name");
+        let cluster = str_of(&entry, "context.cluster");
+        let user = str_of(&entry, "context.user");
+        if !cluster_names.contains(cluster.as_str()) {
+            warnings.push(format!("上下文 {} 引用了不存在的集群 {}", name, cluster));
+        }
+        if !user.is_empty() && !user_names.contains(user.as_str()) {
+            warnings.push(format!("上下文 {} 引用了不存在的用户 {}", name, user));
+        }
+        contexts.push(KubeContext {
+            is_current: current_context.as_deref() == Some(name.as_str()),
+            namespace: get_path(&entry, "context.namespace")
+                .and_then(|n| n.as_str())
+                .map(str::to_string),
+            name,
+            cluster,
+            user,
+        });
+    }
+    if let Some(current) = &current_context {
+        if !contexts.iter().any(|c| &c.name == current) {
+            warnings.push(format!("current-context {} 不存在", current));
+        }
+    }
+
+    // 同名条目会导致 kubectl 只使用第一个
+    let mut seen: HashMap<&str, usize> = HashMap::new();
+    for name in contexts.iter().map(|c| c.name.as_str()) {
+        *seen.entry(name).or_default() += 1;
+    }
+    for (name, count) in seen {
+        if count > 1 {
+            warnings.push(format!("上下文 {} 定义了 {} 次", name, count));
+        }
+    }
+
+    Ok(KubeconfigInfo {
+        current_context,
+        contexts,
+        clusters,
+        users,
+        warnings,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validate(content: &str, target_version: Option<&str>) -> K8sValidateResult {
+        validate_k8s_manifest(K8sValidateRequest {
+            content: content.to_string(),
+            target_version: target_version.map(str::to_string),
+        })
+        .unwrap()
+    }
+
+    fn messages(report: &K8sResourceReport) -> Vec<&str> {
+        report.issues.iter().map(|i| i.message.as_str()).collect()
+    }
+
+    #[test]
+    fn test_validate_valid_manifests() {
+        let content = r#"
+apiVersion: apps/v1
+kind: Deployment
+metadata:
+  name: web
+  namespace: prod
+  labels:
+    app: web
+spec:
+  selector:
+    matchLabels:
+      app: web
+  template:
+    metadata:
+      labels:
+        app: web
+    spec:
+      containers:
+        - name: web
+          image: nginx:1.27
+          ports:
+            - containerPort: 80
+          resources:
+            limits:
+              cpu: 500m
+---
+apiVersion: v1
+kind: List
+items:
+  - apiVersion: v1
+    kind: Service
+    metadata:
+      name: web
+    spec:
+      ports:
+        - port: 80
+  - apiVersion: v1
+    kind: ConfigMap
+    metadata:
+      name: web-config
+    data:
+      LEVEL: "debug"
+"#;
+        let result = validate(content, None);
+        assert_eq!(result.target_version, "1.34");
+        assert_eq!((result.error_count, result.warning_count), (0, 0));
+        assert_eq!(result.resources.len(), 3);
+        assert_eq!(result.resources[0].namespace.as_deref(), Some("prod"));
+        assert_eq!(result.resources[2].document_index, 2);
+        assert!(result.resources.iter().all(|r| r.schema_known));
+        assert!(result.resources.iter().all(|r| r.issues.is_empty()));
+    }
+
+    #[test]
+    fn test_validate_deprecated_api() {
+        let content = "apiVersion: extensions/v1beta1\nkind: Ingress\nmetadata:\n  name: old\n";
+        let removed = validate(content, Some("1.29"));
+        let deprecation = removed.resources[0].deprecation.as_ref().unwrap();
+        assert!(deprecation.removed_in_target);
+        assert_eq!(
+            deprecation.replacement.as_deref(),
+            Some("networking.k8s.io/v1")
+        );
+        assert_eq!(removed.error_count, 1);
+
+        let deprecated = validate(content, Some("v1.20"));
+        assert_eq!(deprecated.target_version, "1.20");
+        assert_eq!((deprecated.error_count, deprecated.warning_count), (0, 1));
+    }
+
+    #[test]
+    fn test_validate_invalid_fields() {
+        let content = r#"
+apiVersion: apps/v1
+kind: Deployment
+metadata:
+  name: Web_App
+  labels:
+    tier: 1
+spec:
+  selector:
+    matchLabels:
+      app: web
+  template:
+    metadata:
+      labels:
+        app: api
+    spec:
+      containers:
+        - name: app
+          image: nginx
+---
+apiVersion: batch/v1
+kind: Job
+metadata:
+  name: once
+spec:
+  template:
+    spec:
+      containers: []
+"#;
+        let result = validate(content, None);
+        let deployment = messages(&result.resources[0]);
+        assert!(deployment[0].starts_with("名称 'Web_App' 不符合"));
+        assert!(deployment.contains(&"标签值必须为字符串（数字或布尔值需加引号）"));
+        assert!(deployment.contains(&"selector 与 spec.template.metadata.labels 不匹配"));
+        assert!(deployment.contains(&"镜像 nginx 使用 latest 标签，部署结果不可复现"));
+        let job = messages(&result.resources[1]);
+        assert!(job.contains(&"至少需要一个容器"));
+        assert!(job.contains(&"Job 的 restartPolicy 必须为 Never 或 OnFailure"));
+        assert_eq!(result.error_count, 5);
+    }
+
+    #[test]
+    fn test_validate_malformed_input() {
+        let request = |content: &str, version: Option<&str>| K8sValidateRequest {
+            content: content.to_string(),
+            target_version: version.map(str::to_string),
+        };
+        assert_eq!(
+            validate_k8s_manifest(request("  \n", None)).unwrap_err(),
+            "清单内容不能为空"
+        );
+        assert_eq!(
+            validate_k8s_manifest(request("kind: Pod", Some("latest"))).unwrap_err(),
+            "无效的 Kubernetes 版本: latest"
+        );
+        assert!(validate_k8s_manifest(request("kind: [Pod", None))
+            .unwrap_err()
+            .starts_with("第 1 个文档 YAML 解析失败"));
+        assert_eq!(
+            validate_k8s_manifest(request("---\n---\n", None)).unwrap_err(),
+            "未找到任何 Kubernetes 资源"
+        );
+
+        let result = validate("metadata: {}\n", None);
+        assert_eq!(
+            messages(&result.resources[0]),
+            vec!["缺少 apiVersion", "缺少 kind", "缺少 metadata.name"]
+        );
+    }
+
+    #[test]
+    fn test_parse_kubeconfig() {
+        let content = r#"
+apiVersion: v1
+kind: Config
+current-context: dev
+clusters:
+  - name: dev-cluster
+    cluster:
+      server: https://127.0.0.1:6443
+      insecure-skip-tls-verify: true
+users:
+  - name: dev-user
+    user:
+      exec:
+        command: aws
+        args: ["eks", "get-token"]
+  - name: legacy
+    user:
+      username: admin
+      password: admin
+contexts:
+  - name: dev
+    context:
+      cluster: dev-cluster
+      user: dev-user
+      namespace: team
+  - name: broken
+    context:
+      cluster: missing
+      user: dev-user
+"#;
+        let info = parse_kubeconfig(content.to_string()).unwrap();
+        assert_eq!(info.current_context.as_deref(), Some("dev"));
+        assert!(info.contexts[0].is_current);
+        assert_eq!(info.contexts[0].namespace.as_deref(), Some("team"));
+        assert!(info.clusters[0].insecure_skip_tls_verify);
+        assert_eq!(info.users[0].auth_type, "exec");
+        assert_eq!(
+            info.users[0].exec_command.as_deref(),
+            Some("aws eks get-token")
+        );
+        assert_eq!(info.users[1].auth_type, "basic");
+        assert_eq!(
+            info.warnings,
+            vec![
+                "集群 dev-cluster 跳过了 TLS 证书校验",
+                "用户 legacy 使用 Basic 认证，新版本集群已不再支持",
+                "上下文 broken 引用了不存在的集群 missing",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_malformed_kubeconfig() {
+        assert!(parse_kubeconfig(String::new()).is_err());
+        assert!(parse_kubeconfig("clusters: [".to_string())
+            .unwrap_err()
+            .starts_with("YAML 解析失败"));
+        assert_eq!(
+            parse_kubeconfig("apiVersion: v1\nkind: Pod\n".to_string()).unwrap_err(),
+            "不是有效的 kubeconfig 文件（kind 应为 Config）"
+        );
+
+        let content = r#"
+kind: Config
+current-context: gone
+clusters:
+  - name: c
+    cluster:
+      server: https://c
+      certificate-authority-data: "not base64!"
+contexts: []
+"#;
+        let info = parse_kubeconfig(content.to_string()).unwrap();
+        assert!(
+            info.warnings[0].starts_with("集群 c 的 certificate-authority-data Base64 解码失败")
+        );
+        assert_eq!(info.warnings[1], "current-context gone 不存在");
+    }
+}
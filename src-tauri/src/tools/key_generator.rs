@@ -0,0 +1,639 @@
+use crate::utils::ssh_key::SshKeyCodec;
+use base64::{engine::general_purpose, Engine as _};
+use openssl::asn1::Asn1Time;
+use openssl::bn::{BigNum, MsbOption};
+use openssl::ec::{EcGroup, EcKey};
+use openssl::hash::MessageDigest;
+use openssl::nid::Nid;
+use openssl::pkey::{Id, PKey, Private, Public};
+use openssl::rsa::{Padding, Rsa};
+use openssl::sign::{RsaPssSaltlen, Signer, Verifier};
+use openssl::symm::Cipher;
+use openssl::x509::extension::{
+    BasicConstraints, ExtendedKeyUsage, KeyUsage, SubjectAlternativeName,
+};
+use openssl::x509::{X509NameBuilder, X509};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyPairRequest {
+    pub algorithm: String,                  // rsa / ecdsa / ed25519
+    pub rsa_bits: Option<u32>,              // RSA 位数，默认 2048
+    pub curve: Option<String>,              // ECDSA 曲线：P-256 / P-384 / P-521
+    pub private_key_format: Option<String>, // pkcs8 / pkcs1 / sec1 / openssh
+    pub passphrase: Option<String>,         // 私钥口令（仅 PEM 格式）
+    pub comment: Option<String>,            // OpenSSH 公钥注释
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyPairResult {
+    pub algorithm: String,
+    pub key_size: u32,
+    pub private_key: String,
+    pub public_key_pem: String,
+    pub public_key_openssh: String,
+    pub fingerprint_sha256: String,
+    pub fingerprint_md5: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SshFingerprintResult {
+    pub key_type: String,
+    pub key_size: Option<u32>,
+    pub comment: Option<String>,
+    pub fingerprint_sha256: String,
+    pub fingerprint_md5: String,
+    pub public_key_openssh: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignRequest {
+    pub private_key: String,
+    pub passphrase: Option<String>,
+    pub data: String,
+    pub data_encoding: Option<String>, // utf8 / hex / base64
+    pub hash: Option<String>,          // sha1 / sha256 / sha384 / sha512
+    pub rsa_padding: Option<String>,   // pkcs1 / pss
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignResult {
+    pub algorithm: String,
+    pub hash: String,
+    pub signature_base64: String,
+    pub signature_hex: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerifyRequest {
+    pub public_key: String, // PEM 公钥 / 证书 / 私钥 / OpenSSH 公钥
+    pub data: String,
+    pub data_encoding: Option<String>,
+    pub signature: String,
+    pub signature_encoding: Option<String>, // base64 / hex
+    pub hash: Option<String>,
+    pub rsa_padding: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerifyResult {
+    pub valid: bool,
+    pub algorithm: String,
+    pub hash: String,
+    pub message: String,
+}
+
+/// 生成私钥
+fn generate_private_key(request: &KeyPairRequest) -> Result<PKey<Private>, String> {
+    match request.algorithm.to_lowercase().as_str() {
+        "rsa" => {
+            let bits = request.rsa_bits.unwrap_or(2048);
+            if ![1024, 2048, 3072, 4096, 8192].contains(&bits) {
+                return Err(format!("不支持的RSA密钥长度: {}", bits));
+            }
+            let rsa = Rsa::generate(bits).map_err(|e| format!("生成RSA密钥失败: {}", e))?;
+            PKey::from_rsa(rsa).map_err(|e| e.to_string())
+        }
+        "ecdsa" | "ec" => {
+            let nid = parse_curve(request.curve.as_deref().unwrap_or("P-256"))?;
+            let group = EcGroup::from_curve_name(nid).map_err(|e| e.to_string())?;
+            let ec = EcKey::generate(&group).map_err(|e| format!("生成EC密钥失败: {}", e))?;
+            PKey::from_ec_key(ec).map_err(|e| e.to_string())
+        }
+        "ed25519" => PKey::generate_ed25519().map_err(|e| format!("生成Ed25519密钥失败: {}", e)),
+        other => Err(format!("不支持的密钥算法: {}", other)),
+    }
+}
+
+fn parse_curve(curve: &str) -> Result<Nid, String> {
+    match curve.to_uppercase().replace('_', "-").as_str() {
+        "P-256" | "PRIME256V1" | "SECP256R1" | "NISTP256" => Ok(Nid::X9_62_PRIME256V1),
+        "P-384" | "SECP384R1" | "NISTP384" => Ok(Nid::SECP384R1),
+        "P-521" | "SECP521R1" | "NISTP521" => Ok(Nid::SECP521R1),
+        _ => Err(format!("不支持的椭圆曲线: {}", curve)),
+    }
+}
+
+fn parse_digest(hash: Option<&str>) -> Result<(MessageDigest, String), String> {
+    let name = hash.unwrap_or("sha256").to_lowercase().replace('-', "");
+    let digest = match name.as_str() {
+        "sha1" => MessageDigest::sha1(),
+        "sha224" => MessageDigest::sha224(),
+        "sha256" => MessageDigest::sha256(),
+        "sha384" => MessageDigest::sha384(),
+        "sha512" => MessageDigest::sha512(),
+        "sha3256" => MessageDigest::sha3_256(),
+        "sha3512" => MessageDigest::sha3_512(),
+        _ => return Err(format!("不支持的哈希算法: {}", name)),
+    };
+    Ok((digest, name))
+}
+
+fn decode_input(data: &str, encoding: Option<&str>) -> Result<Vec<u8>, String> {
+    match encoding.unwrap_or("utf8").to_lowercase().as_str() {
+        "utf8" | "utf-8" | "text" => Ok(data.as_bytes().to_vec()),
+        "hex" => hex::decode(data.trim().replace([' ', ':', '\n'], ""))
+            .map_err(|e| format!("Hex解码失败: {}", e)),
+        "base64" => general_purpose::STANDARD
+            .decode(data.trim().replace(['\n', '\r'], ""))
+            .map_err(|e| format!("Base64解码失败: {}", e)),
+        other => Err(format!("不支持的数据编码: {}", other)),
+    }
+}
+
+fn algorithm_name(id: Id) -> &'static str {
+    match id {
+        Id::RSA => "RSA",
+        Id::EC => "ECDSA",
+        Id::ED25519 => "Ed25519",
+        Id::DSA => "DSA",
+        _ => "Unknown",
+    }
+}
+
+fn to_utf8(bytes: Vec<u8>) -> Result<String, String> {
+    String::from_utf8(bytes).map_err(|e| format!("密钥编码转换失败: {}", e))
+}
+
+/// 按指定格式导出私钥
+pub(crate) fn export_private_key(
+    pkey: &PKey<Private>,
+    format: &str,
+    passphrase: Option<&str>,
+    comment: Option<&str>,
+) -> Result<String, String> {
+    let passphrase = passphrase.filter(|p| !p.is_empty());
+    let cipher = Cipher::aes_256_cbc();
+    let pem = match format {
+        "openssh" => {
+            if passphrase.is_some() {
+                return Err("OpenSSH 格式暂不支持口令加密，请选择 PKCS#8 格式".to_string());
+            }
+            return SshKeyCodec::to_openssh_private(pkey, comment);
+        }
+        "pkcs1" => {
+            let rsa = pkey
+                .rsa()
+                .map_err(|_| "PKCS#1 格式仅适用于RSA密钥".to_string())?;
+            match passphrase {
+                Some(p) => rsa.private_key_to_pem_passphrase(cipher, p.as_bytes()),
+                None => rsa.private_key_to_pem(),
+            }
+        }
+        "sec1" => {
+            let ec = pkey
+                .ec_key()
+                .map_err(|_| "SEC1 格式仅适用于EC密钥".to_string())?;
+            match passphrase {
+                Some(p) => ec.private_key_to_pem_passphrase(cipher, p.as_bytes()),
+                None => ec.private_key_to_pem(),
+            }
+        }
+        "pkcs8" => match passphrase {
+            Some(p) => pkey.private_key_to_pem_pkcs8_passphrase(cipher, p.as_bytes()),
+            None => pkey.private_key_to_pem_pkcs8(),
+        },
+        other => return Err(format!("不支持的私钥格式: {}", other)),
+    };
+    pem.map_err(|e| format!("导出私钥失败: {}", e))
+        .and_then(to_utf8)
+}
+
+/// 加载私钥，支持 PEM（PKCS#1/PKCS#8/SEC1，可加密）与 OpenSSH 格式
+pub(crate) fn load_private_key(
+    content: &str,
+    passphrase: Option<&str>,
+) -> Result<PKey<Private>, String> {
+    let content = content.trim();
+    if content.is_empty() {
+        return Err("私钥内容不能为空".to_string());
+    }
+    if SshKeyCodec::is_openssh_private_key(content) {
+        return SshKeyCodec::parse_openssh_private(content).map(|(pkey, _)| pkey);
+    }
+    match passphrase.filter(|p| !p.is_empty()) {
+        Some(p) => PKey::private_key_from_pem_passphrase(content.as_bytes(), p.as_bytes())
+            .map_err(|e| format!("私钥口令错误或解析失败: {}", e)),
+        None => PKey::private_key_from_pem(content.as_bytes())
+            .map_err(|e| format!("私钥解析失败: {}。如私钥已加密请提供口令", e)),
+    }
+}
+
+/// 加载公钥，支持 PEM 公钥、X.509 证书、私钥（推导公钥）与 OpenSSH 公钥行
+pub(crate) fn load_public_key(content: &str) -> Result<PKey<Public>, String> {
+    let content = content.trim();
+    if content.is_empty() {
+        return Err("公钥内容不能为空".to_string());
+    }
+
+    if content.contains("-----BEGIN CERTIFICATE-----") {
+        let cert =
+            X509::from_pem(content.as_bytes()).map_err(|e| format!("证书解析失败: {}", e))?;
+        return cert
+            .public_key()
+            .map_err(|e| format!("读取证书公钥失败: {}", e));
+    }
+    if content.contains("-----BEGIN RSA PUBLIC KEY-----") {
+        let rsa = Rsa::public_key_from_pem_pkcs1(content.as_bytes())
+            .map_err(|e| format!("RSA公钥解析失败: {}", e))?;
+        return PKey::from_rsa(rsa).map_err(|e| e.to_string());
+    }
+    if content.contains("-----BEGIN PUBLIC KEY-----") {
+        return PKey::public_key_from_pem(content.as_bytes())
+            .map_err(|e| format!("公钥解析失败: {}", e));
+    }
+    if content.contains("PRIVATE KEY-----") {
+        let private = load_private_key(content, None)?;
+        let der = private
+            .public_key_to_der()
+            .map_err(|e| format!("导出公钥失败: {}", e))?;
+        return PKey::public_key_from_der(&der).map_err(|e| e.to_string());
+    }
+
+    let line = SshKeyCodec::parse_public_line(content)?;
+    SshKeyCodec::blob_to_public_key(&line.blob)
+}
+
+/// 生成 ECDSA P-256 自签名证书，返回 (证书 PEM, PKCS#8 私钥 PEM)
+pub(crate) fn generate_self_signed_certificate(
+    common_name: &str,
+    sans: &[String],
+    days: u32,
+) -> Result<(String, String), String> {
+    let group = EcGroup::from_curve_name(Nid::X9_62_PRIME256V1).map_err(|e| e.to_string())?;
+    let ec = EcKey::generate(&group).map_err(|e| format!("生成密钥失败: {}", e))?;
+    let pkey = PKey::from_ec_key(ec).map_err(|e| e.to_string())?;
+
+    let build = || -> Result<X509, openssl::error::ErrorStack> {
+        let mut name = X509NameBuilder::new()?;
+        name.append_entry_by_nid(Nid::COMMONNAME, common_name)?;
+        let name = name.build();
+
+        let mut serial = BigNum::new()?;
+        serial.rand(127, MsbOption::MAYBE_ZERO, false)?;
+
+        let serial = serial.to_asn1_integer()?;
+        let not_before = Asn1Time::days_from_now(0)?;
+        let not_after = Asn1Time::days_from_now(days)?;
+
+        let mut builder = X509::builder()?;
+        builder.set_version(2)?;
+        builder.set_serial_number(&serial)?;
+        builder.set_subject_name(&name)?;
+        builder.set_issuer_name(&name)?;
+        builder.set_pubkey(&pkey)?;
+        builder.set_not_before(&not_before)?;
+        builder.set_not_after(&not_after)?;
+        builder.append_extension(BasicConstraints::new().critical().build()?)?;
+        builder.append_extension(
+            KeyUsage::new()
+                .critical()
+                .digital_signature()
+                .key_agreement()
+                .build()?,
+        )?;
+        builder.append_extension(ExtendedKeyUsage::new().server_auth().build()?)?;
+
+        let mut alt_names = SubjectAlternativeName::new();
+        for san in sans {
+            if san.parse::<std::net::IpAddr>().is_ok() {
+                alt_names.ip(san);
+            } else {
+                alt_names.dns(san);
+            }
+        }
+        let alt_names = alt_names.build(&builder.x509v3_context(None, None))?;
+        builder.append_extension(alt_names)?;
+
+        builder.sign(&pkey, MessageDigest::sha256())?;
+        Ok(builder.build())
+    };
+    let cert = build().map_err(|e| format!("生成自签名证书失败: {}", e))?;
+
+    let cert_pem = cert
+        .to_pem()
+        .map_err(|e| format!("导出证书失败: {}", e))
+        .and_then(to_utf8)?;
+    let key_pem = export_private_key(&pkey, "pkcs8", None, None)?;
+    Ok((cert_pem, key_pem))
+}
+
+fn apply_rsa_padding_to_signer(signer: &mut Signer, padding: Option<&str>) -> Result<(), String> {
+    if padding
+        .map(|p| p.eq_ignore_ascii_case("pss"))
+        .unwrap_or(false)
+    {
+        signer
+            .set_rsa_padding(Padding::PKCS1_PSS)
+            .and_then(|_| signer.set_rsa_pss_saltlen(RsaPssSaltlen::DIGEST_LENGTH))
+            .map_err(|e| format!("设置PSS填充失败: {}", e))?;
+    }
+    Ok(())
+}
+
+fn apply_rsa_padding_to_verifier(
+    verifier: &mut Verifier,
+    padding: Option<&str>,
+) -> Result<(), String> {
+    if padding
+        .map(|p| p.eq_ignore_ascii_case("pss"))
+        .unwrap_or(false)
+    {
+        verifier
+            .set_rsa_padding(Padding::PKCS1_PSS)
+            .and_then(|_| verifier.set_rsa_pss_saltlen(RsaPssSaltlen::DIGEST_LENGTH))
+            .map_err(|e| format!("设置PSS填充失败: {}", e))?;
+    }
+    Ok(())
+}
+
+fn generate_keypair_internal(request: KeyPairRequest) -> Result<KeyPairResult, String> {
+    let pkey = generate_private_key(&request)?;
+    let comment = request.comment.as_deref();
+
+    let default_format = "pkcs8".to_string();
+    let format = request
+        .private_key_format
+        .as_ref()
+        .unwrap_or(&default_format)
+        .to_lowercase();
+    let private_key = export_private_key(&pkey, &format, request.passphrase.as_deref(), comment)?;
+
+    let public_key_pem = pkey
+        .public_key_to_pem()
+        .map_err(|e| format!("导出公钥失败: {}", e))
+        .and_then(to_utf8)?;
+    let public_key_openssh = SshKeyCodec::to_openssh_public(&pkey, comment)?;
+    let (_, blob) = SshKeyCodec::public_key_blob(&pkey)?;
+
+    Ok(KeyPairResult {
+        algorithm: algorithm_name(pkey.id()).to_string(),
+        key_size: pkey.bits(),
+        private_key,
+        public_key_pem,
+        public_key_openssh,
+        fingerprint_sha256: SshKeyCodec::fingerprint_sha256(&blob),
+        fingerprint_md5: SshKeyCodec::fingerprint_md5(&blob),
+    })
+}
+
+#[tauri::command]
+pub async fn generate_keypair(request: KeyPairRequest) -> Result<KeyPairResult, String> {
+    // 大位数 RSA 生成较慢，放到阻塞线程执行
+    tokio::task::spawn_blocking(move || generate_keypair_internal(request))
+        .await
+        .map_err(|_| "密钥生成线程执行失败".to_string())?
+}
+
+#[tauri::command]
+pub fn compute_ssh_fingerprint(key: String) -> Result<SshFingerprintResult, String> {
+    let content = key.trim();
+    if content.is_empty() {
+        return Err("密钥内容不能为空".to_string());
+    }
+
+    // OpenSSH 公钥行保留原始注释
+    let (blob, comment) = if content.contains("-----BEGIN") {
+        let comment = if SshKeyCodec::is_openssh_private_key(content) {
+            SshKeyCodec::parse_openssh_private(content)?.1
+        } else {
+            None
+        };
+        let pkey = load_public_key(content)?;
+        (SshKeyCodec::public_key_blob(&pkey)?.1, comment)
+    } else {
+        let line = SshKeyCodec::parse_public_line(content)?;
+        (line.blob, line.comment)
+    };
+
+    let key_type = crate::utils::ssh_key::SshReader::new(&blob)
+        .read_string()
+        .map(|t| String::from_utf8_lossy(t).to_string())?;
+    let mut public_key_openssh =
+        format!("{} {}", key_type, general_purpose::STANDARD.encode(&blob));
+    if let Some(c) = &comment {
+        public_key_openssh.push(' ');
+        public_key_openssh.push_str(c);
+    }
+
+    Ok(SshFingerprintResult {
+        key_type,
+        key_size: SshKeyCodec::blob_key_bits(&blob),
+        comment,
+        fingerprint_sha256: SshKeyCodec::fingerprint_sha256(&blob),
+        fingerprint_md5: SshKeyCodec::fingerprint_md5(&blob),
+        public_key_openssh,
+    })
+}
+
+#[tauri::command]
+pub fn sign_data(request: SignRequest) -> Result<SignResult, String> {
+    let pkey = load_private_key(&request.private_key, request.passphrase.as_deref())?;
+    let data = decode_input(&request.data, request.data_encoding.as_deref())?;
+
+    let (signature, hash_name) = if pkey.id() == Id::ED25519 {
+        // Ed25519 内置哈希，不接受外部摘要算法
+        let mut signer =
+            Signer::new_without_digest(&pkey).map_err(|e| format!("创建签名器失败: {}", e))?;
+        let signature = signer
+            .sign_oneshot_to_vec(&data)
+            .map_err(|e| format!("签名失败: {}", e))?;
+        (signature, "sha512 (Ed25519 内置)".to_string())
+    } else {
+        let (digest, hash_name) = parse_digest(request.hash.as_deref())?;
+        let mut signer =
+            Signer::new(digest, &pkey).map_err(|e| format!("创建签名器失败: {}", e))?;
+        if pkey.id() == Id::RSA {
+            apply_rsa_padding_to_signer(&mut signer, request.rsa_padding.as_deref())?;
+        }
+        signer
+            .update(&data)
+            .map_err(|e| format!("签名失败: {}", e))?;
+        let signature = signer
+            .sign_to_vec()
+            .map_err(|e| format!("签名失败: {}", e))?;
+        (signature, hash_name)
+    };
+
+    Ok(SignResult {
+        algorithm: algorithm_name(pkey.id()).to_string(),
+        hash: hash_name,
+        signature_base64: general_purpose::STANDARD.encode(&signature),
+        signature_hex: hex::encode(&signature),
+    })
+}
+
+#[tauri::command]
+pub fn verify_signature(request: VerifyRequest) -> Result<VerifyResult, String> {
+    let pkey = load_public_key(&request.public_key)?;
+    let data = decode_input(&request.data, request.data_encoding.as_deref())?;
+    let signature_encoding = request.signature_encoding.as_deref().unwrap_or("base64");
+    let signature = decode_input(&request.signature, Some(signature_encoding))?;
+
+    let (valid, hash_name) = if pkey.id() == Id::ED25519 {
+        let mut verifier =
+            Verifier::new_without_digest(&pkey).map_err(|e| format!("创建验签器失败: {}", e))?;
+        let valid = verifier.verify_oneshot(&signature, &data).unwrap_or(false);
+        (valid, "sha512 (Ed25519 内置)".to_string())
+    } else {
+        let (digest, hash_name) = parse_digest(request.hash.as_deref())?;
+        let mut verifier =
+            Verifier::new(digest, &pkey).map_err(|e| format!("创建验签器失败: {}", e))?;
+        if pkey.id() == Id::RSA {
+            apply_rsa_padding_to_verifier(&mut verifier, request.rsa_padding.as_deref())?;
+        }
+        verifier
+            .update(&data)
+            .map_err(|e| format!("验签失败: {}", e))?;
+        // 签名格式不合法时 OpenSSL 返回错误，这里统一视为验签失败
+        (verifier.verify(&signature).unwrap_or(false), hash_name)
+    };
+
+    Ok(VerifyResult {
+        valid,
+        algorithm: algorithm_name(pkey.id()).to_string(),
+        hash: hash_name,
+        message: if valid {
+            "签名验证通过".to_string()
+        } else {
+            "签名验证失败：签名与数据或公钥不匹配".to_string()
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keypair(algorithm: &str, private_key_format: &str) -> KeyPairResult {
+        generate_keypair_internal(KeyPairRequest {
+            algorithm: algorithm.to_string(),
+            rsa_bits: Some(2048),
+            curve: Some("P-256".to_string()),
+            private_key_format: Some(private_key_format.to_string()),
+            passphrase: None,
+            comment: Some("test@devtools".to_string()),
+        })
+        .unwrap()
+    }
+
+    fn sign(private_key: &str, data: &str, rsa_padding: Option<&str>) -> SignResult {
+        sign_data(SignRequest {
+            private_key: private_key.to_string(),
+            passphrase: None,
+            data: data.to_string(),
+            data_encoding: None,
+            hash: Some("sha256".to_string()),
+            rsa_padding: rsa_padding.map(str::to_string),
+        })
+        .unwrap()
+    }
+
+    fn verify(public_key: &str, data: &str, signature: &str, rsa_padding: Option<&str>) -> bool {
+        verify_signature(VerifyRequest {
+            public_key: public_key.to_string(),
+            data: data.to_string(),
+            data_encoding: None,
+            signature: signature.to_string(),
+            signature_encoding: None,
+            hash: Some("sha256".to_string()),
+            rsa_padding: rsa_padding.map(str::to_string),
+        })
+        .unwrap()
+        .valid
+    }
+
+    #[test]
+    fn test_sign_verify_round_trip() {
+        let cases = [
+            ("rsa", "pkcs1", None, "RSA"),
+            ("rsa", "pkcs8", Some("pss"), "RSA"),
+            ("ecdsa", "sec1", None, "ECDSA"),
+            ("ed25519", "pkcs8", None, "Ed25519"),
+        ];
+        for (algorithm, format, padding, name) in cases {
+            let pair = keypair(algorithm, format);
+            assert_eq!(pair.algorithm, name);
+            let signed = sign(&pair.private_key, "hello devtools", padding);
+            assert_eq!(signed.algorithm, name);
+
+            // PEM 公钥和 OpenSSH 公钥都能验签
+            for public_key in [&pair.public_key_pem, &pair.public_key_openssh] {
+                assert!(
+                    verify(
+                        public_key,
+                        "hello devtools",
+                        &signed.signature_base64,
+                        padding
+                    ),
+                    "{} {} 验签失败",
+                    algorithm,
+                    format
+                );
+            }
+            assert!(
+                !verify(
+                    &pair.public_key_pem,
+                    "hello devtoolz",
+                    &signed.signature_base64,
+                    padding
+                ),
+                "{} 篡改数据后仍验签通过",
+                algorithm
+            );
+        }
+    }
+
+    #[test]
+    fn test_verify_rejects_tampered_signature_and_other_key() {
+        let pair = keypair("ecdsa", "pkcs8");
+        let other = keypair("ecdsa", "pkcs8");
+        let signed = sign(&pair.private_key, "payload", None);
+
+        let mut tampered = hex::decode(&signed.signature_hex).unwrap();
+        let last = tampered.len() - 1;
+        tampered[last] ^= 0x01;
+        let tampered = general_purpose::STANDARD.encode(tampered);
+        assert!(!verify(&pair.public_key_pem, "payload", &tampered, None));
+        assert!(!verify(
+            &other.public_key_pem,
+            "payload",
+            &signed.signature_base64,
+            None
+        ));
+    }
+
+    #[test]
+    fn test_openssh_private_key_signs() {
+        let pair = keypair("ed25519", "openssh");
+        assert!(pair.private_key.contains("BEGIN OPENSSH PRIVATE KEY"));
+        assert!(pair.public_key_openssh.ends_with(" test@devtools"));
+        let signed = sign(&pair.private_key, "ssh", None);
+        assert!(verify(
+            &pair.public_key_openssh,
+            "ssh",
+            &signed.signature_base64,
+            None
+        ));
+
+        let fingerprint = compute_ssh_fingerprint(pair.public_key_openssh.clone()).unwrap();
+        assert_eq!(fingerprint.fingerprint_sha256, pair.fingerprint_sha256);
+        assert_eq!(fingerprint.comment.as_deref(), Some("test@devtools"));
+    }
+
+    #[test]
+    fn test_unsupported_algorithm() {
+        let request = KeyPairRequest {
+            algorithm: "dsa".to_string(),
+            rsa_bits: None,
+            curve: None,
+            private_key_format: None,
+            passphrase: None,
+            comment: None,
+        };
+        assert_eq!(
+            generate_keypair_internal(request).unwrap_err(),
+            "不支持的密钥算法: dsa"
+        );
+    }
+}
@@ -0,0 +1,1282 @@
+use crate::tools::ssl_checker::{webpki_tls_connector, EndpointRecordingVerifier};
+use rustls::pki_types::ServerName;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::io::{
+    AsyncBufRead, AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader,
+};
+use tokio::net::TcpStream;
+use tokio_rustls::TlsConnector;
+
+/// 连接和命令的默认超时
+const DEFAULT_TIMEOUT_SECS: u64 = 10;
+/// 集群模式下最多跟随的重定向次数
+const MAX_REDIRECTS: usize = 5;
+/// SCAN 默认每批返回的数量
+const DEFAULT_SCAN_COUNT: u32 = 100;
+/// 读取 list、zset、stream 时最多返回的元素数
+const MAX_COLLECTION_ITEMS: usize = 100;
+/// 统计类型分布时采样的键数
+const TYPE_SAMPLE_SIZE: usize = 200;
+
+/// TCP 或 TLS 连接
+trait KvStream: AsyncRead + AsyncWrite + Unpin + Send {}
+impl<T: AsyncRead + AsyncWrite + Unpin + Send> KvStream for T {}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct KvConnection {
+    pub kind: String, // redis, memcached
+    pub host: String,
+    pub port: Option<u16>, // 默认 Redis 6379，Memcached 11211
+    pub tls: Option<bool>,
+    pub ignore_tls_errors: Option<bool>,
+    pub username: Option<String>, // Redis 6 ACL 用户名
+    pub password: Option<String>,
+    pub database: Option<u32>, // Redis 数据库编号，集群模式下忽略
+    pub cluster: Option<bool>, // Redis 集群：跟随 MOVED/ASK 重定向，SCAN 遍历所有主节点
+    pub timeout_secs: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct KvCommandRequest {
+    pub connection: KvConnection,
+    pub command: String, // get, set, del, scan, ttl
+    pub key: Option<String>,
+    pub value: Option<String>,   // SET 的值
+    pub ttl_secs: Option<u64>,   // SET 的过期时间，0 或为空表示不过期
+    pub pattern: Option<String>, // SCAN 的匹配模式，默认 *
+    pub cursor: Option<String>,  // SCAN 游标，首次为空
+    pub count: Option<u32>,      // SCAN 每批数量
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct KvCommandResult {
+    pub command: String,
+    pub found: bool,
+    pub value_type: Option<String>, // Redis 的 string、hash、list、set、zset、stream
+    pub value: Option<Value>,       // 字符串原样返回，集合类型为 JSON
+    pub pretty: Option<String>,     // JSON 值的格式化文本
+    pub ttl: Option<i64>,           // 秒，-1 表示不过期
+    pub affected: Option<i64>,
+    pub keys: Vec<String>,
+    pub next_cursor: Option<String>, // SCAN 结束时为空
+    pub truncated: bool,             // 集合元素超过上限时只返回前 100 个
+    pub node: String,                // 实际处理命令的节点
+    pub elapsed_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KvDatabaseStats {
+    pub name: String,
+    pub keys: u64,
+    pub expires: u64,
+    pub avg_ttl_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct KvStats {
+    pub kind: String,
+    pub version: Option<String>,
+    pub uptime_secs: Option<u64>,
+    pub used_memory: Option<u64>,
+    pub max_memory: Option<u64>,
+    pub connected_clients: Option<u64>,
+    pub total_keys: u64,
+    pub expiring_keys: u64,
+    pub hits: u64,
+    pub misses: u64,
+    pub hit_rate: Option<f64>, // 百分比
+    pub evictions: u64,
+    pub databases: Vec<KvDatabaseStats>,
+    pub type_distribution: BTreeMap<String, u64>, // 按采样键统计的类型分布
+    pub sampled_keys: usize,
+    pub nodes: Vec<String>,
+    pub elapsed_ms: u64,
+}
+
+/// RESP2 应答
+#[derive(Debug, Clone, PartialEq)]
+enum Resp {
+    Simple(String),
+    Error(String),
+    Integer(i64),
+    Bulk(Option<Vec<u8>>),
+    Array(Option<Vec<Resp>>),
+}
+
+impl Resp {
+    fn into_text(self) -> Option<String> {
+        match self {
+            Resp::Simple(s) => Some(s),
+            Resp::Bulk(Some(bytes)) => Some(String::from_utf8_lossy(&bytes).to_string()),
+            Resp::Integer(n) => Some(n.to_string()),
+            _ => None,
+        }
+    }
+
+    fn into_array(self) -> Vec<Resp> {
+        match self {
+            Resp::Array(Some(items)) => items,
+            _ => Vec::new(),
+        }
+    }
+
+    fn as_integer(&self) -> Option<i64> {
+        match self {
+            Resp::Integer(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        match self {
+            Resp::Simple(s) => Value::String(s.clone()),
+            Resp::Error(e) => serde_json::json!({ "error": e }),
+            Resp::Integer(n) => Value::from(*n),
+            Resp::Bulk(Some(bytes)) => Value::String(String::from_utf8_lossy(bytes).to_string()),
+            Resp::Bulk(None) | Resp::Array(None) => Value::Null,
+            Resp::Array(Some(items)) => Value::Array(items.iter().map(Resp::to_json).collect()),
+        }
+    }
+}
+
+/// 集群重定向
+#[derive(Debug, Clone, PartialEq)]
+enum Redirect {
+    Moved(String),
+    Ask(String),
+}
+
+/// 在 Redis 或 Memcached 上执行 GET / SET / DEL / SCAN / TTL
+#[tauri::command]
+pub async fn kv_execute(request: KvCommandRequest) -> Result<KvCommandResult, String> {
+    let command = request.command.trim().to_lowercase();
+    if command != "scan" && request.key.as_deref().unwrap_or_default().is_empty() {
+        return Err("键不能为空".to_string());
+    }
+    let start = Instant::now();
+    let mut result = match kind(&request.connection)? {
+        Kind::Redis => redis_execute(&request, &command).await?,
+        Kind::Memcached => memcached_execute(&request, &command).await?,
+    };
+    result.command = command;
+    if let Some(Value::String(text)) = &result.value {
+        result.pretty = pretty_json(text);
+    } else if let Some(value) = &result.value {
+        result.pretty = serde_json::to_string_pretty(value).ok();
+    }
+    result.elapsed_ms = start.elapsed().as_millis() as u64;
+    Ok(result)
+}
+
+/// 汇总服务器和键空间统计：键数量、过期键、命中率、内存和采样的类型分布
+#[tauri::command]
+pub async fn kv_stats(connection: KvConnection) -> Result<KvStats, String> {
+    let start = Instant::now();
+    let mut stats = match kind(&connection)? {
+        Kind::Redis => redis_stats(&connection).await?,
+        Kind::Memcached => memcached_stats(&connection).await?,
+    };
+    if stats.hits + stats.misses > 0 {
+        stats.hit_rate = Some(
+            (stats.hits as f64 * 10000.0 / (stats.hits + stats.misses) as f64).round() / 100.0,
+        );
+    }
+    stats.elapsed_ms = start.elapsed().as_millis() as u64;
+    Ok(stats)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    Redis,
+    Memcached,
+}
+
+fn kind(connection: &KvConnection) -> Result<Kind, String> {
+    if connection.host.trim().is_empty() {
+        return Err("主机不能为空".to_string());
+    }
+    match connection.kind.trim().to_lowercase().as_str() {
+        "redis" => Ok(Kind::Redis),
+        "memcached" | "memcache" => {
+            if connection
+                .password
+                .as_deref()
+                .is_some_and(|p| !p.is_empty())
+            {
+                return Err("Memcached 文本协议不支持认证".to_string());
+            }
+            Ok(Kind::Memcached)
+        }
+        _ => Err(format!("不支持的类型: {}", connection.kind)),
+    }
+}
+
+fn timeout(connection: &KvConnection) -> Duration {
+    Duration::from_secs(
+        connection
+            .timeout_secs
+            .unwrap_or(DEFAULT_TIMEOUT_SECS)
+            .max(1),
+    )
+}
+
+fn default_address(connection: &KvConnection) -> String {
+    let default_port = match kind(connection) {
+        Ok(Kind::Memcached) => 11211,
+        _ => 6379,
+    };
+    format_address(
+        connection.host.trim(),
+        connection.port.unwrap_or(default_port),
+    )
+}
+
+fn format_address(host: &str, port: u16) -> String {
+    if host.contains(':') && !host.starts_with('[') {
+        format!("[{}]:{}", host, port)
+    } else {
+        format!("{}:{}", host, port)
+    }
+}
+
+/// 拆分 host:port，支持 [IPv6]:port
+fn split_address(address: &str) -> Result<(String, u16), String> {
+    let (host, port) = address
+        .rsplit_once(':')
+        .ok_or_else(|| format!("无效的地址: {}", address))?;
+    let port = port
+        .parse()
+        .map_err(|_| format!("无效的端口: {}", address))?;
+    Ok((
+        host.trim_start_matches('[')
+            .trim_end_matches(']')
+            .to_string(),
+        port,
+    ))
+}
+
+/// 建立 TCP 连接，需要时完成 TLS 握手
+async fn open_stream(
+    connection: &KvConnection,
+    address: &str,
+) -> Result<BufReader<Box<dyn KvStream>>, String> {
+    let (host, port) = split_address(address)?;
+    let connect = async {
+        let tcp = TcpStream::connect((host.as_str(), port))
+            .await
+            .map_err(|e| format!("连接 {} 失败: {}", address, e))?;
+        let _ = tcp.set_nodelay(true);
+        if !connection.tls.unwrap_or(false) {
+            return Ok::<_, String>(Box::new(tcp) as Box<dyn KvStream>);
+        }
+        let connector = if connection.ignore_tls_errors.unwrap_or(false) {
+            let _ = rustls::crypto::ring::default_provider().install_default();
+            let verifier = Arc::new(EndpointRecordingVerifier::with_webpki_roots()?);
+            let config = rustls::ClientConfig::builder()
+                .dangerous()
+                .with_custom_certificate_verifier(verifier)
+                .with_no_client_auth();
+            TlsConnector::from(Arc::new(config))
+        } else {
+            webpki_tls_connector()
+        };
+        let server_name =
+            ServerName::try_from(host.clone()).map_err(|e| format!("无效的主机名: {}", e))?;
+        let tls = connector
+            .connect(server_name, tcp)
+            .await
+            .map_err(|e| format!("TLS 握手失败: {}", e))?;
+        Ok(Box::new(tls) as Box<dyn KvStream>)
+    };
+    let stream = tokio::time::timeout(timeout(connection), connect)
+        .await
+        .map_err(|_| format!("连接 {} 超时", address))??;
+    Ok(BufReader::new(stream))
+}
+
+/// 编码为 RESP 数组
+fn encode_command<A: AsRef<[u8]>>(args: &[A]) -> Vec<u8> {
+    let mut buf = format!("*{}\r\n", args.len()).into_bytes();
+    for arg in args {
+        let arg = arg.as_ref();
+        buf.extend_from_slice(format!("${}\r\n", arg.len()).as_bytes());
+        buf.extend_from_slice(arg);
+        buf.extend_from_slice(b"\r\n");
+    }
+    buf
+}
+
+async fn read_line<R: AsyncBufRead + Unpin>(reader: &mut R) -> Result<String, String> {
+    let mut line = String::new();
+    let n = reader
+        .read_line(&mut line)
+        .await
+        .map_err(|e| format!("读取应答失败: {}", e))?;
+    if n == 0 {
+        return Err("服务端关闭了连接".to_string());
+    }
+    Ok(line.trim_end_matches(['\r', '\n']).to_string())
+}
+
+async fn read_exact_bulk<R: AsyncBufRead + Unpin>(
+    reader: &mut R,
+    len: usize,
+) -> Result<Vec<u8>, String> {
+    let mut data = vec![0u8; len + 2];
+    reader
+        .read_exact(&mut data)
+        .await
+        .map_err(|e| format!("读取数据失败: {}", e))?;
+    data.truncate(len);
+    Ok(data)
+}
+
+/// 读取一个 RESP2 应答
+async fn read_resp<R: AsyncBufRead + Unpin>(reader: &mut R) -> Result<Resp, String> {
+    let line = read_line(reader).await?;
+    let (prefix, rest) = line.split_at(line.len().min(1));
+    let number = || {
+        rest.parse::<i64>()
+            .map_err(|_| format!("无效的 RESP 应答: {}", line))
+    };
+    match prefix {
+        "+" => Ok(Resp::Simple(rest.to_string())),
+        "-" => Ok(Resp::Error(rest.to_string())),
+        ":" => Ok(Resp::Integer(number()?)),
+        "$" => match number()? {
+            len if len < 0 => Ok(Resp::Bulk(None)),
+            len => Ok(Resp::Bulk(Some(
+                read_exact_bulk(reader, len as usize).await?,
+            ))),
+        },
+        "*" => match number()? {
+            len if len < 0 => Ok(Resp::Array(None)),
+            len => {
+                let mut items = Vec::with_capacity(len as usize);
+                for _ in 0..len {
+                    items.push(Box::pin(read_resp(reader)).await?);
+                }
+                Ok(Resp::Array(Some(items)))
+            }
+        },
+        _ => Err(format!("无效的 RESP 应答: {}", line)),
+    }
+}
+
+/// 解析 "MOVED 3999 127.0.0.1:6381" / "ASK 3999 127.0.0.1:6381"
+fn parse_redirect(error: &str) -> Option<Redirect> {
+    let mut parts = error.split_whitespace();
+    let kind = parts.next()?;
+    let _slot = parts.next()?;
+    let address = parts.next()?.to_string();
+    match kind {
+        "MOVED" => Some(Redirect::Moved(address)),
+        "ASK" => Some(Redirect::Ask(address)),
+        _ => None,
+    }
+}
+
+/// 解析 CLUSTER NODES，返回主节点地址
+fn parse_cluster_masters(nodes: &str) -> Vec<String> {
+    let mut masters: Vec<String> = nodes
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let flags = fields.get(2)?;
+            if !flags.split(',').any(|f| f == "master") || flags.contains("fail") {
+                return None;
+            }
+            // 地址格式为 host:port@cport[,hostname]
+            let address = fields.get(1)?.split(['@', ',']).next()?;
+            (!address.starts_with(':')).then(|| address.to_string())
+        })
+        .collect();
+    masters.sort();
+    masters
+}
+
+struct RedisClient<'a> {
+    connection: &'a KvConnection,
+    cluster: bool,
+    node: String,
+    stream: BufReader<Box<dyn KvStream>>,
+}
+
+impl<'a> RedisClient<'a> {
+    async fn connect(connection: &'a KvConnection, address: String) -> Result<Self, String> {
+        let stream = open_stream(connection, &address).await?;
+        let mut client = Self {
+            connection,
+            cluster: connection.cluster.unwrap_or(false),
+            node: address,
+            stream,
+        };
+        if let Some(password) = connection.password.as_deref().filter(|p| !p.is_empty()) {
+            let reply = match connection.username.as_deref().filter(|u| !u.is_empty()) {
+                Some(username) => client.send(&["AUTH", username, password]).await?,
+                None => client.send(&["AUTH", password]).await?,
+            };
+            if let Resp::Error(e) = reply {
+                return Err(format!("认证失败: {}", e));
+            }
+        }
+        let database = connection.database.unwrap_or(0);
+        if database > 0 && !client.cluster {
+            if let Resp::Error(e) = client.send(&["SELECT", &database.to_string()]).await? {
+                return Err(format!("切换数据库失败: {}", e));
+            }
+        }
+        Ok(client)
+    }
+
+    async fn send<A: AsRef<[u8]>>(&mut self, args: &[A]) -> Result<Resp, String> {
+        let wait = timeout(self.connection);
+        let request = encode_command(args);
+        let exchange = async {
+            self.stream
+                .get_mut()
+                .write_all(&request)
+                .await
+                .map_err(|e| format!("发送命令失败: {}", e))?;
+            read_resp(&mut self.stream).await
+        };
+        tokio::time::timeout(wait, exchange)
+            .await
+            .map_err(|_| "命令超时".to_string())?
+    }
+
+    /// 执行命令，集群模式下跟随 MOVED / ASK 重定向，错误应答转为 Err
+    async fn call<A: AsRef<[u8]>>(&mut self, args: &[A]) -> Result<Resp, String> {
+        let mut asking = false;
+        for _ in 0..=MAX_REDIRECTS {
+            if asking {
+                self.send(&["ASKING"]).await?;
+            }
+            let reply = self.send(args).await?;
+            let Resp::Error(error) = reply else {
+                return Ok(reply);
+            };
+            let redirect = if self.cluster {
+                parse_redirect(&error)
+            } else {
+                None
+            };
+            match redirect {
+                Some(Redirect::Moved(address)) => {
+                    *self = Self::connect(self.connection, address).await?;
+                    asking = false;
+                }
+                Some(Redirect::Ask(address)) => {
+                    *self = Self::connect(self.connection, address).await?;
+                    asking = true;
+                }
+                None if error.starts_with("MOVED") => {
+                    return Err(format!("{}（目标是集群，请开启集群模式）", error));
+                }
+                None => return Err(error),
+            }
+        }
+        Err("集群重定向次数过多".to_string())
+    }
+
+    async fn masters(&mut self) -> Result<Vec<String>, String> {
+        if !self.cluster {
+            return Ok(vec![self.node.clone()]);
+        }
+        let nodes = self
+            .call(&["CLUSTER", "NODES"])
+            .await?
+            .into_text()
+            .unwrap_or_default();
+        let masters = parse_cluster_masters(&nodes);
+        if masters.is_empty() {
+            return Err("未找到可用的集群主节点".to_string());
+        }
+        Ok(masters)
+    }
+}
+
+async fn redis_execute(
+    request: &KvCommandRequest,
+    command: &str,
+) -> Result<KvCommandResult, String> {
+    let connection = &request.connection;
+    let mut client = RedisClient::connect(connection, default_address(connection)).await?;
+    let key = request.key.as_deref().unwrap_or_default();
+    let mut result = KvCommandResult::default();
+
+    match command {
+        "get" => {
+            let value_type = client
+                .call(&["TYPE", key])
+                .await?
+                .into_text()
+                .unwrap_or_default();
+            if value_type != "none" {
+                let (value, truncated) = redis_read_value(&mut client, key, &value_type).await?;
+                result.found = true;
+                result.value = Some(value);
+                result.truncated = truncated;
+                result.value_type = Some(value_type);
+                result.ttl = client.call(&["TTL", key]).await?.as_integer();
+            }
+        }
+        "set" => {
+            let value = request.value.clone().unwrap_or_default();
+            let mut args = vec!["SET".to_string(), key.to_string(), value];
+            if let Some(ttl) = request.ttl_secs.filter(|t| *t > 0) {
+                args.push("EX".to_string());
+                args.push(ttl.to_string());
+            }
+            client.call(&args).await?;
+            result.found = true;
+            result.affected = Some(1);
+        }
+        "del" => {
+            let deleted = client.call(&["DEL", key]).await?.as_integer().unwrap_or(0);
+            result.found = deleted > 0;
+            result.affected = Some(deleted);
+        }
+        "ttl" => {
+            let ttl = client.call(&["TTL", key]).await?.as_integer().unwrap_or(-2);
+            result.found = ttl != -2;
+            result.ttl = Some(ttl).filter(|t| *t != -2);
+        }
+        "scan" => {
+            let (keys, next_cursor) = redis_scan(&mut client, request).await?;
+            result.found = !keys.is_empty();
+            result.keys = keys;
+            result.next_cursor = next_cursor;
+        }
+        _ => return Err(format!("不支持的命令: {}", command)),
+    }
+    result.node = client.node;
+    Ok(result)
+}
+
+/// 按类型读取值，集合类型最多返回 MAX_COLLECTION_ITEMS 个元素
+async fn redis_read_value(
+    client: &mut RedisClient<'_>,
+    key: &str,
+    value_type: &str,
+) -> Result<(Value, bool), String> {
+    let last = (MAX_COLLECTION_ITEMS - 1).to_string();
+    let (reply, len_command) = match value_type {
+        "string" => {
+            let text = client.call(&["GET", key]).await?.into_text();
+            return Ok((text.map(Value::String).unwrap_or(Value::Null), false));
+        }
+        "hash" => {
+            let items = client.call(&["HGETALL", key]).await?.into_array();
+            let map: serde_json::Map<String, Value> = items
+                .chunks(2)
+                .filter_map(|pair| match pair {
+                    [field, value] => Some((
+                        field.clone().into_text()?,
+                        Value::String(value.clone().into_text().unwrap_or_default()),
+                    )),
+                    _ => None,
+                })
+                .collect();
+            return Ok((Value::Object(map), false));
+        }
+        "list" => (client.call(&["LRANGE", key, "0", &last]).await?, "LLEN"),
+        "set" => (client.call(&["SRANDMEMBER", key, &last]).await?, "SCARD"),
+        "zset" => {
+            let items = client
+                .call(&["ZRANGE", key, "0", &last, "WITHSCORES"])
+                .await?
+                .into_array();
+            let members: Vec<Value> = items
+                .chunks(2)
+                .map(|pair| {
+                    let text = |r: Option<&Resp>| r.cloned().and_then(Resp::into_text);
+                    serde_json::json!({
+                        "member": text(pair.first()),
+                        "score": text(pair.get(1)).and_then(|s| s.parse::<f64>().ok()),
+                    })
+                })
+                .collect();
+            let total = client
+                .call(&["ZCARD", key])
+                .await?
+                .as_integer()
+                .unwrap_or(0);
+            let truncated = total as usize > members.len();
+            return Ok((Value::Array(members), truncated));
+        }
+        "stream" => (
+            client
+                .call(&[
+                    "XRANGE",
+                    key,
+                    "-",
+                    "+",
+                    "COUNT",
+                    &MAX_COLLECTION_ITEMS.to_string(),
+                ])
+                .await?,
+            "XLEN",
+        ),
+        other => return Err(format!("暂不支持读取 {} 类型", other)),
+    };
+    let value = reply.to_json();
+    let returned = value.as_array().map(Vec::len).unwrap_or(0);
+    let total = client
+        .call(&[len_command, key])
+        .await?
+        .as_integer()
+        .unwrap_or(0);
+    Ok((value, total as usize > returned))
+}
+
+/// 集群模式下游标格式为 "节点序号:游标"，依次遍历所有主节点
+async fn redis_scan(
+    client: &mut RedisClient<'_>,
+    request: &KvCommandRequest,
+) -> Result<(Vec<String>, Option<String>), String> {
+    let pattern = request
+        .pattern
+        .as_deref()
+        .filter(|p| !p.is_empty())
+        .unwrap_or("*");
+    let count = request
+        .count
+        .unwrap_or(DEFAULT_SCAN_COUNT)
+        .max(1)
+        .to_string();
+    let masters = client.masters().await?;
+    let (mut node_index, mut cursor) = parse_scan_cursor(request.cursor.as_deref())?;
+    if node_index >= masters.len() {
+        return Err("无效的游标".to_string());
+    }
+
+    if client.cluster {
+        *client = RedisClient::connect(client.connection, masters[node_index].clone()).await?;
+    }
+    let reply = client
+        .call(&["SCAN", &cursor, "MATCH", pattern, "COUNT", &count])
+        .await?
+        .into_array();
+    let mut reply = reply.into_iter();
+    cursor = reply
+        .next()
+        .and_then(Resp::into_text)
+        .unwrap_or_else(|| "0".to_string());
+    let keys = reply
+        .next()
+        .map(Resp::into_array)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(Resp::into_text)
+        .collect();
+
+    if cursor == "0" {
+        node_index += 1;
+        if node_index >= masters.len() {
+            return Ok((keys, None));
+        }
+    }
+    let next = if client.cluster {
+        format!("{}:{}", node_index, cursor)
+    } else {
+        cursor
+    };
+    Ok((keys, Some(next)))
+}
+
+fn parse_scan_cursor(cursor: Option<&str>) -> Result<(usize, String), String> {
+    let cursor = cursor
+        .map(str::trim)
+        .filter(|c| !c.is_empty())
+        .unwrap_or("0");
+    let (node, cursor) = cursor.split_once(':').unwrap_or(("0", cursor));
+    let node = node.parse().map_err(|_| "无效的游标".to_string())?;
+    if cursor.parse::<u64>().is_err() {
+        return Err("无效的游标".to_string());
+    }
+    Ok((node, cursor.to_string()))
+}
+
+/// 解析 INFO 输出为键值对，跳过注释和空行
+fn parse_info(info: &str) -> BTreeMap<String, String> {
+    info.lines()
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| line.split_once(':'))
+        .map(|(k, v)| (k.trim().to_string(), v.trim().to_string()))
+        .collect()
+}
+
+/// 解析 keyspace 行，如 "keys=10,expires=2,avg_ttl=3000"
+fn parse_keyspace(name: &str, value: &str) -> Option<KvDatabaseStats> {
+    let fields: BTreeMap<&str, u64> = value
+        .split(',')
+        .filter_map(|pair| pair.split_once('='))
+        .filter_map(|(k, v)| Some((k, v.parse().ok()?)))
+        .collect();
+    Some(KvDatabaseStats {
+        name: name.to_string(),
+        keys: *fields.get("keys")?,
+        expires: fields.get("expires").copied().unwrap_or(0),
+        avg_ttl_ms: fields.get("avg_ttl").copied().filter(|t| *t > 0),
+    })
+}
+
+async fn redis_stats(connection: &KvConnection) -> Result<KvStats, String> {
+    let mut client = RedisClient::connect(connection, default_address(connection)).await?;
+    let masters = client.masters().await?;
+    let mut stats = KvStats {
+        kind: "redis".to_string(),
+        nodes: masters.clone(),
+        ..Default::default()
+    };
+    let number = |info: &BTreeMap<String, String>, key: &str| {
+        info.get(key).and_then(|v| v.parse::<u64>().ok())
+    };
+
+    let mut databases: BTreeMap<String, KvDatabaseStats> = BTreeMap::new();
+    for (index, master) in masters.iter().enumerate() {
+        if client.cluster {
+            client = RedisClient::connect(connection, master.clone()).await?;
+        }
+        let info = client
+            .call(&["INFO"])
+            .await?
+            .into_text()
+            .unwrap_or_default();
+        let info = parse_info(&info);
+        if index == 0 {
+            stats.version = info.get("redis_version").cloned();
+            stats.uptime_secs = number(&info, "uptime_in_seconds");
+            stats.max_memory = number(&info, "maxmemory").filter(|m| *m > 0);
+        }
+        let add = |total: &mut Option<u64>, value: Option<u64>| {
+            if let Some(value) = value {
+                *total = Some(total.unwrap_or(0) + value);
+            }
+        };
+        add(&mut stats.used_memory, number(&info, "used_memory"));
+        add(
+            &mut stats.connected_clients,
+            number(&info, "connected_clients"),
+        );
+        stats.hits += number(&info, "keyspace_hits").unwrap_or(0);
+        stats.misses += number(&info, "keyspace_misses").unwrap_or(0);
+        stats.evictions += number(&info, "evicted_keys").unwrap_or(0);
+        for (name, value) in info.iter().filter(|(k, _)| k.starts_with("db")) {
+            if let Some(db) = parse_keyspace(name, value) {
+                let entry = databases.entry(name.clone()).or_insert(KvDatabaseStats {
+                    name: name.clone(),
+                    keys: 0,
+                    expires: 0,
+                    avg_ttl_ms: None,
+                });
+                entry.keys += db.keys;
+                entry.expires += db.expires;
+                entry.avg_ttl_ms = entry.avg_ttl_ms.or(db.avg_ttl_ms);
+            }
+        }
+    }
+    stats.databases = databases.into_values().collect();
+    stats.total_keys = stats.databases.iter().map(|d| d.keys).sum();
+    stats.expiring_keys = stats.databases.iter().map(|d| d.expires).sum();
+
+    // 采样当前数据库的键统计类型分布
+    let mut cursor: Option<String> = None;
+    let mut sampled = 0;
+    while sampled < TYPE_SAMPLE_SIZE {
+        let request = KvCommandRequest {
+            connection: connection.clone(),
+            cursor: cursor.clone(),
+            count: Some(DEFAULT_SCAN_COUNT),
+            ..Default::default()
+        };
+        let (keys, next) = redis_scan(&mut client, &request).await?;
+        for key in keys.iter().take(TYPE_SAMPLE_SIZE - sampled) {
+            let value_type = client
+                .call(&["TYPE", key])
+                .await?
+                .into_text()
+                .unwrap_or_default();
+            *stats.type_distribution.entry(value_type).or_insert(0) += 1;
+            sampled += 1;
+        }
+        match next {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+    stats.sampled_keys = sampled;
+    Ok(stats)
+}
+
+/// Memcached 文本协议客户端
+struct MemcachedClient {
+    stream: BufReader<Box<dyn KvStream>>,
+    wait: Duration,
+}
+
+impl MemcachedClient {
+    async fn connect(connection: &KvConnection) -> Result<Self, String> {
+        Ok(Self {
+            stream: open_stream(connection, &default_address(connection)).await?,
+            wait: timeout(connection),
+        })
+    }
+
+    async fn write(&mut self, data: &[u8]) -> Result<(), String> {
+        self.stream
+            .get_mut()
+            .write_all(data)
+            .await
+            .map_err(|e| format!("发送命令失败: {}", e))
+    }
+
+    /// 发送命令并读取一行应答
+    async fn call_line(&mut self, command: &[u8]) -> Result<String, String> {
+        let wait = self.wait;
+        let exchange = async {
+            self.write(command).await?;
+            read_line(&mut self.stream).await
+        };
+        let line = tokio::time::timeout(wait, exchange)
+            .await
+            .map_err(|_| "命令超时".to_string())??;
+        memcached_error(&line)?;
+        Ok(line)
+    }
+
+    /// 读取以 END 结尾的多行应答
+    async fn read_until_end(&mut self) -> Result<Vec<String>, String> {
+        let mut lines = Vec::new();
+        loop {
+            let line = tokio::time::timeout(self.wait, read_line(&mut self.stream))
+                .await
+                .map_err(|_| "命令超时".to_string())??;
+            memcached_error(&line)?;
+            if line == "END" {
+                return Ok(lines);
+            }
+            lines.push(line);
+        }
+    }
+
+    async fn get(&mut self, key: &str) -> Result<Option<Vec<u8>>, String> {
+        let header = self
+            .call_line(format!("get {}\r\n", key).as_bytes())
+            .await?;
+        if header == "END" {
+            return Ok(None);
+        }
+        // VALUE <key> <flags> <bytes>
+        let len = header
+            .split_whitespace()
+            .nth(3)
+            .and_then(|n| n.parse::<usize>().ok())
+            .ok_or_else(|| format!("无效的应答: {}", header))?;
+        let data = read_exact_bulk(&mut self.stream, len).await?;
+        self.read_until_end().await?;
+        Ok(Some(data))
+    }
+}
+
+fn memcached_error(line: &str) -> Result<(), String> {
+    if line == "ERROR" {
+        return Err("服务端不支持该命令".to_string());
+    }
+    for prefix in ["CLIENT_ERROR ", "SERVER_ERROR "] {
+        if let Some(message) = line.strip_prefix(prefix) {
+            return Err(message.to_string());
+        }
+    }
+    Ok(())
+}
+
+/// Memcached 键不能包含空白和控制字符，最长 250 字节
+fn validate_memcached_key(key: &str) -> Result<(), String> {
+    if key.len() > 250 || key.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return Err("Memcached 键不能包含空白字符且不超过 250 字节".to_string());
+    }
+    Ok(())
+}
+
+/// 解析 "HD t120" / "VA 5 t-1" 中的剩余过期时间
+fn parse_meta_ttl(line: &str) -> Option<i64> {
+    line.split_whitespace()
+        .find_map(|flag| flag.strip_prefix('t'))
+        .and_then(|t| t.parse().ok())
+}
+
+/// 匹配 glob 模式，支持 * 和 ?
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = star {
+            p = star_p + 1;
+            t = star_t + 1;
+            star = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+async fn memcached_execute(
+    request: &KvCommandRequest,
+    command: &str,
+) -> Result<KvCommandResult, String> {
+    let mut client = MemcachedClient::connect(&request.connection).await?;
+    let key = request.key.as_deref().unwrap_or_default();
+    if command != "scan" {
+        validate_memcached_key(key)?;
+    }
+    let mut result = KvCommandResult {
+        node: default_address(&request.connection),
+        ..Default::default()
+    };
+
+    match command {
+        "get" => {
+            if let Some(data) = client.get(key).await? {
+                result.found = true;
+                result.value_type = Some("string".to_string());
+                result.value = Some(Value::String(String::from_utf8_lossy(&data).to_string()));
+                result.ttl = memcached_ttl(&mut client, key).await?;
+            }
+        }
+        "set" => {
+            let value = request.value.clone().unwrap_or_default();
+            let mut data = format!(
+                "set {} 0 {} {}\r\n",
+                key,
+                request.ttl_secs.unwrap_or(0),
+                value.len()
+            )
+            .into_bytes();
+            data.extend_from_slice(value.as_bytes());
+            data.extend_from_slice(b"\r\n");
+            let reply = client.call_line(&data).await?;
+            if reply != "STORED" {
+                return Err(format!("写入失败: {}", reply));
+            }
+            result.found = true;
+            result.affected = Some(1);
+        }
+        "del" => {
+            let reply = client
+                .call_line(format!("delete {}\r\n", key).as_bytes())
+                .await?;
+            result.found = reply == "DELETED";
+            result.affected = Some(result.found as i64);
+        }
+        "ttl" => {
+            result.ttl = memcached_ttl(&mut client, key).await?;
+            result.found = result.ttl.is_some();
+        }
+        "scan" => {
+            // lru_crawler metadump 一次返回全部键，按模式过滤后截取一批
+            let pattern = request
+                .pattern
+                .as_deref()
+                .filter(|p| !p.is_empty())
+                .unwrap_or("*");
+            let count = request.count.unwrap_or(DEFAULT_SCAN_COUNT).max(1) as usize;
+            let offset: usize = match request.cursor.as_deref().filter(|c| !c.is_empty()) {
+                Some(cursor) => cursor.parse().map_err(|_| "无效的游标".to_string())?,
+                None => 0,
+            };
+            client.write(b"lru_crawler metadump all\r\n").await?;
+            let lines = client.read_until_end().await?;
+            let matched: Vec<String> = lines
+                .iter()
+                .filter_map(|line| parse_metadump_key(line))
+                .filter(|key| glob_match(pattern, key))
+                .collect();
+            result.keys = matched.iter().skip(offset).take(count).cloned().collect();
+            result.found = !result.keys.is_empty();
+            if offset + count < matched.len() {
+                result.next_cursor = Some((offset + count).to_string());
+            }
+        }
+        _ => return Err(format!("不支持的命令: {}", command)),
+    }
+    Ok(result)
+}
+
+/// Memcached 1.6 的 meta get 可返回剩余过期时间，-1 表示不过期
+async fn memcached_ttl(client: &mut MemcachedClient, key: &str) -> Result<Option<i64>, String> {
+    let line = client
+        .call_line(format!("mg {} t\r\n", key).as_bytes())
+        .await?;
+    if line.starts_with("EN") {
+        return Ok(None);
+    }
+    if !line.starts_with("HD") && !line.starts_with("VA") {
+        return Err(format!("服务端不支持 meta 命令: {}", line));
+    }
+    Ok(parse_meta_ttl(&line))
+}
+
+/// 解析 metadump 行 "key=foo%20bar exp=-1 la=... cas=1 fetch=no cls=1 size=63"
+fn parse_metadump_key(line: &str) -> Option<String> {
+    let key = line.split_whitespace().next()?.strip_prefix("key=")?;
+    Some(
+        percent_encoding::percent_decode_str(key)
+            .decode_utf8_lossy()
+            .to_string(),
+    )
+}
+
+async fn memcached_stats(connection: &KvConnection) -> Result<KvStats, String> {
+    let mut client = MemcachedClient::connect(connection).await?;
+    client.write(b"stats\r\n").await?;
+    let stats: BTreeMap<String, String> = client
+        .read_until_end()
+        .await?
+        .iter()
+        .filter_map(|line| {
+            let mut parts = line.splitn(3, ' ');
+            (parts.next() == Some("STAT"))
+                .then(|| Some((parts.next()?.to_string(), parts.next()?.to_string())))
+                .flatten()
+        })
+        .collect();
+    let number = |key: &str| stats.get(key).and_then(|v| v.parse::<u64>().ok());
+    let items = number("curr_items").unwrap_or(0);
+    Ok(KvStats {
+        kind: "memcached".to_string(),
+        version: stats.get("version").cloned(),
+        uptime_secs: number("uptime"),
+        used_memory: number("bytes"),
+        max_memory: number("limit_maxbytes"),
+        connected_clients: number("curr_connections"),
+        total_keys: items,
+        hits: number("get_hits").unwrap_or(0),
+        misses: number("get_misses").unwrap_or(0),
+        evictions: number("evictions").unwrap_or(0),
+        databases: vec![KvDatabaseStats {
+            name: "default".to_string(),
+            keys: items,
+            expires: 0,
+            avg_ttl_ms: None,
+        }],
+        nodes: vec![default_address(connection)],
+        ..Default::default()
+    })
+}
+
+/// 值是 JSON 对象或数组时返回格式化文本
+fn pretty_json(text: &str) -> Option<String> {
+    let trimmed = text.trim_start();
+    if !trimmed.starts_with('{') && !trimmed.starts_with('[') {
+        return None;
+    }
+    serde_json::from_str::<Value>(text)
+        .ok()
+        .and_then(|v| serde_json::to_string_pretty(&v).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    fn connection(kind: &str, port: u16) -> KvConnection {
+        KvConnection {
+            kind: kind.to_string(),
+            host: "127.0.0.1".to_string(),
+            port: Some(port),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_read_resp() {
+        let data: &[u8] = b"*3\r\n$5\r\nhello\r\n:42\r\n*2\r\n$-1\r\n-ERR boom\r\n+OK\r\n";
+        let mut reader = BufReader::new(data);
+        assert_eq!(
+            read_resp(&mut reader).await.unwrap(),
+            Resp::Array(Some(vec![
+                Resp::Bulk(Some(b"hello".to_vec())),
+                Resp::Integer(42),
+                Resp::Array(Some(vec![
+                    Resp::Bulk(None),
+                    Resp::Error("ERR boom".to_string())
+                ])),
+            ]))
+        );
+        assert_eq!(
+            read_resp(&mut reader).await.unwrap(),
+            Resp::Simple("OK".to_string())
+        );
+        assert!(read_resp(&mut reader).await.is_err());
+        assert_eq!(
+            encode_command(&["SET", "k", "a b"]),
+            b"*3\r\n$3\r\nSET\r\n$1\r\nk\r\n$3\r\na b\r\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_cluster() {
+        assert_eq!(
+            parse_redirect("MOVED 3999 10.0.0.2:6381"),
+            Some(Redirect::Moved("10.0.0.2:6381".to_string()))
+        );
+        assert_eq!(
+            parse_redirect("ASK 3999 10.0.0.3:6381"),
+            Some(Redirect::Ask("10.0.0.3:6381".to_string()))
+        );
+        assert_eq!(parse_redirect("ERR unknown command"), None);
+
+        let nodes = "\
+07c3 10.0.0.2:6379@16379 master - 0 0 2 connected 5461-10922
+67ed 10.0.0.1:6379@16379,redis-1 myself,master - 0 0 1 connected 0-5460
+292f 10.0.0.4:6379@16379 slave 67ed 0 0 1 connected
+e7d1 10.0.0.3:6379@16379 master,fail - 0 0 3 disconnected
+";
+        assert_eq!(
+            parse_cluster_masters(nodes),
+            vec!["10.0.0.1:6379", "10.0.0.2:6379"]
+        );
+        assert_eq!(parse_scan_cursor(None).unwrap(), (0, "0".to_string()));
+        assert_eq!(
+            parse_scan_cursor(Some("1:17")).unwrap(),
+            (1, "17".to_string())
+        );
+        assert!(parse_scan_cursor(Some("x")).is_err());
+        assert_eq!(
+            split_address("[::1]:6379").unwrap(),
+            ("::1".to_string(), 6379)
+        );
+    }
+
+    #[test]
+    fn test_parse_info() {
+        let info = parse_info(
+            "# Server\r\nredis_version:7.2.4\r\n\r\n# Keyspace\r\ndb0:keys=10,expires=2,avg_ttl=3000\r\ndb3:keys=1,expires=0,avg_ttl=0\r\n",
+        );
+        assert_eq!(info["redis_version"], "7.2.4");
+        let db0 = parse_keyspace("db0", &info["db0"]).unwrap();
+        assert_eq!((db0.keys, db0.expires, db0.avg_ttl_ms), (10, 2, Some(3000)));
+        assert_eq!(
+            parse_keyspace("db3", &info["db3"]).unwrap().avg_ttl_ms,
+            None
+        );
+    }
+
+    #[test]
+    fn test_memcached_helpers() {
+        assert_eq!(parse_meta_ttl("HD t120"), Some(120));
+        assert_eq!(parse_meta_ttl("VA 5 t-1"), Some(-1));
+        assert_eq!(
+            parse_metadump_key("key=user%3A1 exp=-1 la=1700000000 cas=2 fetch=no cls=1 size=63"),
+            Some("user:1".to_string())
+        );
+        assert!(glob_match("user:*", "user:1"));
+        assert!(glob_match("u?er:*1", "user:21"));
+        assert!(!glob_match("user:*", "session:1"));
+        assert!(validate_memcached_key("has space").is_err());
+        assert_eq!(
+            pretty_json("{\"a\":1}"),
+            Some("{\n  \"a\": 1\n}".to_string())
+        );
+        assert_eq!(pretty_json("plain"), None);
+    }
+
+    #[tokio::test]
+    async fn test_redis_get_follows_moved() {
+        // 第一个节点返回 MOVED，第二个节点保存数据
+        let first = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let second = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let first_port = first.local_addr().unwrap().port();
+        let second_port = second.local_addr().unwrap().port();
+        let moved = format!("-MOVED 866 127.0.0.1:{}\r\n", second_port);
+        tokio::spawn(async move {
+            let (stream, _) = first.accept().await.unwrap();
+            let mut stream = BufReader::new(stream);
+            read_resp(&mut stream).await.unwrap();
+            stream.get_mut().write_all(moved.as_bytes()).await.unwrap();
+        });
+        let server = tokio::spawn(async move {
+            let (stream, _) = second.accept().await.unwrap();
+            let mut stream = BufReader::new(stream);
+            let mut commands = Vec::new();
+            for reply in [
+                "+string\r\n".to_string(),
+                "$12\r\n{\"name\":\"a\"}\r\n".to_string(),
+                ":30\r\n".to_string(),
+            ] {
+                let command = read_resp(&mut stream).await.unwrap().to_json();
+                commands.push(command[0].as_str().unwrap().to_string());
+                stream.get_mut().write_all(reply.as_bytes()).await.unwrap();
+            }
+            commands
+        });
+
+        let mut connection = connection("redis", first_port);
+        connection.cluster = Some(true);
+        let result = kv_execute(KvCommandRequest {
+            connection,
+            command: "GET".to_string(),
+            key: Some("user".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+        assert_eq!(server.await.unwrap(), vec!["TYPE", "GET", "TTL"]);
+        assert!(result.found);
+        assert_eq!(result.value_type.as_deref(), Some("string"));
+        assert_eq!(result.pretty.as_deref(), Some("{\n  \"name\": \"a\"\n}"));
+        assert_eq!(result.ttl, Some(30));
+        assert_eq!(result.node, format!("127.0.0.1:{}", second_port));
+    }
+
+    #[tokio::test]
+    async fn test_memcached_get_and_stats() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            for replies in [
+                vec!["VALUE k 0 5\r\nhello\r\nEND\r\n", "HD t-1\r\n"],
+                vec!["STAT version 1.6.21\r\nSTAT curr_items 3\r\nSTAT get_hits 3\r\nSTAT get_misses 1\r\nEND\r\n"],
+            ] {
+                let (stream, _) = listener.accept().await.unwrap();
+                let mut stream = BufReader::new(stream);
+                for reply in replies {
+                    read_line(&mut stream).await.unwrap();
+                    stream.get_mut().write_all(reply.as_bytes()).await.unwrap();
+                }
+            }
+        });
+
+        let result = kv_execute(KvCommandRequest {
+            connection: connection("memcached", port),
+            command: "get".to_string(),
+            key: Some("k".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+        assert_eq!(result.value, Some(Value::String("hello".to_string())));
+        assert_eq!(result.ttl, Some(-1));
+
+        let stats = kv_stats(connection("memcached", port)).await.unwrap();
+        assert_eq!(stats.version.as_deref(), Some("1.6.21"));
+        assert_eq!(stats.total_keys, 3);
+        assert_eq!(stats.hit_rate, Some(75.0));
+    }
+}
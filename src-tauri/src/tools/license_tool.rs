@@ -0,0 +1,784 @@
+use serde::{Deserialize, Serialize};
+
+/// 候选许可证的最低匹配度
+const MIN_CONFIDENCE: f64 = 0.5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LicenseCategory {
+    PublicDomain,
+    Permissive,
+    WeakCopyleft,
+    StrongCopyleft,
+    NetworkCopyleft,
+    Proprietary,
+}
+
+impl LicenseCategory {
+    fn label(self) -> &'static str {
+        match self {
+            LicenseCategory::PublicDomain => "公有领域",
+            LicenseCategory::Permissive => "宽松许可",
+            LicenseCategory::WeakCopyleft => "弱 Copyleft",
+            LicenseCategory::StrongCopyleft => "强 Copyleft",
+            LicenseCategory::NetworkCopyleft => "网络 Copyleft",
+            LicenseCategory::Proprietary => "专有/闭源",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Compatibility {
+    Incompatible,
+    Unknown,
+    Conditional,
+    Compatible,
+}
+
+struct LicenseDef {
+    id: &'static str,
+    name: &'static str,
+    category: LicenseCategory,
+    phrases: &'static [&'static str], // 归一化后（小写、去标点）的特征短语
+    excludes: &'static [&'static str], // 出现这些短语时不是该许可证
+    obligations: &'static [&'static str],
+}
+
+const LICENSES: &[LicenseDef] = &[
+    LicenseDef {
+        id: "MIT",
+        name: "MIT License",
+        category: LicenseCategory::Permissive,
+        phrases: &[
+            "permission is hereby granted free of charge to any person obtaining a copy",
+            "the above copyright notice and this permission notice shall be included in all copies or substantial portions of the software",
+            "the software is provided as is without warranty of any kind",
+        ],
+        excludes: &[],
+        obligations: &["在所有副本中保留版权声明和许可声明"],
+    },
+    LicenseDef {
+        id: "Apache-2.0",
+        name: "Apache License 2.0",
+        category: LicenseCategory::Permissive,
+        phrases: &[
+            "apache license",
+            "version 2 0",
+            "grant of patent license",
+            "you may not use this file except in compliance with the license",
+        ],
+        excludes: &[],
+        obligations: &[
+            "保留版权、专利、商标和归属声明",
+            "随分发附带许可证副本",
+            "修改过的文件需注明已修改",
+            "存在 NOTICE 文件时需一并分发",
+        ],
+    },
+    LicenseDef {
+        id: "BSD-2-Clause",
+        name: "BSD 2-Clause \"Simplified\" License",
+        category: LicenseCategory::Permissive,
+        phrases: &[
+            "redistribution and use in source and binary forms with or without modification are permitted provided that the following conditions are met",
+            "redistributions of source code must retain the above copyright notice",
+            "redistributions in binary form must reproduce the above copyright notice",
+        ],
+        excludes: &["neither the name of"],
+        obligations: &["源码分发保留版权声明", "二进制分发在文档中附带版权声明"],
+    },
+    LicenseDef {
+        id: "BSD-3-Clause",
+        name: "BSD 3-Clause \"New\" or \"Revised\" License",
+        category: LicenseCategory::Permissive,
+        phrases: &[
+            "redistribution and use in source and binary forms with or without modification are permitted provided that the following conditions are met",
+            "redistributions of source code must retain the above copyright notice",
+            "redistributions in binary form must reproduce the above copyright notice",
+            "neither the name of",
+        ],
+        excludes: &[],
+        obligations: &[
+            "源码分发保留版权声明",
+            "二进制分发在文档中附带版权声明",
+            "未经许可不得使用原作者名义为衍生产品背书",
+        ],
+    },
+    LicenseDef {
+        id: "ISC",
+        name: "ISC License",
+        category: LicenseCategory::Permissive,
+        phrases: &[
+            "permission to use copy modify and or distribute this software for any purpose with or without fee is hereby granted",
+            "provided that the above copyright notice and this permission notice appear in all copies",
+        ],
+        excludes: &[],
+        obligations: &["在所有副本中保留版权声明和许可声明"],
+    },
+    LicenseDef {
+        id: "0BSD",
+        name: "BSD Zero Clause License",
+        category: LicenseCategory::PublicDomain,
+        phrases: &[
+            "permission to use copy modify and or distribute this software for any purpose with or without fee is hereby granted",
+            "the software is provided as is and the author disclaims all warranties",
+        ],
+        excludes: &["provided that the above copyright notice"],
+        obligations: &[],
+    },
+    LicenseDef {
+        id: "Zlib",
+        name: "zlib License",
+        category: LicenseCategory::Permissive,
+        phrases: &[
+            "the origin of this software must not be misrepresented",
+            "altered source versions must be plainly marked as such",
+            "this notice may not be removed or altered from any source distribution",
+        ],
+        excludes: &[],
+        obligations: &["不得歪曲软件来源", "修改后的源码需明确标注", "源码分发保留该声明"],
+    },
+    LicenseDef {
+        id: "BSL-1.0",
+        name: "Boost Software License 1.0",
+        category: LicenseCategory::Permissive,
+        phrases: &[
+            "boost software license version 1 0",
+            "permission is hereby granted free of charge to any person or organization obtaining a copy of the software and accompanying documentation",
+        ],
+        excludes: &[],
+        obligations: &["源码分发保留版权声明和许可证（二进制分发无此要求）"],
+    },
+    LicenseDef {
+        id: "Unlicense",
+        name: "The Unlicense",
+        category: LicenseCategory::PublicDomain,
+        phrases: &[
+            "this is free and unencumbered software released into the public domain",
+            "anyone is free to copy modify publish use compile sell or distribute this software",
+        ],
+        excludes: &[],
+        obligations: &[],
+    },
+    LicenseDef {
+        id: "CC0-1.0",
+        name: "Creative Commons Zero v1.0 Universal",
+        category: LicenseCategory::PublicDomain,
+        phrases: &["creative commons", "cc0 1 0 universal", "statement of purpose"],
+        excludes: &[],
+        obligations: &[],
+    },
+    LicenseDef {
+        id: "MPL-2.0",
+        name: "Mozilla Public License 2.0",
+        category: LicenseCategory::WeakCopyleft,
+        phrases: &[
+            "mozilla public license version 2 0",
+            "exhibit a source code form license notice",
+            "secondary license",
+        ],
+        excludes: &[],
+        obligations: &[
+            "修改过的 MPL 文件需以 MPL-2.0 开源",
+            "分发可执行文件时告知如何获取 MPL 部分的源码",
+            "保留原有的许可证声明",
+        ],
+    },
+    LicenseDef {
+        id: "LGPL-2.1-only",
+        name: "GNU Lesser General Public License v2.1",
+        category: LicenseCategory::WeakCopyleft,
+        phrases: &[
+            "gnu lesser general public license",
+            "version 2 1 february 1999",
+        ],
+        excludes: &[],
+        obligations: &[
+            "对库本身的修改需以 LGPL 开源",
+            "允许用户替换或重新链接该库（建议动态链接）",
+            "提供库的源码或获取方式并附带许可证",
+        ],
+    },
+    LicenseDef {
+        id: "LGPL-3.0-only",
+        name: "GNU Lesser General Public License v3.0",
+        category: LicenseCategory::WeakCopyleft,
+        phrases: &[
+            "gnu lesser general public license",
+            "version 3 29 june 2007",
+            "this version of the gnu lesser general public license incorporates the terms and conditions of version 3 of the gnu general public license",
+        ],
+        excludes: &[],
+        obligations: &[
+            "对库本身的修改需以 LGPL 开源",
+            "允许用户替换或重新链接该库（建议动态链接）",
+            "提供库的源码、安装信息并附带 GPL 和 LGPL 文本",
+        ],
+    },
+    LicenseDef {
+        id: "GPL-2.0-only",
+        name: "GNU General Public License v2.0",
+        category: LicenseCategory::StrongCopyleft,
+        phrases: &[
+            "gnu general public license",
+            "version 2 june 1991",
+            "everyone is permitted to copy and distribute verbatim copies",
+        ],
+        excludes: &["gnu lesser general public license version 2 1"],
+        obligations: &[
+            "衍生作品整体以 GPL-2.0 开源",
+            "分发二进制时提供完整对应源码或书面要约",
+            "保留版权声明和许可证文本",
+        ],
+    },
+    LicenseDef {
+        id: "GPL-3.0-only",
+        name: "GNU General Public License v3.0",
+        category: LicenseCategory::StrongCopyleft,
+        phrases: &[
+            "gnu general public license",
+            "version 3 29 june 2007",
+            "the gnu general public license is a free copyleft license for software and other kinds of works",
+        ],
+        excludes: &[],
+        obligations: &[
+            "衍生作品整体以 GPL-3.0 开源",
+            "分发时提供完整对应源码",
+            "用户产品需提供安装信息（反 Tivo 化）",
+            "保留版权声明和许可证文本",
+        ],
+    },
+    LicenseDef {
+        id: "AGPL-3.0-only",
+        name: "GNU Affero General Public License v3.0",
+        category: LicenseCategory::NetworkCopyleft,
+        phrases: &[
+            "gnu affero general public license",
+            "version 3 19 november 2007",
+            "remote network interaction",
+        ],
+        excludes: &[],
+        obligations: &[
+            "衍生作品整体以 AGPL-3.0 开源",
+            "通过网络向用户提供服务时也需提供对应源码",
+            "保留版权声明和许可证文本",
+        ],
+    },
+    LicenseDef {
+        id: "EPL-2.0",
+        name: "Eclipse Public License 2.0",
+        category: LicenseCategory::WeakCopyleft,
+        phrases: &["eclipse public license", "v 2 0", "secondary license"],
+        excludes: &[],
+        obligations: &[
+            "修改过的 EPL 模块需以 EPL-2.0 开源",
+            "分发目标代码时告知源码获取方式",
+        ],
+    },
+];
+
+/// 常见写法到 SPDX 标识的映射
+const ALIASES: &[(&str, &str)] = &[
+    ("GPL-2.0", "GPL-2.0-only"),
+    ("GPLv2", "GPL-2.0-only"),
+    ("GPL-2.0+", "GPL-2.0-or-later"),
+    ("GPL-3.0", "GPL-3.0-only"),
+    ("GPLv3", "GPL-3.0-only"),
+    ("GPL-3.0+", "GPL-3.0-or-later"),
+    ("LGPL-2.1", "LGPL-2.1-only"),
+    ("LGPL-2.1+", "LGPL-2.1-only"),
+    ("LGPL-2.1-or-later", "LGPL-2.1-only"),
+    ("LGPL-3.0", "LGPL-3.0-only"),
+    ("LGPL-3.0+", "LGPL-3.0-only"),
+    ("LGPL-3.0-or-later", "LGPL-3.0-only"),
+    ("AGPL-3.0", "AGPL-3.0-only"),
+    ("AGPL-3.0-or-later", "AGPL-3.0-only"),
+    ("Apache 2.0", "Apache-2.0"),
+    ("Apache2", "Apache-2.0"),
+    ("BSD-2", "BSD-2-Clause"),
+    ("BSD-3", "BSD-3-Clause"),
+    ("MPL2", "MPL-2.0"),
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LicenseInfo {
+    pub spdx_id: String,
+    pub name: String,
+    pub category: LicenseCategory,
+    pub category_name: String,
+    pub obligations: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LicenseMatch {
+    pub spdx_id: String,
+    pub name: String,
+    pub confidence: f64, // 0-1
+    pub matched_phrases: usize,
+    pub total_phrases: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LicenseDetection {
+    pub spdx_expression: Option<String>, // 文本中的 SPDX-License-Identifier
+    pub best: Option<LicenseInfo>,
+    pub candidates: Vec<LicenseMatch>,
+    pub notes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LicenseCompatibilityRequest {
+    pub project_license: String,
+    pub dependencies: Vec<DependencyLicense>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DependencyLicense {
+    pub name: String,
+    pub license: String, // SPDX 表达式，如 MIT OR Apache-2.0
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DependencyCompatibility {
+    pub name: String,
+    pub license: String,
+    pub selected_license: Option<String>, // OR 表达式中选用的许可证
+    pub compatibility: Compatibility,
+    pub reason: String,
+    pub obligations: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LicenseCompatibilityReport {
+    pub project: LicenseInfo,
+    pub results: Vec<DependencyCompatibility>,
+    pub compatible: bool,         // 不存在不兼容的依赖
+    pub obligations: Vec<String>, // 所有依赖的义务去重汇总
+    pub summary: String,
+}
+
+/// 列出内置的许可证
+#[tauri::command]
+pub fn list_licenses() -> Vec<LicenseInfo> {
+    LICENSES.iter().map(license_info).collect()
+}
+
+/// 从粘贴的许可证文本或源码头部识别许可证
+#[tauri::command]
+pub fn detect_license(text: String) -> Result<LicenseDetection, String> {
+    if text.trim().is_empty() {
+        return Err("许可证文本不能为空".to_string());
+    }
+    let mut notes = Vec::new();
+    let spdx_expression = text.lines().find_map(|line| {
+        line.split_once("SPDX-License-Identifier:")
+            .map(|(_, expr)| expr.trim().trim_end_matches("*/").trim().to_string())
+    });
+
+    let normalized = normalize_text(&text);
+    let mut candidates: Vec<LicenseMatch> = LICENSES
+        .iter()
+        .filter(|def| !def.excludes.iter().any(|p| normalized.contains(p)))
+        .filter_map(|def| {
+            let matched = def
+                .phrases
+                .iter()
+                .filter(|p| normalized.contains(*p))
+                .count();
+            let confidence = matched as f64 / def.phrases.len() as f64;
+            (confidence >= MIN_CONFIDENCE).then(|| LicenseMatch {
+                spdx_id: def.id.to_string(),
+                name: def.name.to_string(),
+                confidence: (confidence * 100.0).round() / 100.0,
+                matched_phrases: matched,
+                total_phrases: def.phrases.len(),
+            })
+        })
+        .collect();
+    candidates.sort_by(|a, b| {
+        b.confidence
+            .total_cmp(&a.confidence)
+            .then(b.matched_phrases.cmp(&a.matched_phrases))
+    });
+
+    let best = match &spdx_expression {
+        Some(expr) => {
+            let first = parse_expression(expr)
+                .into_iter()
+                .flatten()
+                .next()
+                .unwrap_or_default();
+            let info = lookup_license(&first);
+            if info.is_none() {
+                notes.push(format!("SPDX 标识 {} 不在内置许可证列表中", first));
+            }
+            info
+        }
+        None => candidates.first().and_then(|c| lookup_license(&c.spdx_id)),
+    };
+    if let Some(info) = &best {
+        if info.spdx_id.contains("GPL") && !info.spdx_id.starts_with("LGPL") {
+            notes.push(
+                "许可证全文无法区分 only 与 or-later，请以源码文件头部的声明为准".to_string(),
+            );
+        }
+    } else {
+        notes.push("未能识别许可证，可能是自定义许可证或文本不完整".to_string());
+    }
+
+    Ok(LicenseDetection {
+        spdx_expression,
+        best,
+        candidates,
+        notes,
+    })
+}
+
+/// 检查依赖的许可证能否在项目许可证下分发
+#[tauri::command]
+pub fn check_license_compatibility(
+    request: LicenseCompatibilityRequest,
+) -> Result<LicenseCompatibilityReport, String> {
+    let project = lookup_license(&request.project_license)
+        .ok_or_else(|| format!("无法识别项目许可证: {}", request.project_license))?;
+
+    let mut results = Vec::with_capacity(request.dependencies.len());
+    let mut obligations: Vec<String> = Vec::new();
+    for dependency in &request.dependencies {
+        let result = check_dependency(&project, dependency);
+        for obligation in &result.obligations {
+            if !obligations.contains(obligation) {
+                obligations.push(obligation.clone());
+            }
+        }
+        results.push(result);
+    }
+
+    let count = |c: Compatibility| results.iter().filter(|r| r.compatibility == c).count();
+    let incompatible = count(Compatibility::Incompatible);
+    let summary = format!(
+        "项目许可证 {}（{}），共 {} 个依赖：{} 个兼容，{} 个有条件兼容，{} 个不兼容，{} 个无法判断",
+        project.spdx_id,
+        project.category_name,
+        results.len(),
+        count(Compatibility::Compatible),
+        count(Compatibility::Conditional),
+        incompatible,
+        count(Compatibility::Unknown)
+    );
+    Ok(LicenseCompatibilityReport {
+        project,
+        compatible: incompatible == 0,
+        results,
+        obligations,
+        summary,
+    })
+}
+
+fn license_info(def: &LicenseDef) -> LicenseInfo {
+    LicenseInfo {
+        spdx_id: def.id.to_string(),
+        name: def.name.to_string(),
+        category: def.category,
+        category_name: def.category.label().to_string(),
+        obligations: def.obligations.iter().map(|o| o.to_string()).collect(),
+    }
+}
+
+/// 按 SPDX 标识或常见别名查找许可证，or-later 版本沿用对应版本的条款
+fn lookup_license(id: &str) -> Option<LicenseInfo> {
+    let id = id.trim();
+    if ["proprietary", "unlicensed", "commercial", "闭源"]
+        .iter()
+        .any(|p| id.eq_ignore_ascii_case(p))
+    {
+        return Some(LicenseInfo {
+            spdx_id: "Proprietary".to_string(),
+            name: "专有许可".to_string(),
+            category: LicenseCategory::Proprietary,
+            category_name: LicenseCategory::Proprietary.label().to_string(),
+            obligations: Vec::new(),
+        });
+    }
+    let canonical = ALIASES
+        .iter()
+        .find(|(alias, _)| alias.eq_ignore_ascii_case(id))
+        .map(|(_, canonical)| *canonical)
+        .unwrap_or(id);
+    let base = canonical.replace("-or-later", "-only");
+    let def = LICENSES
+        .iter()
+        .find(|def| def.id.eq_ignore_ascii_case(&base))?;
+    let mut info = license_info(def);
+    if canonical.ends_with("-or-later") {
+        info.spdx_id = canonical.to_string();
+        info.name = format!("{} or later", def.name);
+    }
+    Some(info)
+}
+
+/// 小写并把标点、换行统一为单个空格
+fn normalize_text(text: &str) -> String {
+    let mapped: String = text
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect();
+    mapped.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// 解析 SPDX 表达式为“或”连接的多组许可证，组内为 AND
+fn parse_expression(expr: &str) -> Vec<Vec<String>> {
+    let cleaned = expr.replace(['(', ')'], " ");
+    let upper_ops = cleaned
+        .split_whitespace()
+        .map(|token| match token.to_ascii_uppercase().as_str() {
+            "OR" | "AND" | "WITH" => token.to_ascii_uppercase(),
+            _ => token.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    upper_ops
+        .split(" OR ")
+        .map(|group| {
+            group
+                .split(" AND ")
+                .map(|id| id.trim().to_string())
+                .filter(|id| !id.is_empty())
+                .collect()
+        })
+        .filter(|group: &Vec<String>| !group.is_empty())
+        .collect()
+}
+
+fn check_dependency(
+    project: &LicenseInfo,
+    dependency: &DependencyLicense,
+) -> DependencyCompatibility {
+    let mut best: Option<(Compatibility, String, String, Vec<String>)> = None;
+    for group in parse_expression(&dependency.license) {
+        // AND 组合取最差的结果
+        let mut worst = (Compatibility::Compatible, Vec::new(), Vec::new());
+        for id in &group {
+            let (license_id, exception) = match id.split_once(" WITH ") {
+                Some((license, exception)) => (license.trim(), Some(exception.trim())),
+                None => (id.as_str(), None),
+            };
+            let (result, reason, obligations) = match lookup_license(license_id) {
+                Some(mut info) => {
+                    // GPL 加链接例外（如 Classpath-exception）按弱 Copyleft 处理
+                    if exception.is_some() && info.category == LicenseCategory::StrongCopyleft {
+                        info.category = LicenseCategory::WeakCopyleft;
+                    }
+                    let (result, reason) = compatibility(project, &info);
+                    (result, reason, info.obligations)
+                }
+                None => (
+                    Compatibility::Unknown,
+                    format!("无法识别许可证 {}，请人工确认", license_id),
+                    Vec::new(),
+                ),
+            };
+            worst.0 = worst.0.min(result);
+            worst.1.push(reason);
+            worst.2.extend(obligations);
+        }
+        let candidate = (worst.0, group.join(" AND "), worst.1.join("；"), worst.2);
+        if best.as_ref().is_none_or(|b| candidate.0 > b.0) {
+            best = Some(candidate);
+        }
+    }
+
+    match best {
+        Some((compatibility, selected, reason, obligations)) => DependencyCompatibility {
+            name: dependency.name.clone(),
+            license: dependency.license.clone(),
+            selected_license: Some(selected),
+            compatibility,
+            reason,
+            obligations,
+        },
+        None => DependencyCompatibility {
+            name: dependency.name.clone(),
+            license: dependency.license.clone(),
+            selected_license: None,
+            compatibility: Compatibility::Unknown,
+            reason: "许可证为空".to_string(),
+            obligations: Vec::new(),
+        },
+    }
+}
+
+/// GPL 家族可使用的 GPL 主版本
+fn gpl_versions(id: &str) -> &'static [u8] {
+    match id {
+        "GPL-2.0-only" => &[2],
+        "GPL-2.0-or-later" => &[2, 3],
+        "GPL-3.0-only" | "GPL-3.0-or-later" | "AGPL-3.0-only" => &[3],
+        _ => &[],
+    }
+}
+
+fn compatibility(project: &LicenseInfo, dep: &LicenseInfo) -> (Compatibility, String) {
+    use Compatibility::*;
+    use LicenseCategory::*;
+    let p = project.spdx_id.as_str();
+    let d = dep.spdx_id.as_str();
+    let project_gpl = matches!(project.category, StrongCopyleft | NetworkCopyleft);
+
+    match dep.category {
+        PublicDomain => (Compatible, format!("{} 几乎没有限制", d)),
+        Permissive if d == "Apache-2.0" && p == "GPL-2.0-only" => (
+            Incompatible,
+            "Apache-2.0 的专利条款与 GPL-2.0-only 不兼容".to_string(),
+        ),
+        Permissive if d == "Apache-2.0" && p == "GPL-2.0-or-later" => (
+            Conditional,
+            "Apache-2.0 与 GPL-2.0 不兼容，组合作品需按 GPL-3.0 分发".to_string(),
+        ),
+        Permissive => (Compatible, format!("{} 只要求保留版权和许可声明", d)),
+        WeakCopyleft if project_gpl => match d {
+            "LGPL-3.0-only" if p == "GPL-2.0-only" => (
+                Incompatible,
+                "LGPL-3.0 只能转换为 GPL-3.0，与 GPL-2.0-only 不兼容".to_string(),
+            ),
+            "LGPL-3.0-only" if p == "GPL-2.0-or-later" => {
+                (Conditional, "组合作品需按 GPL-3.0 分发".to_string())
+            }
+            "EPL-2.0" => (
+                Conditional,
+                "仅当依赖声明了 GPL 作为次要许可证时才能与 GPL 代码组合".to_string(),
+            ),
+            _ => (Compatible, format!("{} 可以并入 {} 作品", d, p)),
+        },
+        WeakCopyleft => (
+            Conditional,
+            match d {
+                "MPL-2.0" => "可以组合，但修改过的 MPL 文件需继续以 MPL-2.0 开源".to_string(),
+                "EPL-2.0" => "可以组合，但修改过的 EPL 模块需继续以 EPL-2.0 开源".to_string(),
+                _ if dep.spdx_id.contains("GPL") && !d.starts_with("LGPL") => {
+                    format!("{} 带链接例外，可链接使用，修改依赖本身需开源", d)
+                }
+                _ => format!("需以动态链接方式使用 {}，并允许用户替换该库", d),
+            },
+        ),
+        StrongCopyleft | NetworkCopyleft if !project_gpl => (
+            Incompatible,
+            format!("{} 要求组合作品整体以相同许可证开源，{} 无法满足", d, p),
+        ),
+        StrongCopyleft => {
+            let shared = gpl_versions(d).iter().any(|v| gpl_versions(p).contains(v));
+            if shared {
+                (
+                    Compatible,
+                    format!("{} 与 {} 可以使用相同的 GPL 版本", d, p),
+                )
+            } else {
+                (
+                    Incompatible,
+                    format!("{} 与 {} 没有共同可用的 GPL 版本", d, p),
+                )
+            }
+        }
+        NetworkCopyleft => match project.category {
+            NetworkCopyleft => (Compatible, "双方均为 AGPL-3.0".to_string()),
+            _ if gpl_versions(p).contains(&3) => (
+                Conditional,
+                "GPL-3.0 第 13 条允许组合，但 AGPL 部分仍需向网络用户提供源码".to_string(),
+            ),
+            _ => (Incompatible, format!("AGPL-3.0 不能与 {} 组合", p)),
+        },
+        Proprietary => (Unknown, "专有依赖需确认商业授权条款".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MIT_TEXT: &str = "MIT License\n\nCopyright (c) 2024 Someone\n\nPermission is hereby granted, free of charge, to any person obtaining a copy\nof this software and associated documentation files (the \"Software\"), to deal\nin the Software without restriction...\n\nThe above copyright notice and this permission notice shall be included in all\ncopies or substantial portions of the Software.\n\nTHE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR\nIMPLIED.";
+
+    #[test]
+    fn test_detect_license() {
+        let detection = detect_license(MIT_TEXT.to_string()).unwrap();
+        assert_eq!(detection.best.unwrap().spdx_id, "MIT");
+        assert_eq!(detection.candidates[0].confidence, 1.0);
+
+        let bsd2 = "Redistribution and use in source and binary forms, with or without modification, are permitted provided that the following conditions are met:\n1. Redistributions of source code must retain the above copyright notice, this list of conditions.\n2. Redistributions in binary form must reproduce the above copyright notice.";
+        let detection = detect_license(bsd2.to_string()).unwrap();
+        assert_eq!(detection.candidates[0].spdx_id, "BSD-2-Clause");
+        let bsd3 = format!(
+            "{}\n3. Neither the name of the copyright holder nor ...",
+            bsd2
+        );
+        let detection = detect_license(bsd3).unwrap();
+        assert_eq!(detection.candidates[0].spdx_id, "BSD-3-Clause");
+        assert!(detection
+            .candidates
+            .iter()
+            .all(|c| c.spdx_id != "BSD-2-Clause"));
+
+        let header = "// SPDX-License-Identifier: GPL-2.0+ OR MIT\nfn main() {}";
+        let detection = detect_license(header.to_string()).unwrap();
+        assert_eq!(
+            detection.spdx_expression.as_deref(),
+            Some("GPL-2.0+ OR MIT")
+        );
+        assert_eq!(detection.best.unwrap().spdx_id, "GPL-2.0-or-later");
+    }
+
+    #[test]
+    fn test_check_license_compatibility() {
+        let dependency = |name: &str, license: &str| DependencyLicense {
+            name: name.to_string(),
+            license: license.to_string(),
+        };
+        let report = check_license_compatibility(LicenseCompatibilityRequest {
+            project_license: "GPL-2.0-only".to_string(),
+            dependencies: vec![
+                dependency("serde", "MIT OR Apache-2.0"),
+                dependency("openssl", "Apache-2.0"),
+                dependency("glibc", "LGPL-2.1-or-later"),
+                dependency("readline", "GPL-3.0-or-later"),
+                dependency("weird", "WTFPL"),
+            ],
+        })
+        .unwrap();
+        let results: Vec<Compatibility> = report.results.iter().map(|r| r.compatibility).collect();
+        assert_eq!(
+            results,
+            vec![
+                Compatibility::Compatible,
+                Compatibility::Incompatible,
+                Compatibility::Compatible,
+                Compatibility::Incompatible,
+                Compatibility::Unknown,
+            ]
+        );
+        assert_eq!(report.results[0].selected_license.as_deref(), Some("MIT"));
+        assert!(!report.compatible);
+
+        let report = check_license_compatibility(LicenseCompatibilityRequest {
+            project_license: "proprietary".to_string(),
+            dependencies: vec![
+                dependency("gtk", "LGPL-2.1-only"),
+                dependency(
+                    "mysql-connector",
+                    "GPL-2.0-only WITH Universal-FOSS-exception-1.0",
+                ),
+                dependency("ghostscript", "AGPL-3.0"),
+            ],
+        })
+        .unwrap();
+        assert_eq!(report.results[0].compatibility, Compatibility::Conditional);
+        assert_eq!(report.results[1].compatibility, Compatibility::Conditional);
+        assert_eq!(report.results[2].compatibility, Compatibility::Incompatible);
+        assert!(report.obligations.iter().any(|o| o.contains("替换")));
+        assert!(check_license_compatibility(LicenseCompatibilityRequest {
+            project_license: "Foo".to_string(),
+            dependencies: vec![],
+        })
+        .is_err());
+    }
+}
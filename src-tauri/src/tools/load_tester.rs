@@ -0,0 +1,541 @@
+use crate::tools::http_collection::KeyValue;
+use crate::tools::mock_server::{random_bytes, random_uuid};
+use crate::utils::progress::ProgressReporter;
+use crate::utils::proxy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager, State};
+use tokio::task::JoinSet;
+
+const DEFAULT_CONCURRENCY: usize = 10;
+const MAX_CONCURRENCY: usize = 1000;
+const DEFAULT_DURATION_SECS: u64 = 10;
+const MAX_DURATION_SECS: u64 = 600;
+const DEFAULT_TIMEOUT_MS: u64 = 10_000;
+/// 直方图每个数量级内的细分桶数，分位数的相对误差不超过 1/64
+const SUB_BUCKETS: u64 = 128;
+
+type ProgressSink = Arc<dyn Fn(LoadTestProgress) + Send + Sync>;
+
+#[derive(Default)]
+pub struct LoadTesterState {
+    runs: Mutex<HashMap<String, Arc<AtomicBool>>>,
+}
+
+impl LoadTesterState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoadTestRequest {
+    pub url: String, // 支持 {{seq}}、{{uuid}}、{{random}}、{{timestamp}} 占位符，请求头和请求体同样适用
+    pub method: Option<String>, // 默认 GET
+    #[serde(default)]
+    pub headers: Vec<KeyValue>,
+    pub body: Option<String>,
+    pub concurrency: Option<usize>, // 并发连接数，默认 10，上限 1000
+    pub duration_secs: Option<u64>, // 持续时间，默认 10 秒，上限 600 秒
+    pub max_requests: Option<u64>,  // 达到请求总数后提前结束
+    pub timeout_ms: Option<u64>,    // 单个请求超时，默认 10 秒
+    pub ignore_tls_errors: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LatencyStats {
+    pub min_ms: f64,
+    pub mean_ms: f64,
+    pub p50_ms: f64,
+    pub p90_ms: f64,
+    pub p95_ms: f64,
+    pub p99_ms: f64,
+    pub max_ms: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoadTestProgress {
+    pub id: String,
+    pub elapsed_ms: u64,
+    pub requests: u64,
+    pub errors: u64,
+    pub current_rps: f64, // 最近一秒的吞吐
+    pub p50_ms: f64,
+    pub p99_ms: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoadTestReport {
+    pub id: String,
+    pub url: String,
+    pub concurrency: usize,
+    pub duration_ms: u64,
+    pub total_requests: u64,
+    pub successful: u64, // 2xx 和 3xx
+    pub failed: u64,
+    pub requests_per_sec: f64,
+    pub bytes_received: u64,
+    pub bytes_per_sec: f64,
+    pub latency: LatencyStats,
+    pub status_codes: BTreeMap<u16, u64>,
+    pub errors: BTreeMap<String, u64>, // 按错误类型统计，含非 2xx/3xx 状态码
+    pub stopped: bool,                 // 是否被手动停止
+}
+
+/// 启动压测，每秒通过 load-test-progress 推送实时统计，结束时通过 load-test-complete 推送报告
+#[tauri::command]
+pub async fn start_load_test(
+    app: AppHandle,
+    state: State<'_, LoadTesterState>,
+    request: LoadTestRequest,
+) -> Result<String, String> {
+    let plan = LoadTestPlan::new(request)?;
+    let id = format!("load-{}", chrono::Utc::now().timestamp_millis());
+    let stop = Arc::new(AtomicBool::new(false));
+    state.runs.lock().unwrap().insert(id.clone(), stop.clone());
+
+    let duration_ms = plan.duration.as_millis() as u64;
+    let reporter = Arc::new(Mutex::new(ProgressReporter::new(&app, "load_tester")));
+    let progress_app = app.clone();
+    let progress_reporter = reporter.clone();
+    let sink: ProgressSink = Arc::new(move |progress| {
+        progress_reporter.lock().unwrap().update(
+            progress.elapsed_ms.min(duration_ms),
+            Some(duration_ms),
+            Some(format!(
+                "{} 个请求，{} 个错误",
+                progress.requests, progress.errors
+            )),
+        );
+        let _ = progress_app.emit("load-test-progress", progress);
+    });
+    let run_id = id.clone();
+    tokio::spawn(async move {
+        let result = run_load_test(run_id.clone(), plan, stop, sink).await;
+        let state = app.state::<LoadTesterState>();
+        state.runs.lock().unwrap().remove(&run_id);
+        match &result {
+            Ok(report) => reporter.lock().unwrap().finish(Some(format!(
+                "{} 个请求，{:.1} 请求/秒",
+                report.total_requests, report.requests_per_sec
+            ))),
+            Err(message) => reporter.lock().unwrap().fail(message),
+        }
+        let _ = match result {
+            Ok(report) => app.emit("load-test-complete", report),
+            Err(message) => app.emit(
+                "load-test-error",
+                serde_json::json!({ "id": run_id, "message": message }),
+            ),
+        };
+    });
+    Ok(id)
+}
+
+/// 停止正在进行的压测，已完成的请求仍会生成报告
+#[tauri::command]
+pub async fn stop_load_test(state: State<'_, LoadTesterState>, id: String) -> Result<(), String> {
+    match state.runs.lock().unwrap().get(&id) {
+        Some(stop) => {
+            stop.store(true, Ordering::Relaxed);
+            Ok(())
+        }
+        None => Err("压测不存在或已结束".to_string()),
+    }
+}
+
+/// 校验并归一化后的压测参数
+struct LoadTestPlan {
+    request: LoadTestRequest,
+    method: reqwest::Method,
+    concurrency: usize,
+    duration: Duration,
+    client: reqwest::Client,
+}
+
+impl LoadTestPlan {
+    fn new(request: LoadTestRequest) -> Result<Self, String> {
+        let method = request.method.as_deref().unwrap_or("GET").to_uppercase();
+        let method = reqwest::Method::from_bytes(method.as_bytes())
+            .map_err(|_| format!("无效的请求方法: {}", method))?;
+        let probe = render(&request.url, 0);
+        let url = reqwest::Url::parse(&probe).map_err(|e| format!("无效的 URL: {}", e))?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err("只支持 http 和 https 地址".to_string());
+        }
+        let concurrency = request
+            .concurrency
+            .unwrap_or(DEFAULT_CONCURRENCY)
+            .clamp(1, MAX_CONCURRENCY);
+        let duration = Duration::from_secs(
+            request
+                .duration_secs
+                .unwrap_or(DEFAULT_DURATION_SECS)
+                .clamp(1, MAX_DURATION_SECS),
+        );
+        let client = proxy::client_builder("load_tester")
+            .timeout(Duration::from_millis(
+                request.timeout_ms.unwrap_or(DEFAULT_TIMEOUT_MS),
+            ))
+            .pool_max_idle_per_host(concurrency)
+            .danger_accept_invalid_certs(request.ignore_tls_errors.unwrap_or(false))
+            .build()
+            .map_err(|e| format!("创建 HTTP 客户端失败: {}", e))?;
+        Ok(Self {
+            request,
+            method,
+            concurrency,
+            duration,
+            client,
+        })
+    }
+
+    fn build(&self, seq: u64) -> reqwest::RequestBuilder {
+        let mut builder = self
+            .client
+            .request(self.method.clone(), render(&self.request.url, seq));
+        for header in self.request.headers.iter().filter(|h| h.enabled) {
+            builder = builder.header(header.key.as_str(), render(&header.value, seq));
+        }
+        if let Some(body) = &self.request.body {
+            builder = builder.body(render(body, seq));
+        }
+        builder
+    }
+}
+
+/// 对数分桶的延迟直方图（微秒），内存占用固定，适合长时间压测
+struct Histogram {
+    buckets: Vec<u64>,
+    count: u64,
+    sum: u64,
+    min: u64,
+    max: u64,
+}
+
+impl Histogram {
+    fn new() -> Self {
+        Self {
+            buckets: vec![0; (SUB_BUCKETS + 58 * SUB_BUCKETS / 2) as usize],
+            count: 0,
+            sum: 0,
+            min: u64::MAX,
+            max: 0,
+        }
+    }
+
+    fn bucket_index(value: u64) -> usize {
+        if value < SUB_BUCKETS {
+            return value as usize;
+        }
+        // 最高位之后保留 6 位精度
+        let magnitude = 63 - value.leading_zeros() as u64 - 6;
+        let sub = (value >> magnitude) - SUB_BUCKETS / 2;
+        (SUB_BUCKETS + (magnitude - 1) * SUB_BUCKETS / 2 + sub) as usize
+    }
+
+    /// 桶对应区间的下界
+    fn bucket_value(index: usize) -> u64 {
+        let index = index as u64;
+        if index < SUB_BUCKETS {
+            return index;
+        }
+        let offset = index - SUB_BUCKETS;
+        let magnitude = offset / (SUB_BUCKETS / 2) + 1;
+        let sub = offset % (SUB_BUCKETS / 2) + SUB_BUCKETS / 2;
+        sub << magnitude
+    }
+
+    fn record(&mut self, micros: u64) {
+        self.buckets[Self::bucket_index(micros)] += 1;
+        self.count += 1;
+        self.sum += micros;
+        self.min = self.min.min(micros);
+        self.max = self.max.max(micros);
+    }
+
+    fn percentile(&self, p: f64) -> u64 {
+        if self.count == 0 {
+            return 0;
+        }
+        let target = ((p / 100.0) * self.count as f64).ceil().max(1.0) as u64;
+        let mut seen = 0;
+        for (index, count) in self.buckets.iter().enumerate() {
+            seen += count;
+            if seen >= target {
+                return Self::bucket_value(index).clamp(self.min, self.max);
+            }
+        }
+        self.max
+    }
+
+    fn stats(&self) -> LatencyStats {
+        if self.count == 0 {
+            return LatencyStats::default();
+        }
+        let ms = |micros: u64| micros as f64 / 1000.0;
+        LatencyStats {
+            min_ms: ms(self.min),
+            mean_ms: self.sum as f64 / self.count as f64 / 1000.0,
+            p50_ms: ms(self.percentile(50.0)),
+            p90_ms: ms(self.percentile(90.0)),
+            p95_ms: ms(self.percentile(95.0)),
+            p99_ms: ms(self.percentile(99.0)),
+            max_ms: ms(self.max),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Counters {
+    status_codes: BTreeMap<u16, u64>,
+    errors: BTreeMap<String, u64>,
+    failed: u64,
+    bytes: u64,
+}
+
+async fn run_load_test(
+    id: String,
+    plan: LoadTestPlan,
+    stop: Arc<AtomicBool>,
+    sink: ProgressSink,
+) -> Result<LoadTestReport, String> {
+    let plan = Arc::new(plan);
+    let histogram = Arc::new(Mutex::new(Histogram::new()));
+    let counters = Arc::new(Mutex::new(Counters::default()));
+    let issued = Arc::new(AtomicU64::new(0));
+    let started = Instant::now();
+    let deadline = started + plan.duration;
+    let max_requests = plan.request.max_requests;
+
+    let mut workers = JoinSet::new();
+    for _ in 0..plan.concurrency {
+        let plan = plan.clone();
+        let stop = stop.clone();
+        let histogram = histogram.clone();
+        let counters = counters.clone();
+        let issued = issued.clone();
+        workers.spawn(async move {
+            while !stop.load(Ordering::Relaxed) && Instant::now() < deadline {
+                let seq = issued.fetch_add(1, Ordering::Relaxed);
+                if max_requests.is_some_and(|max| seq >= max) {
+                    break;
+                }
+                let sent_at = Instant::now();
+                let outcome = match plan.build(seq).send().await {
+                    Ok(response) => {
+                        let status = response.status();
+                        response
+                            .bytes()
+                            .await
+                            .map(|body| (status, body.len() as u64))
+                    }
+                    Err(e) => Err(e),
+                };
+                let elapsed = sent_at.elapsed().as_micros() as u64;
+
+                let mut counters = counters.lock().unwrap();
+                match outcome {
+                    Ok((status, bytes)) => {
+                        *counters.status_codes.entry(status.as_u16()).or_default() += 1;
+                        counters.bytes += bytes;
+                        if status.is_client_error() || status.is_server_error() {
+                            counters.failed += 1;
+                            *counters
+                                .errors
+                                .entry(format!("HTTP {}", status.as_u16()))
+                                .or_default() += 1;
+                        }
+                    }
+                    Err(e) => {
+                        counters.failed += 1;
+                        *counters.errors.entry(classify_error(&e)).or_default() += 1;
+                    }
+                }
+                drop(counters);
+                histogram.lock().unwrap().record(elapsed);
+            }
+        });
+    }
+
+    let mut ticker = tokio::time::interval(Duration::from_secs(1));
+    ticker.tick().await;
+    let mut last_count = 0;
+    loop {
+        tokio::select! {
+            joined = workers.join_next() => {
+                if joined.is_none() {
+                    break;
+                }
+            }
+            _ = ticker.tick() => {
+                let (count, p50, p99) = {
+                    let histogram = histogram.lock().unwrap();
+                    (histogram.count, histogram.percentile(50.0), histogram.percentile(99.0))
+                };
+                sink(LoadTestProgress {
+                    id: id.clone(),
+                    elapsed_ms: started.elapsed().as_millis() as u64,
+                    requests: count,
+                    errors: counters.lock().unwrap().failed,
+                    current_rps: (count - last_count) as f64,
+                    p50_ms: p50 as f64 / 1000.0,
+                    p99_ms: p99 as f64 / 1000.0,
+                });
+                last_count = count;
+            }
+        }
+    }
+
+    let elapsed = started.elapsed();
+    let seconds = elapsed.as_secs_f64().max(0.001);
+    let histogram = histogram.lock().unwrap();
+    let counters = std::mem::take(&mut *counters.lock().unwrap());
+    Ok(LoadTestReport {
+        id,
+        url: plan.request.url.clone(),
+        concurrency: plan.concurrency,
+        duration_ms: elapsed.as_millis() as u64,
+        total_requests: histogram.count,
+        successful: histogram.count - counters.failed,
+        failed: counters.failed,
+        requests_per_sec: histogram.count as f64 / seconds,
+        bytes_received: counters.bytes,
+        bytes_per_sec: counters.bytes as f64 / seconds,
+        latency: histogram.stats(),
+        status_codes: counters.status_codes,
+        errors: counters.errors,
+        stopped: stop.load(Ordering::Relaxed),
+    })
+}
+
+fn classify_error(error: &reqwest::Error) -> String {
+    if error.is_timeout() {
+        "超时"
+    } else if error.is_connect() {
+        "连接失败"
+    } else if error.is_body() || error.is_decode() {
+        "读取响应失败"
+    } else if error.is_request() {
+        "请求发送失败"
+    } else {
+        "其他错误"
+    }
+    .to_string()
+}
+
+/// 替换请求模板中的占位符，seq 为本次压测中的请求序号
+fn render(template: &str, seq: u64) -> String {
+    if !template.contains("{{") {
+        return template.to_string();
+    }
+    let pattern = Regex::new(r"\{\{\s*(\w+)\s*\}\}").unwrap();
+    pattern
+        .replace_all(template, |caps: &regex::Captures| match &caps[1] {
+            "seq" => seq.to_string(),
+            "uuid" => random_uuid(),
+            "random" => u32::from_le_bytes(random_bytes::<4>()).to_string(),
+            "timestamp" => chrono::Utc::now().timestamp_millis().to_string(),
+            _ => caps[0].to_string(),
+        })
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::static_server;
+    use axum::routing::get;
+    use axum::Router;
+    use tokio::net::TcpListener;
+
+    #[test]
+    fn test_histogram_percentiles() {
+        for value in [
+            0,
+            1,
+            127,
+            128,
+            129,
+            1000,
+            65_535,
+            1_000_000,
+            u32::MAX as u64,
+        ] {
+            let index = Histogram::bucket_index(value);
+            let lower = Histogram::bucket_value(index);
+            assert!(lower <= value, "{} -> {}", value, lower);
+            assert!(value - lower <= value / 64, "{} -> {}", value, lower);
+        }
+
+        let mut histogram = Histogram::new();
+        for micros in 1..=10_000 {
+            histogram.record(micros * 100);
+        }
+        let stats = histogram.stats();
+        assert_eq!(stats.min_ms, 0.1);
+        assert_eq!(stats.max_ms, 1000.0);
+        assert!((stats.p50_ms - 500.0).abs() < 10.0);
+        assert!((stats.p99_ms - 990.0).abs() < 20.0);
+        assert!((stats.mean_ms - 500.05).abs() < 0.01);
+
+        assert_eq!(
+            render("/items/{{seq}}?x={{ unknown }}", 42),
+            "/items/42?x={{ unknown }}"
+        );
+        assert_eq!(render("{{uuid}}", 0).len(), 36);
+    }
+
+    #[tokio::test]
+    async fn test_run_load_test() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let router = Router::new().route("/ok", get(|| async { "ok" })).route(
+            "/fail",
+            get(|| async { (axum::http::StatusCode::SERVICE_UNAVAILABLE, "down") }),
+        );
+        let server = tokio::spawn(static_server::serve(listener, router, None));
+
+        let request = |path: &str| LoadTestRequest {
+            url: format!("http://127.0.0.1:{}{}", port, path),
+            method: None,
+            headers: Vec::new(),
+            body: None,
+            concurrency: Some(4),
+            duration_secs: Some(5),
+            max_requests: Some(40),
+            timeout_ms: None,
+            ignore_tls_errors: None,
+        };
+        let sink: ProgressSink = Arc::new(|_| {});
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let plan = LoadTestPlan::new(request("/ok")).unwrap();
+        let report = run_load_test("t".to_string(), plan, stop.clone(), sink.clone())
+            .await
+            .unwrap();
+        assert_eq!(report.total_requests, 40);
+        assert_eq!(report.successful, 40);
+        assert_eq!(report.status_codes.get(&200), Some(&40));
+        assert_eq!(report.bytes_received, 80);
+        assert!(report.latency.p50_ms > 0.0);
+        assert!(!report.stopped);
+
+        let plan = LoadTestPlan::new(request("/fail")).unwrap();
+        let report = run_load_test("t".to_string(), plan, stop, sink)
+            .await
+            .unwrap();
+        assert_eq!(report.failed, 40);
+        assert_eq!(report.errors.get("HTTP 503"), Some(&40));
+        server.abort();
+
+        assert!(LoadTestPlan::new(request("/ok")).is_ok());
+        let mut invalid = request("/ok");
+        invalid.url = "ftp://example.com".to_string();
+        assert!(LoadTestPlan::new(invalid).is_err());
+    }
+}
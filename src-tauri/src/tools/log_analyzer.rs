@@ -0,0 +1,717 @@
+use crate::utils::progress::ProgressReporter;
+use chrono::{DateTime, Datelike, Local, NaiveDateTime, TimeZone, Utc};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+
+/// 格式检测采样的非空行数
+const DETECT_SAMPLE_LINES: usize = 50;
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(500);
+const DEFAULT_TOP_N: usize = 10;
+const DEFAULT_MAX_SAMPLES: usize = 200;
+
+/// 各字段可能使用的键名
+const TIME_KEYS: &[&str] = &["time", "timestamp", "ts", "@timestamp", "datetime", "date"];
+const LEVEL_KEYS: &[&str] = &["level", "lvl", "severity", "loglevel"];
+const MESSAGE_KEYS: &[&str] = &["msg", "message", "event"];
+const IP_KEYS: &[&str] = &["ip", "remote_addr", "client_ip", "clientip", "remote_ip"];
+const STATUS_KEYS: &[&str] = &["status", "status_code", "statuscode", "code"];
+const PATH_KEYS: &[&str] = &["path", "uri", "url", "request_uri"];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnalyzeLogRequest {
+    pub path: String,
+    pub format: Option<String>, // auto, access, json, logfmt, syslog, plain
+    pub pattern: Option<String>, // 按正则过滤原始行
+    pub levels: Option<Vec<String>>,
+    pub start_time: Option<String>, // RFC3339 或 YYYY-MM-DD HH:MM:SS（本地时间）
+    pub end_time: Option<String>,
+    pub bucket: Option<String>, // minute, hour, day
+    pub top_n: Option<usize>,
+    pub max_samples: Option<usize>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CountEntry {
+    pub key: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TimeBucket {
+    pub time: String,
+    pub total: usize,
+    pub errors: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LogStats {
+    pub lines_read: usize,
+    pub matched_lines: usize,
+    pub unparsed_lines: usize,
+    pub levels: BTreeMap<String, usize>,
+    pub status_codes: BTreeMap<u16, usize>,
+    pub top_ips: Vec<CountEntry>,
+    pub top_paths: Vec<CountEntry>,
+    pub timeline: Vec<TimeBucket>,
+    pub first_time: Option<String>,
+    pub last_time: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogAnalysis {
+    pub path: String,
+    pub format: String,
+    pub total_bytes: u64,
+    pub stats: LogStats,
+    pub samples: Vec<String>, // 匹配的错误行，超过 max_samples 后不再收集
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogAnalysisProgress {
+    pub path: String,
+    pub bytes_read: u64,
+    pub total_bytes: u64,
+    pub stats: LogStats,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum LogFormat {
+    Access,
+    Json,
+    Logfmt,
+    Syslog,
+    Plain,
+}
+
+impl LogFormat {
+    fn parse(value: &str) -> Result<Option<Self>, String> {
+        Ok(match value.to_lowercase().as_str() {
+            "" | "auto" => None,
+            "access" | "nginx" | "apache" => Some(LogFormat::Access),
+            "json" | "jsonl" => Some(LogFormat::Json),
+            "logfmt" => Some(LogFormat::Logfmt),
+            "syslog" => Some(LogFormat::Syslog),
+            "plain" => Some(LogFormat::Plain),
+            other => return Err(format!("不支持的日志格式: {}", other)),
+        })
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            LogFormat::Access => "access",
+            LogFormat::Json => "json",
+            LogFormat::Logfmt => "logfmt",
+            LogFormat::Syslog => "syslog",
+            LogFormat::Plain => "plain",
+        }
+    }
+}
+
+/// 单行日志解析结果
+#[derive(Debug, Default)]
+struct LogRecord {
+    time: Option<DateTime<Utc>>,
+    level: Option<String>,
+    ip: Option<String>,
+    status: Option<u16>,
+    path: Option<String>,
+}
+
+/// 预编译的解析正则
+struct LogParser {
+    access: Regex,
+    syslog_3164: Regex,
+    syslog_5424: Regex,
+    logfmt_pair: Regex,
+    iso_time: Regex,
+    level_word: Regex,
+}
+
+impl LogParser {
+    fn new() -> Self {
+        Self {
+            access: Regex::new(
+                r#"^(\S+) \S+ \S+ \[([^\]]+)\] "(?:(\S+) (\S+)[^"]*|[^"]*)" (\d{3}) (\S+)"#,
+            )
+            .unwrap(),
+            syslog_3164: Regex::new(
+                r"^(?:<(\d{1,3})>)?([A-Z][a-z]{2} [ \d]\d \d{2}:\d{2}:\d{2}) (\S+) ([^:\[\s]+)(?:\[\d+\])?: ?(.*)$",
+            )
+            .unwrap(),
+            syslog_5424: Regex::new(r"^<(\d{1,3})>1 (\S+) (\S+) (\S+) \S+ \S+ (.*)$").unwrap(),
+            logfmt_pair: Regex::new(r#"([\w.@-]+)=("(?:[^"\\]|\\.)*"|\S*)"#).unwrap(),
+            iso_time: Regex::new(
+                r"\d{4}-\d{2}-\d{2}[T ]\d{2}:\d{2}:\d{2}(?:[.,]\d+)?(?:Z|[+-]\d{2}:?\d{2})?",
+            )
+            .unwrap(),
+            level_word: Regex::new(
+                r"(?i)\b(fatal|panic|critical|crit|error|err|warning|warn|notice|info|debug|trace)\b",
+            )
+            .unwrap(),
+        }
+    }
+
+    fn parse(&self, format: LogFormat, line: &str) -> Option<LogRecord> {
+        match format {
+            LogFormat::Access => self.parse_access(line),
+            LogFormat::Json => self.parse_json(line),
+            LogFormat::Logfmt => self.parse_logfmt(line),
+            LogFormat::Syslog => self.parse_syslog(line),
+            LogFormat::Plain => Some(self.parse_plain(line)),
+        }
+    }
+
+    fn parse_access(&self, line: &str) -> Option<LogRecord> {
+        let caps = self.access.captures(line)?;
+        let status = caps[5].parse::<u16>().ok();
+        Some(LogRecord {
+            time: DateTime::parse_from_str(&caps[2], "%d/%b/%Y:%H:%M:%S %z")
+                .ok()
+                .map(|t| t.with_timezone(&Utc)),
+            level: status.map(|s| level_from_status(s).to_string()),
+            ip: Some(caps[1].to_string()),
+            status,
+            path: caps.get(4).map(|p| strip_query(p.as_str())),
+        })
+    }
+
+    fn parse_json(&self, line: &str) -> Option<LogRecord> {
+        let value: Value = serde_json::from_str(line.trim()).ok()?;
+        let object = value.as_object()?;
+        let get = |keys: &[&str]| {
+            keys.iter()
+                .find_map(|key| object.get(*key))
+                .filter(|v| !v.is_null())
+        };
+        Some(self.record_from_fields(
+            get(TIME_KEYS).map(value_text),
+            get(LEVEL_KEYS).map(value_text),
+            get(IP_KEYS).map(value_text),
+            get(STATUS_KEYS).map(value_text),
+            get(PATH_KEYS).map(value_text),
+            get(MESSAGE_KEYS).map(value_text),
+        ))
+    }
+
+    fn parse_logfmt(&self, line: &str) -> Option<LogRecord> {
+        let fields: HashMap<String, String> = self
+            .logfmt_pair
+            .captures_iter(line)
+            .map(|caps| {
+                let value = caps[2].trim_matches('"').replace("\\\"", "\"");
+                (caps[1].to_lowercase(), value)
+            })
+            .collect();
+        // 至少两个键值对才视为 logfmt
+        if fields.len() < 2 {
+            return None;
+        }
+        let get = |keys: &[&str]| keys.iter().find_map(|key| fields.get(*key)).cloned();
+        Some(self.record_from_fields(
+            get(TIME_KEYS),
+            get(LEVEL_KEYS),
+            get(IP_KEYS),
+            get(STATUS_KEYS),
+            get(PATH_KEYS),
+            get(MESSAGE_KEYS),
+        ))
+    }
+
+    fn parse_syslog(&self, line: &str) -> Option<LogRecord> {
+        if let Some(caps) = self.syslog_5424.captures(line) {
+            return Some(LogRecord {
+                time: parse_time(&caps[2]),
+                level: caps[1].parse::<u16>().ok().map(level_from_priority),
+                ..Default::default()
+            });
+        }
+        let caps = self.syslog_3164.captures(line)?;
+        // RFC 3164 不含年份，按当前年份补全
+        let stamp = format!("{} {}", Local::now().year(), &caps[2]);
+        let time = NaiveDateTime::parse_from_str(&stamp, "%Y %b %e %H:%M:%S")
+            .ok()
+            .and_then(local_to_utc);
+        let level = match caps.get(1) {
+            Some(pri) => pri.as_str().parse::<u16>().ok().map(level_from_priority),
+            None => self.detect_level(&caps[5]),
+        };
+        Some(LogRecord {
+            time,
+            level,
+            ..Default::default()
+        })
+    }
+
+    fn parse_plain(&self, line: &str) -> LogRecord {
+        LogRecord {
+            time: self
+                .iso_time
+                .find(line)
+                .and_then(|m| parse_time(m.as_str())),
+            level: self.detect_level(line),
+            ..Default::default()
+        }
+    }
+
+    fn record_from_fields(
+        &self,
+        time: Option<String>,
+        level: Option<String>,
+        ip: Option<String>,
+        status: Option<String>,
+        path: Option<String>,
+        message: Option<String>,
+    ) -> LogRecord {
+        let status = status.and_then(|s| s.parse::<u16>().ok());
+        let level = level
+            .map(|l| normalize_level(&l))
+            .or_else(|| status.map(|s| level_from_status(s).to_string()))
+            .or_else(|| message.as_deref().and_then(|m| self.detect_level(m)));
+        LogRecord {
+            time: time.as_deref().and_then(parse_time),
+            level,
+            ip,
+            status,
+            path: path.map(|p| strip_query(&p)),
+        }
+    }
+
+    fn detect_level(&self, text: &str) -> Option<String> {
+        self.level_word
+            .captures(text)
+            .map(|caps| normalize_level(&caps[1]))
+    }
+
+    /// 采样前若干行，选择解析成功率最高的格式
+    fn detect_format(&self, lines: &[String]) -> LogFormat {
+        let candidates = [
+            LogFormat::Json,
+            LogFormat::Access,
+            LogFormat::Syslog,
+            LogFormat::Logfmt,
+        ];
+        let mut best = (LogFormat::Plain, 0);
+        for format in candidates {
+            let parsed = lines
+                .iter()
+                .filter(|line| self.parse(format, line).is_some())
+                .count();
+            if parsed > best.1 && parsed * 2 >= lines.len() {
+                best = (format, parsed);
+            }
+        }
+        best.0
+    }
+}
+
+fn value_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn strip_query(path: &str) -> String {
+    path.split('?').next().unwrap_or(path).to_string()
+}
+
+fn level_from_status(status: u16) -> &'static str {
+    match status {
+        500..=599 => "error",
+        400..=499 => "warn",
+        _ => "info",
+    }
+}
+
+/// syslog PRI 的低 3 位为严重级别
+fn level_from_priority(priority: u16) -> String {
+    match priority % 8 {
+        0..=2 => "fatal",
+        3 => "error",
+        4 => "warn",
+        5 | 6 => "info",
+        _ => "debug",
+    }
+    .to_string()
+}
+
+fn normalize_level(level: &str) -> String {
+    match level.trim().to_lowercase().as_str() {
+        "fatal" | "panic" | "critical" | "crit" | "emerg" | "alert" => "fatal",
+        "error" | "err" => "error",
+        "warning" | "warn" => "warn",
+        "notice" | "info" | "information" => "info",
+        "debug" => "debug",
+        "trace" => "trace",
+        other => return other.to_string(),
+    }
+    .to_string()
+}
+
+fn is_error_level(level: &str) -> bool {
+    level == "error" || level == "fatal"
+}
+
+fn local_to_utc(naive: NaiveDateTime) -> Option<DateTime<Utc>> {
+    Local
+        .from_local_datetime(&naive)
+        .earliest()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+/// 解析常见时间格式，无时区的时间按本地时间处理
+fn parse_time(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+        return Some(time.with_timezone(&Utc));
+    }
+    if let Ok(time) = DateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S%.f%z") {
+        return Some(time.with_timezone(&Utc));
+    }
+    // Unix 时间戳（秒或毫秒）
+    if let Ok(number) = value.parse::<f64>() {
+        let millis = if number > 1e12 {
+            number
+        } else {
+            number * 1000.0
+        };
+        return DateTime::from_timestamp_millis(millis as i64);
+    }
+    [
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%d %H:%M:%S,%f",
+        "%Y/%m/%d %H:%M:%S",
+    ]
+    .iter()
+    .find_map(|f| NaiveDateTime::parse_from_str(value, f).ok())
+    .and_then(local_to_utc)
+}
+
+fn bucket_key(time: DateTime<Utc>, bucket: &str) -> String {
+    let local = time.with_timezone(&Local);
+    match bucket {
+        "day" => local.format("%Y-%m-%d").to_string(),
+        "hour" => local.format("%Y-%m-%d %H:00").to_string(),
+        _ => local.format("%Y-%m-%d %H:%M").to_string(),
+    }
+}
+
+fn top_entries(counts: &HashMap<String, usize>, n: usize) -> Vec<CountEntry> {
+    let mut entries: Vec<CountEntry> = counts
+        .iter()
+        .map(|(key, count)| CountEntry {
+            key: key.clone(),
+            count: *count,
+        })
+        .collect();
+    entries.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.key.cmp(&b.key)));
+    entries.truncate(n);
+    entries
+}
+
+/// 汇总过程中的可变状态
+struct Aggregator {
+    bucket: String,
+    top_n: usize,
+    stats: LogStats,
+    ips: HashMap<String, usize>,
+    paths: HashMap<String, usize>,
+    timeline: BTreeMap<String, TimeBucket>,
+    first_time: Option<DateTime<Utc>>,
+    last_time: Option<DateTime<Utc>>,
+}
+
+impl Aggregator {
+    fn add(&mut self, record: &LogRecord) {
+        self.stats.matched_lines += 1;
+        let is_error = record.level.as_deref().is_some_and(is_error_level);
+        if let Some(level) = &record.level {
+            *self.stats.levels.entry(level.clone()).or_default() += 1;
+        }
+        if let Some(status) = record.status {
+            *self.stats.status_codes.entry(status).or_default() += 1;
+        }
+        if let Some(ip) = &record.ip {
+            *self.ips.entry(ip.clone()).or_default() += 1;
+        }
+        if let Some(path) = &record.path {
+            *self.paths.entry(path.clone()).or_default() += 1;
+        }
+        if let Some(time) = record.time {
+            self.first_time = Some(self.first_time.map_or(time, |t| t.min(time)));
+            self.last_time = Some(self.last_time.map_or(time, |t| t.max(time)));
+            let key = bucket_key(time, &self.bucket);
+            let bucket = self
+                .timeline
+                .entry(key.clone())
+                .or_insert_with(|| TimeBucket {
+                    time: key,
+                    ..Default::default()
+                });
+            bucket.total += 1;
+            if is_error {
+                bucket.errors += 1;
+            }
+        }
+    }
+
+    fn snapshot(&self) -> LogStats {
+        let format_time = |t: DateTime<Utc>| t.with_timezone(&Local).to_rfc3339();
+        LogStats {
+            top_ips: top_entries(&self.ips, self.top_n),
+            top_paths: top_entries(&self.paths, self.top_n),
+            timeline: self.timeline.values().cloned().collect(),
+            first_time: self.first_time.map(format_time),
+            last_time: self.last_time.map(format_time),
+            ..self.stats.clone()
+        }
+    }
+}
+
+/// 流式分析日志文件，统计结果通过 log-analysis-progress 事件持续推送，通用进度走 task-progress
+#[tauri::command]
+pub async fn analyze_log(
+    app: AppHandle,
+    request: AnalyzeLogRequest,
+) -> Result<LogAnalysis, String> {
+    let mut reporter = ProgressReporter::new(&app, "log_analyzer");
+    tokio::task::spawn_blocking(move || {
+        let result = analyze_log_blocking(&request, |progress| {
+            reporter.update(progress.bytes_read, Some(progress.total_bytes), None);
+            let _ = app.emit("log-analysis-progress", progress);
+        });
+        match &result {
+            Ok(_) => reporter.finish(None),
+            Err(e) => reporter.fail(e),
+        }
+        result
+    })
+    .await
+    .map_err(|e| format!("日志分析任务失败: {}", e))?
+}
+
+fn analyze_log_blocking<F: FnMut(LogAnalysisProgress)>(
+    request: &AnalyzeLogRequest,
+    mut on_progress: F,
+) -> Result<LogAnalysis, String> {
+    let file = File::open(&request.path).map_err(|e| format!("打开日志文件失败: {}", e))?;
+    let total_bytes = file.metadata().map(|m| m.len()).unwrap_or(0);
+    let parser = LogParser::new();
+
+    let pattern = match request.pattern.as_deref().filter(|p| !p.is_empty()) {
+        Some(p) => Some(Regex::new(p).map_err(|e| format!("过滤正则无效: {}", e))?),
+        None => None,
+    };
+    let levels: Option<Vec<String>> = request
+        .levels
+        .as_ref()
+        .filter(|l| !l.is_empty())
+        .map(|l| l.iter().map(|level| normalize_level(level)).collect());
+    let parse_bound =
+        |value: &Option<String>, label: &str| -> Result<Option<DateTime<Utc>>, String> {
+            match value.as_deref().filter(|v| !v.trim().is_empty()) {
+                Some(v) => parse_time(v)
+                    .map(Some)
+                    .ok_or_else(|| format!("{}格式无效: {}", label, v)),
+                None => Ok(None),
+            }
+        };
+    let start = parse_bound(&request.start_time, "开始时间")?;
+    let end = parse_bound(&request.end_time, "结束时间")?;
+    let max_samples = request.max_samples.unwrap_or(DEFAULT_MAX_SAMPLES);
+
+    let mut reader = BufReader::new(file);
+    let mut format = LogFormat::parse(request.format.as_deref().unwrap_or("auto"))?;
+    let mut pending: Vec<String> = Vec::new();
+    let mut aggregator = Aggregator {
+        bucket: request.bucket.clone().unwrap_or_else(|| "hour".to_string()),
+        top_n: request.top_n.unwrap_or(DEFAULT_TOP_N),
+        stats: LogStats::default(),
+        ips: HashMap::new(),
+        paths: HashMap::new(),
+        timeline: BTreeMap::new(),
+        first_time: None,
+        last_time: None,
+    };
+    let mut samples = Vec::new();
+    let mut bytes_read = 0u64;
+    let mut last_progress = Instant::now();
+    let mut buffer = Vec::new();
+
+    let mut process = |line: &str, format: LogFormat, aggregator: &mut Aggregator| {
+        aggregator.stats.lines_read += 1;
+        if let Some(pattern) = &pattern {
+            if !pattern.is_match(line) {
+                return;
+            }
+        }
+        let record = match parser.parse(format, line) {
+            Some(record) => record,
+            None => {
+                aggregator.stats.unparsed_lines += 1;
+                parser.parse_plain(line)
+            }
+        };
+        if let Some(levels) = &levels {
+            if !record.level.as_ref().is_some_and(|l| levels.contains(l)) {
+                return;
+            }
+        }
+        if start.is_some() || end.is_some() {
+            let Some(time) = record.time else {
+                return;
+            };
+            if start.is_some_and(|s| time < s) || end.is_some_and(|e| time > e) {
+                return;
+            }
+        }
+        aggregator.add(&record);
+        if samples.len() < max_samples && record.level.as_deref().is_some_and(is_error_level) {
+            samples.push(line.to_string());
+        }
+    };
+
+    loop {
+        buffer.clear();
+        let read = reader
+            .read_until(b'\n', &mut buffer)
+            .map_err(|e| format!("读取日志失败: {}", e))?;
+        if read == 0 {
+            break;
+        }
+        bytes_read += read as u64;
+        // 非 UTF-8 内容按有损方式处理，不中断分析
+        let line = String::from_utf8_lossy(&buffer);
+        let line = line.trim_end_matches(['\r', '\n']);
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        match format {
+            Some(format) => process(line, format, &mut aggregator),
+            None => {
+                pending.push(line.to_string());
+                if pending.len() >= DETECT_SAMPLE_LINES {
+                    let detected = parser.detect_format(&pending);
+                    for line in pending.drain(..) {
+                        process(&line, detected, &mut aggregator);
+                    }
+                    format = Some(detected);
+                }
+            }
+        }
+
+        if last_progress.elapsed() >= PROGRESS_INTERVAL {
+            last_progress = Instant::now();
+            on_progress(LogAnalysisProgress {
+                path: request.path.clone(),
+                bytes_read,
+                total_bytes,
+                stats: aggregator.snapshot(),
+            });
+        }
+    }
+
+    // 文件行数不足采样数量时在结尾检测
+    let format = format.unwrap_or_else(|| parser.detect_format(&pending));
+    for line in pending.drain(..) {
+        process(&line, format, &mut aggregator);
+    }
+
+    let stats = aggregator.snapshot();
+    on_progress(LogAnalysisProgress {
+        path: request.path.clone(),
+        bytes_read,
+        total_bytes,
+        stats: stats.clone(),
+    });
+    Ok(LogAnalysis {
+        path: request.path.clone(),
+        format: format.name().to_string(),
+        total_bytes,
+        stats,
+        samples,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_format() {
+        let parser = LogParser::new();
+        let access = vec![
+            r#"10.0.0.1 - - [10/Oct/2024:13:55:36 +0000] "GET /api/users?id=1 HTTP/1.1" 200 512 "-" "curl/8.0""#.to_string(),
+            r#"10.0.0.2 - - [10/Oct/2024:13:56:01 +0000] "POST /login HTTP/1.1" 502 0 "-" "-""#.to_string(),
+        ];
+        assert_eq!(parser.detect_format(&access), LogFormat::Access);
+
+        let json =
+            vec![r#"{"time":"2024-10-10T13:55:36Z","level":"ERROR","msg":"db down"}"#.to_string()];
+        assert_eq!(parser.detect_format(&json), LogFormat::Json);
+
+        let logfmt =
+            vec![r#"ts=2024-10-10T13:55:36Z level=warn msg="slow query" duration=3s"#.to_string()];
+        assert_eq!(parser.detect_format(&logfmt), LogFormat::Logfmt);
+
+        let syslog = vec!["<11>Oct 10 13:55:36 web01 nginx[123]: upstream timed out".to_string()];
+        assert_eq!(parser.detect_format(&syslog), LogFormat::Syslog);
+
+        let record = parser.parse_access(&access[0]).unwrap();
+        assert_eq!(record.path.as_deref(), Some("/api/users"));
+        assert_eq!(
+            parser.parse_syslog(&syslog[0]).unwrap().level.as_deref(),
+            Some("error")
+        );
+    }
+
+    #[test]
+    fn test_analyze_access_log() {
+        let path = std::env::temp_dir().join(format!("devtools-log-{}.log", std::process::id()));
+        let lines = [
+            r#"10.0.0.1 - - [10/Oct/2024:13:55:36 +0000] "GET / HTTP/1.1" 200 512 "-" "-""#,
+            r#"10.0.0.1 - - [10/Oct/2024:13:58:36 +0000] "GET /a HTTP/1.1" 404 0 "-" "-""#,
+            r#"10.0.0.2 - - [10/Oct/2024:14:05:00 +0000] "GET /b HTTP/1.1" 500 0 "-" "-""#,
+        ];
+        std::fs::write(&path, lines.join("\n")).unwrap();
+
+        let mut request = AnalyzeLogRequest {
+            path: path.to_string_lossy().to_string(),
+            format: None,
+            pattern: None,
+            levels: None,
+            start_time: None,
+            end_time: None,
+            bucket: Some("day".to_string()),
+            top_n: None,
+            max_samples: None,
+        };
+        let mut events = 0;
+        let result = analyze_log_blocking(&request, |_| events += 1).unwrap();
+        assert_eq!(result.format, "access");
+        assert_eq!(result.stats.matched_lines, 3);
+        assert_eq!(result.stats.status_codes[&404], 1);
+        assert_eq!(result.stats.top_ips[0].key, "10.0.0.1");
+        assert_eq!(result.stats.top_ips[0].count, 2);
+        assert_eq!(result.samples.len(), 1);
+        assert!(events >= 1);
+
+        request.start_time = Some("2024-10-10T14:00:00Z".to_string());
+        let result = analyze_log_blocking(&request, |_| {}).unwrap();
+        assert_eq!(result.stats.matched_lines, 1);
+
+        request.start_time = None;
+        request.levels = Some(vec!["warning".to_string()]);
+        let result = analyze_log_blocking(&request, |_| {}).unwrap();
+        assert_eq!(result.stats.matched_lines, 1);
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,614 @@
+use crate::tools::static_server;
+use axum::body::{to_bytes, Body};
+use axum::extract::{self, ConnectInfo, Request};
+use axum::http::{HeaderName, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::Router;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, State};
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+use tower_http::cors::CorsLayer;
+
+/// 请求体读取上限
+const MAX_BODY_SIZE: usize = 10 * 1024 * 1024;
+/// 日志中保留的请求体长度
+const MAX_LOGGED_BODY: usize = 64 * 1024;
+
+type LogSink = Arc<dyn Fn(MockRequestLog) + Send + Sync>;
+
+#[derive(Default)]
+pub struct MockServerState {
+    servers: Mutex<HashMap<String, RunningMockServer>>,
+}
+
+impl MockServerState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+struct RunningMockServer {
+    info: MockServerInfo,
+    routes: Arc<RwLock<Vec<CompiledRoute>>>,
+    handle: JoinHandle<()>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MockRoute {
+    pub id: Option<String>,
+    pub method: String, // GET/POST/...，* 或 ANY 匹配所有方法
+    pub path: String,   // 支持 /users/:id、/users/{id} 与末尾 * 通配
+    pub status: u16,
+    pub headers: Option<HashMap<String, String>>,
+    pub body: Option<String>, // 模板，如 {{params.id}}、{{query.page}}、{{body.name}}
+    pub delay_ms: Option<u64>,
+    pub jitter_ms: Option<u64>, // 在 delay 基础上随机增加 0~jitter 毫秒
+    pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StartMockServerRequest {
+    pub name: Option<String>,
+    pub port: Option<u16>,
+    pub bind_address: Option<String>,
+    pub cors: Option<bool>,
+    pub routes: Vec<MockRoute>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MockServerInfo {
+    pub id: String,
+    pub name: String,
+    pub bind_address: String,
+    pub port: u16,
+    pub url: String,
+    pub route_count: usize,
+    pub cors: bool,
+    pub started_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MockRequestLog {
+    pub server_id: String,
+    pub timestamp: String,
+    pub remote_addr: String,
+    pub method: String,
+    pub path: String,
+    pub query: HashMap<String, String>,
+    pub headers: HashMap<String, String>,
+    pub body: String,
+    pub body_truncated: bool,
+    pub matched_route: Option<String>, // 未匹配时为空
+    pub status: u16,
+    pub duration_ms: u64,
+}
+
+#[derive(Debug, Clone)]
+enum Segment {
+    Literal(String),
+    Param(String),
+    Wildcard,
+}
+
+#[derive(Debug, Clone)]
+struct CompiledRoute {
+    route: MockRoute,
+    segments: Vec<Segment>,
+}
+
+struct MockContext {
+    id: String,
+    routes: Arc<RwLock<Vec<CompiledRoute>>>,
+    template: Regex,
+    sink: LogSink,
+}
+
+/// 请求数据，用于模板渲染
+struct RequestData<'a> {
+    method: &'a str,
+    path: &'a str,
+    params: HashMap<String, String>,
+    query: &'a HashMap<String, String>,
+    headers: &'a HashMap<String, String>,
+    body: &'a str,
+    json: Option<serde_json::Value>,
+}
+
+/// 启动 Mock 服务，请求日志通过 mock-server-log 事件推送
+#[tauri::command]
+pub async fn start_mock_server(
+    app: AppHandle,
+    state: State<'_, MockServerState>,
+    request: StartMockServerRequest,
+) -> Result<MockServerInfo, String> {
+    let id = format!("mock-{}", chrono::Utc::now().timestamp_millis());
+    let sink: LogSink = Arc::new(move |entry| {
+        let _ = app.emit("mock-server-log", entry);
+    });
+    let server = launch(id, request, sink).await?;
+    let info = server.info.clone();
+    state
+        .servers
+        .lock()
+        .unwrap()
+        .insert(info.id.clone(), server);
+    Ok(info)
+}
+
+/// 停止 Mock 服务
+#[tauri::command]
+pub async fn stop_mock_server(state: State<'_, MockServerState>, id: String) -> Result<(), String> {
+    let server = state.servers.lock().unwrap().remove(&id);
+    match server {
+        Some(server) => {
+            server.handle.abort();
+            Ok(())
+        }
+        None => Err("服务不存在或已停止".to_string()),
+    }
+}
+
+/// 列出正在运行的 Mock 服务
+#[tauri::command]
+pub async fn list_mock_servers(
+    state: State<'_, MockServerState>,
+) -> Result<Vec<MockServerInfo>, String> {
+    let mut servers = state.servers.lock().unwrap();
+    servers.retain(|_, server| !server.handle.is_finished());
+    let mut list: Vec<MockServerInfo> = servers.values().map(|s| s.info.clone()).collect();
+    list.sort_by(|a, b| a.started_at.cmp(&b.started_at));
+    Ok(list)
+}
+
+/// 替换运行中服务的路由，无需重启
+#[tauri::command]
+pub async fn update_mock_routes(
+    state: State<'_, MockServerState>,
+    id: String,
+    routes: Vec<MockRoute>,
+) -> Result<MockServerInfo, String> {
+    let compiled = compile_routes(routes)?;
+    let mut servers = state.servers.lock().unwrap();
+    let server = servers
+        .get_mut(&id)
+        .ok_or_else(|| "服务不存在或已停止".to_string())?;
+    server.info.route_count = compiled.len();
+    *server.routes.write().unwrap() = compiled;
+    Ok(server.info.clone())
+}
+
+async fn launch(
+    id: String,
+    request: StartMockServerRequest,
+    sink: LogSink,
+) -> Result<RunningMockServer, String> {
+    let routes = Arc::new(RwLock::new(compile_routes(request.routes)?));
+    let bind_address = request
+        .bind_address
+        .filter(|a| !a.trim().is_empty())
+        .unwrap_or_else(|| "127.0.0.1".to_string());
+    let bind_ip: IpAddr = bind_address
+        .trim()
+        .parse()
+        .map_err(|_| format!("无效的监听地址: {}", bind_address))?;
+    let listener = TcpListener::bind(SocketAddr::new(bind_ip, request.port.unwrap_or(0)))
+        .await
+        .map_err(|e| format!("监听端口失败: {}", e))?;
+    let port = listener
+        .local_addr()
+        .map_err(|e| format!("获取监听端口失败: {}", e))?
+        .port();
+
+    let context = Arc::new(MockContext {
+        id: id.clone(),
+        routes: routes.clone(),
+        template: Regex::new(r"\{\{\s*([\w.\-]+)\s*\}\}").unwrap(),
+        sink,
+    });
+    let cors = request.cors.unwrap_or(true);
+    let mut router = Router::new().fallback(handle_request).with_state(context);
+    if cors {
+        router = router.layer(CorsLayer::permissive());
+    }
+    let handle = tokio::spawn(static_server::serve(listener, router, None));
+
+    let host = if bind_ip.is_unspecified() {
+        if bind_ip.is_ipv6() {
+            "[::1]"
+        } else {
+            "127.0.0.1"
+        }
+        .to_string()
+    } else if bind_ip.is_ipv6() {
+        format!("[{}]", bind_ip)
+    } else {
+        bind_ip.to_string()
+    };
+    let info = MockServerInfo {
+        name: request.name.unwrap_or_else(|| format!("Mock {}", port)),
+        id,
+        bind_address,
+        port,
+        url: format!("http://{}:{}", host, port),
+        route_count: routes.read().unwrap().len(),
+        cors,
+        started_at: chrono::Local::now().to_rfc3339(),
+    };
+    Ok(RunningMockServer {
+        info,
+        routes,
+        handle,
+    })
+}
+
+fn compile_routes(routes: Vec<MockRoute>) -> Result<Vec<CompiledRoute>, String> {
+    routes
+        .into_iter()
+        .filter(|route| route.enabled.unwrap_or(true))
+        .map(|route| {
+            StatusCode::from_u16(route.status)
+                .map_err(|_| format!("无效的状态码: {}", route.status))?;
+            for name in route.headers.iter().flat_map(|h| h.keys()) {
+                HeaderName::from_bytes(name.as_bytes())
+                    .map_err(|_| format!("无效的响应头名称: {}", name))?;
+            }
+            let segments = parse_pattern(&route.path);
+            Ok(CompiledRoute { route, segments })
+        })
+        .collect()
+}
+
+fn parse_pattern(pattern: &str) -> Vec<Segment> {
+    pattern
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .map(|segment| {
+            if segment == "*" {
+                Segment::Wildcard
+            } else if let Some(name) = segment.strip_prefix(':') {
+                Segment::Param(name.to_string())
+            } else if let Some(name) = segment.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+                Segment::Param(name.to_string())
+            } else {
+                Segment::Literal(segment.to_string())
+            }
+        })
+        .collect()
+}
+
+/// 匹配成功时返回路径参数，末尾的 * 匹配剩余全部路径
+fn match_path(segments: &[Segment], path: &str) -> Option<HashMap<String, String>> {
+    let parts: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    let mut params = HashMap::new();
+    for (index, segment) in segments.iter().enumerate() {
+        let is_last = index == segments.len() - 1;
+        match segment {
+            Segment::Wildcard if is_last => {
+                params.insert("*".to_string(), parts.get(index..)?.join("/"));
+                return Some(params);
+            }
+            Segment::Wildcard => {
+                parts.get(index)?;
+            }
+            Segment::Param(name) => {
+                let value = parts.get(index)?;
+                let decoded = percent_encoding::percent_decode_str(value).decode_utf8_lossy();
+                params.insert(name.clone(), decoded.to_string());
+            }
+            Segment::Literal(literal) => {
+                if parts.get(index) != Some(&literal.as_str()) {
+                    return None;
+                }
+            }
+        }
+    }
+    (parts.len() == segments.len()).then_some(params)
+}
+
+fn method_matches(pattern: &str, method: &str) -> bool {
+    let pattern = pattern.trim();
+    pattern.is_empty()
+        || pattern == "*"
+        || pattern.eq_ignore_ascii_case("ANY")
+        || pattern
+            .split([',', '|'])
+            .any(|m| m.trim().eq_ignore_ascii_case(method))
+}
+
+fn render_template(template: &Regex, body: &str, data: &RequestData) -> String {
+    template
+        .replace_all(body, |caps: &regex::Captures| {
+            resolve_variable(&caps[1], data)
+        })
+        .to_string()
+}
+
+fn resolve_variable(name: &str, data: &RequestData) -> String {
+    let (scope, key) = name.split_once('.').unwrap_or((name, ""));
+    match (scope, key) {
+        ("method", "") => data.method.to_string(),
+        ("path", "") => data.path.to_string(),
+        ("body", "") => data.body.to_string(),
+        ("now", "") => chrono::Local::now().to_rfc3339(),
+        ("timestamp", "") => chrono::Utc::now().timestamp_millis().to_string(),
+        ("uuid", "") => random_uuid(),
+        ("params", key) => data.params.get(key).cloned().unwrap_or_default(),
+        ("query", key) => data.query.get(key).cloned().unwrap_or_default(),
+        ("headers", key) => data
+            .headers
+            .get(&key.to_lowercase())
+            .cloned()
+            .unwrap_or_default(),
+        ("body", key) => {
+            let mut value = data.json.as_ref();
+            for part in key.split('.') {
+                value = value.and_then(|v| match part.parse::<usize>() {
+                    Ok(index) if v.is_array() => v.get(index),
+                    _ => v.get(part),
+                });
+            }
+            match value {
+                Some(serde_json::Value::String(s)) => s.clone(),
+                Some(other) => other.to_string(),
+                None => String::new(),
+            }
+        }
+        _ => String::new(),
+    }
+}
+
+pub(crate) fn random_bytes<const N: usize>() -> [u8; N] {
+    let mut bytes = [0u8; N];
+    let _ = openssl::rand::rand_bytes(&mut bytes);
+    bytes
+}
+
+pub(crate) fn random_uuid() -> String {
+    let mut bytes = random_bytes::<16>();
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex = hex::encode(bytes);
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+fn response_delay(route: &MockRoute) -> Duration {
+    let jitter = match route.jitter_ms.unwrap_or(0) {
+        0 => 0,
+        max => u64::from_le_bytes(random_bytes::<8>()) % (max + 1),
+    };
+    Duration::from_millis(route.delay_ms.unwrap_or(0) + jitter)
+}
+
+async fn handle_request(
+    extract::State(context): extract::State<Arc<MockContext>>,
+    ConnectInfo(remote): ConnectInfo<SocketAddr>,
+    request: Request,
+) -> Response {
+    let start = Instant::now();
+    let (parts, body) = request.into_parts();
+    let method = parts.method.to_string();
+    let path = parts.uri.path().to_string();
+    let query: HashMap<String, String> = parts
+        .uri
+        .query()
+        .map(|q| {
+            url::form_urlencoded::parse(q.as_bytes())
+                .into_owned()
+                .collect()
+        })
+        .unwrap_or_default();
+    let headers: HashMap<String, String> = parts
+        .headers
+        .iter()
+        .map(|(k, v)| {
+            (
+                k.to_string(),
+                String::from_utf8_lossy(v.as_bytes()).to_string(),
+            )
+        })
+        .collect();
+    let body = match to_bytes(body, MAX_BODY_SIZE).await {
+        Ok(bytes) => String::from_utf8_lossy(&bytes).to_string(),
+        Err(_) => return (StatusCode::PAYLOAD_TOO_LARGE, "请求体过大").into_response(),
+    };
+
+    let matched = context.routes.read().unwrap().iter().find_map(|compiled| {
+        if !method_matches(&compiled.route.method, &method) {
+            return None;
+        }
+        match_path(&compiled.segments, &path).map(|params| (compiled.route.clone(), params))
+    });
+
+    let (response, matched_route) = match matched {
+        Some((route, params)) => {
+            let data = RequestData {
+                method: &method,
+                path: &path,
+                params,
+                query: &query,
+                headers: &headers,
+                body: &body,
+                json: serde_json::from_str(&body).ok(),
+            };
+            let delay = response_delay(&route);
+            if !delay.is_zero() {
+                tokio::time::sleep(delay).await;
+            }
+            let label = route
+                .id
+                .clone()
+                .unwrap_or_else(|| format!("{} {}", route.method, route.path));
+            (
+                build_response(&context.template, &route, &data),
+                Some(label),
+            )
+        }
+        None => {
+            let body = serde_json::json!({
+                "error": "没有匹配的 Mock 路由",
+                "method": method,
+                "path": path,
+            });
+            (
+                (StatusCode::NOT_FOUND, axum::Json(body)).into_response(),
+                None,
+            )
+        }
+    };
+
+    let body_truncated = body.len() > MAX_LOGGED_BODY;
+    let logged_body = if body_truncated {
+        let mut end = MAX_LOGGED_BODY;
+        while !body.is_char_boundary(end) {
+            end -= 1;
+        }
+        body[..end].to_string()
+    } else {
+        body
+    };
+    (context.sink)(MockRequestLog {
+        server_id: context.id.clone(),
+        timestamp: chrono::Local::now().to_rfc3339(),
+        remote_addr: remote.to_string(),
+        method,
+        path,
+        query,
+        headers,
+        body: logged_body,
+        body_truncated,
+        matched_route,
+        status: response.status().as_u16(),
+        duration_ms: start.elapsed().as_millis() as u64,
+    });
+    response
+}
+
+fn build_response(template: &Regex, route: &MockRoute, data: &RequestData) -> Response {
+    let body = route
+        .body
+        .as_deref()
+        .map(|b| render_template(template, b, data))
+        .unwrap_or_default();
+    let mut response = Response::new(Body::from(body.clone()));
+    *response.status_mut() = StatusCode::from_u16(route.status).unwrap_or(StatusCode::OK);
+
+    let response_headers = response.headers_mut();
+    for (name, value) in route.headers.iter().flatten() {
+        let value = render_template(template, value, data);
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::from_bytes(name.as_bytes()),
+            HeaderValue::from_str(&value),
+        ) {
+            response_headers.insert(name, value);
+        }
+    }
+    if !response_headers.contains_key("content-type") && !body.is_empty() {
+        let trimmed = body.trim_start();
+        let content_type = if trimmed.starts_with('{') || trimmed.starts_with('[') {
+            "application/json; charset=utf-8"
+        } else if trimmed.starts_with('<') {
+            "text/html; charset=utf-8"
+        } else {
+            "text/plain; charset=utf-8"
+        };
+        response_headers.insert("content-type", HeaderValue::from_static(content_type));
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_match_path() {
+        let segments = parse_pattern("/api/users/:id/posts/{post}");
+        let params = match_path(&segments, "/api/users/42/posts/7").unwrap();
+        assert_eq!(params["id"], "42");
+        assert_eq!(params["post"], "7");
+        assert!(match_path(&segments, "/api/users/42/posts").is_none());
+        assert!(match_path(&segments, "/api/users/42/posts/7/extra").is_none());
+
+        let wildcard = parse_pattern("/static/*");
+        assert_eq!(
+            match_path(&wildcard, "/static/a/b.js").unwrap()["*"],
+            "a/b.js"
+        );
+        assert!(match_path(&parse_pattern("/"), "/").is_some());
+        assert!(method_matches("GET,POST", "post"));
+        assert!(!method_matches("GET", "DELETE"));
+    }
+
+    #[tokio::test]
+    async fn test_mock_server_routes() {
+        let logs = Arc::new(Mutex::new(Vec::new()));
+        let sink_logs = logs.clone();
+        let sink: LogSink = Arc::new(move |entry| sink_logs.lock().unwrap().push(entry));
+        let route = MockRoute {
+            id: Some("create-user".to_string()),
+            method: "POST".to_string(),
+            path: "/users/:id".to_string(),
+            status: 201,
+            headers: Some(HashMap::from([(
+                "X-Request-Path".to_string(),
+                "{{path}}".to_string(),
+            )])),
+            body: Some(
+                r#"{"id":"{{params.id}}","name":"{{body.user.name}}","page":"{{query.page}}"}"#
+                    .to_string(),
+            ),
+            delay_ms: None,
+            jitter_ms: None,
+            enabled: None,
+        };
+        let request = StartMockServerRequest {
+            name: None,
+            port: None,
+            bind_address: None,
+            cors: None,
+            routes: vec![route],
+        };
+        let server = launch("test".to_string(), request, sink).await.unwrap();
+        let client = reqwest::Client::new();
+
+        let response = client
+            .post(format!("{}/users/7?page=2", server.info.url))
+            .body(r#"{"user":{"name":"Alice"}}"#)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status().as_u16(), 201);
+        assert_eq!(response.headers()["x-request-path"], "/users/7");
+        assert_eq!(
+            response.text().await.unwrap(),
+            r#"{"id":"7","name":"Alice","page":"2"}"#
+        );
+
+        let response = client
+            .get(format!("{}/users/7", server.info.url))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status().as_u16(), 404);
+
+        server.handle.abort();
+        let logs = logs.lock().unwrap();
+        assert_eq!(logs.len(), 2);
+        assert_eq!(logs[0].matched_route.as_deref(), Some("create-user"));
+        assert_eq!(logs[0].body, r#"{"user":{"name":"Alice"}}"#);
+        assert!(logs[1].matched_route.is_none());
+    }
+}
@@ -1,14 +1,87 @@
+pub mod animation_converter;
+pub mod app_data;
+pub mod archive_tool;
+pub mod asn1_tool;
+pub mod audio_analyzer;
 pub mod autostart;
+pub mod binary_json_tool;
+pub mod cert_scanner;
 pub mod certificate_converter;
 pub mod certificate_viewer;
+pub mod cheatsheet;
+pub mod checksum_tool;
+pub mod cloud_latency;
+pub mod cn_validators;
+pub mod compression_tool;
+pub mod csv_tool;
+pub mod deep_link;
+pub mod dns_propagation;
+pub mod dns_tool;
+pub mod docker_tool;
+pub mod domain_monitor;
+pub mod email_tool;
+pub mod env_tool;
+pub mod excel_tool;
+pub mod fake_finance;
+pub mod format_tool;
+pub mod git_tool;
 pub mod global_shortcut;
+pub mod go_struct_converter;
+pub mod graphql_tool;
+pub mod hex_viewer;
+pub mod http3_probe;
+pub mod http_collection;
+pub mod id_timestamp;
+pub mod idn_tool;
 pub mod image_converter;
 pub mod ip_info;
 pub mod json_to_go;
+pub mod k8s_tool;
+pub mod key_generator;
+pub mod keystore_tool;
+pub mod kv_console;
+pub mod license_tool;
+pub mod load_tester;
+pub mod log_analyzer;
+pub mod mock_server;
+pub mod mq_client;
+pub mod network_probe;
+pub mod ocr_tool;
+pub mod package_lookup;
+pub mod pdf_tool;
+pub mod plugins;
+pub mod protobuf_wire;
+pub mod proxy_headers;
+pub mod regex_library;
 pub mod regex_tester;
+pub mod robots_sitemap;
+pub mod san_coverage;
+pub mod scheduler;
+pub mod screenshot;
+pub mod semver_tool;
+pub mod site_icons;
+pub mod slug_tool;
+pub mod snippet_generator;
+pub mod spki_pin;
 pub mod sql_to_ent;
 pub mod sql_to_go;
+pub mod sse_client;
+pub mod ssh_audit;
+pub mod ssh_tool;
 pub mod ssl_checker;
+pub mod ssl_history;
+pub mod static_server;
+pub mod string_escape;
 pub mod system_settings;
+pub mod text_encoding;
+pub mod trust_store;
+pub mod unicode_inspector;
+pub mod unit_converter;
+pub mod uptime_monitor;
+pub mod url_tool;
+pub mod user_agent;
 pub mod video_converter;
+pub mod watch_folder;
+pub mod web_server_config;
 pub mod whois;
+pub mod workspaces;
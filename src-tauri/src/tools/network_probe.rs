@@ -0,0 +1,970 @@
+use crate::tools::ip_info::lookup_ip_network;
+use crate::utils::proxy;
+use serde::{Deserialize, Serialize};
+use socket2::{Domain, Protocol, SockAddr, Socket, Type};
+use std::io::ErrorKind;
+use std::net::{IpAddr, SocketAddr, UdpSocket};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+
+const DEFAULT_PAYLOAD_SIZE: usize = 32;
+const DEFAULT_UDP_PORT: u16 = 33434;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PingRequest {
+    pub host: String,
+    pub protocol: Option<String>, // icmp（默认）/ udp
+    pub count: Option<u32>,
+    pub interval_ms: Option<u64>,
+    pub timeout_ms: Option<u64>,
+    pub payload_size: Option<usize>,
+    pub port: Option<u16>,      // UDP 模式的目标端口
+    pub ip_version: Option<u8>, // 4 / 6，域名解析时的地址族偏好
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PingReply {
+    pub seq: u32,
+    pub rtt_ms: Option<f64>,
+    pub ttl: Option<u8>,
+    pub from: Option<String>,
+    pub status: String, // reply / timeout / unreachable / error
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PingResult {
+    pub host: String,
+    pub ip: String,
+    pub protocol: String,
+    pub transmitted: u32,
+    pub received: u32,
+    pub packet_loss: f64, // 百分比
+    pub min_ms: Option<f64>,
+    pub avg_ms: Option<f64>,
+    pub max_ms: Option<f64>,
+    pub stddev_ms: Option<f64>,
+    pub replies: Vec<PingReply>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TracerouteRequest {
+    pub host: String,
+    pub max_hops: Option<u8>,
+    pub queries_per_hop: Option<u8>,
+    pub timeout_ms: Option<u64>,
+    pub resolve_names: Option<bool>, // 反向解析每一跳的主机名，默认开启
+    pub enrich: Option<bool>,        // 查询每一跳的 ASN 与地理位置，默认开启
+    pub ip_version: Option<u8>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TracerouteHop {
+    pub ttl: u8,
+    pub address: Option<String>,
+    pub hostname: Option<String>,
+    pub rtts: Vec<Option<f64>>,
+    pub avg_ms: Option<f64>,
+    pub loss_percent: f64,
+    pub asn: Option<String>,
+    pub org: Option<String>,
+    pub location: Option<String>,
+    pub is_destination: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TracerouteResult {
+    pub host: String,
+    pub ip: String,
+    pub hops: Vec<TracerouteHop>,
+    pub reached: bool,
+    pub elapsed_ms: u64,
+}
+
+enum ProbeOutcome {
+    Reply {
+        from: IpAddr,
+        rtt: Duration,
+        ttl: Option<u8>,
+    },
+    TimeExceeded {
+        from: IpAddr,
+        rtt: Duration,
+    },
+    Unreachable {
+        from: IpAddr,
+        rtt: Duration,
+    },
+    Timeout,
+}
+
+pub(crate) fn to_ms(duration: Duration) -> f64 {
+    (duration.as_secs_f64() * 1000.0 * 1000.0).round() / 1000.0
+}
+
+fn checksum(data: &[u8]) -> u16 {
+    let mut sum: u32 = data
+        .chunks(2)
+        .map(|pair| u16::from_be_bytes([pair[0], *pair.get(1).unwrap_or(&0)]) as u32)
+        .sum();
+    while sum >> 16 != 0 {
+        sum = (sum & 0xffff) + (sum >> 16);
+    }
+    !(sum as u16)
+}
+
+pub(crate) fn resolve_target(host: &str, ip_version: Option<u8>) -> Result<IpAddr, String> {
+    let host = host.trim().trim_start_matches('[').trim_end_matches(']');
+    if host.is_empty() {
+        return Err("主机地址不能为空".to_string());
+    }
+    if let Ok(ip) = host.parse::<IpAddr>() {
+        return Ok(ip);
+    }
+    let addrs: Vec<IpAddr> = dns_lookup::lookup_host(host)
+        .map_err(|e| format!("解析域名失败: {}", e))?
+        .collect();
+    let preferred = match ip_version {
+        Some(6) => addrs.iter().find(|ip| ip.is_ipv6()),
+        _ => addrs.iter().find(|ip| ip.is_ipv4()),
+    };
+    preferred
+        .or(addrs.first())
+        .copied()
+        .ok_or_else(|| format!("域名 {} 没有可用的地址", host))
+}
+
+fn is_public_ip(ip: &IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => {
+            let octets = v4.octets();
+            !(v4.is_private()
+                || v4.is_loopback()
+                || v4.is_link_local()
+                || v4.is_unspecified()
+                || v4.is_broadcast()
+                || (octets[0] == 100 && (64..128).contains(&octets[1])))
+        }
+        IpAddr::V6(v6) => {
+            let first = v6.segments()[0];
+            !(v6.is_loopback()
+                || v6.is_unspecified()
+                || (first & 0xfe00) == 0xfc00
+                || (first & 0xffc0) == 0xfe80)
+        }
+    }
+}
+
+/// ICMP 探测套接字，优先使用非特权的 DGRAM 套接字，不可用时回退到 RAW
+struct IcmpSocket {
+    socket: Socket,
+    io: UdpSocket,
+    raw: bool,
+    v6: bool,
+    ident: u16,
+}
+
+impl IcmpSocket {
+    fn open(v6: bool) -> Result<Self, String> {
+        let (domain, protocol) = if v6 {
+            (Domain::IPV6, Protocol::ICMPV6)
+        } else {
+            (Domain::IPV4, Protocol::ICMPV4)
+        };
+        let (socket, raw) = match Socket::new(domain, Type::DGRAM, Some(protocol)) {
+            Ok(socket) => (socket, false),
+            Err(_) => (
+                Socket::new(domain, Type::RAW, Some(protocol)).map_err(|e| {
+                    format!(
+                        "无法创建 ICMP 套接字，需要管理员权限或系统允许非特权 ICMP: {}",
+                        e
+                    )
+                })?,
+                true,
+            ),
+        };
+        #[cfg(target_os = "linux")]
+        if !raw {
+            linux_errqueue::enable_recv_err(&socket, v6);
+        }
+        let io: UdpSocket = socket
+            .try_clone()
+            .map_err(|e| format!("初始化 ICMP 套接字失败: {}", e))?
+            .into();
+        Ok(Self {
+            socket,
+            io,
+            raw,
+            v6,
+            ident: (std::process::id() & 0xffff) as u16,
+        })
+    }
+
+    fn set_ttl(&self, ttl: u32) -> Result<(), String> {
+        if self.v6 {
+            self.socket.set_unicast_hops_v6(ttl)
+        } else {
+            self.socket.set_ttl(ttl)
+        }
+        .map_err(|e| format!("设置 TTL 失败: {}", e))
+    }
+
+    fn build_echo(&self, seq: u16, payload_size: usize) -> Vec<u8> {
+        let mut packet = vec![0u8; 8 + payload_size];
+        packet[0] = if self.v6 { 128 } else { 8 };
+        packet[4..6].copy_from_slice(&self.ident.to_be_bytes());
+        packet[6..8].copy_from_slice(&seq.to_be_bytes());
+        for (i, byte) in packet[8..].iter_mut().enumerate() {
+            *byte = (i & 0xff) as u8;
+        }
+        // ICMPv6 校验和由内核计算
+        if !self.v6 {
+            let sum = checksum(&packet);
+            packet[2..4].copy_from_slice(&sum.to_be_bytes());
+        }
+        packet
+    }
+
+    fn parse_packet(
+        &self,
+        data: &[u8],
+        from: IpAddr,
+        seq: u16,
+        rtt: Duration,
+    ) -> Option<ProbeOutcome> {
+        let mut icmp = data;
+        let mut ttl = None;
+        // RAW 套接字（以及 macOS 的 DGRAM 套接字）收到的 IPv4 报文带有 IP 头
+        if !self.v6 && data.len() >= 20 && data[0] >> 4 == 4 {
+            let header_len = (data[0] & 0x0f) as usize * 4;
+            ttl = Some(data[8]);
+            icmp = data.get(header_len..)?;
+        }
+        if icmp.len() < 8 {
+            return None;
+        }
+        let (reply, exceeded, unreachable) = if self.v6 { (129, 3, 1) } else { (0, 11, 3) };
+        let icmp_type = icmp[0];
+
+        if icmp_type == reply {
+            let ident = u16::from_be_bytes([icmp[4], icmp[5]]);
+            let reply_seq = u16::from_be_bytes([icmp[6], icmp[7]]);
+            // 非特权套接字中内核会改写 identifier，只能校验序号
+            if reply_seq != seq || (self.raw && ident != self.ident) {
+                return None;
+            }
+            return Some(ProbeOutcome::Reply { from, rtt, ttl });
+        }
+        if icmp_type == exceeded || icmp_type == unreachable {
+            // 差错报文中携带原始 IP 头和 ICMP 头的前 8 字节
+            let inner = icmp.get(8..)?;
+            let inner_icmp = if self.v6 {
+                inner.get(40..)?
+            } else {
+                let header_len = (inner.first()? & 0x0f) as usize * 4;
+                inner.get(header_len..)?
+            };
+            if inner_icmp.len() < 8 || u16::from_be_bytes([inner_icmp[6], inner_icmp[7]]) != seq {
+                return None;
+            }
+            return Some(if icmp_type == exceeded {
+                ProbeOutcome::TimeExceeded { from, rtt }
+            } else {
+                ProbeOutcome::Unreachable { from, rtt }
+            });
+        }
+        None
+    }
+
+    fn probe(
+        &self,
+        target: IpAddr,
+        seq: u16,
+        payload_size: usize,
+        timeout: Duration,
+    ) -> Result<ProbeOutcome, String> {
+        let packet = self.build_echo(seq, payload_size);
+        let start = Instant::now();
+        self.socket
+            .send_to(&packet, &SockAddr::from(SocketAddr::new(target, 0)))
+            .map_err(|e| format!("发送探测包失败: {}", e))?;
+
+        let mut buf = [0u8; 1500];
+        loop {
+            let elapsed = start.elapsed();
+            if elapsed >= timeout {
+                return Ok(ProbeOutcome::Timeout);
+            }
+            self.io
+                .set_read_timeout(Some(timeout - elapsed))
+                .map_err(|e| format!("设置超时失败: {}", e))?;
+            match self.io.recv_from(&mut buf) {
+                Ok((len, from)) => {
+                    if let Some(outcome) =
+                        self.parse_packet(&buf[..len], from.ip(), seq, start.elapsed())
+                    {
+                        return Ok(outcome);
+                    }
+                }
+                Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {
+                    return Ok(ProbeOutcome::Timeout);
+                }
+                Err(e) => {
+                    // Linux 非特权 ICMP 套接字的差错报文通过错误队列投递
+                    #[cfg(target_os = "linux")]
+                    if !self.raw {
+                        if let Some(error) = linux_errqueue::read(&self.socket) {
+                            if error.seq != seq {
+                                continue;
+                            }
+                            let rtt = start.elapsed();
+                            let exceeded = if self.v6 { 3 } else { 11 };
+                            return Ok(if error.icmp_type == exceeded {
+                                ProbeOutcome::TimeExceeded {
+                                    from: error.offender,
+                                    rtt,
+                                }
+                            } else {
+                                ProbeOutcome::Unreachable {
+                                    from: error.offender,
+                                    rtt,
+                                }
+                            });
+                        }
+                    }
+                    return Err(format!("接收响应失败: {}", e));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod linux_errqueue {
+    use socket2::Socket;
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+    use std::os::fd::AsRawFd;
+
+    pub struct QueuedError {
+        pub seq: u16,
+        pub offender: IpAddr,
+        pub icmp_type: u8,
+    }
+
+    pub fn enable_recv_err(socket: &Socket, v6: bool) {
+        let on: libc::c_int = 1;
+        let (level, name) = if v6 {
+            (libc::SOL_IPV6, libc::IPV6_RECVERR)
+        } else {
+            (libc::SOL_IP, libc::IP_RECVERR)
+        };
+        unsafe {
+            libc::setsockopt(
+                socket.as_raw_fd(),
+                level,
+                name,
+                &on as *const libc::c_int as *const libc::c_void,
+                std::mem::size_of_val(&on) as libc::socklen_t,
+            );
+        }
+    }
+
+    /// 从套接字错误队列中读取一条 ICMP 差错信息
+    pub fn read(socket: &Socket) -> Option<QueuedError> {
+        let mut data = [0u8; 512];
+        let mut control = [0u8; 512];
+        let mut iov = libc::iovec {
+            iov_base: data.as_mut_ptr() as *mut libc::c_void,
+            iov_len: data.len(),
+        };
+        let mut msg: libc::msghdr = unsafe { std::mem::zeroed() };
+        msg.msg_iov = &mut iov;
+        msg.msg_iovlen = 1;
+        msg.msg_control = control.as_mut_ptr() as *mut libc::c_void;
+        msg.msg_controllen = control.len() as _;
+
+        let len = unsafe {
+            libc::recvmsg(
+                socket.as_raw_fd(),
+                &mut msg,
+                libc::MSG_ERRQUEUE | libc::MSG_DONTWAIT,
+            )
+        };
+        if len < 8 {
+            return None;
+        }
+        // 错误队列返回的数据是我们发出的原始 ICMP 请求
+        let seq = u16::from_be_bytes([data[6], data[7]]);
+
+        let mut cmsg = unsafe { libc::CMSG_FIRSTHDR(&msg) };
+        while !cmsg.is_null() {
+            let header = unsafe { &*cmsg };
+            let is_error = (header.cmsg_level == libc::SOL_IP
+                && header.cmsg_type == libc::IP_RECVERR)
+                || (header.cmsg_level == libc::SOL_IPV6 && header.cmsg_type == libc::IPV6_RECVERR);
+            if is_error {
+                let ee = unsafe { &*(libc::CMSG_DATA(cmsg) as *const libc::sock_extended_err) };
+                if ee.ee_origin == libc::SO_EE_ORIGIN_ICMP
+                    || ee.ee_origin == libc::SO_EE_ORIGIN_ICMP6
+                {
+                    let offender = unsafe {
+                        (ee as *const libc::sock_extended_err).add(1) as *const libc::sockaddr
+                    };
+                    let offender = unsafe { sockaddr_ip(offender) }?;
+                    return Some(QueuedError {
+                        seq,
+                        offender,
+                        icmp_type: ee.ee_type,
+                    });
+                }
+            }
+            cmsg = unsafe { libc::CMSG_NXTHDR(&msg, cmsg) };
+        }
+        None
+    }
+
+    unsafe fn sockaddr_ip(addr: *const libc::sockaddr) -> Option<IpAddr> {
+        match (*addr).sa_family as libc::c_int {
+            libc::AF_INET => {
+                let v4 = &*(addr as *const libc::sockaddr_in);
+                Some(IpAddr::V4(Ipv4Addr::from(u32::from_be(v4.sin_addr.s_addr))))
+            }
+            libc::AF_INET6 => {
+                let v6 = &*(addr as *const libc::sockaddr_in6);
+                Some(IpAddr::V6(Ipv6Addr::from(v6.sin6_addr.s6_addr)))
+            }
+            _ => None,
+        }
+    }
+}
+
+fn udp_probe(
+    socket: &UdpSocket,
+    payload: &[u8],
+    timeout: Duration,
+) -> Result<Option<Duration>, String> {
+    socket
+        .set_read_timeout(Some(timeout))
+        .map_err(|e| format!("设置超时失败: {}", e))?;
+    let start = Instant::now();
+    socket
+        .send(payload)
+        .map_err(|e| format!("发送探测包失败: {}", e))?;
+    let mut buf = [0u8; 1500];
+    match socket.recv(&mut buf) {
+        Ok(_) => Ok(Some(start.elapsed())),
+        // 收到 ICMP 端口不可达同样说明目标主机可达
+        Err(e)
+            if matches!(
+                e.kind(),
+                ErrorKind::ConnectionRefused | ErrorKind::ConnectionReset
+            ) =>
+        {
+            Ok(Some(start.elapsed()))
+        }
+        Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => Ok(None),
+        Err(e) => Err(format!("接收响应失败: {}", e)),
+    }
+}
+
+fn summarize_ping(
+    host: String,
+    ip: IpAddr,
+    protocol: String,
+    replies: Vec<PingReply>,
+) -> PingResult {
+    let rtts: Vec<f64> = replies
+        .iter()
+        .filter(|r| r.status == "reply")
+        .filter_map(|r| r.rtt_ms)
+        .collect();
+    let transmitted = replies.len() as u32;
+    let received = rtts.len() as u32;
+    let (min_ms, avg_ms, max_ms, stddev_ms) = if rtts.is_empty() {
+        (None, None, None, None)
+    } else {
+        let n = rtts.len() as f64;
+        let avg = rtts.iter().sum::<f64>() / n;
+        let variance = rtts.iter().map(|r| (r - avg).powi(2)).sum::<f64>() / n;
+        let round = |v: f64| (v * 1000.0).round() / 1000.0;
+        (
+            rtts.iter().copied().reduce(f64::min),
+            Some(round(avg)),
+            rtts.iter().copied().reduce(f64::max),
+            Some(round(variance.sqrt())),
+        )
+    };
+    PingResult {
+        host,
+        ip: ip.to_string(),
+        protocol,
+        transmitted,
+        received,
+        packet_loss: if transmitted == 0 {
+            0.0
+        } else {
+            ((transmitted - received) as f64 / transmitted as f64 * 10000.0).round() / 100.0
+        },
+        min_ms,
+        avg_ms,
+        max_ms,
+        stddev_ms,
+        replies,
+    }
+}
+
+/// 使用 ICMP 或 UDP 探测主机连通性并统计延迟
+#[tauri::command]
+pub async fn ping_host(request: PingRequest) -> Result<PingResult, String> {
+    let protocol = request
+        .protocol
+        .clone()
+        .unwrap_or_else(|| "icmp".to_string())
+        .to_lowercase();
+    if protocol != "icmp" && protocol != "udp" {
+        return Err(format!("不支持的探测协议: {}", protocol));
+    }
+    let count = request.count.unwrap_or(4).clamp(1, 1000);
+    let interval = Duration::from_millis(request.interval_ms.unwrap_or(1000).max(200));
+    let timeout = Duration::from_millis(request.timeout_ms.unwrap_or(2000).clamp(100, 30000));
+    let payload_size = request
+        .payload_size
+        .unwrap_or(DEFAULT_PAYLOAD_SIZE)
+        .min(1400);
+
+    let host = request.host.trim().to_string();
+    let ip_version = request.ip_version;
+    let port = request.port.unwrap_or(DEFAULT_UDP_PORT);
+
+    tokio::task::spawn_blocking(move || {
+        let target = resolve_target(&host, ip_version)?;
+        let mut replies = Vec::new();
+
+        if protocol == "icmp" {
+            let socket = IcmpSocket::open(target.is_ipv6())?;
+            for seq in 0..count {
+                if seq > 0 {
+                    std::thread::sleep(interval);
+                }
+                let reply = match socket.probe(target, seq as u16, payload_size, timeout) {
+                    Ok(ProbeOutcome::Reply { from, rtt, ttl }) => PingReply {
+                        seq,
+                        rtt_ms: Some(to_ms(rtt)),
+                        ttl,
+                        from: Some(from.to_string()),
+                        status: "reply".to_string(),
+                        error: None,
+                    },
+                    Ok(ProbeOutcome::TimeExceeded { from, rtt })
+                    | Ok(ProbeOutcome::Unreachable { from, rtt }) => PingReply {
+                        seq,
+                        rtt_ms: Some(to_ms(rtt)),
+                        ttl: None,
+                        from: Some(from.to_string()),
+                        status: "unreachable".to_string(),
+                        error: None,
+                    },
+                    Ok(ProbeOutcome::Timeout) => PingReply {
+                        seq,
+                        rtt_ms: None,
+                        ttl: None,
+                        from: None,
+                        status: "timeout".to_string(),
+                        error: None,
+                    },
+                    Err(e) => PingReply {
+                        seq,
+                        rtt_ms: None,
+                        ttl: None,
+                        from: None,
+                        status: "error".to_string(),
+                        error: Some(e),
+                    },
+                };
+                replies.push(reply);
+            }
+        } else {
+            let bind: SocketAddr = if target.is_ipv6() {
+                "[::]:0".parse().unwrap()
+            } else {
+                "0.0.0.0:0".parse().unwrap()
+            };
+            let socket =
+                UdpSocket::bind(bind).map_err(|e| format!("创建 UDP 套接字失败: {}", e))?;
+            socket
+                .connect(SocketAddr::new(target, port))
+                .map_err(|e| format!("连接目标失败: {}", e))?;
+            let payload = vec![0u8; payload_size];
+            for seq in 0..count {
+                if seq > 0 {
+                    std::thread::sleep(interval);
+                }
+                let (rtt_ms, status, error) = match udp_probe(&socket, &payload, timeout) {
+                    Ok(Some(rtt)) => (Some(to_ms(rtt)), "reply", None),
+                    Ok(None) => (None, "timeout", None),
+                    Err(e) => (None, "error", Some(e)),
+                };
+                replies.push(PingReply {
+                    seq,
+                    rtt_ms,
+                    ttl: None,
+                    from: rtt_ms.map(|_| target.to_string()),
+                    status: status.to_string(),
+                    error,
+                });
+            }
+        }
+
+        Ok(summarize_ping(host, target, protocol, replies))
+    })
+    .await
+    .map_err(|e| format!("探测任务失败: {}", e))?
+}
+
+/// 路由追踪，每完成一跳通过 `traceroute-hop` 事件推送结果
+#[tauri::command]
+pub async fn traceroute(
+    app: AppHandle,
+    request: TracerouteRequest,
+) -> Result<TracerouteResult, String> {
+    let start = Instant::now();
+    let max_hops = request.max_hops.unwrap_or(30).clamp(1, 64);
+    let queries = request.queries_per_hop.unwrap_or(3).clamp(1, 10) as u16;
+    let timeout = Duration::from_millis(request.timeout_ms.unwrap_or(2000).clamp(100, 10000));
+    let resolve_names = request.resolve_names.unwrap_or(true);
+    let enrich = request.enrich.unwrap_or(true);
+
+    let host = request.host.trim().to_string();
+    let lookup_host = host.clone();
+    let ip_version = request.ip_version;
+    let target = tokio::task::spawn_blocking(move || resolve_target(&lookup_host, ip_version))
+        .await
+        .map_err(|e| format!("解析任务失败: {}", e))??;
+    let socket = Arc::new(IcmpSocket::open(target.is_ipv6())?);
+    let client = proxy::client_builder("network_probe")
+        .timeout(Duration::from_secs(5))
+        .build()
+        .map_err(|e| format!("构建 HTTP 客户端失败: {}", e))?;
+
+    let mut hops = Vec::new();
+    let mut reached = false;
+    let mut seq: u16 = 0;
+    for ttl in 1..=max_hops {
+        let base_seq = seq;
+        seq = seq.wrapping_add(queries);
+        let probe_socket = socket.clone();
+        let outcomes = tokio::task::spawn_blocking(move || {
+            probe_socket.set_ttl(ttl as u32)?;
+            (0..queries)
+                .map(|i| {
+                    probe_socket.probe(
+                        target,
+                        base_seq.wrapping_add(i),
+                        DEFAULT_PAYLOAD_SIZE,
+                        timeout,
+                    )
+                })
+                .collect::<Result<Vec<_>, String>>()
+        })
+        .await
+        .map_err(|e| format!("探测任务失败: {}", e))??;
+
+        let mut address: Option<IpAddr> = None;
+        let mut rtts = Vec::new();
+        let mut is_destination = false;
+        for outcome in outcomes {
+            match outcome {
+                ProbeOutcome::Reply { from, rtt, .. } => {
+                    address.get_or_insert(from);
+                    rtts.push(Some(to_ms(rtt)));
+                    is_destination = true;
+                }
+                ProbeOutcome::TimeExceeded { from, rtt } => {
+                    address.get_or_insert(from);
+                    rtts.push(Some(to_ms(rtt)));
+                }
+                ProbeOutcome::Unreachable { from, rtt } => {
+                    address.get_or_insert(from);
+                    rtts.push(Some(to_ms(rtt)));
+                    // 目标返回不可达也意味着已到达终点
+                    is_destination = true;
+                }
+                ProbeOutcome::Timeout => rtts.push(None),
+            }
+        }
+
+        let answered: Vec<f64> = rtts.iter().flatten().copied().collect();
+        let mut hop = TracerouteHop {
+            ttl,
+            address: address.map(|ip| ip.to_string()),
+            hostname: None,
+            avg_ms: (!answered.is_empty()).then(|| {
+                (answered.iter().sum::<f64>() / answered.len() as f64 * 1000.0).round() / 1000.0
+            }),
+            loss_percent: ((rtts.len() - answered.len()) as f64 / rtts.len() as f64 * 10000.0)
+                .round()
+                / 100.0,
+            rtts,
+            asn: None,
+            org: None,
+            location: None,
+            is_destination,
+        };
+
+        if let Some(ip) = address {
+            if resolve_names {
+                hop.hostname =
+                    tokio::task::spawn_blocking(move || dns_lookup::lookup_addr(&ip).ok())
+                        .await
+                        .ok()
+                        .flatten()
+                        .filter(|name| name != &ip.to_string());
+            }
+            if enrich && is_public_ip(&ip) {
+                if let Ok(info) = lookup_ip_network(&client, &ip.to_string()).await {
+                    hop.asn = info.asn;
+                    hop.org = info.as_name.or(info.org);
+                    let location: Vec<String> =
+                        [info.country, info.city].into_iter().flatten().collect();
+                    hop.location = (!location.is_empty()).then(|| location.join(" "));
+                }
+            }
+        }
+
+        let _ = app.emit("traceroute-hop", hop.clone());
+        hops.push(hop);
+        if is_destination {
+            reached = true;
+            break;
+        }
+    }
+
+    Ok(TracerouteResult {
+        host,
+        ip: target.to_string(),
+        hops,
+        reached,
+        elapsed_ms: start.elapsed().as_millis() as u64,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    fn test_socket(raw: bool, v6: bool) -> IcmpSocket {
+        let socket = Socket::new(Domain::IPV4, Type::DGRAM, None).unwrap();
+        let io: UdpSocket = socket.try_clone().unwrap().into();
+        IcmpSocket {
+            socket,
+            io,
+            raw,
+            v6,
+            ident: 0x1234,
+        }
+    }
+
+    fn ipv4_header(ttl: u8) -> Vec<u8> {
+        let mut header = vec![0u8; 20];
+        header[0] = 0x45;
+        header[8] = ttl;
+        header
+    }
+
+    fn reply(seq: u16) -> PingReply {
+        PingReply {
+            seq: seq as u32,
+            rtt_ms: None,
+            ttl: None,
+            from: None,
+            status: "timeout".to_string(),
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_checksum() {
+        // RFC 1071 示例
+        assert_eq!(
+            checksum(&[0x00, 0x01, 0xf2, 0x03, 0xf4, 0xf5, 0xf6, 0xf7]),
+            0x220d
+        );
+        // 奇数长度时末尾补零
+        assert_eq!(checksum(&[0x01]), !0x0100);
+    }
+
+    #[test]
+    fn test_build_echo() {
+        let socket = test_socket(true, false);
+        let packet = socket.build_echo(7, 4);
+        assert_eq!(packet.len(), 12);
+        assert_eq!(packet[0], 8);
+        assert_eq!(&packet[4..8], &[0x12, 0x34, 0x00, 0x07]);
+        assert_eq!(&packet[8..], &[0, 1, 2, 3]);
+        // 带校验和的报文重新计算结果为 0
+        assert_eq!(checksum(&packet), 0);
+
+        let packet = test_socket(false, true).build_echo(1, 0);
+        assert_eq!(packet[0], 128);
+        assert_eq!(&packet[2..4], &[0, 0]);
+    }
+
+    #[test]
+    fn test_parse_echo_reply() {
+        let from = IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1));
+        let rtt = Duration::from_millis(12);
+        let socket = test_socket(true, false);
+        let mut packet = ipv4_header(57);
+        packet.extend_from_slice(&[0, 0, 0, 0, 0x12, 0x34, 0x00, 0x05]);
+
+        match socket.parse_packet(&packet, from, 5, rtt) {
+            Some(ProbeOutcome::Reply { from: f, ttl, .. }) => {
+                assert_eq!(f, from);
+                assert_eq!(ttl, Some(57));
+            }
+            _ => panic!("应解析为回显应答"),
+        }
+        // 序号不匹配
+        assert!(socket.parse_packet(&packet, from, 6, rtt).is_none());
+        // RAW 套接字要求 identifier 一致，DGRAM 套接字只校验序号
+        packet[24] = 0x99;
+        assert!(socket.parse_packet(&packet, from, 5, rtt).is_none());
+        assert!(test_socket(false, false)
+            .parse_packet(&packet, from, 5, rtt)
+            .is_some());
+        // 报文过短
+        assert!(socket.parse_packet(&[0, 0, 0], from, 5, rtt).is_none());
+    }
+
+    #[test]
+    fn test_parse_icmp_errors() {
+        let from = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+        let rtt = Duration::from_millis(3);
+        let socket = test_socket(false, false);
+        // 差错报文：ICMP 头 + 原始 IP 头 + 原始 ICMP 头前 8 字节
+        let error_packet = |icmp_type: u8, seq: u16| {
+            let mut packet = vec![icmp_type, 0, 0, 0, 0, 0, 0, 0];
+            packet.extend(ipv4_header(1));
+            packet.extend_from_slice(&[8, 0, 0, 0, 0x12, 0x34]);
+            packet.extend_from_slice(&seq.to_be_bytes());
+            packet
+        };
+
+        assert!(matches!(
+            socket.parse_packet(&error_packet(11, 9), from, 9, rtt),
+            Some(ProbeOutcome::TimeExceeded { .. })
+        ));
+        assert!(matches!(
+            socket.parse_packet(&error_packet(3, 9), from, 9, rtt),
+            Some(ProbeOutcome::Unreachable { .. })
+        ));
+        assert!(socket
+            .parse_packet(&error_packet(11, 8), from, 9, rtt)
+            .is_none());
+        // 其他类型的报文忽略
+        assert!(socket
+            .parse_packet(&error_packet(5, 9), from, 9, rtt)
+            .is_none());
+
+        // ICMPv6 超时报文携带 40 字节的 IPv6 头
+        let v6 = test_socket(false, true);
+        let mut packet = vec![3, 0, 0, 0, 0, 0, 0, 0];
+        packet.extend(vec![0u8; 40]);
+        packet.extend_from_slice(&[128, 0, 0, 0, 0, 0, 0, 2]);
+        assert!(matches!(
+            v6.parse_packet(&packet, IpAddr::V6(Ipv6Addr::LOCALHOST), 2, rtt),
+            Some(ProbeOutcome::TimeExceeded { .. })
+        ));
+    }
+
+    #[test]
+    fn test_resolve_target_literals() {
+        assert_eq!(
+            resolve_target(" 192.0.2.1 ", None).unwrap(),
+            IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1))
+        );
+        assert_eq!(
+            resolve_target("[::1]", Some(4)).unwrap(),
+            IpAddr::V6(Ipv6Addr::LOCALHOST)
+        );
+        assert!(resolve_target("  ", None).is_err());
+    }
+
+    #[test]
+    fn test_is_public_ip() {
+        for ip in ["8.8.8.8", "2001:4860:4860::8888"] {
+            assert!(is_public_ip(&ip.parse().unwrap()), "{}", ip);
+        }
+        for ip in [
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "127.0.0.1",
+            "169.254.1.1",
+            "100.64.0.1",
+            "0.0.0.0",
+            "::1",
+            "fd00::1",
+            "fe80::1",
+        ] {
+            assert!(!is_public_ip(&ip.parse().unwrap()), "{}", ip);
+        }
+    }
+
+    #[test]
+    fn test_summarize_ping() {
+        let mut replies: Vec<PingReply> = (0..4).map(reply).collect();
+        for (reply, rtt) in replies.iter_mut().zip([10.0, 20.0, 30.0]) {
+            reply.status = "reply".to_string();
+            reply.rtt_ms = Some(rtt);
+        }
+        let result = summarize_ping(
+            "example.com".to_string(),
+            IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)),
+            "icmp".to_string(),
+            replies,
+        );
+        assert_eq!(result.transmitted, 4);
+        assert_eq!(result.received, 3);
+        assert_eq!(result.packet_loss, 25.0);
+        assert_eq!(result.min_ms, Some(10.0));
+        assert_eq!(result.avg_ms, Some(20.0));
+        assert_eq!(result.max_ms, Some(30.0));
+        assert_eq!(result.stddev_ms, Some(8.165));
+
+        let empty = summarize_ping(
+            "example.com".to_string(),
+            IpAddr::V4(Ipv4Addr::LOCALHOST),
+            "udp".to_string(),
+            vec![reply(0)],
+        );
+        assert_eq!(empty.packet_loss, 100.0);
+        assert!(empty.avg_ms.is_none());
+    }
+
+    #[test]
+    fn test_udp_probe_local() {
+        let server = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let address = server.local_addr().unwrap();
+        std::thread::spawn(move || {
+            let mut buf = [0u8; 64];
+            if let Ok((n, peer)) = server.recv_from(&mut buf) {
+                let _ = server.send_to(&buf[..n], peer);
+            }
+        });
+        let client = UdpSocket::bind("127.0.0.1:0").unwrap();
+        client.connect(address).unwrap();
+        assert!(udp_probe(&client, b"ping", Duration::from_secs(2))
+            .unwrap()
+            .is_some());
+
+        // 未响应的端口在超时后返回 None
+        let silent = UdpSocket::bind("127.0.0.1:0").unwrap();
+        client.connect(silent.local_addr().unwrap()).unwrap();
+        assert!(udp_probe(&client, b"ping", Duration::from_millis(100))
+            .unwrap()
+            .is_none());
+    }
+}
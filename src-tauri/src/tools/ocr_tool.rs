@@ -0,0 +1,459 @@
+use base64::{engine::general_purpose, Engine as _};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use tauri::{AppHandle, Manager};
+
+/// 默认识别语言。应用不附带语言数据，需要本机的 tesseract 已安装对应语言包
+const DEFAULT_LANGUAGES: [&str; 2] = ["eng", "chi_sim"];
+const TESSERACT_MISSING: &str =
+    "未找到 tesseract，文字识别依赖本机安装的 tesseract（如 brew install tesseract 或 apt install tesseract-ocr）";
+/// 剪贴板图片大小上限
+const MAX_IMAGE_BYTES: usize = 32 * 1024 * 1024;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct OcrRequest {
+    pub file_path: Option<String>,      // 图片文件路径
+    pub image_base64: Option<String>,   // 剪贴板图片，支持 data URL
+    pub languages: Option<Vec<String>>, // 默认 eng + chi_sim
+    pub psm: Option<u8>,                // 页面分割模式 0-13
+    pub with_boxes: Option<bool>,       // 是否返回单词与行的位置
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OcrBox {
+    pub left: u32,
+    pub top: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OcrWord {
+    pub text: String,
+    pub confidence: f32,
+    pub bbox: OcrBox,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OcrLine {
+    pub text: String,
+    pub confidence: f32,
+    pub bbox: OcrBox,
+    pub words: Vec<OcrWord>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OcrResult {
+    pub text: String,
+    pub languages: Vec<String>,
+    pub lines: Vec<OcrLine>, // 未请求位置信息时为空
+    pub mean_confidence: Option<f32>,
+    pub tessdata_dir: Option<String>,
+    pub warnings: Vec<String>, // 默认语言缺少语言包时的提示
+    pub elapsed_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OcrEnvironment {
+    pub available: bool,
+    pub version: Option<String>,
+    pub tessdata_dir: Option<String>,
+    pub languages: Vec<String>,
+    pub missing_languages: Vec<String>, // 默认语言中未安装的语言包
+    pub user_tessdata_dir: String,      // 可放置 .traineddata 文件的用户目录
+}
+
+/// 检查 tesseract 是否可用及已安装的语言包
+#[tauri::command]
+pub async fn get_ocr_environment(app: AppHandle) -> Result<OcrEnvironment, String> {
+    let tessdata_dir = resolve_tessdata_dir(&app);
+    let version = Command::new("tesseract")
+        .arg("--version")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| {
+            // 旧版本将版本信息输出到 stderr
+            let text = if o.stdout.is_empty() {
+                o.stderr
+            } else {
+                o.stdout
+            };
+            String::from_utf8_lossy(&text)
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .to_string()
+        });
+    let languages = if version.is_some() {
+        list_languages(tessdata_dir.as_deref()).unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+    Ok(OcrEnvironment {
+        available: version.is_some(),
+        version,
+        tessdata_dir: tessdata_dir.map(|d| d.to_string_lossy().to_string()),
+        missing_languages: DEFAULT_LANGUAGES
+            .iter()
+            .filter(|l| !languages.iter().any(|a| a == *l))
+            .map(|l| l.to_string())
+            .collect(),
+        languages,
+        user_tessdata_dir: user_tessdata_dir().to_string_lossy().to_string(),
+    })
+}
+
+/// 识别图片文件或剪贴板图片中的文字
+///
+/// 调用本机安装的 tesseract，语言包来自 tesseract 的默认目录或用户 tessdata 目录，
+/// 应用本身不附带语言数据，未安装 chi_sim 时只能识别英文。
+#[tauri::command]
+pub async fn extract_text_from_image(
+    app: AppHandle,
+    request: OcrRequest,
+) -> Result<OcrResult, String> {
+    let start = std::time::Instant::now();
+    if request.psm.is_some_and(|psm| psm > 13) {
+        return Err("页面分割模式取值范围为 0-13".to_string());
+    }
+    let tessdata_dir = resolve_tessdata_dir(&app);
+    let available = list_languages(tessdata_dir.as_deref())?;
+    let languages = select_languages(request.languages.as_deref(), &available)?;
+    let warnings: Vec<String> = match request.languages.as_deref() {
+        Some(requested) if !requested.is_empty() => Vec::new(),
+        _ => DEFAULT_LANGUAGES
+            .iter()
+            .filter(|l| !languages.iter().any(|s| s == *l))
+            .map(|l| format!("未安装 {} 语言包，已跳过。{}", l, install_hint(l)))
+            .collect(),
+    };
+
+    // 剪贴板图片先写入临时文件
+    let (image_path, temp_file) = match (&request.file_path, &request.image_base64) {
+        (Some(path), _) if !path.is_empty() => {
+            let path = PathBuf::from(path);
+            if !path.is_file() {
+                return Err(format!("图片文件不存在: {}", path.display()));
+            }
+            (path, None)
+        }
+        (_, Some(data)) if !data.is_empty() => {
+            let bytes = decode_image_data(data)?;
+            let path = std::env::temp_dir().join(format!(
+                "devtools-ocr-{}.png",
+                chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+            ));
+            fs::write(&path, bytes).map_err(|e| format!("写入临时图片失败: {}", e))?;
+            (path.clone(), Some(path))
+        }
+        _ => return Err("请提供图片文件或剪贴板图片".to_string()),
+    };
+
+    let with_boxes = request.with_boxes.unwrap_or(false);
+    let output = run_tesseract(
+        &image_path,
+        &languages,
+        request.psm,
+        tessdata_dir.as_deref(),
+        with_boxes,
+    );
+    if let Some(path) = temp_file {
+        let _ = fs::remove_file(path);
+    }
+    let output = output?;
+
+    let (text, lines) = if with_boxes {
+        let lines = parse_tsv(&output);
+        let text = lines
+            .iter()
+            .map(|l| l.text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+        (text, lines)
+    } else {
+        (output.trim().to_string(), Vec::new())
+    };
+    let words: Vec<&OcrWord> = lines.iter().flat_map(|l| &l.words).collect();
+    let mean_confidence = if words.is_empty() {
+        None
+    } else {
+        Some(words.iter().map(|w| w.confidence).sum::<f32>() / words.len() as f32)
+    };
+
+    Ok(OcrResult {
+        text,
+        languages,
+        lines,
+        mean_confidence,
+        tessdata_dir: tessdata_dir.map(|d| d.to_string_lossy().to_string()),
+        warnings,
+        elapsed_ms: start.elapsed().as_millis() as u64,
+    })
+}
+
+fn run_tesseract(
+    image_path: &Path,
+    languages: &[String],
+    psm: Option<u8>,
+    tessdata_dir: Option<&Path>,
+    with_boxes: bool,
+) -> Result<String, String> {
+    let mut command = Command::new("tesseract");
+    command.arg(image_path).arg("stdout");
+    if let Some(dir) = tessdata_dir {
+        command.arg("--tessdata-dir").arg(dir);
+    }
+    command.arg("-l").arg(languages.join("+"));
+    if let Some(psm) = psm {
+        command.arg("--psm").arg(psm.to_string());
+    }
+    // 中文之间不插入空格
+    if languages.iter().any(|l| l.starts_with("chi_")) {
+        command.arg("-c").arg("preserve_interword_spaces=1");
+    }
+    if with_boxes {
+        command.arg("tsv");
+    }
+    let output = command
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .map_err(|e| format!("{}: {}", TESSERACT_MISSING, e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "文字识别失败: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+fn list_languages(tessdata_dir: Option<&Path>) -> Result<Vec<String>, String> {
+    let mut command = Command::new("tesseract");
+    if let Some(dir) = tessdata_dir {
+        command.arg("--tessdata-dir").arg(dir);
+    }
+    let output = command
+        .arg("--list-langs")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .map_err(|e| format!("{}: {}", TESSERACT_MISSING, e))?;
+    // 首行为 "List of available languages ..." 提示
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .skip(1)
+        .map(|l| l.trim().to_string())
+        .filter(|l| !l.is_empty() && l != "osd")
+        .collect())
+}
+
+/// 安装包资源目录中放置了 tessdata 时优先使用（默认安装包不附带），其次是用户目录，
+/// 都没有语言包时使用 tesseract 的默认位置
+fn resolve_tessdata_dir(app: &AppHandle) -> Option<PathBuf> {
+    let bundled = app
+        .path()
+        .resource_dir()
+        .ok()
+        .map(|dir| dir.join("tessdata"));
+    [bundled, Some(user_tessdata_dir())]
+        .into_iter()
+        .flatten()
+        .find(|dir| has_traineddata(dir))
+}
+
+fn user_tessdata_dir() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("devtools")
+        .join("tessdata")
+}
+
+/// 缺少语言包时的安装说明
+fn install_hint(language: &str) -> String {
+    format!(
+        "请通过系统包管理器安装 tesseract 的 {0} 语言包（如 apt install tesseract-ocr-{1}），\
+         或将 {0}.traineddata 与其他所需语言包一起放入 {2}",
+        language,
+        language.replace('_', "-"),
+        user_tessdata_dir().display()
+    )
+}
+
+fn has_traineddata(dir: &Path) -> bool {
+    fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .any(|e| e.path().extension().is_some_and(|ext| ext == "traineddata"))
+        })
+        .unwrap_or(false)
+}
+
+/// 校验请求的语言是否已安装，未指定时使用默认语言中已安装的部分
+fn select_languages(
+    requested: Option<&[String]>,
+    available: &[String],
+) -> Result<Vec<String>, String> {
+    match requested.filter(|r| !r.is_empty()) {
+        Some(requested) => {
+            let missing: Vec<&str> = requested
+                .iter()
+                .filter(|l| !available.contains(l))
+                .map(|l| l.as_str())
+                .collect();
+            if !missing.is_empty() {
+                return Err(format!(
+                    "未安装语言包: {}。{}",
+                    missing.join(", "),
+                    install_hint(missing[0])
+                ));
+            }
+            Ok(requested.to_vec())
+        }
+        None => {
+            let selected: Vec<String> = DEFAULT_LANGUAGES
+                .iter()
+                .filter(|l| available.iter().any(|a| a == *l))
+                .map(|l| l.to_string())
+                .collect();
+            if selected.is_empty() {
+                return Err(format!(
+                    "未找到 eng 或 chi_sim 语言包。{}",
+                    install_hint("chi_sim")
+                ));
+            }
+            Ok(selected)
+        }
+    }
+}
+
+fn decode_image_data(data: &str) -> Result<Vec<u8>, String> {
+    let encoded = match data.split_once(";base64,") {
+        Some((prefix, rest)) if prefix.starts_with("data:") => rest,
+        _ => data,
+    };
+    let bytes = general_purpose::STANDARD
+        .decode(encoded.trim())
+        .map_err(|e| format!("图片数据不是有效的 Base64: {}", e))?;
+    if bytes.len() > MAX_IMAGE_BYTES {
+        return Err("图片过大".to_string());
+    }
+    Ok(bytes)
+}
+
+/// 解析 tesseract 的 TSV 输出，按行聚合单词
+fn parse_tsv(tsv: &str) -> Vec<OcrLine> {
+    let mut lines: Vec<((u32, u32, u32, u32), OcrLine)> = Vec::new();
+    let cjk =
+        |c: char| ('\u{3000}'..='\u{9fff}').contains(&c) || ('\u{ff00}'..='\u{ffef}').contains(&c);
+
+    for row in tsv.lines().skip(1) {
+        let cols: Vec<&str> = row.split('\t').collect();
+        if cols.len() < 12 || cols[0] != "5" {
+            continue;
+        }
+        let text = cols[11].trim();
+        let confidence: f32 = cols[10].parse().unwrap_or(-1.0);
+        if text.is_empty() || confidence < 0.0 {
+            continue;
+        }
+        let num = |i: usize| cols[i].parse::<u32>().unwrap_or(0);
+        let key = (num(1), num(2), num(3), num(4));
+        let word = OcrWord {
+            text: text.to_string(),
+            confidence,
+            bbox: OcrBox {
+                left: num(6),
+                top: num(7),
+                width: num(8),
+                height: num(9),
+            },
+        };
+
+        match lines.last_mut().filter(|(k, _)| *k == key) {
+            Some((_, line)) => {
+                // 中文词之间不加空格
+                let joins_cjk = line.text.chars().last().is_some_and(cjk)
+                    && word.text.chars().next().is_some_and(cjk);
+                if !joins_cjk {
+                    line.text.push(' ');
+                }
+                line.text.push_str(&word.text);
+                let right =
+                    (line.bbox.left + line.bbox.width).max(word.bbox.left + word.bbox.width);
+                let bottom =
+                    (line.bbox.top + line.bbox.height).max(word.bbox.top + word.bbox.height);
+                line.bbox.left = line.bbox.left.min(word.bbox.left);
+                line.bbox.top = line.bbox.top.min(word.bbox.top);
+                line.bbox.width = right - line.bbox.left;
+                line.bbox.height = bottom - line.bbox.top;
+                line.words.push(word);
+            }
+            None => lines.push((
+                key,
+                OcrLine {
+                    text: word.text.clone(),
+                    confidence: 0.0,
+                    bbox: word.bbox.clone(),
+                    words: vec![word],
+                },
+            )),
+        }
+    }
+
+    lines
+        .into_iter()
+        .map(|(_, mut line)| {
+            line.confidence =
+                line.words.iter().map(|w| w.confidence).sum::<f32>() / line.words.len() as f32;
+            line
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tsv() {
+        let tsv = "level\tpage_num\tblock_num\tpar_num\tline_num\tword_num\tleft\ttop\twidth\theight\tconf\ttext\n\
+                   1\t1\t0\t0\t0\t0\t0\t0\t800\t600\t-1\t\n\
+                   5\t1\t1\t1\t1\t1\t10\t20\t50\t12\t96.5\tHello\n\
+                   5\t1\t1\t1\t1\t2\t70\t18\t60\t14\t91.5\tworld\n\
+                   5\t1\t1\t1\t2\t1\t10\t40\t20\t12\t88\t你好\n\
+                   5\t1\t1\t1\t2\t2\t32\t40\t20\t12\t90\t世界\n";
+        let lines = parse_tsv(tsv);
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].text, "Hello world");
+        assert_eq!(lines[0].confidence, 94.0);
+        assert_eq!(lines[0].bbox.left, 10);
+        assert_eq!(lines[0].bbox.top, 18);
+        assert_eq!(lines[0].bbox.width, 120);
+        assert_eq!(lines[0].bbox.height, 14);
+        assert_eq!(lines[1].text, "你好世界");
+        assert_eq!(lines[1].words.len(), 2);
+    }
+
+    #[test]
+    fn test_select_languages_and_decode() {
+        let available = vec!["eng".to_string(), "jpn".to_string()];
+        assert_eq!(select_languages(None, &available).unwrap(), vec!["eng"]);
+        let err = select_languages(Some(&["chi_sim".to_string()]), &available).unwrap_err();
+        assert!(err.starts_with("未安装语言包: chi_sim。"));
+        assert!(err.contains("tesseract-ocr-chi-sim"));
+        assert!(err.contains("chi_sim.traineddata"));
+        assert!(select_languages(None, &["jpn".to_string()]).is_err());
+
+        let bytes = decode_image_data("data:image/png;base64,iVBORw0KGgo=").unwrap();
+        assert_eq!(&bytes[1..4], b"PNG");
+        assert!(decode_image_data("not base64!").is_err());
+    }
+}
@@ -0,0 +1,565 @@
+use crate::utils::proxy;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::time::Duration;
+use tokio::task::JoinSet;
+
+/// 默认返回的版本数
+const DEFAULT_VERSION_LIMIT: usize = 20;
+/// Go 模块需要逐个版本请求发布时间，限制并发数量
+const MAX_GO_INFO_REQUESTS: usize = 50;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LookupPackageRequest {
+    pub ecosystem: String, // go, cargo, npm
+    pub name: String,
+    pub limit: Option<usize>,             // 返回的版本数，默认 20
+    pub include_prerelease: Option<bool>, // 是否包含预发布版本，默认 false
+    pub compare_from: Option<String>,     // 与 compare_to 一起指定时对比两个版本
+    pub compare_to: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageVersion {
+    pub version: String,
+    pub published_at: Option<String>,
+    pub prerelease: bool,
+    pub yanked: bool, // crates.io 的 yanked 或 npm 的 deprecated
+    pub license: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetadataChange {
+    pub field: String,
+    pub from: Option<String>,
+    pub to: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VersionDiff {
+    pub from: String,
+    pub to: String,
+    pub versions_between: usize, // 两个版本之间发布的版本数
+    pub days_between: Option<i64>,
+    pub changes: Vec<MetadataChange>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageInfo {
+    pub ecosystem: String,
+    pub name: String,
+    pub latest_version: Option<String>, // 最新稳定版本，没有稳定版本时为最新版本
+    pub description: Option<String>,
+    pub license: Option<String>,
+    pub homepage: Option<String>,
+    pub repository: Option<String>,
+    pub registry_url: String,
+    pub total_versions: usize,
+    pub versions: Vec<PackageVersion>, // 按版本号从新到旧排列
+    pub diff: Option<VersionDiff>,
+}
+
+/// 单个版本的元数据，用于版本对比
+#[derive(Debug, Clone, Default)]
+struct VersionMeta {
+    version: String,
+    published_at: Option<String>,
+    yanked: bool,
+    license: Option<String>,
+    fields: BTreeMap<String, String>, // 参与对比的其他字段
+}
+
+/// 查询 Go 模块、Cargo crate 或 npm 包的版本、许可证和发布时间
+#[tauri::command]
+pub async fn lookup_package(request: LookupPackageRequest) -> Result<PackageInfo, String> {
+    let name = request.name.trim().to_string();
+    if name.is_empty() {
+        return Err("包名不能为空".to_string());
+    }
+    let client = proxy::client_builder("package_lookup")
+        .timeout(Duration::from_secs(15))
+        .user_agent(concat!("devtools/", env!("CARGO_PKG_VERSION")))
+        .build()
+        .map_err(|e| format!("构建 HTTP 客户端失败: {}", e))?;
+
+    let compare = match (&request.compare_from, &request.compare_to) {
+        (Some(from), Some(to)) if !from.trim().is_empty() && !to.trim().is_empty() => {
+            Some((from.trim().to_string(), to.trim().to_string()))
+        }
+        _ => None,
+    };
+
+    let (mut info, mut metas) = match request.ecosystem.to_lowercase().as_str() {
+        "cargo" | "crates" | "rust" => lookup_crate(&client, &name).await?,
+        "npm" | "node" => lookup_npm(&client, &name).await?,
+        "go" | "golang" => lookup_go_module(&client, &name, compare.as_ref()).await?,
+        _ => return Err(format!("不支持的包生态: {}", request.ecosystem)),
+    };
+    metas.sort_by(|a, b| compare_versions(&b.version, &a.version));
+
+    info.total_versions = metas.len();
+    info.latest_version = metas
+        .iter()
+        .find(|m| !m.yanked && !is_prerelease(&m.version))
+        .or_else(|| metas.first())
+        .map(|m| m.version.clone());
+    if info.license.is_none() {
+        info.license = metas.first().and_then(|m| m.license.clone());
+    }
+    if let Some((from, to)) = &compare {
+        info.diff = Some(diff_versions(&metas, from, to)?);
+    }
+
+    let include_prerelease = request.include_prerelease.unwrap_or(false);
+    info.versions = metas
+        .into_iter()
+        .filter(|m| include_prerelease || !is_prerelease(&m.version))
+        .take(request.limit.unwrap_or(DEFAULT_VERSION_LIMIT).max(1))
+        .map(|m| PackageVersion {
+            prerelease: is_prerelease(&m.version),
+            version: m.version,
+            published_at: m.published_at,
+            yanked: m.yanked,
+            license: m.license,
+        })
+        .collect();
+    Ok(info)
+}
+
+async fn fetch_json(client: &reqwest::Client, url: &str) -> Result<Value, String> {
+    let response = client
+        .get(url)
+        .header("Accept", "application/json")
+        .send()
+        .await
+        .map_err(|e| format!("请求 {} 失败: {}", url, e))?;
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Err("包不存在".to_string());
+    }
+    if !response.status().is_success() {
+        return Err(format!("仓库响应状态异常: {}", response.status()));
+    }
+    response
+        .json()
+        .await
+        .map_err(|e| format!("解析仓库响应失败: {}", e))
+}
+
+async fn fetch_text(client: &reqwest::Client, url: &str) -> Result<String, String> {
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| format!("请求 {} 失败: {}", url, e))?;
+    if matches!(response.status().as_u16(), 404 | 410) {
+        return Err("模块或版本不存在".to_string());
+    }
+    if !response.status().is_success() {
+        return Err(format!("模块代理响应状态异常: {}", response.status()));
+    }
+    response
+        .text()
+        .await
+        .map_err(|e| format!("读取模块代理响应失败: {}", e))
+}
+
+async fn lookup_crate(
+    client: &reqwest::Client,
+    name: &str,
+) -> Result<(PackageInfo, Vec<VersionMeta>), String> {
+    let url = format!("https://crates.io/api/v1/crates/{}", name);
+    let body = fetch_json(client, &url).await?;
+    let krate = &body["crate"];
+    let metas = body["versions"]
+        .as_array()
+        .map(|versions| versions.iter().map(crate_version_meta).collect())
+        .unwrap_or_default();
+
+    let info = PackageInfo {
+        ecosystem: "cargo".to_string(),
+        name: krate["name"].as_str().unwrap_or(name).to_string(),
+        latest_version: None,
+        description: string_field(krate, "description"),
+        license: None,
+        homepage: string_field(krate, "homepage"),
+        repository: string_field(krate, "repository"),
+        registry_url: format!("https://crates.io/crates/{}", name),
+        total_versions: 0,
+        versions: Vec::new(),
+        diff: None,
+    };
+    Ok((info, metas))
+}
+
+fn crate_version_meta(version: &Value) -> VersionMeta {
+    let mut fields = BTreeMap::new();
+    if let Some(rust_version) = string_field(version, "rust_version") {
+        fields.insert("rust_version".to_string(), rust_version);
+    }
+    if let Some(edition) = string_field(version, "edition") {
+        fields.insert("edition".to_string(), edition);
+    }
+    if let Some(size) = version["crate_size"].as_u64() {
+        fields.insert("crate_size".to_string(), size.to_string());
+    }
+    if let Some(features) = version["features"].as_object() {
+        let names: Vec<&str> = features.keys().map(|k| k.as_str()).collect();
+        fields.insert("features".to_string(), names.join(", "));
+    }
+    VersionMeta {
+        version: version["num"].as_str().unwrap_or_default().to_string(),
+        published_at: string_field(version, "created_at"),
+        yanked: version["yanked"].as_bool().unwrap_or(false),
+        license: string_field(version, "license"),
+        fields,
+    }
+}
+
+async fn lookup_npm(
+    client: &reqwest::Client,
+    name: &str,
+) -> Result<(PackageInfo, Vec<VersionMeta>), String> {
+    // 作用域包的 / 需要转义
+    let url = format!("https://registry.npmjs.org/{}", name.replace('/', "%2F"));
+    let body = fetch_json(client, &url).await?;
+    let latest_tag = body["dist-tags"]["latest"].as_str();
+    let metas: Vec<VersionMeta> = body["versions"]
+        .as_object()
+        .map(|versions| {
+            versions
+                .iter()
+                .map(|(version, manifest)| npm_version_meta(version, manifest, &body["time"]))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let repository = match &body["repository"] {
+        Value::String(url) => Some(url.clone()),
+        other => string_field(other, "url"),
+    };
+    let info = PackageInfo {
+        ecosystem: "npm".to_string(),
+        name: body["name"].as_str().unwrap_or(name).to_string(),
+        latest_version: None,
+        description: string_field(&body, "description"),
+        license: latest_tag
+            .and_then(|tag| metas.iter().find(|m| m.version == tag))
+            .and_then(|m| m.license.clone())
+            .or_else(|| string_field(&body, "license")),
+        homepage: string_field(&body, "homepage"),
+        repository: repository.map(|r| r.trim_start_matches("git+").to_string()),
+        registry_url: format!("https://www.npmjs.com/package/{}", name),
+        total_versions: 0,
+        versions: Vec::new(),
+        diff: None,
+    };
+    Ok((info, metas))
+}
+
+fn npm_version_meta(version: &str, manifest: &Value, times: &Value) -> VersionMeta {
+    let mut fields = BTreeMap::new();
+    if let Some(node) = manifest["engines"]["node"].as_str() {
+        fields.insert("engines.node".to_string(), node.to_string());
+    }
+    for section in ["dependencies", "peerDependencies"] {
+        if let Some(deps) = manifest[section].as_object() {
+            for (dep, range) in deps {
+                fields.insert(
+                    format!("{}.{}", section, dep),
+                    range.as_str().unwrap_or_default().to_string(),
+                );
+            }
+        }
+    }
+    // 旧版本的 license 可能是 { "type": "MIT" } 形式
+    let license = match &manifest["license"] {
+        Value::String(license) => Some(license.clone()),
+        other => string_field(other, "type"),
+    };
+    VersionMeta {
+        version: version.to_string(),
+        published_at: string_field(times, version),
+        yanked: manifest.get("deprecated").is_some_and(|d| !d.is_null()),
+        license,
+        fields,
+    }
+}
+
+async fn lookup_go_module(
+    client: &reqwest::Client,
+    module: &str,
+    compare: Option<&(String, String)>,
+) -> Result<(PackageInfo, Vec<VersionMeta>), String> {
+    let base = format!("https://proxy.golang.org/{}", escape_module_path(module));
+    let list = fetch_text(client, &format!("{}/@v/list", base)).await?;
+    let mut versions: Vec<String> = list
+        .lines()
+        .map(|l| l.trim().to_string())
+        .filter(|l| !l.is_empty())
+        .collect();
+    if versions.is_empty() {
+        // 只有伪版本的模块 list 为空，取 @latest
+        let latest = fetch_json(client, &format!("{}/@latest", base)).await?;
+        if let Some(version) = latest["Version"].as_str() {
+            versions.push(version.to_string());
+        }
+    }
+    versions.sort_by(|a, b| compare_versions(b, a));
+
+    // 发布时间需要逐个请求 .info，只取最新的一部分和对比的两个版本
+    let mut wanted: Vec<String> = versions
+        .iter()
+        .take(MAX_GO_INFO_REQUESTS)
+        .cloned()
+        .collect();
+    if let Some((from, to)) = compare {
+        wanted.extend([from.clone(), to.clone()]);
+    }
+    wanted.sort();
+    wanted.dedup();
+    let fetch_mod = compare.is_some();
+    let mut tasks = JoinSet::new();
+    for version in wanted {
+        let client = client.clone();
+        let base = base.clone();
+        tasks.spawn(async move {
+            let info = fetch_json(&client, &format!("{}/@v/{}.info", base, version)).await;
+            let go_mod = if fetch_mod {
+                fetch_text(&client, &format!("{}/@v/{}.mod", base, version))
+                    .await
+                    .ok()
+            } else {
+                None
+            };
+            (version, info.ok(), go_mod)
+        });
+    }
+    let mut details = BTreeMap::new();
+    for (version, info, go_mod) in tasks.join_all().await {
+        details.insert(version, (info, go_mod));
+    }
+
+    let metas = versions
+        .into_iter()
+        .map(|version| {
+            let mut meta = VersionMeta {
+                version: version.clone(),
+                ..Default::default()
+            };
+            if let Some((info, go_mod)) = details.get(&version) {
+                meta.published_at = info.as_ref().and_then(|i| string_field(i, "Time"));
+                if let Some(go_mod) = go_mod {
+                    meta.fields = parse_go_mod(go_mod);
+                }
+            }
+            meta
+        })
+        .collect();
+
+    let info = PackageInfo {
+        ecosystem: "go".to_string(),
+        name: module.to_string(),
+        latest_version: None,
+        description: None,
+        license: None, // 模块代理不提供许可证信息
+        homepage: Some(format!("https://pkg.go.dev/{}", module)),
+        repository: None,
+        registry_url: format!("https://pkg.go.dev/{}", module),
+        total_versions: 0,
+        versions: Vec::new(),
+        diff: None,
+    };
+    Ok((info, metas))
+}
+
+/// 模块代理要求大写字母转义为 ! 加小写字母
+fn escape_module_path(module: &str) -> String {
+    let mut escaped = String::with_capacity(module.len());
+    for c in module.chars() {
+        if c.is_ascii_uppercase() {
+            escaped.push('!');
+            escaped.push(c.to_ascii_lowercase());
+        } else {
+            escaped.push(c);
+        }
+    }
+    escaped
+}
+
+/// 提取 go.mod 中的 go 指令和 require 依赖
+fn parse_go_mod(content: &str) -> BTreeMap<String, String> {
+    let mut fields = BTreeMap::new();
+    let mut in_require = false;
+    for line in content.lines() {
+        let line = line.split("//").next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+        if in_require {
+            if line == ")" {
+                in_require = false;
+            } else if let Some((module, version)) = line.split_once(char::is_whitespace) {
+                fields.insert(format!("require.{}", module), version.trim().to_string());
+            }
+            continue;
+        }
+        if let Some(version) = line.strip_prefix("go ") {
+            fields.insert("go".to_string(), version.trim().to_string());
+        } else if let Some(version) = line.strip_prefix("toolchain ") {
+            fields.insert("toolchain".to_string(), version.trim().to_string());
+        } else if line == "require (" {
+            in_require = true;
+        } else if let Some(rest) = line.strip_prefix("require ") {
+            if let Some((module, version)) = rest.trim().split_once(char::is_whitespace) {
+                fields.insert(format!("require.{}", module), version.trim().to_string());
+            }
+        }
+    }
+    fields
+}
+
+fn diff_versions(metas: &[VersionMeta], from: &str, to: &str) -> Result<VersionDiff, String> {
+    let find = |version: &str| {
+        metas
+            .iter()
+            .position(|m| m.version == version || m.version.trim_start_matches('v') == version)
+            .ok_or_else(|| format!("版本不存在: {}", version))
+    };
+    let (from_index, to_index) = (find(from)?, find(to)?);
+    let (a, b) = (&metas[from_index], &metas[to_index]);
+
+    let mut changes = Vec::new();
+    if a.license != b.license {
+        changes.push(MetadataChange {
+            field: "license".to_string(),
+            from: a.license.clone(),
+            to: b.license.clone(),
+        });
+    }
+    let keys: std::collections::BTreeSet<&String> =
+        a.fields.keys().chain(b.fields.keys()).collect();
+    for key in keys {
+        let (old, new) = (a.fields.get(key), b.fields.get(key));
+        if old != new {
+            changes.push(MetadataChange {
+                field: key.clone(),
+                from: old.cloned(),
+                to: new.cloned(),
+            });
+        }
+    }
+
+    let parse_time = |value: &Option<String>| {
+        value
+            .as_deref()
+            .and_then(|v| chrono::DateTime::parse_from_rfc3339(v).ok())
+    };
+    let days_between = match (parse_time(&a.published_at), parse_time(&b.published_at)) {
+        (Some(x), Some(y)) => Some((y - x).num_days()),
+        _ => None,
+    };
+    Ok(VersionDiff {
+        from: a.version.clone(),
+        to: b.version.clone(),
+        versions_between: from_index.abs_diff(to_index).saturating_sub(1),
+        days_between,
+        changes,
+    })
+}
+
+/// 按语义化版本比较，无法解析的版本按字符串比较并排在后面
+fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
+    let parse = |v: &str| semver::Version::parse(v.trim_start_matches('v')).ok();
+    match (parse(a), parse(b)) {
+        (Some(x), Some(y)) => x.cmp(&y),
+        (Some(_), None) => std::cmp::Ordering::Greater,
+        (None, Some(_)) => std::cmp::Ordering::Less,
+        (None, None) => a.cmp(b),
+    }
+}
+
+fn is_prerelease(version: &str) -> bool {
+    semver::Version::parse(version.trim_start_matches('v'))
+        .map(|v| !v.pre.is_empty())
+        .unwrap_or(false)
+}
+
+fn string_field(value: &Value, key: &str) -> Option<String> {
+    value[key]
+        .as_str()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_go_module_helpers() {
+        assert_eq!(
+            escape_module_path("github.com/BurntSushi/toml"),
+            "github.com/!burnt!sushi/toml"
+        );
+        let fields = parse_go_mod(
+            "module example.com/a\n\ngo 1.21\ntoolchain go1.22.1\n\nrequire golang.org/x/text v0.14.0 // indirect\nrequire (\n\tgithub.com/pkg/errors v0.9.1\n)\n",
+        );
+        assert_eq!(fields["go"], "1.21");
+        assert_eq!(fields["toolchain"], "go1.22.1");
+        assert_eq!(fields["require.golang.org/x/text"], "v0.14.0");
+        assert_eq!(fields["require.github.com/pkg/errors"], "v0.9.1");
+
+        let mut versions = [
+            "v1.10.0",
+            "v1.2.0",
+            "v1.10.0-rc.1",
+            "v0.0.0-20240101000000-abcdef123456",
+        ];
+        versions.sort_by(|a, b| compare_versions(b, a));
+        assert_eq!(versions[0], "v1.10.0");
+        assert_eq!(versions[1], "v1.10.0-rc.1");
+        assert!(is_prerelease("v0.0.0-20240101000000-abcdef123456"));
+    }
+
+    #[test]
+    fn test_diff_npm_versions() {
+        let times = serde_json::json!({
+            "1.0.0": "2023-01-01T00:00:00.000Z",
+            "2.0.0": "2023-03-02T00:00:00.000Z"
+        });
+        let old = serde_json::json!({
+            "license": { "type": "MIT" },
+            "dependencies": { "lodash": "^4.17.0", "debug": "^2.0.0" }
+        });
+        let new = serde_json::json!({
+            "license": "Apache-2.0",
+            "engines": { "node": ">=18" },
+            "dependencies": { "lodash": "^4.17.21" },
+            "deprecated": "use v3"
+        });
+        let mut metas = vec![
+            npm_version_meta("1.0.0", &old, &times),
+            npm_version_meta("1.5.0", &old, &times),
+            npm_version_meta("2.0.0", &new, &times),
+        ];
+        metas.sort_by(|a, b| compare_versions(&b.version, &a.version));
+        assert!(metas[0].yanked);
+
+        let diff = diff_versions(&metas, "1.0.0", "2.0.0").unwrap();
+        assert_eq!(diff.versions_between, 1);
+        assert_eq!(diff.days_between, Some(60));
+        let fields: Vec<&str> = diff.changes.iter().map(|c| c.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            vec![
+                "license",
+                "dependencies.debug",
+                "dependencies.lodash",
+                "engines.node"
+            ]
+        );
+        assert_eq!(diff.changes[0].from.as_deref(), Some("MIT"));
+        assert!(diff_versions(&metas, "1.0.0", "9.9.9").is_err());
+    }
+}
@@ -0,0 +1,487 @@
+use lopdf::{Document, Object, ObjectId};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 文档信息字典中常见的元数据字段
+const INFO_KEYS: &[&str] = &[
+    "Title",
+    "Author",
+    "Subject",
+    "Keywords",
+    "Creator",
+    "Producer",
+    "CreationDate",
+    "ModDate",
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PdfInfo {
+    pub path: String,
+    pub version: String,
+    pub page_count: usize,
+    pub file_size: u64,
+    pub encrypted: bool,
+    pub metadata: BTreeMap<String, String>,
+    pub has_xmp_metadata: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PdfOutput {
+    pub path: String,
+    pub page_count: usize,
+    pub file_size: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergePdfRequest {
+    pub inputs: Vec<String>,
+    pub output: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SplitPdfRequest {
+    pub path: String,
+    pub output_dir: String,
+    pub ranges: Option<Vec<String>>, // 每个范围生成一个文件，如 "1-3,5"；为空时按页拆分
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PdfPageText {
+    pub page: u32,
+    pub text: String,
+}
+
+/// 读取 PDF 的版本、页数和元数据
+#[tauri::command]
+pub async fn get_pdf_info(path: String) -> Result<PdfInfo, String> {
+    tokio::task::spawn_blocking(move || {
+        let doc = load_document(&path)?;
+        let (metadata, has_xmp_metadata) = read_metadata(&doc);
+        Ok(PdfInfo {
+            version: doc.version.clone(),
+            page_count: doc.get_pages().len(),
+            file_size: file_size(&path),
+            encrypted: doc.is_encrypted(),
+            metadata,
+            has_xmp_metadata,
+            path,
+        })
+    })
+    .await
+    .map_err(|e| format!("读取 PDF 任务失败: {}", e))?
+}
+
+/// 按顺序合并多个 PDF
+#[tauri::command]
+pub async fn merge_pdfs(request: MergePdfRequest) -> Result<PdfOutput, String> {
+    tokio::task::spawn_blocking(move || {
+        if request.inputs.len() < 2 {
+            return Err("请至少选择两个 PDF 文件".to_string());
+        }
+        let documents = request
+            .inputs
+            .iter()
+            .map(|path| load_document(path))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut merged = merge_documents(documents)?;
+        save_document(&mut merged, &request.output)
+    })
+    .await
+    .map_err(|e| format!("合并 PDF 任务失败: {}", e))?
+}
+
+/// 按页码范围拆分 PDF
+#[tauri::command]
+pub async fn split_pdf(request: SplitPdfRequest) -> Result<Vec<PdfOutput>, String> {
+    tokio::task::spawn_blocking(move || {
+        let doc = load_document(&request.path)?;
+        let total = doc.get_pages().len() as u32;
+        let groups = match request.ranges.as_ref().filter(|r| !r.is_empty()) {
+            Some(ranges) => ranges
+                .iter()
+                .map(|range| parse_page_ranges(range, total))
+                .collect::<Result<Vec<_>, _>>()?,
+            None => (1..=total).map(|page| vec![page]).collect(),
+        };
+
+        let stem = Path::new(&request.path)
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("document")
+            .to_string();
+        fs::create_dir_all(&request.output_dir).map_err(|e| format!("创建输出目录失败: {}", e))?;
+
+        let mut outputs = Vec::with_capacity(groups.len());
+        for pages in groups {
+            let mut part = extract_pages(&doc, &pages);
+            let suffix = if pages.len() == 1 {
+                pages[0].to_string()
+            } else {
+                format!("{}-{}", pages[0], pages[pages.len() - 1])
+            };
+            let output =
+                PathBuf::from(&request.output_dir).join(format!("{}_{}.pdf", stem, suffix));
+            outputs.push(save_document(&mut part, &output.to_string_lossy())?);
+        }
+        Ok(outputs)
+    })
+    .await
+    .map_err(|e| format!("拆分 PDF 任务失败: {}", e))?
+}
+
+/// 提取文本，pages 为空时提取全部页
+#[tauri::command]
+pub async fn extract_pdf_text(
+    path: String,
+    pages: Option<String>,
+) -> Result<Vec<PdfPageText>, String> {
+    tokio::task::spawn_blocking(move || {
+        let doc = load_document(&path)?;
+        let total = doc.get_pages().len() as u32;
+        let pages = match pages.as_deref().map(str::trim).filter(|p| !p.is_empty()) {
+            Some(ranges) => parse_page_ranges(ranges, total)?,
+            None => (1..=total).collect(),
+        };
+
+        Ok(pages
+            .into_iter()
+            .map(|page| PdfPageText {
+                page,
+                // 单页字体解析失败时不影响其他页
+                text: doc.extract_text(&[page]).unwrap_or_default(),
+            })
+            .collect())
+    })
+    .await
+    .map_err(|e| format!("提取文本任务失败: {}", e))?
+}
+
+/// 移除文档信息字典和 XMP 元数据后另存
+#[tauri::command]
+pub async fn strip_pdf_metadata(path: String, output: String) -> Result<PdfOutput, String> {
+    tokio::task::spawn_blocking(move || {
+        let mut doc = load_document(&path)?;
+        strip_metadata(&mut doc);
+        save_document(&mut doc, &output)
+    })
+    .await
+    .map_err(|e| format!("清除元数据任务失败: {}", e))?
+}
+
+fn load_document(path: &str) -> Result<Document, String> {
+    let doc = Document::load(path).map_err(|e| format!("读取 PDF 失败 {}: {}", path, e))?;
+    if doc.is_encrypted() {
+        return Err(format!("{} 已加密，暂不支持处理加密的 PDF", path));
+    }
+    Ok(doc)
+}
+
+fn save_document(doc: &mut Document, path: &str) -> Result<PdfOutput, String> {
+    doc.compress();
+    doc.save(path)
+        .map_err(|e| format!("保存 PDF 失败 {}: {}", path, e))?;
+    Ok(PdfOutput {
+        path: path.to_string(),
+        page_count: doc.get_pages().len(),
+        file_size: file_size(path),
+    })
+}
+
+fn file_size(path: &str) -> u64 {
+    fs::metadata(path).map(|m| m.len()).unwrap_or(0)
+}
+
+/// 解析 "1-3,5,8-" 形式的页码范围，结果按出现顺序去重
+fn parse_page_ranges(input: &str, total: u32) -> Result<Vec<u32>, String> {
+    let mut pages = Vec::new();
+    let mut seen = BTreeSet::new();
+    let parse = |s: &str| {
+        s.trim()
+            .parse::<u32>()
+            .map_err(|_| format!("无效的页码: {}", s.trim()))
+    };
+    // 省略的起止页分别取首页和末页
+    let bound = |s: &str, default: u32| {
+        if s.trim().is_empty() {
+            Ok(default)
+        } else {
+            parse(s)
+        }
+    };
+
+    for part in input.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let (start, end) = match part.split_once('-') {
+            Some((start, end)) => (bound(start, 1)?, bound(end, total)?),
+            None => (parse(part)?, parse(part)?),
+        };
+        if start == 0 || end > total || start > end {
+            return Err(format!("页码范围 {} 超出文档页数 {}", part, total));
+        }
+        for page in start..=end {
+            if seen.insert(page) {
+                pages.push(page);
+            }
+        }
+    }
+
+    if pages.is_empty() {
+        return Err("请输入页码范围".to_string());
+    }
+    Ok(pages)
+}
+
+fn read_metadata(doc: &Document) -> (BTreeMap<String, String>, bool) {
+    let mut metadata = BTreeMap::new();
+    let info = doc
+        .trailer
+        .get(b"Info")
+        .and_then(|info| doc.dereference(info))
+        .and_then(|(_, info)| info.as_dict());
+    if let Ok(info) = info {
+        for (key, value) in info.iter() {
+            let key = String::from_utf8_lossy(key).to_string();
+            let value = match doc.dereference(value) {
+                Ok((_, value)) => value,
+                Err(_) => continue,
+            };
+            let text = lopdf::decode_text_string(value)
+                .or_else(|_| value.as_name_str().map(str::to_string))
+                .unwrap_or_else(|_| format!("{:?}", value));
+            metadata.insert(key, text);
+        }
+    }
+
+    for key in INFO_KEYS {
+        metadata.entry(key.to_string()).or_default();
+    }
+    let has_xmp = doc.catalog().map(|c| c.has(b"Metadata")).unwrap_or(false);
+    (metadata, has_xmp)
+}
+
+fn strip_metadata(doc: &mut Document) {
+    doc.trailer.remove(b"Info");
+    if let Ok(catalog) = doc.catalog_mut() {
+        catalog.remove(b"Metadata");
+    }
+    // 页面级别的 XMP 也一并移除
+    for page_id in doc.get_pages().into_values() {
+        if let Ok(page) = doc.get_dictionary_mut(page_id) {
+            page.remove(b"Metadata");
+        }
+    }
+    doc.prune_objects();
+}
+
+/// 复制文档并只保留指定页
+fn extract_pages(doc: &Document, keep: &[u32]) -> Document {
+    let mut part = doc.clone();
+    let keep: BTreeSet<u32> = keep.iter().copied().collect();
+    let remove: Vec<u32> = part
+        .get_pages()
+        .into_keys()
+        .filter(|page| !keep.contains(page))
+        .collect();
+    part.delete_pages(&remove);
+
+    // delete_pages 不会更新 Kids，需要移除已删除页面的引用
+    let existing: BTreeSet<ObjectId> = part.objects.keys().copied().collect();
+    for object in part.objects.values_mut() {
+        if let Ok(dict) = object.as_dict_mut() {
+            if dict.type_is(b"Pages") {
+                if let Ok(kids) = dict.get_mut(b"Kids").and_then(Object::as_array_mut) {
+                    kids.retain(|kid| {
+                        kid.as_reference()
+                            .map(|id| existing.contains(&id))
+                            .unwrap_or(true)
+                    });
+                }
+            }
+        }
+    }
+    // 书签可能指向已删除的页面
+    if let Ok(catalog) = part.catalog_mut() {
+        catalog.remove(b"Outlines");
+    }
+    part.prune_objects();
+    part
+}
+
+/// 合并文档，页面统一挂到第一个文档的页面树根节点下
+fn merge_documents(documents: Vec<Document>) -> Result<Document, String> {
+    let mut max_id = 1;
+    let mut pages = Vec::new();
+    let mut objects = BTreeMap::new();
+    let mut merged = Document::with_version(
+        documents
+            .iter()
+            .map(|doc| doc.version.clone())
+            .max()
+            .unwrap_or_else(|| "1.5".to_string()),
+    );
+
+    for mut doc in documents {
+        doc.renumber_objects_with(max_id);
+        max_id = doc.max_id + 1;
+        for page_id in doc.get_pages().into_values() {
+            let page = doc
+                .get_object(page_id)
+                .map_err(|e| format!("读取页面失败: {}", e))?
+                .clone();
+            pages.push((page_id, page));
+        }
+        objects.extend(doc.objects);
+    }
+
+    let mut catalog: Option<(ObjectId, Object)> = None;
+    let mut page_tree: Option<(ObjectId, Object)> = None;
+    for (id, object) in objects {
+        match object.type_name().unwrap_or("") {
+            "Catalog" => {
+                catalog.get_or_insert((id, object));
+            }
+            "Pages" => {
+                page_tree.get_or_insert((id, object));
+            }
+            // 页面单独处理，书签不参与合并
+            "Page" | "Outlines" | "Outline" => {}
+            _ => {
+                merged.objects.insert(id, object);
+            }
+        }
+    }
+
+    let (catalog_id, catalog) = catalog.ok_or("PDF 缺少 Catalog 对象")?;
+    let (pages_id, page_tree) = page_tree.ok_or("PDF 缺少页面树")?;
+
+    for (id, page) in &pages {
+        if let Ok(dict) = page.as_dict() {
+            let mut dict = dict.clone();
+            dict.set("Parent", pages_id);
+            merged.objects.insert(*id, Object::Dictionary(dict));
+        }
+    }
+
+    let mut page_tree = page_tree
+        .as_dict()
+        .map_err(|e| format!("页面树格式错误: {}", e))?
+        .clone();
+    page_tree.set("Count", pages.len() as u32);
+    page_tree.set(
+        "Kids",
+        pages
+            .iter()
+            .map(|(id, _)| Object::Reference(*id))
+            .collect::<Vec<_>>(),
+    );
+    merged
+        .objects
+        .insert(pages_id, Object::Dictionary(page_tree));
+
+    let mut catalog = catalog
+        .as_dict()
+        .map_err(|e| format!("Catalog 格式错误: {}", e))?
+        .clone();
+    catalog.set("Pages", pages_id);
+    catalog.remove(b"Outlines");
+    merged
+        .objects
+        .insert(catalog_id, Object::Dictionary(catalog));
+    merged.trailer.set("Root", catalog_id);
+
+    merged.max_id = merged.objects.keys().map(|(id, _)| *id).max().unwrap_or(0);
+    merged.renumber_objects();
+    Ok(merged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lopdf::content::{Content, Operation};
+    use lopdf::{dictionary, Stream};
+
+    fn sample_document(texts: &[&str]) -> Document {
+        let mut doc = Document::with_version("1.5");
+        let pages_id = doc.new_object_id();
+        let font_id = doc.add_object(dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type1",
+            "BaseFont" => "Courier",
+        });
+        let resources_id = doc.add_object(dictionary! {
+            "Font" => dictionary! { "F1" => font_id },
+        });
+
+        let mut kids = Vec::new();
+        for text in texts {
+            let content = Content {
+                operations: vec![
+                    Operation::new("BT", vec![]),
+                    Operation::new("Tf", vec!["F1".into(), 24.into()]),
+                    Operation::new("Tj", vec![Object::string_literal(*text)]),
+                    Operation::new("ET", vec![]),
+                ],
+            };
+            let content_id = doc.add_object(Stream::new(dictionary! {}, content.encode().unwrap()));
+            let page_id = doc.add_object(dictionary! {
+                "Type" => "Page",
+                "Parent" => pages_id,
+                "Contents" => content_id,
+                "Resources" => resources_id,
+                "MediaBox" => vec![0.into(), 0.into(), 595.into(), 842.into()],
+            });
+            kids.push(page_id.into());
+        }
+        doc.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => kids,
+                "Count" => texts.len() as u32,
+            }),
+        );
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+        });
+        let info_id = doc.add_object(dictionary! {
+            "Title" => Object::string_literal("Report"),
+            "Author" => Object::string_literal("devtools"),
+        });
+        doc.trailer.set("Root", catalog_id);
+        doc.trailer.set("Info", info_id);
+        doc
+    }
+
+    #[test]
+    fn test_parse_page_ranges() {
+        assert_eq!(parse_page_ranges("1-3,5", 10).unwrap(), vec![1, 2, 3, 5]);
+        assert_eq!(parse_page_ranges("8-, 2", 10).unwrap(), vec![8, 9, 10, 2]);
+        assert_eq!(parse_page_ranges("3,1-3", 5).unwrap(), vec![3, 1, 2]);
+        assert!(parse_page_ranges("0", 5).is_err());
+        assert!(parse_page_ranges("4-2", 5).is_err());
+        assert!(parse_page_ranges("6", 5).is_err());
+        assert!(parse_page_ranges("a", 5).is_err());
+    }
+
+    #[test]
+    fn test_merge_split_and_strip() {
+        let merged = merge_documents(vec![
+            sample_document(&["one", "two"]),
+            sample_document(&["three"]),
+        ])
+        .unwrap();
+        assert_eq!(merged.get_pages().len(), 3);
+        assert!(merged.extract_text(&[3]).unwrap().contains("three"));
+
+        let part = extract_pages(&merged, &[2, 3]);
+        assert_eq!(part.get_pages().len(), 2);
+        assert!(part.extract_text(&[1]).unwrap().contains("two"));
+
+        let mut doc = sample_document(&["one"]);
+        assert_eq!(read_metadata(&doc).0["Title"], "Report");
+        strip_metadata(&mut doc);
+        assert_eq!(read_metadata(&doc).0["Title"], "");
+    }
+}
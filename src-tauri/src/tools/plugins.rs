@@ -0,0 +1,711 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::{Duration, Instant};
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+/// 插件目录下的清单文件名
+const MANIFEST_FILE: &str = "plugin.json";
+/// 记录已信任的可执行插件，插件 id 对应入口文件的 SHA-256
+const TRUST_FILE: &str = "plugin-trust.json";
+/// 未在清单中声明时的执行超时
+const DEFAULT_TIMEOUT_SECS: u64 = 60;
+const MAX_TIMEOUT_SECS: u64 = 600;
+/// 标准输出和标准错误各自保留的最大字节数
+const MAX_OUTPUT_BYTES: usize = 4 * 1024 * 1024;
+/// WASM 模块中工作目录的挂载位置
+const WASM_WORKSPACE: &str = "/workspace";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PluginRuntime {
+    Executable, // 本地可执行文件，以当前用户权限运行，需要用户信任当前入口文件后才能执行
+    Wasm,       // WASI 模块，通过 wasmtime 运行，只能访问挂载的工作目录
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PluginInputKind {
+    Text,
+    Number,
+    Boolean,
+    File, // 运行前复制到工作目录，插件收到的是副本路径
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginInput {
+    pub name: String,
+    pub kind: PluginInputKind,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(default)]
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginCommand {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub args: Vec<String>, // 参数模板，{输入名} 替换为输入值，{output_dir} 替换为输出目录
+    #[serde(default)]
+    pub inputs: Vec<PluginInput>,
+    #[serde(default)]
+    pub output: Option<String>, // text 或 json，json 时解析标准输出
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginManifest {
+    pub id: String,
+    pub name: String,
+    pub version: String,
+    #[serde(default)]
+    pub description: String,
+    pub runtime: PluginRuntime,
+    pub entry: String, // 相对插件目录的可执行文件或 .wasm 路径
+    pub commands: Vec<PluginCommand>,
+    pub timeout_secs: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginInfo {
+    pub directory: String,
+    pub manifest: Option<PluginManifest>,
+    pub error: Option<String>, // 清单无效时的原因
+    pub trusted: bool,         // 可执行插件的入口文件已被信任且未被修改，WASM 插件始终为 true
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PluginRunRequest {
+    pub plugin_id: String,
+    pub command: String,
+    #[serde(default)]
+    pub inputs: BTreeMap<String, Value>,
+    pub output_dir: Option<String>, // 输出文件的保存目录，默认为插件输出目录下以插件 id 命名的子目录
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginRunResult {
+    pub exit_code: Option<i32>,
+    pub success: bool,
+    pub stdout: String,
+    pub stderr: String,
+    pub json: Option<Value>,       // 命令声明输出为 json 时的解析结果
+    pub output_files: Vec<String>, // 插件写入输出目录的文件，已复制到保存目录
+    pub elapsed_ms: u64,
+}
+
+/// 列出插件目录中的插件，清单无效的插件也会列出并附带原因
+#[tauri::command]
+pub async fn list_plugins() -> Result<Vec<PluginInfo>, String> {
+    let trust = load_trust(&get_trust_path());
+    let mut plugins = discover_plugins(&get_plugins_dir());
+    for plugin in &mut plugins {
+        plugin.trusted = match &plugin.manifest {
+            Some(manifest) => is_trusted(&trust, manifest, Path::new(&plugin.directory)),
+            None => false,
+        };
+    }
+    Ok(plugins)
+}
+
+/// 信任可执行插件的当前入口文件，入口文件变更后需要重新信任
+#[tauri::command]
+pub async fn trust_plugin(plugin_id: String) -> Result<(), String> {
+    let (plugin_dir, manifest) = find_plugin(&plugin_id)?;
+    let entry = resolve_entry(&plugin_dir, &manifest.entry)?;
+    let path = get_trust_path();
+    let mut trust = load_trust(&path);
+    trust.insert(manifest.id, entry_hash(&entry)?);
+    save_trust(&path, &trust)
+}
+
+/// 撤销对插件的信任
+#[tauri::command]
+pub async fn revoke_plugin_trust(plugin_id: String) -> Result<(), String> {
+    let path = get_trust_path();
+    let mut trust = load_trust(&path);
+    if trust.remove(&plugin_id).is_some() {
+        save_trust(&path, &trust)?;
+    }
+    Ok(())
+}
+
+/// 返回插件目录，不存在时创建
+#[tauri::command]
+pub async fn get_plugins_directory() -> Result<String, String> {
+    let dir = get_plugins_dir();
+    fs::create_dir_all(&dir).map_err(|e| format!("创建插件目录失败: {}", e))?;
+    Ok(dir.to_string_lossy().to_string())
+}
+
+/// 在独立的工作目录中运行插件命令
+#[tauri::command]
+pub async fn run_plugin(request: PluginRunRequest) -> Result<PluginRunResult, String> {
+    let (plugin_dir, manifest) = find_plugin(&request.plugin_id)?;
+    let command = manifest
+        .commands
+        .iter()
+        .find(|c| c.name == request.command)
+        .ok_or_else(|| format!("插件 {} 没有命令 {}", manifest.id, request.command))?;
+    if !is_trusted(&load_trust(&get_trust_path()), &manifest, &plugin_dir) {
+        return Err(format!(
+            "插件 {} 是本地可执行文件，尚未信任或入口文件已变更，请确认信任后再运行",
+            manifest.id
+        ));
+    }
+    let entry = resolve_entry(&plugin_dir, &manifest.entry)?;
+    let destination = request
+        .output_dir
+        .as_deref()
+        .map(|dir| PathBuf::from(dir.trim()))
+        .unwrap_or_else(|| get_plugin_output_dir().join(&manifest.id));
+
+    let workspace = std::env::temp_dir().join(format!(
+        "devtools-plugin-{}-{}",
+        manifest.id,
+        chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+    ));
+    let result = execute(
+        &manifest,
+        command,
+        &entry,
+        &workspace,
+        &destination,
+        &request.inputs,
+    )
+    .await;
+    // 输出文件已复制到保存目录，工作目录整体删除
+    let _ = fs::remove_dir_all(&workspace);
+    result
+}
+
+async fn execute(
+    manifest: &PluginManifest,
+    command: &PluginCommand,
+    entry: &Path,
+    workspace: &Path,
+    destination: &Path,
+    inputs: &BTreeMap<String, Value>,
+) -> Result<PluginRunResult, String> {
+    let start = Instant::now();
+    let output_dir = workspace.join("output");
+    fs::create_dir_all(&output_dir).map_err(|e| format!("创建工作目录失败: {}", e))?;
+
+    // WASM 模块只能访问挂载的工作目录，路径需要换成模块内的位置
+    let visible_root = match manifest.runtime {
+        PluginRuntime::Executable => workspace.to_path_buf(),
+        PluginRuntime::Wasm => PathBuf::from(WASM_WORKSPACE),
+    };
+    let values = prepare_inputs(command, inputs, workspace, &visible_root)?;
+    let args = expand_args(&command.args, &values, &visible_root.join("output"))?;
+
+    let mut process = match manifest.runtime {
+        PluginRuntime::Executable => {
+            let mut process = Command::new(entry);
+            process.args(&args);
+            process
+        }
+        PluginRuntime::Wasm => {
+            let mut process = Command::new("wasmtime");
+            process
+                .arg("run")
+                .arg(format!("--dir={}::{}", workspace.display(), WASM_WORKSPACE))
+                .arg(entry)
+                .args(&args);
+            process
+        }
+    };
+    // 不继承应用的环境变量，避免泄露代理凭据等信息；主目录和临时目录都指向工作目录，
+    // 插件按约定写入的配置和临时文件随工作目录一起删除
+    let home = workspace.join("home");
+    let tmp = workspace.join("tmp");
+    for dir in [&home, &tmp] {
+        fs::create_dir_all(dir).map_err(|e| format!("创建工作目录失败: {}", e))?;
+    }
+    process
+        .env_clear()
+        .env("PATH", std::env::var_os("PATH").unwrap_or_default())
+        .env("HOME", &home)
+        .env("USERPROFILE", &home)
+        .env("TMPDIR", &tmp)
+        .env("TMP", &tmp)
+        .env("TEMP", &tmp)
+        .env("DEVTOOLS_PLUGIN_WORKSPACE", &visible_root)
+        .current_dir(workspace)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+    // Windows 下缺少 SystemRoot 时大部分程序无法启动
+    #[cfg(windows)]
+    if let Some(root) = std::env::var_os("SystemRoot") {
+        process.env("SystemRoot", root);
+    }
+
+    let mut child = process.spawn().map_err(|e| match manifest.runtime {
+        PluginRuntime::Wasm => format!("启动 wasmtime 失败，请确认已安装 wasmtime: {}", e),
+        PluginRuntime::Executable => format!("启动插件失败: {}", e),
+    })?;
+    // 通过标准输入传递全部输入值，便于插件自行解析
+    if let Some(mut stdin) = child.stdin.take() {
+        let payload = serde_json::to_vec(&values).unwrap_or_default();
+        tokio::spawn(async move {
+            let _ = stdin.write_all(&payload).await;
+        });
+    }
+
+    let timeout = manifest
+        .timeout_secs
+        .unwrap_or(DEFAULT_TIMEOUT_SECS)
+        .clamp(1, MAX_TIMEOUT_SECS);
+    let output = tokio::time::timeout(Duration::from_secs(timeout), child.wait_with_output())
+        .await
+        .map_err(|_| format!("插件执行超时（{} 秒）", timeout))?
+        .map_err(|e| format!("插件执行失败: {}", e))?;
+
+    // 先保存输出文件，JSON 解析失败时工作目录同样会被删除
+    let output_files = save_outputs(&output_dir, destination)?;
+    let stdout = truncated(&output.stdout);
+    let json = if command.output.as_deref() == Some("json") && output.status.success() {
+        Some(serde_json::from_str(stdout.trim()).map_err(|e| {
+            format!(
+                "插件输出不是有效的 JSON: {}（输出文件已保存到 {}）",
+                e,
+                destination.display()
+            )
+        })?)
+    } else {
+        None
+    };
+
+    Ok(PluginRunResult {
+        exit_code: output.status.code(),
+        success: output.status.success(),
+        stdout,
+        stderr: truncated(&output.stderr),
+        json,
+        output_files,
+        elapsed_ms: start.elapsed().as_millis() as u64,
+    })
+}
+
+/// 把插件输出目录中的文件复制到保存目录，返回保存后的路径
+fn save_outputs(output_dir: &Path, destination: &Path) -> Result<Vec<String>, String> {
+    let mut files: Vec<PathBuf> = fs::read_dir(output_dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|e| e.path())
+                .filter(|path| path.is_file())
+                .collect()
+        })
+        .unwrap_or_default();
+    if files.is_empty() {
+        return Ok(Vec::new());
+    }
+    files.sort();
+    fs::create_dir_all(destination).map_err(|e| format!("创建输出目录失败: {}", e))?;
+    let mut saved = Vec::new();
+    for file in files {
+        let Some(name) = file.file_name() else {
+            continue;
+        };
+        let target = destination.join(name);
+        fs::copy(&file, &target).map_err(|e| format!("保存输出文件失败: {}", e))?;
+        saved.push(target.to_string_lossy().to_string());
+    }
+    Ok(saved)
+}
+
+/// 校验输入并转换为参数值，文件复制到工作目录后替换为副本路径
+fn prepare_inputs(
+    command: &PluginCommand,
+    inputs: &BTreeMap<String, Value>,
+    workspace: &Path,
+    visible_root: &Path,
+) -> Result<BTreeMap<String, String>, String> {
+    if let Some(name) = inputs
+        .keys()
+        .find(|name| !command.inputs.iter().any(|i| &i.name == *name))
+    {
+        return Err(format!("命令 {} 没有输入项 {}", command.name, name));
+    }
+
+    let mut values = BTreeMap::new();
+    for input in &command.inputs {
+        let value = match inputs.get(&input.name) {
+            Some(Value::Null) | None if input.required => {
+                return Err(format!("缺少必填输入项: {}", input.name));
+            }
+            Some(Value::Null) | None => continue,
+            Some(value) => value,
+        };
+        let text = match (input.kind, value) {
+            (PluginInputKind::Text, Value::String(s)) => s.clone(),
+            (PluginInputKind::Number, Value::Number(n)) => n.to_string(),
+            (PluginInputKind::Boolean, Value::Bool(b)) => b.to_string(),
+            (PluginInputKind::File, Value::String(path)) => {
+                let source = PathBuf::from(path);
+                if !source.is_file() {
+                    return Err(format!("输入文件不存在: {}", source.display()));
+                }
+                let file_name = source
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                let relative = Path::new("input").join(&input.name).join(&file_name);
+                let target = workspace.join(&relative);
+                if let Some(parent) = target.parent() {
+                    fs::create_dir_all(parent).map_err(|e| format!("创建工作目录失败: {}", e))?;
+                }
+                fs::copy(&source, &target).map_err(|e| format!("复制输入文件失败: {}", e))?;
+                visible_root.join(relative).to_string_lossy().to_string()
+            }
+            _ => return Err(format!("输入项 {} 的类型不正确", input.name)),
+        };
+        values.insert(input.name.clone(), text);
+    }
+    Ok(values)
+}
+
+/// 替换参数模板中的占位符，引用未提供的可选输入时省略该参数
+fn expand_args(
+    templates: &[String],
+    values: &BTreeMap<String, String>,
+    output_dir: &Path,
+) -> Result<Vec<String>, String> {
+    let mut args = Vec::new();
+    'templates: for template in templates {
+        let mut arg = String::new();
+        let mut rest = template.as_str();
+        while let Some(start) = rest.find('{') {
+            let end = rest[start..]
+                .find('}')
+                .map(|i| start + i)
+                .ok_or_else(|| format!("参数模板缺少右括号: {}", template))?;
+            arg.push_str(&rest[..start]);
+            let name = &rest[start + 1..end];
+            match (name, values.get(name)) {
+                ("output_dir", _) => arg.push_str(&output_dir.to_string_lossy()),
+                (_, Some(value)) => arg.push_str(value),
+                (_, None) => continue 'templates,
+            }
+            rest = &rest[end + 1..];
+        }
+        arg.push_str(rest);
+        args.push(arg);
+    }
+    Ok(args)
+}
+
+/// 入口文件必须位于插件目录内
+fn resolve_entry(plugin_dir: &Path, entry: &str) -> Result<PathBuf, String> {
+    let root = plugin_dir
+        .canonicalize()
+        .map_err(|e| format!("插件目录无效: {}", e))?;
+    let path = root
+        .join(entry)
+        .canonicalize()
+        .map_err(|_| format!("插件入口文件不存在: {}", entry))?;
+    if !path.starts_with(&root) || !path.is_file() {
+        return Err(format!("插件入口必须是插件目录内的文件: {}", entry));
+    }
+    Ok(path)
+}
+
+fn discover_plugins(dir: &Path) -> Vec<PluginInfo> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut plugins: Vec<PluginInfo> = entries
+        .flatten()
+        .filter(|e| e.path().join(MANIFEST_FILE).is_file())
+        .map(|e| {
+            let directory = e.path();
+            let (manifest, error) = match load_manifest(&directory) {
+                Ok(manifest) => (Some(manifest), None),
+                Err(e) => (None, Some(e)),
+            };
+            PluginInfo {
+                directory: directory.to_string_lossy().to_string(),
+                manifest,
+                error,
+                trusted: false,
+            }
+        })
+        .collect();
+    plugins.sort_by(|a, b| a.directory.cmp(&b.directory));
+
+    // 重复的插件 id 只保留第一个
+    let mut seen = std::collections::HashSet::new();
+    for plugin in &mut plugins {
+        let duplicate = plugin
+            .manifest
+            .as_ref()
+            .is_some_and(|m| !seen.insert(m.id.clone()));
+        if duplicate {
+            plugin.manifest = None;
+            plugin.error = Some("插件 id 与其他插件重复".to_string());
+        }
+    }
+    plugins
+}
+
+fn load_manifest(dir: &Path) -> Result<PluginManifest, String> {
+    let content = fs::read_to_string(dir.join(MANIFEST_FILE))
+        .map_err(|e| format!("读取插件清单失败: {}", e))?;
+    let manifest: PluginManifest =
+        serde_json::from_str(&content).map_err(|e| format!("插件清单格式错误: {}", e))?;
+    let valid_id = !manifest.id.is_empty()
+        && manifest
+            .id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid_id {
+        return Err("插件 id 只能包含字母、数字、- 和 _".to_string());
+    }
+    if manifest.commands.is_empty() {
+        return Err("插件清单没有声明命令".to_string());
+    }
+    resolve_entry(dir, &manifest.entry)?;
+    Ok(manifest)
+}
+
+fn find_plugin(plugin_id: &str) -> Result<(PathBuf, PluginManifest), String> {
+    discover_plugins(&get_plugins_dir())
+        .into_iter()
+        .find_map(|info| {
+            let manifest = info.manifest?;
+            (manifest.id == plugin_id).then(|| (PathBuf::from(info.directory), manifest))
+        })
+        .ok_or_else(|| format!("插件不存在或清单无效: {}", plugin_id))
+}
+
+/// WASM 插件只能访问挂载的工作目录，始终允许运行；可执行插件要求入口文件与信任时一致
+fn is_trusted(
+    trust: &BTreeMap<String, String>,
+    manifest: &PluginManifest,
+    plugin_dir: &Path,
+) -> bool {
+    if manifest.runtime == PluginRuntime::Wasm {
+        return true;
+    }
+    let Some(expected) = trust.get(&manifest.id) else {
+        return false;
+    };
+    resolve_entry(plugin_dir, &manifest.entry)
+        .and_then(|entry| entry_hash(&entry))
+        .is_ok_and(|hash| &hash == expected)
+}
+
+fn entry_hash(entry: &Path) -> Result<String, String> {
+    let content = fs::read(entry).map_err(|e| format!("读取插件入口文件失败: {}", e))?;
+    Ok(hex::encode(Sha256::digest(&content)))
+}
+
+fn load_trust(path: &Path) -> BTreeMap<String, String> {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_trust(path: &Path, trust: &BTreeMap<String, String>) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("创建配置目录失败: {}", e))?;
+    }
+    let content = serde_json::to_string_pretty(trust).map_err(|e| e.to_string())?;
+    fs::write(path, content).map_err(|e| format!("保存插件信任记录失败: {}", e))
+}
+
+fn truncated(bytes: &[u8]) -> String {
+    let bytes = &bytes[..bytes.len().min(MAX_OUTPUT_BYTES)];
+    String::from_utf8_lossy(bytes).to_string()
+}
+
+fn get_plugins_dir() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("devtools")
+        .join("plugins")
+}
+
+fn get_trust_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("devtools")
+        .join(TRUST_FILE)
+}
+
+fn get_plugin_output_dir() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("devtools")
+        .join("plugin-output")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn write_plugin(root: &Path, dir: &str, id: &str, entry: &str) {
+        let plugin_dir = root.join(dir);
+        fs::create_dir_all(plugin_dir.join("bin")).unwrap();
+        fs::write(plugin_dir.join("bin").join("run.sh"), "#!/bin/sh\n").unwrap();
+        let manifest = json!({
+            "id": id,
+            "name": "Upper",
+            "version": "1.0.0",
+            "runtime": "executable",
+            "entry": entry,
+            "commands": [{
+                "name": "upper",
+                "args": ["--text={text}", "--file", "{file}", "{output_dir}"],
+                "inputs": [
+                    {"name": "text", "kind": "text", "required": true},
+                    {"name": "file", "kind": "file"}
+                ],
+                "output": "json"
+            }]
+        });
+        fs::write(plugin_dir.join(MANIFEST_FILE), manifest.to_string()).unwrap();
+    }
+
+    #[test]
+    fn test_discover_plugins() {
+        let root = std::env::temp_dir().join(format!("devtools-plugins-{}", std::process::id()));
+        write_plugin(&root, "a-upper", "upper", "bin/run.sh");
+        write_plugin(&root, "b-escape", "escape", "../../etc/passwd");
+        write_plugin(&root, "c-copy", "upper", "bin/run.sh");
+        fs::create_dir_all(root.join("no-manifest")).unwrap();
+
+        let plugins = discover_plugins(&root);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(plugins.len(), 3);
+        assert_eq!(plugins[0].manifest.as_ref().unwrap().id, "upper");
+        assert!(plugins[1].manifest.is_none());
+        assert!(plugins[1].error.is_some());
+        assert_eq!(plugins[2].error.as_deref(), Some("插件 id 与其他插件重复"));
+    }
+
+    #[test]
+    fn test_prepare_inputs_and_expand_args() {
+        let workspace =
+            std::env::temp_dir().join(format!("devtools-plugin-ws-{}", std::process::id()));
+        let source =
+            std::env::temp_dir().join(format!("devtools-plugin-in-{}.txt", std::process::id()));
+        fs::write(&source, "hello").unwrap();
+        let command: PluginCommand = serde_json::from_value(json!({
+            "name": "upper",
+            "args": ["--text={text}", "--file", "{file}", "--count={count}", "{output_dir}"],
+            "inputs": [
+                {"name": "text", "kind": "text", "required": true},
+                {"name": "file", "kind": "file"},
+                {"name": "count", "kind": "number"}
+            ]
+        }))
+        .unwrap();
+
+        let mut inputs = BTreeMap::new();
+        inputs.insert("text".to_string(), json!("a b"));
+        inputs.insert("file".to_string(), json!(source.to_string_lossy()));
+        let values =
+            prepare_inputs(&command, &inputs, &workspace, Path::new("/workspace")).unwrap();
+        let copied = workspace
+            .join("input")
+            .join("file")
+            .join(source.file_name().unwrap());
+        assert_eq!(fs::read_to_string(&copied).unwrap(), "hello");
+        fs::remove_dir_all(&workspace).unwrap();
+        fs::remove_file(&source).unwrap();
+
+        let args = expand_args(&command.args, &values, Path::new("/workspace/output")).unwrap();
+        let expected_file = format!(
+            "/workspace/input/file/{}",
+            source.file_name().unwrap().to_string_lossy()
+        );
+        // 未提供的可选输入 count 对应的参数被省略
+        assert_eq!(
+            args,
+            vec![
+                "--text=a b",
+                "--file",
+                expected_file.as_str(),
+                "/workspace/output"
+            ]
+        );
+
+        inputs.remove("file");
+        inputs.insert("count".to_string(), json!("three"));
+        assert!(prepare_inputs(&command, &inputs, &workspace, &workspace).is_err());
+        inputs.remove("count");
+        inputs.remove("text");
+        assert_eq!(
+            prepare_inputs(&command, &inputs, &workspace, &workspace).unwrap_err(),
+            "缺少必填输入项: text"
+        );
+        inputs.insert("extra".to_string(), json!(1));
+        assert!(prepare_inputs(&command, &inputs, &workspace, &workspace).is_err());
+        let _ = fs::remove_dir_all(&workspace);
+    }
+
+    #[test]
+    fn test_trust_requires_matching_entry_hash() {
+        let root =
+            std::env::temp_dir().join(format!("devtools-plugin-trust-{}", std::process::id()));
+        write_plugin(&root, "upper", "upper", "bin/run.sh");
+        let plugin_dir = root.join("upper");
+        let mut manifest = load_manifest(&plugin_dir).unwrap();
+        let trust_path = root.join(TRUST_FILE);
+
+        let mut trust = load_trust(&trust_path);
+        assert!(!is_trusted(&trust, &manifest, &plugin_dir));
+        let entry = resolve_entry(&plugin_dir, &manifest.entry).unwrap();
+        trust.insert(manifest.id.clone(), entry_hash(&entry).unwrap());
+        save_trust(&trust_path, &trust).unwrap();
+        let trust = load_trust(&trust_path);
+        assert!(is_trusted(&trust, &manifest, &plugin_dir));
+
+        // 入口文件被替换后信任失效
+        fs::write(&entry, "#!/bin/sh\necho changed\n").unwrap();
+        assert!(!is_trusted(&trust, &manifest, &plugin_dir));
+
+        manifest.runtime = PluginRuntime::Wasm;
+        assert!(is_trusted(&BTreeMap::new(), &manifest, &plugin_dir));
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_save_outputs() {
+        let root = std::env::temp_dir().join(format!("devtools-plugin-out-{}", std::process::id()));
+        let output_dir = root.join("workspace").join("output");
+        let destination = root.join("saved");
+        assert!(save_outputs(&output_dir, &destination).unwrap().is_empty());
+        assert!(!destination.exists());
+
+        fs::create_dir_all(output_dir.join("nested")).unwrap();
+        fs::write(output_dir.join("b.txt"), "b").unwrap();
+        fs::write(output_dir.join("a.json"), "{}").unwrap();
+        let saved = save_outputs(&output_dir, &destination).unwrap();
+        fs::remove_dir_all(root.join("workspace")).unwrap();
+
+        assert_eq!(
+            saved,
+            vec![
+                destination.join("a.json").to_string_lossy().to_string(),
+                destination.join("b.txt").to_string_lossy().to_string(),
+            ]
+        );
+        assert_eq!(fs::read_to_string(destination.join("b.txt")).unwrap(), "b");
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,440 @@
+use base64::{engine::general_purpose, Engine as _};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write as _;
+
+const MAX_DEPTH: usize = 32;
+/// bytes 字段十六进制预览的最大字节数
+const MAX_PREVIEW_BYTES: usize = 64;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProtobufWireRequest {
+    pub data: String,
+    pub encoding: Option<String>, // auto, hex, base64，默认 auto
+    pub grpc_frame: Option<bool>, // 是否带 gRPC 5 字节消息头，默认自动检测
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WireField {
+    pub field_number: u64,
+    pub wire_type: u8,
+    pub wire_type_name: String,
+    pub offset: usize,             // 字段标签在消息中的偏移
+    pub length: usize,             // 含标签的总字节数
+    pub value: String,             // 首选解读
+    pub alternatives: Vec<String>, // 其他可能的解读，如 sint64、double、packed
+    pub children: Vec<WireField>,  // 推测为嵌套消息或 group 时的子字段
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProtobufMessage {
+    pub offset: usize,
+    pub length: usize,
+    pub compressed: bool, // gRPC 压缩标志，压缩的消息不解析
+    pub fields: Vec<WireField>,
+    pub text: String, // 类似 protoc --decode_raw 的输出
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProtobufWireResult {
+    pub byte_length: usize,
+    pub grpc_framed: bool,
+    pub messages: Vec<ProtobufMessage>,
+}
+
+/// 在没有 .proto 的情况下按线格式解析 Protobuf 数据
+#[tauri::command]
+pub fn decode_protobuf_wire(request: ProtobufWireRequest) -> Result<ProtobufWireResult, String> {
+    let data = decode_bytes(&request.data, request.encoding.as_deref().unwrap_or("auto"))?;
+    if data.is_empty() {
+        return Err("数据不能为空".to_string());
+    }
+    let frames = match request.grpc_frame {
+        Some(false) => None,
+        Some(true) => Some(split_grpc_frames(&data).ok_or("gRPC 消息头与数据长度不符")?),
+        None => split_grpc_frames(&data),
+    };
+
+    let messages = match &frames {
+        Some(frames) => frames
+            .iter()
+            .map(|&(offset, length, compressed)| {
+                if compressed {
+                    return ProtobufMessage {
+                        offset,
+                        length,
+                        compressed,
+                        fields: Vec::new(),
+                        text: String::new(),
+                        error: Some("消息已压缩，无法直接解析".to_string()),
+                    };
+                }
+                decode_message_at(&data[offset..offset + length], offset)
+            })
+            .collect(),
+        None => vec![decode_message_at(&data, 0)],
+    };
+    Ok(ProtobufWireResult {
+        byte_length: data.len(),
+        grpc_framed: frames.is_some(),
+        messages,
+    })
+}
+
+fn decode_bytes(input: &str, encoding: &str) -> Result<Vec<u8>, String> {
+    let trimmed = input.trim();
+    let hex_text: String = trimmed
+        .replace("0x", "")
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != ':')
+        .collect();
+    let looks_hex = !hex_text.is_empty() && hex_text.chars().all(|c| c.is_ascii_hexdigit());
+    match encoding.to_lowercase().as_str() {
+        "hex" => hex::decode(&hex_text).map_err(|e| format!("十六进制解码失败: {}", e)),
+        "auto" if looks_hex && hex_text.len().is_multiple_of(2) => {
+            hex::decode(&hex_text).map_err(|e| format!("十六进制解码失败: {}", e))
+        }
+        "base64" | "auto" => {
+            let cleaned: String = trimmed.chars().filter(|c| !c.is_whitespace()).collect();
+            general_purpose::STANDARD
+                .decode(&cleaned)
+                .or_else(|_| general_purpose::URL_SAFE_NO_PAD.decode(cleaned.trim_end_matches('=')))
+                .map_err(|e| format!("Base64 解码失败: {}", e))
+        }
+        other => Err(format!("不支持的编码: {}", other)),
+    }
+}
+
+/// 按 gRPC 长度前缀拆分消息，总长度对不上时返回 None
+fn split_grpc_frames(data: &[u8]) -> Option<Vec<(usize, usize, bool)>> {
+    let mut frames = Vec::new();
+    let mut pos = 0;
+    while pos < data.len() {
+        let header = data.get(pos..pos + 5)?;
+        if header[0] > 1 {
+            return None;
+        }
+        let length = u32::from_be_bytes([header[1], header[2], header[3], header[4]]) as usize;
+        let end = (pos + 5).checked_add(length)?;
+        if end > data.len() {
+            return None;
+        }
+        frames.push((pos + 5, length, header[0] == 1));
+        pos = end;
+    }
+    (!frames.is_empty()).then_some(frames)
+}
+
+fn decode_message_at(data: &[u8], base: usize) -> ProtobufMessage {
+    let (fields, error) = match parse_message(data, 0) {
+        Ok(fields) => (fields, None),
+        Err(e) => (Vec::new(), Some(e)),
+    };
+    let mut text = String::new();
+    render(&fields, 0, &mut text);
+    ProtobufMessage {
+        offset: base,
+        length: data.len(),
+        compressed: false,
+        fields,
+        text,
+        error,
+    }
+}
+
+fn read_varint(data: &[u8], pos: &mut usize) -> Result<u64, String> {
+    let start = *pos;
+    let mut value = 0u64;
+    for shift in (0..70).step_by(7) {
+        let byte = *data
+            .get(*pos)
+            .ok_or_else(|| format!("偏移 {} 处的 varint 被截断", start))?;
+        *pos += 1;
+        if shift == 63 && byte > 1 {
+            break;
+        }
+        value |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(format!("偏移 {} 处的 varint 超过 64 位", start))
+}
+
+fn wire_type_name(wire_type: u8) -> &'static str {
+    match wire_type {
+        0 => "varint",
+        1 => "fixed64",
+        2 => "length-delimited",
+        3 => "start group",
+        4 => "end group",
+        5 => "fixed32",
+        _ => "unknown",
+    }
+}
+
+fn parse_message(data: &[u8], depth: usize) -> Result<Vec<WireField>, String> {
+    let mut pos = 0;
+    let fields = parse_fields(data, &mut pos, depth, None)?;
+    Ok(fields)
+}
+
+/// 解析字段直到数据结束，group 内解析到对应的 end group 为止
+fn parse_fields(
+    data: &[u8],
+    pos: &mut usize,
+    depth: usize,
+    group: Option<u64>,
+) -> Result<Vec<WireField>, String> {
+    if depth > MAX_DEPTH {
+        return Err(format!("嵌套层级超过 {}", MAX_DEPTH));
+    }
+    let mut fields = Vec::new();
+    while *pos < data.len() {
+        let offset = *pos;
+        let key = read_varint(data, pos)?;
+        let field_number = key >> 3;
+        let wire_type = (key & 7) as u8;
+        if field_number == 0 || field_number > 0x1fff_ffff {
+            return Err(format!("偏移 {} 处字段号 {} 无效", offset, field_number));
+        }
+
+        let mut field = WireField {
+            field_number,
+            wire_type,
+            wire_type_name: wire_type_name(wire_type).to_string(),
+            offset,
+            length: 0,
+            value: String::new(),
+            alternatives: Vec::new(),
+            children: Vec::new(),
+        };
+        match wire_type {
+            0 => {
+                let value = read_varint(data, pos)?;
+                field.value = value.to_string();
+                let zigzag = ((value >> 1) as i64) ^ -((value & 1) as i64);
+                if value > i64::MAX as u64 {
+                    field.alternatives.push(format!("int64: {}", value as i64));
+                }
+                if zigzag != value as i64 {
+                    field.alternatives.push(format!("sint64: {}", zigzag));
+                }
+                if value <= 1 {
+                    field.alternatives.push(format!("bool: {}", value == 1));
+                }
+            }
+            1 => {
+                let bytes: [u8; 8] = take(data, pos, 8)?
+                    .try_into()
+                    .map_err(|_| "fixed64 长度错误".to_string())?;
+                let value = u64::from_le_bytes(bytes);
+                field.value = format!("0x{:016x}", value);
+                field.alternatives = vec![
+                    format!("fixed64: {}", value),
+                    format!("sfixed64: {}", value as i64),
+                    format!("double: {}", f64::from_le_bytes(bytes)),
+                ];
+            }
+            2 => {
+                let length = read_varint(data, pos)? as usize;
+                let content = take(data, pos, length)?;
+                describe_bytes(content, depth, &mut field);
+            }
+            3 => {
+                field.children = parse_fields(data, pos, depth + 1, Some(field_number))?;
+                field.value = "group".to_string();
+            }
+            4 => {
+                if group == Some(field_number) {
+                    return Ok(fields);
+                }
+                return Err(format!("偏移 {} 处出现不匹配的 end group", offset));
+            }
+            5 => {
+                let bytes: [u8; 4] = take(data, pos, 4)?
+                    .try_into()
+                    .map_err(|_| "fixed32 长度错误".to_string())?;
+                let value = u32::from_le_bytes(bytes);
+                field.value = format!("0x{:08x}", value);
+                field.alternatives = vec![
+                    format!("fixed32: {}", value),
+                    format!("sfixed32: {}", value as i32),
+                    format!("float: {}", f32::from_le_bytes(bytes)),
+                ];
+            }
+            _ => {
+                return Err(format!("偏移 {} 处线类型 {} 无效", offset, wire_type));
+            }
+        }
+        field.length = *pos - offset;
+        fields.push(field);
+    }
+    if group.is_some() {
+        return Err("group 缺少 end group".to_string());
+    }
+    Ok(fields)
+}
+
+fn take<'a>(data: &'a [u8], pos: &mut usize, length: usize) -> Result<&'a [u8], String> {
+    let end = pos
+        .checked_add(length)
+        .filter(|end| *end <= data.len())
+        .ok_or_else(|| format!("偏移 {} 处声明长度 {} 超出剩余数据", pos, length))?;
+    let slice = &data[*pos..end];
+    *pos = end;
+    Ok(slice)
+}
+
+/// 与 protoc --decode_raw 一致，能完整解析为消息时优先视为嵌套消息
+fn describe_bytes(content: &[u8], depth: usize, field: &mut WireField) {
+    let text = std::str::from_utf8(content).ok().filter(|s| {
+        s.chars()
+            .all(|c| !c.is_control() || matches!(c, '\n' | '\r' | '\t'))
+    });
+    let nested = if content.is_empty() {
+        None
+    } else {
+        parse_message(content, depth + 1).ok()
+    };
+
+    match (nested, text) {
+        (Some(children), text) => {
+            field.value = format!("message（{} 个字段）", children.len());
+            field.children = children;
+            if let Some(text) = text {
+                field.alternatives.push(format!("string: {:?}", text));
+            }
+        }
+        (None, Some(text)) => field.value = format!("{:?}", text),
+        (None, None) => field.value = c_escape(content),
+    }
+    if let Some(values) = packed_varints(content) {
+        field
+            .alternatives
+            .push(format!("packed varint: {:?}", values));
+    }
+    if !content.is_empty() && field.value.starts_with('"') {
+        field
+            .alternatives
+            .push(format!("bytes: {}", hex_preview(content)));
+    }
+}
+
+/// 整段都能解析为 varint 时可能是 packed repeated 字段
+fn packed_varints(content: &[u8]) -> Option<Vec<u64>> {
+    if content.is_empty() || content.len() > 1024 {
+        return None;
+    }
+    let mut values = Vec::new();
+    let mut pos = 0;
+    while pos < content.len() {
+        values.push(read_varint(content, &mut pos).ok()?);
+    }
+    (values.len() > 1).then_some(values)
+}
+
+/// 按 protoc 的方式转义 bytes，不可打印字节使用八进制
+fn c_escape(content: &[u8]) -> String {
+    let mut text = String::from("\"");
+    for &b in content {
+        match b {
+            b'"' => text.push_str("\\\""),
+            b'\\' => text.push_str("\\\\"),
+            b'\n' => text.push_str("\\n"),
+            b'\r' => text.push_str("\\r"),
+            b'\t' => text.push_str("\\t"),
+            0x20..=0x7e => text.push(b as char),
+            _ => {
+                let _ = write!(text, "\\{:03o}", b);
+            }
+        }
+    }
+    text.push('"');
+    text
+}
+
+fn hex_preview(content: &[u8]) -> String {
+    let mut text = content
+        .iter()
+        .take(MAX_PREVIEW_BYTES)
+        .map(|b| format!("{:02x}", b))
+        .collect::<Vec<_>>()
+        .join(" ");
+    if content.len() > MAX_PREVIEW_BYTES {
+        let _ = write!(text, " …（共 {} 字节）", content.len());
+    }
+    text
+}
+
+fn render(fields: &[WireField], depth: usize, out: &mut String) {
+    let indent = "  ".repeat(depth);
+    for field in fields {
+        if field.children.is_empty() {
+            let _ = writeln!(out, "{}{}: {}", indent, field.field_number, field.value);
+        } else {
+            let _ = writeln!(out, "{}{} {{", indent, field.field_number);
+            render(&field.children, depth + 1, out);
+            let _ = writeln!(out, "{}}}", indent);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(data: &str) -> ProtobufWireResult {
+        decode_protobuf_wire(ProtobufWireRequest {
+            data: data.to_string(),
+            encoding: None,
+            grpc_frame: None,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_decode_raw_message() {
+        // 1: 150, 2: "testing", 3 { 1: 150 }, 4: fixed32, 5: packed [3, 270]
+        let result = decode(
+            "08 96 01 12 07 74 65 73 74 69 6e 67 1a 03 08 96 01 25 01 00 00 00 2a 03 03 8e 02",
+        );
+        assert!(!result.grpc_framed);
+        let message = &result.messages[0];
+        assert!(message.error.is_none());
+        assert_eq!(
+            message.text,
+            "1: 150\n2: \"testing\"\n3 {\n  1: 150\n}\n4: 0x00000001\n5: \"\\003\\216\\002\"\n"
+        );
+        let fields = &message.fields;
+        assert!(fields[0].alternatives.contains(&"sint64: 75".to_string()));
+        assert_eq!((fields[2].offset, fields[2].length), (12, 5));
+        assert!(fields[4]
+            .alternatives
+            .contains(&"packed varint: [3, 270]".to_string()));
+    }
+
+    #[test]
+    fn test_grpc_frames_and_errors() {
+        // 两个 gRPC 消息，第二个为压缩消息
+        let framed = general_purpose::STANDARD
+            .encode([0, 0, 0, 0, 3, 0x08, 0x96, 0x01, 1, 0, 0, 0, 2, 0xff, 0xff]);
+        let result = decode(&framed);
+        assert!(result.grpc_framed);
+        assert_eq!(result.messages.len(), 2);
+        assert_eq!(result.messages[0].offset, 5);
+        assert_eq!(result.messages[0].text, "1: 150\n");
+        assert!(result.messages[1].compressed);
+
+        // group: 1 { 2: 1 }
+        let result = decode("0b 10 01 0c");
+        assert_eq!(result.messages[0].text, "1 {\n  2: 1\n}\n");
+
+        let result = decode("12 05 61 62");
+        assert!(result.messages[0]
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("超出"));
+    }
+}
@@ -0,0 +1,593 @@
+use crate::tools::static_server;
+use crate::utils::proxy;
+use axum::extract::{self, ConnectInfo, Request};
+use axum::response::{IntoResponse, Response};
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::net::TcpListener;
+
+/// 探测代理链是否透传客户端自带转发头时使用的地址（RFC 5737 文档保留段）
+const SPOOFED_IP: &str = "203.0.113.66";
+
+/// 与客户端 IP 相关的转发头
+const FORWARDING_HEADERS: &[&str] = &[
+    "x-forwarded-for",
+    "forwarded",
+    "x-real-ip",
+    "x-forwarded-proto",
+    "x-forwarded-host",
+    "x-forwarded-port",
+    "via",
+    "cf-connecting-ip",
+    "true-client-ip",
+    "x-client-ip",
+    "fastly-client-ip",
+    "x-original-forwarded-for",
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProxyHeaderRequest {
+    pub url: Option<String>, // 经过代理访问的地址，使用内置回显服务时为空表示直连回显服务
+    pub echo_port: Option<u16>, // 在该端口启动内置回显服务供代理转发，为空时 url 必须是能回显请求头的端点
+    pub trusted_proxies: Option<Vec<String>>, // 可信代理的 IP 或 CIDR，为空时信任回环和内网地址
+    pub spoof_test: Option<bool>, // 是否附带伪造的 X-Forwarded-For，检测代理链是否原样透传，默认开启
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HeaderEntry {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ForwardedElement {
+    pub for_addr: Option<String>,
+    pub by: Option<String>,
+    pub proto: Option<String>,
+    pub host: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProxyHop {
+    pub address: String,
+    pub source: String,   // X-Forwarded-For[n] 或 TCP 对端
+    pub added_by: String, // 这一条是谁添加的
+    pub valid_ip: bool,
+    pub trusted: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProxyHeaderReport {
+    pub url: String,
+    pub remote_addr: Option<String>, // 回显端点看到的 TCP 对端，即最后一跳
+    pub forwarding_headers: Vec<HeaderEntry>,
+    pub forwarded_for: Vec<String>, // X-Forwarded-For 中依次列出的地址
+    pub forwarded: Vec<ForwardedElement>, // RFC 7239 Forwarded 头
+    pub hops: Vec<ProxyHop>,        // 从客户端到回显端点的完整链路
+    pub leftmost_ip: Option<String>, // 直接取最左侧地址的结果，可被客户端伪造
+    pub client_ip: Option<String>,  // 从右向左跳过可信代理后得到的真实客户端 IP
+    pub real_ip_header: Option<String>,
+    pub spoof_passed_through: Option<bool>,
+    pub findings: Vec<String>,
+}
+
+/// 回显端点收到的请求
+#[derive(Debug, Clone, Default)]
+struct EchoedRequest {
+    remote_addr: Option<String>,
+    headers: Vec<(String, String)>,
+}
+
+/// 通过代理链访问回显端点，分析 X-Forwarded-For / Forwarded / X-Real-IP 的处理情况
+#[tauri::command]
+pub async fn inspect_proxy_headers(
+    request: ProxyHeaderRequest,
+) -> Result<ProxyHeaderReport, String> {
+    let trusted = request
+        .trusted_proxies
+        .unwrap_or_default()
+        .iter()
+        .filter(|t| !t.trim().is_empty())
+        .map(|t| parse_cidr(t))
+        .collect::<Result<Vec<_>, _>>()?;
+    let spoof = request.spoof_test.unwrap_or(true);
+    let target = request
+        .url
+        .map(|u| u.trim().to_string())
+        .filter(|u| !u.is_empty());
+
+    let (url, echoed) = match request.echo_port {
+        Some(port) => {
+            let captured = Arc::new(Mutex::new(None));
+            let listener = TcpListener::bind(SocketAddr::new(IpAddr::from([0, 0, 0, 0]), port))
+                .await
+                .map_err(|e| format!("启动回显服务失败: {}", e))?;
+            let port = listener
+                .local_addr()
+                .map_err(|e| format!("获取监听端口失败: {}", e))?
+                .port();
+            let router = Router::new()
+                .fallback(handle_echo)
+                .with_state(captured.clone());
+            let server = tokio::spawn(static_server::serve(listener, router, None));
+            let url = target.unwrap_or_else(|| format!("http://127.0.0.1:{}/", port));
+            let sent = send_probe(&url, spoof).await;
+            server.abort();
+            sent?;
+            let echoed = captured
+                .lock()
+                .unwrap()
+                .take()
+                .ok_or("请求已完成但回显服务未收到请求，请确认代理已转发到回显端口")?;
+            (url, echoed)
+        }
+        None => {
+            let url = target.ok_or("请输入回显端点地址或指定内置回显服务端口")?;
+            let body = send_probe(&url, spoof).await?;
+            let echoed = parse_echo_response(&body)?;
+            (url, echoed)
+        }
+    };
+
+    let spoofed = spoof.then(|| SPOOFED_IP.parse().unwrap());
+    let mut report = analyze_headers(&echoed, &trusted, spoofed);
+    report.url = url;
+    Ok(report)
+}
+
+async fn handle_echo(
+    extract::State(captured): extract::State<Arc<Mutex<Option<EchoedRequest>>>>,
+    ConnectInfo(remote): ConnectInfo<SocketAddr>,
+    request: Request,
+) -> Response {
+    let headers: Vec<(String, String)> = request
+        .headers()
+        .iter()
+        .map(|(k, v)| {
+            (
+                k.to_string(),
+                String::from_utf8_lossy(v.as_bytes()).to_string(),
+            )
+        })
+        .collect();
+    let body = serde_json::json!({
+        "remote_addr": remote.ip().to_string(),
+        "headers": headers.iter().map(|(k, v)| serde_json::json!([k, v])).collect::<Vec<_>>(),
+    });
+    captured.lock().unwrap().get_or_insert(EchoedRequest {
+        remote_addr: Some(remote.ip().to_string()),
+        headers,
+    });
+    Json(body).into_response()
+}
+
+async fn send_probe(url: &str, spoof: bool) -> Result<String, String> {
+    let client = proxy::client_builder("proxy_headers")
+        .timeout(Duration::from_secs(15))
+        .build()
+        .map_err(|e| format!("构建 HTTP 客户端失败: {}", e))?;
+    let mut builder = client.get(url).header("Accept", "application/json");
+    if spoof {
+        builder = builder.header("X-Forwarded-For", SPOOFED_IP);
+    }
+    let response = builder
+        .send()
+        .await
+        .map_err(|e| format!("请求失败: {}", e))?;
+    if !response.status().is_success() {
+        return Err(format!("回显端点返回 HTTP {}", response.status().as_u16()));
+    }
+    response
+        .text()
+        .await
+        .map_err(|e| format!("读取响应失败: {}", e))
+}
+
+/// 兼容 httpbin、内置回显服务以及常见回显端点的 JSON 格式
+fn parse_echo_response(body: &str) -> Result<EchoedRequest, String> {
+    let value: serde_json::Value =
+        serde_json::from_str(body).map_err(|_| "回显端点返回的不是 JSON".to_string())?;
+    let mut headers = Vec::new();
+    match value.get("headers") {
+        Some(serde_json::Value::Object(map)) => {
+            for (name, value) in map {
+                match value {
+                    serde_json::Value::String(s) => headers.push((name.clone(), s.clone())),
+                    serde_json::Value::Array(values) => {
+                        for v in values.iter().filter_map(|v| v.as_str()) {
+                            headers.push((name.clone(), v.to_string()));
+                        }
+                    }
+                    other => headers.push((name.clone(), other.to_string())),
+                }
+            }
+        }
+        Some(serde_json::Value::Array(pairs)) => {
+            for pair in pairs {
+                if let (Some(name), Some(value)) = (
+                    pair.get(0).and_then(|v| v.as_str()),
+                    pair.get(1).and_then(|v| v.as_str()),
+                ) {
+                    headers.push((name.to_string(), value.to_string()));
+                }
+            }
+        }
+        _ => return Err("回显端点的响应中没有 headers 字段".to_string()),
+    }
+    let remote_addr = ["remote_addr", "ip", "client_ip", "origin"]
+        .iter()
+        .find_map(|key| value.get(*key).and_then(|v| v.as_str()))
+        // httpbin 的 origin 是逗号分隔的链路，最后一个才是 TCP 对端
+        .and_then(|v| v.rsplit(',').next())
+        .map(|v| v.trim().to_string());
+    Ok(EchoedRequest {
+        remote_addr,
+        headers,
+    })
+}
+
+fn analyze_headers(
+    echoed: &EchoedRequest,
+    trusted: &[(IpAddr, u8)],
+    spoofed: Option<IpAddr>,
+) -> ProxyHeaderReport {
+    let header_values = |name: &str| -> Vec<&str> {
+        echoed
+            .headers
+            .iter()
+            .filter(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+            .collect()
+    };
+    let mut findings = Vec::new();
+
+    let forwarding_headers: Vec<HeaderEntry> = echoed
+        .headers
+        .iter()
+        .filter(|(k, _)| FORWARDING_HEADERS.contains(&k.to_lowercase().as_str()))
+        .map(|(k, v)| HeaderEntry {
+            name: k.to_lowercase(),
+            value: v.clone(),
+        })
+        .collect();
+    if forwarding_headers.is_empty() {
+        findings.push(
+            "未发现任何转发头，请求可能未经过代理，或代理没有设置 X-Forwarded-For".to_string(),
+        );
+    }
+
+    let xff_lines = header_values("x-forwarded-for");
+    if xff_lines.len() > 1 {
+        findings.push(format!(
+            "收到 {} 行 X-Forwarded-For，按规范应合并为一个逗号分隔列表，部分框架只读取第一行",
+            xff_lines.len()
+        ));
+    }
+    let forwarded_for: Vec<String> = xff_lines
+        .iter()
+        .flat_map(|line| line.split(','))
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect();
+    let forwarded: Vec<ForwardedElement> = header_values("forwarded")
+        .iter()
+        .flat_map(|line| parse_forwarded(line))
+        .collect();
+
+    let is_trusted = |ip: IpAddr| {
+        if trusted.is_empty() {
+            is_internal(ip)
+        } else {
+            trusted.iter().any(|net| cidr_contains(*net, ip))
+        }
+    };
+
+    let mut hops: Vec<ProxyHop> = Vec::new();
+    let remote = echoed.remote_addr.as_deref().and_then(parse_address);
+    for (index, address) in forwarded_for.iter().enumerate() {
+        let ip = parse_address(address);
+        // 每一跳代理把它看到的对端地址追加到末尾，所以第 n 项由第 n+1 项对应的代理添加
+        let mut added_by = match forwarded_for.get(index + 1) {
+            Some(next) => format!("由代理 {} 追加", next),
+            None => format!(
+                "由最后一跳代理 {} 追加",
+                echoed.remote_addr.as_deref().unwrap_or("（未知）")
+            ),
+        };
+        if index == 0 {
+            added_by.push_str("；最左侧条目即声称的原始客户端，若客户端自带该头则可被伪造");
+        }
+        if ip.is_none() {
+            findings.push(format!(
+                "X-Forwarded-For 第 {} 项 {:?} 不是合法的 IP 地址",
+                index + 1,
+                address
+            ));
+        }
+        hops.push(ProxyHop {
+            address: address.clone(),
+            source: format!("X-Forwarded-For[{}]", index),
+            added_by,
+            valid_ip: ip.is_some(),
+            trusted: ip.map(is_trusted).unwrap_or(false),
+        });
+    }
+    if let Some(remote_addr) = &echoed.remote_addr {
+        hops.push(ProxyHop {
+            address: remote_addr.clone(),
+            source: "TCP 对端".to_string(),
+            added_by: "直接连接回显端点的地址，无法伪造".to_string(),
+            valid_ip: remote.is_some(),
+            trusted: remote.map(is_trusted).unwrap_or(false),
+        });
+    }
+
+    // 从右向左跳过可信代理，第一个不可信的地址才是真实客户端
+    let client_index = hops.iter().rposition(|hop| !hop.trusted).unwrap_or(0);
+    let client_ip = hops.get(client_index).map(|hop| hop.address.clone());
+    let leftmost_ip = forwarded_for.first().cloned();
+    let real_ip_header = header_values("x-real-ip")
+        .first()
+        .map(|v| v.trim().to_string());
+
+    if client_index > 0 {
+        let unverified: Vec<&str> = hops[..client_index]
+            .iter()
+            .map(|hop| hop.address.as_str())
+            .collect();
+        findings.push(format!(
+            "客户端 {} 左侧还有 {} 个地址（{}），它们不是可信代理写入的，无法验证",
+            client_ip.as_deref().unwrap_or_default(),
+            unverified.len(),
+            unverified.join(", ")
+        ));
+    }
+    if leftmost_ip.is_some() && leftmost_ip != client_ip {
+        findings.push(format!(
+            "直接取 X-Forwarded-For 最左侧地址会得到 {}，而按可信代理计算的客户端是 {}",
+            leftmost_ip.as_deref().unwrap_or_default(),
+            client_ip.as_deref().unwrap_or("（未知）")
+        ));
+    }
+    if let Some(real_ip) = &real_ip_header {
+        if Some(real_ip) != client_ip.as_ref() {
+            findings.push(format!(
+                "X-Real-IP 为 {}，与计算出的客户端 IP 不一致",
+                real_ip
+            ));
+        }
+    }
+
+    let spoof_passed_through = spoofed.map(|spoofed| {
+        let in_chain = forwarded_for
+            .iter()
+            .any(|a| parse_address(a) == Some(spoofed));
+        if in_chain {
+            findings.push(format!(
+                "代理链原样透传了客户端伪造的 X-Forwarded-For ({})，应在第一跳代理覆盖而不是追加",
+                spoofed
+            ));
+        }
+        if real_ip_header.as_deref().and_then(parse_address) == Some(spoofed) {
+            findings.push(
+                "X-Real-IP 取自伪造的 X-Forwarded-For，攻击者可以任意冒充客户端 IP".to_string(),
+            );
+        }
+        in_chain
+    });
+
+    if !forwarded.is_empty() {
+        let forwarded_chain: Vec<String> = forwarded
+            .iter()
+            .filter_map(|e| e.for_addr.clone())
+            .collect();
+        if !forwarded_for.is_empty() && forwarded_chain != forwarded_for {
+            findings.push(format!(
+                "Forwarded 记录的链路 [{}] 与 X-Forwarded-For [{}] 不一致，不同代理可能只维护了其中一个",
+                forwarded_chain.join(", "),
+                forwarded_for.join(", ")
+            ));
+        }
+    }
+
+    ProxyHeaderReport {
+        url: String::new(),
+        remote_addr: echoed.remote_addr.clone(),
+        forwarding_headers,
+        forwarded_for,
+        forwarded,
+        hops,
+        leftmost_ip,
+        client_ip,
+        real_ip_header,
+        spoof_passed_through,
+        findings,
+    }
+}
+
+/// 解析 RFC 7239 Forwarded 头，如 for="[2001:db8::1]:443";proto=https, for=198.51.100.7
+fn parse_forwarded(value: &str) -> Vec<ForwardedElement> {
+    let mut elements = Vec::new();
+    for element in split_unquoted(value, ',') {
+        let mut parsed = ForwardedElement::default();
+        for pair in split_unquoted(&element, ';') {
+            let Some((key, raw)) = pair.split_once('=') else {
+                continue;
+            };
+            let raw = raw.trim();
+            let value = raw
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .map(|v| v.replace("\\\"", "\"").replace("\\\\", "\\"))
+                .unwrap_or_else(|| raw.to_string());
+            match key.trim().to_lowercase().as_str() {
+                "for" => parsed.for_addr = Some(strip_node_port(&value)),
+                "by" => parsed.by = Some(strip_node_port(&value)),
+                "proto" => parsed.proto = Some(value),
+                "host" => parsed.host = Some(value),
+                _ => {}
+            }
+        }
+        if parsed != ForwardedElement::default() {
+            elements.push(parsed);
+        }
+    }
+    elements
+}
+
+fn split_unquoted(value: &str, separator: char) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    let mut escaped = false;
+    for c in value.chars() {
+        if escaped {
+            escaped = false;
+        } else if quoted && c == '\\' {
+            escaped = true;
+        } else if c == '"' {
+            quoted = !quoted;
+        } else if c == separator && !quoted {
+            parts.push(std::mem::take(&mut current).trim().to_string());
+            continue;
+        }
+        current.push(c);
+    }
+    parts.push(current.trim().to_string());
+    parts.retain(|p| !p.is_empty());
+    parts
+}
+
+/// 去掉节点标识中的端口和 IPv6 方括号，unknown 和 _混淆标识原样保留
+fn strip_node_port(node: &str) -> String {
+    if let Some(rest) = node.strip_prefix('[') {
+        return rest.split(']').next().unwrap_or(rest).to_string();
+    }
+    match node.rsplit_once(':') {
+        Some((host, _)) if host.parse::<IpAddr>().is_ok() => host.to_string(),
+        _ => node.to_string(),
+    }
+}
+
+/// 解析 IP 地址，兼容带端口和方括号的写法
+fn parse_address(value: &str) -> Option<IpAddr> {
+    let value = value.trim();
+    value
+        .parse::<IpAddr>()
+        .ok()
+        .or_else(|| value.parse::<SocketAddr>().ok().map(|a| a.ip()))
+        .or_else(|| strip_node_port(value).parse::<IpAddr>().ok())
+}
+
+fn parse_cidr(value: &str) -> Result<(IpAddr, u8), String> {
+    let value = value.trim();
+    let (address, prefix) = match value.split_once('/') {
+        Some((address, prefix)) => (address, Some(prefix)),
+        None => (value, None),
+    };
+    let ip: IpAddr = address
+        .parse()
+        .map_err(|_| format!("可信代理地址无效: {}", value))?;
+    let max = if ip.is_ipv4() { 32 } else { 128 };
+    let prefix = match prefix {
+        Some(p) => p
+            .parse::<u8>()
+            .ok()
+            .filter(|p| *p <= max)
+            .ok_or_else(|| format!("可信代理网段前缀无效: {}", value))?,
+        None => max,
+    };
+    Ok((ip, prefix))
+}
+
+fn cidr_contains((network, prefix): (IpAddr, u8), ip: IpAddr) -> bool {
+    match (network, ip) {
+        (IpAddr::V4(net), IpAddr::V4(ip)) => {
+            let mask = u32::MAX.checked_shl(32 - prefix as u32).unwrap_or(0);
+            u32::from(net) & mask == u32::from(ip) & mask
+        }
+        (IpAddr::V6(net), IpAddr::V6(ip)) => {
+            let mask = u128::MAX.checked_shl(128 - prefix as u32).unwrap_or(0);
+            u128::from(net) & mask == u128::from(ip) & mask
+        }
+        _ => false,
+    }
+}
+
+fn is_internal(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => v4.is_loopback() || v4.is_private() || v4.is_link_local(),
+        IpAddr::V6(v6) => {
+            v6.is_loopback()
+                || (v6.segments()[0] & 0xfe00) == 0xfc00
+                || (v6.segments()[0] & 0xffc0) == 0xfe80
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_forwarded_and_cidr() {
+        let elements = parse_forwarded(
+            r#"for="_gazonk", For="[2001:db8:cafe::17]:4711";proto=https;by=203.0.113.43, for=192.0.2.60;host="example.com""#,
+        );
+        assert_eq!(elements.len(), 3);
+        assert_eq!(elements[0].for_addr.as_deref(), Some("_gazonk"));
+        assert_eq!(elements[1].for_addr.as_deref(), Some("2001:db8:cafe::17"));
+        assert_eq!(elements[1].proto.as_deref(), Some("https"));
+        assert_eq!(elements[1].by.as_deref(), Some("203.0.113.43"));
+        assert_eq!(elements[2].host.as_deref(), Some("example.com"));
+
+        let net = parse_cidr("10.0.0.0/8").unwrap();
+        assert!(cidr_contains(net, "10.20.30.40".parse().unwrap()));
+        assert!(!cidr_contains(net, "11.0.0.1".parse().unwrap()));
+        assert!(cidr_contains(
+            parse_cidr("2001:db8::/32").unwrap(),
+            "2001:db8:1::1".parse().unwrap()
+        ));
+        assert!(parse_cidr("10.0.0.0/33").is_err());
+        assert_eq!(
+            parse_address("[2001:db8::1]:443"),
+            Some("2001:db8::1".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_analyze_proxy_chain() {
+        let echoed = parse_echo_response(
+            r#"{"headers": {"X-Forwarded-For": "203.0.113.66, 198.51.100.7, 10.0.0.5", "X-Real-Ip": "203.0.113.66"}, "origin": "10.0.0.9"}"#,
+        )
+        .unwrap();
+        assert_eq!(echoed.remote_addr.as_deref(), Some("10.0.0.9"));
+
+        let report = analyze_headers(&echoed, &[], Some(SPOOFED_IP.parse().unwrap()));
+        assert_eq!(
+            report.forwarded_for,
+            vec!["203.0.113.66", "198.51.100.7", "10.0.0.5"]
+        );
+        assert_eq!(report.hops.len(), 4);
+        assert!(report.hops[1].added_by.contains("10.0.0.5"));
+        assert_eq!(report.client_ip.as_deref(), Some("198.51.100.7"));
+        assert_eq!(report.leftmost_ip.as_deref(), Some("203.0.113.66"));
+        assert_eq!(report.spoof_passed_through, Some(true));
+        assert!(report
+            .findings
+            .iter()
+            .any(|f| f.contains("X-Real-IP 取自伪造")));
+
+        // 把中间地址加入可信列表后，伪造地址会被当作客户端
+        let trusted = vec![
+            parse_cidr("10.0.0.0/8").unwrap(),
+            parse_cidr("198.51.100.7").unwrap(),
+        ];
+        let report = analyze_headers(&echoed, &trusted, None);
+        assert_eq!(report.client_ip.as_deref(), Some("203.0.113.66"));
+        assert_eq!(report.spoof_passed_through, None);
+    }
+}
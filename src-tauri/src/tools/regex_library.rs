@@ -0,0 +1,482 @@
+use crate::tools::regex_tester::{self, RegexFlags};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+const ALL_ENGINES: [&str; 5] = ["rust", "re2", "pcre", "golang", "javascript"];
+/// 依赖环视、反向引用的表达式只有 PCRE 引擎支持
+const PCRE_ONLY: [&str; 1] = ["pcre"];
+
+/// (分类 ID, 显示名称)
+const CATEGORIES: &[(&str, &str)] = &[
+    ("web", "Web"),
+    ("network", "网络"),
+    ("datetime", "日期时间"),
+    ("identifier", "标识符"),
+    ("finance", "金融"),
+    ("text", "文本"),
+    ("custom", "自定义"),
+];
+
+/// 串行化正则库文件的读写
+static LIBRARY_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegexTestCase {
+    pub input: String,
+    pub should_match: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegexLibraryPattern {
+    #[serde(default)]
+    pub id: String,
+    pub name: String,
+    pub category: String,
+    pub pattern: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub flags: RegexFlags,
+    #[serde(default)]
+    pub engines: Vec<String>, // 支持的引擎，保存时为空则自动检测
+    #[serde(default)]
+    pub test_cases: Vec<RegexTestCase>,
+    #[serde(default)]
+    pub builtin: bool,
+    #[serde(default)]
+    pub favorite: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegexLibraryCategory {
+    pub id: String,
+    pub name: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegexLibrary {
+    pub categories: Vec<RegexLibraryCategory>,
+    pub patterns: Vec<RegexLibraryPattern>,
+}
+
+/// 持久化内容：用户自定义的表达式和收藏的内置表达式 id
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct RegexLibraryStore {
+    #[serde(default)]
+    patterns: Vec<RegexLibraryPattern>,
+    #[serde(default)]
+    favorites: Vec<String>,
+}
+
+/// 获取正则库，可按分类和引擎过滤，收藏的排在前面
+#[tauri::command]
+pub async fn get_regex_library(
+    category: Option<String>,
+    engine: Option<String>,
+) -> Result<RegexLibrary, String> {
+    let store = {
+        let _guard = LIBRARY_LOCK.lock().unwrap();
+        load_store(&get_library_path())
+    };
+    let all = merge_patterns(&store);
+
+    let categories = CATEGORIES
+        .iter()
+        .map(|(id, name)| RegexLibraryCategory {
+            id: id.to_string(),
+            name: name.to_string(),
+            count: all.iter().filter(|p| p.category == *id).count(),
+        })
+        .collect();
+
+    let category = category.filter(|c| !c.is_empty());
+    let engine = engine.filter(|e| !e.is_empty());
+    let mut patterns: Vec<RegexLibraryPattern> = all
+        .into_iter()
+        .filter(|p| category.as_ref().is_none_or(|c| &p.category == c))
+        .filter(|p| engine.as_ref().is_none_or(|e| p.engines.contains(e)))
+        .collect();
+    patterns.sort_by_key(|p| !p.favorite);
+
+    Ok(RegexLibrary {
+        categories,
+        patterns,
+    })
+}
+
+/// 保存自定义表达式（按 id 匹配，id 为空时新增），内置表达式不可修改
+#[tauri::command]
+pub async fn save_regex_pattern(
+    pattern: RegexLibraryPattern,
+) -> Result<RegexLibraryPattern, String> {
+    let mut pattern = normalize_pattern(pattern).await?;
+    if builtin_patterns().iter().any(|p| p.id == pattern.id) {
+        return Err("内置表达式不可修改".to_string());
+    }
+    if pattern.id.is_empty() {
+        pattern.id = format!(
+            "custom-{:x}",
+            Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        );
+    }
+
+    let path = get_library_path();
+    let _guard = LIBRARY_LOCK.lock().unwrap();
+    let mut store = load_store(&path);
+    match store.patterns.iter_mut().find(|p| p.id == pattern.id) {
+        Some(existing) => *existing = pattern.clone(),
+        None => store.patterns.push(pattern.clone()),
+    }
+    save_store(&path, &store)?;
+    Ok(pattern)
+}
+
+/// 删除自定义表达式
+#[tauri::command]
+pub async fn delete_regex_pattern(id: String) -> Result<(), String> {
+    let path = get_library_path();
+    let _guard = LIBRARY_LOCK.lock().unwrap();
+    let mut store = load_store(&path);
+    let before = store.patterns.len();
+    store.patterns.retain(|p| p.id != id);
+    if store.patterns.len() == before {
+        return Err("自定义表达式不存在".to_string());
+    }
+    save_store(&path, &store)
+}
+
+/// 收藏或取消收藏表达式，内置和自定义表达式均可收藏
+#[tauri::command]
+pub async fn set_regex_pattern_favorite(id: String, favorite: bool) -> Result<(), String> {
+    let path = get_library_path();
+    let _guard = LIBRARY_LOCK.lock().unwrap();
+    let mut store = load_store(&path);
+    if let Some(custom) = store.patterns.iter_mut().find(|p| p.id == id) {
+        custom.favorite = favorite;
+    } else if builtin_patterns().iter().any(|p| p.id == id) {
+        store.favorites.retain(|f| f != &id);
+        if favorite {
+            store.favorites.push(id);
+        }
+    } else {
+        return Err("表达式不存在".to_string());
+    }
+    save_store(&path, &store)
+}
+
+fn merge_patterns(store: &RegexLibraryStore) -> Vec<RegexLibraryPattern> {
+    let mut patterns = builtin_patterns();
+    for pattern in patterns.iter_mut() {
+        pattern.favorite = store.favorites.contains(&pattern.id);
+    }
+    patterns.extend(store.patterns.iter().cloned());
+    patterns
+}
+
+async fn normalize_pattern(pattern: RegexLibraryPattern) -> Result<RegexLibraryPattern, String> {
+    let mut pattern = pattern;
+    pattern.name = pattern.name.trim().to_string();
+    if pattern.name.is_empty() {
+        return Err("名称不能为空".to_string());
+    }
+    if pattern.pattern.is_empty() {
+        return Err("正则表达式不能为空".to_string());
+    }
+    if !CATEGORIES.iter().any(|(id, _)| *id == pattern.category) {
+        pattern.category = "custom".to_string();
+    }
+    pattern.builtin = false;
+
+    // 未指定引擎时按实际能否编译推断
+    let candidates: Vec<String> = if pattern.engines.is_empty() {
+        ALL_ENGINES.iter().map(|e| e.to_string()).collect()
+    } else {
+        pattern.engines.clone()
+    };
+    let mut engines = Vec::new();
+    for engine in candidates {
+        if regex_tester::validate_regex(pattern.pattern.clone(), engine.clone()).await? {
+            engines.push(engine);
+        } else if !pattern.engines.is_empty() {
+            return Err(format!("表达式在 {} 引擎下无效", engine));
+        }
+    }
+    if engines.is_empty() {
+        return Err("表达式在所有引擎下均无效".to_string());
+    }
+    pattern.engines = engines;
+    Ok(pattern)
+}
+
+fn builtin_patterns() -> Vec<RegexLibraryPattern> {
+    let case = |input: &str, should_match: bool| RegexTestCase {
+        input: input.to_string(),
+        should_match,
+    };
+    let entry = |id: &str,
+                 name: &str,
+                 category: &str,
+                 pattern: &str,
+                 description: &str,
+                 engines: &[&str],
+                 test_cases: Vec<RegexTestCase>| RegexLibraryPattern {
+        id: id.to_string(),
+        name: name.to_string(),
+        category: category.to_string(),
+        pattern: pattern.to_string(),
+        description: description.to_string(),
+        flags: RegexFlags::default(),
+        engines: engines.iter().map(|e| e.to_string()).collect(),
+        test_cases,
+        builtin: true,
+        favorite: false,
+    };
+
+    vec![
+        entry(
+            "email",
+            "邮箱地址",
+            "web",
+            r"^[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}$",
+            "常见邮箱格式，不覆盖 RFC 5322 的全部写法",
+            &ALL_ENGINES,
+            vec![
+                case("dev.ops+ci@example.com", true),
+                case("user@localhost", false),
+                case("a@@b.com", false),
+            ],
+        ),
+        entry(
+            "url",
+            "HTTP(S) URL",
+            "web",
+            r"^https?://[A-Za-z0-9.-]+(?::\d{1,5})?(?:/[^\s?#]*)?(?:\?[^\s#]*)?(?:#\S*)?$",
+            "协议、主机、端口、路径、查询参数和片段",
+            &ALL_ENGINES,
+            vec![
+                case("https://example.com:8443/a/b?x=1#top", true),
+                case("http://localhost", true),
+                case("ftp://example.com", false),
+            ],
+        ),
+        entry(
+            "hex-color",
+            "十六进制颜色",
+            "web",
+            r"^#(?:[0-9a-fA-F]{3}){1,2}$",
+            "#RGB 或 #RRGGBB",
+            &ALL_ENGINES,
+            vec![
+                case("#1e90ff", true),
+                case("#FFF", true),
+                case("#12345", false),
+            ],
+        ),
+        entry(
+            "ipv4",
+            "IPv4 地址",
+            "network",
+            r"^(?:(?:25[0-5]|2[0-4]\d|1\d\d|[1-9]?\d)\.){3}(?:25[0-5]|2[0-4]\d|1\d\d|[1-9]?\d)$",
+            "点分十进制，每段 0-255，不允许前导零",
+            &ALL_ENGINES,
+            vec![
+                case("192.168.0.1", true),
+                case("255.255.255.255", true),
+                case("256.1.1.1", false),
+                case("01.2.3.4", false),
+            ],
+        ),
+        entry(
+            "ipv6",
+            "IPv6 地址",
+            "network",
+            r"^(?:(?:[0-9A-Fa-f]{1,4}:){7}[0-9A-Fa-f]{1,4}|(?:[0-9A-Fa-f]{1,4}:){1,7}:|(?:[0-9A-Fa-f]{1,4}:){1,6}:[0-9A-Fa-f]{1,4}|(?:[0-9A-Fa-f]{1,4}:){1,5}(?::[0-9A-Fa-f]{1,4}){1,2}|(?:[0-9A-Fa-f]{1,4}:){1,4}(?::[0-9A-Fa-f]{1,4}){1,3}|(?:[0-9A-Fa-f]{1,4}:){1,3}(?::[0-9A-Fa-f]{1,4}){1,4}|(?:[0-9A-Fa-f]{1,4}:){1,2}(?::[0-9A-Fa-f]{1,4}){1,5}|[0-9A-Fa-f]{1,4}:(?::[0-9A-Fa-f]{1,4}){1,6}|:(?:(?::[0-9A-Fa-f]{1,4}){1,7}|:))$",
+            "完整及 :: 压缩写法，不含内嵌 IPv4 和区域标识",
+            &ALL_ENGINES,
+            vec![
+                case("2001:db8::1", true),
+                case("::1", true),
+                case("fe80:0:0:0:200:f8ff:fe21:67cf", true),
+                case("2001:db8:::1", false),
+            ],
+        ),
+        entry(
+            "iso-date",
+            "ISO 8601 日期",
+            "datetime",
+            r"^\d{4}-(?:0[1-9]|1[0-2])-(?:0[1-9]|[12]\d|3[01])$",
+            "YYYY-MM-DD，不校验各月天数",
+            &ALL_ENGINES,
+            vec![
+                case("2024-02-29", true),
+                case("2024-13-01", false),
+                case("2024-1-1", false),
+            ],
+        ),
+        entry(
+            "iso-datetime",
+            "ISO 8601 日期时间",
+            "datetime",
+            r"^\d{4}-(?:0[1-9]|1[0-2])-(?:0[1-9]|[12]\d|3[01])T(?:[01]\d|2[0-3]):[0-5]\d:[0-5]\d(?:\.\d+)?(?:Z|[+-](?:[01]\d|2[0-3]):[0-5]\d)$",
+            "RFC 3339 格式，必须带时区",
+            &ALL_ENGINES,
+            vec![
+                case("2024-05-01T08:30:00Z", true),
+                case("2024-05-01T08:30:00.123+08:00", true),
+                case("2024-05-01 08:30:00", false),
+            ],
+        ),
+        entry(
+            "uuid",
+            "UUID",
+            "identifier",
+            r"^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[1-8][0-9a-fA-F]{3}-[89abAB][0-9a-fA-F]{3}-[0-9a-fA-F]{12}$",
+            "RFC 9562 版本 1-8",
+            &ALL_ENGINES,
+            vec![
+                case("550e8400-e29b-41d4-a716-446655440000", true),
+                case("550e8400-e29b-91d4-a716-446655440000", false),
+            ],
+        ),
+        entry(
+            "semver",
+            "语义化版本",
+            "identifier",
+            r"^(0|[1-9]\d*)\.(0|[1-9]\d*)\.(0|[1-9]\d*)(?:-((?:0|[1-9]\d*|\d*[a-zA-Z-][0-9a-zA-Z-]*)(?:\.(?:0|[1-9]\d*|\d*[a-zA-Z-][0-9a-zA-Z-]*))*))?(?:\+([0-9a-zA-Z-]+(?:\.[0-9a-zA-Z-]+)*))?$",
+            "semver.org 官方表达式，捕获主版本、次版本、修订号、预发布和构建元数据",
+            &ALL_ENGINES,
+            vec![
+                case("1.2.3-rc.1+build.5", true),
+                case("0.10.0", true),
+                case("1.02.3", false),
+                case("v1.2.3", false),
+            ],
+        ),
+        entry(
+            "credit-card",
+            "银行卡号（国际卡组织）",
+            "finance",
+            r"^(?:4\d{12}(?:\d{3})?|5[1-5]\d{14}|2(?:2[2-9]\d|[3-6]\d\d|7[01]\d|720)\d{12}|3[47]\d{13}|6(?:011|5\d\d)\d{12})$",
+            "Visa、Mastercard、American Express、Discover 的号段与长度，不做 Luhn 校验",
+            &ALL_ENGINES,
+            vec![
+                case("4111111111111111", true),
+                case("378282246310005", true),
+                case("5105105105105100", true),
+                case("1234567890123456", false),
+            ],
+        ),
+        entry(
+            "cn-mobile",
+            "中国大陆手机号",
+            "text",
+            r"^1[3-9]\d{9}$",
+            "11 位，不含 +86 前缀",
+            &ALL_ENGINES,
+            vec![case("13800138000", true), case("12800138000", false)],
+        ),
+        entry(
+            "strong-password",
+            "强密码",
+            "text",
+            r"^(?=.*[a-z])(?=.*[A-Z])(?=.*\d)(?=.*[^A-Za-z0-9]).{8,}$",
+            "至少 8 位，包含大小写字母、数字和特殊字符；依赖先行断言",
+            &PCRE_ONLY,
+            vec![case("Passw0rd!", true), case("password1", false)],
+        ),
+    ]
+}
+
+fn get_library_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("devtools")
+        .join("regex-library.json")
+}
+
+fn load_store(path: &Path) -> RegexLibraryStore {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_store(path: &Path, store: &RegexLibraryStore) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("创建配置目录失败: {}", e))?;
+    }
+    let content =
+        serde_json::to_string_pretty(store).map_err(|e| format!("序列化正则库失败: {}", e))?;
+    fs::write(path, content).map_err(|e| format!("保存正则库失败: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_builtin_patterns_pass_their_cases() {
+        for pattern in builtin_patterns() {
+            assert!(
+                CATEGORIES.iter().any(|(id, _)| *id == pattern.category),
+                "{} has unknown category",
+                pattern.id
+            );
+            for engine in &pattern.engines {
+                for case in &pattern.test_cases {
+                    let result = regex_tester::test_regex(
+                        pattern.pattern.clone(),
+                        case.input.clone(),
+                        pattern.flags.clone(),
+                        engine.clone(),
+                    )
+                    .await
+                    .unwrap();
+                    assert!(result.is_valid, "{} invalid for {}", pattern.id, engine);
+                    assert_eq!(
+                        result.match_count > 0,
+                        case.should_match,
+                        "{} on {:?} with {}",
+                        pattern.id,
+                        case.input,
+                        engine
+                    );
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_normalize_detects_engines() {
+        let lookahead = RegexLibraryPattern {
+            id: String::new(),
+            name: " 价格 ".to_string(),
+            category: "unknown".to_string(),
+            pattern: r"\d+(?=元)".to_string(),
+            description: String::new(),
+            flags: RegexFlags::default(),
+            engines: Vec::new(),
+            test_cases: Vec::new(),
+            builtin: true,
+            favorite: false,
+        };
+        let normalized = normalize_pattern(lookahead.clone()).await.unwrap();
+        assert_eq!(normalized.name, "价格");
+        assert_eq!(normalized.category, "custom");
+        assert!(!normalized.builtin);
+        assert_eq!(normalized.engines, vec!["pcre"]);
+
+        let mut explicit = lookahead;
+        explicit.engines = vec!["rust".to_string()];
+        assert!(normalize_pattern(explicit).await.is_err());
+
+        let mut store = RegexLibraryStore::default();
+        store.favorites.push("uuid".to_string());
+        let merged = merge_patterns(&store);
+        assert!(merged.iter().find(|p| p.id == "uuid").unwrap().favorite);
+    }
+}
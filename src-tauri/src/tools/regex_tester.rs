@@ -1,5 +1,17 @@
 use pcre2::bytes::RegexBuilder as Pcre2RegexBuilder;
 use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Seek, SeekFrom};
+use std::time::Instant;
+
+/// 文件模式默认返回的匹配条数
+const DEFAULT_FILE_MAX_RESULTS: usize = 1000;
+/// 文件模式单次最多返回的匹配条数
+const MAX_FILE_RESULTS: usize = 10000;
+/// 明细模式最多返回的替换位置数
+const MAX_REPLACE_SITES: usize = 5000;
+/// 文件模式下匹配文本的最大字符数，超出部分截断
+const MAX_MATCH_PREVIEW_CHARS: usize = 500;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct RegexFlags {
@@ -43,6 +55,7 @@ pub struct RegexReplaceRequest {
     pub flags: RegexFlags,
     pub engine: String,
     pub replace_all: bool,
+    pub detailed: bool, // 是否返回每处替换的明细
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -68,6 +81,187 @@ pub struct RegexReplaceResult {
     pub error_message: Option<String>,
     pub result: Option<String>,
     pub replacement_count: usize,
+    pub replacement_syntax: Option<String>, // 当前引擎替换模板语法说明
+    pub sites: Option<Vec<RegexReplaceSite>>, // 仅明细模式返回
+    pub sites_truncated: bool,              // 明细超过上限时只返回前面部分
+}
+
+/// 单处替换明细，偏移均为字节偏移
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RegexReplaceSite {
+    pub start: usize, // 原文中的位置
+    pub end: usize,
+    pub output_start: usize, // 替换结果中的位置
+    pub output_end: usize,
+    pub matched: String,
+    pub replacement: String,
+    pub groups: Vec<Option<String>>,
+    pub named_groups: std::collections::HashMap<String, Option<String>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RegexFileCursor {
+    pub byte_offset: u64,    // 下一次读取的行首偏移
+    pub line: usize,         // byte_offset 所在行号，从 1 开始
+    pub skip_in_line: usize, // 该行中已返回的匹配数
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RegexFileTestRequest {
+    pub file_path: String,
+    pub pattern: String,
+    pub flags: RegexFlags,
+    pub engine: String,
+    pub max_results: Option<usize>,      // 默认 1000，最大 10000
+    pub cursor: Option<RegexFileCursor>, // 上一次返回的 next_cursor，为空时从头开始
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RegexLineMatch {
+    pub line: usize,       // 行号，从 1 开始
+    pub column: usize,     // 起始列（字符），从 1 开始
+    pub end_column: usize, // 结束列（字符，不含）
+    pub full_match: String,
+    pub truncated: bool,
+    pub groups: Vec<Option<String>>,
+    pub named_groups: std::collections::HashMap<String, Option<String>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RegexFileTestResult {
+    pub is_valid: bool,
+    pub error_message: Option<String>,
+    pub matches: Vec<RegexLineMatch>,
+    pub match_count: usize,
+    pub file_size: u64,
+    pub scanned_bytes: u64, // 本次扫描结束时的文件偏移
+    pub has_more: bool,
+    pub next_cursor: Option<RegexFileCursor>,
+    pub elapsed_ms: u64,
+}
+
+/// 文件模式使用的匹配器，按行匹配
+enum LineMatcher {
+    Regex(regex::Regex),
+    Pcre(pcre2::bytes::Regex),
+}
+
+impl LineMatcher {
+    fn build(pattern: &str, flags: &RegexFlags, engine: &str) -> Result<Self, String> {
+        let inline_flags = |with_greed: bool| {
+            let mut value = String::new();
+            if flags.case_insensitive {
+                value.push('i');
+            }
+            if flags.multiline {
+                value.push('m');
+            }
+            if flags.dot_matches_new_line {
+                value.push('s');
+            }
+            if with_greed && flags.swap_greed {
+                value.push('U');
+            }
+            value
+        };
+        let regex = match engine {
+            "rust" => build_rust_regex(pattern, flags)
+                .map_err(|e| format!("正则表达式语法错误: {}", e))?,
+            "re2" | "javascript" => {
+                let value = inline_flags(false);
+                let pattern = if value.is_empty() {
+                    pattern.to_string()
+                } else {
+                    format!("(?{}){}", value, pattern)
+                };
+                regex::Regex::new(&pattern).map_err(|e| format!("正则表达式语法错误: {}", e))?
+            }
+            "golang" => {
+                let value = inline_flags(true);
+                let pattern = if value.is_empty() {
+                    pattern.to_string()
+                } else {
+                    format!("(?{}:{})", value, pattern)
+                };
+                regex::Regex::new(&convert_to_go_style(&pattern))
+                    .map_err(|e| format!("Golang正则表达式语法错误: {}", e))?
+            }
+            "pcre" => {
+                let mut builder = Pcre2RegexBuilder::new();
+                builder
+                    .caseless(flags.case_insensitive)
+                    .multi_line(flags.multiline)
+                    .dotall(flags.dot_matches_new_line)
+                    .utf(flags.unicode);
+                let re = builder
+                    .build(pattern)
+                    .map_err(|e| format!("PCRE2正则表达式语法错误: {}", e))?;
+                return Ok(LineMatcher::Pcre(re));
+            }
+            _ => return Err("不支持的正则表达式引擎".to_string()),
+        };
+        Ok(LineMatcher::Regex(regex))
+    }
+
+    /// 返回一行内的全部匹配，位置为行内字节偏移
+    fn find_in_line(&self, line: &str) -> Result<Vec<LineHit>, String> {
+        let mut hits = Vec::new();
+        match self {
+            LineMatcher::Regex(re) => {
+                for caps in re.captures_iter(line) {
+                    let Some(full) = caps.get(0) else {
+                        continue;
+                    };
+                    hits.push(LineHit {
+                        start: full.start(),
+                        end: full.end(),
+                        groups: (1..caps.len())
+                            .map(|i| caps.get(i).map(|m| m.as_str().to_string()))
+                            .collect(),
+                        named_groups: re
+                            .capture_names()
+                            .flatten()
+                            .map(|name| {
+                                (
+                                    name.to_string(),
+                                    caps.name(name).map(|m| m.as_str().to_string()),
+                                )
+                            })
+                            .collect(),
+                    });
+                }
+            }
+            LineMatcher::Pcre(re) => {
+                for caps in re.captures_iter(line.as_bytes()) {
+                    let caps = caps.map_err(|e| format!("PCRE2匹配失败: {}", e))?;
+                    let Some(full) = caps.get(0) else {
+                        continue;
+                    };
+                    let text =
+                        |m: pcre2::bytes::Match| String::from_utf8_lossy(m.as_bytes()).to_string();
+                    hits.push(LineHit {
+                        start: full.start(),
+                        end: full.end(),
+                        groups: (1..caps.len()).map(|i| caps.get(i).map(text)).collect(),
+                        named_groups: re
+                            .capture_names()
+                            .iter()
+                            .flatten()
+                            .map(|name| (name.clone(), caps.name(name).map(text)))
+                            .collect(),
+                    });
+                }
+            }
+        }
+        Ok(hits)
+    }
+}
+
+struct LineHit {
+    start: usize,
+    end: usize,
+    groups: Vec<Option<String>>,
+    named_groups: std::collections::HashMap<String, Option<String>>,
 }
 
 fn build_rust_regex(pattern: &str, flags: &RegexFlags) -> Result<regex::Regex, regex::Error> {
@@ -532,6 +726,7 @@ pub async fn replace_regex(
     flags: RegexFlags,
     engine: String,
     replace_all: bool,
+    detailed: Option<bool>,
 ) -> Result<RegexReplaceResult, String> {
     if pattern.is_empty() {
         return Err("正则表达式不能为空".to_string());
@@ -544,53 +739,417 @@ pub async fn replace_regex(
         flags,
         engine,
         replace_all,
+        detailed: detailed.unwrap_or(false),
     };
 
-    match request.engine.as_str() {
+    let engine = request.engine.clone();
+    let mut result = match engine.as_str() {
         "rust" => replace_rust_regex(request).await,
         "re2" => replace_re2_regex(request).await,
         "pcre" => replace_pcre_regex(request).await,
         "golang" => replace_golang_regex(request).await,
         "javascript" => replace_javascript_regex(request).await,
         _ => Err("不支持的正则表达式引擎".to_string()),
+    }?;
+    result.replacement_syntax = Some(replacement_syntax_help(&engine).to_string());
+    Ok(result)
+}
+
+/// 各引擎替换模板语法的差异说明
+fn replacement_syntax_help(engine: &str) -> &'static str {
+    match engine {
+        "pcre" => "PCRE2: $1、${1}、\\1 引用编号捕获组，$name、${name} 引用命名捕获组，$$ 输出 $；\\U…\\E / \\L…\\E 转换大小写，\\u / \\l 转换下一个字符",
+        "javascript" => "JavaScript: $1-$99 引用编号捕获组，$<name> 引用命名捕获组，$& 为整个匹配，$` / $' 为匹配前 / 后的文本，$$ 输出 $；不支持大小写转换",
+        "re2" => "RE2: \\0-\\9 引用捕获组，\\\\ 输出反斜杠；不支持命名引用和大小写转换",
+        "golang" => "Go: $1、${1} 引用编号捕获组，$name、${name} 引用命名捕获组，$$ 输出 $；不支持大小写转换",
+        _ => "Rust: $1、${1} 引用编号捕获组，$name、${name} 引用命名捕获组，$$ 输出 $；不支持大小写转换",
     }
 }
 
-async fn replace_rust_regex(request: RegexReplaceRequest) -> Result<RegexReplaceResult, String> {
-    match build_rust_regex(&request.pattern, &request.flags) {
-        Ok(re) => {
-            let result = if request.replace_all {
-                re.replace_all(&request.text, request.replacement.as_str())
-                    .to_string()
-            } else {
-                re.replace(&request.text, request.replacement.as_str())
-                    .to_string()
-            };
+/// 替换模板语法
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ReplacementSyntax {
+    Rust,
+    Pcre,
+    JavaScript,
+    Re2,
+}
 
-            // 计算替换次数
-            let original_matches = re.find_iter(&request.text).count();
-            let replacement_count = if request.replace_all {
-                original_matches
-            } else {
-                if original_matches > 0 {
-                    1
+/// 单次匹配的上下文，groups[0] 为整个匹配
+struct TemplateContext<'a> {
+    groups: &'a [Option<String>],
+    names: &'a [Option<String>],
+    before: &'a str,
+    after: &'a str,
+}
+
+impl TemplateContext<'_> {
+    fn group(&self, index: usize) -> Result<&str, String> {
+        match self.groups.get(index) {
+            Some(value) => Ok(value.as_deref().unwrap_or("")),
+            None => Err(format!("替换模板引用了不存在的捕获组: {}", index)),
+        }
+    }
+
+    fn named(&self, name: &str) -> Result<&str, String> {
+        if let Ok(index) = name.parse::<usize>() {
+            return self.group(index);
+        }
+        match self.names.iter().position(|n| n.as_deref() == Some(name)) {
+            Some(index) => self.group(index),
+            None => Err(format!("替换模板引用了不存在的命名捕获组: {}", name)),
+        }
+    }
+}
+
+/// 按匹配位置拼接替换结果，spans 为 (起始, 结束, 捕获组)
+fn substitute(
+    text: &str,
+    spans: Vec<(usize, usize, Vec<Option<String>>)>,
+    names: &[Option<String>],
+    template: &str,
+    syntax: ReplacementSyntax,
+    detailed: bool,
+) -> RegexReplaceResult {
+    let mut output = String::with_capacity(text.len());
+    let mut sites = Vec::new();
+    let mut last = 0;
+    let replacement_count = spans.len();
+    for (start, end, groups) in spans {
+        let context = TemplateContext {
+            groups: &groups,
+            names,
+            before: &text[..start],
+            after: &text[end..],
+        };
+        let expanded = match syntax {
+            ReplacementSyntax::Rust => Ok(expand_rust_template(template, &context)),
+            ReplacementSyntax::Pcre => expand_pcre_template(template, &context),
+            ReplacementSyntax::JavaScript => Ok(expand_javascript_template(template, &context)),
+            ReplacementSyntax::Re2 => expand_re2_template(template, &context),
+        };
+        let expanded = match expanded {
+            Ok(expanded) => expanded,
+            Err(e) => {
+                return RegexReplaceResult {
+                    is_valid: false,
+                    error_message: Some(e),
+                    result: None,
+                    replacement_count: 0,
+                    replacement_syntax: None,
+                    sites: None,
+                    sites_truncated: false,
+                }
+            }
+        };
+        output.push_str(&text[last..start]);
+        let output_start = output.len();
+        output.push_str(&expanded);
+        last = end;
+
+        if detailed && sites.len() < MAX_REPLACE_SITES {
+            let named_groups = names
+                .iter()
+                .enumerate()
+                .filter_map(|(i, name)| {
+                    name.as_ref()
+                        .map(|n| (n.clone(), groups.get(i).cloned().flatten()))
+                })
+                .collect();
+            let mut groups = groups;
+            let matched = groups[0].take().unwrap_or_default();
+            groups.remove(0);
+            sites.push(RegexReplaceSite {
+                start,
+                end,
+                output_start,
+                output_end: output.len(),
+                matched,
+                replacement: expanded,
+                groups,
+                named_groups,
+            });
+        }
+    }
+    output.push_str(&text[last..]);
+    RegexReplaceResult {
+        is_valid: true,
+        error_message: None,
+        result: Some(output),
+        replacement_count,
+        replacement_syntax: None,
+        sites_truncated: detailed && replacement_count > sites.len(),
+        sites: detailed.then_some(sites),
+    }
+}
+
+fn collect_rust_spans(
+    re: &regex::Regex,
+    text: &str,
+    replace_all: bool,
+) -> Vec<(usize, usize, Vec<Option<String>>)> {
+    let mut spans = Vec::new();
+    for caps in re.captures_iter(text) {
+        let Some(full) = caps.get(0) else {
+            continue;
+        };
+        let groups = (0..caps.len())
+            .map(|i| caps.get(i).map(|m| m.as_str().to_string()))
+            .collect();
+        spans.push((full.start(), full.end(), groups));
+        if !replace_all {
+            break;
+        }
+    }
+    spans
+}
+
+/// 大小写转换状态：\U / \L 持续生效直到 \E，\u / \l 只作用于下一个字符
+#[derive(Default)]
+struct CaseWriter {
+    output: String,
+    mode: Option<bool>,     // Some(true) 大写，Some(false) 小写
+    next_one: Option<bool>, // 仅作用于下一个字符
+}
+
+impl CaseWriter {
+    fn push_str(&mut self, value: &str) {
+        for c in value.chars() {
+            let upper = self.next_one.take().or(self.mode);
+            match upper {
+                Some(true) => self.output.extend(c.to_uppercase()),
+                Some(false) => self.output.extend(c.to_lowercase()),
+                None => self.output.push(c),
+            }
+        }
+    }
+}
+
+/// 展开 Rust / Go 替换模板，与 regex::Captures::expand 一致：不存在的捕获组替换为空
+fn expand_rust_template(template: &str, context: &TemplateContext) -> String {
+    let chars: Vec<char> = template.chars().collect();
+    let mut output = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i] != '$' {
+            output.push(chars[i]);
+            i += 1;
+            continue;
+        }
+        if chars.get(i + 1) == Some(&'$') {
+            output.push('$');
+            i += 2;
+            continue;
+        }
+        let (name, consumed) = if chars.get(i + 1) == Some(&'{') {
+            match chars[i + 2..].iter().position(|c| *c == '}') {
+                Some(close) => (chars[i + 2..i + 2 + close].iter().collect(), close + 3),
+                None => (String::new(), 0),
+            }
+        } else {
+            let len = take_while(&chars[i + 1..], |c| c.is_ascii_alphanumeric() || c == '_');
+            (
+                chars[i + 1..i + 1 + len].iter().collect::<String>(),
+                len + 1,
+            )
+        };
+        if name.is_empty() {
+            output.push('$');
+            i += 1;
+            continue;
+        }
+        output.push_str(context.named(&name).unwrap_or(""));
+        i += consumed;
+    }
+    output
+}
+
+/// 展开 PCRE2 扩展替换模板
+fn expand_pcre_template(template: &str, context: &TemplateContext) -> Result<String, String> {
+    let chars: Vec<char> = template.chars().collect();
+    let mut writer = CaseWriter::default();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '$' if i + 1 < chars.len() => {
+                let next = chars[i + 1];
+                if next == '$' {
+                    writer.push_str("$");
+                    i += 2;
+                } else if next == '{' {
+                    let close = chars[i + 2..]
+                        .iter()
+                        .position(|c| *c == '}')
+                        .ok_or_else(|| "替换模板中的 ${ 缺少 }".to_string())?;
+                    let name: String = chars[i + 2..i + 2 + close].iter().collect();
+                    writer.push_str(context.named(&name)?);
+                    i += close + 3;
+                } else if next.is_ascii_digit() {
+                    let len = take_while(&chars[i + 1..], |c| c.is_ascii_digit());
+                    let index: String = chars[i + 1..i + 1 + len].iter().collect();
+                    writer.push_str(context.named(&index)?);
+                    i += len + 1;
+                } else if next.is_alphabetic() || next == '_' {
+                    let len = take_while(&chars[i + 1..], |c| c.is_alphanumeric() || c == '_');
+                    let name: String = chars[i + 1..i + 1 + len].iter().collect();
+                    writer.push_str(context.named(&name)?);
+                    i += len + 1;
                 } else {
-                    0
+                    writer.push_str("$");
+                    i += 1;
                 }
-            };
+            }
+            '\\' if i + 1 < chars.len() => {
+                let next = chars[i + 1];
+                i += 2;
+                match next {
+                    'U' => writer.mode = Some(true),
+                    'L' => writer.mode = Some(false),
+                    'E' => writer.mode = None,
+                    'u' => writer.next_one = Some(true),
+                    'l' => writer.next_one = Some(false),
+                    'n' => writer.push_str("\n"),
+                    'r' => writer.push_str("\r"),
+                    't' => writer.push_str("\t"),
+                    '0'..='9' => {
+                        let index = next.to_digit(10).unwrap_or(0) as usize;
+                        writer.push_str(context.group(index)?);
+                    }
+                    other => writer.push_str(other.encode_utf8(&mut [0; 4])),
+                }
+            }
+            c => {
+                writer.push_str(c.encode_utf8(&mut [0; 4]));
+                i += 1;
+            }
+        }
+    }
+    Ok(writer.output)
+}
 
-            Ok(RegexReplaceResult {
-                is_valid: true,
-                error_message: None,
-                result: Some(result),
-                replacement_count,
-            })
+/// 展开 JavaScript String.prototype.replace 替换模板，无法识别的 $ 序列保留原样
+fn expand_javascript_template(template: &str, context: &TemplateContext) -> String {
+    let chars: Vec<char> = template.chars().collect();
+    let group_count = context.groups.len() - 1;
+    let has_names = context.names.iter().any(|n| n.is_some());
+    let mut output = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i] != '$' || i + 1 >= chars.len() {
+            output.push(chars[i]);
+            i += 1;
+            continue;
+        }
+        match chars[i + 1] {
+            '$' => {
+                output.push('$');
+                i += 2;
+            }
+            '&' => {
+                output.push_str(context.groups[0].as_deref().unwrap_or(""));
+                i += 2;
+            }
+            '`' => {
+                output.push_str(context.before);
+                i += 2;
+            }
+            '\'' => {
+                output.push_str(context.after);
+                i += 2;
+            }
+            '<' if has_names => match chars[i + 2..].iter().position(|c| *c == '>') {
+                Some(close) => {
+                    let name: String = chars[i + 2..i + 2 + close].iter().collect();
+                    if let Some(index) = context
+                        .names
+                        .iter()
+                        .position(|n| n.as_deref() == Some(name.as_str()))
+                    {
+                        output.push_str(context.groups[index].as_deref().unwrap_or(""));
+                    }
+                    i += close + 3;
+                }
+                None => {
+                    output.push('$');
+                    i += 1;
+                }
+            },
+            d if d.is_ascii_digit() => {
+                // 优先按两位数解析，超出捕获组数量时退回一位数
+                let one = d.to_digit(10).unwrap_or(0) as usize;
+                let two = chars
+                    .get(i + 2)
+                    .and_then(|c| c.to_digit(10))
+                    .map(|d2| one * 10 + d2 as usize);
+                if let Some(two) = two.filter(|n| (1..=group_count).contains(n)) {
+                    output.push_str(context.groups[two].as_deref().unwrap_or(""));
+                    i += 3;
+                } else if (1..=group_count).contains(&one) {
+                    output.push_str(context.groups[one].as_deref().unwrap_or(""));
+                    i += 2;
+                } else {
+                    output.push('$');
+                    i += 1;
+                }
+            }
+            _ => {
+                output.push('$');
+                i += 1;
+            }
+        }
+    }
+    output
+}
+
+/// 展开 RE2 替换模板，只支持 \0-\9 和 \\
+fn expand_re2_template(template: &str, context: &TemplateContext) -> Result<String, String> {
+    let mut output = String::new();
+    let mut chars = template.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            output.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('\\') => output.push('\\'),
+            Some(d) if d.is_ascii_digit() => {
+                output.push_str(context.group(d.to_digit(10).unwrap_or(0) as usize)?)
+            }
+            _ => return Err("RE2 替换模板中的反斜杠只能后接数字或反斜杠".to_string()),
+        }
+    }
+    Ok(output)
+}
+
+fn take_while(chars: &[char], predicate: impl Fn(char) -> bool) -> usize {
+    chars.iter().take_while(|c| predicate(**c)).count()
+}
+
+async fn replace_rust_regex(request: RegexReplaceRequest) -> Result<RegexReplaceResult, String> {
+    match build_rust_regex(&request.pattern, &request.flags) {
+        Ok(re) => {
+            let names: Vec<Option<String>> = re
+                .capture_names()
+                .map(|name| name.map(|n| n.to_string()))
+                .collect();
+            let spans = collect_rust_spans(&re, &request.text, request.replace_all);
+            Ok(substitute(
+                &request.text,
+                spans,
+                &names,
+                &request.replacement,
+                ReplacementSyntax::Rust,
+                request.detailed,
+            ))
         }
         Err(e) => Ok(RegexReplaceResult {
             is_valid: false,
             error_message: Some(format!("正则表达式语法错误: {}", e)),
             result: None,
             replacement_count: 0,
+            replacement_syntax: None,
+            sites: None,
+            sites_truncated: false,
         }),
     }
 }
@@ -616,52 +1175,29 @@ async fn replace_re2_regex(request: RegexReplaceRequest) -> Result<RegexReplaceR
 
     match regex::Regex::new(&pattern) {
         Ok(re) => {
-            // RE2 的替换功能实现
-            let result = if request.replace_all {
-                // 手动实现全部替换
-                let mut result_text = request.text.clone();
-                let mut replacement_count = 0;
-
-                loop {
-                    let find_result = re.find(&result_text);
-                    if let Some(mat) = find_result {
-                        let start = mat.start();
-                        let end = mat.end();
-                        result_text.replace_range(start..end, &request.replacement);
-                        replacement_count += 1;
-                        // 为了避免无限循环，如果替换后没有变化就停止
-                        if start == end {
-                            break;
-                        }
-                    } else {
-                        break;
-                    }
-                }
-
-                (result_text, replacement_count)
-            } else {
-                // 单次替换
-                if let Some(mat) = re.find(&request.text) {
-                    let mut result_text = request.text.clone();
-                    result_text.replace_range(mat.start()..mat.end(), &request.replacement);
-                    (result_text, 1)
-                } else {
-                    (request.text.clone(), 0)
-                }
-            };
-
-            Ok(RegexReplaceResult {
-                is_valid: true,
-                error_message: None,
-                result: Some(result.0),
-                replacement_count: result.1,
-            })
+            // RE2 的替换模板使用 \0-\9 引用捕获组
+            let names: Vec<Option<String>> = re
+                .capture_names()
+                .map(|name| name.map(|n| n.to_string()))
+                .collect();
+            let spans = collect_rust_spans(&re, &request.text, request.replace_all);
+            Ok(substitute(
+                &request.text,
+                spans,
+                &names,
+                &request.replacement,
+                ReplacementSyntax::Re2,
+                request.detailed,
+            ))
         }
         Err(e) => Ok(RegexReplaceResult {
             is_valid: false,
             error_message: Some(format!("RE2正则表达式语法错误: {:?}", e)),
             result: None,
             replacement_count: 0,
+            replacement_syntax: None,
+            sites: None,
+            sites_truncated: false,
         }),
     }
 }
@@ -684,64 +1220,53 @@ async fn replace_pcre_regex(request: RegexReplaceRequest) -> Result<RegexReplace
         // PCRE2 中使用 ungreedy 的替代方法需要在模式中添加 (?U)
         // 暂时跳过此标志
     }
-    if !request.flags.unicode {
-        builder.utf(false);
-    }
+    builder
+        .utf(request.flags.unicode)
+        .ucp(request.flags.unicode);
 
     match builder.build(&request.pattern) {
         Ok(re) => {
             let text_bytes = request.text.as_bytes();
-
-            // 计算原始匹配数
-            let original_matches = re.find_iter(text_bytes).count();
-
-            // 简化替换实现
-            let result = if request.replace_all {
-                // 手动实现全部替换
-                let mut result_text = request.text.clone();
-                let mut replacement_count = 0;
-
-                // 重复查找并替换直到没有更多匹配
-                loop {
-                    let find_result = re.find(result_text.as_bytes());
-                    if let Ok(Some(mat)) = find_result {
-                        let start = mat.start();
-                        let end = mat.end();
-                        result_text.replace_range(start..end, &request.replacement);
-                        replacement_count += 1;
-                        if replacement_count >= original_matches * 2 {
-                            // 防止无限循环
-                            break;
-                        }
-                    } else {
-                        break;
-                    }
+            let mut spans = Vec::new();
+            for caps in re.captures_iter(text_bytes) {
+                let caps = caps.map_err(|e| format!("PCRE2匹配失败: {}", e))?;
+                let Some(full) = caps.get(0) else {
+                    continue;
+                };
+                // 按字节匹配时可能命中多字节字符内部，这样的位置无法替换
+                if !request.text.is_char_boundary(full.start())
+                    || !request.text.is_char_boundary(full.end())
+                {
+                    continue;
                 }
-
-                (result_text, replacement_count)
-            } else {
-                // 单次替换
-                if let Ok(Some(mat)) = re.find(text_bytes) {
-                    let mut result_text = request.text.clone();
-                    result_text.replace_range(mat.start()..mat.end(), &request.replacement);
-                    (result_text, 1)
-                } else {
-                    (request.text.clone(), 0)
+                let groups = (0..caps.len())
+                    .map(|i| {
+                        caps.get(i)
+                            .map(|m| String::from_utf8_lossy(m.as_bytes()).to_string())
+                    })
+                    .collect();
+                spans.push((full.start(), full.end(), groups));
+                if !request.replace_all {
+                    break;
                 }
-            };
-
-            Ok(RegexReplaceResult {
-                is_valid: true,
-                error_message: None,
-                result: Some(result.0),
-                replacement_count: result.1,
-            })
+            }
+            Ok(substitute(
+                &request.text,
+                spans,
+                re.capture_names(),
+                &request.replacement,
+                ReplacementSyntax::Pcre,
+                request.detailed,
+            ))
         }
         Err(e) => Ok(RegexReplaceResult {
             is_valid: false,
             error_message: Some(format!("PCRE2正则表达式语法错误: {}", e)),
             result: None,
             replacement_count: 0,
+            replacement_syntax: None,
+            sites: None,
+            sites_truncated: false,
         }),
     }
 }
@@ -749,8 +1274,48 @@ async fn replace_pcre_regex(request: RegexReplaceRequest) -> Result<RegexReplace
 async fn replace_javascript_regex(
     request: RegexReplaceRequest,
 ) -> Result<RegexReplaceResult, String> {
-    // 使用与test_javascript_regex相同的模式处理
-    replace_rust_regex(request).await
+    // 与 test_javascript_regex 相同的模式处理，替换模板按 String.prototype.replace 规则展开
+    let mut pattern = request.pattern.clone();
+    let mut flags = String::new();
+    if request.flags.case_insensitive {
+        flags.push('i');
+    }
+    if request.flags.multiline {
+        flags.push('m');
+    }
+    if request.flags.dot_matches_new_line {
+        flags.push('s');
+    }
+    if !flags.is_empty() {
+        pattern = format!("(?{}){}", flags, pattern);
+    }
+
+    match regex::Regex::new(&pattern) {
+        Ok(re) => {
+            let names: Vec<Option<String>> = re
+                .capture_names()
+                .map(|name| name.map(|n| n.to_string()))
+                .collect();
+            let spans = collect_rust_spans(&re, &request.text, request.replace_all);
+            Ok(substitute(
+                &request.text,
+                spans,
+                &names,
+                &request.replacement,
+                ReplacementSyntax::JavaScript,
+                request.detailed,
+            ))
+        }
+        Err(e) => Ok(RegexReplaceResult {
+            is_valid: false,
+            error_message: Some(format!("JavaScript正则表达式语法错误: {}", e)),
+            result: None,
+            replacement_count: 0,
+            replacement_syntax: None,
+            sites: None,
+            sites_truncated: false,
+        }),
+    }
 }
 
 async fn replace_golang_regex(request: RegexReplaceRequest) -> Result<RegexReplaceResult, String> {
@@ -783,37 +1348,28 @@ async fn replace_golang_regex(request: RegexReplaceRequest) -> Result<RegexRepla
 
     match regex::Regex::new(&pattern) {
         Ok(re) => {
-            let result = if request.replace_all {
-                re.replace_all(&request.text, request.replacement.as_str())
-                    .to_string()
-            } else {
-                re.replace(&request.text, request.replacement.as_str())
-                    .to_string()
-            };
-
-            let original_matches = re.find_iter(&request.text).count();
-            let replacement_count = if request.replace_all {
-                original_matches
-            } else {
-                if original_matches > 0 {
-                    1
-                } else {
-                    0
-                }
-            };
-
-            Ok(RegexReplaceResult {
-                is_valid: true,
-                error_message: None,
-                result: Some(result),
-                replacement_count,
-            })
+            let names: Vec<Option<String>> = re
+                .capture_names()
+                .map(|name| name.map(|n| n.to_string()))
+                .collect();
+            let spans = collect_rust_spans(&re, &request.text, request.replace_all);
+            Ok(substitute(
+                &request.text,
+                spans,
+                &names,
+                &request.replacement,
+                ReplacementSyntax::Rust,
+                request.detailed,
+            ))
         }
         Err(e) => Ok(RegexReplaceResult {
             is_valid: false,
             error_message: Some(format!("Golang正则表达式语法错误: {}", e)),
             result: None,
             replacement_count: 0,
+            replacement_syntax: None,
+            sites: None,
+            sites_truncated: false,
         }),
     }
 }
@@ -850,6 +1406,130 @@ pub async fn validate_regex(pattern: String, engine: String) -> Result<bool, Str
     Ok(is_valid)
 }
 
+/// 在文件中流式查找匹配，适用于大文件
+///
+/// 按行读取并逐行匹配，因此跨行的匹配不会被找到；返回行号和字符列而非绝对偏移。
+/// 达到 max_results 后停止扫描，通过 next_cursor 继续获取后续匹配。
+#[tauri::command]
+pub async fn test_regex_file(request: RegexFileTestRequest) -> Result<RegexFileTestResult, String> {
+    if request.pattern.is_empty() {
+        return Err("正则表达式不能为空".to_string());
+    }
+    tokio::task::spawn_blocking(move || test_regex_file_blocking(&request))
+        .await
+        .map_err(|e| format!("正则匹配任务失败: {}", e))?
+}
+
+fn test_regex_file_blocking(request: &RegexFileTestRequest) -> Result<RegexFileTestResult, String> {
+    let started = Instant::now();
+    let mut file = File::open(&request.file_path).map_err(|e| format!("打开文件失败: {}", e))?;
+    let file_size = file.metadata().map(|m| m.len()).unwrap_or(0);
+    let max_results = request
+        .max_results
+        .unwrap_or(DEFAULT_FILE_MAX_RESULTS)
+        .clamp(1, MAX_FILE_RESULTS);
+
+    let matcher = match LineMatcher::build(&request.pattern, &request.flags, &request.engine) {
+        Ok(matcher) => matcher,
+        Err(e) => {
+            return Ok(RegexFileTestResult {
+                is_valid: false,
+                error_message: Some(e),
+                matches: Vec::new(),
+                match_count: 0,
+                file_size,
+                scanned_bytes: 0,
+                has_more: false,
+                next_cursor: None,
+                elapsed_ms: started.elapsed().as_millis() as u64,
+            })
+        }
+    };
+
+    let cursor = request.cursor.clone().unwrap_or(RegexFileCursor {
+        byte_offset: 0,
+        line: 1,
+        skip_in_line: 0,
+    });
+    if cursor.byte_offset > file_size {
+        return Err("游标超出文件范围".to_string());
+    }
+    file.seek(SeekFrom::Start(cursor.byte_offset))
+        .map_err(|e| format!("定位文件失败: {}", e))?;
+
+    let mut reader = BufReader::new(file);
+    let mut buffer = Vec::new();
+    let mut offset = cursor.byte_offset;
+    let mut line_number = cursor.line.max(1);
+    let mut skip = cursor.skip_in_line;
+    let mut matches = Vec::new();
+    let mut next_cursor = None;
+
+    'lines: loop {
+        buffer.clear();
+        let read = reader
+            .read_until(b'\n', &mut buffer)
+            .map_err(|e| format!("读取文件失败: {}", e))?;
+        if read == 0 {
+            break;
+        }
+        let line = String::from_utf8_lossy(&buffer);
+        let line = line.trim_end_matches(['\n', '\r']);
+
+        for (index, hit) in matcher.find_in_line(line)?.into_iter().enumerate() {
+            if index < skip {
+                continue;
+            }
+            if matches.len() == max_results {
+                next_cursor = Some(RegexFileCursor {
+                    byte_offset: offset,
+                    line: line_number,
+                    skip_in_line: index,
+                });
+                break 'lines;
+            }
+            matches.push(to_line_match(line, line_number, hit));
+        }
+        skip = 0;
+        offset += read as u64;
+        line_number += 1;
+    }
+
+    Ok(RegexFileTestResult {
+        is_valid: true,
+        error_message: None,
+        match_count: matches.len(),
+        matches,
+        file_size,
+        scanned_bytes: offset,
+        has_more: next_cursor.is_some(),
+        next_cursor,
+        elapsed_ms: started.elapsed().as_millis() as u64,
+    })
+}
+
+fn to_line_match(line: &str, line_number: usize, hit: LineHit) -> RegexLineMatch {
+    // PCRE 未开启 unicode 时按字节匹配，位置可能落在多字节字符内部
+    let bytes = line.as_bytes();
+    let column = String::from_utf8_lossy(&bytes[..hit.start]).chars().count() + 1;
+    let matched = String::from_utf8_lossy(&bytes[hit.start..hit.end]);
+    let length = matched.chars().count();
+    let truncated = length > MAX_MATCH_PREVIEW_CHARS;
+    RegexLineMatch {
+        line: line_number,
+        column,
+        end_column: column + length,
+        full_match: if truncated {
+            matched.chars().take(MAX_MATCH_PREVIEW_CHARS).collect()
+        } else {
+            matched.to_string()
+        },
+        truncated,
+        groups: hit.groups,
+        named_groups: hit.named_groups,
+    }
+}
+
 // Go 风格模式转换函数
 fn convert_to_go_style(pattern: &str) -> String {
     let result = pattern.to_string();
@@ -930,6 +1610,199 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_regex_file_pagination() {
+        let path = std::env::temp_dir().join(format!("regex-file-{}.log", std::process::id()));
+        std::fs::write(&path, "id=1 id=2\r\n中文 id=3\nnone\nid=4\n").unwrap();
+        let request = |engine: &str, cursor: Option<RegexFileCursor>| RegexFileTestRequest {
+            file_path: path.to_string_lossy().to_string(),
+            pattern: r"id=(?P<n>\d)".to_string(),
+            flags: RegexFlags::default(),
+            engine: engine.to_string(),
+            max_results: Some(2),
+            cursor,
+        };
+
+        for engine in ["rust", "pcre", "golang"] {
+            let first = test_regex_file(request(engine, None)).await.unwrap();
+            assert!(first.is_valid, "Engine {} should be valid", engine);
+            assert_eq!(first.match_count, 2);
+            assert!(first.has_more);
+            let cursor = first.next_cursor.clone().unwrap();
+            assert_eq!((cursor.line, cursor.skip_in_line), (2, 0));
+
+            let second = test_regex_file(request(engine, Some(cursor)))
+                .await
+                .unwrap();
+            assert_eq!(second.match_count, 2);
+            assert!(!second.has_more);
+            let third = &second.matches[0];
+            assert_eq!((third.line, third.column, third.end_column), (2, 4, 8));
+            assert_eq!(third.named_groups["n"].as_deref(), Some("3"));
+            assert_eq!(second.matches[1].line, 4);
+            assert_eq!(second.scanned_bytes, second.file_size);
+        }
+
+        let mut single = request("rust", None);
+        single.max_results = Some(1);
+        let first = test_regex_file(single).await.unwrap();
+        let cursor = first.next_cursor.unwrap();
+        assert_eq!((cursor.byte_offset, cursor.skip_in_line), (0, 1));
+        let rest = test_regex_file(request("rust", Some(cursor)))
+            .await
+            .unwrap();
+        assert_eq!(rest.matches[0].full_match, "id=2");
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_line_match_inside_multibyte_char() {
+        let hit = |start, end| LineHit {
+            start,
+            end,
+            groups: Vec::new(),
+            named_groups: std::collections::HashMap::new(),
+        };
+        let matched = to_line_match("a中文", 1, hit(4, 7));
+        assert_eq!((matched.column, matched.full_match.as_str()), (3, "文"));
+        // 按字节匹配的 PCRE 命中字符内部时不会越界切片
+        let matched = to_line_match("a中文", 1, hit(2, 4));
+        assert_eq!(matched.column, 3);
+    }
+
+    #[tokio::test]
+    async fn test_replacement_templates() {
+        let replace = |pattern: &str, text: &str, replacement: &str, engine: &str, all: bool| {
+            replace_regex(
+                pattern.to_string(),
+                text.to_string(),
+                replacement.to_string(),
+                RegexFlags::default(),
+                engine.to_string(),
+                all,
+                None,
+            )
+        };
+
+        let pcre = replace(
+            r"(?<first>\w+) (\w+)",
+            "hello world, foo bar",
+            r"\u$2 ${first} \U$1\E-\2!",
+            "pcre",
+            true,
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            pcre.result.as_deref(),
+            Some("World hello HELLO-world!, Bar foo FOO-bar!")
+        );
+        assert_eq!(pcre.replacement_count, 2);
+        assert!(pcre.replacement_syntax.unwrap().starts_with("PCRE2"));
+
+        let missing = replace(r"(\w+)", "abc", "$2", "pcre", true).await.unwrap();
+        assert!(!missing.is_valid);
+
+        let js = replace(
+            r"(\d)(\d)",
+            "a12b34",
+            "[$2$1|$&|$$|$3]",
+            "javascript",
+            false,
+        )
+        .await
+        .unwrap();
+        assert_eq!(js.result.as_deref(), Some("a[21|12|$|$3]b34"));
+        assert_eq!(js.replacement_count, 1);
+        let js = replace("b", "abc", "<$'$`>", "javascript", true)
+            .await
+            .unwrap();
+        assert_eq!(js.result.as_deref(), Some("a<ca>c"));
+
+        let re2 = replace(r"(\w+)@(\w+)", "me@host", r"\2\\\1", "re2", true)
+            .await
+            .unwrap();
+        assert_eq!(re2.result.as_deref(), Some(r"host\me"));
+        let re2 = replace(r"\w+", "abc", r"\x", "re2", true).await.unwrap();
+        assert!(!re2.is_valid);
+
+        let rust = replace(r"(?P<y>\d{4})-(\d{2})", "2024-05", "$2/${y}", "rust", true)
+            .await
+            .unwrap();
+        assert_eq!(rust.result.as_deref(), Some("05/2024"));
+
+        let cjk = replace("文", "中文", "字", "pcre", true).await.unwrap();
+        assert_eq!(cjk.result.as_deref(), Some("中字"));
+        let bytes = replace_regex(
+            "x*".to_string(),
+            "中文".to_string(),
+            "-".to_string(),
+            RegexFlags {
+                unicode: false,
+                ..RegexFlags::default()
+            },
+            "pcre".to_string(),
+            true,
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(bytes.result.as_deref(), Some("-中-文-"));
+    }
+
+    #[tokio::test]
+    async fn test_detailed_replace_sites() {
+        for engine in ["rust", "golang", "pcre", "javascript"] {
+            let replacement = if engine == "javascript" {
+                "$<k>=[$2]"
+            } else {
+                "${k}=[$2]"
+            };
+            let result = replace_regex(
+                r"(?P<k>\w+):(\d+)".to_string(),
+                "a:1, 值:22".to_string(),
+                replacement.to_string(),
+                RegexFlags::default(),
+                engine.to_string(),
+                true,
+                Some(true),
+            )
+            .await
+            .unwrap();
+            let output = result.result.unwrap();
+            assert_eq!(output, "a=[1], 值=[22]", "engine {}", engine);
+            let sites = result.sites.unwrap();
+            assert_eq!(sites.len(), 2);
+            assert!(!result.sites_truncated);
+
+            let second = &sites[1];
+            assert_eq!((second.start, second.end), (5, 11));
+            assert_eq!(second.matched, "值:22");
+            assert_eq!(second.replacement, "值=[22]");
+            assert_eq!(&output[second.output_start..second.output_end], "值=[22]");
+            assert_eq!(
+                second.groups,
+                vec![Some("值".to_string()), Some("22".to_string())]
+            );
+            assert_eq!(second.named_groups["k"].as_deref(), Some("值"));
+        }
+
+        let plain = replace_regex(
+            "x".to_string(),
+            "xx".to_string(),
+            "$1y".to_string(),
+            RegexFlags::default(),
+            "rust".to_string(),
+            false,
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(plain.result.as_deref(), Some("x"));
+        assert_eq!(plain.replacement_count, 1);
+        assert!(plain.sites.is_none());
+    }
+
     #[tokio::test]
     async fn test_regex_validation() {
         let engines = vec!["rust", "re2", "pcre", "golang", "javascript"];
@@ -0,0 +1,832 @@
+use crate::utils::proxy;
+use chrono::{DateTime, NaiveDate, Utc};
+use flate2::read::GzDecoder;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::io::Read;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+use url::Url;
+
+/// 协议规定的单个 sitemap 上限
+const SITEMAP_MAX_URLS: usize = 50_000;
+const SITEMAP_MAX_BYTES: usize = 50 * 1024 * 1024;
+/// 读取 sitemap 索引时最多展开的子 sitemap 数量
+const MAX_CHILD_SITEMAPS: usize = 50;
+const DEFAULT_SAMPLE_SIZE: usize = 20;
+const DEFAULT_CONCURRENCY: usize = 5;
+const MAX_CONCURRENCY: usize = 20;
+/// 报告中最多列出的异常示例数
+const MAX_EXAMPLES: usize = 10;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RobotsCheckRequest {
+    pub url: String,                // 站点地址或 robots.txt 地址
+    pub content: Option<String>,    // 直接粘贴 robots.txt 内容时不再请求
+    pub test_url: Option<String>,   // 要判断是否允许抓取的地址或路径
+    pub user_agent: Option<String>, // 默认 *
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RobotsRule {
+    pub directive: String, // allow 或 disallow
+    pub path: String,
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RobotsGroup {
+    pub user_agents: Vec<String>,
+    pub rules: Vec<RobotsRule>,
+    pub crawl_delay: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RobotsEvaluation {
+    pub url: String,
+    pub user_agent: String,
+    pub allowed: bool,
+    pub matched_group: Option<Vec<String>>, // 生效分组的 User-agent 列表
+    pub matched_rule: Option<RobotsRule>,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RobotsReport {
+    pub robots_url: String,
+    pub status: Option<u16>,
+    pub groups: Vec<RobotsGroup>,
+    pub sitemaps: Vec<String>,
+    pub evaluation: Option<RobotsEvaluation>,
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SitemapCheckRequest {
+    pub url: String,
+    pub sample_size: Option<usize>, // 抽样检查的 URL 数，默认 20，0 表示不检查
+    pub concurrency: Option<usize>, // 抽样请求的并发数，默认 5，最大 20
+    pub follow_index: Option<bool>, // 是否展开 sitemap 索引，默认 true
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChildSitemap {
+    pub url: String,
+    pub url_count: usize,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LastmodStats {
+    pub with_lastmod: usize,
+    pub invalid: usize,
+    pub future: usize,
+    pub oldest: Option<String>,
+    pub newest: Option<String>,
+    pub invalid_examples: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SampledUrl {
+    pub url: String,
+    pub status: Option<u16>,
+    pub final_url: Option<String>, // 发生重定向时的最终地址
+    pub elapsed_ms: u64,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SitemapReport {
+    pub url: String,
+    pub kind: String, // urlset 或 sitemapindex
+    pub url_count: usize,
+    pub children: Vec<ChildSitemap>,
+    pub lastmod: LastmodStats,
+    pub duplicate_count: usize,
+    pub issues: Vec<String>,
+    pub sampled: Vec<SampledUrl>,
+    pub broken_count: usize, // 抽样中状态码非 2xx 或请求失败的数量
+}
+
+/// 解析 robots.txt，列出分组和 sitemap，并判断指定 URL 是否允许抓取
+#[tauri::command]
+pub async fn check_robots(request: RobotsCheckRequest) -> Result<RobotsReport, String> {
+    let base = parse_site_url(&request.url)?;
+    let robots_url = if base.path() == "/robots.txt" {
+        base.clone()
+    } else {
+        base.join("/robots.txt")
+            .map_err(|e| format!("URL 格式无效: {}", e))?
+    };
+
+    let mut warnings = Vec::new();
+    let (status, content) = match request.content {
+        Some(content) => (None, content),
+        None => {
+            let client = http_client()?;
+            let response = client
+                .get(robots_url.clone())
+                .send()
+                .await
+                .map_err(|e| format!("请求 robots.txt 失败: {}", e))?;
+            let status = response.status().as_u16();
+            let text = response
+                .text()
+                .await
+                .map_err(|e| format!("读取响应失败: {}", e))?;
+            (
+                Some(status),
+                if status < 300 { text } else { String::new() },
+            )
+        }
+    };
+    if content.len() > 500 * 1024 {
+        warnings.push("robots.txt 超过 500 KiB，Google 会忽略超出部分".to_string());
+    }
+
+    let (groups, sitemaps) = parse_robots(&content, &mut warnings);
+    let evaluation = request.test_url.as_deref().map(|test_url| {
+        let target = base
+            .join(test_url)
+            .map(|u| {
+                let mut path = u.path().to_string();
+                if let Some(query) = u.query() {
+                    path.push('?');
+                    path.push_str(query);
+                }
+                path
+            })
+            .unwrap_or_else(|_| test_url.to_string());
+        let user_agent = request
+            .user_agent
+            .clone()
+            .unwrap_or_else(|| "*".to_string());
+        evaluate(&groups, status, &target, &user_agent)
+    });
+
+    Ok(RobotsReport {
+        robots_url: robots_url.to_string(),
+        status,
+        groups,
+        sitemaps,
+        evaluation,
+        warnings,
+    })
+}
+
+/// 获取并校验 sitemap，统计条目和 lastmod，抽样检查 URL 是否可访问
+#[tauri::command]
+pub async fn check_sitemap(request: SitemapCheckRequest) -> Result<SitemapReport, String> {
+    let url = parse_site_url(&request.url)?;
+    let client = http_client()?;
+    let mut issues = Vec::new();
+
+    let root = fetch_sitemap(&client, &url).await?;
+    if root.size > SITEMAP_MAX_BYTES {
+        issues.push(format!(
+            "sitemap 解压后超过 {} MB",
+            SITEMAP_MAX_BYTES / 1024 / 1024
+        ));
+    }
+    let parsed = parse_sitemap(&root.xml)?;
+
+    let mut entries = Vec::new();
+    let mut children = Vec::new();
+    if parsed.kind == "sitemapindex" {
+        if request.follow_index.unwrap_or(true) {
+            if parsed.entries.len() > MAX_CHILD_SITEMAPS {
+                issues.push(format!(
+                    "索引包含 {} 个子 sitemap，只展开前 {} 个",
+                    parsed.entries.len(),
+                    MAX_CHILD_SITEMAPS
+                ));
+            }
+            for child in parsed.entries.iter().take(MAX_CHILD_SITEMAPS) {
+                let result = match Url::parse(&child.loc) {
+                    Ok(child_url) => match fetch_sitemap(&client, &child_url).await {
+                        Ok(fetched) => parse_sitemap(&fetched.xml),
+                        Err(e) => Err(e),
+                    },
+                    Err(e) => Err(format!("URL 格式无效: {}", e)),
+                };
+                match result {
+                    Ok(child_sitemap) => {
+                        if child_sitemap.entries.len() > SITEMAP_MAX_URLS {
+                            issues.push(format!(
+                                "{} 包含 {} 个 URL，超过 {} 的上限",
+                                child.loc,
+                                child_sitemap.entries.len(),
+                                SITEMAP_MAX_URLS
+                            ));
+                        }
+                        children.push(ChildSitemap {
+                            url: child.loc.clone(),
+                            url_count: child_sitemap.entries.len(),
+                            error: None,
+                        });
+                        entries.extend(child_sitemap.entries);
+                    }
+                    Err(e) => children.push(ChildSitemap {
+                        url: child.loc.clone(),
+                        url_count: 0,
+                        error: Some(e),
+                    }),
+                }
+            }
+        } else {
+            children = parsed
+                .entries
+                .iter()
+                .map(|child| ChildSitemap {
+                    url: child.loc.clone(),
+                    url_count: 0,
+                    error: None,
+                })
+                .collect();
+        }
+    } else {
+        if parsed.entries.len() > SITEMAP_MAX_URLS {
+            issues.push(format!(
+                "包含 {} 个 URL，超过 {} 的上限",
+                parsed.entries.len(),
+                SITEMAP_MAX_URLS
+            ));
+        }
+        entries = parsed.entries;
+    }
+
+    let lastmod = lastmod_stats(&entries, Utc::now());
+    let mut seen = HashSet::new();
+    let duplicate_count = entries.iter().filter(|e| !seen.insert(&e.loc)).count();
+    let foreign: Vec<&str> = entries
+        .iter()
+        .filter(|e| {
+            Url::parse(&e.loc)
+                .map(|u| u.host_str() != url.host_str())
+                .unwrap_or(true)
+        })
+        .map(|e| e.loc.as_str())
+        .collect();
+    if !foreign.is_empty() {
+        issues.push(format!(
+            "{} 个 URL 不属于 {} 或格式无效，例如 {}",
+            foreign.len(),
+            url.host_str().unwrap_or_default(),
+            foreign[0]
+        ));
+    }
+
+    let sample = sample_evenly(
+        &entries.iter().map(|e| e.loc.clone()).collect::<Vec<_>>(),
+        request.sample_size.unwrap_or(DEFAULT_SAMPLE_SIZE),
+    );
+    let concurrency = request
+        .concurrency
+        .unwrap_or(DEFAULT_CONCURRENCY)
+        .clamp(1, MAX_CONCURRENCY);
+    let sampled = check_urls(&client, sample, concurrency).await;
+    let broken_count = sampled
+        .iter()
+        .filter(|s| !s.status.is_some_and(|code| (200..300).contains(&code)))
+        .count();
+
+    Ok(SitemapReport {
+        url: url.to_string(),
+        kind: parsed.kind,
+        url_count: entries.len(),
+        children,
+        lastmod,
+        duplicate_count,
+        issues,
+        sampled,
+        broken_count,
+    })
+}
+
+fn parse_site_url(input: &str) -> Result<Url, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err("请输入网址".to_string());
+    }
+    let input = if input.contains("://") {
+        input.to_string()
+    } else {
+        format!("https://{}", input)
+    };
+    Url::parse(&input).map_err(|e| format!("URL 格式无效: {}", e))
+}
+
+fn http_client() -> Result<reqwest::Client, String> {
+    proxy::client_builder("robots_sitemap")
+        .timeout(Duration::from_secs(15))
+        .user_agent("Mozilla/5.0 (compatible; devtools-sitemap-checker)")
+        .build()
+        .map_err(|e| format!("构建 HTTP 客户端失败: {}", e))
+}
+
+/// 按 RFC 9309 解析，连续的 User-agent 行共享同一组规则
+fn parse_robots(content: &str, warnings: &mut Vec<String>) -> (Vec<RobotsGroup>, Vec<String>) {
+    let mut groups: Vec<RobotsGroup> = Vec::new();
+    let mut sitemaps = Vec::new();
+    let mut collecting_agents = false;
+
+    for (index, raw) in content.lines().enumerate() {
+        let line_no = index + 1;
+        let line = raw.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let Some((key, value)) = line.split_once(':') else {
+            warnings.push(format!("第 {} 行缺少冒号: {}", line_no, line));
+            continue;
+        };
+        let value = value.trim().to_string();
+        match key.trim().to_lowercase().as_str() {
+            "user-agent" => {
+                if !collecting_agents {
+                    groups.push(RobotsGroup {
+                        user_agents: Vec::new(),
+                        rules: Vec::new(),
+                        crawl_delay: None,
+                    });
+                }
+                collecting_agents = true;
+                if let Some(group) = groups.last_mut() {
+                    group.user_agents.push(value.to_lowercase());
+                }
+            }
+            directive @ ("allow" | "disallow") => {
+                collecting_agents = false;
+                let Some(group) = groups.last_mut() else {
+                    warnings.push(format!("第 {} 行的规则不属于任何 User-agent 分组", line_no));
+                    continue;
+                };
+                // 空的 Disallow 表示不限制
+                if !value.is_empty() {
+                    group.rules.push(RobotsRule {
+                        directive: directive.to_string(),
+                        path: value,
+                        line: line_no,
+                    });
+                }
+            }
+            "crawl-delay" => {
+                collecting_agents = false;
+                match (groups.last_mut(), value.parse::<f64>()) {
+                    (Some(group), Ok(delay)) => group.crawl_delay = Some(delay),
+                    _ => warnings.push(format!("第 {} 行的 Crawl-delay 无效", line_no)),
+                }
+            }
+            "sitemap" => sitemaps.push(value),
+            "host" | "clean-param" => {}
+            other => warnings.push(format!("第 {} 行的指令 {} 无法识别", line_no, other)),
+        }
+    }
+    (groups, sitemaps)
+}
+
+/// 取第一个 / 或空格之前的产品名，如 Googlebot/2.1 取 googlebot
+fn product_token(user_agent: &str) -> String {
+    user_agent
+        .split(['/', ' '])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase()
+}
+
+fn evaluate(
+    groups: &[RobotsGroup],
+    status: Option<u16>,
+    path: &str,
+    user_agent: &str,
+) -> RobotsEvaluation {
+    let mut result = RobotsEvaluation {
+        url: path.to_string(),
+        user_agent: user_agent.to_string(),
+        allowed: true,
+        matched_group: None,
+        matched_rule: None,
+        reason: String::new(),
+    };
+    match status {
+        Some(code) if (500..600).contains(&code) => {
+            result.allowed = false;
+            result.reason = format!("robots.txt 返回 HTTP {}，按规范视为全部禁止", code);
+            return result;
+        }
+        Some(code) if (400..500).contains(&code) => {
+            result.reason = format!("robots.txt 返回 HTTP {}，按规范视为全部允许", code);
+            return result;
+        }
+        _ => {}
+    }
+    if path == "/robots.txt" {
+        result.reason = "robots.txt 本身始终允许抓取".to_string();
+        return result;
+    }
+
+    // 名称完全相同的分组优先，其次是最长的前缀匹配，最后是 *
+    let token = product_token(user_agent);
+    let specificity = |agent: &str| {
+        if agent == token {
+            Some(usize::MAX)
+        } else if agent != "*" && token.starts_with(agent) {
+            Some(agent.len())
+        } else if agent == "*" {
+            Some(0)
+        } else {
+            None
+        }
+    };
+    let best = groups
+        .iter()
+        .flat_map(|g| g.user_agents.iter().filter_map(|a| specificity(a)))
+        .max();
+    let Some(best) = best else {
+        result.reason = "没有适用的 User-agent 分组".to_string();
+        return result;
+    };
+    // 同一 User-agent 出现在多个分组时合并规则
+    let matched: Vec<&RobotsGroup> = groups
+        .iter()
+        .filter(|g| g.user_agents.iter().any(|a| specificity(a) == Some(best)))
+        .collect();
+    result.matched_group = Some(
+        matched
+            .iter()
+            .flat_map(|g| g.user_agents.iter().cloned())
+            .collect(),
+    );
+
+    let winner = matched
+        .iter()
+        .flat_map(|g| g.rules.iter())
+        .filter(|rule| pattern_matches(&rule.path, path))
+        .max_by_key(|rule| (rule.path.len(), rule.directive == "allow"));
+    match winner {
+        Some(rule) => {
+            result.allowed = rule.directive == "allow";
+            result.reason = format!(
+                "第 {} 行 {}: {} 是最长的匹配规则",
+                rule.line, rule.directive, rule.path
+            );
+            result.matched_rule = Some(rule.clone());
+        }
+        None => result.reason = "没有匹配的规则，默认允许".to_string(),
+    }
+    result
+}
+
+/// 支持 * 通配和结尾的 $ 锚定
+fn pattern_matches(pattern: &str, path: &str) -> bool {
+    let (pattern, anchored) = match pattern.strip_suffix('$') {
+        Some(p) => (p, true),
+        None => (pattern, false),
+    };
+    let parts: Vec<&str> = pattern.split('*').collect();
+    let Some(rest) = path.strip_prefix(parts[0]) else {
+        return false;
+    };
+    if parts.len() == 1 {
+        return !anchored || rest.is_empty();
+    }
+    let mut rest = rest;
+    for (i, part) in parts[1..].iter().enumerate() {
+        let last = i == parts.len() - 2;
+        if last && anchored {
+            return rest.ends_with(part);
+        }
+        match rest.find(part) {
+            Some(pos) => rest = &rest[pos + part.len()..],
+            None => return false,
+        }
+    }
+    true
+}
+
+struct FetchedSitemap {
+    xml: String,
+    size: usize,
+}
+
+/// .gz 结尾或 gzip 内容的 sitemap 自动解压
+async fn fetch_sitemap(client: &reqwest::Client, url: &Url) -> Result<FetchedSitemap, String> {
+    let response = client
+        .get(url.clone())
+        .send()
+        .await
+        .map_err(|e| format!("请求 sitemap 失败: {}", e))?;
+    if !response.status().is_success() {
+        return Err(format!("请求 sitemap 失败: HTTP {}", response.status()));
+    }
+    let bytes = response
+        .bytes()
+        .await
+        .map_err(|e| format!("读取响应失败: {}", e))?;
+    let data = if bytes.starts_with(&[0x1f, 0x8b]) {
+        let mut decoded = Vec::new();
+        GzDecoder::new(&bytes[..])
+            .take(SITEMAP_MAX_BYTES as u64 + 1)
+            .read_to_end(&mut decoded)
+            .map_err(|e| format!("解压 sitemap 失败: {}", e))?;
+        decoded
+    } else {
+        bytes.to_vec()
+    };
+    Ok(FetchedSitemap {
+        size: data.len(),
+        xml: String::from_utf8_lossy(&data).to_string(),
+    })
+}
+
+#[derive(Debug, Clone)]
+struct SitemapEntry {
+    loc: String,
+    lastmod: Option<String>,
+}
+
+struct ParsedSitemap {
+    kind: String,
+    entries: Vec<SitemapEntry>,
+}
+
+fn parse_sitemap(xml: &str) -> Result<ParsedSitemap, String> {
+    let mut reader = Reader::from_str(xml);
+    reader.trim_text(true);
+    let mut kind = None;
+    let mut entries = Vec::new();
+    let mut current: Option<SitemapEntry> = None;
+    let mut field = String::new();
+
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|e| format!("XML解析失败（偏移 {}）: {}", reader.buffer_position(), e))?;
+        match event {
+            Event::Start(e) => {
+                let name = String::from_utf8_lossy(e.local_name().as_ref()).to_string();
+                match name.as_str() {
+                    "urlset" | "sitemapindex" if kind.is_none() => kind = Some(name),
+                    "url" | "sitemap" => {
+                        current = Some(SitemapEntry {
+                            loc: String::new(),
+                            lastmod: None,
+                        })
+                    }
+                    _ => field = name,
+                }
+            }
+            Event::Text(text) => {
+                let value = text
+                    .unescape()
+                    .map_err(|e| format!("XML解析失败: {}", e))?
+                    .trim()
+                    .to_string();
+                apply_field(&mut current, &field, value);
+            }
+            Event::CData(data) => {
+                let value = String::from_utf8_lossy(&data.into_inner())
+                    .trim()
+                    .to_string();
+                apply_field(&mut current, &field, value);
+            }
+            Event::End(e) => {
+                let name = e.local_name();
+                if matches!(name.as_ref(), b"url" | b"sitemap") {
+                    if let Some(entry) = current.take().filter(|e| !e.loc.is_empty()) {
+                        entries.push(entry);
+                    }
+                }
+                field.clear();
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    let kind = kind.ok_or("不是有效的 sitemap：根元素应为 urlset 或 sitemapindex")?;
+    Ok(ParsedSitemap { kind, entries })
+}
+
+fn apply_field(current: &mut Option<SitemapEntry>, field: &str, value: String) {
+    if let Some(entry) = current.as_mut() {
+        match field {
+            "loc" => entry.loc = value,
+            "lastmod" => entry.lastmod = Some(value),
+            _ => {}
+        }
+    }
+}
+
+/// 按 W3C Datetime 解析 lastmod，允许只有年、年月或日期
+fn parse_lastmod(value: &str) -> Option<DateTime<Utc>> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    // 省略秒的形式，如 2024-01-02T10:30+08:00
+    let normalized = value.replace('Z', "+00:00");
+    if let Ok(dt) = DateTime::parse_from_str(&normalized, "%Y-%m-%dT%H:%M%:z") {
+        return Some(dt.with_timezone(&Utc));
+    }
+    let date = match value.len() {
+        10 => NaiveDate::parse_from_str(value, "%Y-%m-%d").ok(),
+        7 => NaiveDate::parse_from_str(&format!("{}-01", value), "%Y-%m-%d").ok(),
+        4 => NaiveDate::parse_from_str(&format!("{}-01-01", value), "%Y-%m-%d").ok(),
+        _ => None,
+    }?;
+    Some(date.and_hms_opt(0, 0, 0)?.and_utc())
+}
+
+fn lastmod_stats(entries: &[SitemapEntry], now: DateTime<Utc>) -> LastmodStats {
+    let mut stats = LastmodStats {
+        with_lastmod: 0,
+        invalid: 0,
+        future: 0,
+        oldest: None,
+        newest: None,
+        invalid_examples: Vec::new(),
+    };
+    let mut oldest: Option<DateTime<Utc>> = None;
+    let mut newest: Option<DateTime<Utc>> = None;
+    for entry in entries {
+        let Some(value) = &entry.lastmod else {
+            continue;
+        };
+        stats.with_lastmod += 1;
+        match parse_lastmod(value) {
+            Some(date) => {
+                // 允许一天的时区误差
+                if date > now + chrono::Duration::days(1) {
+                    stats.future += 1;
+                }
+                oldest = Some(oldest.map_or(date, |o| o.min(date)));
+                newest = Some(newest.map_or(date, |n| n.max(date)));
+            }
+            None => {
+                stats.invalid += 1;
+                if stats.invalid_examples.len() < MAX_EXAMPLES {
+                    stats
+                        .invalid_examples
+                        .push(format!("{}: {}", entry.loc, value));
+                }
+            }
+        }
+    }
+    stats.oldest = oldest.map(|d| d.to_rfc3339());
+    stats.newest = newest.map(|d| d.to_rfc3339());
+    stats
+}
+
+/// 在整个列表中等间隔取样，避免只检查开头的 URL
+fn sample_evenly(urls: &[String], count: usize) -> Vec<String> {
+    if count == 0 || urls.is_empty() {
+        return Vec::new();
+    }
+    if urls.len() <= count {
+        return urls.to_vec();
+    }
+    (0..count)
+        .map(|i| urls[i * urls.len() / count].clone())
+        .collect()
+}
+
+async fn check_urls(
+    client: &reqwest::Client,
+    urls: Vec<String>,
+    concurrency: usize,
+) -> Vec<SampledUrl> {
+    let semaphore = Arc::new(Semaphore::new(concurrency));
+    let mut tasks = JoinSet::new();
+    for (index, url) in urls.into_iter().enumerate() {
+        let client = client.clone();
+        let semaphore = semaphore.clone();
+        tasks.spawn(async move {
+            let _permit = semaphore.acquire_owned().await;
+            let started = Instant::now();
+            // 部分服务器不支持 HEAD，返回 405 时改用 GET
+            let mut result = client.head(&url).send().await;
+            if result
+                .as_ref()
+                .is_ok_and(|r| r.status() == reqwest::StatusCode::METHOD_NOT_ALLOWED)
+            {
+                result = client.get(&url).send().await;
+            }
+            let elapsed_ms = started.elapsed().as_millis() as u64;
+            let sampled = match result {
+                Ok(response) => SampledUrl {
+                    final_url: (response.url().as_str() != url).then(|| response.url().to_string()),
+                    status: Some(response.status().as_u16()),
+                    url,
+                    elapsed_ms,
+                    error: None,
+                },
+                Err(e) => SampledUrl {
+                    url,
+                    status: None,
+                    final_url: None,
+                    elapsed_ms,
+                    error: Some(e.to_string()),
+                },
+            };
+            (index, sampled)
+        });
+    }
+    let mut results = tasks.join_all().await;
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, sampled)| sampled).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROBOTS: &str = "\
+# example
+User-agent: *
+Disallow: /private/
+Allow: /private/public
+Disallow: /*.pdf$
+Crawl-delay: 2
+
+User-agent: Googlebot
+User-agent: bingbot
+Disallow: /nogoogle
+Allow: /
+
+Sitemap: https://example.com/sitemap.xml
+Noindex: /x
+";
+
+    #[test]
+    fn test_robots_rules() {
+        let mut warnings = Vec::new();
+        let (groups, sitemaps) = parse_robots(ROBOTS, &mut warnings);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].crawl_delay, Some(2.0));
+        assert_eq!(groups[1].user_agents, vec!["googlebot", "bingbot"]);
+        assert_eq!(sitemaps, vec!["https://example.com/sitemap.xml"]);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("第 14 行"));
+
+        let check = |path: &str, agent: &str| evaluate(&groups, Some(200), path, agent);
+        assert!(!check("/private/data", "MyBot/1.0").allowed);
+        let public = check("/private/public/page", "MyBot/1.0");
+        assert!(public.allowed);
+        assert_eq!(public.matched_rule.unwrap().line, 4);
+        assert!(!check("/docs/a.pdf", "*").allowed);
+        assert!(check("/docs/a.pdf?x=1", "*").allowed);
+        assert!(check("/private/data", "Googlebot/2.1").allowed);
+        let google = check("/nogoogle/page", "Googlebot-Image/1.0");
+        assert!(!google.allowed);
+        assert_eq!(google.matched_group.unwrap(), vec!["googlebot", "bingbot"]);
+        assert!(check("/robots.txt", "*").allowed);
+        assert!(!evaluate(&groups, Some(503), "/", "*").allowed);
+        assert!(evaluate(&groups, Some(404), "/private/", "*").allowed);
+
+        assert!(pattern_matches("/a*b*c", "/a-x-b-y-c-z"));
+        assert!(!pattern_matches("/a*c$", "/abcd"));
+        assert!(pattern_matches("/a*c$", "/abc"));
+    }
+
+    #[test]
+    fn test_parse_sitemap_and_lastmod() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+  <url><loc>https://example.com/</loc><lastmod>2024-01-15</lastmod></url>
+  <url><loc>https://example.com/a?x=1&amp;y=2</loc><lastmod>2024-02-01T10:30+08:00</lastmod></url>
+  <url><loc><![CDATA[https://example.com/b]]></loc><lastmod>2099-01-01</lastmod></url>
+  <url><loc>https://example.com/c</loc><lastmod>yesterday</lastmod></url>
+  <url><loc>https://example.com/d</loc></url>
+</urlset>"#;
+        let parsed = parse_sitemap(xml).unwrap();
+        assert_eq!(parsed.kind, "urlset");
+        assert_eq!(parsed.entries.len(), 5);
+        assert_eq!(parsed.entries[1].loc, "https://example.com/a?x=1&y=2");
+        assert_eq!(parsed.entries[2].loc, "https://example.com/b");
+
+        let now = DateTime::parse_from_rfc3339("2024-06-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let stats = lastmod_stats(&parsed.entries, now);
+        assert_eq!(stats.with_lastmod, 4);
+        assert_eq!(stats.invalid, 1);
+        assert_eq!(stats.future, 1);
+        assert_eq!(stats.oldest.as_deref(), Some("2024-01-15T00:00:00+00:00"));
+        assert!(stats.invalid_examples[0].contains("yesterday"));
+
+        let index = parse_sitemap(
+            "<sitemapindex><sitemap><loc>https://example.com/s1.xml</loc></sitemap></sitemapindex>",
+        )
+        .unwrap();
+        assert_eq!(index.kind, "sitemapindex");
+        assert!(parse_sitemap("<html></html>").is_err());
+        assert!(parse_sitemap("<urlset><url><loc>x</url>").is_err());
+
+        let urls: Vec<String> = (0..100).map(|i| i.to_string()).collect();
+        assert_eq!(sample_evenly(&urls, 4), vec!["0", "25", "50", "75"]);
+    }
+}
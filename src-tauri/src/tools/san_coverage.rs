@@ -0,0 +1,324 @@
+use crate::tools::spki_pin::{fetch_certificate_chain, parse_certificate_bytes};
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+use x509_parser::prelude::*;
+
+#[derive(Debug, Deserialize)]
+pub struct SanCoverageRequest {
+    pub host: Option<String>,        // 从服务器获取证书
+    pub port: Option<u16>,           // 默认 443
+    pub pem_content: Option<String>, // 或直接提供 PEM 证书，取第一张
+    pub file_path: Option<String>,   // 或读取 PEM/DER 证书文件
+    #[serde(default)]
+    pub hostnames: Vec<String>, // 计划使用的主机名，为空时检查 host 本身
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HostCoverage {
+    pub hostname: String,
+    pub ascii_hostname: String, // 国际化域名转换为 punycode 后的形式
+    pub covered: bool,
+    pub matched_by: Option<String>, // 匹配到的 SAN
+    pub reason: Option<String>,     // 未覆盖的原因
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SanCoverageResult {
+    pub source: String,
+    pub subject: String,
+    pub dns_names: Vec<String>,
+    pub ip_addresses: Vec<String>,
+    pub hosts: Vec<HostCoverage>,
+    pub covered_count: usize,
+    pub uncovered_count: usize,
+    pub warnings: Vec<String>,
+}
+
+/// 检查计划使用的主机名是否都被证书的 SAN 覆盖
+#[tauri::command]
+pub async fn analyze_san_coverage(
+    request: SanCoverageRequest,
+) -> Result<SanCoverageResult, String> {
+    let host = request
+        .host
+        .as_deref()
+        .map(str::trim)
+        .filter(|h| !h.is_empty());
+    let port = request.port.unwrap_or(443);
+
+    let (source, ders) = if let Some(host) = host {
+        (
+            format!("{}:{}", host, port),
+            fetch_certificate_chain(host, port).await?,
+        )
+    } else if let Some(content) = request
+        .pem_content
+        .as_deref()
+        .filter(|c| !c.trim().is_empty())
+    {
+        (
+            "PEM".to_string(),
+            parse_certificate_bytes(content.as_bytes())?,
+        )
+    } else if let Some(path) = request
+        .file_path
+        .as_deref()
+        .filter(|p| !p.trim().is_empty())
+    {
+        let data = std::fs::read(path).map_err(|e| format!("读取证书文件失败: {}", e))?;
+        (path.to_string(), parse_certificate_bytes(&data)?)
+    } else {
+        return Err("请提供主机名、PEM 内容或证书文件".to_string());
+    };
+
+    let mut hostnames: Vec<String> = request
+        .hostnames
+        .iter()
+        .map(|h| h.trim().to_string())
+        .filter(|h| !h.is_empty())
+        .collect();
+    if hostnames.is_empty() {
+        match host {
+            Some(host) => hostnames.push(host.to_string()),
+            None => return Err("请提供要检查的主机名".to_string()),
+        }
+    }
+
+    let (_, cert) =
+        X509Certificate::from_der(&ders[0]).map_err(|e| format!("证书解析失败: {}", e))?;
+    let mut result = analyze_certificate(&cert, &hostnames);
+    result.source = source;
+    Ok(result)
+}
+
+fn analyze_certificate(cert: &X509Certificate, hostnames: &[String]) -> SanCoverageResult {
+    let mut dns_names = Vec::new();
+    let mut ip_addresses = Vec::new();
+    let mut warnings = Vec::new();
+
+    match cert.subject_alternative_name() {
+        Ok(Some(san)) => {
+            for name in &san.value.general_names {
+                match name {
+                    GeneralName::DNSName(dns) => dns_names.push(dns.to_string()),
+                    GeneralName::IPAddress(bytes) => {
+                        if let Some(ip) = ip_from_bytes(bytes) {
+                            ip_addresses.push(ip.to_string());
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        _ => warnings
+            .push("证书没有 subjectAltName 扩展，主流浏览器不再按 CN 匹配主机名".to_string()),
+    }
+
+    // SAN 中本应是 punycode，统一转换后再比较
+    let patterns: Vec<(String, String)> = dns_names
+        .iter()
+        .map(|name| {
+            let normalized = normalize_pattern(name);
+            if !is_valid_pattern(&normalized) {
+                warnings.push(format!("SAN {} 的通配符写法不被浏览器接受", name));
+            }
+            (name.clone(), normalized)
+        })
+        .collect();
+
+    let hosts: Vec<HostCoverage> = hostnames
+        .iter()
+        .map(|hostname| check_host(hostname, &patterns, &ip_addresses))
+        .collect();
+    let covered_count = hosts.iter().filter(|h| h.covered).count();
+
+    SanCoverageResult {
+        source: String::new(),
+        subject: cert.subject().to_string(),
+        dns_names,
+        ip_addresses,
+        uncovered_count: hosts.len() - covered_count,
+        covered_count,
+        hosts,
+        warnings,
+    }
+}
+
+fn check_host(hostname: &str, patterns: &[(String, String)], ips: &[String]) -> HostCoverage {
+    let mut coverage = HostCoverage {
+        hostname: hostname.to_string(),
+        ascii_hostname: hostname.to_string(),
+        covered: false,
+        matched_by: None,
+        reason: None,
+    };
+
+    let trimmed = hostname.trim().trim_end_matches('.');
+    let trimmed = trimmed
+        .strip_prefix('[')
+        .and_then(|h| h.strip_suffix(']'))
+        .unwrap_or(trimmed);
+    if let Ok(ip) = trimmed.parse::<IpAddr>() {
+        coverage.ascii_hostname = ip.to_string();
+        match ips
+            .iter()
+            .find(|san| san.parse::<IpAddr>().ok() == Some(ip))
+        {
+            Some(san) => {
+                coverage.covered = true;
+                coverage.matched_by = Some(san.clone());
+            }
+            None => coverage.reason = Some("IP 地址只能由 IP 类型的 SAN 覆盖".to_string()),
+        }
+        return coverage;
+    }
+
+    let ascii = match idna::domain_to_ascii(trimmed) {
+        Ok(ascii) if !ascii.is_empty() => ascii,
+        _ => {
+            coverage.reason = Some("无效的主机名".to_string());
+            return coverage;
+        }
+    };
+    coverage.ascii_hostname = ascii.clone();
+
+    let mut reasons = Vec::new();
+    for (original, pattern) in patterns {
+        match match_pattern(pattern, &ascii) {
+            Ok(()) => {
+                coverage.covered = true;
+                coverage.matched_by = Some(original.clone());
+                return coverage;
+            }
+            Err(Some(reason)) => reasons.push(reason),
+            Err(None) => {}
+        }
+    }
+    coverage.reason = Some(
+        reasons
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| "没有匹配的 SAN".to_string()),
+    );
+    coverage
+}
+
+/// 按 RFC 6125 匹配，未匹配但相关时返回原因
+fn match_pattern(pattern: &str, host: &str) -> Result<(), Option<String>> {
+    if pattern == host {
+        return Ok(());
+    }
+    let Some(base) = pattern.strip_prefix("*.") else {
+        return Err(None);
+    };
+    if !is_valid_pattern(pattern) {
+        return Err(host
+            .ends_with(base)
+            .then(|| format!("{} 的通配符写法无效", pattern)));
+    }
+    if host == base {
+        return Err(Some(format!("{} 不覆盖裸域名 {}", pattern, base)));
+    }
+    match host
+        .strip_suffix(base)
+        .and_then(|rest| rest.strip_suffix('.'))
+    {
+        Some(label) if !label.contains('.') => Ok(()),
+        Some(_) => Err(Some(format!("{} 只覆盖一级子域名", pattern))),
+        None => Err(None),
+    }
+}
+
+/// 通配符只能是最左侧的完整标签，且不能直接位于顶级域名之下
+fn is_valid_pattern(pattern: &str) -> bool {
+    if !pattern.contains('*') {
+        return true;
+    }
+    match pattern.strip_prefix("*.") {
+        Some(base) => !base.contains('*') && base.split('.').filter(|l| !l.is_empty()).count() >= 2,
+        None => false,
+    }
+}
+
+fn normalize_pattern(name: &str) -> String {
+    let name = name.trim().trim_end_matches('.').to_lowercase();
+    match name.strip_prefix("*.") {
+        Some(base) => format!(
+            "*.{}",
+            idna::domain_to_ascii(base).unwrap_or_else(|_| base.to_string())
+        ),
+        None => idna::domain_to_ascii(&name).unwrap_or(name),
+    }
+}
+
+fn ip_from_bytes(bytes: &[u8]) -> Option<IpAddr> {
+    match bytes.len() {
+        4 => Some(IpAddr::from(<[u8; 4]>::try_from(bytes).ok()?)),
+        16 => Some(IpAddr::from(<[u8; 16]>::try_from(bytes).ok()?)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_match_pattern() {
+        assert!(match_pattern("*.example.com", "api.example.com").is_ok());
+        assert!(match_pattern("api.example.com", "api.example.com").is_ok());
+        assert_eq!(
+            match_pattern("*.example.com", "api.v2.example.com"),
+            Err(Some("*.example.com 只覆盖一级子域名".to_string()))
+        );
+        assert_eq!(
+            match_pattern("*.example.com", "example.com"),
+            Err(Some("*.example.com 不覆盖裸域名 example.com".to_string()))
+        );
+        assert_eq!(match_pattern("*.example.com", "example.org"), Err(None));
+        assert!(match_pattern("*.com", "example.com").is_err());
+        assert!(!is_valid_pattern("f*.example.com"));
+        assert!(!is_valid_pattern("*.*.example.com"));
+        assert_eq!(normalize_pattern("*.Bücher.de."), "*.xn--bcher-kva.de");
+    }
+
+    #[test]
+    fn test_analyze_generated_certificate() {
+        let sans = [
+            "example.com".to_string(),
+            "*.example.com".to_string(),
+            "xn--bcher-kva.de".to_string(),
+            "10.0.0.1".to_string(),
+        ];
+        let (pem, _) =
+            crate::tools::key_generator::generate_self_signed_certificate("example.com", &sans, 30)
+                .unwrap();
+        let ders = parse_certificate_bytes(pem.as_bytes()).unwrap();
+        let (_, cert) = X509Certificate::from_der(&ders[0]).unwrap();
+
+        let hostnames: Vec<String> = [
+            "www.example.com",
+            "api.v2.example.com",
+            "Bücher.de",
+            "10.0.0.1",
+            "10.0.0.2",
+        ]
+        .iter()
+        .map(|h| h.to_string())
+        .collect();
+        let result = analyze_certificate(&cert, &hostnames);
+        assert_eq!(result.ip_addresses, vec!["10.0.0.1"]);
+        assert_eq!(result.covered_count, 3);
+        assert_eq!(result.uncovered_count, 2);
+        assert_eq!(result.hosts[0].matched_by.as_deref(), Some("*.example.com"));
+        assert!(!result.hosts[1].covered);
+        assert_eq!(result.hosts[2].ascii_hostname, "xn--bcher-kva.de");
+        assert!(result.hosts[2].covered);
+        assert!(result.hosts[3].covered);
+        assert_eq!(
+            result.hosts[4].reason.as_deref(),
+            Some("IP 地址只能由 IP 类型的 SAN 覆盖")
+        );
+        assert!(result.warnings.is_empty());
+    }
+}
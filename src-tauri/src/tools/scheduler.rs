@@ -0,0 +1,561 @@
+use crate::tools::domain_monitor::{self, lookup_expiry, parse_expiry_date};
+use crate::tools::spki_pin::fetch_certificate_chain;
+use crate::tools::system_settings;
+use crate::tools::uptime_monitor;
+use crate::utils::cron::CronSchedule;
+use crate::utils::dns::DnsClient;
+use crate::utils::notification::{self, NotificationKind};
+use crate::utils::proxy;
+use chrono::{DateTime, Local, Utc};
+use hickory_resolver::proto::rr::RecordType;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+use x509_parser::prelude::*;
+
+/// 后台轮询间隔，定时任务、可用性监控和域名到期检查在同一循环中判断是否到期
+const TICK_SECS: u64 = 15;
+/// 应用启动后延迟首次检查，避免与启动流程争抢网络
+const STARTUP_DELAY_SECS: u64 = 30;
+/// 每个任务保留的执行记录条数
+const MAX_RUNS_PER_TASK: usize = 50;
+/// 证书和域名默认提前告警天数
+const DEFAULT_WARN_DAYS: i64 = 14;
+const HTTP_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// 串行化任务列表和执行记录文件的读写
+static SCHEDULER_LOCK: Mutex<()> = Mutex::new(());
+/// 各后台作业是否仍在执行，上一轮未结束时跳过本轮
+static TASKS_RUNNING: AtomicBool = AtomicBool::new(false);
+static UPTIME_RUNNING: AtomicBool = AtomicBool::new(false);
+static DOMAIN_RUNNING: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckKind {
+    Ssl,   // 证书剩余有效期，目标为 host 或 host:port
+    Dns,   // 记录能否解析，目标为域名
+    Http,  // HTTP 状态码，目标为 URL
+    Whois, // 域名注册到期时间，目标为域名
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledTask {
+    #[serde(default)]
+    pub id: String,
+    pub name: String,
+    pub kind: CheckKind,
+    pub target: String,
+    pub cron: String, // 五段 cron 表达式，按本地时间执行
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    #[serde(default)]
+    pub warn_days: Option<i64>, // ssl、whois：剩余天数低于该值视为失败，默认 14
+    #[serde(default)]
+    pub expected_status: Option<u16>, // http：期望的状态码，默认接受 2xx 和 3xx
+    #[serde(default)]
+    pub record_type: Option<String>, // dns：记录类型，默认 A
+    #[serde(default)]
+    pub next_run: Option<String>,
+    #[serde(default)]
+    pub last_run: Option<String>,
+    #[serde(default)]
+    pub last_success: Option<bool>,
+    #[serde(default)]
+    pub last_message: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskRun {
+    pub task_id: String,
+    pub task_name: String,
+    pub started_at: String,
+    pub duration_ms: u64,
+    pub success: bool,
+    pub message: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SchedulerData {
+    #[serde(default)]
+    tasks: Vec<ScheduledTask>,
+    #[serde(default)]
+    runs: BTreeMap<String, Vec<TaskRun>>, // 按任务 id 保存，最新的在前
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[tauri::command]
+pub async fn list_scheduled_tasks() -> Result<Vec<ScheduledTask>, String> {
+    let _guard = SCHEDULER_LOCK.lock().unwrap();
+    Ok(load_data(&get_data_path()).tasks)
+}
+
+/// 新增或更新定时任务（按 id 匹配，id 为空时新增）
+#[tauri::command]
+pub async fn save_scheduled_task(task: ScheduledTask) -> Result<ScheduledTask, String> {
+    let mut task = validate_task(task)?;
+    let schedule = CronSchedule::parse(&task.cron)?;
+    task.next_run = schedule.next_after(Local::now()).map(|t| t.to_rfc3339());
+    if task.next_run.is_none() {
+        return Err("该 cron 表达式不会触发".to_string());
+    }
+
+    let _guard = SCHEDULER_LOCK.lock().unwrap();
+    let path = get_data_path();
+    let mut data = load_data(&path);
+    match data.tasks.iter_mut().find(|t| t.id == task.id) {
+        Some(existing) if !task.id.is_empty() => {
+            task.last_run = existing.last_run.take();
+            task.last_success = existing.last_success.take();
+            task.last_message = existing.last_message.take();
+            *existing = task.clone();
+        }
+        _ => {
+            task.id = format!("{:x}", Utc::now().timestamp_nanos_opt().unwrap_or_default());
+            task.last_run = None;
+            task.last_success = None;
+            task.last_message = None;
+            data.tasks.push(task.clone());
+        }
+    }
+    save_data(&path, &data)?;
+    Ok(task)
+}
+
+#[tauri::command]
+pub async fn remove_scheduled_task(id: String) -> Result<(), String> {
+    let _guard = SCHEDULER_LOCK.lock().unwrap();
+    let path = get_data_path();
+    let mut data = load_data(&path);
+    let before = data.tasks.len();
+    data.tasks.retain(|t| t.id != id);
+    if data.tasks.len() == before {
+        return Err("定时任务不存在".to_string());
+    }
+    data.runs.remove(&id);
+    save_data(&path, &data)
+}
+
+/// 立即执行一次任务，不影响下次计划时间
+#[tauri::command]
+pub async fn run_scheduled_task(app: AppHandle, id: String) -> Result<TaskRun, String> {
+    let task = {
+        let _guard = SCHEDULER_LOCK.lock().unwrap();
+        load_data(&get_data_path())
+            .tasks
+            .into_iter()
+            .find(|t| t.id == id)
+            .ok_or_else(|| "定时任务不存在".to_string())?
+    };
+    let run = execute_task(&task).await;
+    record_runs(&app, vec![run.clone()], false)?;
+    Ok(run)
+}
+
+/// 查看执行记录，不指定任务时返回全部任务的记录
+#[tauri::command]
+pub async fn get_scheduled_task_runs(id: Option<String>) -> Result<Vec<TaskRun>, String> {
+    let _guard = SCHEDULER_LOCK.lock().unwrap();
+    let data = load_data(&get_data_path());
+    let mut runs: Vec<TaskRun> = data
+        .runs
+        .into_iter()
+        .filter(|(task_id, _)| id.as_deref().is_none_or(|id| id == task_id))
+        .flat_map(|(_, runs)| runs)
+        .collect();
+    runs.sort_by(|a, b| b.started_at.cmp(&a.started_at));
+    Ok(runs)
+}
+
+/// 预览 cron 表达式接下来的执行时间
+#[tauri::command]
+pub fn preview_cron_schedule(
+    expression: String,
+    count: Option<usize>,
+) -> Result<Vec<String>, String> {
+    let schedule = CronSchedule::parse(&expression)?;
+    let mut times = Vec::new();
+    let mut after = Local::now();
+    for _ in 0..count.unwrap_or(5).clamp(1, 50) {
+        let Some(next) = schedule.next_after(after) else {
+            break;
+        };
+        times.push(next.to_rfc3339());
+        after = next;
+    }
+    Ok(times)
+}
+
+/// 启动后台调度：定时任务、可用性监控和域名到期检查共用一个轮询循环，
+/// 每个作业作为独立的异步任务执行，慢作业（如批量 WHOIS 查询）不会拖慢其他作业
+pub fn spawn_scheduler(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(Duration::from_secs(STARTUP_DELAY_SECS)).await;
+        loop {
+            spawn_job(&app, &TASKS_RUNNING, run_due_tasks);
+            spawn_job(&app, &UPTIME_RUNNING, uptime_monitor::run_due_checks);
+            spawn_job(&app, &DOMAIN_RUNNING, domain_monitor::run_due_checks);
+            tokio::time::sleep(Duration::from_secs(TICK_SECS)).await;
+        }
+    });
+}
+
+fn spawn_job<F, Fut>(app: &AppHandle, running: &'static AtomicBool, job: F)
+where
+    F: FnOnce(AppHandle) -> Fut,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    if running.swap(true, Ordering::AcqRel) {
+        return;
+    }
+    let job = job(app.clone());
+    tauri::async_runtime::spawn(async move {
+        job.await;
+        running.store(false, Ordering::Release);
+    });
+}
+
+/// 按 cron 表达式执行到期的任务
+async fn run_due_tasks(app: AppHandle) {
+    let due = {
+        let _guard = SCHEDULER_LOCK.lock().unwrap();
+        let path = get_data_path();
+        let mut data = load_data(&path);
+        let due = take_due_tasks(&mut data.tasks, Local::now());
+        if !due.is_empty() {
+            if let Err(e) = save_data(&path, &data) {
+                eprintln!("Failed to save scheduled tasks: {}", e);
+            }
+        }
+        due
+    };
+    if due.is_empty() {
+        return;
+    }
+    let mut runs = Vec::with_capacity(due.len());
+    for task in &due {
+        runs.push(execute_task(task).await);
+    }
+    if let Err(e) = record_runs(&app, runs, true) {
+        eprintln!("Failed to record scheduled task runs: {}", e);
+    }
+}
+
+/// 取出已到执行时间的任务，并将其下次执行时间推进到 now 之后
+fn take_due_tasks(tasks: &mut [ScheduledTask], now: DateTime<Local>) -> Vec<ScheduledTask> {
+    let mut due = Vec::new();
+    for task in tasks.iter_mut().filter(|t| t.enabled) {
+        let Ok(schedule) = CronSchedule::parse(&task.cron) else {
+            continue;
+        };
+        let next_run = task
+            .next_run
+            .as_deref()
+            .and_then(|t| DateTime::parse_from_rfc3339(t).ok());
+        // 应用未运行期间错过的多次执行只补一次
+        let is_due = next_run.is_some_and(|t| t <= now);
+        if is_due {
+            due.push(task.clone());
+        }
+        if is_due || next_run.is_none() {
+            task.next_run = schedule.next_after(now).map(|t| t.to_rfc3339());
+        }
+    }
+    due
+}
+
+/// 保存执行结果，失败时通过事件和托盘提示
+fn record_runs(app: &AppHandle, runs: Vec<TaskRun>, scheduled: bool) -> Result<(), String> {
+    let failing = {
+        let _guard = SCHEDULER_LOCK.lock().unwrap();
+        let path = get_data_path();
+        let mut data = load_data(&path);
+        for run in &runs {
+            if let Some(task) = data.tasks.iter_mut().find(|t| t.id == run.task_id) {
+                task.last_run = Some(run.started_at.clone());
+                task.last_success = Some(run.success);
+                task.last_message = Some(run.message.clone());
+            }
+            let history = data.runs.entry(run.task_id.clone()).or_default();
+            history.insert(0, run.clone());
+            history.truncate(MAX_RUNS_PER_TASK);
+        }
+        save_data(&path, &data)?;
+        data.tasks
+            .iter()
+            .filter(|t| t.enabled && t.last_success == Some(false))
+            .count()
+    };
+
+    for run in &runs {
+        let _ = app.emit("scheduled-task-run", run);
+    }
+    let failures: Vec<&TaskRun> = runs.iter().filter(|r| !r.success).collect();
+    if scheduled && !failures.is_empty() {
+        let _ = app.emit("scheduled-task-failed", &failures);
+        for run in &failures {
+            let body = format!("{}: {}", run.task_name, run.message);
+            notification::notify(app, NotificationKind::TaskFailed, &body);
+        }
+    }
+    let tooltip = (failing > 0).then(|| format!("devtools - {} 个定时检查失败", failing));
+    system_settings::set_tray_tooltip(app, tooltip.as_deref());
+    Ok(())
+}
+
+async fn execute_task(task: &ScheduledTask) -> TaskRun {
+    let started_at = Local::now().to_rfc3339();
+    let start = Instant::now();
+    let result = match task.kind {
+        CheckKind::Ssl => check_ssl(task).await,
+        CheckKind::Dns => check_dns(task).await,
+        CheckKind::Http => check_http(task).await,
+        CheckKind::Whois => check_whois(task).await,
+    };
+    let success = result.is_ok();
+    TaskRun {
+        task_id: task.id.clone(),
+        task_name: task.name.clone(),
+        started_at,
+        duration_ms: start.elapsed().as_millis() as u64,
+        success,
+        message: result.unwrap_or_else(|e| e),
+    }
+}
+
+async fn check_ssl(task: &ScheduledTask) -> Result<String, String> {
+    let (host, port) = split_host_port(&task.target)?;
+    let chain = fetch_certificate_chain(&host, port).await?;
+    let (_, cert) =
+        X509Certificate::from_der(&chain[0]).map_err(|e| format!("证书解析失败: {}", e))?;
+    let not_after = DateTime::from_timestamp(cert.validity().not_after.timestamp(), 0)
+        .ok_or_else(|| "证书到期时间无效".to_string())?;
+    evaluate_days("证书", not_after, task.warn_days)
+}
+
+async fn check_whois(task: &ScheduledTask) -> Result<String, String> {
+    let parsed = lookup_expiry(&task.target, false).await?;
+    let expires = parsed
+        .expires
+        .as_deref()
+        .and_then(parse_expiry_date)
+        .ok_or_else(|| "未查询到域名到期时间".to_string())?;
+    evaluate_days("域名", expires, task.warn_days)
+}
+
+async fn check_dns(task: &ScheduledTask) -> Result<String, String> {
+    let record_type = task.record_type.as_deref().unwrap_or("A").to_uppercase();
+    let parsed = RecordType::from_str(&record_type)
+        .map_err(|_| format!("不支持的记录类型: {}", record_type))?;
+    let resolver = DnsClient::system_resolver();
+    let records = DnsClient::lookup(&resolver, &task.target, parsed).await?;
+    if records.is_empty() {
+        return Err(format!("{} 没有 {} 记录", task.target, record_type));
+    }
+    Ok(format!("{} 记录: {}", record_type, records.join(", ")))
+}
+
+async fn check_http(task: &ScheduledTask) -> Result<String, String> {
+    let client = proxy::client_builder("scheduler")
+        .timeout(HTTP_TIMEOUT)
+        .build()
+        .map_err(|e| format!("创建 HTTP 客户端失败: {}", e))?;
+    let start = Instant::now();
+    let response = client
+        .get(&task.target)
+        .send()
+        .await
+        .map_err(|e| format!("请求失败: {}", e))?;
+    let status = response.status();
+    let elapsed = start.elapsed().as_millis();
+    let ok = match task.expected_status {
+        Some(expected) => status.as_u16() == expected,
+        None => status.is_success() || status.is_redirection(),
+    };
+    let message = format!("HTTP {}，耗时 {} ms", status.as_u16(), elapsed);
+    if ok {
+        Ok(message)
+    } else {
+        Err(message)
+    }
+}
+
+fn evaluate_days(
+    label: &str,
+    expires: DateTime<Utc>,
+    warn_days: Option<i64>,
+) -> Result<String, String> {
+    let days = (expires - Utc::now()).num_days();
+    let warn_days = warn_days.unwrap_or(DEFAULT_WARN_DAYS);
+    let date = expires.with_timezone(&Local).format("%Y-%m-%d");
+    if expires <= Utc::now() {
+        Err(format!("{}已于 {} 过期", label, date))
+    } else if days < warn_days {
+        Err(format!("{}将于 {} 过期，剩余 {} 天", label, date, days))
+    } else {
+        Ok(format!("{}将于 {} 过期，剩余 {} 天", label, date, days))
+    }
+}
+
+fn validate_task(mut task: ScheduledTask) -> Result<ScheduledTask, String> {
+    task.name = task.name.trim().to_string();
+    task.target = task.target.trim().to_string();
+    task.cron = task.cron.trim().to_string();
+    if task.target.is_empty() {
+        return Err("请输入检查目标".to_string());
+    }
+    if task.name.is_empty() {
+        task.name = task.target.clone();
+    }
+    match task.kind {
+        CheckKind::Http => {
+            let url = url::Url::parse(&task.target).map_err(|e| format!("无效的 URL: {}", e))?;
+            if !matches!(url.scheme(), "http" | "https") {
+                return Err("只支持 http 和 https 地址".to_string());
+            }
+        }
+        CheckKind::Ssl => {
+            split_host_port(&task.target)?;
+        }
+        CheckKind::Dns | CheckKind::Whois => {
+            task.target = task.target.trim_end_matches('.').to_lowercase();
+        }
+    }
+    if task.warn_days.is_some_and(|d| d < 0) {
+        return Err("告警天数不能为负数".to_string());
+    }
+    Ok(task)
+}
+
+fn split_host_port(target: &str) -> Result<(String, u16), String> {
+    match target.rsplit_once(':') {
+        Some((host, port)) if !host.contains(':') => {
+            let port = port.parse().map_err(|_| format!("无效的端口: {}", port))?;
+            Ok((host.to_string(), port))
+        }
+        _ => Ok((target.to_string(), 443)),
+    }
+}
+
+fn get_data_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("devtools")
+        .join("scheduler.json")
+}
+
+fn load_data(path: &Path) -> SchedulerData {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_data(path: &Path, data: &SchedulerData) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("创建配置目录失败: {}", e))?;
+    }
+    let content = serde_json::to_string(data).map_err(|e| format!("序列化定时任务失败: {}", e))?;
+    fs::write(path, content).map_err(|e| format!("保存定时任务失败: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn task(cron: &str, next_run: Option<&str>) -> ScheduledTask {
+        ScheduledTask {
+            id: "1".to_string(),
+            name: "example".to_string(),
+            kind: CheckKind::Http,
+            target: "https://example.com".to_string(),
+            cron: cron.to_string(),
+            enabled: true,
+            warn_days: None,
+            expected_status: None,
+            record_type: None,
+            next_run: next_run.map(str::to_string),
+            last_run: None,
+            last_success: None,
+            last_message: None,
+        }
+    }
+
+    #[test]
+    fn test_take_due_tasks() {
+        let now = Local.with_ymd_and_hms(2026, 3, 1, 10, 7, 0).unwrap();
+        let past = Local
+            .with_ymd_and_hms(2026, 3, 1, 9, 0, 0)
+            .unwrap()
+            .to_rfc3339();
+        let future = Local
+            .with_ymd_and_hms(2026, 3, 1, 11, 0, 0)
+            .unwrap()
+            .to_rfc3339();
+        let mut tasks = vec![
+            task("*/15 * * * *", Some(&past)),
+            task("0 * * * *", Some(&future)),
+            task("0 * * * *", None),
+            task("invalid", Some(&past)),
+        ];
+        tasks[3].enabled = false;
+
+        let due = take_due_tasks(&mut tasks, now);
+        assert_eq!(due.len(), 1);
+        // 错过的执行只补一次，下次时间从当前时间往后算
+        let expected = Local.with_ymd_and_hms(2026, 3, 1, 10, 15, 0).unwrap();
+        assert_eq!(tasks[0].next_run, Some(expected.to_rfc3339()));
+        assert_eq!(tasks[1].next_run, Some(future));
+        // 新任务只计算下次时间，不立即执行
+        let expected = Local.with_ymd_and_hms(2026, 3, 1, 11, 0, 0).unwrap();
+        assert_eq!(tasks[2].next_run, Some(expected.to_rfc3339()));
+        assert_eq!(tasks[3].next_run, Some(past));
+    }
+
+    #[test]
+    fn test_validate_task() {
+        let mut ssl = task("@daily", None);
+        ssl.kind = CheckKind::Ssl;
+        ssl.target = " example.com:8443 ".to_string();
+        ssl.name = String::new();
+        let ssl = validate_task(ssl).unwrap();
+        assert_eq!(ssl.name, "example.com:8443");
+        assert_eq!(
+            split_host_port(&ssl.target).unwrap(),
+            ("example.com".to_string(), 8443)
+        );
+        assert_eq!(
+            split_host_port("example.com").unwrap(),
+            ("example.com".to_string(), 443)
+        );
+
+        let mut http = task("@daily", None);
+        http.target = "ftp://example.com".to_string();
+        assert!(validate_task(http).is_err());
+
+        let mut whois = task("@daily", None);
+        whois.kind = CheckKind::Whois;
+        whois.target = "Example.COM.".to_string();
+        assert_eq!(validate_task(whois).unwrap().target, "example.com");
+
+        let days = Utc::now() + chrono::Duration::days(30);
+        assert!(evaluate_days("证书", days, None).is_ok());
+        assert!(evaluate_days("证书", days, Some(60)).is_err());
+        assert!(
+            evaluate_days("证书", Utc::now() - chrono::Duration::days(1), None)
+                .unwrap_err()
+                .contains("已于")
+        );
+    }
+}
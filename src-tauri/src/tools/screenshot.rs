@@ -0,0 +1,310 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+
+/// 截图完成后通知前端的事件
+pub const SCREENSHOT_EVENT: &str = "screenshot-captured";
+/// 隐藏主窗口后等待其从屏幕上消失的时间
+const HIDE_DELAY: Duration = Duration::from_millis(250);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CaptureMode {
+    Region, // 框选区域
+    Window, // 选择窗口
+    Full,   // 整个屏幕
+}
+
+/// 截图后可直接进行的操作
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScreenshotAction {
+    pub id: String,      // ocr、image_convert、qr_decode
+    pub tool_id: String, // 前端对应的工具
+    pub label: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScreenshotCaptured {
+    pub path: String, // 临时文件路径
+    pub mode: CaptureMode,
+    pub width: u32,
+    pub height: u32,
+    pub size_bytes: u64,
+    pub actions: Vec<ScreenshotAction>,
+}
+
+/// 截图并保存到临时文件，用户取消选择时返回 None
+#[tauri::command]
+pub async fn capture_screenshot(
+    app: AppHandle,
+    mode: Option<CaptureMode>,
+) -> Result<Option<ScreenshotCaptured>, String> {
+    let mode = mode.unwrap_or(CaptureMode::Region);
+    let path = std::env::temp_dir().join(format!(
+        "devtools-screenshot-{}.png",
+        chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+    ));
+
+    // 截图时隐藏主窗口，避免遮挡要截取的内容
+    let window = app.get_webview_window("main");
+    let was_visible = window
+        .as_ref()
+        .is_some_and(|w| w.is_visible().unwrap_or(false));
+    if was_visible {
+        if let Some(window) = &window {
+            let _ = window.hide();
+        }
+        tokio::time::sleep(HIDE_DELAY).await;
+    }
+
+    let target = path.clone();
+    let result = tokio::task::spawn_blocking(move || run_capture(mode, &target))
+        .await
+        .map_err(|e| format!("截图任务失败: {}", e))?;
+
+    if let Some(window) = &window {
+        if was_visible || matches!(result, Ok(true)) {
+            let _ = window.show();
+            let _ = window.set_focus();
+        }
+    }
+    if !result? {
+        return Ok(None);
+    }
+
+    let captured = describe_capture(&path, mode)?;
+    let _ = app.emit(SCREENSHOT_EVENT, &captured);
+    Ok(Some(captured))
+}
+
+/// 识别图片中的二维码，依赖 zbarimg
+#[tauri::command]
+pub async fn decode_qr_code(file_path: String) -> Result<Vec<String>, String> {
+    let path = PathBuf::from(file_path.trim());
+    if !path.is_file() {
+        return Err(format!("图片文件不存在: {}", path.display()));
+    }
+    let output = Command::new("zbarimg")
+        .arg("--quiet")
+        .arg("--raw")
+        .arg("-Sqrcode.enable")
+        .arg(&path)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .map_err(|e| format!("启动 zbarimg 失败，请确认已安装 zbar: {}", e))?;
+    // 未找到二维码时退出码为 4
+    match output.status.code() {
+        Some(0) => Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(|l| l.trim_end().to_string())
+            .filter(|l| !l.is_empty())
+            .collect()),
+        Some(4) => Ok(Vec::new()),
+        _ => Err(format!(
+            "二维码识别失败: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )),
+    }
+}
+
+/// 快捷键触发截图，结果通过事件通知前端
+pub fn spawn_shortcut_capture(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = capture_screenshot(app, Some(CaptureMode::Region)).await {
+            eprintln!("Failed to capture screenshot: {}", e);
+        }
+    });
+}
+
+/// 执行截图，文件未生成时视为用户取消
+fn run_capture(mode: CaptureMode, path: &Path) -> Result<bool, String> {
+    let candidates = capture_commands(mode, path);
+    if candidates.is_empty() {
+        return Err("当前系统不支持该截图模式".to_string());
+    }
+    let Some((program, args)) = candidates
+        .into_iter()
+        .find(|(program, _)| command_available(program))
+    else {
+        return Err(
+            "未找到截图工具，请安装 gnome-screenshot、spectacle、grim 或 scrot".to_string(),
+        );
+    };
+
+    let output = Command::new(&program)
+        .args(&args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .map_err(|e| format!("启动 {} 失败: {}", program, e))?;
+    let captured = fs::metadata(path).is_ok_and(|m| m.len() > 0);
+    if !captured && !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        // 取消选择时多数工具以非零状态退出且不输出错误
+        if !stderr.trim().is_empty() && !stderr.to_lowercase().contains("cancel") {
+            return Err(format!("截图失败: {}", stderr.trim()));
+        }
+    }
+    Ok(captured)
+}
+
+/// 按平台列出可用的截图命令，按优先级排序
+fn capture_commands(mode: CaptureMode, path: &Path) -> Vec<(String, Vec<String>)> {
+    let target = path.to_string_lossy().to_string();
+    let command = |program: &str, args: &[&str]| {
+        let mut args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+        args.push(target.clone());
+        (program.to_string(), args)
+    };
+
+    if cfg!(target_os = "macos") {
+        return vec![match mode {
+            CaptureMode::Region => command("screencapture", &["-x", "-i", "-s"]),
+            CaptureMode::Window => command("screencapture", &["-x", "-i", "-W"]),
+            CaptureMode::Full => command("screencapture", &["-x"]),
+        }];
+    }
+    if cfg!(target_os = "windows") {
+        // 系统自带的截图工具只能写入剪贴板，这里只支持全屏截图
+        if mode != CaptureMode::Full {
+            return Vec::new();
+        }
+        let script = format!(
+            "Add-Type -AssemblyName System.Windows.Forms,System.Drawing; \
+             $b = [System.Windows.Forms.SystemInformation]::VirtualScreen; \
+             $bmp = New-Object System.Drawing.Bitmap $b.Width, $b.Height; \
+             $g = [System.Drawing.Graphics]::FromImage($bmp); \
+             $g.CopyFromScreen($b.Left, $b.Top, 0, 0, $bmp.Size); \
+             $bmp.Save('{}', [System.Drawing.Imaging.ImageFormat]::Png)",
+            target.replace('\'', "''")
+        );
+        return vec![(
+            "powershell".to_string(),
+            vec!["-NoProfile".to_string(), "-Command".to_string(), script],
+        )];
+    }
+
+    let grim = match mode {
+        CaptureMode::Region => Some(format!("grim -g \"$(slurp)\" '{}'", target)),
+        CaptureMode::Full => Some(format!("grim '{}'", target)),
+        CaptureMode::Window => None,
+    };
+    let mut commands = match mode {
+        CaptureMode::Region => vec![
+            command("gnome-screenshot", &["-a", "-f"]),
+            command("spectacle", &["-b", "-n", "-r", "-o"]),
+            command("scrot", &["-s", "-o"]),
+            command("import", &[]),
+        ],
+        CaptureMode::Window => vec![
+            command("gnome-screenshot", &["-w", "-f"]),
+            command("spectacle", &["-b", "-n", "-a", "-o"]),
+            command("scrot", &["-u", "-o"]),
+        ],
+        CaptureMode::Full => vec![
+            command("gnome-screenshot", &["-f"]),
+            command("spectacle", &["-b", "-n", "-f", "-o"]),
+            command("scrot", &["-o"]),
+            command("import", &["-window", "root"]),
+        ],
+    };
+    // Wayland 下通常只有 grim 能截图
+    if let Some(script) = grim {
+        let index = if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+            0
+        } else {
+            commands.len()
+        };
+        commands.insert(index, ("sh".to_string(), vec!["-c".to_string(), script]));
+    }
+    commands
+}
+
+fn command_available(program: &str) -> bool {
+    // sh 只用于调用 grim，需要检查实际的截图工具
+    let program = if program == "sh" { "grim" } else { program };
+    std::env::var_os("PATH").is_some_and(|paths| {
+        std::env::split_paths(&paths).any(|dir| {
+            let candidate = dir.join(program);
+            candidate.is_file() || candidate.with_extension("exe").is_file()
+        })
+    })
+}
+
+fn describe_capture(path: &Path, mode: CaptureMode) -> Result<ScreenshotCaptured, String> {
+    let size_bytes = fs::metadata(path)
+        .map_err(|e| format!("读取截图文件失败: {}", e))?
+        .len();
+    let (width, height) =
+        image::image_dimensions(path).map_err(|e| format!("截图文件无效: {}", e))?;
+    Ok(ScreenshotCaptured {
+        path: path.to_string_lossy().to_string(),
+        mode,
+        width,
+        height,
+        size_bytes,
+        actions: follow_up_actions(),
+    })
+}
+
+fn follow_up_actions() -> Vec<ScreenshotAction> {
+    [
+        ("ocr", "ocr", "识别文字"),
+        ("image_convert", "imageconverter", "转换格式"),
+        ("qr_decode", "qrdecoder", "识别二维码"),
+    ]
+    .iter()
+    .map(|(id, tool_id, label)| ScreenshotAction {
+        id: id.to_string(),
+        tool_id: tool_id.to_string(),
+        label: label.to_string(),
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capture_commands() {
+        let path = Path::new("/tmp/shot.png");
+        let region = capture_commands(CaptureMode::Region, path);
+        assert!(!region.is_empty());
+        // 目标文件始终作为最后一个参数
+        assert!(region
+            .iter()
+            .all(|(_, args)| args.last().is_some_and(|a| a.contains("/tmp/shot.png"))));
+        if cfg!(target_os = "linux") {
+            assert!(region
+                .iter()
+                .any(|(program, args)| program == "gnome-screenshot" && args[0] == "-a"));
+            let window = capture_commands(CaptureMode::Window, path);
+            assert!(window.iter().all(|(program, _)| program != "sh"));
+        }
+        let mode: CaptureMode = serde_json::from_str("\"window\"").unwrap();
+        assert_eq!(mode, CaptureMode::Window);
+    }
+
+    #[test]
+    fn test_describe_capture() {
+        let path = std::env::temp_dir().join(format!(
+            "devtools-screenshot-test-{}.png",
+            std::process::id()
+        ));
+        image::RgbImage::new(40, 20).save(&path).unwrap();
+        let captured = describe_capture(&path, CaptureMode::Full).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!((captured.width, captured.height), (40, 20));
+        assert!(captured.size_bytes > 0);
+        let ids: Vec<&str> = captured.actions.iter().map(|a| a.id.as_str()).collect();
+        assert_eq!(ids, vec!["ocr", "image_convert", "qr_decode"]);
+        assert!(describe_capture(&path, CaptureMode::Full).is_err());
+    }
+}
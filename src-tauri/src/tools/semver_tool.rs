@@ -0,0 +1,554 @@
+use semver::{Prerelease, Version};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvaluateSemverRequest {
+    pub versions: Vec<String>,
+    pub range: String,
+    pub dialect: Option<String>,          // npm, cargo, go，默认 npm
+    pub explain: Option<String>,          // 需要解释匹配结果的版本
+    pub include_prerelease: Option<bool>, // 预发布版本是否参与所有范围的匹配
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SemverVersionResult {
+    pub version: String,
+    pub valid: bool,
+    pub matched: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SemverExplanation {
+    pub version: String,
+    pub matched: bool,
+    pub details: Vec<String>, // 按范围分组逐条说明比较结果
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SemverEvaluation {
+    pub dialect: String,
+    pub normalized_range: String, // 展开后的比较器，如 >=1.2.3 <2.0.0-0
+    pub results: Vec<SemverVersionResult>, // 与输入顺序一致
+    pub matching: Vec<String>,    // 匹配的版本，从新到旧
+    pub max_satisfying: Option<String>,
+    pub explanation: Option<SemverExplanation>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Dialect {
+    Npm,
+    Cargo,
+    Go,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Eq,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+#[derive(Debug, Clone)]
+struct Comparator {
+    op: Op,
+    version: Version,
+}
+
+impl fmt::Display for Comparator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let op = match self.op {
+            Op::Eq => "=",
+            Op::Gt => ">",
+            Op::Ge => ">=",
+            Op::Lt => "<",
+            Op::Le => "<=",
+        };
+        write!(f, "{}{}", op, self.version)
+    }
+}
+
+impl Comparator {
+    fn matches(&self, version: &Version) -> bool {
+        let ordering = precedence(version, &self.version);
+        match self.op {
+            Op::Eq => ordering == Ordering::Equal,
+            Op::Gt => ordering == Ordering::Greater,
+            Op::Ge => ordering != Ordering::Less,
+            Op::Lt => ordering == Ordering::Less,
+            Op::Le => ordering != Ordering::Greater,
+        }
+    }
+}
+
+/// 只写了部分位数的版本，如 1、1.2、1.x
+#[derive(Debug, Clone)]
+struct Partial {
+    major: Option<u64>,
+    minor: Option<u64>,
+    patch: Option<u64>,
+    pre: Prerelease,
+}
+
+impl Partial {
+    fn floor(&self) -> Version {
+        let mut version = Version::new(
+            self.major.unwrap_or(0),
+            self.minor.unwrap_or(0),
+            self.patch.unwrap_or(0),
+        );
+        version.pre = self.pre.clone();
+        version
+    }
+
+    fn is_full(&self) -> bool {
+        self.patch.is_some()
+    }
+}
+
+/// 计算满足范围的版本，并解释指定版本为何匹配或不匹配
+///
+/// 支持 npm（^ ~ x 通配、a - b、||）、Cargo（逗号分隔，裸版本按 ^ 处理）
+/// 以及 Go（v 前缀、伪版本，裸版本表示同一主版本内的最低版本）。
+#[tauri::command]
+pub fn evaluate_semver(request: EvaluateSemverRequest) -> Result<SemverEvaluation, String> {
+    let dialect = match request
+        .dialect
+        .as_deref()
+        .unwrap_or("npm")
+        .to_lowercase()
+        .as_str()
+    {
+        "npm" | "node" => Dialect::Npm,
+        "cargo" | "rust" => Dialect::Cargo,
+        "go" | "golang" => Dialect::Go,
+        other => return Err(format!("不支持的版本规则: {}", other)),
+    };
+    let sets = parse_range(&request.range, dialect)?;
+    let include_prerelease = request.include_prerelease.unwrap_or(false) || dialect == Dialect::Go;
+
+    let mut results = Vec::with_capacity(request.versions.len());
+    let mut matching: Vec<(Version, String)> = Vec::new();
+    for raw in &request.versions {
+        let raw = raw.trim();
+        if raw.is_empty() {
+            continue;
+        }
+        let parsed = parse_version(raw).ok();
+        let matched = parsed
+            .as_ref()
+            .is_some_and(|v| satisfies(v, &sets, include_prerelease));
+        if let (true, Some(version)) = (matched, &parsed) {
+            matching.push((version.clone(), raw.to_string()));
+        }
+        results.push(SemverVersionResult {
+            version: raw.to_string(),
+            valid: parsed.is_some(),
+            matched,
+        });
+    }
+    matching.sort_by(|a, b| precedence(&b.0, &a.0));
+    matching.dedup_by(|a, b| a.1 == b.1);
+
+    let explanation = match request.explain.as_deref().map(str::trim) {
+        Some(raw) if !raw.is_empty() => Some(explain(raw, &sets, include_prerelease)),
+        _ => None,
+    };
+
+    Ok(SemverEvaluation {
+        dialect: format!("{:?}", dialect).to_lowercase(),
+        normalized_range: format_range(&sets),
+        results,
+        max_satisfying: matching.first().map(|m| m.1.clone()),
+        matching: matching.into_iter().map(|m| m.1).collect(),
+        explanation,
+    })
+}
+
+/// 按语义化版本优先级比较，忽略构建元数据
+fn precedence(a: &Version, b: &Version) -> Ordering {
+    (a.major, a.minor, a.patch, &a.pre).cmp(&(b.major, b.minor, b.patch, &b.pre))
+}
+
+fn parse_version(raw: &str) -> Result<Version, String> {
+    let trimmed = raw.trim().trim_start_matches('=');
+    let trimmed = trimmed.strip_prefix('v').unwrap_or(trimmed);
+    Version::parse(trimmed).map_err(|e| format!("版本号无效 {}: {}", raw, e))
+}
+
+fn parse_partial(raw: &str) -> Result<Partial, String> {
+    let text = raw.strip_prefix('v').unwrap_or(raw);
+    if text.is_empty() {
+        return Err("缺少版本号".to_string());
+    }
+    // 构建元数据不参与比较
+    let text = text.split('+').next().unwrap_or_default();
+    let (core, pre) = match text.split_once('-') {
+        Some((core, pre)) => (core, pre),
+        None => (text, ""),
+    };
+    let mut parts = [None, None, None];
+    let segments: Vec<&str> = core.split('.').collect();
+    if segments.len() > 3 {
+        return Err(format!("版本号无效: {}", raw));
+    }
+    for (slot, segment) in parts.iter_mut().zip(&segments) {
+        *slot = match *segment {
+            "x" | "X" | "*" => None,
+            number => Some(
+                number
+                    .parse::<u64>()
+                    .map_err(|_| format!("版本号无效: {}", raw))?,
+            ),
+        };
+    }
+    // 通配符之后的位只能是通配符
+    let first_wild = parts.iter().position(Option::is_none).unwrap_or(3);
+    if parts[first_wild..].iter().any(Option::is_some) {
+        return Err(format!("版本号无效: {}", raw));
+    }
+    let pre = if pre.is_empty() {
+        Prerelease::EMPTY
+    } else if first_wild < 3 {
+        return Err(format!("预发布版本必须写全三位版本号: {}", raw));
+    } else {
+        Prerelease::new(pre).map_err(|e| format!("预发布标识无效 {}: {}", raw, e))?
+    };
+    Ok(Partial {
+        major: parts[0],
+        minor: parts[1],
+        patch: parts[2],
+        pre,
+    })
+}
+
+/// 上界使用 -0 预发布，排除下一个版本的预发布版本
+fn upper(major: u64, minor: u64, patch: u64) -> Comparator {
+    let mut version = Version::new(major, minor, patch);
+    version.pre = Prerelease::new("0").unwrap_or(Prerelease::EMPTY);
+    Comparator {
+        op: Op::Lt,
+        version,
+    }
+}
+
+fn lower(version: Version) -> Comparator {
+    Comparator {
+        op: Op::Ge,
+        version,
+    }
+}
+
+fn caret(p: &Partial) -> Vec<Comparator> {
+    let Some(major) = p.major else {
+        return vec![lower(Version::new(0, 0, 0))];
+    };
+    let bound = match (major, p.minor, p.patch) {
+        (0, None, _) => upper(1, 0, 0),
+        (0, Some(0), None) => upper(0, 1, 0),
+        (0, Some(0), Some(patch)) => upper(0, 0, patch + 1),
+        (0, Some(minor), _) => upper(0, minor + 1, 0),
+        (major, _, _) => upper(major + 1, 0, 0),
+    };
+    vec![lower(p.floor()), bound]
+}
+
+fn tilde(p: &Partial) -> Vec<Comparator> {
+    let Some(major) = p.major else {
+        return vec![lower(Version::new(0, 0, 0))];
+    };
+    let bound = match p.minor {
+        Some(minor) => upper(major, minor + 1, 0),
+        None => upper(major + 1, 0, 0),
+    };
+    vec![lower(p.floor()), bound]
+}
+
+/// 通配和部分版本，1.2 等价于 >=1.2.0 <1.3.0-0
+fn wildcard(p: &Partial) -> Vec<Comparator> {
+    match (p.major, p.minor, p.patch) {
+        (None, _, _) => vec![lower(Version::new(0, 0, 0))],
+        (Some(major), None, _) => vec![lower(p.floor()), upper(major + 1, 0, 0)],
+        (Some(major), Some(minor), None) => {
+            vec![lower(p.floor()), upper(major, minor + 1, 0)]
+        }
+        _ => vec![Comparator {
+            op: Op::Eq,
+            version: p.floor(),
+        }],
+    }
+}
+
+/// 部分版本的下一个版本，如 1.2 的下一个是 1.3.0
+fn next_after(p: &Partial) -> Option<Comparator> {
+    match (p.major, p.minor) {
+        (Some(major), None) => Some(upper(major + 1, 0, 0)),
+        (Some(major), Some(minor)) => Some(upper(major, minor + 1, 0)),
+        _ => None,
+    }
+}
+
+fn parse_comparator(token: &str, dialect: Dialect) -> Result<Vec<Comparator>, String> {
+    let token = token.trim();
+    let (op, rest) = ["~>", ">=", "<=", "^", "~", ">", "<", "="]
+        .iter()
+        .find_map(|op| token.strip_prefix(op).map(|rest| (*op, rest.trim())))
+        .unwrap_or(("", token));
+    let p = parse_partial(rest).map_err(|e| format!("比较器 {} 无效: {}", token, e))?;
+    let comparators = match op {
+        "^" => caret(&p),
+        "~" | "~>" => tilde(&p),
+        "=" => wildcard(&p),
+        ">=" => vec![lower(p.floor())],
+        ">" if p.is_full() => vec![Comparator {
+            op: Op::Gt,
+            version: p.floor(),
+        }],
+        // >1.2 等价于 >=1.3.0
+        ">" => match next_after(&p) {
+            Some(next) => vec![lower(Version::new(
+                next.version.major,
+                next.version.minor,
+                next.version.patch,
+            ))],
+            None => vec![upper(0, 0, 0)],
+        },
+        "<" => vec![Comparator {
+            op: Op::Lt,
+            version: p.floor(),
+        }],
+        "<=" if p.is_full() => vec![Comparator {
+            op: Op::Le,
+            version: p.floor(),
+        }],
+        "<=" => match next_after(&p) {
+            Some(next) => vec![next],
+            None => vec![lower(Version::new(0, 0, 0))],
+        },
+        _ => match dialect {
+            Dialect::Npm => wildcard(&p),
+            Dialect::Cargo => caret(&p),
+            // go.mod 中的版本是最低版本，主版本变化意味着模块路径变化
+            Dialect::Go => match p.major {
+                Some(major) => vec![lower(p.floor()), upper(major + 1, 0, 0)],
+                None => vec![lower(Version::new(0, 0, 0))],
+            },
+        },
+    };
+    Ok(comparators)
+}
+
+/// 解析为“或”连接的多组比较器，每组内的比较器需同时满足
+fn parse_range(range: &str, dialect: Dialect) -> Result<Vec<Vec<Comparator>>, String> {
+    let range = range.trim();
+    if range.is_empty() {
+        return Err("范围表达式不能为空".to_string());
+    }
+    let mut sets = Vec::new();
+    for group in range.split("||") {
+        let group = group.trim();
+        if group.is_empty() {
+            return Err("|| 两侧不能为空".to_string());
+        }
+        let mut comparators = Vec::new();
+        if dialect == Dialect::Cargo {
+            for token in group.split(',') {
+                if token.trim().is_empty() {
+                    return Err("逗号之间缺少比较器".to_string());
+                }
+                comparators.extend(parse_comparator(token, dialect)?);
+            }
+        } else if let Some((from, to)) = group.split_once(" - ") {
+            // 连字符范围，上界为部分版本时不包含下一个版本
+            let from = parse_partial(from.trim())?;
+            let to = parse_partial(to.trim())?;
+            comparators.push(lower(from.floor()));
+            if to.is_full() {
+                comparators.push(Comparator {
+                    op: Op::Le,
+                    version: to.floor(),
+                });
+            } else if let Some(next) = next_after(&to) {
+                comparators.push(next);
+            }
+        } else {
+            // npm 允许运算符与版本之间有空格，如 >= 1.2.3
+            let mut pending = String::new();
+            for token in group.split_whitespace().map(|t| t.trim_end_matches(',')) {
+                if token.is_empty() {
+                    continue;
+                }
+                pending.push_str(token);
+                if token.chars().all(|c| "<>=~^".contains(c)) {
+                    continue;
+                }
+                comparators.extend(parse_comparator(&pending, dialect)?);
+                pending.clear();
+            }
+            if !pending.is_empty() {
+                return Err(format!("运算符 {} 后缺少版本号", pending));
+            }
+        }
+        sets.push(comparators);
+    }
+    Ok(sets)
+}
+
+/// 预发布版本只有在同组中存在相同主次修订号的预发布比较器时才参与匹配
+fn prerelease_allowed(version: &Version, set: &[Comparator], include_prerelease: bool) -> bool {
+    version.pre.is_empty()
+        || include_prerelease
+        || set.iter().any(|c| {
+            !c.version.pre.is_empty()
+                && c.version.pre.as_str() != "0"
+                && (c.version.major, c.version.minor, c.version.patch)
+                    == (version.major, version.minor, version.patch)
+        })
+}
+
+fn satisfies(version: &Version, sets: &[Vec<Comparator>], include_prerelease: bool) -> bool {
+    sets.iter().any(|set| {
+        set.iter().all(|c| c.matches(version))
+            && prerelease_allowed(version, set, include_prerelease)
+    })
+}
+
+fn explain(raw: &str, sets: &[Vec<Comparator>], include_prerelease: bool) -> SemverExplanation {
+    let version = match parse_version(raw) {
+        Ok(version) => version,
+        Err(e) => {
+            return SemverExplanation {
+                version: raw.to_string(),
+                matched: false,
+                details: vec![e],
+            }
+        }
+    };
+    let mut details = Vec::new();
+    for (index, set) in sets.iter().enumerate() {
+        let prefix = if sets.len() > 1 {
+            format!("第 {} 组: ", index + 1)
+        } else {
+            String::new()
+        };
+        let failed: Vec<String> = set
+            .iter()
+            .filter(|c| !c.matches(&version))
+            .map(|c| c.to_string())
+            .collect();
+        if !failed.is_empty() {
+            details.push(format!("{}{} 不满足 {}", prefix, raw, failed.join(" 和 ")));
+        } else if !prerelease_allowed(&version, set, include_prerelease) {
+            details.push(format!(
+                "{}{} 是预发布版本，而该组没有 {}.{}.{} 的预发布比较器，默认不匹配",
+                prefix, raw, version.major, version.minor, version.patch
+            ));
+        } else {
+            details.push(format!("{}{} 满足 {}", prefix, raw, format_set(set)));
+        }
+    }
+    SemverExplanation {
+        version: raw.to_string(),
+        matched: satisfies(&version, sets, include_prerelease),
+        details,
+    }
+}
+
+fn format_set(set: &[Comparator]) -> String {
+    set.iter()
+        .map(|c| c.to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn format_range(sets: &[Vec<Comparator>]) -> String {
+    sets.iter()
+        .map(|set| format_set(set))
+        .collect::<Vec<_>>()
+        .join(" || ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn evaluate(versions: &[&str], range: &str, dialect: &str) -> SemverEvaluation {
+        evaluate_semver(EvaluateSemverRequest {
+            versions: versions.iter().map(|v| v.to_string()).collect(),
+            range: range.to_string(),
+            dialect: Some(dialect.to_string()),
+            explain: None,
+            include_prerelease: None,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_npm_ranges() {
+        let versions = [
+            "0.2.5",
+            "0.3.0",
+            "1.2.3",
+            "1.9.0",
+            "2.0.0-beta.1",
+            "2.0.0",
+            "bad",
+        ];
+        let result = evaluate(&versions, "^0.2.3 || ~1.2", "npm");
+        assert_eq!(
+            result.normalized_range,
+            ">=0.2.3 <0.3.0-0 || >=1.2.0 <1.3.0-0"
+        );
+        assert_eq!(result.matching, vec!["1.2.3", "0.2.5"]);
+        assert!(!result.results[6].valid);
+
+        let result = evaluate(&versions, ">= 1.2 <2 || 2.0.0-beta.1", "npm");
+        assert_eq!(result.matching, vec!["2.0.0-beta.1", "1.9.0", "1.2.3"]);
+        assert_eq!(
+            evaluate(&versions, "1.2.3 - 2", "npm")
+                .max_satisfying
+                .as_deref(),
+            Some("2.0.0")
+        );
+        assert!(parse_range(">=", Dialect::Npm).is_err());
+        assert!(parse_range("1.x.3", Dialect::Npm).is_err());
+    }
+
+    #[test]
+    fn test_cargo_go_and_explain() {
+        let result = evaluate(
+            &["0.1.9", "0.2.0", "0.2.7", "0.3.0"],
+            "0.2, <0.2.5",
+            "cargo",
+        );
+        assert_eq!(result.matching, vec!["0.2.0"]);
+
+        let go_versions = [
+            "v1.4.0",
+            "v1.5.0-0.20240101120000-abcdef123456",
+            "v1.5.0",
+            "v2.0.0+incompatible",
+        ];
+        let result = evaluate(&go_versions, "v1.4.0", "go");
+        assert_eq!(
+            result.matching,
+            vec!["v1.5.0", "v1.5.0-0.20240101120000-abcdef123456", "v1.4.0"]
+        );
+
+        let result = evaluate_semver(EvaluateSemverRequest {
+            versions: vec![],
+            range: ">=1.0.0 <1.5.0 || ^2.1".to_string(),
+            dialect: None,
+            explain: Some("1.5.0-rc.1".to_string()),
+            include_prerelease: None,
+        })
+        .unwrap();
+        let explanation = result.explanation.unwrap();
+        assert!(!explanation.matched);
+        assert!(explanation.details[0].contains("预发布"));
+        assert_eq!(explanation.details[1], "第 2 组: 1.5.0-rc.1 不满足 >=2.1.0");
+    }
+}
@@ -0,0 +1,639 @@
+use crate::utils::progress::ProgressReporter;
+use crate::utils::proxy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::io::Cursor;
+use std::path::Path;
+use std::time::Duration;
+use tauri::AppHandle;
+use tokio::task::JoinSet;
+use url::Url;
+
+/// 页面和图标的大小上限
+const MAX_PAGE_BYTES: usize = 5 * 1024 * 1024;
+const MAX_ICON_BYTES: usize = 5 * 1024 * 1024;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SiteIconsRequest {
+    pub url: String,
+    pub download_dir: Option<String>, // 指定时把找到的图标保存到该目录
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SiteIcon {
+    pub source: String, // link, manifest, default（未声明时尝试的 /favicon.ico）
+    pub rel: String,    // icon, apple-touch-icon, mask-icon, manifest 中为 purpose
+    pub url: String,
+    pub declared_sizes: Option<String>,
+    pub declared_type: Option<String>,
+    pub status: Option<u16>,
+    pub content_type: Option<String>,
+    pub format: Option<String>, // 按文件头识别：png, ico, svg, jpeg, webp, gif
+    pub width: Option<u32>,     // ico 为其中最大的一张
+    pub height: Option<u32>,
+    pub size_bytes: Option<usize>,
+    pub issues: Vec<String>,
+    pub saved_path: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SiteIconsReport {
+    pub url: String, // 跟随重定向后的最终地址
+    pub manifest_url: Option<String>,
+    pub manifest_name: Option<String>,
+    pub theme_color: Option<String>,
+    pub icons: Vec<SiteIcon>,
+    pub missing: Vec<String>, // 缺少的推荐配置
+    pub warnings: Vec<String>,
+}
+
+/// 页面中声明的图标候选项
+#[derive(Debug, Clone)]
+struct IconCandidate {
+    source: &'static str,
+    rel: String,
+    url: String,
+    sizes: Option<String>,
+    mime: Option<String>,
+}
+
+/// 抓取页面并分析 favicon、apple-touch-icon 和 Web App Manifest 中的图标
+#[tauri::command]
+pub async fn analyze_site_icons(
+    app: AppHandle,
+    request: SiteIconsRequest,
+) -> Result<SiteIconsReport, String> {
+    let mut reporter = ProgressReporter::new(&app, "site_icons");
+    let result = analyze(&request, &mut reporter).await;
+    match &result {
+        Ok(report) => reporter.finish(Some(report.url.clone())),
+        Err(e) => reporter.fail(e),
+    }
+    result
+}
+
+async fn analyze(
+    request: &SiteIconsRequest,
+    reporter: &mut ProgressReporter,
+) -> Result<SiteIconsReport, String> {
+    let mut url = request.url.trim().to_string();
+    if url.is_empty() {
+        return Err("请输入网址".to_string());
+    }
+    if !url.contains("://") {
+        url = format!("https://{}", url);
+    }
+    let client = proxy::client_builder("site_icons")
+        .timeout(Duration::from_secs(15))
+        .user_agent("Mozilla/5.0 (compatible; devtools-icon-analyzer)")
+        .build()
+        .map_err(|e| format!("构建 HTTP 客户端失败: {}", e))?;
+
+    let response = client
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| format!("请求页面失败: {}", e))?;
+    if !response.status().is_success() {
+        return Err(format!("请求页面失败: HTTP {}", response.status()));
+    }
+    let page_url = response.url().clone();
+    let html = read_limited(response, MAX_PAGE_BYTES).await?;
+    let html = String::from_utf8_lossy(&html);
+
+    let mut warnings = Vec::new();
+    let (mut candidates, manifest_href, theme_color) = parse_links(&html, &page_url);
+
+    let mut manifest_url = None;
+    let mut manifest_name = None;
+    if let Some(href) = manifest_href {
+        manifest_url = Some(href.to_string());
+        match fetch_manifest(&client, &href).await {
+            Ok(manifest) => {
+                manifest_name = manifest
+                    .get("name")
+                    .or_else(|| manifest.get("short_name"))
+                    .and_then(Value::as_str)
+                    .map(String::from);
+                candidates.extend(parse_manifest_icons(&manifest, &href));
+            }
+            Err(e) => warnings.push(format!("读取 manifest 失败: {}", e)),
+        }
+    }
+    if !candidates.iter().any(|c| c.rel == "icon") {
+        if let Ok(default) = page_url.join("/favicon.ico") {
+            candidates.push(IconCandidate {
+                source: "default",
+                rel: "icon".to_string(),
+                url: default.to_string(),
+                sizes: None,
+                mime: None,
+            });
+        }
+    }
+
+    let total = candidates.len() as u64;
+    reporter.update(0, Some(total), None);
+    let mut tasks = JoinSet::new();
+    for (index, candidate) in candidates.into_iter().enumerate() {
+        let client = client.clone();
+        tasks.spawn(async move {
+            let fetched = fetch_icon(&client, &candidate.url).await;
+            (index, candidate, fetched)
+        });
+    }
+    let mut fetched = Vec::new();
+    while let Some(joined) = tasks.join_next().await {
+        let (index, candidate, result) = joined.map_err(|e| format!("下载图标任务失败: {}", e))?;
+        reporter.update(
+            fetched.len() as u64 + 1,
+            Some(total),
+            Some(candidate.url.clone()),
+        );
+        fetched.push((index, candidate, result));
+    }
+    fetched.sort_by_key(|(index, _, _)| *index);
+
+    let mut icons = Vec::new();
+    for (_, candidate, result) in fetched {
+        let (mut icon, bytes) = analyze_icon(&candidate, result);
+        if let (Some(dir), Some(bytes)) = (&request.download_dir, bytes) {
+            match save_icon(dir, icons.len(), &icon, &bytes) {
+                Ok(path) => icon.saved_path = Some(path),
+                Err(e) => warnings.push(e),
+            }
+        }
+        icons.push(icon);
+    }
+
+    let missing = missing_recommendations(&icons, manifest_url.is_some());
+    if page_url.scheme() == "http" {
+        warnings.push("页面通过 HTTP 访问，PWA 需要 HTTPS".to_string());
+    }
+
+    Ok(SiteIconsReport {
+        url: page_url.to_string(),
+        manifest_url,
+        manifest_name,
+        theme_color,
+        icons,
+        missing,
+        warnings,
+    })
+}
+
+async fn read_limited(response: reqwest::Response, limit: usize) -> Result<Vec<u8>, String> {
+    if response
+        .content_length()
+        .is_some_and(|len| len as usize > limit)
+    {
+        return Err(format!("响应超过 {} MB", limit / 1024 / 1024));
+    }
+    let bytes = response
+        .bytes()
+        .await
+        .map_err(|e| format!("读取响应失败: {}", e))?;
+    if bytes.len() > limit {
+        return Err(format!("响应超过 {} MB", limit / 1024 / 1024));
+    }
+    Ok(bytes.to_vec())
+}
+
+fn attributes(tag: &str) -> Vec<(String, String)> {
+    let re = Regex::new(r#"([a-zA-Z][a-zA-Z0-9:_-]*)\s*=\s*(?:"([^"]*)"|'([^']*)'|([^\s"'>]+))"#)
+        .expect("属性正则无效");
+    re.captures_iter(tag)
+        .map(|cap| {
+            let value = cap
+                .get(2)
+                .or_else(|| cap.get(3))
+                .or_else(|| cap.get(4))
+                .map(|m| m.as_str())
+                .unwrap_or_default();
+            (cap[1].to_lowercase(), decode_entities(value))
+        })
+        .collect()
+}
+
+fn decode_entities(value: &str) -> String {
+    value
+        .replace("&amp;", "&")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+}
+
+/// 从 HTML 中提取图标链接、manifest 地址和 theme-color，<base href> 会影响相对地址
+fn parse_links(html: &str, page_url: &Url) -> (Vec<IconCandidate>, Option<Url>, Option<String>) {
+    let tag_re = Regex::new(r"(?is)<(link|meta|base)\b[^>]*>").expect("标签正则无效");
+    let mut base = page_url.clone();
+    let mut candidates = Vec::new();
+    let mut manifest = None;
+    let mut theme_color = None;
+
+    for cap in tag_re.captures_iter(html) {
+        let attrs = attributes(&cap[0]);
+        let get = |name: &str| {
+            attrs
+                .iter()
+                .find(|(k, _)| k == name)
+                .map(|(_, v)| v.trim().to_string())
+        };
+        match cap[1].to_lowercase().as_str() {
+            "base" => {
+                if let Some(href) = get("href").and_then(|h| page_url.join(&h).ok()) {
+                    base = href;
+                }
+            }
+            "meta" => {
+                if get("name").is_some_and(|n| n.eq_ignore_ascii_case("theme-color")) {
+                    theme_color = theme_color.or(get("content"));
+                }
+            }
+            _ => {
+                let (Some(rel), Some(href)) = (get("rel"), get("href")) else {
+                    continue;
+                };
+                let Ok(url) = base.join(&href) else {
+                    continue;
+                };
+                let rel = rel.to_lowercase();
+                let rels: Vec<&str> = rel.split_whitespace().collect();
+                if rels.contains(&"manifest") {
+                    manifest = manifest.or(Some(url));
+                    continue;
+                }
+                let kind = if rels.contains(&"apple-touch-icon")
+                    || rels.contains(&"apple-touch-icon-precomposed")
+                {
+                    "apple-touch-icon"
+                } else if rels.contains(&"mask-icon") {
+                    "mask-icon"
+                } else if rels.contains(&"icon") {
+                    "icon"
+                } else {
+                    continue;
+                };
+                candidates.push(IconCandidate {
+                    source: "link",
+                    rel: kind.to_string(),
+                    url: url.to_string(),
+                    sizes: get("sizes"),
+                    mime: get("type"),
+                });
+            }
+        }
+    }
+    (candidates, manifest, theme_color)
+}
+
+async fn fetch_manifest(client: &reqwest::Client, url: &Url) -> Result<Value, String> {
+    let response = client
+        .get(url.clone())
+        .send()
+        .await
+        .map_err(|e| format!("请求失败: {}", e))?;
+    if !response.status().is_success() {
+        return Err(format!("HTTP {}", response.status()));
+    }
+    let bytes = read_limited(response, MAX_PAGE_BYTES).await?;
+    serde_json::from_slice(&bytes).map_err(|e| format!("JSON解析失败: {}", e))
+}
+
+/// manifest 中的 src 相对于 manifest 自身的地址
+fn parse_manifest_icons(manifest: &Value, manifest_url: &Url) -> Vec<IconCandidate> {
+    manifest
+        .get("icons")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|icon| {
+            let src = icon.get("src")?.as_str()?;
+            let url = manifest_url.join(src).ok()?;
+            let text = |key: &str| icon.get(key).and_then(Value::as_str).map(String::from);
+            Some(IconCandidate {
+                source: "manifest",
+                rel: text("purpose").unwrap_or_else(|| "any".to_string()),
+                url: url.to_string(),
+                sizes: text("sizes"),
+                mime: text("type"),
+            })
+        })
+        .collect()
+}
+
+struct FetchedIcon {
+    status: u16,
+    content_type: Option<String>,
+    bytes: Option<Vec<u8>>,
+}
+
+async fn fetch_icon(client: &reqwest::Client, url: &str) -> Result<FetchedIcon, String> {
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| format!("请求失败: {}", e))?;
+    let status = response.status().as_u16();
+    let content_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.to_string());
+    let bytes = if response.status().is_success() {
+        Some(read_limited(response, MAX_ICON_BYTES).await?)
+    } else {
+        None
+    };
+    Ok(FetchedIcon {
+        status,
+        content_type,
+        bytes,
+    })
+}
+
+/// 返回分析结果和原始数据，原始数据仅用于保存到本地
+fn analyze_icon(
+    candidate: &IconCandidate,
+    fetched: Result<FetchedIcon, String>,
+) -> (SiteIcon, Option<Vec<u8>>) {
+    let mut icon = SiteIcon {
+        source: candidate.source.to_string(),
+        rel: candidate.rel.clone(),
+        url: candidate.url.clone(),
+        declared_sizes: candidate.sizes.clone(),
+        declared_type: candidate.mime.clone(),
+        status: None,
+        content_type: None,
+        format: None,
+        width: None,
+        height: None,
+        size_bytes: None,
+        issues: Vec::new(),
+        saved_path: None,
+    };
+    let fetched = match fetched {
+        Ok(fetched) => fetched,
+        Err(e) => {
+            icon.issues.push(e);
+            return (icon, None);
+        }
+    };
+    icon.status = Some(fetched.status);
+    icon.content_type = fetched.content_type;
+    let Some(bytes) = fetched.bytes else {
+        icon.issues
+            .push(format!("无法访问: HTTP {}", fetched.status));
+        return (icon, None);
+    };
+    icon.size_bytes = Some(bytes.len());
+    inspect_image(&mut icon, &bytes);
+    (icon, Some(bytes))
+}
+
+/// 识别格式和实际尺寸，并与声明的 sizes、type 对比
+fn inspect_image(icon: &mut SiteIcon, bytes: &[u8]) {
+    let head = String::from_utf8_lossy(&bytes[..bytes.len().min(512)]).to_lowercase();
+    let format = if head.contains("<svg") {
+        Some("svg".to_string())
+    } else {
+        image::guess_format(bytes)
+            .ok()
+            .and_then(|f| f.extensions_str().first().copied())
+            .map(|ext| if ext == "jpg" { "jpeg" } else { ext }.to_string())
+    };
+    let Some(format) = format else {
+        icon.issues.push("无法识别的图片格式".to_string());
+        return;
+    };
+
+    if format != "svg" {
+        match image::ImageReader::new(Cursor::new(bytes))
+            .with_guessed_format()
+            .map_err(|e| e.to_string())
+            .and_then(|reader| reader.into_dimensions().map_err(|e| e.to_string()))
+        {
+            Ok((width, height)) => {
+                icon.width = Some(width);
+                icon.height = Some(height);
+                if width != height {
+                    icon.issues
+                        .push(format!("图标不是正方形: {}x{}", width, height));
+                }
+                let declared = icon.declared_sizes.clone().unwrap_or_default();
+                let sizes: Vec<&str> = declared.split_whitespace().collect();
+                let actual = format!("{}x{}", width, height);
+                if !sizes.is_empty()
+                    && !sizes.contains(&"any")
+                    && !sizes.iter().any(|s| s.eq_ignore_ascii_case(&actual))
+                    && format != "ico"
+                {
+                    icon.issues
+                        .push(format!("声明尺寸 {} 与实际尺寸 {} 不符", declared, actual));
+                }
+            }
+            Err(e) => icon.issues.push(format!("图片解码失败: {}", e)),
+        }
+    }
+
+    if let Some(declared) = &icon.declared_type {
+        let declared_format = declared
+            .trim_start_matches("image/")
+            .replace("svg+xml", "svg")
+            .replace("x-icon", "ico")
+            .replace("vnd.microsoft.icon", "ico");
+        if declared_format != format {
+            icon.issues
+                .push(format!("声明类型 {} 与实际格式 {} 不符", declared, format));
+        }
+    }
+    if icon.rel == "apple-touch-icon" && format != "png" {
+        icon.issues
+            .push("apple-touch-icon 应使用 PNG，iOS 不支持其他格式".to_string());
+    }
+    icon.format = Some(format);
+}
+
+fn save_icon(dir: &str, index: usize, icon: &SiteIcon, bytes: &[u8]) -> Result<String, String> {
+    std::fs::create_dir_all(dir).map_err(|e| format!("创建目录失败: {}", e))?;
+    let last_segment = Url::parse(&icon.url)
+        .ok()
+        .and_then(|u| {
+            u.path_segments()
+                .and_then(|mut s| s.next_back().map(String::from))
+        })
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| format!("icon.{}", icon.format.as_deref().unwrap_or("bin")));
+    let name = crate::tools::slug_tool::sanitize_filename(
+        crate::tools::slug_tool::SanitizeFilenameRequest {
+            name: format!("{:02}-{}", index + 1, last_segment),
+            replacement: None,
+            ascii_only: Some(false),
+        },
+    )?
+    .filename;
+    let path = Path::new(dir).join(name);
+    std::fs::write(&path, bytes).map_err(|e| format!("保存图标失败: {}", e))?;
+    Ok(path.to_string_lossy().to_string())
+}
+
+fn has_size(icon: &SiteIcon, size: u32) -> bool {
+    icon.width == Some(size) && icon.height == Some(size)
+        || icon.format.as_deref() == Some("svg")
+            && icon.declared_sizes.as_deref().is_some_and(|s| {
+                s.split_whitespace()
+                    .any(|s| s == "any" || s == format!("{}x{}", size, size))
+            })
+}
+
+/// 对照常见的浏览器、iOS 和 PWA 安装要求列出缺失项
+fn missing_recommendations(icons: &[SiteIcon], has_manifest: bool) -> Vec<String> {
+    let usable: Vec<&SiteIcon> = icons.iter().filter(|i| i.format.is_some()).collect();
+    let mut missing = Vec::new();
+
+    if !usable.iter().any(|i| i.rel == "icon") {
+        missing.push("缺少可用的 favicon（<link rel=\"icon\"> 或 /favicon.ico）".to_string());
+    }
+    if !usable
+        .iter()
+        .any(|i| i.rel == "icon" && i.format.as_deref() == Some("svg"))
+    {
+        missing.push("建议提供 SVG 格式的 favicon 以适配高分屏和深色模式".to_string());
+    }
+    if !usable
+        .iter()
+        .any(|i| i.rel == "apple-touch-icon" && has_size(i, 180))
+    {
+        missing.push("缺少 180x180 的 apple-touch-icon".to_string());
+    }
+    if !has_manifest {
+        missing.push("缺少 Web App Manifest（<link rel=\"manifest\">）".to_string());
+        return missing;
+    }
+    let manifest_icons: Vec<&&SiteIcon> =
+        usable.iter().filter(|i| i.source == "manifest").collect();
+    for size in [192, 512] {
+        if !manifest_icons.iter().any(|i| has_size(i, size)) {
+            missing.push(format!("manifest 缺少 {}x{} 图标", size, size));
+        }
+    }
+    if !manifest_icons
+        .iter()
+        .any(|i| i.rel.split_whitespace().any(|p| p == "maskable"))
+    {
+        missing.push("manifest 缺少 purpose 为 maskable 的图标".to_string());
+    }
+    missing
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        image::RgbaImage::new(width, height)
+            .write_to(&mut Cursor::new(&mut bytes), image::ImageFormat::Png)
+            .unwrap();
+        bytes
+    }
+
+    fn candidate(rel: &str, sizes: Option<&str>, mime: Option<&str>) -> IconCandidate {
+        IconCandidate {
+            source: "link",
+            rel: rel.to_string(),
+            url: "https://example.com/icon".to_string(),
+            sizes: sizes.map(String::from),
+            mime: mime.map(String::from),
+        }
+    }
+
+    fn fetched(bytes: Vec<u8>) -> Result<FetchedIcon, String> {
+        Ok(FetchedIcon {
+            status: 200,
+            content_type: None,
+            bytes: Some(bytes),
+        })
+    }
+
+    #[test]
+    fn test_parse_links_and_manifest() {
+        let html = r##"<html><head>
+            <base href="/static/">
+            <link rel="icon" href="favicon.svg" type="image/svg+xml" sizes="any">
+            <LINK REL="apple-touch-icon" HREF='/apple.png' sizes=180x180>
+            <link rel="stylesheet" href="app.css">
+            <link rel="manifest" href="/site.webmanifest?v=1&amp;x=2">
+            <meta name="theme-color" content="#336699">
+        </head></html>"##;
+        let page = Url::parse("https://example.com/blog/post").unwrap();
+        let (candidates, manifest, theme) = parse_links(html, &page);
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].url, "https://example.com/static/favicon.svg");
+        assert_eq!(candidates[1].rel, "apple-touch-icon");
+        assert_eq!(candidates[1].url, "https://example.com/apple.png");
+        assert_eq!(candidates[1].sizes.as_deref(), Some("180x180"));
+        let manifest = manifest.unwrap();
+        assert_eq!(
+            manifest.as_str(),
+            "https://example.com/site.webmanifest?v=1&x=2"
+        );
+        assert_eq!(theme.as_deref(), Some("#336699"));
+
+        let json = serde_json::json!({"icons": [
+            {"src": "icons/192.png", "sizes": "192x192", "type": "image/png"},
+            {"src": "/icons/512.png", "sizes": "512x512", "purpose": "maskable"}
+        ]});
+        let manifest_url = Url::parse("https://example.com/app/manifest.json").unwrap();
+        let icons = parse_manifest_icons(&json, &manifest_url);
+        assert_eq!(icons[0].url, "https://example.com/app/icons/192.png");
+        assert_eq!(icons[0].rel, "any");
+        assert_eq!(icons[1].url, "https://example.com/icons/512.png");
+        assert_eq!(icons[1].rel, "maskable");
+    }
+
+    #[test]
+    fn test_icon_validation_and_recommendations() {
+        let (good, _) = analyze_icon(
+            &candidate("apple-touch-icon", Some("180x180"), Some("image/png")),
+            fetched(png(180, 180)),
+        );
+        assert_eq!(good.format.as_deref(), Some("png"));
+        assert_eq!((good.width, good.height), (Some(180), Some(180)));
+        assert!(good.issues.is_empty(), "{:?}", good.issues);
+
+        let (bad, _) = analyze_icon(
+            &candidate("icon", Some("32x32"), Some("image/x-icon")),
+            fetched(png(16, 20)),
+        );
+        assert_eq!(bad.issues.len(), 3, "{:?}", bad.issues);
+        assert!(bad.issues[1].contains("32x32"));
+
+        let (svg, _) = analyze_icon(
+            &candidate("icon", Some("any"), Some("image/svg+xml")),
+            fetched(b"<?xml version=\"1.0\"?><svg xmlns=\"http://www.w3.org/2000/svg\"/>".to_vec()),
+        );
+        assert_eq!(svg.format.as_deref(), Some("svg"));
+        assert!(svg.issues.is_empty());
+
+        let (missing_page, _) = analyze_icon(
+            &candidate("icon", None, None),
+            Ok(FetchedIcon {
+                status: 404,
+                content_type: None,
+                bytes: None,
+            }),
+        );
+        assert!(missing_page.issues[0].contains("404"));
+
+        let icons = vec![good, svg, missing_page];
+        let missing = missing_recommendations(&icons, false);
+        assert_eq!(missing.len(), 1);
+        assert!(missing[0].contains("Manifest"));
+
+        let missing = missing_recommendations(&icons[..1], true);
+        assert_eq!(missing.len(), 5, "{:?}", missing);
+    }
+}
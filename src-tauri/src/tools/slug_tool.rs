@@ -0,0 +1,342 @@
+use crate::utils::string_utils::StringUtils;
+use serde::{Deserialize, Serialize};
+
+/// 大多数文件系统单个文件名的字节上限
+const MAX_FILENAME_BYTES: usize = 255;
+
+/// Windows 保留的设备名，带扩展名也不能使用
+const WINDOWS_RESERVED: &[&str] = &[
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlugRequest {
+    pub text: String,
+    pub separator: Option<String>, // 默认 -
+    pub max_length: Option<usize>, // 按字符数截断，尽量在单词边界处截断
+    pub case: Option<String>,      // lower, upper, keep，默认 lower
+    pub chinese: Option<String>,   // pinyin: 全拼，initials: 首字母，keep: 保留汉字，默认 pinyin
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlugResult {
+    pub slug: String,
+    pub truncated: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SanitizeFilenameRequest {
+    pub name: String,
+    pub replacement: Option<String>, // 非法字符的替换字符，默认 _
+    pub ascii_only: Option<bool>,    // 是否把非 ASCII 字符音译为 ASCII
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SanitizedFilename {
+    pub filename: String,
+    pub changes: Vec<String>, // 做了哪些修改，为空表示原名已可在各平台使用
+}
+
+/// 把任意文本音译为 URL 安全的 slug
+#[tauri::command]
+pub fn generate_slug(request: SlugRequest) -> Result<SlugResult, String> {
+    let separator = request.separator.unwrap_or_else(|| "-".to_string());
+    if separator.chars().any(|c| !is_url_safe(c)) {
+        return Err(format!("分隔符 {:?} 不是 URL 安全字符", separator));
+    }
+    let chinese = request.chinese.as_deref().unwrap_or("pinyin");
+    if !matches!(chinese, "pinyin" | "initials" | "keep") {
+        return Err(format!("不支持的汉字处理方式: {}", chinese));
+    }
+
+    let words: Vec<String> = split_words(&request.text, chinese)
+        .into_iter()
+        .map(|word| match request.case.as_deref().unwrap_or("lower") {
+            "upper" => Ok(word.to_uppercase()),
+            "keep" => Ok(word),
+            "lower" => Ok(word.to_lowercase()),
+            other => Err(format!("不支持的大小写选项: {}", other)),
+        })
+        .collect::<Result<_, _>>()?;
+
+    let mut slug = words.join(&separator);
+    let mut truncated = false;
+    if let Some(max) = request.max_length.filter(|max| slug.chars().count() > *max) {
+        truncated = true;
+        slug.clear();
+        for word in &words {
+            let candidate_len = slug.chars().count()
+                + word.chars().count()
+                + if slug.is_empty() {
+                    0
+                } else {
+                    separator.chars().count()
+                };
+            if candidate_len > max {
+                break;
+            }
+            if !slug.is_empty() {
+                slug.push_str(&separator);
+            }
+            slug.push_str(word);
+        }
+        // 第一个单词就超长时只能硬截断
+        if slug.is_empty() {
+            slug = words
+                .first()
+                .map(|w| w.chars().take(max).collect())
+                .unwrap_or_default();
+        }
+    }
+
+    Ok(SlugResult { slug, truncated })
+}
+
+/// 清理文件名中在 Windows、macOS 或 Linux 上非法的字符和名称
+#[tauri::command]
+pub fn sanitize_filename(request: SanitizeFilenameRequest) -> Result<SanitizedFilename, String> {
+    let replacement = request.replacement.unwrap_or_else(|| "_".to_string());
+    if replacement.chars().any(is_illegal_filename_char) {
+        return Err(format!("替换字符 {:?} 本身不能用于文件名", replacement));
+    }
+
+    let mut changes = Vec::new();
+    let mut name = StringUtils::clean_string(&request.name);
+    if name != request.name {
+        changes.push("移除了控制字符和多余空白".to_string());
+    }
+
+    if request.ascii_only.unwrap_or(false) && !name.is_ascii() {
+        name = name
+            .chars()
+            .map(|c| {
+                if c.is_ascii() {
+                    c.to_string()
+                } else {
+                    deunicode::deunicode_char(c)
+                        .unwrap_or("")
+                        .trim()
+                        .to_string()
+                }
+            })
+            .collect();
+        changes.push("非 ASCII 字符已音译".to_string());
+    }
+
+    if name.chars().any(is_illegal_filename_char) {
+        name = name
+            .chars()
+            .map(|c| {
+                if is_illegal_filename_char(c) {
+                    replacement.clone()
+                } else {
+                    c.to_string()
+                }
+            })
+            .collect();
+        changes.push(format!("非法字符 <>:\"/\\|?* 已替换为 {:?}", replacement));
+    }
+
+    // Windows 会静默去掉结尾的点和空格
+    let trimmed = name.trim_end_matches(['.', ' ']).to_string();
+    if trimmed != name {
+        name = trimmed;
+        changes.push("移除了结尾的点和空格".to_string());
+    }
+
+    let stem = name.split('.').next().unwrap_or_default().to_string();
+    if WINDOWS_RESERVED.contains(&stem.to_uppercase().as_str()) {
+        name = format!("{}{}", replacement, name);
+        changes.push(format!("{} 是 Windows 保留名称", stem));
+    }
+
+    if name.len() > MAX_FILENAME_BYTES {
+        name = truncate_keep_extension(&name, MAX_FILENAME_BYTES);
+        changes.push(format!(
+            "已截断到 {} 字节以内并保留扩展名",
+            MAX_FILENAME_BYTES
+        ));
+    }
+
+    if name.is_empty() || name == "." || name == ".." {
+        name = format!("{}file", replacement);
+        changes.push("文件名为空或只含点号，已使用默认名称".to_string());
+    }
+
+    Ok(SanitizedFilename {
+        filename: name,
+        changes,
+    })
+}
+
+fn is_url_safe(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "-_.~".contains(c)
+}
+
+fn is_illegal_filename_char(c: char) -> bool {
+    c.is_control() || "<>:\"/\\|?*".contains(c)
+}
+
+/// 基本汉字、扩展 A、扩展 B 和兼容汉字
+fn is_han(c: char) -> bool {
+    matches!(
+        c,
+        '\u{4E00}'..='\u{9FFF}'
+            | '\u{3400}'..='\u{4DBF}'
+            | '\u{20000}'..='\u{2A6DF}'
+            | '\u{F900}'..='\u{FAFF}'
+    )
+}
+
+/// 拆分出只含 URL 安全字符的单词，汉字按 chinese 选项处理，其余字符先音译
+fn split_words(text: &str, chinese: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut previous_han = false;
+
+    let flush = |current: &mut String, words: &mut Vec<String>| {
+        if !current.is_empty() {
+            words.push(std::mem::take(current));
+        }
+    };
+
+    for c in text.chars() {
+        let han = is_han(c);
+        if han != previous_han && chinese == "initials" {
+            flush(&mut current, &mut words);
+        }
+        previous_han = han;
+
+        if c.is_ascii_alphanumeric() {
+            current.push(c);
+        } else if matches!(c, '\'' | '’') {
+            // don't → dont
+        } else if han && chinese == "keep" {
+            current.push(c);
+        } else if han {
+            let pinyin = deunicode::deunicode_char(c).unwrap_or("").trim();
+            if chinese == "initials" {
+                current.extend(pinyin.chars().next());
+            } else {
+                flush(&mut current, &mut words);
+                words.push(pinyin.to_string());
+            }
+        } else if c.is_ascii() {
+            flush(&mut current, &mut words);
+        } else {
+            for t in deunicode::deunicode_char(c).unwrap_or("").chars() {
+                if t.is_ascii_alphanumeric() {
+                    current.push(t);
+                } else {
+                    flush(&mut current, &mut words);
+                }
+            }
+        }
+    }
+    flush(&mut current, &mut words);
+    words.retain(|w| !w.is_empty());
+    words
+}
+
+fn truncate_keep_extension(name: &str, max_bytes: usize) -> String {
+    let (stem, extension) = match name.rfind('.') {
+        Some(dot) if dot > 0 && name.len() - dot <= 16 => name.split_at(dot),
+        _ => (name, ""),
+    };
+    let budget = max_bytes.saturating_sub(extension.len());
+    let mut end = budget.min(stem.len());
+    while !stem.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}{}", &stem[..end], extension)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slug(text: &str, chinese: &str, max_length: Option<usize>) -> SlugResult {
+        generate_slug(SlugRequest {
+            text: text.to_string(),
+            separator: None,
+            max_length,
+            case: None,
+            chinese: Some(chinese.to_string()),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_generate_slug() {
+        assert_eq!(
+            slug("Hello, World! Don't Panic", "pinyin", None).slug,
+            "hello-world-dont-panic"
+        );
+        assert_eq!(
+            slug("Crème Brûlée & Straße", "pinyin", None).slug,
+            "creme-brulee-strasse"
+        );
+        assert_eq!(
+            slug("Rust 中文教程", "pinyin", None).slug,
+            "rust-zhong-wen-jiao-cheng"
+        );
+        assert_eq!(slug("Rust 中文教程", "initials", None).slug, "rust-zwjc");
+        assert_eq!(slug("Rust 中文教程", "keep", None).slug, "rust-中文教程");
+
+        let result = slug("one two three four", "pinyin", Some(12));
+        assert_eq!(result.slug, "one-two");
+        assert!(result.truncated);
+        assert_eq!(
+            slug("supercalifragilistic", "pinyin", Some(5)).slug,
+            "super"
+        );
+
+        let upper = generate_slug(SlugRequest {
+            text: "api v2 docs".to_string(),
+            separator: Some("_".to_string()),
+            max_length: None,
+            case: Some("upper".to_string()),
+            chinese: None,
+        })
+        .unwrap();
+        assert_eq!(upper.slug, "API_V2_DOCS");
+        assert!(generate_slug(SlugRequest {
+            text: "a".to_string(),
+            separator: Some("/".to_string()),
+            max_length: None,
+            case: None,
+            chinese: None,
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_sanitize_filename() {
+        let sanitize = |name: &str, ascii_only: bool| {
+            sanitize_filename(SanitizeFilenameRequest {
+                name: name.to_string(),
+                replacement: None,
+                ascii_only: Some(ascii_only),
+            })
+            .unwrap()
+        };
+        let result = sanitize("report: Q1/Q2?.pdf", false);
+        assert_eq!(result.filename, "report_ Q1_Q2_.pdf");
+        assert_eq!(result.changes.len(), 1);
+
+        assert!(sanitize("notes.txt", false).changes.is_empty());
+        assert_eq!(sanitize("con.txt", false).filename, "_con.txt");
+        assert_eq!(sanitize("draft. . ", false).filename, "draft");
+        assert_eq!(sanitize("..", false).filename, "_file");
+        assert_eq!(
+            sanitize("简历 2024.docx", true).filename,
+            "JianLi 2024.docx"
+        );
+
+        let long = format!("{}.tar.gz", "文".repeat(100));
+        let truncated = sanitize(&long, false).filename;
+        assert!(truncated.len() <= MAX_FILENAME_BYTES);
+        assert!(truncated.ends_with(".gz"));
+    }
+}
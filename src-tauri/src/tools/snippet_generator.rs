@@ -0,0 +1,597 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tera::{Context, Tera};
+
+const PACK_VERSION: u32 = 1;
+
+/// 串行化模板文件的读写
+static SNIPPETS_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnippetVariable {
+    pub name: String,
+    #[serde(default)]
+    pub label: String,
+    pub default: Option<String>,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(default)]
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnippetTemplate {
+    #[serde(default)]
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub category: String, // docker, ci, build, service ...
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub file_name: String, // 输出文件名，同样支持模板变量
+    pub content: String, // Tera 模板
+    #[serde(default)]
+    pub variables: Vec<SnippetVariable>,
+    #[serde(default)]
+    pub builtin: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RenderSnippetRequest {
+    pub template_id: Option<String>,
+    pub content: Option<String>, // 未指定 template_id 时直接渲染该模板
+    #[serde(default)]
+    pub variables: HashMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RenderSnippetResponse {
+    pub content: String,
+    pub file_name: String,
+}
+
+/// 模板包，用于导入导出
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnippetPack {
+    pub version: u32,
+    pub exported_at: String,
+    pub templates: Vec<SnippetTemplate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnippetImportResult {
+    pub imported: Vec<String>,
+    pub skipped: Vec<String>, // 已存在且未选择覆盖的模板名称
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct SnippetStore {
+    #[serde(default)]
+    templates: Vec<SnippetTemplate>,
+}
+
+/// 获取全部模板，内置模板在前
+#[tauri::command]
+pub async fn list_snippet_templates() -> Result<Vec<SnippetTemplate>, String> {
+    let _guard = SNIPPETS_LOCK.lock().unwrap();
+    let mut templates = builtin_templates();
+    templates.extend(load_store(&get_store_path()).templates);
+    Ok(templates)
+}
+
+/// 新增或更新自定义模板（按 id 匹配，id 为空时新增）
+#[tauri::command]
+pub async fn save_snippet_template(template: SnippetTemplate) -> Result<SnippetTemplate, String> {
+    let mut template = normalize_template(template)?;
+    if builtin_templates().iter().any(|t| t.id == template.id) {
+        return Err("内置模板不可修改".to_string());
+    }
+    if template.id.is_empty() {
+        template.id = new_id();
+    }
+
+    let path = get_store_path();
+    let _guard = SNIPPETS_LOCK.lock().unwrap();
+    let mut store = load_store(&path);
+    match store.templates.iter_mut().find(|t| t.id == template.id) {
+        Some(existing) => *existing = template.clone(),
+        None => store.templates.push(template.clone()),
+    }
+    save_store(&path, &store)?;
+    Ok(template)
+}
+
+/// 删除自定义模板
+#[tauri::command]
+pub async fn delete_snippet_template(id: String) -> Result<(), String> {
+    let path = get_store_path();
+    let _guard = SNIPPETS_LOCK.lock().unwrap();
+    let mut store = load_store(&path);
+    let before = store.templates.len();
+    store.templates.retain(|t| t.id != id);
+    if store.templates.len() == before {
+        return Err("模板不存在".to_string());
+    }
+    save_store(&path, &store)
+}
+
+/// 使用变量渲染模板，未提供的变量取默认值
+#[tauri::command]
+pub async fn render_snippet(
+    request: RenderSnippetRequest,
+) -> Result<RenderSnippetResponse, String> {
+    let template = match request.template_id.as_deref().filter(|id| !id.is_empty()) {
+        Some(id) => find_template(id)?,
+        None => SnippetTemplate {
+            id: String::new(),
+            name: String::new(),
+            category: String::new(),
+            description: String::new(),
+            file_name: String::new(),
+            content: request
+                .content
+                .clone()
+                .filter(|c| !c.is_empty())
+                .ok_or_else(|| "请选择模板或输入模板内容".to_string())?,
+            variables: Vec::new(),
+            builtin: false,
+        },
+    };
+    render_template(&template, &request.variables)
+}
+
+/// 导出模板包，ids 为空时导出全部自定义模板
+#[tauri::command]
+pub async fn export_snippet_pack(path: String, ids: Option<Vec<String>>) -> Result<usize, String> {
+    let templates = {
+        let _guard = SNIPPETS_LOCK.lock().unwrap();
+        let mut all = builtin_templates();
+        all.extend(load_store(&get_store_path()).templates);
+        match ids.filter(|ids| !ids.is_empty()) {
+            Some(ids) => all.into_iter().filter(|t| ids.contains(&t.id)).collect(),
+            None => all.into_iter().filter(|t| !t.builtin).collect::<Vec<_>>(),
+        }
+    };
+    if templates.is_empty() {
+        return Err("没有可导出的模板".to_string());
+    }
+
+    let pack = SnippetPack {
+        version: PACK_VERSION,
+        exported_at: Utc::now().to_rfc3339(),
+        templates,
+    };
+    let content =
+        serde_json::to_string_pretty(&pack).map_err(|e| format!("序列化模板包失败: {}", e))?;
+    fs::write(&path, content).map_err(|e| format!("写入模板包失败: {}", e))?;
+    Ok(pack.templates.len())
+}
+
+/// 导入模板包，名称相同的模板按 overwrite 决定覆盖或跳过
+#[tauri::command]
+pub async fn import_snippet_pack(
+    path: String,
+    overwrite: bool,
+) -> Result<SnippetImportResult, String> {
+    let content = fs::read_to_string(&path).map_err(|e| format!("读取模板包失败: {}", e))?;
+    let pack: SnippetPack =
+        serde_json::from_str(&content).map_err(|e| format!("模板包格式无效: {}", e))?;
+    if pack.version > PACK_VERSION {
+        return Err(format!("不支持的模板包版本: {}", pack.version));
+    }
+
+    let store_path = get_store_path();
+    let _guard = SNIPPETS_LOCK.lock().unwrap();
+    let mut store = load_store(&store_path);
+    let mut result = SnippetImportResult {
+        imported: Vec::new(),
+        skipped: Vec::new(),
+    };
+    for template in pack.templates {
+        let mut template = normalize_template(template)?;
+        match store.templates.iter_mut().find(|t| t.name == template.name) {
+            Some(existing) if overwrite => {
+                template.id = existing.id.clone();
+                *existing = template.clone();
+            }
+            Some(_) => {
+                result.skipped.push(template.name);
+                continue;
+            }
+            None => {
+                template.id = new_id();
+                store.templates.push(template.clone());
+            }
+        }
+        result.imported.push(template.name);
+    }
+    save_store(&store_path, &store)?;
+    Ok(result)
+}
+
+fn find_template(id: &str) -> Result<SnippetTemplate, String> {
+    if let Some(template) = builtin_templates().into_iter().find(|t| t.id == id) {
+        return Ok(template);
+    }
+    let _guard = SNIPPETS_LOCK.lock().unwrap();
+    load_store(&get_store_path())
+        .templates
+        .into_iter()
+        .find(|t| t.id == id)
+        .ok_or_else(|| "模板不存在".to_string())
+}
+
+fn render_template(
+    template: &SnippetTemplate,
+    values: &HashMap<String, Value>,
+) -> Result<RenderSnippetResponse, String> {
+    let mut data = Map::new();
+    let mut missing = Vec::new();
+    for variable in &template.variables {
+        let value = values
+            .get(&variable.name)
+            .filter(|v| !is_blank(v))
+            .cloned()
+            .or_else(|| variable.default.clone().map(Value::String));
+        match value {
+            Some(value) => {
+                data.insert(variable.name.clone(), value);
+            }
+            None if variable.required => missing.push(variable.name.clone()),
+            None => {
+                data.insert(variable.name.clone(), Value::String(String::new()));
+            }
+        }
+    }
+    if !missing.is_empty() {
+        return Err(format!("缺少必填变量: {}", missing.join(", ")));
+    }
+    // 模板中未声明的变量也允许直接传入
+    for (name, value) in values {
+        data.entry(name.clone()).or_insert_with(|| value.clone());
+    }
+
+    let context =
+        Context::from_value(Value::Object(data)).map_err(|e| format!("变量无效: {}", e))?;
+    let render = |source: &str| {
+        Tera::one_off(source, &context, false)
+            .map_err(|e| format!("模板渲染失败: {}", describe(&e)))
+    };
+    Ok(RenderSnippetResponse {
+        content: render(&template.content)?,
+        file_name: if template.file_name.is_empty() {
+            String::new()
+        } else {
+            render(&template.file_name)?
+        },
+    })
+}
+
+fn normalize_template(template: SnippetTemplate) -> Result<SnippetTemplate, String> {
+    let mut template = template;
+    template.name = template.name.trim().to_string();
+    if template.name.is_empty() {
+        return Err("模板名称不能为空".to_string());
+    }
+    if template.content.trim().is_empty() {
+        return Err("模板内容不能为空".to_string());
+    }
+    for variable in &template.variables {
+        let valid = variable
+            .name
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && variable
+                .name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !valid {
+            return Err(format!("变量名无效: {}", variable.name));
+        }
+    }
+    // 保存前检查模板语法
+    let mut tera = Tera::default();
+    tera.add_raw_template("content", &template.content)
+        .map_err(|e| format!("模板语法错误: {}", describe(&e)))?;
+    template.builtin = false;
+    Ok(template)
+}
+
+/// Tera 的错误信息分散在 source 链上，拼接后才能看到具体原因
+fn describe(error: &tera::Error) -> String {
+    let mut message = error.to_string();
+    let mut source = std::error::Error::source(error);
+    while let Some(inner) = source {
+        message.push_str(": ");
+        message.push_str(&inner.to_string());
+        source = inner.source();
+    }
+    message
+}
+
+fn is_blank(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(s) => s.trim().is_empty(),
+        _ => false,
+    }
+}
+
+fn new_id() -> String {
+    format!(
+        "snippet-{:x}",
+        Utc::now().timestamp_nanos_opt().unwrap_or_default()
+    )
+}
+
+fn builtin_templates() -> Vec<SnippetTemplate> {
+    let var = |name: &str, label: &str, default: Option<&str>, required: bool| SnippetVariable {
+        name: name.to_string(),
+        label: label.to_string(),
+        default: default.map(|d| d.to_string()),
+        required,
+        description: String::new(),
+    };
+    let template = |id: &str,
+                    name: &str,
+                    category: &str,
+                    description: &str,
+                    file_name: &str,
+                    content: &str,
+                    variables: Vec<SnippetVariable>| SnippetTemplate {
+        id: id.to_string(),
+        name: name.to_string(),
+        category: category.to_string(),
+        description: description.to_string(),
+        file_name: file_name.to_string(),
+        content: content.to_string(),
+        variables,
+        builtin: true,
+    };
+
+    vec![
+        template(
+            "builtin-go-dockerfile",
+            "Go 服务 Dockerfile",
+            "docker",
+            "多阶段构建，运行镜像使用 distroless",
+            "Dockerfile",
+            r#"FROM golang:{{ go_version }}-alpine AS builder
+WORKDIR /src
+COPY go.mod go.sum ./
+RUN go mod download
+COPY . .
+RUN CGO_ENABLED=0 go build -trimpath -ldflags="-s -w" -o /out/{{ binary }} {{ main_package }}
+
+FROM gcr.io/distroless/static-debian12:nonroot
+COPY --from=builder /out/{{ binary }} /usr/local/bin/{{ binary }}
+EXPOSE {{ port }}
+USER nonroot:nonroot
+ENTRYPOINT ["/usr/local/bin/{{ binary }}"]
+"#,
+            vec![
+                var("binary", "可执行文件名", None, true),
+                var("go_version", "Go 版本", Some("1.23"), false),
+                var("main_package", "main 包路径", Some("./cmd/server"), false),
+                var("port", "端口", Some("8080"), false),
+            ],
+        ),
+        template(
+            "builtin-github-actions-go",
+            "GitHub Actions Go CI",
+            "ci",
+            "推送和 PR 时执行 vet、test、build",
+            ".github/workflows/{{ workflow }}.yml",
+            r#"name: {{ workflow }}
+
+on:
+  push:
+    branches: [{{ branch }}]
+  pull_request:
+
+jobs:
+  test:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v4
+      - uses: actions/setup-go@v5
+        with:
+          go-version: "{{ go_version }}"
+          cache: true
+      - run: go vet ./...
+      - run: go test -race ./...
+      - run: go build ./...
+{%- if docker_image %}
+      - uses: docker/build-push-action@v6
+        with:
+          push: {% raw %}${{ github.event_name == 'push' }}{% endraw %}
+          tags: {{ docker_image }}:{% raw %}${{ github.sha }}{% endraw %}
+{%- endif %}
+"#,
+            vec![
+                var("workflow", "工作流名称", Some("ci"), false),
+                var("branch", "分支", Some("main"), false),
+                var("go_version", "Go 版本", Some("1.23"), false),
+                var("docker_image", "Docker 镜像（留空则不构建）", None, false),
+            ],
+        ),
+        template(
+            "builtin-go-makefile",
+            "Go 项目 Makefile",
+            "build",
+            "build、test、lint、clean 常用目标",
+            "Makefile",
+            r#"BINARY := {{ binary }}
+VERSION ?= $(shell git describe --tags --always --dirty)
+LDFLAGS := -s -w -X main.version=$(VERSION)
+
+.PHONY: build test lint clean
+
+build:
+	go build -trimpath -ldflags "$(LDFLAGS)" -o bin/$(BINARY) {{ main_package }}
+
+test:
+	go test -race -cover ./...
+
+lint:
+	golangci-lint run
+
+clean:
+	rm -rf bin/
+"#,
+            vec![
+                var("binary", "可执行文件名", None, true),
+                var("main_package", "main 包路径", Some("./cmd/server"), false),
+            ],
+        ),
+        template(
+            "builtin-systemd-service",
+            "systemd 服务单元",
+            "service",
+            "以非 root 用户运行并在失败时自动重启",
+            "{{ name }}.service",
+            r#"[Unit]
+Description={% if description %}{{ description }}{% else %}{{ name }}{% endif %}
+After=network-online.target
+Wants=network-online.target
+
+[Service]
+Type=simple
+User={{ user }}
+Group={{ user }}
+WorkingDirectory={{ working_dir }}
+ExecStart={{ exec_start }}
+Restart=on-failure
+RestartSec=5
+{%- if env_file %}
+EnvironmentFile={{ env_file }}
+{%- endif %}
+NoNewPrivileges=true
+ProtectSystem=full
+
+[Install]
+WantedBy=multi-user.target
+"#,
+            vec![
+                var("name", "服务名", None, true),
+                var("exec_start", "启动命令", None, true),
+                var("description", "描述", None, false),
+                var("user", "运行用户", Some("www-data"), false),
+                var("working_dir", "工作目录", Some("/opt/app"), false),
+                var("env_file", "环境变量文件", None, false),
+            ],
+        ),
+    ]
+}
+
+fn get_store_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("devtools")
+        .join("snippets.json")
+}
+
+fn load_store(path: &Path) -> SnippetStore {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_store(path: &Path, store: &SnippetStore) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("创建配置目录失败: {}", e))?;
+    }
+    let content =
+        serde_json::to_string_pretty(store).map_err(|e| format!("序列化模板失败: {}", e))?;
+    fs::write(path, content).map_err(|e| format!("保存模板失败: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(pairs: &[(&str, &str)]) -> HashMap<String, Value> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), Value::String(v.to_string())))
+            .collect()
+    }
+
+    #[test]
+    fn test_render_builtin_templates() {
+        let templates = builtin_templates();
+        let workflow = templates
+            .iter()
+            .find(|t| t.id == "builtin-github-actions-go")
+            .unwrap();
+        let rendered =
+            render_template(workflow, &values(&[("docker_image", "ghcr.io/acme/api")])).unwrap();
+        assert_eq!(rendered.file_name, ".github/workflows/ci.yml");
+        assert!(rendered.content.contains("branches: [main]"));
+        assert!(rendered
+            .content
+            .contains("tags: ghcr.io/acme/api:${{ github.sha }}"));
+
+        let without_docker = render_template(workflow, &HashMap::new()).unwrap();
+        assert!(!without_docker.content.contains("docker/build-push-action"));
+
+        let service = templates
+            .iter()
+            .find(|t| t.id == "builtin-systemd-service")
+            .unwrap();
+        assert!(render_template(service, &values(&[("name", "api")]))
+            .unwrap_err()
+            .contains("exec_start"));
+        let rendered = render_template(
+            service,
+            &values(&[("name", "api"), ("exec_start", "/opt/app/api")]),
+        )
+        .unwrap();
+        assert_eq!(rendered.file_name, "api.service");
+        assert!(rendered.content.contains("Description=api\n"));
+        assert!(!rendered.content.contains("EnvironmentFile"));
+
+        for template in templates {
+            assert!(normalize_template(template).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_normalize_template() {
+        let template = SnippetTemplate {
+            id: String::new(),
+            name: "  nginx ".to_string(),
+            category: "web".to_string(),
+            description: String::new(),
+            file_name: String::new(),
+            content: "server_name {{ domain }};".to_string(),
+            variables: vec![SnippetVariable {
+                name: "domain".to_string(),
+                label: String::new(),
+                default: None,
+                required: true,
+                description: String::new(),
+            }],
+            builtin: true,
+        };
+        let normalized = normalize_template(template.clone()).unwrap();
+        assert_eq!(normalized.name, "nginx");
+        assert!(!normalized.builtin);
+
+        let mut broken = template.clone();
+        broken.content = "{% if %}".to_string();
+        assert!(normalize_template(broken).is_err());
+
+        let mut bad_name = template;
+        bad_name.variables[0].name = "server-name".to_string();
+        assert!(normalize_template(bad_name).is_err());
+    }
+}
@@ -0,0 +1,364 @@
+use crate::tools::ssl_checker::EndpointRecordingVerifier;
+use base64::{engine::general_purpose, Engine as _};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_rustls::rustls::pki_types::ServerName;
+use tokio_rustls::{rustls, TlsConnector};
+use x509_parser::pem::Pem;
+use x509_parser::prelude::*;
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Deserialize)]
+pub struct SpkiPinRequest {
+    pub host: Option<String>,        // 从服务器获取证书链
+    pub port: Option<u16>,           // 默认 443
+    pub pem_content: Option<String>, // 或直接提供 PEM 证书（可包含多张）
+    pub file_path: Option<String>,   // 或读取 PEM/DER 证书文件
+    pub domain: Option<String>,      // 配置片段中使用的域名，默认取主机名或证书 CN
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SpkiPin {
+    pub index: usize,
+    pub role: String, // leaf, intermediate, root
+    pub subject: String,
+    pub issuer: String,
+    pub not_after: String,
+    pub pin_sha256: String, // SPKI SHA-256 的 Base64 编码
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PinSnippets {
+    pub okhttp: String,
+    pub android_nsc: String,
+    pub ios_ats: String,
+    pub nginx_hpkp: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SpkiPinResult {
+    pub source: String,
+    pub domain: String,
+    pub pins: Vec<SpkiPin>,
+    pub snippets: PinSnippets,
+    pub warnings: Vec<String>,
+}
+
+/// 计算证书链中各证书的 SPKI SHA-256 指纹，并生成常见平台的证书锁定配置
+#[tauri::command]
+pub async fn calculate_spki_pins(request: SpkiPinRequest) -> Result<SpkiPinResult, String> {
+    let host = request
+        .host
+        .as_deref()
+        .map(str::trim)
+        .filter(|h| !h.is_empty());
+    let port = request.port.unwrap_or(443);
+
+    let (source, ders) = if let Some(host) = host {
+        (
+            format!("{}:{}", host, port),
+            fetch_certificate_chain(host, port).await?,
+        )
+    } else if let Some(content) = request
+        .pem_content
+        .as_deref()
+        .filter(|c| !c.trim().is_empty())
+    {
+        (
+            "PEM".to_string(),
+            parse_certificate_bytes(content.as_bytes())?,
+        )
+    } else if let Some(path) = request
+        .file_path
+        .as_deref()
+        .filter(|p| !p.trim().is_empty())
+    {
+        let data = std::fs::read(path).map_err(|e| format!("读取证书文件失败: {}", e))?;
+        (path.to_string(), parse_certificate_bytes(&data)?)
+    } else {
+        return Err("请提供主机名、PEM 内容或证书文件".to_string());
+    };
+
+    let mut pins = Vec::new();
+    let mut leaf_names = Vec::new();
+    for (index, der) in ders.iter().enumerate() {
+        let (_, cert) = X509Certificate::from_der(der)
+            .map_err(|e| format!("第{}个证书解析失败: {}", index + 1, e))?;
+        let role = if cert.subject() == cert.issuer() {
+            "root"
+        } else if cert.is_ca() {
+            "intermediate"
+        } else {
+            "leaf"
+        };
+        if role == "leaf" && leaf_names.is_empty() {
+            leaf_names = certificate_names(&cert);
+        }
+        pins.push(SpkiPin {
+            index,
+            role: role.to_string(),
+            subject: cert.subject().to_string(),
+            issuer: cert.issuer().to_string(),
+            not_after: cert.validity().not_after.to_datetime().date().to_string(),
+            pin_sha256: spki_pin(cert.public_key().raw),
+        });
+    }
+
+    let domain = request
+        .domain
+        .map(|d| d.trim().to_string())
+        .filter(|d| !d.is_empty())
+        .or_else(|| host.map(|h| h.to_string()))
+        .or_else(|| leaf_names.into_iter().next())
+        .unwrap_or_else(|| "example.com".to_string())
+        .trim_start_matches("*.")
+        .to_string();
+
+    let mut warnings = Vec::new();
+    if !pins.iter().any(|p| p.role != "leaf") {
+        warnings.push("仅有叶子证书，证书续期后锁定将失效，建议同时锁定中间 CA".to_string());
+    }
+    if pins.len() < 2 {
+        warnings.push("至少需要两个锁定值（含备份），否则证书更换时应用将无法连接".to_string());
+    }
+    if host.is_some() && !pins.iter().any(|p| p.role == "root") {
+        warnings.push("服务器未发送根证书，如需锁定根 CA 请另行导入".to_string());
+    }
+
+    let snippets = build_snippets(&domain, &pins);
+    Ok(SpkiPinResult {
+        source,
+        domain,
+        pins,
+        snippets,
+        warnings,
+    })
+}
+
+fn spki_pin(spki_der: &[u8]) -> String {
+    general_purpose::STANDARD.encode(Sha256::digest(spki_der))
+}
+
+fn certificate_names(cert: &X509Certificate) -> Vec<String> {
+    let mut names: Vec<String> = cert
+        .subject()
+        .iter_common_name()
+        .filter_map(|cn| cn.as_str().ok().map(|s| s.to_string()))
+        .collect();
+    if let Ok(Some(san)) = cert.subject_alternative_name() {
+        for name in &san.value.general_names {
+            if let GeneralName::DNSName(dns) = name {
+                names.push(dns.to_string());
+            }
+        }
+    }
+    names
+}
+
+/// 解析 PEM（可包含多张证书）或 DER 格式的证书
+pub(crate) fn parse_certificate_bytes(data: &[u8]) -> Result<Vec<Vec<u8>>, String> {
+    if data.windows(10).any(|w| w == b"-----BEGIN") {
+        let mut ders = Vec::new();
+        for pem in Pem::iter_from_buffer(data) {
+            let pem = pem.map_err(|e| format!("PEM解析失败: {}", e))?;
+            if pem.label == "CERTIFICATE" {
+                ders.push(pem.contents);
+            }
+        }
+        if ders.is_empty() {
+            return Err("未找到有效的证书".to_string());
+        }
+        Ok(ders)
+    } else {
+        X509Certificate::from_der(data).map_err(|e| format!("无效的证书格式: {}", e))?;
+        Ok(vec![data.to_vec()])
+    }
+}
+
+/// 获取服务器发送的证书链（不校验证书有效性）
+pub(crate) async fn fetch_certificate_chain(host: &str, port: u16) -> Result<Vec<Vec<u8>>, String> {
+    let _ = rustls::crypto::ring::default_provider().install_default();
+    let verifier = Arc::new(EndpointRecordingVerifier::with_webpki_roots()?);
+    let config = rustls::ClientConfig::builder()
+        .dangerous()
+        .with_custom_certificate_verifier(verifier)
+        .with_no_client_auth();
+    let connector = TlsConnector::from(Arc::new(config));
+    let server_name =
+        ServerName::try_from(host.to_string()).map_err(|e| format!("无效的主机名: {}", e))?;
+
+    let connect = async {
+        let stream = tokio::net::TcpStream::connect((host, port))
+            .await
+            .map_err(|e| format!("TCP 连接失败: {}", e))?;
+        connector
+            .connect(server_name, stream)
+            .await
+            .map_err(|e| format!("TLS 握手失败: {}", e))
+    };
+    let tls = tokio::time::timeout(CONNECT_TIMEOUT, connect)
+        .await
+        .map_err(|_| "连接超时".to_string())??;
+
+    let chain: Vec<Vec<u8>> = tls
+        .get_ref()
+        .1
+        .peer_certificates()
+        .map(|certs| certs.iter().map(|c| c.as_ref().to_vec()).collect())
+        .unwrap_or_default();
+    if chain.is_empty() {
+        return Err("服务器未返回证书".to_string());
+    }
+    Ok(chain)
+}
+
+fn build_snippets(domain: &str, pins: &[SpkiPin]) -> PinSnippets {
+    // 锁定配置的过期时间取所有证书中最早的到期日
+    let expiration = pins
+        .iter()
+        .map(|p| p.not_after.as_str())
+        .min()
+        .unwrap_or_default();
+
+    let okhttp = format!(
+        "val certificatePinner = CertificatePinner.Builder()\n{}    .build()",
+        pins.iter()
+            .map(|p| format!(
+                "    .add(\"{}\", \"sha256/{}\") // {}\n",
+                domain, p.pin_sha256, p.role
+            ))
+            .collect::<String>()
+    );
+
+    let android_nsc = format!(
+        r#"<?xml version="1.0" encoding="utf-8"?>
+<network-security-config>
+    <domain-config>
+        <domain includeSubdomains="true">{}</domain>
+        <pin-set expiration="{}">
+{}        </pin-set>
+    </domain-config>
+</network-security-config>"#,
+        domain,
+        expiration,
+        pins.iter()
+            .map(|p| format!(
+                "            <pin digest=\"SHA-256\">{}</pin> <!-- {} -->\n",
+                p.pin_sha256, p.role
+            ))
+            .collect::<String>()
+    );
+
+    let identities = |leaf: bool| {
+        pins.iter()
+            .filter(|p| (p.role == "leaf") == leaf)
+            .map(|p| {
+                format!(
+                    "                <dict>\n                    <key>SPKI-SHA256-BASE64</key>\n                    <string>{}</string>\n                </dict>\n",
+                    p.pin_sha256
+                )
+            })
+            .collect::<String>()
+    };
+    let mut ats_keys = String::new();
+    for (key, leaf) in [
+        ("NSPinnedCAIdentities", false),
+        ("NSPinnedLeafIdentities", true),
+    ] {
+        let items = identities(leaf);
+        if !items.is_empty() {
+            ats_keys.push_str(&format!(
+                "            <key>{}</key>\n            <array>\n{}            </array>\n",
+                key, items
+            ));
+        }
+    }
+    let ios_ats = format!(
+        r#"<key>NSAppTransportSecurity</key>
+<dict>
+    <key>NSPinnedDomains</key>
+    <dict>
+        <key>{}</key>
+        <dict>
+            <key>NSIncludesSubdomains</key>
+            <true/>
+{}        </dict>
+    </dict>
+</dict>"#,
+        domain, ats_keys
+    );
+
+    let nginx_hpkp = format!(
+        "# HPKP 已被主流浏览器废弃，仅用于兼容旧客户端\nadd_header Public-Key-Pins '{}; max-age=5184000; includeSubDomains' always;",
+        pins.iter()
+            .map(|p| format!("pin-sha256=\"{}\"", p.pin_sha256))
+            .collect::<Vec<_>>()
+            .join("; ")
+    );
+
+    PinSnippets {
+        okhttp,
+        android_nsc,
+        ios_ats,
+        nginx_hpkp,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spki_pin_of_generated_certificate() {
+        let (cert_pem, key_pem) = crate::tools::key_generator::generate_self_signed_certificate(
+            "pin.test",
+            &["pin.test".to_string()],
+            30,
+        )
+        .unwrap();
+        let ders = parse_certificate_bytes(cert_pem.as_bytes()).unwrap();
+        let (_, cert) = X509Certificate::from_der(&ders[0]).unwrap();
+
+        // 与 openssl pkey -pubout -outform der | sha256 | base64 的结果一致
+        let key = openssl::pkey::PKey::private_key_from_pem(key_pem.as_bytes()).unwrap();
+        let expected = spki_pin(&key.public_key_to_der().unwrap());
+        assert_eq!(spki_pin(cert.public_key().raw), expected);
+        assert_eq!(expected.len(), 44);
+        assert!(certificate_names(&cert).contains(&"pin.test".to_string()));
+    }
+
+    #[test]
+    fn test_build_snippets() {
+        let pins = vec![
+            SpkiPin {
+                index: 0,
+                role: "leaf".to_string(),
+                subject: "CN=example.com".to_string(),
+                issuer: "CN=CA".to_string(),
+                not_after: "2031-01-01".to_string(),
+                pin_sha256: "LEAF=".to_string(),
+            },
+            SpkiPin {
+                index: 1,
+                role: "intermediate".to_string(),
+                subject: "CN=CA".to_string(),
+                issuer: "CN=Root".to_string(),
+                not_after: "2030-06-01".to_string(),
+                pin_sha256: "CA=".to_string(),
+            },
+        ];
+        let snippets = build_snippets("example.com", &pins);
+        assert!(snippets
+            .okhttp
+            .contains(".add(\"example.com\", \"sha256/CA=\")"));
+        assert!(snippets.android_nsc.contains("expiration=\"2030-06-01\""));
+        assert!(snippets.ios_ats.contains("NSPinnedCAIdentities"));
+        assert!(snippets.ios_ats.contains("NSPinnedLeafIdentities"));
+        assert!(snippets
+            .nginx_hpkp
+            .contains("pin-sha256=\"LEAF=\"; pin-sha256=\"CA=\""));
+    }
+}
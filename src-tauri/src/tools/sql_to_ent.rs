@@ -1,8 +1,8 @@
 use crate::utils::code_formatter::CodeFormatter;
 use serde::{Deserialize, Serialize};
 use sqlparser::ast::{
-    ColumnDef, ColumnOption, ColumnOptionDef, CreateTable, DataType, ExactNumberInfo, Expr,
-    ObjectName, Statement, TableConstraint, Value,
+    ColumnDef, ColumnOption, ColumnOptionDef, CreateTable, DataType, EnumMember, ExactNumberInfo,
+    Expr, ObjectName, Statement, TableConstraint, Value,
 };
 use sqlparser::dialect::{GenericDialect, MySqlDialect, PostgreSqlDialect, SQLiteDialect};
 use sqlparser::parser::Parser;
@@ -19,6 +19,14 @@ pub struct SqlToEntOptions {
     pub enable_soft_delete: bool,
     pub enable_pluralization: bool,
     pub package_name: String,
+    #[serde(default)]
+    pub generate_enum_consts: bool, // Go consts for ENUM/SET values
+    #[serde(default = "default_set_field_type")]
+    pub set_field_type: String, // "strings" (field.Strings) or "json" (field.JSON)
+}
+
+fn default_set_field_type() -> String {
+    "strings".to_string()
 }
 
 impl Default for SqlToEntOptions {
@@ -32,6 +40,8 @@ impl Default for SqlToEntOptions {
             enable_soft_delete: false,
             enable_pluralization: true,
             package_name: "schema".to_string(),
+            generate_enum_consts: false,
+            set_field_type: default_set_field_type(),
         }
     }
 }
@@ -46,6 +56,7 @@ pub struct ColumnDefinition {
     pub is_unique: bool,
     pub default_value: Option<String>,
     pub references: Option<ForeignKeyReference>,
+    pub enum_values: Vec<String>, // values of ENUM and SET columns
 }
 
 #[derive(Debug, Clone)]
@@ -184,6 +195,7 @@ impl SqlToEntParser {
                         .any(|uc| uc.contains(&column_name)),
                 default_value: Self::extract_default_value_from_options(&column_def.options),
                 references: None,
+                enum_values: Self::extract_enum_values(&column_def.data_type),
             };
 
             // Set foreign key reference if exists
@@ -318,10 +330,27 @@ impl SqlToEntParser {
             DataType::JSON => "JSON".to_string(),
             DataType::Uuid => "UUID".to_string(),
             DataType::Array(_) => "ARRAY".to_string(),
+            DataType::Enum(_, _) => "ENUM".to_string(),
+            DataType::Set(_) => "SET".to_string(),
             _ => "TEXT".to_string(), // Default fallback
         }
     }
 
+    /// Extract the allowed values of ENUM and SET columns
+    fn extract_enum_values(data_type: &DataType) -> Vec<String> {
+        match data_type {
+            DataType::Enum(members, _) => members
+                .iter()
+                .map(|member| match member {
+                    EnumMember::Name(name) => name.clone(),
+                    EnumMember::NamedValue(name, _) => name.clone(),
+                })
+                .collect(),
+            DataType::Set(values) => values.clone(),
+            _ => Vec::new(),
+        }
+    }
+
     /// Check if column has NOT NULL constraint
     fn has_not_null_constraint(options: &[ColumnOptionDef]) -> bool {
         options
@@ -499,7 +528,7 @@ impl EntSchemaGenerator {
             } else if column.name == "id" && column.is_primary_key && options.use_uuid_primary_key {
                 schema.push_str("\t\tfield.UUID(\"id\", uuid.UUID{}).Default(uuid.New),\n");
             } else {
-                schema.push_str(&Self::generate_field_definition(column));
+                schema.push_str(&Self::generate_field_definition(column, options));
             }
         }
 
@@ -551,16 +580,76 @@ impl EntSchemaGenerator {
             schema.push_str("}\n");
         }
 
+        // Enum value consts
+        if options.generate_enum_consts {
+            for column in table.columns.iter().filter(|c| !c.enum_values.is_empty()) {
+                schema.push_str(&Self::generate_enum_consts(&class_name, column));
+            }
+        }
+
         Ok(CodeFormatter::format_go_code(&schema))
     }
 
-    /// Generate field definition for a column
-    fn generate_field_definition(column: &ColumnDefinition) -> String {
-        let mut field = format!(
-            "\t\tfield.{}",
-            Self::sql_type_to_ent_field(&column.sql_type)
+    /// Generate a const block holding the values of an ENUM/SET column
+    fn generate_enum_consts(class_name: &str, column: &ColumnDefinition) -> String {
+        let prefix = format!("{}{}", class_name, Self::to_pascal_case(&column.name));
+        let const_names: Vec<String> = column
+            .enum_values
+            .iter()
+            .map(|value| format!("{}{}", prefix, Self::enum_value_name(value)))
+            .collect();
+        let max_len = const_names.iter().map(|n| n.len()).max().unwrap_or(0);
+
+        let mut consts = format!(
+            "\n// {} values of the {}.\nconst (\n",
+            Self::to_pascal_case(&column.name),
+            class_name
         );
-        field.push_str(&format!("(\"{}\")", column.name));
+        for (name, value) in const_names.iter().zip(&column.enum_values) {
+            consts.push_str(&format!(
+                "\t{:width$} = {}\n",
+                name,
+                Self::go_string(value),
+                width = max_len
+            ));
+        }
+        consts.push_str(")\n");
+        consts
+    }
+
+    /// Generate field definition for a column
+    fn generate_field_definition(column: &ColumnDefinition, options: &SqlToEntOptions) -> String {
+        let base_type = column.sql_type.split('(').next().unwrap().to_uppercase();
+        let mut field = if base_type == "SET" && options.set_field_type == "json" {
+            format!("\t\tfield.JSON(\"{}\", []string{{}})", column.name)
+        } else if base_type == "SET" {
+            format!("\t\tfield.Strings(\"{}\")", column.name)
+        } else {
+            format!(
+                "\t\tfield.{}(\"{}\")",
+                Self::sql_type_to_ent_field(&column.sql_type),
+                column.name
+            )
+        };
+
+        // Enum values; NamedValues is needed when a value is not a valid Go identifier
+        if base_type == "ENUM" && !column.enum_values.is_empty() {
+            if column.enum_values.iter().all(|v| Self::is_identifier(v)) {
+                let values: Vec<String> = column
+                    .enum_values
+                    .iter()
+                    .map(|v| Self::go_string(v))
+                    .collect();
+                field.push_str(&format!(".Values({})", values.join(", ")));
+            } else {
+                let values: Vec<String> = column
+                    .enum_values
+                    .iter()
+                    .map(|v| format!("\"{}\", {}", Self::enum_value_name(v), Self::go_string(v)))
+                    .collect();
+                field.push_str(&format!(".NamedValues({})", values.join(", ")));
+            }
+        }
 
         // Add constraints
         if column.nullable {
@@ -573,8 +662,6 @@ impl EntSchemaGenerator {
 
         // Add default values
         if let Some(ref default_val) = column.default_value {
-            let base_type = column.sql_type.split('(').next().unwrap().to_uppercase();
-
             match base_type.as_str() {
                 "BOOLEAN" | "BOOL" => {
                     if default_val.to_uppercase() == "TRUE" || default_val == "1" {
@@ -600,6 +687,14 @@ impl EntSchemaGenerator {
                         field.push_str(&format!(".Default(\"{}\")", default_val));
                     }
                 }
+                "SET" => {
+                    let values: Vec<String> = default_val
+                        .split(',')
+                        .filter(|v| !v.is_empty())
+                        .map(Self::go_string)
+                        .collect();
+                    field.push_str(&format!(".Default([]string{{{}}})", values.join(", ")));
+                }
                 _ => {
                     field.push_str(&format!(".Default(\"{}\")", default_val));
                 }
@@ -644,6 +739,8 @@ impl EntSchemaGenerator {
             "FLOAT" => "Float",
             "DOUBLE" => "Float64",
             "JSON" => "JSON",
+            "ENUM" => "Enum",
+            "SET" => "Strings",
             "BINARY" | "VARBINARY" | "BLOB" => "Bytes",
             _ => "String", // Default fallback
         }
@@ -664,6 +761,32 @@ impl EntSchemaGenerator {
         }
     }
 
+    /// Whether a value can be used as an Ent enum value directly
+    fn is_identifier(value: &str) -> bool {
+        let mut chars = value.chars();
+        matches!(chars.next(), Some(c) if c.is_ascii_alphabetic())
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+    }
+
+    /// Turn an enum value into an identifier suffix, e.g. "in-progress" -> "InProgress"
+    fn enum_value_name(value: &str) -> String {
+        let normalized: String = value
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        let name = Self::to_pascal_case(&normalized);
+        if name.is_empty() {
+            "Empty".to_string()
+        } else {
+            name
+        }
+    }
+
+    /// Quote a value as a Go string literal
+    fn go_string(value: &str) -> String {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+
     /// Check if time import is needed
     fn needs_time_import(table: &TableDefinition) -> bool {
         table.columns.iter().any(|col| {
@@ -780,3 +903,47 @@ pub async fn convert_sql_to_ent(
         Err(e) => Err(format!("SQL解析失败: {}", e)),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SQL: &str = "CREATE TABLE users (
+        id BIGINT NOT NULL PRIMARY KEY,
+        status ENUM('active', 'disabled') NOT NULL DEFAULT 'active',
+        stage ENUM('in-progress', 'done'),
+        tags SET('a', 'b') DEFAULT 'a,b'
+    )";
+
+    #[test]
+    fn test_enum_and_set_fields() {
+        let tables = SqlToEntParser::parse_sql_tables(SQL).unwrap();
+        assert_eq!(tables[0].columns[1].sql_type, "ENUM");
+        assert_eq!(tables[0].columns[3].enum_values, vec!["a", "b"]);
+
+        let options = SqlToEntOptions {
+            generate_enum_consts: true,
+            ..SqlToEntOptions::default()
+        };
+        let code = EntSchemaGenerator::generate_single_schema(&tables[0], &options).unwrap();
+        assert!(code.contains(
+            "field.Enum(\"status\").Values(\"active\", \"disabled\").Default(\"active\"),"
+        ));
+        assert!(code.contains(
+            "field.Enum(\"stage\").NamedValues(\"InProgress\", \"in-progress\", \"Done\", \"done\").Optional(),"
+        ));
+        assert!(
+            code.contains("field.Strings(\"tags\").Optional().Default([]string{\"a\", \"b\"}),")
+        );
+        assert!(code.contains("UserStageInProgress = \"in-progress\""));
+        assert!(code.contains("UserTagsA = \"a\""));
+
+        let options = SqlToEntOptions {
+            set_field_type: "json".to_string(),
+            ..SqlToEntOptions::default()
+        };
+        let code = EntSchemaGenerator::generate_single_schema(&tables[0], &options).unwrap();
+        assert!(code.contains("field.JSON(\"tags\", []string{}).Optional()"));
+        assert!(!code.contains("UserStatusActive"));
+    }
+}
@@ -1,13 +1,14 @@
+use crate::tools::system_settings::{save_config, GlobalTrayState};
 use crate::utils::code_formatter::CodeFormatter;
 use serde::{Deserialize, Serialize};
 use sqlparser::ast::{
-    ColumnDef, ColumnOption, ColumnOptionDef, CreateTable, DataType, ExactNumberInfo, ObjectName,
-    Statement, TableConstraint,
+    ColumnDef, ColumnOption, ColumnOptionDef, CreateTable, DataType, EnumMember, ExactNumberInfo,
+    ObjectName, Statement, TableConstraint,
 };
 use sqlparser::dialect::{GenericDialect, MySqlDialect, PostgreSqlDialect, SQLiteDialect};
 use sqlparser::parser::Parser;
-use std::collections::HashMap;
-use tauri::command;
+use std::collections::{BTreeSet, HashMap};
+use tauri::{command, State};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SqlToGoOptions {
@@ -16,6 +17,34 @@ pub struct SqlToGoOptions {
     pub is_go_124_or_above: bool,
     pub json_null_handling: String, // "none", "omitempty", "omitzero"
     pub selected_tags: HashMap<String, bool>, // json, gorm, db, sql, etc.
+    #[serde(default)]
+    pub mapping_profile: Option<String>, // name of a saved or builtin type mapping profile
+    #[serde(default)]
+    pub type_overrides: Vec<SqlTypeOverride>, // applied after the profile, later entries win
+    #[serde(default)]
+    pub generate_column_helpers: bool, // column name consts, Columns() and Scan/Value for enums
+}
+
+/// Maps a SQL type to a custom Go type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SqlTypeOverride {
+    pub sql_type: String, // "DECIMAL" matches any precision, "TINYINT(1)" only that exact type
+    pub go_type: String,
+    #[serde(default)]
+    pub nullable_go_type: Option<String>, // defaults to a pointer to go_type
+    #[serde(default)]
+    pub import: Option<String>, // e.g. github.com/shopspring/decimal
+}
+
+/// Named set of type overrides persisted in settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SqlTypeMappingProfile {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub overrides: Vec<SqlTypeOverride>,
+    #[serde(default)]
+    pub builtin: bool,
 }
 
 impl Default for SqlToGoOptions {
@@ -36,6 +65,9 @@ impl Default for SqlToGoOptions {
             is_go_124_or_above: true,
             json_null_handling: "omitempty".to_string(),
             selected_tags,
+            mapping_profile: None,
+            type_overrides: Vec::new(),
+            generate_column_helpers: false,
         }
     }
 }
@@ -51,6 +83,7 @@ pub struct ColumnDefinition {
     pub has_index: bool,
     pub index_names: Vec<String>,
     pub unique_index_names: Vec<String>,
+    pub enum_values: Vec<String>, // values of ENUM columns
 }
 
 #[derive(Debug, Clone)]
@@ -211,6 +244,7 @@ impl SqlParser {
                 has_index,
                 index_names,
                 unique_index_names,
+                enum_values: Self::extract_enum_values(&column_def.data_type),
             };
 
             result_columns.push(column);
@@ -307,6 +341,8 @@ impl SqlParser {
                 }
             }
             DataType::Text => "TEXT".to_string(),
+            // Keep the display width so TINYINT(1) can be mapped separately
+            DataType::TinyInt(Some(width)) => format!("TINYINT({})", width),
             DataType::TinyInt(_) => "TINYINT".to_string(),
             DataType::SmallInt(_) => "SMALLINT".to_string(),
             DataType::MediumInt(_) => "MEDIUMINT".to_string(),
@@ -337,10 +373,25 @@ impl SqlParser {
             DataType::JSON => "JSON".to_string(),
             DataType::Uuid => "UUID".to_string(),
             DataType::Array(_) => "ARRAY".to_string(),
+            DataType::Enum(_, _) => "ENUM".to_string(),
             _ => "TEXT".to_string(), // Default fallback
         }
     }
 
+    /// Extract the values of an ENUM column
+    fn extract_enum_values(data_type: &DataType) -> Vec<String> {
+        match data_type {
+            DataType::Enum(members, _) => members
+                .iter()
+                .map(|member| match member {
+                    EnumMember::Name(name) => name.clone(),
+                    EnumMember::NamedValue(name, _) => name.clone(),
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
     /// Check if column has NOT NULL constraint
     fn has_not_null_constraint(options: &[ColumnOptionDef]) -> bool {
         options
@@ -385,6 +436,100 @@ impl SqlParser {
     }
 }
 
+fn type_override(
+    sql_type: &str,
+    go_type: &str,
+    nullable_go_type: Option<&str>,
+    import: Option<&str>,
+) -> SqlTypeOverride {
+    SqlTypeOverride {
+        sql_type: sql_type.to_string(),
+        go_type: go_type.to_string(),
+        nullable_go_type: nullable_go_type.map(str::to_string),
+        import: import.map(str::to_string),
+    }
+}
+
+/// Builtin type mapping profiles
+pub fn builtin_sql_type_profiles() -> Vec<SqlTypeMappingProfile> {
+    let decimal = Some("github.com/shopspring/decimal");
+    let datatypes = Some("gorm.io/datatypes");
+    let sql = Some("database/sql");
+    vec![
+        SqlTypeMappingProfile {
+            name: "gorm-datatypes".to_string(),
+            description: "TINYINT(1) as bool, shopspring decimal and gorm datatypes".to_string(),
+            overrides: vec![
+                type_override("TINYINT(1)", "bool", None, None),
+                type_override(
+                    "DECIMAL",
+                    "decimal.Decimal",
+                    Some("decimal.NullDecimal"),
+                    decimal,
+                ),
+                type_override(
+                    "NUMERIC",
+                    "decimal.Decimal",
+                    Some("decimal.NullDecimal"),
+                    decimal,
+                ),
+                type_override("JSON", "datatypes.JSON", Some("datatypes.JSON"), datatypes),
+                type_override("JSONB", "datatypes.JSON", Some("datatypes.JSON"), datatypes),
+                type_override("DATE", "datatypes.Date", Some("*datatypes.Date"), datatypes),
+            ],
+            builtin: true,
+        },
+        SqlTypeMappingProfile {
+            name: "database-sql-null".to_string(),
+            description: "Nullable columns use database/sql Null types instead of pointers"
+                .to_string(),
+            overrides: vec![
+                type_override("CHAR", "string", Some("sql.NullString"), sql),
+                type_override("VARCHAR", "string", Some("sql.NullString"), sql),
+                type_override("TEXT", "string", Some("sql.NullString"), sql),
+                type_override("TINYINT(1)", "bool", Some("sql.NullBool"), sql),
+                type_override("SMALLINT", "int16", Some("sql.NullInt16"), sql),
+                type_override("INT", "int32", Some("sql.NullInt32"), sql),
+                type_override("INTEGER", "int32", Some("sql.NullInt32"), sql),
+                type_override("BIGINT", "int64", Some("sql.NullInt64"), sql),
+                type_override("DOUBLE", "float64", Some("sql.NullFloat64"), sql),
+                type_override("BOOLEAN", "bool", Some("sql.NullBool"), sql),
+                type_override("DATETIME", "time.Time", Some("sql.NullTime"), sql),
+                type_override("TIMESTAMP", "time.Time", Some("sql.NullTime"), sql),
+            ],
+            builtin: true,
+        },
+    ]
+}
+
+/// Find a profile by name, user profiles take precedence over builtin ones
+pub(crate) fn find_sql_type_profile(
+    user_profiles: &[SqlTypeMappingProfile],
+    name: &str,
+) -> Option<SqlTypeMappingProfile> {
+    user_profiles
+        .iter()
+        .find(|p| p.name == name)
+        .cloned()
+        .or_else(|| {
+            builtin_sql_type_profiles()
+                .into_iter()
+                .find(|p| p.name == name)
+        })
+}
+
+fn validate_sql_type_profile(profile: &SqlTypeMappingProfile) -> Result<(), String> {
+    if profile.name.trim().is_empty() {
+        return Err("映射方案名称不能为空".to_string());
+    }
+    for item in &profile.overrides {
+        if item.sql_type.trim().is_empty() || item.go_type.trim().is_empty() {
+            return Err("SQL 类型和 Go 类型不能为空".to_string());
+        }
+    }
+    Ok(())
+}
+
 pub struct GoStructGenerator;
 
 impl GoStructGenerator {
@@ -418,23 +563,29 @@ impl GoStructGenerator {
         let struct_name = Self::generate_struct_name(&table.name, options.enable_pluralization);
 
         // Check what imports are needed
-        let needs_time = table.columns.iter().any(|col| {
-            Self::sql_type_to_go_type(&col.sql_type, col.nullable).contains("time.Time")
-        });
-        let needs_json = table.columns.iter().any(|col| {
-            Self::sql_type_to_go_type(&col.sql_type, col.nullable).contains("json.RawMessage")
-        });
+        let field_types: Vec<String> = table
+            .columns
+            .iter()
+            .map(|col| Self::resolve_go_type(&struct_name, col, options))
+            .collect();
+        let mut imports = Self::collect_imports(&field_types, &options.type_overrides);
+        let enum_columns: Vec<&ColumnDefinition> = table
+            .columns
+            .iter()
+            .filter(|col| Self::is_enum_helper_column(col, options))
+            .collect();
+        if !enum_columns.is_empty() {
+            imports.insert("database/sql/driver".to_string());
+            imports.insert("fmt".to_string());
+        }
 
         let mut result = String::new();
 
         // Add imports if needed
-        if needs_time || needs_json {
+        if !imports.is_empty() {
             result.push_str("import (\n");
-            if needs_json {
-                result.push_str("\t\"encoding/json\"\n");
-            }
-            if needs_time {
-                result.push_str("\t\"time\"\n");
+            for import in &imports {
+                result.push_str(&format!("\t\"{}\"\n", import));
             }
             result.push_str(")\n\n");
         }
@@ -443,7 +594,8 @@ impl GoStructGenerator {
         result.push_str(&format!("type {} struct {{\n", struct_name));
 
         // Generate fields
-        let field_definitions = Self::generate_field_definitions(&table.columns, options)?;
+        let field_definitions =
+            Self::generate_field_definitions(&struct_name, &table.columns, options)?;
         for field in &field_definitions {
             result.push_str(&format!("\t{}\n", field));
         }
@@ -490,9 +642,142 @@ impl GoStructGenerator {
             }
         }
 
+        if options.generate_column_helpers {
+            result.push('\n');
+            result.push_str(&Self::generate_column_helpers(&struct_name, table));
+            for column in enum_columns {
+                result.push('\n');
+                result.push_str(&Self::generate_enum_type(&struct_name, column));
+            }
+        }
+
         Ok(CodeFormatter::format_go_code(&result))
     }
 
+    /// Generate the column name const block and the Columns() helper
+    fn generate_column_helpers(struct_name: &str, table: &TableDefinition) -> String {
+        let const_names: Vec<String> = table
+            .columns
+            .iter()
+            .map(|col| {
+                format!(
+                    "{}Column{}",
+                    struct_name,
+                    Self::to_camel_case(&col.name, true)
+                )
+            })
+            .collect();
+        let max_len = const_names.iter().map(|n| n.len()).max().unwrap_or(0);
+
+        let mut result = format!("// Column names of table {}\nconst (\n", table.name);
+        for (const_name, column) in const_names.iter().zip(&table.columns) {
+            result.push_str(&format!(
+                "\t{:width$} = \"{}\"\n",
+                const_name,
+                column.name,
+                width = max_len
+            ));
+        }
+        result.push_str(")\n\n");
+
+        result.push_str(&format!(
+            "// Columns returns all column names of the table\n\
+             func ({}) Columns() []string {{\n\
+             \treturn []string{{{}}}\n\
+             }}\n",
+            struct_name,
+            const_names.join(", ")
+        ));
+        result
+    }
+
+    /// Generate a string type with value consts and Scan/Value methods for an ENUM column
+    fn generate_enum_type(struct_name: &str, column: &ColumnDefinition) -> String {
+        let type_name = Self::enum_type_name(struct_name, column);
+        let const_names: Vec<String> = column
+            .enum_values
+            .iter()
+            .map(|value| format!("{}{}", type_name, Self::enum_value_name(value)))
+            .collect();
+        let max_len = const_names.iter().map(|n| n.len()).max().unwrap_or(0);
+
+        let mut result = format!(
+            "// {} is the value type of column {}\ntype {} string\n\nconst (\n",
+            type_name, column.name, type_name
+        );
+        for (const_name, value) in const_names.iter().zip(&column.enum_values) {
+            result.push_str(&format!(
+                "\t{:width$} {} = \"{}\"\n",
+                const_name,
+                type_name,
+                value.replace('\\', "\\\\").replace('"', "\\\""),
+                width = max_len
+            ));
+        }
+        result.push_str(")\n\n");
+
+        result.push_str(&format!(
+            "// IsValid reports whether the value is one of the defined values\n\
+             func (v {type_name}) IsValid() bool {{\n\
+             \tswitch v {{\n\
+             \tcase {cases}:\n\
+             \t\treturn true\n\
+             \t}}\n\
+             \treturn false\n\
+             }}\n\n\
+             // Scan implements the sql.Scanner interface\n\
+             func (v *{type_name}) Scan(value interface{{}}) error {{\n\
+             \tswitch val := value.(type) {{\n\
+             \tcase string:\n\
+             \t\t*v = {type_name}(val)\n\
+             \tcase []byte:\n\
+             \t\t*v = {type_name}(val)\n\
+             \tdefault:\n\
+             \t\treturn fmt.Errorf(\"cannot scan %T into {type_name}\", value)\n\
+             \t}}\n\
+             \tif !v.IsValid() {{\n\
+             \t\treturn fmt.Errorf(\"invalid {type_name} value: %q\", string(*v))\n\
+             \t}}\n\
+             \treturn nil\n\
+             }}\n\n\
+             // Value implements the driver.Valuer interface\n\
+             func (v {type_name}) Value() (driver.Value, error) {{\n\
+             \tif !v.IsValid() {{\n\
+             \t\treturn nil, fmt.Errorf(\"invalid {type_name} value: %q\", string(v))\n\
+             \t}}\n\
+             \treturn string(v), nil\n\
+             }}\n",
+            type_name = type_name,
+            cases = const_names.join(", ")
+        ));
+        result
+    }
+
+    /// ENUM columns get a generated type unless a type override applies
+    fn is_enum_helper_column(column: &ColumnDefinition, options: &SqlToGoOptions) -> bool {
+        options.generate_column_helpers
+            && !column.enum_values.is_empty()
+            && Self::find_type_override(&column.sql_type, &options.type_overrides).is_none()
+    }
+
+    fn enum_type_name(struct_name: &str, column: &ColumnDefinition) -> String {
+        format!("{}{}", struct_name, Self::to_camel_case(&column.name, true))
+    }
+
+    /// Turn an enum value into an identifier suffix, e.g. "in-progress" -> "InProgress"
+    fn enum_value_name(value: &str) -> String {
+        let normalized: String = value
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        let name = Self::to_camel_case(&normalized, true);
+        if name.is_empty() {
+            "Empty".to_string()
+        } else {
+            name
+        }
+    }
+
     /// Generate struct name from table name
     pub fn generate_struct_name(table_name: &str, enable_pluralization: bool) -> String {
         let mut name = table_name.to_string();
@@ -588,6 +873,117 @@ impl GoStructGenerator {
         }
     }
 
+    /// Merge the overrides of the selected mapping profile in front of the inline overrides
+    pub fn apply_mapping_profile(
+        options: SqlToGoOptions,
+        user_profiles: &[SqlTypeMappingProfile],
+    ) -> Result<SqlToGoOptions, String> {
+        let Some(name) = options.mapping_profile.as_deref().filter(|n| !n.is_empty()) else {
+            return Ok(options);
+        };
+        let profile = find_sql_type_profile(user_profiles, name)
+            .ok_or_else(|| format!("类型映射方案不存在: {}", name))?;
+        let mut type_overrides = profile.overrides;
+        type_overrides.extend(options.type_overrides);
+        Ok(SqlToGoOptions {
+            type_overrides,
+            ..options
+        })
+    }
+
+    /// Resolve the Go type of a column, honoring type overrides and generated enum types
+    fn resolve_go_type(
+        struct_name: &str,
+        column: &ColumnDefinition,
+        options: &SqlToGoOptions,
+    ) -> String {
+        if Self::is_enum_helper_column(column, options) {
+            let type_name = Self::enum_type_name(struct_name, column);
+            return if column.nullable {
+                format!("*{}", type_name)
+            } else {
+                type_name
+            };
+        }
+        match Self::find_type_override(&column.sql_type, &options.type_overrides) {
+            Some(item) if column.nullable => item.nullable_go_type.clone().unwrap_or_else(|| {
+                if item.go_type.starts_with('*') || item.go_type.starts_with("[]") {
+                    item.go_type.clone()
+                } else {
+                    format!("*{}", item.go_type)
+                }
+            }),
+            Some(item) => item.go_type.clone(),
+            None => Self::sql_type_to_go_type(&column.sql_type, column.nullable),
+        }
+    }
+
+    /// Find the override for a SQL type; exact matches win over base type matches
+    /// and later overrides win over earlier ones
+    fn find_type_override<'a>(
+        sql_type: &str,
+        overrides: &'a [SqlTypeOverride],
+    ) -> Option<&'a SqlTypeOverride> {
+        let normalized = Self::normalize_sql_type(sql_type);
+        let base = Self::base_sql_type(&normalized);
+        overrides
+            .iter()
+            .rev()
+            .find(|o| Self::normalize_sql_type(&o.sql_type) == normalized)
+            .or_else(|| {
+                overrides
+                    .iter()
+                    .rev()
+                    .find(|o| Self::normalize_sql_type(&o.sql_type) == base)
+            })
+    }
+
+    /// Uppercase and remove insignificant whitespace, e.g. "decimal(10, 2)" -> "DECIMAL(10,2)"
+    fn normalize_sql_type(sql_type: &str) -> String {
+        sql_type
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_uppercase()
+            .replace(" (", "(")
+            .replace("( ", "(")
+            .replace(" )", ")")
+            .replace(", ", ",")
+    }
+
+    /// Strip the length/precision part, e.g. "INT(11) UNSIGNED" -> "INT UNSIGNED"
+    fn base_sql_type(normalized: &str) -> String {
+        match (normalized.find('('), normalized.find(')')) {
+            (Some(start), Some(end)) if start < end => {
+                format!("{}{}", &normalized[..start], &normalized[end + 1..])
+                    .trim()
+                    .to_string()
+            }
+            _ => normalized.to_string(),
+        }
+    }
+
+    /// Collect the packages referenced by the generated field types
+    fn collect_imports(field_types: &[String], overrides: &[SqlTypeOverride]) -> BTreeSet<String> {
+        let mut imports = BTreeSet::new();
+        for field_type in field_types {
+            let bare = field_type.trim_start_matches(['*', '[', ']']);
+            if bare.starts_with("time.") {
+                imports.insert("time".to_string());
+            }
+            if bare.starts_with("json.") {
+                imports.insert("encoding/json".to_string());
+            }
+            for import in overrides.iter().filter_map(|o| o.import.as_deref()) {
+                let alias = import.rsplit('/').next().unwrap_or(import);
+                if bare.starts_with(&format!("{}.", alias)) {
+                    imports.insert(import.to_string());
+                }
+            }
+        }
+        imports
+    }
+
     /// Convert SQL type to Go type
     fn sql_type_to_go_type(sql_type: &str, nullable: bool) -> String {
         // Normalize to lowercase for easier matching
@@ -771,6 +1167,7 @@ impl GoStructGenerator {
 
     /// Generate field definitions
     pub fn generate_field_definitions(
+        struct_name: &str,
         columns: &[ColumnDefinition],
         options: &SqlToGoOptions,
     ) -> Result<Vec<String>, String> {
@@ -781,7 +1178,7 @@ impl GoStructGenerator {
         // Calculate max lengths for alignment
         for column in columns {
             let field_name = Self::to_field_name(&column.name, options.exported_fields);
-            let field_type = Self::sql_type_to_go_type(&column.sql_type, column.nullable);
+            let field_type = Self::resolve_go_type(struct_name, column, options);
             max_name_len = max_name_len.max(field_name.len());
             max_type_len = max_type_len.max(field_type.len());
         }
@@ -789,7 +1186,7 @@ impl GoStructGenerator {
         // Generate field definitions
         for column in columns {
             let field_name = Self::to_field_name(&column.name, options.exported_fields);
-            let field_type = Self::sql_type_to_go_type(&column.sql_type, column.nullable);
+            let field_type = Self::resolve_go_type(struct_name, column, options);
             let tags = Self::generate_field_tags(column, &field_name, options);
 
             let name_padded = format!("{:width$}", field_name, width = max_name_len);
@@ -989,10 +1386,17 @@ impl GoStructGenerator {
 
 #[command]
 pub async fn convert_sql_to_go(
+    tray_state: State<'_, GlobalTrayState>,
     sql: String,
     options: Option<SqlToGoOptions>,
 ) -> Result<GoStructOutput, String> {
-    let options = options.unwrap_or_default();
+    let options = {
+        let config = tray_state.config.lock().map_err(|e| e.to_string())?;
+        GoStructGenerator::apply_mapping_profile(
+            options.unwrap_or_default(),
+            &config.sql_type_profiles,
+        )?
+    };
 
     // Validate input
     if sql.trim().is_empty() {
@@ -1007,3 +1411,178 @@ pub async fn convert_sql_to_go(
         Err(e) => Err(format!("SQL解析失败: {}", e)),
     }
 }
+
+/// List builtin and saved type mapping profiles
+#[command]
+pub async fn list_sql_type_profiles(
+    tray_state: State<'_, GlobalTrayState>,
+) -> Result<Vec<SqlTypeMappingProfile>, String> {
+    let config = tray_state.config.lock().map_err(|e| e.to_string())?;
+    let mut profiles = builtin_sql_type_profiles();
+    profiles.extend(config.sql_type_profiles.iter().cloned());
+    Ok(profiles)
+}
+
+/// Create or update a saved type mapping profile
+#[command]
+pub async fn save_sql_type_profile(
+    tray_state: State<'_, GlobalTrayState>,
+    profile: SqlTypeMappingProfile,
+) -> Result<Vec<SqlTypeMappingProfile>, String> {
+    let mut profile = profile;
+    profile.name = profile.name.trim().to_string();
+    profile.builtin = false;
+    validate_sql_type_profile(&profile)?;
+    if builtin_sql_type_profiles()
+        .iter()
+        .any(|p| p.name == profile.name)
+    {
+        return Err(format!("不能覆盖内置映射方案: {}", profile.name));
+    }
+
+    let mut config = tray_state.config.lock().map_err(|e| e.to_string())?;
+    match config
+        .sql_type_profiles
+        .iter_mut()
+        .find(|p| p.name == profile.name)
+    {
+        Some(existing) => *existing = profile,
+        None => config.sql_type_profiles.push(profile),
+    }
+    save_config(&config).map_err(|e| e.to_string())?;
+    Ok(config.sql_type_profiles.clone())
+}
+
+/// Delete a saved type mapping profile
+#[command]
+pub async fn delete_sql_type_profile(
+    tray_state: State<'_, GlobalTrayState>,
+    name: String,
+) -> Result<Vec<SqlTypeMappingProfile>, String> {
+    if builtin_sql_type_profiles().iter().any(|p| p.name == name) {
+        return Err(format!("不能删除内置映射方案: {}", name));
+    }
+    let mut config = tray_state.config.lock().map_err(|e| e.to_string())?;
+    let before = config.sql_type_profiles.len();
+    config.sql_type_profiles.retain(|p| p.name != name);
+    if config.sql_type_profiles.len() == before {
+        return Err(format!("类型映射方案不存在: {}", name));
+    }
+    save_config(&config).map_err(|e| e.to_string())?;
+    Ok(config.sql_type_profiles.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SQL: &str = "CREATE TABLE orders (
+        id BIGINT NOT NULL PRIMARY KEY,
+        paid TINYINT(1) NOT NULL,
+        level TINYINT NOT NULL,
+        amount DECIMAL(10, 2) NOT NULL,
+        discount DECIMAL(10, 2),
+        extra JSON,
+        created_at DATETIME NOT NULL
+    )";
+
+    fn generate(options: &SqlToGoOptions) -> String {
+        let tables = SqlParser::parse_sql_tables(SQL).unwrap();
+        GoStructGenerator::generate_struct(&tables[0], options).unwrap()
+    }
+
+    #[test]
+    fn test_type_overrides() {
+        let options = SqlToGoOptions {
+            type_overrides: vec![
+                type_override("tinyint(1)", "bool", None, None),
+                type_override("decimal", "float64", None, None),
+                type_override(
+                    "DECIMAL(10,2)",
+                    "decimal.Decimal",
+                    Some("decimal.NullDecimal"),
+                    Some("github.com/shopspring/decimal"),
+                ),
+            ],
+            ..SqlToGoOptions::default()
+        };
+        let code = generate(&options);
+
+        assert!(code.contains("\"github.com/shopspring/decimal\""));
+        assert!(code.contains("\"encoding/json\""));
+        assert!(code.contains("\"time\""));
+        assert!(code.contains("Paid      bool "));
+        assert!(code.contains("Level     int8 "));
+        // The exact match wins over the earlier base type match
+        assert!(code.contains("Amount    decimal.Decimal "));
+        assert!(code.contains("Discount  decimal.NullDecimal "));
+        assert!(code.contains("Extra     *json.RawMessage "));
+    }
+
+    #[test]
+    fn test_mapping_profile() {
+        let options = SqlToGoOptions {
+            mapping_profile: Some("gorm-datatypes".to_string()),
+            type_overrides: vec![type_override("JSON", "map[string]any", None, None)],
+            ..SqlToGoOptions::default()
+        };
+        let options = GoStructGenerator::apply_mapping_profile(options, &[]).unwrap();
+        let code = generate(&options);
+
+        assert!(code.contains("\"github.com/shopspring/decimal\""));
+        assert!(!code.contains("\"gorm.io/datatypes\""));
+        assert!(code.contains("Paid      bool "));
+        // Inline overrides are applied after the profile
+        assert!(code.contains("Extra     *map[string]any "));
+
+        let missing = SqlToGoOptions {
+            mapping_profile: Some("missing".to_string()),
+            ..SqlToGoOptions::default()
+        };
+        assert!(GoStructGenerator::apply_mapping_profile(missing, &[]).is_err());
+    }
+
+    #[test]
+    fn test_column_helpers() {
+        let sql = "CREATE TABLE users (
+            id BIGINT NOT NULL PRIMARY KEY,
+            user_name VARCHAR(64) NOT NULL,
+            status ENUM('active', 'in-progress', '') NOT NULL,
+            role ENUM('admin', 'guest')
+        )";
+        let tables = SqlParser::parse_sql_tables(sql).unwrap();
+        assert_eq!(
+            tables[0].columns[2].enum_values,
+            vec!["active", "in-progress", ""]
+        );
+
+        let options = SqlToGoOptions {
+            enable_pluralization: false,
+            generate_column_helpers: true,
+            type_overrides: vec![type_override("ENUM", "string", None, None)],
+            ..SqlToGoOptions::default()
+        };
+        let code = GoStructGenerator::generate_struct(&tables[0], &options).unwrap();
+        assert!(code.contains("UsersColumnUserName = \"user_name\""));
+        assert!(code.contains(
+            "return []string{UsersColumnId, UsersColumnUserName, UsersColumnStatus, UsersColumnRole}"
+        ));
+        // Type overrides take precedence over generated enum types
+        assert!(!code.contains("type UsersStatus string"));
+
+        let options = SqlToGoOptions {
+            type_overrides: Vec::new(),
+            ..options
+        };
+        let code = GoStructGenerator::generate_struct(&tables[0], &options).unwrap();
+        assert!(code.contains("\"database/sql/driver\""));
+        assert!(code.contains("\"fmt\""));
+        assert!(code.contains("Status   UsersStatus "));
+        assert!(code.contains("Role     *UsersRole "));
+        assert!(code.contains("type UsersStatus string"));
+        assert!(code.contains("UsersStatusInProgress UsersStatus = \"in-progress\""));
+        assert!(code.contains("UsersStatusEmpty      UsersStatus = \"\""));
+        assert!(code.contains("func (v *UsersRole) Scan(value interface{}) error {"));
+        assert!(code.contains("func (v UsersRole) Value() (driver.Value, error) {"));
+    }
+}
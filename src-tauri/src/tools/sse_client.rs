@@ -0,0 +1,568 @@
+use crate::tools::http_collection::KeyValue;
+use crate::utils::proxy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, State};
+use tokio::task::JoinHandle;
+
+/// 默认重连间隔，服务端可通过 retry 字段调整
+const DEFAULT_RECONNECT_DELAY_MS: u64 = 3000;
+/// 长轮询单次请求的默认超时
+const DEFAULT_POLL_TIMEOUT_SECS: u64 = 60;
+
+type UpdateSink = Arc<dyn Fn(SseUpdate) + Send + Sync>;
+
+#[derive(Default)]
+pub struct SseClientState {
+    connections: Mutex<HashMap<String, JoinHandle<()>>>,
+}
+
+impl SseClientState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SseConnectRequest {
+    pub url: String,
+    pub method: Option<String>, // 默认 GET
+    #[serde(default)]
+    pub headers: Vec<KeyValue>,
+    pub body: Option<String>,
+    pub mode: Option<String>,          // sse（默认）/ long-poll
+    pub last_event_id: Option<String>, // 首次连接携带的 Last-Event-ID
+    pub auto_reconnect: Option<bool>,  // 默认开启
+    pub reconnect_delay_ms: Option<u64>,
+    pub max_retries: Option<u32>,      // 为空表示不限次数
+    pub poll_interval_ms: Option<u64>, // 长轮询两次请求之间的间隔
+    pub timeout_secs: Option<u64>,     // 长轮询单次请求超时
+    pub ignore_tls_errors: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SseEvent {
+    pub connection_id: String,
+    pub sequence: u64,
+    pub event: String,
+    pub id: Option<String>,
+    pub data: String,
+    pub retry: Option<u64>, // 当前重连间隔（毫秒）
+    pub received_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SseStatus {
+    pub connection_id: String,
+    pub status: String, // connecting, open, reconnecting, closed, error
+    pub message: Option<String>,
+    pub attempt: u32,
+    pub http_status: Option<u16>,
+    pub last_event_id: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+enum SseUpdate {
+    Event(SseEvent),
+    Status(SseStatus),
+}
+
+/// 建立 SSE / 长轮询连接，事件通过 sse-event 推送，状态变化通过 sse-status 推送
+#[tauri::command]
+pub async fn sse_connect(
+    app: AppHandle,
+    state: State<'_, SseClientState>,
+    request: SseConnectRequest,
+) -> Result<String, String> {
+    reqwest::Url::parse(&request.url).map_err(|e| format!("无效的 URL: {}", e))?;
+    let client = build_client(&request)?;
+
+    let id = format!("sse-{}", chrono::Utc::now().timestamp_millis());
+    let sink: UpdateSink = Arc::new(move |update| {
+        let _ = match update {
+            SseUpdate::Event(event) => app.emit("sse-event", event),
+            SseUpdate::Status(status) => app.emit("sse-status", status),
+        };
+    });
+    let mut connection = Connection::new(id.clone(), request, client, sink);
+    let handle = tokio::spawn(async move { connection.run().await });
+
+    let mut connections = state.connections.lock().unwrap();
+    connections.retain(|_, handle| !handle.is_finished());
+    connections.insert(id.clone(), handle);
+    Ok(id)
+}
+
+/// 断开 SSE / 长轮询连接
+#[tauri::command]
+pub async fn sse_disconnect(state: State<'_, SseClientState>, id: String) -> Result<(), String> {
+    match state.connections.lock().unwrap().remove(&id) {
+        Some(handle) => {
+            handle.abort();
+            Ok(())
+        }
+        None => Err("连接不存在或已关闭".to_string()),
+    }
+}
+
+fn build_client(request: &SseConnectRequest) -> Result<reqwest::Client, String> {
+    let mut builder = proxy::client_builder("sse_client")
+        .connect_timeout(Duration::from_secs(10))
+        .danger_accept_invalid_certs(request.ignore_tls_errors.unwrap_or(false));
+    if request.mode.as_deref() == Some("long-poll") {
+        builder = builder.timeout(Duration::from_secs(
+            request.timeout_secs.unwrap_or(DEFAULT_POLL_TIMEOUT_SECS),
+        ));
+    }
+    builder
+        .build()
+        .map_err(|e| format!("创建 HTTP 客户端失败: {}", e))
+}
+
+/// 单次连接的结果
+enum Outcome {
+    Continue,
+    Retry(String),
+    Stop(&'static str, String),
+}
+
+struct Connection {
+    id: String,
+    request: SseConnectRequest,
+    client: reqwest::Client,
+    sink: UpdateSink,
+    last_event_id: Option<String>,
+    reconnect_delay: Duration,
+    attempt: u32,
+    sequence: u64,
+    open: bool,
+}
+
+impl Connection {
+    fn new(
+        id: String,
+        request: SseConnectRequest,
+        client: reqwest::Client,
+        sink: UpdateSink,
+    ) -> Self {
+        Self {
+            id,
+            last_event_id: request.last_event_id.clone().filter(|id| !id.is_empty()),
+            reconnect_delay: Duration::from_millis(
+                request
+                    .reconnect_delay_ms
+                    .unwrap_or(DEFAULT_RECONNECT_DELAY_MS),
+            ),
+            request,
+            client,
+            sink,
+            attempt: 0,
+            sequence: 0,
+            open: false,
+        }
+    }
+
+    async fn run(&mut self) {
+        let long_poll = self.request.mode.as_deref() == Some("long-poll");
+        self.status("connecting", None, None);
+        loop {
+            let outcome = if long_poll {
+                self.poll_once().await
+            } else {
+                self.stream_once().await
+            };
+            match outcome {
+                Outcome::Continue => {}
+                Outcome::Stop(status, message) => {
+                    self.status(status, Some(message), None);
+                    return;
+                }
+                Outcome::Retry(message) => {
+                    let exhausted = self
+                        .request
+                        .max_retries
+                        .is_some_and(|max| self.attempt >= max);
+                    if !self.request.auto_reconnect.unwrap_or(true) || exhausted {
+                        self.status("closed", Some(message), None);
+                        return;
+                    }
+                    self.attempt += 1;
+                    self.open = false;
+                    self.status("reconnecting", Some(message), None);
+                    tokio::time::sleep(self.reconnect_delay).await;
+                }
+            }
+        }
+    }
+
+    async fn send(&self) -> Result<reqwest::Response, reqwest::Error> {
+        let method = self
+            .request
+            .method
+            .as_deref()
+            .filter(|m| !m.is_empty())
+            .unwrap_or("GET")
+            .to_uppercase();
+        let method = reqwest::Method::from_bytes(method.as_bytes()).unwrap_or(reqwest::Method::GET);
+        let mut builder = self.client.request(method, &self.request.url);
+        if self.request.mode.as_deref() != Some("long-poll") {
+            builder = builder
+                .header("Accept", "text/event-stream")
+                .header("Cache-Control", "no-cache");
+        }
+        for header in self.request.headers.iter().filter(|h| h.enabled) {
+            builder = builder.header(header.key.as_str(), header.value.as_str());
+        }
+        if let Some(id) = &self.last_event_id {
+            builder = builder.header("Last-Event-ID", id.as_str());
+        }
+        if let Some(body) = &self.request.body {
+            builder = builder.body(body.clone());
+        }
+        builder.send().await
+    }
+
+    async fn stream_once(&mut self) -> Outcome {
+        let mut response = match self.send().await {
+            Ok(response) => response,
+            Err(e) => return Outcome::Retry(format!("连接失败: {}", e)),
+        };
+        if let Some(outcome) = check_status(response.status()) {
+            return outcome;
+        }
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+        if !content_type.starts_with("text/event-stream") {
+            return Outcome::Stop(
+                "error",
+                format!("响应类型不是 text/event-stream: {}", content_type),
+            );
+        }
+
+        self.attempt = 0;
+        self.status("open", None, Some(response.status().as_u16()));
+        let mut parser = SseParser::new(self.last_event_id.clone());
+        loop {
+            let chunk = match response.chunk().await {
+                Ok(Some(chunk)) => chunk,
+                Ok(None) => return Outcome::Retry("服务端关闭了连接".to_string()),
+                Err(e) => return Outcome::Retry(format!("读取事件流失败: {}", e)),
+            };
+            for parsed in parser.feed(&chunk) {
+                self.emit_event(parsed);
+            }
+            self.last_event_id = parser.last_event_id.clone();
+            if let Some(retry) = parser.retry.take() {
+                self.reconnect_delay = Duration::from_millis(retry);
+            }
+        }
+    }
+
+    async fn poll_once(&mut self) -> Outcome {
+        let response = match self.send().await {
+            Ok(response) => response,
+            // 长轮询超时表示本轮没有数据
+            Err(e) if e.is_timeout() => return Outcome::Continue,
+            Err(e) => return Outcome::Retry(format!("请求失败: {}", e)),
+        };
+        let status = response.status();
+        if status != reqwest::StatusCode::NOT_MODIFIED {
+            if let Some(outcome) = check_status(status) {
+                return outcome;
+            }
+        }
+        if !self.open {
+            self.attempt = 0;
+            self.open = true;
+            self.status("open", None, Some(status.as_u16()));
+        }
+
+        let header = |name: &str| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string)
+        };
+        let event_id = header("Last-Event-ID").or_else(|| header("ETag"));
+        if status.is_success() && status != reqwest::StatusCode::NO_CONTENT {
+            match response.text().await {
+                Ok(data) => {
+                    if event_id.is_some() {
+                        self.last_event_id = event_id;
+                    }
+                    self.emit_event(ParsedEvent {
+                        event: "message".to_string(),
+                        id: self.last_event_id.clone(),
+                        data,
+                    });
+                }
+                Err(e) => return Outcome::Retry(format!("读取响应失败: {}", e)),
+            }
+        }
+
+        let interval = self.request.poll_interval_ms.unwrap_or(0);
+        if interval > 0 {
+            tokio::time::sleep(Duration::from_millis(interval)).await;
+        }
+        Outcome::Continue
+    }
+
+    fn emit_event(&mut self, parsed: ParsedEvent) {
+        self.sequence += 1;
+        (self.sink)(SseUpdate::Event(SseEvent {
+            connection_id: self.id.clone(),
+            sequence: self.sequence,
+            event: parsed.event,
+            id: parsed.id,
+            data: parsed.data,
+            retry: Some(self.reconnect_delay.as_millis() as u64),
+            received_at: chrono::Local::now().to_rfc3339(),
+        }));
+    }
+
+    fn status(&self, status: &str, message: Option<String>, http_status: Option<u16>) {
+        (self.sink)(SseUpdate::Status(SseStatus {
+            connection_id: self.id.clone(),
+            status: status.to_string(),
+            message,
+            attempt: self.attempt,
+            http_status,
+            last_event_id: self.last_event_id.clone(),
+        }));
+    }
+}
+
+/// 204 表示服务端要求停止重连，5xx 可重试，其余非 2xx 视为失败
+fn check_status(status: reqwest::StatusCode) -> Option<Outcome> {
+    if status == reqwest::StatusCode::NO_CONTENT {
+        return Some(Outcome::Stop(
+            "closed",
+            "服务端返回 204，停止重连".to_string(),
+        ));
+    }
+    if status.is_server_error() {
+        return Some(Outcome::Retry(format!("HTTP {}", status)));
+    }
+    if !status.is_success() {
+        return Some(Outcome::Stop("error", format!("HTTP {}", status)));
+    }
+    None
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ParsedEvent {
+    event: String,
+    id: Option<String>,
+    data: String,
+}
+
+/// 按 WHATWG EventSource 规范解析事件流
+struct SseParser {
+    buffer: Vec<u8>,
+    event: String,
+    data: String,
+    has_data: bool,
+    last_event_id: Option<String>,
+    retry: Option<u64>,
+}
+
+impl SseParser {
+    fn new(last_event_id: Option<String>) -> Self {
+        Self {
+            buffer: Vec::new(),
+            event: String::new(),
+            data: String::new(),
+            has_data: false,
+            last_event_id,
+            retry: None,
+        }
+    }
+
+    fn feed(&mut self, chunk: &[u8]) -> Vec<ParsedEvent> {
+        self.buffer.extend_from_slice(chunk);
+        let mut events = Vec::new();
+        let mut start = 0;
+        let mut index = 0;
+        while index < self.buffer.len() {
+            let end = match self.buffer[index] {
+                b'\n' => index + 1,
+                // \r 可能与下一个分块中的 \n 组成 CRLF，需等待更多数据
+                b'\r' if index + 1 == self.buffer.len() => break,
+                b'\r' if self.buffer[index + 1] == b'\n' => index + 2,
+                b'\r' => index + 1,
+                _ => {
+                    index += 1;
+                    continue;
+                }
+            };
+            let line = String::from_utf8_lossy(&self.buffer[start..index]).to_string();
+            if let Some(event) = self.process_line(&line) {
+                events.push(event);
+            }
+            start = end;
+            index = end;
+        }
+        self.buffer.drain(..start);
+        events
+    }
+
+    fn process_line(&mut self, line: &str) -> Option<ParsedEvent> {
+        if line.is_empty() {
+            return self.dispatch();
+        }
+        if line.starts_with(':') {
+            return None;
+        }
+        let (field, value) = match line.split_once(':') {
+            Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+            None => (line, ""),
+        };
+        match field {
+            "event" => self.event = value.to_string(),
+            "data" => {
+                if self.has_data {
+                    self.data.push('\n');
+                }
+                self.data.push_str(value);
+                self.has_data = true;
+            }
+            "id" if !value.contains('\0') => {
+                self.last_event_id = Some(value.to_string()).filter(|v| !v.is_empty());
+            }
+            "retry" => {
+                if let Ok(retry) = value.parse() {
+                    self.retry = Some(retry);
+                }
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn dispatch(&mut self) -> Option<ParsedEvent> {
+        let event = std::mem::take(&mut self.event);
+        let data = std::mem::take(&mut self.data);
+        if !std::mem::replace(&mut self.has_data, false) {
+            return None;
+        }
+        Some(ParsedEvent {
+            event: if event.is_empty() {
+                "message".to_string()
+            } else {
+                event
+            },
+            id: self.last_event_id.clone(),
+            data,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    #[test]
+    fn test_parse_event_stream() {
+        let mut parser = SseParser::new(None);
+        let mut events = parser.feed(b": comment\r\nevent: update\r\nid: 1\r");
+        events.extend(parser.feed(b"\ndata: first\r\ndata:second\r\n\r\n"));
+        events.extend(parser.feed(b"retry: 500\ndata: {\"a\":1}\n\nid\nevent: empty\n\n"));
+
+        assert_eq!(
+            events,
+            vec![
+                ParsedEvent {
+                    event: "update".to_string(),
+                    id: Some("1".to_string()),
+                    data: "first\nsecond".to_string(),
+                },
+                ParsedEvent {
+                    event: "message".to_string(),
+                    id: Some("1".to_string()),
+                    data: "{\"a\":1}".to_string(),
+                },
+            ]
+        );
+        assert_eq!(parser.retry, Some(500));
+        assert_eq!(parser.last_event_id, None);
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_with_last_event_id() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/events", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            for response in [
+                "HTTP/1.1 200 OK\r\ncontent-type: text/event-stream\r\nconnection: close\r\n\r\nretry: 10\nid: 1\ndata: a\n\nid: 2\ndata: b\n\n",
+                "HTTP/1.1 204 No Content\r\nconnection: close\r\n\r\n",
+            ] {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut buf = vec![0u8; 4096];
+                let n = stream.read(&mut buf).await.unwrap();
+                requests.push(String::from_utf8_lossy(&buf[..n]).to_lowercase());
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+            requests
+        });
+
+        let updates = Arc::new(Mutex::new(Vec::new()));
+        let sink_updates = updates.clone();
+        let sink: UpdateSink = Arc::new(move |update| sink_updates.lock().unwrap().push(update));
+        let request = SseConnectRequest {
+            url,
+            method: None,
+            headers: vec![KeyValue {
+                key: "X-Token".to_string(),
+                value: "abc".to_string(),
+                enabled: true,
+            }],
+            body: None,
+            mode: None,
+            last_event_id: None,
+            auto_reconnect: None,
+            reconnect_delay_ms: None,
+            max_retries: Some(3),
+            poll_interval_ms: None,
+            timeout_secs: None,
+            ignore_tls_errors: None,
+        };
+        let client = build_client(&request).unwrap();
+        Connection::new("test".to_string(), request, client, sink)
+            .run()
+            .await;
+
+        let requests = server.await.unwrap();
+        assert!(requests[0].contains("x-token: abc"));
+        assert!(requests[1].contains("last-event-id: 2"));
+
+        let updates = updates.lock().unwrap();
+        let data: Vec<String> = updates
+            .iter()
+            .filter_map(|u| match u {
+                SseUpdate::Event(e) => Some(e.data.clone()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(data, vec!["a", "b"]);
+        let statuses: Vec<&str> = updates
+            .iter()
+            .filter_map(|u| match u {
+                SseUpdate::Status(s) => Some(s.status.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            statuses,
+            vec!["connecting", "open", "reconnecting", "closed"]
+        );
+    }
+}
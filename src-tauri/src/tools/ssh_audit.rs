@@ -0,0 +1,633 @@
+use crate::utils::ssh_key::{SshKeyCodec, SshReader};
+use base64::{engine::general_purpose, Engine as _};
+use openssl::bn::BigNumContext;
+use openssl::ec::{EcGroup, EcKey, PointConversionForm};
+use openssl::nid::Nid;
+use openssl::pkey::PKey;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+const CLIENT_BANNER: &str = "SSH-2.0-DevTools_SshAudit";
+const MAX_PACKET_SIZE: usize = 256 * 1024;
+const MAX_BANNER_SIZE: usize = 8192;
+
+const SSH_MSG_IGNORE: u8 = 2;
+const SSH_MSG_DEBUG: u8 = 4;
+const SSH_MSG_KEXINIT: u8 = 20;
+const SSH_MSG_KEX_ECDH_INIT: u8 = 30;
+const SSH_MSG_KEX_ECDH_REPLY: u8 = 31;
+
+/// 能够完成密钥交换以获取主机密钥的算法
+const PROBE_KEX: [&str; 4] = [
+    "curve25519-sha256",
+    "curve25519-sha256@libssh.org",
+    "ecdh-sha2-nistp256",
+    "ecdh-sha2-nistp384",
+];
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SshScanRequest {
+    pub host: String,
+    pub port: Option<u16>,         // 默认 22
+    pub timeout_secs: Option<u64>, // 单次连接超时，默认 10 秒
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SshAlgorithm {
+    pub name: String,
+    pub level: String, // good, warn, fail, info
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SshHostKey {
+    pub algorithm: String,
+    pub key_type: String,
+    pub bits: Option<u32>,
+    pub fingerprint_sha256: String,
+    pub fingerprint_md5: String,
+    pub public_key: String, // known_hosts 格式
+    pub level: String,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SshScanResult {
+    pub host: String,
+    pub port: u16,
+    pub banner: String,
+    pub protocol_version: String,
+    pub software: Option<String>,
+    pub kex_algorithms: Vec<SshAlgorithm>,
+    pub host_key_algorithms: Vec<SshAlgorithm>,
+    pub encryption_algorithms: Vec<SshAlgorithm>,
+    pub mac_algorithms: Vec<SshAlgorithm>,
+    pub compression_algorithms: Vec<SshAlgorithm>,
+    pub host_keys: Vec<SshHostKey>,
+    pub strict_kex: bool,
+    pub terrapin_vulnerable: bool, // CVE-2023-48795
+    pub findings: Vec<String>,
+    pub errors: Vec<String>,
+    pub elapsed_ms: u64,
+}
+
+/// 服务器 KEXINIT 中的算法列表
+#[derive(Debug, Clone, Default)]
+struct KexInit {
+    kex: Vec<String>,
+    host_key: Vec<String>,
+    encryption_c2s: Vec<String>,
+    encryption_s2c: Vec<String>,
+    mac_c2s: Vec<String>,
+    mac_s2c: Vec<String>,
+    compression_c2s: Vec<String>,
+    compression_s2c: Vec<String>,
+}
+
+/// 扫描 SSH 服务器的主机密钥与支持的算法，并标记弱算法
+#[tauri::command]
+pub async fn scan_ssh(request: SshScanRequest) -> Result<SshScanResult, String> {
+    let host = request.host.trim().to_string();
+    if host.is_empty() {
+        return Err("主机地址不能为空".to_string());
+    }
+    let port = request.port.unwrap_or(22);
+    let timeout = Duration::from_secs(request.timeout_secs.unwrap_or(10).max(1));
+    let start = std::time::Instant::now();
+
+    let (banner, kexinit) = tokio::time::timeout(timeout, fetch_kexinit(&host, port))
+        .await
+        .map_err(|_| "连接超时".to_string())??;
+
+    // 每种主机密钥单独建立连接完成密钥交换，RSA 的多个签名算法共用同一密钥
+    let mut probe_algorithms: Vec<String> = Vec::new();
+    for algorithm in &kexinit.host_key {
+        if algorithm.contains("-cert-v01@openssh.com") {
+            continue;
+        }
+        let family = host_key_family(algorithm);
+        if !probe_algorithms
+            .iter()
+            .any(|a| host_key_family(a) == family)
+        {
+            probe_algorithms.push(algorithm.clone());
+        }
+    }
+
+    let mut errors = Vec::new();
+    let kex = PROBE_KEX
+        .iter()
+        .find(|k| kexinit.kex.iter().any(|s| s == *k))
+        .map(|k| k.to_string());
+    let mut host_keys = Vec::new();
+    match kex {
+        Some(kex) => {
+            let handles: Vec<_> = probe_algorithms
+                .into_iter()
+                .map(|algorithm| {
+                    let host = host.clone();
+                    let kex = kex.clone();
+                    let kexinit = kexinit.clone();
+                    tokio::spawn(async move {
+                        let result = tokio::time::timeout(
+                            timeout,
+                            fetch_host_key(&host, port, &kex, &algorithm, &kexinit),
+                        )
+                        .await
+                        .unwrap_or_else(|_| Err("连接超时".to_string()));
+                        (algorithm, result)
+                    })
+                })
+                .collect();
+            for handle in handles {
+                match handle.await {
+                    Ok((algorithm, Ok(blob))) => {
+                        host_keys.push(describe_host_key(&algorithm, &blob))
+                    }
+                    Ok((algorithm, Err(e))) => errors.push(format!("{}: {}", algorithm, e)),
+                    Err(e) => errors.push(format!("主机密钥探测失败: {}", e)),
+                }
+            }
+        }
+        None => errors.push("服务器不支持 curve25519/ECDH 密钥交换，无法获取主机密钥".to_string()),
+    }
+
+    let strict_kex = kexinit
+        .kex
+        .iter()
+        .any(|k| k == "kex-strict-s-v00@openssh.com");
+    let encryption = merge_lists(&kexinit.encryption_c2s, &kexinit.encryption_s2c);
+    let macs = merge_lists(&kexinit.mac_c2s, &kexinit.mac_s2c);
+    let compression = merge_lists(&kexinit.compression_c2s, &kexinit.compression_s2c);
+    let has_chacha = encryption
+        .iter()
+        .any(|c| c == "chacha20-poly1305@openssh.com");
+    let has_cbc_etm = encryption.iter().any(|c| c.ends_with("-cbc"))
+        && macs.iter().any(|m| m.contains("-etm@openssh.com"));
+    let terrapin_vulnerable = !strict_kex && (has_chacha || has_cbc_etm);
+
+    let (protocol_version, software) = parse_banner(&banner);
+    let kex_algorithms = assess_all(&kexinit.kex, assess_kex);
+    let host_key_algorithms = assess_all(&kexinit.host_key, assess_host_key);
+    let encryption_algorithms = assess_all(&encryption, assess_cipher);
+    let mac_algorithms = assess_all(&macs, assess_mac);
+    let compression_algorithms = assess_all(&compression, assess_compression);
+
+    let mut findings = Vec::new();
+    if protocol_version != "2.0" && protocol_version != "1.99" {
+        findings.push(format!(
+            "服务器使用 SSH 协议 {}，存在严重安全缺陷",
+            protocol_version
+        ));
+    }
+    if terrapin_vulnerable {
+        findings.push(
+            "支持 chacha20-poly1305 或 CBC+EtM 且未启用严格密钥交换，受 Terrapin 攻击影响 (CVE-2023-48795)"
+                .to_string(),
+        );
+    }
+    for (category, list) in [
+        ("密钥交换", &kex_algorithms),
+        ("主机密钥", &host_key_algorithms),
+        ("加密", &encryption_algorithms),
+        ("MAC", &mac_algorithms),
+    ] {
+        let failed: Vec<&str> = list
+            .iter()
+            .filter(|a| a.level == "fail")
+            .map(|a| a.name.as_str())
+            .collect();
+        if !failed.is_empty() {
+            findings.push(format!(
+                "{}算法中包含不安全的算法: {}",
+                category,
+                failed.join(", ")
+            ));
+        }
+    }
+    for key in host_keys.iter().filter(|k| k.level == "fail") {
+        findings.push(format!(
+            "主机密钥 {} 不安全: {}",
+            key.key_type,
+            key.note.clone().unwrap_or_default()
+        ));
+    }
+
+    Ok(SshScanResult {
+        host,
+        port,
+        banner,
+        protocol_version,
+        software,
+        kex_algorithms,
+        host_key_algorithms,
+        encryption_algorithms,
+        mac_algorithms,
+        compression_algorithms,
+        host_keys,
+        strict_kex,
+        terrapin_vulnerable,
+        findings,
+        errors,
+        elapsed_ms: start.elapsed().as_millis() as u64,
+    })
+}
+
+/// 交换版本标识并读取服务器的 KEXINIT
+async fn fetch_kexinit(host: &str, port: u16) -> Result<(String, KexInit), String> {
+    let mut stream = TcpStream::connect((host, port))
+        .await
+        .map_err(|e| format!("TCP 连接失败: {}", e))?;
+    let banner = exchange_banner(&mut stream).await?;
+    let payload = read_until(&mut stream, SSH_MSG_KEXINIT).await?;
+    Ok((banner, parse_kexinit(&payload)?))
+}
+
+/// 仅提供指定的主机密钥算法完成一次 ECDH 密钥交换，返回服务器主机密钥 blob
+async fn fetch_host_key(
+    host: &str,
+    port: u16,
+    kex: &str,
+    host_key_algorithm: &str,
+    server: &KexInit,
+) -> Result<Vec<u8>, String> {
+    let mut stream = TcpStream::connect((host, port))
+        .await
+        .map_err(|e| format!("TCP 连接失败: {}", e))?;
+    exchange_banner(&mut stream).await?;
+    read_until(&mut stream, SSH_MSG_KEXINIT).await?;
+
+    // 其余算法直接沿用服务器的列表，保证协商成功
+    let client = KexInit {
+        kex: vec![kex.to_string()],
+        host_key: vec![host_key_algorithm.to_string()],
+        ..server.clone()
+    };
+    write_packet(&mut stream, &build_kexinit(&client)?).await?;
+
+    let mut init = vec![SSH_MSG_KEX_ECDH_INIT];
+    SshKeyCodec::write_string(&mut init, &ephemeral_public_key(kex)?);
+    write_packet(&mut stream, &init).await?;
+
+    let reply = read_until(&mut stream, SSH_MSG_KEX_ECDH_REPLY).await?;
+    let mut reader = SshReader::new(&reply[1..]);
+    Ok(reader.read_string()?.to_vec())
+}
+
+async fn exchange_banner(stream: &mut TcpStream) -> Result<String, String> {
+    stream
+        .write_all(format!("{}\r\n", CLIENT_BANNER).as_bytes())
+        .await
+        .map_err(|e| format!("发送版本标识失败: {}", e))?;
+
+    // 服务器可能在版本标识前输出其他文本行
+    let mut line = Vec::new();
+    let mut total = 0;
+    loop {
+        let byte = stream
+            .read_u8()
+            .await
+            .map_err(|e| format!("读取版本标识失败: {}", e))?;
+        total += 1;
+        if total > MAX_BANNER_SIZE {
+            return Err("未收到 SSH 版本标识".to_string());
+        }
+        if byte != b'\n' {
+            line.push(byte);
+            continue;
+        }
+        let text = String::from_utf8_lossy(&line).trim_end().to_string();
+        if text.starts_with("SSH-") {
+            return Ok(text);
+        }
+        line.clear();
+    }
+}
+
+async fn read_packet(stream: &mut TcpStream) -> Result<Vec<u8>, String> {
+    let length = stream
+        .read_u32()
+        .await
+        .map_err(|e| format!("读取数据包失败: {}", e))? as usize;
+    if !(2..=MAX_PACKET_SIZE).contains(&length) {
+        return Err(format!("无效的数据包长度: {}", length));
+    }
+    let mut packet = vec![0u8; length];
+    stream
+        .read_exact(&mut packet)
+        .await
+        .map_err(|e| format!("读取数据包失败: {}", e))?;
+    let padding = packet[0] as usize;
+    if padding + 1 > length {
+        return Err("无效的数据包填充长度".to_string());
+    }
+    Ok(packet[1..length - padding].to_vec())
+}
+
+/// 读取数据包直到指定类型，跳过 IGNORE/DEBUG 消息
+async fn read_until(stream: &mut TcpStream, message: u8) -> Result<Vec<u8>, String> {
+    loop {
+        let payload = read_packet(stream).await?;
+        match payload.first() {
+            Some(&m) if m == message => return Ok(payload),
+            Some(&SSH_MSG_IGNORE) | Some(&SSH_MSG_DEBUG) => continue,
+            Some(&1) => return Err("服务器断开连接".to_string()),
+            Some(&m) => return Err(format!("收到意外的消息类型: {}", m)),
+            None => return Err("收到空数据包".to_string()),
+        }
+    }
+}
+
+async fn write_packet(stream: &mut TcpStream, payload: &[u8]) -> Result<(), String> {
+    // 未加密阶段按 8 字节对齐，填充至少 4 字节
+    let mut padding = 8 - (payload.len() + 5) % 8;
+    if padding < 4 {
+        padding += 8;
+    }
+    let mut packet = Vec::with_capacity(payload.len() + padding + 5);
+    SshKeyCodec::write_u32(&mut packet, (payload.len() + padding + 1) as u32);
+    packet.push(padding as u8);
+    packet.extend_from_slice(payload);
+    packet.resize(packet.len() + padding, 0);
+    stream
+        .write_all(&packet)
+        .await
+        .map_err(|e| format!("发送数据包失败: {}", e))
+}
+
+fn parse_kexinit(payload: &[u8]) -> Result<KexInit, String> {
+    if payload.first() != Some(&SSH_MSG_KEXINIT) || payload.len() < 17 {
+        return Err("无效的 KEXINIT 消息".to_string());
+    }
+    let mut reader = SshReader::new(&payload[17..]);
+    let mut next = || -> Result<Vec<String>, String> {
+        let list = reader.read_string()?;
+        Ok(String::from_utf8_lossy(list)
+            .split(',')
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_string())
+            .collect())
+    };
+    Ok(KexInit {
+        kex: next()?,
+        host_key: next()?,
+        encryption_c2s: next()?,
+        encryption_s2c: next()?,
+        mac_c2s: next()?,
+        mac_s2c: next()?,
+        compression_c2s: next()?,
+        compression_s2c: next()?,
+    })
+}
+
+fn build_kexinit(init: &KexInit) -> Result<Vec<u8>, String> {
+    let mut cookie = [0u8; 16];
+    openssl::rand::rand_bytes(&mut cookie).map_err(|e| format!("生成随机数失败: {}", e))?;
+    let mut payload = vec![SSH_MSG_KEXINIT];
+    payload.extend_from_slice(&cookie);
+    for list in [
+        &init.kex,
+        &init.host_key,
+        &init.encryption_c2s,
+        &init.encryption_s2c,
+        &init.mac_c2s,
+        &init.mac_s2c,
+        &init.compression_c2s,
+        &init.compression_s2c,
+    ] {
+        SshKeyCodec::write_string(&mut payload, list.join(",").as_bytes());
+    }
+    // languages c2s/s2c、first_kex_packet_follows、reserved
+    SshKeyCodec::write_string(&mut payload, b"");
+    SshKeyCodec::write_string(&mut payload, b"");
+    payload.push(0);
+    SshKeyCodec::write_u32(&mut payload, 0);
+    Ok(payload)
+}
+
+fn ephemeral_public_key(kex: &str) -> Result<Vec<u8>, String> {
+    let nid = match kex {
+        "ecdh-sha2-nistp256" => Nid::X9_62_PRIME256V1,
+        "ecdh-sha2-nistp384" => Nid::SECP384R1,
+        _ => {
+            let key = PKey::generate_x25519().map_err(|e| format!("生成临时密钥失败: {}", e))?;
+            return key
+                .raw_public_key()
+                .map_err(|e| format!("导出临时公钥失败: {}", e));
+        }
+    };
+    let group = EcGroup::from_curve_name(nid).map_err(|e| format!("加载曲线失败: {}", e))?;
+    let key = EcKey::generate(&group).map_err(|e| format!("生成临时密钥失败: {}", e))?;
+    let mut ctx = BigNumContext::new().map_err(|e| format!("初始化失败: {}", e))?;
+    key.public_key()
+        .to_bytes(&group, PointConversionForm::UNCOMPRESSED, &mut ctx)
+        .map_err(|e| format!("导出临时公钥失败: {}", e))
+}
+
+fn describe_host_key(algorithm: &str, blob: &[u8]) -> SshHostKey {
+    let key_type = SshReader::new(blob)
+        .read_string()
+        .map(|t| String::from_utf8_lossy(t).to_string())
+        .unwrap_or_else(|_| algorithm.to_string());
+    let bits = SshKeyCodec::blob_key_bits(blob);
+    let (level, note) = match (key_type.as_str(), bits) {
+        ("ssh-dss", _) => ("fail", Some("DSA 主机密钥已被弃用".to_string())),
+        ("ssh-rsa", Some(b)) if b < 2048 => ("fail", Some(format!("RSA 密钥仅 {} 位", b))),
+        ("ssh-rsa", Some(b)) if b < 3072 => (
+            "warn",
+            Some(format!("RSA 密钥 {} 位，建议使用 3072 位以上", b)),
+        ),
+        (t, _) if t.starts_with("ecdsa-sha2-nistp") => {
+            ("warn", Some("NIST 曲线存在潜在后门争议".to_string()))
+        }
+        _ => ("good", None),
+    };
+    SshHostKey {
+        algorithm: algorithm.to_string(),
+        fingerprint_sha256: SshKeyCodec::fingerprint_sha256(blob),
+        fingerprint_md5: SshKeyCodec::fingerprint_md5(blob),
+        public_key: format!("{} {}", key_type, general_purpose::STANDARD.encode(blob)),
+        key_type,
+        bits,
+        level: level.to_string(),
+        note,
+    }
+}
+
+fn host_key_family(algorithm: &str) -> &str {
+    match algorithm {
+        "ssh-rsa" | "rsa-sha2-256" | "rsa-sha2-512" => "ssh-rsa",
+        other => other,
+    }
+}
+
+fn parse_banner(banner: &str) -> (String, Option<String>) {
+    let rest = banner.strip_prefix("SSH-").unwrap_or(banner);
+    let mut parts = rest.splitn(2, '-');
+    let version = parts.next().unwrap_or_default().to_string();
+    let software = parts
+        .next()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty());
+    (version, software)
+}
+
+fn merge_lists(a: &[String], b: &[String]) -> Vec<String> {
+    let mut merged = a.to_vec();
+    for item in b {
+        if !merged.contains(item) {
+            merged.push(item.clone());
+        }
+    }
+    merged
+}
+
+fn assess_all(
+    list: &[String],
+    assess: fn(&str) -> (&'static str, Option<&'static str>),
+) -> Vec<SshAlgorithm> {
+    list.iter()
+        .map(|name| {
+            let (level, note) = assess(name);
+            SshAlgorithm {
+                name: name.clone(),
+                level: level.to_string(),
+                note: note.map(|n| n.to_string()),
+            }
+        })
+        .collect()
+}
+
+fn assess_kex(name: &str) -> (&'static str, Option<&'static str>) {
+    match name {
+        "diffie-hellman-group1-sha1" | "diffie-hellman-group-exchange-sha1" | "rsa1024-sha1" => {
+            ("fail", Some("使用 SHA-1 或过短的模数"))
+        }
+        "diffie-hellman-group14-sha1" => ("warn", Some("使用 SHA-1 哈希")),
+        n if n.starts_with("gss-") && n.contains("sha1") => ("warn", Some("使用 SHA-1 哈希")),
+        n if n.starts_with("ecdh-sha2-nistp") => ("warn", Some("NIST 曲线存在潜在后门争议")),
+        "ext-info-s" | "ext-info-c" | "kex-strict-s-v00@openssh.com" => {
+            ("info", Some("协议扩展标识"))
+        }
+        n if n.starts_with("sntrup761") || n.starts_with("mlkem768") => {
+            ("good", Some("抗量子混合密钥交换"))
+        }
+        _ => ("good", None),
+    }
+}
+
+fn assess_host_key(name: &str) -> (&'static str, Option<&'static str>) {
+    match name {
+        "ssh-dss" | "ssh-dss-cert-v01@openssh.com" => ("fail", Some("DSA 已被弃用")),
+        "ssh-rsa" | "ssh-rsa-cert-v01@openssh.com" => ("warn", Some("使用 SHA-1 签名")),
+        n if n.starts_with("ecdsa-sha2-nistp") => ("warn", Some("NIST 曲线存在潜在后门争议")),
+        _ => ("good", None),
+    }
+}
+
+fn assess_cipher(name: &str) -> (&'static str, Option<&'static str>) {
+    match name {
+        "none" => ("fail", Some("未加密")),
+        "3des-cbc" | "blowfish-cbc" | "cast128-cbc" | "des-cbc" | "rijndael-cbc@lysator.liu.se" => {
+            ("fail", Some("过时的分组密码"))
+        }
+        n if n.starts_with("arcfour") => ("fail", Some("RC4 已被攻破")),
+        n if n.ends_with("-cbc") => ("warn", Some("CBC 模式易受填充预言攻击")),
+        "chacha20-poly1305@openssh.com" => {
+            ("warn", Some("未启用严格密钥交换时受 Terrapin 攻击影响"))
+        }
+        _ => ("good", None),
+    }
+}
+
+fn assess_mac(name: &str) -> (&'static str, Option<&'static str>) {
+    match name {
+        "none" => ("fail", Some("无完整性保护")),
+        n if n.starts_with("hmac-md5") || n == "hmac-sha1-96" || n.starts_with("hmac-ripemd") => {
+            ("fail", Some("过时的哈希算法"))
+        }
+        n if n.starts_with("umac-64") => ("fail", Some("标签长度过短")),
+        n if n.contains("-etm@openssh.com") => {
+            if n.starts_with("hmac-sha1") {
+                ("warn", Some("使用 SHA-1 哈希"))
+            } else {
+                ("good", None)
+            }
+        }
+        n if n.starts_with("hmac-sha1") => {
+            ("warn", Some("使用 SHA-1 哈希且为 Encrypt-and-MAC 模式"))
+        }
+        _ => ("warn", Some("Encrypt-and-MAC 模式，建议使用 -etm 变体")),
+    }
+}
+
+fn assess_compression(name: &str) -> (&'static str, Option<&'static str>) {
+    match name {
+        "zlib" => ("warn", Some("认证前即启用压缩")),
+        _ => ("info", None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kexinit_roundtrip() {
+        let init = KexInit {
+            kex: vec![
+                "curve25519-sha256".to_string(),
+                "kex-strict-s-v00@openssh.com".to_string(),
+            ],
+            host_key: vec!["ssh-ed25519".to_string()],
+            encryption_c2s: vec!["aes256-gcm@openssh.com".to_string()],
+            encryption_s2c: vec!["aes256-gcm@openssh.com".to_string()],
+            mac_c2s: vec!["hmac-sha2-256-etm@openssh.com".to_string()],
+            mac_s2c: vec!["hmac-sha2-256-etm@openssh.com".to_string()],
+            compression_c2s: vec!["none".to_string()],
+            compression_s2c: vec!["none".to_string(), "zlib@openssh.com".to_string()],
+        };
+        let parsed = parse_kexinit(&build_kexinit(&init).unwrap()).unwrap();
+        assert_eq!(parsed.kex, init.kex);
+        assert_eq!(parsed.host_key, init.host_key);
+        assert_eq!(parsed.compression_s2c, init.compression_s2c);
+        assert_eq!(
+            merge_lists(&parsed.compression_c2s, &parsed.compression_s2c),
+            vec!["none", "zlib@openssh.com"]
+        );
+        assert_eq!(ephemeral_public_key("curve25519-sha256").unwrap().len(), 32);
+        assert_eq!(
+            ephemeral_public_key("ecdh-sha2-nistp256").unwrap().len(),
+            65
+        );
+    }
+
+    #[test]
+    fn test_algorithm_assessment() {
+        assert_eq!(assess_kex("diffie-hellman-group1-sha1").0, "fail");
+        assert_eq!(assess_kex("curve25519-sha256").0, "good");
+        assert_eq!(assess_cipher("aes128-cbc").0, "warn");
+        assert_eq!(assess_cipher("arcfour256").0, "fail");
+        assert_eq!(assess_mac("hmac-sha2-512-etm@openssh.com").0, "good");
+        assert_eq!(assess_mac("hmac-md5").0, "fail");
+        assert_eq!(assess_host_key("ssh-dss").0, "fail");
+        assert_eq!(host_key_family("rsa-sha2-512"), "ssh-rsa");
+        assert_eq!(
+            parse_banner("SSH-2.0-OpenSSH_9.6p1 Ubuntu-3ubuntu13"),
+            (
+                "2.0".to_string(),
+                Some("OpenSSH_9.6p1 Ubuntu-3ubuntu13".to_string())
+            )
+        );
+
+        let mut blob = Vec::new();
+        SshKeyCodec::write_string(&mut blob, b"ssh-ed25519");
+        SshKeyCodec::write_string(&mut blob, &[7u8; 32]);
+        let key = describe_host_key("ssh-ed25519", &blob);
+        assert_eq!(key.bits, Some(256));
+        assert_eq!(key.level, "good");
+        assert!(key.fingerprint_sha256.starts_with("SHA256:"));
+    }
+}
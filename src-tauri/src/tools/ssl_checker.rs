@@ -1,7 +1,12 @@
 use crate::tools::ssl_checker::oid_registry::Oid;
+use crate::tools::ssl_history;
+use crate::tools::trust_store::{self, TrustOverride};
+use crate::utils::dns::DnsClient;
+use crate::utils::{cert_path, i18n, proxy, rate_limit};
 use chrono_tz::Asia::Shanghai;
+use hickory_resolver::proto::rr::rdata::caa::{Property, Value as CaaValue, CAA};
+use hickory_resolver::proto::rr::{RData, RecordType};
 use rustls::pki_types::ServerName;
-use rustls::RootCertStore;
 use serde::{Deserialize, Serialize};
 use std::io::{BufRead, BufReader};
 use std::net::{IpAddr, SocketAddr, TcpStream};
@@ -11,7 +16,7 @@ use tokio_rustls::{rustls, TlsConnector};
 use x509_parser::prelude::*;
 use x509_parser::public_key::PublicKey;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CipherSuite {
     pub name: String,
     pub version: String,
@@ -19,7 +24,7 @@ pub struct CipherSuite {
     pub server_order: bool,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProtocolSupport {
     pub version: String,
     pub supported: bool,
@@ -30,7 +35,7 @@ pub struct ProtocolSupport {
     pub http3_support: Option<bool>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SecurityVulnerability {
     pub cve_id: String,
     pub name: String,
@@ -42,7 +47,7 @@ pub struct SecurityVulnerability {
     pub affected: bool,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SslCertificate {
     pub subject: String,
     pub issuer: String,
@@ -56,7 +61,7 @@ pub struct SslCertificate {
     pub san_domains: Option<Vec<String>>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CertificateChainNode {
     pub certificate: SslCertificate,
     pub is_root: bool,
@@ -65,7 +70,7 @@ pub struct CertificateChainNode {
     pub validation_errors: Vec<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CertificateChain {
     pub certificates: Vec<CertificateChainNode>,
     pub chain_length: u32,
@@ -73,9 +78,104 @@ pub struct CertificateChain {
     pub root_ca_info: Option<String>,
     pub chain_validation_status: String,
     pub chain_errors: Vec<String>,
+    pub trust_anchor: Option<String>, // 锚定链顶的信任库：webpki、system、custom，未找到时为空
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// 单个协议版本的握手耗时
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HandshakeTiming {
+    pub version: String,
+    pub supported: bool,
+    pub handshake_ms: Option<u64>,
+    pub cipher_suite: Option<String>,
+    pub error: Option<String>,
+}
+
+/// TLS 连接各阶段耗时与会话恢复能力
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TlsTiming {
+    pub dns_ms: Option<u64>,
+    pub tcp_connect_ms: Option<u64>,
+    pub handshakes: Vec<HandshakeTiming>,
+    pub resumed_handshake_ms: Option<u64>, // 会话恢复时的握手耗时
+    pub session_id_resumption: Option<bool>, // TLS 1.2 Session ID
+    pub session_ticket_resumption: Option<bool>, // TLS 1.2 Session Ticket，无法区分时为 None
+    pub tls13_resumption: Option<bool>,    // TLS 1.3 PSK 票据
+    pub early_data_supported: Option<bool>, // 0-RTT
+    pub max_early_data_size: Option<u32>,
+    pub ocsp_stapled: Option<bool>,
+    pub ocsp_responder: Option<String>,
+    pub ocsp_responder_ms: Option<u64>, // 未装订时客户端需额外查询 OCSP 的耗时
+    pub ocsp_error: Option<String>,
+}
+
+/// HSTS 响应头评估结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HstsPolicy {
+    pub present: bool,
+    pub header: Option<String>,
+    pub max_age: Option<u64>,
+    pub include_subdomains: bool,
+    pub preload: bool,
+    pub preload_status: Option<String>, // preloaded / pending / unknown，来自 hstspreload.org
+    pub issues: Vec<String>,
+}
+
+/// DNS CAA 记录评估结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CaaPolicy {
+    pub records: Vec<String>,
+    pub source_domain: Option<String>, // 实际生效的 CAA 记录所在域名（可能继承自上级域名）
+    pub issuers: Vec<String>,
+    pub wildcard_issuers: Vec<String>,
+    pub iodef: Vec<String>,
+    pub current_issuer_authorized: Option<bool>, // 当前证书颁发者是否在授权列表中，无法判断时为 None
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TlsaRecordInfo {
+    pub usage: u8,         // 0 PKIX-TA / 1 PKIX-EE / 2 DANE-TA / 3 DANE-EE
+    pub selector: u8,      // 0 完整证书 / 1 公钥
+    pub matching_type: u8, // 0 原始数据 / 1 SHA-256 / 2 SHA-512
+    pub data: String,
+    pub matches_certificate: Option<bool>,
+}
+
+/// DANE/TLSA 记录评估结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DanePolicy {
+    pub name: String,
+    pub records: Vec<TlsaRecordInfo>,
+    pub valid: Option<bool>, // 是否至少有一条记录与服务器证书匹配
+    pub dnssec_validated: bool,
+}
+
+/// SSL 检查选项
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SslCheckOptions {
+    pub all_endpoints: bool,    // 检查所有 A/AAAA 记录对应的地址
+    pub pin_ip: Option<String>, // 只检查指定的 IP
+    #[serde(default)]
+    pub trust: Option<TrustOverride>, // 本次检测的信任库覆盖
+    #[serde(default)]
+    pub save_history: bool, // 保存本次结果，供 diff_ssl_checks 比较
+}
+
+/// 单个 IP 端点的检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EndpointResult {
+    pub ip: String,
+    pub reachable: bool,
+    pub error: Option<String>,
+    pub certificate: Option<SslCertificate>,
+    pub validation_error: Option<String>,
+    pub tls_versions: Vec<String>,
+    pub cipher_suite: Option<String>,
+    pub alpn_protocol: Option<String>,
+    pub handshake_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SslInfo {
     pub domain: String,
     pub server_ip: Option<String>,
@@ -95,9 +195,14 @@ pub struct SslInfo {
     pub spdy_support: Option<bool>,
     pub http3_support: Option<bool>,
     pub alpn_protocols: Option<Vec<String>>,
+    pub timing: Option<TlsTiming>,
+    pub hsts: Option<HstsPolicy>,
+    pub caa: Option<CaaPolicy>,
+    pub dane: Option<DanePolicy>,
+    pub endpoints: Option<Vec<EndpointResult>>,
+    pub endpoint_inconsistencies: Option<Vec<String>>,
 }
 
-
 fn resolve_domain_ip(domain: &str) -> Result<IpAddr, String> {
     match dns_lookup::lookup_host(domain) {
         Ok(ips) => {
@@ -105,10 +210,10 @@ fn resolve_domain_ip(domain: &str) -> Result<IpAddr, String> {
             if let Some(ip) = ip_vec.first() {
                 Ok(*ip)
             } else {
-                Err("No IP address found for domain".to_string())
+                Err(i18n::t("ssl.no_ip_for_domain"))
             }
         }
-        Err(e) => Err(format!("DNS resolution failed: {}", e)),
+        Err(e) => Err(i18n::tr("ssl.dns_failed", &[&e.to_string()])),
     }
 }
 
@@ -180,7 +285,7 @@ fn get_server_info(domain: &str, port: u16) -> Option<String> {
 async fn get_https_server_info(domain: &str) -> Option<String> {
     // Make an HTTPS request to get server headers
     let url = format!("https://{}/", domain);
-    match reqwest::Client::builder()
+    match proxy::client_builder("ssl_checker")
         .timeout(Duration::from_secs(10))
         .danger_accept_invalid_certs(true) // We're just checking headers
         .build()
@@ -366,15 +471,14 @@ async fn check_tls_version_support(
                 Err(_) => return Ok(false),
             };
 
-            let mut root_store = RootCertStore::empty();
-            root_store.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+            let root_store = trust_store::default_root_store();
 
             // Create a config that only supports the specific version
             let version_config = rustls::ClientConfig::builder_with_provider(Arc::new(
                 rustls::crypto::ring::default_provider(),
             ))
             .with_protocol_versions(&[version.rustls_version])
-            .map_err(|_| "Unsupported protocol version".to_string())?
+            .map_err(|_| i18n::t("ssl.unsupported_protocol_version"))?
             .with_root_certificates(root_store.clone())
             .with_no_client_auth();
 
@@ -647,8 +751,7 @@ async fn check_http2_support(domain: &str, port: u16) -> Result<bool, String> {
         Err(_) => return Ok(false),
     };
 
-    let mut root_store = RootCertStore::empty();
-    root_store.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+    let root_store = trust_store::default_root_store();
 
     let config = rustls::ClientConfig::builder()
         .with_root_certificates(root_store)
@@ -668,7 +771,7 @@ async fn check_http2_support(domain: &str, port: u16) -> Result<bool, String> {
 
     // Check if HTTP/2 is supported by trying to make an HTTP/2 request
     let url = format!("https://{}:{}", domain, port);
-    match reqwest::Client::builder()
+    match proxy::client_builder("ssl_checker")
         .timeout(Duration::from_secs(10))
         .danger_accept_invalid_certs(true)
         .http2_prior_knowledge()
@@ -692,7 +795,7 @@ async fn check_spdy_support(domain: &str, port: u16) -> Result<bool, String> {
     // Most modern servers have disabled SPDY in favor of HTTP/2
     let url = format!("https://{}:{}", domain, port);
 
-    match reqwest::Client::builder()
+    match proxy::client_builder("ssl_checker")
         .timeout(Duration::from_secs(10))
         .danger_accept_invalid_certs(true)
         .build()
@@ -721,54 +824,11 @@ async fn check_spdy_support(domain: &str, port: u16) -> Result<bool, String> {
     }
 }
 
-async fn check_http3_support(domain: &str, _port: u16) -> Result<bool, String> {
-    // HTTP/3 检测通过多种方法:
-    // 1. 检查 Alt-Svc 头部是否支持 h3
-    // 2. 尝试 UDP QUIC 连接
-    // 3. 检查 DNS HTTPS 记录
-
-    // Method 1: Check Alt-Svc header for HTTP/3 (h3) support
-    let url = format!("https://{}/", domain);
-    match reqwest::Client::builder()
-        .timeout(Duration::from_secs(10))
-        .danger_accept_invalid_certs(true) // We're checking protocol support
-        .build()
-    {
-        Ok(client) => {
-            match client.head(&url).send().await {
-                Ok(response) => {
-                    // Check Alt-Svc header for HTTP/3 support
-                    if let Some(alt_svc) = response.headers().get("alt-svc") {
-                        if let Ok(alt_svc_str) = alt_svc.to_str() {
-                            // Look for h3, h3-29, h3-27, etc.
-                            return Ok(alt_svc_str.to_lowercase().contains("h3"));
-                        }
-                    }
-
-                    // Check for HTTP/3 indicators in other headers
-                    if let Some(server) = response.headers().get("server") {
-                        if let Ok(server_str) = server.to_str() {
-                            // Some servers advertise HTTP/3 capabilities in server headers
-                            if server_str.to_lowercase().contains("quic") || 
-                               server_str.to_lowercase().contains("h3") {
-                                return Ok(true);
-                            }
-                        }
-                    }
-
-                    // Check for QUIC indicators
-                    if response.headers().contains_key("quic-status") ||
-                       response.headers().contains_key("alt-used") {
-                        return Ok(true);
-                    }
-
-                    Ok(false)
-                }
-                Err(_) => Ok(false),
-            }
-        }
-        Err(_) => Ok(false),
-    }
+async fn check_http3_support(domain: &str, port: u16) -> Result<bool, String> {
+    // 通过真实的 QUIC 握手（ALPN h3）判断，Alt-Svc 可能缺失或已过期
+    Ok(crate::tools::http3_probe::quic_handshake(domain, port)
+        .await
+        .is_ok())
 }
 
 async fn check_alpn_support(domain: &str, port: u16) -> Result<Vec<String>, String> {
@@ -780,8 +840,7 @@ async fn check_alpn_support(domain: &str, port: u16) -> Result<Vec<String>, Stri
         Err(_) => return Ok(vec![]),
     };
 
-    let mut root_store = RootCertStore::empty();
-    root_store.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+    let root_store = trust_store::default_root_store();
 
     let config = rustls::ClientConfig::builder()
         .with_root_certificates(root_store)
@@ -827,35 +886,454 @@ async fn check_server_cipher_order(_domain: &str, _port: u16) -> Result<bool, St
     Ok(false) // Default: server honors its own order
 }
 
+/// 使用内置根证书和自定义信任库构建 TLS 连接器
+pub(crate) fn webpki_tls_connector() -> TlsConnector {
+    // Initialize crypto provider
+    let _ = rustls::crypto::ring::default_provider().install_default();
+
+    let root_store = trust_store::default_root_store();
+
+    let config = rustls::ClientConfig::builder()
+        .with_root_certificates(root_store)
+        .with_no_client_auth();
+
+    TlsConnector::from(Arc::new(config))
+}
+
+/// 包装 webpki 校验器，记录服务端是否装订了 OCSP 响应
+#[derive(Debug)]
+struct OcspRecordingVerifier {
+    inner: Arc<rustls::client::WebPkiServerVerifier>,
+    stapled: std::sync::Mutex<Option<bool>>,
+}
+
+impl rustls::client::danger::ServerCertVerifier for OcspRecordingVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &rustls::pki_types::CertificateDer<'_>,
+        intermediates: &[rustls::pki_types::CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: rustls::pki_types::UnixTime,
+    ) -> Result<rustls::client::danger::ServerCertVerified, rustls::Error> {
+        if let Ok(mut stapled) = self.stapled.lock() {
+            *stapled = Some(!ocsp_response.is_empty());
+        }
+        self.inner
+            .verify_server_cert(end_entity, intermediates, server_name, ocsp_response, now)
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &rustls::pki_types::CertificateDer<'_>,
+        dss: &rustls::DigitallySignedStruct,
+    ) -> Result<rustls::client::danger::HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls12_signature(message, cert, dss)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &rustls::pki_types::CertificateDer<'_>,
+        dss: &rustls::DigitallySignedStruct,
+    ) -> Result<rustls::client::danger::HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls13_signature(message, cert, dss)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<rustls::SignatureScheme> {
+        self.inner.supported_verify_schemes()
+    }
+}
+
+/// 包装会话缓存，记录 TLS 1.3 票据允许的 early data 大小
+#[derive(Debug)]
+struct EarlyDataRecordingStore {
+    inner: rustls::client::ClientSessionMemoryCache,
+    max_early_data_size: std::sync::Mutex<Option<u32>>,
+}
+
+impl rustls::client::ClientSessionStore for EarlyDataRecordingStore {
+    fn set_kx_hint(&self, server_name: ServerName<'static>, group: rustls::NamedGroup) {
+        self.inner.set_kx_hint(server_name, group)
+    }
+
+    fn kx_hint(&self, server_name: &ServerName<'_>) -> Option<rustls::NamedGroup> {
+        self.inner.kx_hint(server_name)
+    }
+
+    fn set_tls12_session(
+        &self,
+        server_name: ServerName<'static>,
+        value: rustls::client::Tls12ClientSessionValue,
+    ) {
+        self.inner.set_tls12_session(server_name, value)
+    }
+
+    fn tls12_session(
+        &self,
+        server_name: &ServerName<'_>,
+    ) -> Option<rustls::client::Tls12ClientSessionValue> {
+        self.inner.tls12_session(server_name)
+    }
+
+    fn remove_tls12_session(&self, server_name: &ServerName<'static>) {
+        self.inner.remove_tls12_session(server_name)
+    }
+
+    fn insert_tls13_ticket(
+        &self,
+        server_name: ServerName<'static>,
+        value: rustls::client::Tls13ClientSessionValue,
+    ) {
+        if let Ok(mut size) = self.max_early_data_size.lock() {
+            *size = Some(value.max_early_data_size());
+        }
+        self.inner.insert_tls13_ticket(server_name, value)
+    }
+
+    fn take_tls13_ticket(
+        &self,
+        server_name: &ServerName<'static>,
+    ) -> Option<rustls::client::Tls13ClientSessionValue> {
+        self.inner.take_tls13_ticket(server_name)
+    }
+}
+
+type TimedTlsStream = tokio_rustls::client::TlsStream<tokio::net::TcpStream>;
+
+/// 建立一次 TLS 连接，返回 (连接, TCP 建连耗时, 握手耗时)
+async fn timed_tls_connect(
+    addr: SocketAddr,
+    domain: &str,
+    config: Arc<rustls::ClientConfig>,
+) -> Result<(TimedTlsStream, Duration, Duration), String> {
+    let server_name =
+        ServerName::try_from(domain.to_string()).map_err(|_| i18n::t("ssl.invalid_server_name"))?;
+
+    let tcp_start = std::time::Instant::now();
+    let stream = tokio::time::timeout(
+        Duration::from_secs(10),
+        tokio::net::TcpStream::connect(addr),
+    )
+    .await
+    .map_err(|_| i18n::t("ssl.tcp_timeout"))?
+    .map_err(|e| i18n::tr("ssl.tcp_failed", &[&e.to_string()]))?;
+    let tcp_elapsed = tcp_start.elapsed();
+
+    let tls_start = std::time::Instant::now();
+    let tls_stream = tokio::time::timeout(
+        Duration::from_secs(10),
+        TlsConnector::from(config).connect(server_name, stream),
+    )
+    .await
+    .map_err(|_| i18n::t("ssl.tls_handshake_timeout"))?
+    .map_err(|e| i18n::tr("ssl.tls_handshake_failed", &[&e.to_string()]))?;
+
+    Ok((tls_stream, tcp_elapsed, tls_start.elapsed()))
+}
+
+/// 发送一个 HEAD 请求并读取响应，使 TLS 1.3 的 NewSessionTicket 得到处理
+async fn exchange_head_request(stream: &mut TimedTlsStream, domain: &str) {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let request = format!(
+        "HEAD / HTTP/1.1\r\nHost: {}\r\nUser-Agent: SSL-Checker/1.0\r\nConnection: close\r\n\r\n",
+        domain
+    );
+    if stream.write_all(request.as_bytes()).await.is_err() {
+        return;
+    }
+    let mut buf = [0u8; 4096];
+    let _ = tokio::time::timeout(Duration::from_secs(3), async {
+        while let Ok(n) = stream.read(&mut buf).await {
+            if n == 0 {
+                break;
+            }
+        }
+    })
+    .await;
+}
+
+/// 连续握手两次，判断第二次是否复用了会话
+async fn check_session_resumption(
+    addr: SocketAddr,
+    domain: &str,
+    config: Arc<rustls::ClientConfig>,
+) -> Result<(bool, Duration), String> {
+    let (mut first, _, _) = timed_tls_connect(addr, domain, config.clone()).await?;
+    exchange_head_request(&mut first, domain).await;
+
+    let (second, _, elapsed) = timed_tls_connect(addr, domain, config).await?;
+    let resumed = second.get_ref().1.handshake_kind() == Some(rustls::HandshakeKind::Resumed);
+    Ok((resumed, elapsed))
+}
+
+/// 向证书 AIA 中的 OCSP 响应器查询吊销状态，返回 (响应器地址, 耗时)
+async fn query_ocsp_responder(
+    leaf_der: &[u8],
+    issuer_der: &[u8],
+) -> Result<(String, Duration), String> {
+    use openssl::hash::MessageDigest;
+    use openssl::ocsp::{OcspCertId, OcspRequest, OcspResponse, OcspResponseStatus};
+    use openssl::x509::X509;
+
+    let leaf = X509::from_der(leaf_der)
+        .map_err(|e| i18n::tr("ssl.parse_certificate_failed", &[&e.to_string()]))?;
+    let issuer = X509::from_der(issuer_der)
+        .map_err(|e| i18n::tr("ssl.parse_issuer_failed", &[&e.to_string()]))?;
+    let responder = leaf
+        .ocsp_responders()
+        .ok()
+        .and_then(|urls| urls.iter().next().map(|url| url.to_string()))
+        .ok_or_else(|| i18n::t("ssl.no_ocsp_responder"))?;
+
+    let cert_id = OcspCertId::from_cert(MessageDigest::sha1(), &leaf, &issuer)
+        .map_err(|e| i18n::tr("ssl.build_ocsp_request_failed", &[&e.to_string()]))?;
+    let mut request = OcspRequest::new()
+        .map_err(|e| i18n::tr("ssl.build_ocsp_request_failed", &[&e.to_string()]))?;
+    request
+        .add_id(cert_id)
+        .map_err(|e| i18n::tr("ssl.build_ocsp_request_failed", &[&e.to_string()]))?;
+    let body = request
+        .to_der()
+        .map_err(|e| i18n::tr("ssl.build_ocsp_request_failed", &[&e.to_string()]))?;
+
+    let client = proxy::client_builder("ssl_checker")
+        .timeout(Duration::from_secs(10))
+        .build()
+        .map_err(|e| i18n::tr("common.http_client_failed", &[&e.to_string()]))?;
+    let start = std::time::Instant::now();
+    let response = client
+        .post(&responder)
+        .header("Content-Type", "application/ocsp-request")
+        .body(body)
+        .send()
+        .await
+        .map_err(|e| i18n::tr("common.request_failed", &["OCSP", &e.to_string()]))?
+        .bytes()
+        .await
+        .map_err(|e| i18n::tr("common.read_response_failed", &[&e.to_string()]))?;
+    let elapsed = start.elapsed();
+
+    let parsed = OcspResponse::from_der(&response)
+        .map_err(|e| i18n::tr("common.parse_response_failed", &["OCSP", &e.to_string()]))?;
+    if parsed.status() != OcspResponseStatus::SUCCESSFUL {
+        return Err(i18n::tr(
+            "ssl.ocsp_error_status",
+            &[&parsed.status().as_raw().to_string()],
+        ));
+    }
+    Ok((responder, elapsed))
+}
+
+/// 测量 DNS、TCP、各协议版本 TLS 握手耗时，以及会话恢复、0-RTT 与 OCSP 装订情况
+async fn measure_tls_timing(domain: &str, port: u16) -> Result<TlsTiming, String> {
+    let _ = rustls::crypto::ring::default_provider().install_default();
+
+    let lookup_domain = domain.to_string();
+    let dns_start = std::time::Instant::now();
+    let ip = tokio::task::spawn_blocking(move || resolve_domain_ip(&lookup_domain))
+        .await
+        .map_err(|e| i18n::tr("ssl.dns_failed", &[&e.to_string()]))??;
+    let dns_ms = dns_start.elapsed().as_millis() as u64;
+    let addr = SocketAddr::new(ip, port);
+
+    let root_store = trust_store::default_root_store();
+    let webpki = rustls::client::WebPkiServerVerifier::builder(Arc::new(root_store))
+        .build()
+        .map_err(|e| i18n::tr("ssl.build_verifier_failed", &[&e.to_string()]))?;
+    let verifier = Arc::new(OcspRecordingVerifier {
+        inner: webpki,
+        stapled: std::sync::Mutex::new(None),
+    });
+
+    let build_config = |version: &'static rustls::SupportedProtocolVersion,
+                        resumption: rustls::client::Resumption|
+     -> Result<Arc<rustls::ClientConfig>, String> {
+        let mut config = rustls::ClientConfig::builder_with_provider(Arc::new(
+            rustls::crypto::ring::default_provider(),
+        ))
+        .with_protocol_versions(&[version])
+        .map_err(|_| i18n::t("ssl.unsupported_protocol_version"))?
+        .dangerous()
+        .with_custom_certificate_verifier(verifier.clone())
+        .with_no_client_auth();
+        config.resumption = resumption;
+        Ok(Arc::new(config))
+    };
+
+    let mut timing = TlsTiming {
+        dns_ms: Some(dns_ms),
+        tcp_connect_ms: None,
+        handshakes: Vec::new(),
+        resumed_handshake_ms: None,
+        session_id_resumption: None,
+        session_ticket_resumption: None,
+        tls13_resumption: None,
+        early_data_supported: None,
+        max_early_data_size: None,
+        ocsp_stapled: None,
+        ocsp_responder: None,
+        ocsp_responder_ms: None,
+        ocsp_error: None,
+    };
+    let mut peer_chain: Vec<Vec<u8>> = Vec::new();
+
+    // TLS 1.3：首次握手 + PSK 票据恢复 + 0-RTT
+    let store = Arc::new(EarlyDataRecordingStore {
+        inner: rustls::client::ClientSessionMemoryCache::new(32),
+        max_early_data_size: std::sync::Mutex::new(None),
+    });
+    let tls13_config = build_config(
+        &rustls::version::TLS13,
+        rustls::client::Resumption::store(store.clone()),
+    )?;
+    match timed_tls_connect(addr, domain, tls13_config.clone()).await {
+        Ok((mut stream, tcp, handshake)) => {
+            let connection = stream.get_ref().1;
+            timing.tcp_connect_ms = Some(tcp.as_millis() as u64);
+            peer_chain = connection
+                .peer_certificates()
+                .map(|certs| certs.iter().map(|c| c.to_vec()).collect())
+                .unwrap_or_default();
+            timing.handshakes.push(HandshakeTiming {
+                version: "TLS 1.3".to_string(),
+                supported: true,
+                handshake_ms: Some(handshake.as_millis() as u64),
+                cipher_suite: connection
+                    .negotiated_cipher_suite()
+                    .map(|suite| format!("{:?}", suite.suite())),
+                error: None,
+            });
+            exchange_head_request(&mut stream, domain).await;
+
+            if let Ok((second, _, elapsed)) = timed_tls_connect(addr, domain, tls13_config).await {
+                let resumed =
+                    second.get_ref().1.handshake_kind() == Some(rustls::HandshakeKind::Resumed);
+                timing.tls13_resumption = Some(resumed);
+                if resumed {
+                    timing.resumed_handshake_ms = Some(elapsed.as_millis() as u64);
+                }
+            }
+            let max_early_data_size = store.max_early_data_size.lock().ok().and_then(|s| *s);
+            timing.max_early_data_size = max_early_data_size;
+            timing.early_data_supported = Some(max_early_data_size.unwrap_or(0) > 0);
+        }
+        Err(e) => timing.handshakes.push(HandshakeTiming {
+            version: "TLS 1.3".to_string(),
+            supported: false,
+            handshake_ms: None,
+            cipher_suite: None,
+            error: Some(e),
+        }),
+    }
+
+    // TLS 1.2：首次握手 + Session ID / Session Ticket 恢复
+    let id_only_config = build_config(
+        &rustls::version::TLS12,
+        rustls::client::Resumption::in_memory_sessions(32)
+            .tls12_resumption(rustls::client::Tls12Resumption::SessionIdOnly),
+    )?;
+    match timed_tls_connect(addr, domain, id_only_config.clone()).await {
+        Ok((stream, tcp, handshake)) => {
+            let connection = stream.get_ref().1;
+            timing.tcp_connect_ms.get_or_insert(tcp.as_millis() as u64);
+            if peer_chain.is_empty() {
+                peer_chain = connection
+                    .peer_certificates()
+                    .map(|certs| certs.iter().map(|c| c.to_vec()).collect())
+                    .unwrap_or_default();
+            }
+            timing.handshakes.push(HandshakeTiming {
+                version: "TLS 1.2".to_string(),
+                supported: true,
+                handshake_ms: Some(handshake.as_millis() as u64),
+                cipher_suite: connection
+                    .negotiated_cipher_suite()
+                    .map(|suite| format!("{:?}", suite.suite())),
+                error: None,
+            });
+            drop(stream);
+
+            let by_id = check_session_resumption(addr, domain, id_only_config)
+                .await
+                .ok();
+            let ticket_config = build_config(
+                &rustls::version::TLS12,
+                rustls::client::Resumption::in_memory_sessions(32),
+            )?;
+            let by_ticket_or_id = check_session_resumption(addr, domain, ticket_config)
+                .await
+                .ok();
+
+            timing.session_id_resumption = by_id.map(|(resumed, _)| resumed);
+            // 仅 Session ID 无法恢复而允许票据时能恢复，才能确认支持 Session Ticket
+            timing.session_ticket_resumption = match (by_id, by_ticket_or_id) {
+                (_, Some((false, _))) => Some(false),
+                (Some((false, _)), Some((true, _))) => Some(true),
+                _ => None,
+            };
+            if timing.resumed_handshake_ms.is_none() {
+                timing.resumed_handshake_ms = [by_id, by_ticket_or_id]
+                    .into_iter()
+                    .flatten()
+                    .find(|(resumed, _)| *resumed)
+                    .map(|(_, elapsed)| elapsed.as_millis() as u64);
+            }
+        }
+        Err(e) => timing.handshakes.push(HandshakeTiming {
+            version: "TLS 1.2".to_string(),
+            supported: false,
+            handshake_ms: None,
+            cipher_suite: None,
+            error: Some(e),
+        }),
+    }
+
+    timing.ocsp_stapled = verifier.stapled.lock().ok().and_then(|s| *s);
+    if peer_chain.len() >= 2 {
+        match query_ocsp_responder(&peer_chain[0], &peer_chain[1]).await {
+            Ok((responder, elapsed)) => {
+                timing.ocsp_responder = Some(responder);
+                timing.ocsp_responder_ms = Some(elapsed.as_millis() as u64);
+            }
+            Err(e) => timing.ocsp_error = Some(e),
+        }
+    } else if !peer_chain.is_empty() {
+        timing.ocsp_error = Some(i18n::t("ssl.ocsp_missing_intermediate"));
+    }
+
+    if timing.handshakes.iter().all(|h| !h.supported) {
+        return Err(i18n::t("ssl.handshakes_failed"));
+    }
+    Ok(timing)
+}
+
 async fn check_tls_connection(
     domain: &str,
     port: u16,
+    trust: Option<&TrustOverride>,
 ) -> Result<(Vec<u8>, Vec<CipherSuite>, Vec<Vec<u8>>), String> {
-    // Initialize crypto provider
-    let _ = rustls::crypto::ring::default_provider().install_default();
-
     let server_name = match ServerName::try_from(domain.to_string()) {
         Ok(name) => name,
-        Err(_) => return Err("Invalid domain name".to_string()),
+        Err(_) => return Err(i18n::t("ssl.invalid_server_name")),
     };
 
-    let mut root_store = RootCertStore::empty();
-    root_store.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
-
+    let _ = rustls::crypto::ring::default_provider().install_default();
     let config = rustls::ClientConfig::builder()
-        .with_root_certificates(root_store)
+        .with_root_certificates(trust_store::root_store(trust)?)
         .with_no_client_auth();
-
     let connector = TlsConnector::from(Arc::new(config));
 
     let stream = match tokio::net::TcpStream::connect(format!("{}:{}", domain, port)).await {
         Ok(stream) => stream,
-        Err(e) => return Err(format!("TCP connection failed: {}", e)),
+        Err(e) => return Err(i18n::tr("ssl.tcp_failed", &[&e.to_string()])),
     };
 
     let tls_stream = match connector.connect(server_name, stream).await {
         Ok(stream) => stream,
-        Err(e) => return Err(format!("TLS connection failed: {}", e)),
+        Err(e) => return Err(i18n::tr("ssl.tls_handshake_failed", &[&e.to_string()])),
     };
 
     // Get connection info and cipher suites
@@ -910,43 +1388,62 @@ async fn check_tls_connection(
         }
     }
 
-    Err("No certificate found".to_string())
+    Err(i18n::t("ssl.no_certificate"))
 }
 
-fn build_certificate_chain(cert_chain_ders: &[Vec<u8>]) -> Result<CertificateChain, String> {
+fn build_certificate_chain(
+    cert_chain_ders: &[Vec<u8>],
+    trust: Option<&TrustOverride>,
+) -> Result<CertificateChain, String> {
     let mut certificates = Vec::new();
     let mut chain_errors = Vec::new();
 
     if cert_chain_ders.is_empty() {
-        return Err("No certificates in chain".to_string());
+        return Err(i18n::t("ssl.empty_chain"));
     }
 
-    for (index, cert_der) in cert_chain_ders.iter().enumerate() {
-        match parse_certificate(cert_der) {
+    // 按 AKI/SKI 和签名验证构建路径，服务器发送的顺序和名称文本都不作为依据
+    let path = cert_path::build_path(cert_chain_ders, Some(0), &|der| {
+        trust_store::anchor_source(der, trust).is_some()
+    });
+    chain_errors.extend(path.errors.iter().cloned());
+
+    // 链顶证书由信任库中的 CA 签名即视为链完整，服务器通常不发送根证书
+    let trust_anchor = path
+        .top()
+        .and_then(|top| trust_store::anchor_source(&cert_chain_ders[top], trust));
+    let trusted = trust_anchor.is_some();
+
+    for index in path.order.iter().chain(path.unlinked.iter()).copied() {
+        match parse_certificate(&cert_chain_ders[index]) {
             Ok(cert) => {
                 let is_leaf = index == 0;
-                let is_root = index == cert_chain_ders.len() - 1;
-
-                // Determine trust status based on certificate position and properties
-                let trust_status = if is_leaf {
-                    "end-entity".to_string() // The server certificate is an end-entity certificate
-                } else if is_root && cert.subject == cert.issuer {
-                    "self-signed".to_string()
-                } else if is_root {
-                    "root-ca".to_string()
+                let is_root = path.top() == Some(index);
+                let linked = path.order.contains(&index);
+
+                let trust_status = if !linked {
+                    "untrusted".to_string()
+                } else if trusted {
+                    "trusted".to_string()
+                } else if path.is_complete() {
+                    "self-signed".to_string() // 终止于不在信任库中的自签名证书
                 } else {
-                    "intermediate".to_string() // Only middle certificates in the chain are intermediate CAs
+                    "untrusted".to_string()
                 };
 
                 let mut validation_errors = Vec::new();
 
+                if !linked {
+                    chain_errors.push(i18n::tr("ssl.cert_not_linked", &[&(index + 1).to_string()]));
+                }
+
                 // Basic certificate validation
                 if let Ok(valid_to) = chrono::DateTime::parse_from_rfc3339(&cert.valid_to) {
                     let now = chrono::Utc::now();
                     let valid_to_utc = valid_to.with_timezone(&chrono::Utc);
 
                     if valid_to_utc < now {
-                        validation_errors.push("证书已过期".to_string());
+                        validation_errors.push(i18n::t("ssl.certificate_expired"));
                     }
                 }
 
@@ -955,20 +1452,21 @@ fn build_certificate_chain(cert_chain_ders: &[Vec<u8>]) -> Result<CertificateCha
                     let valid_from_utc = valid_from.with_timezone(&chrono::Utc);
 
                     if valid_from_utc > now {
-                        validation_errors.push("证书尚未生效".to_string());
+                        validation_errors.push(i18n::t("ssl.cert_not_yet_valid"));
                     }
                 }
 
                 // Check signature algorithm
                 if cert.signature_algorithm.to_lowercase().contains("sha1") {
-                    validation_errors.push("使用已弃用的SHA-1签名算法".to_string());
+                    validation_errors.push(i18n::t("ssl.sha1_deprecated"));
                 }
 
                 // Check key size for RSA
                 if cert.public_key_algorithm == "RSA" {
                     if let Some(key_size) = cert.key_size {
                         if key_size < 2048 {
-                            validation_errors.push(format!("RSA密钥长度过短: {} 位", key_size));
+                            validation_errors
+                                .push(i18n::tr("ssl.rsa_key_too_short", &[&key_size.to_string()]));
                         }
                     }
                 }
@@ -982,31 +1480,21 @@ fn build_certificate_chain(cert_chain_ders: &[Vec<u8>]) -> Result<CertificateCha
                 });
             }
             Err(e) => {
-                chain_errors.push(format!("解析证书 {} 时出错: {}", index + 1, e));
+                chain_errors.push(i18n::tr(
+                    "ssl.chain_parse_failed",
+                    &[&(index + 1).to_string(), &e],
+                ));
             }
         }
     }
 
-    // Validate chain continuity
-    for i in 0..certificates.len() - 1 {
-        let current_cert = &certificates[i];
-        let next_cert = &certificates[i + 1];
-
-        if current_cert.certificate.issuer != next_cert.certificate.subject {
-            chain_errors.push(format!(
-                "证书链中断: 证书 {} 的颁发者与证书 {} 的主体不匹配",
-                i + 1,
-                i + 2
-            ));
+    if !path.is_complete() {
+        if let Some(top) = path.top() {
+            chain_errors.push(i18n::tr("ssl.chain_incomplete", &[&(top + 1).to_string()]));
         }
     }
 
-    let is_complete = if let Some(last_cert) = certificates.last() {
-        // Check if the last certificate is self-signed (root CA)
-        last_cert.certificate.subject == last_cert.certificate.issuer
-    } else {
-        false
-    };
+    let is_complete = path.is_complete();
 
     let chain_validation_status =
         if chain_errors.is_empty() && certificates.iter().all(|c| c.validation_errors.is_empty()) {
@@ -1017,16 +1505,17 @@ fn build_certificate_chain(cert_chain_ders: &[Vec<u8>]) -> Result<CertificateCha
             "invalid".to_string()
         };
 
-    let root_ca_info = certificates.last().map(|cert| {
-        format!(
+    let root_ca_info = path.top().and_then(|top| {
+        let node = certificates.iter().find(|node| node.is_root)?;
+        Some(format!(
             "{} ({})",
-            cert.certificate.subject,
-            if cert.certificate.subject == cert.certificate.issuer {
-                "自签名"
+            node.certificate.subject,
+            if path.self_signed[top] {
+                i18n::t("ssl.self_signed")
             } else {
-                "根CA"
+                i18n::t("ssl.root_ca")
             }
-        )
+        ))
     });
 
     Ok(CertificateChain {
@@ -1036,12 +1525,13 @@ fn build_certificate_chain(cert_chain_ders: &[Vec<u8>]) -> Result<CertificateCha
         root_ca_info,
         chain_validation_status,
         chain_errors,
+        trust_anchor,
     })
 }
 
 fn parse_certificate(cert_der: &[u8]) -> Result<SslCertificate, String> {
-    let (_rem, cert) =
-        parse_x509_certificate(cert_der).map_err(|e| format!("Certificate parse error: {}", e))?;
+    let (_rem, cert) = parse_x509_certificate(cert_der)
+        .map_err(|e| i18n::tr("ssl.parse_certificate_failed", &[&e.to_string()]))?;
 
     let subject = cert.subject().to_string();
     let issuer = cert.issuer().to_string();
@@ -1137,14 +1627,10 @@ fn detect_cve_vulnerabilities(
     vulnerabilities.push(SecurityVulnerability {
         cve_id: "CVE-2014-0160".to_string(),
         name: "Heartbleed".to_string(),
-        description:
-            "OpenSSL TLS 心跳扩展信息泄露漏洞。攻击者可以从服务器或客户端读取最多 64KB 的内存数据。"
-                .to_string(),
+        description: i18n::t("ssl.vuln.heartbleed.description"),
         severity: "CRITICAL".to_string(),
         affected_components: vec!["OpenSSL 1.0.1 - 1.0.1f".to_string()],
-        remediation:
-            "将 OpenSSL 更新到 1.0.1g 或更高版本。对于 Web 服务器，更新后重启服务并重新颁发证书。"
-                .to_string(),
+        remediation: i18n::t("ssl.vuln.heartbleed.remediation"),
         references: vec![
             "https://heartbleed.com/".to_string(),
             "https://nvd.nist.gov/vuln/detail/CVE-2014-0160".to_string(),
@@ -1161,18 +1647,19 @@ fn detect_cve_vulnerabilities(
         vulnerabilities.push(SecurityVulnerability {
             cve_id: "CVE-2014-3566".to_string(),
             name: "POODLE".to_string(),
-            description:
-                "降级传统加密填充甲骨文漏洞。攻击者可以通过向目标服务器发起多次请求来解密密文。"
-                    .to_string(),
-            severity: if poodle_ssl3_affected { "HIGH" } else { "MEDIUM" }.to_string(),
+            description: i18n::t("ssl.vuln.poodle.description"),
+            severity: if poodle_ssl3_affected {
+                "HIGH"
+            } else {
+                "MEDIUM"
+            }
+            .to_string(),
             affected_components: if poodle_ssl3_affected {
                 vec!["SSL 3.0".to_string()]
             } else {
-                vec!["TLS 1.0 with CBC ciphers".to_string()]
+                vec![i18n::t("ssl.vuln.tls10_cbc_component")]
             },
-            remediation:
-                "禁用 SSL 3.0 和 TLS 1.0 中的 CBC 加密套件。使用 TLS 1.2 或更高版本。配置服务器拒绝 SSL/TLS 降级。"
-                    .to_string(),
+            remediation: i18n::t("ssl.vuln.poodle.remediation"),
             references: vec![
                 "https://poodle.io/".to_string(),
                 "https://nvd.nist.gov/vuln/detail/CVE-2014-3566".to_string(),
@@ -1188,10 +1675,10 @@ fn detect_cve_vulnerabilities(
         vulnerabilities.push(SecurityVulnerability {
             cve_id: "CVE-2011-3389".to_string(),
             name: "BEAST".to_string(),
-            description: "针对 SSL/TLS 的浏览器漏洞利用。攻击者可以通过利用 CBC 模式加密套件的弱点来解密 HTTPS 流量。".to_string(),
+            description: i18n::t("ssl.vuln.beast.description"),
             severity: "MEDIUM".to_string(),
-            affected_components: vec!["TLS 1.0 with CBC ciphers".to_string()],
-            remediation: "使用 TLS 1.1 或更高版本，或在 TLS 1.0 中优先使用 RC4/AEAD 加密套件并实施 1/n-1 record splitting。".to_string(),
+            affected_components: vec![i18n::t("ssl.vuln.tls10_cbc_component")],
+            remediation: i18n::t("ssl.vuln.beast.remediation"),
             references: vec!["https://nvd.nist.gov/vuln/detail/CVE-2011-3389".to_string()],
             affected: true,
         });
@@ -1205,14 +1692,10 @@ fn detect_cve_vulnerabilities(
         vulnerabilities.push(SecurityVulnerability {
             cve_id: "CVE-2012-4929".to_string(),
             name: "CRIME".to_string(),
-            description:
-                "压缩率信息泄露漏洞。攻击者可以通过观察压缩数据大小来恢复加密会话中的明文内容。"
-                    .to_string(),
+            description: i18n::t("ssl.vuln.crime.description"),
             severity: "HIGH".to_string(),
-            affected_components: vec!["TLS/SSL 压缩".to_string()],
-            remediation:
-                "禁用 TLS/SSL 压缩。Apache 配置：SSLCompression Off。Nginx 配置：ssl_compression off;"
-                    .to_string(),
+            affected_components: vec![i18n::t("ssl.vuln.crime.component")],
+            remediation: i18n::t("ssl.vuln.crime.remediation"),
             references: vec!["https://nvd.nist.gov/vuln/detail/CVE-2012-4929".to_string()],
             affected: true,
         });
@@ -1226,12 +1709,10 @@ fn detect_cve_vulnerabilities(
         vulnerabilities.push(SecurityVulnerability {
             cve_id: "CVE-2015-0204".to_string(),
             name: "FREAK".to_string(),
-            description: "RSA 导出密钥分解攻击。攻击者可以通过强制降级到出口级加密来拦截连接。"
-                .to_string(),
+            description: i18n::t("ssl.vuln.freak.description"),
             severity: "HIGH".to_string(),
-            affected_components: vec!["出口级 RSA 加密套件".to_string()],
-            remediation: "从服务器配置中移除所有出口级加密套件。更新 OpenSSL 到补丁版本。"
-                .to_string(),
+            affected_components: vec![i18n::t("ssl.vuln.freak.component")],
+            remediation: i18n::t("ssl.vuln.freak.remediation"),
             references: vec![
                 "https://freakattack.com/".to_string(),
                 "https://nvd.nist.gov/vuln/detail/CVE-2015-0204".to_string(),
@@ -1249,11 +1730,14 @@ fn detect_cve_vulnerabilities(
         vulnerabilities.push(SecurityVulnerability {
             cve_id: "CVE-2015-4000".to_string(),
             name: "Logjam".to_string(),
-            description: "弱 Diffie-Hellman 密钥交换漏洞。攻击者可以破解 DH 密钥交换并解密流量。".to_string(),
+            description: i18n::t("ssl.vuln.logjam.description"),
             severity: "HIGH".to_string(),
-            affected_components: vec!["出口级 DH 参数".to_string()],
-            remediation: "使用 2048 位或更大的 DH 参数。禁用出口级加密套件。生成新的 DH 参数：openssl dhparam -out dhparams.pem 2048".to_string(),
-            references: vec!["https://weakdh.org/".to_string(), "https://nvd.nist.gov/vuln/detail/CVE-2015-4000".to_string()],
+            affected_components: vec![i18n::t("ssl.vuln.logjam.component")],
+            remediation: i18n::t("ssl.vuln.logjam.remediation"),
+            references: vec![
+                "https://weakdh.org/".to_string(),
+                "https://nvd.nist.gov/vuln/detail/CVE-2015-4000".to_string(),
+            ],
             affected: true,
         });
     }
@@ -1264,13 +1748,10 @@ fn detect_cve_vulnerabilities(
         vulnerabilities.push(SecurityVulnerability {
             cve_id: "CVE-2016-0800".to_string(),
             name: "DROWN".to_string(),
-            description:
-                "使用过时和弱化加密解密 RSA。攻击者可以通过利用 SSL 2.0 漏洞来破解 TLS 连接。"
-                    .to_string(),
+            description: i18n::t("ssl.vuln.drown.description"),
             severity: "CRITICAL".to_string(),
             affected_components: vec!["SSL 2.0".to_string()],
-            remediation: "完全禁用 SSL 2.0。更新 OpenSSL 到补丁版本。检查并移除所有 SSL 2.0 配置。"
-                .to_string(),
+            remediation: i18n::t("ssl.vuln.drown.remediation"),
             references: vec![
                 "https://drownattack.com/".to_string(),
                 "https://nvd.nist.gov/vuln/detail/CVE-2016-0800".to_string(),
@@ -1287,13 +1768,10 @@ fn detect_cve_vulnerabilities(
         vulnerabilities.push(SecurityVulnerability {
             cve_id: "CVE-2016-2183".to_string(),
             name: "Sweet32".to_string(),
-            description:
-                "针对 64 位分组密码的生日攻击。当使用相同密钥加密大量数据时，攻击者可以恢复明文。"
-                    .to_string(),
+            description: i18n::t("ssl.vuln.sweet32.description"),
             severity: "MEDIUM".to_string(),
-            affected_components: vec!["3DES/DES 加密套件".to_string()],
-            remediation: "禁用 3DES 和 DES 加密套件。改用 AES-GCM 或 ChaCha20-Poly1305。"
-                .to_string(),
+            affected_components: vec![i18n::t("ssl.vuln.sweet32.component")],
+            remediation: i18n::t("ssl.vuln.sweet32.remediation"),
             references: vec![
                 "https://sweet32.info/".to_string(),
                 "https://nvd.nist.gov/vuln/detail/CVE-2016-2183".to_string(),
@@ -1310,10 +1788,10 @@ fn detect_cve_vulnerabilities(
         vulnerabilities.push(SecurityVulnerability {
             cve_id: "CVE-2013-2566".to_string(),
             name: "RC4 NOMORE".to_string(),
-            description: "RC4 不再安全。多个漏洞允许从 RC4 加密的流量中恢复明文。".to_string(),
+            description: i18n::t("ssl.vuln.rc4.description"),
             severity: "HIGH".to_string(),
-            affected_components: vec!["RC4 加密套件".to_string()],
-            remediation: "禁用所有 RC4 加密套件。改用 AES-GCM 或 ChaCha20-Poly1305。".to_string(),
+            affected_components: vec![i18n::t("ssl.vuln.rc4.component")],
+            remediation: i18n::t("ssl.vuln.rc4.remediation"),
             references: vec![
                 "https://www.rc4nomore.com/".to_string(),
                 "https://nvd.nist.gov/vuln/detail/CVE-2013-2566".to_string(),
@@ -1333,11 +1811,13 @@ fn detect_cve_vulnerabilities(
     if weak_key_affected {
         vulnerabilities.push(SecurityVulnerability {
             cve_id: "WEAK-KEY-SIZE".to_string(),
-            name: "弱密钥长度".to_string(),
-            description: format!(
-                "{}密钥长度{}位不符合当前安全标准",
-                cert.public_key_algorithm,
-                cert.key_size.unwrap_or(0)
+            name: i18n::t("ssl.vuln.weak_key.name"),
+            description: i18n::tr(
+                "ssl.vuln.weak_key.description",
+                &[
+                    &cert.public_key_algorithm,
+                    &cert.key_size.unwrap_or(0).to_string(),
+                ],
             ),
             severity: if cert.key_size.unwrap_or(0) < 1024 {
                 "CRITICAL"
@@ -1345,18 +1825,17 @@ fn detect_cve_vulnerabilities(
                 "HIGH"
             }
             .to_string(),
-            affected_components: vec![format!(
-                "{} 密钥 {} 位",
-                cert.public_key_algorithm,
-                cert.key_size.unwrap_or(0)
+            affected_components: vec![i18n::tr(
+                "ssl.vuln.weak_key.component",
+                &[
+                    &cert.public_key_algorithm,
+                    &cert.key_size.unwrap_or(0).to_string(),
+                ],
             )],
             remediation: match cert.public_key_algorithm.as_str() {
-                "RSA" => "生成至少2048位的新RSA密钥。推荐3072位或4096位以获得更好的长期安全性。"
-                    .to_string(),
-                "EC" | "ECDSA" => {
-                    "使用至少256位的ECC密钥（等效于2048位RSA）。推荐384位或521位曲线。".to_string()
-                }
-                _ => "使用符合当前安全标准的密钥长度。".to_string(),
+                "RSA" => i18n::t("ssl.vuln.weak_key.remediation_rsa"),
+                "EC" | "ECDSA" => i18n::t("ssl.vuln.weak_key.remediation_ecc"),
+                _ => i18n::t("ssl.vuln.weak_key.remediation_other"),
             },
             references: vec!["https://www.keylength.com/".to_string()],
             affected: true,
@@ -1367,11 +1846,11 @@ fn detect_cve_vulnerabilities(
     if ssl_versions.contains(&"SSL 2.0".to_string()) && !drown_affected {
         vulnerabilities.push(SecurityVulnerability {
             cve_id: "DEPRECATED-SSL2".to_string(),
-            name: "过时的 SSL 2.0 协议".to_string(),
-            description: "SSL 2.0 已过时且包含多个已知漏洞。".to_string(),
+            name: i18n::t("ssl.vuln.ssl2.name"),
+            description: i18n::t("ssl.vuln.ssl2.description"),
             severity: "CRITICAL".to_string(),
             affected_components: vec!["SSL 2.0".to_string()],
-            remediation: "完全禁用 SSL 2.0。使用 TLS 1.2 或更高版本。".to_string(),
+            remediation: i18n::t("ssl.vuln.ssl2.remediation"),
             references: vec!["https://tools.ietf.org/html/rfc6176".to_string()],
             affected: true,
         });
@@ -1380,11 +1859,11 @@ fn detect_cve_vulnerabilities(
     if ssl_versions.contains(&"SSL 3.0".to_string()) && !poodle_ssl3_affected {
         vulnerabilities.push(SecurityVulnerability {
             cve_id: "DEPRECATED-SSL3".to_string(),
-            name: "过时的 SSL 3.0 协议".to_string(),
-            description: "SSL 3.0 已过时且包含多个已知漏洞，包括 POODLE。".to_string(),
+            name: i18n::t("ssl.vuln.ssl3.name"),
+            description: i18n::t("ssl.vuln.ssl3.description"),
             severity: "HIGH".to_string(),
             affected_components: vec!["SSL 3.0".to_string()],
-            remediation: "完全禁用 SSL 3.0。使用 TLS 1.2 或更高版本。".to_string(),
+            remediation: i18n::t("ssl.vuln.ssl3.remediation"),
             references: vec!["https://tools.ietf.org/html/rfc7568".to_string()],
             affected: true,
         });
@@ -1393,13 +1872,11 @@ fn detect_cve_vulnerabilities(
     if ssl_versions.contains(&"TLS 1.0".to_string()) && !beast_affected && !poodle_tls_affected {
         vulnerabilities.push(SecurityVulnerability {
             cve_id: "WEAK-TLS10".to_string(),
-            name: "弱 TLS 1.0 协议".to_string(),
-            description: "TLS 1.0 被认为较弱，应该弃用以支持更新的TLS版本。".to_string(),
+            name: i18n::t("ssl.vuln.tls10.name"),
+            description: i18n::t("ssl.vuln.tls10.description"),
             severity: "MEDIUM".to_string(),
             affected_components: vec!["TLS 1.0".to_string()],
-            remediation:
-                "禁用 TLS 1.0 并使用 TLS 1.2 或更高版本。如果必须支持，请实施相应的缓解措施。"
-                    .to_string(),
+            remediation: i18n::t("ssl.vuln.tls10.remediation"),
             references: vec!["https://tools.ietf.org/html/rfc7457".to_string()],
             affected: true,
         });
@@ -1408,11 +1885,11 @@ fn detect_cve_vulnerabilities(
     if ssl_versions.contains(&"TLS 1.1".to_string()) {
         vulnerabilities.push(SecurityVulnerability {
             cve_id: "WEAK-TLS11".to_string(),
-            name: "弱 TLS 1.1 协议".to_string(),
-            description: "TLS 1.1 被认为较弱，应该弃用以支持 TLS 1.2 或更高版本。".to_string(),
+            name: i18n::t("ssl.vuln.tls11.name"),
+            description: i18n::t("ssl.vuln.tls11.description"),
             severity: "LOW".to_string(),
             affected_components: vec!["TLS 1.1".to_string()],
-            remediation: "禁用 TLS 1.1 并使用 TLS 1.2 或更高版本以获得更好的安全性。".to_string(),
+            remediation: i18n::t("ssl.vuln.tls11.remediation"),
             references: vec!["https://tools.ietf.org/html/rfc8996".to_string()],
             affected: true,
         });
@@ -1421,7 +1898,7 @@ fn detect_cve_vulnerabilities(
     vulnerabilities
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SslLabsRating {
     pub grade: String,
     pub score: u32,
@@ -1478,19 +1955,19 @@ fn calculate_ssl_labs_rating(
     // Determine warnings and errors
     if critical_count > 0 || protocol_score < 20 || certificate_score < 20 {
         has_errors = true;
-        details.push("存在严重安全问题".to_string());
+        details.push(i18n::t("ssl.rating_errors"));
     }
 
     if high_count > 0 || medium_count > 2 || protocol_score < 50 || cipher_strength_score < 50 {
         has_warnings = true;
-        details.push("存在安全警告".to_string());
+        details.push(i18n::t("ssl.rating_warnings"));
     }
 
     // Determine grade based on improved SSL Labs methodology
     let grade = calculate_ssl_grade(final_score, has_errors, has_warnings, ssl_versions, cert);
 
     let details_text = if details.is_empty() {
-        "配置良好".to_string()
+        i18n::t("ssl.rating_good")
     } else {
         details.join(", ")
     };
@@ -1726,16 +2203,14 @@ fn analyze_security(
         let days_until_expiry = (valid_to_utc - now).num_days();
 
         if days_until_expiry < 0 {
-            vulnerabilities.push("证书已过期".to_string());
-            recommendations.push(
-                "立即更新证书，考虑使用自动化证书管理工具如 Let's Encrypt certbot".to_string(),
-            );
+            vulnerabilities.push(i18n::t("ssl.certificate_expired"));
+            recommendations.push(i18n::t("ssl.advice.renew_now"));
         } else if days_until_expiry < 7 {
-            vulnerabilities.push("证书将在一周内过期".to_string());
-            recommendations.push("紧急更新证书，建议设置证书到期提醒".to_string());
+            vulnerabilities.push(i18n::t("ssl.expires_within_week"));
+            recommendations.push(i18n::t("ssl.advice.renew_urgent"));
         } else if days_until_expiry < 30 {
-            vulnerabilities.push("证书即将在30天内过期".to_string());
-            recommendations.push("建议尽快更新证书，可以使用自动化工具如 Let's Encrypt certbot 或云服务商的证书管理服务".to_string());
+            vulnerabilities.push(i18n::t("ssl.expires_within_30_days"));
+            recommendations.push(i18n::t("ssl.advice.renew_soon"));
         }
     }
 
@@ -1744,28 +2219,26 @@ fn analyze_security(
         match cert.public_key_algorithm.as_str() {
             "RSA" => {
                 if key_size < 1024 {
-                    vulnerabilities.push(format!("RSA密钥长度严重不足: {} 位", key_size));
-                    recommendations
-                        .push("立即更换为至少2048位的RSA密钥或256位的ECC密钥".to_string());
+                    vulnerabilities
+                        .push(i18n::tr("ssl.rsa_key_critical", &[&key_size.to_string()]));
+                    recommendations.push(i18n::t("ssl.advice.replace_weak_key"));
                 } else if key_size < 2048 {
-                    vulnerabilities.push(format!("RSA密钥长度不足: {} 位", key_size));
-                    recommendations.push("建议使用至少2048位的RSA密钥。生成命令：openssl genrsa -out private.key 2048".to_string());
+                    vulnerabilities.push(i18n::tr("ssl.rsa_key_short", &[&key_size.to_string()]));
+                    recommendations.push(i18n::t("ssl.advice.rsa_2048"));
                 } else if key_size < 3072 {
-                    recommendations
-                        .push("考虑使用3072位或4096位RSA密钥以提高长期安全性".to_string());
+                    recommendations.push(i18n::t("ssl.advice.rsa_3072"));
                 }
             }
             "EC" | "ECDSA" => {
                 if key_size < 256 {
-                    vulnerabilities.push(format!("ECC密钥长度不足: {} 位", key_size));
-                    recommendations
-                        .push("建议使用至少256位的ECC密钥（等效于2048位RSA）".to_string());
+                    vulnerabilities.push(i18n::tr("ssl.ecc_key_short", &[&key_size.to_string()]));
+                    recommendations.push(i18n::t("ssl.advice.ecc_256"));
                 } else if key_size >= 384 {
-                    recommendations.push("ECC密钥长度良好，提供了优秀的安全性和性能".to_string());
+                    recommendations.push(i18n::t("ssl.advice.ecc_good"));
                 }
             }
             _ => {
-                recommendations.push("检查密钥算法和长度是否符合当前安全标准".to_string());
+                recommendations.push(i18n::t("ssl.advice.check_key"));
             }
         }
     }
@@ -1773,15 +2246,13 @@ fn analyze_security(
     // Signature algorithm analysis
     let sig_algo_lower = cert.signature_algorithm.to_lowercase();
     if sig_algo_lower.contains("md5") {
-        vulnerabilities.push("使用了已完全破解的MD5签名算法".to_string());
-        recommendations.push("立即更换为SHA-256或更强的签名算法".to_string());
+        vulnerabilities.push(i18n::t("ssl.md5_signature"));
+        recommendations.push(i18n::t("ssl.advice.replace_md5"));
     } else if sig_algo_lower.contains("sha1") {
-        vulnerabilities.push("使用了不安全的SHA-1签名算法".to_string());
-        recommendations.push(
-            "建议使用SHA-256或更强的签名算法。配置示例：在证书请求中指定 -sha256 参数".to_string(),
-        );
+        vulnerabilities.push(i18n::t("ssl.sha1_signature"));
+        recommendations.push(i18n::t("ssl.advice.use_sha256"));
     } else if sig_algo_lower.contains("sha256") {
-        recommendations.push("SHA-256签名算法良好，符合当前安全标准".to_string());
+        recommendations.push(i18n::t("ssl.advice.sha256_good"));
     }
 
     // Cipher suite analysis
@@ -1800,8 +2271,11 @@ fn analyze_security(
     let total_count = cipher_suites.len();
 
     if weak_count > 0 {
-        vulnerabilities.push(format!("发现 {} 个弱加密套件", weak_count));
-        recommendations.push("禁用弱加密套件。推荐配置：ECDHE+AESGCM:ECDHE+CHACHA20:DHE+AESGCM:DHE+CHACHA20:!aNULL:!MD5:!DSS:!3DES:!RC4".to_string());
+        vulnerabilities.push(i18n::tr(
+            "ssl.weak_ciphers_found",
+            &[&weak_count.to_string()],
+        ));
+        recommendations.push(i18n::t("ssl.advice.disable_weak_ciphers"));
     }
 
     if total_count > 0 {
@@ -1809,14 +2283,14 @@ fn analyze_security(
         let high_percentage = (high_count as f32 / total_count as f32 * 100.0) as u32;
 
         if weak_percentage > 25 {
-            vulnerabilities.push(format!("{}% 的加密套件为弱加密", weak_percentage));
+            vulnerabilities.push(i18n::tr(
+                "ssl.weak_cipher_ratio",
+                &[&weak_percentage.to_string()],
+            ));
         }
 
         if high_percentage < 50 {
-            recommendations.push(
-                "增加高强度加密套件的比例，优先使用 AES-256-GCM、ChaCha20-Poly1305 等现代加密算法"
-                    .to_string(),
-            );
+            recommendations.push(i18n::t("ssl.advice.more_strong_ciphers"));
         }
     }
 
@@ -1825,48 +2299,662 @@ fn analyze_security(
         .iter()
         .any(|cs| cs.name.contains("ECDHE") || cs.name.contains("DHE"));
     if !has_pfs {
-        vulnerabilities.push("缺乏完美前向保密支持".to_string());
-        recommendations.push(
-            "配置支持完美前向保密(PFS)的加密套件，如 ECDHE-RSA-AES256-GCM-SHA384".to_string(),
-        );
+        vulnerabilities.push(i18n::t("ssl.no_forward_secrecy"));
+        recommendations.push(i18n::t("ssl.advice.enable_pfs"));
     }
 
     // Enhanced security recommendations based on score
     if score >= 90 {
-        recommendations.push("SSL/TLS配置优秀，建议定期检查更新".to_string());
+        recommendations.push(i18n::t("ssl.advice.score_excellent"));
     } else if score >= 70 {
-        recommendations.push("SSL/TLS配置良好，仍有优化空间".to_string());
+        recommendations.push(i18n::t("ssl.advice.score_good"));
     } else if score >= 50 {
-        recommendations.push("SSL/TLS配置需要改进，存在安全风险".to_string());
+        recommendations.push(i18n::t("ssl.advice.score_fair"));
     } else {
-        recommendations.push("SSL/TLS配置存在严重安全风险，需要立即修复".to_string());
+        recommendations.push(i18n::t("ssl.advice.score_poor"));
     }
 
     // General security best practices
-    recommendations.push(
-        "启用 HSTS：Strict-Transport-Security: max-age=31536000; includeSubDomains; preload"
-            .to_string(),
-    );
-    recommendations.push(
-        "配置 OCSP Stapling 提高证书验证性能：ssl_stapling on; ssl_stapling_verify on;".to_string(),
-    );
-    recommendations.push(
-        "禁用过时协议：仅启用 TLS 1.2 和 TLS 1.3，禁用 SSL 2.0/3.0 和 TLS 1.0/1.1".to_string(),
-    );
-    recommendations.push("定期监控证书到期时间，建议使用证书监控服务或自动化续期".to_string());
-    recommendations.push("考虑实施证书透明度(CT)日志监控，及时发现未授权证书签发".to_string());
+    recommendations.push(i18n::t("ssl.advice.enable_hsts_preload"));
+    recommendations.push(i18n::t("ssl.advice.ocsp_stapling"));
+    recommendations.push(i18n::t("ssl.advice.disable_old_protocols"));
+    recommendations.push(i18n::t("ssl.advice.monitor_expiry"));
+    recommendations.push(i18n::t("ssl.advice.ct_monitoring"));
 
     (score, vulnerabilities, recommendations)
 }
 
+/// 解析 Strict-Transport-Security 响应头
+fn parse_hsts_header(header: &str) -> HstsPolicy {
+    let mut policy = HstsPolicy {
+        present: true,
+        header: Some(header.to_string()),
+        max_age: None,
+        include_subdomains: false,
+        preload: false,
+        preload_status: None,
+        issues: Vec::new(),
+    };
+
+    for directive in header.split(';') {
+        let directive = directive.trim();
+        let (name, value) = match directive.split_once('=') {
+            Some((name, value)) => (name.trim(), Some(value.trim().trim_matches('"'))),
+            None => (directive, None),
+        };
+        match name.to_ascii_lowercase().as_str() {
+            "max-age" => policy.max_age = value.and_then(|v| v.parse().ok()),
+            "includesubdomains" => policy.include_subdomains = true,
+            "preload" => policy.preload = true,
+            _ => {}
+        }
+    }
+
+    match policy.max_age {
+        None => policy.issues.push(i18n::t("ssl.hsts.missing_max_age")),
+        Some(0) => policy.issues.push(i18n::t("ssl.hsts.max_age_zero")),
+        Some(age) if age < 15_552_000 => policy
+            .issues
+            .push(i18n::tr("ssl.hsts.max_age_short", &[&age.to_string()])),
+        _ => {}
+    }
+    if policy.preload && (!policy.include_subdomains || policy.max_age.unwrap_or(0) < 31_536_000) {
+        policy.issues.push(i18n::t("ssl.hsts.preload_requirements"));
+    }
+    policy
+}
+
+/// 获取 HTTPS 响应中的 HSTS 头，并查询 HSTS 预加载列表状态
+async fn check_hsts(domain: &str) -> Result<HstsPolicy, String> {
+    let client = proxy::client_builder("ssl_checker")
+        .timeout(Duration::from_secs(10))
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .map_err(|e| i18n::tr("common.http_client_failed", &[&e.to_string()]))?;
+
+    let response = client
+        .get(format!("https://{}/", domain))
+        .send()
+        .await
+        .map_err(|e| i18n::tr("common.request_failed", &["HTTPS", &e.to_string()]))?;
+
+    let mut policy = match response
+        .headers()
+        .get("strict-transport-security")
+        .and_then(|value| value.to_str().ok())
+    {
+        Some(header) => parse_hsts_header(header),
+        None => HstsPolicy {
+            present: false,
+            header: None,
+            max_age: None,
+            include_subdomains: false,
+            preload: false,
+            preload_status: None,
+            issues: vec![i18n::t("ssl.hsts.header_missing")],
+        },
+    };
+
+    #[derive(Deserialize)]
+    struct PreloadStatus {
+        status: String,
+    }
+    policy.preload_status = match client
+        .get("https://hstspreload.org/api/v2/status")
+        .query(&[("domain", domain)])
+        .send()
+        .await
+    {
+        Ok(response) => response
+            .json::<PreloadStatus>()
+            .await
+            .ok()
+            .map(|s| s.status),
+        Err(_) => None,
+    };
+
+    Ok(policy)
+}
+
+/// CA 名称与其 CAA 标识的对应关系，用于判断当前证书颁发者是否被授权
+const CAA_ISSUER_DOMAINS: &[(&str, &[&str])] = &[
+    ("let's encrypt", &["letsencrypt.org"]),
+    (
+        "digicert",
+        &[
+            "digicert.com",
+            "symantec.com",
+            "geotrust.com",
+            "rapidssl.com",
+            "thawte.com",
+        ],
+    ),
+    ("sectigo", &["sectigo.com", "comodoca.com", "comodo.com"]),
+    ("comodo", &["sectigo.com", "comodoca.com", "comodo.com"]),
+    ("zerossl", &["sectigo.com", "zerossl.com"]),
+    ("globalsign", &["globalsign.com"]),
+    ("google trust services", &["pki.goog"]),
+    (
+        "amazon",
+        &[
+            "amazon.com",
+            "amazontrust.com",
+            "awstrust.com",
+            "amazonaws.com",
+        ],
+    ),
+    ("godaddy", &["godaddy.com"]),
+    ("starfield", &["starfieldtech.com"]),
+    ("entrust", &["entrust.net"]),
+    ("buypass", &["buypass.com"]),
+    ("ssl.com", &["ssl.com"]),
+    ("trustasia", &["trustasia.com", "digicert.com"]),
+    ("microsoft", &["microsoft.com"]),
+];
+
+/// 沿域名层级向上查找生效的 CAA 记录（RFC 8659）
+async fn check_caa(domain: &str, certificate_issuer: Option<&str>) -> Result<CaaPolicy, String> {
+    let resolver = DnsClient::system_resolver();
+    let labels: Vec<&str> = domain.trim_end_matches('.').split('.').collect();
+
+    let mut policy = CaaPolicy {
+        records: Vec::new(),
+        source_domain: None,
+        issuers: Vec::new(),
+        wildcard_issuers: Vec::new(),
+        iodef: Vec::new(),
+        current_issuer_authorized: None,
+    };
+
+    for start in 0..labels.len().saturating_sub(1) {
+        let name = labels[start..].join(".");
+        let records = DnsClient::lookup_rdata(&resolver, &name, RecordType::CAA).await?;
+        let caa_records: Vec<&CAA> = records
+            .iter()
+            .filter_map(|rdata| match rdata {
+                RData::CAA(caa) => Some(caa),
+                _ => None,
+            })
+            .collect();
+        if caa_records.is_empty() {
+            continue;
+        }
+
+        for caa in caa_records {
+            policy.records.push(format!(
+                "{} {} {}",
+                if caa.issuer_critical() { 128 } else { 0 },
+                caa.tag(),
+                caa.value()
+            ));
+            match (caa.tag(), caa.value()) {
+                (Property::Issue, CaaValue::Issuer(Some(name), _)) => policy
+                    .issuers
+                    .push(name.to_string().trim_end_matches('.').to_lowercase()),
+                (Property::IssueWild, CaaValue::Issuer(Some(name), _)) => policy
+                    .wildcard_issuers
+                    .push(name.to_string().trim_end_matches('.').to_lowercase()),
+                (Property::Iodef, CaaValue::Url(url)) => policy.iodef.push(url.to_string()),
+                _ => {}
+            }
+        }
+        policy.source_domain = Some(name);
+        break;
+    }
+
+    if let (Some(issuer), Some(_)) = (certificate_issuer, &policy.source_domain) {
+        let issuer_lower = issuer.to_lowercase();
+        policy.current_issuer_authorized = CAA_ISSUER_DOMAINS
+            .iter()
+            .find(|(name, _)| issuer_lower.contains(name))
+            .map(|(_, domains)| {
+                policy
+                    .issuers
+                    .iter()
+                    .chain(policy.wildcard_issuers.iter())
+                    .any(|authorized| domains.contains(&authorized.as_str()))
+            });
+    }
+
+    Ok(policy)
+}
+
+/// 判断 TLSA 记录是否与证书链中的证书匹配
+fn tlsa_matches(record: &TlsaRecordInfo, cert_chain_ders: &[Vec<u8>]) -> Option<bool> {
+    use sha2::{Digest, Sha256, Sha512};
+
+    let candidates: Vec<&Vec<u8>> = match record.usage {
+        1 | 3 => cert_chain_ders.iter().take(1).collect(),
+        0 | 2 => cert_chain_ders.iter().skip(1).collect(),
+        _ => return None,
+    };
+    let expected = hex::decode(&record.data).ok()?;
+
+    let matched = candidates.into_iter().any(|der| {
+        let selected = match record.selector {
+            0 => der.clone(),
+            1 => match X509Certificate::from_der(der) {
+                Ok((_, cert)) => cert.public_key().raw.to_vec(),
+                Err(_) => return false,
+            },
+            _ => return false,
+        };
+        match record.matching_type {
+            0 => selected == expected,
+            1 => Sha256::digest(&selected).as_slice() == expected.as_slice(),
+            2 => Sha512::digest(&selected).as_slice() == expected.as_slice(),
+            _ => false,
+        }
+    });
+    Some(matched)
+}
+
+/// 查询 _port._tcp 下的 TLSA 记录并与服务器证书比对
+async fn check_dane(
+    domain: &str,
+    port: u16,
+    cert_chain_ders: &[Vec<u8>],
+) -> Result<DanePolicy, String> {
+    let resolver = DnsClient::system_resolver();
+    let name = format!("_{}._tcp.{}", port, domain);
+    let records: Vec<TlsaRecordInfo> = DnsClient::lookup_rdata(&resolver, &name, RecordType::TLSA)
+        .await?
+        .into_iter()
+        .filter_map(|rdata| match rdata {
+            RData::TLSA(tlsa) => Some(TlsaRecordInfo {
+                usage: u8::from(tlsa.cert_usage()),
+                selector: u8::from(tlsa.selector()),
+                matching_type: u8::from(tlsa.matching()),
+                data: hex::encode(tlsa.cert_data()),
+                matches_certificate: None,
+            }),
+            _ => None,
+        })
+        .map(|mut record| {
+            record.matches_certificate = tlsa_matches(&record, cert_chain_ders);
+            record
+        })
+        .collect();
+
+    let valid = if records.is_empty() {
+        None
+    } else {
+        Some(records.iter().any(|r| r.matches_certificate == Some(true)))
+    };
+
+    Ok(DanePolicy {
+        name,
+        records,
+        valid,
+        // 系统解析器不做 DNSSEC 校验，DANE 的安全性依赖于 DNSSEC
+        dnssec_validated: false,
+    })
+}
+
+/// 将 HSTS、CAA、DANE 的检查结果纳入评级与建议
+fn apply_policy_findings(
+    rating: &mut SslLabsRating,
+    vulnerabilities: &mut Vec<String>,
+    recommendations: &mut Vec<String>,
+    hsts: Option<&HstsPolicy>,
+    caa: Option<&CaaPolicy>,
+    dane: Option<&DanePolicy>,
+) {
+    if let Some(hsts) = hsts {
+        let strong = hsts.present && hsts.max_age.unwrap_or(0) >= 15_552_000;
+        if !hsts.present {
+            recommendations.push(i18n::t("ssl.advice.enable_hsts"));
+        } else {
+            for issue in &hsts.issues {
+                recommendations.push(format!("HSTS: {}", issue));
+            }
+            if hsts.preload && hsts.preload_status.as_deref() != Some("preloaded") {
+                recommendations.push(i18n::t("ssl.advice.submit_preload"));
+            }
+        }
+
+        // 与 SSL Labs 一致：A+ 需要长期有效的 HSTS
+        if rating.grade == "A+" && !strong {
+            rating.grade = "A".to_string();
+        } else if rating.grade == "A" && strong && !rating.has_warnings {
+            rating.grade = "A+".to_string();
+        }
+    }
+
+    if let Some(caa) = caa {
+        if caa.source_domain.is_none() {
+            recommendations.push(i18n::t("ssl.advice.add_caa"));
+        } else if caa.current_issuer_authorized == Some(false) {
+            vulnerabilities.push(i18n::t("ssl.caa.issuer_unauthorized"));
+            recommendations.push(i18n::t("ssl.advice.update_caa"));
+            rating.has_warnings = true;
+        }
+        if caa.source_domain.is_some() && caa.iodef.is_empty() {
+            recommendations.push(i18n::t("ssl.advice.caa_iodef"));
+        }
+    }
+
+    if let Some(dane) = dane {
+        if dane.valid == Some(false) {
+            vulnerabilities.push(i18n::tr("ssl.dane.tlsa_mismatch", &[&dane.name]));
+            recommendations.push(i18n::t("ssl.advice.update_tlsa"));
+            rating.has_warnings = true;
+            if rating.grade.starts_with('A') {
+                rating.grade = "B".to_string();
+            }
+        }
+    }
+
+    if rating.has_warnings && rating.details == i18n::t("ssl.rating_good") {
+        rating.details = i18n::t("ssl.rating_warnings");
+    }
+}
+
+/// 解析域名的所有 A/AAAA 地址（去重，保持解析顺序）
+fn resolve_all_domain_ips(domain: &str) -> Result<Vec<IpAddr>, String> {
+    let ips = dns_lookup::lookup_host(domain)
+        .map_err(|e| i18n::tr("ssl.dns_failed", &[&e.to_string()]))?;
+    let mut unique: Vec<IpAddr> = Vec::new();
+    for ip in ips {
+        if !unique.contains(&ip) {
+            unique.push(ip);
+        }
+    }
+    if unique.is_empty() {
+        return Err(i18n::t("ssl.no_ip_for_domain"));
+    }
+    Ok(unique)
+}
+
+/// 接受任意证书但记录 webpki 校验结果，以便获取无效证书的端点信息
+#[derive(Debug)]
+pub(crate) struct EndpointRecordingVerifier {
+    inner: Arc<rustls::client::WebPkiServerVerifier>,
+    verify_error: std::sync::Mutex<Option<String>>,
+}
+
+impl EndpointRecordingVerifier {
+    /// 使用内置根证书和自定义信任库创建校验器
+    pub(crate) fn with_webpki_roots() -> Result<Self, String> {
+        let root_store = trust_store::default_root_store();
+        let inner = rustls::client::WebPkiServerVerifier::builder_with_provider(
+            Arc::new(root_store),
+            Arc::new(rustls::crypto::ring::default_provider()),
+        )
+        .build()
+        .map_err(|e| i18n::tr("ssl.build_verifier_failed", &[&e.to_string()]))?;
+        Ok(Self {
+            inner,
+            verify_error: std::sync::Mutex::new(None),
+        })
+    }
+
+    /// 最近一次证书校验失败的原因
+    pub(crate) fn verify_error(&self) -> Option<String> {
+        self.verify_error.lock().ok().and_then(|e| e.clone())
+    }
+}
+
+impl rustls::client::danger::ServerCertVerifier for EndpointRecordingVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &rustls::pki_types::CertificateDer<'_>,
+        intermediates: &[rustls::pki_types::CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: rustls::pki_types::UnixTime,
+    ) -> Result<rustls::client::danger::ServerCertVerified, rustls::Error> {
+        let result = self.inner.verify_server_cert(
+            end_entity,
+            intermediates,
+            server_name,
+            ocsp_response,
+            now,
+        );
+        if let (Err(e), Ok(mut verify_error)) = (&result, self.verify_error.lock()) {
+            *verify_error = Some(e.to_string());
+        }
+        Ok(rustls::client::danger::ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &rustls::pki_types::CertificateDer<'_>,
+        dss: &rustls::DigitallySignedStruct,
+    ) -> Result<rustls::client::danger::HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls12_signature(message, cert, dss)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &rustls::pki_types::CertificateDer<'_>,
+        dss: &rustls::DigitallySignedStruct,
+    ) -> Result<rustls::client::danger::HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls13_signature(message, cert, dss)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<rustls::SignatureScheme> {
+        self.inner.supported_verify_schemes()
+    }
+}
+
+/// 以域名作为 SNI 连接指定 IP，获取证书、协议版本与 ALPN 协商结果
+async fn check_endpoint(
+    domain: &str,
+    ip: IpAddr,
+    port: u16,
+    trust: Option<&TrustOverride>,
+) -> EndpointResult {
+    let mut result = EndpointResult {
+        ip: ip.to_string(),
+        reachable: false,
+        error: None,
+        certificate: None,
+        validation_error: None,
+        tls_versions: Vec::new(),
+        cipher_suite: None,
+        alpn_protocol: None,
+        handshake_ms: None,
+    };
+
+    let _ = rustls::crypto::ring::default_provider().install_default();
+    let root_store = match trust_store::root_store(trust) {
+        Ok(root_store) => root_store,
+        Err(e) => {
+            result.error = Some(e);
+            return result;
+        }
+    };
+    let webpki = match rustls::client::WebPkiServerVerifier::builder(Arc::new(root_store)).build() {
+        Ok(verifier) => verifier,
+        Err(e) => {
+            result.error = Some(i18n::tr("ssl.build_verifier_failed", &[&e.to_string()]));
+            return result;
+        }
+    };
+
+    let addr = SocketAddr::new(ip, port);
+    let versions: [(&str, &'static rustls::SupportedProtocolVersion); 2] = [
+        ("TLS 1.3", &rustls::version::TLS13),
+        ("TLS 1.2", &rustls::version::TLS12),
+    ];
+    for (name, version) in versions {
+        let verifier = Arc::new(EndpointRecordingVerifier {
+            inner: webpki.clone(),
+            verify_error: std::sync::Mutex::new(None),
+        });
+        let mut config = match rustls::ClientConfig::builder_with_provider(Arc::new(
+            rustls::crypto::ring::default_provider(),
+        ))
+        .with_protocol_versions(&[version])
+        {
+            Ok(builder) => builder
+                .dangerous()
+                .with_custom_certificate_verifier(verifier.clone())
+                .with_no_client_auth(),
+            Err(_) => continue,
+        };
+        config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+
+        match timed_tls_connect(addr, domain, Arc::new(config)).await {
+            Ok((stream, _, handshake)) => {
+                result.reachable = true;
+                result.tls_versions.push(name.to_string());
+                if result.certificate.is_some() {
+                    continue;
+                }
+                let connection = stream.get_ref().1;
+                result.handshake_ms = Some(handshake.as_millis() as u64);
+                result.cipher_suite = connection
+                    .negotiated_cipher_suite()
+                    .map(|suite| format!("{:?}", suite.suite()));
+                result.alpn_protocol = connection
+                    .alpn_protocol()
+                    .map(|p| String::from_utf8_lossy(p).into_owned());
+                result.certificate = connection
+                    .peer_certificates()
+                    .and_then(|certs| certs.first())
+                    .and_then(|cert| parse_certificate(cert).ok());
+                result.validation_error = verifier.verify_error.lock().ok().and_then(|e| e.clone());
+            }
+            Err(e) => {
+                if e.starts_with("TCP") {
+                    result.error = Some(e);
+                    break;
+                }
+            }
+        }
+    }
+
+    if !result.reachable && result.error.is_none() {
+        result.error = Some(i18n::t("ssl.handshakes_failed"));
+    }
+    result
+}
+
+/// 比较各端点的证书与协议配置，找出不一致之处
+fn find_endpoint_inconsistencies(endpoints: &[EndpointResult]) -> Vec<String> {
+    let mut issues = Vec::new();
+    let reachable: Vec<&EndpointResult> = endpoints.iter().filter(|e| e.reachable).collect();
+
+    for endpoint in endpoints.iter().filter(|e| !e.reachable) {
+        let error = endpoint
+            .error
+            .clone()
+            .unwrap_or_else(|| i18n::t("ssl.unknown_error"));
+        issues.push(i18n::tr(
+            "ssl.endpoint.unreachable",
+            &[&endpoint.ip, &error],
+        ));
+    }
+    let Some(first) = reachable.first() else {
+        return issues;
+    };
+
+    let fingerprint = |e: &EndpointResult| e.certificate.as_ref().map(|c| c.fingerprint.clone());
+    let mut fingerprints: Vec<String> = reachable.iter().filter_map(|e| fingerprint(e)).collect();
+    fingerprints.sort();
+    fingerprints.dedup();
+    if fingerprints.len() > 1 {
+        let detail: Vec<String> = reachable
+            .iter()
+            .map(|e| {
+                let cert = e.certificate.as_ref();
+                i18n::tr(
+                    "ssl.endpoint.certificate",
+                    &[
+                        &e.ip,
+                        cert.map(|c| c.fingerprint.as_str()).unwrap_or("-"),
+                        cert.map(|c| c.valid_to.as_str()).unwrap_or("-"),
+                    ],
+                )
+            })
+            .collect();
+        issues.push(i18n::tr(
+            "ssl.endpoint.certificate_mismatch",
+            &[&fingerprints.len().to_string(), &detail.join("; ")],
+        ));
+    }
+
+    for endpoint in &reachable[1..] {
+        if endpoint.tls_versions != first.tls_versions {
+            issues.push(i18n::tr(
+                "ssl.endpoint.protocol_mismatch",
+                &[
+                    &first.ip,
+                    &first.tls_versions.join(", "),
+                    &endpoint.ip,
+                    &endpoint.tls_versions.join(", "),
+                ],
+            ));
+        }
+        if endpoint.alpn_protocol != first.alpn_protocol {
+            let none = i18n::t("ssl.endpoint.none");
+            issues.push(i18n::tr(
+                "ssl.endpoint.alpn_mismatch",
+                &[
+                    &first.ip,
+                    first.alpn_protocol.as_deref().unwrap_or(&none),
+                    &endpoint.ip,
+                    endpoint.alpn_protocol.as_deref().unwrap_or(&none),
+                ],
+            ));
+        }
+    }
+
+    for endpoint in &reachable {
+        if let Some(error) = &endpoint.validation_error {
+            issues.push(i18n::tr(
+                "ssl.endpoint.validation_failed",
+                &[&endpoint.ip, error],
+            ));
+        }
+    }
+
+    issues
+}
+
+/// 并发检查多个端点
+async fn check_endpoints(
+    domain: &str,
+    ips: Vec<IpAddr>,
+    port: u16,
+    trust: Option<&TrustOverride>,
+) -> Vec<EndpointResult> {
+    let handles: Vec<_> = ips
+        .into_iter()
+        .map(|ip| {
+            let domain = domain.to_string();
+            let trust = trust.cloned();
+            tokio::spawn(async move { check_endpoint(&domain, ip, port, trust.as_ref()).await })
+        })
+        .collect();
+
+    let mut results = Vec::new();
+    for handle in handles {
+        if let Ok(result) = handle.await {
+            results.push(result);
+        }
+    }
+    results
+}
+
 #[tauri::command]
-pub async fn check_ssl_info(domain: String) -> Result<SslInfo, String> {
+pub async fn check_ssl_info(
+    domain: String,
+    options: Option<SslCheckOptions>,
+) -> Result<SslInfo, String> {
     let domain = domain.trim().to_lowercase();
 
     if domain.is_empty() {
-        return Err("域名不能为空".to_string());
+        return Err(i18n::t("common.domain_required"));
     }
 
+    // 同一目标主机的完整检测会发起大量握手，按主机限制并发
+    let _permit = rate_limit::acquire(&format!("tls:{}", domain)).await;
+
     // Resolve IP address
     let server_ip = match resolve_domain_ip(&domain) {
         Ok(ip) => Some(ip.to_string()),
@@ -1879,6 +2967,29 @@ pub async fn check_ssl_info(domain: String) -> Result<SslInfo, String> {
         .or_else(|| get_server_info(&domain, 443))
         .or_else(|| get_server_info(&domain, 80));
 
+    // Measure connection and handshake timing
+    let timing = measure_tls_timing(&domain, 443).await.ok();
+
+    // Check HSTS header and preload status
+    let hsts = check_hsts(&domain).await.ok();
+
+    // Check every resolved endpoint, or only the pinned IP
+    let options = options.unwrap_or_default();
+    let (endpoints, endpoint_inconsistencies) = if options.all_endpoints || options.pin_ip.is_some()
+    {
+        let ips = match options.pin_ip.as_deref().map(str::trim) {
+            Some(ip) => vec![ip
+                .parse::<IpAddr>()
+                .map_err(|_| i18n::tr("ssl.invalid_ip", &[ip]))?],
+            None => resolve_all_domain_ips(&domain).unwrap_or_default(),
+        };
+        let endpoints = check_endpoints(&domain, ips, 443, options.trust.as_ref()).await;
+        let inconsistencies = find_endpoint_inconsistencies(&endpoints);
+        (Some(endpoints), Some(inconsistencies))
+    } else {
+        (None, None)
+    };
+
     // Check protocol support and get certificate
     let (
         certificate,
@@ -1896,17 +3007,20 @@ pub async fn check_ssl_info(domain: String) -> Result<SslInfo, String> {
         spdy_support,
         http3_support,
         alpn_protocols,
-    ) = match check_tls_connection(&domain, 443).await {
+        caa,
+        dane,
+    ) = match check_tls_connection(&domain, 443, options.trust.as_ref()).await {
         Ok((cert_der, cipher_suites, cert_chain_ders)) => {
             match parse_certificate(&cert_der) {
                 Ok(cert) => {
-                    let (score, vulns, recs) = analyze_security(&cert, &cipher_suites);
+                    let (score, mut vulns, mut recs) = analyze_security(&cert, &cipher_suites);
 
                     // Build certificate chain
-                    let certificate_chain = match build_certificate_chain(&cert_chain_ders) {
-                        Ok(chain) => Some(chain),
-                        Err(_) => None,
-                    };
+                    let certificate_chain =
+                        match build_certificate_chain(&cert_chain_ders, options.trust.as_ref()) {
+                            Ok(chain) => Some(chain),
+                            Err(_) => None,
+                        };
 
                     // Get detailed protocol support
                     let protocol_support = match check_protocol_support(&domain, 443).await {
@@ -1961,13 +3075,25 @@ pub async fn check_ssl_info(domain: String) -> Result<SslInfo, String> {
                         detect_cve_vulnerabilities(&cert, &supported_versions, &cipher_suites);
 
                     // Calculate SSL Labs rating
-                    let ssl_labs_rating = calculate_ssl_labs_rating(
+                    let mut ssl_labs_rating = calculate_ssl_labs_rating(
                         &cert,
                         &supported_versions,
                         &cipher_suites,
                         &cve_vulnerabilities,
                     );
 
+                    // Check CAA and DANE/TLSA records
+                    let caa = check_caa(&domain, Some(&cert.issuer)).await.ok();
+                    let dane = check_dane(&domain, 443, &cert_chain_ders).await.ok();
+                    apply_policy_findings(
+                        &mut ssl_labs_rating,
+                        &mut vulns,
+                        &mut recs,
+                        hsts.as_ref(),
+                        caa.as_ref(),
+                        dane.as_ref(),
+                    );
+
                     (
                         Some(cert),
                         certificate_chain,
@@ -1984,20 +3110,23 @@ pub async fn check_ssl_info(domain: String) -> Result<SslInfo, String> {
                         spdy_support,
                         http3_support,
                         alpn_protocols,
+                        caa,
+                        dane,
                     )
                 }
                 Err(_) => (
-                    None, None, None, None, None, None, None, None, None, None, None, None, None, None,
-                    None,
+                    None, None, None, None, None, None, None, None, None, None, None, None, None,
+                    None, None, None, None,
                 ),
             }
         }
         Err(_) => (
-            None, None, None, None, None, None, None, None, None, None, None, None, None, None, None,
+            None, None, None, None, None, None, None, None, None, None, None, None, None, None,
+            None, None, None,
         ),
     };
 
-    Ok(SslInfo {
+    let info = SslInfo {
         domain,
         server_ip,
         server_info,
@@ -2016,5 +3145,59 @@ pub async fn check_ssl_info(domain: String) -> Result<SslInfo, String> {
         spdy_support,
         http3_support,
         alpn_protocols,
-    })
+        timing,
+        hsts,
+        caa,
+        dane,
+        endpoints,
+        endpoint_inconsistencies,
+    };
+
+    if options.save_history {
+        if let Err(e) = ssl_history::record_check(info.clone()) {
+            eprintln!("保存 SSL 检测历史失败: {}", e);
+        }
+    }
+
+    Ok(info)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hsts_header() {
+        let policy = parse_hsts_header("max-age=63072000; includeSubDomains; preload");
+        assert_eq!(policy.max_age, Some(63072000));
+        assert!(policy.include_subdomains);
+        assert!(policy.preload);
+        assert!(policy.issues.is_empty());
+
+        let weak = parse_hsts_header("max-age=\"3600\"; preload");
+        assert_eq!(weak.max_age, Some(3600));
+        assert_eq!(weak.issues.len(), 2);
+    }
+
+    #[test]
+    fn test_tlsa_matches_full_certificate() {
+        use sha2::{Digest, Sha256};
+
+        let leaf = b"leaf-certificate".to_vec();
+        let record = TlsaRecordInfo {
+            usage: 3,
+            selector: 0,
+            matching_type: 1,
+            data: hex::encode(Sha256::digest(&leaf)),
+            matches_certificate: None,
+        };
+        assert_eq!(
+            tlsa_matches(&record, std::slice::from_ref(&leaf)),
+            Some(true)
+        );
+        assert_eq!(tlsa_matches(&record, &[b"other".to_vec()]), Some(false));
+
+        let unknown_usage = TlsaRecordInfo { usage: 9, ..record };
+        assert_eq!(tlsa_matches(&unknown_usage, &[leaf]), None);
+    }
 }
@@ -0,0 +1,418 @@
+use crate::tools::ssl_checker::{CertificateChain, SslCertificate, SslInfo};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// 每个域名保留的检测记录条数
+const MAX_RECORDS_PER_DOMAIN: usize = 20;
+
+/// 串行化历史文件的读写
+static HISTORY_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SslCheckRecord {
+    pub id: u64, // 检测时间的毫秒时间戳
+    pub checked_at: String,
+    pub result: SslInfo,
+}
+
+/// 历史列表中的单条记录，不含完整检测结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SslCheckSummary {
+    pub id: u64,
+    pub domain: String,
+    pub checked_at: String,
+    pub grade: Option<String>,
+    pub fingerprint: Option<String>, // 服务器证书指纹
+    pub valid_to: Option<String>,
+}
+
+/// 两次检测之间单项的变化
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SslFieldChange {
+    pub field: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SslCheckDiff {
+    pub domain: String,
+    pub from: SslCheckSummary,
+    pub to: SslCheckSummary,
+    pub certificate_rotated: bool, // 服务器证书指纹不同
+    pub grade_changed: bool,
+    pub protocols_added: Vec<String>,
+    pub protocols_removed: Vec<String>,
+    pub cipher_suites_added: Vec<String>,
+    pub cipher_suites_removed: Vec<String>,
+    pub changes: Vec<SslFieldChange>, // 其余有变化的字段
+}
+
+/// 按域名保存的历史，最新的记录在前
+type SslHistory = BTreeMap<String, Vec<SslCheckRecord>>;
+
+/// 查看检测历史，不指定域名时返回全部
+#[tauri::command]
+pub async fn get_ssl_check_history(domain: Option<String>) -> Result<Vec<SslCheckSummary>, String> {
+    let _guard = HISTORY_LOCK.lock().unwrap();
+    let history = load_history(&get_history_path());
+    let domain = domain.map(|d| d.trim().to_lowercase());
+    Ok(history
+        .values()
+        .flatten()
+        .filter(|record| domain.as_deref().is_none_or(|d| record.result.domain == d))
+        .map(summarize)
+        .collect())
+}
+
+/// 读取一次完整的检测结果
+#[tauri::command]
+pub async fn get_ssl_check_record(domain: String, id: u64) -> Result<SslCheckRecord, String> {
+    let _guard = HISTORY_LOCK.lock().unwrap();
+    let mut history = load_history(&get_history_path());
+    history
+        .get_mut(&domain.trim().to_lowercase())
+        .and_then(|records| {
+            let index = records.iter().position(|r| r.id == id)?;
+            Some(records.swap_remove(index))
+        })
+        .ok_or_else(|| format!("检测记录不存在: {}", id))
+}
+
+/// 比较同一域名的两次检测，未指定时比较最近两次
+#[tauri::command]
+pub async fn diff_ssl_checks(
+    domain: String,
+    from_id: Option<u64>,
+    to_id: Option<u64>,
+) -> Result<SslCheckDiff, String> {
+    let domain = domain.trim().to_lowercase();
+    let _guard = HISTORY_LOCK.lock().unwrap();
+    let history = load_history(&get_history_path());
+    let records = history
+        .get(&domain)
+        .filter(|records| !records.is_empty())
+        .ok_or_else(|| format!("没有 {} 的检测历史", domain))?;
+
+    let find = |id: u64| {
+        records
+            .iter()
+            .find(|r| r.id == id)
+            .ok_or_else(|| format!("检测记录不存在: {}", id))
+    };
+    let to = match to_id {
+        Some(id) => find(id)?,
+        None => &records[0],
+    };
+    let from = match from_id {
+        Some(id) => find(id)?,
+        None => records
+            .iter()
+            .find(|r| r.id < to.id)
+            .ok_or_else(|| format!("{} 只有一次检测记录，无法比较", domain))?,
+    };
+    Ok(diff_records(from, to))
+}
+
+/// 清空检测历史，不指定域名时清空全部
+#[tauri::command]
+pub async fn clear_ssl_check_history(domain: Option<String>) -> Result<(), String> {
+    let _guard = HISTORY_LOCK.lock().unwrap();
+    let path = get_history_path();
+    let mut history = load_history(&path);
+    match domain {
+        Some(domain) => {
+            history.remove(&domain.trim().to_lowercase());
+        }
+        None => history.clear(),
+    }
+    save_history(&path, &history)
+}
+
+/// 保存一次检测结果
+pub(crate) fn record_check(result: SslInfo) -> Result<SslCheckSummary, String> {
+    let now = chrono::Local::now();
+    let record = SslCheckRecord {
+        id: now.timestamp_millis() as u64,
+        checked_at: now.to_rfc3339(),
+        result,
+    };
+    let summary = summarize(&record);
+
+    let _guard = HISTORY_LOCK.lock().unwrap();
+    let path = get_history_path();
+    let mut history = load_history(&path);
+    push_record(&mut history, record);
+    save_history(&path, &history)?;
+    Ok(summary)
+}
+
+fn push_record(history: &mut SslHistory, record: SslCheckRecord) {
+    let records = history.entry(record.result.domain.clone()).or_default();
+    // 同一毫秒内的重复保存沿用递增的 id，保证 id 唯一
+    let id = records
+        .first()
+        .map_or(record.id, |latest| record.id.max(latest.id + 1));
+    records.insert(0, SslCheckRecord { id, ..record });
+    records.truncate(MAX_RECORDS_PER_DOMAIN);
+}
+
+fn summarize(record: &SslCheckRecord) -> SslCheckSummary {
+    let certificate = record.result.certificate.as_ref();
+    SslCheckSummary {
+        id: record.id,
+        domain: record.result.domain.clone(),
+        checked_at: record.checked_at.clone(),
+        grade: record
+            .result
+            .ssl_labs_rating
+            .as_ref()
+            .map(|r| r.grade.clone()),
+        fingerprint: certificate.map(|c| c.fingerprint.clone()),
+        valid_to: certificate.map(|c| c.valid_to.clone()),
+    }
+}
+
+fn diff_records(from: &SslCheckRecord, to: &SslCheckRecord) -> SslCheckDiff {
+    let (before, after) = (&from.result, &to.result);
+    let mut changes = Vec::new();
+    let mut compare = |field: &str, before: Option<String>, after: Option<String>| {
+        if before != after {
+            changes.push(SslFieldChange {
+                field: field.to_string(),
+                before,
+                after,
+            });
+        }
+    };
+
+    let cert_field =
+        |info: &SslInfo, get: fn(&SslCertificate) -> String| info.certificate.as_ref().map(get);
+    compare(
+        "certificate.subject",
+        cert_field(before, |c| c.subject.clone()),
+        cert_field(after, |c| c.subject.clone()),
+    );
+    compare(
+        "certificate.issuer",
+        cert_field(before, |c| c.issuer.clone()),
+        cert_field(after, |c| c.issuer.clone()),
+    );
+    compare(
+        "certificate.serial_number",
+        cert_field(before, |c| c.serial_number.clone()),
+        cert_field(after, |c| c.serial_number.clone()),
+    );
+    compare(
+        "certificate.valid_to",
+        cert_field(before, |c| c.valid_to.clone()),
+        cert_field(after, |c| c.valid_to.clone()),
+    );
+    compare(
+        "certificate.public_key",
+        cert_field(before, describe_key),
+        cert_field(after, describe_key),
+    );
+    compare(
+        "certificate.san_domains",
+        cert_field(before, |c| {
+            c.san_domains.clone().unwrap_or_default().join(", ")
+        }),
+        cert_field(after, |c| {
+            c.san_domains.clone().unwrap_or_default().join(", ")
+        }),
+    );
+
+    let score = |info: &SslInfo| info.security_score.map(|s| s.to_string());
+    compare("security_score", score(before), score(after));
+    let chain = |info: &SslInfo, get: fn(&CertificateChain) -> String| {
+        info.certificate_chain.as_ref().map(get)
+    };
+    compare(
+        "certificate_chain.status",
+        chain(before, |c| c.chain_validation_status.clone()),
+        chain(after, |c| c.chain_validation_status.clone()),
+    );
+    compare(
+        "certificate_chain.trust_anchor",
+        chain(before, |c| c.trust_anchor.clone().unwrap_or_default()),
+        chain(after, |c| c.trust_anchor.clone().unwrap_or_default()),
+    );
+    compare(
+        "server_ip",
+        before.server_ip.clone(),
+        after.server_ip.clone(),
+    );
+    compare(
+        "server_cipher_order",
+        before.server_cipher_order.map(|v| v.to_string()),
+        after.server_cipher_order.map(|v| v.to_string()),
+    );
+    compare(
+        "http2_support",
+        before.http2_support.map(|v| v.to_string()),
+        after.http2_support.map(|v| v.to_string()),
+    );
+    compare(
+        "http3_support",
+        before.http3_support.map(|v| v.to_string()),
+        after.http3_support.map(|v| v.to_string()),
+    );
+    compare(
+        "alpn_protocols",
+        before.alpn_protocols.as_ref().map(|p| p.join(", ")),
+        after.alpn_protocols.as_ref().map(|p| p.join(", ")),
+    );
+    compare(
+        "hsts",
+        before.hsts.as_ref().and_then(|h| h.header.clone()),
+        after.hsts.as_ref().and_then(|h| h.header.clone()),
+    );
+    compare(
+        "vulnerabilities",
+        before.vulnerabilities.as_ref().map(|v| v.join("; ")),
+        after.vulnerabilities.as_ref().map(|v| v.join("; ")),
+    );
+
+    let protocols = |info: &SslInfo| info.ssl_versions.clone().unwrap_or_default();
+    let ciphers = |info: &SslInfo| {
+        info.cipher_suites
+            .as_ref()
+            .map(|suites| suites.iter().map(|s| s.name.clone()).collect())
+            .unwrap_or_default()
+    };
+    let (protocols_added, protocols_removed) = set_changes(protocols(before), protocols(after));
+    let (cipher_suites_added, cipher_suites_removed) = set_changes(ciphers(before), ciphers(after));
+
+    let (from, to) = (summarize(from), summarize(to));
+    SslCheckDiff {
+        domain: to.domain.clone(),
+        certificate_rotated: from.fingerprint != to.fingerprint,
+        grade_changed: from.grade != to.grade,
+        from,
+        to,
+        protocols_added,
+        protocols_removed,
+        cipher_suites_added,
+        cipher_suites_removed,
+        changes,
+    }
+}
+
+fn describe_key(cert: &SslCertificate) -> String {
+    match cert.key_size {
+        Some(size) => format!("{} {}", cert.public_key_algorithm, size),
+        None => cert.public_key_algorithm.clone(),
+    }
+}
+
+/// 返回新增和移除的项，按名称排序
+fn set_changes(before: Vec<String>, after: Vec<String>) -> (Vec<String>, Vec<String>) {
+    let before: BTreeSet<String> = before.into_iter().collect();
+    let after: BTreeSet<String> = after.into_iter().collect();
+    (
+        after.difference(&before).cloned().collect(),
+        before.difference(&after).cloned().collect(),
+    )
+}
+
+fn get_history_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("devtools")
+        .join("ssl-history.json")
+}
+
+fn load_history(path: &Path) -> SslHistory {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_history(path: &Path, history: &SslHistory) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("创建配置目录失败: {}", e))?;
+    }
+    let content =
+        serde_json::to_string(history).map_err(|e| format!("序列化检测历史失败: {}", e))?;
+    fs::write(path, content).map_err(|e| format!("保存检测历史失败: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn sample(id: u64, fingerprint: &str, grade: &str, versions: &[&str]) -> SslCheckRecord {
+        let result = serde_json::from_value(json!({
+            "domain": "example.com",
+            "certificate": {
+                "subject": "CN=example.com",
+                "issuer": "CN=Test CA",
+                "valid_from": "2026-01-01T00:00:00Z",
+                "valid_to": format!("2026-{:02}-01T00:00:00Z", id % 12 + 1),
+                "fingerprint": fingerprint,
+                "serial_number": fingerprint,
+                "signature_algorithm": "SHA256withRSA",
+                "public_key_algorithm": "RSA",
+                "key_size": 2048,
+                "san_domains": ["example.com"]
+            },
+            "ssl_versions": versions,
+            "cipher_suites": [
+                {"name": "TLS_AES_128_GCM_SHA256", "version": "TLS 1.3", "strength": "strong", "server_order": false}
+            ],
+            "ssl_labs_rating": {
+                "grade": grade, "score": 90, "has_warnings": false, "has_errors": false,
+                "certificate_score": 100, "protocol_score": 90, "key_exchange_score": 90,
+                "cipher_strength_score": 90,
+                "details": ""
+            }
+        }))
+        .unwrap();
+        SslCheckRecord {
+            id,
+            checked_at: String::new(),
+            result,
+        }
+    }
+
+    #[test]
+    fn test_diff_records() {
+        let before = sample(1, "AA", "B", &["TLS 1.1", "TLS 1.2"]);
+        let after = sample(2, "BB", "A", &["TLS 1.2", "TLS 1.3"]);
+        let diff = diff_records(&before, &after);
+        assert!(diff.certificate_rotated);
+        assert!(diff.grade_changed);
+        assert_eq!(diff.protocols_added, vec!["TLS 1.3"]);
+        assert_eq!(diff.protocols_removed, vec!["TLS 1.1"]);
+        assert!(diff.cipher_suites_added.is_empty());
+        let fields: Vec<&str> = diff.changes.iter().map(|c| c.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            vec!["certificate.serial_number", "certificate.valid_to"]
+        );
+
+        let same = diff_records(&before, &sample(13, "AA", "B", &["TLS 1.1", "TLS 1.2"]));
+        assert!(!same.certificate_rotated && !same.grade_changed);
+        assert!(same.changes.is_empty() && same.protocols_added.is_empty());
+    }
+
+    #[test]
+    fn test_push_record_keeps_latest() {
+        let mut history = SslHistory::new();
+        for _ in 0..MAX_RECORDS_PER_DOMAIN + 5 {
+            push_record(&mut history, sample(100, "AA", "A", &["TLS 1.3"]));
+        }
+        let records = &history["example.com"];
+        assert_eq!(records.len(), MAX_RECORDS_PER_DOMAIN);
+        // 同一时间戳的记录 id 依次递增，最新的在前
+        assert_eq!(records[0].id, 100 + MAX_RECORDS_PER_DOMAIN as u64 + 4);
+        assert!(records.windows(2).all(|pair| pair[0].id > pair[1].id));
+        assert_eq!(summarize(&records[0]).grade.as_deref(), Some("A"));
+    }
+}
@@ -0,0 +1,603 @@
+use crate::tools::key_generator;
+use axum::extract::{self, ConnectInfo, Request};
+use axum::http::{header, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{Html, IntoResponse, Redirect, Response};
+use axum::Router;
+use base64::{engine::general_purpose, Engine as _};
+use hyper_util::rt::{TokioExecutor, TokioIo};
+use hyper_util::server::conn::auto::Builder as ConnectionBuilder;
+use hyper_util::service::TowerToHyperService;
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, CONTROLS};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, State};
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+use tokio_rustls::TlsAcceptor;
+use tower::ServiceExt;
+use tower_http::cors::CorsLayer;
+use tower_http::services::ServeDir;
+
+/// 目录列表中链接需要转义的字符
+const PATH_SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
+
+/// 自签名证书有效期（天）
+const CERTIFICATE_DAYS: u32 = 30;
+
+type LogSink = Arc<dyn Fn(AccessLogEntry) + Send + Sync>;
+
+#[derive(Default)]
+pub struct StaticServerState {
+    servers: Mutex<HashMap<String, RunningServer>>,
+}
+
+impl StaticServerState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+struct RunningServer {
+    info: StaticServerInfo,
+    handle: JoinHandle<()>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StartStaticServerRequest {
+    pub root_dir: String,
+    pub port: Option<u16>,            // 为空或 0 时自动分配
+    pub bind_address: Option<String>, // 默认 127.0.0.1，局域网共享使用 0.0.0.0
+    pub username: Option<String>,
+    pub password: Option<String>, // 设置后启用 Basic 认证
+    pub directory_listing: Option<bool>,
+    pub cors: Option<bool>,
+    pub https: Option<bool>, // 使用自动生成的自签名证书
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StaticServerInfo {
+    pub id: String,
+    pub root_dir: String,
+    pub bind_address: String,
+    pub port: u16,
+    pub urls: Vec<String>,
+    pub https: bool,
+    pub auth_enabled: bool,
+    pub directory_listing: bool,
+    pub cors: bool,
+    pub certificate_pem: Option<String>, // HTTPS 模式下的自签名证书，便于客户端信任
+    pub started_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessLogEntry {
+    pub server_id: String,
+    pub timestamp: String,
+    pub remote_addr: String,
+    pub method: String,
+    pub path: String,
+    pub status: u16,
+    pub bytes: Option<u64>,
+    pub duration_ms: u64,
+    pub user_agent: Option<String>,
+}
+
+struct ServerContext {
+    id: String,
+    root: PathBuf,
+    directory_listing: bool,
+    auth: Option<String>, // 期望的 Authorization 头
+    sink: LogSink,
+}
+
+/// 启动静态文件服务，访问日志通过 static-server-log 事件推送
+#[tauri::command]
+pub async fn start_static_server(
+    app: AppHandle,
+    state: State<'_, StaticServerState>,
+    request: StartStaticServerRequest,
+) -> Result<StaticServerInfo, String> {
+    let id = format!("static-{}", chrono::Utc::now().timestamp_millis());
+    let sink: LogSink = Arc::new(move |entry| {
+        let _ = app.emit("static-server-log", entry);
+    });
+    let (info, handle) = launch(id, request, sink).await?;
+    state.servers.lock().unwrap().insert(
+        info.id.clone(),
+        RunningServer {
+            info: info.clone(),
+            handle,
+        },
+    );
+    Ok(info)
+}
+
+/// 停止静态文件服务
+#[tauri::command]
+pub async fn stop_static_server(
+    state: State<'_, StaticServerState>,
+    id: String,
+) -> Result<(), String> {
+    let server = state.servers.lock().unwrap().remove(&id);
+    match server {
+        Some(server) => {
+            server.handle.abort();
+            Ok(())
+        }
+        None => Err("服务不存在或已停止".to_string()),
+    }
+}
+
+/// 列出正在运行的静态文件服务
+#[tauri::command]
+pub async fn list_static_servers(
+    state: State<'_, StaticServerState>,
+) -> Result<Vec<StaticServerInfo>, String> {
+    let mut servers = state.servers.lock().unwrap();
+    servers.retain(|_, server| !server.handle.is_finished());
+    let mut list: Vec<StaticServerInfo> = servers.values().map(|s| s.info.clone()).collect();
+    list.sort_by(|a, b| a.started_at.cmp(&b.started_at));
+    Ok(list)
+}
+
+async fn launch(
+    id: String,
+    request: StartStaticServerRequest,
+    sink: LogSink,
+) -> Result<(StaticServerInfo, JoinHandle<()>), String> {
+    let root = PathBuf::from(&request.root_dir);
+    if !root.is_dir() {
+        return Err(format!("目录不存在: {}", request.root_dir));
+    }
+    let root = root
+        .canonicalize()
+        .map_err(|e| format!("解析目录失败: {}", e))?;
+
+    let bind_address = request
+        .bind_address
+        .filter(|a| !a.trim().is_empty())
+        .unwrap_or_else(|| "127.0.0.1".to_string());
+    let bind_ip: IpAddr = bind_address
+        .trim()
+        .parse()
+        .map_err(|_| format!("无效的监听地址: {}", bind_address))?;
+    let listener = TcpListener::bind(SocketAddr::new(bind_ip, request.port.unwrap_or(0)))
+        .await
+        .map_err(|e| format!("监听端口失败: {}", e))?;
+    let port = listener
+        .local_addr()
+        .map_err(|e| format!("获取监听端口失败: {}", e))?
+        .port();
+
+    let auth = match request.password.filter(|p| !p.is_empty()) {
+        Some(password) => {
+            let credentials = format!("{}:{}", request.username.unwrap_or_default(), password);
+            Some(format!(
+                "Basic {}",
+                general_purpose::STANDARD.encode(credentials)
+            ))
+        }
+        None => None,
+    };
+
+    let hosts = access_hosts(bind_ip);
+    let https = request.https.unwrap_or(false);
+    let (tls, certificate_pem) = if https {
+        let mut sans = vec!["localhost".to_string(), "127.0.0.1".to_string()];
+        for host in &hosts {
+            if !sans.contains(host) {
+                sans.push(host.clone());
+            }
+        }
+        let (cert_pem, key_pem) =
+            key_generator::generate_self_signed_certificate("localhost", &sans, CERTIFICATE_DAYS)?;
+        (Some(tls_acceptor(&cert_pem, &key_pem)?), Some(cert_pem))
+    } else {
+        (None, None)
+    };
+
+    let auth_enabled = auth.is_some();
+    let directory_listing = request.directory_listing.unwrap_or(true);
+    let cors = request.cors.unwrap_or(false);
+    let context = Arc::new(ServerContext {
+        id: id.clone(),
+        root: root.clone(),
+        directory_listing,
+        auth,
+        sink,
+    });
+    let handle = tokio::spawn(serve(listener, build_router(context, cors), tls));
+
+    let scheme = if https { "https" } else { "http" };
+    let urls = hosts
+        .iter()
+        .map(|host| match host.parse::<IpAddr>() {
+            Ok(IpAddr::V6(_)) => format!("{}://[{}]:{}/", scheme, host, port),
+            _ => format!("{}://{}:{}/", scheme, host, port),
+        })
+        .collect();
+    let info = StaticServerInfo {
+        id,
+        root_dir: root.to_string_lossy().to_string(),
+        bind_address,
+        port,
+        urls,
+        https,
+        auth_enabled,
+        directory_listing,
+        cors,
+        certificate_pem,
+        started_at: chrono::Local::now().to_rfc3339(),
+    };
+    Ok((info, handle))
+}
+
+/// 可用于访问服务的主机地址，监听所有地址时列出本机网卡地址
+fn access_hosts(bind_ip: IpAddr) -> Vec<String> {
+    if !bind_ip.is_unspecified() {
+        return vec![bind_ip.to_string()];
+    }
+    let mut hosts = vec!["localhost".to_string()];
+    if let Ok(interfaces) = if_addrs::get_if_addrs() {
+        hosts.extend(
+            interfaces
+                .iter()
+                .filter(|iface| !iface.is_loopback())
+                .map(|iface| iface.ip())
+                .filter(|ip| ip.is_ipv4() || bind_ip.is_ipv6())
+                .map(|ip| ip.to_string()),
+        );
+    }
+    hosts
+}
+
+fn tls_acceptor(cert_pem: &str, key_pem: &str) -> Result<TlsAcceptor, String> {
+    let certs = rustls_pemfile::certs(&mut cert_pem.as_bytes())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("读取证书失败: {}", e))?;
+    let key = rustls_pemfile::private_key(&mut key_pem.as_bytes())
+        .map_err(|e| format!("读取私钥失败: {}", e))?
+        .ok_or_else(|| "私钥为空".to_string())?;
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+    let mut config = rustls::ServerConfig::builder_with_provider(provider)
+        .with_safe_default_protocol_versions()
+        .map_err(|e| format!("TLS 配置失败: {}", e))?
+        .with_no_client_auth()
+        .with_single_cert(certs, key)
+        .map_err(|e| format!("TLS 配置失败: {}", e))?;
+    config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+    Ok(TlsAcceptor::from(Arc::new(config)))
+}
+
+fn build_router(context: Arc<ServerContext>, cors: bool) -> Router {
+    let mut router = Router::new()
+        .fallback(serve_path)
+        .layer(middleware::from_fn_with_state(context.clone(), basic_auth))
+        .with_state(context.clone());
+    if cors {
+        // CORS 在认证之外，预检请求无需凭据
+        router = router.layer(CorsLayer::permissive());
+    }
+    router.layer(middleware::from_fn_with_state(context, access_log))
+}
+
+/// 接受连接并交给 hyper 处理，TLS 为空时使用明文 HTTP
+pub(crate) async fn serve(listener: TcpListener, router: Router, tls: Option<TlsAcceptor>) {
+    let make_service = router.into_make_service_with_connect_info::<SocketAddr>();
+    loop {
+        let (stream, remote) = match listener.accept().await {
+            Ok(accepted) => accepted,
+            Err(_) => {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                continue;
+            }
+        };
+        let make_service = make_service.clone();
+        let tls = tls.clone();
+        tokio::spawn(async move {
+            let service = match make_service.oneshot(remote).await {
+                Ok(service) => TowerToHyperService::new(service),
+                Err(never) => match never {},
+            };
+            let builder = ConnectionBuilder::new(TokioExecutor::new());
+            let _ = match tls {
+                Some(acceptor) => match acceptor.accept(stream).await {
+                    Ok(stream) => {
+                        builder
+                            .serve_connection_with_upgrades(TokioIo::new(stream), service)
+                            .await
+                    }
+                    Err(_) => return,
+                },
+                None => {
+                    builder
+                        .serve_connection_with_upgrades(TokioIo::new(stream), service)
+                        .await
+                }
+            };
+        });
+    }
+}
+
+async fn access_log(
+    extract::State(context): extract::State<Arc<ServerContext>>,
+    ConnectInfo(remote): ConnectInfo<SocketAddr>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let start = Instant::now();
+    let method = request.method().to_string();
+    let path = request
+        .uri()
+        .path_and_query()
+        .map(|p| {
+            percent_decode_str(p.as_str())
+                .decode_utf8_lossy()
+                .to_string()
+        })
+        .unwrap_or_default();
+    let user_agent = request
+        .headers()
+        .get(header::USER_AGENT)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+
+    let response = next.run(request).await;
+    let bytes = response
+        .headers()
+        .get(header::CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse().ok());
+    (context.sink)(AccessLogEntry {
+        server_id: context.id.clone(),
+        timestamp: chrono::Local::now().to_rfc3339(),
+        remote_addr: remote.to_string(),
+        method,
+        path,
+        status: response.status().as_u16(),
+        bytes,
+        duration_ms: start.elapsed().as_millis() as u64,
+        user_agent,
+    });
+    response
+}
+
+async fn basic_auth(
+    extract::State(context): extract::State<Arc<ServerContext>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if let Some(expected) = &context.auth {
+        let provided = request
+            .headers()
+            .get(header::AUTHORIZATION)
+            .and_then(|v| v.to_str().ok());
+        if provided != Some(expected.as_str()) {
+            return (
+                StatusCode::UNAUTHORIZED,
+                [(
+                    header::WWW_AUTHENTICATE,
+                    "Basic realm=\"DevTools\", charset=\"UTF-8\"",
+                )],
+                "401 Unauthorized",
+            )
+                .into_response();
+        }
+    }
+    next.run(request).await
+}
+
+async fn serve_path(
+    extract::State(context): extract::State<Arc<ServerContext>>,
+    request: Request,
+) -> Response {
+    let uri_path = request.uri().path().to_string();
+    let Some(relative) = decode_request_path(&uri_path) else {
+        return (StatusCode::BAD_REQUEST, "400 Bad Request").into_response();
+    };
+    let full_path = context.root.join(relative);
+
+    if full_path.is_dir() && !full_path.join("index.html").is_file() {
+        if !context.directory_listing {
+            return (StatusCode::NOT_FOUND, "404 Not Found").into_response();
+        }
+        if !uri_path.ends_with('/') {
+            return Redirect::permanent(&format!("{}/", uri_path)).into_response();
+        }
+        return match render_listing(&full_path, &uri_path).await {
+            Ok(html) => Html(html).into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e).into_response(),
+        };
+    }
+
+    match ServeDir::new(&context.root).oneshot(request).await {
+        Ok(response) => response.into_response(),
+        Err(never) => match never {},
+    }
+}
+
+/// 解码请求路径，拒绝 .. 等越出根目录的路径
+fn decode_request_path(path: &str) -> Option<PathBuf> {
+    let decoded = percent_decode_str(path).decode_utf8().ok()?;
+    let mut result = PathBuf::new();
+    for segment in decoded.split('/').filter(|s| !s.is_empty() && *s != ".") {
+        if segment.contains('\\') {
+            return None;
+        }
+        let mut components = Path::new(segment).components();
+        match (components.next(), components.next()) {
+            (Some(Component::Normal(name)), None) => result.push(name),
+            _ => return None,
+        }
+    }
+    Some(result)
+}
+
+async fn render_listing(dir: &Path, uri_path: &str) -> Result<String, String> {
+    let mut reader = tokio::fs::read_dir(dir)
+        .await
+        .map_err(|e| format!("读取目录失败: {}", e))?;
+    let mut entries = Vec::new();
+    while let Ok(Some(entry)) = reader.next_entry().await {
+        let Ok(metadata) = entry.metadata().await else {
+            continue;
+        };
+        let modified = metadata
+            .modified()
+            .ok()
+            .map(|t| {
+                chrono::DateTime::<chrono::Local>::from(t)
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string()
+            })
+            .unwrap_or_default();
+        entries.push((
+            entry.file_name().to_string_lossy().to_string(),
+            metadata.is_dir(),
+            metadata.len(),
+            modified,
+        ));
+    }
+    entries.sort_by(|a, b| {
+        b.1.cmp(&a.1)
+            .then_with(|| a.0.to_lowercase().cmp(&b.0.to_lowercase()))
+    });
+
+    let title = escape_html(&percent_decode_str(uri_path).decode_utf8_lossy());
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\"><title>Index of {title}</title>\
+         <style>body{{font-family:sans-serif;margin:2em}}td{{padding:2px 16px 2px 0}}\
+         a{{text-decoration:none}}</style></head><body><h1>Index of {title}</h1><table>\n"
+    );
+    if uri_path != "/" {
+        html.push_str("<tr><td><a href=\"../\">../</a></td><td></td><td></td></tr>\n");
+    }
+    for (name, is_dir, size, modified) in entries {
+        let suffix = if is_dir { "/" } else { "" };
+        let size = if is_dir {
+            "-".to_string()
+        } else {
+            format_size(size)
+        };
+        html.push_str(&format!(
+            "<tr><td><a href=\"{}{}\">{}{}</a></td><td>{}</td><td>{}</td></tr>\n",
+            utf8_percent_encode(&name, PATH_SEGMENT),
+            suffix,
+            escape_html(&name),
+            suffix,
+            size,
+            modified
+        ));
+    }
+    html.push_str("</table></body></html>\n");
+    Ok(html)
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn format_size(size: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+    let mut value = size as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", size)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_request_path() {
+        assert_eq!(decode_request_path("/").unwrap(), PathBuf::new());
+        assert_eq!(
+            decode_request_path("/docs/%E6%96%87%E4%BB%B6.txt").unwrap(),
+            PathBuf::from("docs").join("文件.txt")
+        );
+        assert!(decode_request_path("/../etc/passwd").is_none());
+        assert!(decode_request_path("/a/%2e%2e/b").is_none());
+        assert!(decode_request_path("/a%5C..%5Cb").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_serve_directory_with_auth() {
+        let dir = std::env::temp_dir().join(format!("devtools-static-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("sub")).unwrap();
+        std::fs::write(dir.join("sub").join("hello.txt"), "hello").unwrap();
+
+        let logs = Arc::new(Mutex::new(Vec::new()));
+        let sink_logs = logs.clone();
+        let sink: LogSink = Arc::new(move |entry| sink_logs.lock().unwrap().push(entry));
+        let request = StartStaticServerRequest {
+            root_dir: dir.to_string_lossy().to_string(),
+            port: None,
+            bind_address: None,
+            username: Some("user".to_string()),
+            password: Some("secret".to_string()),
+            directory_listing: Some(true),
+            cors: Some(true),
+            https: None,
+        };
+        let (info, handle) = launch("test".to_string(), request, sink).await.unwrap();
+        let base = info.urls[0].clone();
+        let client = reqwest::Client::new();
+
+        let response = client
+            .get(format!("{}sub/hello.txt", base))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status().as_u16(), 401);
+
+        let response = client
+            .get(format!("{}sub/hello.txt", base))
+            .basic_auth("user", Some("secret"))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.text().await.unwrap(), "hello");
+
+        let listing = client
+            .get(format!("{}sub/", base))
+            .basic_auth("user", Some("secret"))
+            .send()
+            .await
+            .unwrap()
+            .text()
+            .await
+            .unwrap();
+        assert!(listing.contains("href=\"hello.txt\""));
+
+        handle.abort();
+        let statuses: Vec<u16> = logs.lock().unwrap().iter().map(|e| e.status).collect();
+        assert_eq!(statuses, vec![401, 200, 200]);
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,574 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EscapeStringRequest {
+    pub input: String,
+    pub language: String,  // json, go, rust, python, java, c, shell, sql, regex
+    pub direction: String, // escape 或 unescape
+    pub quote: Option<bool>, // 转义时是否加上引号，默认 true；shell 和 sql 始终带引号
+    pub raw: Option<bool>, // 转义时优先使用原始字符串（Go 反引号、Rust r#""#）
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EscapeStringResult {
+    pub output: String,
+    pub warnings: Vec<String>,
+}
+
+/// 按目标语言的字面量规则转义或反转义字符串
+#[tauri::command]
+pub fn escape_string(request: EscapeStringRequest) -> Result<EscapeStringResult, String> {
+    let language = request.language.trim().to_lowercase();
+    let language = match language.as_str() {
+        "golang" => "go",
+        "py" => "python",
+        "cpp" | "c++" => "c",
+        "bash" | "sh" | "posix" => "shell",
+        other => other,
+    };
+    if !matches!(
+        language,
+        "json" | "go" | "rust" | "python" | "java" | "c" | "shell" | "sql" | "regex"
+    ) {
+        return Err(format!("不支持的语言: {}", request.language));
+    }
+
+    let mut warnings = Vec::new();
+    let output = match request.direction.as_str() {
+        "escape" => escape(
+            &request.input,
+            language,
+            request.quote.unwrap_or(true),
+            request.raw.unwrap_or(false),
+            &mut warnings,
+        )?,
+        "unescape" => unescape(&request.input, language, &mut warnings)?,
+        other => return Err(format!("不支持的操作: {}", other)),
+    };
+    Ok(EscapeStringResult { output, warnings })
+}
+
+fn escape(
+    input: &str,
+    language: &str,
+    quote: bool,
+    raw: bool,
+    warnings: &mut Vec<String>,
+) -> Result<String, String> {
+    if raw {
+        match raw_literal(input, language) {
+            Some(literal) => return Ok(literal),
+            None => warnings.push(format!(
+                "{} 无法使用原始字符串表示该内容，已改为普通转义",
+                language
+            )),
+        }
+    }
+
+    let escaped = match language {
+        "json" => {
+            let quoted =
+                serde_json::to_string(input).map_err(|e| format!("JSON转义失败: {}", e))?;
+            quoted[1..quoted.len() - 1].to_string()
+        }
+        "shell" => return Ok(shell_quote(input)),
+        "sql" => {
+            if input.contains('\\') {
+                warnings.push(
+                    "MySQL 默认把反斜杠当作转义符，需开启 NO_BACKSLASH_ESCAPES 或将反斜杠再转义"
+                        .to_string(),
+                );
+            }
+            return Ok(format!("'{}'", input.replace('\'', "''")));
+        }
+        "regex" => return Ok(regex::escape(input)),
+        _ => escape_c_like(input, language),
+    };
+    Ok(if quote {
+        format!("\"{}\"", escaped)
+    } else {
+        escaped
+    })
+}
+
+/// Go 反引号字符串不能包含反引号和 \r；Rust 原始字符串用足够多的 # 包裹
+fn raw_literal(input: &str, language: &str) -> Option<String> {
+    match language {
+        "go" if !input.contains('`') && !input.contains('\r') => Some(format!("`{}`", input)),
+        "rust" => {
+            let mut hashes = 0;
+            let mut run = None;
+            for c in input.chars() {
+                run = match (c, run) {
+                    ('"', _) => Some(0),
+                    ('#', Some(n)) => Some(n + 1),
+                    _ => None,
+                };
+                if let Some(n) = run {
+                    hashes = hashes.max(n + 1);
+                }
+            }
+            let fence = "#".repeat(hashes);
+            Some(format!("r{}\"{}\"{}", fence, input, fence))
+        }
+        _ => None,
+    }
+}
+
+fn escape_c_like(input: &str, language: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    for c in input.chars() {
+        match c {
+            '\\' => output.push_str("\\\\"),
+            '"' => output.push_str("\\\""),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            '\u{7}' if matches!(language, "go" | "python" | "c") => output.push_str("\\a"),
+            '\u{8}' if language != "rust" => output.push_str("\\b"),
+            '\u{b}' if matches!(language, "go" | "python" | "c") => output.push_str("\\v"),
+            '\u{c}' if language != "rust" => output.push_str("\\f"),
+            '\0' if language == "rust" => output.push_str("\\0"),
+            c if c.is_control() => {
+                let code = c as u32;
+                match language {
+                    "rust" => output.push_str(&format!("\\u{{{:x}}}", code)),
+                    "java" => output.push_str(&format!("\\u{:04x}", code)),
+                    // C 的 \x 会贪婪匹配后续十六进制字符，固定使用三位八进制
+                    "c" if code < 0x100 => output.push_str(&format!("\\{:03o}", code)),
+                    "go" | "python" if code < 0x100 => output.push_str(&format!("\\x{:02x}", code)),
+                    _ => output.push_str(&format!("\\u{:04x}", code)),
+                }
+            }
+            c => output.push(c),
+        }
+    }
+    output
+}
+
+/// POSIX shell 单引号内没有转义，单引号本身用 '\'' 拼接
+fn shell_quote(input: &str) -> String {
+    let safe = !input.is_empty()
+        && input
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "_@%+=:,./-".contains(c));
+    if safe {
+        input.to_string()
+    } else {
+        format!("'{}'", input.replace('\'', "'\\''"))
+    }
+}
+
+fn unescape(input: &str, language: &str, warnings: &mut Vec<String>) -> Result<String, String> {
+    match language {
+        "json" => {
+            let quoted = if is_quoted(input, '"') {
+                input.to_string()
+            } else {
+                format!("\"{}\"", input)
+            };
+            serde_json::from_str::<String>(&quoted).map_err(|e| format!("JSON反转义失败: {}", e))
+        }
+        "shell" => unescape_shell(input),
+        "sql" => {
+            let inner = if is_quoted(input, '\'') {
+                &input[1..input.len() - 1]
+            } else {
+                input
+            };
+            Ok(inner.replace("''", "'"))
+        }
+        "regex" => Ok(unescape_regex(input, warnings)),
+        _ => {
+            if let Some(raw) = strip_raw_literal(input, language) {
+                return Ok(raw.to_string());
+            }
+            let inner = if is_quoted(input, '"') || (language != "go" && is_quoted(input, '\'')) {
+                &input[1..input.len() - 1]
+            } else {
+                input
+            };
+            unescape_c_like(inner, language)
+        }
+    }
+}
+
+fn is_quoted(input: &str, quote: char) -> bool {
+    input.len() >= 2 && input.starts_with(quote) && input.ends_with(quote)
+}
+
+/// 原始字符串字面量不做反转义，直接去掉外层符号
+fn strip_raw_literal<'a>(input: &'a str, language: &str) -> Option<&'a str> {
+    match language {
+        "go" if is_quoted(input, '`') => Some(&input[1..input.len() - 1]),
+        "rust" => {
+            let rest = input.strip_prefix('r')?;
+            let hashes = rest.len() - rest.trim_start_matches('#').len();
+            let fence = &rest[..hashes];
+            rest[hashes..]
+                .strip_prefix('"')?
+                .strip_suffix(fence)?
+                .strip_suffix('"')
+        }
+        "python" => {
+            let rest = input.strip_prefix(['r', 'R'])?;
+            ["\"\"\"", "'''", "\"", "'"]
+                .iter()
+                .find_map(|q| rest.strip_prefix(q)?.strip_suffix(q))
+        }
+        _ => None,
+    }
+}
+
+fn hex_value(chars: &[char], start: usize, count: usize) -> Option<u32> {
+    let digits: String = chars.get(start..start + count)?.iter().collect();
+    if digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        u32::from_str_radix(&digits, 16).ok()
+    } else {
+        None
+    }
+}
+
+fn push_char(output: &mut Vec<u8>, code: u32, position: usize) -> Result<(), String> {
+    let c = char::from_u32(code)
+        .ok_or_else(|| format!("位置 {} 处的码点 U+{:04X} 无效", position, code))?;
+    output.extend(c.encode_utf8(&mut [0; 4]).as_bytes());
+    Ok(())
+}
+
+/// Go 和 C 的 \x、八进制转义表示字节，其余语言表示字符，结果必须是合法的 UTF-8
+fn unescape_c_like(input: &str, language: &str) -> Result<String, String> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut output = Vec::with_capacity(input.len());
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i] != '\\' {
+            push_char(&mut output, chars[i] as u32, i)?;
+            i += 1;
+            continue;
+        }
+        let position = i;
+        let invalid = || {
+            let sequence: String = chars[position..(position + 2).min(chars.len())]
+                .iter()
+                .collect();
+            format!("位置 {} 处的转义序列 {} 无效", position, sequence)
+        };
+        let escape = *chars
+            .get(i + 1)
+            .ok_or_else(|| format!("位置 {} 处的反斜杠没有后续字符", i))?;
+        i += 2;
+        let simple = match escape {
+            'n' => Some(b'\n'),
+            'r' => Some(b'\r'),
+            't' => Some(b'\t'),
+            '\\' => Some(b'\\'),
+            '"' => Some(b'"'),
+            '\'' => Some(b'\''),
+            'a' if language != "rust" && language != "java" => Some(7),
+            'b' if language != "rust" => Some(8),
+            'f' if language != "rust" => Some(12),
+            'v' if matches!(language, "go" | "python" | "c") => Some(11),
+            '?' if language == "c" => Some(b'?'),
+            '0' if language == "rust" => Some(0),
+            _ => None,
+        };
+        if let Some(byte) = simple {
+            output.push(byte);
+            continue;
+        }
+        match escape {
+            '\n' if matches!(language, "python" | "c") => {}
+            '\n' if language == "rust" => {
+                while chars.get(i).is_some_and(|c| c.is_whitespace()) {
+                    i += 1;
+                }
+            }
+            '0'..='7' if language != "rust" => {
+                let start = i - 1;
+                let count = chars[start..]
+                    .iter()
+                    .take(3)
+                    .take_while(|c| ('0'..='7').contains(*c))
+                    .count();
+                if language == "go" && count != 3 {
+                    return Err(format!("位置 {} 处的八进制转义必须是三位数字", position));
+                }
+                let digits: String = chars[start..start + count].iter().collect();
+                let value = u32::from_str_radix(&digits, 8).map_err(|_| invalid())?;
+                if value > 0xff {
+                    return Err(format!("位置 {} 处的八进制转义超出一个字节", position));
+                }
+                i = start + count;
+                if language == "java" || language == "python" {
+                    push_char(&mut output, value, position)?;
+                } else {
+                    output.push(value as u8);
+                }
+            }
+            'x' if language != "java" => {
+                let value = hex_value(&chars, i, 2).ok_or_else(invalid)?;
+                i += 2;
+                match language {
+                    "go" | "c" => output.push(value as u8),
+                    "rust" if value > 0x7f => {
+                        return Err(format!("位置 {} 处的 \\x 转义不能超过 7F", position))
+                    }
+                    _ => push_char(&mut output, value, position)?,
+                }
+            }
+            'u' if language == "rust" => {
+                let end = chars[i..]
+                    .iter()
+                    .position(|c| *c == '}')
+                    .map(|p| i + p)
+                    .filter(|_| chars.get(i) == Some(&'{'))
+                    .ok_or_else(invalid)?;
+                let digits: String = chars[i + 1..end].iter().filter(|c| **c != '_').collect();
+                let value = u32::from_str_radix(&digits, 16).map_err(|_| invalid())?;
+                i = end + 1;
+                push_char(&mut output, value, position)?;
+            }
+            'u' => {
+                let mut value = hex_value(&chars, i, 4).ok_or_else(invalid)?;
+                i += 4;
+                // Java 和 Python 源码中常见用代理对表示 BMP 之外的字符
+                if (0xd800..0xdc00).contains(&value) {
+                    let low = (chars.get(i) == Some(&'\\') && chars.get(i + 1) == Some(&'u'))
+                        .then(|| hex_value(&chars, i + 2, 4))
+                        .flatten()
+                        .filter(|low| (0xdc00..0xe000).contains(low))
+                        .ok_or_else(|| format!("位置 {} 处是孤立的代理项", position))?;
+                    value = 0x10000 + ((value - 0xd800) << 10) + (low - 0xdc00);
+                    i += 6;
+                }
+                push_char(&mut output, value, position)?;
+            }
+            'U' if language != "java" => {
+                let value = hex_value(&chars, i, 8).ok_or_else(invalid)?;
+                i += 8;
+                push_char(&mut output, value, position)?;
+            }
+            'N' if language == "python" && chars.get(i) == Some(&'{') => {
+                let end = chars[i..]
+                    .iter()
+                    .position(|c| *c == '}')
+                    .map(|p| i + p)
+                    .ok_or_else(invalid)?;
+                let name: String = chars[i + 1..end].iter().collect();
+                let c = unicode_names2::character(&name)
+                    .ok_or_else(|| format!("位置 {} 处的字符名称 {} 不存在", position, name))?;
+                i = end + 1;
+                push_char(&mut output, c as u32, position)?;
+            }
+            _ => return Err(invalid()),
+        }
+    }
+    String::from_utf8(output).map_err(|e| {
+        format!(
+            "反转义结果不是有效的 UTF-8（第 {} 字节）",
+            e.utf8_error().valid_up_to()
+        )
+    })
+}
+
+/// 按 POSIX shell 规则解析一个参数，支持单引号、双引号、反斜杠和 bash 的 $'...'
+fn unescape_shell(input: &str) -> Result<String, String> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut output = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '\'' => {
+                let end = chars[i + 1..]
+                    .iter()
+                    .position(|c| *c == '\'')
+                    .ok_or_else(|| format!("位置 {} 处的单引号没有闭合", i))?;
+                output.extend(&chars[i + 1..i + 1 + end]);
+                i += end + 2;
+            }
+            '"' => {
+                let start = i;
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        None => return Err(format!("位置 {} 处的双引号没有闭合", start)),
+                        Some('"') => break,
+                        Some('\\') if matches!(chars.get(i + 1), Some('$' | '`' | '"' | '\\')) => {
+                            output.push(chars[i + 1]);
+                            i += 2;
+                        }
+                        Some('\\') if chars.get(i + 1) == Some(&'\n') => i += 2,
+                        Some(c) => {
+                            output.push(*c);
+                            i += 1;
+                        }
+                    }
+                }
+                i += 1;
+            }
+            '$' if chars.get(i + 1) == Some(&'\'') => {
+                let start = i;
+                i += 2;
+                let mut body = String::new();
+                loop {
+                    match chars.get(i) {
+                        None => return Err(format!("位置 {} 处的 $' 没有闭合", start)),
+                        Some('\'') => break,
+                        Some('\\') if i + 1 < chars.len() => {
+                            body.push('\\');
+                            body.push(chars[i + 1]);
+                            i += 2;
+                        }
+                        Some(c) => {
+                            body.push(*c);
+                            i += 1;
+                        }
+                    }
+                }
+                output.push_str(&unescape_c_like(&body, "c")?);
+                i += 1;
+            }
+            '\\' => {
+                match chars.get(i + 1) {
+                    Some('\n') | None => {}
+                    Some(c) => output.push(*c),
+                }
+                i += 2;
+            }
+            c => {
+                output.push(c);
+                i += 1;
+            }
+        }
+    }
+    Ok(output)
+}
+
+fn unescape_regex(input: &str, warnings: &mut Vec<String>) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut chars = input.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            output.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some(next) if next.is_ascii_alphanumeric() => {
+                if warnings.is_empty() {
+                    warnings.push(format!("\\{} 等字符类无法还原为普通文本，已原样保留", next));
+                }
+                output.push('\\');
+                output.push(next);
+            }
+            Some(next) => output.push(next),
+            None => output.push('\\'),
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(input: &str, language: &str, direction: &str) -> Result<String, String> {
+        escape_string(EscapeStringRequest {
+            input: input.to_string(),
+            language: language.to_string(),
+            direction: direction.to_string(),
+            quote: None,
+            raw: None,
+        })
+        .map(|r| r.output)
+    }
+
+    #[test]
+    fn test_escape_languages() {
+        let text = "line \"1\"\n\ttab\\ \u{1}é";
+        assert_eq!(
+            run(text, "json", "escape").unwrap(),
+            "\"line \\\"1\\\"\\n\\ttab\\\\ \\u0001é\""
+        );
+        assert_eq!(
+            run(text, "go", "escape").unwrap(),
+            "\"line \\\"1\\\"\\n\\ttab\\\\ \\x01é\""
+        );
+        assert_eq!(
+            run(text, "rust", "escape").unwrap(),
+            "\"line \\\"1\\\"\\n\\ttab\\\\ \\u{1}é\""
+        );
+        assert_eq!(
+            run(text, "java", "escape").unwrap(),
+            "\"line \\\"1\\\"\\n\\ttab\\\\ \\u0001é\""
+        );
+        assert_eq!(
+            run(text, "c", "escape").unwrap(),
+            "\"line \\\"1\\\"\\n\\ttab\\\\ \\001é\""
+        );
+        assert_eq!(
+            run("it's a test", "shell", "escape").unwrap(),
+            "'it'\\''s a test'"
+        );
+        assert_eq!(
+            run("safe/path-1.txt", "shell", "escape").unwrap(),
+            "safe/path-1.txt"
+        );
+        assert_eq!(run("O'Brien", "sql", "escape").unwrap(), "'O''Brien'");
+        assert_eq!(
+            run("a.b*(c)", "regex", "escape").unwrap(),
+            "a\\.b\\*\\(c\\)"
+        );
+
+        let raw = escape_string(EscapeStringRequest {
+            input: "say \"#hi\"#".to_string(),
+            language: "rust".to_string(),
+            direction: "escape".to_string(),
+            quote: None,
+            raw: Some(true),
+        })
+        .unwrap();
+        assert_eq!(raw.output, "r##\"say \"#hi\"#\"##");
+
+        for language in ["json", "go", "rust", "python", "java", "c", "shell", "sql"] {
+            let escaped = run(text, language, "escape").unwrap();
+            assert_eq!(
+                run(&escaped, language, "unescape").unwrap(),
+                text,
+                "{}",
+                language
+            );
+        }
+        assert!(run("x", "perl", "escape").is_err());
+    }
+
+    #[test]
+    fn test_unescape_sequences() {
+        assert_eq!(run("\\xe4\\xb8\\xad", "go", "unescape").unwrap(), "中");
+        assert_eq!(
+            run("\\u4e2d\\U0001F600", "python", "unescape").unwrap(),
+            "中😀"
+        );
+        assert_eq!(run("\\N{SNOWMAN}", "python", "unescape").unwrap(), "☃");
+        assert_eq!(run("\\uD83D\\uDE00", "java", "unescape").unwrap(), "😀");
+        assert_eq!(run("\\u{1F600}\\0", "rust", "unescape").unwrap(), "😀\0");
+        assert_eq!(run("r#\"a\\n\"#", "rust", "unescape").unwrap(), "a\\n");
+        assert_eq!(run("\\101\\7", "c", "unescape").unwrap(), "A\u{7}");
+        assert_eq!(
+            run("'a b'\"\\$x\"$'\\t'c\\ d", "shell", "unescape").unwrap(),
+            "a b$x\tc d"
+        );
+        assert_eq!(run("\\.\\*", "regex", "unescape").unwrap(), ".*");
+
+        assert!(run("\\q", "go", "unescape")
+            .unwrap_err()
+            .contains("\\q 无效"));
+        assert!(run("\\uD83D", "java", "unescape")
+            .unwrap_err()
+            .contains("孤立的代理项"));
+        assert!(run("\\xff", "go", "unescape")
+            .unwrap_err()
+            .contains("UTF-8"));
+        assert!(run("'open", "shell", "unescape").is_err());
+    }
+}
@@ -1,16 +1,42 @@
+use crate::tools::sql_to_go::SqlTypeMappingProfile;
+use crate::tools::video_converter::VideoPreset;
+use crate::utils::i18n::{self, Language};
+use crate::utils::notification::{
+    self, NotificationKind, NotificationRecord, NotificationSettings,
+};
+use crate::utils::proxy::{self, ProxyConfig, ProxySettings, SystemProxyInfo};
+use crate::utils::rate_limit::{self, Aggressiveness};
+use crate::utils::usage::{self, UsageReport};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
 use std::sync::Mutex;
-use tauri::menu::{Menu, MenuItem};
+use tauri::menu::{Menu, MenuItem, PredefinedMenuItem};
 use tauri::tray::{MouseButton, MouseButtonState, TrayIcon, TrayIconBuilder, TrayIconEvent};
-use tauri::{AppHandle, Manager, State};
+use tauri::{AppHandle, Emitter, Manager, State, Wry};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AppConfig {
     pub tray_enabled: bool,
     pub start_minimized: bool,
     pub close_to_tray: bool,
+    #[serde(default = "default_tray_actions")]
+    pub tray_actions: Vec<TrayAction>,
+    #[serde(default)]
+    pub video_presets: Vec<VideoPreset>, // 用户自定义的视频转换预设
+    #[serde(default = "default_language")]
+    pub language: String, // 后端消息和托盘菜单的语言：zh / en
+    #[serde(default = "default_network_aggressiveness")]
+    pub network_aggressiveness: String, // 对 WHOIS、RDAP 等外部服务的请求强度：gentle / normal / aggressive
+    #[serde(default)]
+    pub proxy: ProxyConfig, // 外部 HTTP 请求使用的代理
+    #[serde(default)]
+    pub usage_tracking: bool, // 是否在本地记录工具使用情况，默认关闭
+    #[serde(default)]
+    pub notifications: NotificationSettings, // 系统通知的开关和免打扰时段
+    #[serde(default)]
+    pub sql_type_profiles: Vec<SqlTypeMappingProfile>, // 用户自定义的 SQL→Go 类型映射方案
 }
 
 impl Default for AppConfig {
@@ -19,10 +45,75 @@ impl Default for AppConfig {
             tray_enabled: true,
             start_minimized: false,
             close_to_tray: true, // 默认启用关闭时最小化到托盘
+            tray_actions: default_tray_actions(),
+            video_presets: Vec::new(),
+            language: default_language(),
+            network_aggressiveness: default_network_aggressiveness(),
+            proxy: ProxyConfig::default(),
+            usage_tracking: false,
+            notifications: NotificationSettings::default(),
+            sql_type_profiles: Vec::new(),
         }
     }
 }
 
+// 托盘菜单快捷操作
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TrayAction {
+    Show,
+    OpenTool { tool_id: String, label: String },
+    FormatClipboardJson,
+    CheckClipboardSsl,
+    Quit,
+}
+
+impl TrayAction {
+    fn menu_id(&self) -> String {
+        match self {
+            TrayAction::Show => "show".to_string(),
+            TrayAction::OpenTool { tool_id, .. } => format!("open_tool:{}", tool_id),
+            TrayAction::FormatClipboardJson => "format_clipboard_json".to_string(),
+            TrayAction::CheckClipboardSsl => "check_clipboard_ssl".to_string(),
+            TrayAction::Quit => "quit".to_string(),
+        }
+    }
+
+    fn label(&self) -> String {
+        match self {
+            TrayAction::Show => i18n::t("tray.show"),
+            TrayAction::OpenTool { label, .. } => label.clone(),
+            TrayAction::FormatClipboardJson => i18n::t("tray.format_clipboard_json"),
+            TrayAction::CheckClipboardSsl => i18n::t("tray.check_clipboard_ssl"),
+            TrayAction::Quit => i18n::t("tray.quit"),
+        }
+    }
+}
+
+fn default_language() -> String {
+    Language::Zh.code().to_string()
+}
+
+fn default_network_aggressiveness() -> String {
+    Aggressiveness::Normal.code().to_string()
+}
+
+fn default_tray_actions() -> Vec<TrayAction> {
+    vec![
+        TrayAction::Show,
+        TrayAction::FormatClipboardJson,
+        TrayAction::CheckClipboardSsl,
+        TrayAction::Quit,
+    ]
+}
+
+// 发送给前端的快捷操作事件，剪贴板内容由前端读取
+#[derive(Debug, Clone, Serialize)]
+pub struct TrayActionEvent {
+    pub action: String,
+    pub tool_id: Option<String>,
+}
+
 // 全局托盘状态，保存托盘图标引用和可见状态
 pub struct GlobalTrayState {
     pub tray_icon: Mutex<Option<TrayIcon>>,
@@ -33,6 +124,7 @@ pub struct GlobalTrayState {
 impl GlobalTrayState {
     pub fn new() -> Self {
         let config = load_config().unwrap_or_default();
+        apply_runtime_settings(&config);
         Self {
             tray_icon: Mutex::new(None),
             is_visible: Mutex::new(config.tray_enabled),
@@ -41,6 +133,34 @@ impl GlobalTrayState {
     }
 }
 
+/// 把配置中的语言、代理等设置应用到运行时状态
+fn apply_runtime_settings(config: &AppConfig) {
+    if let Some(language) = Language::from_code(&config.language) {
+        i18n::set_language(language);
+    }
+    if let Some(aggressiveness) = Aggressiveness::from_code(&config.network_aggressiveness) {
+        rate_limit::set_aggressiveness(aggressiveness);
+    }
+    proxy::set_config(config.proxy.clone());
+    usage::set_enabled(config.usage_tracking);
+    notification::set_settings(config.notifications.clone());
+}
+
+/// 重新读取配置文件并刷新运行时设置和托盘菜单，用于导入备份之后
+pub(crate) fn reload_config(app: &AppHandle, tray_state: &GlobalTrayState) -> Result<(), String> {
+    let config = load_config().map_err(|e| e.to_string())?;
+    apply_runtime_settings(&config);
+    let actions = config.tray_actions.clone();
+    *tray_state.config.lock().map_err(|e| e.to_string())? = config;
+
+    let tray_icon = tray_state.tray_icon.lock().map_err(|e| e.to_string())?;
+    if let Some(ref tray) = *tray_icon {
+        let menu = build_tray_menu(app, &actions).map_err(|e| e.to_string())?;
+        tray.set_menu(Some(menu)).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn toggle_tray(
     app: AppHandle,
@@ -54,7 +174,7 @@ pub async fn toggle_tray(
     if enabled {
         // 启用托盘 - 如果没有托盘，创建一个；如果有托盘，设为可见
         if tray_icon.is_none() {
-            let tray = create_tray_icon(&app).map_err(|e| e.to_string())?;
+            let tray = create_tray_icon(&app, &config.tray_actions).map_err(|e| e.to_string())?;
             *tray_icon = Some(tray);
         } else if let Some(ref tray) = *tray_icon {
             tray.set_visible(true).map_err(|e| e.to_string())?;
@@ -83,7 +203,7 @@ fn get_config_path() -> PathBuf {
         .join("devtools-config.json")
 }
 
-fn load_config() -> Result<AppConfig, Box<dyn std::error::Error>> {
+pub(crate) fn load_config() -> Result<AppConfig, Box<dyn std::error::Error>> {
     let config_path = get_config_path();
     if config_path.exists() {
         let content = fs::read_to_string(config_path)?;
@@ -93,7 +213,7 @@ fn load_config() -> Result<AppConfig, Box<dyn std::error::Error>> {
     }
 }
 
-fn save_config(config: &AppConfig) -> Result<(), Box<dyn std::error::Error>> {
+pub(crate) fn save_config(config: &AppConfig) -> Result<(), Box<dyn std::error::Error>> {
     let config_path = get_config_path();
     if let Some(parent) = config_path.parent() {
         fs::create_dir_all(parent)?;
@@ -147,27 +267,310 @@ pub async fn get_close_to_tray_status(
     Ok(config.close_to_tray)
 }
 
-pub fn create_tray_icon(app: &AppHandle) -> tauri::Result<TrayIcon> {
-    let show = MenuItem::with_id(app, "show", "显示", true, None::<&str>)?;
-    let quit = MenuItem::with_id(app, "quit", "退出", true, None::<&str>)?;
+#[tauri::command]
+pub async fn get_tray_actions(
+    tray_state: State<'_, GlobalTrayState>,
+) -> Result<Vec<TrayAction>, String> {
+    let config = tray_state.config.lock().map_err(|e| e.to_string())?;
+    Ok(config.tray_actions.clone())
+}
+
+#[tauri::command]
+pub async fn set_tray_actions(
+    app: AppHandle,
+    tray_state: State<'_, GlobalTrayState>,
+    actions: Vec<TrayAction>,
+) -> Result<Vec<TrayAction>, String> {
+    let mut actions = actions;
+    actions.dedup_by(|a, b| a.menu_id() == b.menu_id());
+    // 始终保留退出项，避免托盘菜单无法退出程序
+    if !actions.contains(&TrayAction::Quit) {
+        actions.push(TrayAction::Quit);
+    }
+
+    {
+        let mut config = tray_state.config.lock().map_err(|e| e.to_string())?;
+        config.tray_actions = actions.clone();
+        save_config(&config).map_err(|e| e.to_string())?;
+    }
+
+    // 托盘已创建时立即刷新菜单
+    let tray_icon = tray_state.tray_icon.lock().map_err(|e| e.to_string())?;
+    if let Some(ref tray) = *tray_icon {
+        let menu = build_tray_menu(&app, &actions).map_err(|e| e.to_string())?;
+        tray.set_menu(Some(menu)).map_err(|e| e.to_string())?;
+    }
+
+    Ok(actions)
+}
+
+#[tauri::command]
+pub async fn get_language(tray_state: State<'_, GlobalTrayState>) -> Result<String, String> {
+    let config = tray_state.config.lock().map_err(|e| e.to_string())?;
+    Ok(config.language.clone())
+}
+
+/// 切换后端消息语言，托盘菜单随之刷新
+#[tauri::command]
+pub async fn set_language(
+    app: AppHandle,
+    tray_state: State<'_, GlobalTrayState>,
+    language: String,
+) -> Result<String, String> {
+    let parsed = Language::from_code(&language)
+        .ok_or_else(|| i18n::tr("settings.unsupported_language", &[&language]))?;
+    i18n::set_language(parsed);
+
+    let actions = {
+        let mut config = tray_state.config.lock().map_err(|e| e.to_string())?;
+        config.language = parsed.code().to_string();
+        save_config(&config).map_err(|e| e.to_string())?;
+        config.tray_actions.clone()
+    };
+
+    let tray_icon = tray_state.tray_icon.lock().map_err(|e| e.to_string())?;
+    if let Some(ref tray) = *tray_icon {
+        let menu = build_tray_menu(&app, &actions).map_err(|e| e.to_string())?;
+        tray.set_menu(Some(menu)).map_err(|e| e.to_string())?;
+    }
+    Ok(parsed.code().to_string())
+}
+
+#[tauri::command]
+pub async fn get_network_aggressiveness(
+    tray_state: State<'_, GlobalTrayState>,
+) -> Result<String, String> {
+    let config = tray_state.config.lock().map_err(|e| e.to_string())?;
+    Ok(config.network_aggressiveness.clone())
+}
+
+/// 设置外部服务的请求强度，影响并发上限、请求间隔和限流重试次数
+#[tauri::command]
+pub async fn set_network_aggressiveness(
+    tray_state: State<'_, GlobalTrayState>,
+    aggressiveness: String,
+) -> Result<String, String> {
+    let parsed = Aggressiveness::from_code(&aggressiveness)
+        .ok_or_else(|| i18n::tr("settings.unsupported_aggressiveness", &[&aggressiveness]))?;
+    rate_limit::set_aggressiveness(parsed);
+
+    let mut config = tray_state.config.lock().map_err(|e| e.to_string())?;
+    config.network_aggressiveness = parsed.code().to_string();
+    save_config(&config).map_err(|e| e.to_string())?;
+    Ok(parsed.code().to_string())
+}
+
+#[tauri::command]
+pub async fn get_proxy_config(
+    tray_state: State<'_, GlobalTrayState>,
+) -> Result<ProxyConfig, String> {
+    let config = tray_state.config.lock().map_err(|e| e.to_string())?;
+    Ok(config.proxy.clone())
+}
+
+/// 保存代理配置，立即对之后创建的 HTTP 客户端生效
+#[tauri::command]
+pub async fn set_proxy_config(
+    tray_state: State<'_, GlobalTrayState>,
+    proxy_config: ProxyConfig,
+) -> Result<ProxyConfig, String> {
+    proxy::validate(&proxy_config)?;
+    proxy::set_config(proxy_config.clone());
+
+    let mut config = tray_state.config.lock().map_err(|e| e.to_string())?;
+    config.proxy = proxy_config;
+    save_config(&config).map_err(|e| e.to_string())?;
+    Ok(config.proxy.clone())
+}
+
+#[tauri::command]
+pub async fn get_usage_tracking(tray_state: State<'_, GlobalTrayState>) -> Result<bool, String> {
+    let config = tray_state.config.lock().map_err(|e| e.to_string())?;
+    Ok(config.usage_tracking)
+}
+
+/// 开启或关闭本地使用统计，统计数据只保存在本机
+#[tauri::command]
+pub async fn set_usage_tracking(
+    tray_state: State<'_, GlobalTrayState>,
+    enabled: bool,
+) -> Result<bool, String> {
+    usage::set_enabled(enabled);
+
+    let mut config = tray_state.config.lock().map_err(|e| e.to_string())?;
+    config.usage_tracking = enabled;
+    save_config(&config).map_err(|e| e.to_string())?;
+    Ok(enabled)
+}
+
+/// 查看各命令的调用次数和长任务耗时
+#[tauri::command]
+pub async fn get_usage_stats() -> Result<UsageReport, String> {
+    Ok(usage::report())
+}
 
-    let menu = Menu::with_items(app, &[&show, &quit])?;
+/// 导出使用统计为 JSON 文件，便于反馈给维护者
+#[tauri::command]
+pub async fn export_usage_stats(file_path: String) -> Result<UsageReport, String> {
+    usage::export(&PathBuf::from(file_path))
+}
+
+#[tauri::command]
+pub async fn clear_usage_stats() -> Result<(), String> {
+    usage::clear()
+}
+
+#[tauri::command]
+pub async fn get_notification_settings(
+    tray_state: State<'_, GlobalTrayState>,
+) -> Result<NotificationSettings, String> {
+    let config = tray_state.config.lock().map_err(|e| e.to_string())?;
+    Ok(config.notifications.clone())
+}
+
+/// 保存通知设置，关闭的类型和免打扰时段内的通知只记录到历史
+#[tauri::command]
+pub async fn set_notification_settings(
+    tray_state: State<'_, GlobalTrayState>,
+    settings: NotificationSettings,
+) -> Result<NotificationSettings, String> {
+    notification::validate(&settings)?;
+    notification::set_settings(settings.clone());
+
+    let mut config = tray_state.config.lock().map_err(|e| e.to_string())?;
+    config.notifications = settings;
+    save_config(&config).map_err(|e| e.to_string())?;
+    Ok(config.notifications.clone())
+}
+
+/// 查询通知历史，可按类型和未读筛选
+#[tauri::command]
+pub async fn get_notification_history(
+    kind: Option<NotificationKind>,
+    unread_only: Option<bool>,
+    limit: Option<usize>,
+) -> Result<Vec<NotificationRecord>, String> {
+    Ok(notification::history(
+        kind,
+        unread_only.unwrap_or(false),
+        limit,
+    ))
+}
+
+/// 标记通知为已读，不指定 ids 时标记全部，返回标记的条数
+#[tauri::command]
+pub async fn mark_notifications_read(ids: Option<Vec<String>>) -> Result<usize, String> {
+    notification::mark_read(ids)
+}
+
+#[tauri::command]
+pub async fn clear_notification_history() -> Result<(), String> {
+    notification::clear_history()
+}
+
+/// 检测环境变量中的系统代理
+#[tauri::command]
+pub fn get_system_proxy() -> SystemProxyInfo {
+    proxy::detect_system_proxy()
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProxyTestResult {
+    pub success: bool,
+    pub status: Option<u16>,
+    pub latency_ms: u64,
+    pub error: Option<String>,
+}
+
+/// 使用给定代理设置访问测试地址，默认 https://example.com
+#[tauri::command]
+pub async fn test_proxy(
+    settings: ProxySettings,
+    url: Option<String>,
+) -> Result<ProxyTestResult, String> {
+    let url = url
+        .map(|u| u.trim().to_string())
+        .filter(|u| !u.is_empty())
+        .unwrap_or_else(|| "https://example.com".to_string());
+    let client = proxy::client_builder_with(&settings)?
+        .timeout(std::time::Duration::from_secs(10))
+        .build()
+        .map_err(|e| format!("构建 HTTP 客户端失败: {}", e))?;
+
+    let start = std::time::Instant::now();
+    let result = client.head(&url).send().await;
+    let latency_ms = start.elapsed().as_millis() as u64;
+    Ok(match result {
+        Ok(response) => ProxyTestResult {
+            success: !response.status().is_server_error(),
+            status: Some(response.status().as_u16()),
+            latency_ms,
+            error: None,
+        },
+        Err(e) => ProxyTestResult {
+            success: false,
+            status: None,
+            latency_ms,
+            error: Some(e.to_string()),
+        },
+    })
+}
+
+/// 获取消息目录，为空时使用当前语言
+#[tauri::command]
+pub fn get_message_catalog(language: Option<String>) -> Result<BTreeMap<String, String>, String> {
+    let language = match language {
+        Some(code) => Language::from_code(&code)
+            .ok_or_else(|| i18n::tr("settings.unsupported_language", &[&code]))?,
+        None => i18n::current_language(),
+    };
+    Ok(i18n::catalog(language))
+}
+
+fn build_tray_menu(app: &AppHandle, actions: &[TrayAction]) -> tauri::Result<Menu<Wry>> {
+    let menu = Menu::new(app)?;
+    for (index, action) in actions.iter().enumerate() {
+        if *action == TrayAction::Quit && index > 0 {
+            menu.append(&PredefinedMenuItem::separator(app)?)?;
+        }
+        let item = MenuItem::with_id(app, action.menu_id(), action.label(), true, None::<&str>)?;
+        menu.append(&item)?;
+    }
+    Ok(menu)
+}
+
+fn handle_tray_menu_event(app: &AppHandle, id: &str) {
+    if id == "quit" {
+        app.exit(0);
+        return;
+    }
+
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+
+    let event = match id.split_once(':') {
+        Some(("open_tool", tool_id)) => TrayActionEvent {
+            action: "open_tool".to_string(),
+            tool_id: Some(tool_id.to_string()),
+        },
+        _ if id == "format_clipboard_json" || id == "check_clipboard_ssl" => TrayActionEvent {
+            action: id.to_string(),
+            tool_id: None,
+        },
+        _ => return,
+    };
+    let _ = app.emit("tray-quick-action", event);
+}
+
+pub fn create_tray_icon(app: &AppHandle, actions: &[TrayAction]) -> tauri::Result<TrayIcon> {
+    let menu = build_tray_menu(app, actions)?;
 
     let tray = TrayIconBuilder::new()
         .icon(app.default_window_icon().unwrap().clone())
         .menu(&menu)
-        .on_menu_event(move |app, event| match event.id().as_ref() {
-            "show" => {
-                if let Some(window) = app.get_webview_window("main") {
-                    let _ = window.show();
-                    let _ = window.set_focus();
-                }
-            }
-            "quit" => {
-                app.exit(0);
-            }
-            _ => {}
-        })
+        .on_menu_event(move |app, event| handle_tray_menu_event(app, event.id().as_ref()))
         .on_tray_icon_event(|tray, event| {
             if let TrayIconEvent::Click {
                 button: MouseButton::Left,
@@ -186,6 +589,17 @@ pub fn create_tray_icon(app: &AppHandle) -> tauri::Result<TrayIcon> {
     Ok(tray)
 }
 
+/// 更新托盘提示文字，用于展示后台检查的失败情况，传入 None 时恢复默认
+pub fn set_tray_tooltip(app: &AppHandle, text: Option<&str>) {
+    let tray_state = app.state::<GlobalTrayState>();
+    let Ok(tray_icon) = tray_state.tray_icon.lock() else {
+        return;
+    };
+    if let Some(ref tray) = *tray_icon {
+        let _ = tray.set_tooltip(Some(text.unwrap_or("devtools")));
+    }
+}
+
 // 处理窗口关闭事件
 pub fn handle_window_close_event(app: &AppHandle) -> bool {
     // 检查托盘状态和关闭到托盘设置
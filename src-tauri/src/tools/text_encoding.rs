@@ -0,0 +1,440 @@
+use chardetng::EncodingDetector;
+use encoding_rs::Encoding;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// 预览返回的最大字符数
+const PREVIEW_CHARS: usize = 2000;
+
+/// 前端可选的目标编码
+const SUPPORTED_ENCODINGS: &[&str] = &[
+    "UTF-8",
+    "UTF-16LE",
+    "UTF-16BE",
+    "UTF-32LE",
+    "UTF-32BE",
+    "GBK",
+    "GB18030",
+    "Big5",
+    "Shift_JIS",
+    "EUC-JP",
+    "EUC-KR",
+    "ISO-8859-1",
+    "windows-1252",
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LineEndingStats {
+    pub lf: usize,
+    pub crlf: usize,
+    pub cr: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EncodingDetection {
+    pub encoding: String,
+    pub confident: bool,
+    pub has_bom: bool,
+    pub line_endings: LineEndingStats,
+    pub decode_errors: bool,
+    pub preview: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConvertEncodingRequest {
+    pub path: String,
+    pub output_path: Option<String>,     // 为空时在原文件旁生成新文件
+    pub source_encoding: Option<String>, // 为空时自动检测
+    pub target_encoding: String,
+    pub line_ending: Option<String>, // lf, crlf, keep
+    pub bom: Option<bool>,           // 仅对 Unicode 编码生效，为空时保持原文件是否有 BOM
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConvertEncodingResult {
+    pub output_path: String,
+    pub source_encoding: String,
+    pub target_encoding: String,
+    pub detection: EncodingDetection,
+    pub bytes_written: u64,
+    pub lossy: bool, // 目标编码无法表示的字符已被替换
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TextEncoding {
+    Utf8,
+    Utf16Le,
+    Utf16Be,
+    Utf32Le,
+    Utf32Be,
+    Latin1,
+    Legacy(&'static Encoding),
+}
+
+impl TextEncoding {
+    fn from_label(label: &str) -> Result<Self, String> {
+        let normalized = label.trim().to_uppercase().replace('_', "-");
+        Ok(match normalized.as_str() {
+            "UTF-8" | "UTF8" => TextEncoding::Utf8,
+            "UTF-16" | "UTF-16LE" | "UTF16LE" => TextEncoding::Utf16Le,
+            "UTF-16BE" | "UTF16BE" => TextEncoding::Utf16Be,
+            "UTF-32" | "UTF-32LE" | "UTF32LE" => TextEncoding::Utf32Le,
+            "UTF-32BE" | "UTF32BE" => TextEncoding::Utf32Be,
+            // encoding_rs 按 WHATWG 规范把 latin1 视为 windows-1252，这里单独处理
+            "ISO-8859-1" | "LATIN1" | "LATIN-1" => TextEncoding::Latin1,
+            _ => Encoding::for_label(label.trim().as_bytes())
+                .map(TextEncoding::Legacy)
+                .ok_or_else(|| format!("不支持的编码: {}", label))?,
+        })
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            TextEncoding::Utf8 => "UTF-8",
+            TextEncoding::Utf16Le => "UTF-16LE",
+            TextEncoding::Utf16Be => "UTF-16BE",
+            TextEncoding::Utf32Le => "UTF-32LE",
+            TextEncoding::Utf32Be => "UTF-32BE",
+            TextEncoding::Latin1 => "ISO-8859-1",
+            TextEncoding::Legacy(encoding) => encoding.name(),
+        }
+    }
+
+    fn bom(&self) -> &'static [u8] {
+        match self {
+            TextEncoding::Utf8 => b"\xEF\xBB\xBF",
+            TextEncoding::Utf16Le => b"\xFF\xFE",
+            TextEncoding::Utf16Be => b"\xFE\xFF",
+            TextEncoding::Utf32Le => b"\xFF\xFE\x00\x00",
+            TextEncoding::Utf32Be => b"\x00\x00\xFE\xFF",
+            _ => b"",
+        }
+    }
+
+    /// 解码为字符串，返回是否出现无法解码的字节
+    fn decode(&self, bytes: &[u8]) -> (String, bool) {
+        match self {
+            TextEncoding::Utf32Le | TextEncoding::Utf32Be => {
+                let chunks = bytes.chunks_exact(4);
+                let mut errors = !chunks.remainder().is_empty();
+                let text = chunks
+                    .map(|chunk| {
+                        let chunk = [chunk[0], chunk[1], chunk[2], chunk[3]];
+                        let code = if *self == TextEncoding::Utf32Le {
+                            u32::from_le_bytes(chunk)
+                        } else {
+                            u32::from_be_bytes(chunk)
+                        };
+                        char::from_u32(code).unwrap_or_else(|| {
+                            errors = true;
+                            char::REPLACEMENT_CHARACTER
+                        })
+                    })
+                    .collect();
+                (text, errors)
+            }
+            TextEncoding::Latin1 => (bytes.iter().map(|&b| b as char).collect(), false),
+            _ => {
+                let encoding = match self {
+                    TextEncoding::Utf8 => encoding_rs::UTF_8,
+                    TextEncoding::Utf16Le => encoding_rs::UTF_16LE,
+                    TextEncoding::Utf16Be => encoding_rs::UTF_16BE,
+                    TextEncoding::Legacy(encoding) => encoding,
+                    _ => unreachable!(),
+                };
+                let (text, errors) = encoding.decode_without_bom_handling(bytes);
+                (text.into_owned(), errors)
+            }
+        }
+    }
+
+    /// 编码字符串，返回是否有字符无法表示
+    fn encode(&self, text: &str) -> (Vec<u8>, bool) {
+        match self {
+            TextEncoding::Utf8 => (text.as_bytes().to_vec(), false),
+            TextEncoding::Utf16Le => (
+                text.encode_utf16().flat_map(u16::to_le_bytes).collect(),
+                false,
+            ),
+            TextEncoding::Utf16Be => (
+                text.encode_utf16().flat_map(u16::to_be_bytes).collect(),
+                false,
+            ),
+            TextEncoding::Utf32Le => (
+                text.chars()
+                    .flat_map(|c| (c as u32).to_le_bytes())
+                    .collect(),
+                false,
+            ),
+            TextEncoding::Utf32Be => (
+                text.chars()
+                    .flat_map(|c| (c as u32).to_be_bytes())
+                    .collect(),
+                false,
+            ),
+            TextEncoding::Latin1 => {
+                let mut lossy = false;
+                let bytes = text
+                    .chars()
+                    .map(|c| {
+                        u8::try_from(c as u32).unwrap_or_else(|_| {
+                            lossy = true;
+                            b'?'
+                        })
+                    })
+                    .collect();
+                (bytes, lossy)
+            }
+            TextEncoding::Legacy(encoding) => {
+                let (bytes, _, lossy) = encoding.encode(text);
+                (bytes.into_owned(), lossy)
+            }
+        }
+    }
+
+    fn is_unicode(&self) -> bool {
+        !self.bom().is_empty()
+    }
+}
+
+/// 检测文件编码、BOM 和换行符
+#[tauri::command]
+pub async fn detect_text_encoding(path: String) -> Result<EncodingDetection, String> {
+    let bytes = fs::read(&path).map_err(|e| format!("读取文件失败: {}", e))?;
+    let (encoding, confident, bom_len) = detect_encoding(&bytes);
+    Ok(build_detection(&bytes, encoding, confident, bom_len))
+}
+
+/// 转换文件编码并统一换行符和 BOM
+#[tauri::command]
+pub async fn convert_text_encoding(
+    request: ConvertEncodingRequest,
+) -> Result<ConvertEncodingResult, String> {
+    let bytes = fs::read(&request.path).map_err(|e| format!("读取文件失败: {}", e))?;
+    let target = TextEncoding::from_label(&request.target_encoding)?;
+
+    let (source, confident, bom_len) = match request
+        .source_encoding
+        .as_deref()
+        .filter(|s| !s.trim().is_empty() && !s.eq_ignore_ascii_case("auto"))
+    {
+        Some(label) => {
+            let source = TextEncoding::from_label(label)?;
+            let bom = source.bom();
+            let bom_len = if !bom.is_empty() && bytes.starts_with(bom) {
+                bom.len()
+            } else {
+                0
+            };
+            (source, true, bom_len)
+        }
+        None => detect_encoding(&bytes),
+    };
+    let detection = build_detection(&bytes, source, confident, bom_len);
+
+    let (text, _) = source.decode(&bytes[bom_len..]);
+    let text = normalize_line_endings(&text, request.line_ending.as_deref().unwrap_or("keep"))?;
+    let (encoded, lossy) = target.encode(&text);
+
+    let write_bom = target.is_unicode() && request.bom.unwrap_or(bom_len > 0);
+    let mut output = Vec::with_capacity(encoded.len() + 4);
+    if write_bom {
+        output.extend_from_slice(target.bom());
+    }
+    output.extend_from_slice(&encoded);
+
+    let output_path = match request.output_path.filter(|p| !p.trim().is_empty()) {
+        Some(path) => path,
+        None => default_output_path(&request.path, target),
+    };
+    fs::write(&output_path, &output).map_err(|e| format!("写入文件失败: {}", e))?;
+
+    Ok(ConvertEncodingResult {
+        output_path,
+        source_encoding: source.name().to_string(),
+        target_encoding: target.name().to_string(),
+        detection,
+        bytes_written: output.len() as u64,
+        lossy,
+    })
+}
+
+/// 获取支持的目标编码列表
+#[tauri::command]
+pub async fn get_supported_encodings() -> Result<Vec<String>, String> {
+    Ok(SUPPORTED_ENCODINGS.iter().map(|s| s.to_string()).collect())
+}
+
+/// 返回 (编码, 是否可信, BOM 长度)
+fn detect_encoding(bytes: &[u8]) -> (TextEncoding, bool, usize) {
+    // UTF-32LE 的 BOM 以 UTF-16LE 的 BOM 开头，需先判断
+    for encoding in [
+        TextEncoding::Utf32Le,
+        TextEncoding::Utf32Be,
+        TextEncoding::Utf8,
+        TextEncoding::Utf16Le,
+        TextEncoding::Utf16Be,
+    ] {
+        if bytes.starts_with(encoding.bom()) {
+            return (encoding, true, encoding.bom().len());
+        }
+    }
+
+    if let Some(encoding) = detect_wide_without_bom(bytes) {
+        return (encoding, false, 0);
+    }
+    if std::str::from_utf8(bytes).is_ok() {
+        return (TextEncoding::Utf8, true, 0);
+    }
+
+    let mut detector = EncodingDetector::new();
+    detector.feed(bytes, true);
+    let (encoding, confident) = detector.guess_assess(None, false);
+    (TextEncoding::Legacy(encoding), confident, 0)
+}
+
+/// 无 BOM 的 UTF-16/32 文本中 ASCII 字符会带有大量零字节
+fn detect_wide_without_bom(bytes: &[u8]) -> Option<TextEncoding> {
+    let sample = &bytes[..bytes.len().min(4096)];
+    if sample.len() < 4 {
+        return None;
+    }
+
+    let mut zeros = [0usize; 4];
+    for (i, &b) in sample.iter().enumerate() {
+        if b == 0 {
+            zeros[i % 4] += 1;
+        }
+    }
+    let quarter = sample.len() / 4;
+    let mostly = |count: usize, total: usize| count * 10 >= total * 7;
+    let rarely = |count: usize, total: usize| count * 10 <= total;
+
+    if sample.len().is_multiple_of(4) {
+        if mostly(zeros[1] + zeros[2] + zeros[3], quarter * 3) && rarely(zeros[0], quarter) {
+            return Some(TextEncoding::Utf32Le);
+        }
+        if mostly(zeros[0] + zeros[1] + zeros[2], quarter * 3) && rarely(zeros[3], quarter) {
+            return Some(TextEncoding::Utf32Be);
+        }
+    }
+
+    let half = sample.len() / 2;
+    let (even, odd) = (zeros[0] + zeros[2], zeros[1] + zeros[3]);
+    if mostly(odd, half) && rarely(even, half) {
+        Some(TextEncoding::Utf16Le)
+    } else if mostly(even, half) && rarely(odd, half) {
+        Some(TextEncoding::Utf16Be)
+    } else {
+        None
+    }
+}
+
+fn build_detection(
+    bytes: &[u8],
+    encoding: TextEncoding,
+    confident: bool,
+    bom_len: usize,
+) -> EncodingDetection {
+    let (text, decode_errors) = encoding.decode(&bytes[bom_len..]);
+    EncodingDetection {
+        encoding: encoding.name().to_string(),
+        confident,
+        has_bom: bom_len > 0,
+        line_endings: count_line_endings(&text),
+        decode_errors,
+        preview: text.chars().take(PREVIEW_CHARS).collect(),
+    }
+}
+
+fn count_line_endings(text: &str) -> LineEndingStats {
+    let mut stats = LineEndingStats {
+        lf: 0,
+        crlf: 0,
+        cr: 0,
+    };
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\r' if chars.peek() == Some(&'\n') => {
+                chars.next();
+                stats.crlf += 1;
+            }
+            '\r' => stats.cr += 1,
+            '\n' => stats.lf += 1,
+            _ => {}
+        }
+    }
+    stats
+}
+
+fn normalize_line_endings(text: &str, mode: &str) -> Result<String, String> {
+    let unified = || text.replace("\r\n", "\n").replace('\r', "\n");
+    match mode.to_lowercase().as_str() {
+        "keep" | "" => Ok(text.to_string()),
+        "lf" => Ok(unified()),
+        "crlf" => Ok(unified().replace('\n', "\r\n")),
+        other => Err(format!("不支持的换行符类型: {}", other)),
+    }
+}
+
+/// 生成 name.gbk.csv 形式的输出路径
+fn default_output_path(path: &str, target: TextEncoding) -> String {
+    let source = Path::new(path);
+    let stem = source
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("output");
+    let suffix = target.name().to_lowercase();
+    let file_name = match source.extension().and_then(|e| e.to_str()) {
+        Some(ext) => format!("{}.{}.{}", stem, suffix, ext),
+        None => format!("{}.{}", stem, suffix),
+    };
+    source
+        .with_file_name(file_name)
+        .to_string_lossy()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_encoding() {
+        let text = "姓名,城市,备注\r\n张三,北京,这是一段用于检测编码的中文内容\r\n李四,上海,逗号分隔的数据文件\r\n";
+        let (gbk, _, _) = encoding_rs::GBK.encode(text);
+        let (encoding, _, bom_len) = detect_encoding(&gbk);
+        assert_eq!(encoding, TextEncoding::Legacy(encoding_rs::GBK));
+        assert_eq!(bom_len, 0);
+
+        let (encoding, confident, _) = detect_encoding(text.as_bytes());
+        assert_eq!(encoding, TextEncoding::Utf8);
+        assert!(confident);
+
+        let (utf16, _) = TextEncoding::Utf16Le.encode("hello, world");
+        assert_eq!(detect_encoding(&utf16).0, TextEncoding::Utf16Le);
+        assert_eq!(detect_encoding(b"\xFF\xFE\x00\x00h\x00\x00\x00").2, 4);
+    }
+
+    #[test]
+    fn test_convert_round_trip() {
+        let text = "a,b\r\n你好,世界\r\n";
+        let target = TextEncoding::from_label("gbk").unwrap();
+        let (bytes, lossy) = target.encode(&normalize_line_endings(text, "lf").unwrap());
+        assert!(!lossy);
+        assert_eq!(target.decode(&bytes).0, "a,b\n你好,世界\n");
+
+        let stats = count_line_endings("a\r\nb\nc\rd");
+        assert_eq!((stats.lf, stats.crlf, stats.cr), (1, 1, 1));
+
+        let (_, lossy) = TextEncoding::Latin1.encode("café 你好");
+        assert!(lossy);
+        assert!(TextEncoding::from_label("unknown-charset").is_err());
+        assert_eq!(
+            default_output_path("/tmp/data.csv", TextEncoding::Utf8),
+            "/tmp/data.utf-8.csv"
+        );
+    }
+}
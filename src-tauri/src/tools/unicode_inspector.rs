@@ -0,0 +1,295 @@
+use serde::{Deserialize, Serialize};
+use unicode_general_category::{get_general_category, GeneralCategory};
+use unicode_normalization::{is_nfc, UnicodeNormalization};
+
+/// 逐字符列表的默认上限，超长文本只统计不逐个展示
+const DEFAULT_CHAR_LIMIT: usize = 2000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InspectTextRequest {
+    pub text: String,
+    pub compare_with: Option<String>, // 与另一段文本对比，排查看起来相同却不相等的字符串
+    pub limit: Option<usize>,         // 逐字符列表的最大条数，默认 2000
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CharInfo {
+    pub index: usize,       // 字符序号
+    pub byte_offset: usize, // UTF-8 字节偏移
+    pub character: String,
+    pub code_point: String, // U+0041
+    pub name: String,
+    pub category: String,      // 通用类别缩写，如 Lu、Cf
+    pub category_name: String, // 通用类别全称
+    pub utf8: String,          // 十六进制，空格分隔
+    pub utf16: String,
+    pub flags: Vec<String>, // invisible, zero_width, bidi_control, control, non_ascii_space, combining, confusable
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NormalizationForms {
+    pub nfc: String,
+    pub nfd: String,
+    pub nfkc: String,
+    pub nfkd: String,
+    pub is_nfc: bool,
+    pub skeleton: String, // UTS #39 混淆骨架，骨架相同的字符串视觉上容易混淆
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TextComparison {
+    pub equal: bool,
+    pub equal_nfc: bool,
+    pub equal_nfkc: bool,
+    pub same_skeleton: bool,
+    pub first_difference: Option<usize>, // 第一个不同字符的序号
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TextInspection {
+    pub char_count: usize,
+    pub utf8_length: usize,
+    pub utf16_length: usize,
+    pub characters: Vec<CharInfo>,
+    pub truncated: bool,
+    pub suspicious_count: usize, // 带有不可见、双向控制或混淆标记的字符数
+    pub normalization: NormalizationForms,
+    pub comparison: Option<TextComparison>,
+}
+
+/// 逐字符检查文本，标出不可见、双向控制和易混淆字符，并给出各种规范化形式
+#[tauri::command]
+pub fn inspect_text(request: InspectTextRequest) -> Result<TextInspection, String> {
+    let text = &request.text;
+    let limit = request.limit.unwrap_or(DEFAULT_CHAR_LIMIT);
+
+    let mut characters = Vec::new();
+    let mut suspicious_count = 0;
+    for (index, (byte_offset, c)) in text.char_indices().enumerate() {
+        let info = char_info(index, byte_offset, c);
+        if info.flags.iter().any(|f| {
+            matches!(
+                f.as_str(),
+                "invisible" | "zero_width" | "bidi_control" | "confusable"
+            )
+        }) {
+            suspicious_count += 1;
+        }
+        if characters.len() < limit {
+            characters.push(info);
+        }
+    }
+    let char_count = text.chars().count();
+
+    let comparison = request.compare_with.as_ref().map(|other| TextComparison {
+        equal: text == other,
+        equal_nfc: text.nfc().eq(other.nfc()),
+        equal_nfkc: text.nfkc().eq(other.nfkc()),
+        same_skeleton: unicode_security::skeleton(text).eq(unicode_security::skeleton(other)),
+        first_difference: text
+            .chars()
+            .zip(other.chars())
+            .position(|(a, b)| a != b)
+            .or_else(|| {
+                let other_count = other.chars().count();
+                (char_count != other_count).then(|| char_count.min(other_count))
+            }),
+    });
+
+    Ok(TextInspection {
+        char_count,
+        utf8_length: text.len(),
+        utf16_length: text.encode_utf16().count(),
+        truncated: characters.len() < char_count,
+        characters,
+        suspicious_count,
+        normalization: NormalizationForms {
+            nfc: text.nfc().collect(),
+            nfd: text.nfd().collect(),
+            nfkc: text.nfkc().collect(),
+            nfkd: text.nfkd().collect(),
+            is_nfc: is_nfc(text),
+            skeleton: unicode_security::skeleton(text).collect(),
+        },
+        comparison,
+    })
+}
+
+fn char_info(index: usize, byte_offset: usize, c: char) -> CharInfo {
+    let category = get_general_category(c);
+    let mut utf8 = [0u8; 4];
+    let mut utf16 = [0u16; 2];
+
+    CharInfo {
+        index,
+        byte_offset,
+        character: c.to_string(),
+        code_point: format!("U+{:04X}", c as u32),
+        name: char_name(c),
+        category: category.abbreviation().to_string(),
+        category_name: format!("{:?}", category),
+        utf8: c
+            .encode_utf8(&mut utf8)
+            .bytes()
+            .map(|b| format!("{:02X}", b))
+            .collect::<Vec<_>>()
+            .join(" "),
+        utf16: c
+            .encode_utf16(&mut utf16)
+            .iter()
+            .map(|u| format!("{:04X}", u))
+            .collect::<Vec<_>>()
+            .join(" "),
+        flags: char_flags(c, category),
+    }
+}
+
+/// 控制字符没有正式名称，使用常见别名
+fn char_name(c: char) -> String {
+    if let Some(name) = unicode_names2::name(c) {
+        return name.to_string();
+    }
+    match c {
+        '\0' => "NULL".to_string(),
+        '\t' => "CHARACTER TABULATION".to_string(),
+        '\n' => "LINE FEED (LF)".to_string(),
+        '\r' => "CARRIAGE RETURN (CR)".to_string(),
+        '\u{1b}' => "ESCAPE".to_string(),
+        '\u{7f}' => "DELETE".to_string(),
+        '\u{85}' => "NEXT LINE (NEL)".to_string(),
+        c if c.is_control() => format!("<control-{:04X}>", c as u32),
+        c => format!("<unassigned-{:04X}>", c as u32),
+    }
+}
+
+fn char_flags(c: char, category: GeneralCategory) -> Vec<String> {
+    let mut flags = Vec::new();
+    let is_bidi = matches!(
+        c,
+        '\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}'
+    );
+    let is_zero_width = matches!(
+        c,
+        '\u{180E}' | '\u{200B}'..='\u{200D}' | '\u{2060}' | '\u{FEFF}'
+    );
+    // 不属于 Cf 类别但显示为空白的填充字符
+    let is_filler = matches!(
+        c,
+        '\u{034F}'
+            | '\u{115F}'
+            | '\u{1160}'
+            | '\u{2800}'
+            | '\u{3164}'
+            | '\u{FFA0}'
+            | '\u{FE00}'..='\u{FE0F}'
+            | '\u{E0000}'..='\u{E007F}'
+    );
+
+    if is_bidi {
+        flags.push("bidi_control");
+    }
+    if is_zero_width {
+        flags.push("zero_width");
+    }
+    if is_bidi || is_zero_width || is_filler || category == GeneralCategory::Format {
+        flags.push("invisible");
+    }
+    if category == GeneralCategory::Control && !matches!(c, '\t' | '\n' | '\r') {
+        flags.push("control");
+    }
+    if matches!(
+        category,
+        GeneralCategory::SpaceSeparator
+            | GeneralCategory::LineSeparator
+            | GeneralCategory::ParagraphSeparator
+    ) && c != ' '
+    {
+        flags.push("non_ascii_space");
+    }
+    if matches!(
+        category,
+        GeneralCategory::NonspacingMark
+            | GeneralCategory::SpacingMark
+            | GeneralCategory::EnclosingMark
+    ) {
+        flags.push("combining");
+    }
+    let text = c.to_string();
+    if !c.is_ascii() && !unicode_security::skeleton(&text).eq(text.nfd()) {
+        flags.push("confusable");
+    }
+    flags.into_iter().map(String::from).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inspect(text: &str, compare_with: Option<&str>) -> TextInspection {
+        inspect_text(InspectTextRequest {
+            text: text.to_string(),
+            compare_with: compare_with.map(String::from),
+            limit: None,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_character_details() {
+        let result = inspect("A\u{200B}é😀", None);
+        assert_eq!(result.char_count, 4);
+        assert_eq!(result.utf8_length, 1 + 3 + 2 + 4);
+        assert_eq!(result.utf16_length, 5);
+        assert_eq!(result.characters[0].name, "LATIN CAPITAL LETTER A");
+        assert_eq!(result.characters[0].category, "Lu");
+
+        let zwsp = &result.characters[1];
+        assert_eq!(zwsp.code_point, "U+200B");
+        assert_eq!(zwsp.utf8, "E2 80 8B");
+        assert!(zwsp.flags.contains(&"zero_width".to_string()));
+        assert!(zwsp.flags.contains(&"invisible".to_string()));
+
+        let emoji = &result.characters[3];
+        assert_eq!(emoji.byte_offset, 6);
+        assert_eq!(emoji.utf16, "D83D DE00");
+        assert_eq!(emoji.name, "GRINNING FACE");
+        assert_eq!(result.suspicious_count, 1);
+
+        let bidi = inspect("a\u{202E}b\t", None);
+        assert!(bidi.characters[1]
+            .flags
+            .contains(&"bidi_control".to_string()));
+        assert_eq!(bidi.characters[3].name, "CHARACTER TABULATION");
+        assert!(bidi.characters[3].flags.is_empty());
+    }
+
+    #[test]
+    fn test_normalization_and_comparison() {
+        let decomposed = "e\u{0301}";
+        let result = inspect(decomposed, Some("é"));
+        assert!(!result.normalization.is_nfc);
+        assert_eq!(result.normalization.nfc, "é");
+        assert_eq!(result.normalization.nfkc, "é");
+        assert!(result.characters[1]
+            .flags
+            .contains(&"combining".to_string()));
+        let comparison = result.comparison.unwrap();
+        assert!(!comparison.equal);
+        assert!(comparison.equal_nfc);
+        assert_eq!(comparison.first_difference, Some(0));
+
+        let ligature = inspect("ﬁle", Some("file"));
+        assert_eq!(ligature.normalization.nfkd, "file");
+        assert!(ligature.comparison.unwrap().equal_nfkc);
+
+        // 西里尔字母 а 与拉丁字母 a 外观相同
+        let cyrillic = inspect("p\u{0430}ypal", Some("paypal"));
+        assert!(cyrillic.characters[1]
+            .flags
+            .contains(&"confusable".to_string()));
+        let comparison = cyrillic.comparison.unwrap();
+        assert!(!comparison.equal_nfkc);
+        assert!(comparison.same_skeleton);
+        assert_eq!(comparison.first_difference, Some(1));
+    }
+}
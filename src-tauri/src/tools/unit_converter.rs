@@ -0,0 +1,544 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnitConvertRequest {
+    pub category: String, // data_size / bandwidth / duration / number_base / constants
+    pub value: Option<String>,
+    pub unit: Option<String>,   // 为空时尝试从 value 中解析，如 "1.5 GiB"
+    pub bit_width: Option<u32>, // number_base: 补码位宽 8/16/32/64/128
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnitValue {
+    pub unit: String,
+    pub label: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnitConvertResult {
+    pub category: String,
+    pub input: String,
+    pub results: Vec<UnitValue>,
+    pub human_readable: Option<String>,
+}
+
+/// (单位, 名称, 相对基准单位的倍数)
+type UnitDef = (&'static str, &'static str, f64);
+
+const KIB: f64 = 1024.0;
+
+// 基准单位：bit
+const DATA_SIZE_UNITS: &[UnitDef] = &[
+    ("bit", "比特", 1.0),
+    ("B", "字节", 8.0),
+    ("Kbit", "千比特 (10³)", 1e3),
+    ("Mbit", "兆比特 (10⁶)", 1e6),
+    ("Gbit", "吉比特 (10⁹)", 1e9),
+    ("KB", "千字节 (10³)", 8e3),
+    ("MB", "兆字节 (10⁶)", 8e6),
+    ("GB", "吉字节 (10⁹)", 8e9),
+    ("TB", "太字节 (10¹²)", 8e12),
+    ("PB", "拍字节 (10¹⁵)", 8e15),
+    ("KiB", "千字节 (2¹⁰)", 8.0 * KIB),
+    ("MiB", "兆字节 (2²⁰)", 8.0 * KIB * KIB),
+    ("GiB", "吉字节 (2³⁰)", 8.0 * KIB * KIB * KIB),
+    ("TiB", "太字节 (2⁴⁰)", 8.0 * KIB * KIB * KIB * KIB),
+    ("PiB", "拍字节 (2⁵⁰)", 8.0 * KIB * KIB * KIB * KIB * KIB),
+];
+
+// 基准单位：bit/s
+const BANDWIDTH_UNITS: &[UnitDef] = &[
+    ("bps", "比特每秒", 1.0),
+    ("Kbps", "千比特每秒", 1e3),
+    ("Mbps", "兆比特每秒", 1e6),
+    ("Gbps", "吉比特每秒", 1e9),
+    ("Tbps", "太比特每秒", 1e12),
+    ("B/s", "字节每秒", 8.0),
+    ("KB/s", "千字节每秒 (10³)", 8e3),
+    ("MB/s", "兆字节每秒 (10⁶)", 8e6),
+    ("GB/s", "吉字节每秒 (10⁹)", 8e9),
+    ("KiB/s", "千字节每秒 (2¹⁰)", 8.0 * KIB),
+    ("MiB/s", "兆字节每秒 (2²⁰)", 8.0 * KIB * KIB),
+    ("GiB/s", "吉字节每秒 (2³⁰)", 8.0 * KIB * KIB * KIB),
+];
+
+// 基准单位：ns
+const DURATION_UNITS: &[UnitDef] = &[
+    ("ns", "纳秒", 1.0),
+    ("us", "微秒", 1e3),
+    ("ms", "毫秒", 1e6),
+    ("s", "秒", 1e9),
+    ("min", "分钟", 60e9),
+    ("h", "小时", 3600e9),
+    ("d", "天", 86400e9),
+    ("w", "周", 604800e9),
+];
+
+/// (名称, 值, 说明)
+const COMMON_CONSTANTS: &[(&str, &str, &str)] = &[
+    ("i8", "-128 ~ 127", "8 位有符号整数范围"),
+    ("u8", "0 ~ 255", "8 位无符号整数范围"),
+    ("i16", "-32768 ~ 32767", "16 位有符号整数范围"),
+    ("u16", "0 ~ 65535", "16 位无符号整数范围"),
+    ("i32", "-2147483648 ~ 2147483647", "32 位有符号整数范围"),
+    ("u32", "0 ~ 4294967295", "32 位无符号整数范围"),
+    (
+        "i64",
+        "-9223372036854775808 ~ 9223372036854775807",
+        "64 位有符号整数范围",
+    ),
+    ("u64", "0 ~ 18446744073709551615", "64 位无符号整数范围"),
+    (
+        "JS MAX_SAFE_INTEGER",
+        "9007199254740991",
+        "JavaScript 可精确表示的最大整数 (2⁵³-1)",
+    ),
+    ("1 KiB", "1024 B", "二进制前缀 (IEC)"),
+    ("1 KB", "1000 B", "十进制前缀 (SI)"),
+    ("1 Gbps", "125 MB/s", "千兆网卡理论吞吐"),
+    ("1 天", "86400 s", "一天的秒数"),
+    ("1 年", "31536000 s", "365 天的秒数"),
+    (
+        "Unix 2038",
+        "2147483647 (2038-01-19T03:14:07Z)",
+        "32 位 time_t 溢出时刻",
+    ),
+    ("MTU", "1500 B", "以太网默认最大传输单元"),
+    ("TCP MSS", "1460 B", "以太网 IPv4 默认最大报文段长度"),
+    ("页大小", "4096 B", "常见内存页大小"),
+];
+
+/// 解析 "1.5 GiB" / "100ms" 这类带单位的输入
+fn split_value_unit(input: &str) -> (String, String) {
+    let input = input.trim();
+    let split_at = input
+        .char_indices()
+        .find(|(i, c)| {
+            !(c.is_ascii_digit()
+                || *c == '.'
+                || *c == '_'
+                || *c == ','
+                || ((*c == '-' || *c == '+') && *i == 0)
+                || ((*c == 'e' || *c == 'E') && *i > 0))
+        })
+        .map(|(i, _)| i)
+        .unwrap_or(input.len());
+    (
+        input[..split_at].trim().to_string(),
+        input[split_at..].trim().to_string(),
+    )
+}
+
+fn parse_number(value: &str) -> Result<f64, String> {
+    let cleaned: String = value.chars().filter(|c| *c != '_' && *c != ',').collect();
+    cleaned
+        .parse::<f64>()
+        .map_err(|_| format!("无效的数值: {}", value))
+        .and_then(|v| {
+            if v.is_finite() {
+                Ok(v)
+            } else {
+                Err("数值超出范围".to_string())
+            }
+        })
+}
+
+fn normalize_unit(unit: &str) -> String {
+    unit.trim().replace(['µ', 'μ'], "u")
+}
+
+fn find_unit(units: &'static [UnitDef], unit: &str) -> Option<&'static UnitDef> {
+    let unit = normalize_unit(unit);
+    // 先精确匹配（区分 b/B），再忽略大小写匹配
+    units.iter().find(|(u, _, _)| *u == unit).or_else(|| {
+        let aliases: &[(&str, &str)] = &[
+            ("b", "bit"),
+            ("bits", "bit"),
+            ("byte", "B"),
+            ("bytes", "B"),
+            ("kb/s", "KB/s"),
+            ("sec", "s"),
+            ("second", "s"),
+            ("seconds", "s"),
+            ("m", "min"),
+            ("minute", "min"),
+            ("minutes", "min"),
+            ("hour", "h"),
+            ("hours", "h"),
+            ("day", "d"),
+            ("days", "d"),
+            ("week", "w"),
+            ("weeks", "w"),
+        ];
+        let target = aliases
+            .iter()
+            .find(|(alias, _)| *alias == unit)
+            .map(|(_, t)| t.to_string())
+            .unwrap_or(unit.clone());
+        units.iter().find(|(u, _, _)| *u == target).or_else(|| {
+            units
+                .iter()
+                .find(|(u, _, _)| u.eq_ignore_ascii_case(&target))
+        })
+    })
+}
+
+/// 格式化浮点数，去除多余的尾随零
+fn format_number(value: f64) -> String {
+    if value == 0.0 {
+        return "0".to_string();
+    }
+    let abs = value.abs();
+    if !(1e-6..1e21).contains(&abs) {
+        return format!("{:e}", value);
+    }
+    if value.fract() == 0.0 {
+        return format!("{:.0}", value);
+    }
+    let formatted = format!("{:.10}", value);
+    formatted
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+fn convert_linear(
+    units: &'static [UnitDef],
+    value: f64,
+    unit: &str,
+) -> Result<Vec<UnitValue>, String> {
+    let (_, _, factor) = find_unit(units, unit).ok_or_else(|| format!("不支持的单位: {}", unit))?;
+    let base = value * factor;
+    Ok(units
+        .iter()
+        .map(|(u, label, f)| UnitValue {
+            unit: u.to_string(),
+            label: label.to_string(),
+            value: format_number(base / f),
+        })
+        .collect())
+}
+
+/// 选择最合适的单位展示，如 1536 KiB -> 1.5 MiB
+fn best_fit(units: &'static [UnitDef], base: f64, candidates: &[&str]) -> String {
+    let chosen = candidates
+        .iter()
+        .filter_map(|c| units.iter().find(|(u, _, _)| u == c))
+        .rfind(|(_, _, f)| base.abs() >= *f)
+        .or_else(|| units.iter().find(|(u, _, _)| Some(u) == candidates.first()));
+    match chosen {
+        Some((u, _, f)) => format!(
+            "{} {}",
+            format_number((base / f * 100.0).round() / 100.0),
+            u
+        ),
+        None => format_number(base),
+    }
+}
+
+/// 将纳秒转换为 "1d 2h 3m 4s 500ms" 形式
+fn humanize_duration(ns: f64) -> String {
+    if ns == 0.0 {
+        return "0s".to_string();
+    }
+    let sign = if ns < 0.0 { "-" } else { "" };
+    let mut remaining = ns.abs().round() as u128;
+    let parts: &[(&str, u128)] = &[
+        ("d", 86_400_000_000_000),
+        ("h", 3_600_000_000_000),
+        ("m", 60_000_000_000),
+        ("s", 1_000_000_000),
+        ("ms", 1_000_000),
+        ("µs", 1_000),
+        ("ns", 1),
+    ];
+    let mut output = Vec::new();
+    for (suffix, size) in parts {
+        if remaining >= *size {
+            output.push(format!("{}{}", remaining / size, suffix));
+            remaining %= size;
+        }
+    }
+    format!("{}{}", sign, output.join(" "))
+}
+
+fn parse_integer(value: &str, unit: Option<&str>) -> Result<i128, String> {
+    let trimmed: String = value
+        .trim()
+        .chars()
+        .filter(|c| *c != '_' && !c.is_whitespace())
+        .collect();
+    let (negative, body) = match trimmed.strip_prefix('-') {
+        Some(rest) => (true, rest.to_string()),
+        None => (false, trimmed.trim_start_matches('+').to_string()),
+    };
+    let lower = body.to_lowercase();
+    let (radix, digits) = if let Some(d) = lower.strip_prefix("0x") {
+        (16, d.to_string())
+    } else if let Some(d) = lower.strip_prefix("0b") {
+        (2, d.to_string())
+    } else if let Some(d) = lower.strip_prefix("0o") {
+        (8, d.to_string())
+    } else {
+        let radix = match unit.map(|u| u.trim().to_lowercase()).as_deref() {
+            Some("hex") | Some("16") => 16,
+            Some("bin") | Some("2") => 2,
+            Some("oct") | Some("8") => 8,
+            Some("dec") | Some("10") | Some("") | None => 10,
+            Some(other) => return Err(format!("不支持的进制: {}", other)),
+        };
+        (radix, lower)
+    };
+    if digits.is_empty() {
+        return Err("数值不能为空".to_string());
+    }
+    let magnitude = u128::from_str_radix(&digits, radix)
+        .map_err(|_| format!("无效的{}进制数: {}", radix, value.trim()))?;
+    if negative {
+        if magnitude > i128::MAX as u128 + 1 {
+            return Err("数值超出128位范围".to_string());
+        }
+        Ok((magnitude as i128).wrapping_neg())
+    } else {
+        i128::try_from(magnitude).map_err(|_| "数值超出128位有符号范围".to_string())
+    }
+}
+
+/// 二进制按 4 位分组，便于阅读
+fn group_binary(bits: &str) -> String {
+    let chars: Vec<char> = bits.chars().collect();
+    chars
+        .rchunks(4)
+        .rev()
+        .map(|c| c.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn convert_number_base(
+    value: &str,
+    unit: Option<&str>,
+    bit_width: Option<u32>,
+) -> Result<Vec<UnitValue>, String> {
+    let number = parse_integer(value, unit)?;
+    let width = match bit_width {
+        Some(w @ (8 | 16 | 32 | 64 | 128)) => w,
+        Some(other) => return Err(format!("不支持的位宽: {}，可选 8/16/32/64/128", other)),
+        // 自动选择能容纳该值（按有符号或无符号）的最小位宽
+        None => [8u32, 16, 32, 64, 128]
+            .into_iter()
+            .find(|w| *w == 128 || (number >= -(1i128 << (w - 1)) && number < (1i128 << w)))
+            .unwrap_or(128),
+    };
+    if width < 128 && (number < -(1i128 << (width - 1)) || number >= (1i128 << width)) {
+        return Err(format!("数值超出 {} 位可表示范围", width));
+    }
+
+    let mask: u128 = if width == 128 {
+        u128::MAX
+    } else {
+        (1u128 << width) - 1
+    };
+    let unsigned = (number as u128) & mask;
+    let signed = if width == 128 {
+        unsigned as i128
+    } else if unsigned >> (width - 1) & 1 == 1 {
+        (unsigned as i128) - (1i128 << width)
+    } else {
+        unsigned as i128
+    };
+
+    let sign = if number < 0 { "-" } else { "" };
+    let magnitude = number.unsigned_abs();
+    let item = |unit: &str, label: &str, value: String| UnitValue {
+        unit: unit.to_string(),
+        label: label.to_string(),
+        value,
+    };
+
+    Ok(vec![
+        item("dec", "十进制", number.to_string()),
+        item("hex", "十六进制", format!("{}0x{:X}", sign, magnitude)),
+        item("oct", "八进制", format!("{}0o{:o}", sign, magnitude)),
+        item("bin", "二进制", format!("{}0b{:b}", sign, magnitude)),
+        item("bit_width", "补码位宽", width.to_string()),
+        item("unsigned", "无符号解释", unsigned.to_string()),
+        item("signed", "有符号解释", signed.to_string()),
+        item(
+            "twos_hex",
+            "补码（十六进制）",
+            format!("0x{:0w$X}", unsigned, w = (width / 4) as usize),
+        ),
+        item(
+            "twos_bin",
+            "补码（二进制）",
+            group_binary(&format!("{:0w$b}", unsigned, w = width as usize)),
+        ),
+    ])
+}
+
+/// 开发常用单位换算：数据大小、带宽、时长、进制与常用常量
+#[tauri::command]
+pub fn convert_units(request: UnitConvertRequest) -> Result<UnitConvertResult, String> {
+    let category = request.category.trim().to_lowercase();
+    let input = request.value.clone().unwrap_or_default();
+
+    if category == "constants" {
+        let keyword = input.trim().to_lowercase();
+        let results = COMMON_CONSTANTS
+            .iter()
+            .filter(|(name, value, desc)| {
+                keyword.is_empty()
+                    || name.to_lowercase().contains(&keyword)
+                    || value.to_lowercase().contains(&keyword)
+                    || desc.contains(&keyword)
+            })
+            .map(|(name, value, desc)| UnitValue {
+                unit: name.to_string(),
+                label: desc.to_string(),
+                value: value.to_string(),
+            })
+            .collect();
+        return Ok(UnitConvertResult {
+            category,
+            input,
+            results,
+            human_readable: None,
+        });
+    }
+
+    if input.trim().is_empty() {
+        return Err("请输入要转换的数值".to_string());
+    }
+
+    if category == "number_base" {
+        let results = convert_number_base(&input, request.unit.as_deref(), request.bit_width)?;
+        return Ok(UnitConvertResult {
+            category,
+            input,
+            results,
+            human_readable: None,
+        });
+    }
+
+    let (number_part, unit_part) = split_value_unit(&input);
+    let unit = match request.unit.as_deref().map(str::trim) {
+        Some(u) if !u.is_empty() => u.to_string(),
+        _ => unit_part,
+    };
+    let value = parse_number(&number_part)?;
+
+    let (results, human_readable) = match category.as_str() {
+        "data_size" => {
+            let unit = if unit.is_empty() {
+                "B".to_string()
+            } else {
+                unit
+            };
+            let results = convert_linear(DATA_SIZE_UNITS, value, &unit)?;
+            let bits = value * find_unit(DATA_SIZE_UNITS, &unit).map_or(1.0, |u| u.2);
+            let human = format!(
+                "{} / {}",
+                best_fit(
+                    DATA_SIZE_UNITS,
+                    bits,
+                    &["B", "KiB", "MiB", "GiB", "TiB", "PiB"]
+                ),
+                best_fit(DATA_SIZE_UNITS, bits, &["B", "KB", "MB", "GB", "TB", "PB"])
+            );
+            (results, Some(human))
+        }
+        "bandwidth" => {
+            let unit = if unit.is_empty() {
+                "Mbps".to_string()
+            } else {
+                unit
+            };
+            let results = convert_linear(BANDWIDTH_UNITS, value, &unit)?;
+            let bps = value * find_unit(BANDWIDTH_UNITS, &unit).map_or(1.0, |u| u.2);
+            // 附带传输 1 GiB 所需时间，便于估算
+            let human = if bps > 0.0 {
+                let seconds = 8.0 * KIB * KIB * KIB / bps;
+                Some(format!(
+                    "{}，传输 1 GiB 约需 {}",
+                    best_fit(
+                        BANDWIDTH_UNITS,
+                        bps,
+                        &["bps", "Kbps", "Mbps", "Gbps", "Tbps"]
+                    ),
+                    humanize_duration(seconds * 1e9)
+                ))
+            } else {
+                None
+            };
+            (results, human)
+        }
+        "duration" => {
+            let unit = if unit.is_empty() {
+                "ms".to_string()
+            } else {
+                unit
+            };
+            let results = convert_linear(DURATION_UNITS, value, &unit)?;
+            let ns = value * find_unit(DURATION_UNITS, &unit).map_or(1.0, |u| u.2);
+            (results, Some(humanize_duration(ns)))
+        }
+        other => return Err(format!("不支持的换算类别: {}", other)),
+    };
+
+    Ok(UnitConvertResult {
+        category,
+        input,
+        results,
+        human_readable,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value_of(results: &[UnitValue], unit: &str) -> String {
+        results
+            .iter()
+            .find(|r| r.unit == unit)
+            .map(|r| r.value.clone())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_data_size() {
+        let result = convert_units(UnitConvertRequest {
+            category: "data_size".to_string(),
+            value: Some("1.5 GiB".to_string()),
+            unit: None,
+            bit_width: None,
+        })
+        .unwrap();
+        assert_eq!(value_of(&result.results, "MiB"), "1536");
+        assert_eq!(value_of(&result.results, "B"), "1610612736");
+        assert_eq!(result.human_readable.as_deref(), Some("1.5 GiB / 1.61 GB"));
+    }
+
+    #[test]
+    fn test_duration_humanize() {
+        assert_eq!(humanize_duration(93_784_500_000_000.0), "1d 2h 3m 4s 500ms");
+        assert_eq!(humanize_duration(1_500.0), "1µs 500ns");
+    }
+
+    #[test]
+    fn test_twos_complement() {
+        let results = convert_number_base("-1", None, Some(8)).unwrap();
+        assert_eq!(value_of(&results, "twos_hex"), "0xFF");
+        assert_eq!(value_of(&results, "twos_bin"), "1111 1111");
+        assert_eq!(value_of(&results, "unsigned"), "255");
+
+        let results = convert_number_base("0xFF", None, None).unwrap();
+        assert_eq!(value_of(&results, "bit_width"), "8");
+        assert_eq!(value_of(&results, "signed"), "-1");
+        assert_eq!(value_of(&results, "dec"), "255");
+
+        assert!(convert_number_base("256", None, Some(8)).is_err());
+    }
+}
@@ -0,0 +1,679 @@
+use crate::tools::spki_pin::fetch_certificate_chain;
+use crate::utils::notification::{self, NotificationKind};
+use crate::utils::proxy;
+use chrono::{DateTime, Local, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+use x509_parser::prelude::*;
+
+/// 默认检查间隔（秒）
+const DEFAULT_INTERVAL_SECS: u64 = 300;
+/// 最短检查间隔（秒）
+const MIN_INTERVAL_SECS: u64 = 10;
+/// 每个监控项保留的检查记录条数
+const MAX_CHECKS_PER_MONITOR: usize = 500;
+/// 每个监控项保留的故障记录条数
+const MAX_INCIDENTS_PER_MONITOR: usize = 100;
+/// 证书默认提前告警天数
+const DEFAULT_CERT_WARN_DAYS: i64 = 14;
+const DEFAULT_TIMEOUT_SECS: u64 = 15;
+
+/// 串行化监控列表和历史文件的读写
+static UPTIME_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UptimeMonitor {
+    #[serde(default)]
+    pub id: String,
+    pub name: String,
+    pub url: String,
+    #[serde(default = "default_interval_secs")]
+    pub interval_secs: u64,
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+    #[serde(default)]
+    pub expected_status: Option<u16>, // 期望的状态码，默认接受 2xx 和 3xx
+    #[serde(default)]
+    pub keyword: Option<String>, // 响应体必须包含的文本
+    #[serde(default)]
+    pub max_latency_ms: Option<u64>, // 超过该耗时视为故障
+    #[serde(default = "default_true")]
+    pub check_certificate: bool, // https 地址同时检查证书剩余有效期
+    #[serde(default)]
+    pub cert_warn_days: Option<i64>,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    #[serde(default)]
+    pub last_checked: Option<String>,
+    #[serde(default)]
+    pub last_up: Option<bool>,
+    #[serde(default)]
+    pub last_cert_notified: Option<String>, // 最近一次证书提醒的日期，每天最多提醒一次
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UptimeCheck {
+    pub monitor_id: String,
+    pub checked_at: String,
+    pub up: bool,
+    pub status_code: Option<u16>,
+    pub latency_ms: u64,
+    pub error: Option<String>, // 判定为故障的原因
+    #[serde(default)]
+    pub cert_expires: Option<String>,
+    #[serde(default)]
+    pub cert_days_remaining: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UptimeIncident {
+    pub monitor_id: String,
+    pub started_at: String,
+    pub ended_at: Option<String>, // 为空表示故障仍在持续
+    pub reason: String,
+}
+
+impl UptimeIncident {
+    /// 故障持续时长，未恢复时计算到 now
+    fn duration_secs(&self, now: DateTime<Utc>) -> i64 {
+        let parse = |t: &str| DateTime::parse_from_rfc3339(t).ok().map(|t| t.to_utc());
+        let Some(start) = parse(&self.started_at) else {
+            return 0;
+        };
+        let end = self.ended_at.as_deref().and_then(parse).unwrap_or(now);
+        (end - start).num_seconds().max(0)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IncidentSummary {
+    #[serde(flatten)]
+    pub incident: UptimeIncident,
+    pub duration_secs: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UptimeHistory {
+    pub monitor_id: String,
+    pub checks: Vec<UptimeCheck>, // 最新的在前
+    pub incidents: Vec<IncidentSummary>,
+    pub uptime_percent: Option<f64>,
+    pub avg_latency_ms: Option<u64>,
+    pub total_downtime_secs: i64,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct UptimeData {
+    #[serde(default)]
+    monitors: Vec<UptimeMonitor>,
+    #[serde(default)]
+    checks: BTreeMap<String, Vec<UptimeCheck>>, // 按监控项 id 保存，最新的在前
+    #[serde(default)]
+    incidents: BTreeMap<String, Vec<UptimeIncident>>, // 最新的在前
+}
+
+/// 一次检查带来的状态变化
+#[derive(Debug, Clone, PartialEq)]
+enum Transition {
+    None,
+    Down,
+    Recovered,
+}
+
+fn default_interval_secs() -> u64 {
+    DEFAULT_INTERVAL_SECS
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[tauri::command]
+pub async fn list_uptime_monitors() -> Result<Vec<UptimeMonitor>, String> {
+    let _guard = UPTIME_LOCK.lock().unwrap();
+    Ok(load_data(&get_data_path()).monitors)
+}
+
+/// 新增或更新监控项（按 id 匹配，id 为空时新增）
+#[tauri::command]
+pub async fn save_uptime_monitor(monitor: UptimeMonitor) -> Result<UptimeMonitor, String> {
+    let mut monitor = validate_monitor(monitor)?;
+
+    let _guard = UPTIME_LOCK.lock().unwrap();
+    let path = get_data_path();
+    let mut data = load_data(&path);
+    match data.monitors.iter_mut().find(|m| m.id == monitor.id) {
+        Some(existing) if !monitor.id.is_empty() => {
+            // 更换地址后历史记录不再有效
+            if existing.url != monitor.url {
+                data.checks.remove(&monitor.id);
+                data.incidents.remove(&monitor.id);
+                monitor.last_checked = None;
+                monitor.last_up = None;
+                monitor.last_cert_notified = None;
+            } else {
+                monitor.last_checked = existing.last_checked.take();
+                monitor.last_up = existing.last_up.take();
+                monitor.last_cert_notified = existing.last_cert_notified.take();
+            }
+            *existing = monitor.clone();
+        }
+        _ => {
+            monitor.id = format!("{:x}", Utc::now().timestamp_nanos_opt().unwrap_or_default());
+            monitor.last_checked = None;
+            monitor.last_up = None;
+            monitor.last_cert_notified = None;
+            data.monitors.push(monitor.clone());
+        }
+    }
+    save_data(&path, &data)?;
+    Ok(monitor)
+}
+
+#[tauri::command]
+pub async fn remove_uptime_monitor(id: String) -> Result<(), String> {
+    let _guard = UPTIME_LOCK.lock().unwrap();
+    let path = get_data_path();
+    let mut data = load_data(&path);
+    let before = data.monitors.len();
+    data.monitors.retain(|m| m.id != id);
+    if data.monitors.len() == before {
+        return Err("监控项不存在".to_string());
+    }
+    data.checks.remove(&id);
+    data.incidents.remove(&id);
+    save_data(&path, &data)
+}
+
+/// 立即检查一次，结果同样计入历史
+#[tauri::command]
+pub async fn check_uptime_monitor(app: AppHandle, id: String) -> Result<UptimeCheck, String> {
+    let monitor = {
+        let _guard = UPTIME_LOCK.lock().unwrap();
+        load_data(&get_data_path())
+            .monitors
+            .into_iter()
+            .find(|m| m.id == id)
+            .ok_or_else(|| "监控项不存在".to_string())?
+    };
+    let check = execute_check(&monitor).await;
+    record_checks(&app, vec![check.clone()])?;
+    Ok(check)
+}
+
+/// 查看检查记录和故障时长，limit 限制返回的检查记录条数
+#[tauri::command]
+pub async fn get_uptime_history(id: String, limit: Option<usize>) -> Result<UptimeHistory, String> {
+    let _guard = UPTIME_LOCK.lock().unwrap();
+    let mut data = load_data(&get_data_path());
+    if !data.monitors.iter().any(|m| m.id == id) {
+        return Err("监控项不存在".to_string());
+    }
+    let mut checks = data.checks.remove(&id).unwrap_or_default();
+    let incidents = data.incidents.remove(&id).unwrap_or_default();
+    let mut history = summarize(&id, &checks, incidents, Utc::now());
+    checks.truncate(limit.unwrap_or(MAX_CHECKS_PER_MONITOR));
+    history.checks = checks;
+    Ok(history)
+}
+
+/// 清空监控项的检查记录和故障记录
+#[tauri::command]
+pub async fn clear_uptime_history(id: String) -> Result<(), String> {
+    let _guard = UPTIME_LOCK.lock().unwrap();
+    let path = get_data_path();
+    let mut data = load_data(&path);
+    data.checks.remove(&id);
+    data.incidents.remove(&id);
+    save_data(&path, &data)
+}
+
+/// 检查已到间隔的监控项，由后台调度循环定期调用
+pub(crate) async fn run_due_checks(app: AppHandle) {
+    let due = {
+        let _guard = UPTIME_LOCK.lock().unwrap();
+        due_monitors(&load_data(&get_data_path()).monitors, Utc::now())
+    };
+    if due.is_empty() {
+        return;
+    }
+    let mut checks = Vec::with_capacity(due.len());
+    for monitor in &due {
+        checks.push(execute_check(monitor).await);
+    }
+    if let Err(e) = record_checks(&app, checks) {
+        eprintln!("Failed to record uptime checks: {}", e);
+    }
+}
+
+/// 筛选距上次检查已超过检查间隔的启用项
+fn due_monitors(monitors: &[UptimeMonitor], now: DateTime<Utc>) -> Vec<UptimeMonitor> {
+    monitors
+        .iter()
+        .filter(|m| m.enabled)
+        .filter(|m| {
+            let last = m
+                .last_checked
+                .as_deref()
+                .and_then(|t| DateTime::parse_from_rfc3339(t).ok());
+            last.is_none_or(|t| (now - t.to_utc()).num_seconds() >= m.interval_secs as i64)
+        })
+        .cloned()
+        .collect()
+}
+
+/// 保存检查结果，状态变化和证书即将过期时通过事件提醒
+fn record_checks(app: &AppHandle, checks: Vec<UptimeCheck>) -> Result<(), String> {
+    let today = Local::now().date_naive().to_string();
+    let mut transitions = Vec::new();
+    let mut cert_alerts = Vec::new();
+    {
+        let _guard = UPTIME_LOCK.lock().unwrap();
+        let path = get_data_path();
+        let mut data = load_data(&path);
+        for check in &checks {
+            let Some(monitor) = data.monitors.iter().find(|m| m.id == check.monitor_id) else {
+                continue;
+            };
+            let monitor = monitor.clone();
+            let transition = apply_check(&mut data, check);
+            if transition != Transition::None {
+                transitions.push((transition, monitor.clone(), check.clone()));
+            }
+            let warn_days = monitor.cert_warn_days.unwrap_or(DEFAULT_CERT_WARN_DAYS);
+            let cert_expiring = check.cert_days_remaining.is_some_and(|d| d < warn_days);
+            if let Some(m) = data.monitors.iter_mut().find(|m| m.id == check.monitor_id) {
+                if cert_expiring && m.last_cert_notified.as_deref() != Some(today.as_str()) {
+                    m.last_cert_notified = Some(today.clone());
+                    cert_alerts.push((monitor, check.clone()));
+                }
+            }
+        }
+        save_data(&path, &data)?;
+    }
+
+    for check in &checks {
+        let _ = app.emit("uptime-monitor-check", check);
+    }
+    for (transition, monitor, check) in &transitions {
+        let payload = serde_json::json!({ "monitor": monitor, "check": check });
+        let (event, kind, body) = match transition {
+            Transition::Down => (
+                "uptime-monitor-down",
+                NotificationKind::MonitorDown,
+                format!(
+                    "{}: {}",
+                    monitor.name,
+                    check.error.as_deref().unwrap_or_default()
+                ),
+            ),
+            Transition::Recovered => (
+                "uptime-monitor-recovered",
+                NotificationKind::MonitorRecovered,
+                format!("{} 已恢复，耗时 {} ms", monitor.name, check.latency_ms),
+            ),
+            Transition::None => continue,
+        };
+        let _ = app.emit(event, payload);
+        notification::notify(app, kind, &body);
+    }
+    for (monitor, check) in &cert_alerts {
+        let payload = serde_json::json!({ "monitor": monitor, "check": check });
+        let _ = app.emit("uptime-monitor-cert-expiring", payload);
+        let body = match check.cert_days_remaining {
+            Some(days) if days > 0 => format!("{} 的证书剩余 {} 天", monitor.name, days),
+            _ => format!("{} 的证书已过期", monitor.name),
+        };
+        notification::notify(app, NotificationKind::CertExpiring, &body);
+    }
+    Ok(())
+}
+
+/// 写入检查记录，并根据状态变化开启或结束故障
+fn apply_check(data: &mut UptimeData, check: &UptimeCheck) -> Transition {
+    let Some(monitor) = data.monitors.iter_mut().find(|m| m.id == check.monitor_id) else {
+        return Transition::None;
+    };
+    monitor.last_checked = Some(check.checked_at.clone());
+    monitor.last_up = Some(check.up);
+
+    let history = data.checks.entry(check.monitor_id.clone()).or_default();
+    history.insert(0, check.clone());
+    history.truncate(MAX_CHECKS_PER_MONITOR);
+
+    let incidents = data.incidents.entry(check.monitor_id.clone()).or_default();
+    // 首次检查即失败也视为故障开始
+    let open = incidents.first_mut().filter(|i| i.ended_at.is_none());
+    match (check.up, open) {
+        (true, Some(incident)) => {
+            incident.ended_at = Some(check.checked_at.clone());
+            Transition::Recovered
+        }
+        (false, None) => {
+            incidents.insert(
+                0,
+                UptimeIncident {
+                    monitor_id: check.monitor_id.clone(),
+                    started_at: check.checked_at.clone(),
+                    ended_at: None,
+                    reason: check.error.clone().unwrap_or_default(),
+                },
+            );
+            incidents.truncate(MAX_INCIDENTS_PER_MONITOR);
+            Transition::Down
+        }
+        _ => Transition::None,
+    }
+}
+
+fn summarize(
+    id: &str,
+    checks: &[UptimeCheck],
+    incidents: Vec<UptimeIncident>,
+    now: DateTime<Utc>,
+) -> UptimeHistory {
+    let up = checks.iter().filter(|c| c.up).count();
+    let uptime_percent = (!checks.is_empty()).then(|| {
+        let percent = up as f64 * 100.0 / checks.len() as f64;
+        (percent * 100.0).round() / 100.0
+    });
+    let latencies: Vec<u64> = checks
+        .iter()
+        .filter(|c| c.status_code.is_some())
+        .map(|c| c.latency_ms)
+        .collect();
+    let avg_latency_ms =
+        (!latencies.is_empty()).then(|| latencies.iter().sum::<u64>() / latencies.len() as u64);
+    let incidents: Vec<IncidentSummary> = incidents
+        .into_iter()
+        .map(|incident| IncidentSummary {
+            duration_secs: incident.duration_secs(now),
+            incident,
+        })
+        .collect();
+    UptimeHistory {
+        monitor_id: id.to_string(),
+        checks: Vec::new(),
+        total_downtime_secs: incidents.iter().map(|i| i.duration_secs).sum(),
+        incidents,
+        uptime_percent,
+        avg_latency_ms,
+    }
+}
+
+async fn execute_check(monitor: &UptimeMonitor) -> UptimeCheck {
+    let checked_at = Utc::now().to_rfc3339();
+    let start = Instant::now();
+    let response = request(monitor).await;
+    let latency_ms = start.elapsed().as_millis() as u64;
+
+    let (status_code, result) = match response {
+        Ok((status, body)) => (
+            Some(status),
+            evaluate_response(monitor, status, body.as_deref(), latency_ms),
+        ),
+        Err(e) => (None, Err(e)),
+    };
+
+    let mut check = UptimeCheck {
+        monitor_id: monitor.id.clone(),
+        checked_at,
+        up: result.is_ok(),
+        status_code,
+        latency_ms,
+        error: result.err(),
+        cert_expires: None,
+        cert_days_remaining: None,
+    };
+    if monitor.check_certificate {
+        if let Some(expires) = certificate_expiry(&monitor.url).await {
+            check.cert_days_remaining = Some((expires - Utc::now()).num_days());
+            check.cert_expires = Some(expires.to_rfc3339());
+        }
+    }
+    check
+}
+
+/// 发送请求，只有设置了关键字时才读取响应体
+async fn request(monitor: &UptimeMonitor) -> Result<(u16, Option<String>), String> {
+    let timeout = monitor.timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS);
+    let client = proxy::client_builder("uptime_monitor")
+        .timeout(Duration::from_secs(timeout))
+        .build()
+        .map_err(|e| format!("创建 HTTP 客户端失败: {}", e))?;
+    let response = client.get(&monitor.url).send().await.map_err(|e| {
+        if e.is_timeout() {
+            format!("请求超时（{} 秒）", timeout)
+        } else {
+            format!("请求失败: {}", e)
+        }
+    })?;
+    let status = response.status().as_u16();
+    let body = if monitor.keyword.as_deref().is_some_and(|k| !k.is_empty()) {
+        Some(
+            response
+                .text()
+                .await
+                .map_err(|e| format!("读取响应失败: {}", e))?,
+        )
+    } else {
+        None
+    };
+    Ok((status, body))
+}
+
+fn evaluate_response(
+    monitor: &UptimeMonitor,
+    status: u16,
+    body: Option<&str>,
+    latency_ms: u64,
+) -> Result<(), String> {
+    let status_ok = match monitor.expected_status {
+        Some(expected) => status == expected,
+        None => (200..400).contains(&status),
+    };
+    if !status_ok {
+        return Err(format!("HTTP 状态码 {}", status));
+    }
+    if let Some(keyword) = monitor.keyword.as_deref().filter(|k| !k.is_empty()) {
+        if !body.unwrap_or_default().contains(keyword) {
+            return Err(format!("响应中未找到关键字: {}", keyword));
+        }
+    }
+    if let Some(max) = monitor.max_latency_ms {
+        if latency_ms > max {
+            return Err(format!("响应耗时 {} ms 超过 {} ms", latency_ms, max));
+        }
+    }
+    Ok(())
+}
+
+/// 读取 https 地址的服务器证书到期时间，失败时忽略
+async fn certificate_expiry(url: &str) -> Option<DateTime<Utc>> {
+    let url = url::Url::parse(url).ok()?;
+    if url.scheme() != "https" {
+        return None;
+    }
+    let host = url.host_str()?;
+    let chain = fetch_certificate_chain(host, url.port().unwrap_or(443))
+        .await
+        .ok()?;
+    let (_, cert) = X509Certificate::from_der(chain.first()?).ok()?;
+    DateTime::from_timestamp(cert.validity().not_after.timestamp(), 0)
+}
+
+fn validate_monitor(mut monitor: UptimeMonitor) -> Result<UptimeMonitor, String> {
+    monitor.name = monitor.name.trim().to_string();
+    monitor.url = monitor.url.trim().to_string();
+    let url = url::Url::parse(&monitor.url).map_err(|e| format!("无效的 URL: {}", e))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err("只支持 http 和 https 地址".to_string());
+    }
+    if monitor.name.is_empty() {
+        monitor.name = url.host_str().unwrap_or(&monitor.url).to_string();
+    }
+    if monitor.interval_secs < MIN_INTERVAL_SECS {
+        return Err(format!("检查间隔不能小于 {} 秒", MIN_INTERVAL_SECS));
+    }
+    if monitor.timeout_secs == Some(0) {
+        return Err("超时时间必须大于 0".to_string());
+    }
+    if monitor.cert_warn_days.is_some_and(|d| d < 0) {
+        return Err("告警天数不能为负数".to_string());
+    }
+    monitor.keyword = monitor.keyword.filter(|k| !k.is_empty());
+    Ok(monitor)
+}
+
+fn get_data_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("devtools")
+        .join("uptime-monitor.json")
+}
+
+fn load_data(path: &Path) -> UptimeData {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_data(path: &Path, data: &UptimeData) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("创建配置目录失败: {}", e))?;
+    }
+    let content = serde_json::to_string(data).map_err(|e| format!("序列化监控数据失败: {}", e))?;
+    fs::write(path, content).map_err(|e| format!("保存监控数据失败: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monitor() -> UptimeMonitor {
+        UptimeMonitor {
+            id: "1".to_string(),
+            name: "example".to_string(),
+            url: "https://example.com/health".to_string(),
+            interval_secs: 60,
+            timeout_secs: None,
+            expected_status: None,
+            keyword: None,
+            max_latency_ms: None,
+            check_certificate: false,
+            cert_warn_days: None,
+            enabled: true,
+            last_checked: None,
+            last_up: None,
+            last_cert_notified: None,
+        }
+    }
+
+    fn check(at: &str, up: bool) -> UptimeCheck {
+        UptimeCheck {
+            monitor_id: "1".to_string(),
+            checked_at: at.to_string(),
+            up,
+            status_code: Some(if up { 200 } else { 503 }),
+            latency_ms: 100,
+            error: (!up).then(|| "HTTP 状态码 503".to_string()),
+            cert_expires: None,
+            cert_days_remaining: None,
+        }
+    }
+
+    #[test]
+    fn test_evaluate_response() {
+        let mut m = monitor();
+        assert!(evaluate_response(&m, 301, None, 10).is_ok());
+        assert!(evaluate_response(&m, 500, None, 10).is_err());
+
+        m.expected_status = Some(401);
+        assert!(evaluate_response(&m, 401, None, 10).is_ok());
+        assert!(evaluate_response(&m, 200, None, 10).is_err());
+
+        m.expected_status = None;
+        m.keyword = Some("\"status\":\"ok\"".to_string());
+        assert!(evaluate_response(&m, 200, Some(r#"{"status":"ok"}"#), 10).is_ok());
+        assert!(
+            evaluate_response(&m, 200, Some(r#"{"status":"degraded"}"#), 10)
+                .unwrap_err()
+                .contains("关键字")
+        );
+
+        m.keyword = None;
+        m.max_latency_ms = Some(500);
+        assert!(evaluate_response(&m, 200, None, 500).is_ok());
+        assert!(evaluate_response(&m, 200, None, 501).is_err());
+    }
+
+    #[test]
+    fn test_incident_lifecycle() {
+        let mut data = UptimeData {
+            monitors: vec![monitor()],
+            ..Default::default()
+        };
+        let t = |min: u32| format!("2026-03-01T10:{:02}:00+00:00", min);
+
+        assert_eq!(
+            apply_check(&mut data, &check(&t(0), true)),
+            Transition::None
+        );
+        assert_eq!(
+            apply_check(&mut data, &check(&t(1), false)),
+            Transition::Down
+        );
+        assert_eq!(
+            apply_check(&mut data, &check(&t(2), false)),
+            Transition::None
+        );
+        assert_eq!(
+            apply_check(&mut data, &check(&t(4), true)),
+            Transition::Recovered
+        );
+        assert_eq!(data.checks["1"].len(), 4);
+        assert_eq!(data.monitors[0].last_up, Some(true));
+
+        let now = DateTime::parse_from_rfc3339(&t(10)).unwrap().to_utc();
+        let incidents = data.incidents["1"].clone();
+        let history = summarize("1", &data.checks["1"], incidents, now);
+        assert_eq!(history.incidents.len(), 1);
+        assert_eq!(history.incidents[0].duration_secs, 180);
+        assert_eq!(history.total_downtime_secs, 180);
+        assert_eq!(history.uptime_percent, Some(50.0));
+        assert_eq!(history.avg_latency_ms, Some(100));
+
+        // 未恢复的故障计算到当前时间
+        apply_check(&mut data, &check(&t(8), false));
+        let incidents = data.incidents["1"].clone();
+        let history = summarize("1", &data.checks["1"], incidents, now);
+        assert_eq!(history.incidents[0].duration_secs, 120);
+        assert_eq!(history.total_downtime_secs, 300);
+    }
+
+    #[test]
+    fn test_due_monitors() {
+        let now = DateTime::parse_from_rfc3339("2026-03-01T10:05:00+00:00")
+            .unwrap()
+            .to_utc();
+        let mut fresh = monitor();
+        fresh.last_checked = Some("2026-03-01T10:04:30+00:00".to_string());
+        let mut stale = monitor();
+        stale.last_checked = Some("2026-03-01T10:04:00+00:00".to_string());
+        let mut disabled = monitor();
+        disabled.enabled = false;
+        let due = due_monitors(&[fresh, stale, monitor(), disabled], now);
+        assert_eq!(due.len(), 2);
+    }
+}
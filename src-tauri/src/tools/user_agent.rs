@@ -0,0 +1,544 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UaComponent {
+    pub name: Option<String>,
+    pub version: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserAgentInfo {
+    pub user_agent: String,
+    pub browser: UaComponent,
+    pub engine: UaComponent,
+    pub os: UaComponent,
+    pub device_type: String, // desktop / mobile / tablet / tv / bot / unknown
+    pub device_vendor: Option<String>,
+    pub device_model: Option<String>,
+    pub cpu_architecture: Option<String>,
+    pub is_bot: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserAgentPreset {
+    pub name: String,
+    pub browser: String,
+    pub platform: String,
+    pub user_agent: String,
+}
+
+/// 浏览器识别规则：(名称, 正则)，按顺序匹配，基于 Chromium 的浏览器须排在 Chrome 之前
+const BROWSER_RULES: &[(&str, &str)] = &[
+    ("Edge", r"Edg(?:e|A|iOS)?/([\d.]+)"),
+    ("Opera", r"(?:OPR|OPiOS)/([\d.]+)"),
+    ("Opera", r"Opera[/ ]([\d.]+)"),
+    ("WeChat", r"MicroMessenger/([\d.]+)"),
+    ("QQ Browser", r"MQQBrowser/([\d.]+)|QQBrowser/([\d.]+)"),
+    ("UC Browser", r"UCBrowser/([\d.]+)"),
+    ("Samsung Internet", r"SamsungBrowser/([\d.]+)"),
+    ("Yandex", r"YaBrowser/([\d.]+)"),
+    ("Vivaldi", r"Vivaldi/([\d.]+)"),
+    ("Brave", r"Brave/([\d.]+)"),
+    ("Firefox", r"(?:Firefox|FxiOS)/([\d.]+)"),
+    ("Chrome", r"(?:Chrome|CriOS)/([\d.]+)"),
+    ("Internet Explorer", r"MSIE ([\d.]+)|Trident/.*rv:([\d.]+)"),
+    ("Safari", r"Version/([\d.]+).*Safari/"),
+    ("Android Browser", r"Android.*AppleWebKit.*Version/([\d.]+)"),
+];
+
+/// 爬虫与命令行客户端识别规则
+const BOT_RULES: &[(&str, &str)] = &[
+    ("Googlebot", r"Googlebot(?:-\w+)?/([\d.]+)"),
+    ("Bingbot", r"bingbot/([\d.]+)"),
+    ("Baiduspider", r"Baiduspider(?:-\w+)?/([\d.]+)"),
+    ("YandexBot", r"YandexBot/([\d.]+)"),
+    ("DuckDuckBot", r"DuckDuckBot/([\d.]+)"),
+    ("Sogou Spider", r"Sogou web spider/([\d.]+)"),
+    ("Bytespider", r"Bytespider(?:/([\d.]+))?"),
+    ("GPTBot", r"GPTBot/([\d.]+)"),
+    ("facebookexternalhit", r"facebookexternalhit/([\d.]+)"),
+    ("Twitterbot", r"Twitterbot/([\d.]+)"),
+    ("curl", r"^curl/([\d.]+)"),
+    ("Wget", r"^Wget/([\d.]+)"),
+    ("python-requests", r"python-requests/([\d.]+)"),
+    ("Go-http-client", r"Go-http-client/([\d.]+)"),
+    ("PostmanRuntime", r"PostmanRuntime/([\d.]+)"),
+    ("okhttp", r"okhttp/([\d.]+)"),
+    ("Generic Bot", r"(?i)(?:bot|crawler|spider|slurp)\b"),
+];
+
+fn first_capture(pattern: &str, ua: &str) -> Option<Option<String>> {
+    let re = Regex::new(pattern).ok()?;
+    let caps = re.captures(ua)?;
+    Some(
+        caps.iter()
+            .skip(1)
+            .flatten()
+            .next()
+            .map(|m| m.as_str().to_string()),
+    )
+}
+
+fn windows_version_name(nt: &str) -> &str {
+    match nt {
+        "10.0" => "10/11",
+        "6.3" => "8.1",
+        "6.2" => "8",
+        "6.1" => "7",
+        "6.0" => "Vista",
+        "5.2" => "XP x64",
+        "5.1" | "5.01" => "XP",
+        "5.0" => "2000",
+        other => other,
+    }
+}
+
+fn detect_os(ua: &str) -> UaComponent {
+    let rules: &[(&str, &str)] = &[
+        ("HarmonyOS", r"HarmonyOS[ /]?([\d.]+)?"),
+        ("Windows Phone", r"Windows Phone(?: OS)? ([\d.]+)"),
+        ("Windows", r"Windows NT ([\d.]+)"),
+        ("iPadOS", r"iPad.*? OS ([\d_]+)"),
+        ("iOS", r"(?:iPhone|iPod).*? OS ([\d_]+)"),
+        ("macOS", r"Mac OS X ?([\d_.]+)?"),
+        ("Android", r"Android ?([\d.]+)?"),
+        ("Chrome OS", r"CrOS \S+ ([\d.]+)"),
+        ("Ubuntu", r"Ubuntu(?:/([\d.]+))?"),
+        ("Fedora", r"Fedora(?:/([\d.]+))?"),
+        ("FreeBSD", r"FreeBSD()"),
+        ("Linux", r"Linux()"),
+    ];
+    for (name, pattern) in rules {
+        if let Some(version) = first_capture(pattern, ua) {
+            let version = version
+                .filter(|v| !v.is_empty())
+                .map(|v| v.replace('_', "."));
+            let version = if *name == "Windows" {
+                version.map(|v| windows_version_name(&v).to_string())
+            } else {
+                version
+            };
+            return UaComponent {
+                name: Some(name.to_string()),
+                version,
+            };
+        }
+    }
+    UaComponent {
+        name: None,
+        version: None,
+    }
+}
+
+fn detect_engine(ua: &str, browser: Option<&str>) -> UaComponent {
+    let make = |name: &str, version: Option<String>| UaComponent {
+        name: Some(name.to_string()),
+        version,
+    };
+    if let Some(v) = first_capture(r"Trident/([\d.]+)", ua) {
+        return make("Trident", v);
+    }
+    if let Some(v) = first_capture(r"Edge/([\d.]+)", ua) {
+        return make("EdgeHTML", v);
+    }
+    if let Some(v) = first_capture(r"Presto/([\d.]+)", ua) {
+        return make("Presto", v);
+    }
+    // iOS 上所有浏览器都强制使用 WebKit
+    let is_ios = ua.contains("iPhone") || ua.contains("iPad") || ua.contains("iPod");
+    if !is_ios {
+        if let Some(v) = first_capture(r"Chrome/([\d.]+)", ua) {
+            return make("Blink", v);
+        }
+    }
+    if let Some(v) = first_capture(r"AppleWebKit/([\d.]+)", ua) {
+        return make("WebKit", v);
+    }
+    if browser == Some("Firefox") || ua.contains("Gecko/") {
+        return make("Gecko", first_capture(r"rv:([\d.]+)", ua).flatten());
+    }
+    UaComponent {
+        name: None,
+        version: None,
+    }
+}
+
+fn detect_device(ua: &str, os: Option<&str>) -> (String, Option<String>, Option<String>) {
+    let lower = ua.to_lowercase();
+    if [
+        "smart-tv", "smarttv", "googletv", "appletv", "hbbtv", "bravia", "crkey",
+    ]
+    .iter()
+    .any(|k| lower.contains(k))
+    {
+        return ("tv".to_string(), None, None);
+    }
+    if ua.contains("iPad") {
+        return (
+            "tablet".to_string(),
+            Some("Apple".to_string()),
+            Some("iPad".to_string()),
+        );
+    }
+    if ua.contains("iPhone") || ua.contains("iPod") {
+        let model = if ua.contains("iPod") {
+            "iPod"
+        } else {
+            "iPhone"
+        };
+        return (
+            "mobile".to_string(),
+            Some("Apple".to_string()),
+            Some(model.to_string()),
+        );
+    }
+    if os == Some("Android") || os == Some("HarmonyOS") {
+        // 形如 "Android 14; Pixel 8 Build/..." 或 "Android 13; SM-S9180)"
+        let model = Regex::new(
+            r"Android[^;)]*;(?:\s*[a-z]{2}[-_][A-Za-z]{2};)?\s*([^;)]+?)(?:\s+Build/|\)|;)",
+        )
+        .ok()
+        .and_then(|re| re.captures(ua))
+        .map(|c| c[1].trim().to_string())
+        .filter(|m| !m.is_empty() && !matches!(m.as_str(), "K" | "wv" | "Mobile" | "Tablet"));
+        let vendor = model.as_deref().and_then(|m| {
+            let prefixes: &[(&str, &str)] = &[
+                ("SM-", "Samsung"),
+                ("Pixel", "Google"),
+                ("Nexus", "Google"),
+                ("MI ", "Xiaomi"),
+                ("Mi ", "Xiaomi"),
+                ("Redmi", "Xiaomi"),
+                ("M2", "Xiaomi"),
+                ("HUAWEI", "Huawei"),
+                ("ONEPLUS", "OnePlus"),
+                ("OPPO", "OPPO"),
+                ("CPH", "OPPO"),
+                ("vivo", "vivo"),
+                ("V2", "vivo"),
+                ("Moto", "Motorola"),
+            ];
+            prefixes
+                .iter()
+                .find(|(prefix, _)| m.starts_with(prefix))
+                .map(|(_, vendor)| vendor.to_string())
+        });
+        let device_type = if ua.contains("Mobile") {
+            "mobile"
+        } else {
+            "tablet"
+        };
+        return (device_type.to_string(), vendor, model);
+    }
+    if ua.contains("Windows Phone") || lower.contains("mobile") {
+        return ("mobile".to_string(), None, None);
+    }
+    if lower.contains("tablet") {
+        return ("tablet".to_string(), None, None);
+    }
+    if matches!(
+        os,
+        Some("Windows")
+            | Some("macOS")
+            | Some("Linux")
+            | Some("Chrome OS")
+            | Some("Ubuntu")
+            | Some("Fedora")
+            | Some("FreeBSD")
+    ) {
+        return ("desktop".to_string(), None, None);
+    }
+    ("unknown".to_string(), None, None)
+}
+
+fn detect_cpu(ua: &str) -> Option<String> {
+    let rules: &[(&str, &str)] = &[
+        (r"(?i)\b(x86_64|x64|win64|wow64|amd64)\b", "amd64"),
+        (r"(?i)\b(aarch64|arm64)\b", "arm64"),
+        (r"(?i)\barmv?\d*[a-z]*\b", "arm"),
+        (r"(?i)\b(i[3-6]86|x86)\b", "ia32"),
+    ];
+    rules.iter().find_map(|(pattern, arch)| {
+        Regex::new(pattern)
+            .ok()
+            .filter(|re| re.is_match(ua))
+            .map(|_| arch.to_string())
+    })
+}
+
+/// 解析 User-Agent 字符串
+#[tauri::command]
+pub fn parse_user_agent(user_agent: String) -> Result<UserAgentInfo, String> {
+    let ua = user_agent.trim();
+    if ua.is_empty() {
+        return Err("User-Agent 不能为空".to_string());
+    }
+
+    let bot = BOT_RULES
+        .iter()
+        .find_map(|(name, pattern)| first_capture(pattern, ua).map(|v| (*name, v)));
+
+    let browser = match &bot {
+        Some((name, version)) => UaComponent {
+            name: Some(name.to_string()),
+            version: version.clone(),
+        },
+        None => BROWSER_RULES
+            .iter()
+            .find_map(|(name, pattern)| {
+                first_capture(pattern, ua).map(|version| UaComponent {
+                    name: Some(name.to_string()),
+                    version,
+                })
+            })
+            .unwrap_or(UaComponent {
+                name: None,
+                version: None,
+            }),
+    };
+
+    let os = detect_os(ua);
+    let engine = detect_engine(ua, browser.name.as_deref());
+    let (device_type, device_vendor, device_model) = if bot.is_some() {
+        ("bot".to_string(), None, None)
+    } else {
+        detect_device(ua, os.name.as_deref())
+    };
+
+    Ok(UserAgentInfo {
+        user_agent: ua.to_string(),
+        browser,
+        engine,
+        os,
+        device_type,
+        device_vendor,
+        device_model,
+        cpu_architecture: detect_cpu(ua),
+        is_bot: bot.is_some(),
+    })
+}
+
+/// (名称, 浏览器, 平台, UA)
+#[rustfmt::skip]
+const UA_PRESETS: &[(&str, &str, &str, &str)] = &[
+    ("Chrome (Windows)", "chrome", "windows", "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/{chrome} Safari/537.36"),
+    ("Chrome (macOS)", "chrome", "macos", "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/{chrome} Safari/537.36"),
+    ("Chrome (Linux)", "chrome", "linux", "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/{chrome} Safari/537.36"),
+    ("Chrome (Android)", "chrome", "android", "Mozilla/5.0 (Linux; Android 14; Pixel 8) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/{chrome} Mobile Safari/537.36"),
+    ("Chrome (iOS)", "chrome", "ios", "Mozilla/5.0 (iPhone; CPU iPhone OS 17_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) CriOS/{chrome} Mobile/15E148 Safari/604.1"),
+    ("Edge (Windows)", "edge", "windows", "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/{chrome} Safari/537.36 Edg/{chrome}"),
+    ("Edge (macOS)", "edge", "macos", "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/{chrome} Safari/537.36 Edg/{chrome}"),
+    ("Firefox (Windows)", "firefox", "windows", "Mozilla/5.0 (Windows NT 10.0; Win64; x64; rv:{firefox}) Gecko/20100101 Firefox/{firefox}"),
+    ("Firefox (macOS)", "firefox", "macos", "Mozilla/5.0 (Macintosh; Intel Mac OS X 10.15; rv:{firefox}) Gecko/20100101 Firefox/{firefox}"),
+    ("Firefox (Linux)", "firefox", "linux", "Mozilla/5.0 (X11; Linux x86_64; rv:{firefox}) Gecko/20100101 Firefox/{firefox}"),
+    ("Firefox (Android)", "firefox", "android", "Mozilla/5.0 (Android 14; Mobile; rv:{firefox}) Gecko/{firefox} Firefox/{firefox}"),
+    ("Safari (macOS)", "safari", "macos", "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/{safari} Safari/605.1.15"),
+    ("Safari (iPhone)", "safari", "ios", "Mozilla/5.0 (iPhone; CPU iPhone OS 17_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/{safari} Mobile/15E148 Safari/604.1"),
+    ("Safari (iPad)", "safari", "ipados", "Mozilla/5.0 (iPad; CPU OS 17_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/{safari} Mobile/15E148 Safari/604.1"),
+    ("Samsung Internet", "samsung", "android", "Mozilla/5.0 (Linux; Android 14; SM-S928B) AppleWebKit/537.36 (KHTML, like Gecko) SamsungBrowser/25.0 Chrome/{chrome} Mobile Safari/537.36"),
+    ("WeChat (Android)", "wechat", "android", "Mozilla/5.0 (Linux; Android 14; Pixel 8 Build/UQ1A.240205.004; wv) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/{chrome} Mobile Safari/537.36 MicroMessenger/8.0.49.2600 NetType/WIFI Language/zh_CN"),
+    ("WeChat (iOS)", "wechat", "ios", "Mozilla/5.0 (iPhone; CPU iPhone OS 17_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Mobile/15E148 MicroMessenger/8.0.49(0x18003137) NetType/WIFI Language/zh_CN"),
+    ("Googlebot", "bot", "any", "Mozilla/5.0 (compatible; Googlebot/2.1; +http://www.google.com/bot.html)"),
+    ("Bingbot", "bot", "any", "Mozilla/5.0 (compatible; bingbot/2.0; +http://www.bing.com/bingbot.htm)"),
+    ("Baiduspider", "bot", "any", "Mozilla/5.0 (compatible; Baiduspider/2.0; +http://www.baidu.com/search/spider.html)"),
+    ("curl", "cli", "any", "curl/8.7.1"),
+];
+
+const DEFAULT_CHROME_VERSION: &str = "126.0.0.0";
+const DEFAULT_FIREFOX_VERSION: &str = "127.0";
+const DEFAULT_SAFARI_VERSION: &str = "17.5";
+
+/// 生成常用 UA 预设，可按浏览器与平台过滤，并可指定浏览器主版本号
+#[tauri::command]
+pub fn generate_user_agents(
+    browser: Option<String>,
+    platform: Option<String>,
+    version: Option<String>,
+) -> Vec<UserAgentPreset> {
+    let browser = browser
+        .map(|b| b.trim().to_lowercase())
+        .filter(|b| !b.is_empty() && b != "all");
+    let platform = platform
+        .map(|p| p.trim().to_lowercase())
+        .filter(|p| !p.is_empty() && p != "all");
+    let version = version
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty());
+
+    UA_PRESETS
+        .iter()
+        .filter(|(_, b, _, _)| browser.as_deref().is_none_or(|f| f == *b))
+        .filter(|(_, _, p, _)| platform.as_deref().is_none_or(|f| f == *p))
+        .map(|(name, b, p, template)| {
+            // 指定版本时只替换当前浏览器自身的版本号
+            let pick = |key: &str, default: &str| -> String {
+                match &version {
+                    Some(v)
+                        if (key == "chrome" && matches!(*b, "chrome" | "edge")) || key == *b =>
+                    {
+                        if key == "chrome" && !v.contains('.') {
+                            format!("{}.0.0.0", v)
+                        } else if key == "firefox" && !v.contains('.') {
+                            format!("{}.0", v)
+                        } else {
+                            v.clone()
+                        }
+                    }
+                    _ => default.to_string(),
+                }
+            };
+            let user_agent = template
+                .replace("{chrome}", &pick("chrome", DEFAULT_CHROME_VERSION))
+                .replace("{firefox}", &pick("firefox", DEFAULT_FIREFOX_VERSION))
+                .replace("{safari}", &pick("safari", DEFAULT_SAFARI_VERSION));
+            UserAgentPreset {
+                name: name.to_string(),
+                browser: b.to_string(),
+                platform: p.to_string(),
+                user_agent,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(ua: &str) -> UserAgentInfo {
+        parse_user_agent(ua.to_string()).unwrap()
+    }
+
+    #[test]
+    fn test_parse_browsers() {
+        let chrome = parse("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/126.0.0.0 Safari/537.36");
+        assert_eq!(chrome.browser.name.as_deref(), Some("Chrome"));
+        assert_eq!(chrome.browser.version.as_deref(), Some("126.0.0.0"));
+        assert_eq!(chrome.engine.name.as_deref(), Some("Blink"));
+        assert_eq!(chrome.os.name.as_deref(), Some("Windows"));
+        assert_eq!(chrome.os.version.as_deref(), Some("10/11"));
+        assert_eq!(chrome.device_type, "desktop");
+        assert_eq!(chrome.cpu_architecture.as_deref(), Some("amd64"));
+        assert!(!chrome.is_bot);
+
+        let safari = parse("Mozilla/5.0 (iPhone; CPU iPhone OS 17_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) CriOS/126.0.0.0 Mobile/15E148 Safari/604.1");
+        assert_eq!(safari.browser.name.as_deref(), Some("Chrome"));
+        // iOS 上的 Chrome 仍使用 WebKit
+        assert_eq!(safari.engine.name.as_deref(), Some("WebKit"));
+        assert_eq!(safari.os.name.as_deref(), Some("iOS"));
+        assert_eq!(safari.os.version.as_deref(), Some("17.5"));
+        assert_eq!(safari.device_type, "mobile");
+        assert_eq!(safari.device_model.as_deref(), Some("iPhone"));
+
+        let samsung = parse("Mozilla/5.0 (Linux; Android 14; SM-S928B) AppleWebKit/537.36 (KHTML, like Gecko) SamsungBrowser/25.0 Chrome/126.0.0.0 Mobile Safari/537.36");
+        assert_eq!(samsung.browser.name.as_deref(), Some("Samsung Internet"));
+        assert_eq!(samsung.os.version.as_deref(), Some("14"));
+        assert_eq!(samsung.device_vendor.as_deref(), Some("Samsung"));
+        assert_eq!(samsung.device_model.as_deref(), Some("SM-S928B"));
+
+        let firefox =
+            parse("Mozilla/5.0 (X11; Linux x86_64; rv:127.0) Gecko/20100101 Firefox/127.0");
+        assert_eq!(firefox.browser.version.as_deref(), Some("127.0"));
+        assert_eq!(firefox.engine.name.as_deref(), Some("Gecko"));
+        assert_eq!(firefox.engine.version.as_deref(), Some("127.0"));
+        assert_eq!(firefox.os.name.as_deref(), Some("Linux"));
+
+        let ie = parse("Mozilla/5.0 (Windows NT 6.1; Trident/7.0; rv:11.0) like Gecko");
+        assert_eq!(ie.browser.name.as_deref(), Some("Internet Explorer"));
+        assert_eq!(ie.browser.version.as_deref(), Some("11.0"));
+        assert_eq!(ie.os.version.as_deref(), Some("7"));
+    }
+
+    #[test]
+    fn test_parse_bots() {
+        let google =
+            parse("Mozilla/5.0 (compatible; Googlebot/2.1; +http://www.google.com/bot.html)");
+        assert!(google.is_bot);
+        assert_eq!(google.device_type, "bot");
+        assert_eq!(google.browser.name.as_deref(), Some("Googlebot"));
+        assert_eq!(google.browser.version.as_deref(), Some("2.1"));
+
+        let curl = parse("  curl/8.7.1 ");
+        assert_eq!(curl.user_agent, "curl/8.7.1");
+        assert_eq!(curl.browser.name.as_deref(), Some("curl"));
+
+        let generic = parse("MyCrawler/1.0 (+https://example.com) crawler");
+        assert_eq!(generic.browser.name.as_deref(), Some("Generic Bot"));
+        assert_eq!(generic.browser.version, None);
+    }
+
+    #[test]
+    fn test_parse_malformed_user_agent() {
+        assert_eq!(
+            parse_user_agent(" \t".to_string()).unwrap_err(),
+            "User-Agent 不能为空"
+        );
+        let unknown = parse("not a user agent");
+        assert_eq!(unknown.browser.name, None);
+        assert_eq!(unknown.engine.name, None);
+        assert_eq!(unknown.os.name, None);
+        assert_eq!(unknown.device_type, "unknown");
+        assert_eq!(unknown.cpu_architecture, None);
+        assert!(!unknown.is_bot);
+    }
+
+    #[test]
+    fn test_generated_presets_round_trip() {
+        let presets = generate_user_agents(None, None, None);
+        assert_eq!(presets.len(), UA_PRESETS.len());
+        for preset in &presets {
+            assert!(!preset.user_agent.contains('{'), "{}", preset.user_agent);
+            let info = parse(&preset.user_agent);
+            let expected = match preset.browser.as_str() {
+                "chrome" => "Chrome",
+                "edge" => "Edge",
+                "firefox" => "Firefox",
+                "safari" => "Safari",
+                "samsung" => "Samsung Internet",
+                "wechat" => "WeChat",
+                "bot" | "cli" => preset.name.as_str(),
+                other => panic!("未知浏览器 {}", other),
+            };
+            assert_eq!(
+                info.browser.name.as_deref(),
+                Some(expected),
+                "{}",
+                preset.name
+            );
+            let os = match preset.platform.as_str() {
+                "windows" => Some("Windows"),
+                "macos" => Some("macOS"),
+                "linux" => Some("Linux"),
+                "android" => Some("Android"),
+                "ios" => Some("iOS"),
+                "ipados" => Some("iPadOS"),
+                _ => info.os.name.as_deref(),
+            };
+            assert_eq!(info.os.name.as_deref(), os, "{}", preset.name);
+        }
+    }
+
+    #[test]
+    fn test_generate_with_filters_and_version() {
+        let presets = generate_user_agents(
+            Some("Edge".to_string()),
+            Some("windows".to_string()),
+            Some("125".to_string()),
+        );
+        assert_eq!(presets.len(), 1);
+        assert!(presets[0].user_agent.ends_with("Edg/125.0.0.0"));
+        assert!(presets[0].user_agent.contains("Chrome/125.0.0.0"));
+
+        // 版本只作用于所选浏览器，Safari 预设保持默认版本
+        let firefox = generate_user_agents(Some("firefox".to_string()), None, Some("128".into()));
+        assert!(firefox
+            .iter()
+            .all(|p| p.user_agent.contains("Firefox/128.0")));
+        let safari = generate_user_agents(Some("safari".to_string()), Some("all".into()), None);
+        assert!(safari.iter().all(|p| p
+            .user_agent
+            .contains(&format!("Version/{}", DEFAULT_SAFARI_VERSION))));
+        assert!(generate_user_agents(Some("netscape".to_string()), None, None).is_empty());
+    }
+}
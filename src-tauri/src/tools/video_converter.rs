@@ -1,12 +1,42 @@
+use crate::tools::system_settings::{save_config, GlobalTrayState};
+use crate::utils::progress::ProgressReporter;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::io::{BufRead, BufReader, Read};
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
+use tauri::{AppHandle, State};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VideoConversionRequest {
     pub input_path: String,
     pub output_path: String,
     pub delete_source_file: Option<bool>,
+    pub preset: Option<String>, // 转换预设名称，为空时使用默认参数
+}
+
+/// 视频转换预设
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VideoPreset {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub container: String,              // mp4, webm, mkv, mov, gif
+    pub video_codec: Option<String>,    // libx264, libx265, libvpx-vp9 等
+    pub crf: Option<u8>,                // 质量参数，与码率二选一
+    pub video_bitrate: Option<String>,  // 如 2500k
+    pub encoder_preset: Option<String>, // ultrafast ~ veryslow
+    pub width: Option<u32>,             // 仅指定一边时按比例缩放
+    pub height: Option<u32>,
+    pub fps: Option<u32>,
+    pub audio_codec: Option<String>,
+    pub audio_bitrate: Option<String>, // 如 128k
+    pub audio_channels: Option<u8>,
+    #[serde(default)]
+    pub remove_audio: bool,
+    #[serde(default)]
+    pub builtin: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -17,7 +47,7 @@ pub struct VideoConversionResponse {
 }
 
 /// 验证输入文件是否存在且是有效的视频文件
-fn validate_input_file(input_path: &str) -> Result<PathBuf, String> {
+pub(crate) fn validate_input_file(input_path: &str) -> Result<PathBuf, String> {
     let path = Path::new(input_path);
 
     if !path.exists() {
@@ -46,14 +76,22 @@ fn validate_input_file(input_path: &str) -> Result<PathBuf, String> {
 }
 
 /// 生成输出文件路径
-fn generate_output_path(input_path: &Path, custom_output_path: &str) -> Result<PathBuf, String> {
+fn generate_output_path(
+    input_path: &Path,
+    custom_output_path: &str,
+    extension: &str,
+) -> Result<PathBuf, String> {
     let output_path = if custom_output_path.is_empty() {
-        // 如果没有指定输出路径，使用输入文件所在目录，并转换为 MP4
+        // 如果没有指定输出路径，使用输入文件所在目录，扩展名由预设的容器决定
         let mut output = input_path.to_path_buf();
         if let Some(stem) = input_path.file_stem() {
-            output.set_file_name(format!("{}_converted.mp4", stem.to_string_lossy()));
+            output.set_file_name(format!(
+                "{}_converted.{}",
+                stem.to_string_lossy(),
+                extension
+            ));
         } else {
-            output.set_file_name("converted_video.mp4");
+            output.set_file_name(format!("converted_video.{}", extension));
         }
         output
     } else {
@@ -64,8 +102,203 @@ fn generate_output_path(input_path: &Path, custom_output_path: &str) -> Result<P
     Ok(output_path)
 }
 
-/// 使用 FFmpeg 转换视频
-fn convert_video_with_ffmpeg(input_path: &Path, output_path: &Path) -> Result<(), String> {
+/// 内置的转换预设
+pub fn builtin_video_presets() -> Vec<VideoPreset> {
+    vec![
+        VideoPreset {
+            name: "web-1080p".to_string(),
+            description: "H.264 1080p，适合网页播放".to_string(),
+            container: "mp4".to_string(),
+            video_codec: Some("libx264".to_string()),
+            crf: Some(23),
+            video_bitrate: None,
+            encoder_preset: Some("medium".to_string()),
+            width: None,
+            height: Some(1080),
+            fps: None,
+            audio_codec: Some("aac".to_string()),
+            audio_bitrate: Some("192k".to_string()),
+            audio_channels: None,
+            remove_audio: false,
+            builtin: true,
+        },
+        VideoPreset {
+            name: "mobile-720p".to_string(),
+            description: "H.264 720p 低码率，适合移动设备".to_string(),
+            container: "mp4".to_string(),
+            video_codec: Some("libx264".to_string()),
+            crf: Some(26),
+            video_bitrate: None,
+            encoder_preset: Some("fast".to_string()),
+            width: None,
+            height: Some(720),
+            fps: Some(30),
+            audio_codec: Some("aac".to_string()),
+            audio_bitrate: Some("128k".to_string()),
+            audio_channels: Some(2),
+            remove_audio: false,
+            builtin: true,
+        },
+        VideoPreset {
+            name: "gif-preview".to_string(),
+            description: "480 宽 10fps 动图预览".to_string(),
+            container: "gif".to_string(),
+            video_codec: None,
+            crf: None,
+            video_bitrate: None,
+            encoder_preset: None,
+            width: Some(480),
+            height: None,
+            fps: Some(10),
+            audio_codec: None,
+            audio_bitrate: None,
+            audio_channels: None,
+            remove_audio: true,
+            builtin: true,
+        },
+    ]
+}
+
+/// 未指定预设时的默认参数，与早期版本的转换行为一致
+pub(crate) fn default_video_preset() -> VideoPreset {
+    VideoPreset {
+        name: "default".to_string(),
+        description: String::new(),
+        container: "mp4".to_string(),
+        video_codec: Some("libx264".to_string()),
+        crf: Some(23),
+        video_bitrate: None,
+        encoder_preset: Some("medium".to_string()),
+        width: None,
+        height: None,
+        fps: None,
+        audio_codec: Some("aac".to_string()),
+        audio_bitrate: None,
+        audio_channels: None,
+        remove_audio: false,
+        builtin: true,
+    }
+}
+
+/// 校验预设参数
+fn validate_preset(preset: &VideoPreset) -> Result<(), String> {
+    let name = preset.name.trim();
+    if name.is_empty() {
+        return Err("预设名称不能为空".to_string());
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '-' || c == '_' || c == ' ')
+    {
+        return Err(format!("预设名称包含非法字符: {}", name));
+    }
+    if !["mp4", "webm", "mkv", "mov", "gif"].contains(&preset.container.as_str()) {
+        return Err(format!("不支持的容器格式: {}", preset.container));
+    }
+    if preset.crf.is_some() && preset.video_bitrate.is_some() {
+        return Err("CRF 与视频码率只能设置其中一个".to_string());
+    }
+    if preset.crf.is_some_and(|crf| crf > 51) {
+        return Err("CRF 取值范围为 0-51".to_string());
+    }
+    for bitrate in [&preset.video_bitrate, &preset.audio_bitrate]
+        .into_iter()
+        .flatten()
+    {
+        let digits = bitrate.trim_end_matches(['k', 'K', 'm', 'M']);
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err(format!("无效的码率: {}", bitrate));
+        }
+    }
+    if preset.width == Some(0) || preset.height == Some(0) || preset.fps == Some(0) {
+        return Err("分辨率和帧率必须大于 0".to_string());
+    }
+    Ok(())
+}
+
+/// 根据预设生成 FFmpeg 编码参数（不含输入输出）
+fn build_ffmpeg_args(preset: &VideoPreset) -> Vec<String> {
+    let mut args: Vec<String> = Vec::new();
+    let mut filters = Vec::new();
+    if let Some(fps) = preset.fps {
+        filters.push(format!("fps={}", fps));
+    }
+    // 只指定一边时用 -2 保持宽高比且保证偶数尺寸
+    let fallback = if preset.container == "gif" {
+        "-1"
+    } else {
+        "-2"
+    };
+    match (preset.width, preset.height) {
+        (Some(w), Some(h)) => filters.push(format!("scale={}:{}", w, h)),
+        (Some(w), None) => filters.push(format!("scale={}:{}", w, fallback)),
+        (None, Some(h)) => filters.push(format!("scale={}:{}", fallback, h)),
+        (None, None) => {}
+    }
+    if preset.container == "gif" && !filters.is_empty() {
+        if let Some(scale) = filters.last_mut().filter(|f| f.starts_with("scale=")) {
+            scale.push_str(":flags=lanczos");
+        }
+    }
+    if !filters.is_empty() {
+        args.push("-vf".to_string());
+        args.push(filters.join(","));
+    }
+
+    if let Some(codec) = &preset.video_codec {
+        args.extend(["-c:v".to_string(), codec.clone()]);
+    }
+    if let Some(encoder_preset) = &preset.encoder_preset {
+        args.extend(["-preset".to_string(), encoder_preset.clone()]);
+    }
+    if let Some(crf) = preset.crf {
+        args.extend(["-crf".to_string(), crf.to_string()]);
+        // VP9 需要将码率设为 0 才会启用恒定质量模式
+        if preset.video_codec.as_deref() == Some("libvpx-vp9") {
+            args.extend(["-b:v".to_string(), "0".to_string()]);
+        }
+    }
+    if let Some(bitrate) = &preset.video_bitrate {
+        args.extend(["-b:v".to_string(), bitrate.clone()]);
+    }
+
+    if preset.remove_audio || preset.container == "gif" {
+        args.push("-an".to_string());
+    } else {
+        if let Some(codec) = &preset.audio_codec {
+            args.extend(["-c:a".to_string(), codec.clone()]);
+        }
+        if let Some(bitrate) = &preset.audio_bitrate {
+            args.extend(["-b:a".to_string(), bitrate.clone()]);
+        }
+        if let Some(channels) = preset.audio_channels {
+            args.extend(["-ac".to_string(), channels.to_string()]);
+        }
+    }
+
+    if matches!(preset.container.as_str(), "mp4" | "mov") {
+        args.extend(["-movflags".to_string(), "+faststart".to_string()]); // 优化网络播放
+    }
+    args.push("-y".to_string()); // 覆盖输出文件
+    args
+}
+
+/// 按名称查找预设，用户预设优先于内置预设
+pub(crate) fn find_preset(user_presets: &[VideoPreset], name: &str) -> Option<VideoPreset> {
+    user_presets
+        .iter()
+        .find(|p| p.name == name)
+        .cloned()
+        .or_else(|| builtin_video_presets().into_iter().find(|p| p.name == name))
+}
+
+/// 使用 FFmpeg 转换视频，通过 -progress 输出按已转换时长上报进度
+fn convert_video_with_ffmpeg(
+    input_path: &Path,
+    output_path: &Path,
+    preset: &VideoPreset,
+    reporter: &mut ProgressReporter,
+) -> Result<(), String> {
     // 检查输出目录是否存在，如果不存在则创建
     if let Some(parent) = output_path.parent() {
         if !parent.exists() {
@@ -73,42 +306,78 @@ fn convert_video_with_ffmpeg(input_path: &Path, output_path: &Path) -> Result<()
         }
     }
 
+    // 总时长未知时只上报已处理时长
+    let total_ms = extract_video_info(input_path)
+        .ok()
+        .and_then(|info| info.container.duration_seconds)
+        .map(|seconds| (seconds * 1000.0) as u64);
+
     // 构建 FFmpeg 命令
     let mut command = Command::new("ffmpeg");
 
     command
+        .arg("-progress")
+        .arg("pipe:1")
+        .arg("-nostats")
         .arg("-i")
         .arg(input_path)
-        .arg("-c:v")
-        .arg("libx264") // 视频编码器
-        .arg("-c:a")
-        .arg("aac") // 音频编码器
-        .arg("-preset")
-        .arg("medium") // 编码速度与质量平衡
-        .arg("-crf")
-        .arg("23") // 质量参数（0-51，越小质量越好）
-        .arg("-movflags")
-        .arg("+faststart") // 优化网络播放
-        .arg("-y") // 覆盖输出文件
+        .args(build_ffmpeg_args(preset))
         .arg(output_path);
 
     // 执行转换
-    let output = command
+    let mut child = command
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
         .spawn()
-        .map_err(|e| format!("启动 FFmpeg 进程失败: {}", e))?
-        .wait_with_output()
+        .map_err(|e| format!("启动 FFmpeg 进程失败: {}", e))?;
+
+    // stderr 单独读取，避免管道写满阻塞 FFmpeg
+    let stderr = child.stderr.take();
+    let stderr_reader = std::thread::spawn(move || {
+        let mut buffer = String::new();
+        if let Some(mut stderr) = stderr {
+            let _ = stderr.read_to_string(&mut buffer);
+        }
+        buffer
+    });
+
+    if let Some(stdout) = child.stdout.take() {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            if let Some(out_ms) = parse_progress_time(&line) {
+                let processed = total_ms.map_or(out_ms, |total| out_ms.min(total));
+                reporter.update(
+                    processed,
+                    total_ms,
+                    Some(format!(
+                        "已转换 {}",
+                        format_duration(out_ms as f64 / 1000.0)
+                    )),
+                );
+            }
+        }
+    }
+
+    let status = child
+        .wait()
         .map_err(|e| format!("等待 FFmpeg 进程完成失败: {}", e))?;
+    let error_msg = stderr_reader.join().unwrap_or_default();
 
-    if output.status.success() {
+    if status.success() {
         Ok(())
     } else {
-        let error_msg = String::from_utf8_lossy(&output.stderr);
         Err(format!("视频转换失败: {}", error_msg))
     }
 }
 
+/// 解析 -progress 输出中的 out_time_us/out_time_ms 行，两者单位均为微秒，返回毫秒
+fn parse_progress_time(line: &str) -> Option<u64> {
+    let (key, value) = line.trim().split_once('=')?;
+    match key {
+        "out_time_us" | "out_time_ms" => value.parse::<u64>().ok().map(|us| us / 1000),
+        _ => None,
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VideoInfo {
     pub name: String,
@@ -117,10 +386,77 @@ pub struct VideoInfo {
     pub duration: String,
     pub resolution: String,
     pub path: String,
+    pub container: MediaContainer,
+    pub streams: Vec<MediaStream>,
+    pub chapters: Vec<MediaChapter>,
+}
+
+/// 容器级别信息
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MediaContainer {
+    pub format_name: Option<String>,      // 如 mov,mp4,m4a,3gp,3g2,mj2
+    pub format_long_name: Option<String>, // 如 QuickTime / MOV
+    pub duration_seconds: Option<f64>,
+    pub start_time: Option<f64>,
+    pub size_bytes: Option<u64>,
+    pub bit_rate: Option<u64>,
+    pub probe_score: Option<u64>,
+    pub tags: BTreeMap<String, String>, // 标题、编码器、创建时间等元数据
+}
+
+/// 单条流的详细信息，视频/音频专有字段在其他类型中为空
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MediaStream {
+    pub index: u64,
+    pub codec_type: String, // video, audio, subtitle, data, attachment
+    pub codec_name: Option<String>,
+    pub codec_long_name: Option<String>,
+    pub codec_tag: Option<String>,
+    pub profile: Option<String>,
+    pub level: Option<i64>,
+    pub bit_rate: Option<u64>,
+    pub duration_seconds: Option<f64>,
+    pub start_time: Option<f64>,
+    pub time_base: Option<String>,
+    pub frame_count: Option<u64>,
+    pub language: Option<String>,
+    pub title: Option<String>,
+    pub is_default: bool,
+    pub is_forced: bool,
+    pub is_attached_pic: bool, // 内嵌封面
+    // 视频
+    pub width: Option<u64>,
+    pub height: Option<u64>,
+    pub pixel_format: Option<String>,
+    pub bit_depth: Option<u32>,
+    pub color_space: Option<String>,
+    pub color_range: Option<String>,
+    pub color_primaries: Option<String>,
+    pub color_transfer: Option<String>, // smpte2084/arib-std-b67 表示 HDR
+    pub field_order: Option<String>,
+    pub frame_rate: Option<f64>,     // r_frame_rate
+    pub avg_frame_rate: Option<f64>, // 可变帧率时与 frame_rate 不同
+    pub display_aspect_ratio: Option<String>,
+    pub sample_aspect_ratio: Option<String>,
+    pub rotation: Option<i64>,
+    // 音频
+    pub sample_rate: Option<u64>,
+    pub channels: Option<u64>,
+    pub channel_layout: Option<String>,
+    pub sample_format: Option<String>,
+    pub tags: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MediaChapter {
+    pub id: i64,
+    pub title: Option<String>,
+    pub start_seconds: f64,
+    pub end_seconds: f64,
 }
 
 /// 获取视频文件信息（内部函数）
-fn extract_video_info(input_path: &Path) -> Result<VideoInfo, String> {
+pub(crate) fn extract_video_info(input_path: &Path) -> Result<VideoInfo, String> {
     let output = Command::new("ffprobe")
         .arg("-v")
         .arg("quiet")
@@ -128,6 +464,7 @@ fn extract_video_info(input_path: &Path) -> Result<VideoInfo, String> {
         .arg("json")
         .arg("-show_format")
         .arg("-show_streams")
+        .arg("-show_chapters")
         .arg(input_path)
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
@@ -144,42 +481,63 @@ fn extract_video_info(input_path: &Path) -> Result<VideoInfo, String> {
     let probe_data: serde_json::Value =
         serde_json::from_str(&probe_output).map_err(|e| format!("解析视频信息失败: {}", e))?;
 
-    // 获取时长
-    let duration = probe_data
-        .get("format")
-        .and_then(|f| f.get("duration"))
-        .and_then(|d| d.as_str())
-        .and_then(|s| s.parse::<f64>().ok())
-        .map(|seconds| format_duration(seconds))
-        .unwrap_or_else(|| "未知".to_string());
+    Ok(parse_probe_output(input_path, &probe_data))
+}
+
+/// 将 ffprobe 的 JSON 输出转换为结构化信息
+fn parse_probe_output(input_path: &Path, probe_data: &Value) -> VideoInfo {
+    let null = Value::Null;
+    let format = probe_data.get("format").unwrap_or(&null);
+    let container = MediaContainer {
+        format_name: str_field(format, "format_name"),
+        format_long_name: str_field(format, "format_long_name"),
+        duration_seconds: f64_field(format, "duration"),
+        start_time: f64_field(format, "start_time"),
+        size_bytes: u64_field(format, "size"),
+        bit_rate: u64_field(format, "bit_rate"),
+        probe_score: u64_field(format, "probe_score"),
+        tags: tags_field(format),
+    };
 
-    // 获取分辨率
-    let resolution = probe_data
+    let streams: Vec<MediaStream> = probe_data
         .get("streams")
         .and_then(|s| s.as_array())
-        .and_then(|streams| {
-            streams
+        .map(|streams| streams.iter().map(parse_stream).collect())
+        .unwrap_or_default();
+
+    let chapters = probe_data
+        .get("chapters")
+        .and_then(|c| c.as_array())
+        .map(|chapters| {
+            chapters
                 .iter()
-                .find(|stream| stream.get("codec_type").and_then(|t| t.as_str()) == Some("video"))
+                .map(|chapter| MediaChapter {
+                    id: chapter.get("id").and_then(|i| i.as_i64()).unwrap_or(0),
+                    title: tags_field(chapter).remove("title"),
+                    start_seconds: f64_field(chapter, "start_time").unwrap_or(0.0),
+                    end_seconds: f64_field(chapter, "end_time").unwrap_or(0.0),
+                })
+                .collect()
         })
-        .and_then(|video_stream| {
-            let width = video_stream
-                .get("width")
-                .and_then(|w| w.as_u64())
-                .unwrap_or(0);
-            let height = video_stream
-                .get("height")
-                .and_then(|h| h.as_u64())
-                .unwrap_or(0);
-            if width > 0 && height > 0 {
-                Some(format!("{}x{}", width, height))
-            } else {
-                None
-            }
+        .unwrap_or_default();
+
+    // 获取时长
+    let duration = container
+        .duration_seconds
+        .map(format_duration)
+        .unwrap_or_else(|| "未知".to_string());
+
+    // 获取分辨率（跳过内嵌封面）
+    let resolution = streams
+        .iter()
+        .find(|s| s.codec_type == "video" && !s.is_attached_pic)
+        .and_then(|s| match (s.width, s.height) {
+            (Some(w), Some(h)) if w > 0 && h > 0 => Some(format!("{}x{}", w, h)),
+            _ => None,
         })
         .unwrap_or_else(|| "未知".to_string());
 
-    Ok(VideoInfo {
+    VideoInfo {
         name: input_path
             .file_name()
             .unwrap_or_default()
@@ -196,11 +554,150 @@ fn extract_video_info(input_path: &Path) -> Result<VideoInfo, String> {
         duration,
         resolution,
         path: input_path.to_string_lossy().to_string(),
-    })
+        container,
+        streams,
+        chapters,
+    }
+}
+
+fn parse_stream(stream: &Value) -> MediaStream {
+    let mut tags = tags_field(stream);
+    let disposition = |key: &str| {
+        stream
+            .get("disposition")
+            .and_then(|d| d.get(key))
+            .and_then(|v| v.as_i64())
+            == Some(1)
+    };
+    let pixel_format = str_field(stream, "pix_fmt");
+    let bit_depth = u64_field(stream, "bits_per_raw_sample")
+        .map(|b| b as u32)
+        .or_else(|| pixel_format.as_deref().and_then(pixel_format_bit_depth));
+    // 旋转信息可能在 tags.rotate 或 side_data_list 的显示矩阵中
+    let rotation = tags
+        .get("rotate")
+        .and_then(|r| r.parse::<i64>().ok())
+        .or_else(|| {
+            stream
+                .get("side_data_list")
+                .and_then(|l| l.as_array())
+                .and_then(|list| list.iter().find_map(|d| d.get("rotation")))
+                .and_then(|r| r.as_i64())
+        });
+
+    MediaStream {
+        index: stream.get("index").and_then(|i| i.as_u64()).unwrap_or(0),
+        codec_type: str_field(stream, "codec_type").unwrap_or_else(|| "unknown".to_string()),
+        codec_name: str_field(stream, "codec_name"),
+        codec_long_name: str_field(stream, "codec_long_name"),
+        codec_tag: str_field(stream, "codec_tag_string").filter(|t| !t.starts_with('[')),
+        profile: str_field(stream, "profile"),
+        level: stream
+            .get("level")
+            .and_then(|l| l.as_i64())
+            .filter(|l| *l > 0),
+        bit_rate: u64_field(stream, "bit_rate"),
+        duration_seconds: f64_field(stream, "duration"),
+        start_time: f64_field(stream, "start_time"),
+        time_base: str_field(stream, "time_base"),
+        frame_count: u64_field(stream, "nb_frames"),
+        language: tags.remove("language").filter(|l| l != "und"),
+        title: tags.remove("title"),
+        is_default: disposition("default"),
+        is_forced: disposition("forced"),
+        is_attached_pic: disposition("attached_pic"),
+        width: stream.get("width").and_then(|w| w.as_u64()),
+        height: stream.get("height").and_then(|h| h.as_u64()),
+        pixel_format,
+        bit_depth,
+        color_space: str_field(stream, "color_space"),
+        color_range: str_field(stream, "color_range"),
+        color_primaries: str_field(stream, "color_primaries"),
+        color_transfer: str_field(stream, "color_transfer"),
+        field_order: str_field(stream, "field_order"),
+        frame_rate: str_field(stream, "r_frame_rate")
+            .as_deref()
+            .and_then(parse_rational),
+        avg_frame_rate: str_field(stream, "avg_frame_rate")
+            .as_deref()
+            .and_then(parse_rational),
+        display_aspect_ratio: str_field(stream, "display_aspect_ratio"),
+        sample_aspect_ratio: str_field(stream, "sample_aspect_ratio"),
+        rotation,
+        sample_rate: u64_field(stream, "sample_rate"),
+        channels: stream.get("channels").and_then(|c| c.as_u64()),
+        channel_layout: str_field(stream, "channel_layout"),
+        sample_format: str_field(stream, "sample_fmt"),
+        tags,
+    }
+}
+
+fn str_field(value: &Value, key: &str) -> Option<String> {
+    value
+        .get(key)
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string())
+        .filter(|s| !s.is_empty() && s != "unknown" && s != "N/A")
+}
+
+/// ffprobe 的数值字段多以字符串形式输出
+fn f64_field(value: &Value, key: &str) -> Option<f64> {
+    match value.get(key)? {
+        Value::String(s) => s.parse().ok(),
+        v => v.as_f64(),
+    }
+}
+
+fn u64_field(value: &Value, key: &str) -> Option<u64> {
+    match value.get(key)? {
+        Value::String(s) => s.parse().ok(),
+        v => v.as_u64(),
+    }
+}
+
+fn tags_field(value: &Value) -> BTreeMap<String, String> {
+    value
+        .get("tags")
+        .and_then(|t| t.as_object())
+        .map(|tags| {
+            tags.iter()
+                .filter_map(|(k, v)| v.as_str().map(|s| (k.to_lowercase(), s.to_string())))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// 解析 30000/1001 形式的帧率
+fn parse_rational(value: &str) -> Option<f64> {
+    let (num, den) = value.split_once('/')?;
+    let num: f64 = num.parse().ok()?;
+    let den: f64 = den.parse().ok()?;
+    if den == 0.0 || num == 0.0 {
+        return None;
+    }
+    Some((num / den * 1000.0).round() / 1000.0)
+}
+
+/// 根据像素格式推断位深，如 yuv420p10le
+fn pixel_format_bit_depth(pix_fmt: &str) -> Option<u32> {
+    let trimmed = pix_fmt.trim_end_matches("le").trim_end_matches("be");
+    let digits: String = trimmed
+        .chars()
+        .rev()
+        .take_while(|c| c.is_ascii_digit())
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .collect();
+    match digits.parse::<u32>() {
+        Ok(depth) if trimmed.ends_with(&format!("p{}", digits)) && depth <= 16 => Some(depth),
+        _ if pix_fmt.starts_with("yuv") || pix_fmt.starts_with("nv12") => Some(8),
+        _ => None,
+    }
 }
 
 /// 将秒数格式化为 HH:MM:SS 格式
-fn format_duration(seconds: f64) -> String {
+pub(crate) fn format_duration(seconds: f64) -> String {
     let total_seconds = seconds as i64;
     let hours = total_seconds / 3600;
     let minutes = (total_seconds % 3600) / 60;
@@ -216,8 +713,20 @@ fn format_duration(seconds: f64) -> String {
 /// Tauri 命令：转换视频文件
 #[tauri::command]
 pub async fn convert_video(
+    app: AppHandle,
+    tray_state: State<'_, GlobalTrayState>,
     request: VideoConversionRequest,
 ) -> Result<VideoConversionResponse, String> {
+    // 解析转换预设
+    let preset = match request.preset.as_deref().filter(|p| !p.is_empty()) {
+        Some(name) => {
+            let config = tray_state.config.lock().map_err(|e| e.to_string())?;
+            find_preset(&config.video_presets, name)
+                .ok_or_else(|| format!("转换预设不存在: {}", name))?
+        }
+        None => default_video_preset(),
+    };
+
     // 检查 FFmpeg 是否安装
     let ffmpeg_check = Command::new("ffmpeg")
         .arg("-version")
@@ -234,10 +743,15 @@ pub async fn convert_video(
     let input_path = validate_input_file(&request.input_path)?;
 
     // 生成输出路径
-    let output_path = generate_output_path(&input_path, &request.output_path)?;
+    let output_path = generate_output_path(&input_path, &request.output_path, &preset.container)?;
 
-    // 执行转换
-    convert_video_with_ffmpeg(&input_path, &output_path)?;
+    // 执行转换，进度通过 task-progress 事件推送
+    let mut reporter = ProgressReporter::new(&app, "video_converter");
+    if let Err(e) = convert_video_with_ffmpeg(&input_path, &output_path, &preset, &mut reporter) {
+        reporter.fail(&e);
+        return Err(e);
+    }
+    reporter.finish(Some(output_path.to_string_lossy().to_string()));
 
     // 删除源文件（如果用户选择删除）
     let mut deletion_message = String::new();
@@ -284,3 +798,162 @@ pub async fn check_ffmpeg_available() -> Result<bool, String> {
         _ => Ok(false),
     }
 }
+
+/// Tauri 命令：列出全部转换预设（内置预设在前）
+#[tauri::command]
+pub async fn list_video_presets(
+    tray_state: State<'_, GlobalTrayState>,
+) -> Result<Vec<VideoPreset>, String> {
+    let config = tray_state.config.lock().map_err(|e| e.to_string())?;
+    let mut presets = builtin_video_presets();
+    presets.extend(config.video_presets.iter().cloned());
+    Ok(presets)
+}
+
+/// Tauri 命令：新增或更新自定义转换预设
+#[tauri::command]
+pub async fn save_video_preset(
+    tray_state: State<'_, GlobalTrayState>,
+    preset: VideoPreset,
+) -> Result<Vec<VideoPreset>, String> {
+    let mut preset = preset;
+    preset.name = preset.name.trim().to_string();
+    preset.builtin = false;
+    validate_preset(&preset)?;
+    if builtin_video_presets()
+        .iter()
+        .any(|p| p.name == preset.name)
+    {
+        return Err(format!("不能覆盖内置预设: {}", preset.name));
+    }
+
+    let mut config = tray_state.config.lock().map_err(|e| e.to_string())?;
+    match config
+        .video_presets
+        .iter_mut()
+        .find(|p| p.name == preset.name)
+    {
+        Some(existing) => *existing = preset,
+        None => config.video_presets.push(preset),
+    }
+    save_config(&config).map_err(|e| e.to_string())?;
+    Ok(config.video_presets.clone())
+}
+
+/// Tauri 命令：删除自定义转换预设
+#[tauri::command]
+pub async fn delete_video_preset(
+    tray_state: State<'_, GlobalTrayState>,
+    name: String,
+) -> Result<Vec<VideoPreset>, String> {
+    if builtin_video_presets().iter().any(|p| p.name == name) {
+        return Err(format!("不能删除内置预设: {}", name));
+    }
+    let mut config = tray_state.config.lock().map_err(|e| e.to_string())?;
+    let before = config.video_presets.len();
+    config.video_presets.retain(|p| p.name != name);
+    if config.video_presets.len() == before {
+        return Err(format!("转换预设不存在: {}", name));
+    }
+    save_config(&config).map_err(|e| e.to_string())?;
+    Ok(config.video_presets.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_preset_matches_legacy_args() {
+        let args = build_ffmpeg_args(&default_video_preset());
+        assert_eq!(
+            args,
+            vec![
+                "-c:v",
+                "libx264",
+                "-preset",
+                "medium",
+                "-crf",
+                "23",
+                "-c:a",
+                "aac",
+                "-movflags",
+                "+faststart",
+                "-y"
+            ]
+        );
+
+        let gif = find_preset(&[], "gif-preview").unwrap();
+        let args = build_ffmpeg_args(&gif);
+        assert_eq!(args[..2], ["-vf", "fps=10,scale=480:-1:flags=lanczos"]);
+        assert!(args.contains(&"-an".to_string()));
+    }
+
+    #[test]
+    fn test_parse_probe_output() {
+        let probe: Value = serde_json::from_str(
+            r#"{
+                "streams": [
+                    {"index": 0, "codec_type": "video", "codec_name": "hevc", "profile": "Main 10",
+                     "width": 3840, "height": 2160, "pix_fmt": "yuv420p10le", "color_transfer": "smpte2084",
+                     "r_frame_rate": "30000/1001", "avg_frame_rate": "30000/1001", "nb_frames": "1798",
+                     "disposition": {"default": 1, "attached_pic": 0}},
+                    {"index": 1, "codec_type": "audio", "codec_name": "aac", "sample_rate": "48000",
+                     "channels": 6, "channel_layout": "5.1", "bit_rate": "384000",
+                     "tags": {"language": "jpn", "handler_name": "SoundHandler"}},
+                    {"index": 2, "codec_type": "subtitle", "codec_name": "mov_text",
+                     "tags": {"language": "chi", "title": "简体中文"}, "disposition": {"forced": 1}}
+                ],
+                "chapters": [{"id": 0, "start_time": "0.000000", "end_time": "60.000000", "tags": {"title": "Intro"}}],
+                "format": {"format_name": "mov,mp4,m4a,3gp,3g2,mj2", "duration": "3723.5", "bit_rate": "25000000",
+                           "tags": {"encoder": "Lavf60.3.100"}}
+            }"#,
+        )
+        .unwrap();
+        let info = parse_probe_output(Path::new("/tmp/movie.mp4"), &probe);
+        assert_eq!(info.duration, "01:02:03");
+        assert_eq!(info.resolution, "3840x2160");
+        assert_eq!(info.container.bit_rate, Some(25_000_000));
+        assert_eq!(info.container.tags["encoder"], "Lavf60.3.100");
+
+        let video = &info.streams[0];
+        assert_eq!(video.bit_depth, Some(10));
+        assert_eq!(video.frame_rate, Some(29.97));
+        assert_eq!(video.frame_count, Some(1798));
+        assert!(video.is_default);
+
+        let audio = &info.streams[1];
+        assert_eq!(audio.sample_rate, Some(48000));
+        assert_eq!(audio.channel_layout.as_deref(), Some("5.1"));
+        assert_eq!(audio.language.as_deref(), Some("jpn"));
+
+        let subtitle = &info.streams[2];
+        assert_eq!(subtitle.title.as_deref(), Some("简体中文"));
+        assert!(subtitle.is_forced);
+        assert_eq!(info.chapters[0].title.as_deref(), Some("Intro"));
+        assert_eq!(pixel_format_bit_depth("yuv420p"), Some(8));
+    }
+
+    #[test]
+    fn test_validate_preset() {
+        for preset in builtin_video_presets() {
+            assert!(validate_preset(&preset).is_ok(), "{}", preset.name);
+        }
+        let mut preset = builtin_video_presets().remove(0);
+        preset.video_bitrate = Some("4M".to_string());
+        assert!(validate_preset(&preset).is_err());
+        preset.crf = None;
+        assert!(validate_preset(&preset).is_ok());
+        preset.container = "exe".to_string();
+        assert!(validate_preset(&preset).is_err());
+    }
+
+    #[test]
+    fn test_parse_progress_time() {
+        assert_eq!(parse_progress_time("out_time_us=1500000"), Some(1500));
+        assert_eq!(parse_progress_time("out_time_ms=2500000\n"), Some(2500));
+        assert_eq!(parse_progress_time("out_time_us=N/A"), None);
+        assert_eq!(parse_progress_time("out_time=00:00:01.500000"), None);
+        assert_eq!(parse_progress_time("progress=continue"), None);
+    }
+}
@@ -0,0 +1,571 @@
+use crate::tools::image_converter::{self, ImageConversionRequest};
+use crate::tools::system_settings::GlobalTrayState;
+use crate::tools::video_converter::{self, VideoConversionRequest};
+use crate::utils::notification::{self, NotificationKind};
+use chrono::Utc;
+use notify::event::{ModifyKind, RenameMode};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager, State};
+use tokio::sync::mpsc::{self, UnboundedSender};
+
+/// 保留的运行记录条数
+const MAX_HISTORY: usize = 200;
+/// 同一文件在该时间内的重复事件只处理一次
+const DEDUP_WINDOW_SECS: u64 = 10;
+/// 等待文件写入完成的最长时间
+const STABLE_TIMEOUT_SECS: u64 = 120;
+
+/// 串行化规则文件的读写
+static RULES_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WatchAction {
+    ConvertImage {
+        target_format: String, // webp, png, jpg 等
+        quality: Option<u8>,
+        width: Option<u32>,
+        height: Option<u32>,
+    },
+    ConvertVideo {
+        preset: Option<String>, // 视频转换预设名称
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchRule {
+    #[serde(default)]
+    pub id: String,
+    pub name: String,
+    pub watch_dir: String,
+    #[serde(default)]
+    pub recursive: bool,
+    pub extensions: Vec<String>, // 匹配的扩展名，不含点
+    pub action: WatchAction,
+    pub output_dir: String, // 相对路径基于监控目录
+    #[serde(default)]
+    pub delete_source: bool,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchRunRecord {
+    pub rule_id: String,
+    pub rule_name: String,
+    pub input_path: String,
+    pub output_path: Option<String>,
+    pub success: bool,
+    pub message: String,
+    pub started_at: String,
+    pub duration_ms: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchFolderConfig {
+    #[serde(default)]
+    pub rules: Vec<WatchRule>,
+    #[serde(default)]
+    pub history: Vec<WatchRunRecord>, // 最新的记录在前
+}
+
+/// 已启动的目录监听器，按规则 id 索引
+#[derive(Default)]
+pub struct WatchFolderState {
+    watchers: Mutex<HashMap<String, RecommendedWatcher>>,
+    sender: Mutex<Option<UnboundedSender<(String, PathBuf)>>>,
+}
+
+impl WatchFolderState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// 获取全部监控规则
+#[tauri::command]
+pub async fn get_watch_rules() -> Result<Vec<WatchRule>, String> {
+    let _guard = RULES_LOCK.lock().unwrap();
+    Ok(load_config(&get_config_path()).rules)
+}
+
+/// 新增或更新监控规则（按 id 匹配，id 为空时新增），并立即生效
+#[tauri::command]
+pub async fn save_watch_rule(
+    state: State<'_, WatchFolderState>,
+    rule: WatchRule,
+) -> Result<WatchRule, String> {
+    let mut rule = normalize_rule(rule)?;
+    if rule.id.is_empty() {
+        rule.id = format!("{:x}", Utc::now().timestamp_nanos_opt().unwrap_or_default());
+    }
+    apply_watcher(&state, &rule)?;
+
+    let path = get_config_path();
+    let _guard = RULES_LOCK.lock().unwrap();
+    let mut config = load_config(&path);
+    match config.rules.iter_mut().find(|r| r.id == rule.id) {
+        Some(existing) => *existing = rule.clone(),
+        None => config.rules.push(rule.clone()),
+    }
+    save_config(&path, &config)?;
+    Ok(rule)
+}
+
+/// 删除监控规则
+#[tauri::command]
+pub async fn remove_watch_rule(
+    state: State<'_, WatchFolderState>,
+    id: String,
+) -> Result<(), String> {
+    let path = get_config_path();
+    let _guard = RULES_LOCK.lock().unwrap();
+    let mut config = load_config(&path);
+    let before = config.rules.len();
+    config.rules.retain(|r| r.id != id);
+    if config.rules.len() == before {
+        return Err("监控规则不存在".to_string());
+    }
+    state
+        .watchers
+        .lock()
+        .map_err(|e| e.to_string())?
+        .remove(&id);
+    save_config(&path, &config)
+}
+
+/// 启用或停用监控规则
+#[tauri::command]
+pub async fn set_watch_rule_enabled(
+    state: State<'_, WatchFolderState>,
+    id: String,
+    enabled: bool,
+) -> Result<WatchRule, String> {
+    let path = get_config_path();
+    let _guard = RULES_LOCK.lock().unwrap();
+    let mut config = load_config(&path);
+    let rule = config
+        .rules
+        .iter_mut()
+        .find(|r| r.id == id)
+        .ok_or_else(|| "监控规则不存在".to_string())?;
+    rule.enabled = enabled;
+    apply_watcher(&state, rule)?;
+    let rule = rule.clone();
+    save_config(&path, &config)?;
+    Ok(rule)
+}
+
+/// 获取运行记录，可按规则过滤
+#[tauri::command]
+pub async fn get_watch_history(rule_id: Option<String>) -> Result<Vec<WatchRunRecord>, String> {
+    let _guard = RULES_LOCK.lock().unwrap();
+    let history = load_config(&get_config_path()).history;
+    Ok(match rule_id {
+        Some(id) => history.into_iter().filter(|r| r.rule_id == id).collect(),
+        None => history,
+    })
+}
+
+/// 清空运行记录
+#[tauri::command]
+pub async fn clear_watch_history() -> Result<(), String> {
+    let path = get_config_path();
+    let _guard = RULES_LOCK.lock().unwrap();
+    let mut config = load_config(&path);
+    config.history.clear();
+    save_config(&path, &config)
+}
+
+/// 启动处理队列并为已启用的规则建立目录监听
+pub fn spawn_watch_folders(app: AppHandle) {
+    let (sender, mut receiver) = mpsc::unbounded_channel::<(String, PathBuf)>();
+    {
+        let state = app.state::<WatchFolderState>();
+        *state.sender.lock().unwrap() = Some(sender);
+
+        let rules = {
+            let _guard = RULES_LOCK.lock().unwrap();
+            load_config(&get_config_path()).rules
+        };
+        for rule in rules.iter().filter(|r| r.enabled) {
+            if let Err(e) = apply_watcher(&state, rule) {
+                eprintln!("Failed to watch folder {}: {}", rule.watch_dir, e);
+            }
+        }
+    }
+
+    tauri::async_runtime::spawn(async move {
+        let mut recent: HashMap<PathBuf, Instant> = HashMap::new();
+        while let Some((rule_id, path)) = receiver.recv().await {
+            // 创建、写入、重命名会产生多个事件，短时间内只处理一次
+            recent.retain(|_, at| at.elapsed() < Duration::from_secs(DEDUP_WINDOW_SECS));
+            if recent.contains_key(&path) {
+                continue;
+            }
+            recent.insert(path.clone(), Instant::now());
+
+            let app = app.clone();
+            tauri::async_runtime::spawn(async move {
+                process_file(&app, &rule_id, &path).await;
+            });
+        }
+    });
+}
+
+/// 按规则启动或停止目录监听
+fn apply_watcher(state: &WatchFolderState, rule: &WatchRule) -> Result<(), String> {
+    let mut watchers = state.watchers.lock().map_err(|e| e.to_string())?;
+    watchers.remove(&rule.id);
+    if !rule.enabled {
+        return Ok(());
+    }
+
+    let sender = state
+        .sender
+        .lock()
+        .map_err(|e| e.to_string())?
+        .clone()
+        .ok_or_else(|| "监控服务尚未启动".to_string())?;
+    let rule_id = rule.id.clone();
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<Event>| {
+        let Ok(event) = event else {
+            return;
+        };
+        let created = matches!(
+            event.kind,
+            EventKind::Create(_)
+                | EventKind::Modify(ModifyKind::Name(RenameMode::To | RenameMode::Both))
+        );
+        if created {
+            for path in event.paths {
+                let _ = sender.send((rule_id.clone(), path));
+            }
+        }
+    })
+    .map_err(|e| format!("创建目录监听失败: {}", e))?;
+    let mode = if rule.recursive {
+        RecursiveMode::Recursive
+    } else {
+        RecursiveMode::NonRecursive
+    };
+    watcher
+        .watch(Path::new(&rule.watch_dir), mode)
+        .map_err(|e| format!("监听目录失败: {}", e))?;
+    watchers.insert(rule.id.clone(), watcher);
+    Ok(())
+}
+
+async fn process_file(app: &AppHandle, rule_id: &str, path: &Path) {
+    let rule = {
+        let _guard = RULES_LOCK.lock().unwrap();
+        load_config(&get_config_path())
+            .rules
+            .into_iter()
+            .find(|r| r.id == rule_id)
+    };
+    let Some(rule) = rule.filter(|r| r.enabled && rule_matches(r, path)) else {
+        return;
+    };
+    if !wait_until_stable(path).await {
+        return;
+    }
+
+    let started_at = Utc::now();
+    let start = Instant::now();
+    let result = run_action(app, &rule, path).await;
+    let record = WatchRunRecord {
+        rule_id: rule.id.clone(),
+        rule_name: rule.name.clone(),
+        input_path: path.to_string_lossy().to_string(),
+        output_path: result.as_ref().ok().cloned(),
+        success: result.is_ok(),
+        message: match &result {
+            Ok(_) => "转换成功".to_string(),
+            Err(e) => e.clone(),
+        },
+        started_at: started_at.to_rfc3339(),
+        duration_ms: start.elapsed().as_millis() as u64,
+    };
+
+    {
+        let path = get_config_path();
+        let _guard = RULES_LOCK.lock().unwrap();
+        let mut config = load_config(&path);
+        config.history.insert(0, record.clone());
+        config.history.truncate(MAX_HISTORY);
+        if let Err(e) = save_config(&path, &config) {
+            eprintln!("Failed to save watch folder history: {}", e);
+        }
+    }
+
+    let event = if record.success {
+        "watch-folder-processed"
+    } else {
+        "watch-folder-failed"
+    };
+    let _ = app.emit(event, &record);
+
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    let body = match &record.output_path {
+        Some(output) => format!("{}: {} -> {}", rule.name, file_name, output),
+        None => format!("{}: {} 转换失败: {}", rule.name, file_name, record.message),
+    };
+    notification::notify(app, NotificationKind::ConversionFinished, &body);
+}
+
+/// 执行规则动作，返回输出文件路径
+async fn run_action(app: &AppHandle, rule: &WatchRule, input: &Path) -> Result<String, String> {
+    let output_dir = resolve_output_dir(rule);
+    match &rule.action {
+        WatchAction::ConvertImage {
+            target_format,
+            quality,
+            width,
+            height,
+        } => {
+            let output = unique_output_path(&output_dir, input, target_format);
+            let response = image_converter::convert_image(ImageConversionRequest {
+                input_path: input.to_string_lossy().to_string(),
+                output_path: output.to_string_lossy().to_string(),
+                target_format: target_format.clone(),
+                quality: *quality,
+                width: *width,
+                height: *height,
+                remove_exif: false,
+                delete_source_file: Some(rule.delete_source),
+            })
+            .await?;
+            Ok(response.output_path)
+        }
+        WatchAction::ConvertVideo { preset } => {
+            let tray_state = app.state::<GlobalTrayState>();
+            let container = match preset.as_deref().filter(|p| !p.is_empty()) {
+                Some(name) => {
+                    let config = tray_state.config.lock().map_err(|e| e.to_string())?;
+                    video_converter::find_preset(&config.video_presets, name)
+                        .ok_or_else(|| format!("转换预设不存在: {}", name))?
+                        .container
+                }
+                None => video_converter::default_video_preset().container,
+            };
+            let output = unique_output_path(&output_dir, input, &container);
+            let response = video_converter::convert_video(
+                app.clone(),
+                tray_state,
+                VideoConversionRequest {
+                    input_path: input.to_string_lossy().to_string(),
+                    output_path: output.to_string_lossy().to_string(),
+                    delete_source_file: Some(rule.delete_source),
+                    preset: preset.clone(),
+                },
+            )
+            .await?;
+            Ok(response.output_path)
+        }
+    }
+}
+
+/// 等待文件大小稳定，避免处理尚未写完的文件
+async fn wait_until_stable(path: &Path) -> bool {
+    let start = Instant::now();
+    let mut last_size = None;
+    while start.elapsed() < Duration::from_secs(STABLE_TIMEOUT_SECS) {
+        let Ok(metadata) = fs::metadata(path) else {
+            return false;
+        };
+        if !metadata.is_file() {
+            return false;
+        }
+        let size = metadata.len();
+        if size > 0 && last_size == Some(size) {
+            return true;
+        }
+        last_size = Some(size);
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+    false
+}
+
+fn normalize_rule(rule: WatchRule) -> Result<WatchRule, String> {
+    let mut rule = rule;
+    rule.name = rule.name.trim().to_string();
+    if rule.name.is_empty() {
+        return Err("规则名称不能为空".to_string());
+    }
+    if !Path::new(&rule.watch_dir).is_dir() {
+        return Err(format!("监控目录不存在: {}", rule.watch_dir));
+    }
+    if rule.output_dir.trim().is_empty() {
+        return Err("输出目录不能为空".to_string());
+    }
+    rule.extensions = rule
+        .extensions
+        .iter()
+        .map(|e| e.trim().trim_start_matches('.').to_lowercase())
+        .filter(|e| !e.is_empty())
+        .collect();
+    if rule.extensions.is_empty() {
+        return Err("至少需要指定一个扩展名".to_string());
+    }
+    match &rule.action {
+        WatchAction::ConvertImage {
+            target_format,
+            quality,
+            ..
+        } => {
+            if rule.extensions.contains(&target_format.to_lowercase()) {
+                return Err("目标格式不能与监控的扩展名相同".to_string());
+            }
+            if quality.is_some_and(|q| q == 0 || q > 100) {
+                return Err("图片质量取值范围为 1-100".to_string());
+            }
+        }
+        WatchAction::ConvertVideo { .. } => {}
+    }
+    Ok(rule)
+}
+
+/// 判断文件是否应由规则处理
+fn rule_matches(rule: &WatchRule, path: &Path) -> bool {
+    let Some(name) = path.file_name().map(|n| n.to_string_lossy().to_string()) else {
+        return false;
+    };
+    // 跳过隐藏文件和下载中的临时文件
+    if name.starts_with('.') || name.starts_with("~$") {
+        return false;
+    }
+    let ext = path
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    if !rule.extensions.contains(&ext) {
+        return false;
+    }
+    // 输出目录位于监控目录内时，避免处理自己生成的文件
+    !path.starts_with(resolve_output_dir(rule))
+}
+
+fn resolve_output_dir(rule: &WatchRule) -> PathBuf {
+    let output = PathBuf::from(rule.output_dir.trim());
+    if output.is_absolute() {
+        output
+    } else {
+        Path::new(&rule.watch_dir).join(output)
+    }
+}
+
+/// 生成不与现有文件冲突的输出路径
+fn unique_output_path(dir: &Path, input: &Path, extension: &str) -> PathBuf {
+    let stem = input
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| "output".to_string());
+    let extension = extension.to_lowercase();
+    let mut candidate = dir.join(format!("{}.{}", stem, extension));
+    let mut index = 1;
+    while candidate.exists() {
+        candidate = dir.join(format!("{}_{}.{}", stem, index, extension));
+        index += 1;
+    }
+    candidate
+}
+
+fn get_config_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("devtools")
+        .join("watch-folders.json")
+}
+
+fn load_config(path: &Path) -> WatchFolderConfig {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_config(path: &Path, config: &WatchFolderConfig) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("创建配置目录失败: {}", e))?;
+    }
+    let content =
+        serde_json::to_string_pretty(config).map_err(|e| format!("序列化监控规则失败: {}", e))?;
+    fs::write(path, content).map_err(|e| format!("保存监控规则失败: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_rule(dir: &Path) -> WatchRule {
+        WatchRule {
+            id: String::new(),
+            name: " 截图转 WebP ".to_string(),
+            watch_dir: dir.to_string_lossy().to_string(),
+            recursive: true,
+            extensions: vec![".HEIC".to_string(), "png".to_string()],
+            action: WatchAction::ConvertImage {
+                target_format: "webp".to_string(),
+                quality: Some(80),
+                width: None,
+                height: None,
+            },
+            output_dir: "converted".to_string(),
+            delete_source: false,
+            enabled: true,
+        }
+    }
+
+    #[test]
+    fn test_rule_matching() {
+        let dir = std::env::temp_dir();
+        let rule = normalize_rule(sample_rule(&dir)).unwrap();
+        assert_eq!(rule.name, "截图转 WebP");
+        assert_eq!(rule.extensions, vec!["heic", "png"]);
+
+        assert!(rule_matches(&rule, &dir.join("shot.HEIC")));
+        assert!(rule_matches(&rule, &dir.join("sub").join("a.png")));
+        assert!(!rule_matches(&rule, &dir.join("a.jpg")));
+        assert!(!rule_matches(&rule, &dir.join(".hidden.png")));
+        assert!(!rule_matches(&rule, &dir.join("converted").join("a.png")));
+
+        let mut invalid = sample_rule(&dir);
+        invalid.action = WatchAction::ConvertImage {
+            target_format: "PNG".to_string(),
+            quality: None,
+            width: None,
+            height: None,
+        };
+        assert!(normalize_rule(invalid).is_err());
+    }
+
+    #[test]
+    fn test_unique_output_path() {
+        let dir = std::env::temp_dir().join(format!("watch-folder-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let input = Path::new("/photos/IMG_0001.heic");
+
+        let first = unique_output_path(&dir, input, "WEBP");
+        assert_eq!(first, dir.join("IMG_0001.webp"));
+        fs::write(&first, b"x").unwrap();
+        assert_eq!(
+            unique_output_path(&dir, input, "webp"),
+            dir.join("IMG_0001_1.webp")
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,1034 @@
+use crate::tools::certificate_viewer;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 嵌套 include 的最大深度，防止循环引用
+const MAX_INCLUDE_DEPTH: usize = 8;
+
+/// 推荐的协议配置
+const RECOMMENDED_NGINX_PROTOCOLS: &str = "ssl_protocols TLSv1.2 TLSv1.3;";
+const RECOMMENDED_APACHE_PROTOCOLS: &str = "SSLProtocol -all +TLSv1.2 +TLSv1.3";
+
+/// Mozilla intermediate 配置的加密套件
+const RECOMMENDED_CIPHERS: &str = "ECDHE-ECDSA-AES128-GCM-SHA256:ECDHE-RSA-AES128-GCM-SHA256:ECDHE-ECDSA-AES256-GCM-SHA384:ECDHE-RSA-AES256-GCM-SHA384:ECDHE-ECDSA-CHACHA20-POLY1305:ECDHE-RSA-CHACHA20-POLY1305:DHE-RSA-AES128-GCM-SHA256:DHE-RSA-AES256-GCM-SHA384";
+
+/// 不安全的协议版本
+const WEAK_PROTOCOLS: &[&str] = &["SSLv2", "SSLv3", "TLSv1", "TLSv1.1"];
+
+/// (加密套件关键字, 原因)
+const WEAK_CIPHER_TOKENS: &[(&str, &str)] = &[
+    ("RC4", "RC4 存在可恢复明文的偏差漏洞"),
+    ("3DES", "3DES 受 SWEET32 攻击影响"),
+    ("DES", "DES 密钥长度过短"),
+    ("MD5", "MD5 摘要已不安全"),
+    ("NULL", "NULL 套件不加密流量"),
+    ("EXPORT", "出口级套件可被 FREAK/Logjam 降级"),
+    ("ANULL", "匿名套件不验证服务器身份"),
+    ("ADH", "匿名 DH 不验证服务器身份"),
+    ("AECDH", "匿名 ECDH 不验证服务器身份"),
+    ("PSK", "PSK 套件通常不适用于公网服务"),
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParseServerConfigRequest {
+    pub path: Option<String>,
+    pub content: Option<String>, // 与 path 二选一，粘贴内容时相对路径以 base_dir 为准
+    pub base_dir: Option<String>,
+    pub check_certificates: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CertificateCheck {
+    pub path: String,
+    pub exists: bool,
+    pub subject: Option<String>,
+    pub not_after: Option<String>,
+    pub days_until_expiry: Option<i64>,
+    pub sans: Vec<String>,
+    pub is_full_chain: Option<bool>,
+    pub uncovered_names: Vec<String>, // server_name 中未被证书覆盖的域名
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerBlock {
+    pub file: String,
+    pub line: usize,
+    pub listen: Vec<String>,
+    pub server_names: Vec<String>,
+    pub ssl_enabled: bool,
+    pub certificate: Option<String>,
+    pub certificate_key: Option<String>,
+    pub protocols: Option<Vec<String>>, // 生效的协议版本，未配置时为 None
+    pub ciphers: Option<String>,
+    pub certificate_check: Option<CertificateCheck>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigFinding {
+    pub severity: String, // high, medium, low, info
+    pub server: String,
+    pub file: String,
+    pub line: usize,
+    pub directive: String,
+    pub message: String,
+    pub recommendation: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerConfigAnalysis {
+    pub server_type: String,
+    pub files: Vec<String>,
+    pub servers: Vec<ServerBlock>,
+    pub findings: Vec<ConfigFinding>,
+    pub errors: Vec<String>, // include 读取失败等非致命错误
+}
+
+/// 解析 Nginx 配置中的 server 块并检查 SSL 配置
+#[tauri::command]
+pub async fn parse_nginx_config(
+    request: ParseServerConfigRequest,
+) -> Result<ServerConfigAnalysis, String> {
+    let (content, file, base_dir) = read_request(&request)?;
+    let mut loader = Loader::default();
+    let directives = loader.load_nginx(&content, &file, &base_dir, 0)?;
+
+    let mut servers = Vec::new();
+    collect_nginx_servers(&directives, &SslSettings::default(), &mut servers);
+    finish_analysis("nginx", &request, &base_dir, servers, loader)
+}
+
+/// 解析 Apache 配置中的 VirtualHost 并检查 SSL 配置
+#[tauri::command]
+pub async fn parse_apache_config(
+    request: ParseServerConfigRequest,
+) -> Result<ServerConfigAnalysis, String> {
+    let (content, file, base_dir) = read_request(&request)?;
+    let mut loader = Loader::default();
+    let lines = loader.load_apache(&content, &file, &base_dir, 0)?;
+    let servers = collect_apache_servers(&lines);
+    finish_analysis("apache", &request, &base_dir, servers, loader)
+}
+
+fn read_request(request: &ParseServerConfigRequest) -> Result<(String, String, PathBuf), String> {
+    match (&request.content, &request.path) {
+        (Some(content), _) if !content.trim().is_empty() => {
+            let base_dir = request
+                .base_dir
+                .as_deref()
+                .map(PathBuf::from)
+                .unwrap_or_else(|| PathBuf::from("."));
+            Ok((content.clone(), "<input>".to_string(), base_dir))
+        }
+        (_, Some(path)) if !path.is_empty() => {
+            let content =
+                fs::read_to_string(path).map_err(|e| format!("读取配置文件失败: {}", e))?;
+            let base_dir = match request.base_dir.as_deref() {
+                Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+                _ => Path::new(path)
+                    .parent()
+                    .map(Path::to_path_buf)
+                    .unwrap_or_else(|| PathBuf::from(".")),
+            };
+            Ok((content, path.clone(), base_dir))
+        }
+        _ => Err("请提供配置文件或配置内容".to_string()),
+    }
+}
+
+fn finish_analysis(
+    server_type: &str,
+    request: &ParseServerConfigRequest,
+    base_dir: &Path,
+    mut servers: Vec<ServerBlock>,
+    loader: Loader,
+) -> Result<ServerConfigAnalysis, String> {
+    if request.check_certificates.unwrap_or(true) {
+        for server in servers.iter_mut() {
+            if let Some(cert) = &server.certificate {
+                server.certificate_check = Some(check_certificate(
+                    &resolve_path(base_dir, cert),
+                    &server.server_names,
+                ));
+            }
+        }
+    }
+    let findings = servers
+        .iter()
+        .flat_map(|server| analyze_server(server_type, server))
+        .collect();
+    Ok(ServerConfigAnalysis {
+        server_type: server_type.to_string(),
+        files: loader.files,
+        servers,
+        findings,
+        errors: loader.errors,
+    })
+}
+
+/// Nginx 指令树
+#[derive(Debug, Clone)]
+struct Directive {
+    name: String,
+    args: Vec<String>,
+    file: String,
+    line: usize,
+    block: Option<Vec<Directive>>,
+}
+
+/// Apache 配置行
+#[derive(Debug, Clone)]
+struct ApacheLine {
+    name: String,
+    args: Vec<String>,
+    file: String,
+    line: usize,
+}
+
+/// 读取主配置及 include 文件
+#[derive(Default)]
+struct Loader {
+    files: Vec<String>,
+    errors: Vec<String>,
+}
+
+impl Loader {
+    fn load_nginx(
+        &mut self,
+        content: &str,
+        file: &str,
+        base_dir: &Path,
+        depth: usize,
+    ) -> Result<Vec<Directive>, String> {
+        self.files.push(file.to_string());
+        let tokens = tokenize_nginx(content);
+        let mut index = 0;
+        let directives = parse_nginx_block(&tokens, &mut index, file)?;
+        if index < tokens.len() {
+            return Err(format!("{}:{} 存在多余的 '}}'", file, tokens[index].1));
+        }
+        Ok(self.expand_nginx_includes(directives, base_dir, depth))
+    }
+
+    fn expand_nginx_includes(
+        &mut self,
+        directives: Vec<Directive>,
+        base_dir: &Path,
+        depth: usize,
+    ) -> Vec<Directive> {
+        let mut result = Vec::with_capacity(directives.len());
+        for mut directive in directives {
+            if directive.name == "include" && directive.block.is_none() {
+                let Some(pattern) = directive.args.first() else {
+                    continue;
+                };
+                for path in self.include_paths(base_dir, pattern, depth) {
+                    let path_str = path.to_string_lossy().to_string();
+                    let loaded = fs::read_to_string(&path)
+                        .map_err(|e| format!("读取 {} 失败: {}", path_str, e))
+                        .and_then(|content| {
+                            self.load_nginx(&content, &path_str, base_dir, depth + 1)
+                        });
+                    match loaded {
+                        Ok(children) => result.extend(children),
+                        Err(e) => self.errors.push(e),
+                    }
+                }
+                continue;
+            }
+            if let Some(block) = directive.block.take() {
+                directive.block = Some(self.expand_nginx_includes(block, base_dir, depth));
+            }
+            result.push(directive);
+        }
+        result
+    }
+
+    fn load_apache(
+        &mut self,
+        content: &str,
+        file: &str,
+        base_dir: &Path,
+        depth: usize,
+    ) -> Result<Vec<ApacheLine>, String> {
+        self.files.push(file.to_string());
+        let mut result = Vec::new();
+        let mut pending = String::new();
+        let mut start_line = 0;
+
+        for (index, raw) in content.lines().enumerate() {
+            // 行尾反斜杠表示续行
+            if pending.is_empty() {
+                start_line = index + 1;
+            }
+            let trimmed = raw.trim();
+            if let Some(stripped) = trimmed.strip_suffix('\\') {
+                pending.push_str(stripped);
+                pending.push(' ');
+                continue;
+            }
+            pending.push_str(trimmed);
+            let line = std::mem::take(&mut pending);
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let words = split_words(&line);
+            let Some((name, args)) = words.split_first() else {
+                continue;
+            };
+            if name.eq_ignore_ascii_case("Include") || name.eq_ignore_ascii_case("IncludeOptional")
+            {
+                let optional = name.eq_ignore_ascii_case("IncludeOptional");
+                for pattern in args {
+                    for path in self.include_paths(base_dir, pattern, depth) {
+                        let path_str = path.to_string_lossy().to_string();
+                        let loaded = fs::read_to_string(&path)
+                            .map_err(|e| format!("读取 {} 失败: {}", path_str, e))
+                            .and_then(|c| self.load_apache(&c, &path_str, base_dir, depth + 1));
+                        match loaded {
+                            Ok(lines) => result.extend(lines),
+                            Err(e) if !optional => self.errors.push(e),
+                            Err(_) => {}
+                        }
+                    }
+                }
+                continue;
+            }
+            result.push(ApacheLine {
+                name: name.to_string(),
+                args: args.to_vec(),
+                file: file.to_string(),
+                line: start_line,
+            });
+        }
+        Ok(result)
+    }
+
+    /// 展开 include 路径，支持文件名中的 * 通配符
+    fn include_paths(&mut self, base_dir: &Path, pattern: &str, depth: usize) -> Vec<PathBuf> {
+        if depth >= MAX_INCLUDE_DEPTH {
+            self.errors.push(format!(
+                "include 嵌套超过 {} 层: {}",
+                MAX_INCLUDE_DEPTH, pattern
+            ));
+            return Vec::new();
+        }
+        let path = resolve_path(base_dir, pattern);
+        let file_name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        if !file_name.contains('*') {
+            if path.is_dir() {
+                // Apache 允许 include 整个目录
+                return list_dir_matching(&path, "*");
+            }
+            return vec![path];
+        }
+        match path.parent() {
+            Some(dir) => list_dir_matching(dir, &file_name),
+            None => Vec::new(),
+        }
+    }
+}
+
+fn list_dir_matching(dir: &Path, pattern: &str) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|e| e.path())
+                .filter(|p| p.is_file())
+                .filter(|p| {
+                    p.file_name()
+                        .and_then(|n| n.to_str())
+                        .is_some_and(|n| wildcard_match(pattern, n))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    paths.sort();
+    paths
+}
+
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let parts: Vec<&str> = pattern.split('*').collect();
+    if parts.len() == 1 {
+        return pattern == name;
+    }
+    let mut rest = name;
+    for (i, part) in parts.iter().enumerate() {
+        if i == 0 {
+            let Some(stripped) = rest.strip_prefix(part) else {
+                return false;
+            };
+            rest = stripped;
+        } else if i == parts.len() - 1 {
+            return rest.ends_with(part);
+        } else {
+            match rest.find(part) {
+                Some(pos) => rest = &rest[pos + part.len()..],
+                None => return false,
+            }
+        }
+    }
+    true
+}
+
+fn resolve_path(base_dir: &Path, value: &str) -> PathBuf {
+    let path = Path::new(value);
+    if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        base_dir.join(path)
+    }
+}
+
+/// 按空白拆分，保留引号内的空格
+fn split_words(line: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut quote = None;
+    for c in line.chars() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if c == q => quote = None,
+            (None, c) if c.is_whitespace() => {
+                if !current.is_empty() {
+                    words.push(std::mem::take(&mut current));
+                }
+            }
+            _ => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+/// 返回 (token, 行号)，`{` `}` `;` 作为单独的 token
+fn tokenize_nginx(content: &str) -> Vec<(String, usize)> {
+    let mut tokens = Vec::new();
+    let mut chars = content.chars().peekable();
+    let mut line = 1;
+    let mut current = String::new();
+    let mut current_line = 1;
+
+    let flush = |current: &mut String, tokens: &mut Vec<(String, usize)>, line: usize| {
+        if !current.is_empty() {
+            tokens.push((std::mem::take(current), line));
+        }
+    };
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\n' => {
+                flush(&mut current, &mut tokens, current_line);
+                line += 1;
+            }
+            '#' if current.is_empty() => {
+                while chars.peek().is_some_and(|&n| n != '\n') {
+                    chars.next();
+                }
+            }
+            '"' | '\'' => {
+                if current.is_empty() {
+                    current_line = line;
+                }
+                while let Some(n) = chars.next() {
+                    match n {
+                        '\\' => {
+                            if let Some(escaped) = chars.next() {
+                                current.push(escaped);
+                            }
+                        }
+                        _ if n == c => break,
+                        '\n' => {
+                            line += 1;
+                            current.push(n);
+                        }
+                        _ => current.push(n),
+                    }
+                }
+            }
+            '{' | '}' | ';' => {
+                flush(&mut current, &mut tokens, current_line);
+                tokens.push((c.to_string(), line));
+            }
+            c if c.is_whitespace() => flush(&mut current, &mut tokens, current_line),
+            _ => {
+                if current.is_empty() {
+                    current_line = line;
+                }
+                current.push(c);
+            }
+        }
+    }
+    flush(&mut current, &mut tokens, current_line);
+    tokens
+}
+
+fn parse_nginx_block(
+    tokens: &[(String, usize)],
+    index: &mut usize,
+    file: &str,
+) -> Result<Vec<Directive>, String> {
+    let mut directives = Vec::new();
+    let mut words: Vec<String> = Vec::new();
+    let mut start_line = 0;
+
+    while *index < tokens.len() {
+        let (token, line) = &tokens[*index];
+        *index += 1;
+        match token.as_str() {
+            ";" => {
+                if let Some((name, args)) = words.split_first() {
+                    directives.push(Directive {
+                        name: name.clone(),
+                        args: args.to_vec(),
+                        file: file.to_string(),
+                        line: start_line,
+                        block: None,
+                    });
+                }
+                words.clear();
+            }
+            "{" => {
+                let (name, args) = words
+                    .split_first()
+                    .ok_or_else(|| format!("{}:{} 块缺少指令名", file, line))?;
+                let (name, args, block_line) = (name.clone(), args.to_vec(), start_line);
+                words.clear();
+                let block = parse_nginx_block(tokens, index, file)?;
+                if tokens.get(*index - 1).map(|t| t.0.as_str()) != Some("}") {
+                    return Err(format!("{}:{} 块 {} 缺少 '}}'", file, block_line, name));
+                }
+                directives.push(Directive {
+                    name,
+                    args,
+                    file: file.to_string(),
+                    line: block_line,
+                    block: Some(block),
+                });
+            }
+            "}" => {
+                if !words.is_empty() {
+                    return Err(format!(
+                        "{}:{} 指令 {} 缺少 ';'",
+                        file, start_line, words[0]
+                    ));
+                }
+                return Ok(directives);
+            }
+            _ => {
+                if words.is_empty() {
+                    start_line = *line;
+                }
+                words.push(token.clone());
+            }
+        }
+    }
+    if !words.is_empty() {
+        return Err(format!(
+            "{}:{} 指令 {} 缺少 ';'",
+            file, start_line, words[0]
+        ));
+    }
+    Ok(directives)
+}
+
+/// 可从 http 块继承到 server 块的 SSL 设置
+#[derive(Debug, Clone, Default)]
+struct SslSettings {
+    certificate: Option<String>,
+    certificate_key: Option<String>,
+    protocols: Option<Vec<String>>,
+    ciphers: Option<String>,
+    ssl_on: bool,
+}
+
+impl SslSettings {
+    fn apply(&mut self, directive: &Directive) {
+        let joined = directive.args.join(" ");
+        match directive.name.as_str() {
+            "ssl_certificate" => self.certificate = directive.args.first().cloned(),
+            "ssl_certificate_key" => self.certificate_key = directive.args.first().cloned(),
+            "ssl_protocols" => self.protocols = Some(directive.args.clone()),
+            "ssl_ciphers" => self.ciphers = Some(joined),
+            "ssl" => self.ssl_on = joined == "on",
+            _ => {}
+        }
+    }
+}
+
+fn collect_nginx_servers(
+    directives: &[Directive],
+    inherited: &SslSettings,
+    servers: &mut Vec<ServerBlock>,
+) {
+    let mut settings = inherited.clone();
+    for directive in directives.iter().filter(|d| d.block.is_none()) {
+        settings.apply(directive);
+    }
+
+    for directive in directives {
+        let Some(block) = &directive.block else {
+            continue;
+        };
+        match directive.name.as_str() {
+            "server" => {
+                let mut server_settings = settings.clone();
+                let mut listen = Vec::new();
+                let mut server_names = Vec::new();
+                for child in block {
+                    server_settings.apply(child);
+                    match child.name.as_str() {
+                        "listen" => listen.push(child.args.join(" ")),
+                        "server_name" => server_names.extend(child.args.iter().cloned()),
+                        _ => {}
+                    }
+                }
+                let ssl_listen = listen
+                    .iter()
+                    .any(|l| l.split_whitespace().any(|w| w == "ssl" || w == "quic"));
+                servers.push(ServerBlock {
+                    file: directive.file.clone(),
+                    line: directive.line,
+                    listen,
+                    server_names,
+                    ssl_enabled: ssl_listen || server_settings.ssl_on,
+                    certificate: server_settings.certificate,
+                    certificate_key: server_settings.certificate_key,
+                    protocols: server_settings.protocols,
+                    ciphers: server_settings.ciphers,
+                    certificate_check: None,
+                });
+            }
+            // stream 块中也可能包含 server
+            "http" | "stream" => collect_nginx_servers(block, &settings, servers),
+            _ => {}
+        }
+    }
+}
+
+/// 计算 SSLProtocol 最终启用的协议
+fn apache_protocols(args: &[String]) -> Vec<String> {
+    let all = ["SSLv3", "TLSv1", "TLSv1.1", "TLSv1.2", "TLSv1.3"];
+    let mut enabled: Vec<String> = Vec::new();
+    for arg in args {
+        let (op, name) = match arg.chars().next() {
+            Some('-') => ('-', &arg[1..]),
+            Some('+') => ('+', &arg[1..]),
+            _ => ('+', arg.as_str()),
+        };
+        let targets: Vec<String> = if name.eq_ignore_ascii_case("all") {
+            all.iter().map(|s| s.to_string()).collect()
+        } else {
+            vec![name.to_string()]
+        };
+        for target in targets {
+            if op == '-' {
+                enabled.retain(|p| !p.eq_ignore_ascii_case(&target));
+            } else if !enabled.iter().any(|p| p.eq_ignore_ascii_case(&target)) {
+                enabled.push(target);
+            }
+        }
+    }
+    enabled
+}
+
+fn collect_apache_servers(lines: &[ApacheLine]) -> Vec<ServerBlock> {
+    let mut servers = Vec::new();
+    let mut global = SslSettings::default();
+    let mut current: Option<(ServerBlock, SslSettings)> = None;
+
+    for line in lines {
+        let name = line.name.to_lowercase();
+        if name.starts_with("<virtualhost") {
+            let address = line.args.join(" ").trim_end_matches('>').to_string();
+            let block = ServerBlock {
+                file: line.file.clone(),
+                line: line.line,
+                listen: address.split_whitespace().map(str::to_string).collect(),
+                server_names: Vec::new(),
+                ssl_enabled: false,
+                certificate: None,
+                certificate_key: None,
+                protocols: None,
+                ciphers: None,
+                certificate_check: None,
+            };
+            current = Some((block, global.clone()));
+            continue;
+        }
+        if name.starts_with("</virtualhost") {
+            if let Some((mut block, settings)) = current.take() {
+                block.ssl_enabled = settings.ssl_on;
+                block.certificate = settings.certificate;
+                block.certificate_key = settings.certificate_key;
+                block.protocols = settings.protocols;
+                block.ciphers = settings.ciphers;
+                servers.push(block);
+            }
+            continue;
+        }
+
+        let settings = match current.as_mut() {
+            Some((block, settings)) => {
+                if name == "servername" || name == "serveralias" {
+                    block.server_names.extend(line.args.iter().cloned());
+                }
+                settings
+            }
+            None => &mut global,
+        };
+        let first = line.args.first().cloned();
+        match name.as_str() {
+            "sslengine" => settings.ssl_on = first.is_some_and(|v| v.eq_ignore_ascii_case("on")),
+            "sslcertificatefile" => settings.certificate = first,
+            "sslcertificatekeyfile" => settings.certificate_key = first,
+            "sslprotocol" => settings.protocols = Some(apache_protocols(&line.args)),
+            "sslciphersuite" => {
+                // 带协议前缀的写法（如 TLSv1.3 ...）只检查最后一个参数
+                settings.ciphers = line.args.last().cloned();
+            }
+            _ => {}
+        }
+    }
+    servers
+}
+
+fn server_label(server: &ServerBlock) -> String {
+    server
+        .server_names
+        .first()
+        .cloned()
+        .or_else(|| server.listen.first().cloned())
+        .unwrap_or_else(|| format!("{}:{}", server.file, server.line))
+}
+
+fn cert_name_matches(pattern: &str, name: &str) -> bool {
+    let (pattern, name) = (pattern.to_lowercase(), name.to_lowercase());
+    if pattern == name {
+        return true;
+    }
+    // 通配符只匹配一级子域名
+    match pattern.strip_prefix("*.") {
+        Some(suffix) => name
+            .split_once('.')
+            .is_some_and(|(label, rest)| !label.is_empty() && rest == suffix),
+        None => false,
+    }
+}
+
+fn check_certificate(path: &Path, server_names: &[String]) -> CertificateCheck {
+    let path_str = path.to_string_lossy().to_string();
+    let mut check = CertificateCheck {
+        path: path_str,
+        exists: path.is_file(),
+        subject: None,
+        not_after: None,
+        days_until_expiry: None,
+        sans: Vec::new(),
+        is_full_chain: None,
+        uncovered_names: Vec::new(),
+        error: None,
+    };
+    if !check.exists {
+        check.error = Some("证书文件不存在或无法访问".to_string());
+        return check;
+    }
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) => {
+            check.error = Some(format!("读取证书失败: {}", e));
+            return check;
+        }
+    };
+    let chain = match certificate_viewer::parse_pem_certificate(content, None) {
+        Ok(chain) => chain,
+        Err(e) => {
+            check.error = Some(e);
+            return check;
+        }
+    };
+
+    if let Some(leaf) = chain.certificates.iter().min_by_key(|c| c.chain_level) {
+        check.subject = leaf.subject.get("通用名称 (CN)").cloned();
+        check.not_after = Some(leaf.validity.not_after.clone());
+        check.days_until_expiry = Some(leaf.validity.days_until_expiry);
+        check.sans = leaf.sans.clone();
+        check.uncovered_names = server_names
+            .iter()
+            .filter(|name| {
+                // 跳过默认、正则和通配写法的 server_name
+                !(name.is_empty()
+                    || *name == "_"
+                    || name.starts_with('~')
+                    || name.starts_with('.')
+                    || name.contains('$'))
+            })
+            .filter(|name| !leaf.sans.iter().any(|san| cert_name_matches(san, name)))
+            .cloned()
+            .collect();
+    }
+    check.is_full_chain = Some(chain.is_full_chain);
+    check
+}
+
+fn analyze_server(server_type: &str, server: &ServerBlock) -> Vec<ConfigFinding> {
+    let mut findings = Vec::new();
+    let label = server_label(server);
+    let is_nginx = server_type == "nginx";
+    let mut add = |severity: &str, directive: &str, message: String, recommendation: String| {
+        findings.push(ConfigFinding {
+            severity: severity.to_string(),
+            server: label.clone(),
+            file: server.file.clone(),
+            line: server.line,
+            directive: directive.to_string(),
+            message,
+            recommendation,
+        });
+    };
+
+    if !server.ssl_enabled {
+        return findings;
+    }
+    let (cert_directive, protocol_directive, cipher_directive, recommended_protocols) = if is_nginx
+    {
+        (
+            "ssl_certificate",
+            "ssl_protocols",
+            "ssl_ciphers",
+            RECOMMENDED_NGINX_PROTOCOLS,
+        )
+    } else {
+        (
+            "SSLCertificateFile",
+            "SSLProtocol",
+            "SSLCipherSuite",
+            RECOMMENDED_APACHE_PROTOCOLS,
+        )
+    };
+
+    if server.certificate.is_none() {
+        add(
+            "high",
+            cert_directive,
+            "已启用 SSL 但未配置证书".to_string(),
+            format!("添加 {} 指向证书链文件", cert_directive),
+        );
+    }
+
+    match &server.protocols {
+        Some(protocols) => {
+            let weak: Vec<&String> = protocols
+                .iter()
+                .filter(|p| WEAK_PROTOCOLS.iter().any(|w| w.eq_ignore_ascii_case(p)))
+                .collect();
+            if !weak.is_empty() {
+                add(
+                    "high",
+                    protocol_directive,
+                    format!(
+                        "启用了不安全的协议: {}",
+                        weak.iter()
+                            .map(|s| s.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                    recommended_protocols.to_string(),
+                );
+            }
+            if !protocols.iter().any(|p| p.eq_ignore_ascii_case("TLSv1.3")) {
+                add(
+                    "low",
+                    protocol_directive,
+                    "未启用 TLSv1.3".to_string(),
+                    recommended_protocols.to_string(),
+                );
+            }
+        }
+        None if is_nginx => add(
+            "info",
+            protocol_directive,
+            "未显式配置 ssl_protocols，1.23.4 之前的 Nginx 默认仍启用 TLSv1 和 TLSv1.1".to_string(),
+            recommended_protocols.to_string(),
+        ),
+        None => add(
+            "medium",
+            protocol_directive,
+            "未显式配置 SSLProtocol，旧版本 Apache 默认启用 TLSv1 和 TLSv1.1".to_string(),
+            recommended_protocols.to_string(),
+        ),
+    }
+
+    if let Some(ciphers) = &server.ciphers {
+        let weak = weak_cipher_tokens(ciphers);
+        if !weak.is_empty() {
+            add(
+                "high",
+                cipher_directive,
+                format!("加密套件包含不安全的算法: {}", weak.join("；")),
+                format!("{} {}", cipher_directive, RECOMMENDED_CIPHERS),
+            );
+        }
+    }
+
+    if let Some(check) = &server.certificate_check {
+        if let Some(error) = &check.error {
+            add(
+                "high",
+                cert_directive,
+                format!("证书 {} 检查失败: {}", check.path, error),
+                "确认证书路径正确且 Web 服务进程有读取权限".to_string(),
+            );
+        }
+        match check.days_until_expiry {
+            Some(days) if days < 0 => add(
+                "high",
+                cert_directive,
+                format!("证书已过期 {} 天", -days),
+                "立即更新证书".to_string(),
+            ),
+            Some(days) if days < 30 => add(
+                "medium",
+                cert_directive,
+                format!("证书将在 {} 天后过期", days),
+                "尽快续期证书，建议配置自动续期".to_string(),
+            ),
+            _ => {}
+        }
+        if check.is_full_chain == Some(false) {
+            add(
+                "medium",
+                cert_directive,
+                "证书文件缺少中间证书，部分客户端可能无法验证".to_string(),
+                "将中间证书追加到证书文件（fullchain）中".to_string(),
+            );
+        }
+        if !check.uncovered_names.is_empty() {
+            add(
+                "high",
+                cert_directive,
+                format!("证书未覆盖域名: {}", check.uncovered_names.join(", ")),
+                "重新签发包含这些域名的证书，或调整 server_name".to_string(),
+            );
+        }
+    }
+    findings
+}
+
+/// 找出未被 ! 或 - 排除的弱加密关键字
+fn weak_cipher_tokens(ciphers: &str) -> Vec<String> {
+    let mut result = Vec::new();
+    for token in ciphers.split([':', ' ', ',']).filter(|t| !t.is_empty()) {
+        if token.starts_with('!') || token.starts_with('-') {
+            continue;
+        }
+        let upper = token.trim_start_matches('+').to_uppercase();
+        let parts: Vec<&str> = upper.split(['-', '+']).collect();
+        for (keyword, reason) in WEAK_CIPHER_TOKENS {
+            // DES 需避免误判 3DES 中的 DES
+            let matched = parts.iter().any(|part| {
+                part == keyword
+                    || (*keyword == "3DES" && *part == "DES-CBC3")
+                    || (*keyword == "3DES" && part.starts_with("DES") && upper.contains("CBC3"))
+            });
+            if matched {
+                result.push(format!("{} ({})", token, reason));
+                break;
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NGINX_CONFIG: &str = r#"
+# global
+http {
+    ssl_protocols TLSv1 TLSv1.2;
+    include missing/*.conf;
+
+    server {
+        listen 80;
+        server_name example.com;
+    }
+
+    server {
+        listen 443 ssl http2;
+        listen [::]:443 ssl;
+        server_name example.com "www.example.com";
+        ssl_certificate /nonexistent/fullchain.pem;
+        ssl_ciphers 'ECDHE-RSA-AES128-GCM-SHA256:RC4-SHA:!aNULL:DES-CBC3-SHA';
+        location / { proxy_pass http://127.0.0.1:8080; }
+    }
+}
+"#;
+
+    #[test]
+    fn test_parse_nginx_servers() {
+        let mut loader = Loader::default();
+        let directives = loader
+            .load_nginx(NGINX_CONFIG, "nginx.conf", Path::new("/nonexistent"), 0)
+            .unwrap();
+        let mut servers = Vec::new();
+        collect_nginx_servers(&directives, &SslSettings::default(), &mut servers);
+
+        assert_eq!(servers.len(), 2);
+        assert!(!servers[0].ssl_enabled);
+        let ssl = &servers[1];
+        assert!(ssl.ssl_enabled);
+        assert_eq!(ssl.server_names, vec!["example.com", "www.example.com"]);
+        assert_eq!(ssl.listen, vec!["443 ssl http2", "[::]:443 ssl"]);
+        assert_eq!(ssl.protocols.as_ref().unwrap(), &vec!["TLSv1", "TLSv1.2"]);
+        assert_eq!(ssl.line, 12);
+
+        let mut server = ssl.clone();
+        server.certificate_check = Some(check_certificate(
+            Path::new(server.certificate.as_ref().unwrap()),
+            &server.server_names,
+        ));
+        let findings = analyze_server("nginx", &server);
+        let messages: Vec<&str> = findings.iter().map(|f| f.message.as_str()).collect();
+        assert!(messages.iter().any(|m| m.contains("TLSv1")));
+        assert!(messages
+            .iter()
+            .any(|m| m.contains("RC4-SHA") && m.contains("DES-CBC3-SHA")));
+        assert!(messages.iter().any(|m| m.contains("检查失败")));
+
+        assert!(parse_nginx_block(&tokenize_nginx("server { listen 80 }"), &mut 0, "x").is_err());
+    }
+
+    #[test]
+    fn test_parse_apache_vhost() {
+        let config = "SSLProtocol all -SSLv3\n<VirtualHost *:443>\n  ServerName example.com\n  ServerAlias api.example.com\n  SSLEngine on\n  SSLCertificateFile /etc/ssl/site.pem\n  SSLCipherSuite HIGH:!aNULL:!MD5\n</VirtualHost>\n";
+        let mut loader = Loader::default();
+        let lines = loader
+            .load_apache(config, "site.conf", Path::new("."), 0)
+            .unwrap();
+        let servers = collect_apache_servers(&lines);
+
+        assert_eq!(servers.len(), 1);
+        assert_eq!(servers[0].listen, vec!["*:443"]);
+        assert_eq!(
+            servers[0].server_names,
+            vec!["example.com", "api.example.com"]
+        );
+        assert_eq!(
+            servers[0].protocols.as_ref().unwrap(),
+            &vec!["TLSv1", "TLSv1.1", "TLSv1.2", "TLSv1.3"]
+        );
+        assert!(weak_cipher_tokens("HIGH:!aNULL:!MD5").is_empty());
+        assert!(cert_name_matches("*.example.com", "api.example.com"));
+        assert!(!cert_name_matches("*.example.com", "a.b.example.com"));
+    }
+}
@@ -1,9 +1,23 @@
+use crate::utils::{i18n, proxy, rate_limit};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::future::Future;
 use std::io::{Read, Write};
 use std::net::{TcpStream, ToSocketAddrs};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 use std::time::Duration;
 
+/// 缓存有效期，注册局数据变化不频繁
+const WHOIS_CACHE_TTL_SECS: i64 = 12 * 3600;
+/// 缓存条目上限，超出时淘汰最早的条目
+const WHOIS_CACHE_MAX_ENTRIES: usize = 500;
+
+/// 串行化缓存文件的读写
+static WHOIS_CACHE_LOCK: Mutex<()> = Mutex::new(());
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct WhoisParsed {
@@ -17,6 +31,8 @@ pub struct WhoisParsed {
     pub status: Option<Vec<String>>,
     pub name_servers: Option<Vec<String>>,
     pub raw_text: Option<String>,
+    #[serde(default)]
+    pub cached_at: Option<String>, // 命中缓存时为缓存时间
 }
 
 fn extract_tld(domain: &str) -> Option<String> {
@@ -28,7 +44,7 @@ fn extract_tld(domain: &str) -> Option<String> {
     }
 }
 
-fn parse_whois_text(domain: &str, source: &str, text: &str) -> WhoisParsed {
+pub(crate) fn parse_whois_text(domain: &str, source: &str, text: &str) -> WhoisParsed {
     let registrar_re = Regex::new(r"(?i)^\s*(Registrar|Sponsoring Registrar)\s*:\s*(.+)$").ok();
     let registrant_re =
         Regex::new(r"(?i)^\s*(Registrant Organization|Registrant Name)\s*:\s*(.+)$").ok();
@@ -128,6 +144,7 @@ fn parse_whois_text(domain: &str, source: &str, text: &str) -> WhoisParsed {
             Some(name_servers)
         },
         raw_text: Some(text.to_string()),
+        cached_at: None,
     }
 }
 
@@ -233,56 +250,65 @@ fn parse_rdap_json(domain: &str, source: &str, val: &serde_json::Value) -> Whois
         status,
         name_servers,
         raw_text: Some(val.to_string()),
+        cached_at: None,
     }
 }
 
 async fn rdap_org_query(domain: &str) -> Result<WhoisParsed, String> {
     let url = format!("https://rdap.org/domain/{}", domain);
-    let client = reqwest::Client::builder()
+    let client = proxy::client_builder("whois")
         .timeout(Duration::from_secs(3))
         .build()
-        .map_err(|e| format!("构建 HTTP 客户端失败: {}", e))?;
-    let resp = client
-        .get(&url)
-        .header("Accept", "application/rdap+json")
-        .send()
-        .await
-        .map_err(|e| format!("rdap.org 请求失败: {}", e))?;
+        .map_err(|e| i18n::tr("common.http_client_failed", &[&e.to_string()]))?;
+    let resp = rate_limit::send_with_retry("rdap.org", || {
+        client.get(&url).header("Accept", "application/rdap+json")
+    })
+    .await
+    .map_err(|e| i18n::tr("common.request_failed", &["rdap.org", &e]))?;
     let status = resp.status();
     if !status.is_success() {
-        return Err(format!("rdap.org 响应状态异常: {}", status));
+        return Err(i18n::tr(
+            "common.unexpected_status",
+            &["rdap.org", &status.to_string()],
+        ));
     }
-    let val: serde_json::Value = resp
-        .json()
-        .await
-        .map_err(|e| format!("解析 rdap.org 响应失败: {}", e))?;
+    let val: serde_json::Value = resp.json().await.map_err(|e| {
+        i18n::tr(
+            "common.parse_response_failed",
+            &["rdap.org", &e.to_string()],
+        )
+    })?;
     Ok(parse_rdap_json(domain, "rdap.org", &val))
 }
 
 async fn rdap_verisign_query(domain: &str) -> Result<WhoisParsed, String> {
     let tld = extract_tld(domain).unwrap_or_default();
     if tld != "com" && tld != "net" {
-        return Err("Verisign RDAP 仅支持 .com/.net".to_string());
+        return Err(i18n::t("whois.verisign_com_net_only"));
     }
     let url = format!("https://rdap.verisign.com/{}/v1/domain/{}", tld, domain);
-    let client = reqwest::Client::builder()
+    let client = proxy::client_builder("whois")
         .timeout(Duration::from_secs(3))
         .build()
-        .map_err(|e| format!("构建 HTTP 客户端失败: {}", e))?;
-    let resp = client
-        .get(&url)
-        .header("Accept", "application/rdap+json")
-        .send()
-        .await
-        .map_err(|e| format!("Verisign RDAP 请求失败: {}", e))?;
+        .map_err(|e| i18n::tr("common.http_client_failed", &[&e.to_string()]))?;
+    let resp = rate_limit::send_with_retry("rdap.verisign.com", || {
+        client.get(&url).header("Accept", "application/rdap+json")
+    })
+    .await
+    .map_err(|e| i18n::tr("common.request_failed", &["Verisign RDAP", &e]))?;
     let status = resp.status();
     if !status.is_success() {
-        return Err(format!("Verisign RDAP 响应状态异常: {}", status));
+        return Err(i18n::tr(
+            "common.unexpected_status",
+            &["Verisign RDAP", &status.to_string()],
+        ));
     }
-    let val: serde_json::Value = resp
-        .json()
-        .await
-        .map_err(|e| format!("解析 Verisign RDAP 响应失败: {}", e))?;
+    let val: serde_json::Value = resp.json().await.map_err(|e| {
+        i18n::tr(
+            "common.parse_response_failed",
+            &["Verisign RDAP", &e.to_string()],
+        )
+    })?;
     Ok(parse_rdap_json(domain, "rdap.verisign.com", &val))
 }
 
@@ -291,35 +317,78 @@ fn query_whois_server(server: &str, query: &str) -> Result<String, String> {
     // Resolve hostname to SocketAddr (supports DNS hostnames)
     let mut addrs = (server, 43)
         .to_socket_addrs()
-        .map_err(|e| format!("解析 WHOIS 服务器地址失败: {}", e))?;
+        .map_err(|e| i18n::tr("whois.resolve_server_failed", &[&e.to_string()]))?;
     let socket_addr = addrs
         .next()
-        .ok_or_else(|| "解析 WHOIS 服务器地址失败: 无有效地址".to_string())?;
+        .ok_or_else(|| i18n::t("whois.server_no_address"))?;
     let mut stream = TcpStream::connect_timeout(&socket_addr, timeout)
-        .map_err(|e| format!("连接 WHOIS 服务器失败: {}", e))?;
+        .map_err(|e| i18n::tr("whois.connect_failed", &[&e.to_string()]))?;
     stream
         .set_read_timeout(Some(timeout))
-        .map_err(|e| format!("设置读取超时失败: {}", e))?;
+        .map_err(|e| i18n::tr("common.set_read_timeout_failed", &[&e.to_string()]))?;
     stream
         .set_write_timeout(Some(timeout))
-        .map_err(|e| format!("设置写入超时失败: {}", e))?;
+        .map_err(|e| i18n::tr("common.set_write_timeout_failed", &[&e.to_string()]))?;
 
     let q = format!("{}\r\n", query);
     stream
         .write_all(q.as_bytes())
-        .map_err(|e| format!("发送查询失败: {}", e))?;
+        .map_err(|e| i18n::tr("whois.send_query_failed", &[&e.to_string()]))?;
     let mut buf = Vec::new();
     stream
         .read_to_end(&mut buf)
-        .map_err(|e| format!("读取响应失败: {}", e))?;
+        .map_err(|e| i18n::tr("common.read_response_failed", &[&e.to_string()]))?;
 
     let text = String::from_utf8_lossy(&buf).to_string();
     Ok(text)
 }
 
-fn resolve_whois_server_for_tld(tld: &str) -> Option<String> {
+/// 按服务器限流查询 WHOIS，响应提示查询过频时退避重试
+async fn query_whois_polite(server: &str, query: &str) -> Result<String, String> {
+    let provider = format!("whois:{}", server);
+    let max_retries = rate_limit::current_aggressiveness().max_retries();
+    let mut attempt = 0;
+    loop {
+        let permit = rate_limit::acquire(&provider).await;
+        let s_owned = server.to_string();
+        let q_owned = query.to_string();
+        let text = tokio::task::spawn_blocking(move || query_whois_server(&s_owned, &q_owned))
+            .await
+            .map_err(|_| i18n::t("error.async_execution"))??;
+        drop(permit);
+        if !is_whois_throttled(&text) {
+            return Ok(text);
+        }
+        if attempt >= max_retries {
+            return Err(i18n::tr(
+                "rate_limit.throttled",
+                &[server, &attempt.to_string()],
+            ));
+        }
+        rate_limit::penalize(&provider, rate_limit::backoff_delay(attempt, None));
+        attempt += 1;
+    }
+}
+
+/// WHOIS 服务器限流时通常只返回一句简短提示
+fn is_whois_throttled(text: &str) -> bool {
+    const MARKERS: &[&str] = &[
+        "limit exceeded",
+        "query rate",
+        "too many queries",
+        "too many requests",
+        "exceeded the maximum allowable",
+        "please try again later",
+    ];
+    let lower = text.to_ascii_lowercase();
+    text.len() < 2048
+        && !lower.contains("domain name:")
+        && MARKERS.iter().any(|marker| lower.contains(marker))
+}
+
+async fn resolve_whois_server_for_tld(tld: &str) -> Option<String> {
     // Try IANA referral first
-    if let Ok(resp) = query_whois_server("whois.iana.org", tld) {
+    if let Ok(resp) = query_whois_polite("whois.iana.org", tld).await {
         for line in resp.lines() {
             let l = line.trim();
             if l.to_ascii_lowercase().starts_with("refer:")
@@ -343,11 +412,18 @@ fn resolve_whois_server_for_tld(tld: &str) -> Option<String> {
 }
 
 #[tauri::command]
-pub async fn query_rdap(domain: String) -> Result<WhoisParsed, String> {
+pub async fn query_rdap(
+    domain: String,
+    force_refresh: Option<bool>,
+) -> Result<WhoisParsed, String> {
     let d = domain.trim();
     if d.is_empty() {
-        return Err("域名不能为空".to_string());
+        return Err(i18n::t("common.domain_required"));
     }
+    with_cache("rdap", d, force_refresh, rdap_lookup(d)).await
+}
+
+async fn rdap_lookup(d: &str) -> Result<WhoisParsed, String> {
     // Try rdap.org, then Verisign RDAP if appropriate
     match rdap_org_query(d).await {
         Ok(p) => Ok(p),
@@ -355,7 +431,7 @@ pub async fn query_rdap(domain: String) -> Result<WhoisParsed, String> {
             if matches!(extract_tld(d).as_deref(), Some("com") | Some("net")) {
                 match rdap_verisign_query(d).await {
                     Ok(p) => Ok(p),
-                    Err(e2) => Err(format!("RDAP 查询失败: {}; {}", e1, e2)),
+                    Err(e2) => Err(i18n::tr("whois.rdap_failed", &[&e1, &e2])),
                 }
             } else {
                 Err(e1)
@@ -365,21 +441,24 @@ pub async fn query_rdap(domain: String) -> Result<WhoisParsed, String> {
 }
 
 #[tauri::command]
-pub async fn query_whois(domain: String) -> Result<WhoisParsed, String> {
+pub async fn query_whois(
+    domain: String,
+    force_refresh: Option<bool>,
+) -> Result<WhoisParsed, String> {
     let d = domain.trim();
     if d.is_empty() {
-        return Err("域名不能为空".to_string());
+        return Err(i18n::t("common.domain_required"));
     }
-    let tld = extract_tld(d).ok_or_else(|| "无法解析域名 TLD".to_string())?;
-    let server =
-        resolve_whois_server_for_tld(&tld).ok_or_else(|| "无法解析 WHOIS 服务器".to_string())?;
+    with_cache("whois", d, force_refresh, whois_lookup(d)).await
+}
 
-    // Use blocking network in a blocking task
-    let d_owned = d.to_string();
-    let s_owned = server.clone();
-    let text = tokio::task::spawn_blocking(move || query_whois_server(&s_owned, &d_owned))
+async fn whois_lookup(d: &str) -> Result<WhoisParsed, String> {
+    let tld = extract_tld(d).ok_or_else(|| i18n::t("whois.tld_unresolved"))?;
+    let server = resolve_whois_server_for_tld(&tld)
         .await
-        .map_err(|_| "WHOIS 查询线程执行失败".to_string())??;
+        .ok_or_else(|| i18n::t("whois.server_unresolved"))?;
+
+    let text = query_whois_polite(&server, d).await?;
 
     Ok(parse_whois_text(d, &server, &text))
 }
@@ -393,27 +472,30 @@ pub struct MultiSourceResult {
 }
 
 #[tauri::command]
-pub async fn query_domain_multi_source(domain: String) -> Result<MultiSourceResult, String> {
+pub async fn query_domain_multi_source(
+    domain: String,
+    force_refresh: Option<bool>,
+) -> Result<MultiSourceResult, String> {
     let d = domain.trim();
     if d.is_empty() {
-        return Err("域名不能为空".to_string());
+        return Err(i18n::t("common.domain_required"));
     }
     let mut results = Vec::new();
     let mut errors = Vec::new();
 
     // Channel 1: RDAP.org
-    match query_rdap(d.to_string()).await {
+    match query_rdap(d.to_string(), force_refresh).await {
         Ok(p) => results.push(p),
         Err(e) => errors.push(format!("rdap.org: {}", e)),
     }
     // Channel 2: WHOIS via IANA referral
-    match query_whois(d.to_string()).await {
+    match query_whois(d.to_string(), force_refresh).await {
         Ok(p) => results.push(p),
         Err(e) => errors.push(format!("whois-referral: {}", e)),
     }
     // Channel 3: Verisign RDAP (only for com/net)
     if matches!(extract_tld(d).as_deref(), Some("com") | Some("net")) {
-        match rdap_verisign_query(d).await {
+        match with_cache("verisign-rdap", d, force_refresh, rdap_verisign_query(d)).await {
             Ok(p) => results.push(p),
             Err(e) => errors.push(format!("verisign-rdap: {}", e)),
         }
@@ -434,17 +516,13 @@ pub async fn query_domain_multi_source(domain: String) -> Result<MultiSourceResu
 async fn whois_from_server(domain: String, server: String) -> Result<WhoisParsed, String> {
     let d = domain.trim();
     if d.is_empty() {
-        return Err("域名不能为空".to_string());
+        return Err(i18n::t("common.domain_required"));
     }
     let s = server.trim();
     if s.is_empty() {
-        return Err("WHOIS 服务器不能为空".to_string());
+        return Err(i18n::t("whois.server_required"));
     }
-    let d_owned = d.to_string();
-    let s_owned = s.to_string();
-    let text = tokio::task::spawn_blocking(move || query_whois_server(&s_owned, &d_owned))
-        .await
-        .map_err(|_| "WHOIS 查询线程执行失败".to_string())??;
+    let text = query_whois_polite(s, d).await?;
     Ok(parse_whois_text(d, s, &text))
 }
 
@@ -452,17 +530,23 @@ async fn whois_from_server(domain: String, server: String) -> Result<WhoisParsed
 pub async fn query_whois_unified(
     domain: String,
     source: Option<String>,
+    force_refresh: Option<bool>,
 ) -> Result<WhoisParsed, String> {
     let d = domain.trim();
     if d.is_empty() {
-        return Err("域名不能为空".to_string());
+        return Err(i18n::t("common.domain_required"));
     }
     let mode = source.unwrap_or_else(|| "auto".to_string());
+    let kind = format!("unified:{}", mode.trim().to_lowercase());
+    with_cache(&kind, d, force_refresh, whois_unified_lookup(d, mode)).await
+}
+
+async fn whois_unified_lookup(d: &str, mode: String) -> Result<WhoisParsed, String> {
     if mode.eq_ignore_ascii_case("auto") {
         // Prefer TLD-specific server via IANA referral, then fallback list
         let mut servers: Vec<String> = Vec::new();
         if let Some(tld) = extract_tld(d) {
-            if let Some(s) = resolve_whois_server_for_tld(&tld) {
+            if let Some(s) = resolve_whois_server_for_tld(&tld).await {
                 servers.push(s);
             }
         }
@@ -480,8 +564,247 @@ pub async fn query_whois_unified(
                 }
             }
         }
-        Err(last_err.unwrap_or_else(|| "自动 WHOIS 查询失败".to_string()))
+        Err(last_err.unwrap_or_else(|| i18n::t("whois.auto_lookup_failed")))
     } else {
         whois_from_server(d.to_string(), mode).await
     }
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct WhoisCacheEntry {
+    kind: String,
+    domain: String,
+    cached_at: i64,
+    result: WhoisParsed,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WhoisCacheItem {
+    pub key: String,
+    pub kind: String,
+    pub domain: String,
+    pub source: String,
+    pub cached_at: String,
+    pub expires_at: String,
+    pub expired: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WhoisCacheInfo {
+    pub path: String,
+    pub ttl_secs: i64,
+    pub size_bytes: u64,
+    pub expired_count: usize,
+    pub entries: Vec<WhoisCacheItem>,
+}
+
+/// 查看 WHOIS/RDAP 缓存
+#[tauri::command]
+pub async fn get_whois_cache() -> Result<WhoisCacheInfo, String> {
+    let path = get_whois_cache_path();
+    let _guard = WHOIS_CACHE_LOCK.lock().unwrap();
+    let cache = load_whois_cache(&path);
+    let now = chrono::Utc::now().timestamp();
+
+    let mut entries: Vec<WhoisCacheItem> = cache
+        .iter()
+        .map(|(key, entry)| WhoisCacheItem {
+            key: key.clone(),
+            kind: entry.kind.clone(),
+            domain: entry.domain.clone(),
+            source: entry.result.source.clone(),
+            cached_at: format_cache_time(entry.cached_at),
+            expires_at: format_cache_time(entry.cached_at + WHOIS_CACHE_TTL_SECS),
+            expired: is_expired(entry, now),
+        })
+        .collect();
+    entries.sort_by(|a, b| b.cached_at.cmp(&a.cached_at));
+
+    Ok(WhoisCacheInfo {
+        path: path.to_string_lossy().to_string(),
+        ttl_secs: WHOIS_CACHE_TTL_SECS,
+        size_bytes: fs::metadata(&path).map(|m| m.len()).unwrap_or(0),
+        expired_count: entries.iter().filter(|e| e.expired).count(),
+        entries,
+    })
+}
+
+/// 清理 WHOIS/RDAP 缓存，可按域名或仅清理过期条目，返回删除数量
+#[tauri::command]
+pub async fn clear_whois_cache(
+    domain: Option<String>,
+    expired_only: Option<bool>,
+) -> Result<usize, String> {
+    let path = get_whois_cache_path();
+    let _guard = WHOIS_CACHE_LOCK.lock().unwrap();
+    let mut cache = load_whois_cache(&path);
+    let domain = domain
+        .map(|d| d.trim().to_lowercase())
+        .filter(|d| !d.is_empty());
+    let expired_only = expired_only.unwrap_or(false);
+    let now = chrono::Utc::now().timestamp();
+
+    let before = cache.len();
+    cache.retain(|_, entry| {
+        let domain_matches = domain.as_ref().is_none_or(|d| *d == entry.domain);
+        let expiry_matches = !expired_only || is_expired(entry, now);
+        !(domain_matches && expiry_matches)
+    });
+    let removed = before - cache.len();
+    save_whois_cache(&path, &cache)?;
+    Ok(removed)
+}
+
+fn get_whois_cache_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("devtools")
+        .join("whois-cache.json")
+}
+
+fn whois_cache_key(kind: &str, domain: &str) -> String {
+    format!("{}:{}", kind, domain.to_lowercase())
+}
+
+fn format_cache_time(secs: i64) -> String {
+    chrono::DateTime::from_timestamp(secs, 0)
+        .map(|t| t.with_timezone(&chrono::Local).to_rfc3339())
+        .unwrap_or_default()
+}
+
+fn is_expired(entry: &WhoisCacheEntry, now: i64) -> bool {
+    now - entry.cached_at >= WHOIS_CACHE_TTL_SECS
+}
+
+fn load_whois_cache(path: &Path) -> BTreeMap<String, WhoisCacheEntry> {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_whois_cache(path: &Path, cache: &BTreeMap<String, WhoisCacheEntry>) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| i18n::tr("common.create_dir_failed", &[&e.to_string()]))?;
+    }
+    let content = serde_json::to_string(cache)
+        .map_err(|e| i18n::tr("common.serialize_failed", &[&e.to_string()]))?;
+    fs::write(path, content).map_err(|e| i18n::tr("whois.write_cache_failed", &[&e.to_string()]))
+}
+
+fn read_cached(path: &Path, key: &str, now: i64) -> Option<WhoisParsed> {
+    let _guard = WHOIS_CACHE_LOCK.lock().unwrap();
+    let cache = load_whois_cache(path);
+    let entry = cache.get(key).filter(|entry| !is_expired(entry, now))?;
+    let mut result = entry.result.clone();
+    result.cached_at = Some(format_cache_time(entry.cached_at));
+    Some(result)
+}
+
+fn write_cached(path: &Path, kind: &str, domain: &str, result: &WhoisParsed, now: i64) {
+    let _guard = WHOIS_CACHE_LOCK.lock().unwrap();
+    let mut cache = load_whois_cache(path);
+    cache.retain(|_, entry| !is_expired(entry, now));
+    while cache.len() >= WHOIS_CACHE_MAX_ENTRIES {
+        let oldest = cache
+            .iter()
+            .min_by_key(|(_, entry)| entry.cached_at)
+            .map(|(key, _)| key.clone());
+        match oldest {
+            Some(key) => cache.remove(&key),
+            None => break,
+        };
+    }
+    cache.insert(
+        whois_cache_key(kind, domain),
+        WhoisCacheEntry {
+            kind: kind.to_string(),
+            domain: domain.to_lowercase(),
+            cached_at: now,
+            result: result.clone(),
+        },
+    );
+    // 缓存写入失败不影响查询结果
+    let _ = save_whois_cache(path, &cache);
+}
+
+/// 命中未过期缓存时直接返回，否则执行查询并写入缓存；失败结果不缓存
+async fn with_cache<F>(
+    kind: &str,
+    domain: &str,
+    force_refresh: Option<bool>,
+    lookup: F,
+) -> Result<WhoisParsed, String>
+where
+    F: Future<Output = Result<WhoisParsed, String>>,
+{
+    let path = get_whois_cache_path();
+    let key = whois_cache_key(kind, domain);
+    if !force_refresh.unwrap_or(false) {
+        if let Some(cached) = read_cached(&path, &key, chrono::Utc::now().timestamp()) {
+            return Ok(cached);
+        }
+    }
+    let result = lookup.await?;
+    write_cached(&path, kind, domain, &result, chrono::Utc::now().timestamp());
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_cache_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("devtools-{}-{}.json", name, std::process::id()))
+    }
+
+    #[test]
+    fn test_whois_cache_hit_and_expiry() {
+        let path = temp_cache_path("whois-cache-hit");
+        let result = parse_whois_text("Example.com", "whois.test", "Registrar: Test\n");
+        write_cached(&path, "whois", "Example.com", &result, 1_000);
+
+        let key = whois_cache_key("whois", "example.com");
+        let cached = read_cached(&path, &key, 1_060).unwrap();
+        assert_eq!(cached.source, "whois.test");
+        assert!(cached.cached_at.is_some());
+        assert!(read_cached(&path, &key, 1_000 + WHOIS_CACHE_TTL_SECS).is_none());
+        assert!(read_cached(&path, &whois_cache_key("rdap", "example.com"), 1_000).is_none());
+        fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_whois_cache_prunes_expired_on_write() {
+        let path = temp_cache_path("whois-cache-prune");
+        let result = parse_whois_text("a.com", "whois.test", "");
+        write_cached(&path, "whois", "a.com", &result, 1_000);
+        write_cached(
+            &path,
+            "rdap",
+            "b.com",
+            &result,
+            1_000 + WHOIS_CACHE_TTL_SECS,
+        );
+
+        let cache = load_whois_cache(&path);
+        assert_eq!(cache.keys().collect::<Vec<_>>(), vec!["rdap:b.com"]);
+        fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_is_whois_throttled() {
+        assert!(is_whois_throttled(
+            "WHOIS LIMIT EXCEEDED - SEE WWW.PIR.ORG/WHOIS FOR DETAILS"
+        ));
+        assert!(is_whois_throttled(
+            "%ERROR:201: access denied\n% Query rate of 60 per minute exceeded"
+        ));
+        assert!(!is_whois_throttled(
+            "Domain Name: EXAMPLE.COM\nRegistrar: RESERVED-Internet Assigned Numbers Authority\n\nPlease try again later if the registry is unavailable."
+        ));
+        assert!(!is_whois_throttled("No match for \"NOPE.COM\"."));
+    }
+}
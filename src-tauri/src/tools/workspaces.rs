@@ -0,0 +1,346 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// 工作区名称的最大长度（字符数）
+const MAX_NAME_LEN: usize = 64;
+/// 单个工作区序列化后的大小上限，避免大段检测结果撑大配置文件
+const MAX_WORKSPACE_BYTES: usize = 10 * 1024 * 1024;
+
+/// 串行化工作区文件的读写
+static WORKSPACE_LOCK: Mutex<()> = Mutex::new(());
+
+/// 单个工具的状态，内容由前端决定
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ToolState {
+    #[serde(default)]
+    pub inputs: Value,
+    #[serde(default)]
+    pub options: Value,
+    #[serde(default)]
+    pub results: Value,
+    #[serde(default)]
+    pub saved_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Workspace {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub tools: BTreeMap<String, ToolState>, // 键为前端工具 id，如 sslchecker、whois
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspaceSummary {
+    pub name: String,
+    pub description: String,
+    pub tool_ids: Vec<String>,
+    pub created_at: String,
+    pub updated_at: String,
+    pub active: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SaveWorkspaceRequest {
+    pub name: String,
+    pub description: Option<String>,
+    pub tools: BTreeMap<String, ToolState>,
+    #[serde(default)]
+    pub merge: bool, // 为 true 时只更新传入的工具，保留工作区中其他工具的状态
+}
+
+/// 保存在配置目录下，随应用数据一起备份
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct WorkspaceStore {
+    active: Option<String>, // 最近一次打开的工作区
+    #[serde(default)]
+    workspaces: BTreeMap<String, Workspace>,
+}
+
+#[tauri::command]
+pub async fn list_workspaces() -> Result<Vec<WorkspaceSummary>, String> {
+    let _guard = WORKSPACE_LOCK.lock().unwrap();
+    let store = load_store(&get_store_path());
+    let mut summaries: Vec<WorkspaceSummary> = store
+        .workspaces
+        .values()
+        .map(|w| summarize(w, store.active.as_deref()))
+        .collect();
+    summaries.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+    Ok(summaries)
+}
+
+/// 将所选工具的当前状态保存到命名工作区
+#[tauri::command]
+pub async fn save_workspace(request: SaveWorkspaceRequest) -> Result<WorkspaceSummary, String> {
+    let _guard = WORKSPACE_LOCK.lock().unwrap();
+    let path = get_store_path();
+    let mut store = load_store(&path);
+    let now = chrono::Local::now().to_rfc3339();
+    let summary = apply_save(&mut store, request, &now)?;
+    save_store(&path, &store)?;
+    Ok(summary)
+}
+
+/// 读取工作区并设为当前工作区，由前端恢复各工具的状态
+#[tauri::command]
+pub async fn load_workspace(name: String) -> Result<Workspace, String> {
+    let _guard = WORKSPACE_LOCK.lock().unwrap();
+    let path = get_store_path();
+    let mut store = load_store(&path);
+    let name = name.trim().to_string();
+    let workspace = store
+        .workspaces
+        .get(&name)
+        .cloned()
+        .ok_or_else(|| format!("工作区不存在: {}", name))?;
+    store.active = Some(name);
+    save_store(&path, &store)?;
+    Ok(workspace)
+}
+
+#[tauri::command]
+pub async fn rename_workspace(name: String, new_name: String) -> Result<WorkspaceSummary, String> {
+    let _guard = WORKSPACE_LOCK.lock().unwrap();
+    let path = get_store_path();
+    let mut store = load_store(&path);
+    let summary = apply_rename(&mut store, name.trim(), new_name.trim())?;
+    save_store(&path, &store)?;
+    Ok(summary)
+}
+
+#[tauri::command]
+pub async fn delete_workspace(name: String) -> Result<(), String> {
+    let _guard = WORKSPACE_LOCK.lock().unwrap();
+    let path = get_store_path();
+    let mut store = load_store(&path);
+    let name = name.trim();
+    if store.workspaces.remove(name).is_none() {
+        return Err(format!("工作区不存在: {}", name));
+    }
+    if store.active.as_deref() == Some(name) {
+        store.active = None;
+    }
+    save_store(&path, &store)
+}
+
+fn apply_save(
+    store: &mut WorkspaceStore,
+    request: SaveWorkspaceRequest,
+    now: &str,
+) -> Result<WorkspaceSummary, String> {
+    let name = validate_name(&request.name)?;
+    if request.tools.is_empty() && !request.merge {
+        return Err("请至少选择一个工具".to_string());
+    }
+
+    let existing = store.workspaces.remove(&name);
+    let created_at = existing
+        .as_ref()
+        .map_or_else(|| now.to_string(), |w| w.created_at.clone());
+    let description = request
+        .description
+        .or_else(|| existing.as_ref().map(|w| w.description.clone()))
+        .unwrap_or_default();
+    let mut tools = match existing {
+        Some(workspace) if request.merge => workspace.tools,
+        _ => BTreeMap::new(),
+    };
+    for (tool_id, mut state) in request.tools {
+        state.saved_at = now.to_string();
+        tools.insert(tool_id, state);
+    }
+
+    let workspace = Workspace {
+        name: name.clone(),
+        description,
+        created_at,
+        updated_at: now.to_string(),
+        tools,
+    };
+    let size = serde_json::to_vec(&workspace)
+        .map_err(|e| format!("序列化工作区失败: {}", e))?
+        .len();
+    if size > MAX_WORKSPACE_BYTES {
+        return Err(format!(
+            "工作区内容过大（{} KB），请减少保存的检测结果",
+            size / 1024
+        ));
+    }
+
+    let summary = summarize(&workspace, Some(&name));
+    store.active = Some(name.clone());
+    store.workspaces.insert(name, workspace);
+    Ok(summary)
+}
+
+fn apply_rename(
+    store: &mut WorkspaceStore,
+    name: &str,
+    new_name: &str,
+) -> Result<WorkspaceSummary, String> {
+    let new_name = validate_name(new_name)?;
+    if name != new_name && store.workspaces.contains_key(&new_name) {
+        return Err(format!("工作区已存在: {}", new_name));
+    }
+    let mut workspace = store
+        .workspaces
+        .remove(name)
+        .ok_or_else(|| format!("工作区不存在: {}", name))?;
+    workspace.name = new_name.clone();
+    if store.active.as_deref() == Some(name) {
+        store.active = Some(new_name.clone());
+    }
+    let summary = summarize(&workspace, store.active.as_deref());
+    store.workspaces.insert(new_name, workspace);
+    Ok(summary)
+}
+
+fn validate_name(name: &str) -> Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("请输入工作区名称".to_string());
+    }
+    if name.chars().count() > MAX_NAME_LEN {
+        return Err(format!("工作区名称不能超过 {} 个字符", MAX_NAME_LEN));
+    }
+    if name.chars().any(char::is_control) {
+        return Err("工作区名称包含非法字符".to_string());
+    }
+    Ok(name.to_string())
+}
+
+fn summarize(workspace: &Workspace, active: Option<&str>) -> WorkspaceSummary {
+    WorkspaceSummary {
+        name: workspace.name.clone(),
+        description: workspace.description.clone(),
+        tool_ids: workspace.tools.keys().cloned().collect(),
+        created_at: workspace.created_at.clone(),
+        updated_at: workspace.updated_at.clone(),
+        active: active == Some(workspace.name.as_str()),
+    }
+}
+
+fn get_store_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("devtools")
+        .join("workspaces.json")
+}
+
+fn load_store(path: &Path) -> WorkspaceStore {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_store(path: &Path, store: &WorkspaceStore) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("创建配置目录失败: {}", e))?;
+    }
+    let content = serde_json::to_string(store).map_err(|e| format!("序列化工作区失败: {}", e))?;
+    fs::write(path, content).map_err(|e| format!("保存工作区失败: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn request(name: &str, tools: &[(&str, Value)], merge: bool) -> SaveWorkspaceRequest {
+        SaveWorkspaceRequest {
+            name: name.to_string(),
+            description: None,
+            tools: tools
+                .iter()
+                .map(|(id, inputs)| {
+                    (
+                        id.to_string(),
+                        ToolState {
+                            inputs: inputs.clone(),
+                            ..Default::default()
+                        },
+                    )
+                })
+                .collect(),
+            merge,
+        }
+    }
+
+    #[test]
+    fn test_save_merge_and_replace() {
+        let mut store = WorkspaceStore::default();
+        let first = request(
+            " customer-X incident ",
+            &[
+                ("sslchecker", json!({"domain": "x.com"})),
+                ("whois", json!({"domain": "x.com"})),
+            ],
+            false,
+        );
+        let summary = apply_save(&mut store, first, "2026-01-01").unwrap();
+        assert_eq!(summary.name, "customer-X incident");
+        assert_eq!(summary.tool_ids, vec!["sslchecker", "whois"]);
+        assert!(summary.active);
+
+        // 合并时只覆盖传入的工具，创建时间不变
+        let update = request(
+            "customer-X incident",
+            &[("sslchecker", json!({"domain": "api.x.com"}))],
+            true,
+        );
+        let summary = apply_save(&mut store, update, "2026-01-02").unwrap();
+        assert_eq!(summary.tool_ids, vec!["sslchecker", "whois"]);
+        assert_eq!(summary.created_at, "2026-01-01");
+        let workspace = &store.workspaces["customer-X incident"];
+        assert_eq!(workspace.tools["sslchecker"].inputs["domain"], "api.x.com");
+        assert_eq!(workspace.tools["sslchecker"].saved_at, "2026-01-02");
+        assert_eq!(workspace.tools["whois"].saved_at, "2026-01-01");
+
+        let replace = request("customer-X incident", &[("regextester", json!({}))], false);
+        let summary = apply_save(&mut store, replace, "2026-01-03").unwrap();
+        assert_eq!(summary.tool_ids, vec!["regextester"]);
+        assert!(apply_save(
+            &mut store,
+            request("  ", &[("whois", json!({}))], false),
+            ""
+        )
+        .is_err());
+        assert!(apply_save(&mut store, request("empty", &[], false), "").is_err());
+    }
+
+    #[test]
+    fn test_rename() {
+        let mut store = WorkspaceStore::default();
+        apply_save(
+            &mut store,
+            request("a", &[("whois", json!({}))], false),
+            "1",
+        )
+        .unwrap();
+        apply_save(
+            &mut store,
+            request("b", &[("whois", json!({}))], false),
+            "2",
+        )
+        .unwrap();
+        assert_eq!(
+            apply_rename(&mut store, "a", "b").unwrap_err(),
+            "工作区已存在: b"
+        );
+        assert!(apply_rename(&mut store, "missing", "c").is_err());
+
+        let summary = apply_rename(&mut store, "b", "c").unwrap();
+        assert!(summary.active);
+        assert_eq!(store.active.as_deref(), Some("c"));
+        assert_eq!(store.workspaces.keys().collect::<Vec<_>>(), vec!["a", "c"]);
+        assert_eq!(store.workspaces["c"].name, "c");
+    }
+}
@@ -0,0 +1,371 @@
+use openssl::x509::X509;
+use x509_parser::prelude::*;
+
+/// 按签发关系构建的证书路径，下标对应传入的证书顺序
+#[derive(Debug, Clone, Default)]
+pub struct CertPath {
+    pub order: Vec<usize>,           // 从终端证书到链顶
+    pub unlinked: Vec<usize>,        // 无法接入路径的证书，含解析失败的证书
+    pub issuers: Vec<Option<usize>>, // 每张证书经签名验证的颁发者
+    pub self_signed: Vec<bool>,      // 签名可由自身公钥验证
+    pub top_anchored: bool,          // 链顶证书由信任锚签发
+    pub errors: Vec<String>,
+}
+
+impl CertPath {
+    pub fn top(&self) -> Option<usize> {
+        self.order.last().copied()
+    }
+
+    /// 路径终止于自签名证书或信任锚
+    pub fn is_complete(&self) -> bool {
+        self.top()
+            .is_some_and(|top| self.self_signed[top] || self.top_anchored)
+    }
+}
+
+struct Node<'a> {
+    cert: X509Certificate<'a>,
+    key: X509,
+}
+
+impl Node<'_> {
+    fn subject_key_id(&self) -> Option<&[u8]> {
+        self.cert
+            .extensions()
+            .iter()
+            .find_map(|ext| match ext.parsed_extension() {
+                ParsedExtension::SubjectKeyIdentifier(id) => Some(id.0),
+                _ => None,
+            })
+    }
+
+    fn authority_key_id(&self) -> Option<&[u8]> {
+        self.cert
+            .extensions()
+            .iter()
+            .find_map(|ext| match ext.parsed_extension() {
+                ParsedExtension::AuthorityKeyIdentifier(aki) => {
+                    aki.key_identifier.as_ref().map(|id| id.0)
+                }
+                _ => None,
+            })
+    }
+
+    fn is_ca(&self) -> bool {
+        matches!(self.cert.basic_constraints(), Ok(Some(bc)) if bc.value.ca)
+    }
+}
+
+/// 名称和 AKI/SKI 都对得上时才视为候选颁发者
+fn is_candidate(child: &Node, parent: &Node) -> bool {
+    if child.cert.issuer().as_raw() != parent.cert.subject().as_raw() {
+        return false;
+    }
+    match (child.authority_key_id(), parent.subject_key_id()) {
+        (Some(aki), Some(ski)) => aki == ski,
+        _ => true,
+    }
+}
+
+/// 用颁发者公钥验证证书签名
+fn signed_by(child: &Node, parent: &Node) -> bool {
+    parent
+        .key
+        .public_key()
+        .and_then(|key| child.key.verify(&key))
+        .unwrap_or(false)
+}
+
+/// 证书签名可由自身公钥验证，且主体与颁发者名称一致
+pub fn is_self_signed(der: &[u8]) -> bool {
+    parse_node(der).is_some_and(|node| {
+        node.cert.subject().as_raw() == node.cert.issuer().as_raw() && signed_by(&node, &node)
+    })
+}
+
+fn parse_node(der: &[u8]) -> Option<Node<'_>> {
+    let (_, cert) = parse_x509_certificate(der).ok()?;
+    let key = X509::from_der(der).ok()?;
+    Some(Node { cert, key })
+}
+
+/// 从终端证书出发，按 AKI/SKI 和签名验证构建证书路径
+/// leaf 为空时自动选择未给其他证书签名的证书；is_anchor 判断证书是否由信任锚签发
+pub fn build_path(
+    ders: &[Vec<u8>],
+    leaf: Option<usize>,
+    is_anchor: &dyn Fn(&[u8]) -> bool,
+) -> CertPath {
+    let nodes: Vec<Option<Node>> = ders.iter().map(|der| parse_node(der)).collect();
+    let count = nodes.len();
+    let mut path = CertPath {
+        issuers: vec![None; count],
+        self_signed: vec![false; count],
+        ..Default::default()
+    };
+
+    // 每张证书经签名验证的颁发者，交叉签名时可能有多个
+    let mut verified: Vec<Vec<usize>> = vec![Vec::new(); count];
+    for (child_index, child) in nodes.iter().enumerate() {
+        let Some(child) = child else {
+            path.errors
+                .push(format!("证书 {} 无法解析", child_index + 1));
+            continue;
+        };
+        let mut failed = Vec::new();
+        for (parent_index, parent) in nodes.iter().enumerate() {
+            let Some(parent) = parent else { continue };
+            if !is_candidate(child, parent) {
+                continue;
+            }
+            if !signed_by(child, parent) {
+                failed.push(parent_index);
+            } else if parent_index == child_index {
+                path.self_signed[child_index] = true;
+            } else {
+                verified[child_index].push(parent_index);
+            }
+        }
+        // 同名的其他 CA 验证失败是正常的，只在找不到任何颁发者时报告
+        if verified[child_index].is_empty() && !path.self_signed[child_index] {
+            for parent_index in failed {
+                path.errors.push(format!(
+                    "证书 {} 的签名无法用证书 {} 的公钥验证",
+                    child_index + 1,
+                    parent_index + 1
+                ));
+            }
+        }
+        // 优先选择自签名的颁发者，便于交叉签名时直接到达根证书
+        verified[child_index].sort_by_key(|index| !path.self_signed[*index]);
+        path.issuers[child_index] = verified[child_index].first().copied();
+    }
+
+    let start = leaf
+        .filter(|index| nodes.get(*index).is_some_and(Option::is_some))
+        .or_else(|| {
+            let signs_nothing =
+                |index: usize| verified.iter().all(|issuers| !issuers.contains(&index));
+            let parsed = || (0..count).filter(|index| nodes[*index].is_some());
+            parsed()
+                .find(|index| {
+                    signs_nothing(*index) && !nodes[*index].as_ref().is_some_and(Node::is_ca)
+                })
+                .or_else(|| parsed().find(|index| signs_nothing(*index)))
+                .or_else(|| parsed().next())
+        });
+
+    if let Some(start) = start {
+        let mut best = Vec::new();
+        let mut current = vec![start];
+        search(
+            &mut current,
+            &mut best,
+            &verified,
+            &path.self_signed,
+            &|index| is_anchor(&ders[index]),
+        );
+        path.order = best;
+    }
+    if let Some(top) = path.top() {
+        path.top_anchored = is_anchor(&ders[top]);
+        for pair in path.order.windows(2) {
+            path.issuers[pair[0]] = Some(pair[1]);
+        }
+    }
+    path.unlinked = (0..count)
+        .filter(|index| !path.order.contains(index))
+        .collect();
+    path
+}
+
+/// 深度优先搜索，找到完整路径即停止，否则保留最长的路径
+fn search(
+    current: &mut Vec<usize>,
+    best: &mut Vec<usize>,
+    verified: &[Vec<usize>],
+    self_signed: &[bool],
+    is_anchor: &dyn Fn(usize) -> bool,
+) -> bool {
+    let top = *current.last().unwrap();
+    let complete = self_signed[top] || is_anchor(top);
+    if complete || current.len() > best.len() {
+        *best = current.clone();
+    }
+    if complete {
+        return true;
+    }
+    for &issuer in &verified[top] {
+        if current.contains(&issuer) {
+            continue;
+        }
+        current.push(issuer);
+        let found = search(current, best, verified, self_signed, is_anchor);
+        current.pop();
+        if found {
+            return true;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use openssl::asn1::Asn1Time;
+    use openssl::bn::BigNum;
+    use openssl::hash::MessageDigest;
+    use openssl::pkey::{PKey, Private};
+    use openssl::rsa::Rsa;
+    use openssl::x509::extension::{
+        AuthorityKeyIdentifier, BasicConstraints, SubjectKeyIdentifier,
+    };
+    use openssl::x509::{X509NameBuilder, X509Ref};
+
+    fn key() -> PKey<Private> {
+        PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap()
+    }
+
+    /// 签发证书，issuer 为空时自签名
+    fn issue(
+        common_name: &str,
+        key: &PKey<Private>,
+        issuer: Option<(&X509Ref, &PKey<Private>)>,
+        ca: bool,
+    ) -> X509 {
+        let mut name = X509NameBuilder::new().unwrap();
+        name.append_entry_by_text("CN", common_name).unwrap();
+        let name = name.build();
+        let mut builder = X509::builder().unwrap();
+        builder.set_version(2).unwrap();
+        builder
+            .set_serial_number(&BigNum::from_u32(7).unwrap().to_asn1_integer().unwrap())
+            .unwrap();
+        builder.set_subject_name(&name).unwrap();
+        builder
+            .set_issuer_name(issuer.map(|(cert, _)| cert.subject_name()).unwrap_or(&name))
+            .unwrap();
+        builder.set_pubkey(key).unwrap();
+        builder
+            .set_not_before(&Asn1Time::days_from_now(0).unwrap())
+            .unwrap();
+        builder
+            .set_not_after(&Asn1Time::days_from_now(30).unwrap())
+            .unwrap();
+        let mut constraints = BasicConstraints::new();
+        if ca {
+            constraints.ca();
+        }
+        builder
+            .append_extension(constraints.critical().build().unwrap())
+            .unwrap();
+        let ski = SubjectKeyIdentifier::new()
+            .build(&builder.x509v3_context(None, None))
+            .unwrap();
+        builder.append_extension(ski).unwrap();
+        if let Some((issuer_cert, _)) = issuer {
+            let aki = AuthorityKeyIdentifier::new()
+                .keyid(true)
+                .build(&builder.x509v3_context(Some(issuer_cert), None))
+                .unwrap();
+            builder.append_extension(aki).unwrap();
+        }
+        let signing_key = issuer.map(|(_, key)| key).unwrap_or(key);
+        builder.sign(signing_key, MessageDigest::sha256()).unwrap();
+        builder.build()
+    }
+
+    #[test]
+    fn test_build_path_with_same_name_cas() {
+        let root_key = key();
+        let root = issue("Path Test Root", &root_key, None, true);
+        // 另一个同名但密钥不同的根 CA，按名称比对会被误认为颁发者
+        let impostor_key = key();
+        let impostor = issue("Path Test Root", &impostor_key, None, true);
+        let inter_key = key();
+        let inter = issue(
+            "Path Test Intermediate",
+            &inter_key,
+            Some((&root, &root_key)),
+            true,
+        );
+        let leaf = issue("leaf.example", &key(), Some((&inter, &inter_key)), false);
+
+        let ders: Vec<Vec<u8>> = [&impostor, &leaf, &root, &inter]
+            .iter()
+            .map(|cert| cert.to_der().unwrap())
+            .collect();
+        let path = build_path(&ders, None, &|_| false);
+        assert_eq!(path.order, vec![1, 3, 2]);
+        assert_eq!(path.unlinked, vec![0]);
+        assert!(path.is_complete());
+        assert!(path.self_signed[0] && path.self_signed[2]);
+        assert!(is_self_signed(&ders[0]));
+        assert!(!is_self_signed(&ders[1]));
+
+        // 只有冒名的根 CA 时，中间 CA 找不到颁发者
+        let path = build_path(
+            &ders[..2]
+                .iter()
+                .chain([&ders[3]])
+                .cloned()
+                .collect::<Vec<_>>(),
+            Some(1),
+            &|_| false,
+        );
+        assert_eq!(path.order, vec![1, 2]);
+        assert!(!path.is_complete());
+        assert_eq!(path.issuers[2], None);
+    }
+
+    #[test]
+    fn test_build_path_cross_signed_and_bad_signature() {
+        let old_root_key = key();
+        let old_root = issue("Old Root", &old_root_key, None, true);
+        let root_key = key();
+        let root = issue("New Root", &root_key, None, true);
+        // 新根 CA 由旧根 CA 交叉签名，主体和密钥与自签名版本相同
+        let cross = issue(
+            "New Root",
+            &root_key,
+            Some((&old_root, &old_root_key)),
+            true,
+        );
+        let leaf = issue("cross.example", &key(), Some((&root, &root_key)), false);
+
+        let ders: Vec<Vec<u8>> = [&leaf, &cross, &root]
+            .iter()
+            .map(|cert| cert.to_der().unwrap())
+            .collect();
+        let path = build_path(&ders, Some(0), &|_| false);
+        assert_eq!(path.order, vec![0, 2]);
+        assert_eq!(path.unlinked, vec![1]);
+        assert!(path.errors.is_empty());
+
+        // 不发送自签名根证书时，交叉签名证书由信任锚（旧根）签发
+        let old_root_der = old_root.to_der().unwrap();
+        let anchored = |der: &[u8]| {
+            let (_, cert) = parse_x509_certificate(der).unwrap();
+            cert.issuer().as_raw()
+                == parse_x509_certificate(&old_root_der)
+                    .unwrap()
+                    .1
+                    .subject()
+                    .as_raw()
+        };
+        let path = build_path(&ders[..2], Some(0), &anchored);
+        assert_eq!(path.order, vec![0, 1]);
+        assert!(path.top_anchored && path.is_complete());
+
+        // 名称匹配但签名不对的证书会被报告
+        let forged = issue("cross.example", &key(), Some((&root, &key())), false);
+        let ders = vec![forged.to_der().unwrap(), root.to_der().unwrap()];
+        let path = build_path(&ders, Some(0), &|_| false);
+        assert_eq!(path.order, vec![0]);
+        assert_eq!(
+            path.errors,
+            vec!["证书 1 的签名无法用证书 2 的公钥验证".to_string()]
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, NaiveDateTime, TimeZone, Timelike};
+
+/// 查找下次执行时间时最多尝试的步数，避免 2 月 30 日这类永不触发的表达式死循环
+const MAX_SEARCH_STEPS: usize = 100_000;
+
+const MONTH_NAMES: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+const WEEKDAY_NAMES: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+/// 标准五段 cron 表达式：分 时 日 月 周，按本地时间计算
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    minutes: u64,  // 第 n 位表示第 n 分钟
+    hours: u32,    // 0-23
+    days: u32,     // 1-31
+    months: u16,   // 1-12
+    weekdays: u8,  // 0-6，0 为周日
+    any_day: bool, // 日字段为 *
+    any_weekday: bool,
+}
+
+impl CronSchedule {
+    /// 解析 cron 表达式，支持 *、列表、范围、步长、月份和星期缩写以及 @daily 等别名
+    pub fn parse(expression: &str) -> Result<Self, String> {
+        let expanded = match expression.trim().to_lowercase().as_str() {
+            "@yearly" | "@annually" => "0 0 1 1 *".to_string(),
+            "@monthly" => "0 0 1 * *".to_string(),
+            "@weekly" => "0 0 * * 0".to_string(),
+            "@daily" | "@midnight" => "0 0 * * *".to_string(),
+            "@hourly" => "0 * * * *".to_string(),
+            other => other.to_string(),
+        };
+        let fields: Vec<&str> = expanded.split_whitespace().collect();
+        if fields.len() != 5 {
+            return Err(format!(
+                "cron 表达式应包含 5 个字段（分 时 日 月 周），实际为 {} 个",
+                fields.len()
+            ));
+        }
+
+        let minutes = parse_field(fields[0], 0, 59, &[]).map_err(|e| format!("分钟字段: {}", e))?;
+        let hours = parse_field(fields[1], 0, 23, &[]).map_err(|e| format!("小时字段: {}", e))?;
+        let days = parse_field(fields[2], 1, 31, &[]).map_err(|e| format!("日期字段: {}", e))?;
+        let months =
+            parse_field(fields[3], 1, 12, &MONTH_NAMES).map_err(|e| format!("月份字段: {}", e))?;
+        // 星期允许用 7 表示周日
+        let weekdays =
+            parse_field(fields[4], 0, 7, &WEEKDAY_NAMES).map_err(|e| format!("星期字段: {}", e))?;
+        let weekdays = (weekdays | (weekdays >> 7)) & 0x7f;
+
+        Ok(Self {
+            minutes,
+            hours: hours as u32,
+            days: days as u32,
+            months: months as u16,
+            weekdays: weekdays as u8,
+            any_day: fields[2].starts_with('*'),
+            any_weekday: fields[4].starts_with('*'),
+        })
+    }
+
+    /// 严格晚于 after 的下一次执行时间
+    pub fn next_after(&self, after: DateTime<Local>) -> Option<DateTime<Local>> {
+        let start = after.naive_local().with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+        let mut current = start;
+        for _ in 0..MAX_SEARCH_STEPS {
+            let date = current.date();
+            if self.months & (1 << date.month()) == 0 {
+                current = first_of_next_month(date)?;
+                continue;
+            }
+            if !self.matches_day(date) {
+                current = (date + Duration::days(1)).and_hms_opt(0, 0, 0)?;
+                continue;
+            }
+            if self.hours & (1 << current.hour()) == 0 {
+                current = current.with_minute(0)? + Duration::hours(1);
+                continue;
+            }
+            if self.minutes & (1 << current.minute()) == 0 {
+                current += Duration::minutes(1);
+                continue;
+            }
+            // 夏令时跳过的时间不存在，顺延到下一个匹配时间
+            match Local.from_local_datetime(&current).earliest() {
+                Some(time) if time > after => return Some(time),
+                _ => current += Duration::minutes(1),
+            }
+        }
+        None
+    }
+
+    /// 日和周都有限制时满足其一即可，与 crontab 的行为一致
+    fn matches_day(&self, date: NaiveDate) -> bool {
+        let day = self.days & (1 << date.day()) != 0;
+        let weekday = self.weekdays & (1 << date.weekday().num_days_from_sunday()) != 0;
+        match (self.any_day, self.any_weekday) {
+            (true, true) => true,
+            (true, false) => weekday,
+            (false, true) => day,
+            (false, false) => day || weekday,
+        }
+    }
+}
+
+fn first_of_next_month(date: NaiveDate) -> Option<NaiveDateTime> {
+    let (year, month) = if date.month() == 12 {
+        (date.year() + 1, 1)
+    } else {
+        (date.year(), date.month() + 1)
+    };
+    NaiveDate::from_ymd_opt(year, month, 1)?.and_hms_opt(0, 0, 0)
+}
+
+/// 解析单个字段为位图，names 为从 min 开始的名称
+fn parse_field(field: &str, min: u32, max: u32, names: &[&str]) -> Result<u64, String> {
+    let mut mask = 0u64;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step
+                    .parse()
+                    .ok()
+                    .filter(|s| *s > 0)
+                    .ok_or_else(|| format!("无效的步长: {}", step))?;
+                (range, step)
+            }
+            None => (part, 1),
+        };
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (
+                parse_value(start, min, max, names)?,
+                parse_value(end, min, max, names)?,
+            )
+        } else {
+            let start = parse_value(range, min, max, names)?;
+            // 5/10 表示从 5 开始每 10 个单位
+            (start, if part.contains('/') { max } else { start })
+        };
+        if start > end {
+            return Err(format!("范围起点大于终点: {}", range));
+        }
+        for value in (start..=end).step_by(step as usize) {
+            mask |= 1 << value;
+        }
+    }
+    Ok(mask)
+}
+
+fn parse_value(value: &str, min: u32, max: u32, names: &[&str]) -> Result<u32, String> {
+    let parsed = match names.iter().position(|name| *name == value) {
+        Some(index) => index as u32 + min,
+        None => value
+            .parse()
+            .map_err(|_| format!("无法识别的值: {}", value))?,
+    };
+    if parsed < min || parsed > max {
+        return Err(format!("{} 超出范围 {}-{}", parsed, min, max));
+    }
+    Ok(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn local(text: &str) -> DateTime<Local> {
+        let naive = NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M").unwrap();
+        Local.from_local_datetime(&naive).earliest().unwrap()
+    }
+
+    fn next(expression: &str, after: &str) -> String {
+        CronSchedule::parse(expression)
+            .unwrap()
+            .next_after(local(after))
+            .unwrap()
+            .format("%Y-%m-%d %H:%M")
+            .to_string()
+    }
+
+    #[test]
+    fn test_next_after() {
+        assert_eq!(next("*/15 * * * *", "2026-03-01 10:07"), "2026-03-01 10:15");
+        assert_eq!(next("*/15 * * * *", "2026-03-01 10:15"), "2026-03-01 10:30");
+        assert_eq!(
+            next("0 9 * * mon-fri", "2026-03-06 10:00"),
+            "2026-03-09 09:00"
+        );
+        assert_eq!(next("@daily", "2026-12-31 23:59"), "2027-01-01 00:00");
+        assert_eq!(next("30 8 1 * *", "2026-01-31 12:00"), "2026-02-01 08:30");
+        // 日和周同时限制时满足其一即可：每月 13 日或每个周五
+        assert_eq!(next("0 0 13 * 5", "2026-03-01 00:00"), "2026-03-06 00:00");
+        assert_eq!(next("0 0 29 2 *", "2026-03-01 00:00"), "2028-02-29 00:00");
+        assert_eq!(next("0 0 * * 7", "2026-03-01 12:00"), "2026-03-08 00:00");
+        assert!(CronSchedule::parse("0 0 30 2 *")
+            .unwrap()
+            .next_after(local("2026-01-01 00:00"))
+            .is_none());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(CronSchedule::parse("* * * *").is_err());
+        assert!(CronSchedule::parse("60 * * * *").is_err());
+        assert!(CronSchedule::parse("*/0 * * * *").is_err());
+        assert!(CronSchedule::parse("0 5-1 * * *").is_err());
+        assert!(CronSchedule::parse("0 0 * foo *").is_err());
+        assert_eq!(
+            CronSchedule::parse("0 0 1 jan,JUL *").unwrap(),
+            CronSchedule::parse("0 0 1 1,7 *").unwrap()
+        );
+        assert_eq!(
+            CronSchedule::parse("5/20 * * * *").unwrap().minutes,
+            (1 << 5) | (1 << 25) | (1 << 45)
+        );
+    }
+}
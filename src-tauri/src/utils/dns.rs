@@ -0,0 +1,204 @@
+use hickory_resolver::config::{NameServerConfigGroup, ResolverConfig, ResolverOpts};
+use hickory_resolver::error::ResolveErrorKind;
+use hickory_resolver::proto::rr::{RData, RecordType};
+use hickory_resolver::TokioAsyncResolver;
+use std::net::IpAddr;
+use std::time::Duration;
+
+/// DNS 查询工具集
+/// 统一封装解析器的创建与常用记录查询
+pub struct DnsClient;
+
+impl DnsClient {
+    fn default_options() -> ResolverOpts {
+        let mut opts = ResolverOpts::default();
+        opts.timeout = Duration::from_secs(5);
+        opts.attempts = 2;
+        opts
+    }
+
+    /// 使用系统 DNS 配置创建解析器，读取失败时回退到公共 DNS
+    pub fn system_resolver() -> TokioAsyncResolver {
+        TokioAsyncResolver::tokio_from_system_conf().unwrap_or_else(|_| {
+            TokioAsyncResolver::tokio(ResolverConfig::cloudflare(), Self::default_options())
+        })
+    }
+
+    /// 创建使用指定 DNS 服务器的解析器（不使用缓存，便于比较不同服务器的结果）
+    pub fn resolver_for(server: IpAddr, port: u16) -> TokioAsyncResolver {
+        let group = NameServerConfigGroup::from_ips_clear(&[server], port, true);
+        let config = ResolverConfig::from_parts(None, vec![], group);
+        let mut opts = Self::default_options();
+        opts.cache_size = 0;
+        TokioAsyncResolver::tokio(config, opts)
+    }
+
+    /// 将记录数据格式化为文本，TXT 记录会拼接多个字符串片段
+    pub fn format_rdata(rdata: &RData) -> String {
+        match rdata {
+            RData::TXT(txt) => txt
+                .txt_data()
+                .iter()
+                .map(|part| String::from_utf8_lossy(part).into_owned())
+                .collect::<String>(),
+            other => other.to_string(),
+        }
+    }
+
+    /// 查询指定类型的原始记录数据；记录不存在时返回空列表而不是错误
+    pub async fn lookup_rdata(
+        resolver: &TokioAsyncResolver,
+        name: &str,
+        record_type: RecordType,
+    ) -> Result<Vec<RData>, String> {
+        match resolver.lookup(name, record_type).await {
+            Ok(lookup) => Ok(lookup
+                .iter()
+                .filter(|rdata| rdata.record_type() == record_type)
+                .cloned()
+                .collect()),
+            Err(e) => match e.kind() {
+                ResolveErrorKind::NoRecordsFound { .. } => Ok(Vec::new()),
+                _ => Err(format!("查询 {} 的 {} 记录失败: {}", name, record_type, e)),
+            },
+        }
+    }
+
+    /// 查询指定类型的记录，返回格式化文本及其 TTL
+    pub async fn lookup_with_ttl(
+        resolver: &TokioAsyncResolver,
+        name: &str,
+        record_type: RecordType,
+    ) -> Result<Vec<(String, u32)>, String> {
+        match resolver.lookup(name, record_type).await {
+            Ok(lookup) => Ok(lookup
+                .record_iter()
+                .filter(|record| record.record_type() == record_type)
+                .filter_map(|record| Some((Self::format_rdata(record.data()?), record.ttl())))
+                .collect()),
+            Err(e) => match e.kind() {
+                ResolveErrorKind::NoRecordsFound { .. } => Ok(Vec::new()),
+                _ => Err(format!("查询 {} 的 {} 记录失败: {}", name, record_type, e)),
+            },
+        }
+    }
+
+    /// 查询指定类型的记录并格式化为文本
+    pub async fn lookup(
+        resolver: &TokioAsyncResolver,
+        name: &str,
+        record_type: RecordType,
+    ) -> Result<Vec<String>, String> {
+        Ok(Self::lookup_rdata(resolver, name, record_type)
+            .await?
+            .iter()
+            .map(Self::format_rdata)
+            .collect())
+    }
+
+    /// 查询 TXT 记录
+    pub async fn lookup_txt(
+        resolver: &TokioAsyncResolver,
+        name: &str,
+    ) -> Result<Vec<String>, String> {
+        Self::lookup(resolver, name, RecordType::TXT).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hickory_resolver::proto::op::{Message, MessageType, ResponseCode};
+    use hickory_resolver::proto::rr::rdata::{A, TXT};
+    use hickory_resolver::proto::rr::Record;
+    use std::net::Ipv4Addr;
+    use tokio::net::UdpSocket;
+
+    /// 本地 DNS 服务：example.test 有 A 和 TXT 记录，其他类型无记录，其他域名 NXDOMAIN
+    async fn start_server() -> u16 {
+        let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let port = socket.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            let mut buf = [0u8; 512];
+            while let Ok((n, peer)) = socket.recv_from(&mut buf).await {
+                let Ok(request) = Message::from_vec(&buf[..n]) else {
+                    continue;
+                };
+                let mut response = Message::new();
+                response
+                    .set_id(request.id())
+                    .set_message_type(MessageType::Response)
+                    .set_recursion_desired(true)
+                    .set_recursion_available(true)
+                    .add_queries(request.queries().to_vec());
+                let query = &request.queries()[0];
+                let name = query.name().clone();
+                if name.to_ascii() != "example.test." {
+                    response.set_response_code(ResponseCode::NXDomain);
+                } else if query.query_type() == RecordType::A {
+                    let rdata = RData::A(A(Ipv4Addr::new(192, 0, 2, 1)));
+                    response.add_answer(Record::from_rdata(name, 60, rdata));
+                } else if query.query_type() == RecordType::TXT {
+                    let rdata = RData::TXT(TXT::new(vec![
+                        "v=spf1 include:_spf.example.test ".to_string(),
+                        "-all".to_string(),
+                    ]));
+                    response.add_answer(Record::from_rdata(name, 300, rdata));
+                }
+                let _ = socket.send_to(&response.to_vec().unwrap(), peer).await;
+            }
+        });
+        port
+    }
+
+    #[test]
+    fn test_format_rdata() {
+        let txt = RData::TXT(TXT::new(vec!["part1 ".to_string(), "part2".to_string()]));
+        assert_eq!(DnsClient::format_rdata(&txt), "part1 part2");
+        let a = RData::A(A(Ipv4Addr::new(192, 0, 2, 1)));
+        assert_eq!(DnsClient::format_rdata(&a), "192.0.2.1");
+    }
+
+    #[tokio::test]
+    async fn test_lookup_against_local_server() {
+        let port = start_server().await;
+        let resolver = DnsClient::resolver_for(IpAddr::V4(Ipv4Addr::LOCALHOST), port);
+
+        assert_eq!(
+            DnsClient::lookup_txt(&resolver, "example.test.")
+                .await
+                .unwrap(),
+            vec!["v=spf1 include:_spf.example.test -all"]
+        );
+        assert_eq!(
+            DnsClient::lookup_with_ttl(&resolver, "example.test.", RecordType::A)
+                .await
+                .unwrap(),
+            vec![("192.0.2.1".to_string(), 60)]
+        );
+        let rdata = DnsClient::lookup_rdata(&resolver, "example.test.", RecordType::A)
+            .await
+            .unwrap();
+        assert_eq!(rdata, vec![RData::A(A(Ipv4Addr::new(192, 0, 2, 1)))]);
+    }
+
+    #[tokio::test]
+    async fn test_missing_records_return_empty() {
+        let port = start_server().await;
+        let resolver = DnsClient::resolver_for(IpAddr::V4(Ipv4Addr::LOCALHOST), port);
+
+        // 记录类型不存在与域名不存在都返回空列表
+        assert!(
+            DnsClient::lookup(&resolver, "example.test.", RecordType::CAA)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            DnsClient::lookup_with_ttl(&resolver, "missing.test.", RecordType::TXT)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
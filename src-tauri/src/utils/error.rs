@@ -1,3 +1,4 @@
+use crate::utils::i18n;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -14,19 +15,25 @@ pub enum DevToolError {
 }
 
 impl DevToolError {
-    pub fn to_localized_string(&self) -> String {
+    /// 消息目录中的键和参数
+    pub fn message_key(&self) -> (&'static str, Vec<&str>) {
         match self {
-            DevToolError::EmptyInput(field) => format!("{}不能为空", field),
-            DevToolError::ParseError(context, error) => format!("{}解析失败: {}", context, error),
-            DevToolError::NetworkError(error) => format!("网络请求失败: {}", error),
-            DevToolError::CertificateError(error) => format!("证书处理错误: {}", error),
-            DevToolError::ValidationError(error) => format!("验证失败: {}", error),
-            DevToolError::FileError(error) => format!("文件操作错误: {}", error),
-            DevToolError::SystemError(error) => format!("系统错误: {}", error),
-            DevToolError::ConversionError(error) => format!("格式转换失败: {}", error),
-            DevToolError::AsyncExecutionError => "异步执行失败".to_string(),
+            DevToolError::EmptyInput(field) => ("error.empty_input", vec![field]),
+            DevToolError::ParseError(context, error) => ("error.parse", vec![context, error]),
+            DevToolError::NetworkError(error) => ("error.network", vec![error]),
+            DevToolError::CertificateError(error) => ("error.certificate", vec![error]),
+            DevToolError::ValidationError(error) => ("error.validation", vec![error]),
+            DevToolError::FileError(error) => ("error.file", vec![error]),
+            DevToolError::SystemError(error) => ("error.system", vec![error]),
+            DevToolError::ConversionError(error) => ("error.conversion", vec![error]),
+            DevToolError::AsyncExecutionError => ("error.async_execution", vec![]),
         }
     }
+
+    pub fn to_localized_string(&self) -> String {
+        let (key, args) = self.message_key();
+        i18n::tr(key, &args)
+    }
 }
 
 impl From<DevToolError> for String {
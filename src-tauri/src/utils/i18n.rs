@@ -0,0 +1,402 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// 当前语言，启动时从系统设置读取
+static CURRENT: AtomicU8 = AtomicU8::new(Language::Zh as u8);
+
+/// 消息目录：键、中文、英文，{0}、{1} 为参数占位符
+///
+/// 目前覆盖系统设置、托盘菜单、通知、限速、SSL 检测、WHOIS、域名监控、URL 工具和
+/// `DevToolError` 的消息，其余工具的提示仍直接返回中文文本
+const CATALOG: &[(&str, &str, &str)] = &[
+    (
+        "common.domain_required",
+        "域名不能为空",
+        "Domain must not be empty",
+    ),
+    (
+        "ssl.certificate_expired",
+        "证书已过期",
+        "Certificate has expired",
+    ),
+    ("error.empty_input", "{0}不能为空", "{0} must not be empty"),
+    (
+        "error.parse",
+        "{0}解析失败: {1}",
+        "Failed to parse {0}: {1}",
+    ),
+    (
+        "error.network",
+        "网络请求失败: {0}",
+        "Network request failed: {0}",
+    ),
+    (
+        "error.certificate",
+        "证书处理错误: {0}",
+        "Certificate error: {0}",
+    ),
+    (
+        "error.validation",
+        "验证失败: {0}",
+        "Validation failed: {0}",
+    ),
+    (
+        "error.file",
+        "文件操作错误: {0}",
+        "File operation failed: {0}",
+    ),
+    ("error.system", "系统错误: {0}", "System error: {0}"),
+    (
+        "error.conversion",
+        "格式转换失败: {0}",
+        "Conversion failed: {0}",
+    ),
+    (
+        "error.async_execution",
+        "异步执行失败",
+        "Async execution failed",
+    ),
+    (
+        "settings.unsupported_language",
+        "不支持的语言: {0}",
+        "Unsupported language: {0}",
+    ),
+    (
+        "settings.unsupported_aggressiveness",
+        "不支持的请求强度: {0}",
+        "Unsupported request aggressiveness: {0}",
+    ),
+    (
+        "rate_limit.throttled",
+        "{0} 请求过于频繁，重试 {1} 次后仍被限流，请稍后再试",
+        "{0} is rate limiting requests and still refused after {1} retries, please try again later",
+    ),
+    ("tray.show", "显示", "Show"),
+    (
+        "tray.format_clipboard_json",
+        "格式化剪贴板中的 JSON",
+        "Format JSON in clipboard",
+    ),
+    (
+        "tray.check_clipboard_ssl",
+        "检测剪贴板中域名的 SSL",
+        "Check SSL for domain in clipboard",
+    ),
+    ("tray.quit", "退出", "Quit"),
+    (
+        "notification.cert_expiring",
+        "证书即将过期",
+        "Certificate expiring",
+    ),
+    (
+        "notification.domain_expiring",
+        "域名即将到期",
+        "Domain expiring",
+    ),
+    (
+        "notification.conversion_finished",
+        "转换完成",
+        "Conversion finished",
+    ),
+    ("notification.monitor_down", "服务不可用", "Monitor down"),
+    (
+        "notification.monitor_recovered",
+        "服务已恢复",
+        "Monitor recovered",
+    ),
+    (
+        "notification.task_failed",
+        "定时检查失败",
+        "Scheduled check failed",
+    ),
+    ("common.http_client_failed", "构建 HTTP 客户端失败: {0}", "Failed to build HTTP client: {0}"),
+    ("common.request_failed", "{0} 请求失败: {1}", "{0} request failed: {1}"),
+    ("common.unexpected_status", "{0} 响应状态异常: {1}", "{0} returned an unexpected status: {1}"),
+    ("common.parse_response_failed", "解析 {0} 响应失败: {1}", "Failed to parse {0} response: {1}"),
+    ("common.read_response_failed", "读取响应失败: {0}", "Failed to read response: {0}"),
+    ("common.set_read_timeout_failed", "设置读取超时失败: {0}", "Failed to set read timeout: {0}"),
+    ("common.set_write_timeout_failed", "设置写入超时失败: {0}", "Failed to set write timeout: {0}"),
+    ("common.create_dir_failed", "创建目录失败: {0}", "Failed to create directory: {0}"),
+    ("common.serialize_failed", "序列化失败: {0}", "Serialization failed: {0}"),
+    ("common.invalid_domain", "无效的域名: {0}", "Invalid domain: {0}"),
+    ("whois.verisign_com_net_only", "Verisign RDAP 仅支持 .com/.net", "Verisign RDAP only supports .com/.net"),
+    ("whois.resolve_server_failed", "解析 WHOIS 服务器地址失败: {0}", "Failed to resolve WHOIS server address: {0}"),
+    ("whois.server_no_address", "解析 WHOIS 服务器地址失败: 无有效地址", "Failed to resolve WHOIS server address: no usable address"),
+    ("whois.connect_failed", "连接 WHOIS 服务器失败: {0}", "Failed to connect to WHOIS server: {0}"),
+    ("whois.send_query_failed", "发送查询失败: {0}", "Failed to send query: {0}"),
+    ("whois.rdap_failed", "RDAP 查询失败: {0}; {1}", "RDAP lookup failed: {0}; {1}"),
+    ("whois.tld_unresolved", "无法解析域名 TLD", "Could not determine the domain's TLD"),
+    ("whois.server_unresolved", "无法解析 WHOIS 服务器", "Could not find a WHOIS server"),
+    ("whois.server_required", "WHOIS 服务器不能为空", "WHOIS server must not be empty"),
+    ("whois.auto_lookup_failed", "自动 WHOIS 查询失败", "Automatic WHOIS lookup failed"),
+    ("whois.write_cache_failed", "写入缓存失败: {0}", "Failed to write cache: {0}"),
+    ("domain_monitor.negative_notify_days", "提醒天数不能为负数", "Reminder days must not be negative"),
+    ("domain_monitor.already_watched", "域名 {0} 已在监控列表中", "Domain {0} is already being monitored"),
+    ("domain_monitor.watch_not_found", "监控项不存在", "Monitor entry not found"),
+    ("domain_monitor.days_remaining", "{0} 剩余 {1} 天", "{0} expires in {1} days"),
+    ("domain_monitor.expired", "{0} 已过期", "{0} has expired"),
+    ("domain_monitor.unparsable_expiry", "无法解析到期时间: {0}", "Could not parse expiry date: {0}"),
+    ("domain_monitor.expiry_not_found", "未查询到到期时间", "No expiry date found"),
+    ("domain_monitor.save_failed", "保存监控列表失败: {0}", "Failed to save watchlist: {0}"),
+    ("url.url_required", "URL 不能为空", "URL must not be empty"),
+    ("url.parse_failed", "URL 解析失败: {0}", "Failed to parse URL: {0}"),
+    ("url.scheme_host_required", "协议和主机不能为空", "Scheme and host must not be empty"),
+    ("url.build_failed", "URL 构建失败: {0}", "Failed to build URL: {0}"),
+    ("url.port_unsupported", "该协议不支持设置端口", "This scheme does not support a port"),
+    ("url.username_unsupported", "该协议不支持设置用户名", "This scheme does not support a username"),
+    ("url.password_unsupported", "该协议不支持设置密码", "This scheme does not support a password"),
+    ("url.form_empty", "表单内容为空", "Form body is empty"),
+    ("url.json_object_required", "顶层必须是 JSON 对象", "Top level must be a JSON object"),
+    ("url.unsupported_array_format", "不支持的数组格式: {0}", "Unsupported array format: {0}"),
+    ("url.idn_convert_failed", "域名转换失败: {0}", "Domain conversion failed: {0}"),
+    ("ssl.no_ip_for_domain", "未解析到域名的 IP 地址", "No IP address found for domain"),
+    ("ssl.dns_failed", "DNS 解析失败: {0}", "DNS resolution failed: {0}"),
+    ("ssl.unsupported_protocol_version", "不支持的协议版本", "Unsupported protocol version"),
+    ("ssl.invalid_server_name", "无效的域名", "Invalid domain name"),
+    ("ssl.tcp_timeout", "TCP 连接超时", "TCP connection timed out"),
+    ("ssl.tcp_failed", "TCP 连接失败: {0}", "TCP connection failed: {0}"),
+    ("ssl.tls_handshake_timeout", "TLS 握手超时", "TLS handshake timed out"),
+    ("ssl.tls_handshake_failed", "TLS 握手失败: {0}", "TLS handshake failed: {0}"),
+    ("ssl.no_certificate", "服务器未返回证书", "No certificate found"),
+    ("ssl.empty_chain", "证书链为空", "No certificates in chain"),
+    ("ssl.parse_certificate_failed", "解析证书失败: {0}", "Failed to parse certificate: {0}"),
+    ("ssl.parse_issuer_failed", "解析颁发者证书失败: {0}", "Failed to parse issuer certificate: {0}"),
+    ("ssl.no_ocsp_responder", "证书未提供 OCSP 响应器地址", "Certificate does not list an OCSP responder"),
+    ("ssl.build_ocsp_request_failed", "构建 OCSP 请求失败: {0}", "Failed to build OCSP request: {0}"),
+    ("ssl.ocsp_error_status", "OCSP 响应器返回错误状态: {0}", "OCSP responder returned error status: {0}"),
+    ("ssl.build_verifier_failed", "构建证书校验器失败: {0}", "Failed to build certificate verifier: {0}"),
+    ("ssl.ocsp_missing_intermediate", "服务器未发送中间证书，无法构建 OCSP 请求", "Server did not send an intermediate certificate, cannot build OCSP request"),
+    ("ssl.handshakes_failed", "TLS 1.2 与 TLS 1.3 握手均失败", "Both TLS 1.2 and TLS 1.3 handshakes failed"),
+    ("ssl.cert_not_linked", "证书 {0} 不在证书链中：找不到经签名验证的签发关系", "Certificate {0} is not part of the chain: no signature-verified issuer relationship found"),
+    ("ssl.cert_not_yet_valid", "证书尚未生效", "Certificate is not yet valid"),
+    ("ssl.sha1_deprecated", "使用已弃用的SHA-1签名算法", "Uses the deprecated SHA-1 signature algorithm"),
+    ("ssl.rsa_key_too_short", "RSA密钥长度过短: {0} 位", "RSA key is too short: {0} bits"),
+    ("ssl.chain_parse_failed", "解析证书 {0} 时出错: {1}", "Error parsing certificate {0}: {1}"),
+    ("ssl.chain_incomplete", "证书链不完整: 证书 {0} 的颁发者既不在证书链中也不在信任库中", "Chain is incomplete: the issuer of certificate {0} is neither in the chain nor in the trust store"),
+    ("ssl.self_signed", "自签名", "self-signed"),
+    ("ssl.root_ca", "根CA", "root CA"),
+    ("ssl.vuln.heartbleed.description", "OpenSSL TLS 心跳扩展信息泄露漏洞。攻击者可以从服务器或客户端读取最多 64KB 的内存数据。", "Information disclosure in the OpenSSL TLS heartbeat extension. An attacker can read up to 64KB of memory from the server or client."),
+    ("ssl.vuln.heartbleed.remediation", "将 OpenSSL 更新到 1.0.1g 或更高版本。对于 Web 服务器，更新后重启服务并重新颁发证书。", "Update OpenSSL to 1.0.1g or later. For web servers, restart the service and reissue certificates after updating."),
+    ("ssl.vuln.poodle.description", "降级传统加密填充甲骨文漏洞。攻击者可以通过向目标服务器发起多次请求来解密密文。", "Padding Oracle On Downgraded Legacy Encryption. An attacker can decrypt ciphertext by sending many requests to the target server."),
+    ("ssl.vuln.poodle.remediation", "禁用 SSL 3.0 和 TLS 1.0 中的 CBC 加密套件。使用 TLS 1.2 或更高版本。配置服务器拒绝 SSL/TLS 降级。", "Disable SSL 3.0 and CBC cipher suites in TLS 1.0. Use TLS 1.2 or later. Configure the server to refuse SSL/TLS downgrades."),
+    ("ssl.vuln.beast.description", "针对 SSL/TLS 的浏览器漏洞利用。攻击者可以通过利用 CBC 模式加密套件的弱点来解密 HTTPS 流量。", "Browser Exploit Against SSL/TLS. An attacker can decrypt HTTPS traffic by exploiting weaknesses in CBC-mode cipher suites."),
+    ("ssl.vuln.beast.remediation", "使用 TLS 1.1 或更高版本，或在 TLS 1.0 中优先使用 RC4/AEAD 加密套件并实施 1/n-1 record splitting。", "Use TLS 1.1 or later, or prefer RC4/AEAD cipher suites in TLS 1.0 and apply 1/n-1 record splitting."),
+    ("ssl.vuln.crime.description", "压缩率信息泄露漏洞。攻击者可以通过观察压缩数据大小来恢复加密会话中的明文内容。", "Compression Ratio Info-leak Made Easy. An attacker can recover plaintext from an encrypted session by observing compressed data sizes."),
+    ("ssl.vuln.crime.component", "TLS/SSL 压缩", "TLS/SSL compression"),
+    ("ssl.vuln.crime.remediation", "禁用 TLS/SSL 压缩。Apache 配置：SSLCompression Off。Nginx 配置：ssl_compression off;", "Disable TLS/SSL compression. Apache: SSLCompression Off. Nginx: ssl_compression off;"),
+    ("ssl.vuln.freak.description", "RSA 导出密钥分解攻击。攻击者可以通过强制降级到出口级加密来拦截连接。", "Factoring RSA Export Keys. An attacker can intercept connections by forcing a downgrade to export-grade encryption."),
+    ("ssl.vuln.freak.component", "出口级 RSA 加密套件", "Export-grade RSA cipher suites"),
+    ("ssl.vuln.freak.remediation", "从服务器配置中移除所有出口级加密套件。更新 OpenSSL 到补丁版本。", "Remove all export-grade cipher suites from the server configuration. Update OpenSSL to a patched version."),
+    ("ssl.vuln.logjam.description", "弱 Diffie-Hellman 密钥交换漏洞。攻击者可以破解 DH 密钥交换并解密流量。", "Weak Diffie-Hellman key exchange. An attacker can break the DH key exchange and decrypt traffic."),
+    ("ssl.vuln.logjam.component", "出口级 DH 参数", "Export-grade DH parameters"),
+    ("ssl.vuln.logjam.remediation", "使用 2048 位或更大的 DH 参数。禁用出口级加密套件。生成新的 DH 参数：openssl dhparam -out dhparams.pem 2048", "Use DH parameters of 2048 bits or more. Disable export-grade cipher suites. Generate new DH parameters: openssl dhparam -out dhparams.pem 2048"),
+    ("ssl.vuln.drown.description", "使用过时和弱化加密解密 RSA。攻击者可以通过利用 SSL 2.0 漏洞来破解 TLS 连接。", "Decrypting RSA with Obsolete and Weakened eNcryption. An attacker can break TLS connections by exploiting SSL 2.0 flaws."),
+    ("ssl.vuln.drown.remediation", "完全禁用 SSL 2.0。更新 OpenSSL 到补丁版本。检查并移除所有 SSL 2.0 配置。", "Disable SSL 2.0 completely. Update OpenSSL to a patched version. Find and remove all SSL 2.0 configuration."),
+    ("ssl.vuln.sweet32.description", "针对 64 位分组密码的生日攻击。当使用相同密钥加密大量数据时，攻击者可以恢复明文。", "Birthday attack on 64-bit block ciphers. When large amounts of data are encrypted with the same key, an attacker can recover plaintext."),
+    ("ssl.vuln.sweet32.component", "3DES/DES 加密套件", "3DES/DES cipher suites"),
+    ("ssl.vuln.sweet32.remediation", "禁用 3DES 和 DES 加密套件。改用 AES-GCM 或 ChaCha20-Poly1305。", "Disable 3DES and DES cipher suites. Use AES-GCM or ChaCha20-Poly1305 instead."),
+    ("ssl.vuln.rc4.description", "RC4 不再安全。多个漏洞允许从 RC4 加密的流量中恢复明文。", "RC4 is no longer secure. Multiple attacks allow plaintext recovery from RC4-encrypted traffic."),
+    ("ssl.vuln.rc4.component", "RC4 加密套件", "RC4 cipher suites"),
+    ("ssl.vuln.rc4.remediation", "禁用所有 RC4 加密套件。改用 AES-GCM 或 ChaCha20-Poly1305。", "Disable all RC4 cipher suites. Use AES-GCM or ChaCha20-Poly1305 instead."),
+    ("ssl.vuln.weak_key.name", "弱密钥长度", "Weak key size"),
+    ("ssl.vuln.weak_key.remediation_rsa", "生成至少2048位的新RSA密钥。推荐3072位或4096位以获得更好的长期安全性。", "Generate a new RSA key of at least 2048 bits. 3072 or 4096 bits is recommended for better long-term security."),
+    ("ssl.vuln.weak_key.remediation_ecc", "使用至少256位的ECC密钥（等效于2048位RSA）。推荐384位或521位曲线。", "Use an ECC key of at least 256 bits (equivalent to 2048-bit RSA). 384- or 521-bit curves are recommended."),
+    ("ssl.vuln.weak_key.remediation_other", "使用符合当前安全标准的密钥长度。", "Use a key size that meets current security standards."),
+    ("ssl.vuln.ssl2.name", "过时的 SSL 2.0 协议", "Obsolete SSL 2.0 protocol"),
+    ("ssl.vuln.ssl2.description", "SSL 2.0 已过时且包含多个已知漏洞。", "SSL 2.0 is obsolete and has multiple known vulnerabilities."),
+    ("ssl.vuln.ssl2.remediation", "完全禁用 SSL 2.0。使用 TLS 1.2 或更高版本。", "Disable SSL 2.0 completely. Use TLS 1.2 or later."),
+    ("ssl.vuln.ssl3.name", "过时的 SSL 3.0 协议", "Obsolete SSL 3.0 protocol"),
+    ("ssl.vuln.ssl3.description", "SSL 3.0 已过时且包含多个已知漏洞，包括 POODLE。", "SSL 3.0 is obsolete and has multiple known vulnerabilities, including POODLE."),
+    ("ssl.vuln.ssl3.remediation", "完全禁用 SSL 3.0。使用 TLS 1.2 或更高版本。", "Disable SSL 3.0 completely. Use TLS 1.2 or later."),
+    ("ssl.vuln.tls10.name", "弱 TLS 1.0 协议", "Weak TLS 1.0 protocol"),
+    ("ssl.vuln.tls10.description", "TLS 1.0 被认为较弱，应该弃用以支持更新的TLS版本。", "TLS 1.0 is considered weak and should be retired in favour of newer TLS versions."),
+    ("ssl.vuln.tls10.remediation", "禁用 TLS 1.0 并使用 TLS 1.2 或更高版本。如果必须支持，请实施相应的缓解措施。", "Disable TLS 1.0 and use TLS 1.2 or later. If it must stay enabled, apply the appropriate mitigations."),
+    ("ssl.vuln.tls11.name", "弱 TLS 1.1 协议", "Weak TLS 1.1 protocol"),
+    ("ssl.vuln.tls11.description", "TLS 1.1 被认为较弱，应该弃用以支持 TLS 1.2 或更高版本。", "TLS 1.1 is considered weak and should be retired in favour of TLS 1.2 or later."),
+    ("ssl.vuln.tls11.remediation", "禁用 TLS 1.1 并使用 TLS 1.2 或更高版本以获得更好的安全性。", "Disable TLS 1.1 and use TLS 1.2 or later for better security."),
+    ("ssl.vuln.weak_key.description", "{0}密钥长度{1}位不符合当前安全标准", "{0} key size of {1} bits does not meet current security standards"),
+    ("ssl.vuln.weak_key.component", "{0} 密钥 {1} 位", "{0} key, {1} bits"),
+    ("ssl.vuln.tls10_cbc_component", "TLS 1.0 的 CBC 加密套件", "TLS 1.0 with CBC ciphers"),
+    ("ssl.rating_errors", "存在严重安全问题", "Serious security issues found"),
+    ("ssl.rating_warnings", "存在安全警告", "Security warnings found"),
+    ("ssl.rating_good", "配置良好", "Well configured"),
+    ("ssl.advice.renew_now", "立即更新证书，考虑使用自动化证书管理工具如 Let's Encrypt certbot", "Renew the certificate now and consider automated certificate management such as Let's Encrypt certbot"),
+    ("ssl.expires_within_week", "证书将在一周内过期", "Certificate expires within a week"),
+    ("ssl.advice.renew_urgent", "紧急更新证书，建议设置证书到期提醒", "Renew the certificate urgently and set up expiry reminders"),
+    ("ssl.expires_within_30_days", "证书即将在30天内过期", "Certificate expires within 30 days"),
+    ("ssl.advice.renew_soon", "建议尽快更新证书，可以使用自动化工具如 Let's Encrypt certbot 或云服务商的证书管理服务", "Renew the certificate soon, for example with Let's Encrypt certbot or your cloud provider's certificate manager"),
+    ("ssl.advice.replace_weak_key", "立即更换为至少2048位的RSA密钥或256位的ECC密钥", "Replace it now with an RSA key of at least 2048 bits or a 256-bit ECC key"),
+    ("ssl.advice.rsa_2048", "建议使用至少2048位的RSA密钥。生成命令：openssl genrsa -out private.key 2048", "Use an RSA key of at least 2048 bits. Generate one with: openssl genrsa -out private.key 2048"),
+    ("ssl.advice.rsa_3072", "考虑使用3072位或4096位RSA密钥以提高长期安全性", "Consider a 3072- or 4096-bit RSA key for better long-term security"),
+    ("ssl.advice.ecc_256", "建议使用至少256位的ECC密钥（等效于2048位RSA）", "Use an ECC key of at least 256 bits (equivalent to 2048-bit RSA)"),
+    ("ssl.advice.ecc_good", "ECC密钥长度良好，提供了优秀的安全性和性能", "ECC key size is good and offers excellent security and performance"),
+    ("ssl.advice.check_key", "检查密钥算法和长度是否符合当前安全标准", "Check that the key algorithm and size meet current security standards"),
+    ("ssl.md5_signature", "使用了已完全破解的MD5签名算法", "Uses the completely broken MD5 signature algorithm"),
+    ("ssl.advice.replace_md5", "立即更换为SHA-256或更强的签名算法", "Switch to SHA-256 or a stronger signature algorithm now"),
+    ("ssl.sha1_signature", "使用了不安全的SHA-1签名算法", "Uses the insecure SHA-1 signature algorithm"),
+    ("ssl.advice.use_sha256", "建议使用SHA-256或更强的签名算法。配置示例：在证书请求中指定 -sha256 参数", "Use SHA-256 or a stronger signature algorithm, for example by passing -sha256 when creating the certificate request"),
+    ("ssl.advice.sha256_good", "SHA-256签名算法良好，符合当前安全标准", "SHA-256 signature algorithm is good and meets current security standards"),
+    ("ssl.advice.disable_weak_ciphers", "禁用弱加密套件。推荐配置：ECDHE+AESGCM:ECDHE+CHACHA20:DHE+AESGCM:DHE+CHACHA20:!aNULL:!MD5:!DSS:!3DES:!RC4", "Disable weak cipher suites. Recommended: ECDHE+AESGCM:ECDHE+CHACHA20:DHE+AESGCM:DHE+CHACHA20:!aNULL:!MD5:!DSS:!3DES:!RC4"),
+    ("ssl.advice.more_strong_ciphers", "增加高强度加密套件的比例，优先使用 AES-256-GCM、ChaCha20-Poly1305 等现代加密算法", "Increase the share of strong cipher suites and prefer modern algorithms such as AES-256-GCM and ChaCha20-Poly1305"),
+    ("ssl.no_forward_secrecy", "缺乏完美前向保密支持", "No forward secrecy support"),
+    ("ssl.advice.enable_pfs", "配置支持完美前向保密(PFS)的加密套件，如 ECDHE-RSA-AES256-GCM-SHA384", "Configure cipher suites with forward secrecy (PFS), such as ECDHE-RSA-AES256-GCM-SHA384"),
+    ("ssl.advice.score_excellent", "SSL/TLS配置优秀，建议定期检查更新", "SSL/TLS configuration is excellent; keep checking it regularly"),
+    ("ssl.advice.score_good", "SSL/TLS配置良好，仍有优化空间", "SSL/TLS configuration is good, with room for improvement"),
+    ("ssl.advice.score_fair", "SSL/TLS配置需要改进，存在安全风险", "SSL/TLS configuration needs improvement and carries security risks"),
+    ("ssl.advice.score_poor", "SSL/TLS配置存在严重安全风险，需要立即修复", "SSL/TLS configuration has serious security risks and needs fixing now"),
+    ("ssl.advice.enable_hsts_preload", "启用 HSTS：Strict-Transport-Security: max-age=31536000; includeSubDomains; preload", "Enable HSTS: Strict-Transport-Security: max-age=31536000; includeSubDomains; preload"),
+    ("ssl.advice.ocsp_stapling", "配置 OCSP Stapling 提高证书验证性能：ssl_stapling on; ssl_stapling_verify on;", "Enable OCSP stapling to speed up certificate validation: ssl_stapling on; ssl_stapling_verify on;"),
+    ("ssl.advice.disable_old_protocols", "禁用过时协议：仅启用 TLS 1.2 和 TLS 1.3，禁用 SSL 2.0/3.0 和 TLS 1.0/1.1", "Disable obsolete protocols: enable only TLS 1.2 and TLS 1.3, and disable SSL 2.0/3.0 and TLS 1.0/1.1"),
+    ("ssl.advice.monitor_expiry", "定期监控证书到期时间，建议使用证书监控服务或自动化续期", "Monitor certificate expiry regularly, using a monitoring service or automated renewal"),
+    ("ssl.advice.ct_monitoring", "考虑实施证书透明度(CT)日志监控，及时发现未授权证书签发", "Consider Certificate Transparency (CT) log monitoring to detect unauthorised issuance early"),
+    ("ssl.hsts.missing_max_age", "缺少有效的 max-age 指令", "Missing a valid max-age directive"),
+    ("ssl.hsts.max_age_zero", "max-age=0 会让浏览器清除 HSTS 策略", "max-age=0 makes browsers drop the HSTS policy"),
+    ("ssl.hsts.preload_requirements", "声明了 preload，但预加载列表要求 includeSubDomains 且 max-age 不少于 1 年", "preload is declared, but the preload list requires includeSubDomains and a max-age of at least 1 year"),
+    ("ssl.hsts.header_missing", "未设置 Strict-Transport-Security 响应头", "Strict-Transport-Security header is not set"),
+    ("ssl.advice.enable_hsts", "启用 HSTS：添加 Strict-Transport-Security: max-age=31536000; includeSubDomains", "Enable HSTS: add Strict-Transport-Security: max-age=31536000; includeSubDomains"),
+    ("ssl.advice.submit_preload", "已声明 preload，可在 hstspreload.org 提交加入预加载列表", "preload is declared; submit the domain at hstspreload.org to join the preload list"),
+    ("ssl.advice.add_caa", "未配置 CAA 记录，建议添加 CAA 以限制可为该域名签发证书的 CA", "No CAA records; add CAA to restrict which CAs may issue certificates for this domain"),
+    ("ssl.caa.issuer_unauthorized", "当前证书的颁发者不在 CAA 授权列表中", "The current certificate's issuer is not authorised by CAA"),
+    ("ssl.advice.update_caa", "更新 CAA 记录以包含当前 CA，否则证书续期可能被拒绝", "Update CAA to include the current CA, or certificate renewals may be refused"),
+    ("ssl.advice.caa_iodef", "可在 CAA 中添加 iodef 记录以接收违规签发报告", "Add an iodef CAA record to receive reports of policy violations"),
+    ("ssl.advice.update_tlsa", "更新 TLSA 记录以匹配当前证书，否则支持 DANE 的客户端会拒绝连接", "Update the TLSA records to match the current certificate, or DANE-aware clients will refuse to connect"),
+    ("ssl.unknown_error", "未知错误", "unknown error"),
+    ("ssl.rsa_key_critical", "RSA密钥长度严重不足: {0} 位", "RSA key is critically short: {0} bits"),
+    ("ssl.rsa_key_short", "RSA密钥长度不足: {0} 位", "RSA key is too short: {0} bits"),
+    ("ssl.ecc_key_short", "ECC密钥长度不足: {0} 位", "ECC key is too short: {0} bits"),
+    ("ssl.weak_ciphers_found", "发现 {0} 个弱加密套件", "Found {0} weak cipher suites"),
+    ("ssl.weak_cipher_ratio", "{0}% 的加密套件为弱加密", "{0}% of cipher suites are weak"),
+    ("ssl.hsts.max_age_short", "max-age 仅 {0} 秒，建议至少 180 天（15552000）", "max-age is only {0} seconds; at least 180 days (15552000) is recommended"),
+    ("ssl.dane.tlsa_mismatch", "{0} 的 TLSA 记录与服务器证书不匹配", "TLSA records at {0} do not match the server certificate"),
+    ("ssl.endpoint.unreachable", "{0} 无法建立 TLS 连接: {1}", "{0} could not establish a TLS connection: {1}"),
+    ("ssl.endpoint.certificate", "{0} → {1}（到期 {2}）", "{0} → {1} (expires {2})"),
+    ("ssl.endpoint.certificate_mismatch", "不同端点返回了 {0} 张不同的证书: {1}", "Endpoints returned {0} different certificates: {1}"),
+    ("ssl.endpoint.protocol_mismatch", "协议支持不一致: {0} 支持 [{1}]，{2} 支持 [{3}]", "Protocol support differs: {0} supports [{1}], {2} supports [{3}]"),
+    ("ssl.endpoint.alpn_mismatch", "ALPN 协商结果不一致: {0} 为 {1}，{2} 为 {3}", "ALPN negotiation differs: {0} chose {1}, {2} chose {3}"),
+    ("ssl.endpoint.none", "无", "none"),
+    ("ssl.endpoint.validation_failed", "{0} 的证书校验失败: {1}", "Certificate validation failed for {0}: {1}"),
+    ("ssl.invalid_ip", "无效的 IP 地址: {0}", "Invalid IP address: {0}"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    Zh = 0,
+    En = 1,
+}
+
+impl Language {
+    /// 解析语言代码，兼容 zh-CN、en_US 这类带地区的写法
+    pub fn from_code(code: &str) -> Option<Self> {
+        let primary = code
+            .trim()
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+        match primary.as_str() {
+            "zh" => Some(Language::Zh),
+            "en" => Some(Language::En),
+            _ => None,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::Zh => "zh",
+            Language::En => "en",
+        }
+    }
+}
+
+pub fn current_language() -> Language {
+    match CURRENT.load(Ordering::Relaxed) {
+        1 => Language::En,
+        _ => Language::Zh,
+    }
+}
+
+pub fn set_language(language: Language) {
+    CURRENT.store(language as u8, Ordering::Relaxed);
+}
+
+/// 按当前语言取消息文本
+pub fn t(key: &str) -> String {
+    tr(key, &[])
+}
+
+/// 按当前语言取消息文本并填充参数
+pub fn tr(key: &str, args: &[&str]) -> String {
+    tr_in(current_language(), key, args)
+}
+
+/// 按指定语言取消息文本，未收录的键原样返回
+pub fn tr_in(language: Language, key: &str, args: &[&str]) -> String {
+    let template = CATALOG
+        .iter()
+        .find(|(k, ..)| *k == key)
+        .map(|(_, zh, en)| match language {
+            Language::Zh => *zh,
+            Language::En => *en,
+        })
+        .unwrap_or(key);
+    args.iter()
+        .enumerate()
+        .fold(template.to_string(), |text, (index, arg)| {
+            text.replace(&format!("{{{}}}", index), arg)
+        })
+}
+
+/// 导出指定语言的完整消息目录
+pub fn catalog(language: Language) -> BTreeMap<String, String> {
+    CATALOG
+        .iter()
+        .map(|(key, ..)| (key.to_string(), tr_in(language, key, &[])))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_translate() {
+        assert_eq!(
+            tr_in(Language::Zh, "common.domain_required", &[]),
+            "域名不能为空"
+        );
+        assert_eq!(
+            tr_in(Language::En, "error.parse", &["JSON", "EOF"]),
+            "Failed to parse JSON: EOF"
+        );
+        assert_eq!(
+            tr_in(Language::Zh, "error.empty_input", &["证书"]),
+            "证书不能为空"
+        );
+        assert_eq!(tr_in(Language::En, "missing.key", &[]), "missing.key");
+
+        assert_eq!(Language::from_code("zh-CN"), Some(Language::Zh));
+        assert_eq!(Language::from_code("en_US"), Some(Language::En));
+        assert_eq!(Language::from_code("fr"), None);
+    }
+
+    #[test]
+    fn test_catalog_consistency() {
+        let placeholders = |text: &str| {
+            (0..5)
+                .filter(|i| text.contains(&format!("{{{}}}", i)))
+                .count()
+        };
+        for (index, (key, zh, en)) in CATALOG.iter().enumerate() {
+            assert!(!zh.is_empty() && !en.is_empty(), "{}", key);
+            assert_eq!(placeholders(zh), placeholders(en), "{}", key);
+            assert!(
+                CATALOG[..index].iter().all(|(k, ..)| k != key),
+                "重复的消息键 {}",
+                key
+            );
+        }
+        assert_eq!(catalog(Language::En).len(), CATALOG.len());
+    }
+}
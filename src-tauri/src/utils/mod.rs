@@ -1,5 +1,15 @@
+pub mod cert_path;
 pub mod code_formatter;
+pub mod cron;
 pub mod crypto;
+pub mod dns;
 pub mod error;
+pub mod i18n;
+pub mod notification;
+pub mod progress;
+pub mod proxy;
+pub mod rate_limit;
+pub mod ssh_key;
 pub mod string_utils;
+pub mod usage;
 pub mod validation;
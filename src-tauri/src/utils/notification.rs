@@ -0,0 +1,301 @@
+use crate::utils::i18n;
+use chrono::{Local, NaiveTime, Timelike};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock, RwLock};
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_notification::NotificationExt;
+
+/// 推送给前端通知中心的事件名
+pub const NOTIFICATION_EVENT: &str = "notification";
+
+/// 保留的通知历史条数
+const MAX_HISTORY: usize = 200;
+
+/// 当前通知设置，启动时从系统设置读取
+static SETTINGS: OnceLock<RwLock<NotificationSettings>> = OnceLock::new();
+
+/// 串行化通知历史文件的读写
+static HISTORY_LOCK: Mutex<()> = Mutex::new(());
+
+static SEQ: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationKind {
+    CertExpiring,       // 证书即将过期
+    DomainExpiring,     // 域名即将到期
+    ConversionFinished, // 文件转换完成或失败
+    MonitorDown,        // 可用性监控发现故障
+    MonitorRecovered,   // 可用性监控恢复
+    TaskFailed,         // 定时检查失败
+}
+
+impl NotificationKind {
+    fn title_key(&self) -> &'static str {
+        match self {
+            NotificationKind::CertExpiring => "notification.cert_expiring",
+            NotificationKind::DomainExpiring => "notification.domain_expiring",
+            NotificationKind::ConversionFinished => "notification.conversion_finished",
+            NotificationKind::MonitorDown => "notification.monitor_down",
+            NotificationKind::MonitorRecovered => "notification.monitor_recovered",
+            NotificationKind::TaskFailed => "notification.task_failed",
+        }
+    }
+}
+
+/// 免打扰时段，按本地时间，结束早于开始时表示跨越午夜
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuietHours {
+    pub start: String, // HH:MM
+    pub end: String,   // HH:MM
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NotificationSettings {
+    #[serde(default = "default_true")]
+    pub enabled: bool, // 总开关，关闭后仍记录历史但不弹出系统通知
+    #[serde(default)]
+    pub disabled_kinds: Vec<NotificationKind>, // 不弹出系统通知的事件类型
+    #[serde(default)]
+    pub quiet_hours: Option<QuietHours>,
+}
+
+impl Default for NotificationSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            disabled_kinds: Vec::new(),
+            quiet_hours: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationRecord {
+    pub id: String,
+    pub kind: NotificationKind,
+    pub title: String,
+    pub body: String,
+    pub created_at: String,
+    pub delivered: bool,                   // 是否弹出了系统通知
+    pub suppressed_reason: Option<String>, // disabled / kind_disabled / quiet_hours / error
+    #[serde(default)]
+    pub read: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn settings() -> &'static RwLock<NotificationSettings> {
+    SETTINGS.get_or_init(|| RwLock::new(NotificationSettings::default()))
+}
+
+pub fn current_settings() -> NotificationSettings {
+    settings().read().unwrap().clone()
+}
+
+pub fn set_settings(value: NotificationSettings) {
+    *settings().write().unwrap() = value;
+}
+
+pub fn validate(value: &NotificationSettings) -> Result<(), String> {
+    if let Some(quiet) = &value.quiet_hours {
+        parse_time(&quiet.start)?;
+        parse_time(&quiet.end)?;
+    }
+    Ok(())
+}
+
+/// 发送通知：按设置决定是否弹出系统通知，并写入历史、推送到前端
+pub fn notify(app: &AppHandle, kind: NotificationKind, body: &str) -> NotificationRecord {
+    let title = i18n::t(kind.title_key());
+    let mut suppressed_reason = suppress_reason(&current_settings(), kind, Local::now().time());
+    if suppressed_reason.is_none() {
+        if let Err(e) = app.notification().builder().title(&title).body(body).show() {
+            eprintln!("Failed to show notification: {}", e);
+            suppressed_reason = Some("error".to_string());
+        }
+    }
+
+    let now = Local::now();
+    let record = NotificationRecord {
+        id: format!(
+            "{:x}-{}",
+            now.timestamp_millis(),
+            SEQ.fetch_add(1, Ordering::Relaxed)
+        ),
+        kind,
+        title,
+        body: body.to_string(),
+        created_at: now.to_rfc3339(),
+        delivered: suppressed_reason.is_none(),
+        suppressed_reason,
+        read: false,
+    };
+    {
+        let _guard = HISTORY_LOCK.lock().unwrap();
+        let path = get_history_path();
+        let mut history = load_history(&path);
+        history.insert(0, record.clone());
+        history.truncate(MAX_HISTORY);
+        if let Err(e) = save_history(&path, &history) {
+            eprintln!("Failed to save notification history: {}", e);
+        }
+    }
+    let _ = app.emit(NOTIFICATION_EVENT, &record);
+    record
+}
+
+/// 查询通知历史，最新的在前
+pub fn history(
+    kind: Option<NotificationKind>,
+    unread_only: bool,
+    limit: Option<usize>,
+) -> Vec<NotificationRecord> {
+    let _guard = HISTORY_LOCK.lock().unwrap();
+    load_history(&get_history_path())
+        .into_iter()
+        .filter(|r| kind.is_none_or(|k| r.kind == k))
+        .filter(|r| !unread_only || !r.read)
+        .take(limit.unwrap_or(MAX_HISTORY))
+        .collect()
+}
+
+/// 标记为已读，ids 为空时标记全部
+pub fn mark_read(ids: Option<Vec<String>>) -> Result<usize, String> {
+    let _guard = HISTORY_LOCK.lock().unwrap();
+    let path = get_history_path();
+    let mut history = load_history(&path);
+    let mut count = 0;
+    for record in history
+        .iter_mut()
+        .filter(|r| !r.read && ids.as_ref().is_none_or(|ids| ids.contains(&r.id)))
+    {
+        record.read = true;
+        count += 1;
+    }
+    save_history(&path, &history)?;
+    Ok(count)
+}
+
+pub fn clear_history() -> Result<(), String> {
+    let _guard = HISTORY_LOCK.lock().unwrap();
+    save_history(&get_history_path(), &[])
+}
+
+/// 不弹出系统通知的原因，返回 None 表示正常弹出
+fn suppress_reason(
+    settings: &NotificationSettings,
+    kind: NotificationKind,
+    now: NaiveTime,
+) -> Option<String> {
+    if !settings.enabled {
+        return Some("disabled".to_string());
+    }
+    if settings.disabled_kinds.contains(&kind) {
+        return Some("kind_disabled".to_string());
+    }
+    let quiet = settings.quiet_hours.as_ref()?;
+    let (Ok(start), Ok(end)) = (parse_time(&quiet.start), parse_time(&quiet.end)) else {
+        return None;
+    };
+    in_quiet_hours(start, end, now).then(|| "quiet_hours".to_string())
+}
+
+fn in_quiet_hours(start: NaiveTime, end: NaiveTime, now: NaiveTime) -> bool {
+    let now = NaiveTime::from_hms_opt(now.hour(), now.minute(), 0).unwrap_or(now);
+    if start <= end {
+        start <= now && now < end
+    } else {
+        now >= start || now < end
+    }
+}
+
+fn parse_time(value: &str) -> Result<NaiveTime, String> {
+    NaiveTime::parse_from_str(value.trim(), "%H:%M")
+        .map_err(|_| format!("无效的时间: {}，格式应为 HH:MM", value))
+}
+
+fn get_history_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("devtools")
+        .join("notifications.json")
+}
+
+fn load_history(path: &Path) -> Vec<NotificationRecord> {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_history(path: &Path, history: &[NotificationRecord]) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("创建配置目录失败: {}", e))?;
+    }
+    let content =
+        serde_json::to_string(history).map_err(|e| format!("序列化通知历史失败: {}", e))?;
+    fs::write(path, content).map_err(|e| format!("保存通知历史失败: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(value: &str) -> NaiveTime {
+        parse_time(value).unwrap()
+    }
+
+    #[test]
+    fn test_in_quiet_hours() {
+        // 跨越午夜
+        assert!(in_quiet_hours(time("22:00"), time("08:00"), time("23:30")));
+        assert!(in_quiet_hours(time("22:00"), time("08:00"), time("07:59")));
+        assert!(!in_quiet_hours(time("22:00"), time("08:00"), time("08:00")));
+        assert!(!in_quiet_hours(time("22:00"), time("08:00"), time("12:00")));
+        // 同一天内
+        assert!(in_quiet_hours(time("12:00"), time("14:00"), time("13:00")));
+        assert!(!in_quiet_hours(time("12:00"), time("14:00"), time("14:30")));
+        assert!(parse_time("25:00").is_err());
+    }
+
+    #[test]
+    fn test_suppress_reason() {
+        let mut settings = NotificationSettings::default();
+        let noon = time("12:00");
+        assert_eq!(
+            suppress_reason(&settings, NotificationKind::MonitorDown, noon),
+            None
+        );
+
+        settings.disabled_kinds = vec![NotificationKind::ConversionFinished];
+        assert_eq!(
+            suppress_reason(&settings, NotificationKind::ConversionFinished, noon).as_deref(),
+            Some("kind_disabled")
+        );
+        assert_eq!(
+            suppress_reason(&settings, NotificationKind::MonitorDown, noon),
+            None
+        );
+
+        settings.quiet_hours = Some(QuietHours {
+            start: "11:00".to_string(),
+            end: "13:00".to_string(),
+        });
+        assert_eq!(
+            suppress_reason(&settings, NotificationKind::MonitorDown, noon).as_deref(),
+            Some("quiet_hours")
+        );
+
+        settings.enabled = false;
+        assert_eq!(
+            suppress_reason(&settings, NotificationKind::MonitorDown, noon).as_deref(),
+            Some("disabled")
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use crate::utils::usage;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Emitter};
+
+/// 所有长任务共用的进度事件名
+pub const PROGRESS_EVENT: &str = "task-progress";
+
+/// 两次 running 事件之间的最小间隔
+const EMIT_INTERVAL: Duration = Duration::from_millis(200);
+
+static TASK_SEQ: AtomicU64 = AtomicU64::new(0);
+
+pub type ProgressSink = Arc<dyn Fn(ProgressEvent) + Send + Sync>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProgressPhase {
+    Started,
+    Running,
+    Finished,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProgressEvent {
+    pub task_id: String,
+    pub tool: String, // 发起任务的工具，如 video_converter、archive_tool
+    pub phase: ProgressPhase,
+    pub percent: Option<f64>, // 总量未知时为空
+    pub message: Option<String>,
+    pub eta_secs: Option<u64>, // 按已用时间和当前进度线性估算
+    pub processed: u64,
+    pub total: Option<u64>,
+    pub elapsed_ms: u64,
+}
+
+/// 长任务进度上报器，负责节流、百分比和剩余时间计算
+pub struct ProgressReporter {
+    task_id: String,
+    tool: String,
+    sink: ProgressSink,
+    started: Instant,
+    last_emit: Option<Instant>,
+    processed: u64,
+    total: Option<u64>,
+}
+
+impl ProgressReporter {
+    /// 创建上报器并通过 task-progress 事件推送到前端
+    pub fn new(app: &AppHandle, tool: &str) -> Self {
+        let app = app.clone();
+        Self::with_sink(
+            tool,
+            Arc::new(move |event| {
+                let _ = app.emit(PROGRESS_EVENT, event);
+            }),
+        )
+    }
+
+    /// 使用自定义接收方创建上报器，立即发送 started 事件
+    pub fn with_sink(tool: &str, sink: ProgressSink) -> Self {
+        let millis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis())
+            .unwrap_or_default();
+        let reporter = Self {
+            task_id: format!(
+                "{}-{}-{}",
+                tool,
+                millis,
+                TASK_SEQ.fetch_add(1, Ordering::Relaxed)
+            ),
+            tool: tool.to_string(),
+            sink,
+            started: Instant::now(),
+            last_emit: None,
+            processed: 0,
+            total: None,
+        };
+        reporter.emit(ProgressPhase::Started, None);
+        reporter
+    }
+
+    pub fn task_id(&self) -> &str {
+        &self.task_id
+    }
+
+    /// 更新进度，距上次推送不足 200ms 时跳过（完成时总会推送）
+    pub fn update(&mut self, processed: u64, total: Option<u64>, message: Option<String>) {
+        self.processed = processed;
+        self.total = total;
+        let done = total.is_some_and(|t| processed >= t);
+        if !done
+            && self
+                .last_emit
+                .is_some_and(|last| last.elapsed() < EMIT_INTERVAL)
+        {
+            return;
+        }
+        self.last_emit = Some(Instant::now());
+        self.emit(ProgressPhase::Running, message);
+    }
+
+    pub fn finish(&mut self, message: Option<String>) {
+        if let Some(total) = self.total {
+            self.processed = self.processed.max(total);
+        }
+        self.emit(ProgressPhase::Finished, message);
+        usage::record_operation(&self.tool, self.started.elapsed(), true);
+    }
+
+    pub fn fail(&mut self, error: &str) {
+        self.emit(ProgressPhase::Failed, Some(error.to_string()));
+        usage::record_operation(&self.tool, self.started.elapsed(), false);
+    }
+
+    fn emit(&self, phase: ProgressPhase, message: Option<String>) {
+        let elapsed = self.started.elapsed();
+        let percent = self.total.map(|total| {
+            if total == 0 {
+                100.0
+            } else {
+                (self.processed.min(total) as f64 / total as f64 * 10000.0).round() / 100.0
+            }
+        });
+        let eta_secs = match (phase, self.total) {
+            (ProgressPhase::Running, Some(total)) if self.processed > 0 => {
+                let remaining = total.saturating_sub(self.processed) as f64;
+                Some((elapsed.as_secs_f64() / self.processed as f64 * remaining).ceil() as u64)
+            }
+            (ProgressPhase::Finished, _) => Some(0),
+            _ => None,
+        };
+        (self.sink)(ProgressEvent {
+            task_id: self.task_id.clone(),
+            tool: self.tool.clone(),
+            phase,
+            percent,
+            message,
+            eta_secs,
+            processed: self.processed,
+            total: self.total,
+            elapsed_ms: elapsed.as_millis() as u64,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    fn collecting_reporter(tool: &str) -> (ProgressReporter, Arc<Mutex<Vec<ProgressEvent>>>) {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink_events = events.clone();
+        let reporter = ProgressReporter::with_sink(
+            tool,
+            Arc::new(move |event| sink_events.lock().unwrap().push(event)),
+        );
+        (reporter, events)
+    }
+
+    #[test]
+    fn test_percent_and_eta() {
+        let (mut reporter, events) = collecting_reporter("checksum_tool");
+        std::thread::sleep(Duration::from_millis(20));
+        reporter.update(25, Some(100), Some("a.iso".to_string()));
+        reporter.finish(None);
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0].phase, ProgressPhase::Started);
+        assert!(events[0].task_id.starts_with("checksum_tool-"));
+        assert_eq!(events[1].percent, Some(25.0));
+        assert_eq!(events[1].message.as_deref(), Some("a.iso"));
+        // 已用约 20ms 完成 1/4，剩余 3/4 向上取整为 1 秒
+        assert_eq!(events[1].eta_secs, Some(1));
+        assert_eq!(events[2].phase, ProgressPhase::Finished);
+        assert_eq!(events[2].percent, Some(100.0));
+        assert!(events.iter().all(|e| e.task_id == reporter.task_id()));
+    }
+
+    #[test]
+    fn test_throttle_and_unknown_total() {
+        let (mut reporter, events) = collecting_reporter("log_analyzer");
+        for processed in 1..=50 {
+            reporter.update(processed, None, None);
+        }
+        reporter.update(100, Some(100), None);
+        reporter.fail("读取失败");
+
+        let events = events.lock().unwrap();
+        // started、首个 running、完成时强制推送的 running、failed
+        assert_eq!(events.len(), 4);
+        assert_eq!(events[1].percent, None);
+        assert_eq!(events[1].eta_secs, None);
+        assert_eq!(events[2].percent, Some(100.0));
+        assert_eq!(events[3].phase, ProgressPhase::Failed);
+        assert_eq!(events[3].message.as_deref(), Some("读取失败"));
+    }
+}
@@ -0,0 +1,209 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::{OnceLock, RwLock};
+
+/// 当前代理配置，启动时从系统设置读取
+static CONFIG: OnceLock<RwLock<ProxyConfig>> = OnceLock::new();
+
+const SUPPORTED_SCHEMES: &[&str] = &["http", "https", "socks5", "socks5h"];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProxyMode {
+    None,
+    #[default]
+    System,
+    Manual,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProxySettings {
+    #[serde(default)]
+    pub mode: ProxyMode, // none 直连，system 使用环境变量和系统代理，manual 使用下方地址
+    pub url: Option<String>, // http://、https://、socks5://、socks5h://（由代理解析域名）
+    pub username: Option<String>,
+    pub password: Option<String>,
+    #[serde(default)]
+    pub no_proxy: Vec<String>, // 直连的主机，支持 example.com、.example.com、IP 和 CIDR
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProxyConfig {
+    #[serde(default)]
+    pub global: ProxySettings,
+    #[serde(default)]
+    pub tool_overrides: BTreeMap<String, ProxySettings>, // 按工具覆盖全局设置，键为工具模块名，如 whois
+}
+
+impl ProxyConfig {
+    /// 工具有覆盖时使用覆盖，否则使用全局设置
+    fn resolve(&self, tool: &str) -> &ProxySettings {
+        self.tool_overrides.get(tool).unwrap_or(&self.global)
+    }
+}
+
+/// 环境变量中检测到的系统代理
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SystemProxyInfo {
+    pub http_proxy: Option<String>,
+    pub https_proxy: Option<String>,
+    pub all_proxy: Option<String>,
+    pub no_proxy: Option<String>,
+}
+
+fn config() -> &'static RwLock<ProxyConfig> {
+    CONFIG.get_or_init(|| RwLock::new(ProxyConfig::default()))
+}
+
+pub fn current_config() -> ProxyConfig {
+    config().read().unwrap().clone()
+}
+
+pub fn set_config(proxy: ProxyConfig) {
+    *config().write().unwrap() = proxy;
+}
+
+/// 工具实际使用的代理设置，有覆盖时优先使用覆盖
+pub fn settings_for(tool: &str) -> ProxySettings {
+    config().read().unwrap().resolve(tool).clone()
+}
+
+/// 校验全局设置和全部工具覆盖
+pub fn validate(proxy: &ProxyConfig) -> Result<(), String> {
+    build_proxy(&proxy.global).map_err(|e| format!("全局代理: {}", e))?;
+    for (tool, settings) in &proxy.tool_overrides {
+        build_proxy(settings).map_err(|e| format!("{} 的代理: {}", tool, e))?;
+    }
+    Ok(())
+}
+
+/// 按 manual 模式的设置构造 reqwest 代理，其他模式返回 None
+fn build_proxy(settings: &ProxySettings) -> Result<Option<reqwest::Proxy>, String> {
+    if settings.mode != ProxyMode::Manual {
+        return Ok(None);
+    }
+    let url = settings
+        .url
+        .as_deref()
+        .map(str::trim)
+        .filter(|url| !url.is_empty())
+        .ok_or_else(|| "代理地址不能为空".to_string())?;
+    let parsed = reqwest::Url::parse(url).map_err(|e| format!("代理地址无效: {}", e))?;
+    if !SUPPORTED_SCHEMES.contains(&parsed.scheme()) {
+        return Err(format!("不支持的代理协议: {}", parsed.scheme()));
+    }
+    if parsed.host_str().is_none() {
+        return Err("代理地址缺少主机".to_string());
+    }
+
+    let mut proxy = reqwest::Proxy::all(url).map_err(|e| format!("代理地址无效: {}", e))?;
+    if let Some(username) = settings.username.as_deref().filter(|u| !u.is_empty()) {
+        proxy = proxy.basic_auth(username, settings.password.as_deref().unwrap_or_default());
+    }
+    let no_proxy: Vec<&str> = settings
+        .no_proxy
+        .iter()
+        .map(|host| host.trim())
+        .filter(|host| !host.is_empty())
+        .collect();
+    Ok(Some(proxy.no_proxy(reqwest::NoProxy::from_string(
+        &no_proxy.join(","),
+    ))))
+}
+
+/// 按指定设置创建 HTTP 客户端构造器
+pub fn client_builder_with(settings: &ProxySettings) -> Result<reqwest::ClientBuilder, String> {
+    let builder = reqwest::Client::builder();
+    Ok(match settings.mode {
+        ProxyMode::None => builder.no_proxy(),
+        // reqwest 默认读取 HTTP(S)_PROXY 等环境变量以及 macOS/Windows 的系统代理
+        ProxyMode::System => builder,
+        ProxyMode::Manual => match build_proxy(settings)? {
+            Some(proxy) => builder.proxy(proxy),
+            None => builder,
+        },
+    })
+}
+
+/// 创建应用了该工具代理设置的 HTTP 客户端构造器，保存时已校验过配置
+pub fn client_builder(tool: &str) -> reqwest::ClientBuilder {
+    client_builder_with(&settings_for(tool)).unwrap_or_else(|e| {
+        eprintln!("{} 的代理配置无效，改为直连: {}", tool, e);
+        reqwest::Client::builder().no_proxy()
+    })
+}
+
+/// 读取环境变量中的系统代理，大小写两种写法都检查
+pub fn detect_system_proxy() -> SystemProxyInfo {
+    let env = |name: &str| {
+        std::env::var(name)
+            .or_else(|_| std::env::var(name.to_lowercase()))
+            .ok()
+            .filter(|value| !value.trim().is_empty())
+    };
+    SystemProxyInfo {
+        http_proxy: env("HTTP_PROXY"),
+        https_proxy: env("HTTPS_PROXY"),
+        all_proxy: env("ALL_PROXY"),
+        no_proxy: env("NO_PROXY"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manual(url: &str) -> ProxySettings {
+        ProxySettings {
+            mode: ProxyMode::Manual,
+            url: Some(url.to_string()),
+            username: Some("user".to_string()),
+            password: Some("secret".to_string()),
+            no_proxy: vec!["localhost".to_string(), ".corp.example".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_validate_proxy_settings() {
+        assert!(validate(&ProxyConfig::default()).is_ok());
+        assert!(build_proxy(&manual("http://proxy.local:3128"))
+            .unwrap()
+            .is_some());
+        assert!(build_proxy(&manual("socks5h://127.0.0.1:1080"))
+            .unwrap()
+            .is_some());
+        assert!(build_proxy(&manual("ftp://proxy.local")).is_err());
+        assert!(build_proxy(&manual("  ")).is_err());
+
+        let mut proxy = ProxyConfig::default();
+        proxy
+            .tool_overrides
+            .insert("whois".to_string(), manual("gopher://x"));
+        let error = validate(&proxy).unwrap_err();
+        assert!(error.starts_with("whois 的代理"), "{}", error);
+    }
+
+    #[test]
+    fn test_tool_override() {
+        let mut proxy = ProxyConfig {
+            global: manual("http://proxy.local:3128"),
+            ..Default::default()
+        };
+        proxy.tool_overrides.insert(
+            "ip_info".to_string(),
+            ProxySettings {
+                mode: ProxyMode::None,
+                ..Default::default()
+            },
+        );
+        assert_eq!(proxy.resolve("ip_info").mode, ProxyMode::None);
+        assert_eq!(proxy.resolve("whois"), &proxy.global);
+        assert!(client_builder_with(proxy.resolve("whois"))
+            .unwrap()
+            .build()
+            .is_ok());
+
+        let parsed: ProxyConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!(parsed.global.mode, ProxyMode::System);
+    }
+}
@@ -0,0 +1,280 @@
+use crate::utils::i18n;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// 当前请求强度，启动时从系统设置读取
+static AGGRESSIVENESS: AtomicU8 = AtomicU8::new(Aggressiveness::Normal as u8);
+
+static GATES: OnceLock<Mutex<HashMap<String, Arc<Gate>>>> = OnceLock::new();
+
+/// 各服务方的默认限制：名称、最大并发、相邻请求最小间隔（毫秒）
+const PROVIDER_LIMITS: &[(&str, usize, u64)] = &[
+    ("rdap.org", 2, 500),
+    ("rdap.verisign.com", 4, 200),
+    ("whois", 1, 1000), // 按 WHOIS 服务器分别计数，键为 whois:<server>
+    ("ipinfo.io", 2, 300),
+    ("ip-api.com", 1, 1400), // 免费接口每分钟 45 次
+    ("crt.sh", 1, 2000),
+    ("tls", 2, 500), // 按目标主机分别计数，键为 tls:<host>
+];
+const DEFAULT_LIMIT: (usize, u64) = (4, 100);
+
+const BACKOFF_BASE: Duration = Duration::from_millis(500);
+const BACKOFF_MAX: Duration = Duration::from_secs(30);
+/// Retry-After 超过该值时不再等待
+const RETRY_AFTER_MAX: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Aggressiveness {
+    Gentle = 0,
+    Normal = 1,
+    Aggressive = 2,
+}
+
+impl Aggressiveness {
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code.trim().to_lowercase().as_str() {
+            "gentle" => Some(Aggressiveness::Gentle),
+            "normal" => Some(Aggressiveness::Normal),
+            "aggressive" => Some(Aggressiveness::Aggressive),
+            _ => None,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Aggressiveness::Gentle => "gentle",
+            Aggressiveness::Normal => "normal",
+            Aggressiveness::Aggressive => "aggressive",
+        }
+    }
+
+    /// 被限流后的最大重试次数
+    pub fn max_retries(&self) -> u32 {
+        match self {
+            Aggressiveness::Gentle => 4,
+            Aggressiveness::Normal => 3,
+            Aggressiveness::Aggressive => 1,
+        }
+    }
+
+    /// 按强度调整服务方的并发上限和请求间隔
+    fn scale(&self, (max_concurrent, interval_ms): (usize, u64)) -> (usize, Duration) {
+        let (max_concurrent, interval_ms) = match self {
+            Aggressiveness::Gentle => ((max_concurrent / 2).max(1), interval_ms * 2),
+            Aggressiveness::Normal => (max_concurrent, interval_ms),
+            Aggressiveness::Aggressive => (max_concurrent * 2, interval_ms / 2),
+        };
+        (max_concurrent, Duration::from_millis(interval_ms))
+    }
+}
+
+struct Gate {
+    semaphore: Arc<Semaphore>,
+    interval: Duration,
+    next_slot: Mutex<Instant>, // 下一个请求最早的发出时间
+}
+
+/// 限流许可，持有期间占用服务方的一个并发名额
+pub struct RateLimitPermit {
+    _permit: OwnedSemaphorePermit,
+}
+
+pub fn current_aggressiveness() -> Aggressiveness {
+    match AGGRESSIVENESS.load(Ordering::Relaxed) {
+        0 => Aggressiveness::Gentle,
+        2 => Aggressiveness::Aggressive,
+        _ => Aggressiveness::Normal,
+    }
+}
+
+/// 切换请求强度，已有的限流状态随之重建
+pub fn set_aggressiveness(aggressiveness: Aggressiveness) {
+    AGGRESSIVENESS.store(aggressiveness as u8, Ordering::Relaxed);
+    gates().lock().unwrap().clear();
+}
+
+fn gates() -> &'static Mutex<HashMap<String, Arc<Gate>>> {
+    GATES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// 查找服务方限制，whois:<server> 这类键按冒号前的名称匹配
+fn provider_limit(provider: &str) -> (usize, u64) {
+    let name = provider.split(':').next().unwrap_or(provider);
+    PROVIDER_LIMITS
+        .iter()
+        .find(|(key, ..)| *key == provider || *key == name)
+        .map(|(_, max_concurrent, interval_ms)| (*max_concurrent, *interval_ms))
+        .unwrap_or(DEFAULT_LIMIT)
+}
+
+fn gate(provider: &str) -> Arc<Gate> {
+    gates()
+        .lock()
+        .unwrap()
+        .entry(provider.to_string())
+        .or_insert_with(|| {
+            let (max_concurrent, interval) =
+                current_aggressiveness().scale(provider_limit(provider));
+            Arc::new(Gate {
+                semaphore: Arc::new(Semaphore::new(max_concurrent)),
+                interval,
+                next_slot: Mutex::new(Instant::now()),
+            })
+        })
+        .clone()
+}
+
+/// 等待服务方的并发名额和请求间隔
+pub async fn acquire(provider: &str) -> RateLimitPermit {
+    let gate = gate(provider);
+    let permit = gate
+        .semaphore
+        .clone()
+        .acquire_owned()
+        .await
+        .expect("限流信号量不会被关闭");
+    let wait = {
+        let mut next_slot = gate.next_slot.lock().unwrap();
+        let now = Instant::now();
+        let start = (*next_slot).max(now);
+        *next_slot = start + gate.interval;
+        start - now
+    };
+    if !wait.is_zero() {
+        tokio::time::sleep(wait).await;
+    }
+    RateLimitPermit { _permit: permit }
+}
+
+/// 被限流后推迟该服务方的所有后续请求，避免并发请求继续撞上限流
+pub fn penalize(provider: &str, delay: Duration) {
+    let gate = gate(provider);
+    let mut next_slot = gate.next_slot.lock().unwrap();
+    *next_slot = (*next_slot).max(Instant::now() + delay);
+}
+
+/// 第 attempt 次重试前的等待时间，优先使用服务端给出的 Retry-After
+pub fn backoff_delay(attempt: u32, retry_after: Option<Duration>) -> Duration {
+    match retry_after {
+        Some(delay) => delay.min(RETRY_AFTER_MAX),
+        None => BACKOFF_BASE
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(BACKOFF_MAX),
+    }
+}
+
+/// 解析 Retry-After，支持秒数和 HTTP 日期两种格式
+pub fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    let seconds = (date.timestamp() - chrono::Utc::now().timestamp()).max(0);
+    Some(Duration::from_secs(seconds as u64))
+}
+
+/// 服务方提示请求过多时的状态码
+fn is_throttled(status: reqwest::StatusCode) -> bool {
+    status == reqwest::StatusCode::TOO_MANY_REQUESTS
+        || status == reqwest::StatusCode::SERVICE_UNAVAILABLE
+}
+
+/// 按服务方限流发送请求，429/503 时按 Retry-After 或指数退避自动重试
+pub async fn send_with_retry<F>(provider: &str, build: F) -> Result<reqwest::Response, String>
+where
+    F: Fn() -> reqwest::RequestBuilder,
+{
+    let max_retries = current_aggressiveness().max_retries();
+    let mut attempt = 0;
+    loop {
+        let permit = acquire(provider).await;
+        let response = build().send().await.map_err(|e| e.to_string())?;
+        drop(permit);
+        if !is_throttled(response.status()) {
+            return Ok(response);
+        }
+        if attempt >= max_retries {
+            return Err(i18n::tr(
+                "rate_limit.throttled",
+                &[provider, &attempt.to_string()],
+            ));
+        }
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_retry_after);
+        let delay = backoff_delay(attempt, retry_after);
+        penalize(provider, delay);
+        attempt += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_and_retry_after() {
+        assert_eq!(backoff_delay(0, None), Duration::from_millis(500));
+        assert_eq!(backoff_delay(3, None), Duration::from_secs(4));
+        assert_eq!(backoff_delay(20, None), BACKOFF_MAX);
+        assert_eq!(
+            backoff_delay(0, Some(Duration::from_secs(600))),
+            RETRY_AFTER_MAX
+        );
+
+        assert_eq!(parse_retry_after("120"), Some(Duration::from_secs(120)));
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon"), None);
+
+        assert_eq!(provider_limit("whois:whois.verisign-grs.com"), (1, 1000));
+        assert_eq!(provider_limit("unknown.example"), DEFAULT_LIMIT);
+        assert_eq!(
+            Aggressiveness::Gentle.scale((2, 500)),
+            (1, Duration::from_secs(1))
+        );
+        assert_eq!(
+            Aggressiveness::from_code("Aggressive"),
+            Some(Aggressiveness::Aggressive)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_acquire_spaces_requests() {
+        // 未收录的服务方默认间隔 100 毫秒
+        let provider = "limiter-test.local";
+        let start = Instant::now();
+        for _ in 0..3 {
+            drop(acquire(provider).await);
+        }
+        assert!(start.elapsed() >= Duration::from_millis(200));
+
+        let permits: Vec<_> = hold_permits(provider, 4).await;
+        assert_eq!(gate(provider).semaphore.available_permits(), 0);
+        drop(permits);
+
+        penalize(provider, Duration::from_millis(500));
+        let start = Instant::now();
+        drop(acquire(provider).await);
+        assert!(start.elapsed() >= Duration::from_millis(450));
+    }
+
+    async fn hold_permits(provider: &str, count: usize) -> Vec<RateLimitPermit> {
+        let mut permits = Vec::new();
+        for _ in 0..count {
+            permits.push(acquire(provider).await);
+        }
+        permits
+    }
+}
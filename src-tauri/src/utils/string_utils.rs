@@ -27,6 +27,41 @@ impl StringUtils {
         Self::convert_case(s, false)
     }
 
+    /// 转换为 snake_case，连续大写视为缩写
+    ///
+    /// # Examples
+    /// ```
+    /// assert_eq!(StringUtils::to_snake_case("UserID"), "user_id");
+    /// assert_eq!(StringUtils::to_snake_case("HTTPServer"), "http_server");
+    /// ```
+    pub fn to_snake_case(s: &str) -> String {
+        let chars: Vec<char> = s.chars().collect();
+        let mut result = String::new();
+
+        for (i, &c) in chars.iter().enumerate() {
+            if c == '-' || c == ' ' || c == '.' {
+                if !result.ends_with('_') && !result.is_empty() {
+                    result.push('_');
+                }
+                continue;
+            }
+            if c.is_uppercase() && i > 0 {
+                let prev = chars[i - 1];
+                let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+                if (prev.is_lowercase()
+                    || prev.is_ascii_digit()
+                    || (prev.is_uppercase() && next_is_lower))
+                    && !result.ends_with('_')
+                {
+                    result.push('_');
+                }
+            }
+            result.extend(c.to_lowercase());
+        }
+
+        result
+    }
+
     /// 通用大小写转换
     fn convert_case(s: &str, capitalize_first: bool) -> String {
         if s.is_empty() {
@@ -146,7 +181,6 @@ impl StringUtils {
     }
 
     /// 移除字符串中的非打印字符和多余空白
-    #[allow(dead_code)]
     pub fn clean_string(s: &str) -> String {
         s.chars()
             .filter(|c| !c.is_control() || c.is_whitespace())
@@ -171,6 +205,14 @@ impl StringUtils {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_to_snake_case() {
+        assert_eq!(StringUtils::to_snake_case("UserID"), "user_id");
+        assert_eq!(StringUtils::to_snake_case("HTTPServer"), "http_server");
+        assert_eq!(StringUtils::to_snake_case("createdAt"), "created_at");
+        assert_eq!(StringUtils::to_snake_case("Address2Line"), "address2_line");
+    }
+
     #[test]
     fn test_to_pascal_case() {
         assert_eq!(StringUtils::to_pascal_case("hello_world"), "HelloWorld");
@@ -0,0 +1,331 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tauri::ipc::Invoke;
+
+/// 是否记录使用情况，默认关闭，启动时从系统设置读取
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+static STATE: OnceLock<Mutex<UsageState>> = OnceLock::new();
+
+/// 两次写入统计文件之间的最小间隔
+const SAVE_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CommandUsage {
+    pub count: u64,
+    pub last_used: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OperationUsage {
+    pub count: u64,
+    pub failures: u64,
+    pub total_ms: u64,
+    pub max_ms: u64,
+    pub last_used: String,
+}
+
+/// 保存在本地的统计数据，不会上传
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UsageStats {
+    pub since: Option<String>, // 开始统计的时间
+    #[serde(default)]
+    pub commands: BTreeMap<String, CommandUsage>, // 键为命令名
+    #[serde(default)]
+    pub operations: BTreeMap<String, OperationUsage>, // 长任务耗时，键为工具名
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommandStat {
+    pub command: String,
+    pub count: u64,
+    pub last_used: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperationStat {
+    pub tool: String,
+    pub count: u64,
+    pub failures: u64,
+    pub avg_ms: u64,
+    pub max_ms: u64,
+    pub last_used: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UsageReport {
+    pub enabled: bool,
+    pub since: Option<String>,
+    pub total_invocations: u64,
+    pub commands: Vec<CommandStat>,     // 按调用次数降序
+    pub operations: Vec<OperationStat>, // 按执行次数降序
+}
+
+struct UsageState {
+    stats: UsageStats,
+    dirty: bool,
+    last_saved: Instant,
+}
+
+fn state() -> &'static Mutex<UsageState> {
+    STATE.get_or_init(|| {
+        Mutex::new(UsageState {
+            stats: load_stats(&get_stats_path()),
+            dirty: false,
+            last_saved: Instant::now(),
+        })
+    })
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// 关闭时先写入尚未保存的数据
+pub fn set_enabled(enabled: bool) {
+    if !enabled && is_enabled() {
+        let _ = flush();
+    }
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// 包装命令处理器，分发前记录调用的命令
+pub fn track<F>(handler: F) -> impl Fn(Invoke) -> bool + Send + Sync + 'static
+where
+    F: Fn(Invoke) -> bool + Send + Sync + 'static,
+{
+    move |invoke| {
+        record_command(invoke.message.command());
+        handler(invoke)
+    }
+}
+
+pub fn record_command(command: &str) {
+    if !is_enabled() {
+        return;
+    }
+    update(|stats, now| add_command(stats, command, now));
+}
+
+/// 记录一次长任务的耗时，由进度上报器在任务结束时调用
+pub fn record_operation(tool: &str, elapsed: Duration, success: bool) {
+    if !is_enabled() {
+        return;
+    }
+    update(|stats, now| add_operation(stats, tool, elapsed, success, now));
+}
+
+pub fn report() -> UsageReport {
+    let _ = flush();
+    let guard = state().lock().unwrap();
+    build_report(&guard.stats, is_enabled())
+}
+
+pub fn clear() -> Result<(), String> {
+    let mut guard = state().lock().unwrap();
+    guard.stats = UsageStats::default();
+    guard.dirty = false;
+    guard.last_saved = Instant::now();
+    save_stats(&get_stats_path(), &guard.stats)
+}
+
+/// 丢弃内存中的统计并重新读取文件，用于导入备份之后
+pub fn reload() {
+    let mut guard = state().lock().unwrap();
+    guard.stats = load_stats(&get_stats_path());
+    guard.dirty = false;
+    guard.last_saved = Instant::now();
+}
+
+/// 导出统计报告为 JSON 文件
+pub fn export(path: &Path) -> Result<UsageReport, String> {
+    let report = report();
+    let content =
+        serde_json::to_string_pretty(&report).map_err(|e| format!("序列化使用统计失败: {}", e))?;
+    fs::write(path, content).map_err(|e| format!("导出使用统计失败: {}", e))?;
+    Ok(report)
+}
+
+pub fn flush() -> Result<(), String> {
+    let mut guard = state().lock().unwrap();
+    if !guard.dirty {
+        return Ok(());
+    }
+    guard.dirty = false;
+    guard.last_saved = Instant::now();
+    save_stats(&get_stats_path(), &guard.stats)
+}
+
+fn update<F: FnOnce(&mut UsageStats, &str)>(apply: F) {
+    let now = chrono::Local::now().to_rfc3339();
+    let mut guard = state().lock().unwrap();
+    apply(&mut guard.stats, &now);
+    guard.dirty = true;
+    if guard.last_saved.elapsed() >= SAVE_INTERVAL {
+        guard.dirty = false;
+        guard.last_saved = Instant::now();
+        if let Err(e) = save_stats(&get_stats_path(), &guard.stats) {
+            eprintln!("Failed to save usage stats: {}", e);
+        }
+    }
+}
+
+fn add_command(stats: &mut UsageStats, command: &str, now: &str) {
+    stats.since.get_or_insert_with(|| now.to_string());
+    let entry = stats.commands.entry(command.to_string()).or_default();
+    entry.count += 1;
+    entry.last_used = now.to_string();
+}
+
+fn add_operation(stats: &mut UsageStats, tool: &str, elapsed: Duration, success: bool, now: &str) {
+    stats.since.get_or_insert_with(|| now.to_string());
+    let millis = elapsed.as_millis() as u64;
+    let entry = stats.operations.entry(tool.to_string()).or_default();
+    entry.count += 1;
+    if !success {
+        entry.failures += 1;
+    }
+    entry.total_ms += millis;
+    entry.max_ms = entry.max_ms.max(millis);
+    entry.last_used = now.to_string();
+}
+
+fn build_report(stats: &UsageStats, enabled: bool) -> UsageReport {
+    let mut commands: Vec<CommandStat> = stats
+        .commands
+        .iter()
+        .map(|(command, usage)| CommandStat {
+            command: command.clone(),
+            count: usage.count,
+            last_used: usage.last_used.clone(),
+        })
+        .collect();
+    // 次数相同时按名称排序，BTreeMap 已保证名称有序
+    commands.sort_by_key(|c| std::cmp::Reverse(c.count));
+
+    let mut operations: Vec<OperationStat> = stats
+        .operations
+        .iter()
+        .map(|(tool, usage)| OperationStat {
+            tool: tool.clone(),
+            count: usage.count,
+            failures: usage.failures,
+            avg_ms: usage.total_ms / usage.count.max(1),
+            max_ms: usage.max_ms,
+            last_used: usage.last_used.clone(),
+        })
+        .collect();
+    operations.sort_by_key(|o| std::cmp::Reverse(o.count));
+
+    UsageReport {
+        enabled,
+        since: stats.since.clone(),
+        total_invocations: commands.iter().map(|c| c.count).sum(),
+        commands,
+        operations,
+    }
+}
+
+fn get_stats_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("devtools")
+        .join("usage-stats.json")
+}
+
+fn load_stats(path: &Path) -> UsageStats {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_stats(path: &Path, stats: &UsageStats) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("创建配置目录失败: {}", e))?;
+    }
+    let content = serde_json::to_string(stats).map_err(|e| format!("序列化使用统计失败: {}", e))?;
+    fs::write(path, content).map_err(|e| format!("保存使用统计失败: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_report() {
+        let mut stats = UsageStats::default();
+        for command in [
+            "check_ssl_info",
+            "format_json",
+            "format_json",
+            "query_whois",
+        ] {
+            add_command(&mut stats, command, "2026-01-01T00:00:00+08:00");
+        }
+        add_command(&mut stats, "check_ssl_info", "2026-01-02T00:00:00+08:00");
+        add_command(&mut stats, "format_json", "2026-01-03T00:00:00+08:00");
+
+        let report = build_report(&stats, true);
+        assert_eq!(report.since.as_deref(), Some("2026-01-01T00:00:00+08:00"));
+        assert_eq!(report.total_invocations, 6);
+        let order: Vec<(&str, u64)> = report
+            .commands
+            .iter()
+            .map(|c| (c.command.as_str(), c.count))
+            .collect();
+        assert_eq!(
+            order,
+            vec![
+                ("format_json", 3),
+                ("check_ssl_info", 2),
+                ("query_whois", 1)
+            ]
+        );
+        assert_eq!(report.commands[1].last_used, "2026-01-02T00:00:00+08:00");
+    }
+
+    #[test]
+    fn test_operation_durations() {
+        let mut stats = UsageStats::default();
+        let now = "2026-01-01T00:00:00+08:00";
+        add_operation(
+            &mut stats,
+            "video_converter",
+            Duration::from_millis(1000),
+            true,
+            now,
+        );
+        add_operation(
+            &mut stats,
+            "video_converter",
+            Duration::from_millis(3000),
+            false,
+            now,
+        );
+        add_operation(
+            &mut stats,
+            "checksum_tool",
+            Duration::from_millis(50),
+            true,
+            now,
+        );
+
+        let report = build_report(&stats, false);
+        assert!(!report.enabled);
+        assert_eq!(report.total_invocations, 0);
+        let video = &report.operations[0];
+        assert_eq!(video.tool, "video_converter");
+        assert_eq!((video.count, video.failures), (2, 1));
+        assert_eq!((video.avg_ms, video.max_ms), (2000, 3000));
+
+        // 反序列化旧文件时缺少的字段使用默认值
+        let parsed: UsageStats = serde_json::from_str("{\"since\":null}").unwrap();
+        assert!(parsed.commands.is_empty() && parsed.operations.is_empty());
+    }
+}
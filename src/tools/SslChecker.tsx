@@ -1089,26 +1089,21 @@ const SslChecker: React.FC = () => {
                                           {/* Trust Status Badge */}
                                           <span
                                             className={`px-2 py-1 text-xs font-medium rounded-full ${
-                                              node.trust_status === 'end-entity'
-                                                ? 'bg-blue-100 text-blue-800 dark:bg-blue-900 dark:text-blue-200'
+                                              node.trust_status === 'trusted'
+                                                ? 'bg-green-100 text-green-800 dark:bg-green-900 dark:text-green-200'
                                                 : node.trust_status ===
                                                   'self-signed'
                                                 ? 'bg-orange-100 text-orange-800 dark:bg-orange-900 dark:text-orange-200'
-                                                : node.trust_status ===
-                                                  'root-ca'
-                                                ? 'bg-green-100 text-green-800 dark:bg-green-900 dark:text-green-200'
-                                                : 'bg-purple-100 text-purple-800 dark:bg-purple-900 dark:text-purple-200'
+                                                : 'bg-red-100 text-red-800 dark:bg-red-900 dark:text-red-200'
                                             }`}>
-                                            {node.trust_status === 'end-entity'
-                                              ? '服务器证书'
+                                            {node.trust_status === 'trusted'
+                                              ? '已验证'
                                               : node.trust_status ===
                                                 'self-signed'
                                               ? '自签名'
-                                              : node.trust_status === 'root-ca'
-                                              ? '根CA'
                                               : node.trust_status ===
-                                                'intermediate'
-                                              ? '中间CA'
+                                                'untrusted'
+                                              ? '不受信任'
                                               : node.trust_status}
                                           </span>
                                         </div>